@@ -59,7 +59,7 @@ export class ActivityService {
         item_type: activityData.item_type || "ceremony",
         participants: activityData.participants || 0,
         date: activityData.date || createISOTime,
-        state: activityData.state || "upcoming",
+        state: activityData.state || "active",
         icon: activityData.icon || "🕯️",
         description: activityData.description || "",
         location: activityData.location || "",
@@ -361,7 +361,7 @@ export class ActivityService {
    * @returns {Promise<Object>} 活動列表
    */
   async getUpcomingActivities() {
-    return this.getActivitiesByState("upcoming");
+    return this.getActivitiesByState("active");
   }
 
   /**
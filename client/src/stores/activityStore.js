@@ -28,6 +28,12 @@ export const useActivityStore = defineStore("activity", () => {
 
   // ========== 工具函數 ==========
 
+  /**
+   * 尚未結束的活動狀態：planning（籌劃中）、active（進行中）
+   * 舊資料的 upcoming / ongoing 視為 active
+   */
+  const OPEN_ACTIVITY_STATES = ["planning", "active", "upcoming", "ongoing"];
+
   /**
    * 計算一年前的日期
    */
@@ -76,11 +82,11 @@ export const useActivityStore = defineStore("activity", () => {
   });
 
   /**
-   * 獲取即將到來的活動
+   * 獲取即將到來的活動（籌劃中與進行中）
    */
   const upcomingActivities = computed(() => {
     return activities1Year.value.filter((activity) => {
-      return activity.state === "upcoming";
+      return OPEN_ACTIVITY_STATES.includes(activity.state);
     });
   });
 
@@ -97,7 +103,7 @@ export const useActivityStore = defineStore("activity", () => {
     // 取得即將到來的活動，日期排序最近的兩筆
     return activities1Year.value
       .filter((activity) => {
-        return activity.state === "upcoming";
+        return OPEN_ACTIVITY_STATES.includes(activity.state);
       })
       .sort((a, b) => {
         const dateA = new Date(a.date);
@@ -420,7 +426,7 @@ export const useActivityStore = defineStore("activity", () => {
         ...newActivity,
         item_type: newActivity.item_type,
         participants: newActivity.participants || 0,
-        state: newActivity.state || "upcoming",
+        state: newActivity.state || "active",
         createdAt: createISOTime,
         createdUser: getCurrentUser(),
         updatedAt: "",
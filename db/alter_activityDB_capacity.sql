@@ -0,0 +1,2 @@
+-- 活動加入名額上限（依參加記錄筆數計算，不含已取消），NULL 表示不限
+ALTER TABLE `activityDB` ADD COLUMN `capacity` integer NULL;
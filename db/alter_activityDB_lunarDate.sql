@@ -0,0 +1,4 @@
+-- 活動加入農曆日期（依農曆排定的法會），國曆 date 由農曆換算；lunarLeapMonth = 1 表示閏月
+ALTER TABLE `activityDB` ADD COLUMN `lunarMonth` integer NULL;
+ALTER TABLE `activityDB` ADD COLUMN `lunarDay` integer NULL;
+ALTER TABLE `activityDB` ADD COLUMN `lunarLeapMonth` integer NULL DEFAULT '0';
@@ -0,0 +1,4 @@
+-- 活動狀態統一為 planning / active / completed / cancelled（舊資料的 upcoming / ongoing 改為 active）
+UPDATE `activityDB` SET `state` = 'active' WHERE `state` IN ('upcoming', 'ongoing');
+UPDATE `activityDB` SET `state` = 'cancelled' WHERE `state` = 'canceled';
+UPDATE `activityDB` SET `state` = 'planning' WHERE `state` IS NULL OR `state` = '';
@@ -0,0 +1,6 @@
+-- 活動加入範本 ID（activityTemplateDB.id）、可報名項目與價格配置（priceConfigDB.id），-1 表示未使用範本 / 生效中的價格配置
+ALTER TABLE `activityDB` ADD COLUMN `templateId` integer NULL DEFAULT '-1';
+ALTER TABLE `activityDB` ADD COLUMN `allowedItems` json NULL;
+ALTER TABLE `activityDB` ADD COLUMN `priceConfigId` integer NULL DEFAULT '-1';
+
+CREATE INDEX `idx_activity_template` ON `activityDB` (`templateId`);
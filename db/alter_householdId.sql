@@ -0,0 +1,8 @@
+-- 報名、每月贊助、參加記錄加入戶ID（householdDB.id），-1 表示尚未歸戶
+ALTER TABLE `registrationDB` ADD COLUMN `householdId` integer NULL DEFAULT '-1';
+ALTER TABLE `monthlyDonateDB` ADD COLUMN `householdId` integer NULL DEFAULT '-1';
+ALTER TABLE `joinRecordDB` ADD COLUMN `householdId` integer NULL DEFAULT '-1';
+
+CREATE INDEX `idx_registration_household` ON `registrationDB` (`householdId`);
+CREATE INDEX `idx_monthlydonate_household` ON `monthlyDonateDB` (`householdId`);
+CREATE INDEX `idx_joinrecord_household` ON `joinRecordDB` (`householdId`);
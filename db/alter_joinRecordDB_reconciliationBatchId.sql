@@ -0,0 +1,4 @@
+-- 參加記錄加入沖帳批次 ID（reconciliationBatchDB.id），-1 表示尚未批次沖帳
+ALTER TABLE `joinRecordDB` ADD COLUMN `reconciliationBatchId` integer NULL DEFAULT '-1';
+
+CREATE INDEX `idx_joinrecord_reconciliation_batch` ON `joinRecordDB` (`reconciliationBatchId`);
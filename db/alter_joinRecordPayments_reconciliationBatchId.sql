@@ -0,0 +1,16 @@
+-- 付款流水加入沖帳批次 ID（reconciliationBatchDB.id），-1 表示尚未沖帳
+-- 分次付款時每筆流水各自沖帳，後續付款不會因參加記錄已沖帳而被遺漏
+ALTER TABLE `joinRecordPayments` ADD COLUMN `reconciliationBatchId` integer NULL DEFAULT '-1';
+
+CREATE INDEX `idx_joinrecordpayments_reconciliation_batch` ON `joinRecordPayments` (`reconciliationBatchId`);
+
+-- 既有資料：參加記錄所屬的有效批次營業日（含）之前的流水視為已由該批次沖帳
+UPDATE `joinRecordPayments` SET `reconciliationBatchId` = COALESCE((
+    SELECT b.id
+    FROM joinRecordDB j
+    JOIN reconciliationBatchDB b ON b.id = j.reconciliationBatchId
+    WHERE j.id = joinRecordPayments.joinRecordId
+      AND j.accountingState = 'reconciled'
+      AND b.state = 'reconciled'
+      AND date(joinRecordPayments.paymentDate, 'localtime') <= b.batchDate
+), -1);
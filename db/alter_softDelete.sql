@@ -0,0 +1,21 @@
+-- 活動、報名、參加記錄、每月贊助、價格配置、表單資料改為軟刪除：刪除時記錄時間（毫秒時間戳）與刪除者，
+-- 所有查詢排除 date_deleted 不為 NULL 的資料，超過保留期限後由背景工作永久刪除
+ALTER TABLE `activityDB` ADD COLUMN `user_deleted` char(36) NULL;
+ALTER TABLE `activityDB` ADD COLUMN `date_deleted` datetime NULL;
+ALTER TABLE `registrationDB` ADD COLUMN `user_deleted` char(36) NULL;
+ALTER TABLE `registrationDB` ADD COLUMN `date_deleted` datetime NULL;
+ALTER TABLE `joinRecordDB` ADD COLUMN `user_deleted` char(36) NULL;
+ALTER TABLE `joinRecordDB` ADD COLUMN `date_deleted` datetime NULL;
+ALTER TABLE `monthlyDonateDB` ADD COLUMN `user_deleted` char(36) NULL;
+ALTER TABLE `monthlyDonateDB` ADD COLUMN `date_deleted` datetime NULL;
+ALTER TABLE `priceConfigDB` ADD COLUMN `user_deleted` char(36) NULL;
+ALTER TABLE `priceConfigDB` ADD COLUMN `date_deleted` datetime NULL;
+ALTER TABLE `mydata` ADD COLUMN `user_deleted` char(36) NULL;
+ALTER TABLE `mydata` ADD COLUMN `date_deleted` datetime NULL;
+
+CREATE INDEX `idx_activity_deleted` ON `activityDB` (`date_deleted`);
+CREATE INDEX `idx_registration_deleted` ON `registrationDB` (`date_deleted`);
+CREATE INDEX `idx_joinrecord_deleted` ON `joinRecordDB` (`date_deleted`);
+CREATE INDEX `idx_monthlydonate_deleted` ON `monthlyDonateDB` (`date_deleted`);
+CREATE INDEX `idx_priceconfig_deleted` ON `priceConfigDB` (`date_deleted`);
+CREATE INDEX `idx_mydata_deleted` ON `mydata` (`date_deleted`);
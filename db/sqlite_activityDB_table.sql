@@ -4,15 +4,24 @@ CREATE TABLE "activityDB" (
     "date_created" datetime NULL,
     "user_updated" char(36) NULL,
     "date_updated" datetime NULL,
+    "user_deleted" char(36) NULL, # 刪除者，軟刪除時記錄
+    "date_deleted" datetime NULL, # 刪除時間（毫秒時間戳），NULL 表示未刪除
     "activityId" varchar(255) NULL,
     "name" varchar(255) NULL,
     "item_type" varchar(255) NULL,
     "participants" integer NULL DEFAULT '0',
     "date" varchar(255) NULL,
-    "state" varchar(255) NULL,
+    "state" varchar(255) NULL, # planning / active / completed / cancelled
     "icon" varchar(255) NULL DEFAULT '🕯️',
     "description" text NULL,
     "location" varchar(255) NULL,
+    "lunarMonth" integer NULL,
+    "lunarDay" integer NULL,
+    "lunarLeapMonth" integer NULL DEFAULT '0',
+    "templateId" integer NULL DEFAULT '-1',
+    "allowedItems" json NULL,
+    "priceConfigId" integer NULL DEFAULT '-1',
+    "capacity" integer NULL,
     "createdAt" varchar(255) NULL,
     "updatedAt" varchar(255) NULL,    
     CONSTRAINT "activitydb_user_created_foreign" FOREIGN KEY ("user_created") REFERENCES "directus_users" ("id"),
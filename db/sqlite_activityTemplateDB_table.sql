@@ -0,0 +1,26 @@
+CREATE TABLE "activityTemplateDB" (
+    `id` integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+    `user_created` char(36) NULL,
+    `date_created` datetime NULL,
+    `user_updated` char(36) NULL,
+    `date_updated` datetime NULL,
+    `name` varchar(255) NULL, # 活動名稱，可用 {year}（西元）、{rocYear}（民國）代入年份，例如 "{rocYear}新春禮懺法會"
+    `item_type` varchar(255) NULL DEFAULT 'ceremony',
+    `icon` varchar(255) NULL DEFAULT '🕯️',
+    `description` text NULL,
+    `location` varchar(255) NULL,
+    `lunarMonth` integer NULL, # 農曆月日（依農曆排定），與 solarMonth / solarDay 擇一
+    `lunarDay` integer NULL,
+    `lunarLeapMonth` integer NULL DEFAULT '0',
+    `solarMonth` integer NULL, # 國曆月日（依國曆排定）
+    `solarDay` integer NULL,
+    `time` varchar(255) NULL DEFAULT '09:00', # 活動開始時間 HH:MM
+    `allowedItems` json NULL, # 可報名的項目類型，例如 ["chaodu", "survivors", "pudu"]；NULL 表示不限
+    `priceConfigId` integer NULL DEFAULT '-1', # 使用的價格配置（priceConfigDB.id），-1 表示生效中的配置
+    `state` varchar(255) NULL DEFAULT 'active', # "active" 使用中、"inactive" 停用
+    `notes` varchar(255) NULL,
+    `createdAt` varchar(255) NULL,
+    `updatedAt` varchar(255) NULL,
+    CONSTRAINT `activitytemplatedb_user_created_foreign` FOREIGN KEY (`user_created`) REFERENCES `directus_users` (`id`),
+    CONSTRAINT `activitytemplatedb_user_updated_foreign` FOREIGN KEY (`user_updated`) REFERENCES `directus_users` (`id`)
+);
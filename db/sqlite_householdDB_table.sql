@@ -0,0 +1,19 @@
+CREATE TABLE "householdDB" (
+    `id` integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+    `user_created` char(36) NULL,
+    `date_created` datetime NULL,
+    `user_updated` char(36) NULL,
+    `date_updated` datetime NULL,
+    `headName` varchar(255) NULL, # 戶長姓名（消災人員 isHouseholdHead，沒有戶長時取聯絡人姓名）
+    `mobile` varchar(255) NULL, # 建立時的聯絡手機
+    `phone` varchar(255) NULL, # 建立時的聯絡電話
+    `address` varchar(255) NULL, # 消災地址，沒有時取超度地址
+    `state` varchar(255) NULL DEFAULT 'active', # "active" 使用中
+    `notes` varchar(255) NULL,
+    `createdAt` varchar(255) NULL,
+    `updatedAt` varchar(255) NULL,
+    CONSTRAINT `householddb_user_created_foreign` FOREIGN KEY (`user_created`) REFERENCES `directus_users` (`id`),
+    CONSTRAINT `householddb_user_updated_foreign` FOREIGN KEY (`user_updated`) REFERENCES `directus_users` (`id`)
+);
+
+CREATE INDEX `idx_household_head_name` ON `householdDB` (`headName`);
@@ -4,6 +4,8 @@ CREATE TABLE "joinRecordDB" (
     `date_created` datetime NULL,
     `user_updated` char(36) NULL,
     `date_updated` datetime NULL,
+    `user_deleted` char(36) NULL, # 刪除者，軟刪除時記錄
+    `date_deleted` datetime NULL, # 刪除時間（毫秒時間戳），NULL 表示未刪除
     `registrationId` integer null default '-1', # 報名 ID，registrationDB.id
     `activityId` integer null default '-1', # 活動 ID，activityDB.id
     `state` varchar(255) null,
@@ -30,6 +32,8 @@ CREATE TABLE "joinRecordDB" (
     `updatedAt` varchar(255) null,
     `contact` json null default '{}',
     `receiptId` integer null default '-1', # 打印ID，receiptNumberDB.id
+    `reconciliationBatchId` integer null default '-1', # 沖帳批次 ID，reconciliationBatchDB.id
+    `householdId` integer null default '-1', # 戶ID，householdDB.id，-1 表示尚未歸戶
     CONSTRAINT `participationrecorddb_user_created_foreign` FOREIGN KEY (`user_created`) REFERENCES `directus_users` (`id`),
     CONSTRAINT `participationrecorddb_user_updated_foreign` FOREIGN KEY (`user_updated`) REFERENCES `directus_users` (`id`)
   )
@@ -0,0 +1,29 @@
+CREATE TABLE "joinRecordPayments" (
+    `id` integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+    `user_created` char(36) NULL,
+    `date_created` datetime NULL,
+    `user_updated` char(36) NULL,
+    `date_updated` datetime NULL,
+    `joinRecordId` integer NOT NULL, # 參加記錄 ID，joinRecordDB.id
+    `paymentType` varchar(255) NOT NULL DEFAULT 'payment', # "payment" 付款, "refund" 退款, "reversal" 沖銷（更正錯誤的付款/退款）
+    `amount` integer NOT NULL DEFAULT '0', # 有號金額：付款為正數，退款與沖銷付款為負數，joinRecordDB.paidAmount = SUM(amount)
+    `paymentMethod` varchar(255) NULL, # "cash" 現金, "transfer" 銀行轉帳, "card" 信用卡
+    `paymentDate` varchar(255) NULL,
+    `handledBy` varchar(255) NULL, # 經手人
+    `state` varchar(255) NULL DEFAULT 'active', # "active" 有效, "reversed" 已被沖銷
+    `reversedPaymentId` integer NULL DEFAULT '-1', # 沖銷列指向被沖銷的 joinRecordPayments.id，其餘為 -1
+    `reconciliationBatchId` integer NULL DEFAULT '-1', # 沖帳批次 ID，reconciliationBatchDB.id，-1 表示尚未沖帳
+    `notes` varchar(255) NULL,
+    `createdAt` varchar(255) NULL,
+    `updatedAt` varchar(255) NULL,
+    CONSTRAINT `joinrecordpayments_join_record_foreign` FOREIGN KEY (`joinRecordId`) REFERENCES `joinRecordDB` (`id`) ON DELETE CASCADE,
+    CONSTRAINT `joinrecordpayments_user_created_foreign` FOREIGN KEY (`user_created`) REFERENCES `directus_users` (`id`),
+    CONSTRAINT `joinrecordpayments_user_updated_foreign` FOREIGN KEY (`user_updated`) REFERENCES `directus_users` (`id`)
+);
+
+-- 依參加記錄查詢流水
+CREATE INDEX `idx_joinrecordpayments_join_record` ON `joinRecordPayments` (`joinRecordId`);
+-- 依付款日期查詢（日結、對帳）
+CREATE INDEX `idx_joinrecordpayments_payment_date` ON `joinRecordPayments` (`paymentDate`);
+-- 依沖帳批次查詢（作廢批次時還原）
+CREATE INDEX `idx_joinrecordpayments_reconciliation_batch` ON `joinRecordPayments` (`reconciliationBatchId`);
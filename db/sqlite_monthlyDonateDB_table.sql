@@ -4,6 +4,8 @@ CREATE TABLE "monthlyDonateDB" (
     "date_created" datetime NULL,
     "user_updated" char(36) NULL,
     "date_updated" datetime NULL,
+    "user_deleted" char(36) NULL, # 刪除者，軟刪除時記錄
+    "date_deleted" datetime NULL, # 刪除時間（毫秒時間戳），NULL 表示未刪除
     "name" varchar(255) null,
     "registrationId" integer null default '-1',
     "donateId" varchar(255) null,
@@ -12,6 +14,7 @@ CREATE TABLE "monthlyDonateDB" (
     "memo" text null,
     "createdAt" varchar(255) null,
     "updatedAt" varchar(255) null,
+    "householdId" integer null default '-1', # 戶ID，householdDB.id，-1 表示尚未歸戶
     CONSTRAINT "monthlydonatedb_user_created_foreign" FOREIGN KEY ("user_created") REFERENCES "directus_users" ("id"),
     CONSTRAINT "monthlydonatedb_user_updated_foreign" FOREIGN KEY ("user_updated") REFERENCES "directus_users" ("id")
 );
\ No newline at end of file
@@ -4,6 +4,8 @@ CREATE TABLE "priceConfigDB" (
     "date_created" datetime NULL,
     "user_updated" char(36) NULL,
     "date_updated" datetime NULL,
+    "user_deleted" char(36) NULL, # 刪除者，軟刪除時記錄
+    "date_deleted" datetime NULL, # 刪除時間（毫秒時間戳），NULL 表示未刪除
     "version" varchar(255) null,
     "state" varchar(255) null,
     "prices" json null,
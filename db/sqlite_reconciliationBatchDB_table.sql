@@ -0,0 +1,29 @@
+CREATE TABLE "reconciliationBatchDB" (
+    `id` integer PRIMARY KEY AUTOINCREMENT NOT NULL,
+    `user_created` char(36) NULL,
+    `date_created` datetime NULL,
+    `user_updated` char(36) NULL,
+    `date_updated` datetime NULL,
+    `batchNumber` varchar(255) NOT NULL, # 沖帳批號，格式 RC + YYYYMMDD + '-' + 當日流水號（例如 RC20261019-01）
+    `batchDate` varchar(255) NOT NULL, # 沖帳的營業日（YYYY-MM-DD，依伺服器本地時區）
+    `paymentMethod` varchar(255) NULL, # 篩選的付款方式，空值表示全部
+    `expectedCash` integer NULL DEFAULT '0', # 系統計算應收現金
+    `expectedTransfer` integer NULL DEFAULT '0', # 系統計算應收轉帳
+    `expectedOther` integer NULL DEFAULT '0', # 其他付款方式（信用卡、未填寫等）
+    `expectedTotal` integer NULL DEFAULT '0',
+    `countedAmount` integer NULL DEFAULT '0', # 錢櫃實點現金
+    `variance` integer NULL DEFAULT '0', # 差額 = countedAmount - expectedCash
+    `recordCount` integer NULL DEFAULT '0',
+    `recordIds` json NULL DEFAULT '[]', # 本批沖帳的 joinRecordDB.id 陣列
+    `state` varchar(255) NULL DEFAULT 'reconciled', # "reconciled" 已沖帳, "void" 已作廢
+    `reconciledBy` varchar(255) NULL, # 沖帳者
+    `notes` varchar(255) NULL,
+    `voidReason` varchar(255) NULL,
+    `createdAt` varchar(255) NULL,
+    `updatedAt` varchar(255) NULL,
+    CONSTRAINT `reconciliationbatchdb_user_created_foreign` FOREIGN KEY (`user_created`) REFERENCES `directus_users` (`id`),
+    CONSTRAINT `reconciliationbatchdb_user_updated_foreign` FOREIGN KEY (`user_updated`) REFERENCES `directus_users` (`id`)
+);
+
+CREATE UNIQUE INDEX `idx_reconciliationbatch_number` ON `reconciliationBatchDB` (`batchNumber`);
+CREATE INDEX `idx_reconciliationbatch_date` ON `reconciliationBatchDB` (`batchDate`);
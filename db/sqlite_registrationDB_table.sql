@@ -4,6 +4,8 @@ CREATE TABLE "registrationDB" (
     "date_created" datetime NULL,
     "user_updated" char(36) NULL,
     "date_updated" datetime NULL,
+    "user_deleted" char(36) NULL, # 刪除者，軟刪除時記錄
+    "date_deleted" datetime NULL, # 刪除時間（毫秒時間戳），NULL 表示未刪除
     "state" varchar(255) NULL,
     "formId" varchar(255) NULL,
     "formName" varchar(255) NULL,
@@ -13,6 +15,7 @@ CREATE TABLE "registrationDB" (
     "blessing" json NULL,
     "createdAt" varchar(255) null,
     "updatedAt" varchar(255) null,
+    "householdId" integer null default '-1', # 戶ID，householdDB.id，-1 表示尚未歸戶
     CONSTRAINT "registrationdb_user_created_foreign" FOREIGN KEY ("user_created") REFERENCES "directus_users" ("id"),
     CONSTRAINT "registrationdb_user_updated_foreign" FOREIGN KEY ("user_updated") REFERENCES "directus_users" ("id")
 );
\ No newline at end of file
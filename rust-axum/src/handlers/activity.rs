@@ -4,15 +4,25 @@ use axum::{
     http::StatusCode,
     Json,
 };
-use sqlx::SqlitePool;
+use chrono::NaiveDate;
+use sqlx::{SqliteConnection, SqlitePool};
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use crate::models::api_response::{ApiResponse, Meta};
 
 use crate::models::activity::{
     Activity, ActivityQuery, CreateActivityRequest, UpdateActivityRequest, ActivityResponse,
+    ActivityParticipantCounts, ActivityState, ItemTypeCount, ActivityAutoCompleteResult,
 };
+use crate::models::json_column::JsonColumn;
+use crate::models::json_types::JoinItem;
+use crate::models::soft_delete::DeleteQuery;
+use crate::handlers::soft_delete;
+use crate::handlers::join_record_item::{item_quantity, item_type_label, item_type_order, ITEM_TYPES};
+use crate::utils::lunar::{self, LunarDate};
 
-const ACTIVITY_FULL_QUERY: &str = r#"
+/// 活動完整欄位查詢，已刪除的活動以子查詢排除
+pub(crate) const ACTIVITY_FULL_QUERY: &str = r#"
 SELECT 
     id,
     user_created,
@@ -36,22 +46,382 @@ SELECT
     icon,
     description,
     location,
+    lunarMonth,
+    lunarDay,
+    lunarLeapMonth,
+    templateId,
+    allowedItems,
+    priceConfigId,
+    capacity,
     createdAt,
     updatedAt
-FROM activityDB
+FROM (SELECT * FROM activityDB WHERE date_deleted IS NULL) AS activityDB
 "#;
 
+/// 依農曆日期換算活動的國曆 date：沿用原 date 的時間部分（沒有時為 09:00），
+/// 未指定農曆年時取原 date 所在的農曆年，兩者皆無則為今年
+pub(crate) fn resolve_lunar_date(
+    date: Option<&str>,
+    lunar_year: Option<i32>,
+    month: u32,
+    day: u32,
+    is_leap: bool,
+) -> Result<(String, LunarDate), String> {
+    let date = date.map(str::trim).filter(|d| !d.is_empty());
+    let year = lunar_year
+        .or_else(|| {
+            let solar = NaiveDate::parse_from_str(date?.get(..10)?, "%Y-%m-%d").ok()?;
+            lunar::from_solar(solar).map(|d| d.year)
+        })
+        .unwrap_or_else(lunar::current_year);
+    if !(lunar::MIN_YEAR..=lunar::MAX_YEAR).contains(&year) {
+        return Err(format!(
+            "農曆年份需介於 {}-{}",
+            lunar::MIN_YEAR,
+            lunar::MAX_YEAR
+        ));
+    }
+
+    let lunar_date = LunarDate {
+        year,
+        month,
+        day,
+        is_leap,
+    };
+    let solar = lunar::to_solar(lunar_date)
+        .ok_or_else(|| format!("農曆 {} 年沒有{}", year, lunar_date.text()))?;
+    let time = date
+        .and_then(|d| d.get(10..))
+        .filter(|t| !t.is_empty())
+        .unwrap_or(" 09:00");
+    Ok((format!("{}{}", solar.format("%Y-%m-%d"), time), lunar_date))
+}
+
+/// 檢查可報名項目是否為已知的項目類型、價格配置是否存在（-1 表示生效中的配置）
+pub(crate) async fn validate_activity_settings<T>(
+    conn: &mut SqliteConnection,
+    allowed_items: Option<&[String]>,
+    price_config_id: Option<i64>,
+) -> Result<(), (StatusCode, Json<ApiResponse<T>>)> {
+    if let Some(unknown) = allowed_items
+        .unwrap_or_default()
+        .iter()
+        .find(|item| !ITEM_TYPES.iter().any(|(t, ..)| t == item))
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!("無效的項目類型: {}", unknown))),
+        ));
+    }
+
+    if let Some(id) = price_config_id.filter(|id| *id > 0) {
+        let exists: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM priceConfigDB WHERE id = ? AND date_deleted IS NULL")
+            .bind(id)
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(|e| {
+                tracing::error!("查詢價格配置失敗: {}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiResponse::error(format!("查詢價格配置失敗: {}", e))),
+                )
+            })?;
+        if exists.0 == 0 {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::error(format!("找不到 ID 為 {} 的價格配置", id))),
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// 可報名項目存入資料庫的內容：空陣列視為不限（NULL）
+fn allowed_items_json(allowed_items: Option<&[String]>) -> Option<String> {
+    allowed_items
+        .filter(|items| !items.is_empty())
+        .map(|items| serde_json::to_string(items).unwrap_or_default())
+}
+
+/// 插入一筆活動，返回新 ID（單筆創建與範本建立共用）；date 為換算後的國曆日期
+pub(crate) async fn insert_activity(
+    conn: &mut SqliteConnection,
+    payload: &CreateActivityRequest,
+    date: &str,
+    lunar_date: Option<LunarDate>,
+    template_id: i64,
+) -> Result<i64, sqlx::Error> {
+    // 生成當前時間戳
+    let now = chrono::Utc::now().to_rfc3339();
+
+    let result = sqlx::query(
+        r#"
+        INSERT INTO activityDB (
+            activityId, name, item_type, date, 
+            state, icon, description, location,
+            lunarMonth, lunarDay, lunarLeapMonth,
+            templateId, allowedItems, priceConfigId, capacity, createdAt, updatedAt
+        )
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(&payload.activity_id)
+    .bind(&payload.name)
+    .bind(&payload.item_type)
+    .bind(date)
+    .bind(&payload.state)
+    .bind(&payload.icon)
+    .bind(&payload.description)
+    .bind(&payload.location)
+    .bind(lunar_date.map(|d| d.month))
+    .bind(lunar_date.map(|d| d.day))
+    .bind(lunar_date.map(|d| d.is_leap))
+    .bind(template_id)
+    .bind(allowed_items_json(payload.allowed_items.as_deref()))
+    .bind(payload.price_config_id.unwrap_or(-1))
+    .bind(payload.capacity.filter(|c| *c > 0))
+    .bind(&now)
+    .bind(&now)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// 參加記錄未取消且未刪除的條件（前端同時使用 canceled / cancelled）
+pub(crate) const ACTIVE_JOIN_RECORD_CONDITION: &str =
+    "(date_deleted IS NULL AND (state IS NULL OR state NOT IN ('canceled', 'cancelled')))";
+
+pub(crate) fn is_cancelled_state(state: Option<&str>) -> bool {
+    matches!(state, Some("canceled" | "cancelled"))
+}
+
+/// 項目類型 → (名稱, 記錄數, 數量)
+type ItemTypeTotals = BTreeMap<String, (String, i64, i64)>;
+
+/// 由參加記錄計算各活動的報名統計（不含已取消的記錄）
+pub(crate) async fn load_participant_counts(
+    conn: &mut SqliteConnection,
+    activity_ids: &[i64],
+) -> Result<HashMap<i64, ActivityParticipantCounts>, sqlx::Error> {
+    if activity_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let placeholders = activity_ids.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+    let query = format!(
+        "SELECT id, activityId, items FROM joinRecordDB WHERE activityId IN ({}) AND {}",
+        placeholders, ACTIVE_JOIN_RECORD_CONDITION
+    );
+    let mut query_builder = sqlx::query_as::<_, (i64, i64, JsonColumn<Vec<JoinItem>>)>(&query);
+    for id in activity_ids {
+        query_builder = query_builder.bind(id);
+    }
+    let rows = query_builder.fetch_all(&mut *conn).await?;
+
+    let mut totals: HashMap<i64, (ActivityParticipantCounts, ItemTypeTotals)> = HashMap::new();
+    for (id, activity_id, items) in rows {
+        let items = items.into_reported("joinRecordDB.items", id).unwrap_or_default();
+        let (counts, by_type) = totals.entry(activity_id).or_default();
+        counts.record_count += 1;
+
+        // 同一筆記錄中同一人出現在多個項目（例如消災與點燈）只計一次
+        let mut people = HashSet::new();
+        let mut ancestors = HashSet::new();
+        let mut item_types = HashSet::new();
+        for item in &items {
+            let entry = by_type
+                .entry(item.item_type.clone())
+                .or_insert_with(|| (item.label.clone(), 0, 0));
+            if item_types.insert(item.item_type.as_str()) {
+                entry.1 += 1;
+            }
+            entry.2 += item_quantity(item);
+
+            for data in &item.source_data {
+                let text = |key: &str| data.get(key).and_then(|v| v.as_str()).unwrap_or("").trim().to_string();
+                if item.source == "salvation.ancestors" {
+                    let id = data.get("id").map(|v| v.to_string()).unwrap_or_default();
+                    ancestors.insert(format!("{}:{}", id, text("surname")));
+                } else if !text("name").is_empty() {
+                    people.insert(text("name"));
+                }
+            }
+        }
+        counts.people_count += people.len() as i64;
+        counts.ancestor_count += ancestors.len() as i64;
+    }
+
+    Ok(totals
+        .into_iter()
+        .map(|(activity_id, (mut counts, by_type))| {
+            counts.by_item_type = by_type
+                .into_iter()
+                .map(|(item_type, (label, record_count, quantity))| ItemTypeCount {
+                    label: item_type_label(&item_type, &label),
+                    item_type,
+                    record_count,
+                    quantity,
+                })
+                .collect();
+            counts.by_item_type.sort_by_key(|c| item_type_order(&c.item_type));
+            (activity_id, counts)
+        })
+        .collect())
+}
+
+/// 轉換為活動響應並附上參加統計
+pub(crate) async fn activity_responses(
+    conn: &mut SqliteConnection,
+    activities: Vec<Activity>,
+) -> Result<Vec<ActivityResponse>, sqlx::Error> {
+    let ids: Vec<i64> = activities.iter().map(|a| a.id).collect();
+    let mut counts = load_participant_counts(conn, &ids).await?;
+    Ok(activities
+        .into_iter()
+        .map(|activity| {
+            let activity_counts = counts.remove(&activity.id).unwrap_or_default();
+            ActivityResponse::from(activity).with_counts(activity_counts)
+        })
+        .collect())
+}
+
+pub(crate) async fn activity_response(
+    conn: &mut SqliteConnection,
+    activity: Activity,
+) -> Result<ActivityResponse, sqlx::Error> {
+    Ok(activity_responses(conn, vec![activity])
+        .await?
+        .pop()
+        .expect("one activity in, one response out"))
+}
+
+/// 活動名額已滿時返回錯誤訊息；exclude_id 為更新中的參加記錄（不重複計入）
+pub(crate) async fn activity_capacity_error(
+    conn: &mut SqliteConnection,
+    activity_id: i64,
+    exclude_id: Option<i64>,
+) -> Result<Option<String>, sqlx::Error> {
+    let activity: Option<(Option<i64>, Option<String>)> =
+        sqlx::query_as("SELECT capacity, name FROM activityDB WHERE id = ? AND date_deleted IS NULL")
+            .bind(activity_id)
+            .fetch_optional(&mut *conn)
+            .await?;
+    let Some((Some(capacity), name)) = activity else {
+        return Ok(None);
+    };
+    if capacity <= 0 {
+        return Ok(None);
+    }
+
+    let query = format!(
+        "SELECT COUNT(*) FROM joinRecordDB WHERE activityId = ? AND id != ? AND {}",
+        ACTIVE_JOIN_RECORD_CONDITION
+    );
+    let count: (i64,) = sqlx::query_as(&query)
+        .bind(activity_id)
+        .bind(exclude_id.unwrap_or(-1))
+        .fetch_one(&mut *conn)
+        .await?;
+
+    Ok((count.0 >= capacity).then(|| {
+        format!(
+            "活動「{}」名額已滿（{} / {}）",
+            name.unwrap_or_else(|| activity_id.to_string()),
+            count.0,
+            capacity
+        )
+    }))
+}
+
+/// 將活動所有未取消的參加記錄改為已取消，返回更新筆數
+async fn cancel_activity_join_records(
+    conn: &mut SqliteConnection,
+    activity_id: i64,
+) -> Result<u64, sqlx::Error> {
+    let query = format!(
+        "UPDATE joinRecordDB SET state = 'cancelled', updatedAt = ? WHERE activityId = ? AND {}",
+        ACTIVE_JOIN_RECORD_CONDITION
+    );
+    let result = sqlx::query(&query)
+        .bind(chrono::Utc::now().to_rfc3339())
+        .bind(activity_id)
+        .execute(&mut *conn)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+/// 將日期已過（早於 today）的進行中活動標記為已完成，返回更新筆數
+pub(crate) async fn complete_past_activities(
+    pool: &SqlitePool,
+    today: &str,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        r#"
+        UPDATE activityDB SET state = 'completed', updatedAt = ?
+        WHERE state IN ('active', 'upcoming', 'ongoing')
+          AND date IS NOT NULL AND date != ''
+          AND substr(date, 1, 10) < ?
+          AND date_deleted IS NULL
+        "#,
+    )
+    .bind(chrono::Utc::now().to_rfc3339())
+    .bind(today)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// 啟動背景工作：每隔 ACTIVITY_AUTO_COMPLETE_INTERVAL 秒（預設 3600，0 表示停用）
+/// 將日期已過的活動標記為已完成；啟動時先執行一次
+pub fn spawn_activity_auto_complete(pool: SqlitePool) {
+    let seconds = std::env::var("ACTIVITY_AUTO_COMPLETE_INTERVAL")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(3600);
+    if seconds == 0 {
+        tracing::info!("⏸️ 活動自動完成已停用（ACTIVITY_AUTO_COMPLETE_INTERVAL=0）");
+        return;
+    }
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(seconds));
+        loop {
+            interval.tick().await;
+            let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+            match complete_past_activities(&pool, &today).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("✅ 已將 {} 個日期已過的活動標記為已完成", count),
+                Err(e) => tracing::error!("活動自動完成失敗: {}", e),
+            }
+        }
+    });
+}
+
+/// activityId 是否已被使用（含已刪除的活動，避免還原後重複）
+pub(crate) async fn activity_id_exists(
+    conn: &mut SqliteConnection,
+    activity_id: &str,
+) -> Result<bool, sqlx::Error> {
+    let exists: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM activityDB WHERE activityId = ?")
+        .bind(activity_id)
+        .fetch_one(&mut *conn)
+        .await?;
+    Ok(exists.0 > 0)
+}
+
 /// 獲取所有活動
 pub async fn get_all_activities(
     Query(params): Query<ActivityQuery>,
     Extension(pool): Extension<SqlitePool>,
 ) -> Result<Json<ApiResponse<Vec<ActivityResponse>>>, (StatusCode, Json<ApiResponse<Vec<ActivityResponse>>>)> {
     let mut query = format!("{} WHERE 1=1", ACTIVITY_FULL_QUERY);
-    let mut count_query = String::from("SELECT COUNT(*) FROM activityDB WHERE 1=1");
+    let mut count_query = String::from("SELECT COUNT(*) FROM activityDB WHERE date_deleted IS NULL");
 
     // 添加過濾條件
     if let Some(state) = &params.state {
-        let condition = format!(" AND state = '{}'", state);
+        let state = ActivityState::parse(state)
+            .map_err(|message| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(message))))?;
+        let condition = format!(" AND state = '{}'", state.as_str());
         query.push_str(&condition);
         count_query.push_str(&condition);
     }
@@ -103,11 +473,22 @@ pub async fn get_all_activities(
             )
         })?;
 
-    // 🔥 關鍵：將 Vec<Activity> 轉換為 Vec<ActivityResponse>
-    let responses: Vec<ActivityResponse> = activities
-        .into_iter()
-        .map(|activity| activity.into())
-        .collect();
+    // 🔥 關鍵：將 Vec<Activity> 轉換為 Vec<ActivityResponse>，參加人數由參加記錄計算
+    let mut conn = pool.acquire().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("取得資料庫連線失敗: {}", e))),
+        )
+    })?;
+    let responses = activity_responses(&mut conn, activities)
+        .await
+        .map_err(|e| {
+            tracing::error!("統計活動參加人數失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("統計參加人數失敗: {}", e))),
+            )
+        })?;
 
     Ok(Json(ApiResponse::success_with_meta(
         responses,
@@ -141,7 +522,21 @@ pub async fn get_activity_by_id(
     match activity {
         Some(activity) => {
             // 🔥 轉換為 ActivityResponse
-            let response: ActivityResponse = activity.into();
+            let mut conn = pool.acquire().await.map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiResponse::error(format!("取得資料庫連線失敗: {}", e))),
+                )
+            })?;
+            let response = activity_response(&mut conn, activity)
+                .await
+                .map_err(|e| {
+                    tracing::error!("統計活動參加人數失敗: {}", e);
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(ApiResponse::error(format!("統計參加人數失敗: {}", e))),
+                    )
+                })?;
             Ok(Json(ApiResponse::success(response)))
         },
         None => Err((
@@ -173,7 +568,22 @@ pub async fn get_activity_by_activity_id(
     match activity {
         Some(activity) => {
             // 🔥 轉換為 ActivityResponse
-            Ok(Json(ApiResponse::success(activity.into())))
+            let mut conn = pool.acquire().await.map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiResponse::error(format!("取得資料庫連線失敗: {}", e))),
+                )
+            })?;
+            let response = activity_response(&mut conn, activity)
+                .await
+                .map_err(|e| {
+                    tracing::error!("統計活動參加人數失敗: {}", e);
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(ApiResponse::error(format!("統計參加人數失敗: {}", e))),
+                    )
+                })?;
+            Ok(Json(ApiResponse::success(response)))
         },
         None => Err((
             StatusCode::NOT_FOUND,
@@ -188,23 +598,31 @@ pub async fn get_activity_by_activity_id(
 /// 創建新活動
 pub async fn create_activity(
     Extension(pool): Extension<SqlitePool>,
-    Json(payload): Json<CreateActivityRequest>,
+    Json(mut payload): Json<CreateActivityRequest>,
 ) -> Result<Json<ApiResponse<ActivityResponse>>, (StatusCode, Json<ApiResponse<ActivityResponse>>)> {
+    let state = ActivityState::parse(&payload.state)
+        .map_err(|message| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(message))))?;
+    payload.state = state.as_str().to_string();
+
+    let mut conn = pool.acquire().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("取得資料庫連線失敗: {}", e))),
+        )
+    })?;
+
     // 檢查 activityId 是否已存在
-    let exists: (i64,) =
-        sqlx::query_as("SELECT COUNT(*) FROM activityDB WHERE activityId = ?")
-            .bind(&payload.activity_id)
-            .fetch_one(&pool)
-            .await
-            .map_err(|e| {
-                tracing::error!("檢查活動 ID 失敗: {}", e);
-                (
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ApiResponse::error(format!("檢查失敗: {}", e))),
-                )
-            })?;
+    let exists = activity_id_exists(&mut conn, &payload.activity_id)
+        .await
+        .map_err(|e| {
+            tracing::error!("檢查活動 ID 失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("檢查失敗: {}", e))),
+            )
+        })?;
 
-    if exists.0 > 0 {
+    if exists {
         return Err((
             StatusCode::CONFLICT,
             Json(ApiResponse::error(format!(
@@ -214,47 +632,68 @@ pub async fn create_activity(
         ));
     }
 
-    // 生成當前時間戳
-    let now = chrono::Utc::now().to_rfc3339();
-
-    // 插入新記錄
-    let result = sqlx::query(
-        r#"
-        INSERT INTO activityDB (
-            activityId, name, item_type, participants, date, 
-            state, icon, description, location, createdAt, updatedAt
-        )
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-        "#,
+    validate_activity_settings(
+        &mut conn,
+        payload.allowed_items.as_deref(),
+        payload.price_config_id,
     )
-    .bind(&payload.activity_id)
-    .bind(&payload.name)
-    .bind(&payload.item_type)
-    .bind(payload.participants)
-    .bind(&payload.date)
-    .bind(&payload.state)
-    .bind(&payload.icon)
-    .bind(&payload.description)
-    .bind(&payload.location)
-    .bind(&now)
-    .bind(&now)
-    .execute(&pool)
-    .await
-    .map_err(|e| {
-        tracing::error!("創建活動失敗: {}", e);
-        (
-            StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ApiResponse::error(format!("創建失敗: {}", e))),
-        )
-    })?;
+    .await?;
 
-    let id = result.last_insert_rowid();
+    // 有農曆日期時以農曆換算國曆日期
+    let (date, lunar_date) = match (payload.lunar_month, payload.lunar_day) {
+        (Some(month), Some(day)) => {
+            let (date, lunar_date) = resolve_lunar_date(
+                payload.date.as_deref(),
+                payload.lunar_year,
+                month,
+                day,
+                payload.lunar_leap_month,
+            )
+            .map_err(|message| {
+                (
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    Json(ApiResponse::error(message)),
+                )
+            })?;
+            (date, Some(lunar_date))
+        }
+        (None, None) => match payload.date.as_deref().map(str::trim).filter(|d| !d.is_empty()) {
+            Some(date) => (date.to_string(), None),
+            None => {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    Json(ApiResponse::error(
+                        "請提供 date，或 lunarMonth 與 lunarDay".to_string(),
+                    )),
+                ))
+            }
+        },
+        _ => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::error(
+                    "lunarMonth 與 lunarDay 需同時提供".to_string(),
+                )),
+            ))
+        }
+    };
+
+    // 插入新記錄
+    let id = insert_activity(&mut conn, &payload, &date, lunar_date, -1)
+        .await
+        .map_err(|e| {
+            tracing::error!("創建活動失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("創建失敗: {}", e))),
+            )
+        })?;
 
     // 返回創建的記錄
     let query = format!("{} WHERE id = ?", ACTIVITY_FULL_QUERY);
     let activity = sqlx::query_as::<_, Activity>(&query)
         .bind(id)
-        .fetch_one(&pool)
+        .fetch_one(&mut *conn)
         .await
         .map_err(|e| {
             tracing::error!("查詢新創建的活動失敗: {}", e);
@@ -265,8 +704,17 @@ pub async fn create_activity(
         })?;
 
     // 🔥 轉換為 ActivityResponse
+    let response = activity_response(&mut conn, activity)
+        .await
+        .map_err(|e| {
+            tracing::error!("統計活動參加人數失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("統計參加人數失敗: {}", e))),
+            )
+        })?;
     Ok(Json(ApiResponse::success_with_message(
-        activity.into(),
+        response,
         "成功創建活動".to_string(),
     )))
 }
@@ -277,10 +725,19 @@ pub async fn update_activity(
     Extension(pool): Extension<SqlitePool>,
     Json(payload): Json<UpdateActivityRequest>,
 ) -> Result<Json<ApiResponse<ActivityResponse>>, (StatusCode, Json<ApiResponse<ActivityResponse>>)> {
+    // 在事務中讀取目前狀態，狀態轉換檢查與更新不會被並行的更新穿插
+    let mut tx = pool.begin().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("啟動事務失敗: {}", e))),
+        )
+    })?;
+
     // 檢查活動是否存在
-    let exists: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM activityDB WHERE id = ?")
+    let query = format!("{} WHERE id = ?", ACTIVITY_FULL_QUERY);
+    let existing = sqlx::query_as::<_, Activity>(&query)
         .bind(id)
-        .fetch_one(&pool)
+        .fetch_optional(&mut *tx)
         .await
         .map_err(|e| {
             tracing::error!("檢查活動失敗: {}", e);
@@ -288,14 +745,20 @@ pub async fn update_activity(
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ApiResponse::error(format!("檢查失敗: {}", e))),
             )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::error(format!("找不到 ID 為 {} 的活動", id))),
+            )
         })?;
 
-    if exists.0 == 0 {
-        return Err((
-            StatusCode::NOT_FOUND,
-            Json(ApiResponse::error(format!("找不到 ID 為 {} 的活動", id))),
-        ));
-    }
+    validate_activity_settings(
+        &mut tx,
+        payload.allowed_items.as_deref(),
+        payload.price_config_id,
+    )
+    .await?;
 
     // 構建動態更新語句
     let mut updates = Vec::new();
@@ -309,17 +772,116 @@ pub async fn update_activity(
         updates.push("item_type = ?");
         bindings.push(item_type.clone());
     }
-    if let Some(participants) = payload.participants {
-        updates.push("participants = ?");
-        bindings.push(participants.to_string());
+    if let Some(capacity) = payload.capacity {
+        match capacity {
+            c if c > 0 => {
+                updates.push("capacity = ?");
+                bindings.push(c.to_string());
+            }
+            _ => updates.push("capacity = NULL"),
+        }
+    }
+    if let Some(allowed_items) = &payload.allowed_items {
+        match allowed_items_json(Some(allowed_items)) {
+            Some(json) => {
+                updates.push("allowedItems = ?");
+                bindings.push(json);
+            }
+            None => updates.push("allowedItems = NULL"),
+        }
+    }
+    if let Some(price_config_id) = payload.price_config_id {
+        updates.push("priceConfigId = ?");
+        bindings.push(price_config_id.max(-1).to_string());
     }
-    if let Some(date) = &payload.date {
+
+    // 農曆日期：提供任一農曆欄位時依農曆重新換算 date；
+    // 只改 date 時，原本依農曆排定的活動同步更新農曆日期
+    let existing_month = existing.lunar_month.filter(|m| *m > 0).map(|m| m as u32);
+    let existing_day = existing.lunar_day.filter(|d| *d > 0).map(|d| d as u32);
+    let lunar_date = if payload.lunar_year.is_some()
+        || payload.lunar_month.is_some()
+        || payload.lunar_day.is_some()
+        || payload.lunar_leap_month.is_some()
+    {
+        let (Some(month), Some(day)) = (
+            payload.lunar_month.or(existing_month),
+            payload.lunar_day.or(existing_day),
+        ) else {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::error(
+                    "活動沒有農曆日期，請同時提供 lunarMonth 與 lunarDay".to_string(),
+                )),
+            ));
+        };
+        let is_leap = payload
+            .lunar_leap_month
+            .or(existing.lunar_leap_month)
+            .unwrap_or(false);
+        let (date, lunar_date) = resolve_lunar_date(
+            payload.date.as_deref().or(existing.date.as_deref()),
+            payload.lunar_year,
+            month,
+            day,
+            is_leap,
+        )
+        .map_err(|message| {
+            (
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(ApiResponse::error(message)),
+            )
+        })?;
+        updates.push("date = ?");
+        bindings.push(date);
+        Some(lunar_date)
+    } else if let Some(date) = &payload.date {
         updates.push("date = ?");
         bindings.push(date.clone());
+        existing_month
+            .and(date.get(..10))
+            .and_then(|d| NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+            .and_then(lunar::from_solar)
+    } else {
+        None
+    };
+    if let Some(lunar_date) = lunar_date {
+        updates.push("lunarMonth = ?");
+        bindings.push(lunar_date.month.to_string());
+        updates.push("lunarDay = ?");
+        bindings.push(lunar_date.day.to_string());
+        updates.push("lunarLeapMonth = ?");
+        bindings.push((lunar_date.is_leap as i32).to_string());
     }
-    if let Some(state) = &payload.state {
-        updates.push("state = ?");
-        bindings.push(state.clone());
+    // 狀態：planning → active → completed，任何狀態皆可取消
+    let next_state = match &payload.state {
+        Some(state) => {
+            let next = ActivityState::parse(state)
+                .map_err(|message| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(message))))?;
+            let current = ActivityState::from_stored(existing.state.as_deref());
+            if !current.can_transition_to(next) {
+                return Err((
+                    StatusCode::CONFLICT,
+                    Json(ApiResponse::error(format!(
+                        "活動狀態不可由「{}」變更為「{}」",
+                        current.label(),
+                        next.label()
+                    ))),
+                ));
+            }
+            updates.push("state = ?");
+            bindings.push(next.as_str().to_string());
+            Some(next)
+        }
+        None => None,
+    };
+    if payload.cascade_join_records && next_state != Some(ActivityState::Cancelled) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(
+                "cascadeJoinRecords 僅能在取消活動（state = 'cancelled'）時使用".to_string(),
+            )),
+        ));
     }
     if let Some(icon) = &payload.icon {
         updates.push("icon = ?");
@@ -357,7 +919,7 @@ pub async fn update_activity(
     }
     query_builder = query_builder.bind(id);
 
-    query_builder.execute(&pool).await.map_err(|e| {
+    query_builder.execute(&mut *tx).await.map_err(|e| {
         tracing::error!("更新活動失敗: {}", e);
         (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -365,6 +927,25 @@ pub async fn update_activity(
         )
     })?;
 
+    let cancelled_records = if payload.cascade_join_records {
+        cancel_activity_join_records(&mut tx, id).await.map_err(|e| {
+            tracing::error!("取消活動參加記錄失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("取消參加記錄失敗: {}", e))),
+            )
+        })?
+    } else {
+        0
+    };
+
+    tx.commit().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("提交事務失敗: {}", e))),
+        )
+    })?;
+
     // 返回更新後的記錄
     let query = format!("{} WHERE id = ?", ACTIVITY_FULL_QUERY);
     let activity = sqlx::query_as::<_, Activity>(&query)
@@ -380,20 +961,36 @@ pub async fn update_activity(
         })?;
 
     // 🔥 轉換為 ActivityResponse
-    Ok(Json(ApiResponse::success_with_message(
-        activity.into(),
-        "成功更新活動".to_string(),
-    )))
+    let mut conn = pool.acquire().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("取得資料庫連線失敗: {}", e))),
+        )
+    })?;
+    let response = activity_response(&mut conn, activity)
+        .await
+        .map_err(|e| {
+            tracing::error!("統計活動參加人數失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("統計參加人數失敗: {}", e))),
+            )
+        })?;
+    let message = if payload.cascade_join_records {
+        format!("成功取消活動，並取消 {} 筆參加記錄", cancelled_records)
+    } else {
+        "成功更新活動".to_string()
+    };
+    Ok(Json(ApiResponse::success_with_message(response, message)))
 }
 
-/// 刪除活動
+/// 刪除活動（軟刪除，可還原）
 pub async fn delete_activity(
     Path(id): Path<i64>,
+    Query(params): Query<DeleteQuery>,
     Extension(pool): Extension<SqlitePool>,
 ) -> Result<Json<ApiResponse<()>>, (StatusCode, Json<ApiResponse<()>>)> {
-    let result = sqlx::query("DELETE FROM activityDB WHERE id = ?")
-        .bind(id)
-        .execute(&pool)
+    let deleted = soft_delete::soft_delete(&pool, "activityDB", id, params.user_deleted.as_deref())
         .await
         .map_err(|e| {
             tracing::error!("刪除活動失敗: {}", e);
@@ -403,7 +1000,7 @@ pub async fn delete_activity(
             )
         })?;
 
-    if result.rows_affected() == 0 {
+    if !deleted {
         return Err((
             StatusCode::NOT_FOUND,
             Json(ApiResponse::error(format!("找不到 ID 為 {} 的活動", id))),
@@ -417,4 +1014,55 @@ pub async fn delete_activity(
         meta: None,
         errors: None,
     }))
-}
\ No newline at end of file
+}
+
+/// 還原已刪除的活動
+pub async fn restore_activity(
+    Path(id): Path<i64>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<ActivityResponse>>, (StatusCode, Json<ApiResponse<ActivityResponse>>)> {
+    let restored = soft_delete::restore(&pool, "activityDB", id)
+        .await
+        .map_err(|e| {
+            tracing::error!("還原活動失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("還原失敗: {}", e))),
+            )
+        })?;
+
+    if !restored {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!("找不到 ID 為 {} 的已刪除活動", id))),
+        ));
+    }
+
+    let Json(mut response) = get_activity_by_id(Path(id), Extension(pool)).await?;
+    response.message = Some("成功還原活動".to_string());
+    Ok(Json(response))
+}
+
+/// 立即將日期已過的進行中活動標記為已完成（與背景工作相同）
+pub async fn auto_complete_activities(
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<ActivityAutoCompleteResult>>, (StatusCode, Json<ApiResponse<ActivityAutoCompleteResult>>)> {
+    let today = chrono::Local::now().format("%Y-%m-%d").to_string();
+    let completed_count = complete_past_activities(&pool, &today)
+        .await
+        .map_err(|e| {
+            tracing::error!("活動自動完成失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("更新失敗: {}", e))),
+            )
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        ActivityAutoCompleteResult {
+            completed_count,
+            before_date: today,
+        },
+        format!("已將 {} 個活動標記為已完成", completed_count),
+    )))
+}
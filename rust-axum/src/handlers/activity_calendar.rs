@@ -0,0 +1,180 @@
+// src/handlers/activity_calendar.rs
+//! 活動行事曆：以 iCalendar（RFC 5545）輸出 activityDB，供志工以手機行事曆訂閱
+use axum::{
+    extract::{Extension, Query},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use sqlx::SqlitePool;
+
+use crate::handlers::activity::ACTIVITY_FULL_QUERY;
+use crate::models::activity::{Activity, ActivityResponse, ActivityState};
+use crate::models::activity_calendar::ActivityCalendarQuery;
+use crate::models::api_response::ApiResponse;
+use crate::utils::ics::{self, Calendar, EventTime};
+
+const CALENDAR_NAME: &str = "寺院活動";
+/// 活動日期未標時區時視為台灣時間
+const CALENDAR_TZID: &str = "Asia/Taipei";
+const CALENDAR_UTC_OFFSET: &str = "+0800";
+
+/// 以逗號分隔的查詢值，去除空白與空值
+fn split_values(value: Option<&str>) -> Vec<&str> {
+    value
+        .unwrap_or("")
+        .split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .collect()
+}
+
+/// 事件 UID：以 activityId 為準，活動更新時行事曆會取代原事件而不會重複
+fn event_uid(activity: &ActivityResponse) -> String {
+    match activity.activity_id.as_deref().map(str::trim) {
+        Some(activity_id) if !activity_id.is_empty() => {
+            format!("activity-{}@{}", activity_id, env!("CARGO_PKG_NAME"))
+        }
+        _ => format!("activity-id-{}@{}", activity.id, env!("CARGO_PKG_NAME")),
+    }
+}
+
+/// 事件說明：活動說明之後附上狀態與農曆日期
+fn event_description(activity: &ActivityResponse) -> String {
+    let mut lines = Vec::new();
+    if let Some(description) = activity
+        .description
+        .as_deref()
+        .filter(|d| !d.trim().is_empty())
+    {
+        lines.push(description.trim().to_string());
+    }
+    if let Some(state_label) = &activity.state_label {
+        lines.push(format!("狀態：{}", state_label));
+    }
+    if let Some(lunar_date_text) = &activity.lunar_date_text {
+        lines.push(format!("農曆：{}", lunar_date_text));
+    }
+    lines.join("\n")
+}
+
+fn push_event(
+    calendar: &mut Calendar,
+    activity: &ActivityResponse,
+    start: EventTime,
+    now: DateTime<Utc>,
+) {
+    let state = ActivityState::from_stored(activity.state.as_deref());
+
+    calendar.line("BEGIN:VEVENT");
+    calendar.property("UID", &event_uid(activity));
+    calendar.line(&format!("DTSTAMP:{}", ics::utc_stamp(now)));
+    calendar.time_property("DTSTART", start);
+    if let EventTime::Date(date) = start {
+        calendar.time_property("DTEND", EventTime::Date(date + Duration::days(1)));
+    }
+    let summary = activity
+        .name
+        .as_deref()
+        .or(activity.activity_id.as_deref())
+        .unwrap_or("");
+    calendar.property("SUMMARY", summary);
+    if let Some(location) = activity
+        .location
+        .as_deref()
+        .filter(|l| !l.trim().is_empty())
+    {
+        calendar.property("LOCATION", location.trim());
+    }
+    let description = event_description(activity);
+    if !description.is_empty() {
+        calendar.property("DESCRIPTION", &description);
+    }
+    if let Some(item_type) = activity.item_type.as_deref().filter(|t| !t.is_empty()) {
+        calendar.property("CATEGORIES", item_type);
+    }
+    let status = match state {
+        ActivityState::Planning => "TENTATIVE",
+        ActivityState::Active | ActivityState::Completed => "CONFIRMED",
+        ActivityState::Cancelled => "CANCELLED",
+    };
+    calendar.line(&format!("STATUS:{}", status));
+    if let Some(updated_at) = activity
+        .updated_at
+        .as_deref()
+        .and_then(|u| DateTime::parse_from_rfc3339(u).ok())
+    {
+        calendar.line(&format!(
+            "LAST-MODIFIED:{}",
+            ics::utc_stamp(updated_at.with_timezone(&Utc))
+        ));
+    }
+    calendar.line("END:VEVENT");
+}
+
+/// 活動行事曆（GET /api/activities.ics），可依 item_type 與 state 篩選（逗號分隔多個值）；
+/// 日期無法解析的活動不輸出
+pub async fn get_activities_ics(
+    Query(params): Query<ActivityCalendarQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Response, (StatusCode, Json<ApiResponse<()>>)> {
+    let states = split_values(params.state.as_deref())
+        .into_iter()
+        .map(ActivityState::parse)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|message| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(message))))?;
+    let item_types = split_values(params.item_type.as_deref());
+
+    let mut query = format!("{} WHERE 1=1", ACTIVITY_FULL_QUERY);
+    if !item_types.is_empty() {
+        query.push_str(&format!(
+            " AND item_type IN ({})",
+            vec!["?"; item_types.len()].join(", ")
+        ));
+    }
+    query.push_str(" ORDER BY date ASC, id ASC");
+
+    let mut sql = sqlx::query_as::<_, Activity>(&query);
+    for item_type in &item_types {
+        sql = sql.bind(*item_type);
+    }
+    let activities = sql.fetch_all(&pool).await.map_err(|e| {
+        tracing::error!("查詢活動行事曆失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+        )
+    })?;
+
+    let now = Utc::now();
+    let mut calendar = Calendar::new(CALENDAR_NAME, CALENDAR_TZID, CALENDAR_UTC_OFFSET);
+    for activity in activities {
+        // 舊資料的狀態（upcoming、canceled 等）先正規化再比對
+        let state = ActivityState::from_stored(activity.state.as_deref());
+        if !states.is_empty() && !states.contains(&state) {
+            continue;
+        }
+        let Some(start) = activity.date.as_deref().and_then(EventTime::parse) else {
+            tracing::warn!(
+                "活動 {} 的日期無法解析，未輸出至行事曆: {:?}",
+                activity.id,
+                activity.date
+            );
+            continue;
+        };
+        push_event(&mut calendar, &ActivityResponse::from(activity), start, now);
+    }
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/calendar; charset=utf-8"),
+            (
+                header::CONTENT_DISPOSITION,
+                "inline; filename=\"activities.ics\"",
+            ),
+        ],
+        calendar.finish(),
+    )
+        .into_response())
+}
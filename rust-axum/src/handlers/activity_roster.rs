@@ -0,0 +1,490 @@
+// src/handlers/activity_roster.rs
+//! 法會名冊：超度牌位、消災疏文與點燈名單
+//! 由參加記錄的 items[].sourceData / sourceAddress 展開，依地址分組並去除重複
+use axum::{
+    extract::{Extension, Path, Query},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde_json::Value as JsonValue;
+use sqlx::SqlitePool;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::handlers::activity::{ACTIVE_JOIN_RECORD_CONDITION, ACTIVITY_FULL_QUERY};
+use crate::models::activity::Activity;
+use crate::models::activity_roster::{
+    ActivityRoster, ActivityRosterQuery, RosterEntry, RosterGroup,
+};
+use crate::models::api_response::ApiResponse;
+use crate::models::json_column::JsonColumn;
+use crate::models::json_types::JoinItem;
+use crate::utils::csv;
+use crate::utils::pdf::{self, PdfDocument, MARGIN_LEFT};
+
+/// 名冊類型：(type, 名冊名稱, 納入的項目類型)
+const ROSTER_TYPES: &[(&str, &str, &[&str])] = &[
+    ("chaodu", "超度牌位", &["chaodu"]),
+    ("qifu", "消災祈福名冊", &["qifu", "xiaozai"]),
+    ("diandeng", "點燈名冊", &["diandeng"]),
+];
+
+const NO_ADDRESS_LABEL: &str = "（未填地址）";
+
+/// 牌位排版：每頁 2 列 × 6 個
+const TABLET_COLUMNS: usize = 6;
+const TABLET_ROWS: usize = 2;
+const TABLET_WIDTH: f32 = 80.0;
+const TABLET_HEIGHT: f32 = 320.0;
+const TABLET_GAP_X: f32 = 7.0;
+const TABLET_GAP_Y: f32 = 20.0;
+
+/// 名單排版：每行 4 人
+const LIST_COLUMNS: usize = 4;
+const LIST_COLUMN_WIDTH: f32 = 130.0;
+
+fn json_text(data: &JsonValue, key: &str) -> String {
+    data.get(key)
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .trim()
+        .to_string()
+}
+
+/// 同一地址的名冊與去重索引
+#[derive(Default)]
+struct GroupBuilder {
+    entries: Vec<RosterEntry>,
+    index: HashMap<(String, String), usize>,
+}
+
+impl GroupBuilder {
+    /// 加入一筆；同名（點燈另需同燈種）已存在時合併參加記錄與陽上人
+    fn add(&mut self, entry: RosterEntry) {
+        let key = (
+            entry.name.clone(),
+            entry.lamp_type.clone().unwrap_or_default(),
+        );
+        match self.index.get(&key) {
+            Some(&i) => {
+                let existing = &mut self.entries[i];
+                for id in entry.join_record_ids {
+                    if !existing.join_record_ids.contains(&id) {
+                        existing.join_record_ids.push(id);
+                    }
+                }
+                for survivor in entry.survivors {
+                    if !existing.survivors.contains(&survivor) {
+                        existing.survivors.push(survivor);
+                    }
+                }
+                existing.is_household_head |= entry.is_household_head;
+                if existing.zodiac.is_none() {
+                    existing.zodiac = entry.zodiac;
+                }
+            }
+            None => {
+                self.index.insert(key, self.entries.len());
+                self.entries.push(entry);
+            }
+        }
+    }
+}
+
+/// 展開單筆參加記錄的名冊項目
+fn expand_record(
+    record_id: i64,
+    items: &[JoinItem],
+    roster_type: &str,
+    item_types: &[&str],
+    groups: &mut BTreeMap<String, GroupBuilder>,
+) {
+    // 超度牌位的陽上人取自同一筆記錄的陽上人項目
+    let survivors: Vec<String> = if roster_type == "chaodu" {
+        let mut names: Vec<String> = Vec::new();
+        for data in items
+            .iter()
+            .filter(|item| item.item_type == "survivors")
+            .flat_map(|item| &item.source_data)
+        {
+            let name = json_text(data, "name");
+            if !name.is_empty() && !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    } else {
+        Vec::new()
+    };
+
+    for item in items
+        .iter()
+        .filter(|item| item_types.contains(&item.item_type.as_str()))
+    {
+        let group = groups
+            .entry(item.source_address.trim().to_string())
+            .or_default();
+
+        for data in &item.source_data {
+            let entry = if roster_type == "chaodu" {
+                let name = format!("{}{}", json_text(data, "surname"), json_text(data, "notes"));
+                RosterEntry {
+                    name,
+                    zodiac: None,
+                    lamp_type: None,
+                    lamp_type_label: None,
+                    survivors: survivors.clone(),
+                    is_household_head: false,
+                    join_record_ids: vec![record_id],
+                }
+            } else {
+                let person_id = data.get("id").and_then(|v| v.as_i64());
+                let lamp = item
+                    .lamp_details
+                    .as_ref()
+                    .and_then(|details| details.iter().find(|d| Some(d.person_id) == person_id));
+                RosterEntry {
+                    name: json_text(data, "name"),
+                    zodiac: Some(json_text(data, "zodiac")).filter(|z| !z.is_empty()),
+                    lamp_type: lamp.map(|l| l.lamp_type.clone()),
+                    lamp_type_label: lamp.map(|l| l.lamp_type_label.clone()),
+                    survivors: Vec::new(),
+                    is_household_head: data
+                        .get("isHouseholdHead")
+                        .and_then(|v| v.as_bool())
+                        .unwrap_or(false),
+                    join_record_ids: vec![record_id],
+                }
+            };
+            if !entry.name.is_empty() {
+                group.add(entry);
+            }
+        }
+    }
+}
+
+/// 彙整活動名冊；活動不存在時返回 None
+async fn build_roster(
+    pool: &SqlitePool,
+    id: i64,
+    roster_type: &str,
+    roster_label: &str,
+    item_types: &[&str],
+) -> Result<Option<ActivityRoster>, sqlx::Error> {
+    let activity = sqlx::query_as::<_, Activity>(&format!("{} WHERE id = ?", ACTIVITY_FULL_QUERY))
+        .bind(id)
+        .fetch_optional(pool)
+        .await?;
+    let Some(activity) = activity else {
+        return Ok(None);
+    };
+
+    let query = format!(
+        "SELECT id, items FROM joinRecordDB WHERE activityId = ? AND {} ORDER BY id",
+        ACTIVE_JOIN_RECORD_CONDITION
+    );
+    let rows: Vec<(i64, JsonColumn<Vec<JoinItem>>)> =
+        sqlx::query_as(&query).bind(id).fetch_all(pool).await?;
+
+    let mut builders: BTreeMap<String, GroupBuilder> = BTreeMap::new();
+    for (record_id, items) in rows {
+        let items = items
+            .into_reported("joinRecordDB.items", record_id)
+            .unwrap_or_default();
+        expand_record(record_id, &items, roster_type, item_types, &mut builders);
+    }
+
+    // 地址排序，未填地址排最後；同地址內戶長優先，其餘依名稱排序
+    let mut groups: Vec<RosterGroup> = builders
+        .into_iter()
+        .filter(|(_, builder)| !builder.entries.is_empty())
+        .map(|(address, builder)| {
+            let mut entries = builder.entries;
+            entries.sort_by(|a, b| {
+                b.is_household_head
+                    .cmp(&a.is_household_head)
+                    .then_with(|| a.name.cmp(&b.name))
+                    .then_with(|| a.lamp_type.cmp(&b.lamp_type))
+            });
+            RosterGroup { address, entries }
+        })
+        .collect();
+    groups.sort_by_key(|g| g.address.is_empty());
+
+    Ok(Some(ActivityRoster {
+        id,
+        activity_id: activity.activity_id,
+        name: activity.name,
+        date: activity.date,
+        roster_type: roster_type.to_string(),
+        roster_label: roster_label.to_string(),
+        group_count: groups.len() as i64,
+        entry_count: groups.iter().map(|g| g.entries.len() as i64).sum(),
+        groups,
+    }))
+}
+
+fn address_label(address: &str) -> &str {
+    if address.is_empty() {
+        NO_ADDRESS_LABEL
+    } else {
+        address
+    }
+}
+
+fn join_record_ids_text(ids: &[i64]) -> String {
+    ids.iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// 名冊轉 CSV（含 UTF-8 BOM 供 Excel 開啟）
+fn render_roster_csv(roster: &ActivityRoster) -> String {
+    let mut out = String::from(csv::UTF8_BOM);
+    out.push_str(&csv::row(&[
+        roster.roster_label.as_str(),
+        roster.name.as_deref().unwrap_or_default(),
+        roster.date.as_deref().unwrap_or_default(),
+    ]));
+    out.push_str("\r\n");
+
+    match roster.roster_type.as_str() {
+        "chaodu" => out.push_str(&csv::row(&["地址", "序號", "牌位", "陽上人", "參加記錄ID"])),
+        "diandeng" => out.push_str(&csv::row(&[
+            "地址",
+            "序號",
+            "姓名",
+            "生肖",
+            "燈種",
+            "參加記錄ID",
+        ])),
+        _ => out.push_str(&csv::row(&["地址", "序號", "姓名", "生肖", "參加記錄ID"])),
+    }
+    for group in &roster.groups {
+        for (i, entry) in group.entries.iter().enumerate() {
+            let address = address_label(&group.address).to_string();
+            let seq = (i + 1).to_string();
+            let ids = join_record_ids_text(&entry.join_record_ids);
+            let zodiac = entry.zodiac.clone().unwrap_or_default();
+            let row = match roster.roster_type.as_str() {
+                "chaodu" => csv::row(&[
+                    address,
+                    seq,
+                    entry.name.clone(),
+                    entry.survivors.join("、"),
+                    ids,
+                ]),
+                "diandeng" => csv::row(&[
+                    address,
+                    seq,
+                    entry.name.clone(),
+                    zodiac,
+                    entry.lamp_type_label.clone().unwrap_or_default(),
+                    ids,
+                ]),
+                _ => csv::row(&[address, seq, entry.name.clone(), zodiac, ids]),
+            };
+            out.push_str(&row);
+        }
+    }
+    out
+}
+
+/// 依可用高度縮小直書字級
+fn fit_font_size(text: &str, height: f32, max_size: f32) -> f32 {
+    let count = text.chars().filter(|c| !c.is_whitespace()).count().max(1) as f32;
+    (height / (count * 1.1)).clamp(6.0, max_size)
+}
+
+/// 超度牌位 PDF：每個牌位一格，中間直書牌位稱謂，左側陽上人
+fn render_tablets_pdf(roster: &ActivityRoster) -> Vec<u8> {
+    let mut doc = PdfDocument::new();
+    let title = format!(
+        "{}　{}　{}",
+        roster.name.as_deref().unwrap_or_default(),
+        roster.roster_label,
+        roster.date.as_deref().unwrap_or_default()
+    );
+    let per_page = TABLET_COLUMNS * TABLET_ROWS;
+    let tablets: Vec<&RosterEntry> = roster.groups.iter().flat_map(|g| &g.entries).collect();
+    let top = pdf::PAGE_HEIGHT - 90.0;
+
+    for (page, chunk) in tablets.chunks(per_page).enumerate() {
+        if page > 0 {
+            doc.new_page();
+        }
+        let page_count = tablets.len().div_ceil(per_page);
+        doc.line(
+            &[(
+                MARGIN_LEFT,
+                &format!("{}　第 {} / {} 頁", title, page + 1, page_count),
+            )],
+            11.0,
+        );
+
+        for (i, entry) in chunk.iter().enumerate() {
+            let x = MARGIN_LEFT + (i % TABLET_COLUMNS) as f32 * (TABLET_WIDTH + TABLET_GAP_X);
+            let y_top = top - (i / TABLET_COLUMNS) as f32 * (TABLET_HEIGHT + TABLET_GAP_Y);
+            doc.rect(x, y_top - TABLET_HEIGHT, TABLET_WIDTH, TABLET_HEIGHT);
+
+            let center = format!("{}往生蓮位", entry.name);
+            let size = fit_font_size(&center, TABLET_HEIGHT - 60.0, 18.0);
+            doc.vertical_text(x + TABLET_WIDTH / 2.0, y_top - 30.0, &center, size);
+
+            doc.vertical_text(x + TABLET_WIDTH - 12.0, y_top - 8.0, "佛力超薦", 9.0);
+
+            if !entry.survivors.is_empty() {
+                let survivors = format!("陽上{}叩薦", entry.survivors.join("、"));
+                let size = fit_font_size(&survivors, TABLET_HEIGHT - 70.0, 9.0);
+                doc.vertical_text(x + 12.0, y_top - 60.0, &survivors, size);
+            }
+        }
+    }
+
+    doc.finish()
+}
+
+/// 消災 / 點燈名冊 PDF：依地址分組列出姓名
+fn render_list_pdf(roster: &ActivityRoster) -> Vec<u8> {
+    let mut doc = PdfDocument::new();
+    let x = MARGIN_LEFT;
+
+    doc.line(
+        &[(
+            x,
+            &format!(
+                "{}　{}",
+                roster.name.as_deref().unwrap_or_default(),
+                roster.roster_label
+            ),
+        )],
+        16.0,
+    );
+    doc.line(
+        &[(
+            x,
+            &format!(
+                "日期：{}　共 {} 戶、{} 人",
+                roster.date.as_deref().unwrap_or_default(),
+                roster.group_count,
+                roster.entry_count
+            ),
+        )],
+        10.0,
+    );
+    doc.rule();
+
+    for group in &roster.groups {
+        doc.gap(6.0);
+        doc.line(
+            &[(x, &format!("地址：{}", address_label(&group.address)))],
+            11.0,
+        );
+        let names: Vec<String> = group
+            .entries
+            .iter()
+            .map(|entry| {
+                let mut text = entry.name.clone();
+                if let Some(zodiac) = &entry.zodiac {
+                    text.push_str(&format!("（{}）", zodiac));
+                }
+                if let Some(label) = &entry.lamp_type_label {
+                    text.push_str(&format!(" {}", label));
+                }
+                text
+            })
+            .collect();
+        for row in names.chunks(LIST_COLUMNS) {
+            let columns: Vec<(f32, &str)> = row
+                .iter()
+                .enumerate()
+                .map(|(i, name)| (x + 12.0 + i as f32 * LIST_COLUMN_WIDTH, name.as_str()))
+                .collect();
+            doc.line(&columns, 10.0);
+        }
+    }
+
+    doc.finish()
+}
+
+/// 法會名冊：type=chaodu（超度牌位）| qifu（消災，含固定消災）| diandeng（點燈），
+/// 依地址分組、去除重複，format=json|csv|pdf
+pub async fn get_activity_roster(
+    Path(id): Path<i64>,
+    Query(params): Query<ActivityRosterQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Response, (StatusCode, Json<ApiResponse<ActivityRoster>>)> {
+    let roster_type = params.roster_type.as_deref().unwrap_or("chaodu");
+    let Some(&(roster_type, roster_label, item_types)) =
+        ROSTER_TYPES.iter().find(|(t, _, _)| *t == roster_type)
+    else {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!(
+                "不支援的名冊類型: {}（僅接受 chaodu、qifu、diandeng）",
+                roster_type
+            ))),
+        ));
+    };
+    let format = params.format.as_deref().unwrap_or("json");
+    if !matches!(format, "json" | "csv" | "pdf") {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!(
+                "不支援的格式: {}（僅接受 json、csv、pdf）",
+                format
+            ))),
+        ));
+    }
+
+    let roster = build_roster(&pool, id, roster_type, roster_label, item_types)
+        .await
+        .map_err(|e| {
+            tracing::error!("查詢法會名冊失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::error(format!("找不到 ID 為 {} 的活動", id))),
+            )
+        })?;
+
+    let filename = format!("activity-{}-roster-{}", id, roster_type);
+    match format {
+        "csv" => Ok((
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"{}.csv\"", filename),
+                ),
+            ],
+            render_roster_csv(&roster),
+        )
+            .into_response()),
+        "pdf" => {
+            let body = if roster_type == "chaodu" {
+                render_tablets_pdf(&roster)
+            } else {
+                render_list_pdf(&roster)
+            };
+            Ok((
+                [
+                    (header::CONTENT_TYPE, "application/pdf".to_string()),
+                    (
+                        header::CONTENT_DISPOSITION,
+                        format!("inline; filename=\"{}.pdf\"", filename),
+                    ),
+                ],
+                body,
+            )
+                .into_response())
+        }
+        _ => Ok(Json(ApiResponse::success(roster)).into_response()),
+    }
+}
@@ -0,0 +1,341 @@
+// src/handlers/activity_summary.rs
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    Json,
+};
+use sqlx::SqlitePool;
+use std::collections::{BTreeMap, HashSet};
+
+use crate::handlers::activity::{
+    is_cancelled_state, ACTIVE_JOIN_RECORD_CONDITION, ACTIVITY_FULL_QUERY,
+};
+use crate::handlers::join_record_item::{item_quantity, item_type_label, item_type_order};
+use crate::handlers::report::{payment_method_label, receipt_type_label};
+use crate::models::activity::Activity;
+use crate::models::activity_summary::{
+    ActivitySummary, ActivitySummaryAmounts, CancelledSummary, ItemTypeSummary,
+    PaymentMethodSummary, PaymentStateSummary, ReceiptSummary, ReceiptTypeSummary,
+    ReconciliationBatchRef, ReconciliationSummary,
+};
+use crate::models::api_response::ApiResponse;
+use crate::models::json_column::JsonColumn;
+use crate::models::json_types::JoinItem;
+
+/// 本活動未取消的參加記錄 ID（CTE），後續查詢以 records 限定範圍
+fn active_records_cte() -> String {
+    format!(
+        "WITH records AS (SELECT id FROM joinRecordDB WHERE activityId = ? AND {})",
+        ACTIVE_JOIN_RECORD_CONDITION
+    )
+}
+
+/// 付款方式統計：有付款流水的記錄以流水為準，舊記錄（無流水）以 paidAmount 計
+const PAYMENT_METHOD_QUERY: &str = r#"
+SELECT method, COUNT(*), COALESCE(SUM(amount), 0)
+FROM (
+    SELECT COALESCE(p.paymentMethod, '') AS method, p.amount AS amount
+    FROM joinRecordPayments p
+    WHERE p.joinRecordId IN (SELECT id FROM records)
+    UNION ALL
+    SELECT COALESCE(j.paymentMethod, ''), COALESCE(j.paidAmount, 0)
+    FROM joinRecordDB j
+    WHERE j.id IN (SELECT id FROM records)
+      AND COALESCE(j.paidAmount, 0) != 0
+      AND NOT EXISTS (SELECT 1 FROM joinRecordPayments p WHERE p.joinRecordId = j.id)
+)
+GROUP BY method
+ORDER BY SUM(amount) DESC
+"#;
+
+/// 沖帳明細：依記錄與沖帳批次彙整收款金額
+/// 有付款流水的記錄以流水的 reconciliationBatchId 為準（分次付款可能部分沖帳），
+/// 舊記錄（無流水）以 paidAmount 計，並以記錄的 accountingState / reconciliationBatchId 判斷
+const RECONCILIATION_QUERY: &str = r#"
+, lines AS (
+    SELECT p.joinRecordId AS recordId,
+           COALESCE(p.reconciliationBatchId, -1) != -1 AS reconciled,
+           COALESCE(p.reconciliationBatchId, -1) AS batchId,
+           p.amount AS amount
+    FROM joinRecordPayments p
+    WHERE p.joinRecordId IN (SELECT id FROM records)
+    UNION ALL
+    SELECT j.id,
+           COALESCE(j.accountingState, '') = 'reconciled',
+           CASE WHEN j.accountingState = 'reconciled' THEN COALESCE(j.reconciliationBatchId, -1) ELSE -1 END,
+           COALESCE(j.paidAmount, 0)
+    FROM joinRecordDB j
+    WHERE j.id IN (SELECT id FROM records)
+      AND NOT EXISTS (SELECT 1 FROM joinRecordPayments p WHERE p.joinRecordId = j.id)
+)
+SELECT l.recordId, l.reconciled, b.id, b.batchNumber, b.batchDate, b.state, SUM(l.amount)
+FROM lines l
+LEFT JOIN reconciliationBatchDB b ON b.id = l.batchId
+GROUP BY l.recordId, l.reconciled, l.batchId
+HAVING SUM(l.amount) != 0
+"#;
+
+/// 沖帳明細列：(記錄 ID, 是否已沖帳, 批次 ID, 批次編號, 批次日期, 批次狀態, 金額)
+type ReconciliationRow = (
+    i64,
+    bool,
+    Option<i64>,
+    Option<String>,
+    Option<String>,
+    Option<String>,
+    i64,
+);
+
+#[derive(sqlx::FromRow)]
+struct SummaryRow {
+    id: i64,
+    state: Option<String>,
+    items: JsonColumn<Vec<JoinItem>>,
+    #[sqlx(rename = "totalAmount")]
+    total_amount: Option<i64>,
+    #[sqlx(rename = "discountAmount")]
+    discount_amount: Option<i64>,
+    #[sqlx(rename = "finalAmount")]
+    final_amount: Option<i64>,
+    #[sqlx(rename = "paidAmount")]
+    paid_amount: Option<i64>,
+    #[sqlx(rename = "paymentState")]
+    payment_state: Option<String>,
+    #[sqlx(rename = "needReceipt")]
+    need_receipt: Option<String>,
+    #[sqlx(rename = "receiptIssued")]
+    receipt_issued: Option<String>,
+}
+
+fn payment_state_label(payment_state: &str) -> &str {
+    match payment_state {
+        "unpaid" => "未付款",
+        "partial" => "部分付款",
+        "paid" => "已付款",
+        "waived" => "免付款",
+        other => other,
+    }
+}
+
+/// 活動財務摘要：依參加記錄彙整項目、金額、付款方式、收據與沖帳狀態（不含已取消的記錄）
+pub async fn get_activity_summary(
+    Path(id): Path<i64>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<ActivitySummary>>, (StatusCode, Json<ApiResponse<ActivitySummary>>)> {
+    let internal_error = |e: sqlx::Error| {
+        tracing::error!("查詢活動財務摘要失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+        )
+    };
+
+    let activity = sqlx::query_as::<_, Activity>(&format!("{} WHERE id = ?", ACTIVITY_FULL_QUERY))
+        .bind(id)
+        .fetch_optional(&pool)
+        .await
+        .map_err(internal_error)?;
+    let Some(activity) = activity else {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!("找不到 ID 為 {} 的活動", id))),
+        ));
+    };
+
+    let rows = sqlx::query_as::<_, SummaryRow>(
+        r#"
+        SELECT id, state, items, totalAmount, discountAmount, finalAmount, paidAmount,
+               paymentState, needReceipt, receiptIssued
+        FROM joinRecordDB WHERE activityId = ? AND date_deleted IS NULL ORDER BY id
+        "#,
+    )
+    .bind(id)
+    .fetch_all(&pool)
+    .await
+    .map_err(internal_error)?;
+
+    let mut record_count = 0;
+    let mut amounts = ActivitySummaryAmounts::default();
+    let mut cancelled = CancelledSummary::default();
+    let mut receipts = ReceiptSummary::default();
+    let mut reconciliation = ReconciliationSummary::default();
+    // 項目類型 → (名稱, 記錄數, 數量, 金額)
+    let mut by_item_type: BTreeMap<String, (String, i64, i64, i64)> = BTreeMap::new();
+    let mut by_payment_state: BTreeMap<String, (i64, i64, i64)> = BTreeMap::new();
+    let mut by_receipt_type: BTreeMap<String, (i64, i64)> = BTreeMap::new();
+
+    for row in rows {
+        let final_amount = row.final_amount.unwrap_or(0);
+        let paid_amount = row.paid_amount.unwrap_or(0);
+        if is_cancelled_state(row.state.as_deref()) {
+            cancelled.record_count += 1;
+            cancelled.paid_amount += paid_amount;
+            continue;
+        }
+        record_count += 1;
+
+        amounts.total_amount += row.total_amount.unwrap_or(0);
+        amounts.discount_amount += row.discount_amount.unwrap_or(0);
+        amounts.final_amount += final_amount;
+        amounts.paid_amount += paid_amount;
+        amounts.outstanding_amount += (final_amount - paid_amount).max(0);
+        amounts.overpaid_amount += (paid_amount - final_amount).max(0);
+
+        let items = row
+            .items
+            .into_reported("joinRecordDB.items", row.id)
+            .unwrap_or_default();
+        let mut item_types = HashSet::new();
+        for item in &items {
+            let entry = by_item_type
+                .entry(item.item_type.clone())
+                .or_insert_with(|| (item.label.clone(), 0, 0, 0));
+            if item_types.insert(item.item_type.as_str()) {
+                entry.1 += 1;
+            }
+            entry.2 += item_quantity(item);
+            entry.3 += item.subtotal;
+        }
+
+        let payment_state = row
+            .payment_state
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| "unpaid".to_string());
+        let entry = by_payment_state.entry(payment_state).or_default();
+        entry.0 += 1;
+        entry.1 += final_amount;
+        entry.2 += paid_amount;
+
+        match row.receipt_issued.as_deref() {
+            Some(receipt_type @ ("stamp" | "standard")) => {
+                let entry = by_receipt_type.entry(receipt_type.to_string()).or_default();
+                entry.0 += 1;
+                entry.1 += final_amount;
+            }
+            _ if matches!(row.need_receipt.as_deref(), Some("1" | "true")) => {
+                receipts.pending_count += 1;
+            }
+            _ => {}
+        }
+    }
+
+    let mut by_item_type: Vec<ItemTypeSummary> = by_item_type
+        .into_iter()
+        .map(
+            |(item_type, (label, record_count, quantity, amount))| ItemTypeSummary {
+                label: item_type_label(&item_type, &label),
+                item_type,
+                record_count,
+                quantity,
+                amount,
+            },
+        )
+        .collect();
+    by_item_type.sort_by_key(|s| item_type_order(&s.item_type));
+
+    let by_payment_state = by_payment_state
+        .into_iter()
+        .map(
+            |(payment_state, (record_count, final_amount, paid_amount))| PaymentStateSummary {
+                label: payment_state_label(&payment_state).to_string(),
+                payment_state,
+                record_count,
+                final_amount,
+                paid_amount,
+            },
+        )
+        .collect();
+
+    receipts.by_type = by_receipt_type
+        .into_iter()
+        .map(
+            |(receipt_type, (record_count, amount))| ReceiptTypeSummary {
+                label: receipt_type_label(&receipt_type).to_string(),
+                receipt_type,
+                record_count,
+                amount,
+            },
+        )
+        .collect();
+
+    let query = format!("{}{}", active_records_cte(), PAYMENT_METHOD_QUERY);
+    let methods: Vec<(String, i64, i64)> = sqlx::query_as(&query)
+        .bind(id)
+        .fetch_all(&pool)
+        .await
+        .map_err(internal_error)?;
+    let by_payment_method = methods
+        .into_iter()
+        .map(
+            |(payment_method, payment_count, amount)| PaymentMethodSummary {
+                label: payment_method_label(&payment_method).to_string(),
+                payment_method,
+                payment_count,
+                amount,
+            },
+        )
+        .collect();
+
+    // 部分沖帳的記錄同時計入已沖帳與未沖帳
+    let query = format!("{}{}", active_records_cte(), RECONCILIATION_QUERY);
+    let lines: Vec<ReconciliationRow> = sqlx::query_as(&query)
+        .bind(id)
+        .fetch_all(&pool)
+        .await
+        .map_err(internal_error)?;
+    let mut reconciled_records = HashSet::new();
+    let mut unreconciled_records = HashSet::new();
+    // (批次日期, 批次編號, 批次 ID) → (狀態, 記錄數, 金額)
+    let mut batches: BTreeMap<(String, String, i64), (String, i64, i64)> = BTreeMap::new();
+    for (record_id, reconciled, batch_id, batch_number, batch_date, state, amount) in lines {
+        if !reconciled {
+            unreconciled_records.insert(record_id);
+            reconciliation.unreconciled_amount += amount;
+            continue;
+        }
+        reconciled_records.insert(record_id);
+        reconciliation.reconciled_amount += amount;
+        if let Some(batch_id) = batch_id {
+            let entry = batches
+                .entry((
+                    batch_date.unwrap_or_default(),
+                    batch_number.unwrap_or_default(),
+                    batch_id,
+                ))
+                .or_insert_with(|| (state.unwrap_or_default(), 0, 0));
+            entry.1 += 1;
+            entry.2 += amount;
+        }
+    }
+    reconciliation.reconciled_count = reconciled_records.len() as i64;
+    reconciliation.unreconciled_count = unreconciled_records.len() as i64;
+    reconciliation.batches = batches
+        .into_iter()
+        .map(
+            |((batch_date, batch_number, id), (state, record_count, amount))| ReconciliationBatchRef {
+                id,
+                batch_number,
+                batch_date,
+                state,
+                record_count,
+                amount,
+            },
+        )
+        .collect();
+
+    Ok(Json(ApiResponse::success(ActivitySummary {
+        id,
+        activity_id: activity.activity_id,
+        name: activity.name,
+        date: activity.date,
+        state: activity.state,
+        record_count,
+        amounts,
+        by_item_type,
+        by_payment_state,
+        by_payment_method,
+        receipts,
+        reconciliation,
+        cancelled,
+    })))
+}
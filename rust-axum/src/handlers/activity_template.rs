@@ -0,0 +1,773 @@
+// src/handlers/activity_template.rs
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    Json,
+};
+use chrono::{NaiveDate, NaiveTime};
+use sqlx::{SqliteConnection, SqlitePool};
+
+use crate::handlers::activity::{
+    activity_id_exists, activity_response, insert_activity, validate_activity_settings,
+    ACTIVITY_FULL_QUERY,
+};
+use crate::models::activity::{Activity, ActivityResponse, ActivityState, CreateActivityRequest};
+use crate::models::activity_template::{
+    ActivityTemplate, ActivityTemplateQuery, ActivityTemplateResponse,
+    CreateActivityFromTemplateRequest, CreateActivityTemplateRequest,
+    UpdateActivityTemplateRequest,
+};
+use crate::models::api_response::{ApiResponse, Meta};
+use crate::utils::lunar::{self, LunarDate};
+
+const ACTIVITY_TEMPLATE_FULL_QUERY: &str = r#"
+SELECT
+    id,
+    user_created,
+    CASE
+        WHEN date_created IS NOT NULL
+        THEN datetime(date_created / 1000, 'unixepoch')
+        ELSE NULL
+    END as date_created,
+    user_updated,
+    CASE
+        WHEN date_updated IS NOT NULL
+        THEN datetime(date_updated / 1000, 'unixepoch')
+        ELSE NULL
+    END as date_updated,
+    name,
+    item_type,
+    icon,
+    description,
+    location,
+    lunarMonth,
+    lunarDay,
+    lunarLeapMonth,
+    solarMonth,
+    solarDay,
+    time,
+    allowedItems,
+    priceConfigId,
+    state,
+    notes,
+    createdAt,
+    updatedAt
+FROM activityTemplateDB
+"#;
+
+const INACTIVE_STATE: &str = "inactive";
+
+/// 範本的排定日期：農曆月日或國曆月日
+#[derive(Debug, Clone, Copy)]
+enum Schedule {
+    Lunar { month: u32, day: u32, is_leap: bool },
+    Solar { month: u32, day: u32 },
+}
+
+/// 檢查排定日期與時間：農曆與國曆月日需擇一且成對提供
+fn validate_schedule(
+    lunar_month: Option<u32>,
+    lunar_day: Option<u32>,
+    is_leap: bool,
+    solar_month: Option<u32>,
+    solar_day: Option<u32>,
+    time: &str,
+) -> Result<Schedule, String> {
+    if NaiveTime::parse_from_str(time.trim(), "%H:%M").is_err() {
+        return Err(format!("時間格式錯誤（需為 HH:MM）: {}", time));
+    }
+
+    match (lunar_month, lunar_day, solar_month, solar_day) {
+        (Some(month), Some(day), None, None) => {
+            if !(1..=12).contains(&month) || !(1..=30).contains(&day) {
+                return Err(format!("農曆月日無效: {} 月 {} 日", month, day));
+            }
+            Ok(Schedule::Lunar {
+                month,
+                day,
+                is_leap,
+            })
+        }
+        (None, None, Some(month), Some(day)) => {
+            // 以閏年檢查，2 月 29 日於平年改為 2 月 28 日
+            if NaiveDate::from_ymd_opt(2024, month, day).is_none() {
+                return Err(format!("國曆月日無效: {} 月 {} 日", month, day));
+            }
+            Ok(Schedule::Solar { month, day })
+        }
+        (None, None, None, None) => {
+            Err("請提供 lunarMonth 與 lunarDay，或 solarMonth 與 solarDay".to_string())
+        }
+        _ => Err("農曆月日與國曆月日需擇一，且月、日需同時提供".to_string()),
+    }
+}
+
+/// 範本在指定年份的國曆日期；
+/// 當年沒有範本的閏月時改用該月，小月沒有三十時改為該月最後一天
+fn schedule_date(schedule: Schedule, year: i32) -> Option<(NaiveDate, Option<LunarDate>)> {
+    match schedule {
+        Schedule::Lunar {
+            month,
+            day,
+            is_leap,
+        } => {
+            let is_leap = is_leap && lunar::leap_month(year) == Some(month);
+            let days = lunar::month_days(year, month, is_leap)?;
+            let lunar_date = LunarDate {
+                year,
+                month,
+                day: day.min(days),
+                is_leap,
+            };
+            Some((lunar::to_solar(lunar_date)?, Some(lunar_date)))
+        }
+        Schedule::Solar { month, day } => {
+            let date = NaiveDate::from_ymd_opt(year, month, day)
+                .or_else(|| NaiveDate::from_ymd_opt(year, month, day - 1))?;
+            Some((date, None))
+        }
+    }
+}
+
+/// 範本名稱代入年份：{year} 為西元年、{rocYear} 為民國年
+fn render_name(name: &str, year: i32) -> String {
+    name.replace("{rocYear}", &(year - 1911).to_string())
+        .replace("{year}", &year.to_string())
+}
+
+/// 產生 7 碼 activityId（與前端 generateGitHashBrowser 長度一致），並避開已存在的 ID
+async fn generate_activity_id(conn: &mut SqliteConnection) -> Result<String, sqlx::Error> {
+    loop {
+        let candidate = uuid::Uuid::new_v4().simple().to_string()[..7].to_string();
+        if !activity_id_exists(conn, &candidate).await? {
+            return Ok(candidate);
+        }
+    }
+}
+
+async fn fetch_template(
+    conn: &mut SqliteConnection,
+    id: i64,
+) -> Result<Option<ActivityTemplate>, sqlx::Error> {
+    let query = format!("{} WHERE id = ?", ACTIVITY_TEMPLATE_FULL_QUERY);
+    sqlx::query_as::<_, ActivityTemplate>(&query)
+        .bind(id)
+        .fetch_optional(&mut *conn)
+        .await
+}
+
+/// 獲取活動範本列表
+pub async fn get_all_activity_templates(
+    Query(params): Query<ActivityTemplateQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<
+    Json<ApiResponse<Vec<ActivityTemplateResponse>>>,
+    (StatusCode, Json<ApiResponse<Vec<ActivityTemplateResponse>>>),
+> {
+    let mut query = format!("{} WHERE 1=1", ACTIVITY_TEMPLATE_FULL_QUERY);
+    let mut count_query = String::from("SELECT COUNT(*) FROM activityTemplateDB WHERE 1=1");
+    let mut bindings: Vec<String> = Vec::new();
+
+    if let Some(state) = params
+        .state
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+    {
+        query.push_str(" AND state = ?");
+        count_query.push_str(" AND state = ?");
+        bindings.push(state.to_string());
+    }
+
+    query.push_str(" ORDER BY id");
+
+    let limit = params.limit.unwrap_or(100);
+    let offset = params.offset.unwrap_or(0);
+    query.push_str(&format!(" LIMIT {} OFFSET {}", limit, offset));
+
+    let mut query_builder = sqlx::query_as::<_, ActivityTemplate>(&query);
+    let mut count_builder = sqlx::query_as::<_, (i64,)>(&count_query);
+    for binding in &bindings {
+        query_builder = query_builder.bind(binding);
+        count_builder = count_builder.bind(binding);
+    }
+
+    let templates = query_builder.fetch_all(&pool).await.map_err(|e| {
+        tracing::error!("查詢活動範本失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+        )
+    })?;
+
+    let total = count_builder.fetch_one(&pool).await.map_err(|e| {
+        tracing::error!("查詢活動範本總數失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢總數失敗: {}", e))),
+        )
+    })?;
+
+    Ok(Json(ApiResponse::success_with_meta(
+        templates
+            .into_iter()
+            .map(ActivityTemplateResponse::from)
+            .collect(),
+        Meta {
+            total: total.0,
+            limit: Some(limit),
+            offset: Some(offset),
+        },
+    )))
+}
+
+/// 根據 ID 獲取活動範本
+pub async fn get_activity_template_by_id(
+    Path(id): Path<i64>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<
+    Json<ApiResponse<ActivityTemplateResponse>>,
+    (StatusCode, Json<ApiResponse<ActivityTemplateResponse>>),
+> {
+    let mut conn = pool.acquire().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("取得資料庫連線失敗: {}", e))),
+        )
+    })?;
+
+    match fetch_template(&mut conn, id).await {
+        Ok(Some(template)) => Ok(Json(ApiResponse::success(template.into()))),
+        Ok(None) => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "找不到 ID 為 {} 的活動範本",
+                id
+            ))),
+        )),
+        Err(e) => {
+            tracing::error!("查詢活動範本失敗: {}", e);
+            Err((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+            ))
+        }
+    }
+}
+
+/// 創建活動範本
+pub async fn create_activity_template(
+    Extension(pool): Extension<SqlitePool>,
+    Json(payload): Json<CreateActivityTemplateRequest>,
+) -> Result<
+    Json<ApiResponse<ActivityTemplateResponse>>,
+    (StatusCode, Json<ApiResponse<ActivityTemplateResponse>>),
+> {
+    let internal_error = |e: sqlx::Error| {
+        tracing::error!("創建活動範本失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("創建失敗: {}", e))),
+        )
+    };
+
+    if payload.name.trim().is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error("範本名稱不可為空".to_string())),
+        ));
+    }
+    validate_schedule(
+        payload.lunar_month,
+        payload.lunar_day,
+        payload.lunar_leap_month,
+        payload.solar_month,
+        payload.solar_day,
+        &payload.time,
+    )
+    .map_err(|message| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(message))))?;
+
+    let mut conn = pool.acquire().await.map_err(internal_error)?;
+    validate_activity_settings(
+        &mut conn,
+        payload.allowed_items.as_deref(),
+        payload.price_config_id,
+    )
+    .await?;
+
+    let now = chrono::Utc::now();
+    let allowed_items = payload
+        .allowed_items
+        .as_ref()
+        .filter(|items| !items.is_empty())
+        .map(|items| serde_json::to_string(items).unwrap_or_default());
+    let result = sqlx::query(
+        r#"
+        INSERT INTO activityTemplateDB (
+            user_created, date_created, name, item_type, icon, description, location,
+            lunarMonth, lunarDay, lunarLeapMonth, solarMonth, solarDay, time,
+            allowedItems, priceConfigId, state, notes, createdAt, updatedAt
+        )
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(&payload.user_created)
+    .bind(now.timestamp_millis())
+    .bind(payload.name.trim())
+    .bind(&payload.item_type)
+    .bind(&payload.icon)
+    .bind(&payload.description)
+    .bind(&payload.location)
+    .bind(payload.lunar_month)
+    .bind(payload.lunar_day)
+    .bind(payload.lunar_month.map(|_| payload.lunar_leap_month))
+    .bind(payload.solar_month)
+    .bind(payload.solar_day)
+    .bind(payload.time.trim())
+    .bind(allowed_items)
+    .bind(payload.price_config_id.unwrap_or(-1))
+    .bind(&payload.state)
+    .bind(&payload.notes)
+    .bind(now.to_rfc3339())
+    .bind(now.to_rfc3339())
+    .execute(&mut *conn)
+    .await
+    .map_err(internal_error)?;
+
+    let template = fetch_template(&mut conn, result.last_insert_rowid())
+        .await
+        .map_err(internal_error)?
+        .ok_or_else(|| internal_error(sqlx::Error::RowNotFound))?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        template.into(),
+        "成功創建活動範本".to_string(),
+    )))
+}
+
+/// 更新活動範本
+pub async fn update_activity_template(
+    Path(id): Path<i64>,
+    Extension(pool): Extension<SqlitePool>,
+    Json(payload): Json<UpdateActivityTemplateRequest>,
+) -> Result<
+    Json<ApiResponse<ActivityTemplateResponse>>,
+    (StatusCode, Json<ApiResponse<ActivityTemplateResponse>>),
+> {
+    let internal_error = |e: sqlx::Error| {
+        tracing::error!("更新活動範本失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("更新失敗: {}", e))),
+        )
+    };
+    let bad_request =
+        |message: String| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(message)));
+
+    let mut conn = pool.acquire().await.map_err(internal_error)?;
+    let existing = fetch_template(&mut conn, id)
+        .await
+        .map_err(internal_error)?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::error(format!(
+                    "找不到 ID 為 {} 的活動範本",
+                    id
+                ))),
+            )
+        })?;
+
+    validate_activity_settings(
+        &mut conn,
+        payload.allowed_items.as_deref(),
+        payload.price_config_id,
+    )
+    .await?;
+
+    // (欄位, 值)；值為 None 時寫入 NULL
+    let mut changes: Vec<(&str, Option<String>)> = Vec::new();
+
+    if let Some(name) = payload.name.as_deref().map(str::trim) {
+        if name.is_empty() {
+            return Err(bad_request("範本名稱不可為空".to_string()));
+        }
+        changes.push(("name = ?", Some(name.to_string())));
+    }
+    if let Some(item_type) = &payload.item_type {
+        changes.push(("item_type = ?", Some(item_type.clone())));
+    }
+    if let Some(icon) = &payload.icon {
+        changes.push(("icon = ?", Some(icon.clone())));
+    }
+    if let Some(description) = &payload.description {
+        changes.push(("description = ?", Some(description.clone())));
+    }
+    if let Some(location) = &payload.location {
+        changes.push(("location = ?", Some(location.clone())));
+    }
+
+    // 排定日期：改用農曆時清除國曆月日，反之亦然
+    let lunar_given = payload.lunar_month.is_some()
+        || payload.lunar_day.is_some()
+        || payload.lunar_leap_month.is_some();
+    let solar_given = payload.solar_month.is_some() || payload.solar_day.is_some();
+    if lunar_given && solar_given {
+        return Err(bad_request("農曆月日與國曆月日需擇一".to_string()));
+    }
+    let to_u32 = |v: Option<i64>| v.filter(|v| *v > 0).map(|v| v as u32);
+    let (lunar_month, lunar_day, solar_month, solar_day) = if lunar_given {
+        (
+            payload.lunar_month.or(to_u32(existing.lunar_month)),
+            payload.lunar_day.or(to_u32(existing.lunar_day)),
+            None,
+            None,
+        )
+    } else if solar_given {
+        (
+            None,
+            None,
+            payload.solar_month.or(to_u32(existing.solar_month)),
+            payload.solar_day.or(to_u32(existing.solar_day)),
+        )
+    } else {
+        (
+            to_u32(existing.lunar_month),
+            to_u32(existing.lunar_day),
+            to_u32(existing.solar_month),
+            to_u32(existing.solar_day),
+        )
+    };
+    let is_leap = payload
+        .lunar_leap_month
+        .or(existing.lunar_leap_month)
+        .unwrap_or(false);
+    let time = payload
+        .time
+        .clone()
+        .or(existing.time.clone())
+        .unwrap_or_else(|| "09:00".to_string());
+    validate_schedule(
+        lunar_month,
+        lunar_day,
+        is_leap,
+        solar_month,
+        solar_day,
+        &time,
+    )
+    .map_err(bad_request)?;
+
+    if lunar_given || solar_given {
+        changes.push(("lunarMonth = ?", lunar_month.map(|v| v.to_string())));
+        changes.push(("lunarDay = ?", lunar_day.map(|v| v.to_string())));
+        changes.push((
+            "lunarLeapMonth = ?",
+            Some((lunar_month.is_some() && is_leap) as i32).map(|v| v.to_string()),
+        ));
+        changes.push(("solarMonth = ?", solar_month.map(|v| v.to_string())));
+        changes.push(("solarDay = ?", solar_day.map(|v| v.to_string())));
+    }
+    if payload.time.is_some() {
+        changes.push(("time = ?", Some(time.trim().to_string())));
+    }
+    if let Some(allowed_items) = &payload.allowed_items {
+        changes.push((
+            "allowedItems = ?",
+            Some(allowed_items)
+                .filter(|items| !items.is_empty())
+                .map(|items| serde_json::to_string(items).unwrap_or_default()),
+        ));
+    }
+    if let Some(price_config_id) = payload.price_config_id {
+        changes.push((
+            "priceConfigId = ?",
+            Some(price_config_id.max(-1).to_string()),
+        ));
+    }
+    if let Some(state) = &payload.state {
+        changes.push(("state = ?", Some(state.clone())));
+    }
+    if let Some(notes) = &payload.notes {
+        changes.push(("notes = ?", Some(notes.clone())));
+    }
+
+    if changes.is_empty() {
+        return Err(bad_request("沒有提供要更新的字段".to_string()));
+    }
+
+    let now = chrono::Utc::now();
+    changes.push(("user_updated = ?", payload.user_updated.clone()));
+    changes.push(("date_updated = ?", Some(now.timestamp_millis().to_string())));
+    changes.push(("updatedAt = ?", Some(now.to_rfc3339())));
+
+    let query = format!(
+        "UPDATE activityTemplateDB SET {} WHERE id = ?",
+        changes
+            .iter()
+            .map(|(column, _)| *column)
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    let mut query_builder = sqlx::query(&query);
+    for (_, value) in changes {
+        query_builder = query_builder.bind(value);
+    }
+    query_builder
+        .bind(id)
+        .execute(&mut *conn)
+        .await
+        .map_err(internal_error)?;
+
+    let template = fetch_template(&mut conn, id)
+        .await
+        .map_err(internal_error)?
+        .ok_or_else(|| internal_error(sqlx::Error::RowNotFound))?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        template.into(),
+        "成功更新活動範本".to_string(),
+    )))
+}
+
+/// 刪除活動範本；已用於建立活動的範本請改為停用（state = 'inactive'）
+pub async fn delete_activity_template(
+    Path(id): Path<i64>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<()>>, (StatusCode, Json<ApiResponse<()>>)> {
+    let internal_error = |e: sqlx::Error| {
+        tracing::error!("刪除活動範本失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("刪除失敗: {}", e))),
+        )
+    };
+
+    // 已刪除的活動仍可能被還原，一併計入
+    let used: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM activityDB WHERE templateId = ?")
+        .bind(id)
+        .fetch_one(&pool)
+        .await
+        .map_err(internal_error)?;
+    if used.0 > 0 {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ApiResponse::error(format!(
+                "活動範本 {} 已建立 {} 個活動，請改為停用（state = 'inactive'）",
+                id, used.0
+            ))),
+        ));
+    }
+
+    let result = sqlx::query("DELETE FROM activityTemplateDB WHERE id = ?")
+        .bind(id)
+        .execute(&pool)
+        .await
+        .map_err(internal_error)?;
+
+    if result.rows_affected() == 0 {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "找不到 ID 為 {} 的活動範本",
+                id
+            ))),
+        ));
+    }
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: None,
+        message: Some("成功刪除活動範本".to_string()),
+        meta: None,
+        errors: None,
+    }))
+}
+
+/// 由範本建立指定年份的活動（農曆範本依農曆換算國曆日期），同一範本同一天只能建立一次
+pub async fn create_activity_from_template(
+    Extension(pool): Extension<SqlitePool>,
+    Json(payload): Json<CreateActivityFromTemplateRequest>,
+) -> Result<Json<ApiResponse<ActivityResponse>>, (StatusCode, Json<ApiResponse<ActivityResponse>>)>
+{
+    let internal_error = |e: sqlx::Error| {
+        tracing::error!("由範本建立活動失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("創建失敗: {}", e))),
+        )
+    };
+
+    let state = ActivityState::parse(&payload.state)
+        .map_err(|message| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(message))))?;
+    if !(lunar::MIN_YEAR..=lunar::MAX_YEAR).contains(&payload.year) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!(
+                "年份需介於 {}-{}",
+                lunar::MIN_YEAR,
+                lunar::MAX_YEAR
+            ))),
+        ));
+    }
+
+    let mut tx = pool.begin().await.map_err(internal_error)?;
+
+    let template = fetch_template(&mut tx, payload.template_id)
+        .await
+        .map_err(internal_error)?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::error(format!(
+                    "找不到 ID 為 {} 的活動範本",
+                    payload.template_id
+                ))),
+            )
+        })?;
+    if template.state.as_deref() == Some(INACTIVE_STATE) {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ApiResponse::error(format!(
+                "活動範本 {} 已停用",
+                payload.template_id
+            ))),
+        ));
+    }
+
+    let to_u32 = |v: Option<i64>| v.filter(|v| *v > 0).map(|v| v as u32);
+    let time = template
+        .time
+        .clone()
+        .filter(|t| !t.trim().is_empty())
+        .unwrap_or_else(|| "09:00".to_string());
+    let schedule = validate_schedule(
+        to_u32(template.lunar_month),
+        to_u32(template.lunar_day),
+        template.lunar_leap_month.unwrap_or(false),
+        to_u32(template.solar_month),
+        to_u32(template.solar_day),
+        &time,
+    )
+    .map_err(|message| {
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ApiResponse::error(format!("範本設定有誤: {}", message))),
+        )
+    })?;
+    let (solar, lunar_date) = schedule_date(schedule, payload.year).ok_or_else(|| {
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ApiResponse::error(format!(
+                "無法換算範本在 {} 年的日期",
+                payload.year
+            ))),
+        )
+    })?;
+    let day = solar.format("%Y-%m-%d").to_string();
+
+    // 同一範本同一天已建立過活動（已刪除的不算）
+    let existing: Option<(i64, Option<String>)> = sqlx::query_as(
+        "SELECT id, activityId FROM activityDB WHERE templateId = ? AND substr(date, 1, 10) = ? AND date_deleted IS NULL LIMIT 1",
+    )
+    .bind(payload.template_id)
+    .bind(&day)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(internal_error)?;
+    if let Some((id, activity_id)) = existing {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ApiResponse::error(format!(
+                "範本 {} 已於 {} 建立活動（ID {}，activityId {}）",
+                payload.template_id,
+                day,
+                id,
+                activity_id.unwrap_or_default()
+            ))),
+        ));
+    }
+
+    let activity_id = match payload
+        .activity_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+    {
+        Some(activity_id) => {
+            if activity_id_exists(&mut tx, activity_id)
+                .await
+                .map_err(internal_error)?
+            {
+                return Err((
+                    StatusCode::CONFLICT,
+                    Json(ApiResponse::error(format!(
+                        "activityId '{}' 已存在",
+                        activity_id
+                    ))),
+                ));
+            }
+            activity_id.to_string()
+        }
+        None => generate_activity_id(&mut tx)
+            .await
+            .map_err(internal_error)?,
+    };
+
+    let name = payload
+        .name
+        .clone()
+        .filter(|n| !n.trim().is_empty())
+        .or(template.name.clone())
+        .unwrap_or_default();
+    let create = CreateActivityRequest {
+        activity_id,
+        name: render_name(&name, payload.year),
+        item_type: template
+            .item_type
+            .clone()
+            .unwrap_or_else(|| "ceremony".to_string()),
+        date: None,
+        state: state.as_str().to_string(),
+        icon: template.icon.clone().unwrap_or_else(|| "🕯️".to_string()),
+        description: template.description.clone(),
+        location: payload.location.clone().or(template.location.clone()),
+        lunar_year: lunar_date.map(|d| d.year),
+        lunar_month: lunar_date.map(|d| d.month),
+        lunar_day: lunar_date.map(|d| d.day),
+        lunar_leap_month: lunar_date.is_some_and(|d| d.is_leap),
+        allowed_items: template
+            .allowed_items
+            .into_reported("activityTemplateDB.allowedItems", template.id),
+        price_config_id: template.price_config_id,
+        capacity: None,
+    };
+    let date = format!("{} {}", day, time.trim());
+    let id = insert_activity(&mut tx, &create, &date, lunar_date, template.id)
+        .await
+        .map_err(internal_error)?;
+
+    sqlx::query("UPDATE activityDB SET user_created = ?, date_created = ? WHERE id = ?")
+        .bind(&payload.user_created)
+        .bind(chrono::Utc::now().timestamp_millis())
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(internal_error)?;
+
+    let activity = sqlx::query_as::<_, Activity>(&format!("{} WHERE id = ?", ACTIVITY_FULL_QUERY))
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(internal_error)?;
+
+    let response = activity_response(&mut tx, activity)
+        .await
+        .map_err(internal_error)?;
+
+    tx.commit().await.map_err(internal_error)?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        response,
+        format!("成功由範本 {} 建立 {} 年活動", template.id, payload.year),
+    )))
+}
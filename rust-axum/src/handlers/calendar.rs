@@ -0,0 +1,184 @@
+// src/handlers/calendar.rs
+use axum::{extract::Query, http::StatusCode, Json};
+use chrono::NaiveDate;
+
+use crate::handlers::registration::ZODIACS;
+use crate::models::api_response::ApiResponse;
+use crate::models::calendar::{
+    LunarCalendar, LunarCalendarQuery, LunarConversion, LunarConvertQuery, LunarFestival,
+    LunarMonth,
+};
+use crate::utils::lunar::{self, LunarDate};
+
+/// 農曆節日與寺院常用紀念日（月, 日）；除夕另依當年十二月大小計算
+const FESTIVALS: &[(&str, u32, u32)] = &[
+    ("春節", 1, 1),
+    ("彌勒菩薩聖誕", 1, 1),
+    ("天公生", 1, 9),
+    ("元宵節（上元）", 1, 15),
+    ("釋迦牟尼佛出家日", 2, 8),
+    ("釋迦牟尼佛涅槃日", 2, 15),
+    ("觀世音菩薩聖誕", 2, 19),
+    ("普賢菩薩聖誕", 2, 21),
+    ("文殊菩薩聖誕", 4, 4),
+    ("浴佛節（佛誕）", 4, 8),
+    ("端午節", 5, 5),
+    ("觀世音菩薩成道日", 6, 19),
+    ("七夕", 7, 7),
+    ("中元普度（盂蘭盆）", 7, 15),
+    ("地藏菩薩聖誕", 7, 30),
+    ("中秋節", 8, 15),
+    ("重陽節", 9, 9),
+    ("觀世音菩薩出家日", 9, 19),
+    ("藥師佛聖誕", 9, 30),
+    ("下元節", 10, 15),
+    ("阿彌陀佛聖誕", 11, 17),
+    ("釋迦牟尼佛成道日（臘八）", 12, 8),
+    ("送神", 12, 24),
+];
+
+fn validate_lunar_year(year: i32) -> Result<i32, String> {
+    if !(lunar::MIN_YEAR..=lunar::MAX_YEAR).contains(&year) {
+        return Err(format!(
+            "農曆年份需介於 {}-{}",
+            lunar::MIN_YEAR,
+            lunar::MAX_YEAR
+        ));
+    }
+    Ok(year)
+}
+
+fn zodiac(year: i32) -> String {
+    ZODIACS[lunar::year_dizhi_index(year)].to_string()
+}
+
+fn conversion(date: NaiveDate, lunar_date: LunarDate) -> LunarConversion {
+    LunarConversion {
+        date: date.format("%Y-%m-%d").to_string(),
+        weekday: lunar::weekday_text(date).to_string(),
+        lunar_year: lunar_date.year,
+        lunar_month: lunar_date.month,
+        lunar_day: lunar_date.day,
+        leap_month: lunar_date.is_leap,
+        lunar_text: lunar_date.text(),
+        ganzhi: lunar::ganzhi(lunar_date.year),
+        zodiac: zodiac(lunar_date.year),
+    }
+}
+
+fn festival(name: &str, date: NaiveDate, lunar_date: LunarDate) -> LunarFestival {
+    LunarFestival {
+        name: name.to_string(),
+        lunar_month: lunar_date.month,
+        lunar_day: lunar_date.day,
+        lunar_text: lunar_date.text(),
+        date: date.format("%Y-%m-%d").to_string(),
+        weekday: lunar::weekday_text(date).to_string(),
+    }
+}
+
+/// 農曆年曆：各月大小、閏月與節日的國曆日期
+pub async fn get_lunar_calendar(
+    Query(params): Query<LunarCalendarQuery>,
+) -> Result<Json<ApiResponse<LunarCalendar>>, (StatusCode, Json<ApiResponse<LunarCalendar>>)> {
+    let year = validate_lunar_year(params.year.unwrap_or_else(lunar::current_year))
+        .map_err(|message| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(message))))?;
+    let new_year = lunar::new_year(year).ok_or_else(|| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!("不支援農曆 {} 年", year))),
+        )
+    })?;
+
+    let mut months = Vec::new();
+    let mut start = new_year;
+    for (month, is_leap, days) in lunar::months(year) {
+        let end = start + chrono::Days::new(days as u64 - 1);
+        months.push(LunarMonth {
+            month,
+            leap_month: is_leap,
+            name: lunar::month_text(month, is_leap),
+            days,
+            start_date: start.format("%Y-%m-%d").to_string(),
+            end_date: end.format("%Y-%m-%d").to_string(),
+        });
+        start = end + chrono::Days::new(1);
+    }
+
+    // 小月沒有三十，紀念日改在該月最後一天
+    let mut festivals: Vec<LunarFestival> = FESTIVALS
+        .iter()
+        .filter_map(|(name, month, day)| {
+            let days = lunar::month_days(year, *month, false)?;
+            let lunar_date = LunarDate {
+                year,
+                month: *month,
+                day: (*day).min(days),
+                is_leap: false,
+            };
+            Some(festival(name, lunar::to_solar(lunar_date)?, lunar_date))
+        })
+        .collect();
+    if let Some(&(month, is_leap, days)) = lunar::months(year).last() {
+        let lunar_date = LunarDate {
+            year,
+            month,
+            day: days,
+            is_leap,
+        };
+        if let Some(date) = lunar::to_solar(lunar_date) {
+            festivals.push(festival("除夕", date, lunar_date));
+        }
+    }
+    festivals.sort_by(|a, b| a.date.cmp(&b.date));
+
+    Ok(Json(ApiResponse::success(LunarCalendar {
+        year,
+        ganzhi: lunar::ganzhi(year),
+        zodiac: zodiac(year),
+        leap_month: lunar::leap_month(year),
+        new_year_date: new_year.format("%Y-%m-%d").to_string(),
+        days: months.iter().map(|m| m.days).sum(),
+        months,
+        festivals,
+    })))
+}
+
+/// 國曆 ↔ 農曆換算
+pub async fn convert_lunar_date(
+    Query(params): Query<LunarConvertQuery>,
+) -> Result<Json<ApiResponse<LunarConversion>>, (StatusCode, Json<ApiResponse<LunarConversion>>)> {
+    let bad_request =
+        |message: String| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(message)));
+
+    if let Some(date) = params
+        .date
+        .as_deref()
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+    {
+        let solar = NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| bad_request(format!("日期格式錯誤（需為 YYYY-MM-DD）: {}", date)))?;
+        let lunar_date = lunar::from_solar(solar)
+            .ok_or_else(|| bad_request(format!("日期 {} 超出農曆換算範圍", date)))?;
+        return Ok(Json(ApiResponse::success(conversion(solar, lunar_date))));
+    }
+
+    let (Some(month), Some(day)) = (params.lunar_month, params.lunar_day) else {
+        return Err(bad_request(
+            "請提供 date，或 lunarMonth 與 lunarDay（可加 lunarYear、leapMonth）".to_string(),
+        ));
+    };
+    let year = validate_lunar_year(params.lunar_year.unwrap_or_else(lunar::current_year))
+        .map_err(bad_request)?;
+    let lunar_date = LunarDate {
+        year,
+        month,
+        day,
+        is_leap: params.leap_month,
+    };
+    let solar = lunar::to_solar(lunar_date)
+        .ok_or_else(|| bad_request(format!("農曆 {} 年沒有{}", year, lunar_date.text())))?;
+
+    Ok(Json(ApiResponse::success(conversion(solar, lunar_date))))
+}
@@ -0,0 +1,810 @@
+// src/handlers/household.rs
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    Json,
+};
+use sqlx::{sqlite::SqliteRow, FromRow, SqlitePool};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use crate::handlers::activity::{activity_responses, ACTIVITY_FULL_QUERY};
+use crate::handlers::join_record::JOIN_RECORD_FULL_QUERY;
+use crate::handlers::join_record_payment::PAYMENT_FULL_QUERY;
+use crate::handlers::monthly_donate::MONTHLY_DONATE_FULL_QUERY;
+use crate::handlers::receipt_number::RECEIPT_FULL_QUERY;
+use crate::handlers::registration::{normalize_digits, normalize_text, REGISTRATION_FULL_QUERY};
+use crate::models::activity::Activity;
+use crate::models::api_response::{ApiResponse, Meta};
+use crate::models::household::{
+    DeriveHouseholdsRequest, DeriveHouseholdsResult, DerivedHousehold, Household, HouseholdDetail,
+    HouseholdMember, HouseholdQuery, HouseholdResponse, HouseholdSummary, LinkHouseholdRequest,
+    LinkHouseholdResult,
+};
+use crate::models::join_record::JoinRecord;
+use crate::models::join_record_payment::JoinRecordPayment;
+use crate::models::monthly_donate::MonthlyDonate;
+use crate::models::receipt_number::ReceiptNumber;
+use crate::models::registration::{Registration, RegistrationResponse};
+
+const HOUSEHOLD_FULL_QUERY: &str = r#"
+SELECT
+    id,
+    user_created,
+    CASE
+        WHEN date_created IS NOT NULL
+        THEN datetime(date_created / 1000, 'unixepoch')
+        ELSE NULL
+    END as date_created,
+    user_updated,
+    CASE
+        WHEN date_updated IS NOT NULL
+        THEN datetime(date_updated / 1000, 'unixepoch')
+        ELSE NULL
+    END as date_updated,
+    headName,
+    mobile,
+    phone,
+    address,
+    state,
+    notes,
+    createdAt,
+    updatedAt
+FROM householdDB
+"#;
+
+/// 依 ID 清單查詢，清單為空時不查資料庫
+async fn fetch_by_ids<T>(
+    pool: &SqlitePool,
+    base_query: &str,
+    column: &str,
+    ids: &[i64],
+) -> Result<Vec<T>, sqlx::Error>
+where
+    T: for<'r> FromRow<'r, SqliteRow> + Send + Unpin,
+{
+    if ids.is_empty() {
+        return Ok(Vec::new());
+    }
+    let placeholders = ids.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+    let query = format!(
+        "{} WHERE {} IN ({}) ORDER BY id",
+        base_query, column, placeholders
+    );
+    let mut query_builder = sqlx::query_as::<_, T>(&query);
+    for id in ids {
+        query_builder = query_builder.bind(id);
+    }
+    query_builder.fetch_all(pool).await
+}
+
+/// 獲取戶列表（可依戶長姓名、手機、電話、地址搜尋）
+pub async fn get_all_households(
+    Query(params): Query<HouseholdQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<
+    Json<ApiResponse<Vec<HouseholdResponse>>>,
+    (StatusCode, Json<ApiResponse<Vec<HouseholdResponse>>>),
+> {
+    let mut query = format!("{} WHERE 1=1", HOUSEHOLD_FULL_QUERY);
+    let mut count_query = String::from("SELECT COUNT(*) FROM householdDB WHERE 1=1");
+    let mut bindings: Vec<String> = Vec::new();
+
+    if let Some(q) = params.q.as_deref().map(str::trim).filter(|q| !q.is_empty()) {
+        let condition = " AND (headName LIKE ? OR mobile LIKE ? OR phone LIKE ? OR address LIKE ?)";
+        query.push_str(condition);
+        count_query.push_str(condition);
+        let pattern = format!("%{}%", q);
+        bindings.extend(std::iter::repeat_n(pattern, 4));
+    }
+
+    query.push_str(" ORDER BY id DESC");
+
+    let limit = params.limit.unwrap_or(100);
+    let offset = params.offset.unwrap_or(0);
+    query.push_str(&format!(" LIMIT {} OFFSET {}", limit, offset));
+
+    let mut query_builder = sqlx::query_as::<_, Household>(&query);
+    let mut count_builder = sqlx::query_as::<_, (i64,)>(&count_query);
+    for binding in &bindings {
+        query_builder = query_builder.bind(binding);
+        count_builder = count_builder.bind(binding);
+    }
+
+    let households = query_builder.fetch_all(&pool).await.map_err(|e| {
+        tracing::error!("查詢戶列表失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+        )
+    })?;
+
+    let total = count_builder.fetch_one(&pool).await.map_err(|e| {
+        tracing::error!("查詢戶總數失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢總數失敗: {}", e))),
+        )
+    })?;
+
+    Ok(Json(ApiResponse::success_with_meta(
+        households
+            .into_iter()
+            .map(HouseholdResponse::from)
+            .collect(),
+        Meta {
+            total: total.0,
+            limit: Some(limit),
+            offset: Some(offset),
+        },
+    )))
+}
+
+/// 根據 ID 獲取戶的完整歷史：成員、歷年報名、參加活動、付款流水、收據與每月贊助
+pub async fn get_household_by_id(
+    Path(id): Path<i64>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<HouseholdDetail>>, (StatusCode, Json<ApiResponse<HouseholdDetail>>)> {
+    let internal_error = |message: String| {
+        tracing::error!("{}", message);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(message)),
+        )
+    };
+
+    let query = format!("{} WHERE id = ?", HOUSEHOLD_FULL_QUERY);
+    let household = sqlx::query_as::<_, Household>(&query)
+        .bind(id)
+        .fetch_optional(&pool)
+        .await
+        .map_err(|e| internal_error(format!("查詢戶失敗: {}", e)))?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::error(format!("找不到 ID 為 {} 的戶", id))),
+            )
+        })?;
+
+    let query = format!(
+        "{} WHERE householdId = ? ORDER BY id",
+        REGISTRATION_FULL_QUERY
+    );
+    let registrations = sqlx::query_as::<_, Registration>(&query)
+        .bind(id)
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| internal_error(format!("查詢報名記錄失敗: {}", e)))?;
+
+    // 參加記錄與每月贊助：已歸戶，或所屬報名已歸戶
+    let query = format!(
+        "{} WHERE householdId = ?1 OR registrationId IN (SELECT id FROM registrationDB WHERE householdId = ?1) ORDER BY id",
+        JOIN_RECORD_FULL_QUERY
+    );
+    let join_records = sqlx::query_as::<_, JoinRecord>(&query)
+        .bind(id)
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| internal_error(format!("查詢參與記錄失敗: {}", e)))?;
+
+    let query = format!(
+        "{} WHERE householdId = ?1 OR registrationId IN (SELECT id FROM registrationDB WHERE householdId = ?1) ORDER BY id",
+        MONTHLY_DONATE_FULL_QUERY
+    );
+    let monthly_donates = sqlx::query_as::<_, MonthlyDonate>(&query)
+        .bind(id)
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| internal_error(format!("查詢每月贊助失敗: {}", e)))?;
+
+    let join_record_ids: Vec<i64> = join_records.iter().map(|r| r.id).collect();
+    let activity_ids: Vec<i64> = join_records
+        .iter()
+        .filter_map(|r| r.activity_id)
+        .filter(|id| *id > 0)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+
+    let activities: Vec<Activity> = fetch_by_ids(&pool, ACTIVITY_FULL_QUERY, "id", &activity_ids)
+        .await
+        .map_err(|e| internal_error(format!("查詢活動失敗: {}", e)))?;
+    let mut conn = pool
+        .acquire()
+        .await
+        .map_err(|e| internal_error(format!("取得資料庫連線失敗: {}", e)))?;
+    let activities = activity_responses(&mut conn, activities)
+        .await
+        .map_err(|e| internal_error(format!("統計活動參加人數失敗: {}", e)))?;
+    drop(conn);
+
+    let payments: Vec<JoinRecordPayment> =
+        fetch_by_ids(&pool, PAYMENT_FULL_QUERY, "joinRecordId", &join_record_ids)
+            .await
+            .map_err(|e| internal_error(format!("查詢付款流水失敗: {}", e)))?;
+
+    // 收據：參加記錄指向的 receiptId，以及單筆收據的 recordId
+    let receipt_ids: HashSet<i64> = join_records
+        .iter()
+        .filter_map(|r| r.receipt_id)
+        .filter(|id| *id > 0)
+        .collect();
+    let mut receipts: Vec<ReceiptNumber> =
+        fetch_by_ids(&pool, RECEIPT_FULL_QUERY, "recordId", &join_record_ids)
+            .await
+            .map_err(|e| internal_error(format!("查詢收據失敗: {}", e)))?;
+    let missing_receipt_ids: Vec<i64> = receipt_ids
+        .iter()
+        .copied()
+        .filter(|id| !receipts.iter().any(|r| r.id == *id))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    receipts.extend(
+        fetch_by_ids::<ReceiptNumber>(&pool, RECEIPT_FULL_QUERY, "id", &missing_receipt_ids)
+            .await
+            .map_err(|e| internal_error(format!("查詢收據失敗: {}", e)))?,
+    );
+    receipts.sort_by_key(|r| r.id);
+
+    let final_amount: i64 = join_records
+        .iter()
+        .map(|r| r.final_amount.unwrap_or(0))
+        .sum();
+    let paid_amount: i64 = join_records
+        .iter()
+        .map(|r| r.paid_amount.unwrap_or(0))
+        .sum();
+    let summary = HouseholdSummary {
+        registration_count: registrations.len(),
+        join_record_count: join_records.len(),
+        activity_count: activities.len(),
+        monthly_donate_count: monthly_donates.len(),
+        receipt_count: receipts.len(),
+        final_amount,
+        paid_amount,
+        balance: final_amount - paid_amount,
+    };
+
+    let registrations: Vec<RegistrationResponse> = registrations
+        .into_iter()
+        .map(RegistrationResponse::from)
+        .collect();
+    let (members, ancestors) = household_members(&registrations);
+
+    Ok(Json(ApiResponse::success(HouseholdDetail {
+        household: household.into(),
+        summary,
+        members,
+        ancestors,
+        registrations,
+        activities,
+        join_records: join_records.into_iter().map(Into::into).collect(),
+        payments: payments.into_iter().map(Into::into).collect(),
+        receipts: receipts.into_iter().map(Into::into).collect(),
+        monthly_donates: monthly_donates.into_iter().map(Into::into).collect(),
+    })))
+}
+
+/// 彙整歷年報名的消災人員（依姓名去重，生肖取最近一次報名）與祖先姓氏
+fn household_members(
+    registrations: &[RegistrationResponse],
+) -> (Vec<HouseholdMember>, Vec<String>) {
+    let mut members: Vec<HouseholdMember> = Vec::new();
+    let mut index: HashMap<String, usize> = HashMap::new();
+    let mut ancestors: Vec<String> = Vec::new();
+    let mut seen_ancestors: HashSet<String> = HashSet::new();
+
+    for registration in registrations {
+        let seen_at = registration.created_at.clone();
+        for person in registration.blessing.iter().flat_map(|b| &b.persons) {
+            let key = normalize_text(&person.name);
+            if key.is_empty() {
+                continue;
+            }
+            let position = *index.entry(key).or_insert_with(|| {
+                members.push(HouseholdMember {
+                    name: person.name.trim().to_string(),
+                    zodiac: String::new(),
+                    is_household_head: false,
+                    registration_ids: Vec::new(),
+                    first_seen: seen_at.clone(),
+                    last_seen: None,
+                });
+                members.len() - 1
+            });
+            let member = &mut members[position];
+            let is_latest = seen_at >= member.last_seen;
+            if is_latest {
+                member.last_seen = seen_at.clone();
+            }
+            if seen_at.is_some() && (member.first_seen.is_none() || seen_at < member.first_seen) {
+                member.first_seen = seen_at.clone();
+            }
+            if !person.zodiac.is_empty() && (is_latest || member.zodiac.is_empty()) {
+                member.zodiac = person.zodiac.clone();
+            }
+            member.is_household_head |= person.is_household_head;
+            if !member.registration_ids.contains(&registration.id) {
+                member.registration_ids.push(registration.id);
+            }
+        }
+        for ancestor in registration.salvation.iter().flat_map(|s| &s.ancestors) {
+            let surname = ancestor.surname.trim();
+            if !surname.is_empty() && seen_ancestors.insert(normalize_text(surname)) {
+                ancestors.push(surname.to_string());
+            }
+        }
+    }
+
+    // 戶長優先，其次依首次出現順序
+    members.sort_by_key(|m| !m.is_household_head);
+    (members, ancestors)
+}
+
+/// 歸戶比對用的報名資料
+struct HouseholdProfile {
+    registration_id: i64,
+    household_id: Option<i64>,
+    head: String,         // 正規化後的戶長姓名
+    head_display: String, // 原始戶長姓名
+    mobile: String,
+    phone: String,
+    address: String,
+    raw_mobile: String,
+    raw_phone: String,
+    raw_address: String,
+}
+
+impl HouseholdProfile {
+    fn new(record: Registration) -> Self {
+        let contact = record
+            .contact
+            .into_reported("registrationDB.contact", record.id)
+            .unwrap_or_default();
+        let blessing = record
+            .blessing
+            .into_reported("registrationDB.blessing", record.id)
+            .unwrap_or_default();
+        let salvation = record
+            .salvation
+            .into_reported("registrationDB.salvation", record.id)
+            .unwrap_or_default();
+
+        // 戶長：消災人員中標記 isHouseholdHead 者，沒有時取聯絡人
+        let head_display = blessing
+            .persons
+            .iter()
+            .find(|p| p.is_household_head && !p.name.trim().is_empty())
+            .map(|p| p.name.trim().to_string())
+            .unwrap_or_else(|| contact.name.trim().to_string());
+        let raw_address = if blessing.address.trim().is_empty() {
+            salvation.address.trim().to_string()
+        } else {
+            blessing.address.trim().to_string()
+        };
+
+        Self {
+            registration_id: record.id,
+            household_id: record.household_id.filter(|id| *id > 0),
+            head: normalize_text(&head_display),
+            head_display,
+            mobile: normalize_digits(&contact.mobile),
+            phone: normalize_digits(&contact.phone),
+            address: normalize_text(&raw_address),
+            raw_mobile: contact.mobile,
+            raw_phone: contact.phone,
+            raw_address,
+        }
+    }
+
+    /// 歸戶鍵：同一戶長姓名且手機、電話或地址任一相同
+    /// 只比手機會把同一聯絡人代登記的本家、娘家併成一戶，因此一律搭配戶長姓名
+    fn keys(&self) -> Vec<String> {
+        let mut keys = Vec::new();
+        if let Some(household_id) = self.household_id {
+            keys.push(format!("household:{}", household_id));
+        }
+        if self.head.is_empty() {
+            return keys;
+        }
+        if self.mobile.len() >= 8 {
+            keys.push(format!("{}|mobile:{}", self.head, self.mobile));
+        }
+        if self.phone.len() >= 7 {
+            keys.push(format!("{}|phone:{}", self.head, self.phone));
+        }
+        if !self.address.is_empty() {
+            keys.push(format!("{}|address:{}", self.head, self.address));
+        }
+        keys
+    }
+}
+
+fn find_root(parent: &mut [usize], index: usize) -> usize {
+    let mut root = index;
+    while parent[root] != root {
+        root = parent[root];
+    }
+    let mut current = index;
+    while parent[current] != root {
+        let next = parent[current];
+        parent[current] = root;
+        current = next;
+    }
+    root
+}
+
+/// 由報名記錄推導戶別：尚未歸戶的報名依戶長姓名 + 手機 / 電話 / 地址併入既有戶或建立新戶，
+/// 已歸戶的報名不會被改動；再將所屬報名已歸戶的參加記錄與每月贊助補上戶ID
+pub async fn derive_households(
+    Extension(pool): Extension<SqlitePool>,
+    Json(payload): Json<DeriveHouseholdsRequest>,
+) -> Result<
+    Json<ApiResponse<DeriveHouseholdsResult>>,
+    (StatusCode, Json<ApiResponse<DeriveHouseholdsResult>>),
+> {
+    let internal_error = |message: String| {
+        tracing::error!("{}", message);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(message)),
+        )
+    };
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| internal_error(format!("啟動事務失敗: {}", e)))?;
+
+    // 封存（已合併）的報名仍一併歸戶，保留完整歷史
+    let query = format!("{} ORDER BY id", REGISTRATION_FULL_QUERY);
+    let registrations = sqlx::query_as::<_, Registration>(&query)
+        .fetch_all(&mut *tx)
+        .await
+        .map_err(|e| internal_error(format!("查詢報名記錄失敗: {}", e)))?;
+    let profiles: Vec<HouseholdProfile> = registrations
+        .into_iter()
+        .map(HouseholdProfile::new)
+        .collect();
+
+    // 共用任一歸戶鍵的報名視為同一戶
+    let mut parent: Vec<usize> = (0..profiles.len()).collect();
+    let mut first_by_key: HashMap<String, usize> = HashMap::new();
+    for (index, profile) in profiles.iter().enumerate() {
+        for key in profile.keys() {
+            let first = *first_by_key.entry(key).or_insert(index);
+            let (a, b) = (find_root(&mut parent, first), find_root(&mut parent, index));
+            if a != b {
+                parent[a.max(b)] = a.min(b);
+            }
+        }
+    }
+    let mut groups: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for index in 0..profiles.len() {
+        let root = find_root(&mut parent, index);
+        groups.entry(root).or_default().push(index);
+    }
+
+    let now = chrono::Utc::now();
+    let mut result = DeriveHouseholdsResult {
+        dry_run: payload.dry_run,
+        created_households: 0,
+        assigned_registrations: 0,
+        assigned_join_records: 0,
+        assigned_monthly_donates: 0,
+        skipped_registration_ids: Vec::new(),
+        households: Vec::new(),
+    };
+    // 推導後有戶ID的報名（含既有），用於 dryRun 時估算參加記錄與每月贊助
+    let mut assigned: HashSet<i64> = profiles
+        .iter()
+        .filter(|p| p.household_id.is_some())
+        .map(|p| p.registration_id)
+        .collect();
+
+    for members in groups.values() {
+        let pending: Vec<&HouseholdProfile> = members
+            .iter()
+            .map(|&i| &profiles[i])
+            .filter(|p| p.household_id.is_none())
+            .collect();
+        if pending.is_empty() {
+            continue;
+        }
+        // 沒有戶長或聯絡人姓名、也沒有其他歸戶鍵的報名無法歸戶
+        if members.len() == 1 && pending[0].head.is_empty() {
+            result
+                .skipped_registration_ids
+                .push(pending[0].registration_id);
+            continue;
+        }
+
+        // 併入同組中 ID 最小的既有戶；沒有時以最新一筆報名的資料建立新戶
+        let existing = members
+            .iter()
+            .filter_map(|&i| profiles[i].household_id)
+            .min();
+        let latest = members
+            .iter()
+            .map(|&i| &profiles[i])
+            .rev()
+            .find(|p| !p.head.is_empty())
+            .unwrap_or(pending[pending.len() - 1]);
+        let household_id = match existing {
+            Some(id) => Some(id),
+            None if payload.dry_run => None,
+            None => {
+                let inserted = sqlx::query(
+                    r#"
+                    INSERT INTO householdDB (
+                        user_created, date_created, headName, mobile, phone, address, state, createdAt, updatedAt
+                    )
+                    VALUES (?, ?, ?, ?, ?, ?, 'active', ?, ?)
+                    "#,
+                )
+                .bind(&payload.user_created)
+                .bind(now.timestamp_millis())
+                .bind(&latest.head_display)
+                .bind(&latest.raw_mobile)
+                .bind(&latest.raw_phone)
+                .bind(&latest.raw_address)
+                .bind(now.to_rfc3339())
+                .bind(now.to_rfc3339())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| internal_error(format!("建立戶失敗: {}", e)))?;
+                Some(inserted.last_insert_rowid())
+            }
+        };
+        if existing.is_none() {
+            result.created_households += 1;
+        }
+
+        let new_registration_ids: Vec<i64> = pending.iter().map(|p| p.registration_id).collect();
+        if let Some(household_id) = household_id.filter(|_| !payload.dry_run) {
+            let placeholders = new_registration_ids
+                .iter()
+                .map(|_| "?")
+                .collect::<Vec<_>>()
+                .join(", ");
+            let sql = format!(
+                "UPDATE registrationDB SET householdId = ?, updatedAt = ? WHERE COALESCE(householdId, -1) <= 0 AND id IN ({})",
+                placeholders
+            );
+            let mut q = sqlx::query(&sql).bind(household_id).bind(now.to_rfc3339());
+            for id in &new_registration_ids {
+                q = q.bind(id);
+            }
+            q.execute(&mut *tx)
+                .await
+                .map_err(|e| internal_error(format!("更新報名記錄戶ID失敗: {}", e)))?;
+        }
+
+        result.assigned_registrations += new_registration_ids.len();
+        assigned.extend(new_registration_ids.iter().copied());
+        result.households.push(DerivedHousehold {
+            household_id,
+            head_name: latest.head_display.clone(),
+            registration_ids: members
+                .iter()
+                .map(|&i| profiles[i].registration_id)
+                .collect(),
+            new_registration_ids,
+        });
+    }
+
+    if payload.dry_run {
+        for (table, count) in [
+            ("joinRecordDB", &mut result.assigned_join_records),
+            ("monthlyDonateDB", &mut result.assigned_monthly_donates),
+        ] {
+            let rows: Vec<(i64,)> = sqlx::query_as(&format!(
+                "SELECT registrationId FROM {} WHERE COALESCE(householdId, -1) <= 0 AND registrationId > 0",
+                table
+            ))
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| internal_error(format!("查詢 {} 失敗: {}", table, e)))?;
+            *count = rows.iter().filter(|(id,)| assigned.contains(id)).count() as u64;
+        }
+        return Ok(Json(ApiResponse::success_with_message(
+            result,
+            "預覽完成，尚未寫入（dryRun=false 才會歸戶）".to_string(),
+        )));
+    }
+
+    // 參加記錄與每月贊助沿用所屬報名的戶ID
+    for (table, count) in [
+        ("joinRecordDB", &mut result.assigned_join_records),
+        ("monthlyDonateDB", &mut result.assigned_monthly_donates),
+    ] {
+        *count = sqlx::query(&format!(
+            r#"
+            UPDATE {table}
+            SET householdId = (SELECT r.householdId FROM registrationDB r WHERE r.id = {table}.registrationId)
+            WHERE COALESCE(householdId, -1) <= 0
+              AND registrationId IN (SELECT id FROM registrationDB WHERE householdId > 0)
+            "#,
+            table = table
+        ))
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| internal_error(format!("更新 {} 戶ID失敗: {}", table, e)))?
+        .rows_affected();
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| internal_error(format!("提交事務失敗: {}", e)))?;
+
+    let message = format!(
+        "已建立 {} 戶，歸戶報名 {} 筆、參加記錄 {} 筆、每月贊助 {} 筆",
+        result.created_households,
+        result.assigned_registrations,
+        result.assigned_join_records,
+        result.assigned_monthly_donates
+    );
+    Ok(Json(ApiResponse::success_with_message(result, message)))
+}
+
+/// 手動歸戶：指定報名、參加記錄、每月贊助改屬此戶（報名的參加記錄與每月贊助一併改屬）
+pub async fn link_household(
+    Path(id): Path<i64>,
+    Extension(pool): Extension<SqlitePool>,
+    Json(payload): Json<LinkHouseholdRequest>,
+) -> Result<
+    Json<ApiResponse<LinkHouseholdResult>>,
+    (StatusCode, Json<ApiResponse<LinkHouseholdResult>>),
+> {
+    let internal_error = |message: String| {
+        tracing::error!("{}", message);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(message)),
+        )
+    };
+
+    if payload.registration_ids.is_empty()
+        && payload.join_record_ids.is_empty()
+        && payload.monthly_donate_ids.is_empty()
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(
+                "請提供 registrationIds、joinRecordIds 或 monthlyDonateIds".to_string(),
+            )),
+        ));
+    }
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| internal_error(format!("啟動事務失敗: {}", e)))?;
+
+    let exists: Option<(i64,)> = sqlx::query_as("SELECT id FROM householdDB WHERE id = ?")
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| internal_error(format!("查詢戶失敗: {}", e)))?;
+    if exists.is_none() {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!("找不到 ID 為 {} 的戶", id))),
+        ));
+    }
+
+    let now = chrono::Utc::now();
+    let placeholders = |ids: &[i64]| ids.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+    let registration_placeholders = placeholders(&payload.registration_ids);
+
+    // 找不到的報名記錄整批放棄，避免部分歸戶
+    if !payload.registration_ids.is_empty() {
+        let sql = format!(
+            "SELECT id FROM registrationDB WHERE id IN ({}) AND date_deleted IS NULL",
+            registration_placeholders
+        );
+        let mut q = sqlx::query_as::<_, (i64,)>(&sql);
+        for registration_id in &payload.registration_ids {
+            q = q.bind(registration_id);
+        }
+        let found: HashSet<i64> = q
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| internal_error(format!("查詢報名記錄失敗: {}", e)))?
+            .into_iter()
+            .map(|(id,)| id)
+            .collect();
+        let missing: Vec<String> = payload
+            .registration_ids
+            .iter()
+            .filter(|id| !found.contains(id))
+            .map(|id| format!("找不到 ID 為 {} 的報名記錄", id))
+            .collect();
+        if !missing.is_empty() {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::error_with_details(
+                    "部分報名記錄不存在".to_string(),
+                    missing,
+                )),
+            ));
+        }
+    }
+
+    let mut result = LinkHouseholdResult {
+        household_id: id,
+        linked_registrations: 0,
+        linked_join_records: 0,
+        linked_monthly_donates: 0,
+    };
+
+    if !payload.registration_ids.is_empty() {
+        let sql = format!(
+            "UPDATE registrationDB SET householdId = ?, updatedAt = ?, user_updated = COALESCE(?, user_updated) WHERE id IN ({})",
+            registration_placeholders
+        );
+        let mut q = sqlx::query(&sql)
+            .bind(id)
+            .bind(now.to_rfc3339())
+            .bind(&payload.user_updated);
+        for registration_id in &payload.registration_ids {
+            q = q.bind(registration_id);
+        }
+        result.linked_registrations = q
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| internal_error(format!("更新報名記錄戶ID失敗: {}", e)))?
+            .rows_affected();
+    }
+
+    for (table, record_ids, count) in [
+        (
+            "joinRecordDB",
+            &payload.join_record_ids,
+            &mut result.linked_join_records,
+        ),
+        (
+            "monthlyDonateDB",
+            &payload.monthly_donate_ids,
+            &mut result.linked_monthly_donates,
+        ),
+    ] {
+        let mut conditions = Vec::new();
+        if !record_ids.is_empty() {
+            conditions.push(format!("id IN ({})", placeholders(record_ids)));
+        }
+        if !payload.registration_ids.is_empty() {
+            conditions.push(format!("registrationId IN ({})", registration_placeholders));
+        }
+        if conditions.is_empty() {
+            continue;
+        }
+        let sql = format!(
+            "UPDATE {} SET householdId = ?, updatedAt = ?, user_updated = COALESCE(?, user_updated) WHERE {}",
+            table,
+            conditions.join(" OR ")
+        );
+        let mut q = sqlx::query(&sql)
+            .bind(id)
+            .bind(now.to_rfc3339())
+            .bind(&payload.user_updated);
+        for record_id in record_ids.iter().chain(&payload.registration_ids) {
+            q = q.bind(record_id);
+        }
+        *count = q
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| internal_error(format!("更新 {} 戶ID失敗: {}", table, e)))?
+            .rows_affected();
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| internal_error(format!("提交事務失敗: {}", e)))?;
+
+    let message = format!(
+        "已歸戶：報名 {} 筆、參加記錄 {} 筆、每月贊助 {} 筆",
+        result.linked_registrations, result.linked_join_records, result.linked_monthly_donates
+    );
+    Ok(Json(ApiResponse::success_with_message(result, message)))
+}
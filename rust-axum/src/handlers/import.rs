@@ -0,0 +1,1103 @@
+// src/handlers/import.rs
+//! 歷史資料匯入（CSV / XLSX）
+//! 檔案欄位依 mapping 對應到目標欄位，逐列驗證並組裝 contact / salvation / blessing 等巢狀 JSON；
+//! dryRun 只回報錯誤與預覽，正式匯入時全部資料在同一事務中寫入，任何一列有誤就不寫入
+
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    Json,
+};
+use base64::Engine;
+use serde_json::{json, Value as JsonValue};
+use sqlx::{SqliteConnection, SqlitePool};
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use crate::handlers::join_record::insert_join_record;
+use crate::handlers::join_record_item::{
+    load_effective_prices, DEFAULT_LAMP_TYPE, ITEM_TYPES, LAMP_TYPES,
+};
+use crate::handlers::monthly_donate::{
+    is_valid_year_month, next_year_month, validate_donate_items,
+};
+use crate::handlers::registration::{
+    parse_registration_parts, validate_registration, RegistrationLimits,
+};
+use crate::handlers::soft_delete::NOT_DELETED;
+use crate::models::api_response::ApiResponse;
+use crate::models::import::{ImportField, ImportRequest, ImportResult, ImportRowError};
+use crate::models::json_types::PriceTable;
+use crate::models::join_record::CreateJoinRecordRequest;
+use crate::utils::{csv, xlsx};
+
+const MAX_IMPORT_ROWS: usize = 5000;
+const PREVIEW_RECORDS: usize = 5;
+const MAX_MONTH_RANGE: usize = 120;
+const RELATIONSHIPS: &[&str] = &["本家", "娘家", "朋友", "其它", "其他"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Collection {
+    Registrations,
+    MonthlyDonates,
+    JoinRecords,
+}
+
+impl Collection {
+    fn parse(name: &str) -> Option<Self> {
+        match name {
+            "registrations" => Some(Collection::Registrations),
+            "monthly-donates" => Some(Collection::MonthlyDonates),
+            "join-records" => Some(Collection::JoinRecords),
+            _ => None,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Collection::Registrations => "registrations",
+            Collection::MonthlyDonates => "monthly-donates",
+            Collection::JoinRecords => "join-records",
+        }
+    }
+
+    /// 可匯入的目標欄位：(欄位, 說明, 是否必填)
+    fn fields(self) -> Vec<ImportField> {
+        let field = |field: &str, label: &str, required: bool| ImportField {
+            field: field.to_string(),
+            label: label.to_string(),
+            required,
+        };
+        let contact_fields = || {
+            vec![
+                field("contact.name", "聯絡人姓名", true),
+                field("contact.phone", "電話", false),
+                field("contact.mobile", "手機", false),
+                field(
+                    "contact.relationship",
+                    "關係（本家、娘家、朋友、其它）",
+                    false,
+                ),
+                field("contact.otherRelationship", "其它關係", false),
+            ]
+        };
+
+        match self {
+            Collection::Registrations => {
+                let mut fields = contact_fields();
+                fields.extend([
+                    field("salvation.address", "超度地址", false),
+                    field("salvation.ancestors", "祖先姓氏（多筆以、分隔）", false),
+                    field("salvation.survivors", "陽上人（多筆以、分隔）", false),
+                    field("blessing.address", "消災地址", false),
+                    field("blessing.persons", "消災人員（多筆以、分隔）", false),
+                    field(
+                        "blessing.householdHead",
+                        "戶長（需為消災人員之一，預設第一位）",
+                        false,
+                    ),
+                    field("state", "狀態（預設 submitted）", false),
+                    field("formName", "表單名稱", false),
+                    field("formSource", "表單來源（預設 匯入）", false),
+                    field("createdAt", "建立日期（YYYY-MM-DD 或 ISO 8601）", false),
+                ]);
+                fields
+            }
+            Collection::MonthlyDonates => vec![
+                field("name", "贊助者姓名", true),
+                field("price", "金額（該列所有月份合計）", true),
+                field(
+                    "months",
+                    "贊助月份（YYYYMM，多筆以、分隔，或 202601-202612 區間）",
+                    true,
+                ),
+                field(
+                    "donateId",
+                    "贊助編號（相同編號的多列合併為同一贊助者）",
+                    false,
+                ),
+                field("donateType", "贊助類型", false),
+                field("registrationId", "報名ID（預設 -1）", false),
+                field("memo", "備註", false),
+                field("createdAt", "項目建立日期（YYYY-MM-DD 或 ISO 8601）", false),
+            ],
+            Collection::JoinRecords => {
+                let mut fields = contact_fields();
+                fields.extend([
+                    field("registrationId", "報名ID（預設 -1）", false),
+                    field("activityId", "活動ID（預設 -1）", false),
+                    field("state", "狀態（預設 confirmed）", false),
+                ]);
+                fields.extend(ITEM_TYPES.iter().map(|(item_type, label, ..)| {
+                    field(
+                        &format!("items.{}", item_type),
+                        &format!("{}名單（多筆以、分隔）", label),
+                        false,
+                    )
+                }));
+                fields.extend([
+                    field("totalAmount", "總金額（預設依價格配置計算）", false),
+                    field("discountAmount", "折扣", false),
+                    field("finalAmount", "應收金額（預設 總金額 − 折扣）", false),
+                    field("paidAmount", "已收金額", false),
+                    field("paymentState", "付款狀態", false),
+                    field("paymentMethod", "付款方式", false),
+                    field("paymentDate", "付款日期", false),
+                    field("receiptNumber", "收據號碼", false),
+                    field("notes", "備註", false),
+                ]);
+                fields
+            }
+        }
+    }
+}
+
+/// 一列資料（number 為檔案中的列號，標題列為第 1 列）
+struct ImportRow<'a> {
+    number: usize,
+    cells: &'a [String],
+    columns: &'a HashMap<String, usize>,
+}
+
+impl ImportRow<'_> {
+    fn text(&self, field: &str) -> Option<String> {
+        self.columns
+            .get(field)
+            .and_then(|&index| self.cells.get(index))
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+    }
+
+    fn list(&self, field: &str) -> Vec<String> {
+        self.text(field)
+            .map(|value| split_list(&value))
+            .unwrap_or_default()
+    }
+
+    fn int(&self, field: &str, errors: &mut Vec<ImportRowError>) -> Option<i64> {
+        let value = self.text(field)?;
+        match parse_amount(&value) {
+            Some(n) => Some(n),
+            None => {
+                errors.push(self.error(field, format!("無法解析數字: {}", value)));
+                None
+            }
+        }
+    }
+
+    fn date(&self, field: &str, errors: &mut Vec<ImportRowError>) -> Option<String> {
+        let value = self.text(field)?;
+        match parse_date(&value) {
+            Some(date) => Some(date),
+            None => {
+                errors.push(self.error(field, format!("無法解析日期: {}", value)));
+                None
+            }
+        }
+    }
+
+    fn error(&self, field: &str, message: String) -> ImportRowError {
+        ImportRowError {
+            row: self.number,
+            field: Some(field.to_string()),
+            message,
+        }
+    }
+}
+
+/// 組裝完成的記錄（rows 為來源列號，每月贊助可由多列合併）
+struct BuiltRecord {
+    rows: Vec<usize>,
+    data: JsonValue,
+}
+
+/// 驗證時需要的既有資料
+#[derive(Default)]
+struct ImportContext {
+    registration_ids: HashSet<i64>,
+    activity_ids: HashSet<i64>,
+    donate_ids: HashSet<String>,
+    prices: PriceTable,
+    has_price_config: bool,
+}
+
+/// 多筆值分隔：、 , ， ; ； 換行
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split(['、', ',', '，', ';', '；', '\n'])
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// 金額：允許千分位、「元」與 Excel 的 "400.0"
+fn parse_amount(value: &str) -> Option<i64> {
+    let cleaned: String = value
+        .chars()
+        .filter(|c| !matches!(c, ',' | '，' | '元' | '$' | ' '))
+        .collect();
+    let cleaned = cleaned.trim_start_matches("NT");
+    cleaned.parse::<i64>().ok().or_else(|| {
+        cleaned
+            .parse::<f64>()
+            .ok()
+            .filter(|n| n.fract() == 0.0)
+            .map(|n| n as i64)
+    })
+}
+
+/// 日期：ISO 8601 原樣保留；YYYY-MM-DD、YYYY/MM/DD 與 Excel 日期序號轉為當地午夜
+fn parse_date(value: &str) -> Option<String> {
+    if chrono::DateTime::parse_from_rfc3339(value).is_ok() {
+        return Some(value.to_string());
+    }
+    let date = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .or_else(|_| chrono::NaiveDate::parse_from_str(value, "%Y/%m/%d"))
+        .ok()
+        .or_else(|| {
+            let serial = value
+                .parse::<f64>()
+                .ok()
+                .filter(|n| (1.0..100000.0).contains(n))?;
+            chrono::NaiveDate::from_ymd_opt(1899, 12, 30)?
+                .checked_add_days(chrono::Days::new(serial as u64))
+        })?;
+    let local = date
+        .and_hms_opt(0, 0, 0)?
+        .and_local_timezone(chrono::Local)
+        .single()?;
+    Some(
+        local
+            .with_timezone(&chrono::Utc)
+            .to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+    )
+}
+
+/// 單一月份：202601、2026-01、2026/1、2026年1月
+fn normalize_month(value: &str) -> Option<String> {
+    let parts: Vec<&str> = value
+        .split(['-', '/', '.', '年', '月'])
+        .filter(|s| !s.is_empty())
+        .collect();
+    let month = match parts.as_slice() {
+        [single] if single.len() == 6 => single.to_string(),
+        [year, month] if year.len() == 4 && month.len() <= 2 => {
+            format!("{}{:0>2}", year, month)
+        }
+        _ => return None,
+    };
+    is_valid_year_month(&month).then_some(month)
+}
+
+/// 月份清單，支援 202601-202612 或 202601~202612 區間
+fn parse_months(value: &str) -> Result<Vec<String>, String> {
+    let mut months: Vec<String> = Vec::new();
+    for token in split_list(value) {
+        let range = token
+            .split_once(['~', '～'])
+            .or_else(|| {
+                (token.len() == 13 && token.as_bytes()[6] == b'-').then(|| token.split_at(6))
+            })
+            .map(|(from, to)| (from.trim(), to.trim().trim_start_matches('-')));
+
+        match range {
+            Some((from, to)) => {
+                let (Some(from), Some(to)) = (normalize_month(from), normalize_month(to)) else {
+                    return Err(format!("無效的月份區間: {}", token));
+                };
+                if from > to {
+                    return Err(format!("月份區間起訖顛倒: {}", token));
+                }
+                let mut month = Some(from);
+                while let Some(current) = month.filter(|m| *m <= to) {
+                    month = next_year_month(&current);
+                    months.push(current);
+                    if months.len() > MAX_MONTH_RANGE {
+                        return Err(format!("月份區間過長: {}", token));
+                    }
+                }
+            }
+            None => months.push(
+                normalize_month(&token)
+                    .ok_or_else(|| format!("無效的月份: {}（應為 YYYYMM）", token))?,
+            ),
+        }
+    }
+    Ok(months)
+}
+
+fn generate_short_id(taken: &HashSet<String>) -> String {
+    loop {
+        let candidate = uuid::Uuid::new_v4().simple().to_string()[..7].to_string();
+        if !taken.contains(&candidate) {
+            return candidate;
+        }
+    }
+}
+
+fn now_iso_millis() -> String {
+    chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true)
+}
+
+/// 聯絡人（報名記錄與參與記錄共用），「其他」統一為「其它」
+fn build_contact(row: &ImportRow) -> JsonValue {
+    let relationship = match row
+        .text("contact.relationship")
+        .unwrap_or_default()
+        .as_str()
+    {
+        "其他" => "其它".to_string(),
+        other => other.to_string(),
+    };
+
+    json!({
+        "name": row.text("contact.name").unwrap_or_default(),
+        "phone": row.text("contact.phone").unwrap_or_default(),
+        "mobile": row.text("contact.mobile").unwrap_or_default(),
+        "relationship": relationship,
+        "otherRelationship": row.text("contact.otherRelationship").unwrap_or_default(),
+    })
+}
+
+/// 參與記錄的聯絡人檢查（報名記錄改用完整的報名業務規則）
+fn check_contact(row: &ImportRow, errors: &mut Vec<ImportRowError>) {
+    if row.text("contact.name").is_none() {
+        errors.push(row.error("contact.name", "聯絡人姓名為必填".to_string()));
+    }
+    let relationship = row.text("contact.relationship").unwrap_or_default();
+    if !relationship.is_empty() && !RELATIONSHIPS.contains(&relationship.as_str()) {
+        errors.push(row.error(
+            "contact.relationship",
+            format!(
+                "無效的關係: {}（僅接受 本家、娘家、朋友、其它）",
+                relationship
+            ),
+        ));
+    }
+}
+
+fn build_registrations(
+    rows: &[ImportRow],
+    limits: &RegistrationLimits,
+    errors: &mut Vec<ImportRowError>,
+) -> Vec<BuiltRecord> {
+    let mut form_ids: HashSet<String> = HashSet::new();
+    let mut records = Vec::new();
+
+    for row in rows {
+        let before = errors.len();
+        let contact = build_contact(row);
+
+        let ancestors: Vec<JsonValue> = row
+            .list("salvation.ancestors")
+            .into_iter()
+            .enumerate()
+            .map(|(i, surname)| json!({ "id": i + 1, "surname": surname, "notes": "" }))
+            .collect();
+        let survivors: Vec<JsonValue> = row
+            .list("salvation.survivors")
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| json!({ "id": i + 1, "name": name, "notes": "", "zodiac": "" }))
+            .collect();
+
+        let person_names = row.list("blessing.persons");
+        let heads = row.list("blessing.householdHead");
+        for head in &heads {
+            if !person_names.contains(head) {
+                errors.push(row.error(
+                    "blessing.householdHead",
+                    format!("戶長 {} 不在消災人員名單中", head),
+                ));
+            }
+        }
+        let persons: Vec<JsonValue> = person_names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let is_head = if heads.is_empty() { i == 0 } else { heads.contains(name) };
+                json!({ "id": i + 1, "name": name, "notes": "", "zodiac": "", "isHouseholdHead": is_head })
+            })
+            .collect();
+
+        let salvation = json!({
+            "address": row.text("salvation.address").unwrap_or_default(),
+            "ancestors": ancestors,
+            "survivors": survivors,
+        });
+        let blessing = json!({
+            "address": row.text("blessing.address").unwrap_or_default(),
+            "persons": persons,
+        });
+
+        // 與 API 建立報名記錄相同的業務規則
+        let field_errors =
+            match parse_registration_parts(Some(&contact), Some(&blessing), Some(&salvation)) {
+                Ok((contact, blessing, salvation)) => {
+                    validate_registration(&contact, &blessing, &salvation, limits)
+                }
+                Err(field_errors) => field_errors,
+            };
+        errors.extend(
+            field_errors
+                .into_iter()
+                .map(|e| row.error(&e.field, e.message)),
+        );
+
+        let created_at = row.date("createdAt", errors).unwrap_or_else(now_iso_millis);
+
+        if errors.len() > before {
+            continue;
+        }
+
+        let form_id = generate_short_id(&form_ids);
+        form_ids.insert(form_id.clone());
+
+        records.push(BuiltRecord {
+            rows: vec![row.number],
+            data: json!({
+                "state": row.text("state").unwrap_or_else(|| "submitted".to_string()),
+                "formId": form_id,
+                "formName": row.text("formName").unwrap_or_else(|| "匯入資料".to_string()),
+                "formSource": row.text("formSource").unwrap_or_else(|| "匯入".to_string()),
+                "contact": contact,
+                "salvation": salvation,
+                "blessing": blessing,
+                "createdAt": created_at,
+            }),
+        });
+    }
+    records
+}
+
+fn build_monthly_donates(
+    rows: &[ImportRow],
+    context: &ImportContext,
+    user_created: &str,
+    errors: &mut Vec<ImportRowError>,
+) -> Vec<BuiltRecord> {
+    // 以贊助編號分組（保持檔案順序），沒有編號的列各自成為一筆
+    let mut groups: Vec<(Option<String>, Vec<&ImportRow>)> = Vec::new();
+    for row in rows {
+        let donate_id = row.text("donateId");
+        match donate_id
+            .as_ref()
+            .and_then(|id| groups.iter_mut().find(|(key, _)| key.as_ref() == Some(id)))
+        {
+            Some((_, members)) => members.push(row),
+            None => groups.push((donate_id, vec![row])),
+        }
+    }
+
+    let mut taken_ids: HashSet<String> = context.donate_ids.clone();
+    let mut records = Vec::new();
+
+    for (donate_id, members) in groups {
+        let first = members[0];
+        let before = errors.len();
+
+        let name = first.text("name");
+        if name.is_none() {
+            errors.push(first.error("name", "贊助者姓名為必填".to_string()));
+        }
+        if let Some(id) = &donate_id {
+            if context.donate_ids.contains(id) {
+                errors.push(first.error("donateId", format!("贊助編號 {} 已存在", id)));
+            }
+        }
+        let registration_id = first.int("registrationId", errors).unwrap_or(-1);
+        if registration_id != -1 && !context.registration_ids.contains(&registration_id) {
+            errors.push(first.error(
+                "registrationId",
+                format!("找不到報名記錄 {}", registration_id),
+            ));
+        }
+
+        let mut item_ids: HashSet<String> = HashSet::new();
+        let mut items: Vec<JsonValue> = Vec::new();
+        for row in &members {
+            if row.number != first.number && row.text("name").is_some() && row.text("name") != name
+            {
+                errors.push(row.error("name", "同一贊助編號的姓名不一致".to_string()));
+            }
+            let price = row.int("price", errors);
+            if price.is_none() && row.text("price").is_none() {
+                errors.push(row.error("price", "金額為必填".to_string()));
+            }
+            let months = match row.text("months").map(|v| parse_months(&v)) {
+                Some(Ok(months)) => months,
+                Some(Err(message)) => {
+                    errors.push(row.error("months", message));
+                    Vec::new()
+                }
+                None => {
+                    errors.push(row.error("months", "贊助月份為必填".to_string()));
+                    Vec::new()
+                }
+            };
+            let created_at = row.date("createdAt", errors).unwrap_or_else(now_iso_millis);
+
+            let item_id = generate_short_id(&item_ids);
+            item_ids.insert(item_id.clone());
+            items.push(json!({
+                "donateItemsId": item_id,
+                "price": price.unwrap_or(0),
+                "months": months,
+                "createdAt": created_at,
+                "createdUser": user_created,
+                "updatedAt": "",
+                "updatedUser": "",
+            }));
+        }
+
+        if errors.len() > before {
+            continue;
+        }
+
+        // 金額、月份格式與月份重複檢查與 API 新增時相同
+        let items = JsonValue::Array(items);
+        if let Err((_, message, details)) = validate_donate_items(&items, false, None) {
+            errors.push(ImportRowError {
+                row: first.number,
+                field: Some("months".to_string()),
+                message: format!("{}：{}", message, details.join("；")),
+            });
+            continue;
+        }
+
+        let donate_id = donate_id.unwrap_or_else(|| generate_short_id(&taken_ids));
+        taken_ids.insert(donate_id.clone());
+
+        records.push(BuiltRecord {
+            rows: members.iter().map(|row| row.number).collect(),
+            data: json!({
+                "name": name,
+                "donateId": donate_id,
+                "donateType": first.text("donateType").unwrap_or_default(),
+                "registrationId": registration_id,
+                "memo": first.text("memo").unwrap_or_default(),
+                "donateItems": items,
+            }),
+        });
+    }
+    records
+}
+
+fn build_join_records(
+    rows: &[ImportRow],
+    context: &ImportContext,
+    errors: &mut Vec<ImportRowError>,
+) -> Vec<BuiltRecord> {
+    let mut records = Vec::new();
+
+    for row in rows {
+        let before = errors.len();
+        check_contact(row, errors);
+        let contact = build_contact(row);
+
+        let registration_id = row.int("registrationId", errors).unwrap_or(-1);
+        if registration_id != -1 && !context.registration_ids.contains(&registration_id) {
+            errors.push(row.error(
+                "registrationId",
+                format!("找不到報名記錄 {}", registration_id),
+            ));
+        }
+        let activity_id = row.int("activityId", errors).unwrap_or(-1);
+        if activity_id != -1 && !context.activity_ids.contains(&activity_id) {
+            errors.push(row.error("activityId", format!("找不到活動 {}", activity_id)));
+        }
+
+        let mut items: Vec<JsonValue> = Vec::new();
+        let mut computed_total = 0;
+        for (item_type, label, source, default_price) in ITEM_TYPES {
+            let field = format!("items.{}", item_type);
+            let names = row.list(&field);
+            if names.is_empty() {
+                continue;
+            }
+            let price = if *item_type == "diandeng" {
+                let (_, _, lamp_price) = LAMP_TYPES
+                    .iter()
+                    .find(|(t, _, _)| *t == DEFAULT_LAMP_TYPE)
+                    .copied()
+                    .unwrap_or((DEFAULT_LAMP_TYPE, "", 0));
+                context
+                    .prices
+                    .get(&format!("diandeng_{}", DEFAULT_LAMP_TYPE))
+                    .copied()
+                    .unwrap_or(lamp_price)
+            } else {
+                context.prices.get(*item_type).copied().unwrap_or(*default_price)
+            };
+
+            let source_data: Vec<JsonValue> = names
+                .iter()
+                .enumerate()
+                .map(|(i, name)| {
+                    if *item_type == "chaodu" {
+                        json!({ "id": i + 1, "surname": name, "notes": "" })
+                    } else {
+                        json!({ "id": i + 1, "name": name, "notes": "", "zodiac": "" })
+                    }
+                })
+                .collect();
+            let quantity = names.len() as i64;
+            let subtotal = price * quantity;
+            computed_total += subtotal;
+
+            let mut item = json!({
+                "type": item_type,
+                "label": label,
+                "price": price,
+                "quantity": quantity,
+                "subtotal": subtotal,
+                "source": source,
+                "sourceData": source_data,
+                "sourceAddress": "",
+            });
+            if *item_type == "diandeng" {
+                item["lampDetails"] = JsonValue::Array(
+                    names
+                        .iter()
+                        .enumerate()
+                        .map(|(i, name)| {
+                            json!({
+                                "personId": i + 1,
+                                "personName": name,
+                                "lampType": DEFAULT_LAMP_TYPE,
+                                "lampTypeLabel": "光明燈",
+                                "price": price,
+                            })
+                        })
+                        .collect(),
+                );
+            }
+            items.push(item);
+        }
+
+        if items.is_empty() && row.columns.keys().any(|k| k.starts_with("items.")) {
+            errors.push(ImportRowError {
+                row: row.number,
+                field: None,
+                message: "至少需要一個參加項目".to_string(),
+            });
+        }
+        if !items.is_empty() && !context.has_price_config {
+            errors.push(ImportRowError {
+                row: row.number,
+                field: None,
+                message: "目前沒有生效中的價格配置，無法計算項目金額".to_string(),
+            });
+        }
+
+        let total_amount = row.int("totalAmount", errors).unwrap_or(computed_total);
+        let discount_amount = row.int("discountAmount", errors).unwrap_or(0);
+        let final_amount = row
+            .int("finalAmount", errors)
+            .unwrap_or(total_amount - discount_amount);
+        let paid_amount = row.int("paidAmount", errors).unwrap_or(0);
+        if final_amount < 0 || paid_amount < 0 {
+            errors.push(ImportRowError {
+                row: row.number,
+                field: Some("finalAmount".to_string()),
+                message: "金額不可為負數".to_string(),
+            });
+        }
+
+        if errors.len() > before {
+            continue;
+        }
+
+        records.push(BuiltRecord {
+            rows: vec![row.number],
+            data: json!({
+                "registrationId": registration_id,
+                "activityId": activity_id,
+                "state": row.text("state").unwrap_or_else(|| "confirmed".to_string()),
+                "items": items,
+                "contact": contact,
+                "totalAmount": total_amount,
+                "discountAmount": discount_amount,
+                "finalAmount": final_amount,
+                "paidAmount": paid_amount,
+                "paymentState": row.text("paymentState"),
+                "paymentMethod": row.text("paymentMethod"),
+                "paymentDate": row.text("paymentDate"),
+                "receiptNumber": row.text("receiptNumber"),
+                "notes": row.text("notes"),
+            }),
+        });
+    }
+    records
+}
+
+async fn load_context(
+    pool: &SqlitePool,
+    collection: Collection,
+) -> Result<ImportContext, sqlx::Error> {
+    let mut context = ImportContext::default();
+    if collection == Collection::Registrations {
+        return Ok(context);
+    }
+
+    let query = format!("SELECT id FROM registrationDB WHERE {}", NOT_DELETED);
+    let ids: Vec<(i64,)> = sqlx::query_as(&query).fetch_all(pool).await?;
+    context.registration_ids = ids.into_iter().map(|(id,)| id).collect();
+
+    match collection {
+        Collection::MonthlyDonates => {
+            let ids: Vec<(String,)> =
+                sqlx::query_as("SELECT donateId FROM monthlyDonateDB WHERE donateId IS NOT NULL")
+                    .fetch_all(pool)
+                    .await?;
+            context.donate_ids = ids.into_iter().map(|(id,)| id).collect();
+        }
+        Collection::JoinRecords => {
+            let query = format!("SELECT id FROM activityDB WHERE {}", NOT_DELETED);
+            let ids: Vec<(i64,)> = sqlx::query_as(&query).fetch_all(pool).await?;
+            context.activity_ids = ids.into_iter().map(|(id,)| id).collect();
+            let mut conn = pool.acquire().await?;
+            if let Some(prices) = load_effective_prices(&mut conn).await? {
+                context.prices = prices;
+                context.has_price_config = true;
+            }
+        }
+        Collection::Registrations => {}
+    }
+    Ok(context)
+}
+
+async fn insert_record(
+    conn: &mut SqliteConnection,
+    collection: Collection,
+    data: &JsonValue,
+    user_created: Option<&str>,
+) -> Result<i64, sqlx::Error> {
+    let now = chrono::Utc::now();
+    let text = |key: &str| {
+        data.get(key)
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+    };
+    let number = |key: &str| data.get(key).and_then(|v| v.as_i64());
+
+    match collection {
+        Collection::Registrations => {
+            let result = sqlx::query(
+                r#"
+                INSERT INTO registrationDB (
+                    user_created, date_created, state, formId, formName, formSource,
+                    salvation, contact, blessing, createdAt, updatedAt
+                )
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                "#,
+            )
+            .bind(user_created)
+            .bind(now.timestamp_millis())
+            .bind(text("state"))
+            .bind(text("formId"))
+            .bind(text("formName"))
+            .bind(text("formSource"))
+            .bind(data["salvation"].to_string())
+            .bind(data["contact"].to_string())
+            .bind(data["blessing"].to_string())
+            .bind(text("createdAt"))
+            .bind(now.to_rfc3339())
+            .execute(&mut *conn)
+            .await?;
+            Ok(result.last_insert_rowid())
+        }
+        Collection::MonthlyDonates => {
+            let result = sqlx::query(
+                r#"
+                INSERT INTO monthlyDonateDB (
+                    user_created, date_created, name, registrationId, donateId, donateType,
+                    donateItems, memo, createdAt, updatedAt, householdId
+                )
+                VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, COALESCE((SELECT householdId FROM registrationDB WHERE id = ?), -1))
+                "#,
+            )
+            .bind(user_created)
+            .bind(now.timestamp_millis())
+            .bind(text("name"))
+            .bind(number("registrationId"))
+            .bind(text("donateId"))
+            .bind(text("donateType"))
+            .bind(data["donateItems"].to_string())
+            .bind(text("memo"))
+            .bind(now.to_rfc3339())
+            .bind(now.to_rfc3339())
+            .bind(number("registrationId"))
+            .execute(&mut *conn)
+            .await?;
+            Ok(result.last_insert_rowid())
+        }
+        Collection::JoinRecords => {
+            let request = CreateJoinRecordRequest {
+                registration_id: number("registrationId"),
+                activity_id: number("activityId"),
+                state: text("state"),
+                items: data.get("items").cloned(),
+                contact: data.get("contact").cloned(),
+                total_amount: number("totalAmount"),
+                discount_amount: number("discountAmount"),
+                final_amount: number("finalAmount"),
+                paid_amount: number("paidAmount"),
+                payment_state: text("paymentState"),
+                payment_method: text("paymentMethod"),
+                payment_date: text("paymentDate"),
+                receipt_number: text("receiptNumber"),
+                notes: text("notes"),
+                ..Default::default()
+            };
+            let id = insert_join_record(&mut *conn, &request).await?;
+            if let Some(user) = user_created {
+                sqlx::query(
+                    "UPDATE joinRecordDB SET user_created = ?, date_created = ? WHERE id = ?",
+                )
+                .bind(user)
+                .bind(now.timestamp_millis())
+                .bind(id)
+                .execute(&mut *conn)
+                .await?;
+            }
+            Ok(id)
+        }
+    }
+}
+
+/// 讀取上傳內容為表格（第一列為標題），每列附上試算表中的原始列號
+fn read_table(payload: &ImportRequest) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let format = payload.format.as_deref().unwrap_or("csv");
+    if !matches!(format, "csv" | "xlsx") {
+        return Err(format!("不支援的格式: {}（僅接受 csv、xlsx）", format));
+    }
+
+    let bytes: Vec<u8> = match (&payload.content, &payload.content_base64) {
+        (_, Some(encoded)) => base64::engine::general_purpose::STANDARD
+            .decode(encoded.trim())
+            .map_err(|e| format!("contentBase64 解碼失敗: {}", e))?,
+        (Some(content), None) => content.clone().into_bytes(),
+        (None, None) => return Err("請提供 content 或 contentBase64".to_string()),
+    };
+
+    if format == "xlsx" {
+        xlsx::read_first_sheet(&bytes)
+    } else {
+        let text = String::from_utf8(bytes).map_err(|_| "CSV 必須為 UTF-8 編碼".to_string())?;
+        Ok(csv::parse(&text))
+    }
+}
+
+/// 列出可匯入的目標欄位
+pub async fn get_import_fields(
+    Path(collection): Path<String>,
+) -> Result<Json<ApiResponse<Vec<ImportField>>>, (StatusCode, Json<ApiResponse<Vec<ImportField>>>)>
+{
+    let collection = Collection::parse(&collection).ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "不支援匯入: {}（僅接受 registrations、monthly-donates、join-records）",
+                collection
+            ))),
+        )
+    })?;
+    Ok(Json(ApiResponse::success(collection.fields())))
+}
+
+/// 匯入 CSV / XLSX：dryRun（預設）只驗證，dryRun=false 時在單一事務中寫入
+pub async fn import_collection(
+    Path(collection_name): Path<String>,
+    Extension(pool): Extension<SqlitePool>,
+    Json(payload): Json<ImportRequest>,
+) -> Result<Json<ApiResponse<ImportResult>>, (StatusCode, Json<ApiResponse<ImportResult>>)> {
+    let bad_request =
+        |message: String| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(message)));
+    let internal_error = |message: String| {
+        tracing::error!("{}", message);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(message)),
+        )
+    };
+
+    let collection = Collection::parse(&collection_name).ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "不支援匯入: {}（僅接受 registrations、monthly-donates、join-records）",
+                collection_name
+            ))),
+        )
+    })?;
+
+    let table = read_table(&payload).map_err(bad_request)?;
+    let Some(((_, headers), data_rows)) = table.split_first() else {
+        return Err(bad_request("檔案沒有任何資料".to_string()));
+    };
+    if data_rows.len() > MAX_IMPORT_ROWS {
+        return Err(bad_request(format!(
+            "資料列數 {} 超過上限 {}，請分批匯入",
+            data_rows.len(),
+            MAX_IMPORT_ROWS
+        )));
+    }
+
+    // 欄位對應：明確指定優先，其次為同名標題
+    let fields = collection.fields();
+    let known: HashSet<&str> = fields.iter().map(|f| f.field.as_str()).collect();
+    let header_index: HashMap<&str, usize> = headers
+        .iter()
+        .enumerate()
+        .map(|(i, h)| (h.trim(), i))
+        .collect();
+
+    let mut mapping: HashMap<String, String> = HashMap::new();
+    let mut columns: HashMap<String, usize> = HashMap::new();
+    let mut mapping_errors: Vec<String> = Vec::new();
+    for (target, source) in &payload.mapping {
+        if !known.contains(target.as_str()) {
+            mapping_errors.push(format!("未知的目標欄位: {}", target));
+        } else if let Some(&index) = header_index.get(source.trim()) {
+            columns.insert(target.clone(), index);
+            mapping.insert(target.clone(), source.clone());
+        } else {
+            mapping_errors.push(format!("檔案中找不到欄位: {}（對應 {}）", source, target));
+        }
+    }
+    for field in &fields {
+        if !columns.contains_key(&field.field) {
+            if let Some(&index) = header_index.get(field.field.as_str()) {
+                columns.insert(field.field.clone(), index);
+                mapping.insert(field.field.clone(), field.field.clone());
+            }
+        }
+    }
+    let missing: Vec<&str> = fields
+        .iter()
+        .filter(|f| f.required && !columns.contains_key(&f.field))
+        .map(|f| f.field.as_str())
+        .collect();
+    if !missing.is_empty() {
+        mapping_errors.push(format!("必填欄位未對應: {}", missing.join("、")));
+    }
+    if !mapping_errors.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error_with_details(
+                "欄位對應不正確".to_string(),
+                mapping_errors,
+            )),
+        ));
+    }
+
+    let user_created = payload.user_created.as_deref().filter(|u| !u.is_empty());
+    if let Some(user) = user_created {
+        let (exists,): (i64,) = sqlx::query_as("SELECT COUNT(*) FROM directus_users WHERE id = ?")
+            .bind(user)
+            .fetch_one(&pool)
+            .await
+            .map_err(|e| internal_error(format!("查詢使用者失敗: {}", e)))?;
+        if exists == 0 {
+            return Err(bad_request(format!("找不到使用者: {}", user)));
+        }
+    } else if collection == Collection::Registrations && !payload.dry_run {
+        return Err(bad_request("匯入報名記錄需要 userId（建立者）".to_string()));
+    }
+
+    let context = load_context(&pool, collection)
+        .await
+        .map_err(|e| internal_error(format!("查詢既有資料失敗: {}", e)))?;
+
+    let rows: Vec<ImportRow> = data_rows
+        .iter()
+        .map(|(number, cells)| ImportRow {
+            number: *number,
+            cells,
+            columns: &columns,
+        })
+        .collect();
+
+    let mut errors: Vec<ImportRowError> = Vec::new();
+    let records = match collection {
+        Collection::Registrations => {
+            build_registrations(&rows, &RegistrationLimits::from_env(), &mut errors)
+        }
+        Collection::MonthlyDonates => build_monthly_donates(
+            &rows,
+            &context,
+            user_created.unwrap_or_default(),
+            &mut errors,
+        ),
+        Collection::JoinRecords => build_join_records(&rows, &context, &mut errors),
+    };
+    errors.sort_by_key(|e| e.row);
+
+    let error_rows: BTreeSet<usize> = errors.iter().map(|e| e.row).collect();
+    let mut result = ImportResult {
+        collection: collection.name().to_string(),
+        dry_run: payload.dry_run,
+        total_rows: rows.len(),
+        valid_rows: rows.len() - error_rows.len(),
+        record_count: records.len(),
+        mapping,
+        errors,
+        preview: records
+            .iter()
+            .take(PREVIEW_RECORDS)
+            .map(|r| r.data.clone())
+            .collect(),
+        created_ids: Vec::new(),
+    };
+
+    if payload.dry_run {
+        let message = format!(
+            "驗證完成：{} / {} 列可匯入",
+            result.valid_rows, result.total_rows
+        );
+        return Ok(Json(ApiResponse::success_with_message(result, message)));
+    }
+
+    if !result.errors.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse {
+                success: false,
+                data: Some(result),
+                message: Some("資料有誤，未匯入任何記錄".to_string()),
+                meta: None,
+                errors: None,
+            }),
+        ));
+    }
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| internal_error(format!("啟動事務失敗: {}", e)))?;
+
+    for record in &records {
+        let id = insert_record(&mut tx, collection, &record.data, user_created)
+            .await
+            .map_err(|e| {
+                internal_error(format!(
+                    "匯入第 {} 列失敗: {}",
+                    record
+                        .rows
+                        .iter()
+                        .map(|r| r.to_string())
+                        .collect::<Vec<_>>()
+                        .join("、"),
+                    e
+                ))
+            })?;
+        result.created_ids.push(id);
+    }
+
+    tx.commit()
+        .await
+        .map_err(|e| internal_error(format!("提交事務失敗: {}", e)))?;
+
+    let message = format!("成功匯入 {} 筆記錄", result.created_ids.len());
+    Ok(Json(ApiResponse::success_with_message(result, message)))
+}
@@ -2,19 +2,33 @@
 use axum::{
     extract::{Extension, Path, Query},
     http::StatusCode,
+    response::Response,
     Json,
 };
-use sqlx::SqlitePool;
+use sqlx::{Connection, SqliteConnection, SqlitePool};
 
 // 導入共享的 API 響應結構
 use crate::models::api_response::{ApiResponse, Meta};
+use crate::models::json_column::JsonColumn;
 
 use crate::models::join_record::{
     CreateJoinRecordRequest, JoinRecord, JoinRecordResponse, 
     JoinRecordQuery, UpdateJoinRecordRequest,
+    JoinRecordBatchItemResult, JoinRecordBatchOperation, JoinRecordBatchRequest, JoinRecordBatchResult,
+    CreateJoinRecordFromRegistrationRequest, JoinRecordExportQuery,
 };
+use crate::handlers::activity::{activity_capacity_error, is_cancelled_state};
+use crate::handlers::join_record_payment::sync_payment_summary;
+use crate::handlers::soft_delete;
+use crate::models::soft_delete::DeleteQuery;
+use crate::handlers::join_record_item::{
+    build_items, load_effective_prices, load_price_config, person_names, ITEM_TYPES,
+};
+use crate::utils::export::{export_response, order_by_clause, spawn_export_rows, ExportCell, ExportFormat, ExportRow};
+use serde_json::Value as JsonValue;
 
-const JOIN_RECORD_FULL_QUERY: &str = r#"
+/// 參與記錄完整欄位查詢（不含已刪除的記錄）
+pub(crate) const JOIN_RECORD_FULL_QUERY: &str = r#"
 SELECT 
     id,
     user_created,
@@ -54,17 +68,269 @@ SELECT
     notes,
     createdAt,
     updatedAt,
-    receiptId
-FROM joinRecordDB
+    receiptId,
+    reconciliationBatchId,
+    householdId
+FROM (SELECT * FROM joinRecordDB WHERE date_deleted IS NULL) AS joinRecordDB
 "#;
 
+/// 插入一筆參與記錄，返回新 ID（單筆創建與批次操作共用）
+pub(crate) async fn insert_join_record(
+    conn: &mut SqliteConnection,
+    payload: &CreateJoinRecordRequest,
+) -> Result<i64, sqlx::Error> {
+    // 生成當前時間戳
+    let now = chrono::Utc::now().to_rfc3339();
+
+    // 將 JsonValue 轉換為字符串存入資料庫
+    let items_str = payload.items.as_ref().map(|v| v.to_string());
+    let contact_str = payload.contact.as_ref().map(|v| v.to_string());
+
+    let result = sqlx::query(
+        r#"
+        INSERT INTO joinRecordDB (
+            registrationId, activityId, state, items, contact, totalAmount, discountAmount,
+            finalAmount, paidAmount, needReceipt, receiptNumber, receiptIssued,
+            receiptIssuedAt, receiptIssuedBy, accountingState, accountingDate,
+            accountingBy, accountingNotes, paymentState, paymentMethod,
+            paymentDate, paymentNotes, notes, createdAt, updatedAt, receiptId, householdId
+        )
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?,
+            COALESCE((SELECT householdId FROM registrationDB WHERE id = ?), -1))
+        "#,
+    )
+    .bind(&payload.registration_id)
+    .bind(&payload.activity_id)
+    .bind(&payload.state)
+    .bind(&items_str)
+    .bind(&contact_str)
+    .bind(&payload.total_amount)
+    .bind(&payload.discount_amount)
+    .bind(&payload.final_amount)
+    .bind(&payload.paid_amount)
+    .bind(&payload.need_receipt)
+    .bind(&payload.receipt_number)
+    .bind(&payload.receipt_issued)
+    .bind(&payload.receipt_issued_at)
+    .bind(&payload.receipt_issued_by)
+    .bind(&payload.accounting_state)
+    .bind(&payload.accounting_date)
+    .bind(&payload.accounting_by)
+    .bind(&payload.accounting_notes)
+    .bind(&payload.payment_state)
+    .bind(&payload.payment_method)
+    .bind(&payload.payment_date)
+    .bind(&payload.payment_notes)
+    .bind(&payload.notes)
+    .bind(&now)
+    .bind(&now)
+    .bind(&payload.receipt_id)
+    .bind(payload.registration_id) // 沿用報名記錄的戶ID
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(result.last_insert_rowid())
+}
+
+/// 新增參與記錄前檢查活動名額；已取消的記錄不佔名額
+async fn create_capacity_error(
+    conn: &mut SqliteConnection,
+    payload: &CreateJoinRecordRequest,
+) -> Result<Option<String>, sqlx::Error> {
+    match payload.activity_id.filter(|id| *id > 0) {
+        Some(activity_id) if !is_cancelled_state(payload.state.as_deref()) => {
+            activity_capacity_error(conn, activity_id, None).await
+        }
+        _ => Ok(None),
+    }
+}
+
+/// 更新參與記錄前檢查名額：只有改到其他活動，或由已取消恢復時才需要佔用新名額
+async fn update_capacity_error(
+    conn: &mut SqliteConnection,
+    id: i64,
+    payload: &UpdateJoinRecordRequest,
+) -> Result<Option<String>, sqlx::Error> {
+    if payload.activity_id.is_none() && payload.state.is_none() {
+        return Ok(None);
+    }
+
+    let current: Option<(Option<i64>, Option<String>)> =
+        sqlx::query_as("SELECT activityId, state FROM joinRecordDB WHERE id = ?")
+            .bind(id)
+            .fetch_optional(&mut *conn)
+            .await?;
+    let Some((current_activity, current_state)) = current else {
+        return Ok(None);
+    };
+
+    let state = payload.state.as_deref().or(current_state.as_deref());
+    let Some(activity_id) = payload.activity_id.or(current_activity).filter(|id| *id > 0) else {
+        return Ok(None);
+    };
+    if is_cancelled_state(state)
+        || (Some(activity_id) == current_activity
+            && !is_cancelled_state(current_state.as_deref()))
+    {
+        return Ok(None);
+    }
+
+    activity_capacity_error(conn, activity_id, Some(id)).await
+}
+
+/// 依請求動態更新參與記錄，並以付款流水同步 paidAmount / paymentState
+/// 沒有提供任何字段時返回 Ok(false)（單筆更新與批次操作共用）
+async fn apply_join_record_update(
+    conn: &mut SqliteConnection,
+    id: i64,
+    payload: &UpdateJoinRecordRequest,
+) -> Result<bool, sqlx::Error> {
+    // 構建動態更新語句
+    let mut updates = Vec::new();
+    let mut bindings: Vec<String> = Vec::new();
+
+    if let Some(registration_id) = &payload.registration_id {
+        updates.push("registrationId = ?");
+        bindings.push(registration_id.to_string());
+    }
+    if let Some(activity_id) = &payload.activity_id {
+        updates.push("activityId = ?");
+        bindings.push(activity_id.to_string());
+    }
+    if let Some(state) = &payload.state {
+        updates.push("state = ?");
+        bindings.push(state.clone());
+    }
+    
+    // 將 JsonValue 轉換為字符串
+    if let Some(items) = &payload.items {
+        updates.push("items = ?");
+        bindings.push(items.to_string());
+    }
+    
+    if let Some(contact) = &payload.contact {
+        updates.push("contact = ?");
+        bindings.push(contact.to_string());
+    }
+
+    if let Some(total_amount) = &payload.total_amount {
+        updates.push("totalAmount = ?");
+        bindings.push(total_amount.to_string());
+    }
+    if let Some(discount_amount) = &payload.discount_amount {
+        updates.push("discountAmount = ?");
+        bindings.push(discount_amount.to_string());
+    }
+    if let Some(final_amount) = &payload.final_amount {
+        updates.push("finalAmount = ?");
+        bindings.push(final_amount.to_string());
+    }
+    if let Some(paid_amount) = &payload.paid_amount {
+        updates.push("paidAmount = ?");
+        bindings.push(paid_amount.to_string());
+    }
+    if let Some(need_receipt) = &payload.need_receipt {
+        updates.push("needReceipt = ?");
+        bindings.push(need_receipt.clone());
+    }
+    if let Some(receipt_number) = &payload.receipt_number {
+        updates.push("receiptNumber = ?");
+        bindings.push(receipt_number.clone());
+    }
+    if let Some(receipt_issued) = &payload.receipt_issued {
+        updates.push("receiptIssued = ?");
+        bindings.push(receipt_issued.clone());
+    }
+    if let Some(receipt_issued_at) = &payload.receipt_issued_at {
+        updates.push("receiptIssuedAt = ?");
+        bindings.push(receipt_issued_at.clone());
+    }
+    if let Some(receipt_issued_by) = &payload.receipt_issued_by {
+        updates.push("receiptIssuedBy = ?");
+        bindings.push(receipt_issued_by.clone());
+    }
+    if let Some(accounting_state) = &payload.accounting_state {
+        updates.push("accountingState = ?");
+        bindings.push(accounting_state.clone());
+    }
+    if let Some(accounting_date) = &payload.accounting_date {
+        updates.push("accountingDate = ?");
+        bindings.push(accounting_date.clone());
+    }
+    if let Some(accounting_by) = &payload.accounting_by {
+        updates.push("accountingBy = ?");
+        bindings.push(accounting_by.clone());
+    }
+    if let Some(accounting_notes) = &payload.accounting_notes {
+        updates.push("accountingNotes = ?");
+        bindings.push(accounting_notes.clone());
+    }
+    if let Some(payment_state) = &payload.payment_state {
+        updates.push("paymentState = ?");
+        bindings.push(payment_state.clone());
+    }
+    if let Some(payment_method) = &payload.payment_method {
+        updates.push("paymentMethod = ?");
+        bindings.push(payment_method.clone());
+    }
+    if let Some(payment_date) = &payload.payment_date {
+        updates.push("paymentDate = ?");
+        bindings.push(payment_date.clone());
+    }
+    if let Some(payment_notes) = &payload.payment_notes {
+        updates.push("paymentNotes = ?");
+        bindings.push(payment_notes.clone());
+    }
+    if let Some(notes) = &payload.notes {
+        updates.push("notes = ?");
+        bindings.push(notes.clone());
+    }
+
+    // 在更新語句中添加 user_updated
+    if let Some(user_updated) = &payload.user_updated {
+        updates.push("user_updated = ?");
+        bindings.push(user_updated.clone());
+    }    
+
+    if updates.is_empty() {
+        return Ok(false);
+    }
+
+    // 添加 updatedAt
+    let now = chrono::Utc::now().to_rfc3339();
+    updates.push("updatedAt = ?");
+    bindings.push(now);
+
+    // 添加 receiptId
+    if let Some(receipt_id) = &payload.receipt_id {
+        updates.push("receiptId = ?");
+        bindings.push(receipt_id.to_string());
+    }
+
+    let query = format!(
+        "UPDATE joinRecordDB SET {} WHERE id = ?",
+        updates.join(", ")
+    );
+
+    let mut query_builder = sqlx::query(&query);
+    for binding in bindings {
+        query_builder = query_builder.bind(binding);
+    }
+    query_builder.bind(id).execute(&mut *conn).await?;
+
+    // 有付款流水的記錄，paidAmount / paymentState 以流水為準（例如 finalAmount 變更後重算）
+    sync_payment_summary(conn, id).await?;
+
+    Ok(true)
+}
+
 /// 獲取所有參與記錄
 pub async fn get_all_join_records(
     Query(params): Query<JoinRecordQuery>,
     Extension(pool): Extension<SqlitePool>,
 ) -> Result<Json<ApiResponse<Vec<JoinRecordResponse>>>, (StatusCode, Json<ApiResponse<Vec<JoinRecordResponse>>>)> {
     let mut query = format!("{} WHERE 1=1", JOIN_RECORD_FULL_QUERY);
-    let mut count_query = String::from("SELECT COUNT(*) FROM joinRecordDB WHERE 1=1");
+    let mut count_query = String::from("SELECT COUNT(*) FROM joinRecordDB WHERE date_deleted IS NULL");
 
     // 添加過濾條件
     if let Some(registration_id) = &params.registration_id {
@@ -246,55 +512,28 @@ pub async fn create_join_record(
     Extension(pool): Extension<SqlitePool>,
     Json(payload): Json<CreateJoinRecordRequest>,
 ) -> Result<Json<ApiResponse<JoinRecordResponse>>, (StatusCode, Json<ApiResponse<JoinRecordResponse>>)> {
-    // 生成當前時間戳
-    let now = chrono::Utc::now().to_rfc3339();
+    // 名額檢查與寫入在同一事務內，避免同時報名時超收
+    let mut tx = pool.begin().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("啟動事務失敗: {}", e))),
+        )
+    })?;
 
-    // 將 JsonValue 轉換為字符串存入資料庫
-    let items_str = payload.items.map(|v| v.to_string());
-    let contact_str = payload.contact.map(|v| v.to_string());
+    // 活動額滿時拒絕報名
+    let full = create_capacity_error(&mut tx, &payload).await.map_err(|e| {
+        tracing::error!("檢查活動名額失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("檢查名額失敗: {}", e))),
+        )
+    })?;
+    if let Some(message) = full {
+        return Err((StatusCode::CONFLICT, Json(ApiResponse::error(message))));
+    }
 
     // 插入新記錄
-    let result = sqlx::query(
-        r#"
-        INSERT INTO joinRecordDB (
-            registrationId, activityId, state, items, contact, totalAmount, discountAmount,
-            finalAmount, paidAmount, needReceipt, receiptNumber, receiptIssued,
-            receiptIssuedAt, receiptIssuedBy, accountingState, accountingDate,
-            accountingBy, accountingNotes, paymentState, paymentMethod,
-            paymentDate, paymentNotes, notes, createdAt, updatedAt, receiptId
-        )
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-        "#,
-    )
-    .bind(&payload.registration_id)
-    .bind(&payload.activity_id)
-    .bind(&payload.state)
-    .bind(&items_str)
-    .bind(&contact_str)
-    .bind(&payload.total_amount)
-    .bind(&payload.discount_amount)
-    .bind(&payload.final_amount)
-    .bind(&payload.paid_amount)
-    .bind(&payload.need_receipt)
-    .bind(&payload.receipt_number)
-    .bind(&payload.receipt_issued)
-    .bind(&payload.receipt_issued_at)
-    .bind(&payload.receipt_issued_by)
-    .bind(&payload.accounting_state)
-    .bind(&payload.accounting_date)
-    .bind(&payload.accounting_by)
-    .bind(&payload.accounting_notes)
-    .bind(&payload.payment_state)
-    .bind(&payload.payment_method)
-    .bind(&payload.payment_date)
-    .bind(&payload.payment_notes)
-    .bind(&payload.notes)
-    .bind(&now)
-    .bind(&now)
-    .bind(&payload.receipt_id)
-    .execute(&pool)
-    .await
-    .map_err(|e| {
+    let id = insert_join_record(&mut tx, &payload).await.map_err(|e| {
         tracing::error!("創建參與記錄失敗: {}", e);
         (
             StatusCode::INTERNAL_SERVER_ERROR,
@@ -302,7 +541,12 @@ pub async fn create_join_record(
         )
     })?;
 
-    let id = result.last_insert_rowid();
+    tx.commit().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("提交事務失敗: {}", e))),
+        )
+    })?;
 
     // 返回創建的記錄
     let query = format!("{} WHERE id = ?", JOIN_RECORD_FULL_QUERY);
@@ -331,7 +575,7 @@ pub async fn update_join_record(
     Json(payload): Json<UpdateJoinRecordRequest>,
 ) -> Result<Json<ApiResponse<JoinRecordResponse>>, (StatusCode, Json<ApiResponse<JoinRecordResponse>>)> {
     // 檢查記錄是否存在
-    let exists: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM joinRecordDB WHERE id = ?")
+    let exists: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM joinRecordDB WHERE id = ? AND date_deleted IS NULL")
         .bind(id)
         .fetch_one(&pool)
         .await
@@ -350,147 +594,43 @@ pub async fn update_join_record(
         ));
     }
 
-    // 構建動態更新語句
-    let mut updates = Vec::new();
-    let mut bindings: Vec<String> = Vec::new();
+    let mut tx = pool.begin().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("啟動事務失敗: {}", e))),
+        )
+    })?;
 
-    if let Some(registration_id) = &payload.registration_id {
-        updates.push("registrationId = ?");
-        bindings.push(registration_id.to_string());
-    }
-    if let Some(activity_id) = &payload.activity_id {
-        updates.push("activityId = ?");
-        bindings.push(activity_id.to_string());
-    }
-    if let Some(state) = &payload.state {
-        updates.push("state = ?");
-        bindings.push(state.clone());
-    }
-    
-    // 將 JsonValue 轉換為字符串
-    if let Some(items) = &payload.items {
-        updates.push("items = ?");
-        bindings.push(items.to_string());
-    }
-    
-    if let Some(contact) = &payload.contact {
-        updates.push("contact = ?");
-        bindings.push(contact.to_string());
-    }
-
-    if let Some(total_amount) = &payload.total_amount {
-        updates.push("totalAmount = ?");
-        bindings.push(total_amount.to_string());
-    }
-    if let Some(discount_amount) = &payload.discount_amount {
-        updates.push("discountAmount = ?");
-        bindings.push(discount_amount.to_string());
-    }
-    if let Some(final_amount) = &payload.final_amount {
-        updates.push("finalAmount = ?");
-        bindings.push(final_amount.to_string());
-    }
-    if let Some(paid_amount) = &payload.paid_amount {
-        updates.push("paidAmount = ?");
-        bindings.push(paid_amount.to_string());
-    }
-    if let Some(need_receipt) = &payload.need_receipt {
-        updates.push("needReceipt = ?");
-        bindings.push(need_receipt.clone());
-    }
-    if let Some(receipt_number) = &payload.receipt_number {
-        updates.push("receiptNumber = ?");
-        bindings.push(receipt_number.clone());
-    }
-    if let Some(receipt_issued) = &payload.receipt_issued {
-        updates.push("receiptIssued = ?");
-        bindings.push(receipt_issued.clone());
-    }
-    if let Some(receipt_issued_at) = &payload.receipt_issued_at {
-        updates.push("receiptIssuedAt = ?");
-        bindings.push(receipt_issued_at.clone());
-    }
-    if let Some(receipt_issued_by) = &payload.receipt_issued_by {
-        updates.push("receiptIssuedBy = ?");
-        bindings.push(receipt_issued_by.clone());
-    }
-    if let Some(accounting_state) = &payload.accounting_state {
-        updates.push("accountingState = ?");
-        bindings.push(accounting_state.clone());
-    }
-    if let Some(accounting_date) = &payload.accounting_date {
-        updates.push("accountingDate = ?");
-        bindings.push(accounting_date.clone());
-    }
-    if let Some(accounting_by) = &payload.accounting_by {
-        updates.push("accountingBy = ?");
-        bindings.push(accounting_by.clone());
-    }
-    if let Some(accounting_notes) = &payload.accounting_notes {
-        updates.push("accountingNotes = ?");
-        bindings.push(accounting_notes.clone());
-    }
-    if let Some(payment_state) = &payload.payment_state {
-        updates.push("paymentState = ?");
-        bindings.push(payment_state.clone());
-    }
-    if let Some(payment_method) = &payload.payment_method {
-        updates.push("paymentMethod = ?");
-        bindings.push(payment_method.clone());
-    }
-    if let Some(payment_date) = &payload.payment_date {
-        updates.push("paymentDate = ?");
-        bindings.push(payment_date.clone());
-    }
-    if let Some(payment_notes) = &payload.payment_notes {
-        updates.push("paymentNotes = ?");
-        bindings.push(payment_notes.clone());
-    }
-    if let Some(notes) = &payload.notes {
-        updates.push("notes = ?");
-        bindings.push(notes.clone());
+    let full = update_capacity_error(&mut tx, id, &payload).await.map_err(|e| {
+        tracing::error!("檢查活動名額失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("檢查名額失敗: {}", e))),
+        )
+    })?;
+    if let Some(message) = full {
+        return Err((StatusCode::CONFLICT, Json(ApiResponse::error(message))));
     }
 
-    // 在更新語句中添加 user_updated
-    if let Some(user_updated) = &payload.user_updated {
-        updates.push("user_updated = ?");
-        bindings.push(user_updated.clone());
-    }    
+    let updated = apply_join_record_update(&mut tx, id, &payload).await.map_err(|e| {
+        tracing::error!("更新參與記錄失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("更新失敗: {}", e))),
+        )
+    })?;
 
-    if updates.is_empty() {
+    if !updated {
         return Err((
             StatusCode::BAD_REQUEST,
             Json(ApiResponse::error("沒有提供要更新的字段".to_string())),
         ));
     }
 
-    // 添加 updatedAt
-    let now = chrono::Utc::now().to_rfc3339();
-    updates.push("updatedAt = ?");
-    bindings.push(now);
-
-    // 添加 receiptId
-    if let Some(receipt_id) = &payload.receipt_id {
-        updates.push("receiptId = ?");
-        bindings.push(receipt_id.to_string());
-    }
-
-    let query = format!(
-        "UPDATE joinRecordDB SET {} WHERE id = ?",
-        updates.join(", ")
-    );
-
-    let mut query_builder = sqlx::query(&query);
-    for binding in bindings {
-        query_builder = query_builder.bind(binding);
-    }
-    query_builder = query_builder.bind(id);
-
-    query_builder.execute(&pool).await.map_err(|e| {
-        tracing::error!("更新參與記錄失敗: {}", e);
+    tx.commit().await.map_err(|e| {
         (
             StatusCode::INTERNAL_SERVER_ERROR,
-            Json(ApiResponse::error(format!("更新失敗: {}", e))),
+            Json(ApiResponse::error(format!("提交事務失敗: {}", e))),
         )
     })?;
 
@@ -514,30 +654,63 @@ pub async fn update_join_record(
     )))
 }
 
-/// 刪除參與記錄
+/// 參與記錄是否已沖帳（記錄已標記沖帳，或有付款流水屬於沖帳批次）
+async fn is_reconciled(conn: &mut SqliteConnection, id: i64) -> Result<bool, sqlx::Error> {
+    let reconciled: (i64,) = sqlx::query_as(
+        r#"
+        SELECT EXISTS (SELECT 1 FROM joinRecordDB WHERE id = ? AND accountingState = 'reconciled')
+            OR EXISTS (
+                SELECT 1 FROM joinRecordPayments
+                WHERE joinRecordId = ? AND COALESCE(reconciliationBatchId, -1) != -1
+            )
+        "#,
+    )
+    .bind(id)
+    .bind(id)
+    .fetch_one(&mut *conn)
+    .await?;
+    Ok(reconciled.0 != 0)
+}
+
+/// 刪除參與記錄（軟刪除，可還原）；已沖帳的記錄需先作廢沖帳批次
 pub async fn delete_join_record(
     Path(id): Path<i64>,
+    Query(params): Query<DeleteQuery>,
     Extension(pool): Extension<SqlitePool>,
 ) -> Result<Json<ApiResponse<()>>, (StatusCode, Json<ApiResponse<()>>)> {
-    let result = sqlx::query("DELETE FROM joinRecordDB WHERE id = ?")
-        .bind(id)
-        .execute(&pool)
+    let internal_error = |e: sqlx::Error| {
+        tracing::error!("刪除參與記錄失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("刪除失敗: {}", e))),
+        )
+    };
+
+    let mut tx = pool.begin().await.map_err(internal_error)?;
+
+    if is_reconciled(&mut tx, id).await.map_err(internal_error)? {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ApiResponse::error(format!(
+                "參與記錄 {} 已沖帳，請先作廢沖帳批次再刪除",
+                id
+            ))),
+        ));
+    }
+
+    let deleted = soft_delete::soft_delete(&mut *tx, "joinRecordDB", id, params.user_deleted.as_deref())
         .await
-        .map_err(|e| {
-            tracing::error!("刪除參與記錄失敗: {}", e);
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(ApiResponse::error(format!("刪除失敗: {}", e))),
-            )
-        })?;
+        .map_err(internal_error)?;
 
-    if result.rows_affected() == 0 {
+    if !deleted {
         return Err((
             StatusCode::NOT_FOUND,
             Json(ApiResponse::error(format!("找不到 ID 為 {} 的參與記錄", id))),
         ));
     }
 
+    tx.commit().await.map_err(internal_error)?;
+
     Ok(Json(ApiResponse {
         success: true,
         data: None,
@@ -546,3 +719,597 @@ pub async fn delete_join_record(
         errors: None,
     }))
 }
+
+/// 還原已刪除的參與記錄；未取消的記錄會重新佔用活動名額，名額已滿時不可還原
+pub async fn restore_join_record(
+    Path(id): Path<i64>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<JoinRecordResponse>>, (StatusCode, Json<ApiResponse<JoinRecordResponse>>)> {
+    let internal_error = |e: sqlx::Error| {
+        tracing::error!("還原參與記錄失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("還原失敗: {}", e))),
+        )
+    };
+
+    let mut tx = pool.begin().await.map_err(internal_error)?;
+
+    let deleted: Option<(Option<i64>, Option<String>)> = sqlx::query_as(
+        "SELECT activityId, state FROM joinRecordDB WHERE id = ? AND date_deleted IS NOT NULL",
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(internal_error)?;
+    let Some((activity_id, state)) = deleted else {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!("找不到 ID 為 {} 的已刪除參與記錄", id))),
+        ));
+    };
+
+    if let Some(activity_id) = activity_id.filter(|id| *id > 0) {
+        if !is_cancelled_state(state.as_deref()) {
+            if let Some(message) = activity_capacity_error(&mut tx, activity_id, Some(id))
+                .await
+                .map_err(internal_error)?
+            {
+                return Err((StatusCode::CONFLICT, Json(ApiResponse::error(message))));
+            }
+        }
+    }
+
+    soft_delete::restore(&mut *tx, "joinRecordDB", id)
+        .await
+        .map_err(internal_error)?;
+    tx.commit().await.map_err(internal_error)?;
+
+    let Json(mut response) = get_join_record_by_id(Path(id), Extension(pool)).await?;
+    response.message = Some("成功還原參與記錄".to_string());
+    Ok(Json(response))
+}
+
+/// 批次操作單次上限
+const MAX_BATCH_OPERATIONS: usize = 500;
+
+/// 執行批次中的單一操作，返回 (記錄ID, 操作後的記錄)
+async fn run_join_record_batch_operation(
+    conn: &mut SqliteConnection,
+    operation: JoinRecordBatchOperation,
+) -> Result<(i64, Option<JoinRecordResponse>), String> {
+    let query = format!("{} WHERE id = ?", JOIN_RECORD_FULL_QUERY);
+
+    match operation {
+        JoinRecordBatchOperation::Create { data } => {
+            if let Some(message) = create_capacity_error(conn, &data)
+                .await
+                .map_err(|e| format!("檢查名額失敗: {}", e))?
+            {
+                return Err(message);
+            }
+            let id = insert_join_record(conn, &data)
+                .await
+                .map_err(|e| format!("創建失敗: {}", e))?;
+            let record = sqlx::query_as::<_, JoinRecord>(&query)
+                .bind(id)
+                .fetch_one(&mut *conn)
+                .await
+                .map_err(|e| format!("查詢失敗: {}", e))?;
+            Ok((id, Some(record.into())))
+        }
+        JoinRecordBatchOperation::Update { id, data } => {
+            let exists: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM joinRecordDB WHERE id = ? AND date_deleted IS NULL")
+                .bind(id)
+                .fetch_one(&mut *conn)
+                .await
+                .map_err(|e| format!("檢查失敗: {}", e))?;
+            if exists.0 == 0 {
+                return Err(format!("找不到 ID 為 {} 的參與記錄", id));
+            }
+            if let Some(message) = update_capacity_error(conn, id, &data)
+                .await
+                .map_err(|e| format!("檢查名額失敗: {}", e))?
+            {
+                return Err(message);
+            }
+
+            let updated = apply_join_record_update(conn, id, &data)
+                .await
+                .map_err(|e| format!("更新失敗: {}", e))?;
+            if !updated {
+                return Err("沒有提供要更新的字段".to_string());
+            }
+
+            let record = sqlx::query_as::<_, JoinRecord>(&query)
+                .bind(id)
+                .fetch_one(&mut *conn)
+                .await
+                .map_err(|e| format!("查詢失敗: {}", e))?;
+            Ok((id, Some(record.into())))
+        }
+        JoinRecordBatchOperation::Delete { id, user_deleted } => {
+            if is_reconciled(conn, id)
+                .await
+                .map_err(|e| format!("檢查沖帳狀態失敗: {}", e))?
+            {
+                return Err(format!("參與記錄 {} 已沖帳，請先作廢沖帳批次再刪除", id));
+            }
+            let deleted = soft_delete::soft_delete(&mut *conn, "joinRecordDB", id, user_deleted.as_deref())
+                .await
+                .map_err(|e| format!("刪除失敗: {}", e))?;
+            if !deleted {
+                return Err(format!("找不到 ID 為 {} 的參與記錄", id));
+            }
+            Ok((id, None))
+        }
+    }
+}
+
+/// 🔥 批次操作參與記錄：在同一個事務中執行多筆創建 / 更新 / 刪除
+/// 每筆操作使用 SAVEPOINT，atomic=true（預設）時任一失敗整批回滾，
+/// atomic=false 時只回滾失敗的那一筆
+pub async fn batch_join_records(
+    Extension(pool): Extension<SqlitePool>,
+    Json(payload): Json<JoinRecordBatchRequest>,
+) -> Result<Json<ApiResponse<JoinRecordBatchResult>>, (StatusCode, Json<ApiResponse<JoinRecordBatchResult>>)> {
+    if payload.operations.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error("沒有提供任何操作".to_string())),
+        ));
+    }
+
+    if payload.operations.len() > MAX_BATCH_OPERATIONS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!(
+                "單次批次操作最多 {} 筆，收到 {} 筆",
+                MAX_BATCH_OPERATIONS,
+                payload.operations.len()
+            ))),
+        ));
+    }
+
+    // 1. 開始資料庫事務
+    let mut tx = pool.begin().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("啟動事務失敗: {}", e))),
+        )
+    })?;
+
+    let atomic = payload.atomic;
+    let mut results: Vec<JoinRecordBatchItemResult> = Vec::with_capacity(payload.operations.len());
+
+    // 2. 逐筆執行，每筆一個 SAVEPOINT
+    for (index, operation) in payload.operations.into_iter().enumerate() {
+        let (action, target_id) = match &operation {
+            JoinRecordBatchOperation::Create { .. } => ("create", None),
+            JoinRecordBatchOperation::Update { id, .. } => ("update", Some(*id)),
+            JoinRecordBatchOperation::Delete { id, .. } => ("delete", Some(*id)),
+        };
+
+        let mut savepoint = tx.begin().await.map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("建立 SAVEPOINT 失敗: {}", e))),
+            )
+        })?;
+
+        match run_join_record_batch_operation(&mut savepoint, operation).await {
+            Ok((id, data)) => {
+                savepoint.commit().await.map_err(|e| {
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(ApiResponse::error(format!("釋放 SAVEPOINT 失敗: {}", e))),
+                    )
+                })?;
+                results.push(JoinRecordBatchItemResult {
+                    index,
+                    action: action.to_string(),
+                    id: Some(id),
+                    success: true,
+                    data,
+                    error: None,
+                });
+            }
+            Err(error) => {
+                tracing::warn!("批次操作第 {} 筆（{}）失敗: {}", index, action, error);
+                savepoint.rollback().await.map_err(|e| {
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(ApiResponse::error(format!("回滾 SAVEPOINT 失敗: {}", e))),
+                    )
+                })?;
+                results.push(JoinRecordBatchItemResult {
+                    index,
+                    action: action.to_string(),
+                    id: target_id,
+                    success: false,
+                    data: None,
+                    error: Some(error),
+                });
+            }
+        }
+    }
+
+    let succeeded = results.iter().filter(|r| r.success).count();
+    let failed = results.len() - succeeded;
+
+    // 3. atomic 模式下有任何失敗就整批回滾，仍返回逐筆結果方便前端標示
+    if atomic && failed > 0 {
+        tx.rollback().await.map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("回滾事務失敗: {}", e))),
+            )
+        })?;
+
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse {
+                success: false,
+                data: Some(JoinRecordBatchResult {
+                    atomic,
+                    committed: false,
+                    succeeded,
+                    failed,
+                    results,
+                }),
+                message: Some(format!("批次操作有 {} 筆失敗，已全部回滾", failed)),
+                meta: None,
+                errors: None,
+            }),
+        ));
+    }
+
+    // 4. 提交事務
+    tx.commit().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("提交事務失敗: {}", e))),
+        )
+    })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        JoinRecordBatchResult {
+            atomic,
+            committed: true,
+            succeeded,
+            failed,
+            results,
+        },
+        format!("批次操作完成：成功 {} 筆，失敗 {} 筆", succeeded, failed),
+    )))
+}
+
+/// 🔥 從祈福登記表產生參與記錄
+/// 依項目類型從 salvation / blessing 組裝 items（source / sourceData / sourceAddress），
+/// 複製聯絡人，並以生效中的價格配置計價
+pub async fn create_join_record_from_registration(
+    Extension(pool): Extension<SqlitePool>,
+    Json(payload): Json<CreateJoinRecordFromRegistrationRequest>,
+) -> Result<Json<ApiResponse<JoinRecordResponse>>, (StatusCode, Json<ApiResponse<JoinRecordResponse>>)> {
+    if payload.item_types.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error("請至少選擇一個項目類型".to_string())),
+        ));
+    }
+
+    // 1. 開始資料庫事務
+    let mut tx = pool.begin().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("啟動事務失敗: {}", e))),
+        )
+    })?;
+
+    // 2. 讀取祈福登記表
+    let registration: Option<(Option<String>, Option<String>, Option<String>)> =
+        sqlx::query_as("SELECT salvation, blessing, contact FROM registrationDB WHERE id = ? AND date_deleted IS NULL")
+            .bind(payload.registration_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| {
+                tracing::error!("查詢祈福登記失敗: {}", e);
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiResponse::error(format!("查詢祈福登記失敗: {}", e))),
+                )
+            })?;
+
+    let Some((salvation, blessing, contact)) = registration else {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "找不到 ID 為 {} 的祈福登記",
+                payload.registration_id
+            ))),
+        ));
+    };
+
+    let activity: Option<(JsonColumn<Vec<String>>, Option<i64>)> =
+        sqlx::query_as("SELECT allowedItems, priceConfigId FROM activityDB WHERE id = ? AND date_deleted IS NULL")
+            .bind(payload.activity_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ApiResponse::error(format!("查詢活動失敗: {}", e))),
+                )
+            })?;
+
+    let Some((allowed_items, price_config_id)) = activity else {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!("找不到 ID 為 {} 的活動", payload.activity_id))),
+        ));
+    };
+
+    // 活動有限定可報名項目時，其他項目不可報名
+    if let Some(allowed_items) = allowed_items
+        .into_reported("activityDB.allowedItems", payload.activity_id)
+        .filter(|items| !items.is_empty())
+    {
+        let rejected: Vec<&str> = payload
+            .item_types
+            .iter()
+            .filter(|t| !allowed_items.contains(t))
+            .map(String::as_str)
+            .collect();
+        if !rejected.is_empty() {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::error(format!(
+                    "活動 {} 不開放報名項目: {}（可報名：{}）",
+                    payload.activity_id,
+                    rejected.join("、"),
+                    allowed_items.join("、")
+                ))),
+            ));
+        }
+    }
+
+    // 3. 價格配置：活動有指定時使用指定的配置，否則為生效中的配置
+    let prices = match price_config_id.filter(|id| *id > 0) {
+        Some(id) => load_price_config(&mut tx, id).await,
+        None => load_effective_prices(&mut tx).await,
+    };
+    let prices = prices
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("查詢價格配置失敗: {}", e))),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::error("找不到生效中的價格配置".to_string())),
+            )
+        })?;
+
+    // 4. 組裝項目
+    let parse_json = |s: Option<String>| -> JsonValue {
+        s.and_then(|s| serde_json::from_str(&s).ok()).unwrap_or(JsonValue::Null)
+    };
+    let registration_data = serde_json::json!({
+        "salvation": parse_json(salvation),
+        "blessing": parse_json(blessing),
+    });
+
+    let (items, total_amount) = build_items(
+        &registration_data,
+        &payload.item_types,
+        &payload.selections,
+        &payload.person_lamp_types,
+        &prices,
+    )
+    .map_err(|msg| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(msg))))?;
+
+    if items.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error("所選項目在祈福登記表中沒有對應的資料".to_string())),
+        ));
+    }
+
+    let discount_amount = payload.discount_amount.unwrap_or(0);
+    if discount_amount < 0 || discount_amount > total_amount {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!(
+                "折扣金額 {} 無效（總金額 {}）",
+                discount_amount, total_amount
+            ))),
+        ));
+    }
+
+    // 5. 寫入參與記錄
+    let create = CreateJoinRecordRequest {
+        registration_id: Some(payload.registration_id),
+        activity_id: Some(payload.activity_id),
+        state: Some(payload.state.clone().unwrap_or_else(|| "confirmed".to_string())),
+        items: Some(JsonValue::Array(items)),
+        contact: Some(parse_json(contact)).filter(|c| !c.is_null()),
+        total_amount: Some(total_amount),
+        discount_amount: Some(discount_amount),
+        final_amount: Some(total_amount - discount_amount),
+        paid_amount: Some(0),
+        need_receipt: payload.need_receipt.clone(),
+        notes: payload.notes.clone(),
+        ..Default::default()
+    };
+
+    let full = create_capacity_error(&mut tx, &create).await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("檢查名額失敗: {}", e))),
+        )
+    })?;
+    if let Some(message) = full {
+        return Err((StatusCode::CONFLICT, Json(ApiResponse::error(message))));
+    }
+
+    let id = insert_join_record(&mut tx, &create).await.map_err(|e| {
+        tracing::error!("創建參與記錄失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("創建失敗: {}", e))),
+        )
+    })?;
+
+    sqlx::query("UPDATE joinRecordDB SET user_created = ?, date_created = ? WHERE id = ?")
+        .bind(&payload.user_id)
+        .bind(chrono::Utc::now().timestamp_millis())
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("創建失敗: {}", e))),
+            )
+        })?;
+
+    let record = sqlx::query_as::<_, JoinRecord>(&format!("{} WHERE id = ?", JOIN_RECORD_FULL_QUERY))
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("查詢新創建的參與記錄失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+            )
+        })?;
+
+    // 6. 提交事務
+    tx.commit().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("提交事務失敗: {}", e))),
+        )
+    })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        record.into(),
+        format!("成功從祈福登記 {} 產生參與記錄", payload.registration_id),
+    )))
+}
+
+/// 匯出欄位：基本資料 + 每種項目類型一欄小計 + 金額與付款資料
+fn join_record_export_headers() -> Vec<&'static str> {
+    let mut headers = vec!["記錄ID", "報名ID", "活動ID", "狀態", "聯絡人", "電話", "手機"];
+    headers.extend(ITEM_TYPES.iter().map(|(_, label, ..)| *label));
+    headers.extend([
+        "參加名單", "總金額", "折扣", "應收金額", "已收金額", "付款狀態", "付款方式",
+        "收據號碼", "會計狀態", "備註", "建立時間",
+    ]);
+    headers
+}
+
+fn join_record_export_rows(record: JoinRecord) -> Vec<ExportRow> {
+    let items = record.items.into_reported("joinRecordDB.items", record.id).unwrap_or_default();
+    let contact = record.contact.into_reported("joinRecordDB.contact", record.id).unwrap_or_default();
+
+    let mut row: ExportRow = vec![
+        record.id.into(),
+        record.registration_id.into(),
+        record.activity_id.into(),
+        record.state.clone().into(),
+        contact.name.into(),
+        contact.phone.into(),
+        contact.mobile.into(),
+    ];
+
+    // 各項目類型小計（同類型多筆時加總）
+    for (item_type, ..) in ITEM_TYPES {
+        let subtotals: Vec<i64> = items
+            .iter()
+            .filter(|item| item.item_type == *item_type)
+            .map(|item| item.subtotal)
+            .collect();
+        row.push(if subtotals.is_empty() {
+            ExportCell::from("")
+        } else {
+            subtotals.iter().sum::<i64>().into()
+        });
+    }
+
+    let roster = items
+        .iter()
+        .filter_map(|item| {
+            let names = person_names(&item.source_data);
+            (!names.is_empty()).then(|| format!("{}：{}", item.label, names))
+        })
+        .collect::<Vec<_>>()
+        .join("；");
+
+    row.extend([
+        roster.into(),
+        record.total_amount.into(),
+        record.discount_amount.into(),
+        record.final_amount.into(),
+        record.paid_amount.into(),
+        record.payment_state.clone().into(),
+        record.payment_method.clone().into(),
+        record.receipt_number.clone().into(),
+        record.accounting_state.clone().into(),
+        record.notes.clone().into(),
+        record.created_at.clone().into(),
+    ]);
+
+    vec![row]
+}
+
+/// 匯出參與記錄：format=csv|xlsx|json，篩選條件同列表
+pub async fn export_join_records(
+    Query(params): Query<JoinRecordExportQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Response, (StatusCode, Json<ApiResponse<()>>)> {
+    let format = ExportFormat::parse(params.format.as_deref())
+        .map_err(|msg| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(msg))))?;
+
+    let mut query = format!("{} WHERE 1=1", JOIN_RECORD_FULL_QUERY);
+    let mut bindings: Vec<String> = Vec::new();
+
+    if let Some(registration_id) = params.registration_id {
+        query.push_str(" AND registrationId = ?");
+        bindings.push(registration_id.to_string());
+    }
+    if let Some(activity_id) = params.activity_id {
+        query.push_str(" AND activityId = ?");
+        bindings.push(activity_id.to_string());
+    }
+    if let Some(state) = &params.state {
+        query.push_str(" AND state = ?");
+        bindings.push(state.clone());
+    }
+    if let Some(payment_state) = &params.payment_state {
+        query.push_str(" AND paymentState = ?");
+        bindings.push(payment_state.clone());
+    }
+    if let Some(accounting_state) = &params.accounting_state {
+        query.push_str(" AND accountingState = ?");
+        bindings.push(accounting_state.clone());
+    }
+    query.push_str(
+        &order_by_clause(params.sort.as_deref(), "createdAt DESC")
+            .map_err(|msg| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(msg))))?,
+    );
+
+    let rows = spawn_export_rows(pool, query, bindings, join_record_export_rows);
+    export_response(format, "join-records", join_record_export_headers(), rows)
+        .await
+        .map_err(|e| {
+            tracing::error!("匯出參與記錄失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("匯出失敗: {}", e))),
+            )
+        })
+}
@@ -0,0 +1,239 @@
+// src/handlers/join_record_item.rs
+//! 參加記錄項目（joinRecordDB.items）組裝規則
+//! 與前端 joinRecordStore.createParticipationItem 保持一致：
+//! 項目類型決定資料來源（祖先 / 陽上人 / 消災人員）與地址來源，價格取自生效中的價格配置
+
+use serde_json::{json, Map, Value as JsonValue};
+use sqlx::SqliteConnection;
+use std::collections::HashMap;
+
+use crate::models::json_column::JsonColumn;
+use crate::models::json_types::{JoinItem, PriceTable};
+
+/// 項目類型：(type, label, source, 預設價格)
+/// 價格配置缺少該項目時使用預設價格（與前端 joinRecordStore.activityConfigs 相同）
+pub const ITEM_TYPES: &[(&str, &str, &str, i64)] = &[
+    ("chaodu", "超度/超薦", "salvation.ancestors", 1000),
+    ("survivors", "陽上人", "salvation.survivors", 0),
+    ("diandeng", "點燈", "blessing.persons", 600),
+    ("qifu", "消災祈福", "blessing.persons", 300),
+    ("xiaozai", "固定消災", "blessing.persons", 100),
+    ("pudu", "中元普度", "blessing.persons", 1200),
+    ("support_triple_gem", "護持三寶", "blessing.persons", 200),
+    ("food_offering", "供齋", "blessing.persons", 200),
+    ("support_temple", "護持道場", "blessing.persons", 200),
+    ("sutra_printing", "助印經書", "blessing.persons", 200),
+    ("life_release", "放生", "blessing.persons", 200),
+];
+
+/// 項目類型的排序位置（依 ITEM_TYPES 順序，未知類型排最後）
+pub fn item_type_order(item_type: &str) -> usize {
+    ITEM_TYPES
+        .iter()
+        .position(|(t, ..)| *t == item_type)
+        .unwrap_or(ITEM_TYPES.len())
+}
+
+/// 項目類型名稱；未知類型使用記錄中的 label
+pub fn item_type_label(item_type: &str, fallback: &str) -> String {
+    ITEM_TYPES
+        .iter()
+        .find(|(t, ..)| *t == item_type)
+        .map_or(fallback, |(_, label, ..)| label)
+        .to_string()
+}
+
+/// 項目數量：quantity 為 0 的舊資料以 sourceData 筆數計
+pub fn item_quantity(item: &JoinItem) -> i64 {
+    if item.quantity > 0 {
+        item.quantity
+    } else {
+        item.source_data.len() as i64
+    }
+}
+
+/// 點燈燈種：(lampType, label, 預設價格)；價格配置有 diandeng_{lampType} 時以配置為準
+pub const LAMP_TYPES: &[(&str, &str, i64)] = &[
+    ("guangming", "光明燈", 600),
+    ("taisui", "太歲燈", 800),
+    ("yuanchen", "元辰燈", 1000),
+];
+
+pub const DEFAULT_LAMP_TYPE: &str = "guangming";
+
+/// 讀取生效中（state = 'now'）的價格配置
+pub async fn load_effective_prices(
+    conn: &mut SqliteConnection,
+) -> Result<Option<PriceTable>, sqlx::Error> {
+    let row: Option<(i64, JsonColumn<PriceTable>)> = sqlx::query_as(
+        "SELECT id, prices FROM priceConfigDB WHERE state = 'now' AND date_deleted IS NULL ORDER BY enableDate DESC, id DESC LIMIT 1",
+    )
+    .fetch_optional(&mut *conn)
+    .await?;
+
+    Ok(row.map(|(id, prices)| {
+        prices
+            .into_reported("priceConfigDB.prices", id)
+            .unwrap_or_default()
+    }))
+}
+
+/// 讀取指定的價格配置（活動指定 priceConfigId 時使用）
+pub async fn load_price_config(
+    conn: &mut SqliteConnection,
+    id: i64,
+) -> Result<Option<PriceTable>, sqlx::Error> {
+    let row: Option<(JsonColumn<PriceTable>,)> =
+        sqlx::query_as("SELECT prices FROM priceConfigDB WHERE id = ? AND date_deleted IS NULL")
+            .bind(id)
+            .fetch_optional(&mut *conn)
+            .await?;
+
+    Ok(row.map(|(prices,)| {
+        prices
+            .into_reported("priceConfigDB.prices", id)
+            .unwrap_or_default()
+    }))
+}
+
+/// 根據來源（"salvation.ancestors" 等）取出 registration 中的名單，
+/// selected_ids 有值時只保留指定 id
+fn source_data(registration: &JsonValue, source: &str, selected_ids: Option<&Vec<i64>>) -> Vec<JsonValue> {
+    let (section, field) = source.split_once('.').unwrap_or((source, ""));
+    registration
+        .get(section)
+        .and_then(|s| s.get(field))
+        .and_then(|v| v.as_array())
+        .map(|list| {
+            list.iter()
+                .filter(|person| match selected_ids {
+                    Some(ids) => person
+                        .get("id")
+                        .and_then(|id| id.as_i64())
+                        .map(|id| ids.contains(&id))
+                        .unwrap_or(false),
+                    None => true,
+                })
+                .cloned()
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// 名單顯示名稱（祖先只有 surname），以「、」串接
+pub fn person_names(people: &[JsonValue]) -> String {
+    people
+        .iter()
+        .filter_map(|person| {
+            person
+                .get("name")
+                .or_else(|| person.get("surname"))
+                .and_then(|v| v.as_str())
+                .filter(|name| !name.is_empty())
+        })
+        .collect::<Vec<_>>()
+        .join("、")
+}
+
+/// 超度 / 陽上人使用超度地址，其餘使用消災地址
+fn source_address(registration: &JsonValue, source: &str) -> String {
+    let section = source.split('.').next().unwrap_or_default();
+    registration
+        .get(section)
+        .and_then(|s| s.get("address"))
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// 組裝參加記錄項目
+/// - registration：{ "salvation": {...}, "blessing": {...} }
+/// - selections：各項目類型指定的 sourceData id（未指定則取全部）
+/// - person_lamp_types：點燈人員的燈種 { personId: lampType }，未指定為光明燈
+///
+/// 名單為空的項目會略過；返回 (items, totalAmount)
+pub fn build_items(
+    registration: &JsonValue,
+    item_types: &[String],
+    selections: &HashMap<String, Vec<i64>>,
+    person_lamp_types: &HashMap<String, String>,
+    prices: &PriceTable,
+) -> Result<(Vec<JsonValue>, i64), String> {
+    let mut items: Vec<JsonValue> = Vec::new();
+    let mut total_amount = 0;
+
+    for item_type in item_types {
+        let (_, label, source, default_price) = ITEM_TYPES
+            .iter()
+            .find(|(t, ..)| t == item_type)
+            .ok_or_else(|| format!("無效的項目類型: {}", item_type))?;
+
+        let data = source_data(registration, source, selections.get(item_type));
+        if data.is_empty() {
+            continue;
+        }
+        let quantity = data.len() as i64;
+        let address = source_address(registration, source);
+
+        let mut item = Map::new();
+        item.insert("type".to_string(), json!(item_type));
+        item.insert("label".to_string(), json!(label));
+
+        if item_type == "diandeng" {
+            // 點燈：每人燈種可不同，小計為各燈種價格總和，price 為平均價格（與前端一致）
+            let mut subtotal = 0;
+            let mut lamp_details: Vec<JsonValue> = Vec::new();
+            for person in &data {
+                let person_id = person.get("id").and_then(|v| v.as_i64()).unwrap_or_default();
+                let lamp_type = person_lamp_types
+                    .get(&person_id.to_string())
+                    .map(|s| s.as_str())
+                    .unwrap_or(DEFAULT_LAMP_TYPE);
+                let (_, lamp_label, default_price) = LAMP_TYPES
+                    .iter()
+                    .find(|(t, _, _)| *t == lamp_type)
+                    .ok_or_else(|| format!("無效的燈種: {}", lamp_type))?;
+                let price = prices
+                    .get(&format!("diandeng_{}", lamp_type))
+                    .copied()
+                    .unwrap_or(*default_price);
+
+                subtotal += price;
+                lamp_details.push(json!({
+                    "personId": person_id,
+                    "personName": person.get("name").cloned().unwrap_or(JsonValue::Null),
+                    "lampType": lamp_type,
+                    "lampTypeLabel": lamp_label,
+                    "price": price,
+                }));
+            }
+
+            item.insert("price".to_string(), json!(subtotal / quantity));
+            item.insert("quantity".to_string(), json!(quantity));
+            item.insert("subtotal".to_string(), json!(subtotal));
+            item.insert("source".to_string(), json!(source));
+            item.insert("sourceData".to_string(), JsonValue::Array(data));
+            item.insert("sourceAddress".to_string(), json!(address));
+            item.insert("lampDetails".to_string(), JsonValue::Array(lamp_details));
+            total_amount += subtotal;
+        } else {
+            let price = prices
+                .get(item_type.as_str())
+                .copied()
+                .unwrap_or(*default_price);
+            let subtotal = price * quantity;
+
+            item.insert("price".to_string(), json!(price));
+            item.insert("quantity".to_string(), json!(quantity));
+            item.insert("subtotal".to_string(), json!(subtotal));
+            item.insert("source".to_string(), json!(source));
+            item.insert("sourceData".to_string(), JsonValue::Array(data));
+            item.insert("sourceAddress".to_string(), json!(address));
+            total_amount += subtotal;
+        }
+
+        items.push(JsonValue::Object(item));
+    }
+
+    Ok((items, total_amount))
+}
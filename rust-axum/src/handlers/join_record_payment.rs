@@ -0,0 +1,506 @@
+// src/handlers/join_record_payment.rs
+use axum::{
+    extract::{Extension, Path},
+    http::StatusCode,
+    Json,
+};
+use sqlx::{SqliteConnection, SqlitePool};
+
+use crate::models::api_response::ApiResponse;
+use crate::models::join_record_payment::{
+    AddPaymentRequest, JoinRecordPayment, JoinRecordPaymentSummary, ReversePaymentRequest,
+};
+
+pub(crate) const PAYMENT_FULL_QUERY: &str = r#"
+SELECT
+    id,
+    user_created,
+    CASE
+        WHEN date_created IS NOT NULL
+        THEN datetime(date_created / 1000, 'unixepoch')
+        ELSE NULL
+    END as date_created,
+    user_updated,
+    CASE
+        WHEN date_updated IS NOT NULL
+        THEN datetime(date_updated / 1000, 'unixepoch')
+        ELSE NULL
+    END as date_updated,
+    joinRecordId,
+    paymentType,
+    amount,
+    paymentMethod,
+    paymentDate,
+    handledBy,
+    state,
+    reversedPaymentId,
+    reconciliationBatchId,
+    notes,
+    createdAt,
+    updatedAt
+FROM joinRecordPayments
+"#;
+
+/// 依已付金額推導付款狀態
+/// unpaid → partial → paid，未收款且原本為 waived(免付) 時保留 waived
+pub fn derive_payment_state(paid_amount: i64, final_amount: i64, current_state: Option<&str>) -> String {
+    if paid_amount <= 0 {
+        if current_state == Some("waived") {
+            "waived".to_string()
+        } else {
+            "unpaid".to_string()
+        }
+    } else if paid_amount < final_amount {
+        "partial".to_string()
+    } else {
+        "paid".to_string()
+    }
+}
+
+/// 🔥 依付款流水重算 joinRecordDB 的 paidAmount / paymentState
+/// 沒有任何流水的舊記錄維持原值不動；paymentMethod / paymentDate 同步為最近一筆有效付款
+pub async fn sync_payment_summary(
+    conn: &mut SqliteConnection,
+    join_record_id: i64,
+) -> Result<(), sqlx::Error> {
+    let (count, paid_amount): (i64, i64) = sqlx::query_as(
+        "SELECT COUNT(*), COALESCE(SUM(amount), 0) FROM joinRecordPayments WHERE joinRecordId = ?",
+    )
+    .bind(join_record_id)
+    .fetch_one(&mut *conn)
+    .await?;
+
+    if count == 0 {
+        return Ok(());
+    }
+
+    let record: Option<(Option<i64>, Option<String>)> =
+        sqlx::query_as("SELECT finalAmount, paymentState FROM joinRecordDB WHERE id = ?")
+            .bind(join_record_id)
+            .fetch_optional(&mut *conn)
+            .await?;
+
+    let Some((final_amount, current_state)) = record else {
+        return Ok(());
+    };
+
+    let payment_state = derive_payment_state(
+        paid_amount,
+        final_amount.unwrap_or(0),
+        current_state.as_deref(),
+    );
+
+    let latest: Option<(Option<String>, Option<String>)> = sqlx::query_as(
+        "SELECT paymentMethod, paymentDate FROM joinRecordPayments
+         WHERE joinRecordId = ? AND paymentType = 'payment' AND state = 'active'
+         ORDER BY paymentDate DESC, id DESC LIMIT 1",
+    )
+    .bind(join_record_id)
+    .fetch_optional(&mut *conn)
+    .await?;
+    let (latest_method, latest_date) = latest.unwrap_or((None, None));
+
+    let now = chrono::Utc::now().to_rfc3339();
+    sqlx::query(
+        "UPDATE joinRecordDB SET paidAmount = ?, paymentState = ?,
+         paymentMethod = COALESCE(?, paymentMethod), paymentDate = COALESCE(?, paymentDate),
+         updatedAt = ? WHERE id = ?",
+    )
+    .bind(paid_amount)
+    .bind(&payment_state)
+    .bind(&latest_method)
+    .bind(&latest_date)
+    .bind(&now)
+    .bind(join_record_id)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// 讀取參加記錄的付款摘要與完整流水
+async fn load_payment_summary(
+    pool: &SqlitePool,
+    join_record_id: i64,
+) -> Result<Option<JoinRecordPaymentSummary>, sqlx::Error> {
+    let record: Option<(Option<i64>, Option<i64>, Option<String>)> = sqlx::query_as(
+        "SELECT finalAmount, paidAmount, paymentState FROM joinRecordDB WHERE id = ? AND date_deleted IS NULL",
+    )
+    .bind(join_record_id)
+    .fetch_optional(pool)
+    .await?;
+
+    let Some((final_amount, paid_amount, payment_state)) = record else {
+        return Ok(None);
+    };
+
+    let payments = sqlx::query_as::<_, JoinRecordPayment>(&format!(
+        "{} WHERE joinRecordId = ? ORDER BY paymentDate ASC, id ASC",
+        PAYMENT_FULL_QUERY
+    ))
+    .bind(join_record_id)
+    .fetch_all(pool)
+    .await?;
+
+    let final_amount = final_amount.unwrap_or(0);
+    let paid_amount = paid_amount.unwrap_or(0);
+
+    Ok(Some(JoinRecordPaymentSummary {
+        join_record_id,
+        final_amount,
+        paid_amount,
+        balance: final_amount - paid_amount,
+        payment_state,
+        payments: payments.into_iter().map(|p| p.into()).collect(),
+    }))
+}
+
+/// 獲取參加記錄的付款流水
+pub async fn get_join_record_payments(
+    Path(id): Path<i64>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<JoinRecordPaymentSummary>>, (StatusCode, Json<ApiResponse<JoinRecordPaymentSummary>>)> {
+    let summary = load_payment_summary(&pool, id).await.map_err(|e| {
+        tracing::error!("查詢付款流水失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+        )
+    })?;
+
+    match summary {
+        Some(summary) => Ok(Json(ApiResponse::success(summary))),
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!("找不到 ID 為 {} 的參與記錄", id))),
+        )),
+    }
+}
+
+/// 參加記錄的舊付款欄位：(paidAmount, paymentMethod, paymentDate, accountingState, reconciliationBatchId)
+type LegacyPaymentRow = (Option<i64>, Option<String>, Option<String>, Option<String>, Option<i64>);
+
+/// 🔥 新增一筆付款或退款，並重算參加記錄的已付金額
+pub async fn add_join_record_payment(
+    Path(id): Path<i64>,
+    Extension(pool): Extension<SqlitePool>,
+    Json(payload): Json<AddPaymentRequest>,
+) -> Result<Json<ApiResponse<JoinRecordPaymentSummary>>, (StatusCode, Json<ApiResponse<JoinRecordPaymentSummary>>)> {
+    if payload.amount <= 0 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error("金額必須大於 0".to_string())),
+        ));
+    }
+
+    let signed_amount = match payload.payment_type.as_str() {
+        "payment" => payload.amount,
+        "refund" => -payload.amount,
+        other => {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::error(format!(
+                    "無效的 paymentType: {}（僅接受 payment 或 refund）",
+                    other
+                ))),
+            ));
+        }
+    };
+
+    // 1. 開始資料庫事務
+    let mut tx = pool.begin().await.map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("啟動事務失敗: {}", e))))
+    })?;
+
+    // 2. 確認參加記錄存在，並取得目前流水合計
+    let record: Option<LegacyPaymentRow> = sqlx::query_as(
+        "SELECT paidAmount, paymentMethod, paymentDate, accountingState, reconciliationBatchId
+         FROM joinRecordDB WHERE id = ? AND date_deleted IS NULL",
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("檢查參與記錄失敗: {}", e);
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("檢查失敗: {}", e))))
+    })?;
+
+    let Some((legacy_paid_amount, legacy_method, legacy_date, accounting_state, record_batch_id)) = record else {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!("找不到 ID 為 {} 的參與記錄", id))),
+        ));
+    };
+
+    let (ledger_count, ledger_total): (i64, i64) = sqlx::query_as(
+        "SELECT COUNT(*), COALESCE(SUM(amount), 0) FROM joinRecordPayments WHERE joinRecordId = ?",
+    )
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("查詢付款流水失敗: {}", e))))
+    })?;
+
+    let now_iso = chrono::Utc::now().to_rfc3339();
+    let now_timestamp = chrono::Utc::now().timestamp_millis();
+
+    // 舊記錄尚無流水但已有 paidAmount 時，先補一筆期初付款，避免重算後遺失；
+    // 記錄已沖帳時期初金額已計入該批次，沿用批次 ID，避免再次出現在待沖帳明細
+    let legacy_paid_amount = legacy_paid_amount.unwrap_or(0);
+    let opening_batch_id = if accounting_state.as_deref() == Some("reconciled") {
+        record_batch_id.unwrap_or(-1)
+    } else {
+        -1
+    };
+    let opening_amount = if ledger_count == 0 && legacy_paid_amount > 0 {
+        sqlx::query(
+            r#"
+            INSERT INTO joinRecordPayments (
+                joinRecordId, paymentType, amount, paymentMethod, paymentDate,
+                handledBy, state, reversedPaymentId, notes, createdAt, updatedAt,
+                user_created, date_created, reconciliationBatchId
+            ) VALUES (?, 'payment', ?, ?, ?, NULL, 'active', -1, '期初已付金額', ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(id)
+        .bind(legacy_paid_amount)
+        .bind(&legacy_method)
+        .bind(legacy_date.as_deref().unwrap_or(&now_iso))
+        .bind(&now_iso)
+        .bind(&now_iso)
+        .bind(&payload.user_id)
+        .bind(now_timestamp)
+        .bind(opening_batch_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!("寫入期初付款流水失敗: {}", e);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("新增付款失敗: {}", e))))
+        })?;
+        legacy_paid_amount
+    } else {
+        0
+    };
+    let ledger_total = ledger_total + opening_amount;
+
+    // 退款不可超過已收金額
+    if ledger_total + signed_amount < 0 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!(
+                "退款金額 {} 超過已收金額 {}",
+                payload.amount, ledger_total
+            ))),
+        ));
+    }
+
+    let payment_date = payload.payment_date.clone().unwrap_or_else(|| now_iso.clone());
+
+    // 3. 寫入流水
+    sqlx::query(
+        r#"
+        INSERT INTO joinRecordPayments (
+            joinRecordId, paymentType, amount, paymentMethod, paymentDate,
+            handledBy, state, reversedPaymentId, notes, createdAt, updatedAt,
+            user_created, date_created
+        ) VALUES (?, ?, ?, ?, ?, ?, 'active', -1, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(id)
+    .bind(&payload.payment_type)
+    .bind(signed_amount)
+    .bind(&payload.payment_method)
+    .bind(&payment_date)
+    .bind(&payload.handled_by)
+    .bind(&payload.notes)
+    .bind(&now_iso)
+    .bind(&now_iso)
+    .bind(&payload.user_id)
+    .bind(now_timestamp)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("新增付款流水失敗: {}", e);
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("新增付款失敗: {}", e))))
+    })?;
+
+    // 4. 重算參加記錄的已付金額與付款狀態
+    sync_payment_summary(&mut tx, id).await.map_err(|e| {
+        tracing::error!("同步參加記錄付款狀態失敗: {}", e);
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("同步付款狀態失敗: {}", e))))
+    })?;
+
+    // 5. 提交事務
+    tx.commit().await.map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("提交事務失敗: {}", e))))
+    })?;
+
+    let summary = load_payment_summary(&pool, id)
+        .await
+        .map_err(|e| {
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("查詢失敗: {}", e))))
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::error(format!("找不到 ID 為 {} 的參與記錄", id))),
+            )
+        })?;
+
+    let message = if signed_amount > 0 {
+        format!("成功記錄付款 {}", payload.amount)
+    } else {
+        format!("成功記錄退款 {}", payload.amount)
+    };
+
+    Ok(Json(ApiResponse::success_with_message(summary, message)))
+}
+
+/// 🔥 沖銷一筆付款或退款（保留原流水，另寫一筆反向金額）
+pub async fn reverse_join_record_payment(
+    Path((id, payment_id)): Path<(i64, i64)>,
+    Extension(pool): Extension<SqlitePool>,
+    Json(payload): Json<ReversePaymentRequest>,
+) -> Result<Json<ApiResponse<JoinRecordPaymentSummary>>, (StatusCode, Json<ApiResponse<JoinRecordPaymentSummary>>)> {
+    // 1. 開始資料庫事務
+    let mut tx = pool.begin().await.map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("啟動事務失敗: {}", e))))
+    })?;
+
+    // 2. 取得要沖銷的流水
+    let original = sqlx::query_as::<_, JoinRecordPayment>(&format!(
+        "{} WHERE id = ? AND joinRecordId = ?",
+        PAYMENT_FULL_QUERY
+    ))
+    .bind(payment_id)
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("查詢付款流水失敗: {}", e);
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("查詢失敗: {}", e))))
+    })?
+    .ok_or_else(|| {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "找不到參與記錄 {} 的付款流水 {}",
+                id, payment_id
+            ))),
+        )
+    })?;
+
+    if original.payment_type.as_deref() == Some("reversal") {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error("沖銷流水不可再次沖銷".to_string())),
+        ));
+    }
+
+    if original.state.as_deref() == Some("reversed") {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ApiResponse::error(format!("付款流水 {} 已被沖銷", payment_id))),
+        ));
+    }
+
+    let reversal_amount = -original.amount.unwrap_or(0);
+
+    // 沖銷付款後的已收金額不可為負（例如已全額退款後再沖銷原付款）
+    let (ledger_total,): (i64,) = sqlx::query_as(
+        "SELECT COALESCE(SUM(amount), 0) FROM joinRecordPayments WHERE joinRecordId = ?",
+    )
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("查詢付款流水失敗: {}", e))))
+    })?;
+    if ledger_total + reversal_amount < 0 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!(
+                "沖銷金額 {} 超過已收金額 {}，請先沖銷相關退款",
+                -reversal_amount, ledger_total
+            ))),
+        ));
+    }
+
+    let now_iso = chrono::Utc::now().to_rfc3339();
+    let now_timestamp = chrono::Utc::now().timestamp_millis();
+    let notes = payload
+        .reason
+        .clone()
+        .unwrap_or_else(|| format!("沖銷流水 {}", payment_id));
+
+    // 3. 寫入反向流水
+    sqlx::query(
+        r#"
+        INSERT INTO joinRecordPayments (
+            joinRecordId, paymentType, amount, paymentMethod, paymentDate,
+            handledBy, state, reversedPaymentId, notes, createdAt, updatedAt,
+            user_created, date_created
+        ) VALUES (?, 'reversal', ?, ?, ?, ?, 'active', ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(id)
+    .bind(reversal_amount)
+    .bind(&original.payment_method)
+    .bind(&now_iso)
+    .bind(&payload.handled_by)
+    .bind(payment_id)
+    .bind(&notes)
+    .bind(&now_iso)
+    .bind(&now_iso)
+    .bind(&payload.user_id)
+    .bind(now_timestamp)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("寫入沖銷流水失敗: {}", e);
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("沖銷失敗: {}", e))))
+    })?;
+
+    // 4. 標記原流水為已沖銷
+    sqlx::query(
+        "UPDATE joinRecordPayments SET state = 'reversed', updatedAt = ?, date_updated = ?, user_updated = ? WHERE id = ?",
+    )
+    .bind(&now_iso)
+    .bind(now_timestamp)
+    .bind(&payload.user_id)
+    .bind(payment_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("更新原流水失敗: {}", e))))
+    })?;
+
+    // 5. 重算參加記錄的已付金額與付款狀態
+    sync_payment_summary(&mut tx, id).await.map_err(|e| {
+        tracing::error!("同步參加記錄付款狀態失敗: {}", e);
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("同步付款狀態失敗: {}", e))))
+    })?;
+
+    // 6. 提交事務
+    tx.commit().await.map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("提交事務失敗: {}", e))))
+    })?;
+
+    let summary = load_payment_summary(&pool, id)
+        .await
+        .map_err(|e| {
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("查詢失敗: {}", e))))
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::error(format!("找不到 ID 為 {} 的參與記錄", id))),
+            )
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        summary,
+        format!("成功沖銷付款流水 {}", payment_id),
+    )))
+}
@@ -7,3 +7,16 @@ pub mod receipt_number; // ✅ 新增：收據編號處理器
 pub mod directus_users;
 pub mod price_config; // ✅ 新增：價格配置處理器 by 20260331
 pub mod join_record; // ✅ 新增：加入紀錄處理器 by 20260422
+pub mod join_record_payment; // ✅ 新增：付款流水處理器 by 20261019
+pub mod reconciliation_batch; // ✅ 新增：沖帳批次處理器 by 20261019
+pub mod report; // ✅ 新增：報表處理器 by 20261019
+pub mod join_record_item; // ✅ 新增：參加記錄項目組裝 by 20261019
+pub mod import; // ✅ 新增：資料匯入處理器 by 20261019
+pub mod household; // ✅ 新增：戶處理器 by 20261019
+pub mod taisui; // ✅ 新增：太歲處理器 by 20261019
+pub mod calendar; // ✅ 新增：農曆曆法處理器 by 20261019
+pub mod activity_template; // ✅ 新增：活動範本處理器 by 20261019
+pub mod activity_summary; // ✅ 新增：活動財務摘要處理器 by 20261019
+pub mod activity_roster; // ✅ 新增：法會名冊處理器 by 20261019
+pub mod activity_calendar; // ✅ 新增：活動行事曆處理器 by 20261019
+pub mod soft_delete; // ✅ 新增：軟刪除處理器 by 20261019
@@ -2,18 +2,31 @@
 use axum::{
     extract::{Extension, Path, Query},
     http::StatusCode,
+    response::Response,
     Json,
 };
-use sqlx::SqlitePool;
+use sqlx::{SqliteConnection, SqlitePool};
 
 // 導入共享的 API 響應結構
 use crate::models::api_response::{ApiResponse, Meta};
+use crate::models::soft_delete::DeleteQuery;
+use crate::handlers::soft_delete;
+use crate::models::json_column::JsonColumn;
+use crate::models::json_types::DonateItem;
 
 use crate::models::monthly_donate::{
     CreateMonthlyDonateRequest, MonthlyDonate, MonthlyDonateResponse, MonthlyDonateQuery, UpdateMonthlyDonateRequest,
+    AddDonateItemRequest, UpdateDonateItemRequest, DeleteDonateItemQuery,
+    DonationStats, DonationTrendPoint, DonationTypeStat, MonthlyDonateMonthStat, MonthlyDonateStatsQuery,
+    DonateGap, DonorContact, GapQuery, RenewalCandidate, RenewalQuery,
+    MonthlyDonateLedgerEntry, MonthlyDonateLedgerQuery, MonthlyDonateExportQuery,
 };
+use crate::utils::export::{export_response, order_by_clause, spawn_export_rows, ExportFormat, ExportRow};
+use serde_json::Value as JsonValue;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 
-const MONTHLY_DONATE_FULL_QUERY: &str = r#"
+/// 每月捐款完整欄位查詢，已刪除的捐款不列入
+pub(crate) const MONTHLY_DONATE_FULL_QUERY: &str = r#"
 SELECT 
     id,
     user_created,
@@ -35,8 +48,9 @@ SELECT
     donateItems,
     memo,
     createdAt,
-    updatedAt
-FROM monthlyDonateDB
+    updatedAt,
+    householdId
+FROM (SELECT * FROM monthlyDonateDB WHERE date_deleted IS NULL) AS monthlyDonateDB
 "#;
 
 /// 獲取所有每月捐款記錄
@@ -45,7 +59,7 @@ pub async fn get_all_monthly_donates(
     Extension(pool): Extension<SqlitePool>,
 ) -> Result<Json<ApiResponse<Vec<MonthlyDonateResponse>>>, (StatusCode, Json<ApiResponse<Vec<MonthlyDonateResponse>>>)> {
     let mut query = format!("{} WHERE 1=1", MONTHLY_DONATE_FULL_QUERY);
-    let mut count_query = String::from("SELECT COUNT(*) FROM monthlyDonateDB WHERE 1=1");
+    let mut count_query = String::from("SELECT COUNT(*) FROM monthlyDonateDB WHERE date_deleted IS NULL");
 
     // 添加過濾條件
     if let Some(name) = &params.name {
@@ -267,6 +281,11 @@ pub async fn create_monthly_donate(
     //     "system".to_string()
     // });
 
+    // 驗證月份格式、金額與月份重複
+    if let Some(donate_items) = &payload.donate_items {
+        validate_donate_items(donate_items, payload.allow_overlap, None).map_err(donate_items_error_response)?;
+    }
+
     // 🔥 將 JsonValue 轉換為字符串存入資料庫
     let donate_items_str = payload.donate_items.map(|v| v.to_string());
 
@@ -275,9 +294,9 @@ pub async fn create_monthly_donate(
         r#"
         INSERT INTO monthlyDonateDB (
             name, registrationId, donateId, donateType, 
-            donateItems, memo, createdAt, updatedAt
+            donateItems, memo, createdAt, updatedAt, householdId
         )
-        VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, COALESCE((SELECT householdId FROM registrationDB WHERE id = ?), -1))
         "#,
     )
     //.bind(&user_created_value)
@@ -289,6 +308,7 @@ pub async fn create_monthly_donate(
     .bind(&payload.memo)
     .bind(&now)
     .bind(&now)
+    .bind(payload.registration_id) // 沿用報名記錄的戶ID
     .execute(&pool)
     .await
     .map_err(|e| {
@@ -329,7 +349,7 @@ pub async fn update_monthly_donate(
     Json(payload): Json<UpdateMonthlyDonateRequest>,
 ) -> Result<Json<ApiResponse<MonthlyDonateResponse>>, (StatusCode, Json<ApiResponse<MonthlyDonateResponse>>)> {
     // 檢查記錄是否存在
-    let exists: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM monthlyDonateDB WHERE id = ?")
+    let exists: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM monthlyDonateDB WHERE id = ? AND date_deleted IS NULL")
         .bind(id)
         .fetch_one(&pool)
         .await
@@ -371,6 +391,7 @@ pub async fn update_monthly_donate(
     
     // 🔥 將 JsonValue 轉換為字符串
     if let Some(donate_items) = &payload.donate_items {
+        validate_donate_items(donate_items, payload.allow_overlap, None).map_err(donate_items_error_response)?;
         updates.push("donateItems = ?");
         bindings.push(donate_items.to_string());
     }
@@ -441,11 +462,10 @@ pub async fn update_monthly_donate(
 /// 刪除每月捐款記錄
 pub async fn delete_monthly_donate(
     Path(id): Path<i64>,
+    Query(params): Query<DeleteQuery>,
     Extension(pool): Extension<SqlitePool>,
 ) -> Result<Json<ApiResponse<()>>, (StatusCode, Json<ApiResponse<()>>)> {
-    let result = sqlx::query("DELETE FROM monthlyDonateDB WHERE id = ?")
-        .bind(id)
-        .execute(&pool)
+    let deleted = soft_delete::soft_delete(&pool, "monthlyDonateDB", id, params.user_deleted.as_deref())
         .await
         .map_err(|e| {
             tracing::error!("刪除每月捐款記錄失敗: {}", e);
@@ -455,7 +475,7 @@ pub async fn delete_monthly_donate(
             )
         })?;
 
-    if result.rows_affected() == 0 {
+    if !deleted {
         return Err((
             StatusCode::NOT_FOUND,
             Json(ApiResponse::error(format!("找不到 ID 為 {} 的捐款記錄", id))),
@@ -469,4 +489,1276 @@ pub async fn delete_monthly_donate(
         meta: None,
         errors: None,
     }))
-}
\ No newline at end of file
+}
+
+/// 還原已刪除的每月捐款記錄
+pub async fn restore_monthly_donate(
+    Path(id): Path<i64>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<MonthlyDonateResponse>>, (StatusCode, Json<ApiResponse<MonthlyDonateResponse>>)> {
+    let restored = soft_delete::restore(&pool, "monthlyDonateDB", id)
+        .await
+        .map_err(|e| {
+            tracing::error!("還原 每月捐款記錄 失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("還原失敗: {}", e))),
+            )
+        })?;
+
+    if !restored {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!("找不到 ID 為 {} 的已刪除每月捐款記錄", id))),
+        ));
+    }
+
+    let Json(mut response) = get_monthly_donate_by_id(Path(id), Extension(pool)).await?;
+    response.message = Some("成功還原每月捐款記錄".to_string());
+    Ok(Json(response))
+}
+/// 贊助項目驗證失敗：(狀態碼, 訊息, 明細)
+pub(crate) type DonateItemsError = (StatusCode, String, Vec<String>);
+
+fn donate_items_error_response<T>(
+    (status, message, details): DonateItemsError,
+) -> (StatusCode, Json<ApiResponse<T>>) {
+    (status, Json(ApiResponse::error_with_details(message, details)))
+}
+
+/// 驗證 donateItems：price 必須為正整數、months 必須為不重複的 YYYYMM；
+/// 同一筆記錄中不同項目涵蓋相同月份視為重複贊助，需 allow_overlap 才允許。
+/// focus_item 有值時只檢查與該項目重疊的月份（單一項目新增 / 更新時，不因其他既有重疊而失敗）
+pub(crate) fn validate_donate_items(
+    items: &JsonValue,
+    allow_overlap: bool,
+    focus_item: Option<&str>,
+) -> Result<(), DonateItemsError> {
+    if items.is_null() {
+        return Ok(());
+    }
+    let Some(items) = items.as_array() else {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "donateItems 必須為陣列".to_string(),
+            Vec::new(),
+        ));
+    };
+
+    let mut errors: Vec<String> = Vec::new();
+    let mut month_items: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+    for (index, item) in items.iter().enumerate() {
+        let label = item
+            .get("donateItemsId")
+            .and_then(|v| v.as_str())
+            .filter(|v| !v.is_empty())
+            .map(|v| v.to_string())
+            .unwrap_or_else(|| format!("第 {} 項", index + 1));
+
+        match item.get("price").and_then(|v| v.as_i64()) {
+            Some(price) if price > 0 => {}
+            Some(price) => errors.push(format!("{}: price 必須大於 0（目前為 {}）", label, price)),
+            None => errors.push(format!("{}: price 必須為整數", label)),
+        }
+
+        let months = item.get("months").and_then(|v| v.as_array());
+        if months.is_none_or(|m| m.is_empty()) {
+            errors.push(format!("{}: 請至少選擇一個月份", label));
+            continue;
+        }
+
+        let mut seen: HashSet<&str> = HashSet::new();
+        for month in months.into_iter().flatten() {
+            match month.as_str() {
+                Some(m) if is_valid_year_month(m) => {
+                    if !seen.insert(m) {
+                        errors.push(format!("{}: 月份 {} 重複", label, m));
+                        continue;
+                    }
+                    month_items.entry(m.to_string()).or_default().push(label.clone());
+                }
+                _ => errors.push(format!(
+                    "{}: 無效的月份 {}（應為 YYYYMM）",
+                    label,
+                    month.as_str().map(|m| m.to_string()).unwrap_or_else(|| month.to_string())
+                )),
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "贊助項目資料不正確".to_string(), errors));
+    }
+
+    let overlaps: Vec<String> = month_items
+        .into_iter()
+        .filter(|(_, labels)| {
+            labels.len() > 1 && focus_item.is_none_or(|focus| labels.iter().any(|l| l == focus))
+        })
+        .map(|(month, labels)| format!("{}: {}", month, labels.join("、")))
+        .collect();
+
+    if !overlaps.is_empty() && !allow_overlap {
+        return Err((
+            StatusCode::CONFLICT,
+            "贊助月份重複，如確定要重複贊助請設定 allowOverlap".to_string(),
+            overlaps,
+        ));
+    }
+
+    Ok(())
+}
+
+/// 讀取記錄目前的 donateItems（記錄不存在或已刪除時返回 None）
+async fn load_donate_items(
+    conn: &mut SqliteConnection,
+    id: i64,
+) -> Result<Option<Vec<JsonValue>>, sqlx::Error> {
+    let row: Option<(Option<String>,)> =
+        sqlx::query_as("SELECT donateItems FROM monthlyDonateDB WHERE id = ? AND date_deleted IS NULL")
+            .bind(id)
+            .fetch_optional(&mut *conn)
+            .await?;
+
+    Ok(row.map(|(items,)| {
+        items
+            .as_deref()
+            .and_then(|s| serde_json::from_str::<Vec<JsonValue>>(s).ok())
+            .unwrap_or_default()
+    }))
+}
+
+/// 產生 7 碼 donateItemsId（與前端 generateGitHashBrowser 長度一致），並避開同一筆記錄中已存在的 ID
+async fn generate_donate_items_id(
+    conn: &mut SqliteConnection,
+    id: i64,
+) -> Result<String, sqlx::Error> {
+    loop {
+        let candidate = uuid::Uuid::new_v4().simple().to_string()[..7].to_string();
+        let exists: (i64,) = sqlx::query_as(
+            "SELECT COUNT(*) FROM monthlyDonateDB, json_each(monthlyDonateDB.donateItems) je
+             WHERE monthlyDonateDB.id = ? AND json_extract(je.value, '$.donateItemsId') = ?",
+        )
+        .bind(id)
+        .bind(&candidate)
+        .fetch_one(&mut *conn)
+        .await?;
+        if exists.0 == 0 {
+            return Ok(candidate);
+        }
+    }
+}
+
+/// 查詢單筆捐款記錄並轉為響應
+async fn fetch_monthly_donate_response(
+    conn: &mut SqliteConnection,
+    id: i64,
+) -> Result<MonthlyDonateResponse, sqlx::Error> {
+    let query = format!("{} WHERE id = ?", MONTHLY_DONATE_FULL_QUERY);
+    let monthly_donate = sqlx::query_as::<_, MonthlyDonate>(&query)
+        .bind(id)
+        .fetch_one(&mut *conn)
+        .await?;
+    Ok(monthly_donate.into())
+}
+
+/// 新增贊助項目：以單一 UPDATE 在 donateItems 陣列尾端加入，不覆寫其他項目
+pub async fn add_donate_item(
+    Path(id): Path<i64>,
+    Extension(pool): Extension<SqlitePool>,
+    Json(payload): Json<AddDonateItemRequest>,
+) -> Result<Json<ApiResponse<MonthlyDonateResponse>>, (StatusCode, Json<ApiResponse<MonthlyDonateResponse>>)> {
+    if payload.months.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error("請至少選擇一個月份".to_string())),
+        ));
+    }
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("啟動事務失敗: {}", e))),
+        )
+    })?;
+
+    let current_items = load_donate_items(&mut tx, id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+            )
+        })?
+        .ok_or_else(|| {
+            (
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::error(format!("找不到 ID 為 {} 的捐款記錄", id))),
+            )
+        })?;
+
+    let donate_items_id = generate_donate_items_id(&mut tx, id).await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("產生贊助項目ID失敗: {}", e))),
+        )
+    })?;
+
+    let now = chrono::Utc::now();
+    let now_iso = now.to_rfc3339();
+    let created_user = payload.created_user.clone().unwrap_or_default();
+
+    let new_item = serde_json::json!({
+        "donateItemsId": donate_items_id,
+        "price": payload.price,
+        "months": payload.months,
+        "createdAt": now.to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+        "createdUser": created_user,
+        "updatedAt": "",
+        "updatedUser": "",
+    });
+
+    let mut candidate_items = current_items;
+    candidate_items.push(new_item.clone());
+    validate_donate_items(
+        &JsonValue::Array(candidate_items),
+        payload.allow_overlap,
+        Some(&donate_items_id),
+    )
+    .map_err(donate_items_error_response)?;
+
+    let result = sqlx::query(
+        r#"
+        UPDATE monthlyDonateDB
+        SET donateItems = json_insert(COALESCE(donateItems, '[]'), '$[#]', json(?)),
+            memo = COALESCE(?, memo),
+            updatedAt = ?,
+            user_updated = COALESCE(?, user_updated),
+            date_updated = ?
+        WHERE id = ?
+        "#,
+    )
+    .bind(new_item.to_string())
+    .bind(&payload.memo)
+    .bind(&now_iso)
+    .bind(&payload.created_user)
+    .bind(now.timestamp_millis())
+    .bind(id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("新增贊助項目失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("新增贊助項目失敗: {}", e))),
+        )
+    })?;
+
+    if result.rows_affected() == 0 {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!("找不到 ID 為 {} 的捐款記錄", id))),
+        ));
+    }
+
+    let response = fetch_monthly_donate_response(&mut tx, id).await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+        )
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("提交事務失敗: {}", e))),
+        )
+    })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        response,
+        format!("成功新增贊助項目 {}", donate_items_id),
+    )))
+}
+
+/// 更新贊助項目：只合併（json_patch）指定 donateItemsId 的那一個元素
+pub async fn update_donate_item(
+    Path((id, items_id)): Path<(i64, String)>,
+    Extension(pool): Extension<SqlitePool>,
+    Json(payload): Json<UpdateDonateItemRequest>,
+) -> Result<Json<ApiResponse<MonthlyDonateResponse>>, (StatusCode, Json<ApiResponse<MonthlyDonateResponse>>)> {
+    if payload.price.is_none() && payload.months.is_none() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error("沒有提供要更新的字段".to_string())),
+        ));
+    }
+
+    if payload.months.as_ref().is_some_and(|m| m.is_empty()) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error("請至少選擇一個月份".to_string())),
+        ));
+    }
+
+    let now = chrono::Utc::now();
+    let now_iso = now.to_rfc3339();
+
+    let mut patch = serde_json::Map::new();
+    if let Some(price) = payload.price {
+        patch.insert("price".to_string(), serde_json::json!(price));
+    }
+    if let Some(months) = &payload.months {
+        patch.insert("months".to_string(), serde_json::json!(months));
+    }
+    patch.insert(
+        "updatedAt".to_string(),
+        serde_json::json!(now.to_rfc3339_opts(chrono::SecondsFormat::Millis, true)),
+    );
+    patch.insert(
+        "updatedUser".to_string(),
+        serde_json::json!(payload.updated_user.clone().unwrap_or_default()),
+    );
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("啟動事務失敗: {}", e))),
+        )
+    })?;
+
+    // 以更新後的項目驗證整筆記錄
+    let item_not_found = || {
+        (
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "找不到 ID 為 {} 的捐款記錄中 donateItemsId 為 {} 的贊助項目",
+                id, items_id
+            ))),
+        )
+    };
+    let mut candidate_items = load_donate_items(&mut tx, id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+            )
+        })?
+        .ok_or_else(item_not_found)?;
+    let target = candidate_items
+        .iter_mut()
+        .find(|item| item.get("donateItemsId").and_then(|v| v.as_str()) == Some(items_id.as_str()))
+        .ok_or_else(item_not_found)?;
+    if let Some(object) = target.as_object_mut() {
+        for (key, value) in &patch {
+            object.insert(key.clone(), value.clone());
+        }
+    }
+    validate_donate_items(&JsonValue::Array(candidate_items), payload.allow_overlap, Some(&items_id))
+        .map_err(donate_items_error_response)?;
+
+    let result = sqlx::query(
+        r#"
+        UPDATE monthlyDonateDB
+        SET donateItems = (
+                SELECT json_set(monthlyDonateDB.donateItems, '$[' || je.key || ']', json_patch(je.value, json(?)))
+                FROM json_each(monthlyDonateDB.donateItems) je
+                WHERE json_extract(je.value, '$.donateItemsId') = ?
+            ),
+            updatedAt = ?,
+            user_updated = COALESCE(?, user_updated),
+            date_updated = ?
+        WHERE id = ?
+          AND EXISTS (
+                SELECT 1 FROM json_each(monthlyDonateDB.donateItems) je
+                WHERE json_extract(je.value, '$.donateItemsId') = ?
+            )
+        "#,
+    )
+    .bind(serde_json::Value::Object(patch).to_string())
+    .bind(&items_id)
+    .bind(&now_iso)
+    .bind(&payload.updated_user)
+    .bind(now.timestamp_millis())
+    .bind(id)
+    .bind(&items_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("更新贊助項目失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("更新贊助項目失敗: {}", e))),
+        )
+    })?;
+
+    if result.rows_affected() == 0 {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "找不到 ID 為 {} 的捐款記錄中 donateItemsId 為 {} 的贊助項目",
+                id, items_id
+            ))),
+        ));
+    }
+
+    let response = fetch_monthly_donate_response(&mut tx, id).await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+        )
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("提交事務失敗: {}", e))),
+        )
+    })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        response,
+        format!("成功更新贊助項目 {}", items_id),
+    )))
+}
+
+/// 刪除贊助項目：只移除指定 donateItemsId 的那一個元素
+pub async fn delete_donate_item(
+    Path((id, items_id)): Path<(i64, String)>,
+    Query(params): Query<DeleteDonateItemQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<MonthlyDonateResponse>>, (StatusCode, Json<ApiResponse<MonthlyDonateResponse>>)> {
+    let now = chrono::Utc::now();
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("啟動事務失敗: {}", e))),
+        )
+    })?;
+
+    let result = sqlx::query(
+        r#"
+        UPDATE monthlyDonateDB
+        SET donateItems = (
+                SELECT json_remove(monthlyDonateDB.donateItems, '$[' || je.key || ']')
+                FROM json_each(monthlyDonateDB.donateItems) je
+                WHERE json_extract(je.value, '$.donateItemsId') = ?
+            ),
+            updatedAt = ?,
+            user_updated = COALESCE(?, user_updated),
+            date_updated = ?
+        WHERE id = ?
+          AND EXISTS (
+                SELECT 1 FROM json_each(monthlyDonateDB.donateItems) je
+                WHERE json_extract(je.value, '$.donateItemsId') = ?
+            )
+        "#,
+    )
+    .bind(&items_id)
+    .bind(now.to_rfc3339())
+    .bind(&params.user_id)
+    .bind(now.timestamp_millis())
+    .bind(id)
+    .bind(&items_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("刪除贊助項目失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("刪除贊助項目失敗: {}", e))),
+        )
+    })?;
+
+    if result.rows_affected() == 0 {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!(
+                "找不到 ID 為 {} 的捐款記錄中 donateItemsId 為 {} 的贊助項目",
+                id, items_id
+            ))),
+        ));
+    }
+
+    let response = fetch_monthly_donate_response(&mut tx, id).await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+        )
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("提交事務失敗: {}", e))),
+        )
+    })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        response,
+        format!("成功刪除贊助項目 {}", items_id),
+    )))
+}
+
+/// 贊助項目逐月分攤：price 為整筆金額，平均分攤到 months 的每一個月，
+/// 餘數由最早的月份各多分 1 元，確保各月加總等於 price
+/// 產出 allocations(recordId, donateType, itemKey, donateItemsId, price, monthCount, month, amount)，
+/// 第 1、2 個參數為 donateType 篩選
+pub const DONATE_MONTH_ALLOCATIONS_CTE: &str = r#"
+WITH item_months AS (
+    SELECT
+        d.id AS recordId,
+        COALESCE(d.donateType, '') AS donateType,
+        d.id || '-' || i.key AS itemKey,
+        json_extract(i.value, '$.donateItemsId') AS donateItemsId,
+        CAST(COALESCE(json_extract(i.value, '$.price'), 0) AS INTEGER) AS price,
+        json_array_length(i.value, '$.months') AS monthCount,
+        m.value AS month,
+        ROW_NUMBER() OVER (PARTITION BY d.id, i.key ORDER BY m.value) - 1 AS monthIndex
+    FROM monthlyDonateDB d, json_each(d.donateItems) i, json_each(i.value, '$.months') m
+    WHERE json_valid(d.donateItems)
+      AND d.date_deleted IS NULL
+      AND (? IS NULL OR COALESCE(d.donateType, '') = ?)
+),
+allocations AS (
+    SELECT
+        recordId,
+        donateType,
+        itemKey,
+        donateItemsId,
+        price,
+        monthCount,
+        month,
+        price / monthCount + CASE WHEN monthIndex < price % monthCount THEN 1 ELSE 0 END AS amount
+    FROM item_months
+)
+"#;
+
+/// 檢查是否為有效的 YYYYMM 月份字串（西元 1000–9999 年）
+pub fn is_valid_year_month(month: &str) -> bool {
+    month.len() == 6
+        && month.chars().all(|c| c.is_ascii_digit())
+        && !month.starts_with('0')
+        && matches!(month[4..].parse::<u32>(), Ok(1..=12))
+}
+
+/// 趨勢查詢最多列出的月數（100 年）
+const MAX_TREND_MONTHS: i32 = 1200;
+
+/// 月份轉為月序（年 × 12 + 月 - 1），以整數比較與遞增，避免字串比較在跨位數時出錯
+pub(crate) fn year_month_index(month: &str) -> Option<i32> {
+    if !is_valid_year_month(month) {
+        return None;
+    }
+    let year: i32 = month[..4].parse().ok()?;
+    let m: i32 = month[4..].parse().ok()?;
+    Some(year * 12 + m - 1)
+}
+
+/// 月序轉回 YYYYMM
+fn year_month_from_index(index: i32) -> String {
+    format!("{:04}{:02}", index / 12, index % 12 + 1)
+}
+
+/// 驗證統計查詢的月份範圍
+fn validate_stats_range(params: &MonthlyDonateStatsQuery) -> Result<(), String> {
+    for month in [&params.from, &params.to].into_iter().flatten() {
+        if !is_valid_year_month(month) {
+            return Err(format!("無效的月份格式: {}（應為 YYYYMM）", month));
+        }
+    }
+    Ok(())
+}
+
+/// 獲取月度統計（每月分攤金額、贊助者數）
+pub async fn get_monthly_donate_stats(
+    Query(params): Query<MonthlyDonateStatsQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<Vec<MonthlyDonateMonthStat>>>, (StatusCode, Json<ApiResponse<Vec<MonthlyDonateMonthStat>>>)> {
+    validate_stats_range(&params)
+        .map_err(|msg| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(msg))))?;
+
+    let query = format!(
+        r#"{}
+        SELECT month, COUNT(DISTINCT recordId), COUNT(*), SUM(amount)
+        FROM allocations
+        WHERE (? IS NULL OR month >= ?) AND (? IS NULL OR month <= ?)
+        GROUP BY month
+        ORDER BY month
+        "#,
+        DONATE_MONTH_ALLOCATIONS_CTE
+    );
+
+    let rows: Vec<(String, i64, i64, i64)> = sqlx::query_as(&query)
+        .bind(&params.donate_type)
+        .bind(&params.donate_type)
+        .bind(&params.from)
+        .bind(&params.from)
+        .bind(&params.to)
+        .bind(&params.to)
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("查詢月度統計失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+            )
+        })?;
+
+    let stats: Vec<MonthlyDonateMonthStat> = rows
+        .into_iter()
+        .map(|(month, count, item_count, total_amount)| MonthlyDonateMonthStat {
+            month,
+            count,
+            item_count,
+            total_amount,
+        })
+        .collect();
+
+    Ok(Json(ApiResponse::success(stats)))
+}
+
+/// 獲取贊助總覽統計
+pub async fn get_donation_stats(
+    Query(params): Query<MonthlyDonateStatsQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<DonationStats>>, (StatusCode, Json<ApiResponse<DonationStats>>)> {
+    validate_stats_range(&params)
+        .map_err(|msg| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(msg))))?;
+
+    let map_db_error = |e: sqlx::Error| {
+        tracing::error!("查詢贊助統計失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+        )
+    };
+
+    // 記錄數、項目數與金額（不分攤）
+    let (total_records,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM monthlyDonateDB WHERE date_deleted IS NULL AND (? IS NULL OR COALESCE(donateType, '') = ?)",
+    )
+    .bind(&params.donate_type)
+    .bind(&params.donate_type)
+    .fetch_one(&pool)
+    .await
+    .map_err(map_db_error)?;
+
+    let (total_items, total_amount): (i64, i64) = sqlx::query_as(
+        r#"
+        SELECT COUNT(*), COALESCE(SUM(CAST(json_extract(i.value, '$.price') AS INTEGER)), 0)
+        FROM monthlyDonateDB d, json_each(d.donateItems) i
+        WHERE json_valid(d.donateItems)
+          AND d.date_deleted IS NULL
+          AND (? IS NULL OR COALESCE(d.donateType, '') = ?)
+        "#,
+    )
+    .bind(&params.donate_type)
+    .bind(&params.donate_type)
+    .fetch_one(&pool)
+    .await
+    .map_err(map_db_error)?;
+
+    // 分攤後的月份範圍（可用 from / to 限定）
+    let range_query = format!(
+        r#"{}
+        SELECT COUNT(*), COUNT(DISTINCT month), COALESCE(SUM(amount), 0), MIN(month), MAX(month)
+        FROM allocations
+        WHERE (? IS NULL OR month >= ?) AND (? IS NULL OR month <= ?)
+        "#,
+        DONATE_MONTH_ALLOCATIONS_CTE
+    );
+    let (total_donor_months, distinct_months, allocated_amount, first_month, last_month): (
+        i64,
+        i64,
+        i64,
+        Option<String>,
+        Option<String>,
+    ) = sqlx::query_as(&range_query)
+        .bind(&params.donate_type)
+        .bind(&params.donate_type)
+        .bind(&params.from)
+        .bind(&params.from)
+        .bind(&params.to)
+        .bind(&params.to)
+        .fetch_one(&pool)
+        .await
+        .map_err(map_db_error)?;
+
+    // 本月
+    let current = chrono::Local::now().format("%Y%m").to_string();
+    let current_query = format!(
+        r#"{}
+        SELECT COUNT(DISTINCT recordId), COUNT(*), COALESCE(SUM(amount), 0)
+        FROM allocations
+        WHERE month = ?
+        "#,
+        DONATE_MONTH_ALLOCATIONS_CTE
+    );
+    let (current_count, current_items, current_amount): (i64, i64, i64) = sqlx::query_as(&current_query)
+        .bind(&params.donate_type)
+        .bind(&params.donate_type)
+        .bind(&current)
+        .fetch_one(&pool)
+        .await
+        .map_err(map_db_error)?;
+
+    Ok(Json(ApiResponse::success(DonationStats {
+        total_records,
+        total_items,
+        total_amount,
+        total_donor_months,
+        average_per_record: if total_records > 0 { total_amount / total_records } else { 0 },
+        average_per_month: if distinct_months > 0 { allocated_amount / distinct_months } else { 0 },
+        first_month,
+        last_month,
+        current_month: MonthlyDonateMonthStat {
+            month: current,
+            count: current_count,
+            item_count: current_items,
+            total_amount: current_amount,
+        },
+    })))
+}
+
+/// 獲取贊助類型分布
+pub async fn get_donation_type_stats(
+    Query(params): Query<MonthlyDonateStatsQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<Vec<DonationTypeStat>>>, (StatusCode, Json<ApiResponse<Vec<DonationTypeStat>>>)> {
+    validate_stats_range(&params)
+        .map_err(|msg| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(msg))))?;
+
+    let query = format!(
+        r#"{}
+        SELECT donateType, COUNT(DISTINCT recordId), COUNT(DISTINCT itemKey), SUM(amount)
+        FROM allocations
+        WHERE (? IS NULL OR month >= ?) AND (? IS NULL OR month <= ?)
+        GROUP BY donateType
+        ORDER BY SUM(amount) DESC
+        "#,
+        DONATE_MONTH_ALLOCATIONS_CTE
+    );
+
+    let rows: Vec<(String, i64, i64, i64)> = sqlx::query_as(&query)
+        .bind(&params.donate_type)
+        .bind(&params.donate_type)
+        .bind(&params.from)
+        .bind(&params.from)
+        .bind(&params.to)
+        .bind(&params.to)
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("查詢贊助類型統計失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+            )
+        })?;
+
+    let grand_total: i64 = rows.iter().map(|r| r.3).sum();
+    let stats: Vec<DonationTypeStat> = rows
+        .into_iter()
+        .map(|(donate_type, record_count, item_count, total_amount)| DonationTypeStat {
+            donate_type: if donate_type.is_empty() {
+                "未分類".to_string()
+            } else {
+                donate_type
+            },
+            record_count,
+            item_count,
+            total_amount,
+            percentage: if grand_total > 0 {
+                (total_amount as f64 * 10000.0 / grand_total as f64).round() / 100.0
+            } else {
+                0.0
+            },
+        })
+        .collect();
+
+    Ok(Json(ApiResponse::success(stats)))
+}
+
+/// 月份轉為期間鍵值
+fn trend_period_key(month: &str, period: &str) -> String {
+    let year = &month[..4];
+    match period {
+        "yearly" => year.to_string(),
+        "quarterly" => {
+            let m: u32 = month[4..].parse().unwrap_or(1);
+            format!("{}-Q{}", year, m.div_ceil(3))
+        }
+        _ => month.to_string(),
+    }
+}
+
+/// 下一個月份（YYYYMM），9999 年 12 月之後或格式不正確時為 None
+pub(crate) fn next_year_month(month: &str) -> Option<String> {
+    year_month_index(month)
+        .map(|index| year_month_from_index(index + 1))
+        .filter(|next| is_valid_year_month(next))
+}
+
+/// 獲取贊助趨勢：period = monthly | quarterly | yearly
+pub async fn get_donation_trend(
+    Path(period): Path<String>,
+    Query(params): Query<MonthlyDonateStatsQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<Vec<DonationTrendPoint>>>, (StatusCode, Json<ApiResponse<Vec<DonationTrendPoint>>>)> {
+    if !matches!(period.as_str(), "monthly" | "quarterly" | "yearly") {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!(
+                "無效的期間: {}（僅接受 monthly、quarterly、yearly）",
+                period
+            ))),
+        ));
+    }
+    validate_stats_range(&params)
+        .map_err(|msg| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(msg))))?;
+
+    let query = format!(
+        r#"{}
+        SELECT recordId, month, amount
+        FROM allocations
+        WHERE (? IS NULL OR month >= ?) AND (? IS NULL OR month <= ?)
+        ORDER BY month
+        "#,
+        DONATE_MONTH_ALLOCATIONS_CTE
+    );
+
+    let rows: Vec<(i64, String, i64)> = sqlx::query_as(&query)
+        .bind(&params.donate_type)
+        .bind(&params.donate_type)
+        .bind(&params.from)
+        .bind(&params.from)
+        .bind(&params.to)
+        .bind(&params.to)
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("查詢贊助趨勢失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+            )
+        })?;
+
+    // 過濾格式不正確的月份，避免期間計算出錯
+    let rows: Vec<(i64, String, i64)> = rows
+        .into_iter()
+        .filter(|(_, month, _)| is_valid_year_month(month))
+        .collect();
+
+    let (Some(first), Some(last)) = (
+        params.from.clone().or_else(|| rows.first().map(|r| r.1.clone())),
+        params.to.clone().or_else(|| rows.last().map(|r| r.1.clone())),
+    ) else {
+        return Ok(Json(ApiResponse::success(Vec::new())));
+    };
+
+    let (Some(first_index), Some(last_index)) = (year_month_index(&first), year_month_index(&last))
+    else {
+        return Ok(Json(ApiResponse::success(Vec::new())));
+    };
+    if last_index - first_index >= MAX_TREND_MONTHS {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!(
+                "月份範圍過長: {} ~ {}（最多 {} 個月）",
+                first, last, MAX_TREND_MONTHS
+            ))),
+        ));
+    }
+
+    // 依序列出期間（期間之間沒有資料也補零）
+    let mut periods: Vec<String> = Vec::new();
+    for index in first_index..=last_index {
+        let key = trend_period_key(&year_month_from_index(index), &period);
+        if periods.last() != Some(&key) {
+            periods.push(key);
+        }
+    }
+
+    let mut totals: HashMap<String, (HashSet<i64>, i64)> = HashMap::new();
+    for (record_id, month, amount) in &rows {
+        let entry = totals.entry(trend_period_key(month, &period)).or_default();
+        entry.0.insert(*record_id);
+        entry.1 += amount;
+    }
+
+    let mut trend: Vec<DonationTrendPoint> = Vec::with_capacity(periods.len());
+    let mut previous: Option<i64> = None;
+    for key in periods {
+        let (count, total_amount) = totals
+            .get(&key)
+            .map(|(donors, amount)| (donors.len() as i64, *amount))
+            .unwrap_or((0, 0));
+        let change = previous.map(|p| total_amount - p).unwrap_or(0);
+        let change_rate = previous
+            .filter(|p| *p != 0)
+            .map(|p| ((total_amount - p) as f64 * 10000.0 / p as f64).round() / 100.0);
+        trend.push(DonationTrendPoint {
+            period: key,
+            count,
+            total_amount,
+            change,
+            change_rate,
+        });
+        previous = Some(total_amount);
+    }
+
+    Ok(Json(ApiResponse::success(trend)))
+}
+
+/// 贊助者與關聯報名表聯絡資料（registrationId 為 -1 表示未關聯）
+#[derive(sqlx::FromRow)]
+struct DonorMonthsRow {
+    id: i64,
+    name: Option<String>,
+    #[sqlx(rename = "donateId")]
+    donate_id: Option<String>,
+    #[sqlx(rename = "donateType")]
+    donate_type: Option<String>,
+    #[sqlx(rename = "registrationId")]
+    registration_id: Option<i64>,
+    #[sqlx(rename = "donateItems")]
+    donate_items: JsonColumn<Vec<DonateItem>>,
+    contact: JsonColumn<DonorContact>,
+}
+
+impl DonorMonthsRow {
+    /// 所有項目涵蓋的有效月份（去重、排序）
+    fn months(&self) -> BTreeSet<String> {
+        self.donate_items
+            .as_valid()
+            .into_iter()
+            .flatten()
+            .flat_map(|item| item.months.iter())
+            .filter(|m| is_valid_year_month(m))
+            .cloned()
+            .collect()
+    }
+
+    fn contact(&self) -> Option<DonorContact> {
+        self.contact.as_valid().cloned()
+    }
+}
+
+async fn load_donor_months(
+    pool: &SqlitePool,
+    donate_type: &Option<String>,
+) -> Result<Vec<DonorMonthsRow>, sqlx::Error> {
+    sqlx::query_as::<_, DonorMonthsRow>(
+        r#"
+        SELECT d.id, d.name, d.donateId, d.donateType, d.registrationId, d.donateItems, r.contact
+        FROM monthlyDonateDB d
+        LEFT JOIN registrationDB r
+            ON r.id = d.registrationId AND d.registrationId != -1 AND r.date_deleted IS NULL
+        WHERE d.date_deleted IS NULL
+          AND (? IS NULL OR COALESCE(d.donateType, '') = ?)
+        ORDER BY d.id
+        "#,
+    )
+    .bind(donate_type)
+    .bind(donate_type)
+    .fetch_all(pool)
+    .await
+}
+
+/// 續約名單：最後贊助月份落在本月起 within 個月內的贊助者
+pub async fn get_renewal_list(
+    Query(params): Query<RenewalQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<Vec<RenewalCandidate>>>, (StatusCode, Json<ApiResponse<Vec<RenewalCandidate>>>)> {
+    let within = params.within.unwrap_or(2);
+    if !(0..=120).contains(&within) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error("within 必須介於 0 到 120 之間".to_string())),
+        ));
+    }
+
+    let rows = load_donor_months(&pool, &params.donate_type).await.map_err(|e| {
+        tracing::error!("查詢續約名單失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+        )
+    })?;
+
+    let current_month = chrono::Local::now().format("%Y%m").to_string();
+    let current = year_month_index(&current_month).ok_or_else(|| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("無效的目前月份: {}", current_month))),
+        )
+    })?;
+
+    let mut candidates: Vec<RenewalCandidate> = rows
+        .iter()
+        .filter_map(|row| {
+            let months = row.months();
+            let last_month = months.last()?.clone();
+            // months() 只含有效月份
+            let months_remaining = i64::from(year_month_index(&last_month)? - current);
+            if months_remaining > within || (months_remaining < 0 && !params.include_expired) {
+                return None;
+            }
+            Some(RenewalCandidate {
+                id: row.id,
+                name: row.name.clone(),
+                donate_id: row.donate_id.clone(),
+                donate_type: row.donate_type.clone(),
+                registration_id: row.registration_id,
+                last_month,
+                months_remaining,
+                total_months: months.len() as i64,
+                contact: row.contact(),
+            })
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| a.last_month.cmp(&b.last_month).then(a.id.cmp(&b.id)));
+
+    Ok(Json(ApiResponse::success(candidates)))
+}
+
+/// 缺月名單：第一個與最後一個贊助月份之間有未贊助月份的贊助者
+pub async fn get_donate_gaps(
+    Query(params): Query<GapQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<Vec<DonateGap>>>, (StatusCode, Json<ApiResponse<Vec<DonateGap>>>)> {
+    let rows = load_donor_months(&pool, &params.donate_type).await.map_err(|e| {
+        tracing::error!("查詢缺月名單失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+        )
+    })?;
+
+    let gaps: Vec<DonateGap> = rows
+        .iter()
+        .filter_map(|row| {
+            let months = row.months();
+            let first_month = months.first()?.clone();
+            let last_month = months.last()?.clone();
+
+            let missing_months: Vec<String> = (year_month_index(&first_month)?
+                ..year_month_index(&last_month)?)
+                .map(year_month_from_index)
+                .filter(|month| !months.contains(month))
+                .collect();
+            if missing_months.is_empty() {
+                return None;
+            }
+
+            Some(DonateGap {
+                id: row.id,
+                name: row.name.clone(),
+                donate_id: row.donate_id.clone(),
+                donate_type: row.donate_type.clone(),
+                registration_id: row.registration_id,
+                first_month,
+                last_month,
+                missing_months,
+                contact: row.contact(),
+            })
+        })
+        .collect();
+
+    Ok(Json(ApiResponse::success(gaps)))
+}
+
+/// 每月分攤明細帳：每個贊助項目的每個月份一列（分攤規則見 DONATE_MONTH_ALLOCATIONS_CTE）
+pub async fn get_monthly_donate_ledger(
+    Query(params): Query<MonthlyDonateLedgerQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<Vec<MonthlyDonateLedgerEntry>>>, (StatusCode, Json<ApiResponse<Vec<MonthlyDonateLedgerEntry>>>)> {
+    for month in [&params.from, &params.to].into_iter().flatten() {
+        if !is_valid_year_month(month) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::error(format!("無效的月份格式: {}（應為 YYYYMM）", month))),
+            ));
+        }
+    }
+
+    let limit = params.limit.unwrap_or(1000);
+    let offset = params.offset.unwrap_or(0);
+
+    let map_db_error = |e: sqlx::Error| {
+        tracing::error!("查詢每月分攤明細失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+        )
+    };
+
+    let filters = r#"
+        WHERE (? IS NULL OR a.month >= ?)
+          AND (? IS NULL OR a.month <= ?)
+          AND (? IS NULL OR a.recordId = ?)
+          AND (? IS NULL OR d.donateId = ?)
+    "#;
+
+    let count_query = format!(
+        "{} SELECT COUNT(*), COALESCE(SUM(a.amount), 0) FROM allocations a JOIN monthlyDonateDB d ON d.id = a.recordId {}",
+        DONATE_MONTH_ALLOCATIONS_CTE, filters
+    );
+    let (total, total_amount): (i64, i64) = sqlx::query_as(&count_query)
+        .bind(&params.donate_type)
+        .bind(&params.donate_type)
+        .bind(&params.from)
+        .bind(&params.from)
+        .bind(&params.to)
+        .bind(&params.to)
+        .bind(params.record_id)
+        .bind(params.record_id)
+        .bind(&params.donate_id)
+        .bind(&params.donate_id)
+        .fetch_one(&pool)
+        .await
+        .map_err(map_db_error)?;
+
+    let query = format!(
+        r#"{}
+        SELECT
+            a.recordId,
+            d.name,
+            d.donateId,
+            a.donateType,
+            a.donateItemsId,
+            a.month,
+            a.amount,
+            a.price AS itemPrice,
+            a.monthCount
+        FROM allocations a
+        JOIN monthlyDonateDB d ON d.id = a.recordId
+        {}
+        ORDER BY a.month, a.recordId, a.itemKey
+        LIMIT ? OFFSET ?
+        "#,
+        DONATE_MONTH_ALLOCATIONS_CTE, filters
+    );
+    let entries = sqlx::query_as::<_, MonthlyDonateLedgerEntry>(&query)
+        .bind(&params.donate_type)
+        .bind(&params.donate_type)
+        .bind(&params.from)
+        .bind(&params.from)
+        .bind(&params.to)
+        .bind(&params.to)
+        .bind(params.record_id)
+        .bind(params.record_id)
+        .bind(&params.donate_id)
+        .bind(&params.donate_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&pool)
+        .await
+        .map_err(map_db_error)?;
+
+    Ok(Json(ApiResponse {
+        success: true,
+        data: Some(entries),
+        message: Some(format!("分攤金額合計 {} 元", total_amount)),
+        meta: Some(Meta {
+            total,
+            limit: Some(limit),
+            offset: Some(offset),
+        }),
+        errors: None,
+    }))
+}
+
+const MONTHLY_DONATE_EXPORT_HEADERS: &[&str] = &[
+    "記錄ID", "贊助編號", "姓名", "贊助類型", "報名ID", "項目ID", "金額", "月數",
+    "贊助月份", "起始月份", "結束月份", "項目建立時間", "項目建立者", "備註", "建立時間",
+];
+
+/// 每個贊助項目展開為一列；沒有項目的記錄仍輸出一列基本資料
+fn monthly_donate_export_rows(record: MonthlyDonate) -> Vec<ExportRow> {
+    let items = record
+        .donate_items
+        .clone()
+        .into_reported("monthlyDonateDB.donateItems", record.id)
+        .unwrap_or_default();
+
+    let base = |item: Option<&DonateItem>| -> ExportRow {
+        let mut months: Vec<String> = item.map(|i| i.months.clone()).unwrap_or_default();
+        months.sort();
+
+        vec![
+            record.id.into(),
+            record.donate_id.clone().into(),
+            record.name.clone().into(),
+            record.donate_type.clone().into(),
+            record.registration_id.into(),
+            item.map(|i| i.donate_items_id.clone()).into(),
+            item.map(|i| i.price).into(),
+            item.map(|_| months.len() as i64).into(),
+            months.join("、").into(),
+            months.first().cloned().into(),
+            months.last().cloned().into(),
+            item.map(|i| i.created_at.clone()).into(),
+            item.map(|i| i.created_user.clone()).into(),
+            record.memo.clone().into(),
+            record.created_at.clone().into(),
+        ]
+    };
+
+    if items.is_empty() {
+        vec![base(None)]
+    } else {
+        items.iter().map(|item| base(Some(item))).collect()
+    }
+}
+
+/// 匯出每月捐款記錄：format=csv|xlsx|json，篩選條件同列表
+pub async fn export_monthly_donates(
+    Query(params): Query<MonthlyDonateExportQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Response, (StatusCode, Json<ApiResponse<()>>)> {
+    let format = ExportFormat::parse(params.format.as_deref())
+        .map_err(|msg| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(msg))))?;
+
+    let mut query = format!("{} WHERE 1=1", MONTHLY_DONATE_FULL_QUERY);
+    let mut bindings: Vec<String> = Vec::new();
+
+    if let Some(name) = &params.name {
+        query.push_str(" AND name LIKE ?");
+        bindings.push(format!("%{}%", name));
+    }
+    if let Some(registration_id) = params.registration_id {
+        query.push_str(" AND registrationId = ?");
+        bindings.push(registration_id.to_string());
+    }
+    if let Some(donate_id) = &params.donate_id {
+        query.push_str(" AND donateId = ?");
+        bindings.push(donate_id.clone());
+    }
+    if let Some(donate_type) = &params.donate_type {
+        query.push_str(" AND donateType = ?");
+        bindings.push(donate_type.clone());
+    }
+    query.push_str(
+        &order_by_clause(params.sort.as_deref(), "createdAt DESC")
+            .map_err(|msg| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(msg))))?,
+    );
+
+    let rows = spawn_export_rows(pool, query, bindings, monthly_donate_export_rows);
+    export_response(format, "monthly-donates", MONTHLY_DONATE_EXPORT_HEADERS.to_vec(), rows)
+        .await
+        .map_err(|e| {
+            tracing::error!("匯出每月捐款記錄失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("匯出失敗: {}", e))),
+            )
+        })
+}
@@ -7,10 +7,13 @@ use axum::{
 use sqlx::SqlitePool;
 
 use crate::models::api_response::{ApiResponse, Meta};
+use crate::models::soft_delete::DeleteQuery;
+use crate::handlers::soft_delete;
 use crate::models::my_data::{
     CreateMyDataRequest, MyData, MyDataResponse, MyDataQuery, UpdateMyDataRequest,
 };
 
+/// MyData 查詢（排除已刪除）
 const MY_DATA_FULL_QUERY: &str = r#"
 SELECT 
     id,
@@ -29,7 +32,7 @@ SELECT
     state,
     formName,
     contact
-FROM mydata
+FROM (SELECT * FROM mydata WHERE date_deleted IS NULL) AS mydata
 "#;
 
 pub async fn get_all_my_data(
@@ -37,7 +40,7 @@ pub async fn get_all_my_data(
     Extension(pool): Extension<SqlitePool>,
 ) -> Result<Json<ApiResponse<Vec<MyDataResponse>>>, (StatusCode, Json<ApiResponse<Vec<MyDataResponse>>>)> {
     let mut query = format!("{} WHERE 1=1", MY_DATA_FULL_QUERY);
-    let mut count_query = String::from("SELECT COUNT(*) FROM mydata WHERE 1=1");
+    let mut count_query = String::from("SELECT COUNT(*) FROM mydata WHERE date_deleted IS NULL");
 
     if let Some(state) = &params.state {
         let condition = format!(" AND state = '{}'", state);
@@ -205,7 +208,7 @@ pub async fn update_my_data(
     Extension(pool): Extension<SqlitePool>,
     Json(payload): Json<UpdateMyDataRequest>,
 ) -> Result<Json<ApiResponse<MyDataResponse>>, (StatusCode, Json<ApiResponse<MyDataResponse>>)> {
-    let exists: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM mydata WHERE id = ?")
+    let exists: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM mydata WHERE id = ? AND date_deleted IS NULL")
         .bind(&id)
         .fetch_one(&pool)
         .await
@@ -287,11 +290,10 @@ pub async fn update_my_data(
 
 pub async fn delete_my_data(
     Path(id): Path<String>,
+    Query(params): Query<DeleteQuery>,
     Extension(pool): Extension<SqlitePool>,
 ) -> Result<Json<ApiResponse<()>>, (StatusCode, Json<ApiResponse<()>>)> {
-    let result = sqlx::query("DELETE FROM mydata WHERE id = ?")
-        .bind(&id)
-        .execute(&pool)
+    let deleted = soft_delete::soft_delete(&pool, "mydata", id.clone(), params.user_deleted.as_deref())
         .await
         .map_err(|e| {
             tracing::error!("刪除 myData 失敗: {}", e);
@@ -301,7 +303,7 @@ pub async fn delete_my_data(
             )
         })?;
 
-    if result.rows_affected() == 0 {
+    if !deleted {
         return Err((
             StatusCode::NOT_FOUND,
             Json(ApiResponse::error(format!("找不到 ID 為 {} 的記錄", id))),
@@ -316,3 +318,30 @@ pub async fn delete_my_data(
         errors: None,
     }))
 }
+
+/// 還原已刪除的記錄
+pub async fn restore_my_data(
+    Path(id): Path<String>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<MyDataResponse>>, (StatusCode, Json<ApiResponse<MyDataResponse>>)> {
+    let restored = soft_delete::restore(&pool, "mydata", id.clone())
+        .await
+        .map_err(|e| {
+            tracing::error!("還原 myData 失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("還原失敗: {}", e))),
+            )
+        })?;
+
+    if !restored {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!("找不到 ID 為 {} 的已刪除記錄", id))),
+        ));
+    }
+
+    let Json(mut response) = get_my_data_by_id(Path(id), Extension(pool)).await?;
+    response.message = Some("成功還原記錄".to_string());
+    Ok(Json(response))
+}
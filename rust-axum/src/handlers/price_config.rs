@@ -7,10 +7,13 @@ use axum::{
 use sqlx::SqlitePool;
 
 use crate::models::api_response::{ApiResponse, Meta};
+use crate::models::soft_delete::DeleteQuery;
+use crate::handlers::soft_delete;
 use crate::models::price_config::{
     CreatePriceConfigRequest, PriceConfig, PriceConfigResponse, PriceConfigQuery, UpdatePriceConfigRequest,
 };
 
+/// 價格配置查詢，已刪除的配置不會被取用
 const PRICE_CONFIG_FULL_QUERY: &str = r#"
 SELECT 
     id,
@@ -33,7 +36,7 @@ SELECT
     enableDate,
     createdAt,
     updatedAt
-FROM priceConfigDB
+FROM (SELECT * FROM priceConfigDB WHERE date_deleted IS NULL) AS priceConfigDB
 "#;
 
 pub async fn get_all_price_configs(
@@ -41,7 +44,7 @@ pub async fn get_all_price_configs(
     Extension(pool): Extension<SqlitePool>,
 ) -> Result<Json<ApiResponse<Vec<PriceConfigResponse>>>, (StatusCode, Json<ApiResponse<Vec<PriceConfigResponse>>>)> {
     let mut query = format!("{} WHERE 1=1", PRICE_CONFIG_FULL_QUERY);
-    let mut count_query = String::from("SELECT COUNT(*) FROM priceConfigDB WHERE 1=1");
+    let mut count_query = String::from("SELECT COUNT(*) FROM priceConfigDB WHERE date_deleted IS NULL");
 
     if let Some(version) = &params.version {
         let condition = format!(" AND version LIKE '%{}%'", version);
@@ -213,7 +216,7 @@ pub async fn update_price_config(
     Extension(pool): Extension<SqlitePool>,
     Json(payload): Json<UpdatePriceConfigRequest>,
 ) -> Result<Json<ApiResponse<PriceConfigResponse>>, (StatusCode, Json<ApiResponse<PriceConfigResponse>>)> {
-    let exists: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM priceConfigDB WHERE id = ?")
+    let exists: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM priceConfigDB WHERE id = ? AND date_deleted IS NULL")
         .bind(&id)
         .fetch_one(&pool)
         .await
@@ -311,11 +314,10 @@ pub async fn update_price_config(
 
 pub async fn delete_price_config(
     Path(id): Path<i64>,
+    Query(params): Query<DeleteQuery>,
     Extension(pool): Extension<SqlitePool>,
 ) -> Result<Json<ApiResponse<()>>, (StatusCode, Json<ApiResponse<()>>)> {
-    let result = sqlx::query("DELETE FROM priceConfigDB WHERE id = ?")
-        .bind(&id)
-        .execute(&pool)
+    let deleted = soft_delete::soft_delete(&pool, "priceConfigDB", id, params.user_deleted.as_deref())
         .await
         .map_err(|e| {
             tracing::error!("刪除 priceConfig 失敗: {}", e);
@@ -325,7 +327,7 @@ pub async fn delete_price_config(
             )
         })?;
 
-    if result.rows_affected() == 0 {
+    if !deleted {
         return Err((
             StatusCode::NOT_FOUND,
             Json(ApiResponse::error(format!("找不到 ID 為 {} 的記錄", id))),
@@ -339,4 +341,31 @@ pub async fn delete_price_config(
         meta: None,
         errors: None,
     }))
-}
\ No newline at end of file
+}
+
+/// 還原已刪除的價格配置
+pub async fn restore_price_config(
+    Path(id): Path<i64>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<PriceConfigResponse>>, (StatusCode, Json<ApiResponse<PriceConfigResponse>>)> {
+    let restored = soft_delete::restore(&pool, "priceConfigDB", id)
+        .await
+        .map_err(|e| {
+            tracing::error!("還原 priceConfig 失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("還原失敗: {}", e))),
+            )
+        })?;
+
+    if !restored {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!("找不到 ID 為 {} 的已刪除價格配置", id))),
+        ));
+    }
+
+    let Json(mut response) = get_price_config_by_id(Path(id), Extension(pool)).await?;
+    response.message = Some("成功還原價格配置".to_string());
+    Ok(Json(response))
+}
@@ -13,7 +13,7 @@ use crate::models::receipt_number::{
     ReceiptNumberQuery, UpdateReceiptStatusRequest, MergedReceiptRequest
 };
 
-const RECEIPT_FULL_QUERY: &str = r#"
+pub(crate) const RECEIPT_FULL_QUERY: &str = r#"
 SELECT 
     id,
     user_created,
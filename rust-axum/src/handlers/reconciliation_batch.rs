@@ -0,0 +1,579 @@
+// src/handlers/reconciliation_batch.rs
+use axum::{
+    extract::{Extension, Path, Query},
+    http::StatusCode,
+    Json,
+};
+use sqlx::{SqliteConnection, SqlitePool};
+
+use crate::models::api_response::{ApiResponse, Meta};
+use crate::models::reconciliation_batch::{
+    CreateReconciliationBatchRequest, ReconciliationBatch, ReconciliationBatchQuery,
+    ReconciliationBatchResponse, ReconciliationLine, ReconciliationPreview,
+    ReconciliationPreviewQuery, VoidReconciliationBatchRequest,
+};
+
+const RECONCILIATION_BATCH_FULL_QUERY: &str = r#"
+SELECT
+    id,
+    user_created,
+    CASE
+        WHEN date_created IS NOT NULL
+        THEN datetime(date_created / 1000, 'unixepoch')
+        ELSE NULL
+    END as date_created,
+    user_updated,
+    CASE
+        WHEN date_updated IS NOT NULL
+        THEN datetime(date_updated / 1000, 'unixepoch')
+        ELSE NULL
+    END as date_updated,
+    batchNumber,
+    batchDate,
+    paymentMethod,
+    expectedCash,
+    expectedTransfer,
+    expectedOther,
+    expectedTotal,
+    countedAmount,
+    variance,
+    recordCount,
+    recordIds,
+    state,
+    reconciledBy,
+    notes,
+    voidReason,
+    createdAt,
+    updatedAt
+FROM reconciliationBatchDB
+"#;
+
+/// 當日尚未沖帳的入帳明細
+/// 有付款流水的記錄以 joinRecordPayments 當日尚未沖帳的流水合計為準（分次付款逐筆沖帳），
+/// 舊記錄（無流水）以 paidAmount 計，並以記錄的 accountingState 判斷是否已沖帳
+/// 依業務規則只納入 paymentState 為 paid / partial 的記錄
+const RECONCILIATION_LINES_QUERY: &str = r#"
+WITH day_amounts AS (
+    SELECT p.joinRecordId AS recordId, COALESCE(p.paymentMethod, '') AS method, SUM(p.amount) AS amount
+    FROM joinRecordPayments p
+    WHERE date(p.paymentDate, 'localtime') = ?
+      AND COALESCE(p.reconciliationBatchId, -1) = -1
+    GROUP BY p.joinRecordId, COALESCE(p.paymentMethod, '')
+    UNION ALL
+    SELECT j.id, COALESCE(j.paymentMethod, ''), COALESCE(j.paidAmount, 0)
+    FROM joinRecordDB j
+    WHERE date(j.paymentDate, 'localtime') = ?
+      AND COALESCE(j.accountingState, '') != 'reconciled'
+      AND NOT EXISTS (SELECT 1 FROM joinRecordPayments p WHERE p.joinRecordId = j.id)
+)
+SELECT d.recordId, d.method, d.amount
+FROM day_amounts d
+JOIN joinRecordDB j ON j.id = d.recordId
+WHERE j.date_deleted IS NULL
+  AND j.paymentState IN ('paid', 'partial')
+  AND (? IS NULL OR d.method = ?)
+ORDER BY d.recordId, d.method
+"#;
+
+/// 查詢當日可沖帳的入帳明細並計算應收金額
+async fn build_preview(
+    conn: &mut SqliteConnection,
+    batch_date: &str,
+    payment_method: Option<&str>,
+) -> Result<ReconciliationPreview, sqlx::Error> {
+    let rows: Vec<(i64, String, i64)> = sqlx::query_as(RECONCILIATION_LINES_QUERY)
+        .bind(batch_date)
+        .bind(batch_date)
+        .bind(payment_method)
+        .bind(payment_method)
+        .fetch_all(&mut *conn)
+        .await?;
+
+    let lines: Vec<ReconciliationLine> = rows
+        .into_iter()
+        .map(|(join_record_id, payment_method, amount)| ReconciliationLine {
+            join_record_id,
+            payment_method,
+            amount,
+        })
+        .collect();
+
+    Ok(summarize_lines(batch_date, payment_method, lines))
+}
+
+/// 實點金額對應的應收金額：指定付款方式時為該方式的應收，全部付款方式時只清點現金
+fn counted_expected(preview: &ReconciliationPreview) -> i64 {
+    match preview.payment_method.as_deref() {
+        Some("transfer") => preview.expected_transfer,
+        Some("cash") | None => preview.expected_cash,
+        Some(_) => preview.expected_other,
+    }
+}
+
+/// 依付款方式加總明細
+fn summarize_lines(
+    batch_date: &str,
+    payment_method: Option<&str>,
+    lines: Vec<ReconciliationLine>,
+) -> ReconciliationPreview {
+    let mut expected_cash = 0;
+    let mut expected_transfer = 0;
+    let mut expected_other = 0;
+    let mut record_ids: Vec<i64> = Vec::new();
+
+    for line in &lines {
+        match line.payment_method.as_str() {
+            "cash" => expected_cash += line.amount,
+            "transfer" => expected_transfer += line.amount,
+            _ => expected_other += line.amount,
+        }
+        if !record_ids.contains(&line.join_record_id) {
+            record_ids.push(line.join_record_id);
+        }
+    }
+
+    ReconciliationPreview {
+        batch_date: batch_date.to_string(),
+        payment_method: payment_method.map(|m| m.to_string()),
+        expected_cash,
+        expected_transfer,
+        expected_other,
+        expected_total: expected_cash + expected_transfer + expected_other,
+        record_count: record_ids.len() as i64,
+        record_ids,
+        lines,
+    }
+}
+
+fn validate_batch_date(batch_date: &str) -> Result<(), String> {
+    chrono::NaiveDate::parse_from_str(batch_date, "%Y-%m-%d")
+        .map(|_| ())
+        .map_err(|_| format!("無效的日期格式: {}（應為 YYYY-MM-DD）", batch_date))
+}
+
+/// 獲取所有沖帳批次
+pub async fn get_all_reconciliation_batches(
+    Query(params): Query<ReconciliationBatchQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<Vec<ReconciliationBatchResponse>>>, (StatusCode, Json<ApiResponse<Vec<ReconciliationBatchResponse>>>)> {
+    let mut query = format!("{} WHERE 1=1", RECONCILIATION_BATCH_FULL_QUERY);
+    let mut count_query = String::from("SELECT COUNT(*) FROM reconciliationBatchDB WHERE 1=1");
+    let mut bindings: Vec<String> = Vec::new();
+
+    if let Some(batch_date) = &params.batch_date {
+        query.push_str(" AND batchDate = ?");
+        count_query.push_str(" AND batchDate = ?");
+        bindings.push(batch_date.clone());
+    }
+
+    if let Some(state) = &params.state {
+        query.push_str(" AND state = ?");
+        count_query.push_str(" AND state = ?");
+        bindings.push(state.clone());
+    }
+
+    query.push_str(" ORDER BY batchDate DESC, id DESC");
+
+    let limit = params.limit.unwrap_or(100);
+    let offset = params.offset.unwrap_or(0);
+    query.push_str(&format!(" LIMIT {} OFFSET {}", limit, offset));
+
+    let mut query_builder = sqlx::query_as::<_, ReconciliationBatch>(&query);
+    let mut count_builder = sqlx::query_as::<_, (i64,)>(&count_query);
+    for binding in &bindings {
+        query_builder = query_builder.bind(binding);
+        count_builder = count_builder.bind(binding);
+    }
+
+    let batches = query_builder.fetch_all(&pool).await.map_err(|e| {
+        tracing::error!("查詢沖帳批次失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+        )
+    })?;
+
+    let total = count_builder.fetch_one(&pool).await.map_err(|e| {
+        tracing::error!("查詢沖帳批次總數失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢總數失敗: {}", e))),
+        )
+    })?;
+
+    let responses: Vec<ReconciliationBatchResponse> = batches
+        .into_iter()
+        .map(|batch| batch.into())
+        .collect();
+
+    Ok(Json(ApiResponse::success_with_meta(
+        responses,
+        Meta {
+            total: total.0,
+            limit: Some(limit),
+            offset: Some(offset),
+        },
+    )))
+}
+
+/// 根據 ID 獲取沖帳批次
+pub async fn get_reconciliation_batch_by_id(
+    Path(id): Path<i64>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<ReconciliationBatchResponse>>, (StatusCode, Json<ApiResponse<ReconciliationBatchResponse>>)> {
+    let query = format!("{} WHERE id = ?", RECONCILIATION_BATCH_FULL_QUERY);
+    let batch = sqlx::query_as::<_, ReconciliationBatch>(&query)
+        .bind(id)
+        .fetch_optional(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("查詢沖帳批次失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+            )
+        })?;
+
+    match batch {
+        Some(batch) => Ok(Json(ApiResponse::success(batch.into()))),
+        None => Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!("找不到 ID 為 {} 的沖帳批次", id))),
+        )),
+    }
+}
+
+/// 沖帳預覽：列出當日可沖帳記錄與應收現金/轉帳金額（不寫入）
+pub async fn preview_reconciliation_batch(
+    Query(params): Query<ReconciliationPreviewQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<ReconciliationPreview>>, (StatusCode, Json<ApiResponse<ReconciliationPreview>>)> {
+    validate_batch_date(&params.date)
+        .map_err(|msg| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(msg))))?;
+
+    let mut conn = pool.acquire().await.map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("獲取連接失敗: {}", e))),
+        )
+    })?;
+
+    let preview = build_preview(&mut conn, &params.date, params.payment_method.as_deref())
+        .await
+        .map_err(|e| {
+            tracing::error!("查詢沖帳預覽失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+            )
+        })?;
+
+    Ok(Json(ApiResponse::success(preview)))
+}
+
+/// 🔥 核心功能：建立沖帳批次，原子性地將所有納入的參加記錄標記為 reconciled
+pub async fn create_reconciliation_batch(
+    Extension(pool): Extension<SqlitePool>,
+    Json(payload): Json<CreateReconciliationBatchRequest>,
+) -> Result<Json<ApiResponse<ReconciliationBatchResponse>>, (StatusCode, Json<ApiResponse<ReconciliationBatchResponse>>)> {
+    validate_batch_date(&payload.batch_date)
+        .map_err(|msg| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(msg))))?;
+
+    // 1. 開始資料庫事務
+    let mut tx = pool.begin().await.map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("啟動事務失敗: {}", e))))
+    })?;
+
+    // 2. 在事務中重新計算應收金額，避免與預覽之間的資料變動
+    let preview = build_preview(&mut tx, &payload.batch_date, payload.payment_method.as_deref())
+        .await
+        .map_err(|e| {
+            tracing::error!("計算沖帳金額失敗: {}", e);
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("計算沖帳金額失敗: {}", e))))
+        })?;
+
+    // 3. 指定 recordIds 時只沖帳其中的記錄
+    let preview = match &payload.record_ids {
+        Some(record_ids) => {
+            let missing: Vec<String> = record_ids
+                .iter()
+                .filter(|id| !preview.record_ids.contains(id))
+                .map(|id| id.to_string())
+                .collect();
+            if !missing.is_empty() {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    Json(ApiResponse::error_with_details(
+                        "部分記錄不在當日可沖帳範圍內".to_string(),
+                        missing.iter().map(|id| format!("recordId {} 不可沖帳", id)).collect(),
+                    )),
+                ));
+            }
+            let lines = preview
+                .lines
+                .into_iter()
+                .filter(|line| record_ids.contains(&line.join_record_id))
+                .collect();
+            summarize_lines(&payload.batch_date, payload.payment_method.as_deref(), lines)
+        }
+        None => preview,
+    };
+
+    if preview.record_ids.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!("{} 沒有可沖帳的記錄", payload.batch_date))),
+        ));
+    }
+
+    // 4. 產生批號：RC + YYYYMMDD + '-' + 當日流水號
+    let day_count: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM reconciliationBatchDB WHERE batchDate = ?")
+        .bind(&payload.batch_date)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("獲取批號失敗: {}", e))))
+        })?;
+    let batch_number = format!(
+        "RC{}-{:02}",
+        payload.batch_date.replace('-', ""),
+        day_count.0 + 1
+    );
+
+    let now_iso = chrono::Utc::now().to_rfc3339();
+    let now_timestamp = chrono::Utc::now().timestamp_millis();
+    let variance = payload.counted_amount - counted_expected(&preview);
+    let record_ids_json = serde_json::to_string(&preview.record_ids).unwrap_or_else(|_| "[]".to_string());
+
+    // 5. 寫入批次
+    let insert_result = sqlx::query(
+        r#"
+        INSERT INTO reconciliationBatchDB (
+            batchNumber, batchDate, paymentMethod, expectedCash, expectedTransfer,
+            expectedOther, expectedTotal, countedAmount, variance, recordCount,
+            recordIds, state, reconciledBy, notes, createdAt, updatedAt,
+            user_created, date_created
+        ) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, 'reconciled', ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(&batch_number)
+    .bind(&payload.batch_date)
+    .bind(&payload.payment_method)
+    .bind(preview.expected_cash)
+    .bind(preview.expected_transfer)
+    .bind(preview.expected_other)
+    .bind(preview.expected_total)
+    .bind(payload.counted_amount)
+    .bind(variance)
+    .bind(preview.record_count)
+    .bind(&record_ids_json)
+    .bind(&payload.reconciled_by)
+    .bind(&payload.notes)
+    .bind(&now_iso)
+    .bind(&now_iso)
+    .bind(&payload.user_id)
+    .bind(now_timestamp)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!("建立沖帳批次失敗: {}", e);
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("建立沖帳批次失敗: {}", e))))
+    })?;
+
+    let batch_id = insert_result.last_insert_rowid();
+
+    // 6. 標記本批沖帳的付款流水，構建動態展開 IN (?, ?, ?)
+    let placeholders = preview.record_ids.iter().map(|_| "?").collect::<Vec<_>>().join(", ");
+    let payment_sql = format!(
+        "UPDATE joinRecordPayments SET reconciliationBatchId = ?, updatedAt = ?, date_updated = ?, user_updated = ? WHERE COALESCE(reconciliationBatchId, -1) = -1 AND date(paymentDate, 'localtime') = ? AND (? IS NULL OR COALESCE(paymentMethod, '') = ?) AND joinRecordId IN ({})",
+        placeholders
+    );
+    let mut q = sqlx::query(&payment_sql)
+        .bind(batch_id)
+        .bind(&now_iso)
+        .bind(now_timestamp)
+        .bind(&payload.user_id)
+        .bind(&payload.batch_date)
+        .bind(&payload.payment_method)
+        .bind(&payload.payment_method);
+    for id in &preview.record_ids {
+        q = q.bind(id);
+    }
+    q.execute(&mut *tx).await.map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("同步更新付款流水失敗: {}", e))))
+    })?;
+
+    // 7. 同步更新參加記錄的會計狀態
+    // 有流水的記錄可能已由先前批次沖帳（分次付款），以本批是否標記到其流水判斷；舊記錄需尚未沖帳
+    let sql = format!(
+        "UPDATE joinRecordDB SET accountingState = 'reconciled', accountingDate = ?, accountingBy = ?, reconciliationBatchId = ?, updatedAt = ?, date_updated = ?, user_updated = ? WHERE id IN ({}) AND (COALESCE(accountingState, '') != 'reconciled' OR EXISTS (SELECT 1 FROM joinRecordPayments p WHERE p.joinRecordId = joinRecordDB.id AND p.reconciliationBatchId = ?))",
+        placeholders
+    );
+
+    let mut q = sqlx::query(&sql)
+        .bind(&now_iso)
+        .bind(&payload.reconciled_by)
+        .bind(batch_id)
+        .bind(&now_iso)
+        .bind(now_timestamp)
+        .bind(&payload.user_id);
+
+    for id in &preview.record_ids {
+        q = q.bind(id);
+    }
+    q = q.bind(batch_id);
+
+    let update_result = q.execute(&mut *tx).await.map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("同步更新參加記錄失敗: {}", e))))
+    })?;
+
+    // 其他人同時沖帳了部分記錄時整批放棄
+    if update_result.rows_affected() as usize != preview.record_ids.len() {
+        return Err((
+            StatusCode::CONFLICT,
+            Json(ApiResponse::error(format!(
+                "預期沖帳 {} 筆參加記錄，實際 {} 筆，請重新預覽",
+                preview.record_ids.len(),
+                update_result.rows_affected()
+            ))),
+        ));
+    }
+
+    // 8. 提交事務
+    tx.commit().await.map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("提交事務失敗: {}", e))))
+    })?;
+
+    // 9. 返回新建立的批次
+    let batch = sqlx::query_as::<_, ReconciliationBatch>(&format!("{} WHERE id = ?", RECONCILIATION_BATCH_FULL_QUERY))
+        .bind(batch_id)
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| {
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("查詢沖帳批次失敗: {}", e))))
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        batch.into(),
+        format!(
+            "成功建立沖帳批次 {}，共 {} 筆參加記錄，現金差額 {}",
+            batch_number, preview.record_count, variance
+        ),
+    )))
+}
+
+/// 🔥 作廢沖帳批次（反操作）
+/// 1. reconciliationBatchDB: state 改為 'void'
+/// 2. joinRecordPayments: 本批流水回到未沖帳
+/// 3. joinRecordDB: accountingState 回到 'pending'，清空沖帳欄位（仍有其他批次沖帳的流水時保留）
+pub async fn void_reconciliation_batch(
+    Path(id): Path<i64>,
+    Extension(pool): Extension<SqlitePool>,
+    Json(payload): Json<VoidReconciliationBatchRequest>,
+) -> Result<Json<ApiResponse<ReconciliationBatchResponse>>, (StatusCode, Json<ApiResponse<ReconciliationBatchResponse>>)> {
+    // 1. 開始資料庫事務
+    let mut tx = pool.begin().await.map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("啟動事務失敗: {}", e))))
+    })?;
+
+    let state: Option<(Option<String>,)> = sqlx::query_as("SELECT state FROM reconciliationBatchDB WHERE id = ?")
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("查詢沖帳批次失敗: {}", e))))
+        })?;
+
+    match state {
+        None => {
+            return Err((
+                StatusCode::NOT_FOUND,
+                Json(ApiResponse::error(format!("找不到 ID 為 {} 的沖帳批次", id))),
+            ));
+        }
+        Some((Some(state),)) if state == "void" => {
+            return Err((
+                StatusCode::CONFLICT,
+                Json(ApiResponse::error(format!("沖帳批次 {} 已作廢", id))),
+            ));
+        }
+        Some(_) => {}
+    }
+
+    let now_iso = chrono::Utc::now().to_rfc3339();
+    let now_timestamp = chrono::Utc::now().timestamp_millis();
+    let void_reason = payload.void_reason.clone().unwrap_or_else(|| "作廢沖帳".to_string());
+
+    // 2. 更新批次狀態
+    sqlx::query(
+        "UPDATE reconciliationBatchDB SET state = 'void', voidReason = ?, updatedAt = ?, date_updated = ?, user_updated = ? WHERE id = ?",
+    )
+    .bind(&void_reason)
+    .bind(&now_iso)
+    .bind(now_timestamp)
+    .bind(&payload.user_id)
+    .bind(id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("更新沖帳批次失敗: {}", e))))
+    })?;
+
+    // 3. 還原本批沖帳的付款流水
+    sqlx::query(
+        "UPDATE joinRecordPayments SET reconciliationBatchId = -1, updatedAt = ?, date_updated = ?, user_updated = ? WHERE reconciliationBatchId = ?",
+    )
+    .bind(&now_iso)
+    .bind(now_timestamp)
+    .bind(&payload.user_id)
+    .bind(id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("還原付款流水沖帳狀態失敗: {}", e))))
+    })?;
+
+    // 4. 還原參加記錄的會計狀態；仍有流水屬於其他有效批次時改指向最近的批次
+    sqlx::query(
+        "UPDATE joinRecordDB SET accountingState = 'pending', accountingDate = NULL, accountingBy = NULL, reconciliationBatchId = -1, updatedAt = ?, date_updated = ?, user_updated = ? WHERE reconciliationBatchId = ? AND NOT EXISTS (SELECT 1 FROM joinRecordPayments p WHERE p.joinRecordId = joinRecordDB.id AND p.reconciliationBatchId > 0)",
+    )
+    .bind(&now_iso)
+    .bind(now_timestamp)
+    .bind(&payload.user_id)
+    .bind(id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("還原參加記錄會計狀態失敗: {}", e))))
+    })?;
+    sqlx::query(
+        "UPDATE joinRecordDB SET reconciliationBatchId = (SELECT MAX(p.reconciliationBatchId) FROM joinRecordPayments p WHERE p.joinRecordId = joinRecordDB.id), updatedAt = ?, date_updated = ?, user_updated = ? WHERE reconciliationBatchId = ?",
+    )
+    .bind(&now_iso)
+    .bind(now_timestamp)
+    .bind(&payload.user_id)
+    .bind(id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("還原參加記錄會計狀態失敗: {}", e))))
+    })?;
+
+    // 5. 提交事務
+    tx.commit().await.map_err(|e| {
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("提交事務失敗: {}", e))))
+    })?;
+
+    let batch = sqlx::query_as::<_, ReconciliationBatch>(&format!("{} WHERE id = ?", RECONCILIATION_BATCH_FULL_QUERY))
+        .bind(id)
+        .fetch_one(&pool)
+        .await
+        .map_err(|e| {
+            (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(format!("查詢沖帳批次失敗: {}", e))))
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        batch.into(),
+        format!("成功作廢沖帳批次 {}", id),
+    )))
+}
@@ -2,18 +2,29 @@
 use axum::{
     extract::{Extension, Path, Query},
     http::StatusCode,
+    response::Response,
     Json,
 };
 use sqlx::SqlitePool;
 
 // 導入共享的 API 響應結構
 use crate::models::api_response::{ApiResponse, Meta};
+use crate::models::soft_delete::DeleteQuery;
+use crate::handlers::soft_delete;
 
 use crate::models::registration::{
     CreateRegistrationRequest, Registration, RegistrationResponse, RegistrationQuery, UpdateRegistrationRequest,
+    RegistrationExportQuery, DuplicateCandidate, DuplicateQuery, DuplicateRegistrationSummary,
+    MergeRegistrationRequest, MergeRegistrationResult,
 };
+use crate::models::json_column::JsonColumn;
+use crate::models::json_types::{Ancestor, Blessing, BlessingPerson, Contact, Salvation, Survivor};
+use crate::utils::export::{export_response, order_by_clause, spawn_export_rows, ExportFormat, ExportRow};
+use serde_json::Value as JsonValue;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
-const REGISTRATION_FULL_QUERY: &str = r#"
+/// 報名記錄完整欄位查詢，已刪除的報名以子查詢排除
+pub(crate) const REGISTRATION_FULL_QUERY: &str = r#"
 SELECT 
     id,
     user_created,
@@ -36,8 +47,9 @@ SELECT
     contact,
     blessing,
     createdAt,
-    updatedAt
-FROM registrationDB
+    updatedAt,
+    householdId
+FROM (SELECT * FROM registrationDB WHERE date_deleted IS NULL) AS registrationDB
 "#;
 
 /// 獲取所有報名記錄
@@ -46,7 +58,7 @@ pub async fn get_all_registrations(
     Extension(pool): Extension<SqlitePool>,
 ) -> Result<Json<ApiResponse<Vec<RegistrationResponse>>>, (StatusCode, Json<ApiResponse<Vec<RegistrationResponse>>>)> {
     let mut query = format!("{} WHERE 1=1", REGISTRATION_FULL_QUERY);
-    let mut count_query = String::from("SELECT COUNT(*) FROM registrationDB WHERE 1=1");
+    let mut count_query = String::from("SELECT COUNT(*) FROM registrationDB WHERE date_deleted IS NULL");
 
     // 添加過濾條件
     if let Some(state) = &params.state {
@@ -240,6 +252,249 @@ pub async fn get_registration_by_id(
     }
 }
 
+const RELATIONSHIPS: &[&str] = &["本家", "娘家", "朋友", "其它"];
+pub(crate) const ZODIACS: &[&str] = &["鼠", "牛", "虎", "兔", "龍", "蛇", "馬", "羊", "猴", "雞", "狗", "豬"];
+
+/// 名單數量上限（預設與前端 configStore.formConfig 相同，可用環境變數調整）
+pub(crate) struct RegistrationLimits {
+    pub max_household_heads: usize,
+    pub max_ancestors: usize,
+    pub max_survivors: usize,
+}
+
+impl RegistrationLimits {
+    pub fn from_env() -> Self {
+        let read = |key: &str, default: usize| {
+            std::env::var(key)
+                .ok()
+                .and_then(|v| v.parse::<usize>().ok())
+                .unwrap_or(default)
+        };
+        Self {
+            max_household_heads: read("REGISTRATION_MAX_HOUSEHOLD_HEADS", 1),
+            max_ancestors: read("REGISTRATION_MAX_ANCESTORS", 1),
+            max_survivors: read("REGISTRATION_MAX_SURVIVORS", 2),
+        }
+    }
+}
+
+/// 欄位層級的驗證錯誤（field 為 contact.mobile、blessing.persons[1].name 這類路徑）
+#[derive(Debug, Clone)]
+pub(crate) struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl FieldError {
+    fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+
+    /// ApiResponse.errors 使用的格式：「欄位: 訊息」
+    pub fn detail(&self) -> String {
+        format!("{}: {}", self.field, self.message)
+    }
+}
+
+/// 將 contact / blessing / salvation 的 JSON 轉為型別結構，型別不符時回報欄位錯誤
+pub(crate) fn parse_registration_parts(
+    contact: Option<&JsonValue>,
+    blessing: Option<&JsonValue>,
+    salvation: Option<&JsonValue>,
+) -> Result<(Contact, Blessing, Salvation), Vec<FieldError>> {
+    fn parse<T: serde::de::DeserializeOwned + Default>(
+        field: &str,
+        value: Option<&JsonValue>,
+        errors: &mut Vec<FieldError>,
+    ) -> T {
+        match value {
+            None | Some(JsonValue::Null) => T::default(),
+            // 舊版客戶端會送出 JSON 字串，先解析為物件
+            Some(JsonValue::String(text)) => serde_json::from_str(text).unwrap_or_else(|e| {
+                errors.push(FieldError::new(field, format!("格式錯誤: {}", e)));
+                T::default()
+            }),
+            Some(value) => serde_json::from_value(value.clone()).unwrap_or_else(|e| {
+                errors.push(FieldError::new(field, format!("格式錯誤: {}", e)));
+                T::default()
+            }),
+        }
+    }
+
+    let mut errors = Vec::new();
+    let contact = parse("contact", contact, &mut errors);
+    let blessing = parse("blessing", blessing, &mut errors);
+    let salvation = parse("salvation", salvation, &mut errors);
+    if errors.is_empty() {
+        Ok((contact, blessing, salvation))
+    } else {
+        Err(errors)
+    }
+}
+
+/// 報名業務規則（docs/business-logic.md「登記流程」，與前端 registrationStore.validationDetails 一致）
+pub(crate) fn validate_registration(
+    contact: &Contact,
+    blessing: &Blessing,
+    salvation: &Salvation,
+    limits: &RegistrationLimits,
+) -> Vec<FieldError> {
+    let mut errors = Vec::new();
+    let filled = |value: &str| !value.trim().is_empty();
+
+    // 聯絡人
+    if !filled(&contact.name) {
+        errors.push(FieldError::new("contact.name", "聯絡人姓名為必填"));
+    }
+    if !filled(&contact.relationship) {
+        errors.push(FieldError::new("contact.relationship", "關係為必填"));
+    } else if !RELATIONSHIPS.contains(&contact.relationship.trim()) {
+        errors.push(FieldError::new(
+            "contact.relationship",
+            format!("無效的關係: {}（僅接受 {}）", contact.relationship, RELATIONSHIPS.join("、")),
+        ));
+    } else if contact.relationship.trim() == "其它" && !filled(&contact.other_relationship) {
+        errors.push(FieldError::new("contact.otherRelationship", "選擇『其它』時，請填寫其他關係說明"));
+    }
+    if !filled(&contact.phone) && !filled(&contact.mobile) {
+        errors.push(FieldError::new("contact.phone", "請填寫電話或手機其中之一"));
+    }
+    // 手機：09 開頭共 10 位數字（允許以 - 或空白分隔）
+    let mobile: String = contact.mobile.chars().filter(|c| !matches!(c, '-' | ' ')).collect();
+    let valid_mobile =
+        mobile.len() == 10 && mobile.starts_with("09") && mobile.chars().all(|c| c.is_ascii_digit());
+    if !mobile.is_empty() && !valid_mobile {
+        errors.push(FieldError::new("contact.mobile", "手機號碼格式錯誤，請輸入09開頭的10位數字"));
+    }
+
+    // 消災：地址與人員互為必填，有人員時至少一位戶長
+    let persons = blessing.persons.iter().filter(|p| filled(&p.name)).count();
+    let has_blessing_address = filled(&blessing.address);
+    if persons > 0 && !has_blessing_address {
+        errors.push(FieldError::new("blessing.address", "已填寫消災人員，消災地址為必填"));
+    }
+    if has_blessing_address && persons == 0 {
+        errors.push(FieldError::new("blessing.persons", "消災地址已填寫，請至少填寫一筆消災人員"));
+    }
+    if blessing.persons.len() >= 2 {
+        for (i, person) in blessing.persons.iter().enumerate() {
+            if !filled(&person.name) {
+                errors.push(FieldError::new(format!("blessing.persons[{}].name", i), "消災人員姓名為必填"));
+            }
+        }
+    }
+    for (i, person) in blessing.persons.iter().enumerate() {
+        if filled(&person.zodiac) && !ZODIACS.contains(&person.zodiac.trim()) {
+            errors.push(FieldError::new(
+                format!("blessing.persons[{}].zodiac", i),
+                format!("無效的生肖: {}", person.zodiac),
+            ));
+        }
+    }
+    let heads = blessing.persons.iter().filter(|p| p.is_household_head).count();
+    if persons > 0 && heads == 0 {
+        errors.push(FieldError::new("blessing.persons", "請至少指定一位戶長"));
+    } else if heads > limits.max_household_heads {
+        errors.push(FieldError::new(
+            "blessing.persons",
+            format!("戶長數量超過限制 ({}/{})", heads, limits.max_household_heads),
+        ));
+    }
+
+    // 超度：地址與祖先/陽上人互為必填，有祖先必須有陽上人
+    let ancestors = salvation.ancestors.iter().filter(|a| filled(&a.surname)).count();
+    let survivors = salvation.survivors.iter().filter(|s| filled(&s.name)).count();
+    let has_salvation_address = filled(&salvation.address);
+    if ancestors > limits.max_ancestors {
+        errors.push(FieldError::new(
+            "salvation.ancestors",
+            format!("祖先數量超過限制 ({}/{})", ancestors, limits.max_ancestors),
+        ));
+    }
+    if survivors > limits.max_survivors {
+        errors.push(FieldError::new(
+            "salvation.survivors",
+            format!("陽上人數量超過限制 ({}/{})", survivors, limits.max_survivors),
+        ));
+    }
+    if salvation.ancestors.len() >= 2 {
+        for (i, ancestor) in salvation.ancestors.iter().enumerate() {
+            if !filled(&ancestor.surname) {
+                errors.push(FieldError::new(format!("salvation.ancestors[{}].surname", i), "祖先姓氏為必填"));
+            }
+        }
+    }
+    if salvation.survivors.len() >= 2 {
+        for (i, survivor) in salvation.survivors.iter().enumerate() {
+            if !filled(&survivor.name) {
+                errors.push(FieldError::new(format!("salvation.survivors[{}].name", i), "陽上人姓名為必填"));
+            }
+        }
+    }
+    if ancestors + survivors > 0 && !has_salvation_address {
+        errors.push(FieldError::new("salvation.address", "已填寫祖先或陽上人，超度地址為必填"));
+    }
+    if has_salvation_address && ancestors == 0 {
+        errors.push(FieldError::new("salvation.ancestors", "超度地址已填寫，請至少填寫一筆歷代祖先"));
+    }
+    if ancestors > 0 && survivors == 0 {
+        errors.push(FieldError::new("salvation.survivors", "已填寫祖先，請至少填寫一位陽上人"));
+    }
+
+    if persons == 0 && ancestors == 0 {
+        errors.push(FieldError::new("blessing.persons", "請至少填寫消災人員或歷代祖先其中一項"));
+    }
+
+    errors
+}
+
+fn validation_error_response<T>(errors: &[FieldError]) -> (StatusCode, Json<ApiResponse<T>>) {
+    (
+        StatusCode::UNPROCESSABLE_ENTITY,
+        Json(ApiResponse::error_with_details(
+            "報名資料驗證失敗".to_string(),
+            errors.iter().map(FieldError::detail).collect(),
+        )),
+    )
+}
+
+/// 要存入的 JSON 字串：原樣保留客戶端送出的內容（含型別未定義的欄位），
+/// 舊版客戶端送出的 JSON 字串存入其內容，未提供時存入預設值
+fn stored_registration_json<T: serde::Serialize>(original: Option<&JsonValue>, parsed: &T) -> String {
+    match original {
+        None | Some(JsonValue::Null) => serde_json::json!(parsed).to_string(),
+        Some(JsonValue::String(text)) => text.clone(),
+        Some(value) => value.to_string(),
+    }
+}
+
+/// 解析並驗證報名內容，回傳要存入的 JSON 字串 (contact, blessing, salvation)
+fn validated_registration_json(
+    contact: Option<&JsonValue>,
+    blessing: Option<&JsonValue>,
+    salvation: Option<&JsonValue>,
+) -> Result<(String, String, String), Vec<FieldError>> {
+    let (parsed_contact, parsed_blessing, parsed_salvation) =
+        parse_registration_parts(contact, blessing, salvation)?;
+    let errors = validate_registration(
+        &parsed_contact,
+        &parsed_blessing,
+        &parsed_salvation,
+        &RegistrationLimits::from_env(),
+    );
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+    Ok((
+        stored_registration_json(contact, &parsed_contact),
+        stored_registration_json(blessing, &parsed_blessing),
+        stored_registration_json(salvation, &parsed_salvation),
+    ))
+}
+
 /// 創建新報名記錄
 pub async fn create_registration(
     Extension(pool): Extension<SqlitePool>,
@@ -253,10 +508,13 @@ pub async fn create_registration(
     //     "system".to_string()
     // });
 
-    // 🔥 將 JsonValue 轉換為字符串存入資料庫
-    let salvation_str = payload.salvation.map(|v| v.to_string());
-    let contact_str = payload.contact.map(|v| v.to_string());
-    let blessing_str = payload.blessing.map(|v| v.to_string());
+    // 🔥 驗證業務規則後，將正規化的 JSON 字符串存入資料庫
+    let (contact_str, blessing_str, salvation_str) = validated_registration_json(
+        payload.contact.as_ref(),
+        payload.blessing.as_ref(),
+        payload.salvation.as_ref(),
+    )
+    .map_err(|errors| validation_error_response(&errors))?;
 
     // 插入新記錄
     let result = sqlx::query(
@@ -318,7 +576,7 @@ pub async fn update_registration(
     Json(payload): Json<UpdateRegistrationRequest>,
 ) -> Result<Json<ApiResponse<RegistrationResponse>>, (StatusCode, Json<ApiResponse<RegistrationResponse>>)> {
     // 檢查記錄是否存在
-    let exists: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM registrationDB WHERE id = ?")
+    let exists: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM registrationDB WHERE id = ? AND date_deleted IS NULL")
         .bind(id)
         .fetch_one(&pool)
         .await
@@ -358,18 +616,42 @@ pub async fn update_registration(
         bindings.push(form_source.clone());
     }
     
-    // 🔥 將 JsonValue 轉換為字符串
-    if let Some(salvation) = &payload.salvation {
-        updates.push("salvation = ?");
-        bindings.push(salvation.to_string());
-    }
-    if let Some(contact) = &payload.contact {
-        updates.push("contact = ?");
-        bindings.push(contact.to_string());
-    }
-    if let Some(blessing) = &payload.blessing {
-        updates.push("blessing = ?");
-        bindings.push(blessing.to_string());
+    // 🔥 任一 JSON 欄位有變更時，與既有資料合併後整體驗證
+    if payload.salvation.is_some() || payload.contact.is_some() || payload.blessing.is_some() {
+        let stored: (Option<String>, Option<String>, Option<String>) =
+            sqlx::query_as("SELECT contact, blessing, salvation FROM registrationDB WHERE id = ?")
+                .bind(id)
+                .fetch_one(&pool)
+                .await
+                .map_err(|e| {
+                    tracing::error!("查詢報名記錄失敗: {}", e);
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+                    )
+                })?;
+        let stored_json =
+            |value: Option<String>| value.and_then(|s| serde_json::from_str::<JsonValue>(&s).ok());
+        let contact = payload.contact.clone().or_else(|| stored_json(stored.0));
+        let blessing = payload.blessing.clone().or_else(|| stored_json(stored.1));
+        let salvation = payload.salvation.clone().or_else(|| stored_json(stored.2));
+
+        let (contact_str, blessing_str, salvation_str) =
+            validated_registration_json(contact.as_ref(), blessing.as_ref(), salvation.as_ref())
+                .map_err(|errors| validation_error_response(&errors))?;
+
+        if payload.salvation.is_some() {
+            updates.push("salvation = ?");
+            bindings.push(salvation_str);
+        }
+        if payload.contact.is_some() {
+            updates.push("contact = ?");
+            bindings.push(contact_str);
+        }
+        if payload.blessing.is_some() {
+            updates.push("blessing = ?");
+            bindings.push(blessing_str);
+        }
     }
 
     // 在更新語句中添加 user_updated
@@ -433,11 +715,10 @@ pub async fn update_registration(
 /// 刪除報名記錄
 pub async fn delete_registration(
     Path(id): Path<i64>,
+    Query(params): Query<DeleteQuery>,
     Extension(pool): Extension<SqlitePool>,
 ) -> Result<Json<ApiResponse<()>>, (StatusCode, Json<ApiResponse<()>>)> {
-    let result = sqlx::query("DELETE FROM registrationDB WHERE id = ?")
-        .bind(id)
-        .execute(&pool)
+    let deleted = soft_delete::soft_delete(&pool, "registrationDB", id, params.user_deleted.as_deref())
         .await
         .map_err(|e| {
             tracing::error!("刪除報名記錄失敗: {}", e);
@@ -447,7 +728,7 @@ pub async fn delete_registration(
             )
         })?;
 
-    if result.rows_affected() == 0 {
+    if !deleted {
         return Err((
             StatusCode::NOT_FOUND,
             Json(ApiResponse::error(format!("找不到 ID 為 {} 的報名記錄", id))),
@@ -461,4 +742,642 @@ pub async fn delete_registration(
         meta: None,
         errors: None,
     }))
-}
\ No newline at end of file
+}
+
+/// 還原已刪除的報名記錄
+pub async fn restore_registration(
+    Path(id): Path<i64>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<RegistrationResponse>>, (StatusCode, Json<ApiResponse<RegistrationResponse>>)> {
+    let restored = soft_delete::restore(&pool, "registrationDB", id)
+        .await
+        .map_err(|e| {
+            tracing::error!("還原 報名記錄 失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("還原失敗: {}", e))),
+            )
+        })?;
+
+    if !restored {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(ApiResponse::error(format!("找不到 ID 為 {} 的已刪除報名記錄", id))),
+        ));
+    }
+
+    let Json(mut response) = get_registration_by_id(Path(id), Extension(pool)).await?;
+    response.message = Some("成功還原報名記錄".to_string());
+    Ok(Json(response))
+}
+const REGISTRATION_EXPORT_HEADERS: &[&str] = &[
+    "記錄ID", "狀態", "表單ID", "表單名稱", "聯絡人", "關係", "電話", "手機",
+    "超度地址", "祖先", "陽上人", "消災地址", "消災人員", "戶長", "消災人數", "建立時間",
+];
+
+fn registration_export_rows(record: Registration) -> Vec<ExportRow> {
+    let contact = record.contact.into_reported("registrationDB.contact", record.id).unwrap_or_default();
+    let salvation = record.salvation.into_reported("registrationDB.salvation", record.id).unwrap_or_default();
+    let blessing = record.blessing.into_reported("registrationDB.blessing", record.id).unwrap_or_default();
+
+    let names = |names: Vec<&str>| -> String {
+        names.into_iter().filter(|n| !n.is_empty()).collect::<Vec<_>>().join("、")
+    };
+
+    // 關係為「其它」時顯示自訂關係
+    let relationship = match contact.relationship.as_str() {
+        "其它" | "其他" if !contact.other_relationship.is_empty() => contact.other_relationship.clone(),
+        other => other.to_string(),
+    };
+
+    vec![vec![
+        record.id.into(),
+        record.state.into(),
+        record.form_id.into(),
+        record.form_name.into(),
+        contact.name.into(),
+        relationship.into(),
+        contact.phone.into(),
+        contact.mobile.into(),
+        salvation.address.into(),
+        names(salvation.ancestors.iter().map(|a| a.surname.as_str()).collect()).into(),
+        names(salvation.survivors.iter().map(|s| s.name.as_str()).collect()).into(),
+        blessing.address.into(),
+        names(blessing.persons.iter().map(|p| p.name.as_str()).collect()).into(),
+        names(
+            blessing
+                .persons
+                .iter()
+                .filter(|p| p.is_household_head)
+                .map(|p| p.name.as_str())
+                .collect(),
+        )
+        .into(),
+        (blessing.persons.len() as i64).into(),
+        record.created_at.into(),
+    ]]
+}
+
+/// 匯出報名記錄：format=csv|xlsx|json，篩選條件同列表
+pub async fn export_registrations(
+    Query(params): Query<RegistrationExportQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Response, (StatusCode, Json<ApiResponse<()>>)> {
+    let format = ExportFormat::parse(params.format.as_deref())
+        .map_err(|msg| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(msg))))?;
+
+    let mut query = format!("{} WHERE 1=1", REGISTRATION_FULL_QUERY);
+    let mut bindings: Vec<String> = Vec::new();
+
+    if let Some(state) = &params.state {
+        query.push_str(" AND state = ?");
+        bindings.push(state.clone());
+    }
+    if let Some(form_id) = &params.form_id {
+        query.push_str(" AND formId = ?");
+        bindings.push(form_id.clone());
+    }
+    query.push_str(
+        &order_by_clause(params.sort.as_deref(), "createdAt DESC")
+            .map_err(|msg| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(msg))))?,
+    );
+
+    let rows = spawn_export_rows(pool, query, bindings, registration_export_rows);
+    export_response(format, "registrations", REGISTRATION_EXPORT_HEADERS.to_vec(), rows)
+        .await
+        .map_err(|e| {
+            tracing::error!("匯出報名記錄失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("匯出失敗: {}", e))),
+            )
+        })
+}
+
+/// 合併後被併入的報名記錄狀態
+pub(crate) const ARCHIVED_STATE: &str = "archived";
+/// 同一比對鍵（電話、姓名、地址）超過此筆數時視為常見值，不據以配對
+const MAX_DUPLICATE_BUCKET: usize = 50;
+
+/// 比對用的正規化資料
+struct DuplicateProfile {
+    summary: DuplicateRegistrationSummary,
+    mobile: String,
+    phone: String,
+    name: String,
+    addresses: BTreeSet<String>,
+    people: BTreeSet<String>,
+    ancestors: BTreeSet<String>,
+}
+
+pub(crate) fn normalize_digits(value: &str) -> String {
+    value.chars().filter(|c| c.is_ascii_digit()).collect()
+}
+
+pub(crate) fn normalize_text(value: &str) -> String {
+    value
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| if c == '臺' { '台' } else { c })
+        .collect()
+}
+
+impl DuplicateProfile {
+    fn new(record: Registration) -> Self {
+        let contact = record.contact.into_reported("registrationDB.contact", record.id).unwrap_or_default();
+        let blessing = record.blessing.into_reported("registrationDB.blessing", record.id).unwrap_or_default();
+        let salvation = record.salvation.into_reported("registrationDB.salvation", record.id).unwrap_or_default();
+        let non_empty = |values: Vec<String>| -> BTreeSet<String> {
+            values.into_iter().filter(|v| !v.is_empty()).collect()
+        };
+
+        Self {
+            mobile: normalize_digits(&contact.mobile),
+            phone: normalize_digits(&contact.phone),
+            name: normalize_text(&contact.name),
+            addresses: non_empty(vec![normalize_text(&blessing.address), normalize_text(&salvation.address)]),
+            people: non_empty(
+                blessing
+                    .persons
+                    .iter()
+                    .map(|p| normalize_text(&p.name))
+                    .chain(salvation.survivors.iter().map(|s| normalize_text(&s.name)))
+                    .collect(),
+            ),
+            ancestors: non_empty(salvation.ancestors.iter().map(|a| normalize_text(&a.surname)).collect()),
+            summary: DuplicateRegistrationSummary {
+                id: record.id,
+                form_id: record.form_id,
+                state: record.state,
+                contact_name: contact.name,
+                phone: contact.phone,
+                mobile: contact.mobile,
+                created_at: record.created_at,
+            },
+        }
+    }
+
+    /// 比對鍵：共用任一鍵的兩筆才計算分數
+    fn keys(&self) -> Vec<String> {
+        let mut keys = Vec::new();
+        if self.mobile.len() >= 8 {
+            keys.push(format!("mobile:{}", self.mobile));
+        }
+        if self.phone.len() >= 7 {
+            keys.push(format!("phone:{}", self.phone));
+        }
+        if !self.name.is_empty() {
+            keys.push(format!("name:{}", self.name));
+        }
+        keys.extend(self.addresses.iter().map(|a| format!("address:{}", a)));
+        keys
+    }
+
+    /// 相似度分數（上限 100）與加分原因
+    fn score(&self, other: &Self) -> (i64, Vec<String>) {
+        let mut score = 0;
+        let mut reasons = Vec::new();
+
+        if !self.mobile.is_empty() && self.mobile == other.mobile {
+            score += 40;
+            reasons.push("手機相同".to_string());
+        }
+        if !self.phone.is_empty() && self.phone == other.phone {
+            score += 25;
+            reasons.push("電話相同".to_string());
+        }
+        if !self.name.is_empty() && self.name == other.name {
+            score += 20;
+            reasons.push("聯絡人姓名相同".to_string());
+        }
+        if self.addresses.intersection(&other.addresses).next().is_some() {
+            score += 20;
+            reasons.push("地址相同".to_string());
+        }
+        let shared: Vec<&String> = self.people.intersection(&other.people).collect();
+        if !shared.is_empty() {
+            score += (shared.len() as i64 * 5).min(15);
+            reasons.push(format!(
+                "共同人員 {} 位：{}",
+                shared.len(),
+                shared.iter().map(|s| s.as_str()).collect::<Vec<_>>().join("、")
+            ));
+        }
+        if self.ancestors.intersection(&other.ancestors).next().is_some() {
+            score += 5;
+            reasons.push("祖先相同".to_string());
+        }
+
+        (score.min(100), reasons)
+    }
+}
+
+/// 疑似重複的報名記錄：依手機、電話、聯絡人姓名、地址與人員名單計分
+pub async fn get_duplicate_registrations(
+    Query(params): Query<DuplicateQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<Vec<DuplicateCandidate>>>, (StatusCode, Json<ApiResponse<Vec<DuplicateCandidate>>>)> {
+    let min_score = params.min_score.unwrap_or(40).clamp(0, 100);
+    let limit = params.limit.unwrap_or(100).max(0) as usize;
+
+    let query = format!("{} WHERE state IS NULL OR state != ? ORDER BY id", REGISTRATION_FULL_QUERY);
+    let registrations = sqlx::query_as::<_, Registration>(&query)
+        .bind(ARCHIVED_STATE)
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("查詢報名記錄失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+            )
+        })?;
+
+    let profiles: Vec<DuplicateProfile> = registrations.into_iter().map(DuplicateProfile::new).collect();
+
+    // 依比對鍵分桶，只比較同桶內的組合
+    let mut buckets: HashMap<String, Vec<usize>> = HashMap::new();
+    for (index, profile) in profiles.iter().enumerate() {
+        for key in profile.keys() {
+            buckets.entry(key).or_default().push(index);
+        }
+    }
+    let mut pairs: HashSet<(usize, usize)> = HashSet::new();
+    for members in buckets.values().filter(|m| m.len() > 1 && m.len() <= MAX_DUPLICATE_BUCKET) {
+        for (i, &a) in members.iter().enumerate() {
+            for &b in &members[i + 1..] {
+                if a != b {
+                    pairs.insert((a.min(b), a.max(b)));
+                }
+            }
+        }
+    }
+
+    let mut candidates: Vec<DuplicateCandidate> = pairs
+        .into_iter()
+        .filter_map(|(a, b)| {
+            let (score, reasons) = profiles[a].score(&profiles[b]);
+            (score >= min_score).then(|| DuplicateCandidate {
+                score,
+                reasons,
+                first: profiles[a].summary.clone(),
+                second: profiles[b].summary.clone(),
+            })
+        })
+        .collect();
+    candidates.sort_by(|x, y| {
+        y.score
+            .cmp(&x.score)
+            .then(x.first.id.cmp(&y.first.id))
+            .then(x.second.id.cmp(&y.second.id))
+    });
+
+    let total = candidates.len() as i64;
+    candidates.truncate(limit);
+
+    Ok(Json(ApiResponse::success_with_meta(
+        candidates,
+        Meta {
+            total,
+            limit: Some(limit as i64),
+            offset: Some(0),
+        },
+    )))
+}
+
+/// 被併入者名單 ID → 合併後的 ID（重複的人員對應到保留者的同名人員）
+#[derive(Default)]
+struct MergedIds {
+    persons: HashMap<i64, i64>,
+    ancestors: HashMap<i64, i64>,
+    survivors: HashMap<i64, i64>,
+}
+
+impl MergedIds {
+    /// 依參加項目的 source 取得對應的 ID 表
+    fn for_source(&self, source: &str) -> Option<&HashMap<i64, i64>> {
+        match source {
+            "blessing.persons" => Some(&self.persons),
+            "salvation.ancestors" => Some(&self.ancestors),
+            "salvation.survivors" => Some(&self.survivors),
+            _ => None,
+        }
+    }
+}
+
+/// 將 loser 的名單依 key（姓名 / 姓氏）去重併入 keep，新增者重新編號，回傳新增的人數
+fn merge_list<T>(
+    keep: &mut Vec<T>,
+    loser: Vec<T>,
+    key: impl Fn(&T) -> &str,
+    id: impl Fn(&T) -> i64,
+    renumber: impl Fn(T, i64) -> T,
+    ids: &mut HashMap<i64, i64>,
+) -> usize {
+    let mut known: HashMap<String, i64> = keep.iter().map(|item| (normalize_text(key(item)), id(item))).collect();
+    let mut next_id = keep.iter().map(&id).max().unwrap_or(0);
+    let mut added = 0;
+    for item in loser {
+        if key(&item).trim().is_empty() {
+            continue;
+        }
+        let loser_id = id(&item);
+        if let Some(existing_id) = known.get(&normalize_text(key(&item))) {
+            ids.insert(loser_id, *existing_id);
+            continue;
+        }
+        next_id += 1;
+        added += 1;
+        known.insert(normalize_text(key(&item)), next_id);
+        ids.insert(loser_id, next_id);
+        keep.push(renumber(item, next_id));
+    }
+    added
+}
+
+/// 將 loser 的名單併入 keep（依姓名 / 姓氏去重），回傳新增的人數與 ID 對應
+fn merge_registration_parts(
+    keep: &mut (Contact, Blessing, Salvation),
+    loser: (Contact, Blessing, Salvation),
+) -> ((usize, usize, usize), MergedIds) {
+    let (contact, blessing, salvation) = keep;
+    let (loser_contact, loser_blessing, loser_salvation) = loser;
+
+    // 聯絡人以保留者為主，只補空白欄位
+    let fill = |target: &mut String, source: String| {
+        if target.trim().is_empty() {
+            *target = source;
+        }
+    };
+    fill(&mut contact.name, loser_contact.name);
+    fill(&mut contact.phone, loser_contact.phone);
+    fill(&mut contact.mobile, loser_contact.mobile);
+    if contact.relationship.trim().is_empty() {
+        contact.relationship = loser_contact.relationship;
+        contact.other_relationship = loser_contact.other_relationship;
+    }
+    fill(&mut blessing.address, loser_blessing.address);
+    fill(&mut salvation.address, loser_salvation.address);
+
+    let mut ids = MergedIds::default();
+    let added_persons = merge_list(
+        &mut blessing.persons,
+        loser_blessing.persons,
+        |p| p.name.as_str(),
+        |p| p.id,
+        |p, id| BlessingPerson { id, is_household_head: false, ..p },
+        &mut ids.persons,
+    );
+    let added_ancestors = merge_list(
+        &mut salvation.ancestors,
+        loser_salvation.ancestors,
+        |a| a.surname.as_str(),
+        |a| a.id,
+        |a, id| Ancestor { id, ..a },
+        &mut ids.ancestors,
+    );
+    let added_survivors = merge_list(
+        &mut salvation.survivors,
+        loser_salvation.survivors,
+        |s| s.name.as_str(),
+        |s| s.id,
+        |s, id| Survivor { id, ..s },
+        &mut ids.survivors,
+    );
+
+    ((added_persons, added_ancestors, added_survivors), ids)
+}
+
+/// 將參加項目 sourceData（與點燈 lampDetails）中的名單 ID 改為合併後的 ID，返回是否有變更
+fn remap_join_items(items: &mut [JsonValue], ids: &MergedIds) -> bool {
+    let remap = |value: Option<&mut JsonValue>, map: &HashMap<i64, i64>| -> bool {
+        let Some(value) = value else {
+            return false;
+        };
+        match value.as_i64().and_then(|id| map.get(&id)) {
+            Some(new_id) if value.as_i64() != Some(*new_id) => {
+                *value = JsonValue::from(*new_id);
+                true
+            }
+            _ => false,
+        }
+    };
+
+    let mut changed = false;
+    for item in items.iter_mut() {
+        let Some(map) = item.get("source").and_then(|s| s.as_str()).and_then(|s| ids.for_source(s)) else {
+            continue;
+        };
+        if let Some(source_data) = item.get_mut("sourceData").and_then(|d| d.as_array_mut()) {
+            for entry in source_data {
+                changed |= remap(entry.get_mut("id"), map);
+            }
+        }
+        if let Some(lamp_details) = item.get_mut("lampDetails").and_then(|d| d.as_array_mut()) {
+            for detail in lamp_details {
+                changed |= remap(detail.get_mut("personId"), map);
+            }
+        }
+    }
+    changed
+}
+
+/// 合併兩筆報名記錄：名單併入 keepId，參與記錄與每月贊助改指向 keepId，mergeId 封存
+pub async fn merge_registrations(
+    Extension(pool): Extension<SqlitePool>,
+    Json(payload): Json<MergeRegistrationRequest>,
+) -> Result<Json<ApiResponse<MergeRegistrationResult>>, (StatusCode, Json<ApiResponse<MergeRegistrationResult>>)> {
+    let internal_error = |message: String| {
+        tracing::error!("{}", message);
+        (StatusCode::INTERNAL_SERVER_ERROR, Json(ApiResponse::error(message)))
+    };
+
+    if payload.keep_id == payload.merge_id {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error("keepId 與 mergeId 不可相同".to_string())),
+        ));
+    }
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| internal_error(format!("啟動事務失敗: {}", e)))?;
+
+    let query = format!("{} WHERE id = ?", REGISTRATION_FULL_QUERY);
+    let mut records = Vec::new();
+    for id in [payload.keep_id, payload.merge_id] {
+        let record = sqlx::query_as::<_, Registration>(&query)
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| internal_error(format!("查詢失敗: {}", e)))?
+            .ok_or_else(|| {
+                (
+                    StatusCode::NOT_FOUND,
+                    Json(ApiResponse::error(format!("找不到 ID 為 {} 的報名記錄", id))),
+                )
+            })?;
+        if record.state.as_deref() == Some(ARCHIVED_STATE) {
+            return Err((
+                StatusCode::CONFLICT,
+                Json(ApiResponse::error(format!("報名記錄 {} 已封存，無法合併", id))),
+            ));
+        }
+        records.push(record);
+    }
+
+    // 無法解析的 JSON 不合併，以免覆蓋原始資料
+    fn take<T: Default>(id: i64, column: &str, value: JsonColumn<T>, errors: &mut Vec<String>) -> T {
+        match value {
+            JsonColumn::Valid(value) => value,
+            JsonColumn::Null => T::default(),
+            JsonColumn::Invalid { error, .. } => {
+                errors.push(format!("{} {}: {}", id, column, error));
+                T::default()
+            }
+        }
+    }
+    let mut parse_errors = Vec::new();
+    let mut parts: Vec<(Contact, Blessing, Salvation)> = records
+        .into_iter()
+        .map(|record| {
+            (
+                take(record.id, "contact", record.contact, &mut parse_errors),
+                take(record.id, "blessing", record.blessing, &mut parse_errors),
+                take(record.id, "salvation", record.salvation, &mut parse_errors),
+            )
+        })
+        .collect();
+    if !parse_errors.is_empty() {
+        return Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(ApiResponse::error_with_details(
+                "報名記錄的 JSON 欄位無法解析，請先修正".to_string(),
+                parse_errors,
+            )),
+        ));
+    }
+    let loser = parts.remove(1);
+    let mut keep = parts.remove(0);
+
+    let ((added_persons, added_ancestors, added_survivors), merged_ids) =
+        merge_registration_parts(&mut keep, loser);
+
+    if !payload.force {
+        let errors = validate_registration(&keep.0, &keep.1, &keep.2, &RegistrationLimits::from_env());
+        if !errors.is_empty() {
+            let mut details: Vec<String> = errors.iter().map(FieldError::detail).collect();
+            details.push("如確定要合併請設定 force".to_string());
+            return Err((
+                StatusCode::UNPROCESSABLE_ENTITY,
+                Json(ApiResponse::error_with_details("合併後的報名資料不符合規則".to_string(), details)),
+            ));
+        }
+    }
+
+    let now = chrono::Utc::now().to_rfc3339();
+    let (contact, blessing, salvation) = &keep;
+    // 保留者尚未歸戶時沿用被併入者的戶ID
+    sqlx::query(
+        r#"
+        UPDATE registrationDB
+        SET contact = ?, blessing = ?, salvation = ?, updatedAt = ?, user_updated = COALESCE(?, user_updated),
+            householdId = CASE
+                WHEN COALESCE(householdId, -1) > 0 THEN householdId
+                ELSE COALESCE((SELECT householdId FROM registrationDB WHERE id = ?), -1)
+            END
+        WHERE id = ?
+        "#,
+    )
+    .bind(serde_json::json!(contact).to_string())
+    .bind(serde_json::json!(blessing).to_string())
+    .bind(serde_json::json!(salvation).to_string())
+    .bind(&now)
+    .bind(&payload.user_updated)
+    .bind(payload.merge_id)
+    .bind(payload.keep_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| internal_error(format!("更新報名記錄失敗: {}", e)))?;
+
+    // 被併入者的參加記錄改用合併後的名單 ID，避免 sourceData 指向錯誤的人員
+    let loser_items: Vec<(i64, Option<String>)> =
+        sqlx::query_as("SELECT id, items FROM joinRecordDB WHERE registrationId = ?")
+            .bind(payload.merge_id)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| internal_error(format!("查詢參與記錄失敗: {}", e)))?;
+    for (join_record_id, items) in loser_items {
+        let Some(mut items) = items.and_then(|raw| serde_json::from_str::<Vec<JsonValue>>(&raw).ok()) else {
+            continue;
+        };
+        if !remap_join_items(&mut items, &merged_ids) {
+            continue;
+        }
+        sqlx::query("UPDATE joinRecordDB SET items = ? WHERE id = ?")
+            .bind(JsonValue::Array(items).to_string())
+            .bind(join_record_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| internal_error(format!("更新參與記錄項目失敗: {}", e)))?;
+    }
+
+    let moved_join_records = sqlx::query(
+        "UPDATE joinRecordDB SET registrationId = ?1, householdId = (SELECT COALESCE(householdId, -1) FROM registrationDB WHERE id = ?1) WHERE registrationId = ?2",
+    )
+    .bind(payload.keep_id)
+    .bind(payload.merge_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| internal_error(format!("更新參與記錄失敗: {}", e)))?
+    .rows_affected();
+
+    let moved_monthly_donates = sqlx::query(
+        "UPDATE monthlyDonateDB SET registrationId = ?1, householdId = (SELECT COALESCE(householdId, -1) FROM registrationDB WHERE id = ?1) WHERE registrationId = ?2",
+    )
+    .bind(payload.keep_id)
+    .bind(payload.merge_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| internal_error(format!("更新每月贊助失敗: {}", e)))?
+    .rows_affected();
+
+    sqlx::query(
+        "UPDATE registrationDB SET state = ?, updatedAt = ?, user_updated = COALESCE(?, user_updated) WHERE id = ?",
+    )
+    .bind(ARCHIVED_STATE)
+    .bind(&now)
+    .bind(&payload.user_updated)
+    .bind(payload.merge_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| internal_error(format!("封存報名記錄失敗: {}", e)))?;
+
+    let registration = sqlx::query_as::<_, Registration>(&query)
+        .bind(payload.keep_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| internal_error(format!("查詢合併後的報名記錄失敗: {}", e)))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| internal_error(format!("提交事務失敗: {}", e)))?;
+
+    let message = format!(
+        "已將報名記錄 {} 合併至 {}（新增消災人員 {}、祖先 {}、陽上人 {}）",
+        payload.merge_id, payload.keep_id, added_persons, added_ancestors, added_survivors
+    );
+    Ok(Json(ApiResponse::success_with_message(
+        MergeRegistrationResult {
+            registration: registration.into(),
+            archived_id: payload.merge_id,
+            added_persons,
+            added_ancestors,
+            added_survivors,
+            moved_join_records,
+            moved_monthly_donates,
+        },
+        message,
+    )))
+}
@@ -0,0 +1,572 @@
+// src/handlers/report.rs
+use axum::{
+    extract::{Extension, Query},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::Local;
+use serde_json::Value as JsonValue;
+use sqlx::SqlitePool;
+use serde::de::DeserializeOwned;
+use std::collections::{BTreeMap, HashMap};
+
+use crate::models::api_response::ApiResponse;
+use crate::models::json_column::JsonColumn;
+use crate::models::json_types::{Blessing, Contact, DonateItem, JoinItem, PriceTable, Salvation};
+use crate::models::report::{
+    DailyClosingQuery, DailyClosingReport, DailyClosingTotals, DailyPaymentLine,
+    HandlerMethodTotal, IssuedReceipt, VoidedReceipt, InvalidJsonRow,
+};
+use crate::utils::{csv, pdf};
+
+/// 當日參加記錄入帳：有付款流水以流水為準，舊記錄（無流水）以 paidAmount 計
+const DAILY_JOIN_RECORD_PAYMENTS_QUERY: &str = r#"
+SELECT
+    p.joinRecordId,
+    COALESCE(json_extract(j.contact, '$.name'), ''),
+    COALESCE(NULLIF(p.handledBy, ''), j.receiptIssuedBy, ''),
+    COALESCE(p.paymentMethod, ''),
+    COALESCE(p.paymentType, 'payment'),
+    p.amount
+FROM joinRecordPayments p
+JOIN joinRecordDB j ON j.id = p.joinRecordId
+WHERE date(p.paymentDate, 'localtime') = ?
+  AND j.date_deleted IS NULL
+UNION ALL
+SELECT
+    j.id,
+    COALESCE(json_extract(j.contact, '$.name'), ''),
+    COALESCE(j.receiptIssuedBy, ''),
+    COALESCE(j.paymentMethod, ''),
+    'payment',
+    COALESCE(j.paidAmount, 0)
+FROM joinRecordDB j
+WHERE date(j.paymentDate, 'localtime') = ?
+  AND j.date_deleted IS NULL
+  AND COALESCE(j.paidAmount, 0) != 0
+  AND NOT EXISTS (SELECT 1 FROM joinRecordPayments p WHERE p.joinRecordId = j.id)
+ORDER BY 1
+"#;
+
+/// 當日開立的收據 / 感謝狀
+const DAILY_RECEIPTS_QUERY: &str = r#"
+SELECT
+    id,
+    COALESCE(receiptNumber, ''),
+    receiptIssued,
+    COALESCE(receiptIssuedBy, ''),
+    receiptIssuedAt,
+    COALESCE(finalAmount, 0)
+FROM joinRecordDB
+WHERE receiptIssued IN ('stamp', 'standard')
+  AND date(receiptIssuedAt, 'localtime') = ?
+  AND date_deleted IS NULL
+ORDER BY receiptIssued, receiptNumber
+"#;
+
+/// 當日作廢的收據編號（單筆作廢 void、作廢合併 remove merged）
+const DAILY_VOIDS_QUERY: &str = r#"
+SELECT
+    COALESCE(receiptNumber, ''),
+    COALESCE(receiptType, ''),
+    COALESCE(state, ''),
+    COALESCE(voidReason, ''),
+    recordId,
+    COALESCE(updatedAt, createdAt, '')
+FROM receiptNumbersDB
+WHERE state IN ('void', 'remove merged')
+  AND date(COALESCE(updatedAt, createdAt), 'localtime') = ?
+ORDER BY receiptNumber
+"#;
+
+pub(crate) fn payment_method_label(method: &str) -> &str {
+    match method {
+        "cash" => "現金",
+        "transfer" => "轉帳",
+        "card" => "刷卡",
+        "" => "未指定",
+        other => other,
+    }
+}
+
+pub(crate) fn receipt_type_label(receipt_type: &str) -> &str {
+    match receipt_type {
+        "stamp" => "收據",
+        "standard" => "感謝狀",
+        other => other,
+    }
+}
+
+fn payment_type_label(payment_type: &str) -> &str {
+    match payment_type {
+        "payment" => "付款",
+        "refund" => "退款",
+        "reversal" => "沖銷",
+        other => other,
+    }
+}
+
+/// 將 ISO 時間字串轉為本地日期 'YYYY-MM-DD'
+fn local_date_of(iso: &str) -> Option<String> {
+    chrono::DateTime::parse_from_rfc3339(iso)
+        .ok()
+        .map(|dt| dt.with_timezone(&Local).format("%Y-%m-%d").to_string())
+}
+
+/// 彙整每日結帳報表
+async fn build_daily_closing(pool: &SqlitePool, date: &str) -> Result<DailyClosingReport, sqlx::Error> {
+    let mut payments: Vec<DailyPaymentLine> = Vec::new();
+
+    // 1. 參加記錄入帳
+    let join_rows: Vec<(i64, String, String, String, String, i64)> =
+        sqlx::query_as(DAILY_JOIN_RECORD_PAYMENTS_QUERY)
+            .bind(date)
+            .bind(date)
+            .fetch_all(pool)
+            .await?;
+
+    for (record_id, name, handler, payment_method, payment_type, amount) in join_rows {
+        payments.push(DailyPaymentLine {
+            source: "joinRecord".to_string(),
+            record_id,
+            donate_items_id: None,
+            name,
+            handler,
+            payment_method,
+            payment_type,
+            amount,
+        });
+    }
+
+    // 2. 每月捐款項目：以 donateItems.createdAt 為入帳日，經手人為 createdUser
+    // 使用者 UUID 轉為與 receiptIssuedBy 相同的「姓名(帳號)」格式
+    let user_names: HashMap<String, String> = sqlx::query_as::<_, (String, String)>(
+        "SELECT id, COALESCE(first_name, '') || COALESCE(last_name, '') || '(' || COALESCE(substr(email, 1, instr(email, '@') - 1), '') || ')' FROM directus_users",
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect();
+
+    let donates: Vec<(i64, Option<String>, Option<String>)> =
+        sqlx::query_as("SELECT id, name, donateItems FROM monthlyDonateDB WHERE date_deleted IS NULL ORDER BY id")
+            .fetch_all(pool)
+            .await?;
+
+    for (record_id, name, donate_items) in donates {
+        let items: Vec<JsonValue> = donate_items
+            .as_deref()
+            .and_then(|s| serde_json::from_str(s).ok())
+            .unwrap_or_default();
+
+        for item in items {
+            let created_at = item.get("createdAt").and_then(|v| v.as_str()).unwrap_or_default();
+            if local_date_of(created_at).as_deref() != Some(date) {
+                continue;
+            }
+            let created_user = item.get("createdUser").and_then(|v| v.as_str()).unwrap_or_default();
+            payments.push(DailyPaymentLine {
+                source: "monthlyDonate".to_string(),
+                record_id,
+                donate_items_id: item.get("donateItemsId").and_then(|v| v.as_str()).map(|s| s.to_string()),
+                name: name.clone().unwrap_or_default(),
+                handler: user_names
+                    .get(created_user)
+                    .cloned()
+                    .unwrap_or_else(|| created_user.to_string()),
+                payment_method: item
+                    .get("paymentMethod")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or_default()
+                    .to_string(),
+                payment_type: "payment".to_string(),
+                amount: item.get("price").and_then(|v| v.as_i64()).unwrap_or(0),
+            });
+        }
+    }
+
+    // 3. 收據 / 感謝狀與作廢
+    let receipt_rows: Vec<(i64, String, String, String, String, i64)> = sqlx::query_as(DAILY_RECEIPTS_QUERY)
+        .bind(date)
+        .fetch_all(pool)
+        .await?;
+    let receipts: Vec<IssuedReceipt> = receipt_rows
+        .into_iter()
+        .map(|(join_record_id, receipt_number, receipt_type, handler, issued_at, amount)| IssuedReceipt {
+            join_record_id,
+            receipt_number,
+            receipt_type,
+            handler,
+            issued_at,
+            amount,
+        })
+        .collect();
+
+    let void_rows: Vec<(String, String, String, String, Option<i64>, String)> = sqlx::query_as(DAILY_VOIDS_QUERY)
+        .bind(date)
+        .fetch_all(pool)
+        .await?;
+    let voids: Vec<VoidedReceipt> = void_rows
+        .into_iter()
+        .map(|(receipt_number, receipt_type, state, void_reason, record_id, voided_at)| VoidedReceipt {
+            receipt_number,
+            receipt_type,
+            state,
+            void_reason,
+            record_id: record_id.filter(|id| *id > 0),
+            voided_at,
+        })
+        .collect();
+
+    // 4. 經手人 × 付款方式小計與合計
+    let mut totals = DailyClosingTotals::default();
+    let mut grouped: BTreeMap<(String, String), HandlerMethodTotal> = BTreeMap::new();
+
+    for line in &payments {
+        let entry = grouped
+            .entry((line.handler.clone(), line.payment_method.clone()))
+            .or_insert_with(|| HandlerMethodTotal {
+                handler: line.handler.clone(),
+                payment_method: line.payment_method.clone(),
+                join_record_amount: 0,
+                monthly_donate_amount: 0,
+                amount: 0,
+                count: 0,
+            });
+
+        if line.source == "monthlyDonate" {
+            entry.monthly_donate_amount += line.amount;
+            totals.monthly_donate_amount += line.amount;
+        } else {
+            entry.join_record_amount += line.amount;
+            totals.join_record_amount += line.amount;
+        }
+        entry.amount += line.amount;
+        entry.count += 1;
+
+        match line.payment_method.as_str() {
+            "cash" => totals.cash += line.amount,
+            "transfer" => totals.transfer += line.amount,
+            _ => totals.other += line.amount,
+        }
+    }
+
+    totals.grand_total = totals.join_record_amount + totals.monthly_donate_amount;
+    totals.stamp_count = receipts.iter().filter(|r| r.receipt_type == "stamp").count() as i64;
+    totals.standard_count = receipts.iter().filter(|r| r.receipt_type == "standard").count() as i64;
+    totals.void_count = voids.len() as i64;
+
+    Ok(DailyClosingReport {
+        date: date.to_string(),
+        totals,
+        by_handler: grouped.into_values().collect(),
+        payments,
+        receipts,
+        voids,
+    })
+}
+
+/// 報表轉 CSV（分段輸出，含 UTF-8 BOM 供 Excel 開啟）
+fn render_daily_closing_csv(report: &DailyClosingReport) -> String {
+    let t = &report.totals;
+    let mut out = String::from(csv::UTF8_BOM);
+
+    out.push_str(&csv::row(&["每日結帳報表", report.date.as_str()]));
+    out.push_str("\r\n");
+
+    out.push_str(&csv::row(&["合計"]));
+    out.push_str(&csv::row(&["參加記錄", "每月捐款", "現金", "轉帳", "其他", "總計", "收據張數", "感謝狀張數", "作廢張數"]));
+    out.push_str(&csv::row(&[
+        t.join_record_amount.to_string(),
+        t.monthly_donate_amount.to_string(),
+        t.cash.to_string(),
+        t.transfer.to_string(),
+        t.other.to_string(),
+        t.grand_total.to_string(),
+        t.stamp_count.to_string(),
+        t.standard_count.to_string(),
+        t.void_count.to_string(),
+    ]));
+    out.push_str("\r\n");
+
+    out.push_str(&csv::row(&["經手人小計"]));
+    out.push_str(&csv::row(&["經手人", "付款方式", "參加記錄", "每月捐款", "小計", "筆數"]));
+    for g in &report.by_handler {
+        out.push_str(&csv::row(&[
+            g.handler.clone(),
+            payment_method_label(&g.payment_method).to_string(),
+            g.join_record_amount.to_string(),
+            g.monthly_donate_amount.to_string(),
+            g.amount.to_string(),
+            g.count.to_string(),
+        ]));
+    }
+    out.push_str("\r\n");
+
+    out.push_str(&csv::row(&["入帳明細"]));
+    out.push_str(&csv::row(&["來源", "記錄ID", "項目ID", "姓名", "經手人", "付款方式", "類型", "金額"]));
+    for p in &report.payments {
+        out.push_str(&csv::row(&[
+            if p.source == "monthlyDonate" { "每月捐款" } else { "參加記錄" }.to_string(),
+            p.record_id.to_string(),
+            p.donate_items_id.clone().unwrap_or_default(),
+            p.name.clone(),
+            p.handler.clone(),
+            payment_method_label(&p.payment_method).to_string(),
+            payment_type_label(&p.payment_type).to_string(),
+            p.amount.to_string(),
+        ]));
+    }
+    out.push_str("\r\n");
+
+    out.push_str(&csv::row(&["開立收據"]));
+    out.push_str(&csv::row(&["編號", "類型", "參加記錄ID", "經手人", "開立時間", "金額"]));
+    for r in &report.receipts {
+        out.push_str(&csv::row(&[
+            r.receipt_number.clone(),
+            receipt_type_label(&r.receipt_type).to_string(),
+            r.join_record_id.to_string(),
+            r.handler.clone(),
+            r.issued_at.clone(),
+            r.amount.to_string(),
+        ]));
+    }
+    out.push_str("\r\n");
+
+    out.push_str(&csv::row(&["作廢"]));
+    out.push_str(&csv::row(&["編號", "類型", "狀態", "原因", "參加記錄ID", "作廢時間"]));
+    for v in &report.voids {
+        out.push_str(&csv::row(&[
+            v.receipt_number.clone(),
+            receipt_type_label(&v.receipt_type).to_string(),
+            v.state.clone(),
+            v.void_reason.clone(),
+            v.record_id.map(|id| id.to_string()).unwrap_or_default(),
+            v.voided_at.clone(),
+        ]));
+    }
+
+    out
+}
+
+/// 報表轉可列印 PDF
+fn render_daily_closing_pdf(report: &DailyClosingReport) -> Vec<u8> {
+    let t = &report.totals;
+    let x = pdf::MARGIN_LEFT;
+    let mut doc = pdf::PdfDocument::new();
+
+    doc.line(&[(x, &format!("每日結帳報表　{}", report.date))], 16.0);
+    doc.line(&[(x, &format!("列印時間：{}", Local::now().format("%Y-%m-%d %H:%M")))], 9.0);
+    doc.rule();
+
+    doc.line(&[(x, "合計")], 12.0);
+    doc.line(
+        &[
+            (x, &format!("參加記錄 {}", t.join_record_amount)),
+            (x + 130.0, &format!("每月捐款 {}", t.monthly_donate_amount)),
+            (x + 260.0, &format!("總計 {}", t.grand_total)),
+        ],
+        10.0,
+    );
+    doc.line(
+        &[
+            (x, &format!("現金 {}", t.cash)),
+            (x + 130.0, &format!("轉帳 {}", t.transfer)),
+            (x + 260.0, &format!("其他 {}", t.other)),
+        ],
+        10.0,
+    );
+    doc.line(
+        &[
+            (x, &format!("收據 {} 張", t.stamp_count)),
+            (x + 130.0, &format!("感謝狀 {} 張", t.standard_count)),
+            (x + 260.0, &format!("作廢 {} 張", t.void_count)),
+        ],
+        10.0,
+    );
+    doc.gap(6.0);
+
+    doc.line(&[(x, "經手人小計")], 12.0);
+    doc.line(
+        &[(x, "經手人"), (x + 170.0, "付款方式"), (x + 240.0, "參加記錄"), (x + 310.0, "每月捐款"), (x + 380.0, "小計"), (x + 450.0, "筆數")],
+        9.0,
+    );
+    doc.rule();
+    for g in &report.by_handler {
+        doc.line(
+            &[
+                (x, &g.handler),
+                (x + 170.0, payment_method_label(&g.payment_method)),
+                (x + 240.0, &g.join_record_amount.to_string()),
+                (x + 310.0, &g.monthly_donate_amount.to_string()),
+                (x + 380.0, &g.amount.to_string()),
+                (x + 450.0, &g.count.to_string()),
+            ],
+            9.0,
+        );
+    }
+    doc.gap(6.0);
+
+    doc.line(&[(x, "開立收據 / 感謝狀")], 12.0);
+    doc.line(
+        &[(x, "編號"), (x + 80.0, "類型"), (x + 130.0, "記錄ID"), (x + 180.0, "經手人"), (x + 350.0, "金額")],
+        9.0,
+    );
+    doc.rule();
+    for r in &report.receipts {
+        doc.line(
+            &[
+                (x, &r.receipt_number),
+                (x + 80.0, receipt_type_label(&r.receipt_type)),
+                (x + 130.0, &r.join_record_id.to_string()),
+                (x + 180.0, &r.handler),
+                (x + 350.0, &r.amount.to_string()),
+            ],
+            9.0,
+        );
+    }
+    doc.gap(6.0);
+
+    doc.line(&[(x, "作廢")], 12.0);
+    doc.line(&[(x, "編號"), (x + 80.0, "類型"), (x + 130.0, "原因")], 9.0);
+    doc.rule();
+    for v in &report.voids {
+        doc.line(
+            &[
+                (x, &v.receipt_number),
+                (x + 80.0, receipt_type_label(&v.receipt_type)),
+                (x + 130.0, &v.void_reason),
+            ],
+            9.0,
+        );
+    }
+
+    doc.gap(30.0);
+    doc.line(&[(x, "經手人簽章："), (x + 260.0, "覆核：")], 11.0);
+
+    doc.finish()
+}
+
+/// 每日結帳報表：依經手人與付款方式彙整當日參加記錄付款與每月捐款，
+/// 並列出當日開立的收據 / 感謝狀與作廢記錄。format=json|csv|pdf
+pub async fn get_daily_closing_report(
+    Query(params): Query<DailyClosingQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Response, (StatusCode, Json<ApiResponse<DailyClosingReport>>)> {
+    let date = params
+        .date
+        .clone()
+        .unwrap_or_else(|| Local::now().format("%Y-%m-%d").to_string());
+
+    if chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d").is_err() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!("無效的日期格式: {}（應為 YYYY-MM-DD）", date))),
+        ));
+    }
+
+    let report = build_daily_closing(&pool, &date).await.map_err(|e| {
+        tracing::error!("查詢每日結帳報表失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+        )
+    })?;
+
+    match params.format.as_deref().unwrap_or("json") {
+        "json" => Ok(Json(ApiResponse::success(report)).into_response()),
+        "csv" => Ok((
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("attachment; filename=\"daily-closing-{}.csv\"", date),
+                ),
+            ],
+            render_daily_closing_csv(&report),
+        )
+            .into_response()),
+        "pdf" => Ok((
+            [
+                (header::CONTENT_TYPE, "application/pdf".to_string()),
+                (
+                    header::CONTENT_DISPOSITION,
+                    format!("inline; filename=\"daily-closing-{}.pdf\"", date),
+                ),
+            ],
+            render_daily_closing_pdf(&report),
+        )
+            .into_response()),
+        other => Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!("不支援的格式: {}（僅接受 json、csv、pdf）", other))),
+        )),
+    }
+}
+
+const INVALID_JSON_RAW_PREVIEW: usize = 200;
+
+/// 掃描單一 JSON 欄位，回傳無法解析為 T 的資料列
+async fn scan_json_column<T: DeserializeOwned + Send + Unpin>(
+    pool: &SqlitePool,
+    table: &str,
+    column: &str,
+) -> Result<Vec<InvalidJsonRow>, sqlx::Error> {
+    let sql = format!(
+        "SELECT CAST(id AS TEXT), {column} FROM {table} WHERE {column} IS NOT NULL ORDER BY id",
+        column = column,
+        table = table
+    );
+    let rows: Vec<(String, JsonColumn<T>)> = sqlx::query_as(&sql).fetch_all(pool).await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|(id, value)| match value {
+            JsonColumn::Invalid { raw, error } => Some(InvalidJsonRow {
+                table: table.to_string(),
+                id,
+                column: column.to_string(),
+                error,
+                raw: raw.chars().take(INVALID_JSON_RAW_PREVIEW).collect(),
+            }),
+            _ => None,
+        })
+        .collect())
+}
+
+/// 列出所有 JSON 欄位內容無法解析的記錄（API 回應中這些欄位會是 null）
+pub async fn get_invalid_json_report(
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<Json<ApiResponse<Vec<InvalidJsonRow>>>, (StatusCode, Json<ApiResponse<Vec<InvalidJsonRow>>>)> {
+    let scans = [
+        scan_json_column::<Contact>(&pool, "registrationDB", "contact").await,
+        scan_json_column::<Blessing>(&pool, "registrationDB", "blessing").await,
+        scan_json_column::<Salvation>(&pool, "registrationDB", "salvation").await,
+        scan_json_column::<Vec<JoinItem>>(&pool, "joinRecordDB", "items").await,
+        scan_json_column::<Contact>(&pool, "joinRecordDB", "contact").await,
+        scan_json_column::<Vec<DonateItem>>(&pool, "monthlyDonateDB", "donateItems").await,
+        scan_json_column::<PriceTable>(&pool, "priceConfigDB", "prices").await,
+        scan_json_column::<Vec<String>>(&pool, "activityDB", "allowedItems").await,
+        scan_json_column::<Vec<String>>(&pool, "activityTemplateDB", "allowedItems").await,
+        scan_json_column::<Contact>(&pool, "mydata", "contact").await,
+    ];
+
+    let mut rows = Vec::new();
+    for scan in scans {
+        rows.extend(scan.map_err(|e| {
+            tracing::error!("掃描 JSON 欄位失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+            )
+        })?);
+    }
+
+    let message = if rows.is_empty() {
+        "所有 JSON 欄位皆可正常解析".to_string()
+    } else {
+        format!("共 {} 個 JSON 欄位無法解析", rows.len())
+    };
+    Ok(Json(ApiResponse::success_with_message(rows, message)))
+}
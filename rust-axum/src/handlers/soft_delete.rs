@@ -0,0 +1,210 @@
+// src/handlers/soft_delete.rs
+//! 軟刪除：刪除時只記錄 date_deleted（毫秒時間戳）與 user_deleted，
+//! 所有查詢以 NOT_DELETED 排除，可還原；超過保留期限後由背景工作永久刪除
+use axum::{
+    extract::{Extension, Query},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, TimeDelta, Utc};
+use sqlx::{sqlite::SqliteExecutor, Encode, Sqlite, SqlitePool, Type};
+
+use crate::models::api_response::ApiResponse;
+use crate::models::soft_delete::{PurgeDeletedQuery, PurgeDeletedResult, PurgedTableCount};
+
+/// 未刪除的條件
+pub(crate) const NOT_DELETED: &str = "date_deleted IS NULL";
+
+/// 使用軟刪除的資料表，依永久刪除的順序列出保留條件：先清除參照端（參加記錄、贊助），
+/// 仍被保留資料參照的報名、活動、價格配置不刪除；
+/// 已沖帳或有付款流水屬於沖帳批次的參加記錄不刪除，避免批次金額事後改變
+const PURGE_TABLES: &[(&str, &str)] = &[
+    (
+        "joinRecordDB",
+        "COALESCE(accountingState, '') != 'reconciled'
+         AND NOT EXISTS (
+            SELECT 1 FROM joinRecordPayments p
+            WHERE p.joinRecordId = joinRecordDB.id AND COALESCE(p.reconciliationBatchId, -1) != -1
+         )",
+    ),
+    ("monthlyDonateDB", "1 = 1"),
+    (
+        "activityDB",
+        "NOT EXISTS (SELECT 1 FROM joinRecordDB j WHERE j.activityId = activityDB.id)",
+    ),
+    (
+        "registrationDB",
+        "NOT EXISTS (SELECT 1 FROM joinRecordDB j WHERE j.registrationId = registrationDB.id)
+         AND NOT EXISTS (SELECT 1 FROM monthlyDonateDB m WHERE m.registrationId = registrationDB.id)",
+    ),
+    (
+        "priceConfigDB",
+        "NOT EXISTS (SELECT 1 FROM activityDB a WHERE a.priceConfigId = priceConfigDB.id)
+         AND NOT EXISTS (SELECT 1 FROM activityTemplateDB t WHERE t.priceConfigId = priceConfigDB.id)",
+    ),
+    ("mydata", "1 = 1"),
+];
+
+/// 預設保留天數
+const DEFAULT_RETENTION_DAYS: i64 = 90;
+/// 保留天數上限（100 年）
+const MAX_RETENTION_DAYS: i64 = 36500;
+/// 手動清除時的最少保留天數，避免誤按後無法再還原
+const MIN_PURGE_RETENTION_DAYS: i64 = 7;
+
+/// 標記為已刪除，返回是否有更新（已刪除或不存在時為 false）
+pub(crate) async fn soft_delete<'e, T>(
+    executor: impl SqliteExecutor<'e>,
+    table: &str,
+    id: T,
+    user_deleted: Option<&str>,
+) -> Result<bool, sqlx::Error>
+where
+    T: for<'q> Encode<'q, Sqlite> + Type<Sqlite> + Send + 'static,
+{
+    let query = format!(
+        "UPDATE {} SET date_deleted = ?, user_deleted = ? WHERE id = ? AND {}",
+        table, NOT_DELETED
+    );
+    let result = sqlx::query(&query)
+        .bind(Utc::now().timestamp_millis())
+        .bind(user_deleted.map(str::trim).filter(|u| !u.is_empty()))
+        .bind(id)
+        .execute(executor)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// 還原已刪除的資料，返回是否有更新（未刪除或不存在時為 false）
+pub(crate) async fn restore<'e, T>(
+    executor: impl SqliteExecutor<'e>,
+    table: &str,
+    id: T,
+) -> Result<bool, sqlx::Error>
+where
+    T: for<'q> Encode<'q, Sqlite> + Type<Sqlite> + Send + 'static,
+{
+    let query = format!(
+        "UPDATE {} SET date_deleted = NULL, user_deleted = NULL WHERE id = ? AND date_deleted IS NOT NULL",
+        table
+    );
+    let result = sqlx::query(&query).bind(id).execute(executor).await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// SOFT_DELETE_RETENTION_DAYS（預設 90，介於 MIN_PURGE_RETENTION_DAYS 與 MAX_RETENTION_DAYS）
+fn retention_days() -> i64 {
+    std::env::var("SOFT_DELETE_RETENTION_DAYS")
+        .ok()
+        .and_then(|v| v.trim().parse::<i64>().ok())
+        .unwrap_or(DEFAULT_RETENTION_DAYS)
+        .clamp(MIN_PURGE_RETENTION_DAYS, MAX_RETENTION_DAYS)
+}
+
+/// 永久刪除刪除時間超過 retention_days 天的資料（依 PURGE_TABLES 排除仍被參照或已沖帳的資料）；
+/// 參加記錄的付款流水一併刪除
+pub(crate) async fn purge_deleted(
+    pool: &SqlitePool,
+    retention_days: i64,
+) -> Result<PurgeDeletedResult, sqlx::Error> {
+    // 天數過大無法換算時，沒有任何資料早於該時間
+    let deleted_before = TimeDelta::try_days(retention_days)
+        .and_then(|days| Utc::now().checked_sub_signed(days))
+        .unwrap_or(DateTime::<Utc>::MIN_UTC);
+    let cutoff = deleted_before.timestamp_millis();
+
+    // 舊版建立的 joinRecordPayments 外鍵沒有 ON DELETE CASCADE，先刪除流水
+    let mut tx = pool.begin().await?;
+    let (_, join_record_guard) = PURGE_TABLES[0];
+    let query = format!(
+        "DELETE FROM joinRecordPayments WHERE joinRecordId IN (
+            SELECT id FROM joinRecordDB WHERE date_deleted IS NOT NULL AND date_deleted < ? AND {}
+        )",
+        join_record_guard
+    );
+    sqlx::query(&query).bind(cutoff).execute(&mut *tx).await?;
+
+    let mut by_table = Vec::new();
+    for (table, guard) in PURGE_TABLES {
+        let query = format!(
+            "DELETE FROM {} WHERE date_deleted IS NOT NULL AND date_deleted < ? AND {}",
+            table, guard
+        );
+        let result = sqlx::query(&query).bind(cutoff).execute(&mut *tx).await?;
+        if result.rows_affected() > 0 {
+            by_table.push(PurgedTableCount {
+                table: table.to_string(),
+                count: result.rows_affected(),
+            });
+        }
+    }
+    tx.commit().await?;
+
+    Ok(PurgeDeletedResult {
+        retention_days,
+        deleted_before: deleted_before.to_rfc3339(),
+        purged_count: by_table.iter().map(|t| t.count).sum(),
+        by_table,
+    })
+}
+
+/// 啟動背景工作：每隔 SOFT_DELETE_PURGE_INTERVAL 秒（預設 86400，0 表示停用）
+/// 永久刪除超過保留期限的已刪除資料；啟動時先執行一次
+pub fn spawn_deleted_purge(pool: SqlitePool) {
+    let seconds = std::env::var("SOFT_DELETE_PURGE_INTERVAL")
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .unwrap_or(86400);
+    if seconds == 0 {
+        tracing::info!("⏸️ 已刪除資料清除已停用（SOFT_DELETE_PURGE_INTERVAL=0）");
+        return;
+    }
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(seconds));
+        loop {
+            interval.tick().await;
+            match purge_deleted(&pool, retention_days()).await {
+                Ok(result) if result.purged_count == 0 => {}
+                Ok(result) => tracing::info!(
+                    "🗑️ 已永久刪除 {} 筆超過 {} 天的已刪除資料",
+                    result.purged_count,
+                    result.retention_days
+                ),
+                Err(e) => tracing::error!("清除已刪除資料失敗: {}", e),
+            }
+        }
+    });
+}
+
+/// 立即永久刪除超過保留期限的已刪除資料（與背景工作相同，可用 retentionDays 指定天數，
+/// 至少 MIN_PURGE_RETENTION_DAYS 天）
+pub async fn purge_deleted_records(
+    Query(params): Query<PurgeDeletedQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<
+    Json<ApiResponse<PurgeDeletedResult>>,
+    (StatusCode, Json<ApiResponse<PurgeDeletedResult>>),
+> {
+    let retention_days = params.retention_days.unwrap_or_else(retention_days);
+    if !(MIN_PURGE_RETENTION_DAYS..=MAX_RETENTION_DAYS).contains(&retention_days) {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            Json(ApiResponse::error(format!(
+                "retentionDays 需介於 {} 至 {} 天",
+                MIN_PURGE_RETENTION_DAYS, MAX_RETENTION_DAYS
+            ))),
+        ));
+    }
+
+    let result = purge_deleted(&pool, retention_days).await.map_err(|e| {
+        tracing::error!("清除已刪除資料失敗: {}", e);
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(ApiResponse::error(format!("清除失敗: {}", e))),
+        )
+    })?;
+
+    let message = format!("已永久刪除 {} 筆資料", result.purged_count);
+    Ok(Json(ApiResponse::success_with_message(result, message)))
+}
@@ -0,0 +1,251 @@
+// src/handlers/taisui.rs
+use axum::{
+    extract::{Extension, Query},
+    http::StatusCode,
+    Json,
+};
+use chrono::Datelike;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+
+use crate::handlers::registration::{
+    normalize_text, ARCHIVED_STATE, REGISTRATION_FULL_QUERY, ZODIACS,
+};
+use crate::models::api_response::{ApiResponse, Meta};
+use crate::models::registration::Registration;
+use crate::models::taisui::{
+    TaiSuiCandidate, TaiSuiCandidateQuery, TaiSuiQuery, TaiSuiTypes, TaiSuiYear, ZodiacLamp,
+};
+use crate::utils::lunar::{
+    year_dizhi_index, year_tiangan_index, DIZHIS, MAX_YEAR, MIN_YEAR, TIANGANS,
+};
+
+const ZODIAC_ICONS: &[&str] = &[
+    "🐭", "🐂", "🐯", "🐰", "🐉", "🐍", "🐴", "🐏", "🐒", "🐓", "🐶", "🐷",
+];
+
+/// 太歲類型與相對當年地支的位移（與前端 taisuiStore.getTaiSuiTypes 相同），依值、沖、刑、害、破排序
+const TAI_SUI_OFFSETS: &[(&str, usize)] = &[
+    ("值太歲", 0),
+    ("沖太歲", 6),
+    ("刑太歲", 3),
+    ("害太歲", 9),
+    ("破太歲", 8),
+];
+
+/// 點燈表：索引為生肖相對當年地支的位移（與前端 taisuiStore.dotLampNames 相同）
+const LAMP_TABLE: &[(&[&str], &str)] = &[
+    (&["太歲", "驚天", "劍峰"], "太歲燈"),
+    (&["病符", "凶神"], "元辰燈"),
+    (&["天狗", "八座", "吊客"], "光明燈"),
+    (&["卷舌"], "元辰燈"),
+    (&["白虎", "天殺", "地殺"], "光明燈"),
+    (&["天厄"], "元辰燈"),
+    (&["歲破", "大耗"], "太歲燈"),
+    (&["死符", "小耗"], "元辰燈"),
+    (&["五鬼", "官符"], "光明燈"),
+    (&["勾絞", "羊刄", "空亡"], "元辰燈"),
+    (&["喪門", "地喪"], "光明燈"),
+    (&["天空", "劫殺"], "光明燈"),
+];
+
+/// 驗證年份，未提供時取伺服器本地今年
+fn resolve_year(year: Option<i32>) -> Result<i32, String> {
+    let year = year.unwrap_or_else(|| chrono::Local::now().year());
+    if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+        return Err(format!("請輸入合理的年份（{}-{}）", MIN_YEAR, MAX_YEAR));
+    }
+    Ok(year)
+}
+
+/// 生肖當年的點燈資料
+fn zodiac_lamp(year_index: usize, zodiac_index: usize) -> ZodiacLamp {
+    let offset = (zodiac_index + 12 - year_index) % 12;
+    let (notes, lamp_name) = LAMP_TABLE[offset];
+    // 虛歲 1 歲為當年生肖，往前每早一個生肖多 11 歲（模 12）
+    let first_age = if offset == 0 { 1 } else { 13 - offset as i64 };
+
+    ZodiacLamp {
+        zodiac: ZODIACS[zodiac_index].to_string(),
+        zodiac_icon: ZODIAC_ICONS[zodiac_index].to_string(),
+        dizhi: DIZHIS[zodiac_index].to_string(),
+        tai_sui_type: TAI_SUI_OFFSETS
+            .iter()
+            .find(|(_, o)| *o == offset)
+            .map(|(label, _)| label.to_string()),
+        notes: notes.iter().map(|n| n.to_string()).collect(),
+        ages: (0..9).map(|i| first_age + 12 * i).collect(),
+        lamp_name: lamp_name.to_string(),
+    }
+}
+
+/// 計算指定年份的干支、生肖與犯太歲的生肖
+fn tai_sui_year(year: i32) -> TaiSuiYear {
+    let year_index = year_dizhi_index(year);
+    let tiangan_index = year_tiangan_index(year);
+    let zodiac_at = |offset: usize| ZODIACS[(year_index + offset) % 12].to_string();
+
+    TaiSuiYear {
+        year,
+        tiangan: TIANGANS[tiangan_index].to_string(),
+        dizhi: DIZHIS[year_index].to_string(),
+        zodiac: ZODIACS[year_index].to_string(),
+        zodiac_icon: ZODIAC_ICONS[year_index].to_string(),
+        tai_sui_types: TaiSuiTypes {
+            value_tai_sui: zodiac_at(0),
+            chong_tai_sui: zodiac_at(6),
+            hai_tai_sui: zodiac_at(9),
+            po_tai_sui: zodiac_at(8),
+            xing_tai_sui: zodiac_at(3),
+        },
+        affected: TAI_SUI_OFFSETS
+            .iter()
+            .map(|(_, offset)| zodiac_lamp(year_index, (year_index + offset) % 12))
+            .collect(),
+        zodiacs: (0..12).map(|i| zodiac_lamp(year_index, i)).collect(),
+    }
+}
+
+/// 指定年份值 / 沖 / 刑 / 害 / 破太歲的生肖與十二生肖點燈表
+pub async fn get_tai_sui(
+    Query(params): Query<TaiSuiQuery>,
+) -> Result<Json<ApiResponse<TaiSuiYear>>, (StatusCode, Json<ApiResponse<TaiSuiYear>>)> {
+    let year = resolve_year(params.year)
+        .map_err(|message| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(message))))?;
+    Ok(Json(ApiResponse::success(tai_sui_year(year))))
+}
+
+/// 列出生肖犯太歲的消災人員（排除已封存的報名），同一戶（未歸戶時為同一報名）同名者只列最近一次報名
+pub async fn get_tai_sui_candidates(
+    Query(params): Query<TaiSuiCandidateQuery>,
+    Extension(pool): Extension<SqlitePool>,
+) -> Result<
+    Json<ApiResponse<Vec<TaiSuiCandidate>>>,
+    (StatusCode, Json<ApiResponse<Vec<TaiSuiCandidate>>>),
+> {
+    let year = resolve_year(params.year)
+        .map_err(|message| (StatusCode::BAD_REQUEST, Json(ApiResponse::error(message))))?;
+    let type_filter = params
+        .tai_sui_type
+        .as_deref()
+        .map(str::trim)
+        .filter(|t| !t.is_empty());
+    if let Some(filter) = type_filter {
+        if !TAI_SUI_OFFSETS.iter().any(|(label, _)| *label == filter) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                Json(ApiResponse::error(format!(
+                    "無效的太歲類型: {}（可用：值太歲、沖太歲、刑太歲、害太歲、破太歲）",
+                    filter
+                ))),
+            ));
+        }
+    }
+
+    let query = format!(
+        "{} WHERE state IS NULL OR state != ? ORDER BY id",
+        REGISTRATION_FULL_QUERY
+    );
+    let registrations = sqlx::query_as::<_, Registration>(&query)
+        .bind(ARCHIVED_STATE)
+        .fetch_all(&pool)
+        .await
+        .map_err(|e| {
+            tracing::error!("查詢報名記錄失敗: {}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ApiResponse::error(format!("查詢失敗: {}", e))),
+            )
+        })?;
+
+    // 生肖 → (太歲類型順序, 點燈資料)
+    let year_index = year_dizhi_index(year);
+    let affected: HashMap<&str, (usize, ZodiacLamp)> = TAI_SUI_OFFSETS
+        .iter()
+        .enumerate()
+        .map(|(order, (_, offset))| {
+            let zodiac_index = (year_index + offset) % 12;
+            (
+                ZODIACS[zodiac_index],
+                (order, zodiac_lamp(year_index, zodiac_index)),
+            )
+        })
+        .filter(|(_, (_, lamp))| {
+            type_filter.is_none_or(|t| lamp.tai_sui_type.as_deref() == Some(t))
+        })
+        .collect();
+
+    // 依 id 遞增處理，後出現的報名覆蓋同一人的舊資料
+    let mut candidates: HashMap<(String, String), (usize, TaiSuiCandidate)> = HashMap::new();
+    for record in registrations {
+        let household_id = record.household_id.filter(|id| *id > 0);
+        let owner = match household_id {
+            Some(id) => format!("household:{}", id),
+            None => format!("registration:{}", record.id),
+        };
+        let contact = record
+            .contact
+            .into_reported("registrationDB.contact", record.id)
+            .unwrap_or_default();
+        let blessing = record
+            .blessing
+            .into_reported("registrationDB.blessing", record.id)
+            .unwrap_or_default();
+
+        for person in &blessing.persons {
+            let name = normalize_text(&person.name);
+            let Some((order, lamp)) = affected.get(person.zodiac.trim()) else {
+                continue;
+            };
+            if name.is_empty() {
+                continue;
+            }
+            candidates.insert(
+                (owner.clone(), name),
+                (
+                    *order,
+                    TaiSuiCandidate {
+                        registration_id: record.id,
+                        household_id,
+                        person_name: person.name.trim().to_string(),
+                        zodiac: lamp.zodiac.clone(),
+                        tai_sui_type: lamp.tai_sui_type.clone().unwrap_or_default(),
+                        lamp_name: lamp.lamp_name.clone(),
+                        contact_name: contact.name.clone(),
+                        mobile: contact.mobile.clone(),
+                        phone: contact.phone.clone(),
+                        address: blessing.address.clone(),
+                        registered_at: record.created_at.clone(),
+                    },
+                ),
+            );
+        }
+    }
+
+    let mut candidates: Vec<(usize, TaiSuiCandidate)> = candidates.into_values().collect();
+    candidates.sort_by(|(a_order, a), (b_order, b)| {
+        a_order
+            .cmp(b_order)
+            .then(a.registration_id.cmp(&b.registration_id))
+            .then(a.person_name.cmp(&b.person_name))
+    });
+
+    let total = candidates.len() as i64;
+    let limit = params.limit.unwrap_or(500).max(0);
+    let offset = params.offset.unwrap_or(0).max(0);
+    let page: Vec<TaiSuiCandidate> = candidates
+        .into_iter()
+        .skip(offset as usize)
+        .take(limit as usize)
+        .map(|(_, candidate)| candidate)
+        .collect();
+
+    Ok(Json(ApiResponse::success_with_meta(
+        page,
+        Meta {
+            total,
+            limit: Some(limit),
+            offset: Some(offset),
+        },
+    )))
+}
@@ -13,6 +13,7 @@ mod db;
 mod handlers;
 mod models;
 mod routes;
+mod utils; // ✅ 新增：共用輸出工具 by 20261019
 
 // 重新導出 ApiResponse 和 Meta,這樣編譯器知道它們被外部使用
 pub use models::api_response::{ApiResponse, Meta};
@@ -125,6 +126,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     
     
+    // ✅ 新增：背景工作，定期將日期已過的活動標記為已完成 by 20261019
+    handlers::activity::spawn_activity_auto_complete(pool.clone());
+
+    // ✅ 新增：背景工作，定期永久刪除超過保留期限的已刪除資料 by 20261019
+    handlers::soft_delete::spawn_deleted_purge(pool.clone());
+
     // 創建應用狀態
     let state = Arc::new(AppState {
         pool: pool.clone(),
@@ -147,6 +154,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let directus_users_routes = routes::directus_users::create_routes();
     let price_config_routes = routes::price_config::create_routes(); // ✅ 新增：價格配置路由 by 20260331    
     let join_record_routes = routes::join_record::create_routes(); // ✅ 新增：加入紀錄路由 by 20260422
+    let join_record_payment_routes = routes::join_record_payment::create_routes(); // ✅ 新增：付款流水路由 by 20261019
+    let reconciliation_batch_routes = routes::reconciliation_batch::create_routes(); // ✅ 新增：沖帳批次路由 by 20261019
+    let report_routes = routes::report::create_routes(); // ✅ 新增：報表路由 by 20261019
+    let import_routes = routes::import::create_routes(); // ✅ 新增：資料匯入路由 by 20261019
+    let household_routes = routes::household::create_routes(); // ✅ 新增：戶路由 by 20261019
+    let taisui_routes = routes::taisui::create_routes(); // ✅ 新增：太歲路由 by 20261019
+    let calendar_routes = routes::calendar::create_routes(); // ✅ 新增：農曆曆法路由 by 20261019
+    let activity_template_routes = routes::activity_template::create_routes(); // ✅ 新增：活動範本路由 by 20261019
+    let activity_summary_routes = routes::activity_summary::create_routes(); // ✅ 新增：活動財務摘要路由 by 20261019
+    let activity_roster_routes = routes::activity_roster::create_routes(); // ✅ 新增：法會名冊路由 by 20261019
+    let activity_calendar_routes = routes::activity_calendar::create_routes(); // ✅ 新增：活動行事曆路由 by 20261019
+    let soft_delete_routes = routes::soft_delete::create_routes(); // ✅ 新增：軟刪除路由 by 20261019
 
     // ✅ 創建 SqliteProvider(DatabaseProvider 的實現)
     let sql_viewer_router = SqlViewerLayer::sqlite("/sql-viewer", pool.clone()).into_router();
@@ -168,6 +187,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .merge(directus_users_routes)
         .merge(price_config_routes) // ✅ 新增：價格配置路由 by 20260331        
         .merge(join_record_routes) // ✅ 新增：加入紀錄路由 by 20260422
+        .merge(join_record_payment_routes) // ✅ 新增：付款流水路由 by 20261019
+        .merge(reconciliation_batch_routes) // ✅ 新增：沖帳批次路由 by 20261019
+        .merge(report_routes) // ✅ 新增：報表路由 by 20261019
+        .merge(import_routes) // ✅ 新增：資料匯入路由 by 20261019
+        .merge(household_routes) // ✅ 新增：戶路由 by 20261019
+        .merge(taisui_routes) // ✅ 新增：太歲路由 by 20261019
+        .merge(calendar_routes) // ✅ 新增：農曆曆法路由 by 20261019
+        .merge(activity_template_routes) // ✅ 新增：活動範本路由 by 20261019
+        .merge(activity_summary_routes) // ✅ 新增：活動財務摘要路由 by 20261019
+        .merge(activity_roster_routes) // ✅ 新增：法會名冊路由 by 20261019
+        .merge(activity_calendar_routes) // ✅ 新增：活動行事曆路由 by 20261019
+        .merge(soft_delete_routes) // ✅ 新增：軟刪除路由 by 20261019
         // Add the SQL viewer at /sql-viewer
         .merge(sql_viewer_router)
         .layer(Extension(state.clone()))
@@ -202,6 +233,28 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     tracing::info!("  GET    /api/directus-users           - DIRECTUS使用者");
     tracing::info!("  GET    /api/price-config             - 價格配置列表"); // ✅ 新增：價格配置端點 by 20260331    
     tracing::info!("  GET    /api/join-records             - 參與記錄列表"); // ✅ 新增：加入紀錄端點 by 20260422
+    tracing::info!("  GET    /api/join-records/{{id}}/payments - 付款流水"); // ✅ 新增：付款流水端點 by 20261019
+    tracing::info!("  GET    /api/reconciliation-batches   - 沖帳批次列表"); // ✅ 新增：沖帳批次端點 by 20261019
+    tracing::info!("  GET    /api/reports/daily-closing    - 每日結帳報表"); // ✅ 新增：報表端點 by 20261019
+    tracing::info!("  GET    /api/reports/invalid-json     - 無法解析的 JSON 欄位"); // ✅ 新增：JSON 欄位檢查 by 20261019
+    tracing::info!("  GET    /api/{{resource}}/export       - 匯出 CSV / XLSX / JSON（monthly-donates、join-records、registrations）"); // ✅ 新增：匯出端點 by 20261019
+    tracing::info!("  POST   /api/import/{{collection}}     - 匯入 CSV / XLSX（預設 dryRun 只驗證）"); // ✅ 新增：匯入端點 by 20261019
+    tracing::info!("  GET    /api/registrations/duplicates - 疑似重複的報名記錄"); // ✅ 新增：重複報名偵測 by 20261019
+    tracing::info!("  POST   /api/registrations/merge     - 合併報名記錄"); // ✅ 新增：報名合併 by 20261019
+    tracing::info!("  GET    /api/households/{{id}}         - 戶的完整歷史（成員、活動、付款、收據）"); // ✅ 新增：戶 by 20261019
+    tracing::info!("  POST   /api/households/derive       - 由報名記錄推導戶別（預設 dryRun）"); // ✅ 新增：歸戶 by 20261019
+    tracing::info!("  GET    /api/taisui?year=2027        - 值/沖/刑/害/破太歲生肖與點燈表"); // ✅ 新增：太歲 by 20261019
+    tracing::info!("  GET    /api/registrations/taisui-candidates - 生肖犯太歲的消災人員"); // ✅ 新增：太歲名單 by 20261019
+    tracing::info!("  GET    /api/calendar/lunar?year=2027 - 農曆年曆與節日國曆日期"); // ✅ 新增：農曆年曆 by 20261019
+    tracing::info!("  GET    /api/calendar/convert        - 國曆 ↔ 農曆換算"); // ✅ 新增：農曆換算 by 20261019
+    tracing::info!("  GET    /api/activity-templates      - 活動範本列表（POST 新增，/{{id}} 查詢 / 更新 / 刪除）"); // ✅ 新增：活動範本 by 20261019
+    tracing::info!("  POST   /api/activities/from-template - 由範本建立指定年份的活動"); // ✅ 新增：由範本建立活動 by 20261019
+    tracing::info!("  POST   /api/activities/auto-complete - 將日期已過的進行中活動標記為已完成"); // ✅ 新增：活動自動完成 by 20261019
+    tracing::info!("  GET    /api/activities/{{id}}/summary - 活動財務摘要（項目、付款、收據、沖帳）"); // ✅ 新增：活動財務摘要 by 20261019
+    tracing::info!("  GET    /api/activities/{{id}}/roster - 法會名冊（type=chaodu|qifu|diandeng，format=json|csv|pdf）"); // ✅ 新增：法會名冊 by 20261019
+    tracing::info!("  GET    /api/activities.ics - 活動行事曆訂閱（iCalendar，item_type、state 可逗號分隔）"); // ✅ 新增：活動行事曆 by 20261019
+    tracing::info!("  POST   /api/{{collection}}/{{id}}/restore - 還原已刪除的活動、報名、參加記錄、每月贊助、價格配置、表單資料"); // ✅ 新增：軟刪除 by 20261019
+    tracing::info!("  POST   /api/deleted/purge - 永久刪除超過保留期限的已刪除資料（retentionDays 可指定天數）"); // ✅ 新增：軟刪除 by 20261019
     
     tracing::info!("");
     tracing::info!("💡🦀 [Rust] 提示: Directus 管理 Auth,Axum 處理數據 CRUD");
@@ -249,7 +302,11 @@ async fn root_handler() -> Json<Value> {
             "price_configs": "/api/price-config",
             "db_test": "/db-test",
             "sql_viewer": "/sql-viewer",
-            "join_records": "/api/join-records"
+            "join_records": "/api/join-records",
+            "join_record_payments": "/api/join-records/{id}/payments",
+            "reconciliation_batches": "/api/reconciliation-batches",
+            "daily_closing_report": "/api/reports/daily-closing",
+            "invalid_json_report": "/api/reports/invalid-json"
         },
         "architecture": {
             "auth_backend": "Directus (login, users, permissions)",
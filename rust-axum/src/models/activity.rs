@@ -2,6 +2,9 @@
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
+use crate::models::json_column::JsonColumn;
+use crate::utils::lunar;
+
 /// 活動模型 - 完全對應 Directus 的 activityDB 表結構
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Activity {
@@ -29,6 +32,24 @@ pub struct Activity {
     pub icon: Option<String>,         // varchar(255) DEFAULT '🕯️'
     pub description: Option<String>,  // text
     pub location: Option<String>,     // varchar(255)
+
+    // 農曆日期（依農曆排定的法會），國曆 date 由此換算
+    #[sqlx(rename = "lunarMonth", default)]
+    pub lunar_month: Option<i64>,     // integer
+    #[sqlx(rename = "lunarDay", default)]
+    pub lunar_day: Option<i64>,       // integer
+    #[sqlx(rename = "lunarLeapMonth", default)]
+    pub lunar_leap_month: Option<bool>, // integer DEFAULT 0
+
+    // 範本與報名設定
+    #[sqlx(rename = "templateId", default)]
+    pub template_id: Option<i64>,     // integer DEFAULT -1
+    #[sqlx(rename = "allowedItems", default)]
+    pub allowed_items: JsonColumn<Vec<String>>, // json，NULL 表示不限項目
+    #[sqlx(rename = "priceConfigId", default)]
+    pub price_config_id: Option<i64>, // integer DEFAULT -1（生效中的價格配置）
+    #[sqlx(default)]
+    pub capacity: Option<i64>,        // integer，NULL 表示不限名額
     
     // 自定義時間戳
     #[sqlx(rename = "createdAt")]
@@ -61,18 +82,40 @@ pub struct ActivityResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub item_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub participants: Option<i32>,
+    pub participants: Option<i32>, // 參加記錄筆數（由 joinRecordDB 計算）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub participant_counts: Option<ActivityParticipantCounts>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capacity: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_capacity: Option<i64>, // 剩餘名額，不限名額時為空
     #[serde(skip_serializing_if = "Option::is_none")]
     pub date: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub state_label: Option<String>, // 籌劃中 / 進行中 / 已完成 / 已取消
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub location: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub lunar_month: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lunar_day: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lunar_leap_month: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lunar_date_text: Option<String>, // 例如「七月十五」
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_items: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_config_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<String>,
@@ -81,6 +124,14 @@ pub struct ActivityResponse {
 /// 從 Activity 到 ActivityResponse 的轉換
 impl From<Activity> for ActivityResponse {
     fn from(data: Activity) -> Self {
+        let lunar_date_text = match (data.lunar_month, data.lunar_day) {
+            (Some(month), Some(day)) if month > 0 && day > 0 => Some(format!(
+                "{}{}",
+                lunar::month_text(month as u32, data.lunar_leap_month.unwrap_or(false)),
+                lunar::day_text(day as u32)
+            )),
+            _ => None,
+        };
         Self {
             id: data.id,
             user_created: data.user_created,
@@ -91,17 +142,132 @@ impl From<Activity> for ActivityResponse {
             name: data.name,
             item_type: data.item_type,
             participants: data.participants,
+            participant_counts: None,
+            capacity: data.capacity,
+            remaining_capacity: None,
             date: data.date,
+            state_label: data
+                .state
+                .as_deref()
+                .and_then(|s| ActivityState::parse(s).ok())
+                .map(|s| s.label().to_string()),
             state: data.state,
             icon: data.icon,
             description: data.description,
             location: data.location,
+            lunar_month: data.lunar_month,
+            lunar_day: data.lunar_day,
+            lunar_leap_month: data.lunar_leap_month,
+            lunar_date_text,
+            template_id: data.template_id,
+            allowed_items: data.allowed_items.into_reported("activityDB.allowedItems", data.id),
+            price_config_id: data.price_config_id,
             created_at: data.created_at,
             updated_at: data.updated_at,
         }
     }
 }
 
+impl ActivityResponse {
+    /// 以參加記錄統計取代資料庫中的 participants
+    pub fn with_counts(mut self, counts: ActivityParticipantCounts) -> Self {
+        self.participants = Some(counts.record_count as i32);
+        self.remaining_capacity = self
+            .capacity
+            .map(|capacity| (capacity - counts.record_count).max(0));
+        self.participant_counts = Some(counts);
+        self
+    }
+}
+
+/// 單一項目類型的報名統計
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemTypeCount {
+    pub item_type: String,
+    pub label: String,
+    pub record_count: i64, // 含此項目的參加記錄筆數
+    pub quantity: i64,     // 項目數量合計（人數、祖先數或盞數）
+}
+
+/// 活動參加統計（由 joinRecordDB 計算，不含已取消的記錄）
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityParticipantCounts {
+    pub record_count: i64,
+    pub people_count: i64,   // 消災人員與陽上人（同一筆記錄同名只計一次）
+    pub ancestor_count: i64, // 超度祖先
+    pub by_item_type: Vec<ItemTypeCount>,
+}
+
+/// 活動自動完成結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityAutoCompleteResult {
+    pub completed_count: u64,
+    pub before_date: String, // 日期早於此日的進行中活動會標記為已完成
+}
+
+/// 活動狀態：planning → active → completed，任何狀態皆可取消
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ActivityState {
+    Planning,  // 籌劃中，尚未開放報名
+    Active,    // 進行中，開放報名參與
+    Completed, // 已完成
+    Cancelled, // 已取消
+}
+
+impl ActivityState {
+    /// 解析狀態字串；舊資料的 upcoming / ongoing 視為 active，canceled 視為 cancelled
+    pub fn parse(state: &str) -> Result<Self, String> {
+        match state.trim() {
+            "planning" => Ok(ActivityState::Planning),
+            "active" | "upcoming" | "ongoing" => Ok(ActivityState::Active),
+            "completed" => Ok(ActivityState::Completed),
+            "cancelled" | "canceled" => Ok(ActivityState::Cancelled),
+            other => Err(format!(
+                "不支援的活動狀態: {}（僅接受 planning、active、completed、cancelled）",
+                other
+            )),
+        }
+    }
+
+    /// 資料庫中的狀態；空值或無法辨識時視為 planning
+    pub fn from_stored(state: Option<&str>) -> Self {
+        state
+            .and_then(|s| Self::parse(s).ok())
+            .unwrap_or(ActivityState::Planning)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ActivityState::Planning => "planning",
+            ActivityState::Active => "active",
+            ActivityState::Completed => "completed",
+            ActivityState::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ActivityState::Planning => "籌劃中",
+            ActivityState::Active => "進行中",
+            ActivityState::Completed => "已完成",
+            ActivityState::Cancelled => "已取消",
+        }
+    }
+
+    /// 是否可由目前狀態變更為 next（維持原狀態視為允許）
+    pub fn can_transition_to(self, next: Self) -> bool {
+        use ActivityState::*;
+        self == next
+            || matches!(
+                (self, next),
+                (Planning, Active) | (Active, Completed) | (_, Cancelled)
+            )
+    }
+}
+
 /// 創建活動請求 - 只包含必要字段（Directus 字段由系統處理）
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -110,15 +276,25 @@ pub struct CreateActivityRequest {
     pub name: String,
     #[serde(default = "default_item_type")]
     pub item_type: String,
-    #[serde(default)]
-    pub participants: i32,
-    pub date: String,
+    pub date: Option<String>, // 有農曆日期時可省略，由農曆換算（預設 09:00）
     #[serde(default = "default_state")]
     pub state: String,
     #[serde(default = "default_icon")]
     pub icon: String,
     pub description: Option<String>,
     pub location: Option<String>,
+
+    // 農曆日期：提供 lunarMonth 與 lunarDay 時以農曆為準換算 date
+    pub lunar_year: Option<i32>, // 預設為 date 所在的農曆年，未提供 date 時為今年
+    pub lunar_month: Option<u32>,
+    pub lunar_day: Option<u32>,
+    #[serde(default)]
+    pub lunar_leap_month: bool,
+
+    // 可報名的項目類型（空值表示不限）與價格配置 ID（未提供時使用生效中的配置）
+    pub allowed_items: Option<Vec<String>>,
+    pub price_config_id: Option<i64>,
+    pub capacity: Option<i64>, // 名額上限，未提供或 <= 0 表示不限
 }
 
 /// 更新活動請求
@@ -127,12 +303,23 @@ pub struct CreateActivityRequest {
 pub struct UpdateActivityRequest {
     pub name: Option<String>,
     pub item_type: Option<String>,
-    pub participants: Option<i32>,
     pub date: Option<String>,
-    pub state: Option<String>,
+    pub state: Option<String>, // 需符合狀態流程，見 ActivityState::can_transition_to
+    #[serde(default)]
+    pub cascade_join_records: bool, // 取消活動時一併取消所有參加記錄
     pub icon: Option<String>,
     pub description: Option<String>,
     pub location: Option<String>,
+
+    // 農曆日期：提供任一欄位時依農曆重新換算 date（保留原本的時間）
+    pub lunar_year: Option<i32>,
+    pub lunar_month: Option<u32>,
+    pub lunar_day: Option<u32>,
+    pub lunar_leap_month: Option<bool>,
+
+    pub allowed_items: Option<Vec<String>>, // 空陣列表示不限
+    pub price_config_id: Option<i64>,       // -1 表示生效中的價格配置
+    pub capacity: Option<i64>,              // <= 0 表示不限
 }
 
 /// 查詢參數
@@ -152,7 +339,7 @@ fn default_item_type() -> String {
 }
 
 fn default_state() -> String {
-    "upcoming".to_string()
+    "planning".to_string()
 }
 
 fn default_icon() -> String {
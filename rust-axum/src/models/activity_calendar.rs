@@ -0,0 +1,11 @@
+// src/models/activity_calendar.rs
+use serde::Deserialize;
+
+/// 活動行事曆（iCalendar）查詢參數，多個值以逗號分隔
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityCalendarQuery {
+    #[serde(alias = "item_type")]
+    pub item_type: Option<String>, // 例如 'ceremony,lecture'，未指定表示全部
+    pub state: Option<String>, // 例如 'active,completed'，未指定表示全部（已取消的活動以 STATUS:CANCELLED 輸出）
+}
@@ -0,0 +1,52 @@
+// src/models/activity_roster.rs
+use serde::{Deserialize, Serialize};
+
+/// 法會名冊查詢參數
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityRosterQuery {
+    #[serde(rename = "type")]
+    pub roster_type: Option<String>, // 'chaodu' | 'qifu' | 'diandeng'，預設 chaodu
+    pub format: Option<String>, // 'json' | 'csv' | 'pdf'，預設 json
+}
+
+/// 法會名冊（超度牌位 / 消災疏文 / 點燈名單），依地址分組
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityRoster {
+    pub id: i64,
+    pub activity_id: Option<String>,
+    pub name: Option<String>,
+    pub date: Option<String>,
+    pub roster_type: String,
+    pub roster_label: String,
+    pub group_count: i64,
+    pub entry_count: i64,
+    pub groups: Vec<RosterGroup>,
+}
+
+/// 同一地址的名冊
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RosterGroup {
+    pub address: String, // 未填地址時為空字串
+    pub entries: Vec<RosterEntry>,
+}
+
+/// 名冊中的一筆（牌位或人員），同一地址重複的只列一次
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RosterEntry {
+    pub name: String, // 超度為牌位稱謂（姓氏 + 說明，例如「王府歷代祖先」），其餘為姓名
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zodiac: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lamp_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lamp_type_label: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub survivors: Vec<String>, // 超度牌位的陽上人
+    #[serde(skip)]
+    pub is_household_head: bool,
+    pub join_record_ids: Vec<i64>,
+}
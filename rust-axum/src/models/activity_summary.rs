@@ -0,0 +1,113 @@
+// src/models/activity_summary.rs
+use serde::Serialize;
+
+/// 活動財務摘要（GET /api/activities/{id}/summary），不含已取消的參加記錄
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivitySummary {
+    pub id: i64,
+    pub activity_id: Option<String>,
+    pub name: Option<String>,
+    pub date: Option<String>,
+    pub state: Option<String>,
+    pub record_count: i64,
+    pub amounts: ActivitySummaryAmounts,
+    pub by_item_type: Vec<ItemTypeSummary>,
+    pub by_payment_state: Vec<PaymentStateSummary>,
+    pub by_payment_method: Vec<PaymentMethodSummary>,
+    pub receipts: ReceiptSummary,
+    pub reconciliation: ReconciliationSummary,
+    pub cancelled: CancelledSummary,
+}
+
+/// 金額合計
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivitySummaryAmounts {
+    pub total_amount: i64,
+    pub discount_amount: i64,
+    pub final_amount: i64,
+    pub paid_amount: i64,
+    pub outstanding_amount: i64, // 各記錄未收金額合計（finalAmount - paidAmount > 0 的部分）
+    pub overpaid_amount: i64,    // 各記錄溢收金額合計
+}
+
+/// 依項目類型（items[].type）統計
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ItemTypeSummary {
+    pub item_type: String,
+    pub label: String,
+    pub record_count: i64,
+    pub quantity: i64,
+    pub amount: i64, // items[].subtotal 合計
+}
+
+/// 依付款狀態統計
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentStateSummary {
+    pub payment_state: String, // 'unpaid' | 'partial' | 'paid' | 'waived'
+    pub label: String,
+    pub record_count: i64,
+    pub final_amount: i64,
+    pub paid_amount: i64,
+}
+
+/// 依付款方式統計：有付款流水以流水為準（退款、沖銷為負數），舊記錄以 paidAmount 計
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PaymentMethodSummary {
+    pub payment_method: String,
+    pub label: String,
+    pub payment_count: i64,
+    pub amount: i64,
+}
+
+/// 收據開立統計
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceiptSummary {
+    pub by_type: Vec<ReceiptTypeSummary>,
+    pub pending_count: i64, // 需要收據但尚未開立
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceiptTypeSummary {
+    pub receipt_type: String, // 'stamp' 收據 | 'standard' 感謝狀
+    pub label: String,
+    pub record_count: i64,
+    pub amount: i64, // finalAmount 合計
+}
+
+/// 沖帳狀態統計（只計有收款的記錄，以付款流水各自的沖帳批次判斷，部分沖帳的記錄兩邊都計入）
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconciliationSummary {
+    pub reconciled_count: i64,
+    pub reconciled_amount: i64,
+    pub unreconciled_count: i64,
+    pub unreconciled_amount: i64,
+    pub batches: Vec<ReconciliationBatchRef>,
+}
+
+/// 包含本活動記錄的沖帳批次
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconciliationBatchRef {
+    pub id: i64,
+    pub batch_number: String,
+    pub batch_date: String,
+    pub state: String,
+    pub record_count: i64, // 本活動在此批次中的記錄數
+    pub amount: i64,       // 本活動在此批次中的沖帳金額
+}
+
+/// 已取消的記錄：仍有已收金額時需辦理退款
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelledSummary {
+    pub record_count: i64,
+    pub paid_amount: i64,
+}
@@ -0,0 +1,261 @@
+// src/models/activity_template.rs
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::models::json_column::JsonColumn;
+use crate::utils::lunar;
+
+/// 活動範本模型 - 對應 activityTemplateDB 表結構（每年重複舉辦的法會）
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ActivityTemplate {
+    // Directus 系統字段
+    pub id: i64,
+    #[sqlx(default)]
+    pub user_created: Option<String>,
+    #[sqlx(default)]
+    pub date_created: Option<String>,
+    #[sqlx(default)]
+    pub user_updated: Option<String>,
+    #[sqlx(default)]
+    pub date_updated: Option<String>,
+
+    // 業務字段
+    #[sqlx(default)]
+    pub name: Option<String>, // 可用 {year}、{rocYear} 代入年份
+    #[sqlx(default)]
+    pub item_type: Option<String>,
+    #[sqlx(default)]
+    pub icon: Option<String>,
+    #[sqlx(default)]
+    pub description: Option<String>,
+    #[sqlx(default)]
+    pub location: Option<String>,
+
+    // 排定日期：農曆月日或國曆月日擇一
+    #[sqlx(rename = "lunarMonth", default)]
+    pub lunar_month: Option<i64>,
+    #[sqlx(rename = "lunarDay", default)]
+    pub lunar_day: Option<i64>,
+    #[sqlx(rename = "lunarLeapMonth", default)]
+    pub lunar_leap_month: Option<bool>,
+    #[sqlx(rename = "solarMonth", default)]
+    pub solar_month: Option<i64>,
+    #[sqlx(rename = "solarDay", default)]
+    pub solar_day: Option<i64>,
+    #[sqlx(default)]
+    pub time: Option<String>, // 'HH:MM'
+
+    #[sqlx(rename = "allowedItems", default)]
+    pub allowed_items: JsonColumn<Vec<String>>,
+    #[sqlx(rename = "priceConfigId", default)]
+    pub price_config_id: Option<i64>, // -1 表示生效中的價格配置
+
+    #[sqlx(default)]
+    pub state: Option<String>, // 'active' | 'inactive'
+    #[sqlx(default)]
+    pub notes: Option<String>,
+
+    // 自定義時間戳
+    #[sqlx(rename = "createdAt", default)]
+    pub created_at: Option<String>,
+    #[sqlx(rename = "updatedAt", default)]
+    pub updated_at: Option<String>,
+}
+
+/// API 響應用的活動範本 DTO
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityTemplateResponse {
+    // Directus 系統字段
+    pub id: i64,
+    #[serde(rename = "user_created", skip_serializing_if = "Option::is_none")]
+    pub user_created: Option<String>,
+    #[serde(rename = "date_created", skip_serializing_if = "Option::is_none")]
+    pub date_created: Option<String>,
+    #[serde(rename = "user_updated", skip_serializing_if = "Option::is_none")]
+    pub user_updated: Option<String>,
+    #[serde(rename = "date_updated", skip_serializing_if = "Option::is_none")]
+    pub date_updated: Option<String>,
+
+    // 自定義字段
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lunar_month: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lunar_day: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lunar_leap_month: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solar_month: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solar_day: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time: Option<String>,
+    pub schedule_text: String, // 例如「農曆七月十五 09:00」、「國曆 12 月 31 日 19:00」
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_items: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price_config_id: Option<i64>,
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl From<ActivityTemplate> for ActivityTemplateResponse {
+    fn from(data: ActivityTemplate) -> Self {
+        let date_text = match (
+            data.lunar_month,
+            data.lunar_day,
+            data.solar_month,
+            data.solar_day,
+        ) {
+            (Some(month), Some(day), _, _) if month > 0 && day > 0 => format!(
+                "農曆{}{}",
+                lunar::month_text(month as u32, data.lunar_leap_month.unwrap_or(false)),
+                lunar::day_text(day as u32)
+            ),
+            (_, _, Some(month), Some(day)) => format!("國曆 {} 月 {} 日", month, day),
+            _ => "未設定日期".to_string(),
+        };
+        let schedule_text = match data.time.as_deref().filter(|t| !t.is_empty()) {
+            Some(time) => format!("{} {}", date_text, time),
+            None => date_text,
+        };
+
+        Self {
+            id: data.id,
+            user_created: data.user_created,
+            date_created: data.date_created,
+            user_updated: data.user_updated,
+            date_updated: data.date_updated,
+            name: data.name,
+            item_type: data.item_type,
+            icon: data.icon,
+            description: data.description,
+            location: data.location,
+            lunar_month: data.lunar_month,
+            lunar_day: data.lunar_day,
+            lunar_leap_month: data.lunar_leap_month,
+            solar_month: data.solar_month,
+            solar_day: data.solar_day,
+            time: data.time,
+            schedule_text,
+            allowed_items: data
+                .allowed_items
+                .into_reported("activityTemplateDB.allowedItems", data.id),
+            price_config_id: data.price_config_id,
+            state: data.state,
+            notes: data.notes,
+            created_at: data.created_at,
+            updated_at: data.updated_at,
+        }
+    }
+}
+
+/// 創建活動範本請求：lunarMonth / lunarDay 與 solarMonth / solarDay 擇一
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateActivityTemplateRequest {
+    pub name: String,
+    #[serde(default = "default_item_type")]
+    pub item_type: String,
+    #[serde(default = "default_icon")]
+    pub icon: String,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub lunar_month: Option<u32>,
+    pub lunar_day: Option<u32>,
+    #[serde(default)]
+    pub lunar_leap_month: bool,
+    pub solar_month: Option<u32>,
+    pub solar_day: Option<u32>,
+    #[serde(default = "default_time")]
+    pub time: String,
+    pub allowed_items: Option<Vec<String>>,
+    pub price_config_id: Option<i64>,
+    #[serde(default = "default_state")]
+    pub state: String,
+    pub notes: Option<String>,
+    #[serde(default, alias = "userId")]
+    pub user_created: Option<String>,
+}
+
+/// 更新活動範本請求；設定農曆月日會清除國曆月日，反之亦然
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateActivityTemplateRequest {
+    pub name: Option<String>,
+    pub item_type: Option<String>,
+    pub icon: Option<String>,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub lunar_month: Option<u32>,
+    pub lunar_day: Option<u32>,
+    pub lunar_leap_month: Option<bool>,
+    pub solar_month: Option<u32>,
+    pub solar_day: Option<u32>,
+    pub time: Option<String>,
+    pub allowed_items: Option<Vec<String>>, // 空陣列表示不限
+    pub price_config_id: Option<i64>,
+    pub state: Option<String>,
+    pub notes: Option<String>,
+    #[serde(default, alias = "userId")]
+    pub user_updated: Option<String>,
+}
+
+/// 活動範本查詢參數
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityTemplateQuery {
+    pub state: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// 由範本建立活動請求（POST /api/activities/from-template）
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateActivityFromTemplateRequest {
+    pub template_id: i64,
+    pub year: i32,                   // 農曆範本為農曆年，國曆範本為西元年
+    pub activity_id: Option<String>, // 未提供時自動產生 7 碼
+    pub name: Option<String>,        // 覆寫範本名稱
+    pub location: Option<String>,    // 覆寫範本地點
+    #[serde(default = "default_activity_state")]
+    pub state: String,
+    #[serde(default, alias = "userId")]
+    pub user_created: Option<String>,
+}
+
+// 默認值函數
+fn default_item_type() -> String {
+    "ceremony".to_string()
+}
+
+fn default_icon() -> String {
+    "🕯️".to_string()
+}
+
+fn default_time() -> String {
+    "09:00".to_string()
+}
+
+fn default_state() -> String {
+    "active".to_string()
+}
+
+fn default_activity_state() -> String {
+    "planning".to_string()
+}
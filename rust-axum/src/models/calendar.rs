@@ -0,0 +1,75 @@
+// src/models/calendar.rs
+use serde::{Deserialize, Serialize};
+
+/// 農曆年曆查詢參數
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LunarCalendarQuery {
+    pub year: Option<i32>, // 農曆年（以西元表示），預設今天所在的農曆年
+}
+
+/// 國曆 / 農曆換算查詢參數：提供 date 時換算為農曆，否則以 lunarYear / lunarMonth / lunarDay 換算為國曆
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LunarConvertQuery {
+    pub date: Option<String>, // 'YYYY-MM-DD'
+    pub lunar_year: Option<i32>,
+    pub lunar_month: Option<u32>,
+    pub lunar_day: Option<u32>,
+    #[serde(default)]
+    pub leap_month: bool,
+}
+
+/// 國曆與農曆對照
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LunarConversion {
+    pub date: String,    // 'YYYY-MM-DD'
+    pub weekday: String, // 星期幾，例如「三」
+    pub lunar_year: i32,
+    pub lunar_month: u32,
+    pub lunar_day: u32,
+    pub leap_month: bool,
+    pub lunar_text: String, // 例如「七月十五」
+    pub ganzhi: String,     // 農曆年干支，例如「丁未」
+    pub zodiac: String,
+}
+
+/// 農曆月份
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LunarMonth {
+    pub month: u32,
+    pub leap_month: bool,
+    pub name: String, // 例如「閏六月」
+    pub days: u32,    // 29 或 30
+    pub start_date: String,
+    pub end_date: String,
+}
+
+/// 農曆節日與寺院常用紀念日
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LunarFestival {
+    pub name: String,
+    pub lunar_month: u32,
+    pub lunar_day: u32,
+    pub lunar_text: String,
+    pub date: String, // 國曆 'YYYY-MM-DD'
+    pub weekday: String,
+}
+
+/// 農曆年曆（GET /api/calendar/lunar）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LunarCalendar {
+    pub year: i32,
+    pub ganzhi: String,
+    pub zodiac: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub leap_month: Option<u32>,
+    pub new_year_date: String, // 正月初一的國曆日期
+    pub days: u32,             // 全年天數
+    pub months: Vec<LunarMonth>,
+    pub festivals: Vec<LunarFestival>,
+}
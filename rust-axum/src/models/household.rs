@@ -0,0 +1,219 @@
+// src/models/household.rs
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+use crate::models::activity::ActivityResponse;
+use crate::models::join_record::JoinRecordResponse;
+use crate::models::join_record_payment::JoinRecordPaymentResponse;
+use crate::models::monthly_donate::MonthlyDonateResponse;
+use crate::models::receipt_number::ReceiptNumberResponse;
+use crate::models::registration::RegistrationResponse;
+
+/// 戶模型 - 對應 householdDB 表結構
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Household {
+    // Directus 系統字段
+    pub id: i64,
+    #[sqlx(default)]
+    pub user_created: Option<String>,
+    #[sqlx(default)]
+    pub date_created: Option<String>,
+    #[sqlx(default)]
+    pub user_updated: Option<String>,
+    #[sqlx(default)]
+    pub date_updated: Option<String>,
+
+    // 業務字段
+    #[sqlx(rename = "headName", default)]
+    pub head_name: Option<String>, // 戶長姓名
+
+    #[sqlx(default)]
+    pub mobile: Option<String>,
+
+    #[sqlx(default)]
+    pub phone: Option<String>,
+
+    #[sqlx(default)]
+    pub address: Option<String>,
+
+    #[sqlx(default)]
+    pub state: Option<String>, // 'active'
+
+    #[sqlx(default)]
+    pub notes: Option<String>,
+
+    // 自定義時間戳
+    #[sqlx(rename = "createdAt", default)]
+    pub created_at: Option<String>,
+    #[sqlx(rename = "updatedAt", default)]
+    pub updated_at: Option<String>,
+}
+
+/// API 響應用的戶 DTO
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HouseholdResponse {
+    // Directus 系統字段
+    pub id: i64,
+    #[serde(rename = "user_created", skip_serializing_if = "Option::is_none")]
+    pub user_created: Option<String>,
+    #[serde(rename = "date_created", skip_serializing_if = "Option::is_none")]
+    pub date_created: Option<String>,
+    #[serde(rename = "user_updated", skip_serializing_if = "Option::is_none")]
+    pub user_updated: Option<String>,
+    #[serde(rename = "date_updated", skip_serializing_if = "Option::is_none")]
+    pub date_updated: Option<String>,
+
+    // 自定義字段
+    pub head_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mobile: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub phone: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl From<Household> for HouseholdResponse {
+    fn from(data: Household) -> Self {
+        Self {
+            id: data.id,
+            user_created: data.user_created,
+            date_created: data.date_created,
+            user_updated: data.user_updated,
+            date_updated: data.date_updated,
+            head_name: data.head_name,
+            mobile: data.mobile,
+            phone: data.phone,
+            address: data.address,
+            state: data.state,
+            notes: data.notes,
+            created_at: data.created_at,
+            updated_at: data.updated_at,
+        }
+    }
+}
+
+/// 戶列表查詢參數
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HouseholdQuery {
+    pub q: Option<String>, // 戶長姓名、手機、電話或地址
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// 戶內成員：歷年報名的消災人員依姓名彙整
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HouseholdMember {
+    pub name: String,
+    pub zodiac: String,
+    pub is_household_head: bool,
+    pub registration_ids: Vec<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_seen: Option<String>, // 最早出現的報名 createdAt
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<String>,
+}
+
+/// 戶的金額與筆數統計
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HouseholdSummary {
+    pub registration_count: usize,
+    pub join_record_count: usize,
+    pub activity_count: usize,
+    pub monthly_donate_count: usize,
+    pub receipt_count: usize,
+    pub final_amount: i64, // 參加記錄應收合計
+    pub paid_amount: i64,
+    pub balance: i64, // 未收 = finalAmount - paidAmount
+}
+
+/// 單一戶的完整歷史（GET /api/households/{id}）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HouseholdDetail {
+    pub household: HouseholdResponse,
+    pub summary: HouseholdSummary,
+    pub members: Vec<HouseholdMember>,
+    pub ancestors: Vec<String>, // 歷代祖先姓氏（去重）
+    pub registrations: Vec<RegistrationResponse>,
+    pub activities: Vec<ActivityResponse>,
+    pub join_records: Vec<JoinRecordResponse>,
+    pub payments: Vec<JoinRecordPaymentResponse>,
+    pub receipts: Vec<ReceiptNumberResponse>,
+    pub monthly_donates: Vec<MonthlyDonateResponse>,
+}
+
+fn default_dry_run() -> bool {
+    true
+}
+
+/// 由報名記錄推導戶別請求
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeriveHouseholdsRequest {
+    // 預設只預覽不寫入，確認無誤後再以 dryRun=false 執行
+    #[serde(default = "default_dry_run")]
+    pub dry_run: bool,
+
+    #[serde(default, alias = "userId")]
+    pub user_created: Option<String>,
+}
+
+/// 推導出的一戶（預覽用）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DerivedHousehold {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub household_id: Option<i64>, // 沿用既有戶時為其 ID，新戶在 dryRun 時為空
+    pub head_name: String,
+    pub registration_ids: Vec<i64>,
+    pub new_registration_ids: Vec<i64>, // 本次才歸戶的報名
+}
+
+/// 推導結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeriveHouseholdsResult {
+    pub dry_run: bool,
+    pub created_households: usize,
+    pub assigned_registrations: usize,
+    pub assigned_join_records: u64,
+    pub assigned_monthly_donates: u64,
+    pub skipped_registration_ids: Vec<i64>, // 沒有戶長或聯絡人姓名，無法歸戶
+    pub households: Vec<DerivedHousehold>,  // 只列出本次有變動的戶
+}
+
+/// 手動歸戶請求：報名記錄歸戶時，其參加記錄與每月贊助一併歸戶
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkHouseholdRequest {
+    #[serde(default)]
+    pub registration_ids: Vec<i64>,
+    #[serde(default)]
+    pub join_record_ids: Vec<i64>,
+    #[serde(default)]
+    pub monthly_donate_ids: Vec<i64>,
+    #[serde(default, alias = "userId")]
+    pub user_updated: Option<String>,
+}
+
+/// 手動歸戶結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkHouseholdResult {
+    pub household_id: i64,
+    pub linked_registrations: u64,
+    pub linked_join_records: u64,
+    pub linked_monthly_donates: u64,
+}
@@ -0,0 +1,68 @@
+// src/models/import.rs
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+
+fn default_dry_run() -> bool {
+    true
+}
+
+/// 匯入請求：檔案內容以文字（CSV）或 base64（CSV / XLSX）傳入
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportRequest {
+    #[serde(default)]
+    pub format: Option<String>, // 'csv' | 'xlsx'，預設 csv
+
+    #[serde(default)]
+    pub content: Option<String>, // CSV 文字
+
+    #[serde(default)]
+    pub content_base64: Option<String>, // 檔案內容（base64）
+
+    // 欄位對應：目標欄位 → 檔案欄位標題，例如 { "contact.name": "聯絡人" }
+    // 未指定的目標欄位若與檔案標題同名則自動對應
+    #[serde(default)]
+    pub mapping: HashMap<String, String>,
+
+    // 預設只驗證不寫入，確認無誤後再以 dryRun=false 匯入
+    #[serde(default = "default_dry_run")]
+    pub dry_run: bool,
+
+    #[serde(default, alias = "userId")]
+    pub user_created: Option<String>,
+}
+
+/// 可匯入的目標欄位
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportField {
+    pub field: String,
+    pub label: String,
+    pub required: bool,
+}
+
+/// 單列驗證錯誤（row 為檔案中的列號，標題列為第 1 列）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportRowError {
+    pub row: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    pub message: String,
+}
+
+/// 匯入結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportResult {
+    pub collection: String,
+    pub dry_run: bool,
+    pub total_rows: usize,
+    pub valid_rows: usize,
+    pub record_count: usize, // 將建立的記錄數（每月贊助同一贊助編號的多列合併為一筆）
+    pub mapping: HashMap<String, String>, // 實際使用的欄位對應
+    pub errors: Vec<ImportRowError>,
+    pub preview: Vec<JsonValue>, // 前幾筆組裝後的記錄
+    pub created_ids: Vec<i64>,
+}
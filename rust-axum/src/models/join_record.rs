@@ -3,6 +3,10 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use serde_json::Value as JsonValue;
 
+use crate::models::json_column::JsonColumn;
+use crate::models::json_types::{Contact, JoinItem};
+use std::collections::HashMap;
+
 /// 參與記錄模型 - 對應 joinRecordDB 表結構
 #[allow(dead_code)]
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -28,22 +32,12 @@ pub struct JoinRecord {
     #[sqlx(default)]
     pub state: Option<String>,
     
-    // JSON 字段 - 使用 serde_json::Value
+    // JSON 字段 - 解析為型別結構
     #[sqlx(default)]
-    #[serde(
-        serialize_with = "serialize_json_string",
-        deserialize_with = "deserialize_json_string",
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub items: Option<String>,
+    pub items: JsonColumn<Vec<JoinItem>>,
     
     #[sqlx(default)]
-    #[serde(
-        serialize_with = "serialize_json_string",
-        deserialize_with = "deserialize_json_string",
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub contact: Option<String>,
+    pub contact: JsonColumn<Contact>,
     
     #[sqlx(rename = "totalAmount", default)]
     pub total_amount: Option<i64>,
@@ -108,42 +102,19 @@ pub struct JoinRecord {
     // 打印ID
     #[sqlx(rename = "receiptId", default)]
     pub receipt_id: Option<i64>,
-}
 
-// 自定義序列化函數：將 JSON 字符串轉為 JSON 對象
-fn serialize_json_string<S>(
-    value: &Option<String>,
-    serializer: S,
-) -> Result<S::Ok, S::Error>
-where
-    S: serde::Serializer,
-{
-    match value {
-        Some(s) => {
-            // 嘗試解析 JSON 字符串
-            match serde_json::from_str::<JsonValue>(s) {
-                Ok(json) => json.serialize(serializer),
-                Err(_) => serializer.serialize_none(), // 如果解析失敗，返回 null
-            }
-        }
-        None => serializer.serialize_none(),
-    }
-}
+    // 沖帳批次ID
+    #[sqlx(rename = "reconciliationBatchId", default)]
+    pub reconciliation_batch_id: Option<i64>,
 
-// 自定義反序列化函數：將 JSON 對象轉為字符串
-fn deserialize_json_string<'de, D>(
-    deserializer: D,
-) -> Result<Option<String>, D::Error>
-where
-    D: serde::Deserializer<'de>,
-{
-    let value: Option<JsonValue> = Option::deserialize(deserializer)?;
-    Ok(value.map(|v| v.to_string()))
+    // 戶ID
+    #[sqlx(rename = "householdId", default)]
+    pub household_id: Option<i64>,
 }
 
 /// 創建參與記錄請求
 #[allow(dead_code)]
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreateJoinRecordRequest {
     #[serde(default)]
@@ -221,6 +192,40 @@ pub struct CreateJoinRecordRequest {
     pub receipt_id: Option<i64>,
 }
 
+/// 從祈福登記表產生參與記錄請求
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateJoinRecordFromRegistrationRequest {
+    pub registration_id: i64,
+    pub activity_id: i64,
+
+    // 項目類型，例如 ["chaodu", "survivors", "diandeng"]
+    pub item_types: Vec<String>,
+
+    // 可選：各項目類型只取指定的 sourceData id，例如 { "qifu": [1, 3] }
+    #[serde(default)]
+    pub selections: HashMap<String, Vec<i64>>,
+
+    // 可選：點燈人員燈種 { "personId": "taisui" }，未指定為光明燈
+    #[serde(default)]
+    pub person_lamp_types: HashMap<String, String>,
+
+    #[serde(default)]
+    pub discount_amount: Option<i64>,
+
+    #[serde(default)]
+    pub state: Option<String>, // 預設 'confirmed'
+
+    #[serde(default)]
+    pub need_receipt: Option<String>,
+
+    #[serde(default)]
+    pub notes: Option<String>,
+
+    #[serde(default)]
+    pub user_id: Option<String>,
+}
+
 /// 更新參與記錄請求
 #[allow(dead_code)]
 #[derive(Debug, Deserialize)]
@@ -273,6 +278,19 @@ pub struct JoinRecordQuery {
     pub sort: Option<String>,
 }
 
+/// 匯出查詢參數：篩選條件與列表相同，但不分頁
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JoinRecordExportQuery {
+    pub registration_id: Option<i64>,
+    pub activity_id: Option<i64>,
+    pub state: Option<String>,
+    pub payment_state: Option<String>,
+    pub accounting_state: Option<String>,
+    pub sort: Option<String>,
+    pub format: Option<String>, // 'csv' | 'xlsx' | 'json'，預設 csv
+}
+
 /// API 響應用的參與記錄 DTO
 #[allow(dead_code)]
 #[derive(Debug, Clone, Serialize)]
@@ -297,12 +315,12 @@ pub struct JoinRecordResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
     
-    // JSON 字段直接使用 JsonValue
+    // JSON 字段使用型別結構
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub items: Option<JsonValue>,
+    pub items: Option<Vec<JoinItem>>,
     
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contact: Option<JsonValue>,
+    pub contact: Option<Contact>,
     
     #[serde(skip_serializing_if = "Option::is_none")]
     pub total_amount: Option<i64>,
@@ -349,6 +367,14 @@ pub struct JoinRecordResponse {
     // 打印ID
     #[serde(skip_serializing_if = "Option::is_none")]
     pub receipt_id: Option<i64>,
+
+    // 沖帳批次ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reconciliation_batch_id: Option<i64>,
+
+    // 戶ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub household_id: Option<i64>,
 }
 
 impl From<JoinRecord> for JoinRecordResponse {
@@ -362,10 +388,8 @@ impl From<JoinRecord> for JoinRecordResponse {
             registration_id: data.registration_id,
             activity_id: data.activity_id,
             state: data.state,
-            items: data.items
-                .and_then(|s| serde_json::from_str(&s).ok()),
-            contact: data.contact
-                .and_then(|s| serde_json::from_str(&s).ok()),
+            items: data.items.into_reported("joinRecordDB.items", data.id),
+            contact: data.contact.into_reported("joinRecordDB.contact", data.id),
             total_amount: data.total_amount,
             discount_amount: data.discount_amount,
             final_amount: data.final_amount,
@@ -388,6 +412,66 @@ impl From<JoinRecord> for JoinRecordResponse {
             updated_at: data.updated_at,
             // 打印ID
             receipt_id: data.receipt_id,
+            // 沖帳批次ID
+            reconciliation_batch_id: data.reconciliation_batch_id,
+            // 戶ID
+            household_id: data.household_id,
         }
     }
 }
+
+/// 批次操作中的單一操作
+/// { "action": "create", "data": {...} } / { "action": "update", "id": 1, "data": {...} } / { "action": "delete", "id": 1 }
+/// 刪除為軟刪除，可帶 user_deleted（刪除者）
+#[derive(Debug, Deserialize)]
+#[serde(tag = "action", rename_all = "lowercase")]
+pub enum JoinRecordBatchOperation {
+    Create { data: CreateJoinRecordRequest },
+    Update { id: i64, data: UpdateJoinRecordRequest },
+    Delete {
+        id: i64,
+        #[serde(default)]
+        user_deleted: Option<String>,
+    },
+}
+
+fn default_atomic() -> bool {
+    true
+}
+
+/// 批次操作請求
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JoinRecordBatchRequest {
+    pub operations: Vec<JoinRecordBatchOperation>,
+
+    // true：任一操作失敗則整批回滾；false：只回滾失敗的操作，其餘照常提交
+    #[serde(default = "default_atomic")]
+    pub atomic: bool,
+}
+
+/// 批次操作中單一操作的結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JoinRecordBatchItemResult {
+    pub index: usize,
+    pub action: String, // 'create', 'update', 'delete'
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub success: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data: Option<JoinRecordResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// 批次操作結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JoinRecordBatchResult {
+    pub atomic: bool,
+    pub committed: bool, // 是否已提交（atomic 且有失敗時為 false）
+    pub succeeded: usize,
+    pub failed: usize,
+    pub results: Vec<JoinRecordBatchItemResult>,
+}
@@ -0,0 +1,173 @@
+// src/models/join_record_payment.rs
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+/// 付款流水模型 - 對應 joinRecordPayments 表結構
+/// 每筆付款、退款或沖銷各佔一列，amount 為有號金額（付款為正，退款為負）
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct JoinRecordPayment {
+    // Directus 系統字段
+    pub id: i64,
+    #[sqlx(default)]
+    pub user_created: Option<String>,
+    #[sqlx(default)]
+    pub date_created: Option<String>,
+    #[sqlx(default)]
+    pub user_updated: Option<String>,
+    #[sqlx(default)]
+    pub date_updated: Option<String>,
+
+    // 業務字段
+    #[sqlx(rename = "joinRecordId")]
+    pub join_record_id: i64,
+
+    #[sqlx(rename = "paymentType", default)]
+    pub payment_type: Option<String>, // 'payment', 'refund', 'reversal'
+
+    #[sqlx(default)]
+    pub amount: Option<i64>,
+
+    #[sqlx(rename = "paymentMethod", default)]
+    pub payment_method: Option<String>, // 'cash', 'transfer', 'card'
+
+    #[sqlx(rename = "paymentDate", default)]
+    pub payment_date: Option<String>,
+
+    #[sqlx(rename = "handledBy", default)]
+    pub handled_by: Option<String>, // 經手人
+
+    #[sqlx(default)]
+    pub state: Option<String>, // 'active', 'reversed'
+
+    #[sqlx(rename = "reversedPaymentId", default)]
+    pub reversed_payment_id: Option<i64>, // 沖銷列指向被沖銷的流水 ID，其餘為 -1
+
+    #[sqlx(rename = "reconciliationBatchId", default)]
+    pub reconciliation_batch_id: Option<i64>, // 沖帳批次 ID，-1 表示尚未沖帳
+
+    #[sqlx(default)]
+    pub notes: Option<String>,
+
+    // 自定義時間戳
+    #[sqlx(rename = "createdAt", default)]
+    pub created_at: Option<String>,
+    #[sqlx(rename = "updatedAt", default)]
+    pub updated_at: Option<String>,
+}
+
+/// API 響應用的付款流水 DTO
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JoinRecordPaymentResponse {
+    // Directus 系統字段
+    pub id: i64,
+    #[serde(rename = "user_created", skip_serializing_if = "Option::is_none")]
+    pub user_created: Option<String>,
+    #[serde(rename = "date_created", skip_serializing_if = "Option::is_none")]
+    pub date_created: Option<String>,
+    #[serde(rename = "user_updated", skip_serializing_if = "Option::is_none")]
+    pub user_updated: Option<String>,
+    #[serde(rename = "date_updated", skip_serializing_if = "Option::is_none")]
+    pub date_updated: Option<String>,
+
+    // 自定義字段
+    pub join_record_id: i64,
+    pub payment_type: Option<String>,
+    pub amount: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub handled_by: Option<String>,
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reversed_payment_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reconciliation_batch_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl From<JoinRecordPayment> for JoinRecordPaymentResponse {
+    fn from(data: JoinRecordPayment) -> Self {
+        Self {
+            id: data.id,
+            user_created: data.user_created,
+            date_created: data.date_created,
+            user_updated: data.user_updated,
+            date_updated: data.date_updated,
+            join_record_id: data.join_record_id,
+            payment_type: data.payment_type,
+            amount: data.amount,
+            payment_method: data.payment_method,
+            payment_date: data.payment_date,
+            handled_by: data.handled_by,
+            state: data.state,
+            reversed_payment_id: data.reversed_payment_id,
+            reconciliation_batch_id: data.reconciliation_batch_id,
+            notes: data.notes,
+            created_at: data.created_at,
+            updated_at: data.updated_at,
+        }
+    }
+}
+
+/// 參加記錄的付款摘要（由流水推導）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JoinRecordPaymentSummary {
+    pub join_record_id: i64,
+    pub final_amount: i64,
+    pub paid_amount: i64,
+    pub balance: i64, // 尚欠金額 = finalAmount - paidAmount
+    pub payment_state: Option<String>,
+    pub payments: Vec<JoinRecordPaymentResponse>,
+}
+
+/// 新增付款/退款請求
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddPaymentRequest {
+    #[serde(default = "default_payment_type")]
+    pub payment_type: String, // "payment" 或 "refund"
+
+    pub amount: i64, // 正整數，退款時由後端轉為負數
+
+    #[serde(default)]
+    pub payment_method: Option<String>,
+
+    #[serde(default)]
+    pub payment_date: Option<String>, // 未提供時使用當前時間
+
+    #[serde(default)]
+    pub handled_by: Option<String>, // 經手人
+
+    #[serde(default)]
+    pub notes: Option<String>,
+
+    #[serde(default)]
+    pub user_id: Option<String>, // Directus 用戶 UUID
+}
+
+/// 沖銷付款請求
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReversePaymentRequest {
+    #[serde(default)]
+    pub reason: Option<String>, // 沖銷原因
+
+    #[serde(default)]
+    pub handled_by: Option<String>,
+
+    #[serde(default)]
+    pub user_id: Option<String>,
+}
+
+fn default_payment_type() -> String {
+    "payment".to_string()
+}
@@ -0,0 +1,109 @@
+// src/models/json_column.rs
+//! JSON 欄位包裝：Directus 以 TEXT 儲存 JSON，讀取時解析為型別結構。
+//! 解析失敗不再默默變成 null，而是保留原始內容與錯誤，供 /api/reports/invalid-json 回報
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sqlx::encode::IsNull;
+use sqlx::error::BoxDynError;
+use sqlx::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+use sqlx::{Decode, Encode, Type, ValueRef};
+use std::fmt::Display;
+
+#[derive(Debug, Clone, Default)]
+pub enum JsonColumn<T> {
+    /// 欄位為 NULL
+    #[default]
+    Null,
+    /// 解析成功
+    Valid(T),
+    /// 內容不是預期的 JSON 結構
+    Invalid { raw: String, error: String },
+}
+
+impl<T: DeserializeOwned> JsonColumn<T> {
+    pub fn parse(raw: &str) -> Self {
+        match serde_json::from_str(raw) {
+            Ok(value) => JsonColumn::Valid(value),
+            Err(e) => JsonColumn::Invalid {
+                raw: raw.to_string(),
+                error: e.to_string(),
+            },
+        }
+    }
+}
+
+impl<T> JsonColumn<T> {
+    pub fn as_valid(&self) -> Option<&T> {
+        match self {
+            JsonColumn::Valid(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn into_valid(self) -> Option<T> {
+        match self {
+            JsonColumn::Valid(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// 取出解析結果；內容無法解析時記錄警告（column 如 "registrationDB.contact"）
+    pub fn into_reported(self, column: &str, id: impl Display) -> Option<T> {
+        if let JsonColumn::Invalid { error, .. } = &self {
+            tracing::warn!("⚠️ {} (id = {}) JSON 無法解析: {}", column, id, error);
+        }
+        self.into_valid()
+    }
+}
+
+impl<T> Type<Sqlite> for JsonColumn<T> {
+    fn type_info() -> SqliteTypeInfo {
+        <String as Type<Sqlite>>::type_info()
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        <String as Type<Sqlite>>::compatible(ty)
+    }
+}
+
+impl<'r, T: DeserializeOwned> Decode<'r, Sqlite> for JsonColumn<T> {
+    fn decode(value: SqliteValueRef<'r>) -> Result<Self, BoxDynError> {
+        if value.is_null() {
+            return Ok(JsonColumn::Null);
+        }
+        let raw = <&str as Decode<Sqlite>>::decode(value)?;
+        Ok(JsonColumn::parse(raw))
+    }
+}
+
+impl<'q, T: Serialize> Encode<'q, Sqlite> for JsonColumn<T> {
+    fn encode_by_ref(&self, buf: &mut Vec<SqliteArgumentValue<'q>>) -> Result<IsNull, BoxDynError> {
+        match self {
+            JsonColumn::Null => Ok(IsNull::Yes),
+            JsonColumn::Valid(value) => {
+                <String as Encode<Sqlite>>::encode(serde_json::to_string(value)?, buf)
+            }
+            JsonColumn::Invalid { raw, .. } => <String as Encode<Sqlite>>::encode(raw.clone(), buf),
+        }
+    }
+}
+
+/// 輸出為 JSON 物件；無法解析的內容輸出 null
+impl<T: Serialize> Serialize for JsonColumn<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            JsonColumn::Valid(value) => value.serialize(serializer),
+            _ => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for JsonColumn<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(match Option::<T>::deserialize(deserializer)? {
+            Some(value) => JsonColumn::Valid(value),
+            None => JsonColumn::Null,
+        })
+    }
+}
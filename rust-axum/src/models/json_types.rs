@@ -0,0 +1,157 @@
+// src/models/json_types.rs
+//! JSON 欄位的共用型別（registrationDB、joinRecordDB、monthlyDonateDB、priceConfigDB、mydata）
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Value as JsonValue};
+use std::collections::BTreeMap;
+
+/// 聯絡人（registrationDB、joinRecordDB、mydata 的 contact）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Contact {
+    pub name: String,
+    pub phone: String,
+    pub mobile: String,
+    pub relationship: String, // 本家 | 娘家 | 朋友 | 其它
+    pub other_relationship: String,
+    // 保留前端額外送出的欄位（如 email）
+    #[serde(flatten)]
+    pub extra: Map<String, JsonValue>,
+}
+
+/// 消災人員
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct BlessingPerson {
+    pub id: i64,
+    pub name: String,
+    pub zodiac: String,
+    pub notes: String,
+    pub is_household_head: bool,
+    #[serde(flatten)]
+    pub extra: Map<String, JsonValue>,
+}
+
+/// 消災資料（registrationDB.blessing）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Blessing {
+    pub address: String,
+    pub persons: Vec<BlessingPerson>,
+    #[serde(flatten)]
+    pub extra: Map<String, JsonValue>,
+}
+
+/// 歷代祖先
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Ancestor {
+    pub id: i64,
+    pub surname: String,
+    pub zodiac: String,
+    pub notes: String,
+    #[serde(flatten)]
+    pub extra: Map<String, JsonValue>,
+}
+
+/// 陽上人
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Survivor {
+    pub id: i64,
+    pub name: String,
+    pub zodiac: String,
+    pub notes: String,
+    #[serde(flatten)]
+    pub extra: Map<String, JsonValue>,
+}
+
+/// 超度資料（registrationDB.salvation）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Salvation {
+    pub address: String,
+    pub ancestors: Vec<Ancestor>,
+    pub survivors: Vec<Survivor>,
+    #[serde(flatten)]
+    pub extra: Map<String, JsonValue>,
+}
+
+/// 點燈明細（項目 type = diandeng）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LampDetail {
+    pub person_id: i64,
+    pub person_name: String,
+    pub lamp_type: String,
+    pub lamp_type_label: String,
+    pub price: i64,
+}
+
+/// 參加項目（joinRecordDB.items 陣列元素）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct JoinItem {
+    #[serde(rename = "type")]
+    pub item_type: String,
+    pub label: String,
+    #[serde(serialize_with = "serialize_whole_number")]
+    pub price: f64, // 點燈為各燈種平均價格，前端會存入小數
+    #[serde(deserialize_with = "deserialize_lenient_i64")]
+    pub quantity: i64,
+    #[serde(deserialize_with = "deserialize_lenient_i64")]
+    pub subtotal: i64,
+    pub source: String,
+    pub source_data: Vec<JsonValue>, // 依 source 為祖先、陽上人或消災人員
+    pub source_address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lamp_details: Option<Vec<LampDetail>>,
+    // 保留前端額外送出的欄位
+    #[serde(flatten)]
+    pub extra: Map<String, JsonValue>,
+}
+
+/// 整數值輸出為整數（600 而非 600.0），維持原本的 JSON 格式
+fn serialize_whole_number<S: Serializer>(value: &f64, serializer: S) -> Result<S::Ok, S::Error> {
+    if value.fract() == 0.0 && value.abs() < i64::MAX as f64 {
+        serializer.serialize_i64(*value as i64)
+    } else {
+        serializer.serialize_f64(*value)
+    }
+}
+
+/// 寬鬆讀取整數：接受小數（四捨五入）與數字字串，避免單一欄位使整個 items 欄位無法解析
+fn deserialize_lenient_i64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<i64, D::Error> {
+    let value = JsonValue::deserialize(deserializer)?;
+    if let Some(n) = value.as_i64() {
+        return Ok(n);
+    }
+    let number = match &value {
+        JsonValue::Number(n) => n.as_f64(),
+        JsonValue::String(s) => s.trim().parse::<f64>().ok(),
+        JsonValue::Null => Some(0.0),
+        _ => None,
+    };
+    number
+        .filter(|n| n.is_finite())
+        .map(|n| n.round() as i64)
+        .ok_or_else(|| serde::de::Error::custom(format!("無效的數值: {}", value)))
+}
+
+/// 贊助項目（monthlyDonateDB.donateItems 陣列元素）
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DonateItem {
+    pub donate_items_id: String,
+    pub price: i64, // 所有月份合計
+    pub months: Vec<String>, // YYYYMM
+    pub created_at: String,
+    pub created_user: String,
+    pub updated_at: String,
+    pub updated_user: String,
+    #[serde(flatten)]
+    pub extra: Map<String, JsonValue>,
+}
+
+/// 價格表（priceConfigDB.prices）：項目類型 → 單價，點燈另有 diandeng_{燈種}
+pub type PriceTable = BTreeMap<String, i64>;
@@ -6,4 +6,18 @@ pub mod my_data;
 pub mod receipt_number; // ✅ 新增：收據編號模型
 pub mod directus_users; 
 pub mod price_config; // ✅ 新增：價格配置模型 by 20260331
-pub mod join_record; // ✅ 新增：參與記錄模型 by 20260422
\ No newline at end of file
+pub mod join_record; // ✅ 新增：參與記錄模型 by 20260422
+pub mod join_record_payment; // ✅ 新增：付款流水模型 by 20261019
+pub mod reconciliation_batch; // ✅ 新增：沖帳批次模型 by 20261019
+pub mod report; // ✅ 新增：報表模型 by 20261019
+pub mod import; // ✅ 新增：資料匯入模型 by 20261019
+pub mod json_column; // ✅ 新增：JSON 欄位包裝 by 20261019
+pub mod json_types; // ✅ 新增：JSON 欄位共用型別 by 20261019
+pub mod household; // ✅ 新增：戶模型 by 20261019
+pub mod taisui; // ✅ 新增：太歲模型 by 20261019
+pub mod calendar; // ✅ 新增：農曆曆法模型 by 20261019
+pub mod activity_template; // ✅ 新增：活動範本模型 by 20261019
+pub mod activity_summary; // ✅ 新增：活動財務摘要模型 by 20261019
+pub mod activity_roster; // ✅ 新增：法會名冊模型 by 20261019
+pub mod activity_calendar; // ✅ 新增：活動行事曆模型 by 20261019
+pub mod soft_delete; // ✅ 新增：軟刪除模型 by 20261019
@@ -3,6 +3,9 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use serde_json::Value as JsonValue;
 
+use crate::models::json_column::JsonColumn;
+use crate::models::json_types::DonateItem;
+
 /// 每月捐款記錄模型 - 對應 Directus 的 monthlyDonateDB 表結構
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct MonthlyDonate {
@@ -30,14 +33,9 @@ pub struct MonthlyDonate {
     #[sqlx(rename = "donateType", default)]
     pub donate_type: Option<String>,
     
-    // JSON 字段 - 使用 serde_json::Value
+    // JSON 字段 - 解析為型別結構
     #[sqlx(rename = "donateItems", default)]
-    #[serde(
-        serialize_with = "serialize_json_string",
-        deserialize_with = "deserialize_json_string",
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub donate_items: Option<String>,
+    pub donate_items: JsonColumn<Vec<DonateItem>>,
     
     #[sqlx(default)]
     pub memo: Option<String>,
@@ -47,37 +45,10 @@ pub struct MonthlyDonate {
     pub created_at: Option<String>,
     #[sqlx(rename = "updatedAt", default)]
     pub updated_at: Option<String>,
-}
-
-// 自定義序列化函數：將 JSON 字符串轉為 JSON 對象
-fn serialize_json_string<S>(
-    value: &Option<String>,
-    serializer: S,
-) -> Result<S::Ok, S::Error>
-where
-    S: serde::Serializer,
-{
-    match value {
-        Some(s) => {
-            // 嘗試解析 JSON 字符串
-            match serde_json::from_str::<JsonValue>(s) {
-                Ok(json) => json.serialize(serializer),
-                Err(_) => serializer.serialize_none(), // 如果解析失敗，返回 null
-            }
-        }
-        None => serializer.serialize_none(),
-    }
-}
 
-// 自定義反序列化函數：將 JSON 對象轉為字符串
-fn deserialize_json_string<'de, D>(
-    deserializer: D,
-) -> Result<Option<String>, D::Error>
-where
-    D: serde::Deserializer<'de>,
-{
-    let value: Option<JsonValue> = Option::deserialize(deserializer)?;
-    Ok(value.map(|v| v.to_string()))
+    // 戶ID，householdDB.id
+    #[sqlx(rename = "householdId", default)]
+    pub household_id: Option<i64>,
 }
 
 /// 創建每月捐款記錄請求
@@ -102,6 +73,10 @@ pub struct CreateMonthlyDonateRequest {
     
     #[serde(default)]
     pub memo: Option<String>,    
+
+    // 允許同一筆記錄中不同項目涵蓋相同月份（重複贊助）
+    #[serde(default)]
+    pub allow_overlap: bool,
 }
 
 /// 更新每月捐款記錄請求
@@ -119,6 +94,9 @@ pub struct UpdateMonthlyDonateRequest {
     
     #[serde(default)]
     pub user_updated: Option<String>,
+
+    #[serde(default)]
+    pub allow_overlap: bool,
 }
 
 /// 查詢參數
@@ -160,9 +138,9 @@ pub struct MonthlyDonateResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub donate_type: Option<String>,
     
-    // JSON 字段直接使用 JsonValue
+    // JSON 字段使用型別結構
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub donate_items: Option<JsonValue>,
+    pub donate_items: Option<Vec<DonateItem>>,
     
     #[serde(skip_serializing_if = "Option::is_none")]
     pub memo: Option<String>,
@@ -171,6 +149,8 @@ pub struct MonthlyDonateResponse {
     pub created_at: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub household_id: Option<i64>,
 }
 
 impl From<MonthlyDonate> for MonthlyDonateResponse {
@@ -185,11 +165,216 @@ impl From<MonthlyDonate> for MonthlyDonateResponse {
             registration_id: data.registration_id,
             donate_id: data.donate_id,
             donate_type: data.donate_type,
-            donate_items: data.donate_items
-                .and_then(|s| serde_json::from_str(&s).ok()),
+            donate_items: data.donate_items.into_reported("monthlyDonateDB.donateItems", data.id),
             memo: data.memo,
             created_at: data.created_at,
             updated_at: data.updated_at,
+            household_id: data.household_id,
         }
     }
-}
\ No newline at end of file
+}
+/// 新增贊助項目請求（POST /api/monthly-donates/{id}/items）
+/// 相容前端 itemData：amount / selectedMonths
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddDonateItemRequest {
+    #[serde(alias = "amount")]
+    pub price: i64,
+
+    #[serde(alias = "selectedMonths")]
+    pub months: Vec<String>, // ["202601", "202602"]
+
+    #[serde(default)]
+    pub memo: Option<String>, // 同步更新贊助記錄備註
+
+    #[serde(default, alias = "userId")]
+    pub created_user: Option<String>,
+
+    #[serde(default)]
+    pub allow_overlap: bool, // 允許與其他項目月份重複
+}
+
+/// 更新贊助項目請求（PATCH /api/monthly-donates/{id}/items/{items_id}）
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDonateItemRequest {
+    #[serde(default, alias = "amount")]
+    pub price: Option<i64>,
+
+    #[serde(default, alias = "selectedMonths")]
+    pub months: Option<Vec<String>>,
+
+    #[serde(default, alias = "userId")]
+    pub updated_user: Option<String>,
+
+    #[serde(default)]
+    pub allow_overlap: bool,
+}
+
+/// 刪除贊助項目查詢參數
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteDonateItemQuery {
+    pub user_id: Option<String>,
+}
+
+/// 統計查詢參數（月份皆為 YYYYMM）
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonthlyDonateStatsQuery {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub donate_type: Option<String>,
+}
+
+/// 月度統計：每月分攤後的贊助總額與贊助者數
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonthlyDonateMonthStat {
+    pub month: String,      // YYYYMM
+    pub count: i64,         // 贊助者（記錄）數
+    pub item_count: i64,    // 涵蓋該月的贊助項目數
+    pub total_amount: i64,  // 分攤到該月的金額
+}
+
+/// 贊助總覽統計
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DonationStats {
+    pub total_records: i64,
+    pub total_items: i64,
+    pub total_amount: i64,
+    pub total_donor_months: i64, // 贊助月數合計（人 × 月）
+    pub average_per_record: i64,
+    pub average_per_month: i64,  // 有贊助月份的平均分攤金額
+    pub first_month: Option<String>,
+    pub last_month: Option<String>,
+    pub current_month: MonthlyDonateMonthStat,
+}
+
+/// 贊助類型分布
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DonationTypeStat {
+    pub donate_type: String, // 空值表示未分類
+    pub record_count: i64,
+    pub item_count: i64,
+    pub total_amount: i64,
+    pub percentage: f64,
+}
+
+/// 贊助趨勢（依期間彙總，期間之間補零）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DonationTrendPoint {
+    pub period: String, // 'YYYYMM' | 'YYYY-Qn' | 'YYYY'
+    pub count: i64,
+    pub total_amount: i64,
+    pub change: i64,              // 與上一期差額
+    pub change_rate: Option<f64>, // 與上一期變化率（%），上一期為 0 時為 null
+}
+
+/// 續約名單查詢參數
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenewalQuery {
+    pub within: Option<i64>, // 最後贊助月份落在未來 N 個月內，預設 2
+    #[serde(default)]
+    pub include_expired: bool, // 一併列出已過期（最後月份早於本月）的贊助者
+    pub donate_type: Option<String>,
+}
+
+/// 缺月名單查詢參數
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GapQuery {
+    pub donate_type: Option<String>,
+}
+
+/// 贊助者聯絡資料（取自關聯報名表 contact）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DonorContact {
+    #[serde(default)]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub phone: Option<String>,
+    #[serde(default)]
+    pub mobile: Option<String>,
+}
+
+/// 即將到期（待續約）的贊助者
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RenewalCandidate {
+    pub id: i64,
+    pub name: Option<String>,
+    pub donate_id: Option<String>,
+    pub donate_type: Option<String>,
+    pub registration_id: Option<i64>,
+    pub last_month: String,
+    pub months_remaining: i64, // 0 表示本月為最後一個月，負數表示已過期
+    pub total_months: i64,
+    pub contact: Option<DonorContact>,
+}
+
+/// 贊助期間有缺月的贊助者
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DonateGap {
+    pub id: i64,
+    pub name: Option<String>,
+    pub donate_id: Option<String>,
+    pub donate_type: Option<String>,
+    pub registration_id: Option<i64>,
+    pub first_month: String,
+    pub last_month: String,
+    pub missing_months: Vec<String>,
+    pub contact: Option<DonorContact>,
+}
+
+/// 每月分攤明細帳查詢參數
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonthlyDonateLedgerQuery {
+    pub from: Option<String>, // YYYYMM
+    pub to: Option<String>,   // YYYYMM
+    pub donate_type: Option<String>,
+    pub record_id: Option<i64>,
+    pub donate_id: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// 每月分攤明細：一個贊助項目的一個月份
+#[derive(Debug, Clone, Serialize, FromRow)]
+#[serde(rename_all = "camelCase")]
+pub struct MonthlyDonateLedgerEntry {
+    #[sqlx(rename = "recordId")]
+    pub record_id: i64,
+    pub name: Option<String>,
+    #[sqlx(rename = "donateId")]
+    pub donate_id: Option<String>,
+    #[sqlx(rename = "donateType")]
+    pub donate_type: String,
+    #[sqlx(rename = "donateItemsId")]
+    pub donate_items_id: Option<String>,
+    pub month: String,
+    pub amount: i64,     // 分攤到該月的金額
+    #[sqlx(rename = "itemPrice")]
+    pub item_price: i64, // 項目總金額
+    #[sqlx(rename = "monthCount")]
+    pub month_count: i64,
+}
+
+/// 匯出查詢參數：篩選條件與列表相同，但不分頁
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MonthlyDonateExportQuery {
+    pub name: Option<String>,
+    pub registration_id: Option<i64>,
+    pub donate_id: Option<String>,
+    pub donate_type: Option<String>,
+    pub sort: Option<String>,
+    pub format: Option<String>, // 'csv' | 'xlsx' | 'json'，預設 csv
+}
@@ -3,6 +3,9 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use serde_json::Value as JsonValue;
 
+use crate::models::json_column::JsonColumn;
+use crate::models::json_types::Contact;
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct MyData {
     pub id: String,
@@ -19,38 +22,7 @@ pub struct MyData {
     #[sqlx(rename = "formName", default)]
     pub form_name: Option<String>,
     #[sqlx(default)]
-    #[serde(
-        serialize_with = "serialize_json_string",
-        deserialize_with = "deserialize_json_string",
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub contact: Option<String>,
-}
-
-fn serialize_json_string<S>(
-    value: &Option<String>,
-    serializer: S,
-) -> Result<S::Ok, S::Error>
-where
-    S: serde::Serializer,
-{
-    match value {
-        Some(s) => match serde_json::from_str::<JsonValue>(s) {
-            Ok(json) => json.serialize(serializer),
-            Err(_) => serializer.serialize_none(),
-        },
-        None => serializer.serialize_none(),
-    }
-}
-
-fn deserialize_json_string<'de, D>(
-    deserializer: D,
-) -> Result<Option<String>, D::Error>
-where
-    D: serde::Deserializer<'de>,
-{
-    let value: Option<JsonValue> = Option::deserialize(deserializer)?;
-    Ok(value.map(|v| v.to_string()))
+    pub contact: JsonColumn<Contact>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -101,11 +73,12 @@ pub struct MyDataResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub form_name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contact: Option<JsonValue>,
+    pub contact: Option<Contact>,
 }
 
 impl From<MyData> for MyDataResponse {
     fn from(data: MyData) -> Self {
+        let contact = data.contact.into_reported("mydata.contact", &data.id);
         Self {
             id: data.id,
             user_created: data.user_created,
@@ -114,7 +87,7 @@ impl From<MyData> for MyDataResponse {
             date_updated: data.date_updated,
             state: data.state,
             form_name: data.form_name,
-            contact: data.contact.and_then(|s| serde_json::from_str(&s).ok()),
+            contact,
         }
     }
 }
@@ -3,6 +3,9 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use serde_json::Value as JsonValue;
 
+use crate::models::json_column::JsonColumn;
+use crate::models::json_types::PriceTable;
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct PriceConfig {
     // Directus 系統字段
@@ -21,12 +24,7 @@ pub struct PriceConfig {
     #[sqlx(default)]
     pub state: Option<String>,
     #[sqlx(default)]
-    #[serde(
-        serialize_with = "serialize_json_string",
-        deserialize_with = "deserialize_json_string",
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub prices: Option<String>,
+    pub prices: JsonColumn<PriceTable>,
     #[sqlx(default)]
     pub notes: Option<String>,
     
@@ -40,32 +38,6 @@ pub struct PriceConfig {
     pub updated_at: Option<String>,   // varchar(255)
 }
 
-fn serialize_json_string<S>(
-    value: &Option<String>,
-    serializer: S,
-) -> Result<S::Ok, S::Error>
-where
-    S: serde::Serializer,
-{
-    match value {
-        Some(s) => match serde_json::from_str::<JsonValue>(s) {
-            Ok(json) => json.serialize(serializer),
-            Err(_) => serializer.serialize_none(),
-        },
-        None => serializer.serialize_none(),
-    }
-}
-
-fn deserialize_json_string<'de, D>(
-    deserializer: D,
-) -> Result<Option<String>, D::Error>
-where
-    D: serde::Deserializer<'de>,
-{
-    let value: Option<JsonValue> = Option::deserialize(deserializer)?;
-    Ok(value.map(|v| v.to_string()))
-}
-
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CreatePriceConfigRequest {
@@ -130,7 +102,7 @@ pub struct PriceConfigResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub prices: Option<JsonValue>,
+    pub prices: Option<PriceTable>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub notes: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -152,7 +124,7 @@ impl From<PriceConfig> for PriceConfigResponse {
             date_updated: data.date_updated,
             version: data.version,
             state: data.state,
-            prices: data.prices.and_then(|s| serde_json::from_str(&s).ok()),
+            prices: data.prices.into_reported("priceConfigDB.prices", data.id),
             notes: data.notes,
             enable_date: data.enable_date,
             created_at: data.created_at,
@@ -0,0 +1,224 @@
+// src/models/reconciliation_batch.rs
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use serde_json::Value as JsonValue;
+
+/// 沖帳批次模型 - 對應 reconciliationBatchDB 表結構
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ReconciliationBatch {
+    // Directus 系統字段
+    pub id: i64,
+    #[sqlx(default)]
+    pub user_created: Option<String>,
+    #[sqlx(default)]
+    pub date_created: Option<String>,
+    #[sqlx(default)]
+    pub user_updated: Option<String>,
+    #[sqlx(default)]
+    pub date_updated: Option<String>,
+
+    // 業務字段
+    #[sqlx(rename = "batchNumber")]
+    pub batch_number: String, // 'RC20261019-01'
+
+    #[sqlx(rename = "batchDate")]
+    pub batch_date: String, // 'YYYY-MM-DD'
+
+    #[sqlx(rename = "paymentMethod", default)]
+    pub payment_method: Option<String>, // 空值表示全部付款方式
+
+    #[sqlx(rename = "expectedCash", default)]
+    pub expected_cash: Option<i64>,
+
+    #[sqlx(rename = "expectedTransfer", default)]
+    pub expected_transfer: Option<i64>,
+
+    #[sqlx(rename = "expectedOther", default)]
+    pub expected_other: Option<i64>,
+
+    #[sqlx(rename = "expectedTotal", default)]
+    pub expected_total: Option<i64>,
+
+    #[sqlx(rename = "countedAmount", default)]
+    pub counted_amount: Option<i64>, // 實點金額（全部 / 現金批次為錢櫃現金，其它為核對後的入帳金額）
+
+    #[sqlx(default)]
+    pub variance: Option<i64>, // countedAmount - 該付款方式的應收金額（全部付款方式時為 expectedCash）
+
+    #[sqlx(rename = "recordCount", default)]
+    pub record_count: Option<i64>,
+
+    #[sqlx(rename = "recordIds", default)]
+    pub record_ids: Option<String>, // JSON 陣列字串
+
+    #[sqlx(default)]
+    pub state: Option<String>, // 'reconciled', 'void'
+
+    #[sqlx(rename = "reconciledBy", default)]
+    pub reconciled_by: Option<String>,
+
+    #[sqlx(default)]
+    pub notes: Option<String>,
+
+    #[sqlx(rename = "voidReason", default)]
+    pub void_reason: Option<String>,
+
+    // 自定義時間戳
+    #[sqlx(rename = "createdAt", default)]
+    pub created_at: Option<String>,
+    #[sqlx(rename = "updatedAt", default)]
+    pub updated_at: Option<String>,
+}
+
+/// API 響應用的沖帳批次 DTO
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconciliationBatchResponse {
+    // Directus 系統字段
+    pub id: i64,
+    #[serde(rename = "user_created", skip_serializing_if = "Option::is_none")]
+    pub user_created: Option<String>,
+    #[serde(rename = "date_created", skip_serializing_if = "Option::is_none")]
+    pub date_created: Option<String>,
+    #[serde(rename = "user_updated", skip_serializing_if = "Option::is_none")]
+    pub user_updated: Option<String>,
+    #[serde(rename = "date_updated", skip_serializing_if = "Option::is_none")]
+    pub date_updated: Option<String>,
+
+    // 自定義字段
+    pub batch_number: String,
+    pub batch_date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<String>,
+    pub expected_cash: Option<i64>,
+    pub expected_transfer: Option<i64>,
+    pub expected_other: Option<i64>,
+    pub expected_total: Option<i64>,
+    pub counted_amount: Option<i64>,
+    pub variance: Option<i64>,
+    pub record_count: Option<i64>,
+
+    // JSON 字段直接使用 JsonValue
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub record_ids: Option<JsonValue>,
+
+    pub state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reconciled_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub void_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<String>,
+}
+
+impl From<ReconciliationBatch> for ReconciliationBatchResponse {
+    fn from(data: ReconciliationBatch) -> Self {
+        Self {
+            id: data.id,
+            user_created: data.user_created,
+            date_created: data.date_created,
+            user_updated: data.user_updated,
+            date_updated: data.date_updated,
+            batch_number: data.batch_number,
+            batch_date: data.batch_date,
+            payment_method: data.payment_method,
+            expected_cash: data.expected_cash,
+            expected_transfer: data.expected_transfer,
+            expected_other: data.expected_other,
+            expected_total: data.expected_total,
+            counted_amount: data.counted_amount,
+            variance: data.variance,
+            record_count: data.record_count,
+            record_ids: data.record_ids
+                .and_then(|s| serde_json::from_str(&s).ok()),
+            state: data.state,
+            reconciled_by: data.reconciled_by,
+            notes: data.notes,
+            void_reason: data.void_reason,
+            created_at: data.created_at,
+            updated_at: data.updated_at,
+        }
+    }
+}
+
+/// 沖帳預覽查詢參數
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconciliationPreviewQuery {
+    pub date: String, // 'YYYY-MM-DD'
+    pub payment_method: Option<String>,
+}
+
+/// 單筆參加記錄在當日的入帳金額
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconciliationLine {
+    pub join_record_id: i64,
+    pub payment_method: String,
+    pub amount: i64,
+}
+
+/// 沖帳預覽結果（未寫入資料庫）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconciliationPreview {
+    pub batch_date: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payment_method: Option<String>,
+    pub expected_cash: i64,
+    pub expected_transfer: i64,
+    pub expected_other: i64,
+    pub expected_total: i64,
+    pub record_count: i64,
+    pub record_ids: Vec<i64>,
+    pub lines: Vec<ReconciliationLine>,
+}
+
+/// 建立沖帳批次請求
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateReconciliationBatchRequest {
+    pub batch_date: String, // 'YYYY-MM-DD'
+
+    #[serde(default)]
+    pub payment_method: Option<String>,
+
+    pub counted_amount: i64, // 實點金額（全部 / 現金批次為錢櫃現金，其它為核對後的入帳金額）
+
+    #[serde(default)]
+    pub record_ids: Option<Vec<i64>>, // 可選：只沖帳預覽中的部分記錄
+
+    #[serde(default)]
+    pub reconciled_by: Option<String>,
+
+    #[serde(default)]
+    pub notes: Option<String>,
+
+    #[serde(default)]
+    pub user_id: Option<String>,
+}
+
+/// 作廢沖帳批次請求
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoidReconciliationBatchRequest {
+    #[serde(default)]
+    pub void_reason: Option<String>,
+
+    #[serde(default)]
+    pub user_id: Option<String>,
+}
+
+/// 查詢參數
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconciliationBatchQuery {
+    pub batch_date: Option<String>,
+    pub state: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
@@ -3,6 +3,9 @@ use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use serde_json::Value as JsonValue;
 
+use crate::models::json_column::JsonColumn;
+use crate::models::json_types::{Blessing, Contact, Salvation};
+
 /// 報名記錄模型 - 對應 Directus 的 registrationDB 表結構
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct Registration {
@@ -27,67 +30,25 @@ pub struct Registration {
     #[sqlx(rename = "formSource", default)]
     pub form_source: Option<String>,
     
-    // JSON 字段 - 使用 serde_json::Value
+    // JSON 字段 - 解析為型別結構
     #[sqlx(default)]
-    #[serde(
-        serialize_with = "serialize_json_string",
-        deserialize_with = "deserialize_json_string",
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub salvation: Option<String>,
+    pub salvation: JsonColumn<Salvation>,
     
     #[sqlx(default)]
-    #[serde(
-        serialize_with = "serialize_json_string",
-        deserialize_with = "deserialize_json_string",
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub contact: Option<String>,
+    pub contact: JsonColumn<Contact>,
     
     #[sqlx(default)]
-    #[serde(
-        serialize_with = "serialize_json_string",
-        deserialize_with = "deserialize_json_string",
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub blessing: Option<String>,
+    pub blessing: JsonColumn<Blessing>,
     
     // 自定義時間戳
     #[sqlx(rename = "createdAt", default)]
     pub created_at: Option<String>,
     #[sqlx(rename = "updatedAt", default)]
     pub updated_at: Option<String>,    
-}
-
-// 自定義序列化函數：將 JSON 字符串轉為 JSON 對象
-fn serialize_json_string<S>(
-    value: &Option<String>,
-    serializer: S,
-) -> Result<S::Ok, S::Error>
-where
-    S: serde::Serializer,
-{
-    match value {
-        Some(s) => {
-            // 嘗試解析 JSON 字符串
-            match serde_json::from_str::<JsonValue>(s) {
-                Ok(json) => json.serialize(serializer),
-                Err(_) => serializer.serialize_none(), // 如果解析失敗，返回 null
-            }
-        }
-        None => serializer.serialize_none(),
-    }
-}
 
-// 自定義反序列化函數：將 JSON 對象轉為字符串
-fn deserialize_json_string<'de, D>(
-    deserializer: D,
-) -> Result<Option<String>, D::Error>
-where
-    D: serde::Deserializer<'de>,
-{
-    let value: Option<JsonValue> = Option::deserialize(deserializer)?;
-    Ok(value.map(|v| v.to_string()))
+    // 戶ID，householdDB.id
+    #[sqlx(rename = "householdId", default)]
+    pub household_id: Option<i64>,
 }
 
 /// 創建報名記錄請求
@@ -139,6 +100,16 @@ pub struct RegistrationQuery {
     pub sort: Option<String>,
 }
 
+/// 匯出查詢參數：篩選條件與列表相同，但不分頁
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegistrationExportQuery {
+    pub state: Option<String>,
+    pub form_id: Option<String>,
+    pub sort: Option<String>,
+    pub format: Option<String>, // 'csv' | 'xlsx' | 'json'，預設 csv
+}
+
 /// API 響應用的報名記錄 DTO
 #[derive(Debug, Clone, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -165,18 +136,20 @@ pub struct RegistrationResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub form_source: Option<String>,
     
-    // JSON 字段直接使用 JsonValue
+    // JSON 字段使用型別結構
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub salvation: Option<JsonValue>,
+    pub salvation: Option<Salvation>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub contact: Option<JsonValue>,
+    pub contact: Option<Contact>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub blessing: Option<JsonValue>,
+    pub blessing: Option<Blessing>,
     
     #[serde(skip_serializing_if = "Option::is_none")]
     pub created_at: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub household_id: Option<i64>,
 }
 
 impl From<Registration> for RegistrationResponse {
@@ -191,14 +164,68 @@ impl From<Registration> for RegistrationResponse {
             form_id: data.form_id,
             form_name: data.form_name,
             form_source: data.form_source,
-            salvation: data.salvation
-                .and_then(|s| serde_json::from_str(&s).ok()),
-            contact: data.contact
-                .and_then(|s| serde_json::from_str(&s).ok()),
-            blessing: data.blessing
-                .and_then(|s| serde_json::from_str(&s).ok()),
+            salvation: data.salvation.into_reported("registrationDB.salvation", data.id),
+            contact: data.contact.into_reported("registrationDB.contact", data.id),
+            blessing: data.blessing.into_reported("registrationDB.blessing", data.id),
             created_at: data.created_at,
             updated_at: data.updated_at,
+            household_id: data.household_id,
         }
     }
-}
\ No newline at end of file
+}
+/// 重複報名偵測查詢參數
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateQuery {
+    pub min_score: Option<i64>, // 預設 40
+    pub limit: Option<i64>,     // 預設 100
+}
+
+/// 重複候選中單筆報名的摘要
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateRegistrationSummary {
+    pub id: i64,
+    pub form_id: Option<String>,
+    pub state: Option<String>,
+    pub contact_name: String,
+    pub phone: String,
+    pub mobile: String,
+    pub created_at: Option<String>,
+}
+
+/// 疑似重複的報名組合（分數 0–100，reasons 為加分原因）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateCandidate {
+    pub score: i64,
+    pub reasons: Vec<String>,
+    pub first: DuplicateRegistrationSummary,
+    pub second: DuplicateRegistrationSummary,
+}
+
+/// 合併報名請求：merge_id 的名單併入 keep_id，merge_id 封存
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeRegistrationRequest {
+    pub keep_id: i64,
+    pub merge_id: i64,
+    #[serde(default, alias = "userId")]
+    pub user_updated: Option<String>,
+    // 合併後超過名單上限等規則時仍強制合併
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// 合併結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MergeRegistrationResult {
+    pub registration: RegistrationResponse,
+    pub archived_id: i64,
+    pub added_persons: usize,
+    pub added_ancestors: usize,
+    pub added_survivors: usize,
+    pub moved_join_records: u64,
+    pub moved_monthly_donates: u64,
+}
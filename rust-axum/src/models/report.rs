@@ -0,0 +1,99 @@
+// src/models/report.rs
+use serde::{Deserialize, Serialize};
+
+/// 每日結帳報表查詢參數
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyClosingQuery {
+    pub date: Option<String>,   // 'YYYY-MM-DD'，預設今天
+    pub format: Option<String>, // 'json' | 'csv' | 'pdf'，預設 json
+}
+
+/// 當日單筆入帳明細
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyPaymentLine {
+    pub source: String, // 'joinRecord' | 'monthlyDonate'
+    pub record_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub donate_items_id: Option<String>,
+    pub name: String,
+    pub handler: String,
+    pub payment_method: String,
+    pub payment_type: String, // 'payment' | 'refund' | 'reversal'
+    pub amount: i64,
+}
+
+/// 經手人 × 付款方式小計
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HandlerMethodTotal {
+    pub handler: String,
+    pub payment_method: String,
+    pub join_record_amount: i64,
+    pub monthly_donate_amount: i64,
+    pub amount: i64,
+    pub count: i64,
+}
+
+/// 當日開立的收據 / 感謝狀
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IssuedReceipt {
+    pub join_record_id: i64,
+    pub receipt_number: String,
+    pub receipt_type: String, // 'stamp'（收據）| 'standard'（感謝狀）
+    pub handler: String,
+    pub issued_at: String,
+    pub amount: i64,
+}
+
+/// 當日作廢的收據編號
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VoidedReceipt {
+    pub receipt_number: String,
+    pub receipt_type: String,
+    pub state: String,
+    pub void_reason: String,
+    pub record_id: Option<i64>,
+    pub voided_at: String,
+}
+
+/// 當日合計
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyClosingTotals {
+    pub join_record_amount: i64,
+    pub monthly_donate_amount: i64,
+    pub cash: i64,
+    pub transfer: i64,
+    pub other: i64,
+    pub grand_total: i64,
+    pub stamp_count: i64,
+    pub standard_count: i64,
+    pub void_count: i64,
+}
+
+/// 每日結帳報表
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DailyClosingReport {
+    pub date: String,
+    pub totals: DailyClosingTotals,
+    pub by_handler: Vec<HandlerMethodTotal>,
+    pub payments: Vec<DailyPaymentLine>,
+    pub receipts: Vec<IssuedReceipt>,
+    pub voids: Vec<VoidedReceipt>,
+}
+
+/// 無法解析為預期結構的 JSON 欄位
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvalidJsonRow {
+    pub table: String,
+    pub id: String,
+    pub column: String,
+    pub error: String,
+    pub raw: String, // 原始內容（過長時截斷）
+}
@@ -0,0 +1,33 @@
+// src/models/soft_delete.rs
+use serde::{Deserialize, Serialize};
+
+/// 刪除請求的查詢參數（DELETE 不帶 body，刪除者以查詢參數傳入）
+#[derive(Debug, Default, Deserialize)]
+pub struct DeleteQuery {
+    #[serde(alias = "userDeleted")]
+    pub user_deleted: Option<String>, // Directus 用戶 UUID
+}
+
+/// 永久刪除查詢參數
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgeDeletedQuery {
+    pub retention_days: Option<i64>, // 未指定時使用 SOFT_DELETE_RETENTION_DAYS
+}
+
+/// 永久刪除結果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgeDeletedResult {
+    pub retention_days: i64,
+    pub deleted_before: String, // 刪除時間早於此時間的資料已永久刪除（RFC 3339）
+    pub purged_count: u64,
+    pub by_table: Vec<PurgedTableCount>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PurgedTableCount {
+    pub table: String,
+    pub count: u64,
+}
@@ -0,0 +1,77 @@
+// src/models/taisui.rs
+use serde::{Deserialize, Serialize};
+
+/// 太歲查詢參數
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaiSuiQuery {
+    pub year: Option<i32>, // 西元年，預設今年
+}
+
+/// 犯太歲名單查詢參數
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaiSuiCandidateQuery {
+    pub year: Option<i32>,            // 西元年，預設今年
+    pub tai_sui_type: Option<String>, // 只列出指定類型，例如 '沖太歲'
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+/// 各類太歲對應的生肖（與前端 taisuiStore.getTaiSuiTypes 相同）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaiSuiTypes {
+    pub value_tai_sui: String, // 值太歲（本命年）
+    pub chong_tai_sui: String, // 沖太歲
+    pub hai_tai_sui: String,   // 害太歲
+    pub po_tai_sui: String,    // 破太歲
+    pub xing_tai_sui: String,  // 刑太歲
+}
+
+/// 單一生肖當年的神煞與建議點燈
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZodiacLamp {
+    pub zodiac: String,
+    pub zodiac_icon: String,
+    pub dizhi: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tai_sui_type: Option<String>, // 值 / 沖 / 刑 / 害 / 破太歲，未犯太歲時為空
+    pub notes: Vec<String>, // 神煞，例如 ["歲破", "大耗"]
+    pub ages: Vec<i64>,     // 當年虛歲
+    pub lamp_name: String,  // 建議點燈：太歲燈 / 元辰燈 / 光明燈
+}
+
+/// 指定年份的太歲資料（GET /api/taisui）
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaiSuiYear {
+    pub year: i32,
+    pub tiangan: String,
+    pub dizhi: String,
+    pub zodiac: String,
+    pub zodiac_icon: String,
+    pub tai_sui_types: TaiSuiTypes,
+    pub affected: Vec<ZodiacLamp>, // 犯太歲的五個生肖，依值、沖、刑、害、破排序
+    pub zodiacs: Vec<ZodiacLamp>,  // 十二生肖的點燈表
+}
+
+/// 犯太歲的消災人員
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaiSuiCandidate {
+    pub registration_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub household_id: Option<i64>,
+    pub person_name: String,
+    pub zodiac: String,
+    pub tai_sui_type: String,
+    pub lamp_name: String,
+    pub contact_name: String,
+    pub mobile: String,
+    pub phone: String,
+    pub address: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registered_at: Option<String>, // 最近一次報名的 createdAt
+}
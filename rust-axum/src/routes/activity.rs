@@ -13,12 +13,22 @@ pub fn create_routes() -> Router {
         .route("/api/activities", get(activity::get_all_activities))
         // 創建新活動
         .route("/api/activities", post(activity::create_activity))
+        // 立即將日期已過的進行中活動標記為已完成（背景工作亦會定期執行）
+        .route(
+            "/api/activities/auto-complete",
+            post(activity::auto_complete_activities),
+        )
         // 根據數據庫 ID 獲取活動 - ✅ 修正：使用 {id} 而不是 :id
         .route("/api/activities/{id}", get(activity::get_activity_by_id))
         // 更新活動 - ✅ 修正：使用 {id} 而不是 :id
         .route("/api/activities/{id}", patch(activity::update_activity))
         // 刪除活動 - ✅ 修正：使用 {id} 而不是 :id
         .route("/api/activities/{id}", delete(activity::delete_activity))
+        // 還原已刪除的活動
+        .route(
+            "/api/activities/{id}/restore",
+            post(activity::restore_activity),
+        )
         // 根據 activityId 獲取活動 - ✅ 修正：使用 {activity_id} 而不是 :activity_id
         .route(
             "/api/activities/by-activity-id/{activity_id}",
@@ -0,0 +1,14 @@
+// src/routes/activity_calendar.rs
+use axum::{routing::get, Router};
+
+use crate::handlers::activity_calendar;
+
+/// 創建活動行事曆相關的路由
+pub fn create_routes() -> Router {
+    Router::new()
+        // 活動行事曆訂閱（iCalendar），支援 item_type、state 篩選
+        .route(
+            "/api/activities.ics",
+            get(activity_calendar::get_activities_ics),
+        )
+}
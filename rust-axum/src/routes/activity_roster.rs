@@ -0,0 +1,14 @@
+// src/routes/activity_roster.rs
+use axum::{routing::get, Router};
+
+use crate::handlers::activity_roster;
+
+/// 創建法會名冊相關的路由
+pub fn create_routes() -> Router {
+    Router::new()
+        // 法會名冊（超度牌位 / 消災 / 點燈），支援 json、csv、pdf
+        .route(
+            "/api/activities/{id}/roster",
+            get(activity_roster::get_activity_roster),
+        )
+}
@@ -0,0 +1,14 @@
+// src/routes/activity_summary.rs
+use axum::{routing::get, Router};
+
+use crate::handlers::activity_summary;
+
+/// 創建活動財務摘要相關的路由
+pub fn create_routes() -> Router {
+    Router::new()
+        // 活動財務摘要：項目、金額、付款方式、收據與沖帳狀態
+        .route(
+            "/api/activities/{id}/summary",
+            get(activity_summary::get_activity_summary),
+        )
+}
@@ -0,0 +1,42 @@
+// src/routes/activity_template.rs
+use axum::{
+    routing::{delete, get, patch, post},
+    Router,
+};
+
+use crate::handlers::activity_template;
+
+/// 創建活動範本相關的路由
+pub fn create_routes() -> Router {
+    Router::new()
+        // 獲取所有活動範本
+        .route(
+            "/api/activity-templates",
+            get(activity_template::get_all_activity_templates),
+        )
+        // 創建活動範本
+        .route(
+            "/api/activity-templates",
+            post(activity_template::create_activity_template),
+        )
+        // 根據 ID 獲取活動範本
+        .route(
+            "/api/activity-templates/{id}",
+            get(activity_template::get_activity_template_by_id),
+        )
+        // 更新活動範本
+        .route(
+            "/api/activity-templates/{id}",
+            patch(activity_template::update_activity_template),
+        )
+        // 刪除活動範本
+        .route(
+            "/api/activity-templates/{id}",
+            delete(activity_template::delete_activity_template),
+        )
+        // 由範本建立指定年份的活動
+        .route(
+            "/api/activities/from-template",
+            post(activity_template::create_activity_from_template),
+        )
+}
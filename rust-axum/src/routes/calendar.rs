@@ -0,0 +1,13 @@
+// src/routes/calendar.rs
+use axum::{routing::get, Router};
+
+use crate::handlers::calendar;
+
+/// 創建農曆曆法相關的路由
+pub fn create_routes() -> Router {
+    Router::new()
+        // 農曆年曆與節日國曆日期
+        .route("/api/calendar/lunar", get(calendar::get_lunar_calendar))
+        // 國曆 ↔ 農曆換算
+        .route("/api/calendar/convert", get(calendar::convert_lunar_date))
+}
@@ -0,0 +1,20 @@
+// src/routes/household.rs
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::handlers::household;
+
+/// 創建戶相關的路由
+pub fn create_routes() -> Router {
+    Router::new()
+        // 獲取戶列表
+        .route("/api/households", get(household::get_all_households))
+        // 由報名記錄推導戶別（預設 dryRun）
+        .route("/api/households/derive", post(household::derive_households))
+        // 根據 ID 獲取戶的完整歷史
+        .route("/api/households/{id}", get(household::get_household_by_id))
+        // 手動歸戶
+        .route("/api/households/{id}/link", post(household::link_household))
+}
@@ -0,0 +1,26 @@
+// src/routes/import.rs
+use axum::{
+    extract::DefaultBodyLimit,
+    routing::{get, post},
+    Router,
+};
+
+use crate::handlers::import;
+
+/// 匯入檔案上限（base64 後約為原檔 4/3）
+const IMPORT_BODY_LIMIT: usize = 20 * 1024 * 1024;
+
+/// 創建資料匯入相關的路由
+pub fn create_routes() -> Router {
+    Router::new()
+        // 匯入 CSV / XLSX（collection = registrations | monthly-donates | join-records）
+        .route(
+            "/api/import/{collection}",
+            post(import::import_collection).layer(DefaultBodyLimit::max(IMPORT_BODY_LIMIT)),
+        )
+        // 可匯入的目標欄位
+        .route(
+            "/api/import/{collection}/fields",
+            get(import::get_import_fields),
+        )
+}
@@ -13,12 +13,26 @@ pub fn create_routes() -> Router {
         .route("/api/join-records", get(join_record::get_all_join_records))
         // 創建新參與記錄
         .route("/api/join-records", post(join_record::create_join_record))
+        // 批次創建 / 更新 / 刪除（單一事務）
+        .route("/api/join-records/batch", post(join_record::batch_join_records))
+        // 匯出參與記錄（format=csv|xlsx|json）
+        .route("/api/join-records/export", get(join_record::export_join_records))
+        // 從祈福登記表產生參與記錄（後端組裝 items 並計價）
+        .route(
+            "/api/join-records/from-registration",
+            post(join_record::create_join_record_from_registration),
+        )
         // 根據數據庫 ID 獲取參與記錄
         .route("/api/join-records/{id}", get(join_record::get_join_record_by_id))
         // 更新參與記錄
         .route("/api/join-records/{id}", patch(join_record::update_join_record))
         // 刪除參與記錄
         .route("/api/join-records/{id}", delete(join_record::delete_join_record))
+        // 還原已刪除的參與記錄
+        .route(
+            "/api/join-records/{id}/restore",
+            post(join_record::restore_join_record),
+        )
         // 根據 registrationId 獲取參與記錄
         .route(
             "/api/join-records/by-registration/{registration_id}",
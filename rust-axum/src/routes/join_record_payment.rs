@@ -0,0 +1,27 @@
+// src/routes/join_record_payment.rs
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::handlers::join_record_payment;
+
+/// 創建參加記錄付款流水相關的路由
+pub fn create_routes() -> Router {
+    Router::new()
+        // 獲取參加記錄的付款流水與摘要
+        .route(
+            "/api/join-records/{id}/payments",
+            get(join_record_payment::get_join_record_payments),
+        )
+        // 新增付款或退款
+        .route(
+            "/api/join-records/{id}/payments",
+            post(join_record_payment::add_join_record_payment),
+        )
+        // 沖銷指定付款流水
+        .route(
+            "/api/join-records/{id}/payments/{payment_id}/reverse",
+            post(join_record_payment::reverse_join_record_payment),
+        )
+}
@@ -7,3 +7,15 @@ pub mod receipt_number; // ✅ 新增：收據編號路由
 pub mod directus_users;
 pub mod price_config; // ✅ 新增：價格配置路由 by 20260331
 pub mod join_record; // ✅ 新增：加入紀錄路由 by 20260422
+pub mod join_record_payment; // ✅ 新增：付款流水路由 by 20261019
+pub mod reconciliation_batch; // ✅ 新增：沖帳批次路由 by 20261019
+pub mod report; // ✅ 新增：報表路由 by 20261019
+pub mod import; // ✅ 新增：資料匯入路由 by 20261019
+pub mod household; // ✅ 新增：戶路由 by 20261019
+pub mod taisui; // ✅ 新增：太歲路由 by 20261019
+pub mod calendar; // ✅ 新增：農曆曆法路由 by 20261019
+pub mod activity_template; // ✅ 新增：活動範本路由 by 20261019
+pub mod activity_summary; // ✅ 新增：活動財務摘要路由 by 20261019
+pub mod activity_roster; // ✅ 新增：法會名冊路由 by 20261019
+pub mod activity_calendar; // ✅ 新增：活動行事曆路由 by 20261019
+pub mod soft_delete; // ✅ 新增：軟刪除路由 by 20261019
@@ -19,6 +19,8 @@ pub fn create_routes() -> Router {
         .route("/api/monthly-donates/{id}", patch(monthly_donate::update_monthly_donate))
         // 刪除每月捐款記錄
         .route("/api/monthly-donates/{id}", delete(monthly_donate::delete_monthly_donate))
+        // 還原已刪除的每月捐款記錄
+        .route("/api/monthly-donates/{id}/restore", post(monthly_donate::restore_monthly_donate))
         // 根據 donateId 獲取每月捐款記錄
         .route(
             "/api/monthly-donates/by-donate-id/{donate_id}",
@@ -34,6 +36,21 @@ pub fn create_routes() -> Router {
             "/api/monthly-donates/by-type/{donate_type}",
             get(monthly_donate::get_monthly_donate_by_donate_type),
         )
+        // 續約名單（最後贊助月份即將到期）
+        .route(
+            "/api/monthly-donates/renewals",
+            get(monthly_donate::get_renewal_list),
+        )
+        // 缺月名單
+        .route(
+            "/api/monthly-donates/gaps",
+            get(monthly_donate::get_donate_gaps),
+        )
+        // 每月分攤明細帳
+        .route(
+            "/api/monthly-donates/ledger",
+            get(monthly_donate::get_monthly_donate_ledger),
+        )
         // 以下是根據 rustMonthlyDonateService.js 中的方法添加的路由
         // 批量操作（Rust 特有功能）
         // .route(
@@ -45,47 +62,47 @@ pub fn create_routes() -> Router {
         //     "/api/monthly-donates/search",
         //     post(monthly_donate::search_monthly_donates),
         // )
-        // // 導出贊助數據
-        // .route(
-        //     "/api/monthly-donates/export",
-        //     get(monthly_donate::export_monthly_donates),
-        // )
-        // // 獲取月度統計
-        // .route(
-        //     "/api/monthly-donates/stats/monthly",
-        //     get(monthly_donate::get_monthly_donate_stats),
-        // )
-        // // 獲取捐贈統計
-        // .route(
-        //     "/api/monthly-donates/stats/donation",
-        //     get(monthly_donate::get_donation_stats),
-        // )
-        // // 獲取捐贈類型統計
-        // .route(
-        //     "/api/monthly-donates/stats/types",
-        //     get(monthly_donate::get_donation_type_stats),
-        // )
-        // // 獲取捐贈趨勢
-        // .route(
-        //     "/api/monthly-donates/stats/trend/{period}",
-        //     get(monthly_donate::get_donation_trend),
-        // )
-        // // 以下為 donateItems 操作方法的路由（需要額外實現）
-        // // 新增指定贊助記錄
-        // .route(
-        //     "/api/monthly-donates/{id}/items",
-        //     post(monthly_donate::add_donate_item),
-        // )
-        // // 更新指定贊助記錄
-        // .route(
-        //     "/api/monthly-donates/{id}/items/{items_id}",
-        //     patch(monthly_donate::update_donate_item),
-        // )
-        // // 刪除指定贊助記錄
-        // .route(
-        //     "/api/monthly-donates/{id}/items/{items_id}",
-        //     delete(monthly_donate::delete_donate_item),
-        // )
+        // 導出贊助數據（format=csv|xlsx|json）
+        .route(
+            "/api/monthly-donates/export",
+            get(monthly_donate::export_monthly_donates),
+        )
+        // 獲取月度統計
+        .route(
+            "/api/monthly-donates/stats/monthly",
+            get(monthly_donate::get_monthly_donate_stats),
+        )
+        // 獲取捐贈統計
+        .route(
+            "/api/monthly-donates/stats/donation",
+            get(monthly_donate::get_donation_stats),
+        )
+        // 獲取捐贈類型統計
+        .route(
+            "/api/monthly-donates/stats/types",
+            get(monthly_donate::get_donation_type_stats),
+        )
+        // 獲取捐贈趨勢（monthly | quarterly | yearly）
+        .route(
+            "/api/monthly-donates/stats/trend/{period}",
+            get(monthly_donate::get_donation_trend),
+        )
+        // 以下為 donateItems 操作方法的路由
+        // 新增指定贊助記錄
+        .route(
+            "/api/monthly-donates/{id}/items",
+            post(monthly_donate::add_donate_item),
+        )
+        // 更新指定贊助記錄
+        .route(
+            "/api/monthly-donates/{id}/items/{items_id}",
+            patch(monthly_donate::update_donate_item),
+        )
+        // 刪除指定贊助記錄
+        .route(
+            "/api/monthly-donates/{id}/items/{items_id}",
+            delete(monthly_donate::delete_donate_item),
+        )
         // // 健康檢查
         // .route(
         //     "/api/monthly-donates/health",
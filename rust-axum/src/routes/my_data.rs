@@ -13,5 +13,6 @@ pub fn create_routes() -> Router {
         .route("/api/my-data/{id}", get(my_data::get_my_data_by_id))
         .route("/api/my-data/{id}", patch(my_data::update_my_data))
         .route("/api/my-data/{id}", delete(my_data::delete_my_data))
+        .route("/api/my-data/{id}/restore", post(my_data::restore_my_data))
         .route("/api/my-data/by-state/{state}", get(my_data::get_my_data_by_state))
 }
@@ -13,5 +13,6 @@ pub fn create_routes() -> Router {
         .route("/api/price-configs/{id}", get(price_config::get_price_config_by_id))
         .route("/api/price-configs/{id}", patch(price_config::update_price_config))
         .route("/api/price-configs/{id}", delete(price_config::delete_price_config))
+        .route("/api/price-configs/{id}/restore", post(price_config::restore_price_config))
         .route("/api/price-configs/by-state/{state}", get(price_config::get_price_config_by_state))
 }
@@ -0,0 +1,37 @@
+// src/routes/reconciliation_batch.rs
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::handlers::reconciliation_batch;
+
+/// 創建沖帳批次相關的路由
+pub fn create_routes() -> Router {
+    Router::new()
+        // 獲取所有沖帳批次
+        .route(
+            "/api/reconciliation-batches",
+            get(reconciliation_batch::get_all_reconciliation_batches),
+        )
+        // 建立沖帳批次
+        .route(
+            "/api/reconciliation-batches",
+            post(reconciliation_batch::create_reconciliation_batch),
+        )
+        // 沖帳預覽（不寫入）
+        .route(
+            "/api/reconciliation-batches/preview",
+            get(reconciliation_batch::preview_reconciliation_batch),
+        )
+        // 根據 ID 獲取沖帳批次
+        .route(
+            "/api/reconciliation-batches/{id}",
+            get(reconciliation_batch::get_reconciliation_batch_by_id),
+        )
+        // 作廢沖帳批次
+        .route(
+            "/api/reconciliation-batches/{id}/void",
+            post(reconciliation_batch::void_reconciliation_batch),
+        )
+}
@@ -13,12 +13,20 @@ pub fn create_routes() -> Router {
         .route("/api/registrations", get(registration::get_all_registrations))
         // 創建新報名記錄
         .route("/api/registrations", post(registration::create_registration))
+        // 匯出報名記錄（format=csv|xlsx|json）
+        .route("/api/registrations/export", get(registration::export_registrations))
+        // 疑似重複的報名記錄（minScore、limit）
+        .route("/api/registrations/duplicates", get(registration::get_duplicate_registrations))
+        // 合併兩筆報名記錄
+        .route("/api/registrations/merge", post(registration::merge_registrations))
         // 根據數據庫 ID 獲取報名記錄
         .route("/api/registrations/{id}", get(registration::get_registration_by_id))
         // 更新報名記錄
         .route("/api/registrations/{id}", patch(registration::update_registration))
         // 刪除報名記錄
         .route("/api/registrations/{id}", delete(registration::delete_registration))
+        // 還原已刪除的報名記錄
+        .route("/api/registrations/{id}/restore", post(registration::restore_registration))
         // 根據 formId 獲取報名記錄
         .route(
             "/api/registrations/by-form-id/{form_id}",
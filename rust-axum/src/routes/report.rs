@@ -0,0 +1,19 @@
+// src/routes/report.rs
+use axum::{routing::get, Router};
+
+use crate::handlers::report;
+
+/// 創建報表相關的路由
+pub fn create_routes() -> Router {
+    Router::new()
+        // 每日結帳報表（format=json|csv|pdf）
+        .route(
+            "/api/reports/daily-closing",
+            get(report::get_daily_closing_report),
+        )
+        // JSON 欄位內容無法解析的記錄
+        .route(
+            "/api/reports/invalid-json",
+            get(report::get_invalid_json_report),
+        )
+}
@@ -0,0 +1,14 @@
+// src/routes/soft_delete.rs
+use axum::{routing::post, Router};
+
+use crate::handlers::soft_delete;
+
+/// 創建已刪除資料相關的路由（各資料的還原路由見各自的路由檔）
+pub fn create_routes() -> Router {
+    Router::new()
+        // 立即永久刪除超過保留期限的已刪除資料（背景工作亦會定期執行）
+        .route(
+            "/api/deleted/purge",
+            post(soft_delete::purge_deleted_records),
+        )
+}
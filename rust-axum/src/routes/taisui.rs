@@ -0,0 +1,16 @@
+// src/routes/taisui.rs
+use axum::{routing::get, Router};
+
+use crate::handlers::taisui;
+
+/// 創建太歲相關的路由
+pub fn create_routes() -> Router {
+    Router::new()
+        // 指定年份犯太歲的生肖與點燈表
+        .route("/api/taisui", get(taisui::get_tai_sui))
+        // 生肖犯太歲的消災人員
+        .route(
+            "/api/registrations/taisui-candidates",
+            get(taisui::get_tai_sui_candidates),
+        )
+}
@@ -0,0 +1,71 @@
+// src/utils/csv.rs
+//! CSV 輸出 / 解析輔助函數（Excel 開啟需 UTF-8 BOM 才不會亂碼）
+
+pub const UTF8_BOM: &str = "\u{feff}";
+
+/// 跳脫單一欄位：含逗號、引號或換行時以雙引號包住
+pub fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// 組成一行 CSV（含結尾換行）
+pub fn row<S: AsRef<str>>(fields: &[S]) -> String {
+    let mut line = fields
+        .iter()
+        .map(|f| escape(f.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push_str("\r\n");
+    line
+}
+
+/// 解析 CSV 文字為 (列號, 欄位)（支援雙引號欄位、跳脫引號與欄位內換行），略過 BOM 與空白列；
+/// 列號從 1 起算並計入空白列，與試算表中的列號一致
+pub fn parse(text: &str) -> Vec<(usize, Vec<String>)> {
+    let text = text.strip_prefix(UTF8_BOM).unwrap_or(text);
+    let mut rows: Vec<(usize, Vec<String>)> = Vec::new();
+    let mut row_number = 1;
+    let mut row: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if in_quotes {
+            match c {
+                '"' if chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = false,
+                _ => field.push(c),
+            }
+            continue;
+        }
+        match c {
+            '"' if field.is_empty() => in_quotes = true,
+            ',' => row.push(std::mem::take(&mut field)),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => {
+                row.push(std::mem::take(&mut field));
+                if row.iter().any(|f| !f.trim().is_empty()) {
+                    rows.push((row_number, std::mem::take(&mut row)));
+                } else {
+                    row.clear();
+                }
+                row_number += 1;
+            }
+            _ => field.push(c),
+        }
+    }
+
+    row.push(field);
+    if row.iter().any(|f| !f.trim().is_empty()) {
+        rows.push((row_number, row));
+    }
+    rows
+}
@@ -0,0 +1,244 @@
+// src/utils/export.rs
+//! 資料匯出共用（format=csv|xlsx|json）
+//! CSV / JSON 在背景任務中邊查詢邊輸出（串流），大量資料不需全部載入記憶體；
+//! XLSX 需寫完整個 zip 目錄，因此收齊所有列後一次輸出
+
+use axum::{
+    body::Body,
+    http::header,
+    response::{IntoResponse, Response},
+};
+use futures_util::{stream, StreamExt};
+use serde_json::Value as JsonValue;
+use sqlx::{sqlite::SqliteRow, FromRow, SqlitePool};
+use tokio::sync::mpsc;
+
+use crate::utils::{csv, xlsx};
+
+const EXPORT_CHANNEL_SIZE: usize = 256;
+
+/// 匯出欄位值：數字在 XLSX / JSON 中保留數字型別，方便加總
+#[derive(Debug, Clone)]
+pub enum ExportCell {
+    Text(String),
+    Number(i64),
+}
+
+impl ExportCell {
+    fn as_text(&self) -> String {
+        match self {
+            ExportCell::Text(text) => text.clone(),
+            ExportCell::Number(n) => n.to_string(),
+        }
+    }
+
+    fn to_json(&self) -> JsonValue {
+        match self {
+            ExportCell::Text(text) => JsonValue::String(text.clone()),
+            ExportCell::Number(n) => JsonValue::from(*n),
+        }
+    }
+}
+
+impl From<String> for ExportCell {
+    fn from(value: String) -> Self {
+        ExportCell::Text(value)
+    }
+}
+
+impl From<&str> for ExportCell {
+    fn from(value: &str) -> Self {
+        ExportCell::Text(value.to_string())
+    }
+}
+
+impl From<Option<String>> for ExportCell {
+    fn from(value: Option<String>) -> Self {
+        ExportCell::Text(value.unwrap_or_default())
+    }
+}
+
+impl From<i64> for ExportCell {
+    fn from(value: i64) -> Self {
+        ExportCell::Number(value)
+    }
+}
+
+impl From<Option<i64>> for ExportCell {
+    fn from(value: Option<i64>) -> Self {
+        value.map(ExportCell::Number).unwrap_or(ExportCell::Text(String::new()))
+    }
+}
+
+pub type ExportRow = Vec<ExportCell>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportFormat {
+    Csv,
+    Xlsx,
+    Json,
+}
+
+impl ExportFormat {
+    /// 解析 format 參數，預設 csv
+    pub fn parse(format: Option<&str>) -> Result<Self, String> {
+        match format.unwrap_or("csv") {
+            "csv" => Ok(ExportFormat::Csv),
+            "xlsx" => Ok(ExportFormat::Xlsx),
+            "json" => Ok(ExportFormat::Json),
+            other => Err(format!("不支援的格式: {}（僅接受 csv、xlsx、json）", other)),
+        }
+    }
+
+    fn content_type(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+            ExportFormat::Json => "application/json; charset=utf-8",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Xlsx => "xlsx",
+            ExportFormat::Json => "json",
+        }
+    }
+}
+
+/// 依 sort 參數（"-field" 表示遞減）組 ORDER BY，欄位名稱只允許英數與底線
+pub fn order_by_clause(sort: Option<&str>, default: &str) -> Result<String, String> {
+    let Some(sort) = sort else {
+        return Ok(format!(" ORDER BY {}", default));
+    };
+    let (field, direction) = match sort.strip_prefix('-') {
+        Some(field) => (field, "DESC"),
+        None => (sort, "ASC"),
+    };
+    if field.is_empty() || !field.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(format!("無效的排序欄位: {}", sort));
+    }
+    Ok(format!(" ORDER BY {} {}", field, direction))
+}
+
+/// 在背景任務中逐筆讀取查詢結果，並由 to_rows 轉為匯出列（一筆記錄可展開為多列）
+pub fn spawn_export_rows<T, F>(
+    pool: SqlitePool,
+    sql: String,
+    bindings: Vec<String>,
+    to_rows: F,
+) -> mpsc::Receiver<Result<ExportRow, String>>
+where
+    T: for<'r> FromRow<'r, SqliteRow> + Send + Unpin + 'static,
+    F: Fn(T) -> Vec<ExportRow> + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel(EXPORT_CHANNEL_SIZE);
+
+    tokio::spawn(async move {
+        let mut query = sqlx::query_as::<_, T>(&sql);
+        for binding in &bindings {
+            query = query.bind(binding);
+        }
+        let mut records = query.fetch(&pool);
+
+        while let Some(record) = records.next().await {
+            match record {
+                Ok(record) => {
+                    for row in to_rows(record) {
+                        // 接收端已關閉（用戶端中斷下載）就停止查詢
+                        if tx.send(Ok(row)).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+                Err(e) => {
+                    let _ = tx.send(Err(e.to_string())).await;
+                    return;
+                }
+            }
+        }
+    });
+
+    rx
+}
+
+/// 組成匯出回應；查詢失敗時返回錯誤訊息，CSV / JSON 開始串流後才失敗則只能記錄並中斷輸出
+pub async fn export_response(
+    format: ExportFormat,
+    file_stem: &str,
+    headers: Vec<&'static str>,
+    mut rows: mpsc::Receiver<Result<ExportRow, String>>,
+) -> Result<Response, String> {
+    let filename = format!(
+        "{}-{}.{}",
+        file_stem,
+        chrono::Local::now().format("%Y%m%d"),
+        format.extension()
+    );
+    let response_headers = [
+        (header::CONTENT_TYPE, format.content_type().to_string()),
+        (
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}\"", filename),
+        ),
+    ];
+
+    if format == ExportFormat::Xlsx {
+        let mut all_rows: Vec<ExportRow> = Vec::new();
+        while let Some(row) = rows.recv().await {
+            all_rows.push(row?);
+        }
+        let bytes = xlsx::workbook(file_stem, &headers, &all_rows);
+        return Ok((response_headers, bytes).into_response());
+    }
+
+    // 先等第一列：查詢本身失敗（例如 SQL 錯誤）時仍可返回錯誤狀態碼
+    let first = match rows.recv().await {
+        Some(Ok(row)) => Some(row),
+        Some(Err(e)) => return Err(e),
+        None => None,
+    };
+
+    let remaining = stream::unfold(rows, |mut rows| async move {
+        match rows.recv().await {
+            Some(Ok(row)) => Some((row, rows)),
+            Some(Err(e)) => {
+                tracing::error!("匯出查詢失敗: {}", e);
+                None
+            }
+            None => None,
+        }
+    });
+    let records = stream::iter(first).chain(remaining);
+
+    let body = match format {
+        ExportFormat::Json => {
+            let lines = records.enumerate().map(move |(index, row)| {
+                // 依欄位順序輸出（serde_json::Map 會依鍵排序）
+                let fields = headers
+                    .iter()
+                    .zip(row.iter())
+                    .map(|(key, cell)| format!("{}:{}", JsonValue::from(*key), cell.to_json()))
+                    .collect::<Vec<_>>()
+                    .join(",");
+                let separator = if index == 0 { "\n" } else { ",\n" };
+                format!("{}{{{}}}", separator, fields)
+            });
+            let chunks = stream::once(async { "[".to_string() })
+                .chain(lines)
+                .chain(stream::once(async { "\n]\n".to_string() }));
+            Body::from_stream(chunks.map(Ok::<_, std::io::Error>))
+        }
+        _ => {
+            let header_line = format!("{}{}", csv::UTF8_BOM, csv::row(&headers));
+            let lines = records.map(|row| {
+                csv::row(&row.iter().map(ExportCell::as_text).collect::<Vec<_>>())
+            });
+            let chunks = stream::once(async move { header_line }).chain(lines);
+            Body::from_stream(chunks.map(Ok::<_, std::io::Error>))
+        }
+    };
+
+    Ok((response_headers, body).into_response())
+}
@@ -0,0 +1,140 @@
+// src/utils/ics.rs
+//! iCalendar（RFC 5545）輸出輔助函數：文字跳脫、75 位元組折行與 CRLF 換行
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+
+/// 跳脫 TEXT 值：反斜線、分號、逗號與換行
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\n' | '\r' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 國曆日期 / 時間，依資料庫中的格式決定輸出方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventTime {
+    Date(NaiveDate),      // 只有日期：全天活動
+    Local(NaiveDateTime), // 未標時區：視為本地時間（TZID）
+    Utc(DateTime<Utc>),   // 含時區（RFC 3339）
+}
+
+impl EventTime {
+    /// 解析活動日期：'YYYY-MM-DD'、'YYYY-MM-DD HH:MM[:SS]'（或以 T 分隔）、RFC 3339
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+            return Some(EventTime::Utc(dt.with_timezone(&Utc)));
+        }
+        for fmt in [
+            "%Y-%m-%d %H:%M:%S",
+            "%Y-%m-%d %H:%M",
+            "%Y-%m-%dT%H:%M:%S",
+            "%Y-%m-%dT%H:%M",
+        ] {
+            if let Ok(dt) = NaiveDateTime::parse_from_str(value, fmt) {
+                return Some(EventTime::Local(dt));
+            }
+        }
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .ok()
+            .map(EventTime::Date)
+    }
+}
+
+/// UTC 時間戳（DTSTAMP、LAST-MODIFIED 用）
+pub fn utc_stamp(dt: DateTime<Utc>) -> String {
+    dt.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// iCalendar 文件建構器，輸出時每行以 CRLF 結尾，超過 75 位元組的行自動折行
+pub struct Calendar {
+    lines: Vec<String>,
+    tzid: String,
+}
+
+impl Calendar {
+    /// 建立 VCALENDAR；本地時間以 tzid 標示（固定 UTC 偏移、無日光節約時間）
+    pub fn new(name: &str, tzid: &str, utc_offset: &str) -> Self {
+        let mut calendar = Calendar {
+            lines: Vec::new(),
+            tzid: tzid.to_string(),
+        };
+        calendar.line("BEGIN:VCALENDAR");
+        calendar.line("VERSION:2.0");
+        calendar.line(&format!(
+            "PRODID:-//{}//{}//ZH",
+            escape(name),
+            env!("CARGO_PKG_NAME")
+        ));
+        calendar.line("CALSCALE:GREGORIAN");
+        calendar.line("METHOD:PUBLISH");
+        calendar.property("X-WR-CALNAME", name);
+        calendar.line(&format!("X-WR-TIMEZONE:{}", tzid));
+        calendar.line("BEGIN:VTIMEZONE");
+        calendar.line(&format!("TZID:{}", tzid));
+        calendar.line("BEGIN:STANDARD");
+        calendar.line("DTSTART:19700101T000000");
+        calendar.line(&format!("TZOFFSETFROM:{}", utc_offset));
+        calendar.line(&format!("TZOFFSETTO:{}", utc_offset));
+        calendar.line("END:STANDARD");
+        calendar.line("END:VTIMEZONE");
+        calendar
+    }
+
+    /// 加入一行原始內容（名稱與參數需自行組好）
+    pub fn line(&mut self, line: &str) {
+        self.lines.push(line.to_string());
+    }
+
+    /// 加入 TEXT 屬性（自動跳脫）
+    pub fn property(&mut self, name: &str, value: &str) {
+        self.lines.push(format!("{}:{}", name, escape(value)));
+    }
+
+    /// 加入日期 / 時間屬性（DTSTART、DTEND）
+    pub fn time_property(&mut self, name: &str, time: EventTime) {
+        let line = match time {
+            EventTime::Date(date) => format!("{};VALUE=DATE:{}", name, date.format("%Y%m%d")),
+            EventTime::Local(dt) => {
+                format!("{};TZID={}:{}", name, self.tzid, dt.format("%Y%m%dT%H%M%S"))
+            }
+            EventTime::Utc(dt) => format!("{}:{}", name, utc_stamp(dt)),
+        };
+        self.lines.push(line);
+    }
+
+    /// 結束 VCALENDAR 並輸出
+    pub fn finish(mut self) -> String {
+        self.line("END:VCALENDAR");
+        let mut output = String::new();
+        for line in &self.lines {
+            fold_into(&mut output, line);
+        }
+        output
+    }
+}
+
+/// 折行：每行最多 75 位元組（不切斷 UTF-8 字元），續行以一個空白開頭
+fn fold_into(output: &mut String, line: &str) {
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > 75 {
+            output.push_str("\r\n ");
+            width = 1;
+        }
+        output.push(c);
+        width += len;
+    }
+    output.push_str("\r\n");
+}
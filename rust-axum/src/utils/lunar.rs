@@ -0,0 +1,248 @@
+// src/utils/lunar.rs
+//! 農曆 ↔ 國曆換算（支援農曆 1900–2100 年，以 1900-01-31 = 農曆 1900 年正月初一為基準）
+
+use chrono::{Datelike, NaiveDate};
+
+pub const MIN_YEAR: i32 = 1900;
+pub const MAX_YEAR: i32 = 2100;
+
+pub const TIANGANS: &[&str] = &["甲", "乙", "丙", "丁", "戊", "己", "庚", "辛", "壬", "癸"];
+pub const DIZHIS: &[&str] = &[
+    "子", "丑", "寅", "卯", "辰", "巳", "午", "未", "申", "酉", "戌", "亥",
+];
+
+const MONTH_NAMES: &[&str] = &[
+    "正", "二", "三", "四", "五", "六", "七", "八", "九", "十", "十一", "十二",
+];
+const DAY_TENS: &[&str] = &["初", "十", "廿", "三"];
+const DIGITS: &[&str] = &["一", "二", "三", "四", "五", "六", "七", "八", "九", "十"];
+const WEEKDAYS: &[&str] = &["一", "二", "三", "四", "五", "六", "日"];
+
+/// 每年一筆：bit 0-3 閏幾月（0 表示無閏月）、bit 4-15 正月到十二月是否大月（30 天，正月在 bit 15）、
+/// bit 16 閏月是否大月
+const LUNAR_INFO: [u32; 201] = [
+    0x04bd8, 0x04ae0, 0x0a570, 0x054d5, 0x0d260, 0x0d950, 0x16554, 0x056a0, 0x09ad0,
+    0x055d2, // 1900-1909
+    0x04ae0, 0x0a5b6, 0x0a4d0, 0x0d250, 0x1d255, 0x0b540, 0x0d6a0, 0x0ada2, 0x095b0,
+    0x14977, // 1910-1919
+    0x04970, 0x0a4b0, 0x0b4b5, 0x06a50, 0x06d40, 0x1ab54, 0x02b60, 0x09570, 0x052f2,
+    0x04970, // 1920-1929
+    0x06566, 0x0d4a0, 0x0ea50, 0x16a95, 0x05ad0, 0x02b60, 0x186e3, 0x092e0, 0x1c8d7,
+    0x0c950, // 1930-1939
+    0x0d4a0, 0x1d8a6, 0x0b550, 0x056a0, 0x1a5b4, 0x025d0, 0x092d0, 0x0d2b2, 0x0a950,
+    0x0b557, // 1940-1949
+    0x06ca0, 0x0b550, 0x15355, 0x04da0, 0x0a5b0, 0x14573, 0x052b0, 0x0a9a8, 0x0e950,
+    0x06aa0, // 1950-1959
+    0x0aea6, 0x0ab50, 0x04b60, 0x0aae4, 0x0a570, 0x05260, 0x0f263, 0x0d950, 0x05b57,
+    0x056a0, // 1960-1969
+    0x096d0, 0x04dd5, 0x04ad0, 0x0a4d0, 0x0d4d4, 0x0d250, 0x0d558, 0x0b540, 0x0b6a0,
+    0x195a6, // 1970-1979
+    0x095b0, 0x049b0, 0x0a974, 0x0a4b0, 0x0b27a, 0x06a50, 0x06d40, 0x0af46, 0x0ab60,
+    0x09570, // 1980-1989
+    0x04af5, 0x04970, 0x064b0, 0x074a3, 0x0ea50, 0x06b58, 0x05ac0, 0x0ab60, 0x096d5,
+    0x092e0, // 1990-1999
+    0x0c960, 0x0d954, 0x0d4a0, 0x0da50, 0x07552, 0x056a0, 0x0abb7, 0x025d0, 0x092d0,
+    0x0cab5, // 2000-2009
+    0x0a950, 0x0b4a0, 0x0baa4, 0x0ad50, 0x055d9, 0x04ba0, 0x0a5b0, 0x15176, 0x052b0,
+    0x0a930, // 2010-2019
+    0x07954, 0x06aa0, 0x0ad50, 0x05b52, 0x04b60, 0x0a6e6, 0x0a4e0, 0x0d260, 0x0ea65,
+    0x0d530, // 2020-2029
+    0x05aa0, 0x076a3, 0x096d0, 0x04afb, 0x04ad0, 0x0a4d0, 0x1d0b6, 0x0d250, 0x0d520,
+    0x0dd45, // 2030-2039
+    0x0b5a0, 0x056d0, 0x055b2, 0x049b0, 0x0a577, 0x0a4b0, 0x0aa50, 0x1b255, 0x06d20,
+    0x0ada0, // 2040-2049
+    0x14b63, 0x09370, 0x049f8, 0x04970, 0x064b0, 0x168a6, 0x0ea50, 0x06b20, 0x1a6c4,
+    0x0aae0, // 2050-2059
+    0x092e0, 0x0d2e3, 0x0c960, 0x0d557, 0x0d4a0, 0x0da50, 0x05d55, 0x056a0, 0x0a6d0,
+    0x055d4, // 2060-2069
+    0x052d0, 0x0a9b8, 0x0a950, 0x0b4a0, 0x0b6a6, 0x0ad50, 0x055a0, 0x0aba4, 0x0a5b0,
+    0x052b0, // 2070-2079
+    0x0b273, 0x06930, 0x07337, 0x06aa0, 0x0ad50, 0x14b55, 0x04b60, 0x0a570, 0x054e4,
+    0x0d160, // 2080-2089
+    0x0e968, 0x0d520, 0x0daa0, 0x16aa6, 0x056d0, 0x04ae0, 0x0a9d4, 0x0a2d0, 0x0d150,
+    0x0f252, // 2090-2099
+    0x0d520, // 2100
+];
+
+/// 農曆日期
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LunarDate {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub is_leap: bool, // 是否為閏月
+}
+
+impl LunarDate {
+    /// 月份名稱，例如「正月」、「閏四月」
+    pub fn month_text(&self) -> String {
+        month_text(self.month, self.is_leap)
+    }
+
+    /// 日期名稱，例如「初一」、「十五」、「廿九」
+    pub fn day_text(&self) -> String {
+        day_text(self.day)
+    }
+
+    /// 例如「七月十五」、「閏四月初八」
+    pub fn text(&self) -> String {
+        format!("{}{}", self.month_text(), self.day_text())
+    }
+}
+
+pub fn month_text(month: u32, is_leap: bool) -> String {
+    let name = MONTH_NAMES
+        .get(month.wrapping_sub(1) as usize)
+        .copied()
+        .unwrap_or("?");
+    format!("{}{}月", if is_leap { "閏" } else { "" }, name)
+}
+
+pub fn day_text(day: u32) -> String {
+    match day {
+        10 => "初十".to_string(),
+        20 => "二十".to_string(),
+        30 => "三十".to_string(),
+        1..=29 => format!(
+            "{}{}",
+            DAY_TENS[(day / 10) as usize],
+            DIGITS[(day % 10 - 1) as usize]
+        ),
+        _ => "?".to_string(),
+    }
+}
+
+/// 國曆星期，例如「三」
+pub fn weekday_text(date: NaiveDate) -> &'static str {
+    WEEKDAYS[date.weekday().num_days_from_monday() as usize]
+}
+
+/// 年份的天干索引：公元 4 年為甲子年
+pub fn year_tiangan_index(year: i32) -> usize {
+    (year - 4).rem_euclid(10) as usize
+}
+
+/// 年份的地支（生肖）索引：公元 4 年為甲子年
+pub fn year_dizhi_index(year: i32) -> usize {
+    (year - 4).rem_euclid(12) as usize
+}
+
+/// 農曆年的干支，例如 2027 →「丁未」
+pub fn ganzhi(year: i32) -> String {
+    format!(
+        "{}{}",
+        TIANGANS[year_tiangan_index(year)],
+        DIZHIS[year_dizhi_index(year)]
+    )
+}
+
+fn info(year: i32) -> Option<u32> {
+    (MIN_YEAR..=MAX_YEAR)
+        .contains(&year)
+        .then(|| LUNAR_INFO[(year - MIN_YEAR) as usize])
+}
+
+/// 閏幾月，沒有閏月時為 None
+pub fn leap_month(year: i32) -> Option<u32> {
+    info(year).map(|i| i & 0xf).filter(|m| *m > 0)
+}
+
+/// 農曆月份天數（29 或 30）
+pub fn month_days(year: i32, month: u32, is_leap: bool) -> Option<u32> {
+    let info = info(year)?;
+    if !(1..=12).contains(&month) {
+        return None;
+    }
+    if is_leap {
+        if leap_month(year) != Some(month) {
+            return None;
+        }
+        return Some(if info & 0x10000 != 0 { 30 } else { 29 });
+    }
+    Some(if info & (0x10000 >> month) != 0 {
+        30
+    } else {
+        29
+    })
+}
+
+/// 依序列出農曆年的各月（含閏月）：(月份, 是否閏月, 天數)
+pub fn months(year: i32) -> Vec<(u32, bool, u32)> {
+    let mut months = Vec::new();
+    for month in 1..=12 {
+        if let Some(days) = month_days(year, month, false) {
+            months.push((month, false, days));
+        }
+        if leap_month(year) == Some(month) {
+            if let Some(days) = month_days(year, month, true) {
+                months.push((month, true, days));
+            }
+        }
+    }
+    months
+}
+
+fn year_days(year: i32) -> u32 {
+    months(year).iter().map(|(_, _, days)| days).sum()
+}
+
+fn base_date() -> NaiveDate {
+    NaiveDate::from_ymd_opt(1900, 1, 31).expect("valid base date")
+}
+
+/// 農曆正月初一的國曆日期
+pub fn new_year(year: i32) -> Option<NaiveDate> {
+    info(year)?;
+    let offset: u32 = (MIN_YEAR..year).map(year_days).sum();
+    base_date().checked_add_days(chrono::Days::new(offset as u64))
+}
+
+/// 今天（伺服器本地時區）所在的農曆年
+pub fn current_year() -> i32 {
+    from_solar(chrono::Local::now().date_naive())
+        .map(|d| d.year)
+        .unwrap_or(MIN_YEAR)
+}
+
+/// 農曆 → 國曆；日期不存在（例如小月三十、該年沒有此閏月）時為 None
+pub fn to_solar(date: LunarDate) -> Option<NaiveDate> {
+    let days = month_days(date.year, date.month, date.is_leap)?;
+    if date.day < 1 || date.day > days {
+        return None;
+    }
+    let offset: u32 = months(date.year)
+        .iter()
+        .take_while(|(month, is_leap, _)| (*month, *is_leap) != (date.month, date.is_leap))
+        .map(|(_, _, days)| days)
+        .sum::<u32>()
+        + date.day
+        - 1;
+    new_year(date.year)?.checked_add_days(chrono::Days::new(offset as u64))
+}
+
+/// 國曆 → 農曆；超出支援範圍時為 None
+pub fn from_solar(date: NaiveDate) -> Option<LunarDate> {
+    let mut offset = date.signed_duration_since(base_date()).num_days();
+    if offset < 0 {
+        return None;
+    }
+    for year in MIN_YEAR..=MAX_YEAR {
+        let days = year_days(year) as i64;
+        if offset >= days {
+            offset -= days;
+            continue;
+        }
+        for (month, is_leap, days) in months(year) {
+            if offset < days as i64 {
+                return Some(LunarDate {
+                    year,
+                    month,
+                    day: offset as u32 + 1,
+                    is_leap,
+                });
+            }
+            offset -= days as i64;
+        }
+    }
+    None
+}
@@ -0,0 +1,6 @@
+pub mod csv; // ✅ 新增：CSV 輸出 by 20261019
+pub mod export; // ✅ 新增：CSV / XLSX / JSON 匯出 by 20261019
+pub mod pdf; // ✅ 新增：PDF 報表輸出 by 20261019
+pub mod xlsx; // ✅ 新增：XLSX 輸出 by 20261019
+pub mod lunar; // ✅ 新增：農曆換算 by 20261019
+pub mod ics; // ✅ 新增：iCalendar 輸出 by 20261019
@@ -0,0 +1,188 @@
+// src/utils/pdf.rs
+//! 簡易 PDF 產生器（列印報表用）
+//! 使用 Adobe-CNS1 標準字型 MSung-Light（不嵌入字型檔），
+//! 閱讀器需支援亞洲字型（Acrobat / Chrome / pdf.js 皆可）
+
+pub const PAGE_WIDTH: f32 = 595.0; // A4
+pub const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN_TOP: f32 = 50.0;
+const MARGIN_BOTTOM: f32 = 50.0;
+pub const MARGIN_LEFT: f32 = 40.0;
+
+/// 以行為單位排版的 PDF 文件，超過頁尾自動換頁
+pub struct PdfDocument {
+    pages: Vec<String>,
+    current: String,
+    cursor_y: f32,
+}
+
+impl Default for PdfDocument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PdfDocument {
+    pub fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            current: String::new(),
+            cursor_y: PAGE_HEIGHT - MARGIN_TOP,
+        }
+    }
+
+    /// 寫入一行，columns 為 (x 座標, 文字)
+    pub fn line(&mut self, columns: &[(f32, &str)], font_size: f32) {
+        let line_height = font_size * 1.5;
+        if self.cursor_y - line_height < MARGIN_BOTTOM {
+            self.new_page();
+        }
+        self.cursor_y -= line_height;
+
+        for (x, text) in columns {
+            if text.is_empty() {
+                continue;
+            }
+            self.current.push_str(&format!(
+                "BT /F1 {} Tf {:.1} {:.1} Td <{}> Tj ET\n",
+                font_size,
+                x,
+                self.cursor_y,
+                encode_ucs2(text)
+            ));
+        }
+    }
+
+    /// 空白行
+    pub fn gap(&mut self, height: f32) {
+        self.cursor_y -= height;
+    }
+
+    /// 水平分隔線
+    pub fn rule(&mut self) {
+        self.cursor_y -= 4.0;
+        self.current.push_str(&format!(
+            "0.5 w {:.1} {:.1} m {:.1} {:.1} l S\n",
+            MARGIN_LEFT,
+            self.cursor_y,
+            PAGE_WIDTH - MARGIN_LEFT,
+            self.cursor_y
+        ));
+    }
+
+    /// 矩形框（絕對座標，(x, y) 為左下角）
+    pub fn rect(&mut self, x: f32, y: f32, width: f32, height: f32) {
+        self.current.push_str(&format!(
+            "0.8 w {:.1} {:.1} {:.1} {:.1} re S\n",
+            x, y, width, height
+        ));
+    }
+
+    /// 直書文字：自 (center_x, top_y) 由上而下逐字排列（絕對座標，不影響行游標）
+    pub fn vertical_text(&mut self, center_x: f32, top_y: f32, text: &str, font_size: f32) {
+        let mut y = top_y;
+        for c in text.chars().filter(|c| !c.is_whitespace()) {
+            y -= font_size * 1.1;
+            // 半形字寬為全形的一半
+            let width = if c.is_ascii() { font_size * 0.5 } else { font_size };
+            self.current.push_str(&format!(
+                "BT /F1 {} Tf {:.1} {:.1} Td <{}> Tj ET\n",
+                font_size,
+                center_x - width / 2.0,
+                y,
+                encode_ucs2(&c.to_string())
+            ));
+        }
+    }
+
+    pub fn new_page(&mut self) {
+        let content = std::mem::take(&mut self.current);
+        self.pages.push(content);
+        self.cursor_y = PAGE_HEIGHT - MARGIN_TOP;
+    }
+
+    /// 輸出 PDF 位元組
+    pub fn finish(mut self) -> Vec<u8> {
+        if !self.current.is_empty() || self.pages.is_empty() {
+            self.new_page();
+        }
+
+        // 物件編號：1 Catalog, 2 Pages, 3 Type0 字型, 4 CIDFont, 5 FontDescriptor,
+        // 之後每頁兩個物件（Page + Content）
+        let page_count = self.pages.len();
+        let mut objects: Vec<String> = Vec::with_capacity(5 + page_count * 2);
+
+        let kids = (0..page_count)
+            .map(|i| format!("{} 0 R", 6 + i * 2))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        objects.push("<< /Type /Catalog /Pages 2 0 R >>".to_string());
+        objects.push(format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            kids, page_count
+        ));
+        objects.push(
+            "<< /Type /Font /Subtype /Type0 /BaseFont /MSung-Light /Encoding /UniCNS-UCS2-H /DescendantFonts [4 0 R] >>"
+                .to_string(),
+        );
+        objects.push(
+            "<< /Type /Font /Subtype /CIDFontType0 /BaseFont /MSung-Light /CIDSystemInfo << /Registry (Adobe) /Ordering (CNS1) /Supplement 0 >> /FontDescriptor 5 0 R /DW 1000 /W [1 95 500] >>"
+                .to_string(),
+        );
+        objects.push(
+            "<< /Type /FontDescriptor /FontName /MSung-Light /Flags 6 /FontBBox [-160 -259 1015 888] /ItalicAngle 0 /Ascent 888 /Descent -259 /CapHeight 888 /StemV 93 >>"
+                .to_string(),
+        );
+
+        for (i, content) in self.pages.iter().enumerate() {
+            objects.push(format!(
+                "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+                PAGE_WIDTH,
+                PAGE_HEIGHT,
+                7 + i * 2
+            ));
+            objects.push(format!(
+                "<< /Length {} >>\nstream\n{}endstream",
+                content.len(),
+                content
+            ));
+        }
+
+        let mut out: Vec<u8> = b"%PDF-1.4\n".to_vec();
+        let mut offsets: Vec<usize> = Vec::with_capacity(objects.len());
+        for (i, object) in objects.iter().enumerate() {
+            offsets.push(out.len());
+            out.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", i + 1, object).as_bytes());
+        }
+
+        let xref_offset = out.len();
+        out.extend_from_slice(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).as_bytes());
+        for offset in offsets {
+            out.extend_from_slice(format!("{:010} 00000 n \n", offset).as_bytes());
+        }
+        out.extend_from_slice(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+                objects.len() + 1,
+                xref_offset
+            )
+            .as_bytes(),
+        );
+        out
+    }
+}
+
+/// 文字轉為 UCS-2 十六進位字串（BMP 以外的字元以「?」代替）
+fn encode_ucs2(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            let code = c as u32;
+            if code > 0xFFFF {
+                "003F".to_string()
+            } else {
+                format!("{:04X}", code)
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,372 @@
+// src/utils/xlsx.rs
+//! 簡易 XLSX 讀寫（單一工作表）
+//! XLSX 為 zip 包裝的 XML，寫入時以不壓縮（stored）方式輸出，字串使用 inlineStr 不需 sharedStrings；
+//! 讀取時只取第一個工作表的儲存格文字（日期請在 Excel 中先設為文字格式）
+
+use std::io::{Cursor, Read};
+
+use crate::utils::export::ExportCell;
+
+/// 產生只含一個工作表的 XLSX 檔案
+pub fn workbook(sheet_name: &str, headers: &[&str], rows: &[Vec<ExportCell>]) -> Vec<u8> {
+    let mut sheet = String::from(
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main"><sheetData>"#,
+    );
+
+    let header_cells: Vec<ExportCell> = headers.iter().map(|h| ExportCell::Text(h.to_string())).collect();
+    for (index, cells) in std::iter::once(&header_cells).chain(rows.iter()).enumerate() {
+        let row_number = index + 1;
+        sheet.push_str(&format!("<row r=\"{}\">", row_number));
+        for (col, cell) in cells.iter().enumerate() {
+            let reference = format!("{}{}", column_name(col), row_number);
+            match cell {
+                ExportCell::Number(n) => {
+                    sheet.push_str(&format!("<c r=\"{}\"><v>{}</v></c>", reference, n));
+                }
+                ExportCell::Text(text) if text.is_empty() => {}
+                ExportCell::Text(text) => {
+                    sheet.push_str(&format!(
+                        "<c r=\"{}\" t=\"inlineStr\"><is><t xml:space=\"preserve\">{}</t></is></c>",
+                        reference,
+                        escape_xml(text)
+                    ));
+                }
+            }
+        }
+        sheet.push_str("</row>");
+    }
+    sheet.push_str("</sheetData></worksheet>");
+
+    let files: Vec<(&str, String)> = vec![
+        (
+            "[Content_Types].xml",
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="xml" ContentType="application/xml"/><Override PartName="/xl/workbook.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.sheet.main+xml"/><Override PartName="/xl/worksheets/sheet1.xml" ContentType="application/vnd.openxmlformats-officedocument.spreadsheetml.worksheet+xml"/></Types>"#
+                .to_string(),
+        ),
+        (
+            "_rels/.rels",
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/officeDocument" Target="xl/workbook.xml"/></Relationships>"#
+                .to_string(),
+        ),
+        (
+            "xl/workbook.xml",
+            format!(
+                r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<workbook xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships"><sheets><sheet name="{}" sheetId="1" r:id="rId1"/></sheets></workbook>"#,
+                escape_xml(sheet_name)
+            ),
+        ),
+        (
+            "xl/_rels/workbook.xml.rels",
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/worksheet" Target="worksheets/sheet1.xml"/></Relationships>"#
+                .to_string(),
+        ),
+        ("xl/worksheets/sheet1.xml", sheet),
+    ];
+
+    zip_stored(&files)
+}
+
+/// 欄位編號轉為 Excel 欄名（0 → A、26 → AA）
+fn column_name(mut index: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(b'A' + (index % 26) as u8);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    name.reverse();
+    String::from_utf8(name).unwrap_or_default()
+}
+
+/// XML 跳脫，並移除 XML 不允許的控制字元
+fn escape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\t' | '\n' | '\r' => out.push(c),
+            c if (c as u32) < 0x20 => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+/// 以 stored（不壓縮）方式寫出 zip
+fn zip_stored(files: &[(&str, String)]) -> Vec<u8> {
+    let mut out: Vec<u8> = Vec::new();
+    let mut central: Vec<u8> = Vec::new();
+
+    for (name, content) in files {
+        let data = content.as_bytes();
+        let crc = crc32(data);
+        let offset = out.len() as u32;
+
+        // 本地檔頭
+        out.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        out.extend_from_slice(&20u16.to_le_bytes()); // version needed
+        out.extend_from_slice(&0x0800u16.to_le_bytes()); // UTF-8 檔名
+        out.extend_from_slice(&0u16.to_le_bytes()); // stored
+        out.extend_from_slice(&0u16.to_le_bytes()); // time
+        out.extend_from_slice(&0x0021u16.to_le_bytes()); // date 1980-01-01
+        out.extend_from_slice(&crc.to_le_bytes());
+        out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        out.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        out.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        out.extend_from_slice(&0u16.to_le_bytes());
+        out.extend_from_slice(name.as_bytes());
+        out.extend_from_slice(data);
+
+        // 中央目錄
+        central.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        central.extend_from_slice(&20u16.to_le_bytes()); // version made by
+        central.extend_from_slice(&20u16.to_le_bytes());
+        central.extend_from_slice(&0x0800u16.to_le_bytes());
+        central.extend_from_slice(&0u16.to_le_bytes());
+        central.extend_from_slice(&0u16.to_le_bytes());
+        central.extend_from_slice(&0x0021u16.to_le_bytes());
+        central.extend_from_slice(&crc.to_le_bytes());
+        central.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        central.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        central.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        central.extend_from_slice(&0u16.to_le_bytes()); // extra
+        central.extend_from_slice(&0u16.to_le_bytes()); // comment
+        central.extend_from_slice(&0u16.to_le_bytes()); // disk
+        central.extend_from_slice(&0u16.to_le_bytes()); // internal attrs
+        central.extend_from_slice(&0u32.to_le_bytes()); // external attrs
+        central.extend_from_slice(&offset.to_le_bytes());
+        central.extend_from_slice(name.as_bytes());
+    }
+
+    let central_offset = out.len() as u32;
+    let central_size = central.len() as u32;
+    out.extend_from_slice(&central);
+
+    // 中央目錄結尾
+    out.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes());
+    out.extend_from_slice(&(files.len() as u16).to_le_bytes());
+    out.extend_from_slice(&(files.len() as u16).to_le_bytes());
+    out.extend_from_slice(&central_size.to_le_bytes());
+    out.extend_from_slice(&central_offset.to_le_bytes());
+    out.extend_from_slice(&0u16.to_le_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+/// 單一檔案解壓縮後的大小上限，避免壓縮炸彈耗盡記憶體
+const MAX_ENTRY_SIZE: u64 = 64 * 1024 * 1024;
+/// Excel 最大欄數（XFD）
+const MAX_COLUMNS: usize = 16384;
+
+/// 讀取第一個工作表為 (列號, 欄位)（每列補齊到最長欄位數）；列號取自 <row r>，略過空白列
+pub fn read_first_sheet(bytes: &[u8]) -> Result<Vec<(usize, Vec<String>)>, String> {
+    let mut archive =
+        zip::ZipArchive::new(Cursor::new(bytes)).map_err(|e| format!("無法開啟 XLSX 檔案: {}", e))?;
+
+    let mut read_entry = |name: &str| -> Result<Option<String>, String> {
+        let Ok(file) = archive.by_name(name) else {
+            return Ok(None);
+        };
+        let mut content = String::new();
+        file.take(MAX_ENTRY_SIZE + 1)
+            .read_to_string(&mut content)
+            .map_err(|e| format!("無法讀取 XLSX 檔案中的 {}: {}", name, e))?;
+        if content.len() as u64 > MAX_ENTRY_SIZE {
+            return Err(format!(
+                "XLSX 檔案中的 {} 解壓縮後超過 {} MB",
+                name,
+                MAX_ENTRY_SIZE / 1024 / 1024
+            ));
+        }
+        Ok(Some(content))
+    };
+
+    let shared_strings: Vec<String> = read_entry("xl/sharedStrings.xml")?
+        .map(|xml| {
+            elements(&xml, "si")
+                .into_iter()
+                .map(|(_, inner)| text_content(inner))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let workbook = read_entry("xl/workbook.xml")?;
+    let workbook_rels = read_entry("xl/_rels/workbook.xml.rels")?;
+    let sheet_path = first_sheet_path(workbook.as_deref(), workbook_rels.as_deref());
+    let sheet = read_entry(&sheet_path)?.ok_or("XLSX 檔案中找不到工作表")?;
+
+    let mut rows: Vec<(usize, Vec<String>)> = Vec::new();
+    let mut row_number = 0;
+    for (row_attrs, row_xml) in elements(&sheet, "row") {
+        row_number = attribute(row_attrs, "r")
+            .and_then(|r| r.trim().parse::<usize>().ok())
+            .unwrap_or(row_number + 1);
+        let mut row: Vec<String> = Vec::new();
+        for (attrs, cell_xml) in elements(row_xml, "c") {
+            let col = match attribute(attrs, "r") {
+                Some(r) => column_index(&r).ok_or_else(|| format!("無效的儲存格位置: {}", r))?,
+                None => row.len(),
+            };
+            if col >= MAX_COLUMNS {
+                return Err(format!("工作表超過 {} 欄", MAX_COLUMNS));
+            }
+            let value = match attribute(attrs, "t").as_deref() {
+                Some("s") => elements(cell_xml, "v")
+                    .first()
+                    .and_then(|(_, v)| v.trim().parse::<usize>().ok())
+                    .and_then(|i| shared_strings.get(i).cloned())
+                    .unwrap_or_default(),
+                Some("inlineStr") => text_content(cell_xml),
+                _ => elements(cell_xml, "v")
+                    .first()
+                    .map(|(_, v)| unescape_xml(v))
+                    .unwrap_or_default(),
+            };
+            if row.len() <= col {
+                row.resize(col + 1, String::new());
+            }
+            row[col] = value;
+        }
+        if row.iter().any(|v| !v.trim().is_empty()) {
+            rows.push((row_number, row));
+        }
+    }
+
+    let width = rows.iter().map(|(_, r)| r.len()).max().unwrap_or(0);
+    for (_, row) in rows.iter_mut() {
+        row.resize(width, String::new());
+    }
+    Ok(rows)
+}
+
+/// 找出 xml 中所有 <tag ...>inner</tag>（或自閉合 <tag .../>），返回 (屬性字串, 內容)
+fn elements<'a>(xml: &'a str, tag: &str) -> Vec<(&'a str, &'a str)> {
+    let open = format!("<{}", tag);
+    let close = format!("</{}>", tag);
+    let mut found = Vec::new();
+    let mut rest = xml;
+
+    while let Some(start) = rest.find(&open) {
+        let after = &rest[start + open.len()..];
+        // 避免 <c 誤配 <cols 之類的標籤
+        if !after.starts_with([' ', '>', '/']) {
+            rest = after;
+            continue;
+        }
+        let Some(tag_end) = after.find('>') else { break };
+        let attrs = &after[..tag_end];
+        if attrs.ends_with('/') {
+            found.push((attrs.trim_end_matches('/'), ""));
+            rest = &after[tag_end + 1..];
+            continue;
+        }
+        let body = &after[tag_end + 1..];
+        let Some(end) = body.find(&close) else { break };
+        found.push((attrs, &body[..end]));
+        rest = &body[end + close.len()..];
+    }
+    found
+}
+
+fn attribute(attrs: &str, name: &str) -> Option<String> {
+    let key = format!(" {}=\"", name);
+    let start = format!(" {}", attrs).find(&key)? + key.len() - 1;
+    let value = &attrs[start..];
+    value.find('"').map(|end| value[..end].to_string())
+}
+
+/// 串接所有 <t> 的文字（共用字串可能拆成多段 rich text）
+fn text_content(xml: &str) -> String {
+    elements(xml, "t")
+        .into_iter()
+        .map(|(_, text)| unescape_xml(text))
+        .collect()
+}
+
+/// 由 xl/workbook.xml 的第一個 <sheet> 與 workbook.xml.rels 找出工作表路徑，
+/// 找不到時沿用 xl/worksheets/sheet1.xml
+fn first_sheet_path(workbook: Option<&str>, rels: Option<&str>) -> String {
+    let target = workbook
+        .and_then(|xml| elements(xml, "sheet").into_iter().next())
+        .and_then(|(attrs, _)| attribute(attrs, "r:id"))
+        .and_then(|id| {
+            elements(rels?, "Relationship")
+                .into_iter()
+                .find(|(attrs, _)| attribute(attrs, "Id").as_deref() == Some(id.as_str()))
+                .and_then(|(attrs, _)| attribute(attrs, "Target"))
+        });
+    match target {
+        // 絕對路徑以套件根目錄為準，相對路徑以 xl/ 為準
+        Some(target) if target.starts_with('/') => target.trim_start_matches('/').to_string(),
+        Some(target) => format!("xl/{}", target),
+        None => "xl/worksheets/sheet1.xml".to_string(),
+    }
+}
+
+/// Excel 欄名轉為欄位編號（"B3" → 1），超過 XFD 或沒有欄名時為 None
+fn column_index(reference: &str) -> Option<usize> {
+    let number = reference
+        .chars()
+        .take_while(|c| c.is_ascii_alphabetic())
+        .try_fold(0usize, |acc, c| {
+            acc.checked_mul(26)?
+                .checked_add(c.to_ascii_uppercase() as usize - 'A' as usize + 1)
+        })?;
+    (1..=MAX_COLUMNS).contains(&number).then(|| number - 1)
+}
+
+fn unescape_xml(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start..];
+        let Some(end) = after.find(';') else {
+            out.push_str(after);
+            return out;
+        };
+        let entity = &after[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => out.push(c),
+            None => out.push_str(&after[..=end]),
+        }
+        rest = &after[end + 1..];
+    }
+    out.push_str(rest);
+    out
+}
@@ -0,0 +1,3 @@
+/*! For license information please see swagger-ui-es-bundle-core.js.LICENSE.txt */
+import*as e from"react-immutable-pure-component";import*as t from"remarkable/linkify";import*as a from"dompurify";import*as n from"zenscroll";import*as r from"lodash/reduce";import*as s from"@babel/runtime-corejs3/core-js-stable/string/raw";import*as o from"@babel/runtime-corejs3/core-js-stable/number/epsilon";import*as l from"@babel/runtime-corejs3/core-js-stable/instance/repeat";import*as i from"@babel/runtime-corejs3/core-js-stable/instance/fill";import*as c from"lodash/zipObject";import*as u from"@babel/runtime-corejs3/core-js-stable/promise";import*as d from"@babel/runtime-corejs3/core-js-stable/date/now";import*as p from"lodash/isString";import*as m from"lodash/debounce";import*as f from"lodash/set";import*as h from"lodash/fp/assocPath";import*as g from"swagger-client/es/resolver/strategies/generic";import*as y from"swagger-client/es/resolver/strategies/openapi-2";import*as v from"swagger-client/es/resolver/strategies/openapi-3-0";import*as E from"swagger-client/es/resolver/strategies/openapi-3-1-apidom";import*as S from"swagger-client/es/resolver";import*as w from"swagger-client/es/execute";import*as b from"swagger-client/es/http";import*as x from"swagger-client/es/subtree-resolver";import*as _ from"react-dom";import*as C from"react-redux";import*as j from"react-syntax-highlighter/dist/esm/light";import*as N from"react-syntax-highlighter/dist/esm/languages/hljs/javascript";import*as O from"react-syntax-highlighter/dist/esm/languages/hljs/json";import*as k from"react-syntax-highlighter/dist/esm/languages/hljs/xml";import*as A from"react-syntax-highlighter/dist/esm/languages/hljs/bash";import*as I from"react-syntax-highlighter/dist/esm/languages/hljs/yaml";import*as R from"react-syntax-highlighter/dist/esm/languages/hljs/http";import*as P from"react-syntax-highlighter/dist/esm/languages/hljs/powershell";import*as T from"react-syntax-highlighter/dist/esm/styles/hljs/agate";import*as M from"react-syntax-highlighter/dist/esm/styles/hljs/arta";import*as D from"react-syntax-highlighter/dist/esm/styles/hljs/monokai";import*as L from"react-syntax-highlighter/dist/esm/styles/hljs/nord";import*as q from"react-syntax-highlighter/dist/esm/styles/hljs/obsidian";import*as B from"react-syntax-highlighter/dist/esm/styles/hljs/tomorrow-night";import*as U from"@babel/runtime-corejs3/core-js-stable/instance/starts-with";import*as J from"@braintree/sanitize-url";import*as $ from"lodash/camelCase";import*as V from"lodash/upperFirst";import*as K from"lodash/find";import*as F from"lodash/eq";import*as z from"css.escape";import*as W from"@babel/runtime-corejs3/core-js-stable/instance/find-index";import*as H from"@babel/runtime-corejs3/core-js-stable/array/from";import*as G from"@babel/runtime-corejs3/core-js-stable/array/is-array";import*as Z from"@babel/runtime-corejs3/core-js-stable/instance/bind";import*as Y from"@babel/runtime-corejs3/core-js-stable/instance/concat";import*as X from"@babel/runtime-corejs3/core-js-stable/instance/entries";import*as Q from"@babel/runtime-corejs3/core-js-stable/instance/every";import*as ee from"@babel/runtime-corejs3/core-js-stable/instance/filter";import*as te from"@babel/runtime-corejs3/core-js-stable/instance/find";import*as ae from"@babel/runtime-corejs3/core-js-stable/instance/for-each";import*as ne from"@babel/runtime-corejs3/core-js-stable/instance/includes";import*as re from"@babel/runtime-corejs3/core-js-stable/instance/index-of";import*as se from"@babel/runtime-corejs3/core-js-stable/instance/keys";import*as oe from"@babel/runtime-corejs3/core-js-stable/instance/map";import*as le from"@babel/runtime-corejs3/core-js-stable/instance/reduce";import*as ie from"@babel/runtime-corejs3/core-js-stable/instance/slice";import*as ce from"@babel/runtime-corejs3/core-js-stable/instance/some";import*as ue from"@babel/runtime-corejs3/core-js-stable/instance/sort";import*as de from"@babel/runtime-corejs3/core-js-stable/instance/trim";import*as pe from"@babel/runtime-corejs3/core-js-stable/json/stringify";import*as me from"@babel/runtime-corejs3/core-js-stable/map";import*as fe from"@babel/runtime-corejs3/core-js-stable/number/is-integer";import*as he from"@babel/runtime-corejs3/core-js-stable/object/assign";import*as ge from"@babel/runtime-corejs3/core-js-stable/object/entries";import*as ye from"@babel/runtime-corejs3/core-js-stable/object/from-entries";import*as ve from"@babel/runtime-corejs3/core-js-stable/object/keys";import*as Ee from"@babel/runtime-corejs3/core-js-stable/object/values";import*as Se from"@babel/runtime-corejs3/core-js-stable/set";import*as we from"@babel/runtime-corejs3/core-js-stable/set-timeout";import*as be from"@babel/runtime-corejs3/core-js-stable/url";import*as xe from"@babel/runtime-corejs3/core-js-stable/weak-map";import*as _e from"@babel/runtime-corejs3/core-js-stable/weak-set";import*as Ce from"@babel/runtime-corejs3/helpers/classPrivateFieldGet";import*as je from"@babel/runtime-corejs3/helpers/defineProperty";import*as Ne from"@babel/runtime-corejs3/helpers/extends";import*as Oe from"base64-js";import*as ke from"classnames";import*as Ae from"ieee754";import*as Ie from"immutable";import*as Re from"js-yaml";import*as Pe from"lodash/get";import*as Te from"lodash/identity";import*as Me from"lodash/isEmpty";import*as De from"lodash/isFunction";import*as Le from"lodash/isPlainObject";import*as qe from"lodash/memoize";import*as Be from"lodash/omit";import*as Ue from"lodash/some";import*as Je from"prop-types";import*as $e from"randexp";import*as Ve from"react";import*as Ke from"react-copy-to-clipboard";import*as Fe from"react-immutable-proptypes";import*as ze from"redux";import*as We from"remarkable";import*as He from"reselect";import*as Ge from"serialize-error";import*as Ze from"swagger-client/es/helpers";import*as Ye from"url-parse";import*as Xe from"@babel/runtime-corejs3/core-js-stable/instance/last-index-of";import*as Qe from"redux-immutable";import*as et from"lodash/merge";import*as tt from"lodash/toString";import*as at from"@babel/runtime-corejs3/core-js-stable/instance/splice";import*as nt from"js-file-download";import*as rt from"@babel/runtime-corejs3/core-js-stable/instance/values";import*as st from"xml-but-prettier";import*as ot from"lodash/toLower";import*as lt from"react-debounce-input";var it={1543:(t,a,n)=>{n.d(a,{Z:()=>m});var r=n(863),s=n(775),o=n(8818),l=n(2565),i=n(810);const c=(e=>{var t={};return n.d(t,e),t})({default:()=>e.default});var u=n(9569),d=n(5053);const p=e=>{const t=e.replace(/~1/g,"/").replace(/~0/g,"~");try{return decodeURIComponent(t)}catch{return t}};class m extends c.default{constructor(){super(...arguments),(0,s.default)(this,"getModelName",(e=>-1!==(0,o.default)(e).call(e,"#/definitions/")?p(e.replace(/^.*#\/definitions\//,"")):-1!==(0,o.default)(e).call(e,"#/components/schemas/")?p(e.replace(/^.*#\/components\/schemas\//,"")):void 0)),(0,s.default)(this,"getRefSchema",(e=>{let{specSelectors:t}=this.props;return t.findDefinition(e)}))}render(){let{getComponent:e,getConfigs:t,specSelectors:a,schema:s,required:o,name:l,isRef:c,specPath:u,displayName:d,includeReadOnly:p,includeWriteOnly:m}=this.props;const f=e("ObjectModel"),h=e("ArrayModel"),g=e("PrimitiveModel");let y="object",v=s&&s.get("$$ref");if(!l&&v&&(l=this.getModelName(v)),!s&&v&&(s=this.getRefSchema(l)),!s)return i.default.createElement("span",{className:"model model-title"},i.default.createElement("span",{className:"model-title__text"},d||l),i.default.createElement("img",{src:n(2517),height:"20px",width:"20px"}));const E=a.isOAS3()&&s.get("deprecated");switch(c=void 0!==c?c:!!v,y=s&&s.get("type")||y,y){case"object":return i.default.createElement(f,(0,r.default)({className:"object"},this.props,{specPath:u,getConfigs:t,schema:s,name:l,deprecated:E,isRef:c,includeReadOnly:p,includeWriteOnly:m}));case"array":return i.default.createElement(h,(0,r.default)({className:"array"},this.props,{getConfigs:t,schema:s,name:l,deprecated:E,required:o,includeReadOnly:p,includeWriteOnly:m}));default:return i.default.createElement(g,(0,r.default)({},this.props,{getComponent:e,getConfigs:t,schema:s,name:l,deprecated:E,required:o}))}}}(0,s.default)(m,"propTypes",{schema:(0,l.default)(u.default).isRequired,getComponent:d.default.func.isRequired,getConfigs:d.default.func.isRequired,specSelectors:d.default.object.isRequired,name:d.default.string,displayName:d.default.string,isRef:d.default.bool,required:d.default.bool,expandDepth:d.default.number,depth:d.default.number,specPath:u.default.list.isRequired,includeReadOnly:d.default.bool,includeWriteOnly:d.default.bool})},5623:(e,t,a)=>{a.d(t,{Z:()=>c});var n=a(775),r=a(2740),s=a(810),o=a(8900),l=(a(5053),a(6561)),i=a(7504);class c extends s.default.Component{constructor(e,t){super(e,t),(0,n.default)(this,"getDefinitionUrl",(()=>{let{specSelectors:e}=this.props;return new o.default(e.url(),i.Z.location).toString()}));let{getConfigs:a}=e,{validatorUrl:r}=a();this.state={url:this.getDefinitionUrl(),validatorUrl:void 0===r?"https://validator.swagger.io/validator":r}}UNSAFE_componentWillReceiveProps(e){let{getConfigs:t}=e,{validatorUrl:a}=t();this.setState({url:this.getDefinitionUrl(),validatorUrl:void 0===a?"https://validator.swagger.io/validator":a})}render(){let{getConfigs:e}=this.props,{spec:t}=e(),a=(0,l.Nm)(this.state.validatorUrl);return"object"==typeof t&&(0,r.default)(t).length?null:this.state.url&&(0,l.hW)(this.state.validatorUrl)&&(0,l.hW)(this.state.url)?s.default.createElement("span",{className:"float-right"},s.default.createElement("a",{target:"_blank",rel:"noopener noreferrer",href:`${a}/debug?url=${encodeURIComponent(this.state.url)}`},s.default.createElement(u,{src:`${a}?url=${encodeURIComponent(this.state.url)}`,alt:"Online validator badge"}))):null}}class u extends s.default.Component{constructor(e){super(e),this.state={loaded:!1,error:!1}}componentDidMount(){const e=new Image;e.onload=()=>{this.setState({loaded:!0})},e.onerror=()=>{this.setState({error:!0})},e.src=this.props.src}UNSAFE_componentWillReceiveProps(e){if(e.src!==this.props.src){const t=new Image;t.onload=()=>{this.setState({loaded:!0})},t.onerror=()=>{this.setState({error:!0})},t.src=e.src}}render(){return this.state.error?s.default.createElement("img",{alt:"Error"}):this.state.loaded?s.default.createElement("img",{src:this.props.src,alt:this.props.alt}):null}}},5466:(e,n,r)=>{r.d(n,{Z:()=>d,s:()=>p});var s=r(810),o=(r(5053),r(3952));const l=(e=>{var t={};return r.d(t,e),t})({linkify:()=>t.linkify});const i=(e=>{var t={};return r.d(t,e),t})({default:()=>a.default});var c=r(8096);function u(e){let{source:t,className:a="",getConfigs:n}=e;if("string"!=typeof t)return null;const r=new o.Remarkable({html:!0,typographer:!0,breaks:!0,linkTarget:"_blank"}).use(l.linkify);r.core.ruler.disable(["replacements","smartquotes"]);const{useUnsafeMarkdown:i}=n(),u=r.render(t),d=p(u,{useUnsafeMarkdown:i});return t&&u&&d?s.default.createElement("div",{className:(0,c.default)(a,"markdown"),dangerouslySetInnerHTML:{__html:d}}):null}i.default.addHook&&i.default.addHook("beforeSanitizeElements",(function(e){return e.href&&e.setAttribute("rel","noopener noreferrer"),e})),u.defaultProps={getConfigs:()=>({useUnsafeMarkdown:!1})};const d=u;function p(e){let{useUnsafeMarkdown:t=!1}=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};const a=t,n=t?[]:["style","class"];return t&&!p.hasWarnedAboutDeprecation&&(console.warn("useUnsafeMarkdown display configuration parameter is deprecated since >3.26.0 and will be removed in v4.0.0."),p.hasWarnedAboutDeprecation=!0),i.default.sanitize(e,{ADD_ATTR:["target"],FORBID_TAGS:["style","form"],ALLOW_DATA_ATTR:a,FORBID_ATTR:n})}p.hasWarnedAboutDeprecation=!1},5308:(e,t,a)=>{a.r(t),a.d(t,{default:()=>u});var n,r=a(29),s=a(5487),o=a(6561),l=a(8102);const i=a(5102),c={},u=c;(0,r.default)(n=(0,s.default)(i).call(i)).call(n,(function(e){if("./index.js"===e)return;let t=i(e);c[(0,o.Zl)(e)]=t.default?t.default:t})),c.SafeRender=l.default},5812:(e,t,a)=>{a.r(t),a.d(t,{AUTHORIZE:()=>c,AUTHORIZE_OAUTH2:()=>p,CONFIGURE_AUTH:()=>f,LOGOUT:()=>u,PRE_AUTHORIZE_OAUTH2:()=>d,RESTORE_AUTHORIZATION:()=>h,SHOW_AUTH_POPUP:()=>i,VALIDATE:()=>m,authPopup:()=>R,authorize:()=>y,authorizeAccessCodeWithBasicAuthentication:()=>N,authorizeAccessCodeWithFormParams:()=>j,authorizeApplication:()=>C,authorizeOauth2:()=>b,authorizeOauth2WithPersistOption:()=>x,authorizePassword:()=>_,authorizeRequest:()=>O,authorizeWithPersistOption:()=>v,configureAuth:()=>k,logout:()=>E,logoutWithPersistOption:()=>S,persistAuthorizationIfNeeded:()=>I,preAuthorizeImplicit:()=>w,restoreAuthorization:()=>A,showDefinitions:()=>g});var n=a(313),r=a(7512),s=a(8900),o=a(7504),l=a(6561);const i="show_popup",c="authorize",u="logout",d="pre_authorize_oauth2",p="authorize_oauth2",m="validate",f="configure_auth",h="restore_authorization";function g(e){return{type:i,payload:e}}function y(e){return{type:c,payload:e}}const v=e=>t=>{let{authActions:a}=t;a.authorize(e),a.persistAuthorizationIfNeeded()};function E(e){return{type:u,payload:e}}const S=e=>t=>{let{authActions:a}=t;a.logout(e),a.persistAuthorizationIfNeeded()},w=e=>t=>{let{authActions:a,errActions:r}=t,{auth:s,token:l,isValid:i}=e,{schema:c,name:u}=s,d=c.get("flow");delete o.Z.swaggerUIRedirectOauth2,"accessCode"===d||i||r.newAuthErr({authId:u,source:"auth",level:"warning",message:"Authorization may be unsafe, passed state was changed in server Passed state wasn't returned from auth server"}),l.error?r.newAuthErr({authId:u,source:"auth",level:"error",message:(0,n.default)(l)}):a.authorizeOauth2WithPersistOption({auth:s,token:l})};function b(e){return{type:p,payload:e}}const x=e=>t=>{let{authActions:a}=t;a.authorizeOauth2(e),a.persistAuthorizationIfNeeded()},_=e=>t=>{let{authActions:a}=t,{schema:n,name:s,username:o,password:i,passwordType:c,clientId:u,clientSecret:d}=e,p={grant_type:"password",scope:e.scopes.join(" "),username:o,password:i},m={};switch(c){case"request-body":!function(e,t,a){t&&(0,r.default)(e,{client_id:t});a&&(0,r.default)(e,{client_secret:a})}(p,u,d);break;case"basic":m.Authorization="Basic "+(0,l.r3)(u+":"+d);break;default:console.warn(`Warning: invalid passwordType ${c} was passed, not including client id and secret`)}return a.authorizeRequest({body:(0,l.GZ)(p),url:n.get("tokenUrl"),name:s,headers:m,query:{},auth:e})};const C=e=>t=>{let{authActions:a}=t,{schema:n,scopes:r,name:s,clientId:o,clientSecret:i}=e,c={Authorization:"Basic "+(0,l.r3)(o+":"+i)},u={grant_type:"client_credentials",scope:r.join(" ")};return a.authorizeRequest({body:(0,l.GZ)(u),name:s,url:n.get("tokenUrl"),auth:e,headers:c})},j=e=>{let{auth:t,redirectUrl:a}=e;return e=>{let{authActions:n}=e,{schema:r,name:s,clientId:o,clientSecret:i,codeVerifier:c}=t,u={grant_type:"authorization_code",code:t.code,client_id:o,client_secret:i,redirect_uri:a,code_verifier:c};return n.authorizeRequest({body:(0,l.GZ)(u),name:s,url:r.get("tokenUrl"),auth:t})}},N=e=>{let{auth:t,redirectUrl:a}=e;return e=>{let{authActions:n}=e,{schema:r,name:s,clientId:o,clientSecret:i,codeVerifier:c}=t,u={Authorization:"Basic "+(0,l.r3)(o+":"+i)},d={grant_type:"authorization_code",code:t.code,client_id:o,redirect_uri:a,code_verifier:c};return n.authorizeRequest({body:(0,l.GZ)(d),name:s,url:r.get("tokenUrl"),auth:t,headers:u})}},O=e=>t=>{let a,{fn:o,getConfigs:l,authActions:i,errActions:c,oas3Selectors:u,specSelectors:d,authSelectors:p}=t,{body:m,query:f={},headers:h={},name:g,url:y,auth:v}=e,{additionalQueryStringParams:E}=p.getConfigs()||{};if(d.isOAS3()){let e=u.serverEffectiveValue(u.selectedServer());a=(0,s.default)(y,e,!0)}else a=(0,s.default)(y,d.url(),!0);"object"==typeof E&&(a.query=(0,r.default)({},a.query,E));const S=a.toString();let w=(0,r.default)({Accept:"application/json, text/plain, */*","Content-Type":"application/x-www-form-urlencoded","X-Requested-With":"XMLHttpRequest"},h);o.fetch({url:S,method:"post",headers:w,query:f,body:m,requestInterceptor:l().requestInterceptor,responseInterceptor:l().responseInterceptor}).then((function(e){let t=JSON.parse(e.data),a=t&&(t.error||""),r=t&&(t.parseError||"");e.ok?a||r?c.newAuthErr({authId:g,level:"error",source:"auth",message:(0,n.default)(t)}):i.authorizeOauth2WithPersistOption({auth:v,token:t}):c.newAuthErr({authId:g,level:"error",source:"auth",message:e.statusText})})).catch((e=>{let t=new Error(e).message;if(e.response&&e.response.data){const a=e.response.data;try{const e="string"==typeof a?JSON.parse(a):a;e.error&&(t+=`, error: ${e.error}`),e.error_description&&(t+=`, description: ${e.error_description}`)}catch(e){}}c.newAuthErr({authId:g,level:"error",source:"auth",message:t})}))};function k(e){return{type:f,payload:e}}function A(e){return{type:h,payload:e}}const I=()=>e=>{let{authSelectors:t,getConfigs:a}=e;if(!a().persistAuthorization)return;const r=t.authorized().toJS();localStorage.setItem("authorized",(0,n.default)(r))},R=(e,t)=>()=>{o.Z.swaggerUIRedirectOauth2=t,o.Z.open(e)}},7105:(e,t,a)=>{a.r(t),a.d(t,{default:()=>l});var n=a(2740),r=a(810),s=(a(5053),a(1116));class o extends r.default.Component{mapStateToProps(e,t){return{state:e,ownProps:(0,s.default)(t,(0,n.default)(t.getSystem()))}}render(){const{getComponent:e,ownProps:t}=this.props,a=e("LockIcon");return r.default.createElement(a,t)}}const l=o},3219:(e,t,a)=>{a.r(t),a.d(t,{default:()=>l});var n=a(2740),r=a(810),s=(a(5053),a(1116));class o extends r.default.Component{mapStateToProps(e,t){return{state:e,ownProps:(0,s.default)(t,(0,n.default)(t.getSystem()))}}render(){const{getComponent:e,ownProps:t}=this.props,a=e("UnlockIcon");return r.default.createElement(a,t)}}const l=o},3779:(e,t,a)=>{a.r(t),a.d(t,{loaded:()=>n});const n=(e,t)=>a=>{const{getConfigs:n,authActions:r}=t,s=n();if(e(a),s.persistAuthorization){const e=localStorage.getItem("authorized");e&&r.restoreAuthorization({authorized:JSON.parse(e)})}}},3705:(e,t,a)=>{a.r(t),a.d(t,{default:()=>p,preauthorizeApiKey:()=>f,preauthorizeBasic:()=>m});var n=a(5527),r=a(3962),s=a(5812),o=a(35),l=a(489),i=a(3779),c=a(2849),u=a(7105),d=a(3219);function p(){return{afterLoad(e){this.rootInjects=this.rootInjects||{},this.rootInjects.initOAuth=e.authActions.configureAuth,this.rootInjects.preauthorizeApiKey=(0,n.default)(f).call(f,null,e),this.rootInjects.preauthorizeBasic=(0,n.default)(m).call(m,null,e)},components:{LockAuthIcon:u.default,UnlockAuthIcon:d.default,LockAuthOperationIcon:u.default,UnlockAuthOperationIcon:d.default},statePlugins:{auth:{reducers:r.default,actions:s,selectors:o,wrapActions:{authorize:c.authorize,logout:c.logout}},configs:{wrapActions:{loaded:i.loaded}},spec:{wrapActions:{execute:l.execute}}}}}function m(e,t,a,n){const{authActions:{authorize:r},specSelectors:{specJson:s,isOAS3:o}}=e,l=o()?["components","securitySchemes"]:["securityDefinitions"],i=s().getIn([...l,t]);return i?r({[t]:{value:{username:a,password:n},schema:i.toJS()}}):null}function f(e,t,a){const{authActions:{authorize:n},specSelectors:{specJson:r,isOAS3:s}}=e,o=s()?["components","securitySchemes"]:["securityDefinitions"],l=r().getIn([...o,t]);return l?n({[t]:{value:a,schema:l.toJS()}}):null}},3962:(e,t,a)=>{a.r(t),a.d(t,{default:()=>i});var n=a(29),r=a(7512),s=a(9725),o=a(6561),l=a(5812);const i={[l.SHOW_AUTH_POPUP]:(e,t)=>{let{payload:a}=t;return e.set("showDefinitions",a)},[l.AUTHORIZE]:(e,t)=>{var a;let{payload:r}=t,l=(0,s.fromJS)(r),i=e.get("authorized")||(0,s.Map)();return(0,n.default)(a=l.entrySeq()).call(a,(t=>{let[a,n]=t;if(!(0,o.Wl)(n.getIn))return e.set("authorized",i);let r=n.getIn(["schema","type"]);if("apiKey"===r||"http"===r)i=i.set(a,n);else if("basic"===r){let e=n.getIn(["value","username"]),t=n.getIn(["value","password"]);i=i.setIn([a,"value"],{username:e,header:"Basic "+(0,o.r3)(e+":"+t)}),i=i.setIn([a,"schema"],n.get("schema"))}})),e.set("authorized",i)},[l.AUTHORIZE_OAUTH2]:(e,t)=>{let a,{payload:n}=t,{auth:o,token:l}=n;o.token=(0,r.default)({},l),a=(0,s.fromJS)(o);let i=e.get("authorized")||(0,s.Map)();return i=i.set(a.get("name"),a),e.set("authorized",i)},[l.LOGOUT]:(e,t)=>{let{payload:a}=t,r=e.get("authorized").withMutations((e=>{(0,n.default)(a).call(a,(t=>{e.delete(t)}))}));return e.set("authorized",r)},[l.CONFIGURE_AUTH]:(e,t)=>{let{payload:a}=t;return e.set("configs",a)},[l.RESTORE_AUTHORIZATION]:(e,t)=>{let{payload:a}=t;return e.set("authorized",(0,s.fromJS)(a.authorized))}}},35:(e,t,a)=>{a.r(t),a.d(t,{authorized:()=>g,definitionsForRequirements:()=>h,definitionsToAuthorize:()=>m,getConfigs:()=>v,getDefinitionsByNames:()=>f,isAuthorized:()=>y,shownDefinitions:()=>p});var n=a(29),r=a(1778),s=a(6145),o=a(8818),l=a(2565),i=a(2740),c=a(8639),u=a(9725);const d=e=>e,p=(0,c.createSelector)(d,(e=>e.get("showDefinitions"))),m=(0,c.createSelector)(d,(()=>e=>{var t;let{specSelectors:a}=e,r=a.securityDefinitions()||(0,u.Map)({}),s=(0,u.List)();return(0,n.default)(t=r.entrySeq()).call(t,(e=>{let[t,a]=e,n=(0,u.Map)();n=n.set(t,a),s=s.push(n)})),s})),f=(e,t)=>e=>{var a;let{specSelectors:r}=e;console.warn("WARNING: getDefinitionsByNames is deprecated and will be removed in the next major version.");let s=r.securityDefinitions(),o=(0,u.List)();return(0,n.default)(a=t.valueSeq()).call(a,(e=>{var t;let a=(0,u.Map)();(0,n.default)(t=e.entrySeq()).call(t,(e=>{let t,[r,o]=e,l=s.get(r);var i;"oauth2"===l.get("type")&&o.size&&(t=l.get("scopes"),(0,n.default)(i=t.keySeq()).call(i,(e=>{o.contains(e)||(t=t.delete(e))})),l=l.set("allowedScopes",t));a=a.set(r,l)})),o=o.push(a)})),o},h=function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:(0,u.List)();return e=>{let{authSelectors:a}=e;const s=a.definitionsToAuthorize()||(0,u.List)();let o=(0,u.List)();return(0,n.default)(s).call(s,(e=>{let a=(0,r.default)(t).call(t,(t=>t.get(e.keySeq().first())));a&&((0,n.default)(e).call(e,((t,r)=>{if("oauth2"===t.get("type")){const o=a.get(r);let l=t.get("scopes");var s;if(u.List.isList(o)&&u.Map.isMap(l))(0,n.default)(s=l.keySeq()).call(s,(e=>{o.contains(e)||(l=l.delete(e))})),e=e.set(r,t.set("scopes",l))}})),o=o.push(e))})),o}},g=(0,c.createSelector)(d,(e=>e.get("authorized")||(0,u.Map)())),y=(e,t)=>e=>{var a;let{authSelectors:n}=e,r=n.authorized();return u.List.isList(t)?!!(0,s.default)(a=t.toJS()).call(a,(e=>{var t,a;return-1===(0,o.default)(t=(0,l.default)(a=(0,i.default)(e)).call(a,(e=>!!r.get(e)))).call(t,!1)})).length:null},v=(0,c.createSelector)(d,(e=>e.get("configs")))},489:(e,t,a)=>{a.r(t),a.d(t,{execute:()=>n});const n=(e,t)=>{let{authSelectors:a,specSelectors:n}=t;return t=>{let{path:r,method:s,operation:o,extras:l}=t,i={authorized:a.authorized()&&a.authorized().toJS(),definitions:n.securityDefinitions()&&n.securityDefinitions().toJS(),specSecurity:n.security()&&n.security().toJS()};return e({path:r,method:s,operation:o,securities:i,...l})}}},2849:(e,t,a)=>{a.r(t),a.d(t,{authorize:()=>o,logout:()=>l});var n=a(374),r=a(4163),s=a(29);const o=(e,t)=>a=>{e(a);if(t.getConfigs().persistAuthorization)try{const[{schema:e,value:t}]=(0,n.default)(a),r="apiKey"===e.get("type"),s="cookie"===e.get("in");r&&s&&(document.cookie=`${e.get("name")}=${t}; SameSite=None; Secure`)}catch(e){console.error("Error persisting cookie based apiKey in document.cookie.",e)}},l=(e,t)=>a=>{const n=t.getConfigs(),o=t.authSelectors.authorized();try{n.persistAuthorization&&(0,r.default)(a)&&(0,s.default)(a).call(a,(e=>{const t=o.get(e,{}),a="apiKey"===t.getIn(["schema","type"]),n="cookie"===t.getIn(["schema","in"]);if(a&&n){const e=t.getIn(["schema","name"]);document.cookie=`${e}=; Max-Age=-99999999`}}))}catch(e){console.error("Error deleting cookie based apiKey from document.cookie.",e)}e(a)}},714:(e,t,a)=>{a.r(t),a.d(t,{TOGGLE_CONFIGS:()=>r,UPDATE_CONFIGS:()=>n,loaded:()=>l,toggle:()=>o,update:()=>s});const n="configs_update",r="configs_toggle";function s(e,t){return{type:n,payload:{[e]:t}}}function o(e){return{type:r,payload:e}}const l=()=>()=>{}},2256:(e,t,a)=>{a.r(t),a.d(t,{parseYamlConfig:()=>r});var n=a(3772);const r=(e,t)=>{try{return n.default.load(e)}catch(e){return t&&t.errActions.newThrownErr(new Error(e)),{}}}},6709:(e,t,a)=>{a.r(t),a.d(t,{default:()=>c});var n=a(2256),r=a(714),s=a(2698),o=a(9018),l=a(7743);const i={getLocalConfig:()=>(0,n.parseYamlConfig)('---\nurl: "https://petstore.swagger.io/v2/swagger.json"\ndom_id: "#swagger-ui"\nvalidatorUrl: "https://validator.swagger.io/validator"\n')};function c(){return{statePlugins:{spec:{actions:s,selectors:i},configs:{reducers:l.default,actions:r,selectors:o}}}}},7743:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(9725),r=a(714);const s={[r.UPDATE_CONFIGS]:(e,t)=>e.merge((0,n.fromJS)(t.payload)),[r.TOGGLE_CONFIGS]:(e,t)=>{const a=t.payload,n=e.get(a);return e.set(a,!n)}}},9018:(e,t,a)=>{a.r(t),a.d(t,{get:()=>r});var n=a(4163);const r=(e,t)=>e.getIn((0,n.default)(t)?t:[t])},2698:(e,t,a)=>{a.r(t),a.d(t,{downloadConfig:()=>r,getConfigByUrl:()=>s});var n=a(2256);const r=e=>t=>{const{fn:{fetch:a}}=t;return a(e)},s=(e,t)=>a=>{let{specActions:r}=a;if(e)return r.downloadConfig(e).then(s,s);function s(a){a instanceof Error||a.status>=400?(r.updateLoadingStatus("failedConfig"),r.updateLoadingStatus("failedConfig"),r.updateUrl(""),console.error(a.statusText+" "+e.url),t(null)):t((0,n.parseYamlConfig)(a.text))}}},1970:(e,t,a)=>{a.r(t),a.d(t,{setHash:()=>n});const n=e=>e?history.pushState(null,null,`#${e}`):window.location.hash=""},4980:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(5858),r=a(877),s=a(4584);function o(){return[n.default,{statePlugins:{configs:{wrapActions:{loaded:(e,t)=>function(){e(...arguments);const a=decodeURIComponent(window.location.hash);t.layoutActions.parseDeepLinkHash(a)}}}},wrapComponents:{operation:r.default,OperationTag:s.default}}]}},5858:(e,t,a)=>{a.r(t),a.d(t,{clearScrollTo:()=>E,default:()=>S,parseDeepLinkHash:()=>g,readyToScroll:()=>y,scrollTo:()=>h,scrollToElement:()=>v,show:()=>f});var r=a(4163),s=a(8136),o=a(2565),l=a(8818),i=a(1970);const c=(e=>{var t={};return a.d(t,e),t})({default:()=>n.default});var u=a(6561),d=a(9725);const p="layout_scroll_to",m="layout_clear_scroll",f=(e,t)=>{let{getConfigs:a,layoutSelectors:n}=t;return function(){for(var t=arguments.length,s=new Array(t),o=0;o<t;o++)s[o]=arguments[o];if(e(...s),a().deepLinking)try{let[e,t]=s;e=(0,r.default)(e)?e:[e];const a=n.urlHashArrayFromIsShownKey(e);if(!a.length)return;const[o,l]=a;if(!t)return(0,i.setHash)("/");2===a.length?(0,i.setHash)((0,u.oJ)(`/${encodeURIComponent(o)}/${encodeURIComponent(l)}`)):1===a.length&&(0,i.setHash)((0,u.oJ)(`/${encodeURIComponent(o)}`))}catch(e){console.error(e)}}},h=e=>({type:p,payload:(0,r.default)(e)?e:[e]}),g=e=>t=>{let{layoutActions:a,layoutSelectors:n,getConfigs:r}=t;if(r().deepLinking&&e){var i;let t=(0,s.default)(e).call(e,1);"!"===t[0]&&(t=(0,s.default)(t).call(t,1)),"/"===t[0]&&(t=(0,s.default)(t).call(t,1));const r=(0,o.default)(i=t.split("/")).call(i,(e=>e||"")),c=n.isShownKeyFromUrlHashArray(r),[u,d="",p=""]=c;if("operations"===u){const e=n.isShownKeyFromUrlHashArray([d]);(0,l.default)(d).call(d,"_")>-1&&(console.warn("Warning: escaping deep link whitespace with `_` will be unsupported in v4.0, use `%20` instead."),a.show((0,o.default)(e).call(e,(e=>e.replace(/_/g," "))),!0)),a.show(e,!0)}((0,l.default)(d).call(d,"_")>-1||(0,l.default)(p).call(p,"_")>-1)&&(console.warn("Warning: escaping deep link whitespace with `_` will be unsupported in v4.0, use `%20` instead."),a.show((0,o.default)(c).call(c,(e=>e.replace(/_/g," "))),!0)),a.show(c,!0),a.scrollTo(c)}},y=(e,t)=>a=>{const n=a.layoutSelectors.getScrollToKey();d.default.is(n,(0,d.fromJS)(e))&&(a.layoutActions.scrollToElement(t),a.layoutActions.clearScrollTo())},v=(e,t)=>a=>{try{t=t||a.fn.getScrollParent(e),c.default.createScroller(t).to(e)}catch(e){console.error(e)}},E=()=>({type:m});const S={fn:{getScrollParent:function(e,t){const a=document.documentElement;let n=getComputedStyle(e);const r="absolute"===n.position,s=t?/(auto|scroll|hidden)/:/(auto|scroll)/;if("fixed"===n.position)return a;for(let t=e;t=t.parentElement;)if(n=getComputedStyle(t),(!r||"static"!==n.position)&&s.test(n.overflow+n.overflowY+n.overflowX))return t;return a}},statePlugins:{layout:{actions:{scrollToElement:v,scrollTo:h,clearScrollTo:E,readyToScroll:y,parseDeepLinkHash:g},selectors:{getScrollToKey:e=>e.get("scrollToKey"),isShownKeyFromUrlHashArray(e,t){const[a,n]=t;return n?["operations",a,n]:a?["operations-tag",a]:[]},urlHashArrayFromIsShownKey(e,t){let[a,n,r]=t;return"operations"==a?[n,r]:"operations-tag"==a?[n]:[]}},reducers:{[p]:(e,t)=>e.set("scrollToKey",d.default.fromJS(t.payload)),[m]:e=>e.delete("scrollToKey")},wrapActions:{show:f}}}}},4584:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(775),r=a(810);a(5053);const s=(e,t)=>class extends r.default.Component{constructor(){super(...arguments),(0,n.default)(this,"onLoad",(e=>{const{tag:a}=this.props,n=["operations-tag",a];t.layoutActions.readyToScroll(n,e)}))}render(){return r.default.createElement("span",{ref:this.onLoad},r.default.createElement(e,this.props))}}},877:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(775),r=a(810);a(9569);const s=(e,t)=>class extends r.default.Component{constructor(){super(...arguments),(0,n.default)(this,"onLoad",(e=>{const{operation:a}=this.props,{tag:n,operationId:r}=a.toObject();let{isShownKey:s}=a.toObject();s=s||["operations",n,r],t.layoutActions.readyToScroll(s,e)}))}render(){return r.default.createElement("span",{ref:this.onLoad},r.default.createElement(e,this.props))}}},8011:(e,t,a)=>{a.r(t),a.d(t,{default:()=>u});var n=a(7512),r=a(3769),s=a(8818),o=a(313),l=a(8639),i=a(9725),c=a(7504);function u(e){let{fn:t}=e;return{statePlugins:{spec:{actions:{download:e=>a=>{let{errActions:s,specSelectors:o,specActions:l,getConfigs:i}=a,{fetch:u}=t;const d=i();function p(t){if(t instanceof Error||t.status>=400)return l.updateLoadingStatus("failed"),s.newThrownErr((0,n.default)(new Error((t.message||t.statusText)+" "+e),{source:"fetch"})),void(!t.status&&t instanceof Error&&function(){try{let t;if("URL"in c.Z?t=new r.default(e):(t=document.createElement("a"),t.href=e),"https:"!==t.protocol&&"https:"===c.Z.location.protocol){const e=(0,n.default)(new Error(`Possible mixed-content issue? The page was loaded over https:// but a ${t.protocol}// URL was specified. Check that you are not attempting to load mixed content.`),{source:"fetch"});return void s.newThrownErr(e)}if(t.origin!==c.Z.location.origin){const e=(0,n.default)(new Error(`Possible cross-origin (CORS) issue? The URL origin (${t.origin}) does not match the page (${c.Z.location.origin}). Check the server returns the correct 'Access-Control-Allow-*' headers.`),{source:"fetch"});s.newThrownErr(e)}}catch(e){return}}());l.updateLoadingStatus("success"),l.updateSpec(t.text),o.url()!==e&&l.updateUrl(e)}e=e||o.url(),l.updateLoadingStatus("loading"),s.clear({source:"fetch"}),u({url:e,loadSpec:!0,requestInterceptor:d.requestInterceptor||(e=>e),responseInterceptor:d.responseInterceptor||(e=>e),credentials:"same-origin",headers:{Accept:"application/json,*/*"}}).then(p,p)},updateLoadingStatus:e=>{let t=[null,"loading","failed","success","failedConfig"];return-1===(0,s.default)(t).call(t,e)&&console.error(`Error: ${e} is not one of ${(0,o.default)(t)}`),{type:"spec_update_loading_status",payload:e}}},reducers:{spec_update_loading_status:(e,t)=>"string"==typeof t.payload?e.set("loadingStatus",t.payload):e},selectors:{loadingStatus:(0,l.createSelector)((e=>e||(0,i.Map)()),(e=>e.get("loadingStatus")||null))}}}}}},4966:(e,t,a)=>{a.r(t),a.d(t,{CLEAR:()=>c,CLEAR_BY:()=>u,NEW_AUTH_ERR:()=>i,NEW_SPEC_ERR:()=>o,NEW_SPEC_ERR_BATCH:()=>l,NEW_THROWN_ERR:()=>r,NEW_THROWN_ERR_BATCH:()=>s,clear:()=>g,clearBy:()=>y,newAuthErr:()=>h,newSpecErr:()=>m,newSpecErrBatch:()=>f,newThrownErr:()=>d,newThrownErrBatch:()=>p});var n=a(8518);const r="err_new_thrown_err",s="err_new_thrown_err_batch",o="err_new_spec_err",l="err_new_spec_err_batch",i="err_new_auth_err",c="err_clear",u="err_clear_by";function d(e){return{type:r,payload:(0,n.serializeError)(e)}}function p(e){return{type:s,payload:e}}function m(e){return{type:o,payload:e}}function f(e){return{type:l,payload:e}}function h(e){return{type:i,payload:e}}function g(){return{type:c,payload:arguments.length>0&&void 0!==arguments[0]?arguments[0]:{}}}function y(){return{type:u,payload:arguments.length>0&&void 0!==arguments[0]?arguments[0]:()=>!0}}},6808:(e,t,a)=>{a.r(t),a.d(t,{default:()=>i});var n=a(6145),s=a(2565);const o=(e=>{var t={};return a.d(t,e),t})({default:()=>r.default});const l=[a(2392),a(1835)];function i(e){var t;let a={jsSpec:{}},r=(0,o.default)(l,((e,t)=>{try{let r=t.transform(e,a);return(0,n.default)(r).call(r,(e=>!!e))}catch(t){return console.error("Transformer error:",t),e}}),e);return(0,s.default)(t=(0,n.default)(r).call(r,(e=>!!e))).call(t,(e=>(!e.get("line")&&e.get("path"),e)))}},2392:(e,t,a)=>{a.r(t),a.d(t,{transform:()=>l});var n=a(2565),r=a(8818),s=a(8136),o=a(6785);function l(e){return(0,n.default)(e).call(e,(e=>{var t;let a="is not of a type(s)",n=(0,r.default)(t=e.get("message")).call(t,a);if(n>-1){var l,i;let t=(0,s.default)(l=e.get("message")).call(l,n+19).split(",");return e.set("message",(0,s.default)(i=e.get("message")).call(i,0,n)+function(e){return(0,o.default)(e).call(e,((e,t,a,n)=>a===n.length-1&&n.length>1?e+"or "+t:n[a+1]&&n.length>2?e+t+", ":n[a+1]?e+t+" ":e+t),"should be a")}(t))}return e}))}},1835:(e,t,a)=>{a.r(t),a.d(t,{transform:()=>n});a(2565),a(8818),a(9908),a(9725);function n(e,t){let{jsSpec:a}=t;return e}},7793:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(3527),r=a(4966),s=a(7667);function o(e){return{statePlugins:{err:{reducers:(0,n.default)(e),actions:r,selectors:s}}}}},3527:(e,t,a)=>{a.r(t),a.d(t,{default:()=>p});var n=a(7512),r=a(2565),s=a(5171),o=a(6145),l=a(7930),i=a(4966),c=a(9725),u=a(6808);let d={line:0,level:"error",message:"Unknown error"};function p(){return{[i.NEW_THROWN_ERR]:(e,t)=>{let{payload:a}=t,r=(0,n.default)(d,a,{type:"thrown"});return e.update("errors",(e=>(e||(0,c.List)()).push((0,c.fromJS)(r)))).update("errors",(e=>(0,u.default)(e)))},[i.NEW_THROWN_ERR_BATCH]:(e,t)=>{let{payload:a}=t;return a=(0,r.default)(a).call(a,(e=>(0,c.fromJS)((0,n.default)(d,e,{type:"thrown"})))),e.update("errors",(e=>{var t;return(0,s.default)(t=e||(0,c.List)()).call(t,(0,c.fromJS)(a))})).update("errors",(e=>(0,u.default)(e)))},[i.NEW_SPEC_ERR]:(e,t)=>{let{payload:a}=t,n=(0,c.fromJS)(a);return n=n.set("type","spec"),e.update("errors",(e=>(e||(0,c.List)()).push((0,c.fromJS)(n)).sortBy((e=>e.get("line"))))).update("errors",(e=>(0,u.default)(e)))},[i.NEW_SPEC_ERR_BATCH]:(e,t)=>{let{payload:a}=t;return a=(0,r.default)(a).call(a,(e=>(0,c.fromJS)((0,n.default)(d,e,{type:"spec"})))),e.update("errors",(e=>{var t;return(0,s.default)(t=e||(0,c.List)()).call(t,(0,c.fromJS)(a))})).update("errors",(e=>(0,u.default)(e)))},[i.NEW_AUTH_ERR]:(e,t)=>{let{payload:a}=t,r=(0,c.fromJS)((0,n.default)({},a));return r=r.set("type","auth"),e.update("errors",(e=>(e||(0,c.List)()).push((0,c.fromJS)(r)))).update("errors",(e=>(0,u.default)(e)))},[i.CLEAR]:(e,t)=>{var a;let{payload:n}=t;if(!n||!e.get("errors"))return e;let r=(0,o.default)(a=e.get("errors")).call(a,(e=>{var t;return(0,l.default)(t=e.keySeq()).call(t,(t=>{const a=e.get(t),r=n[t];return!r||a!==r}))}));return e.merge({errors:r})},[i.CLEAR_BY]:(e,t)=>{var a;let{payload:n}=t;if(!n||"function"!=typeof n)return e;let r=(0,o.default)(a=e.get("errors")).call(a,(e=>n(e)));return e.merge({errors:r})}}}},7667:(e,t,a)=>{a.r(t),a.d(t,{allErrors:()=>s,lastError:()=>o});var n=a(9725),r=a(8639);const s=(0,r.createSelector)((e=>e),(e=>e.get("errors",(0,n.List)()))),o=(0,r.createSelector)(s,(e=>e.last()))},9978:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(4309);function r(){return{fn:{opsFilter:n.default}}}},4309:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(6145),r=a(8818);function s(e,t){return(0,n.default)(e).call(e,((e,a)=>-1!==(0,r.default)(a).call(a,t)))}},6395:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(863),r=a(810);a(5053);const s=e=>{let{className:t,width:a,height:s,...o}=e;return r.default.createElement("svg",(0,n.default)({xmlns:"http://www.w3.org/2000/svg",viewBox:"0 0 20 20",className:t,width:a,height:s,"aria-hidden":"true",focusable:"false"},o),r.default.createElement("path",{d:"M17.418 6.109c.272-.268.709-.268.979 0s.271.701 0 .969l-7.908 7.83c-.27.268-.707.268-.979 0l-7.908-7.83c-.27-.268-.27-.701 0-.969.271-.268.709-.268.979 0L10 13.25l7.418-7.141z"}))};s.defaultProps={className:null,width:20,height:20};const o=s},9689:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(863),r=a(810);a(5053);const s=e=>{let{className:t,width:a,height:s,...o}=e;return r.default.createElement("svg",(0,n.default)({xmlns:"http://www.w3.org/2000/svg",viewBox:"0 0 20 20",className:t,width:a,height:s,"aria-hidden":"true",focusable:"false"},o),r.default.createElement("path",{d:"M 17.418 14.908 C 17.69 15.176 18.127 15.176 18.397 14.908 C 18.667 14.64 18.668 14.207 18.397 13.939 L 10.489 6.109 C 10.219 5.841 9.782 5.841 9.51 6.109 L 1.602 13.939 C 1.332 14.207 1.332 14.64 1.602 14.908 C 1.873 15.176 2.311 15.176 2.581 14.908 L 10 7.767 L 17.418 14.908 Z"}))};s.defaultProps={className:null,width:20,height:20};const o=s},6984:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(863),r=a(810);a(5053);const s=e=>{let{className:t,width:a,height:s,...o}=e;return r.default.createElement("svg",(0,n.default)({xmlns:"http://www.w3.org/2000/svg",viewBox:"0 0 20 20",className:t,width:a,height:s,"aria-hidden":"true",focusable:"false"},o),r.default.createElement("path",{d:"M13.25 10L6.109 2.58c-.268-.27-.268-.707 0-.979.268-.27.701-.27.969 0l7.83 7.908c.268.271.268.709 0 .979l-7.83 7.908c-.268.271-.701.27-.969 0-.268-.269-.268-.707 0-.979L13.25 10z"}))};s.defaultProps={className:null,width:20,height:20};const o=s},2478:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(863),r=a(810);a(5053);const s=e=>{let{className:t,width:a,height:s,...o}=e;return r.default.createElement("svg",(0,n.default)({xmlns:"http://www.w3.org/2000/svg",viewBox:"0 0 20 20",className:t,width:a,height:s,"aria-hidden":"true",focusable:"false"},o),r.default.createElement("path",{d:"M14.348 14.849c-.469.469-1.229.469-1.697 0L10 11.819l-2.651 3.029c-.469.469-1.229.469-1.697 0-.469-.469-.469-1.229 0-1.697l2.758-3.15-2.759-3.152c-.469-.469-.469-1.228 0-1.697.469-.469 1.228-.469 1.697 0L10 8.183l2.651-3.031c.469-.469 1.228-.469 1.697 0 .469.469.469 1.229 0 1.697l-2.758 3.152 2.758 3.15c.469.469.469 1.229 0 1.698z"}))};s.defaultProps={className:null,width:20,height:20};const o=s},3388:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(863),r=a(810);a(5053);const s=e=>{let{className:t,width:a,height:s,...o}=e;return r.default.createElement("svg",(0,n.default)({xmlns:"http://www.w3.org/2000/svg",viewBox:"0 0 15 16",className:t,width:a,height:s,"aria-hidden":"true",focusable:"false"},o),r.default.createElement("g",{transform:"translate(2, -1)"},r.default.createElement("path",{fill:"#ffffff",fillRule:"evenodd",d:"M2 13h4v1H2v-1zm5-6H2v1h5V7zm2 3V8l-3 3 3 3v-2h5v-2H9zM4.5 9H2v1h2.5V9zM2 12h2.5v-1H2v1zm9 1h1v2c-.02.28-.11.52-.3.7-.19.18-.42.28-.7.3H1c-.55 0-1-.45-1-1V4c0-.55.45-1 1-1h3c0-1.11.89-2 2-2 1.11 0 2 .89 2 2h3c.55 0 1 .45 1 1v5h-1V6H1v9h10v-2zM2 5h8c0-.55-.45-1-1-1H8c-.55 0-1-.45-1-1s-.45-1-1-1-1 .45-1 1-.45 1-1 1H3c-.55 0-1 .45-1 1z"})))};s.defaultProps={className:null,width:15,height:16};const o=s},6945:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(863),r=a(810);a(5053);const s=e=>{let{className:t,width:a,height:s,...o}=e;return r.default.createElement("svg",(0,n.default)({xmlns:"http://www.w3.org/2000/svg",viewBox:"0 0 20 20",className:t,width:a,height:s,"aria-hidden":"true",focusable:"false"},o),r.default.createElement("path",{d:"M15.8 8H14V5.6C14 2.703 12.665 1 10 1 7.334 1 6 2.703 6 5.6V8H4c-.553 0-1 .646-1 1.199V17c0 .549.428 1.139.951 1.307l1.197.387C5.672 18.861 6.55 19 7.1 19h5.8c.549 0 1.428-.139 1.951-.307l1.196-.387c.524-.167.953-.757.953-1.306V9.199C17 8.646 16.352 8 15.8 8zM12 8H8V5.199C8 3.754 8.797 3 10 3c1.203 0 2 .754 2 2.199V8z"}))};s.defaultProps={className:null,width:20,height:20};const o=s},2568:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(863),r=a(810);a(5053);const s=e=>{let{className:t,width:a,height:s,...o}=e;return r.default.createElement("svg",(0,n.default)({xmlns:"http://www.w3.org/2000/svg",viewBox:"0 0 20 20",className:t,width:a,height:s,"aria-hidden":"true",focusable:"false"},o),r.default.createElement("path",{d:"M15.8 8H14V5.6C14 2.703 12.665 1 10 1 7.334 1 6 2.703 6 5.6V6h2v-.801C8 3.754 8.797 3 10 3c1.203 0 2 .754 2 2.199V8H4c-.553 0-1 .646-1 1.199V17c0 .549.428 1.139.951 1.307l1.197.387C5.672 18.861 6.55 19 7.1 19h5.8c.549 0 1.428-.139 1.951-.307l1.196-.387c.524-.167.953-.757.953-1.306V9.199C17 8.646 16.352 8 15.8 8z"}))};s.defaultProps={className:null,width:20,height:20};const o=s},70:(e,t,a)=>{a.r(t),a.d(t,{default:()=>u});var n=a(9689),r=a(6395),s=a(6984),o=a(2478),l=a(3388),i=a(6945),c=a(2568);const u=()=>({components:{ArrowUpIcon:n.default,ArrowDownIcon:r.default,ArrowIcon:s.default,CloseIcon:o.default,CopyIcon:l.default,LockIcon:i.default,UnlockIcon:c.default}})},7349:(e,t,a)=>{a.r(t),a.d(t,{default:()=>l});var n=a(810),r=(a(5053),a(8096)),s=a(2603);const o=e=>{let{expanded:t,children:a,onChange:o}=e;const l=(0,s.useComponent)("ChevronRightIcon"),i=(0,n.useCallback)((e=>{o(e,!t)}),[t,o]);return n.default.createElement("button",{type:"button",className:"json-schema-2020-12-accordion",onClick:i},n.default.createElement("div",{className:"json-schema-2020-12-accordion__children"},a),n.default.createElement("span",{className:(0,r.default)("json-schema-2020-12-accordion__icon",{"json-schema-2020-12-accordion__icon--expanded":t,"json-schema-2020-12-accordion__icon--collapsed":!t})},n.default.createElement(l,null)))};o.defaultProps={expanded:!1};const l=o},6867:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(5053);const r=e=>{let{expanded:t,onClick:a}=e;const r=(0,n.useCallback)((e=>{a(e,!t)}),[t,a]);return n.default.createElement("button",{type:"button",className:"json-schema-2020-12-expand-deep-button",onClick:r},t?"Collapse all":"Expand all")}},2675:(e,t,a)=>{a.r(t),a.d(t,{default:()=>c});var n=a(2565),r=a(810),s=(a(5053),a(8096)),o=(a(6648),a(2603)),l=a(9006);const i=(0,r.forwardRef)(((e,t)=>{let{schema:a,name:i,dependentRequired:c,onExpand:u}=e;const d=(0,o.useFn)(),p=(0,o.useIsExpanded)(),m=(0,o.useIsExpandedDeeply)(),[f,h]=(0,r.useState)(p||m),[g,y]=(0,r.useState)(m),[v,E]=(0,o.useLevel)(),S=(0,o.useIsEmbedded)(),w=d.isExpandable(a)||c.length>0,b=(0,o.useIsCircular)(a),x=(0,o.useRenderedSchemas)(a),_=d.stringifyConstraints(a),C=(0,o.useComponent)("Accordion"),j=(0,o.useComponent)("Keyword$schema"),N=(0,o.useComponent)("Keyword$vocabulary"),O=(0,o.useComponent)("Keyword$id"),k=(0,o.useComponent)("Keyword$anchor"),A=(0,o.useComponent)("Keyword$dynamicAnchor"),I=(0,o.useComponent)("Keyword$ref"),R=(0,o.useComponent)("Keyword$dynamicRef"),P=(0,o.useComponent)("Keyword$defs"),T=(0,o.useComponent)("Keyword$comment"),M=(0,o.useComponent)("KeywordAllOf"),D=(0,o.useComponent)("KeywordAnyOf"),L=(0,o.useComponent)("KeywordOneOf"),q=(0,o.useComponent)("KeywordNot"),B=(0,o.useComponent)("KeywordIf"),U=(0,o.useComponent)("KeywordThen"),J=(0,o.useComponent)("KeywordElse"),$=(0,o.useComponent)("KeywordDependentSchemas"),V=(0,o.useComponent)("KeywordPrefixItems"),K=(0,o.useComponent)("KeywordItems"),F=(0,o.useComponent)("KeywordContains"),z=(0,o.useComponent)("KeywordProperties"),W=(0,o.useComponent)("KeywordPatternProperties"),H=(0,o.useComponent)("KeywordAdditionalProperties"),G=(0,o.useComponent)("KeywordPropertyNames"),Z=(0,o.useComponent)("KeywordUnevaluatedItems"),Y=(0,o.useComponent)("KeywordUnevaluatedProperties"),X=(0,o.useComponent)("KeywordType"),Q=(0,o.useComponent)("KeywordEnum"),ee=(0,o.useComponent)("KeywordConst"),te=(0,o.useComponent)("KeywordConstraint"),ae=(0,o.useComponent)("KeywordDependentRequired"),ne=(0,o.useComponent)("KeywordContentSchema"),re=(0,o.useComponent)("KeywordTitle"),se=(0,o.useComponent)("KeywordDescription"),oe=(0,o.useComponent)("KeywordDefault"),le=(0,o.useComponent)("KeywordDeprecated"),ie=(0,o.useComponent)("KeywordReadOnly"),ce=(0,o.useComponent)("KeywordWriteOnly"),ue=(0,o.useComponent)("ExpandDeepButton");(0,r.useEffect)((()=>{y(m)}),[m]),(0,r.useEffect)((()=>{y(g)}),[g]);const de=(0,r.useCallback)(((e,t)=>{h(t),!t&&y(!1),u(e,t,!1)}),[u]),pe=(0,r.useCallback)(((e,t)=>{h(t),y(t),u(e,t,!0)}),[u]);return r.default.createElement(l.JSONSchemaLevelContext.Provider,{value:E},r.default.createElement(l.JSONSchemaDeepExpansionContext.Provider,{value:g},r.default.createElement(l.JSONSchemaCyclesContext.Provider,{value:x},r.default.createElement("article",{ref:t,"data-json-schema-level":v,className:(0,s.default)("json-schema-2020-12",{"json-schema-2020-12--embedded":S,"json-schema-2020-12--circular":b})},r.default.createElement("div",{className:"json-schema-2020-12-head"},w&&!b?r.default.createElement(r.default.Fragment,null,r.default.createElement(C,{expanded:f,onChange:de},r.default.createElement(re,{title:i,schema:a})),r.default.createElement(ue,{expanded:f,onClick:pe})):r.default.createElement(re,{title:i,schema:a}),r.default.createElement(le,{schema:a}),r.default.createElement(ie,{schema:a}),r.default.createElement(ce,{schema:a}),r.default.createElement(X,{schema:a,isCircular:b}),_.length>0&&(0,n.default)(_).call(_,(e=>r.default.createElement(te,{key:`${e.scope}-${e.value}`,constraint:e})))),r.default.createElement("div",{className:(0,s.default)("json-schema-2020-12-body",{"json-schema-2020-12-body--collapsed":!f})},f&&r.default.createElement(r.default.Fragment,null,r.default.createElement(se,{schema:a}),!b&&w&&r.default.createElement(r.default.Fragment,null,r.default.createElement(z,{schema:a}),r.default.createElement(W,{schema:a}),r.default.createElement(H,{schema:a}),r.default.createElement(Y,{schema:a}),r.default.createElement(G,{schema:a}),r.default.createElement(M,{schema:a}),r.default.createElement(D,{schema:a}),r.default.createElement(L,{schema:a}),r.default.createElement(q,{schema:a}),r.default.createElement(B,{schema:a}),r.default.createElement(U,{schema:a}),r.default.createElement(J,{schema:a}),r.default.createElement($,{schema:a}),r.default.createElement(V,{schema:a}),r.default.createElement(K,{schema:a}),r.default.createElement(Z,{schema:a}),r.default.createElement(F,{schema:a}),r.default.createElement(ne,{schema:a})),r.default.createElement(Q,{schema:a}),r.default.createElement(ee,{schema:a}),r.default.createElement(ae,{schema:a,dependentRequired:c}),r.default.createElement(oe,{schema:a}),r.default.createElement(j,{schema:a}),r.default.createElement(N,{schema:a}),r.default.createElement(O,{schema:a}),r.default.createElement(k,{schema:a}),r.default.createElement(A,{schema:a}),r.default.createElement(I,{schema:a}),!b&&w&&r.default.createElement(P,{schema:a}),r.default.createElement(R,{schema:a}),r.default.createElement(T,{schema:a})))))))}));i.defaultProps={name:"",dependentRequired:[],onExpand:()=>{}};const c=i},2260:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);const r=()=>n.default.createElement("svg",{xmlns:"http://www.w3.org/2000/svg",width:"24",height:"24",viewBox:"0 0 24 24"},n.default.createElement("path",{d:"M10 6L8.59 7.41 13.17 12l-4.58 4.59L10 18l6-6z"}))},4922:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(6648);const r=e=>{let{schema:t}=e;return null!=t&&t.$anchor?n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$anchor"},n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$anchor"),n.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},t.$anchor)):null}},4685:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(6648);const r=e=>{let{schema:t}=e;return null!=t&&t.$comment?n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$comment"},n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$comment"),n.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},t.$comment)):null}},6418:(e,t,a)=>{a.r(t),a.d(t,{default:()=>u});var n=a(2740),r=a(2565),s=a(7204),o=a(810),l=a(8096),i=(a(6648),a(2603)),c=a(9006);const u=e=>{var t;let{schema:a}=e;const u=(null==a?void 0:a.$defs)||{},d=(0,i.useIsExpandedDeeply)(),[p,m]=(0,o.useState)(d),[f,h]=(0,o.useState)(!1),g=(0,i.useComponent)("Accordion"),y=(0,i.useComponent)("ExpandDeepButton"),v=(0,i.useComponent)("JSONSchema"),E=(0,o.useCallback)((()=>{m((e=>!e))}),[]),S=(0,o.useCallback)(((e,t)=>{m(t),h(t)}),[]);return 0===(0,n.default)(u).length?null:o.default.createElement(c.JSONSchemaDeepExpansionContext.Provider,{value:f},o.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$defs"},o.default.createElement(g,{expanded:p,onChange:E},o.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$defs")),o.default.createElement(y,{expanded:p,onClick:S}),o.default.createElement("strong",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},"object"),o.default.createElement("ul",{className:(0,l.default)("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!p})},p&&o.default.createElement(o.default.Fragment,null,(0,r.default)(t=(0,s.default)(u)).call(t,(e=>{let[t,a]=e;return o.default.createElement("li",{key:t,className:"json-schema-2020-12-property"},o.default.createElement(v,{name:t,schema:a}))}))))))}},1338:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(6648);const r=e=>{let{schema:t}=e;return null!=t&&t.$dynamicAnchor?n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$dynamicAnchor"},n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$dynamicAnchor"),n.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},t.$dynamicAnchor)):null}},7655:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(6648);const r=e=>{let{schema:t}=e;return null!=t&&t.$dynamicRef?n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$dynamicRef"},n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$dynamicRef"),n.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},t.$dynamicRef)):null}},3460:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(6648);const r=e=>{let{schema:t}=e;return null!=t&&t.$id?n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$id"},n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$id"),n.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},t.$id)):null}},2348:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(6648);const r=e=>{let{schema:t}=e;return null!=t&&t.$ref?n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$ref"},n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$ref"),n.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},t.$ref)):null}},9359:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(6648);const r=e=>{let{schema:t}=e;return null!=t&&t.$schema?n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$schema"},n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$schema"),n.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},t.$schema)):null}},7568:(e,t,a)=>{a.r(t),a.d(t,{default:()=>i});var n=a(2565),r=a(7204),s=a(810),o=a(8096),l=(a(6648),a(2603));const i=e=>{var t;let{schema:a}=e;const i=(0,l.useIsExpandedDeeply)(),[c,u]=(0,s.useState)(i),d=(0,l.useComponent)("Accordion"),p=(0,s.useCallback)((()=>{u((e=>!e))}),[]);return null!=a&&a.$vocabulary?"object"!=typeof a.$vocabulary?null:s.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$vocabulary"},s.default.createElement(d,{expanded:c,onChange:p},s.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$vocabulary")),s.default.createElement("strong",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},"object"),s.default.createElement("ul",null,c&&(0,n.default)(t=(0,r.default)(a.$vocabulary)).call(t,(e=>{let[t,a]=e;return s.default.createElement("li",{key:t,className:(0,o.default)("json-schema-2020-12-$vocabulary-uri",{"json-schema-2020-12-$vocabulary-uri--disabled":!a})},s.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},t))})))):null}},5253:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(6648),a(2603));const s=e=>{let{schema:t}=e;const a=(0,r.useFn)(),{additionalProperties:s}=t,o=(0,r.useComponent)("JSONSchema");if(!a.hasKeyword(t,"additionalProperties"))return null;const l=n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Additional properties");return n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--additionalProperties"},!0===s?n.default.createElement(n.default.Fragment,null,l,n.default.createElement("span",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},"allowed")):!1===s?n.default.createElement(n.default.Fragment,null,l,n.default.createElement("span",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},"forbidden")):n.default.createElement(o,{name:l,schema:s}))}},6457:(e,t,a)=>{a.r(t),a.d(t,{default:()=>c});var n=a(4163),r=a(2565),s=a(810),o=a(8096),l=(a(6648),a(2603)),i=a(9006);const c=e=>{let{schema:t}=e;const a=(null==t?void 0:t.allOf)||[],c=(0,l.useFn)(),u=(0,l.useIsExpandedDeeply)(),[d,p]=(0,s.useState)(u),[m,f]=(0,s.useState)(!1),h=(0,l.useComponent)("Accordion"),g=(0,l.useComponent)("ExpandDeepButton"),y=(0,l.useComponent)("JSONSchema"),v=(0,l.useComponent)("KeywordType"),E=(0,s.useCallback)((()=>{p((e=>!e))}),[]),S=(0,s.useCallback)(((e,t)=>{p(t),f(t)}),[]);return(0,n.default)(a)&&0!==a.length?s.default.createElement(i.JSONSchemaDeepExpansionContext.Provider,{value:m},s.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--allOf"},s.default.createElement(h,{expanded:d,onChange:E},s.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"All of")),s.default.createElement(g,{expanded:d,onClick:S}),s.default.createElement(v,{schema:{allOf:a}}),s.default.createElement("ul",{className:(0,o.default)("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!d})},d&&s.default.createElement(s.default.Fragment,null,(0,r.default)(a).call(a,((e,t)=>s.default.createElement("li",{key:`#${t}`,className:"json-schema-2020-12-property"},s.default.createElement(y,{name:`#${t} ${c.getTitle(e)}`,schema:e})))))))):null}},8776:(e,t,a)=>{a.r(t),a.d(t,{default:()=>c});var n=a(4163),r=a(2565),s=a(810),o=a(8096),l=(a(6648),a(2603)),i=a(9006);const c=e=>{let{schema:t}=e;const a=(null==t?void 0:t.anyOf)||[],c=(0,l.useFn)(),u=(0,l.useIsExpandedDeeply)(),[d,p]=(0,s.useState)(u),[m,f]=(0,s.useState)(!1),h=(0,l.useComponent)("Accordion"),g=(0,l.useComponent)("ExpandDeepButton"),y=(0,l.useComponent)("JSONSchema"),v=(0,l.useComponent)("KeywordType"),E=(0,s.useCallback)((()=>{p((e=>!e))}),[]),S=(0,s.useCallback)(((e,t)=>{p(t),f(t)}),[]);return(0,n.default)(a)&&0!==a.length?s.default.createElement(i.JSONSchemaDeepExpansionContext.Provider,{value:m},s.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--anyOf"},s.default.createElement(h,{expanded:d,onChange:E},s.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Any of")),s.default.createElement(g,{expanded:d,onClick:S}),s.default.createElement(v,{schema:{anyOf:a}}),s.default.createElement("ul",{className:(0,o.default)("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!d})},d&&s.default.createElement(s.default.Fragment,null,(0,r.default)(a).call(a,((e,t)=>s.default.createElement("li",{key:`#${t}`,className:"json-schema-2020-12-property"},s.default.createElement(y,{name:`#${t} ${c.getTitle(e)}`,schema:e})))))))):null}},7308:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(6648),a(2603));const s=e=>{let{schema:t}=e;const a=(0,r.useFn)();return a.hasKeyword(t,"const")?n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--const"},n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Const"),n.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--const"},a.stringify(t.const))):null}},9956:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810);a(5053);const r=e=>{let{constraint:t}=e;return n.default.createElement("span",{className:`json-schema-2020-12__constraint json-schema-2020-12__constraint--${t.scope}`},t.value)},s=n.default.memo(r)},8993:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(6648),a(2603));const s=e=>{let{schema:t}=e;const a=(0,r.useFn)(),s=(0,r.useComponent)("JSONSchema");if(!a.hasKeyword(t,"contains"))return null;const o=n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Contains");return n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--contains"},n.default.createElement(s,{name:o,schema:t.contains}))}},3484:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(6648),a(2603));const s=e=>{let{schema:t}=e;const a=(0,r.useFn)(),s=(0,r.useComponent)("JSONSchema");if(!a.hasKeyword(t,"contentSchema"))return null;const o=n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Content schema");return n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--contentSchema"},n.default.createElement(s,{name:o,schema:t.contentSchema}))}},5148:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(6648),a(2603));const s=e=>{let{schema:t}=e;const a=(0,r.useFn)();return a.hasKeyword(t,"default")?n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--default"},n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Default"),n.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--const"},a.stringify(t.default))):null}},4539:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(2565),r=a(810);a(5053),a(6648);const s=e=>{let{dependentRequired:t}=e;return 0===t.length?null:r.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--dependentRequired"},r.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Required when defined"),r.default.createElement("ul",null,(0,n.default)(t).call(t,(e=>r.default.createElement("li",{key:e},r.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--warning"},e))))))}},6076:(e,t,a)=>{a.r(t),a.d(t,{default:()=>u});var n=a(2740),r=a(2565),s=a(7204),o=a(810),l=a(8096),i=(a(6648),a(2603)),c=a(9006);const u=e=>{var t;let{schema:a}=e;const u=(null==a?void 0:a.dependentSchemas)||[],d=(0,i.useIsExpandedDeeply)(),[p,m]=(0,o.useState)(d),[f,h]=(0,o.useState)(!1),g=(0,i.useComponent)("Accordion"),y=(0,i.useComponent)("ExpandDeepButton"),v=(0,i.useComponent)("JSONSchema"),E=(0,o.useCallback)((()=>{m((e=>!e))}),[]),S=(0,o.useCallback)(((e,t)=>{m(t),h(t)}),[]);return"object"!=typeof u||0===(0,n.default)(u).length?null:o.default.createElement(c.JSONSchemaDeepExpansionContext.Provider,{value:f},o.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--dependentSchemas"},o.default.createElement(g,{expanded:p,onChange:E},o.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Dependent schemas")),o.default.createElement(y,{expanded:p,onClick:S}),o.default.createElement("strong",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},"object"),o.default.createElement("ul",{className:(0,l.default)("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!p})},p&&o.default.createElement(o.default.Fragment,null,(0,r.default)(t=(0,s.default)(u)).call(t,(e=>{let[t,a]=e;return o.default.createElement("li",{key:t,className:"json-schema-2020-12-property"},o.default.createElement(v,{name:t,schema:a}))}))))))}},6661:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(6648);const r=e=>{let{schema:t}=e;return!0!==(null==t?void 0:t.deprecated)?null:n.default.createElement("span",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--warning"},"deprecated")}},9446:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(6648);const r=e=>{let{schema:t}=e;return null!=t&&t.description?n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--description"},n.default.createElement("div",{className:"json-schema-2020-12-core-keyword__value json-schema-2020-12-core-keyword__value--secondary"},t.description)):null}},7207:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(6648),a(2603));const s=e=>{let{schema:t}=e;const a=(0,r.useFn)(),s=(0,r.useComponent)("JSONSchema");if(!a.hasKeyword(t,"else"))return null;const o=n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Else");return n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--if"},n.default.createElement(s,{name:o,schema:t.else}))}},1805:(e,t,a)=>{a.r(t),a.d(t,{default:()=>l});var n=a(4163),r=a(2565),s=a(810),o=(a(6648),a(2603));const l=e=>{var t;let{schema:a}=e;const l=(0,o.useFn)();return(0,n.default)(null==a?void 0:a.enum)?s.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--enum"},s.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Allowed values"),s.default.createElement("ul",null,(0,r.default)(t=a.enum).call(t,(e=>{const t=l.stringify(e);return s.default.createElement("li",{key:t},s.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--const"},t))})))):null}},487:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(6648),a(2603));const s=e=>{let{schema:t}=e;const a=(0,r.useFn)(),s=(0,r.useComponent)("JSONSchema");if(!a.hasKeyword(t,"if"))return null;const o=n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"If");return n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--if"},n.default.createElement(s,{name:o,schema:t.if}))}},9206:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(6648),a(2603));const s=e=>{let{schema:t}=e;const a=(0,r.useFn)(),s=(0,r.useComponent)("JSONSchema");if(!a.hasKeyword(t,"items"))return null;const o=n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Items");return n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--items"},n.default.createElement(s,{name:o,schema:t.items}))}},5174:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(6648),a(2603));const s=e=>{let{schema:t}=e;const a=(0,r.useFn)(),s=(0,r.useComponent)("JSONSchema");if(!a.hasKeyword(t,"not"))return null;const o=n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Not");return n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--not"},n.default.createElement(s,{name:o,schema:t.not}))}},3834:(e,t,a)=>{a.r(t),a.d(t,{default:()=>c});var n=a(4163),r=a(2565),s=a(810),o=a(8096),l=(a(6648),a(2603)),i=a(9006);const c=e=>{let{schema:t}=e;const a=(null==t?void 0:t.oneOf)||[],c=(0,l.useFn)(),u=(0,l.useIsExpandedDeeply)(),[d,p]=(0,s.useState)(u),[m,f]=(0,s.useState)(!1),h=(0,l.useComponent)("Accordion"),g=(0,l.useComponent)("ExpandDeepButton"),y=(0,l.useComponent)("JSONSchema"),v=(0,l.useComponent)("KeywordType"),E=(0,s.useCallback)((()=>{p((e=>!e))}),[]),S=(0,s.useCallback)(((e,t)=>{p(t),f(t)}),[]);return(0,n.default)(a)&&0!==a.length?s.default.createElement(i.JSONSchemaDeepExpansionContext.Provider,{value:m},s.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--oneOf"},s.default.createElement(h,{expanded:d,onChange:E},s.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"One of")),s.default.createElement(g,{expanded:d,onClick:S}),s.default.createElement(v,{schema:{oneOf:a}}),s.default.createElement("ul",{className:(0,o.default)("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!d})},d&&s.default.createElement(s.default.Fragment,null,(0,r.default)(a).call(a,((e,t)=>s.default.createElement("li",{key:`#${t}`,className:"json-schema-2020-12-property"},s.default.createElement(y,{name:`#${t} ${c.getTitle(e)}`,schema:e})))))))):null}},6746:(e,t,a)=>{a.r(t),a.d(t,{default:()=>i});var n=a(2740),r=a(2565),s=a(7204),o=a(810),l=(a(6648),a(2603));const i=e=>{var t;let{schema:a}=e;const i=(null==a?void 0:a.patternProperties)||{},c=(0,l.useComponent)("JSONSchema");return 0===(0,n.default)(i).length?null:o.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--patternProperties"},o.default.createElement("ul",null,(0,r.default)(t=(0,s.default)(i)).call(t,(e=>{let[t,a]=e;return o.default.createElement("li",{key:t,className:"json-schema-2020-12-property"},o.default.createElement(c,{name:t,schema:a}))}))))}},3971:(e,t,a)=>{a.r(t),a.d(t,{default:()=>c});var n=a(4163),r=a(2565),s=a(810),o=a(8096),l=(a(6648),a(2603)),i=a(9006);const c=e=>{let{schema:t}=e;const a=(null==t?void 0:t.prefixItems)||[],c=(0,l.useFn)(),u=(0,l.useIsExpandedDeeply)(),[d,p]=(0,s.useState)(u),[m,f]=(0,s.useState)(!1),h=(0,l.useComponent)("Accordion"),g=(0,l.useComponent)("ExpandDeepButton"),y=(0,l.useComponent)("JSONSchema"),v=(0,l.useComponent)("KeywordType"),E=(0,s.useCallback)((()=>{p((e=>!e))}),[]),S=(0,s.useCallback)(((e,t)=>{p(t),f(t)}),[]);return(0,n.default)(a)&&0!==a.length?s.default.createElement(i.JSONSchemaDeepExpansionContext.Provider,{value:m},s.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--prefixItems"},s.default.createElement(h,{expanded:d,onChange:E},s.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Prefix items")),s.default.createElement(g,{expanded:d,onClick:S}),s.default.createElement(v,{schema:{prefixItems:a}}),s.default.createElement("ul",{className:(0,o.default)("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!d})},d&&s.default.createElement(s.default.Fragment,null,(0,r.default)(a).call(a,((e,t)=>s.default.createElement("li",{key:`#${t}`,className:"json-schema-2020-12-property"},s.default.createElement(y,{name:`#${t} ${c.getTitle(e)}`,schema:e})))))))):null}},5472:(e,t,a)=>{a.r(t),a.d(t,{default:()=>d});var n=a(4163),r=a(2740),s=a(2565),o=a(7204),l=a(2372),i=a(810),c=a(8096),u=(a(6648),a(2603));const d=e=>{var t;let{schema:a}=e;const d=(0,u.useFn)(),p=(null==a?void 0:a.properties)||{},m=(0,n.default)(null==a?void 0:a.required)?a.required:[],f=(0,u.useComponent)("JSONSchema");return 0===(0,r.default)(p).length?null:i.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--properties"},i.default.createElement("ul",null,(0,s.default)(t=(0,o.default)(p)).call(t,(e=>{let[t,n]=e;const r=(0,l.default)(m).call(m,t),s=d.getDependentRequired(t,a);return i.default.createElement("li",{key:t,className:(0,c.default)("json-schema-2020-12-property",{"json-schema-2020-12-property--required":r})},i.default.createElement(f,{name:t,schema:n,dependentRequired:s}))}))))}},2338:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(6648),a(2603));const s=e=>{let{schema:t}=e;const a=(0,r.useFn)(),{propertyNames:s}=t,o=(0,r.useComponent)("JSONSchema"),l=n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Property names");return a.hasKeyword(t,"propertyNames")?n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--propertyNames"},n.default.createElement(o,{name:l,schema:s})):null}},6456:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(6648);const r=e=>{let{schema:t}=e;return!0!==(null==t?void 0:t.readOnly)?null:n.default.createElement("span",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--muted"},"read-only")}},7401:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(6648),a(2603));const s=e=>{let{schema:t}=e;const a=(0,r.useFn)(),s=(0,r.useComponent)("JSONSchema");if(!a.hasKeyword(t,"then"))return null;const o=n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Then");return n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--then"},n.default.createElement(s,{name:o,schema:t.then}))}},8137:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(810),r=(a(5053),a(6648),a(2603));const s=e=>{let{title:t,schema:a}=e;const s=(0,r.useFn)();return t||s.getTitle(a)?n.default.createElement("div",{className:"json-schema-2020-12__title"},t||s.getTitle(a)):null};s.defaultProps={title:""};const o=s},2285:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(810),r=(a(5053),a(6648),a(2603));const s=e=>{let{schema:t,isCircular:a}=e;const s=(0,r.useFn)().getType(t),o=a?" [circular]":"";return n.default.createElement("strong",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},`${s}${o}`)};s.defaultProps={isCircular:!1};const o=s},5828:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(6648),a(2603));const s=e=>{let{schema:t}=e;const a=(0,r.useFn)(),{unevaluatedItems:s}=t,o=(0,r.useComponent)("JSONSchema");if(!a.hasKeyword(t,"unevaluatedItems"))return null;const l=n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Unevaluated items");return n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--unevaluatedItems"},n.default.createElement(o,{name:l,schema:s}))}},6907:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(6648),a(2603));const s=e=>{let{schema:t}=e;const a=(0,r.useFn)(),{unevaluatedProperties:s}=t,o=(0,r.useComponent)("JSONSchema");if(!a.hasKeyword(t,"unevaluatedProperties"))return null;const l=n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Unevaluated properties");return n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--unevaluatedProperties"},n.default.createElement(o,{name:l,schema:s}))}},5789:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(6648);const r=e=>{let{schema:t}=e;return!0!==(null==t?void 0:t.writeOnly)?null:n.default.createElement("span",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--muted"},"write-only")}},9006:(e,t,a)=>{a.r(t),a.d(t,{JSONSchemaContext:()=>s,JSONSchemaCyclesContext:()=>i,JSONSchemaDeepExpansionContext:()=>l,JSONSchemaLevelContext:()=>o});var n=a(527),r=a(810);const s=(0,r.createContext)(null);s.displayName="JSONSchemaContext";const o=(0,r.createContext)(0);o.displayName="JSONSchemaLevelContext";const l=(0,r.createContext)(!1);l.displayName="JSONSchemaDeepExpansionContext";const i=(0,r.createContext)(new n.default)},4121:(e,t,a)=>{a.r(t),a.d(t,{getDependentRequired:()=>C,getTitle:()=>y,getType:()=>v,hasKeyword:()=>S,isBooleanJSONSchema:()=>E,isExpandable:()=>w,stringify:()=>b,stringifyConstraints:()=>_,upperFirst:()=>g});var n=a(8136),r=a(3284),s=a(4163),o=a(2565),l=a(2372),i=a(1772),c=a(6145),u=a(313),d=a(8898),p=a(6785),m=a(7204),f=a(527),h=a(2603);const g=e=>"string"==typeof e?`${e.charAt(0).toUpperCase()}${(0,n.default)(e).call(e,1)}`:e,y=e=>{const t=(0,h.useFn)();return null!=e&&e.title?t.upperFirst(e.title):null!=e&&e.$anchor?t.upperFirst(e.$anchor):null!=e&&e.$id?e.$id:""},v=function(e){var t,a;let n=arguments.length>1&&void 0!==arguments[1]?arguments[1]:new r.default;const u=(0,h.useFn)();if(null==e)return"any";if(u.isBooleanJSONSchema(e))return e?"any":"never";if("object"!=typeof e)return"any";if(n.has(e))return"any";n.add(e);const{type:d,prefixItems:p,items:m}=e,f=()=>{if((0,s.default)(p)){const e=(0,o.default)(p).call(p,(e=>v(e,n))),t=m?v(m,n):"any";return`array<[${e.join(", ")}], ${t}>`}if(m){return`array<${v(m,n)}>`}return"array<any>"};if(e.not&&"any"===v(e.not))return"never";const g=(0,s.default)(d)?(0,o.default)(d).call(d,(e=>"array"===e?f():e)).join(" | "):"array"===d?f():(0,l.default)(t=["null","boolean","object","array","number","integer","string"]).call(t,d)?d:(()=>{var t,a;if(Object.hasOwn(e,"prefixItems")||Object.hasOwn(e,"items")||Object.hasOwn(e,"contains"))return f();if(Object.hasOwn(e,"properties")||Object.hasOwn(e,"additionalProperties")||Object.hasOwn(e,"patternProperties"))return"object";if((0,l.default)(t=["int32","int64"]).call(t,e.format))return"integer";if((0,l.default)(a=["float","double"]).call(a,e.format))return"number";if(Object.hasOwn(e,"minimum")||Object.hasOwn(e,"maximum")||Object.hasOwn(e,"exclusiveMinimum")||Object.hasOwn(e,"exclusiveMaximum")||Object.hasOwn(e,"multipleOf"))return"number | integer";if(Object.hasOwn(e,"pattern")||Object.hasOwn(e,"format")||Object.hasOwn(e,"minLength")||Object.hasOwn(e,"maxLength"))return"string";if(void 0!==e.const){if(null===e.const)return"null";if("boolean"==typeof e.const)return"boolean";if("number"==typeof e.const)return(0,i.default)(e.const)?"integer":"number";if("string"==typeof e.const)return"string";if((0,s.default)(e.const))return"array<any>";if("object"==typeof e.const)return"object"}return null})(),y=(t,a)=>{if((0,s.default)(e[t])){var r;return`(${(0,o.default)(r=e[t]).call(r,(e=>v(e,n))).join(a)})`}return null},E=y("oneOf"," | "),S=y("anyOf"," | "),w=y("allOf"," & "),b=(0,c.default)(a=[g,E,S,w]).call(a,Boolean).join(" | ");return n.delete(e),b||"any"},E=e=>"boolean"==typeof e,S=(e,t)=>null!==e&&"object"==typeof e&&Object.hasOwn(e,t),w=e=>{const t=(0,h.useFn)();return(null==e?void 0:e.$schema)||(null==e?void 0:e.$vocabulary)||(null==e?void 0:e.$id)||(null==e?void 0:e.$anchor)||(null==e?void 0:e.$dynamicAnchor)||(null==e?void 0:e.$ref)||(null==e?void 0:e.$dynamicRef)||(null==e?void 0:e.$defs)||(null==e?void 0:e.$comment)||(null==e?void 0:e.allOf)||(null==e?void 0:e.anyOf)||(null==e?void 0:e.oneOf)||t.hasKeyword(e,"not")||t.hasKeyword(e,"if")||t.hasKeyword(e,"then")||t.hasKeyword(e,"else")||(null==e?void 0:e.dependentSchemas)||(null==e?void 0:e.prefixItems)||t.hasKeyword(e,"items")||t.hasKeyword(e,"contains")||(null==e?void 0:e.properties)||(null==e?void 0:e.patternProperties)||t.hasKeyword(e,"additionalProperties")||t.hasKeyword(e,"propertyNames")||t.hasKeyword(e,"unevaluatedItems")||t.hasKeyword(e,"unevaluatedProperties")||(null==e?void 0:e.description)||(null==e?void 0:e.enum)||t.hasKeyword(e,"const")||t.hasKeyword(e,"contentSchema")||t.hasKeyword(e,"default")},b=e=>{var t;return null===e||(0,l.default)(t=["number","bigint","boolean"]).call(t,typeof e)?String(e):(0,s.default)(e)?`[${(0,o.default)(e).call(e,b).join(", ")}]`:(0,u.default)(e)},x=(e,t,a)=>{const n="number"==typeof t,r="number"==typeof a;return n&&r?t===a?`${t} ${e}`:`[${t}, ${a}] ${e}`:n?`>= ${t} ${e}`:r?`<= ${a} ${e}`:null},_=e=>{const t=[],a=(e=>{if("number"!=typeof(null==e?void 0:e.multipleOf))return null;if(e.multipleOf<=0)return null;if(1===e.multipleOf)return null;const{multipleOf:t}=e;if((0,i.default)(t))return`multiple of ${t}`;const a=10**t.toString().split(".")[1].length;return`multiple of ${t*a}/${a}`})(e);null!==a&&t.push({scope:"number",value:a});const n=(e=>{const t=null==e?void 0:e.minimum,a=null==e?void 0:e.maximum,n=null==e?void 0:e.exclusiveMinimum,r=null==e?void 0:e.exclusiveMaximum,s="number"==typeof t,o="number"==typeof a,l="number"==typeof n,i="number"==typeof r,c=l&&(!s||t<n),u=i&&(!o||a>r);if((s||l)&&(o||i))return`${c?"(":"["}${c?n:t}, ${u?r:a}${u?")":"]"}`;if(s||l)return`${c?">":"≥"} ${c?n:t}`;if(o||i)return`${u?"<":"≤"} ${u?r:a}`;return null})(e);null!==n&&t.push({scope:"number",value:n}),null!=e&&e.format&&t.push({scope:"string",value:e.format});const r=x("characters",null==e?void 0:e.minLength,null==e?void 0:e.maxLength);null!==r&&t.push({scope:"string",value:r}),null!=e&&e.pattern&&t.push({scope:"string",value:`matches ${null==e?void 0:e.pattern}`}),null!=e&&e.contentMediaType&&t.push({scope:"string",value:`media type: ${e.contentMediaType}`}),null!=e&&e.contentEncoding&&t.push({scope:"string",value:`encoding: ${e.contentEncoding}`});const s=x(null!=e&&e.hasUniqueItems?"unique items":"items",null==e?void 0:e.minItems,null==e?void 0:e.maxItems);null!==s&&t.push({scope:"array",value:s});const o=x("contained items",null==e?void 0:e.minContains,null==e?void 0:e.maxContains);null!==o&&t.push({scope:"array",value:o});const l=x("properties",null==e?void 0:e.minProperties,null==e?void 0:e.maxProperties);return null!==l&&t.push({scope:"object",value:l}),t},C=(e,t)=>{var a;return null!=t&&t.dependentRequired?(0,d.default)((0,p.default)(a=(0,m.default)(t.dependentRequired)).call(a,((t,a)=>{let[n,r]=a;return(0,s.default)(r)&&(0,l.default)(r).call(r,e)?(t.add(n),t):t}),new f.default)):[]}},5077:(e,t,a)=>{a.r(t),a.d(t,{withJSONSchemaContext:()=>H});var n=a(810),r=a(2675),s=a(9359),o=a(7568),l=a(3460),i=a(4922),c=a(1338),u=a(2348),d=a(7655),p=a(6418),m=a(4685),f=a(6457),h=a(8776),g=a(3834),y=a(5174),v=a(487),E=a(7401),S=a(7207),w=a(6076),b=a(3971),x=a(9206),_=a(8993),C=a(5472),j=a(6746),N=a(5253),O=a(2338),k=a(5828),A=a(6907),I=a(2285),R=a(1805),P=a(7308),T=a(9956),M=a(4539),D=a(3484),L=a(8137),q=a(9446),B=a(5148),U=a(6661),J=a(6456),$=a(5789),V=a(7349),K=a(6867),F=a(2260),z=a(9006),W=a(4121);const H=function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};const a={components:{JSONSchema:r.default,Keyword$schema:s.default,Keyword$vocabulary:o.default,Keyword$id:l.default,Keyword$anchor:i.default,Keyword$dynamicAnchor:c.default,Keyword$ref:u.default,Keyword$dynamicRef:d.default,Keyword$defs:p.default,Keyword$comment:m.default,KeywordAllOf:f.default,KeywordAnyOf:h.default,KeywordOneOf:g.default,KeywordNot:y.default,KeywordIf:v.default,KeywordThen:E.default,KeywordElse:S.default,KeywordDependentSchemas:w.default,KeywordPrefixItems:b.default,KeywordItems:x.default,KeywordContains:_.default,KeywordProperties:C.default,KeywordPatternProperties:j.default,KeywordAdditionalProperties:N.default,KeywordPropertyNames:O.default,KeywordUnevaluatedItems:k.default,KeywordUnevaluatedProperties:A.default,KeywordType:I.default,KeywordEnum:R.default,KeywordConst:P.default,KeywordConstraint:T.default,KeywordDependentRequired:M.default,KeywordContentSchema:D.default,KeywordTitle:L.default,KeywordDescription:q.default,KeywordDefault:B.default,KeywordDeprecated:U.default,KeywordReadOnly:J.default,KeywordWriteOnly:$.default,Accordion:V.default,ExpandDeepButton:K.default,ChevronRightIcon:F.default,...t.components},config:{default$schema:"https://json-schema.org/draft/2020-12/schema",defaultExpandedLevels:0,...t.config},fn:{upperFirst:W.upperFirst,getTitle:W.getTitle,getType:W.getType,isBooleanJSONSchema:W.isBooleanJSONSchema,hasKeyword:W.hasKeyword,isExpandable:W.isExpandable,stringify:W.stringify,stringifyConstraints:W.stringifyConstraints,getDependentRequired:W.getDependentRequired,...t.fn}},H=t=>n.default.createElement(z.JSONSchemaContext.Provider,{value:a},n.default.createElement(e,t));return H.contexts={JSONSchemaContext:z.JSONSchemaContext},H.displayName=e.displayName,H}},2603:(e,t,a)=>{a.r(t),a.d(t,{useComponent:()=>l,useConfig:()=>o,useFn:()=>i,useIsCircular:()=>f,useIsEmbedded:()=>u,useIsExpanded:()=>d,useIsExpandedDeeply:()=>p,useLevel:()=>c,useRenderedSchemas:()=>m});var n=a(527),r=a(810),s=a(9006);const o=()=>{const{config:e}=(0,r.useContext)(s.JSONSchemaContext);return e},l=e=>{const{components:t}=(0,r.useContext)(s.JSONSchemaContext);return t[e]||null},i=function(){let e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:void 0;const{fn:t}=(0,r.useContext)(s.JSONSchemaContext);return void 0!==e?t[e]:t},c=()=>{const e=(0,r.useContext)(s.JSONSchemaLevelContext);return[e,e+1]},u=()=>{const[e]=c();return e>0},d=()=>{const[e]=c(),{defaultExpandedLevels:t}=o();return t-e>0},p=()=>(0,r.useContext)(s.JSONSchemaDeepExpansionContext),m=function(){let e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:void 0;if(void 0===e)return(0,r.useContext)(s.JSONSchemaCyclesContext);const t=(0,r.useContext)(s.JSONSchemaCyclesContext);return new n.default([...t,e])},f=e=>m().has(e)},7139:(e,t,a)=>{a.r(t),a.d(t,{default:()=>Z});var n=a(2675),r=a(9359),s=a(7568),o=a(3460),l=a(4922),i=a(1338),c=a(2348),u=a(7655),d=a(6418),p=a(4685),m=a(6457),f=a(8776),h=a(3834),g=a(5174),y=a(487),v=a(7401),E=a(7207),S=a(6076),w=a(3971),b=a(9206),x=a(8993),_=a(5472),C=a(6746),j=a(5253),N=a(2338),O=a(5828),k=a(6907),A=a(2285),I=a(1805),R=a(7308),P=a(9956),T=a(4539),M=a(3484),D=a(8137),L=a(9446),q=a(5148),B=a(6661),U=a(6456),J=a(5789),$=a(7349),V=a(6867),K=a(2260),F=a(4121),z=a(8591),W=a(9006),H=a(2603),G=a(5077);const Z=()=>({components:{JSONSchema202012:n.default,JSONSchema202012Keyword$schema:r.default,JSONSchema202012Keyword$vocabulary:s.default,JSONSchema202012Keyword$id:o.default,JSONSchema202012Keyword$anchor:l.default,JSONSchema202012Keyword$dynamicAnchor:i.default,JSONSchema202012Keyword$ref:c.default,JSONSchema202012Keyword$dynamicRef:u.default,JSONSchema202012Keyword$defs:d.default,JSONSchema202012Keyword$comment:p.default,JSONSchema202012KeywordAllOf:m.default,JSONSchema202012KeywordAnyOf:f.default,JSONSchema202012KeywordOneOf:h.default,JSONSchema202012KeywordNot:g.default,JSONSchema202012KeywordIf:y.default,JSONSchema202012KeywordThen:v.default,JSONSchema202012KeywordElse:E.default,JSONSchema202012KeywordDependentSchemas:S.default,JSONSchema202012KeywordPrefixItems:w.default,JSONSchema202012KeywordItems:b.default,JSONSchema202012KeywordContains:x.default,JSONSchema202012KeywordProperties:_.default,JSONSchema202012KeywordPatternProperties:C.default,JSONSchema202012KeywordAdditionalProperties:j.default,JSONSchema202012KeywordPropertyNames:N.default,JSONSchema202012KeywordUnevaluatedItems:O.default,JSONSchema202012KeywordUnevaluatedProperties:k.default,JSONSchema202012KeywordType:A.default,JSONSchema202012KeywordEnum:I.default,JSONSchema202012KeywordConst:R.default,JSONSchema202012KeywordConstraint:P.default,JSONSchema202012KeywordDependentRequired:T.default,JSONSchema202012KeywordContentSchema:M.default,JSONSchema202012KeywordTitle:D.default,JSONSchema202012KeywordDescription:L.default,JSONSchema202012KeywordDefault:q.default,JSONSchema202012KeywordDeprecated:B.default,JSONSchema202012KeywordReadOnly:U.default,JSONSchema202012KeywordWriteOnly:J.default,JSONSchema202012Accordion:$.default,JSONSchema202012ExpandDeepButton:V.default,JSONSchema202012ChevronRightIcon:K.default,withJSONSchema202012Context:G.withJSONSchemaContext,JSONSchema202012DeepExpansionContext:()=>W.JSONSchemaDeepExpansionContext},fn:{upperFirst:F.upperFirst,jsonSchema202012:{isExpandable:F.isExpandable,hasKeyword:F.hasKeyword,useFn:H.useFn,useConfig:H.useConfig,useComponent:H.useComponent,useIsExpandedDeeply:H.useIsExpandedDeeply,sampleFromSchema:z.sampleFromSchema,sampleFromSchemaGeneric:z.sampleFromSchemaGeneric,sampleEncoderAPI:z.encoderAPI,sampleFormatAPI:z.formatAPI,sampleMediaTypeAPI:z.mediaTypeAPI,createXMLExample:z.createXMLExample,memoizedSampleFromSchema:z.memoizedSampleFromSchema,memoizedCreateXMLExample:z.memoizedCreateXMLExample}}})},6648:(e,t,a)=>{a.r(t),a.d(t,{booleanSchema:()=>s,objectSchema:()=>r,schema:()=>o});var n=a(5053);const r=n.default.object,s=n.default.bool,o=n.default.oneOfType([r,s])},9507:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});const n=new(a(674).default),r=(e,t)=>"function"==typeof t?n.register(e,t):null===t?n.unregister(e):n.get(e);r.getDefaults=()=>n.defaults;const s=r},2906:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});const n=new(a(4215).default),r=(e,t)=>"function"==typeof t?n.register(e,t):null===t?n.unregister(e):n.get(e)},537:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});const n=new(a(3782).default),r=(e,t)=>{if("function"==typeof t)return n.register(e,t);if(null===t)return n.unregister(e);const a=e.split(";").at(0),r=`${a.split("/").at(0)}/*`;return n.get(e)||n.get(a)||n.get(r)};r.getDefaults=()=>n.defaults;const s=r},674:(e,t,a)=>{a.r(t),a.d(t,{default:()=>y});var n=a(775),r=a(5496),s=a(9651),o=a(4215),l=a(1433),i=a(8509),c=a(4366),u=a(5037),d=a(5709),p=a(4180),m=a(1967);function f(e,t,a){!function(e,t){if(t.has(e))throw new TypeError("Cannot initialize the same private elements twice on an object")}(e,t),t.set(e,a)}var h=new s.default;class g extends o.default{constructor(){super(...arguments),f(this,h,{writable:!0,value:{"7bit":l.default,"8bit":i.default,binary:c.default,"quoted-printable":u.default,base16:d.default,base32:p.default,base64:m.default}}),(0,n.default)(this,"data",{...(0,r.default)(this,h)})}get defaults(){return{...(0,r.default)(this,h)}}}const y=g},3782:(e,t,a)=>{a.r(t),a.d(t,{default:()=>h});var n=a(775),r=a(5496),s=a(9651),o=a(4215),l=a(5378),i=a(6724),c=a(4342),u=a(2974),d=a(5652);function p(e,t,a){!function(e,t){if(t.has(e))throw new TypeError("Cannot initialize the same private elements twice on an object")}(e,t),t.set(e,a)}var m=new s.default;class f extends o.default{constructor(){super(...arguments),p(this,m,{writable:!0,value:{...l.default,...i.default,...c.default,...u.default,...d.default}}),(0,n.default)(this,"data",{...(0,r.default)(this,m)})}get defaults(){return{...(0,r.default)(this,m)}}}const h=f},4215:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(775);const r=class{constructor(){(0,n.default)(this,"data",{})}register(e,t){this.data[e]=t}unregister(e){void 0===e?this.data={}:delete this.data[e]}get(e){return this.data[e]}}},8338:(e,t,a)=>{a.r(t),a.d(t,{ALL_TYPES:()=>r,SCALAR_TYPES:()=>n});const n=["number","integer","string","boolean","null"],r=["array","object",...n]},3783:(e,t,a)=>{a.r(t),a.d(t,{extractExample:()=>o,hasExample:()=>s});var n=a(4163),r=a(3084);const s=e=>{if(!(0,r.isJSONSchemaObject)(e))return!1;const{examples:t,example:a,default:s}=e;return!!((0,n.default)(t)&&t.length>=1)||(void 0!==s||void 0!==a)},o=e=>{if(!(0,r.isJSONSchemaObject)(e))return null;const{examples:t,example:a,default:s}=e;return(0,n.default)(t)&&t.length>=1?t.at(0):void 0!==s?s:void 0!==a?a:void 0}},7078:(e,t,a)=>{a.r(t),a.d(t,{default:()=>p});var n=a(4163),r=a(5171),s=a(8898),o=a(527),l=a(2740),i=a(6145),c=a(6561),u=a(3084);const d=function(e,t){let a=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{};if((0,u.isBooleanJSONSchema)(e)&&!0===e)return!0;if((0,u.isBooleanJSONSchema)(e)&&!1===e)return!1;if((0,u.isBooleanJSONSchema)(t)&&!0===t)return!0;if((0,u.isBooleanJSONSchema)(t)&&!1===t)return!1;if(!(0,u.isJSONSchema)(e))return t;if(!(0,u.isJSONSchema)(t))return e;const p={...t,...e};if(t.type&&e.type&&(0,n.default)(t.type)&&"string"==typeof t.type){var m;const a=(0,r.default)(m=(0,c.AF)(t.type)).call(m,e.type);p.type=(0,s.default)(new o.default(a))}if((0,n.default)(t.required)&&(0,n.default)(e.required)&&(p.required=[...new o.default([...e.required,...t.required])]),t.properties&&e.properties){const n=new o.default([...(0,l.default)(t.properties),...(0,l.default)(e.properties)]);p.properties={};for(const r of n){const n=t.properties[r]||{},s=e.properties[r]||{};var f;if(n.readOnly&&!a.includeReadOnly||n.writeOnly&&!a.includeWriteOnly)p.required=(0,i.default)(f=p.required||[]).call(f,(e=>e!==r));else p.properties[r]=d(s,n,a)}}return(0,u.isJSONSchema)(t.items)&&(0,u.isJSONSchema)(e.items)&&(p.items=d(e.items,t.items,a)),(0,u.isJSONSchema)(t.contains)&&(0,u.isJSONSchema)(e.contains)&&(p.contains=d(e.contains,t.contains,a)),(0,u.isJSONSchema)(t.contentSchema)&&(0,u.isJSONSchema)(e.contentSchema)&&(p.contentSchema=d(e.contentSchema,t.contentSchema,a)),p},p=d},3084:(e,t,a)=>{a.r(t),a.d(t,{isBooleanJSONSchema:()=>r,isJSONSchema:()=>o,isJSONSchemaObject:()=>s});var n=a(8646);const r=e=>"boolean"==typeof e,s=e=>(0,n.default)(e),o=e=>r(e)||s(e)},5202:(e,t,a)=>{a.r(t),a.d(t,{bytes:()=>o,integer:()=>d,number:()=>u,pick:()=>i,randexp:()=>l,string:()=>c});var n=a(1798),r=a.n(n),s=a(2092);const o=e=>r()(e),l=e=>{try{return new s.default(e).gen()}catch{return"string"}},i=e=>e.at(0),c=()=>"string",u=()=>0,d=()=>0},6276:(e,t,a)=>{a.r(t),a.d(t,{foldType:()=>y,getType:()=>E,inferType:()=>v});var n=a(4163),r=a(1772),s=a(2372),o=a(3284),l=a(2740),i=a(2565),c=a(6145),u=a(8338),d=a(3084),p=a(5202),m=a(3783);const f={array:["items","prefixItems","contains","maxContains","minContains","maxItems","minItems","uniqueItems","unevaluatedItems"],object:["properties","additionalProperties","patternProperties","propertyNames","minProperties","maxProperties","required","dependentSchemas","dependentRequired","unevaluatedProperties"],string:["pattern","format","minLength","maxLength","contentEncoding","contentMediaType","contentSchema"],integer:["minimum","maximum","exclusiveMinimum","exclusiveMaximum","multipleOf"]};f.number=f.integer;const h="string",g=e=>void 0===e?null:null===e?"null":(0,n.default)(e)?"array":(0,r.default)(e)?"integer":typeof e,y=e=>{if((0,n.default)(e)&&e.length>=1){if((0,s.default)(e).call(e,"array"))return"array";if((0,s.default)(e).call(e,"object"))return"object";{const t=(0,p.pick)(e);if((0,s.default)(u.ALL_TYPES).call(u.ALL_TYPES,t))return t}}return(0,s.default)(u.ALL_TYPES).call(u.ALL_TYPES,e)?e:null},v=function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:new o.default;if(!(0,d.isJSONSchemaObject)(e))return h;if(t.has(e))return h;t.add(e);let{type:a,const:r}=e;if(a=y(a),"string"!=typeof a){const t=(0,l.default)(f);e:for(let n=0;n<t.length;n+=1){const r=t[n],s=f[r];for(let t=0;t<s.length;t+=1){const n=s[t];if(Object.hasOwn(e,n)){a=r;break e}}}}if("string"!=typeof a&&void 0!==r){const e=g(r);a="string"==typeof e?e:a}if("string"!=typeof a){const r=a=>{if((0,n.default)(e[a])){var r;const n=(0,i.default)(r=e[a]).call(r,(e=>v(e,t)));return y(n)}return null},o=r("allOf"),l=r("anyOf"),u=r("oneOf"),d=e.not?v(e.not,t):null;var s;if(o||l||u||d)a=y((0,c.default)(s=[o,l,u,d]).call(s,Boolean))}if("string"!=typeof a&&(0,m.hasExample)(e)){const t=(0,m.extractExample)(e),n=g(t);a="string"==typeof n?n:a}return t.delete(e),a||h},E=e=>v(e)},9346:(e,t,a)=>{a.r(t),a.d(t,{fromJSONBooleanSchema:()=>r,typeCast:()=>s});var n=a(3084);const r=e=>!1===e?{not:{}}:{},s=e=>(0,n.isBooleanJSONSchema)(e)?r(e):(0,n.isJSONSchemaObject)(e)?e:{}},1433:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(8764).Buffer;const r=e=>n.from(e).toString("ascii")},8509:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(8764).Buffer;const r=e=>n.from(e).toString("utf8")},5709:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(8764).Buffer;const r=e=>n.from(e).toString("hex")},4180:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(8764).Buffer;const r=e=>{const t=n.from(e).toString("utf8"),a="ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";let r=0,s="",o=0,l=0;for(let e=0;e<t.length;e++)for(o=o<<8|t.charCodeAt(e),l+=8;l>=5;)s+=a.charAt(o>>>l-5&31),l-=5;l>0&&(s+=a.charAt(o<<5-l&31),r=(8-8*t.length%5)%5);for(let e=0;e<r;e++)s+="=";return s}},1967:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(8764).Buffer;const r=e=>n.from(e).toString("base64")},4366:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(8764).Buffer;const r=e=>n.from(e).toString("binary")},5037:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(8136);const r=e=>{let t="";for(let s=0;s<e.length;s++){const o=e.charCodeAt(s);if(61===o)t+="=3D";else if(o>=33&&o<=60||o>=62&&o<=126||9===o||32===o)t+=e.charAt(s);else if(13===o||10===o)t+="\r\n";else if(o>126){const r=unescape(encodeURIComponent(e.charAt(s)));for(let e=0;e<r.length;e++){var a;t+="="+(0,n.default)(a="0"+r.charCodeAt(e).toString(16)).call(a,-2).toUpperCase()}}else{var r;t+="="+(0,n.default)(r="0"+o.toString(16)).call(r,-2).toUpperCase()}}return t}},4045:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>(new Date).toISOString()},1456:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>(new Date).toISOString().substring(0,10)},560:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>.1},4299:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"P3D"},3981:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"user@example.com"},1890:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>.1},9375:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"example.com"},4518:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"실례@example.com"},273:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"실례.com"},7864:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>2**30>>>0},1726:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>2**53-1},8793:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"198.51.100.42"},8269:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"2001:0db8:5b96:0000:0000:426f:8e17:642a"},5693:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"path/실례.html"},3080:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"https://실례.com/"},7856:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"/a/b/c"},5652:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});const n=(e=>{var t={};return a.d(t,e),t})({default:()=>s.default});var r=a(5202);const o={"application/json":()=>'{"key":"value"}',"application/ld+json":()=>'{"name": "John Doe"}',"application/x-httpd-php":()=>"<?php echo '<p>Hello World!</p>'; ?>","application/rtf":()=>n.default`{\rtf1\adeflang1025\ansi\ansicpg1252\uc1`,"application/x-sh":()=>'echo "Hello World!"',"application/xhtml+xml":()=>"<p>content</p>","application/*":()=>(0,r.bytes)(25).toString("binary")}},4342:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(5202);const r={"audio/*":()=>(0,n.bytes)(25).toString("binary")}},6724:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(5202);const r={"image/*":()=>(0,n.bytes)(25).toString("binary")}},5378:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n={"text/plain":()=>"string","text/css":()=>".selector { border: 1px solid red }","text/csv":()=>"value1,value2,value3","text/html":()=>"<p>content</p>","text/calendar":()=>"BEGIN:VCALENDAR","text/javascript":()=>"console.dir('Hello world!');","text/xml":()=>'<person age="30">John Doe</person>',"text/*":()=>"string"}},2974:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(5202);const r={"video/*":()=>(0,n.bytes)(25).toString("binary")}},3393:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"********"},4335:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"^[a-z]+$"},375:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"1/0"},5243:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>(new Date).toISOString().substring(11)},4692:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"path/index.html"},3829:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"https://example.com/dictionary/{term:1}/{term}"},2978:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"https://example.com/"},8859:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>"3fa85f64-5717-4562-b3fc-2c963f66afa6"},8591:(e,t,a)=>{a.r(t),a.d(t,{createXMLExample:()=>n.createXMLExample,encoderAPI:()=>r.default,formatAPI:()=>s.default,mediaTypeAPI:()=>o.default,memoizedCreateXMLExample:()=>n.memoizedCreateXMLExample,memoizedSampleFromSchema:()=>n.memoizedSampleFromSchema,sampleFromSchema:()=>n.sampleFromSchema,sampleFromSchemaGeneric:()=>n.sampleFromSchemaGeneric});var n=a(4277),r=a(9507),s=a(2906),o=a(537)},4277:(e,t,a)=>{a.r(t),a.d(t,{createXMLExample:()=>C,memoizedCreateXMLExample:()=>O,memoizedSampleFromSchema:()=>k,sampleFromSchema:()=>j,sampleFromSchemaGeneric:()=>_});var n=a(4163),r=a(1772),s=a(29),o=a(1778),l=a(2372),i=a(5171),c=a(2565),u=a(313),d=a(3479),p=a.n(d),m=a(8747),f=a(8646),h=a(6561),g=a(9669),y=a(3273),v=a(6276),E=a(9346),S=a(3783),w=a(5202),b=a(7078),x=a(3084);const _=function(e){var t;let a=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{},u=arguments.length>2&&void 0!==arguments[2]?arguments[2]:void 0,d=arguments.length>3&&void 0!==arguments[3]&&arguments[3];"function"==typeof(null===(t=e)||void 0===t?void 0:t.toJS)&&(e=e.toJS()),e=(0,E.typeCast)(e);let p=void 0!==u||(0,S.hasExample)(e);const g=!p&&(0,n.default)(e.oneOf)&&e.oneOf.length>0,C=!p&&(0,n.default)(e.anyOf)&&e.anyOf.length>0;if(!p&&(g||C)){const t=(0,E.typeCast)(g?(0,w.pick)(e.oneOf):(0,w.pick)(e.anyOf));!(e=(0,b.default)(e,t,a)).xml&&t.xml&&(e.xml=t.xml),(0,S.hasExample)(e)&&(0,S.hasExample)(t)&&(p=!0)}const j={};let{xml:N,properties:O,additionalProperties:k,items:A,contains:I}=e||{},R=(0,v.getType)(e),{includeReadOnly:P,includeWriteOnly:T}=a;N=N||{};let M,{name:D,prefix:L,namespace:q}=N,B={};if(Object.hasOwn(e,"type")||(e.type=R),d&&(D=D||"notagname",M=(L?`${L}:`:"")+D,q)){j[L?`xmlns:${L}`:"xmlns"]=q}d&&(B[M]=[]);const U=(0,h.mz)(O);let J,$=0;const V=()=>(0,r.default)(e.maxProperties)&&e.maxProperties>0&&$>=e.maxProperties,K=t=>!((0,r.default)(e.maxProperties)&&e.maxProperties>0)||!V()&&(!(t=>{var a;return!(0,n.default)(e.required)||0===e.required.length||!(0,l.default)(a=e.required).call(a,t)})(t)||e.maxProperties-$-(()=>{if(!(0,n.default)(e.required)||0===e.required.length)return 0;let t=0;var a,r;return d?(0,s.default)(a=e.required).call(a,(e=>t+=void 0===B[e]?0:1)):(0,s.default)(r=e.required).call(r,(e=>{var a;t+=void 0===(null===(a=B[M])||void 0===a?void 0:(0,o.default)(a).call(a,(t=>void 0!==t[e])))?0:1})),e.required.length-t})()>0);if(J=d?function(t){let r=arguments.length>1&&void 0!==arguments[1]?arguments[1]:void 0;if(e&&U[t]){if(U[t].xml=U[t].xml||{},U[t].xml.attribute){const e=(0,n.default)(U[t].enum)?(0,w.pick)(U[t].enum):void 0;if((0,S.hasExample)(U[t]))j[U[t].xml.name||t]=(0,S.extractExample)(U[t]);else if(void 0!==e)j[U[t].xml.name||t]=e;else{const e=(0,E.typeCast)(U[t]),a=(0,v.getType)(e),n=U[t].xml.name||t;j[n]=y.default[a](e)}return}U[t].xml.name=U[t].xml.name||t}else U[t]||!1===k||(U[t]={xml:{name:t}});let s=_(U[t],a,r,d);var o;K(t)&&($++,(0,n.default)(s)?B[M]=(0,i.default)(o=B[M]).call(o,s):B[M].push(s))}:(t,n)=>{var r;if(K(t)){if((0,f.default)(null===(r=e.discriminator)||void 0===r?void 0:r.mapping)&&e.discriminator.propertyName===t&&"string"==typeof e.$$ref){for(const a in e.discriminator.mapping)if(-1!==e.$$ref.search(e.discriminator.mapping[a])){B[t]=a;break}}else B[t]=_(U[t],a,n,d);$++}},p){let t;if(t=void 0!==u?u:(0,S.extractExample)(e),!d){if("number"==typeof t&&"string"===R)return`${t}`;if("string"!=typeof t||"string"===R)return t;try{return JSON.parse(t)}catch{return t}}if("array"===R){if(!(0,n.default)(t)){if("string"==typeof t)return t;t=[t]}let r=[];return(0,x.isJSONSchemaObject)(A)&&(A.xml=A.xml||N||{},A.xml.name=A.xml.name||N.name,r=(0,c.default)(t).call(t,(e=>_(A,a,e,d)))),(0,x.isJSONSchemaObject)(I)&&(I.xml=I.xml||N||{},I.xml.name=I.xml.name||N.name,r=[_(I,a,void 0,d),...r]),r=y.default.array(e,{sample:r}),N.wrapped?(B[M]=r,(0,m.default)(j)||B[M].push({_attr:j})):B=r,B}if("object"===R){if("string"==typeof t)return t;for(const e in t){var F,z,W,H;Object.hasOwn(t,e)&&(null!==(F=U[e])&&void 0!==F&&F.readOnly&&!P||null!==(z=U[e])&&void 0!==z&&z.writeOnly&&!T||(null!==(W=U[e])&&void 0!==W&&null!==(H=W.xml)&&void 0!==H&&H.attribute?j[U[e].xml.name||e]=t[e]:J(e,t[e])))}return(0,m.default)(j)||B[M].push({_attr:j}),B}return B[M]=(0,m.default)(j)?t:[{_attr:j},t],B}if("array"===R){let t=[];var G,Z;if((0,x.isJSONSchemaObject)(I))if(d&&(I.xml=I.xml||e.xml||{},I.xml.name=I.xml.name||N.name),(0,n.default)(I.anyOf))t.push(...(0,c.default)(G=I.anyOf).call(G,(e=>_((0,b.default)(e,I,a),a,void 0,d))));else if((0,n.default)(I.oneOf)){var Y;t.push(...(0,c.default)(Y=I.oneOf).call(Y,(e=>_((0,b.default)(e,I,a),a,void 0,d))))}else{if(!(!d||d&&N.wrapped))return _(I,a,void 0,d);t.push(_(I,a,void 0,d))}if((0,x.isJSONSchemaObject)(A))if(d&&(A.xml=A.xml||e.xml||{},A.xml.name=A.xml.name||N.name),(0,n.default)(A.anyOf))t.push(...(0,c.default)(Z=A.anyOf).call(Z,(e=>_((0,b.default)(e,A,a),a,void 0,d))));else if((0,n.default)(A.oneOf)){var X;t.push(...(0,c.default)(X=A.oneOf).call(X,(e=>_((0,b.default)(e,A,a),a,void 0,d))))}else{if(!(!d||d&&N.wrapped))return _(A,a,void 0,d);t.push(_(A,a,void 0,d))}return t=y.default.array(e,{sample:t}),d&&N.wrapped?(B[M]=t,(0,m.default)(j)||B[M].push({_attr:j}),B):t}if("object"===R){for(let e in U){var Q,ee,te;Object.hasOwn(U,e)&&(null!==(Q=U[e])&&void 0!==Q&&Q.deprecated||null!==(ee=U[e])&&void 0!==ee&&ee.readOnly&&!P||null!==(te=U[e])&&void 0!==te&&te.writeOnly&&!T||J(e))}if(d&&j&&B[M].push({_attr:j}),V())return B;if((0,x.isBooleanJSONSchema)(k)&&k)d?B[M].push({additionalProp:"Anything can be here"}):B.additionalProp1={},$++;else if((0,x.isJSONSchemaObject)(k)){var ae,ne;const t=k,n=_(t,a,void 0,d);if(d&&"string"==typeof(null==t||null===(ae=t.xml)||void 0===ae?void 0:ae.name)&&"notagname"!==(null==t||null===(ne=t.xml)||void 0===ne?void 0:ne.name))B[M].push(n);else{const t=(0,r.default)(e.minProperties)&&e.minProperties>0&&$<e.minProperties?e.minProperties-$:3;for(let e=1;e<=t;e++){if(V())return B;if(d){const t={};t["additionalProp"+e]=n.notagname,B[M].push(t)}else B["additionalProp"+e]=n;$++}}}return B}let re;if(void 0!==e.const)re=e.const;else if(e&&(0,n.default)(e.enum))re=(0,w.pick)((0,h.AF)(e.enum));else{const t=(0,x.isJSONSchemaObject)(e.contentSchema)?_(e.contentSchema,a,void 0,d):void 0;re=y.default[R](e,{sample:t})}return d?(B[M]=(0,m.default)(j)?re:[{_attr:j},re],B):re},C=(e,t,a)=>{const n=_(e,t,a,!0);if(n)return"string"==typeof n?n:p()(n,{declaration:!0,indent:"\t"})},j=(e,t,a)=>_(e,t,a,!1),N=(e,t,a)=>[e,(0,u.default)(t),(0,u.default)(a)],O=(0,g.Z)(C,N),k=(0,g.Z)(j,N)},8262:(e,t,a)=>{a.r(t),a.d(t,{applyArrayConstraints:()=>l,default:()=>i});var n=a(1772),r=a(8136),s=a(8898),o=a(527);const l=function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};const{minItems:a,maxItems:l,uniqueItems:i}=t,{contains:c,minContains:u,maxContains:d}=t;let p=[...e];if(null!=c&&"object"==typeof c){if((0,n.default)(u)&&u>1){const e=p.at(0);for(let t=1;t<u;t+=1)p.unshift(e)}(0,n.default)(d)}if((0,n.default)(l)&&l>0&&(p=(0,r.default)(e).call(e,0,l)),(0,n.default)(a)&&a>0)for(let e=0;p.length<a;e+=1)p.push(p[e%p.length]);return!0===i&&(p=(0,s.default)(new o.default(p))),p},i=(e,t)=>{let{sample:a}=t;return l(a,e)}},4108:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=e=>"boolean"!=typeof e.default||e.default},3273:(e,t,a)=>{a.r(t),a.d(t,{default:()=>d});var n=a(8262),r=a(6852),s=a(4522),o=a(7742),l=a(8864),i=a(4108),c=a(853);const u={array:n.default,object:r.default,string:s.default,number:o.default,integer:l.default,boolean:i.default,null:c.default},d=new Proxy(u,{get:(e,t)=>"string"==typeof t&&Object.hasOwn(e,t)?e[t]:()=>`Unknown Type: ${t}`})},8864:(e,t,a)=>{a.r(t),a.d(t,{default:()=>l});var n=a(5202),r=a(2906),s=a(7864),o=a(1726);const l=e=>{const{format:t}=e;return"string"==typeof t?(e=>{const{format:t}=e,a=(0,r.default)(t);if("function"==typeof a)return a(e);switch(t){case"int32":return(0,s.default)();case"int64":return(0,o.default)()}return(0,n.integer)()})(e):(0,n.integer)()}},853:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>null},7742:(e,t,a)=>{a.r(t),a.d(t,{default:()=>u});var n=a(1772);const r=(e=>{var t={};return a.d(t,e),t})({default:()=>o.default});var s=a(5202),l=a(2906),i=a(1890),c=a(560);const u=e=>{const{format:t}=e;let a;return a="string"==typeof t?(e=>{const{format:t}=e,a=(0,l.default)(t);if("function"==typeof a)return a(e);switch(t){case"float":return(0,i.default)();case"double":return(0,c.default)()}return(0,s.number)()})(e):(0,s.number)(),function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};const{minimum:a,maximum:s,exclusiveMinimum:o,exclusiveMaximum:l}=t,{multipleOf:i}=t,c=(0,n.default)(e)?1:r.default;let u="number"==typeof a?a:null,d="number"==typeof s?s:null,p=e;if("number"==typeof o&&(u=null!==u?Math.max(u,o+c):o+c),"number"==typeof l&&(d=null!==d?Math.min(d,l-c):l-c),p=u>d&&e||u||d||p,"number"==typeof i&&i>0){const e=p%i;p=0===e?p:p+i-e}return p}(a,e)}},6852:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=()=>{throw new Error("Not implemented")}},4522:(e,t,a)=>{a.r(t),a.d(t,{default:()=>P});var n=a(1772),r=a(8136),s=a(4163),o=a(313),l=a(1252),i=a(5202),c=a(3084),u=a(3981),d=a(4518),p=a(9375),m=a(273),f=a(8793),h=a(8269),g=a(2978),y=a(4692),v=a(3080),E=a(5693),S=a(8859),w=a(3829),b=a(7856),x=a(375),_=a(4045),C=a(1456),j=a(5243),N=a(4299),O=a(3393),k=a(4335),A=a(2906),I=a(9507),R=a(537);const P=function(e){let{sample:t}=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};const{contentEncoding:a,contentMediaType:P,contentSchema:T}=e,{pattern:M,format:D}=e,L=(0,I.default)(a)||l.default;let q;if("string"==typeof M)q=(0,i.randexp)(M);else if("string"==typeof D)q=(e=>{const{format:t}=e,a=(0,A.default)(t);if("function"==typeof a)return a(e);switch(t){case"email":return(0,u.default)();case"idn-email":return(0,d.default)();case"hostname":return(0,p.default)();case"idn-hostname":return(0,m.default)();case"ipv4":return(0,f.default)();case"ipv6":return(0,h.default)();case"uri":return(0,g.default)();case"uri-reference":return(0,y.default)();case"iri":return(0,v.default)();case"iri-reference":return(0,E.default)();case"uuid":return(0,S.default)();case"uri-template":return(0,w.default)();case"json-pointer":return(0,b.default)();case"relative-json-pointer":return(0,x.default)();case"date-time":return(0,_.default)();case"date":return(0,C.default)();case"time":return(0,j.default)();case"duration":return(0,N.default)();case"password":return(0,O.default)();case"regex":return(0,k.default)()}return(0,i.string)()})(e);else if((0,c.isJSONSchema)(T)&&"string"==typeof P&&void 0!==t)q=(0,s.default)(t)||"object"==typeof t?(0,o.default)(t):String(t);else if("string"==typeof P){const t=(0,R.default)(P);"function"==typeof t&&(q=t(e))}else q=(0,i.string)();return L(function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};const{maxLength:a,minLength:s}=t;let o=e;if((0,n.default)(a)&&a>0&&(o=(0,r.default)(o).call(o,0,a)),(0,n.default)(s)&&s>0){let e=0;for(;o.length<s;)o+=o[e++%o.length]}return o}(q,e))}},5474:(e,t,a)=>{a.r(t),a.d(t,{SHOW:()=>l,UPDATE_FILTER:()=>s,UPDATE_LAYOUT:()=>r,UPDATE_MODE:()=>o,changeMode:()=>d,show:()=>u,updateFilter:()=>c,updateLayout:()=>i});var n=a(6561);const r="layout_update_layout",s="layout_update_filter",o="layout_update_mode",l="layout_show";function i(e){return{type:r,payload:e}}function c(e){return{type:s,payload:e}}function u(e){let t=!(arguments.length>1&&void 0!==arguments[1])||arguments[1];return e=(0,n.AF)(e),{type:l,payload:{thing:e,shown:t}}}function d(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:"";return e=(0,n.AF)(e),{type:o,payload:{thing:e,mode:t}}}},6821:(e,t,a)=>{a.r(t),a.d(t,{default:()=>l});var n=a(5672),r=a(5474),s=a(4400),o=a(8989);function l(){return{statePlugins:{layout:{reducers:n.default,actions:r,selectors:s},spec:{wrapSelectors:o}}}}},5672:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(5171),r=a(9725),s=a(5474);const o={[s.UPDATE_LAYOUT]:(e,t)=>e.set("layout",t.payload),[s.UPDATE_FILTER]:(e,t)=>e.set("filter",t.payload),[s.SHOW]:(e,t)=>{const a=t.payload.shown,n=(0,r.fromJS)(t.payload.thing);return e.update("shown",(0,r.fromJS)({}),(e=>e.set(n,a)))},[s.UPDATE_MODE]:(e,t)=>{var a;let r=t.payload.thing,s=t.payload.mode;return e.setIn((0,n.default)(a=["modes"]).call(a,r),(s||"")+"")}}},4400:(e,t,a)=>{a.r(t),a.d(t,{current:()=>o,currentFilter:()=>l,isShown:()=>i,showSummary:()=>u,whatMode:()=>c});var n=a(8639),r=a(6561),s=a(9725);const o=e=>e.get("layout"),l=e=>e.get("filter"),i=(e,t,a)=>(t=(0,r.AF)(t),e.get("shown",(0,s.fromJS)({})).get((0,s.fromJS)(t),a)),c=function(e,t){let a=arguments.length>2&&void 0!==arguments[2]?arguments[2]:"";return t=(0,r.AF)(t),e.getIn(["modes",...t],a)},u=(0,n.createSelector)((e=>e),(e=>!i(e,"editor")))},8989:(e,t,a)=>{a.r(t),a.d(t,{taggedOperations:()=>r});var n=a(8136);const r=(e,t)=>function(a){for(var r=arguments.length,s=new Array(r>1?r-1:0),o=1;o<r;o++)s[o-1]=arguments[o];let l=e(a,...s);const{fn:i,layoutSelectors:c,getConfigs:u}=t.getSystem(),d=u(),{maxDisplayedTags:p}=d;let m=c.currentFilter();return m&&!0!==m&&"true"!==m&&"false"!==m&&(l=i.opsFilter(l,m)),p&&!isNaN(p)&&p>=0&&(l=(0,n.default)(l).call(l,0,p)),l}},9150:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(5527);function r(e){let{configs:t}=e;const a={debug:0,info:1,log:2,warn:3,error:4},r=e=>a[e]||-1;let{logLevel:s}=t,o=r(s);function l(e){for(var t=arguments.length,a=new Array(t>1?t-1:0),n=1;n<t;n++)a[n-1]=arguments[n];r(e)>=o&&console[e](...a)}return l.warn=(0,n.default)(l).call(l,null,"warn"),l.error=(0,n.default)(l).call(l,null,"error"),l.info=(0,n.default)(l).call(l,null,"info"),l.debug=(0,n.default)(l).call(l,null,"debug"),{rootInjects:{log:l}}}},7002:(e,t,a)=>{a.r(t),a.d(t,{CLEAR_REQUEST_BODY_VALIDATE_ERROR:()=>p,CLEAR_REQUEST_BODY_VALUE:()=>m,SET_REQUEST_BODY_VALIDATE_ERROR:()=>d,UPDATE_ACTIVE_EXAMPLES_MEMBER:()=>l,UPDATE_REQUEST_BODY_INCLUSION:()=>o,UPDATE_REQUEST_BODY_VALUE:()=>r,UPDATE_REQUEST_BODY_VALUE_RETAIN_FLAG:()=>s,UPDATE_REQUEST_CONTENT_TYPE:()=>i,UPDATE_RESPONSE_CONTENT_TYPE:()=>c,UPDATE_SELECTED_SERVER:()=>n,UPDATE_SERVER_VARIABLE_VALUE:()=>u,clearRequestBodyValidateError:()=>x,clearRequestBodyValue:()=>C,initRequestBodyValidateError:()=>_,setActiveExamplesMember:()=>v,setRequestBodyInclusion:()=>y,setRequestBodyValidateError:()=>b,setRequestBodyValue:()=>h,setRequestContentType:()=>E,setResponseContentType:()=>S,setRetainRequestBodyValueFlag:()=>g,setSelectedServer:()=>f,setServerVariableValue:()=>w});const n="oas3_set_servers",r="oas3_set_request_body_value",s="oas3_set_request_body_retain_flag",o="oas3_set_request_body_inclusion",l="oas3_set_active_examples_member",i="oas3_set_request_content_type",c="oas3_set_response_content_type",u="oas3_set_server_variable_value",d="oas3_set_request_body_validate_error",p="oas3_clear_request_body_validate_error",m="oas3_clear_request_body_value";function f(e,t){return{type:n,payload:{selectedServerUrl:e,namespace:t}}}function h(e){let{value:t,pathMethod:a}=e;return{type:r,payload:{value:t,pathMethod:a}}}const g=e=>{let{value:t,pathMethod:a}=e;return{type:s,payload:{value:t,pathMethod:a}}};function y(e){let{value:t,pathMethod:a,name:n}=e;return{type:o,payload:{value:t,pathMethod:a,name:n}}}function v(e){let{name:t,pathMethod:a,contextType:n,contextName:r}=e;return{type:l,payload:{name:t,pathMethod:a,contextType:n,contextName:r}}}function E(e){let{value:t,pathMethod:a}=e;return{type:i,payload:{value:t,pathMethod:a}}}function S(e){let{value:t,path:a,method:n}=e;return{type:c,payload:{value:t,path:a,method:n}}}function w(e){let{server:t,namespace:a,key:n,val:r}=e;return{type:u,payload:{server:t,namespace:a,key:n,val:r}}}const b=e=>{let{path:t,method:a,validationErrors:n}=e;return{type:d,payload:{path:t,method:a,validationErrors:n}}},x=e=>{let{path:t,method:a}=e;return{type:p,payload:{path:t,method:a}}},_=e=>{let{pathMethod:t}=e;return{type:p,payload:{path:t[0],method:t[1]}}},C=e=>{let{pathMethod:t}=e;return{type:m,payload:{pathMethod:t}}}},3723:(e,t,a)=>{a.r(t),a.d(t,{definitionsToAuthorize:()=>i});var n=a(29),r=a(6145),s=a(6785),o=a(8639),l=a(9725);const i=(c=(0,o.createSelector)((e=>e),(e=>{let{specSelectors:t}=e;return t.securityDefinitions()}),((e,t)=>{var a;let o=(0,l.List)();return t?((0,n.default)(a=t.entrySeq()).call(a,(e=>{let[t,a]=e;const i=a.get("type");var c;if("oauth2"===i&&(0,n.default)(c=a.get("flows").entrySeq()).call(c,(e=>{let[n,s]=e,i=(0,l.fromJS)({flow:n,authorizationUrl:s.get("authorizationUrl"),tokenUrl:s.get("tokenUrl"),scopes:s.get("scopes"),type:a.get("type"),description:a.get("description")});o=o.push(new l.Map({[t]:(0,r.default)(i).call(i,(e=>void 0!==e))}))})),"http"!==i&&"apiKey"!==i||(o=o.push(new l.Map({[t]:a}))),"openIdConnect"===i&&a.get("openIdConnectData")){let e=a.get("openIdConnectData"),i=e.get("grant_types_supported")||["authorization_code","implicit"];(0,n.default)(i).call(i,(n=>{var i;let c=e.get("scopes_supported")&&(0,s.default)(i=e.get("scopes_supported")).call(i,((e,t)=>e.set(t,"")),new l.Map),u=(0,l.fromJS)({flow:n,authorizationUrl:e.get("authorization_endpoint"),tokenUrl:e.get("token_endpoint"),scopes:c,type:"oauth2",openIdConnectUrl:a.get("openIdConnectUrl")});o=o.push(new l.Map({[t]:(0,r.default)(u).call(u,(e=>void 0!==e))}))}))}})),o):o})),(e,t)=>function(){for(var a=arguments.length,n=new Array(a),r=0;r<a;r++)n[r]=arguments[r];if(t.getSystem().specSelectors.isOAS3()){let e=t.getState().getIn(["spec","resolvedSubtrees","components","securitySchemes"]);return c(t,e,...n)}return e(...n)});var c},3427:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(2740),r=a(2565),s=a(810);a(5053),a(9569);const o=e=>{let{callbacks:t,specPath:a,specSelectors:o,getComponent:l}=e;const i=o.callbacksOperations({callbacks:t,specPath:a}),c=(0,n.default)(i),u=l("OperationContainer",!0);return 0===c.length?s.default.createElement("span",null,"No callbacks"):s.default.createElement("div",null,(0,r.default)(c).call(c,(e=>{var t;return s.default.createElement("div",{key:`${e}`},s.default.createElement("h2",null,e),(0,r.default)(t=i[e]).call(t,(t=>s.default.createElement(u,{key:`${e}-${t.path}-${t.method}`,op:t.operation,tag:"callbacks",method:t.method,path:t.path,specPath:t.specPath,allowTryItOut:!1}))))})))}},6775:(e,t,a)=>{a.r(t),a.d(t,{default:()=>i});var n=a(775),r=a(7512),s=a(6145),o=a(2565),l=a(810);a(5053);class i extends l.default.Component{constructor(e,t){super(e,t),(0,n.default)(this,"onChange",(e=>{let{onChange:t}=this.props,{value:a,name:n}=e.target,s=(0,r.default)({},this.state.value);n?s[n]=a:s=a,this.setState({value:s},(()=>t(this.state)))}));let{name:a,schema:s}=this.props,o=this.getValue();this.state={name:a,schema:s,value:o}}getValue(){let{name:e,authorized:t}=this.props;return t&&t.getIn([e,"value"])}render(){var e;let{schema:t,getComponent:a,errSelectors:n,name:r}=this.props;const i=a("Input"),c=a("Row"),u=a("Col"),d=a("authError"),p=a("Markdown",!0),m=a("JumpToPath",!0),f=(t.get("scheme")||"").toLowerCase();let h=this.getValue(),g=(0,s.default)(e=n.allErrors()).call(e,(e=>e.get("authId")===r));if("basic"===f){var y;let e=h?h.get("username"):null;return l.default.createElement("div",null,l.default.createElement("h4",null,l.default.createElement("code",null,r||t.get("name")),"  (http, Basic)",l.default.createElement(m,{path:["securityDefinitions",r]})),e&&l.default.createElement("h6",null,"Authorized"),l.default.createElement(c,null,l.default.createElement(p,{source:t.get("description")})),l.default.createElement(c,null,l.default.createElement("label",null,"Username:"),e?l.default.createElement("code",null," ",e," "):l.default.createElement(u,null,l.default.createElement(i,{type:"text",required:"required",name:"username","aria-label":"auth-basic-username",onChange:this.onChange,autoFocus:!0}))),l.default.createElement(c,null,l.default.createElement("label",null,"Password:"),e?l.default.createElement("code",null," ****** "):l.default.createElement(u,null,l.default.createElement(i,{autoComplete:"new-password",name:"password",type:"password","aria-label":"auth-basic-password",onChange:this.onChange}))),(0,o.default)(y=g.valueSeq()).call(y,((e,t)=>l.default.createElement(d,{error:e,key:t}))))}var v;return"bearer"===f?l.default.createElement("div",null,l.default.createElement("h4",null,l.default.createElement("code",null,r||t.get("name")),"  (http, Bearer)",l.default.createElement(m,{path:["securityDefinitions",r]})),h&&l.default.createElement("h6",null,"Authorized"),l.default.createElement(c,null,l.default.createElement(p,{source:t.get("description")})),l.default.createElement(c,null,l.default.createElement("label",null,"Value:"),h?l.default.createElement("code",null," ****** "):l.default.createElement(u,null,l.default.createElement(i,{type:"text","aria-label":"auth-bearer-value",onChange:this.onChange,autoFocus:!0}))),(0,o.default)(v=g.valueSeq()).call(v,((e,t)=>l.default.createElement(d,{error:e,key:t})))):l.default.createElement("div",null,l.default.createElement("em",null,l.default.createElement("b",null,r)," HTTP authentication: unsupported scheme ",`'${f}'`))}}},6467:(e,t,a)=>{a.r(t),a.d(t,{default:()=>d});var n=a(3427),r=a(2458),s=a(5757),o=a(6617),l=a(9928),i=a(5327),c=a(6775),u=a(6796);const d={Callbacks:n.default,HttpAuth:c.default,RequestBody:r.default,Servers:o.default,ServersContainer:l.default,RequestBodyEditor:i.default,OperationServers:u.default,operationLink:s.default}},5757:(e,t,a)=>{a.r(t),a.d(t,{default:()=>l});var n=a(313),r=a(2565),s=a(810);a(5053),a(9569);class o extends s.Component{render(){const{link:e,name:t,getComponent:a}=this.props,o=a("Markdown",!0);let l=e.get("operationId")||e.get("operationRef"),i=e.get("parameters")&&e.get("parameters").toJS(),c=e.get("description");return s.default.createElement("div",{className:"operation-link"},s.default.createElement("div",{className:"description"},s.default.createElement("b",null,s.default.createElement("code",null,t)),c?s.default.createElement(o,{source:c}):null),s.default.createElement("pre",null,"Operation `",l,"`",s.default.createElement("br",null),s.default.createElement("br",null),"Parameters ",function(e,t){var a;if("string"!=typeof t)return"";return(0,r.default)(a=t.split("\n")).call(a,((t,a)=>a>0?Array(e+1).join(" ")+t:t)).join("\n")}(0,(0,n.default)(i,null,2))||"{}",s.default.createElement("br",null)))}}const l=o},6796:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(775),r=a(810);a(5053),a(9569);class s extends r.default.Component{constructor(){super(...arguments),(0,n.default)(this,"setSelectedServer",(e=>{const{path:t,method:a}=this.props;return this.forceUpdate(),this.props.setSelectedServer(e,`${t}:${a}`)})),(0,n.default)(this,"setServerVariableValue",(e=>{const{path:t,method:a}=this.props;return this.forceUpdate(),this.props.setServerVariableValue({...e,namespace:`${t}:${a}`})})),(0,n.default)(this,"getSelectedServer",(()=>{const{path:e,method:t}=this.props;return this.props.getSelectedServer(`${e}:${t}`)})),(0,n.default)(this,"getServerVariable",((e,t)=>{const{path:a,method:n}=this.props;return this.props.getServerVariable({namespace:`${a}:${n}`,server:e},t)})),(0,n.default)(this,"getEffectiveServerValue",(e=>{const{path:t,method:a}=this.props;return this.props.getEffectiveServerValue({server:e,namespace:`${t}:${a}`})}))}render(){const{operationServers:e,pathServers:t,getComponent:a}=this.props;if(!e&&!t)return null;const n=a("Servers"),s=e||t,o=e?"operation":"path";return r.default.createElement("div",{className:"opblock-section operation-servers"},r.default.createElement("div",{className:"opblock-section-header"},r.default.createElement("div",{className:"tab-header"},r.default.createElement("h4",{className:"opblock-title"},"Servers"))),r.default.createElement("div",{className:"opblock-description-wrapper"},r.default.createElement("h4",{className:"message"},"These ",o,"-level options override the global server options."),r.default.createElement(n,{servers:s,currentServer:this.getSelectedServer(),setSelectedServer:this.setSelectedServer,setServerVariableValue:this.setServerVariableValue,getServerVariable:this.getServerVariable,getEffectiveServerValue:this.getEffectiveServerValue})))}}},5327:(e,t,a)=>{a.r(t),a.d(t,{default:()=>i});var n=a(775),r=a(810),s=(a(5053),a(8096)),o=a(6561);const l=Function.prototype;class i extends r.PureComponent{constructor(e,t){super(e,t),(0,n.default)(this,"applyDefaultValue",(e=>{const{onChange:t,defaultValue:a}=e||this.props;return this.setState({value:a}),t(a)})),(0,n.default)(this,"onChange",(e=>{this.props.onChange((0,o.Pz)(e))})),(0,n.default)(this,"onDomChange",(e=>{const t=e.target.value;this.setState({value:t},(()=>this.onChange(t)))})),this.state={value:(0,o.Pz)(e.value)||e.defaultValue},e.onChange(e.value)}UNSAFE_componentWillReceiveProps(e){this.props.value!==e.value&&e.value!==this.state.value&&this.setState({value:(0,o.Pz)(e.value)}),!e.value&&e.defaultValue&&this.state.value&&this.applyDefaultValue(e)}render(){let{getComponent:e,errors:t}=this.props,{value:a}=this.state,n=t.size>0;const o=e("TextArea");return r.default.createElement("div",{className:"body-param"},r.default.createElement(o,{className:(0,s.default)("body-param__text",{invalid:n}),title:t.size?t.join(", "):"",value:a,onChange:this.onDomChange}))}}(0,n.default)(i,"defaultProps",{onChange:l,userHasEditedBody:!1})},2458:(e,t,a)=>{a.r(t),a.d(t,{default:()=>p,getDefaultRequestBodyValue:()=>d});var n=a(2565),r=a(8818),s=a(2372),o=a(4163),l=a(810),i=(a(5053),a(9569),a(9725)),c=a(6561),u=a(2518);const d=(e,t,a,n)=>{const r=e.getIn(["content",t]),s=r.get("schema").toJS(),o=void 0!==r.get("examples"),l=r.get("example"),i=o?r.getIn(["examples",a,"value"]):l,u=n.getSampleSchema(s,t,{includeWriteOnly:!0},i);return(0,c.Pz)(u)},p=e=>{let{userHasEditedBody:t,requestBody:a,requestBodyValue:p,requestBodyInclusionSetting:m,requestBodyErrors:f,getComponent:h,getConfigs:g,specSelectors:y,fn:v,contentType:E,isExecute:S,specPath:w,onChange:b,onChangeIncludeEmpty:x,activeExamplesKey:_,updateActiveExamplesKey:C,setRetainRequestBodyValueFlag:j}=e;const N=e=>{b(e.target.files[0])},O=e=>{let t={key:e,shouldDispatchInit:!1,defaultValue:!0};return"no value"===m.get(e,"no value")&&(t.shouldDispatchInit=!0),t},k=h("Markdown",!0),A=h("modelExample"),I=h("RequestBodyEditor"),R=h("highlightCode"),P=h("ExamplesSelectValueRetainer"),T=h("Example"),M=h("ParameterIncludeEmpty"),{showCommonExtensions:D}=g(),L=a&&a.get("description")||null,q=a&&a.get("content")||new i.OrderedMap;E=E||q.keySeq().first()||"";const B=q.get(E,(0,i.OrderedMap)()),U=B.get("schema",(0,i.OrderedMap)()),J=B.get("examples",null),$=null==J?void 0:(0,n.default)(J).call(J,((e,t)=>{var n;const r=null===(n=e)||void 0===n?void 0:n.get("value",null);return r&&(e=e.set("value",d(a,E,t,v),r)),e}));if(f=i.List.isList(f)?f:(0,i.List)(),!B.size)return null;const V="object"===B.getIn(["schema","type"]),K="binary"===B.getIn(["schema","format"]),F="base64"===B.getIn(["schema","format"]);if("application/octet-stream"===E||0===(0,r.default)(E).call(E,"image/")||0===(0,r.default)(E).call(E,"audio/")||0===(0,r.default)(E).call(E,"video/")||K||F){const e=h("Input");return S?l.default.createElement(e,{type:"file",onChange:N}):l.default.createElement("i",null,"Example values are not available for ",l.default.createElement("code",null,E)," media types.")}if(V&&("application/x-www-form-urlencoded"===E||0===(0,r.default)(E).call(E,"multipart/"))&&U.get("properties",(0,i.OrderedMap)()).size>0){var z;const e=h("JsonSchemaForm"),t=h("ParameterExt"),a=U.get("properties",(0,i.OrderedMap)());return p=i.Map.isMap(p)?p:(0,i.OrderedMap)(),l.default.createElement("div",{className:"table-container"},L&&l.default.createElement(k,{source:L}),l.default.createElement("table",null,l.default.createElement("tbody",null,i.Map.isMap(a)&&(0,n.default)(z=a.entrySeq()).call(z,(a=>{var r,u;let[d,g]=a;if(g.get("readOnly"))return;let y=D?(0,c.po)(g):null;const E=(0,s.default)(r=U.get("required",(0,i.List)())).call(r,d),w=g.get("type"),_=g.get("format"),C=g.get("description"),j=p.getIn([d,"value"]),N=p.getIn([d,"errors"])||f,A=m.get(d)||!1,I=g.has("default")||g.has("example")||g.hasIn(["items","example"])||g.hasIn(["items","default"]),R=g.has("enum")&&(1===g.get("enum").size||E),P=I||R;let T="";"array"!==w||P||(T=[]),("object"===w||P)&&(T=v.getSampleSchema(g,!1,{includeWriteOnly:!0})),"string"!=typeof T&&"object"===w&&(T=(0,c.Pz)(T)),"string"==typeof T&&"array"===w&&(T=JSON.parse(T));const L="string"===w&&("binary"===_||"base64"===_);return l.default.createElement("tr",{key:d,className:"parameters","data-property-name":d},l.default.createElement("td",{className:"parameters-col_name"},l.default.createElement("div",{className:E?"parameter__name required":"parameter__name"},d,E?l.default.createElement("span",null," *"):null),l.default.createElement("div",{className:"parameter__type"},w,_&&l.default.createElement("span",{className:"prop-format"},"($",_,")"),D&&y.size?(0,n.default)(u=y.entrySeq()).call(u,(e=>{let[a,n]=e;return l.default.createElement(t,{key:`${a}-${n}`,xKey:a,xVal:n})})):null),l.default.createElement("div",{className:"parameter__deprecated"},g.get("deprecated")?"deprecated":null)),l.default.createElement("td",{className:"parameters-col_description"},l.default.createElement(k,{source:C}),S?l.default.createElement("div",null,l.default.createElement(e,{fn:v,dispatchInitialValue:!L,schema:g,description:d,getComponent:h,value:void 0===j?T:j,required:E,errors:N,onChange:e=>{b(e,[d])}}),E?null:l.default.createElement(M,{onChange:e=>x(d,e),isIncluded:A,isIncludedOptions:O(d),isDisabled:(0,o.default)(j)?0!==j.length:!(0,c.O2)(j)})):null))})))))}const W=d(a,E,_,v);let H=null;return(0,u.O)(W)&&(H="json"),l.default.createElement("div",null,L&&l.default.createElement(k,{source:L}),$?l.default.createElement(P,{userHasEditedBody:t,examples:$,currentKey:_,currentUserInputValue:p,onSelect:e=>{C(e)},updateValue:b,defaultToFirstExample:!0,getComponent:h,setRetainRequestBodyValueFlag:j}):null,S?l.default.createElement("div",null,l.default.createElement(I,{value:p,errors:f,defaultValue:W,onChange:b,getComponent:h})):l.default.createElement(A,{getComponent:h,getConfigs:g,specSelectors:y,expandDepth:1,isExecute:S,schema:B.get("schema"),specPath:w.push("content",E),example:l.default.createElement(R,{className:"body-param__example",getConfigs:g,language:H,value:(0,c.Pz)(p)||W}),includeWriteOnly:!0}),$?l.default.createElement(T,{example:$.get(_),getComponent:h,getConfigs:g}):null)}},9928:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(5053);class r extends n.default.Component{render(){const{specSelectors:e,oas3Selectors:t,oas3Actions:a,getComponent:r}=this.props,s=e.servers(),o=r("Servers");return s&&s.size?n.default.createElement("div",null,n.default.createElement("span",{className:"servers-title"},"Servers"),n.default.createElement(o,{servers:s,currentServer:t.selectedServer(),setSelectedServer:a.setSelectedServer,setServerVariableValue:a.setServerVariableValue,getServerVariable:t.serverVariableValue,getEffectiveServerValue:t.serverEffectiveValue})):null}}},6617:(e,t,a)=>{a.r(t),a.d(t,{default:()=>i});var n=a(775),r=a(1778),s=a(2565),o=a(810),l=a(9725);a(5053),a(9569);class i extends o.default.Component{constructor(){super(...arguments),(0,n.default)(this,"onServerChange",(e=>{this.setServer(e.target.value)})),(0,n.default)(this,"onServerVariableValueChange",(e=>{let{setServerVariableValue:t,currentServer:a}=this.props,n=e.target.getAttribute("data-variable"),r=e.target.value;"function"==typeof t&&t({server:a,key:n,val:r})})),(0,n.default)(this,"setServer",(e=>{let{setSelectedServer:t}=this.props;t(e)}))}componentDidMount(){var e;let{servers:t,currentServer:a}=this.props;a||this.setServer(null===(e=t.first())||void 0===e?void 0:e.get("url"))}UNSAFE_componentWillReceiveProps(e){let{servers:t,setServerVariableValue:a,getServerVariable:n}=e;if(this.props.currentServer!==e.currentServer||this.props.servers!==e.servers){var o;let i=(0,r.default)(t).call(t,(t=>t.get("url")===e.currentServer)),c=(0,r.default)(o=this.props.servers).call(o,(e=>e.get("url")===this.props.currentServer))||(0,l.OrderedMap)();if(!i)return this.setServer(t.first().get("url"));let u=c.get("variables")||(0,l.OrderedMap)(),d=((0,r.default)(u).call(u,(e=>e.get("default")))||(0,l.OrderedMap)()).get("default"),p=i.get("variables")||(0,l.OrderedMap)(),m=((0,r.default)(p).call(p,(e=>e.get("default")))||(0,l.OrderedMap)()).get("default");(0,s.default)(p).call(p,((t,r)=>{n(e.currentServer,r)&&d===m||a({server:e.currentServer,key:r,val:t.get("default")||""})}))}}render(){var e,t;let{servers:a,currentServer:n,getServerVariable:i,getEffectiveServerValue:c}=this.props,u=((0,r.default)(a).call(a,(e=>e.get("url")===n))||(0,l.OrderedMap)()).get("variables")||(0,l.OrderedMap)(),d=0!==u.size;return o.default.createElement("div",{className:"servers"},o.default.createElement("label",{htmlFor:"servers"},o.default.createElement("select",{onChange:this.onServerChange,value:n},(0,s.default)(e=a.valueSeq()).call(e,(e=>o.default.createElement("option",{value:e.get("url"),key:e.get("url")},e.get("url"),e.get("description")&&` - ${e.get("description")}`))).toArray())),d?o.default.createElement("div",null,o.default.createElement("div",{className:"computed-url"},"Computed URL:",o.default.createElement("code",null,c(n))),o.default.createElement("h4",null,"Server variables"),o.default.createElement("table",null,o.default.createElement("tbody",null,(0,s.default)(t=u.entrySeq()).call(t,(e=>{var t;let[a,r]=e;return o.default.createElement("tr",{key:a},o.default.createElement("td",null,a),o.default.createElement("td",null,r.get("enum")?o.default.createElement("select",{"data-variable":a,onChange:this.onServerVariableValueChange},(0,s.default)(t=r.get("enum")).call(t,(e=>o.default.createElement("option",{selected:e===i(n,a),key:e,value:e},e)))):o.default.createElement("input",{type:"text",value:i(n,a)||"",onChange:this.onServerVariableValueChange,"data-variable":a})))}))))):null)}}},7779:(e,t,a)=>{a.r(t),a.d(t,{OAS30ComponentWrapFactory:()=>i,OAS3ComponentWrapFactory:()=>l,isOAS30:()=>s,isSwagger2:()=>o});var n=a(863),r=a(810);function s(e){const t=e.get("openapi");return"string"==typeof t&&/^3\.0\.([0123])(?:-rc[012])?$/.test(t)}function o(e){const t=e.get("swagger");return"string"==typeof t&&"2.0"===t}function l(e){return(t,a)=>s=>{var o;return"function"==typeof(null===(o=a.specSelectors)||void 0===o?void 0:o.isOAS3)?a.specSelectors.isOAS3()?r.default.createElement(e,(0,n.default)({},s,a,{Ori:t})):r.default.createElement(t,s):(console.warn("OAS3 wrapper: couldn't get spec"),null)}}function i(e){return(t,a)=>s=>{var o;return"function"==typeof(null===(o=a.specSelectors)||void 0===o?void 0:o.isOAS30)?a.specSelectors.isOAS30()?r.default.createElement(e,(0,n.default)({},s,a,{Ori:t})):r.default.createElement(t,s):(console.warn("OAS30 wrapper: couldn't get spec"),null)}}},7451:(e,t,a)=>{a.r(t),a.d(t,{default:()=>d});var n=a(2044),r=a(3723),s=a(1741),o=a(6467),l=a(7761),i=a(7002),c=a(5065),u=a(2109);function d(){return{components:o.default,wrapComponents:l.default,statePlugins:{spec:{wrapSelectors:n,selectors:s},auth:{wrapSelectors:r},oas3:{actions:i,reducers:u.default,selectors:c}}}}},2109:(e,t,a)=>{a.r(t),a.d(t,{default:()=>i});var n=a(5487),r=a(29),s=a(6785),o=a(9725),l=a(7002);const i={[l.UPDATE_SELECTED_SERVER]:(e,t)=>{let{payload:{selectedServerUrl:a,namespace:n}}=t;const r=n?[n,"selectedServer"]:["selectedServer"];return e.setIn(r,a)},[l.UPDATE_REQUEST_BODY_VALUE]:(e,t)=>{let{payload:{value:a,pathMethod:s}}=t,[l,i]=s;if(!o.Map.isMap(a))return e.setIn(["requestData",l,i,"bodyValue"],a);let c,u=e.getIn(["requestData",l,i,"bodyValue"])||(0,o.Map)();o.Map.isMap(u)||(u=(0,o.Map)());const[...d]=(0,n.default)(a).call(a);return(0,r.default)(d).call(d,(e=>{let t=a.getIn([e]);u.has(e)&&o.Map.isMap(t)||(c=u.setIn([e,"value"],t))})),e.setIn(["requestData",l,i,"bodyValue"],c)},[l.UPDATE_REQUEST_BODY_VALUE_RETAIN_FLAG]:(e,t)=>{let{payload:{value:a,pathMethod:n}}=t,[r,s]=n;return e.setIn(["requestData",r,s,"retainBodyValue"],a)},[l.UPDATE_REQUEST_BODY_INCLUSION]:(e,t)=>{let{payload:{value:a,pathMethod:n,name:r}}=t,[s,o]=n;return e.setIn(["requestData",s,o,"bodyInclusion",r],a)},[l.UPDATE_ACTIVE_EXAMPLES_MEMBER]:(e,t)=>{let{payload:{name:a,pathMethod:n,contextType:r,contextName:s}}=t,[o,l]=n;return e.setIn(["examples",o,l,r,s,"activeExample"],a)},[l.UPDATE_REQUEST_CONTENT_TYPE]:(e,t)=>{let{payload:{value:a,pathMethod:n}}=t,[r,s]=n;return e.setIn(["requestData",r,s,"requestContentType"],a)},[l.UPDATE_RESPONSE_CONTENT_TYPE]:(e,t)=>{let{payload:{value:a,path:n,method:r}}=t;return e.setIn(["requestData",n,r,"responseContentType"],a)},[l.UPDATE_SERVER_VARIABLE_VALUE]:(e,t)=>{let{payload:{server:a,namespace:n,key:r,val:s}}=t;const o=n?[n,"serverVariableValues",a,r]:["serverVariableValues",a,r];return e.setIn(o,s)},[l.SET_REQUEST_BODY_VALIDATE_ERROR]:(e,t)=>{let{payload:{path:a,method:n,validationErrors:r}}=t,l=[];if(l.push("Required field is not provided"),r.missingBodyValue)return e.setIn(["requestData",a,n,"errors"],(0,o.fromJS)(l));if(r.missingRequiredKeys&&r.missingRequiredKeys.length>0){const{missingRequiredKeys:t}=r;return e.updateIn(["requestData",a,n,"bodyValue"],(0,o.fromJS)({}),(e=>(0,s.default)(t).call(t,((e,t)=>e.setIn([t,"errors"],(0,o.fromJS)(l))),e)))}return console.warn("unexpected result: SET_REQUEST_BODY_VALIDATE_ERROR"),e},[l.CLEAR_REQUEST_BODY_VALIDATE_ERROR]:(e,t)=>{let{payload:{path:a,method:r}}=t;const l=e.getIn(["requestData",a,r,"bodyValue"]);if(!o.Map.isMap(l))return e.setIn(["requestData",a,r,"errors"],(0,o.fromJS)([]));const[...i]=(0,n.default)(l).call(l);return i?e.updateIn(["requestData",a,r,"bodyValue"],(0,o.fromJS)({}),(e=>(0,s.default)(i).call(i,((e,t)=>e.setIn([t,"errors"],(0,o.fromJS)([]))),e))):e},[l.CLEAR_REQUEST_BODY_VALUE]:(e,t)=>{let{payload:{pathMethod:a}}=t,[n,r]=a;const s=e.getIn(["requestData",n,r,"bodyValue"]);return s?o.Map.isMap(s)?e.setIn(["requestData",n,r,"bodyValue"],(0,o.Map)()):e.setIn(["requestData",n,r,"bodyValue"],""):e}}},5065:(e,t,a)=>{a.r(t),a.d(t,{activeExamplesMember:()=>E,hasUserEditedBody:()=>g,requestBodyErrors:()=>v,requestBodyInclusionSetting:()=>y,requestBodyValue:()=>m,requestContentType:()=>S,responseContentType:()=>w,selectDefaultRequestBodyValue:()=>h,selectedServer:()=>p,serverEffectiveValue:()=>_,serverVariableValue:()=>b,serverVariables:()=>x,shouldRetainRequestBodyValue:()=>f,validOperationMethods:()=>O,validateBeforeExecute:()=>C,validateShallowRequired:()=>N});var n=a(2565),r=a(29),s=a(2740),o=a(8818),l=a(9725),i=a(8639),c=a(2458),u=a(6561);const d=e=>function(t){for(var a=arguments.length,n=new Array(a>1?a-1:0),r=1;r<a;r++)n[r-1]=arguments[r];return a=>{if(a.getSystem().specSelectors.isOAS3()){const r=e(t,...n);return"function"==typeof r?r(a):r}return null}};const p=d(((e,t)=>{const a=t?[t,"selectedServer"]:["selectedServer"];return e.getIn(a)||""})),m=d(((e,t,a)=>e.getIn(["requestData",t,a,"bodyValue"])||null)),f=d(((e,t,a)=>e.getIn(["requestData",t,a,"retainBodyValue"])||!1)),h=(e,t,a)=>e=>{const{oas3Selectors:n,specSelectors:r,fn:s}=e.getSystem();if(r.isOAS3()){const e=n.requestContentType(t,a);if(e)return(0,c.getDefaultRequestBodyValue)(r.specResolvedSubtree(["paths",t,a,"requestBody"]),e,n.activeExamplesMember(t,a,"requestBody","requestBody"),s)}return null},g=d(((e,t,a)=>e=>{const{oas3Selectors:n,specSelectors:r,fn:s}=e;let o=!1;const i=n.requestContentType(t,a);let d=n.requestBodyValue(t,a);const p=r.specResolvedSubtree(["paths",t,a,"requestBody"]);if(!p)return!1;if(l.Map.isMap(d)&&(d=(0,u.Pz)(d.mapEntries((e=>l.Map.isMap(e[1])?[e[0],e[1].get("value")]:e)).toJS())),l.List.isList(d)&&(d=(0,u.Pz)(d)),i){const e=(0,c.getDefaultRequestBodyValue)(p,i,n.activeExamplesMember(t,a,"requestBody","requestBody"),s);o=!!d&&d!==e}return o})),y=d(((e,t,a)=>e.getIn(["requestData",t,a,"bodyInclusion"])||(0,l.Map)())),v=d(((e,t,a)=>e.getIn(["requestData",t,a,"errors"])||null)),E=d(((e,t,a,n,r)=>e.getIn(["examples",t,a,n,r,"activeExample"])||null)),S=d(((e,t,a)=>e.getIn(["requestData",t,a,"requestContentType"])||null)),w=d(((e,t,a)=>e.getIn(["requestData",t,a,"responseContentType"])||null)),b=d(((e,t,a)=>{let n;if("string"!=typeof t){const{server:e,namespace:r}=t;n=r?[r,"serverVariableValues",e,a]:["serverVariableValues",e,a]}else{n=["serverVariableValues",t,a]}return e.getIn(n)||null})),x=d(((e,t)=>{let a;if("string"!=typeof t){const{server:e,namespace:n}=t;a=n?[n,"serverVariableValues",e]:["serverVariableValues",e]}else{a=["serverVariableValues",t]}return e.getIn(a)||(0,l.OrderedMap)()})),_=d(((e,t)=>{var a,r;if("string"!=typeof t){const{server:n,namespace:s}=t;r=n,a=s?e.getIn([s,"serverVariableValues",r]):e.getIn(["serverVariableValues",r])}else r=t,a=e.getIn(["serverVariableValues",r]);a=a||(0,l.OrderedMap)();let s=r;return(0,n.default)(a).call(a,((e,t)=>{s=s.replace(new RegExp(`{${t}}`,"g"),e)})),s})),C=(j=(e,t)=>((e,t)=>(t=t||[],!!e.getIn(["requestData",...t,"bodyValue"])))(e,t),function(){for(var e=arguments.length,t=new Array(e),a=0;a<e;a++)t[a]=arguments[a];return e=>{const a=e.getSystem().specSelectors.specJson();let n=[...t][1]||[];return!a.getIn(["paths",...n,"requestBody","required"])||j(...t)}});var j;const N=(e,t)=>{var a;let{oas3RequiredRequestBodyContentType:n,oas3RequestContentType:i,oas3RequestBodyValue:c}=t,u=[];if(!l.Map.isMap(c))return u;let d=[];return(0,r.default)(a=(0,s.default)(n.requestContentType)).call(a,(e=>{if(e===i){let t=n.requestContentType[e];(0,r.default)(t).call(t,(e=>{(0,o.default)(d).call(d,e)<0&&d.push(e)}))}})),(0,r.default)(d).call(d,(e=>{c.getIn([e,"value"])||u.push(e)})),u},O=(0,i.createSelector)((()=>["get","put","post","delete","options","head","patch","trace"]))},1741:(e,t,a)=>{a.r(t),a.d(t,{callbacksOperations:()=>g,isOAS3:()=>m,isOAS30:()=>p,isSwagger2:()=>d,servers:()=>h});var n=a(2565),r=a(6785),s=a(6145),o=a(2372),l=a(5171),i=a(9725),c=a(7779);const u=(0,i.Map)(),d=()=>e=>{const t=e.getSystem().specSelectors.specJson();return(0,c.isSwagger2)(t)},p=()=>e=>{const t=e.getSystem().specSelectors.specJson();return(0,c.isOAS30)(t)},m=()=>e=>e.getSystem().specSelectors.isOAS30();function f(e){return function(t){for(var a=arguments.length,n=new Array(a>1?a-1:0),r=1;r<a;r++)n[r-1]=arguments[r];return a=>{if(a.specSelectors.isOAS3()){const r=e(t,...n);return"function"==typeof r?r(a):r}return null}}}const h=f((()=>e=>e.specSelectors.specJson().get("servers",u))),g=f(((e,t)=>{let{callbacks:a,specPath:c}=t;return e=>{var t;const u=e.specSelectors.validOperationMethods();return i.Map.isMap(a)?(0,n.default)(t=(0,r.default)(a).call(a,((e,t,a)=>i.Map.isMap(t)?(0,r.default)(t).call(t,((e,t,r)=>{var d,p;if(!i.Map.isMap(t))return e;const m=(0,n.default)(d=(0,s.default)(p=t.entrySeq()).call(p,(e=>{let[t]=e;return(0,o.default)(u).call(u,t)}))).call(d,(e=>{let[t,n]=e;return{operation:(0,i.Map)({operation:n}),method:t,path:r,callbackName:a,specPath:(0,l.default)(c).call(c,[a,r,t])}}));return(0,l.default)(e).call(e,m)}),(0,i.List)()):e),(0,i.List)()).groupBy((e=>e.callbackName))).call(t,(e=>e.toArray())).toObject():{}}}))},2044:(e,t,a)=>{a.r(t),a.d(t,{basePath:()=>f,consumes:()=>h,definitions:()=>c,hasHost:()=>u,host:()=>m,produces:()=>g,schemes:()=>y,securityDefinitions:()=>d,validOperationMethods:()=>p});var n=a(8639),r=a(3881),s=a(9725);const o=(0,s.Map)();function l(e){return(t,a)=>function(){if(a.getSystem().specSelectors.isOAS3()){const t=e(...arguments);return"function"==typeof t?t(a):t}return t(...arguments)}}const i=l((0,n.createSelector)((()=>null))),c=l((()=>e=>{const t=e.getSystem().specSelectors.specJson().getIn(["components","schemas"]);return s.Map.isMap(t)?t:o})),u=l((()=>e=>e.getSystem().specSelectors.specJson().hasIn(["servers",0]))),d=l((0,n.createSelector)(r.specJsonWithResolvedSubtrees,(e=>e.getIn(["components","securitySchemes"])||null))),p=(e,t)=>function(a){if(t.specSelectors.isOAS3())return t.oas3Selectors.validOperationMethods();for(var n=arguments.length,r=new Array(n>1?n-1:0),s=1;s<n;s++)r[s-1]=arguments[s];return e(...r)},m=i,f=i,h=i,g=i,y=i},356:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);const r=(0,a(7779).OAS3ComponentWrapFactory)((e=>{let{Ori:t,...a}=e;const{schema:r,getComponent:s,errSelectors:o,authorized:l,onAuthChange:i,name:c}=a,u=s("HttpAuth");return"http"===r.get("type")?n.default.createElement(u,{key:c,schema:r,name:c,errSelectors:o,authorized:l,getComponent:s,onChange:i}):n.default.createElement(t,a)}))},7761:(e,t,a)=>{a.r(t),a.d(t,{default:()=>c});var n=a(2460),r=a(356),s=a(9487),o=a(58),l=a(3499),i=a(287);const c={Markdown:n.default,AuthItem:r.default,JsonSchema_string:i.default,VersionStamp:s.default,model:l.default,onlineValidatorBadge:o.default}},287:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);const r=(0,a(7779).OAS3ComponentWrapFactory)((e=>{let{Ori:t,...a}=e;const{schema:r,getComponent:s,errors:o,onChange:l}=a,i=r&&r.get?r.get("format"):null,c=r&&r.get?r.get("type"):null,u=s("Input");return c&&"string"===c&&i&&("binary"===i||"base64"===i)?n.default.createElement(u,{type:"file",className:o.length?"invalid":"",title:o.length?o:"",onChange:e=>{l(e.target.files[0])},disabled:t.isDisabled}):n.default.createElement(t,a)}))},2460:(e,t,a)=>{a.r(t),a.d(t,{Markdown:()=>u,default:()=>d});var n=a(5942),r=a(810),s=(a(5053),a(8096)),o=a(3952),l=a(7779),i=a(5466);const c=new o.Remarkable("commonmark");c.block.ruler.enable(["table"]),c.set({linkTarget:"_blank"});const u=e=>{let{source:t,className:a="",getConfigs:o}=e;if("string"!=typeof t)return null;if(t){const{useUnsafeMarkdown:e}=o(),l=c.render(t),u=(0,i.s)(l,{useUnsafeMarkdown:e});let d;return"string"==typeof u&&(d=(0,n.default)(u).call(u)),r.default.createElement("div",{dangerouslySetInnerHTML:{__html:d},className:(0,s.default)(a,"renderedMarkdown")})}return null};u.defaultProps={getConfigs:()=>({useUnsafeMarkdown:!1})};const d=(0,l.OAS3ComponentWrapFactory)(u)},3499:(e,t,a)=>{a.r(t),a.d(t,{default:()=>i});var n=a(863),r=a(810),s=(a(5053),a(7779)),o=a(1543);class l extends r.Component{render(){let{getConfigs:e,schema:t}=this.props,a=["model-box"],s=null;return!0===t.get("deprecated")&&(a.push("deprecated"),s=r.default.createElement("span",{className:"model-deprecated-warning"},"Deprecated:")),r.default.createElement("div",{className:a.join(" ")},s,r.default.createElement(o.Z,(0,n.default)({},this.props,{getConfigs:e,depth:1,expandDepth:this.props.expandDepth||0})))}}const i=(0,s.OAS3ComponentWrapFactory)(l)},58:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(7779),r=a(5623);const s=(0,n.OAS3ComponentWrapFactory)(r.Z)},9487:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);const r=(0,a(7779).OAS30ComponentWrapFactory)((e=>{const{Ori:t}=e;return n.default.createElement("span",null,n.default.createElement(t,e),n.default.createElement("small",{className:"version-stamp"},n.default.createElement("pre",{className:"version"},"OAS 3.0")))}))},7754:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(7512),r=a(5800),s=a(4380);const o=function(e){let{fn:t,getSystem:a}=e;if(t.jsonSchema202012){const e=(0,r.makeIsExpandable)(t.jsonSchema202012.isExpandable,a);(0,n.default)(this.fn.jsonSchema202012,{isExpandable:e,getProperties:r.getProperties})}if("function"==typeof t.sampleFromSchema&&t.jsonSchema202012){const e=(0,s.wrapOAS31Fn)({sampleFromSchema:t.jsonSchema202012.sampleFromSchema,sampleFromSchemaGeneric:t.jsonSchema202012.sampleFromSchemaGeneric,createXMLExample:t.jsonSchema202012.createXMLExample,memoizedSampleFromSchema:t.jsonSchema202012.memoizedSampleFromSchema,memoizedCreateXMLExample:t.jsonSchema202012.memoizedCreateXMLExample},a());(0,n.default)(this.fn,e)}}},9503:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(5053),a(6561));const s=e=>{let{getComponent:t,specSelectors:a}=e;const s=a.selectContactNameField(),o=a.selectContactUrl(),l=a.selectContactEmailField(),i=t("Link");return n.default.createElement("div",{className:"info__contact"},o&&n.default.createElement("div",null,n.default.createElement(i,{href:(0,r.Nm)(o),target:"_blank"},s," - Website")),l&&n.default.createElement(i,{href:(0,r.Nm)(`mailto:${l}`)},o?`Send email to ${s}`:`Contact ${s}`))}},6133:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(5053),a(6561));const s=e=>{let{getComponent:t,specSelectors:a}=e;const s=a.version(),o=a.url(),l=a.basePath(),i=a.host(),c=a.selectInfoSummaryField(),u=a.selectInfoDescriptionField(),d=a.selectInfoTitleField(),p=a.selectInfoTermsOfServiceUrl(),m=a.selectExternalDocsUrl(),f=a.selectExternalDocsDescriptionField(),h=a.contact(),g=a.license(),y=t("Markdown",!0),v=t("Link"),E=t("VersionStamp"),S=t("InfoUrl"),w=t("InfoBasePath"),b=t("License",!0),x=t("Contact",!0),_=t("JsonSchemaDialect",!0);return n.default.createElement("div",{className:"info"},n.default.createElement("hgroup",{className:"main"},n.default.createElement("h2",{className:"title"},d,s&&n.default.createElement(E,{version:s})),(i||l)&&n.default.createElement(w,{host:i,basePath:l}),o&&n.default.createElement(S,{getComponent:t,url:o})),c&&n.default.createElement("p",{className:"info__summary"},c),n.default.createElement("div",{className:"info__description description"},n.default.createElement(y,{source:u})),p&&n.default.createElement("div",{className:"info__tos"},n.default.createElement(v,{target:"_blank",href:(0,r.Nm)(p)},"Terms of service")),h.size>0&&n.default.createElement(x,null),g.size>0&&n.default.createElement(b,null),m&&n.default.createElement(v,{className:"info__extdocs",target:"_blank",href:(0,r.Nm)(m)},f||m),n.default.createElement(_,null))}},2562:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(5053),a(6561));const s=e=>{let{getComponent:t,specSelectors:a}=e;const s=a.selectJsonSchemaDialectField(),o=a.selectJsonSchemaDialectDefault(),l=t("Link");return n.default.createElement(n.default.Fragment,null,s&&s===o&&n.default.createElement("p",{className:"info__jsonschemadialect"},"JSON Schema dialect:"," ",n.default.createElement(l,{target:"_blank",href:(0,r.Nm)(s)},s)),s&&s!==o&&n.default.createElement("div",{className:"error-wrapper"},n.default.createElement("div",{className:"no-margin"},n.default.createElement("div",{className:"errors"},n.default.createElement("div",{className:"errors-wrapper"},n.default.createElement("h4",{className:"center"},"Warning"),n.default.createElement("p",{className:"message"},n.default.createElement("strong",null,"OpenAPI.jsonSchemaDialect")," field contains a value different from the default value of"," ",n.default.createElement(l,{target:"_blank",href:o},o),". Values different from the default one are currently not supported. Please either omit the field or provide it with the default value."))))))}},1876:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810),r=(a(5053),a(6561));const s=e=>{let{getComponent:t,specSelectors:a}=e;const s=a.selectLicenseNameField(),o=a.selectLicenseUrl(),l=t("Link");return n.default.createElement("div",{className:"info__license"},o?n.default.createElement("div",{className:"info__license__url"},n.default.createElement(l,{target:"_blank",href:(0,r.Nm)(o)},s)):n.default.createElement("span",null,s))}},2718:(e,t,a)=>{a.r(t),a.d(t,{default:()=>l});var n=a(2372),r=a(810);a(5053),a(9569);const s=e=>"string"==typeof e&&(0,n.default)(e).call(e,"#/components/schemas/")?(e=>{const t=e.replace(/~1/g,"/").replace(/~0/g,"~");try{return decodeURIComponent(t)}catch{return t}})(e.replace(/^.*#\/components\/schemas\//,"")):null,o=(0,r.forwardRef)(((e,t)=>{let{schema:a,getComponent:n,onToggle:o}=e;const l=n("JSONSchema202012"),i=s(a.get("$$ref")),c=(0,r.useCallback)(((e,t)=>{o(i,t)}),[i,o]);return r.default.createElement(l,{name:i,schema:a.toJS(),ref:t,onExpand:c})}));o.defaultProps={name:"",displayName:"",isRef:!1,required:!1,expandDepth:0,depth:1,includeReadOnly:!1,includeWriteOnly:!1,onToggle:()=>{}};const l=o},263:(e,t,a)=>{a.r(t),a.d(t,{default:()=>i});var n=a(2740),r=a(2565),s=a(7204),o=a(810),l=(a(5053),a(8096));const i=e=>{var t;let{specActions:a,specSelectors:i,layoutSelectors:c,layoutActions:u,getComponent:d,getConfigs:p}=e;const m=i.selectSchemas(),f=(0,n.default)(m).length>0,h=["components","schemas"],{docExpansion:g,defaultModelsExpandDepth:y}=p(),v=y>0&&"none"!==g,E=c.isShown(h,v),S=d("Collapse"),w=d("JSONSchema202012"),b=d("ArrowUpIcon"),x=d("ArrowDownIcon");(0,o.useEffect)((()=>{const e=E&&y>1,t=null!=i.specResolvedSubtree(h);e&&!t&&a.requestResolvedSubtree(h)}),[E,y]);const _=(0,o.useCallback)((()=>{u.show(h,!E)}),[E]),C=(0,o.useCallback)((e=>{null!==e&&u.readyToScroll(h,e)}),[]),j=e=>t=>{null!==t&&u.readyToScroll([...h,e],t)},N=e=>(t,n)=>{if(n){const t=[...h,e];null!=i.specResolvedSubtree(t)||a.requestResolvedSubtree([...h,e])}};return!f||y<0?null:o.default.createElement("section",{className:(0,l.default)("models",{"is-open":E}),ref:C},o.default.createElement("h4",null,o.default.createElement("button",{"aria-expanded":E,className:"models-control",onClick:_},o.default.createElement("span",null,"Schemas"),E?o.default.createElement(b,null):o.default.createElement(x,null))),o.default.createElement(S,{isOpened:E},(0,r.default)(t=(0,s.default)(m)).call(t,(e=>{let[t,a]=e;return o.default.createElement(w,{key:t,ref:j(t),schema:a,name:t,onExpand:N(t)})}))))}},3429:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(5053);const r=e=>{let{bypass:t,isSwagger2:a,isOAS3:r,isOAS31:s,alsoShow:o,children:l}=e;return t?n.default.createElement("div",null,l):a&&(r||s)?n.default.createElement("div",{className:"version-pragma"},o,n.default.createElement("div",{className:"version-pragma__message version-pragma__message--ambiguous"},n.default.createElement("div",null,n.default.createElement("h3",null,"Unable to render this definition"),n.default.createElement("p",null,n.default.createElement("code",null,"swagger")," and ",n.default.createElement("code",null,"openapi")," fields cannot be present in the same Swagger or OpenAPI definition. Please remove one of the fields."),n.default.createElement("p",null,"Supported version fields are ",n.default.createElement("code",null,'swagger: "2.0"')," and those that match ",n.default.createElement("code",null,"openapi: 3.x.y")," (for example,"," ",n.default.createElement("code",null,"openapi: 3.1.0"),").")))):a||r||s?n.default.createElement("div",null,l):n.default.createElement("div",{className:"version-pragma"},o,n.default.createElement("div",{className:"version-pragma__message version-pragma__message--missing"},n.default.createElement("div",null,n.default.createElement("h3",null,"Unable to render this definition"),n.default.createElement("p",null,"The provided definition does not specify a valid version field."),n.default.createElement("p",null,"Please indicate a valid Swagger or OpenAPI version field. Supported version fields are ",n.default.createElement("code",null,'swagger: "2.0"')," and those that match ",n.default.createElement("code",null,"openapi: 3.x.y")," (for example,"," ",n.default.createElement("code",null,"openapi: 3.1.0"),")."))))}},9508:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(2740),r=a(2565),s=a(810);a(5053);const o=e=>{let{specSelectors:t,getComponent:a}=e;const o=t.selectWebhooksOperations(),l=(0,n.default)(o),i=a("OperationContainer",!0);return 0===l.length?null:s.default.createElement("div",{className:"webhooks"},s.default.createElement("h2",null,"Webhooks"),(0,r.default)(l).call(l,(e=>{var t;return s.default.createElement("div",{key:`${e}-webhook`},(0,r.default)(t=o[e]).call(t,(t=>s.default.createElement(i,{key:`${e}-${t.method}-webhook`,op:t.operation,tag:"webhooks",method:t.method,path:e,specPath:t.specPath,allowTryItOut:!1}))))})))}},4380:(e,t,a)=>{a.r(t),a.d(t,{createOnlyOAS31ComponentWrapper:()=>p,createOnlyOAS31Selector:()=>c,createOnlyOAS31SelectorWrapper:()=>u,createSystemSelector:()=>d,isOAS31:()=>i,wrapOAS31Fn:()=>m});var n=a(863),r=a(7415),s=a(2565),o=a(7204),l=a(810);const i=e=>{const t=e.get("openapi");return"string"==typeof t&&/^3\.1\.(?:[1-9]\d*|0)$/.test(t)},c=e=>function(t){for(var a=arguments.length,n=new Array(a>1?a-1:0),r=1;r<a;r++)n[r-1]=arguments[r];return a=>{if(a.getSystem().specSelectors.isOAS31()){const r=e(t,...n);return"function"==typeof r?r(a):r}return null}},u=e=>(t,a)=>function(n){for(var r=arguments.length,s=new Array(r>1?r-1:0),o=1;o<r;o++)s[o-1]=arguments[o];if(a.getSystem().specSelectors.isOAS31()){const r=e(n,...s);return"function"==typeof r?r(t,a):r}return t(...s)},d=e=>function(t){for(var a=arguments.length,n=new Array(a>1?a-1:0),r=1;r<a;r++)n[r-1]=arguments[r];return a=>{const r=e(t,a,...n);return"function"==typeof r?r(a):r}},p=e=>(t,a)=>r=>a.specSelectors.isOAS31()?l.default.createElement(e,(0,n.default)({},r,{originalComponent:t,getSystem:a.getSystem})):l.default.createElement(t,r),m=(e,t)=>{var a;const{fn:n,specSelectors:l}=t;return(0,r.default)((0,s.default)(a=(0,o.default)(e)).call(a,(e=>{let[t,a]=e;const r=n[t];return[t,function(){return l.isOAS31()?a(...arguments):"function"==typeof r?r(...arguments):void 0}]})))}},9806:(e,t,a)=>{a.r(t),a.d(t,{default:()=>A});var n=a(9508),r=a(1876),s=a(9503),o=a(6133),l=a(2562),i=a(3429),c=a(2718),u=a(263),d=a(6608),p=a(7423),m=a(284),f=a(7042),h=a(2914),g=a(1434),y=a(1122),v=a(4380),E=a(9305),S=a(2884),w=a(4280),b=a(9450),x=a(3995),_=a(9525),C=a(5324),j=a(809),N=a(4951),O=a(7536),k=a(7754);const A=e=>{let{fn:t}=e;const a=t.createSystemSelector||v.createSystemSelector,A=t.createOnlyOAS31Selector||v.createOnlyOAS31Selector;return{afterLoad:k.default,fn:{isOAS31:v.isOAS31,createSystemSelector:v.createSystemSelector,createOnlyOAS31Selector:v.createOnlyOAS31Selector},components:{Webhooks:n.default,JsonSchemaDialect:l.default,OAS31Info:o.default,OAS31License:r.default,OAS31Contact:s.default,OAS31VersionPragmaFilter:i.default,OAS31Model:c.default,OAS31Models:u.default,JSONSchema202012KeywordExample:b.default,JSONSchema202012KeywordXml:x.default,JSONSchema202012KeywordDiscriminator:_.default,JSONSchema202012KeywordExternalDocs:C.default},wrapComponents:{InfoContainer:m.default,License:d.default,Contact:p.default,VersionPragmaFilter:g.default,VersionStamp:y.default,Model:f.default,Models:h.default,JSONSchema202012KeywordDescription:j.default,JSONSchema202012KeywordDefault:N.default,JSONSchema202012KeywordProperties:O.default},statePlugins:{spec:{selectors:{isOAS31:a(E.isOAS31),license:E.license,selectLicenseNameField:E.selectLicenseNameField,selectLicenseUrlField:E.selectLicenseUrlField,selectLicenseIdentifierField:A(E.selectLicenseIdentifierField),selectLicenseUrl:a(E.selectLicenseUrl),contact:E.contact,selectContactNameField:E.selectContactNameField,selectContactEmailField:E.selectContactEmailField,selectContactUrlField:E.selectContactUrlField,selectContactUrl:a(E.selectContactUrl),selectInfoTitleField:E.selectInfoTitleField,selectInfoSummaryField:A(E.selectInfoSummaryField),selectInfoDescriptionField:E.selectInfoDescriptionField,selectInfoTermsOfServiceField:E.selectInfoTermsOfServiceField,selectInfoTermsOfServiceUrl:a(E.selectInfoTermsOfServiceUrl),selectExternalDocsDescriptionField:E.selectExternalDocsDescriptionField,selectExternalDocsUrlField:E.selectExternalDocsUrlField,selectExternalDocsUrl:a(E.selectExternalDocsUrl),webhooks:A(E.webhooks),selectWebhooksOperations:A(a(E.selectWebhooksOperations)),selectJsonSchemaDialectField:E.selectJsonSchemaDialectField,selectJsonSchemaDialectDefault:E.selectJsonSchemaDialectDefault,selectSchemas:a(E.selectSchemas)},wrapSelectors:{isOAS3:S.isOAS3,selectLicenseUrl:S.selectLicenseUrl}},oas31:{selectors:{selectLicenseUrl:A(a(w.selectLicenseUrl))}}}}}},5989:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(5053);const r=e=>{let{schema:t,getSystem:a}=e;if(null==t||!t.description)return null;const{getComponent:r}=a(),s=r("Markdown");return n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--description"},n.default.createElement("div",{className:"json-schema-2020-12-core-keyword__value json-schema-2020-12-core-keyword__value--secondary"},n.default.createElement(s,{source:t.description})))}},9525:(e,t,a)=>{a.r(t),a.d(t,{default:()=>l});var n=a(2740),r=a(810),s=(a(5053),a(8096)),o=a(7749);const l=e=>{let{schema:t,getSystem:a}=e;const l=(null==t?void 0:t.discriminator)||{},{fn:i,getComponent:c}=a(),{useIsExpandedDeeply:u,useComponent:d}=i.jsonSchema202012,p=u(),m=!!l.mapping,[f,h]=(0,r.useState)(p),[g,y]=(0,r.useState)(!1),v=d("Accordion"),E=d("ExpandDeepButton"),S=c("JSONSchema202012DeepExpansionContext")(),w=(0,r.useCallback)((()=>{h((e=>!e))}),[]),b=(0,r.useCallback)(((e,t)=>{h(t),y(t)}),[]);return 0===(0,n.default)(l).length?null:r.default.createElement(S.Provider,{value:g},r.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--discriminator"},m?r.default.createElement(r.default.Fragment,null,r.default.createElement(v,{expanded:f,onChange:w},r.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"Discriminator")),r.default.createElement(E,{expanded:f,onClick:b})):r.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"Discriminator"),l.propertyName&&r.default.createElement("span",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--muted"},l.propertyName),r.default.createElement("strong",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},"object"),r.default.createElement("ul",{className:(0,s.default)("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!f})},f&&r.default.createElement("li",{className:"json-schema-2020-12-property"},r.default.createElement(o.default,{discriminator:l})))))}},7749:(e,t,a)=>{a.r(t),a.d(t,{default:()=>i});var n=a(2740),r=a(2565),s=a(7204),o=a(810);a(5053);const l=e=>{var t;let{discriminator:a}=e;const l=(null==a?void 0:a.mapping)||{};return 0===(0,n.default)(l).length?null:(0,r.default)(t=(0,s.default)(l)).call(t,(e=>{let[t,a]=e;return o.default.createElement("div",{key:`${t}-${a}`,className:"json-schema-2020-12-keyword"},o.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},t),o.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},a))}))};l.defaultProps={mapping:void 0};const i=l},9450:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(5053);const r=e=>{let{schema:t,getSystem:a}=e;const{fn:r}=a(),{hasKeyword:s,stringify:o}=r.jsonSchema202012.useFn();return s(t,"example")?n.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--example"},n.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"Example"),n.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--const"},o(t.example))):null}},5324:(e,t,a)=>{a.r(t),a.d(t,{default:()=>l});var n=a(2740),r=a(810),s=(a(5053),a(8096)),o=a(6561);const l=e=>{let{schema:t,getSystem:a}=e;const l=(null==t?void 0:t.externalDocs)||{},{fn:i,getComponent:c}=a(),{useIsExpandedDeeply:u,useComponent:d}=i.jsonSchema202012,p=u(),m=!(!l.description&&!l.url),[f,h]=(0,r.useState)(p),[g,y]=(0,r.useState)(!1),v=d("Accordion"),E=d("ExpandDeepButton"),S=c("JSONSchema202012KeywordDescription"),w=c("Link"),b=c("JSONSchema202012DeepExpansionContext")(),x=(0,r.useCallback)((()=>{h((e=>!e))}),[]),_=(0,r.useCallback)(((e,t)=>{h(t),y(t)}),[]);return 0===(0,n.default)(l).length?null:r.default.createElement(b.Provider,{value:g},r.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--externalDocs"},m?r.default.createElement(r.default.Fragment,null,r.default.createElement(v,{expanded:f,onChange:x},r.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"External documentation")),r.default.createElement(E,{expanded:f,onClick:_})):r.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"External documentation"),r.default.createElement("strong",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},"object"),r.default.createElement("ul",{className:(0,s.default)("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!f})},f&&r.default.createElement(r.default.Fragment,null,l.description&&r.default.createElement("li",{className:"json-schema-2020-12-property"},r.default.createElement(S,{schema:l,getSystem:a})),l.url&&r.default.createElement("li",{className:"json-schema-2020-12-property"},r.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword"},r.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"url"),r.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},r.default.createElement(w,{target:"_blank",href:(0,o.Nm)(l.url)},l.url))))))))}},9023:(e,t,a)=>{a.r(t),a.d(t,{default:()=>u});var n=a(4163),r=a(2740),s=a(2565),o=a(7204),l=a(2372),i=a(810),c=(a(5053),a(8096));const u=e=>{var t;let{schema:a,getSystem:u}=e;const{fn:d}=u(),{useComponent:p}=d.jsonSchema202012,{getDependentRequired:m,getProperties:f}=d.jsonSchema202012.useFn(),h=d.jsonSchema202012.useConfig(),g=(0,n.default)(null==a?void 0:a.required)?a.required:[],y=p("JSONSchema"),v=f(a,h);return 0===(0,r.default)(v).length?null:i.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--properties"},i.default.createElement("ul",null,(0,s.default)(t=(0,o.default)(v)).call(t,(e=>{let[t,n]=e;const r=(0,l.default)(g).call(g,t),s=m(t,a);return i.default.createElement("li",{key:t,className:(0,c.default)("json-schema-2020-12-property",{"json-schema-2020-12-property--required":r})},i.default.createElement(y,{name:t,schema:n,dependentRequired:s}))}))))}},3995:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(2740),r=a(810),s=(a(5053),a(8096));const o=e=>{let{schema:t,getSystem:a}=e;const o=(null==t?void 0:t.xml)||{},{fn:l,getComponent:i}=a(),{useIsExpandedDeeply:c,useComponent:u}=l.jsonSchema202012,d=c(),p=!!(o.name||o.namespace||o.prefix),[m,f]=(0,r.useState)(d),[h,g]=(0,r.useState)(!1),y=u("Accordion"),v=u("ExpandDeepButton"),E=i("JSONSchema202012DeepExpansionContext")(),S=(0,r.useCallback)((()=>{f((e=>!e))}),[]),w=(0,r.useCallback)(((e,t)=>{f(t),g(t)}),[]);return 0===(0,n.default)(o).length?null:r.default.createElement(E.Provider,{value:h},r.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--xml"},p?r.default.createElement(r.default.Fragment,null,r.default.createElement(y,{expanded:m,onChange:S},r.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"XML")),r.default.createElement(v,{expanded:m,onClick:w})):r.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"XML"),!0===o.attribute&&r.default.createElement("span",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--muted"},"attribute"),!0===o.wrapped&&r.default.createElement("span",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--muted"},"wrapped"),r.default.createElement("strong",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},"object"),r.default.createElement("ul",{className:(0,s.default)("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!m})},m&&r.default.createElement(r.default.Fragment,null,o.name&&r.default.createElement("li",{className:"json-schema-2020-12-property"},r.default.createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword"},r.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"name"),r.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},o.name))),o.namespace&&r.default.createElement("li",{className:"json-schema-2020-12-property"},r.default.createElement("div",{className:"json-schema-2020-12-keyword"},r.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"namespace"),r.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},o.namespace))),o.prefix&&r.default.createElement("li",{className:"json-schema-2020-12-property"},r.default.createElement("div",{className:"json-schema-2020-12-keyword"},r.default.createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"prefix"),r.default.createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},o.prefix)))))))}},5800:(e,t,a)=>{a.r(t),a.d(t,{getProperties:()=>l,makeIsExpandable:()=>o});var n=a(7204),r=a(6145),s=a(7415);const o=(e,t)=>{const{fn:a}=t();if("function"!=typeof e)return null;const{hasKeyword:n}=a.jsonSchema202012;return t=>e(t)||n(t,"example")||(null==t?void 0:t.xml)||(null==t?void 0:t.discriminator)||(null==t?void 0:t.externalDocs)},l=(e,t)=>{let{includeReadOnly:a,includeWriteOnly:o}=t;if(null==e||!e.properties)return{};const l=(0,n.default)(e.properties),i=(0,r.default)(l).call(l,(e=>{let[,t]=e;const n=!0===(null==t?void 0:t.readOnly),r=!0===(null==t?void 0:t.writeOnly);return(!n||a)&&(!r||o)}));return(0,s.default)(i)}},4951:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);const r=(0,a(4380).createOnlyOAS31ComponentWrapper)((e=>{let{schema:t,getSystem:a,originalComponent:r}=e;const{getComponent:s}=a(),o=s("JSONSchema202012KeywordDiscriminator"),l=s("JSONSchema202012KeywordXml"),i=s("JSONSchema202012KeywordExample"),c=s("JSONSchema202012KeywordExternalDocs");return n.default.createElement(n.default.Fragment,null,n.default.createElement(r,{schema:t}),n.default.createElement(o,{schema:t,getSystem:a}),n.default.createElement(l,{schema:t,getSystem:a}),n.default.createElement(c,{schema:t,getSystem:a}),n.default.createElement(i,{schema:t,getSystem:a}))}))},809:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(5989);const r=(0,a(4380).createOnlyOAS31ComponentWrapper)(n.default)},7536:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(9023);const r=(0,a(4380).createOnlyOAS31ComponentWrapper)(n.default)},4280:(e,t,a)=>{a.r(t),a.d(t,{selectLicenseUrl:()=>s});var n=a(8639),r=a(3543);const s=(0,n.createSelector)(((e,t)=>t.specSelectors.url()),((e,t)=>t.oas3Selectors.selectedServer()),((e,t)=>t.specSelectors.selectLicenseUrlField()),((e,t)=>t.specSelectors.selectLicenseIdentifierField()),((e,t,a,n)=>a?(0,r.mn)(a,e,{selectedServer:t}):n?`https://spdx.org/licenses/${n}.html`:void 0))},9305:(e,t,a)=>{a.r(t),a.d(t,{contact:()=>b,isOAS31:()=>f,license:()=>y,selectContactEmailField:()=>_,selectContactNameField:()=>x,selectContactUrl:()=>j,selectContactUrlField:()=>C,selectExternalDocsDescriptionField:()=>R,selectExternalDocsUrl:()=>T,selectExternalDocsUrlField:()=>P,selectInfoDescriptionField:()=>k,selectInfoSummaryField:()=>O,selectInfoTermsOfServiceField:()=>A,selectInfoTermsOfServiceUrl:()=>I,selectInfoTitleField:()=>N,selectJsonSchemaDialectDefault:()=>D,selectJsonSchemaDialectField:()=>M,selectLicenseIdentifierField:()=>w,selectLicenseNameField:()=>v,selectLicenseUrl:()=>S,selectLicenseUrlField:()=>E,selectSchemas:()=>L,selectWebhooksOperations:()=>g,webhooks:()=>h});var n=a(2565),r=a(6785),s=a(6145),o=a(2372),l=a(5171),i=a(7204),c=a(9725),u=a(8639),d=a(3543),p=a(4380);const m=(0,c.Map)(),f=(0,u.createSelector)(((e,t)=>t.specSelectors.specJson()),p.isOAS31),h=()=>e=>e.specSelectors.specJson().get("webhooks",m),g=(0,u.createSelector)(((e,t)=>t.specSelectors.webhooks()),((e,t)=>t.specSelectors.validOperationMethods()),((e,t)=>t.specSelectors.specResolvedSubtree(["webhooks"])),((e,t)=>{var a;return c.Map.isMap(e)?(0,n.default)(a=(0,r.default)(e).call(e,((e,a,r)=>{var i,u;if(!c.Map.isMap(a))return e;const d=(0,n.default)(i=(0,s.default)(u=a.entrySeq()).call(u,(e=>{let[a]=e;return(0,o.default)(t).call(t,a)}))).call(i,(e=>{let[t,a]=e;return{operation:(0,c.Map)({operation:a}),method:t,path:r,specPath:(0,c.List)(["webhooks",r,t])}}));return(0,l.default)(e).call(e,d)}),(0,c.List)()).groupBy((e=>e.path))).call(a,(e=>e.toArray())).toObject():{}})),y=()=>e=>e.specSelectors.info().get("license",m),v=()=>e=>e.specSelectors.license().get("name","License"),E=()=>e=>e.specSelectors.license().get("url"),S=(0,u.createSelector)(((e,t)=>t.specSelectors.url()),((e,t)=>t.oas3Selectors.selectedServer()),((e,t)=>t.specSelectors.selectLicenseUrlField()),((e,t,a)=>{if(a)return(0,d.mn)(a,e,{selectedServer:t})})),w=()=>e=>e.specSelectors.license().get("identifier"),b=()=>e=>e.specSelectors.info().get("contact",m),x=()=>e=>e.specSelectors.contact().get("name","the developer"),_=()=>e=>e.specSelectors.contact().get("email"),C=()=>e=>e.specSelectors.contact().get("url"),j=(0,u.createSelector)(((e,t)=>t.specSelectors.url()),((e,t)=>t.oas3Selectors.selectedServer()),((e,t)=>t.specSelectors.selectContactUrlField()),((e,t,a)=>{if(a)return(0,d.mn)(a,e,{selectedServer:t})})),N=()=>e=>e.specSelectors.info().get("title"),O=()=>e=>e.specSelectors.info().get("summary"),k=()=>e=>e.specSelectors.info().get("description"),A=()=>e=>e.specSelectors.info().get("termsOfService"),I=(0,u.createSelector)(((e,t)=>t.specSelectors.url()),((e,t)=>t.oas3Selectors.selectedServer()),((e,t)=>t.specSelectors.selectInfoTermsOfServiceField()),((e,t,a)=>{if(a)return(0,d.mn)(a,e,{selectedServer:t})})),R=()=>e=>e.specSelectors.externalDocs().get("description"),P=()=>e=>e.specSelectors.externalDocs().get("url"),T=(0,u.createSelector)(((e,t)=>t.specSelectors.url()),((e,t)=>t.oas3Selectors.selectedServer()),((e,t)=>t.specSelectors.selectExternalDocsUrlField()),((e,t,a)=>{if(a)return(0,d.mn)(a,e,{selectedServer:t})})),M=()=>e=>e.specSelectors.specJson().get("jsonSchemaDialect"),D=()=>"https://spec.openapis.org/oas/3.1/dialect/base",L=(0,u.createSelector)(((e,t)=>t.specSelectors.definitions()),((e,t)=>t.specSelectors.specResolvedSubtree(["components","schemas"])),((e,t)=>{var a;return c.Map.isMap(e)?c.Map.isMap(t)?(0,r.default)(a=(0,i.default)(e.toJS())).call(a,((e,a)=>{let[n,r]=a;const s=t.get(n);return e[n]=(null==s?void 0:s.toJS())||r,e}),{}):e.toJS():{}}))},2884:(e,t,a)=>{a.r(t),a.d(t,{isOAS3:()=>r,selectLicenseUrl:()=>s});var n=a(4380);const r=(e,t)=>function(a){const n=t.specSelectors.isOAS31();for(var r=arguments.length,s=new Array(r>1?r-1:0),o=1;o<r;o++)s[o-1]=arguments[o];return n||e(...s)},s=(0,n.createOnlyOAS31SelectorWrapper)((()=>(e,t)=>t.oas31Selectors.selectLicenseUrl()))},7423:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);const r=(0,a(4380).createOnlyOAS31ComponentWrapper)((e=>{let{getSystem:t}=e;const a=t().getComponent("OAS31Contact",!0);return n.default.createElement(a,null)}))},284:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);const r=(0,a(4380).createOnlyOAS31ComponentWrapper)((e=>{let{getSystem:t}=e;const a=t().getComponent("OAS31Info",!0);return n.default.createElement(a,null)}))},6608:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);const r=(0,a(4380).createOnlyOAS31ComponentWrapper)((e=>{let{getSystem:t}=e;const a=t().getComponent("OAS31License",!0);return n.default.createElement(a,null)}))},7042:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(810),r=a(4380),s=a(5800);const o=(0,r.createOnlyOAS31ComponentWrapper)((e=>{let{getSystem:t,...a}=e;const r=t(),{getComponent:o,fn:l,getConfigs:i}=r,c=i(),u=o("OAS31Model"),d=o("JSONSchema202012"),p=o("JSONSchema202012Keyword$schema"),m=o("JSONSchema202012Keyword$vocabulary"),f=o("JSONSchema202012Keyword$id"),h=o("JSONSchema202012Keyword$anchor"),g=o("JSONSchema202012Keyword$dynamicAnchor"),y=o("JSONSchema202012Keyword$ref"),v=o("JSONSchema202012Keyword$dynamicRef"),E=o("JSONSchema202012Keyword$defs"),S=o("JSONSchema202012Keyword$comment"),w=o("JSONSchema202012KeywordAllOf"),b=o("JSONSchema202012KeywordAnyOf"),x=o("JSONSchema202012KeywordOneOf"),_=o("JSONSchema202012KeywordNot"),C=o("JSONSchema202012KeywordIf"),j=o("JSONSchema202012KeywordThen"),N=o("JSONSchema202012KeywordElse"),O=o("JSONSchema202012KeywordDependentSchemas"),k=o("JSONSchema202012KeywordPrefixItems"),A=o("JSONSchema202012KeywordItems"),I=o("JSONSchema202012KeywordContains"),R=o("JSONSchema202012KeywordProperties"),P=o("JSONSchema202012KeywordPatternProperties"),T=o("JSONSchema202012KeywordAdditionalProperties"),M=o("JSONSchema202012KeywordPropertyNames"),D=o("JSONSchema202012KeywordUnevaluatedItems"),L=o("JSONSchema202012KeywordUnevaluatedProperties"),q=o("JSONSchema202012KeywordType"),B=o("JSONSchema202012KeywordEnum"),U=o("JSONSchema202012KeywordConst"),J=o("JSONSchema202012KeywordConstraint"),$=o("JSONSchema202012KeywordDependentRequired"),V=o("JSONSchema202012KeywordContentSchema"),K=o("JSONSchema202012KeywordTitle"),F=o("JSONSchema202012KeywordDescription"),z=o("JSONSchema202012KeywordDefault"),W=o("JSONSchema202012KeywordDeprecated"),H=o("JSONSchema202012KeywordReadOnly"),G=o("JSONSchema202012KeywordWriteOnly"),Z=o("JSONSchema202012Accordion"),Y=o("JSONSchema202012ExpandDeepButton"),X=o("JSONSchema202012ChevronRightIcon"),Q=o("withJSONSchema202012Context")(u,{config:{default$schema:"https://spec.openapis.org/oas/3.1/dialect/base",defaultExpandedLevels:c.defaultModelExpandDepth,includeReadOnly:Boolean(a.includeReadOnly),includeWriteOnly:Boolean(a.includeWriteOnly)},components:{JSONSchema:d,Keyword$schema:p,Keyword$vocabulary:m,Keyword$id:f,Keyword$anchor:h,Keyword$dynamicAnchor:g,Keyword$ref:y,Keyword$dynamicRef:v,Keyword$defs:E,Keyword$comment:S,KeywordAllOf:w,KeywordAnyOf:b,KeywordOneOf:x,KeywordNot:_,KeywordIf:C,KeywordThen:j,KeywordElse:N,KeywordDependentSchemas:O,KeywordPrefixItems:k,KeywordItems:A,KeywordContains:I,KeywordProperties:R,KeywordPatternProperties:P,KeywordAdditionalProperties:T,KeywordPropertyNames:M,KeywordUnevaluatedItems:D,KeywordUnevaluatedProperties:L,KeywordType:q,KeywordEnum:B,KeywordConst:U,KeywordConstraint:J,KeywordDependentRequired:$,KeywordContentSchema:V,KeywordTitle:K,KeywordDescription:F,KeywordDefault:z,KeywordDeprecated:W,KeywordReadOnly:H,KeywordWriteOnly:G,Accordion:Z,ExpandDeepButton:Y,ChevronRightIcon:X},fn:{upperFirst:l.upperFirst,isExpandable:(0,s.makeIsExpandable)(l.jsonSchema202012.isExpandable,t),getProperties:s.getProperties}});return n.default.createElement(Q,a)}))},2914:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(810);const r=(0,a(4380).createOnlyOAS31ComponentWrapper)((e=>{let{getSystem:t}=e;const{getComponent:a,fn:s,getConfigs:o}=t(),l=o();if(r.ModelsWithJSONSchemaContext)return n.default.createElement(r.ModelsWithJSONSchemaContext,null);const i=a("OAS31Models",!0),c=a("JSONSchema202012"),u=a("JSONSchema202012Keyword$schema"),d=a("JSONSchema202012Keyword$vocabulary"),p=a("JSONSchema202012Keyword$id"),m=a("JSONSchema202012Keyword$anchor"),f=a("JSONSchema202012Keyword$dynamicAnchor"),h=a("JSONSchema202012Keyword$ref"),g=a("JSONSchema202012Keyword$dynamicRef"),y=a("JSONSchema202012Keyword$defs"),v=a("JSONSchema202012Keyword$comment"),E=a("JSONSchema202012KeywordAllOf"),S=a("JSONSchema202012KeywordAnyOf"),w=a("JSONSchema202012KeywordOneOf"),b=a("JSONSchema202012KeywordNot"),x=a("JSONSchema202012KeywordIf"),_=a("JSONSchema202012KeywordThen"),C=a("JSONSchema202012KeywordElse"),j=a("JSONSchema202012KeywordDependentSchemas"),N=a("JSONSchema202012KeywordPrefixItems"),O=a("JSONSchema202012KeywordItems"),k=a("JSONSchema202012KeywordContains"),A=a("JSONSchema202012KeywordProperties"),I=a("JSONSchema202012KeywordPatternProperties"),R=a("JSONSchema202012KeywordAdditionalProperties"),P=a("JSONSchema202012KeywordPropertyNames"),T=a("JSONSchema202012KeywordUnevaluatedItems"),M=a("JSONSchema202012KeywordUnevaluatedProperties"),D=a("JSONSchema202012KeywordType"),L=a("JSONSchema202012KeywordEnum"),q=a("JSONSchema202012KeywordConst"),B=a("JSONSchema202012KeywordConstraint"),U=a("JSONSchema202012KeywordDependentRequired"),J=a("JSONSchema202012KeywordContentSchema"),$=a("JSONSchema202012KeywordTitle"),V=a("JSONSchema202012KeywordDescription"),K=a("JSONSchema202012KeywordDefault"),F=a("JSONSchema202012KeywordDeprecated"),z=a("JSONSchema202012KeywordReadOnly"),W=a("JSONSchema202012KeywordWriteOnly"),H=a("JSONSchema202012Accordion"),G=a("JSONSchema202012ExpandDeepButton"),Z=a("JSONSchema202012ChevronRightIcon"),Y=a("withJSONSchema202012Context");return r.ModelsWithJSONSchemaContext=Y(i,{config:{default$schema:"https://spec.openapis.org/oas/3.1/dialect/base",defaultExpandedLevels:l.defaultModelsExpandDepth-1,includeReadOnly:!0,includeWriteOnly:!0},components:{JSONSchema:c,Keyword$schema:u,Keyword$vocabulary:d,Keyword$id:p,Keyword$anchor:m,Keyword$dynamicAnchor:f,Keyword$ref:h,Keyword$dynamicRef:g,Keyword$defs:y,Keyword$comment:v,KeywordAllOf:E,KeywordAnyOf:S,KeywordOneOf:w,KeywordNot:b,KeywordIf:x,KeywordThen:_,KeywordElse:C,KeywordDependentSchemas:j,KeywordPrefixItems:N,KeywordItems:O,KeywordContains:k,KeywordProperties:A,KeywordPatternProperties:I,KeywordAdditionalProperties:R,KeywordPropertyNames:P,KeywordUnevaluatedItems:T,KeywordUnevaluatedProperties:M,KeywordType:D,KeywordEnum:L,KeywordConst:q,KeywordConstraint:B,KeywordDependentRequired:U,KeywordContentSchema:J,KeywordTitle:$,KeywordDescription:V,KeywordDefault:K,KeywordDeprecated:F,KeywordReadOnly:z,KeywordWriteOnly:W,Accordion:H,ExpandDeepButton:G,ChevronRightIcon:Z},fn:{upperFirst:s.upperFirst,isExpandable:s.jsonSchema202012.isExpandable,getProperties:s.jsonSchema202012.getProperties}}),n.default.createElement(r.ModelsWithJSONSchemaContext,null)}));r.ModelsWithJSONSchemaContext=null;const s=r},1434:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(863),r=a(810);const s=(e,t)=>e=>{const a=t.specSelectors.isOAS31(),s=t.getComponent("OAS31VersionPragmaFilter");return r.default.createElement(s,(0,n.default)({isOAS31:a},e))}},1122:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);const r=(0,a(4380).createOnlyOAS31ComponentWrapper)((e=>{let{originalComponent:t,...a}=e;return n.default.createElement("span",null,n.default.createElement(t,a),n.default.createElement("small",{className:"version-stamp"},n.default.createElement("pre",{className:"version"},"OAS 3.1")))}))},8560:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(6235);let r=!1;function s(){return{statePlugins:{spec:{wrapActions:{updateSpec:e=>function(){return r=!0,e(...arguments)},updateJsonSpec:(e,t)=>function(){const a=t.getConfigs().onComplete;return r&&"function"==typeof a&&((0,n.default)(a,0),r=!1),e(...arguments)}}}}}}},4624:(e,t,a)=>{a.r(t),a.d(t,{requestSnippetGenerator_curl_bash:()=>E,requestSnippetGenerator_curl_cmd:()=>S,requestSnippetGenerator_curl_powershell:()=>v});var n=a(8818),r=a(5942),s=a(313),o=a(2565);const i=(e=>{var t={};return a.d(t,e),t})({default:()=>l.default});var c=a(2954),u=a(2372),d=a(7504),p=a(9725);const m=e=>{var t;const a="_**[]";return(0,n.default)(e).call(e,a)<0?e:(0,r.default)(t=e.split(a)[0]).call(t)},f=e=>"-d "===e||/^[_\/-]/g.test(e)?e:"'"+e.replace(/'/g,"'\\''")+"'",h=e=>"-d "===(e=e.replace(/\^/g,"^^").replace(/\\"/g,'\\\\"').replace(/"/g,'""').replace(/\n/g,"^\n"))?e.replace(/-d /g,"-d ^\n"):/^[_\/-]/g.test(e)?e:'"'+e+'"',g=e=>"-d "===e?e:/\n/.test(e)?'@"\n'+e.replace(/"/g,'\\"').replace(/`/g,"``").replace(/\$/,"`$")+'\n"@':/^[_\/-]/g.test(e)?e:"'"+e.replace(/"/g,'""').replace(/'/g,"''")+"'";const y=function(e,t,a){let n=arguments.length>3&&void 0!==arguments[3]?arguments[3]:"",r=!1,l="";const f=function(){for(var e=arguments.length,a=new Array(e),n=0;n<e;n++)a[n]=arguments[n];return l+=" "+(0,o.default)(a).call(a,t).join(" ")},h=function(){for(var e=arguments.length,a=new Array(e),n=0;n<e;n++)a[n]=arguments[n];return l+=(0,o.default)(a).call(a,t).join(" ")},g=()=>l+=` ${a}`,y=function(){let e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:1;return l+=(0,i.default)("  ").call("  ",e)};let v=e.get("headers");if(l+="curl"+n,e.has("curlOptions")&&f(...e.get("curlOptions")),f("-X",e.get("method")),g(),y(),h(`${e.get("url")}`),v&&v.size)for(let t of(0,c.default)(E=e.get("headers")).call(E)){var E;g(),y();let[e,a]=t;h("-H",`${e}: ${a}`),r=r||/^content-type$/i.test(e)&&/^multipart\/form-data$/i.test(a)}const S=e.get("body");var w;if(S)if(r&&(0,u.default)(w=["POST","PUT","PATCH"]).call(w,e.get("method")))for(let[e,t]of S.entrySeq()){let a=m(e);g(),y(),h("-F"),t instanceof d.Z.File&&"string"==typeof t.valueOf()?f(`${a}=${t.data}${t.type?`;type=${t.type}`:""}`):t instanceof d.Z.File?f(`${a}=@${t.name}${t.type?`;type=${t.type}`:""}`):f(`${a}=${t}`)}else if(S instanceof d.Z.File)g(),y(),h(`--data-binary '@${S.name}'`);else{g(),y(),h("-d ");let t=S;p.Map.isMap(t)?h(function(e){let t=[];for(let[a,n]of e.get("body").entrySeq()){let e=m(a);n instanceof d.Z.File?t.push(`  "${e}": {\n    "name": "${n.name}"${n.type?`,\n    "type": "${n.type}"`:""}\n  }`):t.push(`  "${e}": ${(0,s.default)(n,null,2).replace(/(\r\n|\r|\n)/g,"\n  ")}`)}return`{\n${t.join(",\n")}\n}`}(e)):("string"!=typeof t&&(t=(0,s.default)(t)),h(t))}else S||"POST"!==e.get("method")||(g(),y(),h("-d ''"));return l},v=e=>y(e,g,"`\n",".exe"),E=e=>y(e,f,"\\\n"),S=e=>y(e,h,"^\n")},6575:(e,t,a)=>{a.r(t),a.d(t,{default:()=>o});var n=a(4624),r=a(4669),s=a(4206);const o=()=>({components:{RequestSnippets:s.default},fn:n,statePlugins:{requestSnippets:{selectors:r}}})},4206:(e,t,a)=>{a.r(t),a.d(t,{default:()=>f});var n=a(6145),r=a(8898),s=a(29),o=a(2565),l=a(810),i=(a(5053),a(9908)),c=a(7068),u=a(9874),d=a(471);const p={cursor:"pointer",lineHeight:1,display:"inline-flex",backgroundColor:"rgb(250, 250, 250)",paddingBottom:"0",paddingTop:"0",border:"1px solid rgb(51, 51, 51)",borderRadius:"4px 4px 0 0",boxShadow:"none",borderBottom:"none"},m={cursor:"pointer",lineHeight:1,display:"inline-flex",backgroundColor:"rgb(51, 51, 51)",boxShadow:"none",border:"1px solid rgb(51, 51, 51)",paddingBottom:"0",paddingTop:"0",borderRadius:"4px 4px 0 0",marginTop:"-5px",marginRight:"-5px",marginLeft:"-5px",zIndex:"9999",borderBottom:"none"},f=e=>{var t,a;let{request:f,requestSnippetsSelectors:h,getConfigs:g,getComponent:y}=e;const v=(0,c.default)(g)?g():null,E=!1!==(0,i.default)(v,"syntaxHighlight")&&(0,i.default)(v,"syntaxHighlight.activated",!0),S=(0,l.useRef)(null),w=y("ArrowUpIcon"),b=y("ArrowDownIcon"),[x,_]=(0,l.useState)(null===(t=h.getSnippetGenerators())||void 0===t?void 0:t.keySeq().first()),[C,j]=(0,l.useState)(null==h?void 0:h.getDefaultExpanded());(0,l.useEffect)((()=>{}),[]),(0,l.useEffect)((()=>{var e;const t=(0,n.default)(e=(0,r.default)(S.current.childNodes)).call(e,(e=>{var t;return!!e.nodeType&&(null===(t=e.classList)||void 0===t?void 0:t.contains("curl-command"))}));return(0,s.default)(t).call(t,(e=>e.addEventListener("mousewheel",R,{passive:!1}))),()=>{(0,s.default)(t).call(t,(e=>e.removeEventListener("mousewheel",R)))}}),[f]);const N=h.getSnippetGenerators(),O=N.get(x),k=O.get("fn")(f),A=()=>{j(!C)},I=e=>e===x?m:p,R=e=>{const{target:t,deltaY:a}=e,{scrollHeight:n,offsetHeight:r,scrollTop:s}=t;n>r&&(0===s&&a<0||r+s>=n&&a>0)&&e.preventDefault()},P=E?l.default.createElement(d.d3,{language:O.get("syntax"),className:"curl microlight",style:(0,d.C2)((0,i.default)(v,"syntaxHighlight.theme"))},k):l.default.createElement("textarea",{readOnly:!0,className:"curl",value:k});return l.default.createElement("div",{className:"request-snippets",ref:S},l.default.createElement("div",{style:{width:"100%",display:"flex",justifyContent:"flex-start",alignItems:"center",marginBottom:"15px"}},l.default.createElement("h4",{onClick:()=>A(),style:{cursor:"pointer"}},"Snippets"),l.default.createElement("button",{onClick:()=>A(),style:{border:"none",background:"none"},title:C?"Collapse operation":"Expand operation"},C?l.default.createElement(b,{className:"arrow",width:"10",height:"10"}):l.default.createElement(w,{className:"arrow",width:"10",height:"10"}))),C&&l.default.createElement("div",{className:"curl-command"},l.default.createElement("div",{style:{paddingLeft:"15px",paddingRight:"10px",width:"100%",display:"flex"}},(0,o.default)(a=N.entrySeq()).call(a,(e=>{let[t,a]=e;return l.default.createElement("div",{style:I(t),className:"btn",key:t,onClick:()=>(e=>{x!==e&&_(e)})(t)},l.default.createElement("h4",{style:t===x?{color:"white"}:{}},a.get("title")))}))),l.default.createElement("div",{className:"copy-to-clipboard"},l.default.createElement(u.CopyToClipboard,{text:k},l.default.createElement("button",null))),l.default.createElement("div",null,P)))}},4669:(e,t,a)=>{a.r(t),a.d(t,{getActiveLanguage:()=>d,getDefaultExpanded:()=>p,getGenerators:()=>c,getSnippetGenerators:()=>u});var n=a(6145),r=a(2372),s=a(2565),o=a(8639),l=a(9725);const i=e=>e||(0,l.Map)(),c=(0,o.createSelector)(i,(e=>{const t=e.get("languages"),a=e.get("generators",(0,l.Map)());return!t||t.isEmpty()?a:(0,n.default)(a).call(a,((e,a)=>(0,r.default)(t).call(t,a)))})),u=e=>t=>{var a,r;let{fn:o}=t;return(0,n.default)(a=(0,s.default)(r=c(e)).call(r,((e,t)=>{const a=(e=>o[`requestSnippetGenerator_${e}`])(t);return"function"!=typeof a?null:e.set("fn",a)}))).call(a,(e=>e))},d=(0,o.createSelector)(i,(e=>e.get("activeLanguage"))),p=(0,o.createSelector)(i,(e=>e.get("defaultExpanded")))},6195:(e,t,a)=>{a.r(t),a.d(t,{ErrorBoundary:()=>o,default:()=>l});a(5053);var n=a(810),r=a(6189),s=a(9403);class o extends n.Component{static getDerivedStateFromError(e){return{hasError:!0,error:e}}constructor(){super(...arguments),this.state={hasError:!1,error:null}}componentDidCatch(e,t){this.props.fn.componentDidCatch(e,t)}render(){const{getComponent:e,targetName:t,children:a}=this.props;if(this.state.hasError){const a=e("Fallback");return n.default.createElement(a,{name:t})}return a}}o.defaultProps={targetName:"this component",getComponent:()=>s.default,fn:{componentDidCatch:r.componentDidCatch},children:null};const l=o},9403:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(810);a(5053);const r=e=>{let{name:t}=e;return n.default.createElement("div",{className:"fallback"},"😱 ",n.default.createElement("i",null,"Could not render ","t"===t?"this component":t,", see the console."))}},6189:(e,t,a)=>{a.r(t),a.d(t,{componentDidCatch:()=>s,withErrorBoundary:()=>o});var n=a(863),r=a(810);const s=console.error,o=e=>t=>{const{getComponent:a,fn:s}=e(),o=a("ErrorBoundary"),l=s.getDisplayName(t);class i extends r.Component{render(){return r.default.createElement(o,{targetName:l,getComponent:a,fn:s},r.default.createElement(t,(0,n.default)({},this.props,this.context)))}}var c;return i.displayName=`WithErrorBoundary(${l})`,(c=t).prototype&&c.prototype.isReactComponent&&(i.prototype.mapStateToProps=t.prototype.mapStateToProps),i}},8102:(e,t,a)=>{a.r(t),a.d(t,{default:()=>u});const n=(e=>{var t={};return a.d(t,e),t})({default:()=>i.default});const r=(e=>{var t={};return a.d(t,e),t})({default:()=>c.default});var s=a(6195),o=a(9403),l=a(6189);const u=function(){let{componentList:e=[],fullOverride:t=!1}=arguments.length>0&&void 0!==arguments[0]?arguments[0]:{};return a=>{var i;let{getSystem:c}=a;const u=t?e:["App","BaseLayout","VersionPragmaFilter","InfoContainer","ServersContainer","SchemesContainer","AuthorizeBtnContainer","FilterContainer","Operations","OperationContainer","parameters","responses","OperationServers","Models","ModelWrapper",...e],d=(0,r.default)(u,(0,n.default)(i=Array(u.length)).call(i,((e,t)=>{let{fn:a}=t;return a.withErrorBoundary(e)})));return{fn:{componentDidCatch:l.componentDidCatch,withErrorBoundary:(0,l.withErrorBoundary)(c)},components:{ErrorBoundary:s.default,Fallback:o.default},wrapComponents:d}}}},2846:(e,t,a)=>{a.r(t),a.d(t,{default:()=>i});var n=a(6785),r=a(313),s=a(841);const o=[{when:/json/,shouldStringifyTypes:["string"]}],l=["object"],i=e=>(t,a,i,c)=>{const{fn:u}=e(),d=u.memoizedSampleFromSchema(t,a,c),p=typeof d,m=(0,n.default)(o).call(o,((e,t)=>t.when.test(i)?[...e,...t.shouldStringifyTypes]:e),l);return(0,s.default)(m,(e=>e===p))?(0,r.default)(d,null,2):d}},6132:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=e=>function(t){var a,n;let r=arguments.length>1&&void 0!==arguments[1]?arguments[1]:"",s=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{},o=arguments.length>3&&void 0!==arguments[3]?arguments[3]:void 0;const{fn:l}=e();return"function"==typeof(null===(a=t)||void 0===a?void 0:a.toJS)&&(t=t.toJS()),"function"==typeof(null===(n=o)||void 0===n?void 0:n.toJS)&&(o=o.toJS()),/xml/.test(r)?l.getXmlSampleSchema(t,s,o):/(yaml|yml)/.test(r)?l.getYamlSampleSchema(t,s,r,o):l.getJsonSampleSchema(t,s,r,o)}},1169:(e,t,a)=>{a.r(t),a.d(t,{default:()=>n});const n=e=>(t,a,n)=>{const{fn:r}=e();if(t&&!t.xml&&(t.xml={}),t&&!t.xml.name){if(!t.$$ref&&(t.type||t.items||t.properties||t.additionalProperties))return'<?xml version="1.0" encoding="UTF-8"?>\n\x3c!-- XML example cannot be generated; root element name is undefined --\x3e';if(t.$$ref){let e=t.$$ref.match(/\S*\/(\S+)$/);t.xml.name=e[1]}}return r.memoizedCreateXMLExample(t,a,n)}},9431:(e,t,a)=>{a.r(t),a.d(t,{default:()=>s});var n=a(8136),r=a(3772);const s=e=>(t,a,s,o)=>{const{fn:l}=e(),i=l.getJsonSampleSchema(t,a,s,o);let c;try{c=r.default.dump(r.default.load(i),{lineWidth:-1},{schema:r.JSON_SCHEMA}),"\n"===c[c.length-1]&&(c=(0,n.default)(c).call(c,0,c.length-1))}catch(e){return console.error(e),"error: could not generate yaml example"}return c.replace(/\t/g,"  ")}},9812:(e,t,a)=>{a.r(t),a.d(t,{createXMLExample:()=>k,inferSchema:()=>O,memoizedCreateXMLExample:()=>R,memoizedSampleFromSchema:()=>P,sampleFromSchema:()=>A,sampleFromSchemaGeneric:()=>N});var n=a(8818),r=a(29),s=a(4163),o=a(2372),l=a(9963),i=a(8136),c=a(1778),u=a(5171),d=a(2565),p=a(313),m=a(3479),f=a.n(m),h=a(2092),g=a(8747),y=a(6561),v=a(9669);const E={string:e=>e.pattern?(e=>{try{return new h.default(e).gen()}catch(e){return"string"}})(e.pattern):"string",string_email:()=>"user@example.com","string_date-time":()=>(new Date).toISOString(),string_date:()=>(new Date).toISOString().substring(0,10),string_uuid:()=>"3fa85f64-5717-4562-b3fc-2c963f66afa6",string_hostname:()=>"example.com",string_ipv4:()=>"198.51.100.42",string_ipv6:()=>"2001:0db8:5b96:0000:0000:426f:8e17:642a",number:()=>0,number_float:()=>0,integer:()=>0,boolean:e=>"boolean"!=typeof e.default||e.default},S=e=>{e=(0,y.mz)(e);let{type:t,format:a}=e,n=E[`${t}_${a}`]||E[t];return(0,y.Wl)(n)?n(e):"Unknown Type: "+e.type},w=e=>(0,y.XV)(e,"$$ref",(e=>"string"==typeof e&&(0,n.default)(e).call(e,"#")>-1)),b=["maxProperties","minProperties"],x=["minItems","maxItems"],_=["minimum","maximum","exclusiveMinimum","exclusiveMaximum"],C=["minLength","maxLength"],j=function(e,t){var a;let l=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{};var i;((0,r.default)(a=["example","default","enum","xml","type",...b,...x,..._,...C]).call(a,(a=>(a=>{void 0===t[a]&&void 0!==e[a]&&(t[a]=e[a])})(a))),void 0!==e.required&&(0,s.default)(e.required))&&(void 0!==t.required&&t.required.length||(t.required=[]),(0,r.default)(i=e.required).call(i,(e=>{var a;(0,o.default)(a=t.required).call(a,e)||t.required.push(e)})));if(e.properties){t.properties||(t.properties={});let a=(0,y.mz)(e.properties);for(let r in a){var c;if(Object.prototype.hasOwnProperty.call(a,r))if(!a[r]||!a[r].deprecated)if(!a[r]||!a[r].readOnly||l.includeReadOnly)if(!a[r]||!a[r].writeOnly||l.includeWriteOnly)if(!t.properties[r])t.properties[r]=a[r],!e.required&&(0,s.default)(e.required)&&-1!==(0,n.default)(c=e.required).call(c,r)&&(t.required?t.required.push(r):t.required=[r])}}return e.items&&(t.items||(t.items={}),t.items=j(e.items,t.items,l)),t},N=function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{},a=arguments.length>2&&void 0!==arguments[2]?arguments[2]:void 0,p=arguments.length>3&&void 0!==arguments[3]&&arguments[3];e&&(0,y.Wl)(e.toJS)&&(e=e.toJS());let m=void 0!==a||e&&void 0!==e.example||e&&void 0!==e.default;const f=!m&&e&&e.oneOf&&e.oneOf.length>0,h=!m&&e&&e.anyOf&&e.anyOf.length>0;if(!m&&(f||h)){const a=(0,y.mz)(f?e.oneOf[0]:e.anyOf[0]);if(j(a,e,t),!e.xml&&a.xml&&(e.xml=a.xml),void 0!==e.example&&void 0!==a.example)m=!0;else if(a.properties){e.properties||(e.properties={});let r=(0,y.mz)(a.properties);for(let o in r){var v;if(Object.prototype.hasOwnProperty.call(r,o))if(!r[o]||!r[o].deprecated)if(!r[o]||!r[o].readOnly||t.includeReadOnly)if(!r[o]||!r[o].writeOnly||t.includeWriteOnly)if(!e.properties[o])e.properties[o]=r[o],!a.required&&(0,s.default)(a.required)&&-1!==(0,n.default)(v=a.required).call(v,o)&&(e.required?e.required.push(o):e.required=[o])}}}const E={};let{xml:C,type:O,example:k,properties:A,additionalProperties:I,items:R}=e||{},{includeReadOnly:P,includeWriteOnly:T}=t;C=C||{};let M,{name:D,prefix:L,namespace:q}=C,B={};if(p&&(D=D||"notagname",M=(L?L+":":"")+D,q)){E[L?"xmlns:"+L:"xmlns"]=q}p&&(B[M]=[]);const U=t=>(0,l.default)(t).call(t,(t=>Object.prototype.hasOwnProperty.call(e,t)));e&&!O&&(A||I||U(b)?O="object":R||U(x)?O="array":U(_)?(O="number",e.type="number"):m||e.enum||(O="string",e.type="string"));const J=t=>{var a,n,r,s,o;null!==(null===(a=e)||void 0===a?void 0:a.maxItems)&&void 0!==(null===(n=e)||void 0===n?void 0:n.maxItems)&&(t=(0,i.default)(t).call(t,0,null===(o=e)||void 0===o?void 0:o.maxItems));if(null!==(null===(r=e)||void 0===r?void 0:r.minItems)&&void 0!==(null===(s=e)||void 0===s?void 0:s.minItems)){let a=0;for(;t.length<(null===(l=e)||void 0===l?void 0:l.minItems);){var l;t.push(t[a++%t.length])}}return t},$=(0,y.mz)(A);let V,K=0;const F=()=>e&&null!==e.maxProperties&&void 0!==e.maxProperties&&K>=e.maxProperties,z=t=>!e||null===e.maxProperties||void 0===e.maxProperties||!F()&&(!(t=>{var a;return!(e&&e.required&&e.required.length&&(0,o.default)(a=e.required).call(a,t))})(t)||e.maxProperties-K-(()=>{if(!e||!e.required)return 0;let t=0;var a,n;return p?(0,r.default)(a=e.required).call(a,(e=>t+=void 0===B[e]?0:1)):(0,r.default)(n=e.required).call(n,(e=>{var a;return t+=void 0===(null===(a=B[M])||void 0===a?void 0:(0,c.default)(a).call(a,(t=>void 0!==t[e])))?0:1})),e.required.length-t})()>0);if(V=p?function(a){let n=arguments.length>1&&void 0!==arguments[1]?arguments[1]:void 0;if(e&&$[a]){if($[a].xml=$[a].xml||{},$[a].xml.attribute){const e=(0,s.default)($[a].enum)?$[a].enum[0]:void 0,t=$[a].example,n=$[a].default;return void(E[$[a].xml.name||a]=void 0!==t?t:void 0!==n?n:void 0!==e?e:S($[a]))}$[a].xml.name=$[a].xml.name||a}else $[a]||!1===I||($[a]={xml:{name:a}});let r=N(e&&$[a]||void 0,t,n,p);var o;z(a)&&(K++,(0,s.default)(r)?B[M]=(0,u.default)(o=B[M]).call(o,r):B[M].push(r))}:(a,n)=>{if(z(a)){if(Object.prototype.hasOwnProperty.call(e,"discriminator")&&e.discriminator&&Object.prototype.hasOwnProperty.call(e.discriminator,"mapping")&&e.discriminator.mapping&&Object.prototype.hasOwnProperty.call(e,"$$ref")&&e.$$ref&&e.discriminator.propertyName===a){for(let t in e.discriminator.mapping)if(-1!==e.$$ref.search(e.discriminator.mapping[t])){B[a]=t;break}}else B[a]=N($[a],t,n,p);K++}},m){let n;if(n=w(void 0!==a?a:void 0!==k?k:e.default),!p){if("number"==typeof n&&"string"===O)return`${n}`;if("string"!=typeof n||"string"===O)return n;try{return JSON.parse(n)}catch(e){return n}}if(e||(O=(0,s.default)(n)?"array":typeof n),"array"===O){if(!(0,s.default)(n)){if("string"==typeof n)return n;n=[n]}const a=e?e.items:void 0;a&&(a.xml=a.xml||C||{},a.xml.name=a.xml.name||C.name);let r=(0,d.default)(n).call(n,(e=>N(a,t,e,p)));return r=J(r),C.wrapped?(B[M]=r,(0,g.default)(E)||B[M].push({_attr:E})):B=r,B}if("object"===O){if("string"==typeof n)return n;for(let t in n)Object.prototype.hasOwnProperty.call(n,t)&&(e&&$[t]&&$[t].readOnly&&!P||e&&$[t]&&$[t].writeOnly&&!T||(e&&$[t]&&$[t].xml&&$[t].xml.attribute?E[$[t].xml.name||t]=n[t]:V(t,n[t])));return(0,g.default)(E)||B[M].push({_attr:E}),B}return B[M]=(0,g.default)(E)?n:[{_attr:E},n],B}if("object"===O){for(let e in $)Object.prototype.hasOwnProperty.call($,e)&&($[e]&&$[e].deprecated||$[e]&&$[e].readOnly&&!P||$[e]&&$[e].writeOnly&&!T||V(e));if(p&&E&&B[M].push({_attr:E}),F())return B;if(!0===I)p?B[M].push({additionalProp:"Anything can be here"}):B.additionalProp1={},K++;else if(I){const a=(0,y.mz)(I),n=N(a,t,void 0,p);if(p&&a.xml&&a.xml.name&&"notagname"!==a.xml.name)B[M].push(n);else{const t=null!==e.minProperties&&void 0!==e.minProperties&&K<e.minProperties?e.minProperties-K:3;for(let e=1;e<=t;e++){if(F())return B;if(p){const t={};t["additionalProp"+e]=n.notagname,B[M].push(t)}else B["additionalProp"+e]=n;K++}}}return B}if("array"===O){if(!R)return;let a;var W,H;if(p)R.xml=R.xml||(null===(W=e)||void 0===W?void 0:W.xml)||{},R.xml.name=R.xml.name||C.name;if((0,s.default)(R.anyOf))a=(0,d.default)(H=R.anyOf).call(H,(e=>N(j(R,e,t),t,void 0,p)));else if((0,s.default)(R.oneOf)){var G;a=(0,d.default)(G=R.oneOf).call(G,(e=>N(j(R,e,t),t,void 0,p)))}else{if(!(!p||p&&C.wrapped))return N(R,t,void 0,p);a=[N(R,t,void 0,p)]}return a=J(a),p&&C.wrapped?(B[M]=a,(0,g.default)(E)||B[M].push({_attr:E}),B):a}let Z;if(e&&(0,s.default)(e.enum))Z=(0,y.AF)(e.enum)[0];else{if(!e)return;if(Z=S(e),"number"==typeof Z){let t=e.minimum;null!=t&&(e.exclusiveMinimum&&t++,Z=t);let a=e.maximum;null!=a&&(e.exclusiveMaximum&&a--,Z=a)}if("string"==typeof Z&&(null!==e.maxLength&&void 0!==e.maxLength&&(Z=(0,i.default)(Z).call(Z,0,e.maxLength)),null!==e.minLength&&void 0!==e.minLength)){let t=0;for(;Z.length<e.minLength;)Z+=Z[t++%Z.length]}}if("file"!==O)return p?(B[M]=(0,g.default)(E)?Z:[{_attr:E},Z],B):Z},O=e=>(e.schema&&(e=e.schema),e.properties&&(e.type="object"),e),k=(e,t,a)=>{const n=N(e,t,a,!0);if(n)return"string"==typeof n?n:f()(n,{declaration:!0,indent:"\t"})},A=(e,t,a)=>N(e,t,a,!1),I=(e,t,a)=>[e,(0,p.default)(t),(0,p.default)(a)],R=(0,v.Z)(k,I),P=(0,v.Z)(A,I)},8883:(e,t,a)=>{a.r(t),a.d(t,{default:()=>i});var n=a(9812),r=a(2846),s=a(9431),o=a(1169),l=a(6132);const i=e=>{let{getSystem:t}=e;return{fn:{inferSchema:n.inferSchema,sampleFromSchema:n.sampleFromSchema,sampleFromSchemaGeneric:n.sampleFromSchemaGeneric,createXMLExample:n.createXMLExample,memoizedSampleFromSchema:n.memoizedSampleFromSchema,memoizedCreateXMLExample:n.memoizedCreateXMLExample,getJsonSampleSchema:(0,r.default)(t),getYamlSampleSchema:(0,s.default)(t),getXmlSampleSchema:(0,o.default)(t),getSampleSchema:(0,l.default)(t)}}}},7612:(e,t,a)=>{a.r(t),a.d(t,{CLEAR_REQUEST:()=>V,CLEAR_RESPONSE:()=>$,CLEAR_VALIDATE_PARAMS:()=>K,LOG_REQUEST:()=>J,SET_MUTATED_REQUEST:()=>U,SET_REQUEST:()=>B,SET_RESPONSE:()=>q,SET_SCHEME:()=>H,UPDATE_EMPTY_PARAM_INCLUSION:()=>D,UPDATE_JSON:()=>T,UPDATE_OPERATION_META_VALUE:()=>F,UPDATE_PARAM:()=>M,UPDATE_RESOLVED:()=>z,UPDATE_RESOLVED_SUBTREE:()=>W,UPDATE_SPEC:()=>R,UPDATE_URL:()=>P,VALIDATE_PARAMS:()=>L,changeConsumesValue:()=>me,changeParam:()=>oe,changeParamByIdentity:()=>le,changeProducesValue:()=>fe,clearRequest:()=>be,clearResponse:()=>we,clearValidateParams:()=>pe,execute:()=>Se,executeRequest:()=>Ee,invalidateResolvedSubtreeCache:()=>ce,logRequest:()=>ve,parseToJson:()=>ee,requestResolvedSubtree:()=>se,resolveSpec:()=>ae,setMutatedRequest:()=>ye,setRequest:()=>ge,setResponse:()=>he,setScheme:()=>xe,updateEmptyParamInclusion:()=>de,updateJsonSpec:()=>Q,updateResolved:()=>Y,updateResolvedSubtree:()=>ie,updateSpec:()=>Z,updateUrl:()=>X,validateParams:()=>ue});var n=a(4163),r=a(2565),s=a(6718),o=a.n(s),l=a(6785),i=a(7930);const c=(e=>{var t={};return a.d(t,e),t})({default:()=>u.default});var g=a(6145),y=a(374),v=a(8818),E=a(29),S=a(2740),w=a(7512);const b=(e=>{var t={};return a.d(t,e),t})({default:()=>d.default});var x=a(3772),_=a(9725),C=a(8900),j=a(8518);const N=(e=>{var t={};return a.d(t,e),t})({default:()=>p.default});const O=(e=>{var t={};return a.d(t,e),t})({default:()=>m.default});const k=(e=>{var t={};return a.d(t,e),t})({default:()=>f.default});const A=(e=>{var t={};return a.d(t,e),t})({default:()=>h.default});var I=a(6561);const R="spec_update_spec",P="spec_update_url",T="spec_update_json",M="spec_update_param",D="spec_update_empty_param_inclusion",L="spec_validate_param",q="spec_set_response",B="spec_set_request",U="spec_set_mutated_request",J="spec_log_request",$="spec_clear_response",V="spec_clear_request",K="spec_clear_validate_param",F="spec_update_operation_meta_value",z="spec_update_resolved",W="spec_update_resolved_subtree",H="set_scheme",G=e=>(0,N.default)(e)?e:"";function Z(e){const t=G(e).replace(/\t/g,"  ");if("string"==typeof e)return{type:R,payload:t}}function Y(e){return{type:z,payload:e}}function X(e){return{type:P,payload:e}}function Q(e){return{type:T,payload:e}}const ee=e=>t=>{let{specActions:a,specSelectors:n,errActions:r}=t,{specStr:s}=n,o=null;try{e=e||s(),r.clear({source:"parser"}),o=x.default.load(e,{schema:x.JSON_SCHEMA})}catch(e){return console.error(e),r.newSpecErr({source:"parser",level:"error",message:e.reason,line:e.mark&&e.mark.line?e.mark.line+1:void 0})}return o&&"object"==typeof o?a.updateJsonSpec(o):{}};let te=!1;const ae=(e,t)=>a=>{let{specActions:s,specSelectors:l,errActions:i,fn:{fetch:c,resolve:u,AST:d={}},getConfigs:p}=a;te||(console.warn("specActions.resolveSpec is deprecated since v3.10.0 and will be removed in v4.0.0; use requestResolvedSubtree instead!"),te=!0);const{modelPropertyMacro:m,parameterMacro:f,requestInterceptor:h,responseInterceptor:g}=p();void 0===e&&(e=l.specJson()),void 0===t&&(t=l.url());let y=d.getLineNumberForPath?d.getLineNumberForPath:()=>{},v=l.specStr();return u({fetch:c,spec:e,baseDoc:t,modelPropertyMacro:m,parameterMacro:f,requestInterceptor:h,responseInterceptor:g}).then((e=>{let{spec:t,errors:a}=e;if(i.clear({type:"thrown"}),(0,n.default)(a)&&a.length>0){let e=(0,r.default)(a).call(a,(e=>(console.error(e),e.line=e.fullPath?y(v,e.fullPath):null,e.path=e.fullPath?e.fullPath.join("."):null,e.level="error",e.type="thrown",e.source="resolver",o()(e,"message",{enumerable:!0,value:e.message}),e)));i.newThrownErrBatch(e)}return s.updateResolved(t)}))};let ne=[];const re=(0,O.default)((async()=>{const e=ne.system;if(!e)return void console.error("debResolveSubtrees: don't have a system to operate on, aborting.");const{errActions:t,errSelectors:a,fn:{resolveSubtree:s,fetch:u,AST:d={}},specSelectors:p,specActions:m}=e;if(!s)return void console.error("Error: Swagger-Client did not provide a `resolveSubtree` method, doing nothing.");let f=d.getLineNumberForPath?d.getLineNumberForPath:()=>{};const h=p.specStr(),{modelPropertyMacro:v,parameterMacro:E,requestInterceptor:S,responseInterceptor:w}=e.getConfigs();try{var b=await(0,l.default)(ne).call(ne,(async(e,l)=>{let{resultMap:d,specWithCurrentSubtrees:m}=await e;const{errors:b,spec:x}=await s(m,l,{baseDoc:p.url(),modelPropertyMacro:v,parameterMacro:E,requestInterceptor:S,responseInterceptor:w});if(a.allErrors().size&&t.clearBy((e=>{var t;return"thrown"!==e.get("type")||"resolver"!==e.get("source")||!(0,i.default)(t=e.get("fullPath")).call(t,((e,t)=>e===l[t]||void 0===l[t]))})),(0,n.default)(b)&&b.length>0){let e=(0,r.default)(b).call(b,(e=>(e.line=e.fullPath?f(h,e.fullPath):null,e.path=e.fullPath?e.fullPath.join("."):null,e.level="error",e.type="thrown",e.source="resolver",o()(e,"message",{enumerable:!0,value:e.message}),e)));t.newThrownErrBatch(e)}var _,C;x&&p.isOAS3()&&"components"===l[0]&&"securitySchemes"===l[1]&&await c.default.all((0,r.default)(_=(0,g.default)(C=(0,y.default)(x)).call(C,(e=>"openIdConnect"===e.type))).call(_,(async e=>{const t={url:e.openIdConnectUrl,requestInterceptor:S,responseInterceptor:w};try{const a=await u(t);a instanceof Error||a.status>=400?console.error(a.statusText+" "+t.url):e.openIdConnectData=JSON.parse(a.text)}catch(e){console.error(e)}})));return(0,k.default)(d,l,x),m=(0,A.default)(l,x,m),{resultMap:d,specWithCurrentSubtrees:m}}),c.default.resolve({resultMap:(p.specResolvedSubtree([])||(0,_.Map)()).toJS(),specWithCurrentSubtrees:p.specJS()}));delete ne.system,ne=[]}catch(e){console.error(e)}m.updateResolvedSubtree([],b.resultMap)}),35),se=e=>t=>{var a;(0,v.default)(a=(0,r.default)(ne).call(ne,(e=>e.join("@@")))).call(a,e.join("@@"))>-1||(ne.push(e),ne.system=t,re())};function oe(e,t,a,n,r){return{type:M,payload:{path:e,value:n,paramName:t,paramIn:a,isXml:r}}}function le(e,t,a,n){return{type:M,payload:{path:e,param:t,value:a,isXml:n}}}const ie=(e,t)=>({type:W,payload:{path:e,value:t}}),ce=()=>({type:W,payload:{path:[],value:(0,_.Map)()}}),ue=(e,t)=>({type:L,payload:{pathMethod:e,isOAS3:t}}),de=(e,t,a,n)=>({type:D,payload:{pathMethod:e,paramName:t,paramIn:a,includeEmptyValue:n}});function pe(e){return{type:K,payload:{pathMethod:e}}}function me(e,t){return{type:F,payload:{path:e,value:t,key:"consumes_value"}}}function fe(e,t){return{type:F,payload:{path:e,value:t,key:"produces_value"}}}const he=(e,t,a)=>({payload:{path:e,method:t,res:a},type:q}),ge=(e,t,a)=>({payload:{path:e,method:t,req:a},type:B}),ye=(e,t,a)=>({payload:{path:e,method:t,req:a},type:U}),ve=e=>({payload:e,type:J}),Ee=e=>t=>{let{fn:a,specActions:s,specSelectors:o,getConfigs:l,oas3Selectors:i}=t,{pathName:c,method:u,operation:d}=e,{requestInterceptor:p,responseInterceptor:m}=l(),f=d.toJS();var h,y;d&&d.get("parameters")&&(0,E.default)(h=(0,g.default)(y=d.get("parameters")).call(y,(e=>e&&!0===e.get("allowEmptyValue")))).call(h,(t=>{if(o.parameterInclusionSettingFor([c,u],t.get("name"),t.get("in"))){e.parameters=e.parameters||{};const a=(0,I.cz)(t,e.parameters);(!a||a&&0===a.size)&&(e.parameters[t.get("name")]="")}}));if(e.contextUrl=(0,C.default)(o.url()).toString(),f&&f.operationId?e.operationId=f.operationId:f&&c&&u&&(e.operationId=a.opId(f,c,u)),o.isOAS3()){const t=`${c}:${u}`;e.server=i.selectedServer(t)||i.selectedServer();const a=i.serverVariables({server:e.server,namespace:t}).toJS(),s=i.serverVariables({server:e.server}).toJS();e.serverVariables=(0,S.default)(a).length?a:s,e.requestContentType=i.requestContentType(c,u),e.responseContentType=i.responseContentType(c,u)||"*/*";const o=i.requestBodyValue(c,u),l=i.requestBodyInclusionSetting(c,u);var v;if(o&&o.toJS)e.requestBody=(0,g.default)(v=(0,r.default)(o).call(o,(e=>_.Map.isMap(e)?e.get("value"):e))).call(v,((e,t)=>((0,n.default)(e)?0!==e.length:!(0,I.O2)(e))||l.get(t))).toJS();else e.requestBody=o}let x=(0,w.default)({},e);x=a.buildRequest(x),s.setRequest(e.pathName,e.method,x);e.requestInterceptor=async t=>{let a=await p.apply(void 0,[t]),n=(0,w.default)({},a);return s.setMutatedRequest(e.pathName,e.method,n),a},e.responseInterceptor=m;const N=(0,b.default)();return a.execute(e).then((t=>{t.duration=(0,b.default)()-N,s.setResponse(e.pathName,e.method,t)})).catch((t=>{"Failed to fetch"===t.message&&(t.name="",t.message='**Failed to fetch.**  \n**Possible Reasons:** \n  - CORS \n  - Network Failure \n  - URL scheme must be "http" or "https" for CORS request.'),s.setResponse(e.pathName,e.method,{error:!0,err:(0,j.serializeError)(t)})}))},Se=function(){let{path:e,method:t,...a}=arguments.length>0&&void 0!==arguments[0]?arguments[0]:{};return n=>{let{fn:{fetch:r},specSelectors:s,specActions:o}=n,l=s.specJsonWithResolvedSubtrees().toJS(),i=s.operationScheme(e,t),{requestContentType:c,responseContentType:u}=s.contentTypeValues([e,t]).toJS(),d=/xml/i.test(c),p=s.parameterValues([e,t],d).toJS();return o.executeRequest({...a,fetch:r,spec:l,pathName:e,method:t,parameters:p,requestContentType:c,scheme:i,responseContentType:u})}};function we(e,t){return{type:$,payload:{path:e,method:t}}}function be(e,t){return{type:V,payload:{path:e,method:t}}}function xe(e,t,a){return{type:H,payload:{scheme:e,path:t,method:a}}}},7038:(e,t,a)=>{a.r(t),a.d(t,{default:()=>l});var n=a(32),r=a(7612),s=a(3881),o=a(7508);function l(){return{statePlugins:{spec:{wrapActions:o,reducers:n.default,actions:r,selectors:s}}}}},32:(e,t,a)=>{a.r(t),a.d(t,{default:()=>d});var n=a(6785),r=a(2565),s=a(7512),o=a(9725),l=a(6561),i=a(7504),c=a(3881),u=a(7612);const d={[u.UPDATE_SPEC]:(e,t)=>"string"==typeof t.payload?e.set("spec",t.payload):e,[u.UPDATE_URL]:(e,t)=>e.set("url",t.payload+""),[u.UPDATE_JSON]:(e,t)=>e.set("json",(0,l.oG)(t.payload)),[u.UPDATE_RESOLVED]:(e,t)=>e.setIn(["resolved"],(0,l.oG)(t.payload)),[u.UPDATE_RESOLVED_SUBTREE]:(e,t)=>{const{value:a,path:n}=t.payload;return e.setIn(["resolvedSubtrees",...n],(0,l.oG)(a))},[u.UPDATE_PARAM]:(e,t)=>{let{payload:a}=t,{path:n,paramName:r,paramIn:s,param:o,value:i,isXml:c}=a,u=o?(0,l.V9)(o):`${s}.${r}`;const d=c?"value_xml":"value";return e.setIn(["meta","paths",...n,"parameters",u,d],i)},[u.UPDATE_EMPTY_PARAM_INCLUSION]:(e,t)=>{let{payload:a}=t,{pathMethod:n,paramName:r,paramIn:s,includeEmptyValue:o}=a;if(!r||!s)return console.warn("Warning: UPDATE_EMPTY_PARAM_INCLUSION could not generate a paramKey."),e;const l=`${s}.${r}`;return e.setIn(["meta","paths",...n,"parameter_inclusions",l],o)},[u.VALIDATE_PARAMS]:(e,t)=>{let{payload:{pathMethod:a,isOAS3:r}}=t;const s=(0,c.specJsonWithResolvedSubtrees)(e).getIn(["paths",...a]),i=(0,c.parameterValues)(e,a).toJS();return e.updateIn(["meta","paths",...a,"parameters"],(0,o.fromJS)({}),(t=>{var u;return(0,n.default)(u=s.get("parameters",(0,o.List)())).call(u,((t,n)=>{const s=(0,l.cz)(n,i),u=(0,c.parameterInclusionSettingFor)(e,a,n.get("name"),n.get("in")),d=(0,l.Ik)(n,s,{bypassRequiredCheck:u,isOAS3:r});return t.setIn([(0,l.V9)(n),"errors"],(0,o.fromJS)(d))}),t)}))},[u.CLEAR_VALIDATE_PARAMS]:(e,t)=>{let{payload:{pathMethod:a}}=t;return e.updateIn(["meta","paths",...a,"parameters"],(0,o.fromJS)([]),(e=>(0,r.default)(e).call(e,(e=>e.set("errors",(0,o.fromJS)([]))))))},[u.SET_RESPONSE]:(e,t)=>{let a,{payload:{res:n,path:r,method:o}}=t;a=n.error?(0,s.default)({error:!0,name:n.err.name,message:n.err.message,statusCode:n.err.statusCode},n.err.response):n,a.headers=a.headers||{};let c=e.setIn(["responses",r,o],(0,l.oG)(a));return i.Z.Blob&&n.data instanceof i.Z.Blob&&(c=c.setIn(["responses",r,o,"text"],n.data)),c},[u.SET_REQUEST]:(e,t)=>{let{payload:{req:a,path:n,method:r}}=t;return e.setIn(["requests",n,r],(0,l.oG)(a))},[u.SET_MUTATED_REQUEST]:(e,t)=>{let{payload:{req:a,path:n,method:r}}=t;return e.setIn(["mutatedRequests",n,r],(0,l.oG)(a))},[u.UPDATE_OPERATION_META_VALUE]:(e,t)=>{let{payload:{path:a,value:n,key:r}}=t,s=["paths",...a],l=["meta","paths",...a];return e.getIn(["json",...s])||e.getIn(["resolved",...s])||e.getIn(["resolvedSubtrees",...s])?e.setIn([...l,r],(0,o.fromJS)(n)):e},[u.CLEAR_RESPONSE]:(e,t)=>{let{payload:{path:a,method:n}}=t;return e.deleteIn(["responses",a,n])},[u.CLEAR_REQUEST]:(e,t)=>{let{payload:{path:a,method:n}}=t;return e.deleteIn(["requests",a,n])},[u.SET_SCHEME]:(e,t)=>{let{payload:{scheme:a,path:n,method:r}}=t;return n&&r?e.setIn(["scheme",n,r],a):n||r?void 0:e.setIn(["scheme","_defaultScheme"],a)}}},3881:(e,t,a)=>{a.r(t),a.d(t,{allowTryItOutFor:()=>ne,basePath:()=>V,canExecuteScheme:()=>ve,consumes:()=>L,consumesOptionsFor:()=>ge,contentTypeValues:()=>me,currentProducesFor:()=>fe,definitions:()=>$,externalDocs:()=>I,findDefinition:()=>J,getOAS3RequiredRequestBodyContentType:()=>we,getParameter:()=>ie,hasHost:()=>ce,host:()=>K,info:()=>A,isMediaTypeSchemaPropertiesEqual:()=>be,isOAS3:()=>k,lastError:()=>v,mutatedRequestFor:()=>ae,mutatedRequests:()=>Q,operationScheme:()=>ye,operationWithMeta:()=>le,operations:()=>D,operationsWithRootInherited:()=>z,operationsWithTags:()=>G,parameterInclusionSettingFor:()=>se,parameterValues:()=>ue,parameterWithMeta:()=>oe,parameterWithMetaByIdentity:()=>re,parametersIncludeIn:()=>de,parametersIncludeType:()=>pe,paths:()=>T,produces:()=>q,producesOptionsFor:()=>he,requestFor:()=>te,requests:()=>X,responseFor:()=>ee,responses:()=>Y,schemes:()=>F,security:()=>B,securityDefinitions:()=>U,semver:()=>P,spec:()=>O,specJS:()=>x,specJson:()=>b,specJsonWithResolvedSubtrees:()=>N,specResolved:()=>_,specResolvedSubtree:()=>C,specSource:()=>w,specStr:()=>S,tagDetails:()=>H,taggedOperations:()=>Z,tags:()=>W,url:()=>E,validOperationMethods:()=>M,validateBeforeExecute:()=>Se,validationErrors:()=>Ee,version:()=>R});var n=a(8136),r=a(29),s=a(8818),o=a(2565),l=a(6145),i=a(1778),c=a(6785),u=a(4350),d=a(9963),p=a(4163),m=a(8639),f=a(6561),h=a(9725);const g=["get","put","post","delete","options","head","patch","trace"],y=e=>e||(0,h.Map)(),v=(0,m.createSelector)(y,(e=>e.get("lastError"))),E=(0,m.createSelector)(y,(e=>e.get("url"))),S=(0,m.createSelector)(y,(e=>e.get("spec")||"")),w=(0,m.createSelector)(y,(e=>e.get("specSource")||"not-editor")),b=(0,m.createSelector)(y,(e=>e.get("json",(0,h.Map)()))),x=(0,m.createSelector)(b,(e=>e.toJS())),_=(0,m.createSelector)(y,(e=>e.get("resolved",(0,h.Map)()))),C=(e,t)=>e.getIn(["resolvedSubtrees",...t],void 0),j=(e,t)=>h.Map.isMap(e)&&h.Map.isMap(t)?t.get("$$ref")?t:(0,h.OrderedMap)().mergeWith(j,e,t):t,N=(0,m.createSelector)(y,(e=>(0,h.OrderedMap)().mergeWith(j,e.get("json"),e.get("resolvedSubtrees")))),O=e=>b(e),k=(0,m.createSelector)(O,(()=>!1)),A=(0,m.createSelector)(O,(e=>xe(e&&e.get("info")))),I=(0,m.createSelector)(O,(e=>xe(e&&e.get("externalDocs")))),R=(0,m.createSelector)(A,(e=>e&&e.get("version"))),P=(0,m.createSelector)(R,(e=>{var t;return(0,n.default)(t=/v?([0-9]*)\.([0-9]*)\.([0-9]*)/i.exec(e)).call(t,1)})),T=(0,m.createSelector)(N,(e=>e.get("paths"))),M=(0,m.createSelector)((()=>["get","put","post","delete","options","head","patch"])),D=(0,m.createSelector)(T,(e=>{if(!e||e.size<1)return(0,h.List)();let t=(0,h.List)();return e&&(0,r.default)(e)?((0,r.default)(e).call(e,((e,a)=>{if(!e||!(0,r.default)(e))return{};(0,r.default)(e).call(e,((e,n)=>{(0,s.default)(g).call(g,n)<0||(t=t.push((0,h.fromJS)({path:a,method:n,operation:e,id:`${n}-${a}`})))}))})),t):(0,h.List)()})),L=(0,m.createSelector)(O,(e=>(0,h.Set)(e.get("consumes")))),q=(0,m.createSelector)(O,(e=>(0,h.Set)(e.get("produces")))),B=(0,m.createSelector)(O,(e=>e.get("security",(0,h.List)()))),U=(0,m.createSelector)(O,(e=>e.get("securityDefinitions"))),J=(e,t)=>{const a=e.getIn(["resolvedSubtrees","definitions",t],null),n=e.getIn(["json","definitions",t],null);return a||n||null},$=(0,m.createSelector)(O,(e=>{const t=e.get("definitions");return h.Map.isMap(t)?t:(0,h.Map)()})),V=(0,m.createSelector)(O,(e=>e.get("basePath"))),K=(0,m.createSelector)(O,(e=>e.get("host"))),F=(0,m.createSelector)(O,(e=>e.get("schemes",(0,h.Map)()))),z=(0,m.createSelector)(D,L,q,((e,t,a)=>(0,o.default)(e).call(e,(e=>e.update("operation",(e=>{if(e){if(!h.Map.isMap(e))return;return e.withMutations((e=>(e.get("consumes")||e.update("consumes",(e=>(0,h.Set)(e).merge(t))),e.get("produces")||e.update("produces",(e=>(0,h.Set)(e).merge(a))),e)))}return(0,h.Map)()})))))),W=(0,m.createSelector)(O,(e=>{const t=e.get("tags",(0,h.List)());return h.List.isList(t)?(0,l.default)(t).call(t,(e=>h.Map.isMap(e))):(0,h.List)()})),H=(e,t)=>{var a;let n=W(e)||(0,h.List)();return(0,i.default)(a=(0,l.default)(n).call(n,h.Map.isMap)).call(a,(e=>e.get("name")===t),(0,h.Map)())},G=(0,m.createSelector)(z,W,((e,t)=>(0,c.default)(e).call(e,((e,t)=>{let a=(0,h.Set)(t.getIn(["operation","tags"]));return a.count()<1?e.update("default",(0,h.List)(),(e=>e.push(t))):(0,c.default)(a).call(a,((e,a)=>e.update(a,(0,h.List)(),(e=>e.push(t)))),e)}),(0,c.default)(t).call(t,((e,t)=>e.set(t.get("name"),(0,h.List)())),(0,h.OrderedMap)())))),Z=e=>t=>{var a;let{getConfigs:n}=t,{tagsSorter:r,operationsSorter:s}=n();return(0,o.default)(a=G(e).sortBy(((e,t)=>t),((e,t)=>{let a="function"==typeof r?r:f.wh.tagsSorter[r];return a?a(e,t):null}))).call(a,((t,a)=>{let n="function"==typeof s?s:f.wh.operationsSorter[s],r=n?(0,u.default)(t).call(t,n):t;return(0,h.Map)({tagDetails:H(e,a),operations:r})}))},Y=(0,m.createSelector)(y,(e=>e.get("responses",(0,h.Map)()))),X=(0,m.createSelector)(y,(e=>e.get("requests",(0,h.Map)()))),Q=(0,m.createSelector)(y,(e=>e.get("mutatedRequests",(0,h.Map)()))),ee=(e,t,a)=>Y(e).getIn([t,a],null),te=(e,t,a)=>X(e).getIn([t,a],null),ae=(e,t,a)=>Q(e).getIn([t,a],null),ne=()=>!0,re=(e,t,a)=>{const n=N(e).getIn(["paths",...t,"parameters"],(0,h.OrderedMap)()),r=e.getIn(["meta","paths",...t,"parameters"],(0,h.OrderedMap)()),s=(0,o.default)(n).call(n,(e=>{const t=r.get(`${a.get("in")}.${a.get("name")}`),n=r.get(`${a.get("in")}.${a.get("name")}.hash-${a.hashCode()}`);return(0,h.OrderedMap)().merge(e,t,n)}));return(0,i.default)(s).call(s,(e=>e.get("in")===a.get("in")&&e.get("name")===a.get("name")),(0,h.OrderedMap)())},se=(e,t,a,n)=>{const r=`${n}.${a}`;return e.getIn(["meta","paths",...t,"parameter_inclusions",r],!1)},oe=(e,t,a,n)=>{const r=N(e).getIn(["paths",...t,"parameters"],(0,h.OrderedMap)()),s=(0,i.default)(r).call(r,(e=>e.get("in")===n&&e.get("name")===a),(0,h.OrderedMap)());return re(e,t,s)},le=(e,t,a)=>{var n;const r=N(e).getIn(["paths",t,a],(0,h.OrderedMap)()),s=e.getIn(["meta","paths",t,a],(0,h.OrderedMap)()),l=(0,o.default)(n=r.get("parameters",(0,h.List)())).call(n,(n=>re(e,[t,a],n)));return(0,h.OrderedMap)().merge(r,s).set("parameters",l)};function ie(e,t,a,n){t=t||[];let r=e.getIn(["meta","paths",...t,"parameters"],(0,h.fromJS)([]));return(0,i.default)(r).call(r,(e=>h.Map.isMap(e)&&e.get("name")===a&&e.get("in")===n))||(0,h.Map)()}const ce=(0,m.createSelector)(O,(e=>{const t=e.get("host");return"string"==typeof t&&t.length>0&&"/"!==t[0]}));function ue(e,t,a){t=t||[];let n=le(e,...t).get("parameters",(0,h.List)());return(0,c.default)(n).call(n,((e,t)=>{let n=a&&"body"===t.get("in")?t.get("value_xml"):t.get("value");return e.set((0,f.V9)(t,{allowHashes:!1}),n)}),(0,h.fromJS)({}))}function de(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:"";if(h.List.isList(e))return(0,d.default)(e).call(e,(e=>h.Map.isMap(e)&&e.get("in")===t))}function pe(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:"";if(h.List.isList(e))return(0,d.default)(e).call(e,(e=>h.Map.isMap(e)&&e.get("type")===t))}function me(e,t){t=t||[];let a=N(e).getIn(["paths",...t],(0,h.fromJS)({})),n=e.getIn(["meta","paths",...t],(0,h.fromJS)({})),r=fe(e,t);const s=a.get("parameters")||new h.List,o=n.get("consumes_value")?n.get("consumes_value"):pe(s,"file")?"multipart/form-data":pe(s,"formData")?"application/x-www-form-urlencoded":void 0;return(0,h.fromJS)({requestContentType:o,responseContentType:r})}function fe(e,t){t=t||[];const a=N(e).getIn(["paths",...t],null);if(null===a)return;const n=e.getIn(["meta","paths",...t,"produces_value"],null),r=a.getIn(["produces",0],null);return n||r||"application/json"}function he(e,t){t=t||[];const a=N(e),n=a.getIn(["paths",...t],null);if(null===n)return;const[r]=t,s=n.get("produces",null),o=a.getIn(["paths",r,"produces"],null),l=a.getIn(["produces"],null);return s||o||l}function ge(e,t){t=t||[];const a=N(e),n=a.getIn(["paths",...t],null);if(null===n)return;const[r]=t,s=n.get("consumes",null),o=a.getIn(["paths",r,"consumes"],null),l=a.getIn(["consumes"],null);return s||o||l}const ye=(e,t,a)=>{let n=e.get("url").match(/^([a-z][a-z0-9+\-.]*):/),r=(0,p.default)(n)?n[1]:null;return e.getIn(["scheme",t,a])||e.getIn(["scheme","_defaultScheme"])||r||""},ve=(e,t,a)=>{var n;return(0,s.default)(n=["http","https"]).call(n,ye(e,t,a))>-1},Ee=(e,t)=>{t=t||[];let a=e.getIn(["meta","paths",...t,"parameters"],(0,h.fromJS)([]));const n=[];return(0,r.default)(a).call(a,(e=>{let t=e.get("errors");t&&t.count()&&(0,r.default)(t).call(t,(e=>n.push(e)))})),n},Se=(e,t)=>0===Ee(e,t).length,we=(e,t)=>{var a;let n={requestBody:!1,requestContentType:{}},s=e.getIn(["resolvedSubtrees","paths",...t,"requestBody"],(0,h.fromJS)([]));return s.size<1||(s.getIn(["required"])&&(n.requestBody=s.getIn(["required"])),(0,r.default)(a=s.getIn(["content"]).entrySeq()).call(a,(e=>{const t=e[0];if(e[1].getIn(["schema","required"])){const a=e[1].getIn(["schema","required"]).toJS();n.requestContentType[t]=a}}))),n},be=(e,t,a,n)=>{if((a||n)&&a===n)return!0;let r=e.getIn(["resolvedSubtrees","paths",...t,"requestBody","content"],(0,h.fromJS)([]));if(r.size<2||!a||!n)return!1;let s=r.getIn([a,"schema","properties"],(0,h.fromJS)([])),o=r.getIn([n,"schema","properties"],(0,h.fromJS)([]));return!!s.equals(o)};function xe(e){return h.Map.isMap(e)?e:new h.Map}},7508:(e,t,a)=>{a.r(t),a.d(t,{executeRequest:()=>i,updateJsonSpec:()=>l,updateSpec:()=>o,validateParams:()=>c});var n=a(2740),r=a(29),s=a(9908);const o=(e,t)=>{let{specActions:a}=t;return function(){e(...arguments),a.parseToJson(...arguments)}},l=(e,t)=>{let{specActions:a}=t;return function(){for(var t=arguments.length,o=new Array(t),l=0;l<t;l++)o[l]=arguments[l];e(...o),a.invalidateResolvedSubtreeCache();const[i]=o,c=(0,s.default)(i,["paths"])||{},u=(0,n.default)(c);(0,r.default)(u).call(u,(e=>{(0,s.default)(c,[e]).$ref&&a.requestResolvedSubtree(["paths",e])})),a.requestResolvedSubtree(["components","securitySchemes"])}},i=(e,t)=>{let{specActions:a}=t;return t=>(a.logRequest(t),e(t))},c=(e,t)=>{let{specSelectors:a}=t;return t=>e(t,a.isOAS3())}},4852:(e,t,a)=>{a.r(t),a.d(t,{loaded:()=>n});const n=(e,t)=>function(){e(...arguments);const a=t.getConfigs().withCredentials;void 0!==a&&(t.fn.fetch.withCredentials="string"==typeof a?"true"===a:!!a)}},9430:(e,t,a)=>{a.r(t),a.d(t,{default:()=>m});const n=(e=>{var t={};return a.d(t,e),t})({default:()=>g.default});const r=(e=>{var t={};return a.d(t,e),t})({default:()=>y.default});const s=(e=>{var t={};return a.d(t,e),t})({default:()=>v.default});const o=(e=>{var t={};return a.d(t,e),t})({default:()=>E.default});const l=(e=>{var t={};return a.d(t,e),t})({makeResolve:()=>S.makeResolve});const i=(e=>{var t={};return a.d(t,e),t})({buildRequest:()=>w.buildRequest,execute:()=>w.execute});const c=(e=>{var t={};return a.d(t,e),t})({default:()=>b.default,makeHttp:()=>b.makeHttp,serializeRes:()=>b.serializeRes});const u=(e=>{var t={};return a.d(t,e),t})({makeResolveSubtree:()=>x.makeResolveSubtree});var d=a(5013),p=a(4852);function m(e){let{configs:t,getConfigs:a}=e;return{fn:{fetch:(0,c.makeHttp)(c.default,t.preFetch,t.postFetch),buildRequest:i.buildRequest,execute:i.execute,resolve:(0,l.makeResolve)({strategies:[o.default,s.default,r.default,n.default]}),resolveSubtree:async function(e,t){let l=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{};const i=a(),c={modelPropertyMacro:i.modelPropertyMacro,parameterMacro:i.parameterMacro,requestInterceptor:i.requestInterceptor,responseInterceptor:i.responseInterceptor,strategies:[o.default,s.default,r.default,n.default]};return(0,u.makeResolveSubtree)(c)(e,t,l)},serializeRes:c.serializeRes,opId:d.opId},statePlugins:{configs:{wrapActions:{loaded:p.loaded}}}}}},8525:(e,t,a)=>{a.r(t),a.d(t,{default:()=>r});var n=a(6561);function r(){return{fn:{shallowEqualKeys:n.be}}}},8347:(e,t,a)=>{a.r(t),a.d(t,{getDisplayName:()=>n});const n=e=>e.displayName||e.name||"Component"},3420:(e,t,a)=>{a.r(t),a.d(t,{default:()=>i});var n=a(313),r=a(6561),s=a(1548),o=a(8347),l=a(9669);const i=e=>{let{getComponents:t,getStore:a,getSystem:i}=e;const c=(u=(0,s.getComponent)(i,a,t),(0,r.HP)(u,(function(){for(var e=arguments.length,t=new Array(e),a=0;a<e;a++)t[a]=arguments[a];return(0,n.default)(t)})));var u;const d=(e=>(0,l.Z)(e,(function(){for(var e=arguments.length,t=new Array(e),a=0;a<e;a++)t[a]=arguments[a];return t})))((0,s.withMappedContainer)(i,a,c));return{rootInjects:{getComponent:c,makeMappedContainer:d,render:(0,s.render)(i,a,s.getComponent,t)},fn:{getDisplayName:o.getDisplayName}}}},1548:(e,t,a)=>{a.r(t),a.d(t,{getComponent:()=>y,render:()=>g,withMappedContainer:()=>h});var n=a(863),r=a(2740),s=a(810);const o=(e=>{var t={};return a.d(t,e),t})({default:()=>_.default});var l=a(9871);const i=(e=>{var t={};return a.d(t,e),t})({Provider:()=>C.Provider,connect:()=>C.connect});var c=a(1116),u=a(1252);const d=e=>t=>{const{fn:a}=e();class r extends s.Component{render(){return s.default.createElement(t,(0,n.default)({},e(),this.props,this.context))}}return r.displayName=`WithSystem(${a.getDisplayName(t)})`,r},p=(e,t)=>a=>{const{fn:r}=e();class o extends s.Component{render(){return s.default.createElement(i.Provider,{store:t},s.default.createElement(a,(0,n.default)({},this.props,this.context)))}}return o.displayName=`WithRoot(${r.getDisplayName(a)})`,o},m=(e,t,a)=>(0,l.compose)(a?p(e,a):u.default,(0,i.connect)(((a,n)=>{var r;const s={...n,...e()},o=(null===(r=t.prototype)||void 0===r?void 0:r.mapStateToProps)||(e=>({state:e}));return o(a,s)})),d(e))(t),f=(e,t,a,n)=>{for(const r in t){const s=t[r];"function"==typeof s&&s(a[r],n[r],e())}},h=(e,t,a)=>(t,n)=>{const{fn:o}=e(),l=a(t,"root");class i extends s.Component{constructor(t,a){super(t,a),f(e,n,t,{})}UNSAFE_componentWillReceiveProps(t){f(e,n,t,this.props)}render(){const e=(0,c.default)(this.props,n?(0,r.default)(n):[]);return s.default.createElement(l,e)}}return i.displayName=`WithMappedContainer(${o.getDisplayName(l)})`,i},g=(e,t,a,n)=>r=>{const l=a(e,t,n)("App","root");o.default.render(s.default.createElement(l,null),r)},y=(e,t,a)=>function(n,r){let s=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{};if("string"!=typeof n)throw new TypeError("Need a string, to fetch a component. Was given a "+typeof n);const o=a(n);return o?r?"root"===r?m(e,o,t()):m(e,o):o:(s.failSilently||e().log.warn("Could not find component:",n),null)}},471:(e,t,a)=>{a.d(t,{d3:()=>s.default,C2:()=>w});var n=a(2740),r=a(2372);const s=(e=>{var t={};return a.d(t,e),t})({default:()=>j.default});const o=(e=>{var t={};return a.d(t,e),t})({default:()=>N.default});const l=(e=>{var t={};return a.d(t,e),t})({default:()=>O.default});const i=(e=>{var t={};return a.d(t,e),t})({default:()=>k.default});const c=(e=>{var t={};return a.d(t,e),t})({default:()=>A.default});const u=(e=>{var t={};return a.d(t,e),t})({default:()=>I.default});const d=(e=>{var t={};return a.d(t,e),t})({default:()=>R.default});const p=(e=>{var t={};return a.d(t,e),t})({default:()=>P.default});const m=(e=>{var t={};return a.d(t,e),t})({default:()=>T.default});const f=(e=>{var t={};return a.d(t,e),t})({default:()=>M.default});const h=(e=>{var t={};return a.d(t,e),t})({default:()=>D.default});const g=(e=>{var t={};return a.d(t,e),t})({default:()=>L.default});const y=(e=>{var t={};return a.d(t,e),t})({default:()=>q.default});const v=(e=>{var t={};return a.d(t,e),t})({default:()=>B.default});s.default.registerLanguage("json",l.default),s.default.registerLanguage("js",o.default),s.default.registerLanguage("xml",i.default),s.default.registerLanguage("yaml",u.default),s.default.registerLanguage("http",d.default),s.default.registerLanguage("bash",c.default),s.default.registerLanguage("powershell",p.default),s.default.registerLanguage("javascript",o.default);const E={agate:m.default,arta:f.default,monokai:h.default,nord:g.default,obsidian:y.default,"tomorrow-night":v.default},S=(0,n.default)(E),w=e=>(0,r.default)(S).call(S,e)?E[e]:(console.warn(`Request style '${e}' is not available, returning default instead`),m.default)},6561:(e,t,a)=>{a.d(t,{r3:()=>le,GZ:()=>ce,Xb:()=>_e,oJ:()=>fe,XV:()=>ve,iQ:()=>ee,J6:()=>he,DR:()=>ae,oG:()=>L,Uj:()=>xe,QG:()=>me,po:()=>ye,nX:()=>ge,gp:()=>te,kJ:()=>G,O2:()=>je,LQ:()=>B,Wl:()=>H,Kn:()=>W,HP:()=>Z,AF:()=>q,D$:()=>Se,Ay:()=>Y,Q2:()=>X,mz:()=>D,V9:()=>we,cz:()=>be,UG:()=>oe,Zl:()=>ne,hW:()=>pe,Nm:()=>de,be:()=>ue,wh:()=>ie,Pz:()=>Ee,_5:()=>Q,Ik:()=>se});var n=a(4163),r=a(2565),s=a(2954),o=a(29),l=a(6145),i=a(2740),c=(a(5527),a(6785)),u=a(7512),d=a(4350),p=(a(8136),a(5171),a(9963)),m=(a(2372),a(8818)),f=a(1778);const h=(e=>{var t={};return a.d(t,e),t})({default:()=>U.default});var g=a(5942),y=a(313),v=a(9725);const E=(e=>{var t={};return a.d(t,e),t})({sanitizeUrl:()=>J.sanitizeUrl});const S=(e=>{var t={};return a.d(t,e),t})({default:()=>$.default});const w=(e=>{var t={};return a.d(t,e),t})({default:()=>V.default});var b=a(5476);const x=(e=>{var t={};return a.d(t,e),t})({default:()=>K.default});a(841);const _=(e=>{var t={};return a.d(t,e),t})({default:()=>F.default});var C=a(7068),j=a(7504);const N=(e=>{var t={};return a.d(t,e),t})({default:()=>z.default});var O=a(9069),k=a(1798),A=a.n(k),I=a(9072),R=a.n(I),P=a(8764).Buffer;const T="default",M=e=>v.default.Iterable.isIterable(e);function D(e){return W(e)?M(e)?e.toJS():e:{}}function L(e){var t,a;if(M(e))return e;if(e instanceof j.Z.File)return e;if(!W(e))return e;if((0,n.default)(e))return(0,r.default)(a=v.default.Seq(e)).call(a,L).toList();if((0,C.default)((0,s.default)(e))){var o;const t=function(e){if(!(0,C.default)((0,s.default)(e)))return e;const t={},a="_**[]",n={};for(let r of(0,s.default)(e).call(e))if(t[r[0]]||n[r[0]]&&n[r[0]].containsMultiple){if(!n[r[0]]){n[r[0]]={containsMultiple:!0,length:1},t[`${r[0]}${a}${n[r[0]].length}`]=t[r[0]],delete t[r[0]]}n[r[0]].length+=1,t[`${r[0]}${a}${n[r[0]].length}`]=r[1]}else t[r[0]]=r[1];return t}(e);return(0,r.default)(o=v.default.OrderedMap(t)).call(o,L)}return(0,r.default)(t=v.default.OrderedMap(e)).call(t,L)}function q(e){return(0,n.default)(e)?e:[e]}function B(e){return"function"==typeof e}function W(e){return!!e&&"object"==typeof e}function H(e){return"function"==typeof e}function G(e){return(0,n.default)(e)}const Z=b.default;function Y(e,t){var a;return(0,c.default)(a=(0,i.default)(e)).call(a,((a,n)=>(a[n]=t(e[n],n),a)),{})}function X(e,t){var a;return(0,c.default)(a=(0,i.default)(e)).call(a,((a,n)=>{let r=t(e[n],n);return r&&"object"==typeof r&&(0,u.default)(a,r),a}),{})}function Q(e){return t=>{let{dispatch:a,getState:n}=t;return t=>a=>"function"==typeof a?a(e()):t(a)}}function ee(e){var t;let a=e.keySeq();return a.contains(T)?T:(0,d.default)(t=(0,l.default)(a).call(a,(e=>"2"===(e+"")[0]))).call(t).first()}function te(e,t){if(!v.default.Iterable.isIterable(e))return v.default.List();let a=e.getIn((0,n.default)(t)?t:[t]);return v.default.List.isList(a)?a:v.default.List()}function ae(e){let t,a=[/filename\*=[^']+'\w*'"([^"]+)";?/i,/filename\*=[^']+'\w*'([^;]+);?/i,/filename="([^;]*);?"/i,/filename=([^;]*);?/i];if((0,p.default)(a).call(a,(a=>(t=a.exec(e),null!==t))),null!==t&&t.length>1)try{return decodeURIComponent(t[1])}catch(e){console.error(e)}return null}function ne(e){return t=e.replace(/\.[^./]*$/,""),(0,w.default)((0,S.default)(t));var t}function re(e,t,a,s,i){if(!t)return[];let c=[],u=t.get("nullable"),d=t.get("required"),m=t.get("maximum"),f=t.get("minimum"),h=t.get("type"),g=t.get("format"),y=t.get("maxLength"),E=t.get("minLength"),S=t.get("uniqueItems"),w=t.get("maxItems"),b=t.get("minItems"),x=t.get("pattern");const _=a||!0===d,C=null!=e;if(u&&null===e||!h||!(_||C&&"array"===h||!(!_&&!C)))return[];let N="string"===h&&e,O="array"===h&&(0,n.default)(e)&&e.length,k="array"===h&&v.default.List.isList(e)&&e.count();const A=[N,O,k,"array"===h&&"string"==typeof e&&e,"file"===h&&e instanceof j.Z.File,"boolean"===h&&(e||!1===e),"number"===h&&(e||0===e),"integer"===h&&(e||0===e),"object"===h&&"object"==typeof e&&null!==e,"object"===h&&"string"==typeof e&&e],I=(0,p.default)(A).call(A,(e=>!!e));if(_&&!I&&!s)return c.push("Required field is not provided"),c;if("object"===h&&(null===i||"application/json"===i)){let a=e;if("string"==typeof e)try{a=JSON.parse(e)}catch(e){return c.push("Parameter string value must be valid JSON"),c}var R;if(t&&t.has("required")&&H(d.isList)&&d.isList()&&(0,o.default)(d).call(d,(e=>{void 0===a[e]&&c.push({propKey:e,error:"Required property not found"})})),t&&t.has("properties"))(0,o.default)(R=t.get("properties")).call(R,((e,t)=>{const n=re(a[t],e,!1,s,i);c.push(...(0,r.default)(n).call(n,(e=>({propKey:t,error:e}))))}))}if(x){let t=((e,t)=>{if(!new RegExp(t).test(e))return"Value must follow pattern "+t})(e,x);t&&c.push(t)}if(b&&"array"===h){let t=((e,t)=>{if(!e&&t>=1||e&&e.length<t)return`Array must contain at least ${t} item${1===t?"":"s"}`})(e,b);t&&c.push(t)}if(w&&"array"===h){let t=((e,t)=>{if(e&&e.length>t)return`Array must not contain more then ${t} item${1===t?"":"s"}`})(e,w);t&&c.push({needRemove:!0,error:t})}if(S&&"array"===h){let t=((e,t)=>{if(e&&("true"===t||!0===t)){const t=(0,v.fromJS)(e),a=t.toSet();if(e.length>a.size){let e=(0,v.Set)();if((0,o.default)(t).call(t,((a,n)=>{(0,l.default)(t).call(t,(e=>H(e.equals)?e.equals(a):e===a)).size>1&&(e=e.add(n))})),0!==e.size)return(0,r.default)(e).call(e,(e=>({index:e,error:"No duplicates allowed."}))).toArray()}}})(e,S);t&&c.push(...t)}if(y||0===y){let t=((e,t)=>{if(e.length>t)return`Value must be no longer than ${t} character${1!==t?"s":""}`})(e,y);t&&c.push(t)}if(E){let t=((e,t)=>{if(e.length<t)return`Value must be at least ${t} character${1!==t?"s":""}`})(e,E);t&&c.push(t)}if(m||0===m){let t=((e,t)=>{if(e>t)return`Value must be less than ${t}`})(e,m);t&&c.push(t)}if(f||0===f){let t=((e,t)=>{if(e<t)return`Value must be greater than ${t}`})(e,f);t&&c.push(t)}if("string"===h){let t;if(t="date-time"===g?(e=>{if(isNaN(Date.parse(e)))return"Value must be a DateTime"})(e):"uuid"===g?(e=>{if(e=e.toString().toLowerCase(),!/^[{(]?[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}[)}]?$/.test(e))return"Value must be a Guid"})(e):(e=>{if(e&&"string"!=typeof e)return"Value must be a string"})(e),!t)return c;c.push(t)}else if("boolean"===h){let t=(e=>{if("true"!==e&&"false"!==e&&!0!==e&&!1!==e)return"Value must be a boolean"})(e);if(!t)return c;c.push(t)}else if("number"===h){let t=(e=>{if(!/^-?\d+(\.?\d+)?$/.test(e))return"Value must be a number"})(e);if(!t)return c;c.push(t)}else if("integer"===h){let t=(e=>{if(!/^-?\d+$/.test(e))return"Value must be an integer"})(e);if(!t)return c;c.push(t)}else if("array"===h){if(!O&&!k)return c;e&&(0,o.default)(e).call(e,((e,a)=>{const n=re(e,t.get("items"),!1,s,i);c.push(...(0,r.default)(n).call(n,(e=>({index:a,error:e}))))}))}else if("file"===h){let t=(e=>{if(e&&!(e instanceof j.Z.File))return"Value must be a file"})(e);if(!t)return c;c.push(t)}return c}const se=function(e,t){let{isOAS3:a=!1,bypassRequiredCheck:n=!1}=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{},r=e.get("required"),{schema:s,parameterContentMediaType:o}=(0,O.Z)(e,{isOAS3:a});return re(t,s,r,n,o)},oe=()=>{let e={},t=j.Z.location.search;if(!t)return{};if(""!=t){let a=t.substr(1).split("&");for(let t in a)Object.prototype.hasOwnProperty.call(a,t)&&(t=a[t].split("="),e[decodeURIComponent(t[0])]=t[1]&&decodeURIComponent(t[1])||"")}return e},le=e=>{let t;return t=e instanceof P?e:P.from(e.toString(),"utf-8"),t.toString("base64")},ie={operationsSorter:{alpha:(e,t)=>e.get("path").localeCompare(t.get("path")),method:(e,t)=>e.get("method").localeCompare(t.get("method"))},tagsSorter:{alpha:(e,t)=>e.localeCompare(t)}},ce=e=>{let t=[];for(let a in e){let n=e[a];void 0!==n&&""!==n&&t.push([a,"=",encodeURIComponent(n).replace(/%20/g,"+")].join(""))}return t.join("&")},ue=(e,t,a)=>!!(0,x.default)(a,(a=>(0,_.default)(e[a],t[a])));function de(e){return"string"!=typeof e||""===e?"":(0,E.sanitizeUrl)(e)}function pe(e){return!(!e||(0,m.default)(e).call(e,"localhost")>=0||(0,m.default)(e).call(e,"127.0.0.1")>=0||"none"===e)}function me(e){if(!v.default.OrderedMap.isOrderedMap(e))return null;if(!e.size)return null;const t=(0,f.default)(e).call(e,((e,t)=>(0,h.default)(t).call(t,"2")&&(0,i.default)(e.get("content")||{}).length>0)),a=e.get("default")||v.default.OrderedMap(),n=(a.get("content")||v.default.OrderedMap()).keySeq().toJS().length?a:null;return t||n}const fe=e=>"string"==typeof e||e instanceof String?(0,g.default)(e).call(e).replace(/\s/g,"%20"):"",he=e=>(0,N.default)(fe(e).replace(/%20/g,"_")),ge=e=>(0,l.default)(e).call(e,((e,t)=>/^x-/.test(t))),ye=e=>(0,l.default)(e).call(e,((e,t)=>/^pattern|maxLength|minLength|maximum|minimum/.test(t)));function ve(e,t){var a;let r=arguments.length>2&&void 0!==arguments[2]?arguments[2]:()=>!0;if("object"!=typeof e||(0,n.default)(e)||null===e||!t)return e;const s=(0,u.default)({},e);return(0,o.default)(a=(0,i.default)(s)).call(a,(e=>{e===t&&r(s[e],e)?delete s[e]:s[e]=ve(s[e],t,r)})),s}function Ee(e){if("string"==typeof e)return e;if(e&&e.toJS&&(e=e.toJS()),"object"==typeof e&&null!==e)try{return(0,y.default)(e,null,2)}catch(t){return String(e)}return null==e?"":e.toString()}function Se(e){return"number"==typeof e?e.toString():e}function we(e){let{returnAll:t=!1,allowHashes:a=!0}=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};if(!v.default.Map.isMap(e))throw new Error("paramToIdentifier: received a non-Im.Map parameter as input");const n=e.get("name"),r=e.get("in");let s=[];return e&&e.hashCode&&r&&n&&a&&s.push(`${r}.${n}.hash-${e.hashCode()}`),r&&n&&s.push(`${r}.${n}`),s.push(n),t?s:s[0]||""}function be(e,t){var a;const n=we(e,{returnAll:!0});return(0,l.default)(a=(0,r.default)(n).call(n,(e=>t[e]))).call(a,(e=>void 0!==e))[0]}function xe(){return Ce(A()(32).toString("base64"))}function _e(e){return Ce(R()("sha256").update(e).digest("base64"))}function Ce(e){return e.replace(/\+/g,"-").replace(/\//g,"_").replace(/=/g,"")}const je=e=>!e||!(!M(e)||!e.isEmpty())},2518:(e,t,a)=>{function n(e){return function(e){try{return!!JSON.parse(e)}catch(e){return null}}(e)?"json":null}a.d(t,{O:()=>n})},3543:(e,t,a)=>{a.d(t,{mn:()=>o});var n=a(3769);function r(e){return e.match(/^(?:[a-z]+:)?\/\//i)}function s(e,t){return e?r(e)?(a=e).match(/^\/\//i)?`${window.location.protocol}${a}`:a:new n.default(e,t).href:t;var a}function o(e,t){let{selectedServer:a=""}=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{};try{return function(e,t){let{selectedServer:a=""}=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{};if(!e)return;if(r(e))return e;const o=s(a,t);return r(o)?new n.default(e,o).href:new n.default(e,window.location.href).href}(e,t,{selectedServer:a})}catch{return}}},7504:(e,t,a)=>{a.d(t,{Z:()=>n});const n=function(){var e={location:{},history:{},open:()=>{},close:()=>{},File:function(){},FormData:function(){}};if("undefined"==typeof window)return e;try{e=window;for(var t of["File","Blob","FormData"])t in window&&(e[t]=window[t])}catch(e){console.error(e)}return e}()},9069:(e,t,a)=>{a.d(t,{Z:()=>l});var n=a(6145),r=a(2372),s=a(9725);const o=s.default.Set.of("type","format","items","default","maximum","exclusiveMaximum","minimum","exclusiveMinimum","maxLength","minLength","pattern","maxItems","minItems","uniqueItems","enum","multipleOf");function l(e){let{isOAS3:t}=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};if(!s.default.Map.isMap(e))return{schema:s.default.Map(),parameterContentMediaType:null};if(!t)return"body"===e.get("in")?{schema:e.get("schema",s.default.Map()),parameterContentMediaType:null}:{schema:(0,n.default)(e).call(e,((e,t)=>(0,r.default)(o).call(o,t))),parameterContentMediaType:null};if(e.get("content")){const t=e.get("content",s.default.Map({})).keySeq().first();return{schema:e.getIn(["content",t,"schema"],s.default.Map()),parameterContentMediaType:t}}return{schema:e.get("schema")?e.get("schema",s.default.Map()):s.default.Map(),parameterContentMediaType:null}}},9669:(e,t,a)=>{a.d(t,{Z:()=>f});var n=a(4163),r=a(7930),s=a(8898),o=a(5487),l=a(1778);const i=(e=>{var t={};return a.d(t,e),t})({default:()=>W.default});var c=a(6914),u=a(5476);const d=e=>t=>(0,n.default)(e)&&(0,n.default)(t)&&e.length===t.length&&(0,r.default)(e).call(e,((e,a)=>e===t[a])),p=function(){for(var e=arguments.length,t=new Array(e),a=0;a<e;a++)t[a]=arguments[a];return t};class m extends c.default{delete(e){const t=(0,s.default)((0,o.default)(this).call(this)),a=(0,l.default)(t).call(t,d(e));return super.delete(a)}get(e){const t=(0,s.default)((0,o.default)(this).call(this)),a=(0,l.default)(t).call(t,d(e));return super.get(a)}has(e){const t=(0,s.default)((0,o.default)(this).call(this));return-1!==(0,i.default)(t).call(t,d(e))}}const f=function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:p;const{Cache:a}=u.default;u.default.Cache=m;const n=(0,u.default)(e,t);return u.default.Cache=a,n}},8764:(e,t,a)=>{const n=a(4780),r=a(3294),s="function"==typeof Symbol&&"function"==typeof Symbol.for?Symbol.for("nodejs.util.inspect.custom"):null;t.Buffer=i,t.SlowBuffer=function(e){+e!=e&&(e=0);return i.alloc(+e)},t.INSPECT_MAX_BYTES=50;const o=2147483647;function l(e){if(e>o)throw new RangeError('The value "'+e+'" is invalid for option "size"');const t=new Uint8Array(e);return Object.setPrototypeOf(t,i.prototype),t}function i(e,t,a){if("number"==typeof e){if("string"==typeof t)throw new TypeError('The "string" argument must be of type string. Received type number');return d(e)}return c(e,t,a)}function c(e,t,a){if("string"==typeof e)return function(e,t){"string"==typeof t&&""!==t||(t="utf8");if(!i.isEncoding(t))throw new TypeError("Unknown encoding: "+t);const a=0|h(e,t);let n=l(a);const r=n.write(e,t);r!==a&&(n=n.slice(0,r));return n}(e,t);if(ArrayBuffer.isView(e))return function(e){if(G(e,Uint8Array)){const t=new Uint8Array(e);return m(t.buffer,t.byteOffset,t.byteLength)}return p(e)}(e);if(null==e)throw new TypeError("The first argument must be one of type string, Buffer, ArrayBuffer, Array, or Array-like Object. Received type "+typeof e);if(G(e,ArrayBuffer)||e&&G(e.buffer,ArrayBuffer))return m(e,t,a);if("undefined"!=typeof SharedArrayBuffer&&(G(e,SharedArrayBuffer)||e&&G(e.buffer,SharedArrayBuffer)))return m(e,t,a);if("number"==typeof e)throw new TypeError('The "value" argument must not be of type number. Received type number');const n=e.valueOf&&e.valueOf();if(null!=n&&n!==e)return i.from(n,t,a);const r=function(e){if(i.isBuffer(e)){const t=0|f(e.length),a=l(t);return 0===a.length||e.copy(a,0,0,t),a}if(void 0!==e.length)return"number"!=typeof e.length||Z(e.length)?l(0):p(e);if("Buffer"===e.type&&Array.isArray(e.data))return p(e.data)}(e);if(r)return r;if("undefined"!=typeof Symbol&&null!=Symbol.toPrimitive&&"function"==typeof e[Symbol.toPrimitive])return i.from(e[Symbol.toPrimitive]("string"),t,a);throw new TypeError("The first argument must be one of type string, Buffer, ArrayBuffer, Array, or Array-like Object. Received type "+typeof e)}function u(e){if("number"!=typeof e)throw new TypeError('"size" argument must be of type number');if(e<0)throw new RangeError('The value "'+e+'" is invalid for option "size"')}function d(e){return u(e),l(e<0?0:0|f(e))}function p(e){const t=e.length<0?0:0|f(e.length),a=l(t);for(let n=0;n<t;n+=1)a[n]=255&e[n];return a}function m(e,t,a){if(t<0||e.byteLength<t)throw new RangeError('"offset" is outside of buffer bounds');if(e.byteLength<t+(a||0))throw new RangeError('"length" is outside of buffer bounds');let n;return n=void 0===t&&void 0===a?new Uint8Array(e):void 0===a?new Uint8Array(e,t):new Uint8Array(e,t,a),Object.setPrototypeOf(n,i.prototype),n}function f(e){if(e>=o)throw new RangeError("Attempt to allocate Buffer larger than maximum size: 0x"+o.toString(16)+" bytes");return 0|e}function h(e,t){if(i.isBuffer(e))return e.length;if(ArrayBuffer.isView(e)||G(e,ArrayBuffer))return e.byteLength;if("string"!=typeof e)throw new TypeError('The "string" argument must be one of type string, Buffer, or ArrayBuffer. Received type '+typeof e);const a=e.length,n=arguments.length>2&&!0===arguments[2];if(!n&&0===a)return 0;let r=!1;for(;;)switch(t){case"ascii":case"latin1":case"binary":return a;case"utf8":case"utf-8":return z(e).length;case"ucs2":case"ucs-2":case"utf16le":case"utf-16le":return 2*a;case"hex":return a>>>1;case"base64":return W(e).length;default:if(r)return n?-1:z(e).length;t=(""+t).toLowerCase(),r=!0}}function g(e,t,a){let n=!1;if((void 0===t||t<0)&&(t=0),t>this.length)return"";if((void 0===a||a>this.length)&&(a=this.length),a<=0)return"";if((a>>>=0)<=(t>>>=0))return"";for(e||(e="utf8");;)switch(e){case"hex":return A(this,t,a);case"utf8":case"utf-8":return j(this,t,a);case"ascii":return O(this,t,a);case"latin1":case"binary":return k(this,t,a);case"base64":return C(this,t,a);case"ucs2":case"ucs-2":case"utf16le":case"utf-16le":return I(this,t,a);default:if(n)throw new TypeError("Unknown encoding: "+e);e=(e+"").toLowerCase(),n=!0}}function y(e,t,a){const n=e[t];e[t]=e[a],e[a]=n}function v(e,t,a,n,r){if(0===e.length)return-1;if("string"==typeof a?(n=a,a=0):a>2147483647?a=2147483647:a<-2147483648&&(a=-2147483648),Z(a=+a)&&(a=r?0:e.length-1),a<0&&(a=e.length+a),a>=e.length){if(r)return-1;a=e.length-1}else if(a<0){if(!r)return-1;a=0}if("string"==typeof t&&(t=i.from(t,n)),i.isBuffer(t))return 0===t.length?-1:E(e,t,a,n,r);if("number"==typeof t)return t&=255,"function"==typeof Uint8Array.prototype.indexOf?r?Uint8Array.prototype.indexOf.call(e,t,a):Uint8Array.prototype.lastIndexOf.call(e,t,a):E(e,[t],a,n,r);throw new TypeError("val must be string, number or Buffer")}function E(e,t,a,n,r){let s,o=1,l=e.length,i=t.length;if(void 0!==n&&("ucs2"===(n=String(n).toLowerCase())||"ucs-2"===n||"utf16le"===n||"utf-16le"===n)){if(e.length<2||t.length<2)return-1;o=2,l/=2,i/=2,a/=2}function c(e,t){return 1===o?e[t]:e.readUInt16BE(t*o)}if(r){let n=-1;for(s=a;s<l;s++)if(c(e,s)===c(t,-1===n?0:s-n)){if(-1===n&&(n=s),s-n+1===i)return n*o}else-1!==n&&(s-=s-n),n=-1}else for(a+i>l&&(a=l-i),s=a;s>=0;s--){let a=!0;for(let n=0;n<i;n++)if(c(e,s+n)!==c(t,n)){a=!1;break}if(a)return s}return-1}function S(e,t,a,n){a=Number(a)||0;const r=e.length-a;n?(n=Number(n))>r&&(n=r):n=r;const s=t.length;let o;for(n>s/2&&(n=s/2),o=0;o<n;++o){const n=parseInt(t.substr(2*o,2),16);if(Z(n))return o;e[a+o]=n}return o}function w(e,t,a,n){return H(z(t,e.length-a),e,a,n)}function b(e,t,a,n){return H(function(e){const t=[];for(let a=0;a<e.length;++a)t.push(255&e.charCodeAt(a));return t}(t),e,a,n)}function x(e,t,a,n){return H(W(t),e,a,n)}function _(e,t,a,n){return H(function(e,t){let a,n,r;const s=[];for(let o=0;o<e.length&&!((t-=2)<0);++o)a=e.charCodeAt(o),n=a>>8,r=a%256,s.push(r),s.push(n);return s}(t,e.length-a),e,a,n)}function C(e,t,a){return 0===t&&a===e.length?n.fromByteArray(e):n.fromByteArray(e.slice(t,a))}function j(e,t,a){a=Math.min(e.length,a);const n=[];let r=t;for(;r<a;){const t=e[r];let s=null,o=t>239?4:t>223?3:t>191?2:1;if(r+o<=a){let a,n,l,i;switch(o){case 1:t<128&&(s=t);break;case 2:a=e[r+1],128==(192&a)&&(i=(31&t)<<6|63&a,i>127&&(s=i));break;case 3:a=e[r+1],n=e[r+2],128==(192&a)&&128==(192&n)&&(i=(15&t)<<12|(63&a)<<6|63&n,i>2047&&(i<55296||i>57343)&&(s=i));break;case 4:a=e[r+1],n=e[r+2],l=e[r+3],128==(192&a)&&128==(192&n)&&128==(192&l)&&(i=(15&t)<<18|(63&a)<<12|(63&n)<<6|63&l,i>65535&&i<1114112&&(s=i))}}null===s?(s=65533,o=1):s>65535&&(s-=65536,n.push(s>>>10&1023|55296),s=56320|1023&s),n.push(s),r+=o}return function(e){const t=e.length;if(t<=N)return String.fromCharCode.apply(String,e);let a="",n=0;for(;n<t;)a+=String.fromCharCode.apply(String,e.slice(n,n+=N));return a}(n)}t.kMaxLength=o,i.TYPED_ARRAY_SUPPORT=function(){try{const e=new Uint8Array(1),t={foo:function(){return 42}};return Object.setPrototypeOf(t,Uint8Array.prototype),Object.setPrototypeOf(e,t),42===e.foo()}catch(e){return!1}}(),i.TYPED_ARRAY_SUPPORT||"undefined"==typeof console||"function"!=typeof console.error||console.error("This browser lacks typed array (Uint8Array) support which is required by `buffer` v5.x. Use `buffer` v4.x if you require old browser support."),Object.defineProperty(i.prototype,"parent",{enumerable:!0,get:function(){if(i.isBuffer(this))return this.buffer}}),Object.defineProperty(i.prototype,"offset",{enumerable:!0,get:function(){if(i.isBuffer(this))return this.byteOffset}}),i.poolSize=8192,i.from=function(e,t,a){return c(e,t,a)},Object.setPrototypeOf(i.prototype,Uint8Array.prototype),Object.setPrototypeOf(i,Uint8Array),i.alloc=function(e,t,a){return function(e,t,a){return u(e),e<=0?l(e):void 0!==t?"string"==typeof a?l(e).fill(t,a):l(e).fill(t):l(e)}(e,t,a)},i.allocUnsafe=function(e){return d(e)},i.allocUnsafeSlow=function(e){return d(e)},i.isBuffer=function(e){return null!=e&&!0===e._isBuffer&&e!==i.prototype},i.compare=function(e,t){if(G(e,Uint8Array)&&(e=i.from(e,e.offset,e.byteLength)),G(t,Uint8Array)&&(t=i.from(t,t.offset,t.byteLength)),!i.isBuffer(e)||!i.isBuffer(t))throw new TypeError('The "buf1", "buf2" arguments must be one of type Buffer or Uint8Array');if(e===t)return 0;let a=e.length,n=t.length;for(let r=0,s=Math.min(a,n);r<s;++r)if(e[r]!==t[r]){a=e[r],n=t[r];break}return a<n?-1:n<a?1:0},i.isEncoding=function(e){switch(String(e).toLowerCase()){case"hex":case"utf8":case"utf-8":case"ascii":case"latin1":case"binary":case"base64":case"ucs2":case"ucs-2":case"utf16le":case"utf-16le":return!0;default:return!1}},i.concat=function(e,t){if(!Array.isArray(e))throw new TypeError('"list" argument must be an Array of Buffers');if(0===e.length)return i.alloc(0);let a;if(void 0===t)for(t=0,a=0;a<e.length;++a)t+=e[a].length;const n=i.allocUnsafe(t);let r=0;for(a=0;a<e.length;++a){let t=e[a];if(G(t,Uint8Array))r+t.length>n.length?(i.isBuffer(t)||(t=i.from(t)),t.copy(n,r)):Uint8Array.prototype.set.call(n,t,r);else{if(!i.isBuffer(t))throw new TypeError('"list" argument must be an Array of Buffers');t.copy(n,r)}r+=t.length}return n},i.byteLength=h,i.prototype._isBuffer=!0,i.prototype.swap16=function(){const e=this.length;if(e%2!=0)throw new RangeError("Buffer size must be a multiple of 16-bits");for(let t=0;t<e;t+=2)y(this,t,t+1);return this},i.prototype.swap32=function(){const e=this.length;if(e%4!=0)throw new RangeError("Buffer size must be a multiple of 32-bits");for(let t=0;t<e;t+=4)y(this,t,t+3),y(this,t+1,t+2);return this},i.prototype.swap64=function(){const e=this.length;if(e%8!=0)throw new RangeError("Buffer size must be a multiple of 64-bits");for(let t=0;t<e;t+=8)y(this,t,t+7),y(this,t+1,t+6),y(this,t+2,t+5),y(this,t+3,t+4);return this},i.prototype.toString=function(){const e=this.length;return 0===e?"":0===arguments.length?j(this,0,e):g.apply(this,arguments)},i.prototype.toLocaleString=i.prototype.toString,i.prototype.equals=function(e){if(!i.isBuffer(e))throw new TypeError("Argument must be a Buffer");return this===e||0===i.compare(this,e)},i.prototype.inspect=function(){let e="";const a=t.INSPECT_MAX_BYTES;return e=this.toString("hex",0,a).replace(/(.{2})/g,"$1 ").trim(),this.length>a&&(e+=" ... "),"<Buffer "+e+">"},s&&(i.prototype[s]=i.prototype.inspect),i.prototype.compare=function(e,t,a,n,r){if(G(e,Uint8Array)&&(e=i.from(e,e.offset,e.byteLength)),!i.isBuffer(e))throw new TypeError('The "target" argument must be one of type Buffer or Uint8Array. Received type '+typeof e);if(void 0===t&&(t=0),void 0===a&&(a=e?e.length:0),void 0===n&&(n=0),void 0===r&&(r=this.length),t<0||a>e.length||n<0||r>this.length)throw new RangeError("out of range index");if(n>=r&&t>=a)return 0;if(n>=r)return-1;if(t>=a)return 1;if(this===e)return 0;let s=(r>>>=0)-(n>>>=0),o=(a>>>=0)-(t>>>=0);const l=Math.min(s,o),c=this.slice(n,r),u=e.slice(t,a);for(let e=0;e<l;++e)if(c[e]!==u[e]){s=c[e],o=u[e];break}return s<o?-1:o<s?1:0},i.prototype.includes=function(e,t,a){return-1!==this.indexOf(e,t,a)},i.prototype.indexOf=function(e,t,a){return v(this,e,t,a,!0)},i.prototype.lastIndexOf=function(e,t,a){return v(this,e,t,a,!1)},i.prototype.write=function(e,t,a,n){if(void 0===t)n="utf8",a=this.length,t=0;else if(void 0===a&&"string"==typeof t)n=t,a=this.length,t=0;else{if(!isFinite(t))throw new Error("Buffer.write(string, encoding, offset[, length]) is no longer supported");t>>>=0,isFinite(a)?(a>>>=0,void 0===n&&(n="utf8")):(n=a,a=void 0)}const r=this.length-t;if((void 0===a||a>r)&&(a=r),e.length>0&&(a<0||t<0)||t>this.length)throw new RangeError("Attempt to write outside buffer bounds");n||(n="utf8");let s=!1;for(;;)switch(n){case"hex":return S(this,e,t,a);case"utf8":case"utf-8":return w(this,e,t,a);case"ascii":case"latin1":case"binary":return b(this,e,t,a);case"base64":return x(this,e,t,a);case"ucs2":case"ucs-2":case"utf16le":case"utf-16le":return _(this,e,t,a);default:if(s)throw new TypeError("Unknown encoding: "+n);n=(""+n).toLowerCase(),s=!0}},i.prototype.toJSON=function(){return{type:"Buffer",data:Array.prototype.slice.call(this._arr||this,0)}};const N=4096;function O(e,t,a){let n="";a=Math.min(e.length,a);for(let r=t;r<a;++r)n+=String.fromCharCode(127&e[r]);return n}function k(e,t,a){let n="";a=Math.min(e.length,a);for(let r=t;r<a;++r)n+=String.fromCharCode(e[r]);return n}function A(e,t,a){const n=e.length;(!t||t<0)&&(t=0),(!a||a<0||a>n)&&(a=n);let r="";for(let n=t;n<a;++n)r+=Y[e[n]];return r}function I(e,t,a){const n=e.slice(t,a);let r="";for(let e=0;e<n.length-1;e+=2)r+=String.fromCharCode(n[e]+256*n[e+1]);return r}function R(e,t,a){if(e%1!=0||e<0)throw new RangeError("offset is not uint");if(e+t>a)throw new RangeError("Trying to access beyond buffer length")}function P(e,t,a,n,r,s){if(!i.isBuffer(e))throw new TypeError('"buffer" argument must be a Buffer instance');if(t>r||t<s)throw new RangeError('"value" argument is out of bounds');if(a+n>e.length)throw new RangeError("Index out of range")}function T(e,t,a,n,r){$(t,n,r,e,a,7);let s=Number(t&BigInt(4294967295));e[a++]=s,s>>=8,e[a++]=s,s>>=8,e[a++]=s,s>>=8,e[a++]=s;let o=Number(t>>BigInt(32)&BigInt(4294967295));return e[a++]=o,o>>=8,e[a++]=o,o>>=8,e[a++]=o,o>>=8,e[a++]=o,a}function M(e,t,a,n,r){$(t,n,r,e,a,7);let s=Number(t&BigInt(4294967295));e[a+7]=s,s>>=8,e[a+6]=s,s>>=8,e[a+5]=s,s>>=8,e[a+4]=s;let o=Number(t>>BigInt(32)&BigInt(4294967295));return e[a+3]=o,o>>=8,e[a+2]=o,o>>=8,e[a+1]=o,o>>=8,e[a]=o,a+8}function D(e,t,a,n,r,s){if(a+n>e.length)throw new RangeError("Index out of range");if(a<0)throw new RangeError("Index out of range")}function L(e,t,a,n,s){return t=+t,a>>>=0,s||D(e,0,a,4),r.write(e,t,a,n,23,4),a+4}function q(e,t,a,n,s){return t=+t,a>>>=0,s||D(e,0,a,8),r.write(e,t,a,n,52,8),a+8}i.prototype.slice=function(e,t){const a=this.length;(e=~~e)<0?(e+=a)<0&&(e=0):e>a&&(e=a),(t=void 0===t?a:~~t)<0?(t+=a)<0&&(t=0):t>a&&(t=a),t<e&&(t=e);const n=this.subarray(e,t);return Object.setPrototypeOf(n,i.prototype),n},i.prototype.readUintLE=i.prototype.readUIntLE=function(e,t,a){e>>>=0,t>>>=0,a||R(e,t,this.length);let n=this[e],r=1,s=0;for(;++s<t&&(r*=256);)n+=this[e+s]*r;return n},i.prototype.readUintBE=i.prototype.readUIntBE=function(e,t,a){e>>>=0,t>>>=0,a||R(e,t,this.length);let n=this[e+--t],r=1;for(;t>0&&(r*=256);)n+=this[e+--t]*r;return n},i.prototype.readUint8=i.prototype.readUInt8=function(e,t){return e>>>=0,t||R(e,1,this.length),this[e]},i.prototype.readUint16LE=i.prototype.readUInt16LE=function(e,t){return e>>>=0,t||R(e,2,this.length),this[e]|this[e+1]<<8},i.prototype.readUint16BE=i.prototype.readUInt16BE=function(e,t){return e>>>=0,t||R(e,2,this.length),this[e]<<8|this[e+1]},i.prototype.readUint32LE=i.prototype.readUInt32LE=function(e,t){return e>>>=0,t||R(e,4,this.length),(this[e]|this[e+1]<<8|this[e+2]<<16)+16777216*this[e+3]},i.prototype.readUint32BE=i.prototype.readUInt32BE=function(e,t){return e>>>=0,t||R(e,4,this.length),16777216*this[e]+(this[e+1]<<16|this[e+2]<<8|this[e+3])},i.prototype.readBigUInt64LE=X((function(e){V(e>>>=0,"offset");const t=this[e],a=this[e+7];void 0!==t&&void 0!==a||K(e,this.length-8);const n=t+256*this[++e]+65536*this[++e]+this[++e]*2**24,r=this[++e]+256*this[++e]+65536*this[++e]+a*2**24;return BigInt(n)+(BigInt(r)<<BigInt(32))})),i.prototype.readBigUInt64BE=X((function(e){V(e>>>=0,"offset");const t=this[e],a=this[e+7];void 0!==t&&void 0!==a||K(e,this.length-8);const n=t*2**24+65536*this[++e]+256*this[++e]+this[++e],r=this[++e]*2**24+65536*this[++e]+256*this[++e]+a;return(BigInt(n)<<BigInt(32))+BigInt(r)})),i.prototype.readIntLE=function(e,t,a){e>>>=0,t>>>=0,a||R(e,t,this.length);let n=this[e],r=1,s=0;for(;++s<t&&(r*=256);)n+=this[e+s]*r;return r*=128,n>=r&&(n-=Math.pow(2,8*t)),n},i.prototype.readIntBE=function(e,t,a){e>>>=0,t>>>=0,a||R(e,t,this.length);let n=t,r=1,s=this[e+--n];for(;n>0&&(r*=256);)s+=this[e+--n]*r;return r*=128,s>=r&&(s-=Math.pow(2,8*t)),s},i.prototype.readInt8=function(e,t){return e>>>=0,t||R(e,1,this.length),128&this[e]?-1*(255-this[e]+1):this[e]},i.prototype.readInt16LE=function(e,t){e>>>=0,t||R(e,2,this.length);const a=this[e]|this[e+1]<<8;return 32768&a?4294901760|a:a},i.prototype.readInt16BE=function(e,t){e>>>=0,t||R(e,2,this.length);const a=this[e+1]|this[e]<<8;return 32768&a?4294901760|a:a},i.prototype.readInt32LE=function(e,t){return e>>>=0,t||R(e,4,this.length),this[e]|this[e+1]<<8|this[e+2]<<16|this[e+3]<<24},i.prototype.readInt32BE=function(e,t){return e>>>=0,t||R(e,4,this.length),this[e]<<24|this[e+1]<<16|this[e+2]<<8|this[e+3]},i.prototype.readBigInt64LE=X((function(e){V(e>>>=0,"offset");const t=this[e],a=this[e+7];void 0!==t&&void 0!==a||K(e,this.length-8);const n=this[e+4]+256*this[e+5]+65536*this[e+6]+(a<<24);return(BigInt(n)<<BigInt(32))+BigInt(t+256*this[++e]+65536*this[++e]+this[++e]*2**24)})),i.prototype.readBigInt64BE=X((function(e){V(e>>>=0,"offset");const t=this[e],a=this[e+7];void 0!==t&&void 0!==a||K(e,this.length-8);const n=(t<<24)+65536*this[++e]+256*this[++e]+this[++e];return(BigInt(n)<<BigInt(32))+BigInt(this[++e]*2**24+65536*this[++e]+256*this[++e]+a)})),i.prototype.readFloatLE=function(e,t){return e>>>=0,t||R(e,4,this.length),r.read(this,e,!0,23,4)},i.prototype.readFloatBE=function(e,t){return e>>>=0,t||R(e,4,this.length),r.read(this,e,!1,23,4)},i.prototype.readDoubleLE=function(e,t){return e>>>=0,t||R(e,8,this.length),r.read(this,e,!0,52,8)},i.prototype.readDoubleBE=function(e,t){return e>>>=0,t||R(e,8,this.length),r.read(this,e,!1,52,8)},i.prototype.writeUintLE=i.prototype.writeUIntLE=function(e,t,a,n){if(e=+e,t>>>=0,a>>>=0,!n){P(this,e,t,a,Math.pow(2,8*a)-1,0)}let r=1,s=0;for(this[t]=255&e;++s<a&&(r*=256);)this[t+s]=e/r&255;return t+a},i.prototype.writeUintBE=i.prototype.writeUIntBE=function(e,t,a,n){if(e=+e,t>>>=0,a>>>=0,!n){P(this,e,t,a,Math.pow(2,8*a)-1,0)}let r=a-1,s=1;for(this[t+r]=255&e;--r>=0&&(s*=256);)this[t+r]=e/s&255;return t+a},i.prototype.writeUint8=i.prototype.writeUInt8=function(e,t,a){return e=+e,t>>>=0,a||P(this,e,t,1,255,0),this[t]=255&e,t+1},i.prototype.writeUint16LE=i.prototype.writeUInt16LE=function(e,t,a){return e=+e,t>>>=0,a||P(this,e,t,2,65535,0),this[t]=255&e,this[t+1]=e>>>8,t+2},i.prototype.writeUint16BE=i.prototype.writeUInt16BE=function(e,t,a){return e=+e,t>>>=0,a||P(this,e,t,2,65535,0),this[t]=e>>>8,this[t+1]=255&e,t+2},i.prototype.writeUint32LE=i.prototype.writeUInt32LE=function(e,t,a){return e=+e,t>>>=0,a||P(this,e,t,4,4294967295,0),this[t+3]=e>>>24,this[t+2]=e>>>16,this[t+1]=e>>>8,this[t]=255&e,t+4},i.prototype.writeUint32BE=i.prototype.writeUInt32BE=function(e,t,a){return e=+e,t>>>=0,a||P(this,e,t,4,4294967295,0),this[t]=e>>>24,this[t+1]=e>>>16,this[t+2]=e>>>8,this[t+3]=255&e,t+4},i.prototype.writeBigUInt64LE=X((function(e,t=0){return T(this,e,t,BigInt(0),BigInt("0xffffffffffffffff"))})),i.prototype.writeBigUInt64BE=X((function(e,t=0){return M(this,e,t,BigInt(0),BigInt("0xffffffffffffffff"))})),i.prototype.writeIntLE=function(e,t,a,n){if(e=+e,t>>>=0,!n){const n=Math.pow(2,8*a-1);P(this,e,t,a,n-1,-n)}let r=0,s=1,o=0;for(this[t]=255&e;++r<a&&(s*=256);)e<0&&0===o&&0!==this[t+r-1]&&(o=1),this[t+r]=(e/s>>0)-o&255;return t+a},i.prototype.writeIntBE=function(e,t,a,n){if(e=+e,t>>>=0,!n){const n=Math.pow(2,8*a-1);P(this,e,t,a,n-1,-n)}let r=a-1,s=1,o=0;for(this[t+r]=255&e;--r>=0&&(s*=256);)e<0&&0===o&&0!==this[t+r+1]&&(o=1),this[t+r]=(e/s>>0)-o&255;return t+a},i.prototype.writeInt8=function(e,t,a){return e=+e,t>>>=0,a||P(this,e,t,1,127,-128),e<0&&(e=255+e+1),this[t]=255&e,t+1},i.prototype.writeInt16LE=function(e,t,a){return e=+e,t>>>=0,a||P(this,e,t,2,32767,-32768),this[t]=255&e,this[t+1]=e>>>8,t+2},i.prototype.writeInt16BE=function(e,t,a){return e=+e,t>>>=0,a||P(this,e,t,2,32767,-32768),this[t]=e>>>8,this[t+1]=255&e,t+2},i.prototype.writeInt32LE=function(e,t,a){return e=+e,t>>>=0,a||P(this,e,t,4,2147483647,-2147483648),this[t]=255&e,this[t+1]=e>>>8,this[t+2]=e>>>16,this[t+3]=e>>>24,t+4},i.prototype.writeInt32BE=function(e,t,a){return e=+e,t>>>=0,a||P(this,e,t,4,2147483647,-2147483648),e<0&&(e=4294967295+e+1),this[t]=e>>>24,this[t+1]=e>>>16,this[t+2]=e>>>8,this[t+3]=255&e,t+4},i.prototype.writeBigInt64LE=X((function(e,t=0){return T(this,e,t,-BigInt("0x8000000000000000"),BigInt("0x7fffffffffffffff"))})),i.prototype.writeBigInt64BE=X((function(e,t=0){return M(this,e,t,-BigInt("0x8000000000000000"),BigInt("0x7fffffffffffffff"))})),i.prototype.writeFloatLE=function(e,t,a){return L(this,e,t,!0,a)},i.prototype.writeFloatBE=function(e,t,a){return L(this,e,t,!1,a)},i.prototype.writeDoubleLE=function(e,t,a){return q(this,e,t,!0,a)},i.prototype.writeDoubleBE=function(e,t,a){return q(this,e,t,!1,a)},i.prototype.copy=function(e,t,a,n){if(!i.isBuffer(e))throw new TypeError("argument should be a Buffer");if(a||(a=0),n||0===n||(n=this.length),t>=e.length&&(t=e.length),t||(t=0),n>0&&n<a&&(n=a),n===a)return 0;if(0===e.length||0===this.length)return 0;if(t<0)throw new RangeError("targetStart out of bounds");if(a<0||a>=this.length)throw new RangeError("Index out of range");if(n<0)throw new RangeError("sourceEnd out of bounds");n>this.length&&(n=this.length),e.length-t<n-a&&(n=e.length-t+a);const r=n-a;return this===e&&"function"==typeof Uint8Array.prototype.copyWithin?this.copyWithin(t,a,n):Uint8Array.prototype.set.call(e,this.subarray(a,n),t),r},i.prototype.fill=function(e,t,a,n){if("string"==typeof e){if("string"==typeof t?(n=t,t=0,a=this.length):"string"==typeof a&&(n=a,a=this.length),void 0!==n&&"string"!=typeof n)throw new TypeError("encoding must be a string");if("string"==typeof n&&!i.isEncoding(n))throw new TypeError("Unknown encoding: "+n);if(1===e.length){const t=e.charCodeAt(0);("utf8"===n&&t<128||"latin1"===n)&&(e=t)}}else"number"==typeof e?e&=255:"boolean"==typeof e&&(e=Number(e));if(t<0||this.length<t||this.length<a)throw new RangeError("Out of range index");if(a<=t)return this;let r;if(t>>>=0,a=void 0===a?this.length:a>>>0,e||(e=0),"number"==typeof e)for(r=t;r<a;++r)this[r]=e;else{const s=i.isBuffer(e)?e:i.from(e,n),o=s.length;if(0===o)throw new TypeError('The value "'+e+'" is invalid for argument "value"');for(r=0;r<a-t;++r)this[r+t]=s[r%o]}return this};const B={};function U(e,t,a){B[e]=class extends a{constructor(){super(),Object.defineProperty(this,"message",{value:t.apply(this,arguments),writable:!0,configurable:!0}),this.name=`${this.name} [${e}]`,this.stack,delete this.name}get code(){return e}set code(e){Object.defineProperty(this,"code",{configurable:!0,enumerable:!0,value:e,writable:!0})}toString(){return`${this.name} [${e}]: ${this.message}`}}}function J(e){let t="",a=e.length;const n="-"===e[0]?1:0;for(;a>=n+4;a-=3)t=`_${e.slice(a-3,a)}${t}`;return`${e.slice(0,a)}${t}`}function $(e,t,a,n,r,s){if(e>a||e<t){const n="bigint"==typeof t?"n":"";let r;throw r=s>3?0===t||t===BigInt(0)?`>= 0${n} and < 2${n} ** ${8*(s+1)}${n}`:`>= -(2${n} ** ${8*(s+1)-1}${n}) and < 2 ** ${8*(s+1)-1}${n}`:`>= ${t}${n} and <= ${a}${n}`,new B.ERR_OUT_OF_RANGE("value",r,e)}!function(e,t,a){V(t,"offset"),void 0!==e[t]&&void 0!==e[t+a]||K(t,e.length-(a+1))}(n,r,s)}function V(e,t){if("number"!=typeof e)throw new B.ERR_INVALID_ARG_TYPE(t,"number",e)}function K(e,t,a){if(Math.floor(e)!==e)throw V(e,a),new B.ERR_OUT_OF_RANGE(a||"offset","an integer",e);if(t<0)throw new B.ERR_BUFFER_OUT_OF_BOUNDS;throw new B.ERR_OUT_OF_RANGE(a||"offset",`>= ${a?1:0} and <= ${t}`,e)}U("ERR_BUFFER_OUT_OF_BOUNDS",(function(e){return e?`${e} is outside of buffer bounds`:"Attempt to access memory outside buffer bounds"}),RangeError),U("ERR_INVALID_ARG_TYPE",(function(e,t){return`The "${e}" argument must be of type number. Received type ${typeof t}`}),TypeError),U("ERR_OUT_OF_RANGE",(function(e,t,a){let n=`The value of "${e}" is out of range.`,r=a;return Number.isInteger(a)&&Math.abs(a)>2**32?r=J(String(a)):"bigint"==typeof a&&(r=String(a),(a>BigInt(2)**BigInt(32)||a<-(BigInt(2)**BigInt(32)))&&(r=J(r)),r+="n"),n+=` It must be ${t}. Received ${r}`,n}),RangeError);const F=/[^+/0-9A-Za-z-_]/g;function z(e,t){let a;t=t||1/0;const n=e.length;let r=null;const s=[];for(let o=0;o<n;++o){if(a=e.charCodeAt(o),a>55295&&a<57344){if(!r){if(a>56319){(t-=3)>-1&&s.push(239,191,189);continue}if(o+1===n){(t-=3)>-1&&s.push(239,191,189);continue}r=a;continue}if(a<56320){(t-=3)>-1&&s.push(239,191,189),r=a;continue}a=65536+(r-55296<<10|a-56320)}else r&&(t-=3)>-1&&s.push(239,191,189);if(r=null,a<128){if((t-=1)<0)break;s.push(a)}else if(a<2048){if((t-=2)<0)break;s.push(a>>6|192,63&a|128)}else if(a<65536){if((t-=3)<0)break;s.push(a>>12|224,a>>6&63|128,63&a|128)}else{if(!(a<1114112))throw new Error("Invalid code point");if((t-=4)<0)break;s.push(a>>18|240,a>>12&63|128,a>>6&63|128,63&a|128)}}return s}function W(e){return n.toByteArray(function(e){if((e=(e=e.split("=")[0]).trim().replace(F,"")).length<2)return"";for(;e.length%4!=0;)e+="=";return e}(e))}function H(e,t,a,n){let r;for(r=0;r<n&&!(r+a>=t.length||r>=e.length);++r)t[r+a]=e[r];return r}function G(e,t){return e instanceof t||null!=e&&null!=e.constructor&&null!=e.constructor.name&&e.constructor.name===t.name}function Z(e){return e!=e}const Y=function(){const e="0123456789abcdef",t=new Array(256);for(let a=0;a<16;++a){const n=16*a;for(let r=0;r<16;++r)t[n+r]=e[a]+e[r]}return t}();function X(e){return"undefined"==typeof BigInt?Q:e}function Q(){throw new Error("BigInt not supported")}},8171:(e,t,a)=>{a(6450);var n=a(4058).Object,r=e.exports=function(e,t,a){return n.defineProperty(e,t,a)};n.defineProperty.sham&&(r.sham=!0)},4883:(e,t,a)=>{var n=a(7475),r=a(9826),s=TypeError;e.exports=function(e){if(n(e))return e;throw s(r(e)+" is not a function")}},6059:(e,t,a)=>{var n=a(941),r=String,s=TypeError;e.exports=function(e){if(n(e))return e;throw s(r(e)+" is not an object")}},2532:(e,t,a)=>{var n=a(5329),r=n({}.toString),s=n("".slice);e.exports=function(e){return s(r(e),8,-1)}},2029:(e,t,a)=>{var n=a(5746),r=a(5988),s=a(1887);e.exports=n?function(e,t,a){return r.f(e,t,s(1,a))}:function(e,t,a){return e[t]=a,e}},1887:e=>{e.exports=function(e,t){return{enumerable:!(1&e),configurable:!(2&e),writable:!(4&e),value:t}}},5609:(e,t,a)=>{var n=a(1899),r=Object.defineProperty;e.exports=function(e,t){try{r(n,e,{value:t,configurable:!0,writable:!0})}catch(a){n[e]=t}return t}},5746:(e,t,a)=>{var n=a(5981);e.exports=!n((function(){return 7!=Object.defineProperty({},1,{get:function(){return 7}})[1]}))},6616:e=>{var t="object"==typeof document&&document.all,a=void 0===t&&void 0!==t;e.exports={all:t,IS_HTMLDDA:a}},1333:(e,t,a)=>{var n=a(1899),r=a(941),s=n.document,o=r(s)&&r(s.createElement);e.exports=function(e){return o?s.createElement(e):{}}},2861:e=>{e.exports="undefined"!=typeof navigator&&String(navigator.userAgent)||""},3385:(e,t,a)=>{var n,r,s=a(1899),o=a(2861),l=s.process,i=s.Deno,c=l&&l.versions||i&&i.version,u=c&&c.v8;u&&(r=(n=u.split("."))[0]>0&&n[0]<4?1:+(n[0]+n[1])),!r&&o&&(!(n=o.match(/Edge\/(\d+)/))||n[1]>=74)&&(n=o.match(/Chrome\/(\d+)/))&&(r=+n[1]),e.exports=r},6887:(e,t,a)=>{var n=a(1899),r=a(9730),s=a(7484),o=a(7475),l=a(9677).f,i=a(7252),c=a(4058),u=a(6843),d=a(2029),p=a(953),m=function(e){var t=function(a,n,s){if(this instanceof t){switch(arguments.length){case 0:return new e;case 1:return new e(a);case 2:return new e(a,n)}return new e(a,n,s)}return r(e,this,arguments)};return t.prototype=e.prototype,t};e.exports=function(e,t){var a,r,f,h,g,y,v,E,S,w=e.target,b=e.global,x=e.stat,_=e.proto,C=b?n:x?n[w]:(n[w]||{}).prototype,j=b?c:c[w]||d(c,w,{})[w],N=j.prototype;for(h in t)r=!(a=i(b?h:w+(x?".":"#")+h,e.forced))&&C&&p(C,h),y=j[h],r&&(v=e.dontCallGetSet?(S=l(C,h))&&S.value:C[h]),g=r&&v?v:t[h],r&&typeof y==typeof g||(E=e.bind&&r?u(g,n):e.wrap&&r?m(g):_&&o(g)?s(g):g,(e.sham||g&&g.sham||y&&y.sham)&&d(E,"sham",!0),d(j,h,E),_&&(p(c,f=w+"Prototype")||d(c,f,{}),d(c[f],h,g),e.real&&N&&(a||!N[h])&&d(N,h,g)))}},5981:e=>{e.exports=function(e){try{return!!e()}catch(e){return!0}}},9730:(e,t,a)=>{var n=a(8285),r=Function.prototype,s=r.apply,o=r.call;e.exports="object"==typeof Reflect&&Reflect.apply||(n?o.bind(s):function(){return o.apply(s,arguments)})},6843:(e,t,a)=>{var n=a(7484),r=a(4883),s=a(8285),o=n(n.bind);e.exports=function(e,t){return r(e),void 0===t?e:s?o(e,t):function(){return e.apply(t,arguments)}}},8285:(e,t,a)=>{var n=a(5981);e.exports=!n((function(){var e=function(){}.bind();return"function"!=typeof e||e.hasOwnProperty("prototype")}))},8834:(e,t,a)=>{var n=a(8285),r=Function.prototype.call;e.exports=n?r.bind(r):function(){return r.apply(r,arguments)}},7484:(e,t,a)=>{var n=a(2532),r=a(5329);e.exports=function(e){if("Function"===n(e))return r(e)}},5329:(e,t,a)=>{var n=a(8285),r=Function.prototype,s=r.call,o=n&&r.bind.bind(s,s);e.exports=n?o:function(e){return function(){return s.apply(e,arguments)}}},626:(e,t,a)=>{var n=a(4058),r=a(1899),s=a(7475),o=function(e){return s(e)?e:void 0};e.exports=function(e,t){return arguments.length<2?o(n[e])||o(r[e]):n[e]&&n[e][t]||r[e]&&r[e][t]}},4229:(e,t,a)=>{var n=a(4883),r=a(2119);e.exports=function(e,t){var a=e[t];return r(a)?void 0:n(a)}},1899:function(e,t,a){var n=function(e){return e&&e.Math==Math&&e};e.exports=n("object"==typeof globalThis&&globalThis)||n("object"==typeof window&&window)||n("object"==typeof self&&self)||n("object"==typeof a.g&&a.g)||function(){return this}()||this||Function("return this")()},953:(e,t,a)=>{var n=a(5329),r=a(9678),s=n({}.hasOwnProperty);e.exports=Object.hasOwn||function(e,t){return s(r(e),t)}},2840:(e,t,a)=>{var n=a(5746),r=a(5981),s=a(1333);e.exports=!n&&!r((function(){return 7!=Object.defineProperty(s("div"),"a",{get:function(){return 7}}).a}))},7026:(e,t,a)=>{var n=a(5329),r=a(5981),s=a(2532),o=Object,l=n("".split);e.exports=r((function(){return!o("z").propertyIsEnumerable(0)}))?function(e){return"String"==s(e)?l(e,""):o(e)}:o},7475:(e,t,a)=>{var n=a(6616),r=n.all;e.exports=n.IS_HTMLDDA?function(e){return"function"==typeof e||e===r}:function(e){return"function"==typeof e}},7252:(e,t,a)=>{var n=a(5981),r=a(7475),s=/#|\.prototype\./,o=function(e,t){var a=i[l(e)];return a==u||a!=c&&(r(t)?n(t):!!t)},l=o.normalize=function(e){return String(e).replace(s,".").toLowerCase()},i=o.data={},c=o.NATIVE="N",u=o.POLYFILL="P";e.exports=o},2119:e=>{e.exports=function(e){return null==e}},941:(e,t,a)=>{var n=a(7475),r=a(6616),s=r.all;e.exports=r.IS_HTMLDDA?function(e){return"object"==typeof e?null!==e:n(e)||e===s}:function(e){return"object"==typeof e?null!==e:n(e)}},2529:e=>{e.exports=!0},6664:(e,t,a)=>{var n=a(626),r=a(7475),s=a(7046),o=a(2302),l=Object;e.exports=o?function(e){return"symbol"==typeof e}:function(e){var t=n("Symbol");return r(t)&&s(t.prototype,l(e))}},5988:(e,t,a)=>{var n=a(5746),r=a(2840),s=a(3937),o=a(6059),l=a(3894),i=TypeError,c=Object.defineProperty,u=Object.getOwnPropertyDescriptor,d="enumerable",p="configurable",m="writable";t.f=n?s?function(e,t,a){if(o(e),t=l(t),o(a),"function"==typeof e&&"prototype"===t&&"value"in a&&m in a&&!a[m]){var n=u(e,t);n&&n[m]&&(e[t]=a.value,a={configurable:p in a?a[p]:n[p],enumerable:d in a?a[d]:n[d],writable:!1})}return c(e,t,a)}:c:function(e,t,a){if(o(e),t=l(t),o(a),r)try{return c(e,t,a)}catch(e){}if("get"in a||"set"in a)throw i("Accessors not supported");return"value"in a&&(e[t]=a.value),e}},9677:(e,t,a)=>{var n=a(5746),r=a(8834),s=a(6760),o=a(1887),l=a(4529),i=a(3894),c=a(953),u=a(2840),d=Object.getOwnPropertyDescriptor;t.f=n?d:function(e,t){if(e=l(e),t=i(t),u)try{return d(e,t)}catch(e){}if(c(e,t))return o(!r(s.f,e,t),e[t])}},7046:(e,t,a)=>{var n=a(5329);e.exports=n({}.isPrototypeOf)},6760:(e,t)=>{var a={}.propertyIsEnumerable,n=Object.getOwnPropertyDescriptor,r=n&&!a.call({1:2},1);t.f=r?function(e){var t=n(this,e);return!!t&&t.enumerable}:a},9811:(e,t,a)=>{var n=a(8834),r=a(7475),s=a(941),o=TypeError;e.exports=function(e,t){var a,l;if("string"===t&&r(a=e.toString)&&!s(l=n(a,e)))return l;if(r(a=e.valueOf)&&!s(l=n(a,e)))return l;if("string"!==t&&r(a=e.toString)&&!s(l=n(a,e)))return l;throw o("Can't convert object to primitive value")}},4058:e=>{e.exports={}},8219:(e,t,a)=>{var n=a(2119),r=TypeError;e.exports=function(e){if(n(e))throw r("Can't call method on "+e);return e}},3030:(e,t,a)=>{var n=a(1899),r=a(5609),s="__core-js_shared__",o=n[s]||r(s,{});e.exports=o},8726:(e,t,a)=>{var n=a(2529),r=a(3030);(e.exports=function(e,t){return r[e]||(r[e]=void 0!==t?t:{})})("versions",[]).push({version:"3.31.1",mode:n?"pure":"global",copyright:"© 2014-2023 Denis Pushkarev (zloirock.ru)",license:"https://github.com/zloirock/core-js/blob/v3.31.1/LICENSE",source:"https://github.com/zloirock/core-js"})},3405:(e,t,a)=>{var n=a(3385),r=a(5981),s=a(1899).String;e.exports=!!Object.getOwnPropertySymbols&&!r((function(){var e=Symbol();return!s(e)||!(Object(e)instanceof Symbol)||!Symbol.sham&&n&&n<41}))},4529:(e,t,a)=>{var n=a(7026),r=a(8219);e.exports=function(e){return n(r(e))}},9678:(e,t,a)=>{var n=a(8219),r=Object;e.exports=function(e){return r(n(e))}},6935:(e,t,a)=>{var n=a(8834),r=a(941),s=a(6664),o=a(4229),l=a(9811),i=a(9813),c=TypeError,u=i("toPrimitive");e.exports=function(e,t){if(!r(e)||s(e))return e;var a,i=o(e,u);if(i){if(void 0===t&&(t="default"),a=n(i,e,t),!r(a)||s(a))return a;throw c("Can't convert object to primitive value")}return void 0===t&&(t="number"),l(e,t)}},3894:(e,t,a)=>{var n=a(6935),r=a(6664);e.exports=function(e){var t=n(e,"string");return r(t)?t:t+""}},9826:e=>{var t=String;e.exports=function(e){try{return t(e)}catch(e){return"Object"}}},9418:(e,t,a)=>{var n=a(5329),r=0,s=Math.random(),o=n(1..toString);e.exports=function(e){return"Symbol("+(void 0===e?"":e)+")_"+o(++r+s,36)}},2302:(e,t,a)=>{var n=a(3405);e.exports=n&&!Symbol.sham&&"symbol"==typeof Symbol.iterator},3937:(e,t,a)=>{var n=a(5746),r=a(5981);e.exports=n&&r((function(){return 42!=Object.defineProperty((function(){}),"prototype",{value:42,writable:!1}).prototype}))},9813:(e,t,a)=>{var n=a(1899),r=a(8726),s=a(953),o=a(9418),l=a(3405),i=a(2302),c=n.Symbol,u=r("wks"),d=i?c.for||c:c&&c.withoutSetter||o;e.exports=function(e){return s(u,e)||(u[e]=l&&s(c,e)?c[e]:d("Symbol."+e)),u[e]}},6450:(e,t,a)=>{var n=a(6887),r=a(5746),s=a(5988).f;n({target:"Object",stat:!0,forced:Object.defineProperty!==s,sham:!r},{defineProperty:s})},1910:(e,t,a)=>{var n=a(8171);e.exports=n},7698:(e,t,a)=>{var n=a(8764).Buffer;function r(e){return e instanceof n||e instanceof Date||e instanceof RegExp}function s(e){if(e instanceof n){var t=n.alloc?n.alloc(e.length):new n(e.length);return e.copy(t),t}if(e instanceof Date)return new Date(e.getTime());if(e instanceof RegExp)return new RegExp(e);throw new Error("Unexpected situation")}function o(e){var t=[];return e.forEach((function(e,a){"object"==typeof e&&null!==e?Array.isArray(e)?t[a]=o(e):r(e)?t[a]=s(e):t[a]=i({},e):t[a]=e})),t}function l(e,t){return"__proto__"===t?void 0:e[t]}var i=e.exports=function(){if(arguments.length<1||"object"!=typeof arguments[0])return!1;if(arguments.length<2)return arguments[0];var e,t,a=arguments[0];return Array.prototype.slice.call(arguments,1).forEach((function(n){"object"!=typeof n||null===n||Array.isArray(n)||Object.keys(n).forEach((function(c){return t=l(a,c),(e=l(n,c))===a?void 0:"object"!=typeof e||null===e?void(a[c]=e):Array.isArray(e)?void(a[c]=o(e)):r(e)?void(a[c]=s(e)):"object"!=typeof t||null===t||Array.isArray(t)?void(a[c]=i({},e)):void(a[c]=i(t,e))}))})),a}},7187:e=>{var t,a="object"==typeof Reflect?Reflect:null,n=a&&"function"==typeof a.apply?a.apply:function(e,t,a){return Function.prototype.apply.call(e,t,a)};t=a&&"function"==typeof a.ownKeys?a.ownKeys:Object.getOwnPropertySymbols?function(e){return Object.getOwnPropertyNames(e).concat(Object.getOwnPropertySymbols(e))}:function(e){return Object.getOwnPropertyNames(e)};var r=Number.isNaN||function(e){return e!=e};function s(){s.init.call(this)}e.exports=s,e.exports.once=function(e,t){return new Promise((function(a,n){function r(a){e.removeListener(t,s),n(a)}function s(){"function"==typeof e.removeListener&&e.removeListener("error",r),a([].slice.call(arguments))}h(e,t,s,{once:!0}),"error"!==t&&function(e,t,a){"function"==typeof e.on&&h(e,"error",t,a)}(e,r,{once:!0})}))},s.EventEmitter=s,s.prototype._events=void 0,s.prototype._eventsCount=0,s.prototype._maxListeners=void 0;var o=10;function l(e){if("function"!=typeof e)throw new TypeError('The "listener" argument must be of type Function. Received type '+typeof e)}function i(e){return void 0===e._maxListeners?s.defaultMaxListeners:e._maxListeners}function c(e,t,a,n){var r,s,o,c;if(l(a),void 0===(s=e._events)?(s=e._events=Object.create(null),e._eventsCount=0):(void 0!==s.newListener&&(e.emit("newListener",t,a.listener?a.listener:a),s=e._events),o=s[t]),void 0===o)o=s[t]=a,++e._eventsCount;else if("function"==typeof o?o=s[t]=n?[a,o]:[o,a]:n?o.unshift(a):o.push(a),(r=i(e))>0&&o.length>r&&!o.warned){o.warned=!0;var u=new Error("Possible EventEmitter memory leak detected. "+o.length+" "+String(t)+" listeners added. Use emitter.setMaxListeners() to increase limit");u.name="MaxListenersExceededWarning",u.emitter=e,u.type=t,u.count=o.length,c=u,console&&console.warn&&console.warn(c)}return e}function u(){if(!this.fired)return this.target.removeListener(this.type,this.wrapFn),this.fired=!0,0===arguments.length?this.listener.call(this.target):this.listener.apply(this.target,arguments)}function d(e,t,a){var n={fired:!1,wrapFn:void 0,target:e,type:t,listener:a},r=u.bind(n);return r.listener=a,n.wrapFn=r,r}function p(e,t,a){var n=e._events;if(void 0===n)return[];var r=n[t];return void 0===r?[]:"function"==typeof r?a?[r.listener||r]:[r]:a?function(e){for(var t=new Array(e.length),a=0;a<t.length;++a)t[a]=e[a].listener||e[a];return t}(r):f(r,r.length)}function m(e){var t=this._events;if(void 0!==t){var a=t[e];if("function"==typeof a)return 1;if(void 0!==a)return a.length}return 0}function f(e,t){for(var a=new Array(t),n=0;n<t;++n)a[n]=e[n];return a}function h(e,t,a,n){if("function"==typeof e.on)n.once?e.once(t,a):e.on(t,a);else{if("function"!=typeof e.addEventListener)throw new TypeError('The "emitter" argument must be of type EventEmitter. Received type '+typeof e);e.addEventListener(t,(function r(s){n.once&&e.removeEventListener(t,r),a(s)}))}}Object.defineProperty(s,"defaultMaxListeners",{enumerable:!0,get:function(){return o},set:function(e){if("number"!=typeof e||e<0||r(e))throw new RangeError('The value of "defaultMaxListeners" is out of range. It must be a non-negative number. Received '+e+".");o=e}}),s.init=function(){void 0!==this._events&&this._events!==Object.getPrototypeOf(this)._events||(this._events=Object.create(null),this._eventsCount=0),this._maxListeners=this._maxListeners||void 0},s.prototype.setMaxListeners=function(e){if("number"!=typeof e||e<0||r(e))throw new RangeError('The value of "n" is out of range. It must be a non-negative number. Received '+e+".");return this._maxListeners=e,this},s.prototype.getMaxListeners=function(){return i(this)},s.prototype.emit=function(e){for(var t=[],a=1;a<arguments.length;a++)t.push(arguments[a]);var r="error"===e,s=this._events;if(void 0!==s)r=r&&void 0===s.error;else if(!r)return!1;if(r){var o;if(t.length>0&&(o=t[0]),o instanceof Error)throw o;var l=new Error("Unhandled error."+(o?" ("+o.message+")":""));throw l.context=o,l}var i=s[e];if(void 0===i)return!1;if("function"==typeof i)n(i,this,t);else{var c=i.length,u=f(i,c);for(a=0;a<c;++a)n(u[a],this,t)}return!0},s.prototype.addListener=function(e,t){return c(this,e,t,!1)},s.prototype.on=s.prototype.addListener,s.prototype.prependListener=function(e,t){return c(this,e,t,!0)},s.prototype.once=function(e,t){return l(t),this.on(e,d(this,e,t)),this},s.prototype.prependOnceListener=function(e,t){return l(t),this.prependListener(e,d(this,e,t)),this},s.prototype.removeListener=function(e,t){var a,n,r,s,o;if(l(t),void 0===(n=this._events))return this;if(void 0===(a=n[e]))return this;if(a===t||a.listener===t)0==--this._eventsCount?this._events=Object.create(null):(delete n[e],n.removeListener&&this.emit("removeListener",e,a.listener||t));else if("function"!=typeof a){for(r=-1,s=a.length-1;s>=0;s--)if(a[s]===t||a[s].listener===t){o=a[s].listener,r=s;break}if(r<0)return this;0===r?a.shift():function(e,t){for(;t+1<e.length;t++)e[t]=e[t+1];e.pop()}(a,r),1===a.length&&(n[e]=a[0]),void 0!==n.removeListener&&this.emit("removeListener",e,o||t)}return this},s.prototype.off=s.prototype.removeListener,s.prototype.removeAllListeners=function(e){var t,a,n;if(void 0===(a=this._events))return this;if(void 0===a.removeListener)return 0===arguments.length?(this._events=Object.create(null),this._eventsCount=0):void 0!==a[e]&&(0==--this._eventsCount?this._events=Object.create(null):delete a[e]),this;if(0===arguments.length){var r,s=Object.keys(a);for(n=0;n<s.length;++n)"removeListener"!==(r=s[n])&&this.removeAllListeners(r);return this.removeAllListeners("removeListener"),this._events=Object.create(null),this._eventsCount=0,this}if("function"==typeof(t=a[e]))this.removeListener(e,t);else if(void 0!==t)for(n=t.length-1;n>=0;n--)this.removeListener(e,t[n]);return this},s.prototype.listeners=function(e){return p(this,e,!0)},s.prototype.rawListeners=function(e){return p(this,e,!1)},s.listenerCount=function(e,t){return"function"==typeof e.listenerCount?e.listenerCount(t):m.call(e,t)},s.prototype.listenerCount=m,s.prototype.eventNames=function(){return this._eventsCount>0?t(this._events):[]}},5717:e=>{"function"==typeof Object.create?e.exports=function(e,t){t&&(e.super_=t,e.prototype=Object.create(t.prototype,{constructor:{value:e,enumerable:!1,writable:!0,configurable:!0}}))}:e.exports=function(e,t){if(t){e.super_=t;var a=function(){};a.prototype=t.prototype,e.prototype=new a,e.prototype.constructor=e}}},4155:e=>{var t,a,n=e.exports={};function r(){throw new Error("setTimeout has not been defined")}function s(){throw new Error("clearTimeout has not been defined")}function o(e){if(t===setTimeout)return setTimeout(e,0);if((t===r||!t)&&setTimeout)return t=setTimeout,setTimeout(e,0);try{return t(e,0)}catch(a){try{return t.call(null,e,0)}catch(a){return t.call(this,e,0)}}}!function(){try{t="function"==typeof setTimeout?setTimeout:r}catch(e){t=r}try{a="function"==typeof clearTimeout?clearTimeout:s}catch(e){a=s}}();var l,i=[],c=!1,u=-1;function d(){c&&l&&(c=!1,l.length?i=l.concat(i):u=-1,i.length&&p())}function p(){if(!c){var e=o(d);c=!0;for(var t=i.length;t;){for(l=i,i=[];++u<t;)l&&l[u].run();u=-1,t=i.length}l=null,c=!1,function(e){if(a===clearTimeout)return clearTimeout(e);if((a===s||!a)&&clearTimeout)return a=clearTimeout,clearTimeout(e);try{return a(e)}catch(t){try{return a.call(null,e)}catch(t){return a.call(this,e)}}}(e)}}function m(e,t){this.fun=e,this.array=t}function f(){}n.nextTick=function(e){var t=new Array(arguments.length-1);if(arguments.length>1)for(var a=1;a<arguments.length;a++)t[a-1]=arguments[a];i.push(new m(e,t)),1!==i.length||c||o(p)},m.prototype.run=function(){this.fun.apply(null,this.array)},n.title="browser",n.browser=!0,n.env={},n.argv=[],n.version="",n.versions={},n.on=f,n.addListener=f,n.once=f,n.off=f,n.removeListener=f,n.removeAllListeners=f,n.emit=f,n.prependListener=f,n.prependOnceListener=f,n.listeners=function(e){return[]},n.binding=function(e){throw new Error("process.binding is not supported")},n.cwd=function(){return"/"},n.chdir=function(e){throw new Error("process.chdir is not supported")},n.umask=function(){return 0}},1798:(e,t,a)=>{var n=a(4155),r=65536,s=4294967295;var o=a(9509).Buffer,l=a.g.crypto||a.g.msCrypto;l&&l.getRandomValues?e.exports=function(e,t){if(e>s)throw new RangeError("requested too many random bytes");var a=o.allocUnsafe(e);if(e>0)if(e>r)for(var i=0;i<e;i+=r)l.getRandomValues(a.slice(i,i+r));else l.getRandomValues(a);if("function"==typeof t)return n.nextTick((function(){t(null,a)}));return a}:e.exports=function(){throw new Error("Secure random number generation is not supported by this browser.\nUse Chrome, Firefox or Internet Explorer 11")}},4281:e=>{var t={};function a(e,a,n){n||(n=Error);var r=function(e){var t,n;function r(t,n,r){return e.call(this,function(e,t,n){return"string"==typeof a?a:a(e,t,n)}(t,n,r))||this}return n=e,(t=r).prototype=Object.create(n.prototype),t.prototype.constructor=t,t.__proto__=n,r}(n);r.prototype.name=n.name,r.prototype.code=e,t[e]=r}function n(e,t){if(Array.isArray(e)){var a=e.length;return e=e.map((function(e){return String(e)})),a>2?"one of ".concat(t," ").concat(e.slice(0,a-1).join(", "),", or ")+e[a-1]:2===a?"one of ".concat(t," ").concat(e[0]," or ").concat(e[1]):"of ".concat(t," ").concat(e[0])}return"of ".concat(t," ").concat(String(e))}a("ERR_INVALID_OPT_VALUE",(function(e,t){return'The value "'+t+'" is invalid for option "'+e+'"'}),TypeError),a("ERR_INVALID_ARG_TYPE",(function(e,t,a){var r,s,o,l;if("string"==typeof t&&(s="not ",t.substr(!o||o<0?0:+o,s.length)===s)?(r="must not be",t=t.replace(/^not /,"")):r="must be",function(e,t,a){return(void 0===a||a>e.length)&&(a=e.length),e.substring(a-t.length,a)===t}(e," argument"))l="The ".concat(e," ").concat(r," ").concat(n(t,"type"));else{var i=function(e,t,a){return"number"!=typeof a&&(a=0),!(a+t.length>e.length)&&-1!==e.indexOf(t,a)}(e,".")?"property":"argument";l='The "'.concat(e,'" ').concat(i," ").concat(r," ").concat(n(t,"type"))}return l+=". Received type ".concat(typeof a)}),TypeError),a("ERR_STREAM_PUSH_AFTER_EOF","stream.push() after EOF"),a("ERR_METHOD_NOT_IMPLEMENTED",(function(e){return"The "+e+" method is not implemented"})),a("ERR_STREAM_PREMATURE_CLOSE","Premature close"),a("ERR_STREAM_DESTROYED",(function(e){return"Cannot call "+e+" after a stream was destroyed"})),a("ERR_MULTIPLE_CALLBACK","Callback called multiple times"),a("ERR_STREAM_CANNOT_PIPE","Cannot pipe, not readable"),a("ERR_STREAM_WRITE_AFTER_END","write after end"),a("ERR_STREAM_NULL_VALUES","May not write null values to stream",TypeError),a("ERR_UNKNOWN_ENCODING",(function(e){return"Unknown encoding: "+e}),TypeError),a("ERR_STREAM_UNSHIFT_AFTER_END_EVENT","stream.unshift() after end event"),e.exports.q=t},6753:(e,t,a)=>{var n=a(4155),r=Object.keys||function(e){var t=[];for(var a in e)t.push(a);return t};e.exports=u;var s=a(9481),o=a(3982);a(5717)(u,s);for(var l=r(o.prototype),i=0;i<l.length;i++){var c=l[i];u.prototype[c]||(u.prototype[c]=o.prototype[c])}function u(e){if(!(this instanceof u))return new u(e);s.call(this,e),o.call(this,e),this.allowHalfOpen=!0,e&&(!1===e.readable&&(this.readable=!1),!1===e.writable&&(this.writable=!1),!1===e.allowHalfOpen&&(this.allowHalfOpen=!1,this.once("end",d)))}function d(){this._writableState.ended||n.nextTick(p,this)}function p(e){e.end()}Object.defineProperty(u.prototype,"writableHighWaterMark",{enumerable:!1,get:function(){return this._writableState.highWaterMark}}),Object.defineProperty(u.prototype,"writableBuffer",{enumerable:!1,get:function(){return this._writableState&&this._writableState.getBuffer()}}),Object.defineProperty(u.prototype,"writableLength",{enumerable:!1,get:function(){return this._writableState.length}}),Object.defineProperty(u.prototype,"destroyed",{enumerable:!1,get:function(){return void 0!==this._readableState&&void 0!==this._writableState&&(this._readableState.destroyed&&this._writableState.destroyed)},set:function(e){void 0!==this._readableState&&void 0!==this._writableState&&(this._readableState.destroyed=e,this._writableState.destroyed=e)}})},2725:(e,t,a)=>{e.exports=r;var n=a(4605);function r(e){if(!(this instanceof r))return new r(e);n.call(this,e)}a(5717)(r,n),r.prototype._transform=function(e,t,a){a(null,e)}},9481:(e,t,a)=>{var n,r=a(4155);e.exports=C,C.ReadableState=_;a(7187).EventEmitter;var s=function(e,t){return e.listeners(t).length},o=a(2503),l=a(8764).Buffer,i=(void 0!==a.g?a.g:"undefined"!=typeof window?window:"undefined"!=typeof self?self:{}).Uint8Array||function(){};var c,u=a(4616);c=u&&u.debuglog?u.debuglog("stream"):function(){};var d,p,m,f=a(7327),h=a(1195),g=a(2457).getHighWaterMark,y=a(4281).q,v=y.ERR_INVALID_ARG_TYPE,E=y.ERR_STREAM_PUSH_AFTER_EOF,S=y.ERR_METHOD_NOT_IMPLEMENTED,w=y.ERR_STREAM_UNSHIFT_AFTER_END_EVENT;a(5717)(C,o);var b=h.errorOrDestroy,x=["error","close","destroy","pause","resume"];function _(e,t,r){n=n||a(6753),e=e||{},"boolean"!=typeof r&&(r=t instanceof n),this.objectMode=!!e.objectMode,r&&(this.objectMode=this.objectMode||!!e.readableObjectMode),this.highWaterMark=g(this,e,"readableHighWaterMark",r),this.buffer=new f,this.length=0,this.pipes=null,this.pipesCount=0,this.flowing=null,this.ended=!1,this.endEmitted=!1,this.reading=!1,this.sync=!0,this.needReadable=!1,this.emittedReadable=!1,this.readableListening=!1,this.resumeScheduled=!1,this.paused=!0,this.emitClose=!1!==e.emitClose,this.autoDestroy=!!e.autoDestroy,this.destroyed=!1,this.defaultEncoding=e.defaultEncoding||"utf8",this.awaitDrain=0,this.readingMore=!1,this.decoder=null,this.encoding=null,e.encoding&&(d||(d=a(2553).s),this.decoder=new d(e.encoding),this.encoding=e.encoding)}function C(e){if(n=n||a(6753),!(this instanceof C))return new C(e);var t=this instanceof n;this._readableState=new _(e,this,t),this.readable=!0,e&&("function"==typeof e.read&&(this._read=e.read),"function"==typeof e.destroy&&(this._destroy=e.destroy)),o.call(this)}function j(e,t,a,n,r){c("readableAddChunk",t);var s,o=e._readableState;if(null===t)o.reading=!1,function(e,t){if(c("onEofChunk"),t.ended)return;if(t.decoder){var a=t.decoder.end();a&&a.length&&(t.buffer.push(a),t.length+=t.objectMode?1:a.length)}t.ended=!0,t.sync?A(e):(t.needReadable=!1,t.emittedReadable||(t.emittedReadable=!0,I(e)))}(e,o);else if(r||(s=function(e,t){var a;n=t,l.isBuffer(n)||n instanceof i||"string"==typeof t||void 0===t||e.objectMode||(a=new v("chunk",["string","Buffer","Uint8Array"],t));var n;return a}(o,t)),s)b(e,s);else if(o.objectMode||t&&t.length>0)if("string"==typeof t||o.objectMode||Object.getPrototypeOf(t)===l.prototype||(t=function(e){return l.from(e)}(t)),n)o.endEmitted?b(e,new w):N(e,o,t,!0);else if(o.ended)b(e,new E);else{if(o.destroyed)return!1;o.reading=!1,o.decoder&&!a?(t=o.decoder.write(t),o.objectMode||0!==t.length?N(e,o,t,!1):R(e,o)):N(e,o,t,!1)}else n||(o.reading=!1,R(e,o));return!o.ended&&(o.length<o.highWaterMark||0===o.length)}function N(e,t,a,n){t.flowing&&0===t.length&&!t.sync?(t.awaitDrain=0,e.emit("data",a)):(t.length+=t.objectMode?1:a.length,n?t.buffer.unshift(a):t.buffer.push(a),t.needReadable&&A(e)),R(e,t)}Object.defineProperty(C.prototype,"destroyed",{enumerable:!1,get:function(){return void 0!==this._readableState&&this._readableState.destroyed},set:function(e){this._readableState&&(this._readableState.destroyed=e)}}),C.prototype.destroy=h.destroy,C.prototype._undestroy=h.undestroy,C.prototype._destroy=function(e,t){t(e)},C.prototype.push=function(e,t){var a,n=this._readableState;return n.objectMode?a=!0:"string"==typeof e&&((t=t||n.defaultEncoding)!==n.encoding&&(e=l.from(e,t),t=""),a=!0),j(this,e,t,!1,a)},C.prototype.unshift=function(e){return j(this,e,null,!0,!1)},C.prototype.isPaused=function(){return!1===this._readableState.flowing},C.prototype.setEncoding=function(e){d||(d=a(2553).s);var t=new d(e);this._readableState.decoder=t,this._readableState.encoding=this._readableState.decoder.encoding;for(var n=this._readableState.buffer.head,r="";null!==n;)r+=t.write(n.data),n=n.next;return this._readableState.buffer.clear(),""!==r&&this._readableState.buffer.push(r),this._readableState.length=r.length,this};var O=1073741824;function k(e,t){return e<=0||0===t.length&&t.ended?0:t.objectMode?1:e!=e?t.flowing&&t.length?t.buffer.head.data.length:t.length:(e>t.highWaterMark&&(t.highWaterMark=function(e){return e>=O?e=O:(e--,e|=e>>>1,e|=e>>>2,e|=e>>>4,e|=e>>>8,e|=e>>>16,e++),e}(e)),e<=t.length?e:t.ended?t.length:(t.needReadable=!0,0))}function A(e){var t=e._readableState;c("emitReadable",t.needReadable,t.emittedReadable),t.needReadable=!1,t.emittedReadable||(c("emitReadable",t.flowing),t.emittedReadable=!0,r.nextTick(I,e))}function I(e){var t=e._readableState;c("emitReadable_",t.destroyed,t.length,t.ended),t.destroyed||!t.length&&!t.ended||(e.emit("readable"),t.emittedReadable=!1),t.needReadable=!t.flowing&&!t.ended&&t.length<=t.highWaterMark,L(e)}function R(e,t){t.readingMore||(t.readingMore=!0,r.nextTick(P,e,t))}function P(e,t){for(;!t.reading&&!t.ended&&(t.length<t.highWaterMark||t.flowing&&0===t.length);){var a=t.length;if(c("maybeReadMore read 0"),e.read(0),a===t.length)break}t.readingMore=!1}function T(e){var t=e._readableState;t.readableListening=e.listenerCount("readable")>0,t.resumeScheduled&&!t.paused?t.flowing=!0:e.listenerCount("data")>0&&e.resume()}function M(e){c("readable nexttick read 0"),e.read(0)}function D(e,t){c("resume",t.reading),t.reading||e.read(0),t.resumeScheduled=!1,e.emit("resume"),L(e),t.flowing&&!t.reading&&e.read(0)}function L(e){var t=e._readableState;for(c("flow",t.flowing);t.flowing&&null!==e.read(););}function q(e,t){return 0===t.length?null:(t.objectMode?a=t.buffer.shift():!e||e>=t.length?(a=t.decoder?t.buffer.join(""):1===t.buffer.length?t.buffer.first():t.buffer.concat(t.length),t.buffer.clear()):a=t.buffer.consume(e,t.decoder),a);var a}function B(e){var t=e._readableState;c("endReadable",t.endEmitted),t.endEmitted||(t.ended=!0,r.nextTick(U,t,e))}function U(e,t){if(c("endReadableNT",e.endEmitted,e.length),!e.endEmitted&&0===e.length&&(e.endEmitted=!0,t.readable=!1,t.emit("end"),e.autoDestroy)){var a=t._writableState;(!a||a.autoDestroy&&a.finished)&&t.destroy()}}function J(e,t){for(var a=0,n=e.length;a<n;a++)if(e[a]===t)return a;return-1}C.prototype.read=function(e){c("read",e),e=parseInt(e,10);var t=this._readableState,a=e;if(0!==e&&(t.emittedReadable=!1),0===e&&t.needReadable&&((0!==t.highWaterMark?t.length>=t.highWaterMark:t.length>0)||t.ended))return c("read: emitReadable",t.length,t.ended),0===t.length&&t.ended?B(this):A(this),null;if(0===(e=k(e,t))&&t.ended)return 0===t.length&&B(this),null;var n,r=t.needReadable;return c("need readable",r),(0===t.length||t.length-e<t.highWaterMark)&&c("length less than watermark",r=!0),t.ended||t.reading?c("reading or ended",r=!1):r&&(c("do read"),t.reading=!0,t.sync=!0,0===t.length&&(t.needReadable=!0),this._read(t.highWaterMark),t.sync=!1,t.reading||(e=k(a,t))),null===(n=e>0?q(e,t):null)?(t.needReadable=t.length<=t.highWaterMark,e=0):(t.length-=e,t.awaitDrain=0),0===t.length&&(t.ended||(t.needReadable=!0),a!==e&&t.ended&&B(this)),null!==n&&this.emit("data",n),n},C.prototype._read=function(e){b(this,new S("_read()"))},C.prototype.pipe=function(e,t){var a=this,n=this._readableState;switch(n.pipesCount){case 0:n.pipes=e;break;case 1:n.pipes=[n.pipes,e];break;default:n.pipes.push(e)}n.pipesCount+=1,c("pipe count=%d opts=%j",n.pipesCount,t);var o=(!t||!1!==t.end)&&e!==r.stdout&&e!==r.stderr?i:g;function l(t,r){c("onunpipe"),t===a&&r&&!1===r.hasUnpiped&&(r.hasUnpiped=!0,c("cleanup"),e.removeListener("close",f),e.removeListener("finish",h),e.removeListener("drain",u),e.removeListener("error",m),e.removeListener("unpipe",l),a.removeListener("end",i),a.removeListener("end",g),a.removeListener("data",p),d=!0,!n.awaitDrain||e._writableState&&!e._writableState.needDrain||u())}function i(){c("onend"),e.end()}n.endEmitted?r.nextTick(o):a.once("end",o),e.on("unpipe",l);var u=function(e){return function(){var t=e._readableState;c("pipeOnDrain",t.awaitDrain),t.awaitDrain&&t.awaitDrain--,0===t.awaitDrain&&s(e,"data")&&(t.flowing=!0,L(e))}}(a);e.on("drain",u);var d=!1;function p(t){c("ondata");var r=e.write(t);c("dest.write",r),!1===r&&((1===n.pipesCount&&n.pipes===e||n.pipesCount>1&&-1!==J(n.pipes,e))&&!d&&(c("false write response, pause",n.awaitDrain),n.awaitDrain++),a.pause())}function m(t){c("onerror",t),g(),e.removeListener("error",m),0===s(e,"error")&&b(e,t)}function f(){e.removeListener("finish",h),g()}function h(){c("onfinish"),e.removeListener("close",f),g()}function g(){c("unpipe"),a.unpipe(e)}return a.on("data",p),function(e,t,a){if("function"==typeof e.prependListener)return e.prependListener(t,a);e._events&&e._events[t]?Array.isArray(e._events[t])?e._events[t].unshift(a):e._events[t]=[a,e._events[t]]:e.on(t,a)}(e,"error",m),e.once("close",f),e.once("finish",h),e.emit("pipe",a),n.flowing||(c("pipe resume"),a.resume()),e},C.prototype.unpipe=function(e){var t=this._readableState,a={hasUnpiped:!1};if(0===t.pipesCount)return this;if(1===t.pipesCount)return e&&e!==t.pipes||(e||(e=t.pipes),t.pipes=null,t.pipesCount=0,t.flowing=!1,e&&e.emit("unpipe",this,a)),this;if(!e){var n=t.pipes,r=t.pipesCount;t.pipes=null,t.pipesCount=0,t.flowing=!1;for(var s=0;s<r;s++)n[s].emit("unpipe",this,{hasUnpiped:!1});return this}var o=J(t.pipes,e);return-1===o||(t.pipes.splice(o,1),t.pipesCount-=1,1===t.pipesCount&&(t.pipes=t.pipes[0]),e.emit("unpipe",this,a)),this},C.prototype.on=function(e,t){var a=o.prototype.on.call(this,e,t),n=this._readableState;return"data"===e?(n.readableListening=this.listenerCount("readable")>0,!1!==n.flowing&&this.resume()):"readable"===e&&(n.endEmitted||n.readableListening||(n.readableListening=n.needReadable=!0,n.flowing=!1,n.emittedReadable=!1,c("on readable",n.length,n.reading),n.length?A(this):n.reading||r.nextTick(M,this))),a},C.prototype.addListener=C.prototype.on,C.prototype.removeListener=function(e,t){var a=o.prototype.removeListener.call(this,e,t);return"readable"===e&&r.nextTick(T,this),a},C.prototype.removeAllListeners=function(e){var t=o.prototype.removeAllListeners.apply(this,arguments);return"readable"!==e&&void 0!==e||r.nextTick(T,this),t},C.prototype.resume=function(){var e=this._readableState;return e.flowing||(c("resume"),e.flowing=!e.readableListening,function(e,t){t.resumeScheduled||(t.resumeScheduled=!0,r.nextTick(D,e,t))}(this,e)),e.paused=!1,this},C.prototype.pause=function(){return c("call pause flowing=%j",this._readableState.flowing),!1!==this._readableState.flowing&&(c("pause"),this._readableState.flowing=!1,this.emit("pause")),this._readableState.paused=!0,this},C.prototype.wrap=function(e){var t=this,a=this._readableState,n=!1;for(var r in e.on("end",(function(){if(c("wrapped end"),a.decoder&&!a.ended){var e=a.decoder.end();e&&e.length&&t.push(e)}t.push(null)})),e.on("data",(function(r){(c("wrapped data"),a.decoder&&(r=a.decoder.write(r)),a.objectMode&&null==r)||(a.objectMode||r&&r.length)&&(t.push(r)||(n=!0,e.pause()))})),e)void 0===this[r]&&"function"==typeof e[r]&&(this[r]=function(t){return function(){return e[t].apply(e,arguments)}}(r));for(var s=0;s<x.length;s++)e.on(x[s],this.emit.bind(this,x[s]));return this._read=function(t){c("wrapped _read",t),n&&(n=!1,e.resume())},this},"function"==typeof Symbol&&(C.prototype[Symbol.asyncIterator]=function(){return void 0===p&&(p=a(5850)),p(this)}),Object.defineProperty(C.prototype,"readableHighWaterMark",{enumerable:!1,get:function(){return this._readableState.highWaterMark}}),Object.defineProperty(C.prototype,"readableBuffer",{enumerable:!1,get:function(){return this._readableState&&this._readableState.buffer}}),Object.defineProperty(C.prototype,"readableFlowing",{enumerable:!1,get:function(){return this._readableState.flowing},set:function(e){this._readableState&&(this._readableState.flowing=e)}}),C._fromList=q,Object.defineProperty(C.prototype,"readableLength",{enumerable:!1,get:function(){return this._readableState.length}}),"function"==typeof Symbol&&(C.from=function(e,t){return void 0===m&&(m=a(5167)),m(C,e,t)})},4605:(e,t,a)=>{e.exports=u;var n=a(4281).q,r=n.ERR_METHOD_NOT_IMPLEMENTED,s=n.ERR_MULTIPLE_CALLBACK,o=n.ERR_TRANSFORM_ALREADY_TRANSFORMING,l=n.ERR_TRANSFORM_WITH_LENGTH_0,i=a(6753);function c(e,t){var a=this._transformState;a.transforming=!1;var n=a.writecb;if(null===n)return this.emit("error",new s);a.writechunk=null,a.writecb=null,null!=t&&this.push(t),n(e);var r=this._readableState;r.reading=!1,(r.needReadable||r.length<r.highWaterMark)&&this._read(r.highWaterMark)}function u(e){if(!(this instanceof u))return new u(e);i.call(this,e),this._transformState={afterTransform:c.bind(this),needTransform:!1,transforming:!1,writecb:null,writechunk:null,writeencoding:null},this._readableState.needReadable=!0,this._readableState.sync=!1,e&&("function"==typeof e.transform&&(this._transform=e.transform),"function"==typeof e.flush&&(this._flush=e.flush)),this.on("prefinish",d)}function d(){var e=this;"function"!=typeof this._flush||this._readableState.destroyed?p(this,null,null):this._flush((function(t,a){p(e,t,a)}))}function p(e,t,a){if(t)return e.emit("error",t);if(null!=a&&e.push(a),e._writableState.length)throw new l;if(e._transformState.transforming)throw new o;return e.push(null)}a(5717)(u,i),u.prototype.push=function(e,t){return this._transformState.needTransform=!1,i.prototype.push.call(this,e,t)},u.prototype._transform=function(e,t,a){a(new r("_transform()"))},u.prototype._write=function(e,t,a){var n=this._transformState;if(n.writecb=a,n.writechunk=e,n.writeencoding=t,!n.transforming){var r=this._readableState;(n.needTransform||r.needReadable||r.length<r.highWaterMark)&&this._read(r.highWaterMark)}},u.prototype._read=function(e){var t=this._transformState;null===t.writechunk||t.transforming?t.needTransform=!0:(t.transforming=!0,this._transform(t.writechunk,t.writeencoding,t.afterTransform))},u.prototype._destroy=function(e,t){i.prototype._destroy.call(this,e,(function(e){t(e)}))}},3982:(e,t,a)=>{var n,r=a(4155);function s(e){var t=this;this.next=null,this.entry=null,this.finish=function(){!function(e,t,a){var n=e.entry;e.entry=null;for(;n;){var r=n.callback;t.pendingcb--,r(a),n=n.next}t.corkedRequestsFree.next=e}(t,e)}}e.exports=C,C.WritableState=_;var o={deprecate:a(4927)},l=a(2503),i=a(8764).Buffer,c=(void 0!==a.g?a.g:"undefined"!=typeof window?window:"undefined"!=typeof self?self:{}).Uint8Array||function(){};var u,d=a(1195),p=a(2457).getHighWaterMark,m=a(4281).q,f=m.ERR_INVALID_ARG_TYPE,h=m.ERR_METHOD_NOT_IMPLEMENTED,g=m.ERR_MULTIPLE_CALLBACK,y=m.ERR_STREAM_CANNOT_PIPE,v=m.ERR_STREAM_DESTROYED,E=m.ERR_STREAM_NULL_VALUES,S=m.ERR_STREAM_WRITE_AFTER_END,w=m.ERR_UNKNOWN_ENCODING,b=d.errorOrDestroy;function x(){}function _(e,t,o){n=n||a(6753),e=e||{},"boolean"!=typeof o&&(o=t instanceof n),this.objectMode=!!e.objectMode,o&&(this.objectMode=this.objectMode||!!e.writableObjectMode),this.highWaterMark=p(this,e,"writableHighWaterMark",o),this.finalCalled=!1,this.needDrain=!1,this.ending=!1,this.ended=!1,this.finished=!1,this.destroyed=!1;var l=!1===e.decodeStrings;this.decodeStrings=!l,this.defaultEncoding=e.defaultEncoding||"utf8",this.length=0,this.writing=!1,this.corked=0,this.sync=!0,this.bufferProcessing=!1,this.onwrite=function(e){!function(e,t){var a=e._writableState,n=a.sync,s=a.writecb;if("function"!=typeof s)throw new g;if(function(e){e.writing=!1,e.writecb=null,e.length-=e.writelen,e.writelen=0}(a),t)!function(e,t,a,n,s){--t.pendingcb,a?(r.nextTick(s,n),r.nextTick(I,e,t),e._writableState.errorEmitted=!0,b(e,n)):(s(n),e._writableState.errorEmitted=!0,b(e,n),I(e,t))}(e,a,n,t,s);else{var o=k(a)||e.destroyed;o||a.corked||a.bufferProcessing||!a.bufferedRequest||O(e,a),n?r.nextTick(N,e,a,o,s):N(e,a,o,s)}}(t,e)},this.writecb=null,this.writelen=0,this.bufferedRequest=null,this.lastBufferedRequest=null,this.pendingcb=0,this.prefinished=!1,this.errorEmitted=!1,this.emitClose=!1!==e.emitClose,this.autoDestroy=!!e.autoDestroy,this.bufferedRequestCount=0,this.corkedRequestsFree=new s(this)}function C(e){var t=this instanceof(n=n||a(6753));if(!t&&!u.call(C,this))return new C(e);this._writableState=new _(e,this,t),this.writable=!0,e&&("function"==typeof e.write&&(this._write=e.write),"function"==typeof e.writev&&(this._writev=e.writev),"function"==typeof e.destroy&&(this._destroy=e.destroy),"function"==typeof e.final&&(this._final=e.final)),l.call(this)}function j(e,t,a,n,r,s,o){t.writelen=n,t.writecb=o,t.writing=!0,t.sync=!0,t.destroyed?t.onwrite(new v("write")):a?e._writev(r,t.onwrite):e._write(r,s,t.onwrite),t.sync=!1}function N(e,t,a,n){a||function(e,t){0===t.length&&t.needDrain&&(t.needDrain=!1,e.emit("drain"))}(e,t),t.pendingcb--,n(),I(e,t)}function O(e,t){t.bufferProcessing=!0;var a=t.bufferedRequest;if(e._writev&&a&&a.next){var n=t.bufferedRequestCount,r=new Array(n),o=t.corkedRequestsFree;o.entry=a;for(var l=0,i=!0;a;)r[l]=a,a.isBuf||(i=!1),a=a.next,l+=1;r.allBuffers=i,j(e,t,!0,t.length,r,"",o.finish),t.pendingcb++,t.lastBufferedRequest=null,o.next?(t.corkedRequestsFree=o.next,o.next=null):t.corkedRequestsFree=new s(t),t.bufferedRequestCount=0}else{for(;a;){var c=a.chunk,u=a.encoding,d=a.callback;if(j(e,t,!1,t.objectMode?1:c.length,c,u,d),a=a.next,t.bufferedRequestCount--,t.writing)break}null===a&&(t.lastBufferedRequest=null)}t.bufferedRequest=a,t.bufferProcessing=!1}function k(e){return e.ending&&0===e.length&&null===e.bufferedRequest&&!e.finished&&!e.writing}function A(e,t){e._final((function(a){t.pendingcb--,a&&b(e,a),t.prefinished=!0,e.emit("prefinish"),I(e,t)}))}function I(e,t){var a=k(t);if(a&&(function(e,t){t.prefinished||t.finalCalled||("function"!=typeof e._final||t.destroyed?(t.prefinished=!0,e.emit("prefinish")):(t.pendingcb++,t.finalCalled=!0,r.nextTick(A,e,t)))}(e,t),0===t.pendingcb&&(t.finished=!0,e.emit("finish"),t.autoDestroy))){var n=e._readableState;(!n||n.autoDestroy&&n.endEmitted)&&e.destroy()}return a}a(5717)(C,l),_.prototype.getBuffer=function(){for(var e=this.bufferedRequest,t=[];e;)t.push(e),e=e.next;return t},function(){try{Object.defineProperty(_.prototype,"buffer",{get:o.deprecate((function(){return this.getBuffer()}),"_writableState.buffer is deprecated. Use _writableState.getBuffer instead.","DEP0003")})}catch(e){}}(),"function"==typeof Symbol&&Symbol.hasInstance&&"function"==typeof Function.prototype[Symbol.hasInstance]?(u=Function.prototype[Symbol.hasInstance],Object.defineProperty(C,Symbol.hasInstance,{value:function(e){return!!u.call(this,e)||this===C&&(e&&e._writableState instanceof _)}})):u=function(e){return e instanceof this},C.prototype.pipe=function(){b(this,new y)},C.prototype.write=function(e,t,a){var n,s=this._writableState,o=!1,l=!s.objectMode&&(n=e,i.isBuffer(n)||n instanceof c);return l&&!i.isBuffer(e)&&(e=function(e){return i.from(e)}(e)),"function"==typeof t&&(a=t,t=null),l?t="buffer":t||(t=s.defaultEncoding),"function"!=typeof a&&(a=x),s.ending?function(e,t){var a=new S;b(e,a),r.nextTick(t,a)}(this,a):(l||function(e,t,a,n){var s;return null===a?s=new E:"string"==typeof a||t.objectMode||(s=new f("chunk",["string","Buffer"],a)),!s||(b(e,s),r.nextTick(n,s),!1)}(this,s,e,a))&&(s.pendingcb++,o=function(e,t,a,n,r,s){if(!a){var o=function(e,t,a){e.objectMode||!1===e.decodeStrings||"string"!=typeof t||(t=i.from(t,a));return t}(t,n,r);n!==o&&(a=!0,r="buffer",n=o)}var l=t.objectMode?1:n.length;t.length+=l;var c=t.length<t.highWaterMark;c||(t.needDrain=!0);if(t.writing||t.corked){var u=t.lastBufferedRequest;t.lastBufferedRequest={chunk:n,encoding:r,isBuf:a,callback:s,next:null},u?u.next=t.lastBufferedRequest:t.bufferedRequest=t.lastBufferedRequest,t.bufferedRequestCount+=1}else j(e,t,!1,l,n,r,s);return c}(this,s,l,e,t,a)),o},C.prototype.cork=function(){this._writableState.corked++},C.prototype.uncork=function(){var e=this._writableState;e.corked&&(e.corked--,e.writing||e.corked||e.bufferProcessing||!e.bufferedRequest||O(this,e))},C.prototype.setDefaultEncoding=function(e){if("string"==typeof e&&(e=e.toLowerCase()),!(["hex","utf8","utf-8","ascii","binary","base64","ucs2","ucs-2","utf16le","utf-16le","raw"].indexOf((e+"").toLowerCase())>-1))throw new w(e);return this._writableState.defaultEncoding=e,this},Object.defineProperty(C.prototype,"writableBuffer",{enumerable:!1,get:function(){return this._writableState&&this._writableState.getBuffer()}}),Object.defineProperty(C.prototype,"writableHighWaterMark",{enumerable:!1,get:function(){return this._writableState.highWaterMark}}),C.prototype._write=function(e,t,a){a(new h("_write()"))},C.prototype._writev=null,C.prototype.end=function(e,t,a){var n=this._writableState;return"function"==typeof e?(a=e,e=null,t=null):"function"==typeof t&&(a=t,t=null),null!=e&&this.write(e,t),n.corked&&(n.corked=1,this.uncork()),n.ending||function(e,t,a){t.ending=!0,I(e,t),a&&(t.finished?r.nextTick(a):e.once("finish",a));t.ended=!0,e.writable=!1}(this,n,a),this},Object.defineProperty(C.prototype,"writableLength",{enumerable:!1,get:function(){return this._writableState.length}}),Object.defineProperty(C.prototype,"destroyed",{enumerable:!1,get:function(){return void 0!==this._writableState&&this._writableState.destroyed},set:function(e){this._writableState&&(this._writableState.destroyed=e)}}),C.prototype.destroy=d.destroy,C.prototype._undestroy=d.undestroy,C.prototype._destroy=function(e,t){t(e)}},5850:(e,t,a)=>{var n,r=a(4155);function s(e,t,a){return(t=function(e){var t=function(e,t){if("object"!=typeof e||null===e)return e;var a=e[Symbol.toPrimitive];if(void 0!==a){var n=a.call(e,t||"default");if("object"!=typeof n)return n;throw new TypeError("@@toPrimitive must return a primitive value.")}return("string"===t?String:Number)(e)}(e,"string");return"symbol"==typeof t?t:String(t)}(t))in e?Object.defineProperty(e,t,{value:a,enumerable:!0,configurable:!0,writable:!0}):e[t]=a,e}var o=a(8610),l=Symbol("lastResolve"),i=Symbol("lastReject"),c=Symbol("error"),u=Symbol("ended"),d=Symbol("lastPromise"),p=Symbol("handlePromise"),m=Symbol("stream");function f(e,t){return{value:e,done:t}}function h(e){var t=e[l];if(null!==t){var a=e[m].read();null!==a&&(e[d]=null,e[l]=null,e[i]=null,t(f(a,!1)))}}function g(e){r.nextTick(h,e)}var y=Object.getPrototypeOf((function(){})),v=Object.setPrototypeOf((s(n={get stream(){return this[m]},next:function(){var e=this,t=this[c];if(null!==t)return Promise.reject(t);if(this[u])return Promise.resolve(f(void 0,!0));if(this[m].destroyed)return new Promise((function(t,a){r.nextTick((function(){e[c]?a(e[c]):t(f(void 0,!0))}))}));var a,n=this[d];if(n)a=new Promise(function(e,t){return function(a,n){e.then((function(){t[u]?a(f(void 0,!0)):t[p](a,n)}),n)}}(n,this));else{var s=this[m].read();if(null!==s)return Promise.resolve(f(s,!1));a=new Promise(this[p])}return this[d]=a,a}},Symbol.asyncIterator,(function(){return this})),s(n,"return",(function(){var e=this;return new Promise((function(t,a){e[m].destroy(null,(function(e){e?a(e):t(f(void 0,!0))}))}))})),n),y);e.exports=function(e){var t,a=Object.create(v,(s(t={},m,{value:e,writable:!0}),s(t,l,{value:null,writable:!0}),s(t,i,{value:null,writable:!0}),s(t,c,{value:null,writable:!0}),s(t,u,{value:e._readableState.endEmitted,writable:!0}),s(t,p,{value:function(e,t){var n=a[m].read();n?(a[d]=null,a[l]=null,a[i]=null,e(f(n,!1))):(a[l]=e,a[i]=t)},writable:!0}),t));return a[d]=null,o(e,(function(e){if(e&&"ERR_STREAM_PREMATURE_CLOSE"!==e.code){var t=a[i];return null!==t&&(a[d]=null,a[l]=null,a[i]=null,t(e)),void(a[c]=e)}var n=a[l];null!==n&&(a[d]=null,a[l]=null,a[i]=null,n(f(void 0,!0))),a[u]=!0})),e.on("readable",g.bind(null,a)),a}},7327:(e,t,a)=>{function n(e,t){var a=Object.keys(e);if(Object.getOwnPropertySymbols){var n=Object.getOwnPropertySymbols(e);t&&(n=n.filter((function(t){return Object.getOwnPropertyDescriptor(e,t).enumerable}))),a.push.apply(a,n)}return a}function r(e){for(var t=1;t<arguments.length;t++){var a=null!=arguments[t]?arguments[t]:{};t%2?n(Object(a),!0).forEach((function(t){s(e,t,a[t])})):Object.getOwnPropertyDescriptors?Object.defineProperties(e,Object.getOwnPropertyDescriptors(a)):n(Object(a)).forEach((function(t){Object.defineProperty(e,t,Object.getOwnPropertyDescriptor(a,t))}))}return e}function s(e,t,a){return(t=l(t))in e?Object.defineProperty(e,t,{value:a,enumerable:!0,configurable:!0,writable:!0}):e[t]=a,e}function o(e,t){for(var a=0;a<t.length;a++){var n=t[a];n.enumerable=n.enumerable||!1,n.configurable=!0,"value"in n&&(n.writable=!0),Object.defineProperty(e,l(n.key),n)}}function l(e){var t=function(e,t){if("object"!=typeof e||null===e)return e;var a=e[Symbol.toPrimitive];if(void 0!==a){var n=a.call(e,t||"default");if("object"!=typeof n)return n;throw new TypeError("@@toPrimitive must return a primitive value.")}return("string"===t?String:Number)(e)}(e,"string");return"symbol"==typeof t?t:String(t)}var i=a(8764).Buffer,c=a(2361).inspect,u=c&&c.custom||"inspect";e.exports=function(){function e(){!function(e,t){if(!(e instanceof t))throw new TypeError("Cannot call a class as a function")}(this,e),this.head=null,this.tail=null,this.length=0}var t,a,n;return t=e,(a=[{key:"push",value:function(e){var t={data:e,next:null};this.length>0?this.tail.next=t:this.head=t,this.tail=t,++this.length}},{key:"unshift",value:function(e){var t={data:e,next:this.head};0===this.length&&(this.tail=t),this.head=t,++this.length}},{key:"shift",value:function(){if(0!==this.length){var e=this.head.data;return 1===this.length?this.head=this.tail=null:this.head=this.head.next,--this.length,e}}},{key:"clear",value:function(){this.head=this.tail=null,this.length=0}},{key:"join",value:function(e){if(0===this.length)return"";for(var t=this.head,a=""+t.data;t=t.next;)a+=e+t.data;return a}},{key:"concat",value:function(e){if(0===this.length)return i.alloc(0);for(var t,a,n,r=i.allocUnsafe(e>>>0),s=this.head,o=0;s;)t=s.data,a=r,n=o,i.prototype.copy.call(t,a,n),o+=s.data.length,s=s.next;return r}},{key:"consume",value:function(e,t){var a;return e<this.head.data.length?(a=this.head.data.slice(0,e),this.head.data=this.head.data.slice(e)):a=e===this.head.data.length?this.shift():t?this._getString(e):this._getBuffer(e),a}},{key:"first",value:function(){return this.head.data}},{key:"_getString",value:function(e){var t=this.head,a=1,n=t.data;for(e-=n.length;t=t.next;){var r=t.data,s=e>r.length?r.length:e;if(s===r.length?n+=r:n+=r.slice(0,e),0==(e-=s)){s===r.length?(++a,t.next?this.head=t.next:this.head=this.tail=null):(this.head=t,t.data=r.slice(s));break}++a}return this.length-=a,n}},{key:"_getBuffer",value:function(e){var t=i.allocUnsafe(e),a=this.head,n=1;for(a.data.copy(t),e-=a.data.length;a=a.next;){var r=a.data,s=e>r.length?r.length:e;if(r.copy(t,t.length-e,0,s),0==(e-=s)){s===r.length?(++n,a.next?this.head=a.next:this.head=this.tail=null):(this.head=a,a.data=r.slice(s));break}++n}return this.length-=n,t}},{key:u,value:function(e,t){return c(this,r(r({},t),{},{depth:0,customInspect:!1}))}}])&&o(t.prototype,a),n&&o(t,n),Object.defineProperty(t,"prototype",{writable:!1}),e}()},1195:(e,t,a)=>{var n=a(4155);function r(e,t){o(e,t),s(e)}function s(e){e._writableState&&!e._writableState.emitClose||e._readableState&&!e._readableState.emitClose||e.emit("close")}function o(e,t){e.emit("error",t)}e.exports={destroy:function(e,t){var a=this,l=this._readableState&&this._readableState.destroyed,i=this._writableState&&this._writableState.destroyed;return l||i?(t?t(e):e&&(this._writableState?this._writableState.errorEmitted||(this._writableState.errorEmitted=!0,n.nextTick(o,this,e)):n.nextTick(o,this,e)),this):(this._readableState&&(this._readableState.destroyed=!0),this._writableState&&(this._writableState.destroyed=!0),this._destroy(e||null,(function(e){!t&&e?a._writableState?a._writableState.errorEmitted?n.nextTick(s,a):(a._writableState.errorEmitted=!0,n.nextTick(r,a,e)):n.nextTick(r,a,e):t?(n.nextTick(s,a),t(e)):n.nextTick(s,a)})),this)},undestroy:function(){this._readableState&&(this._readableState.destroyed=!1,this._readableState.reading=!1,this._readableState.ended=!1,this._readableState.endEmitted=!1),this._writableState&&(this._writableState.destroyed=!1,this._writableState.ended=!1,this._writableState.ending=!1,this._writableState.finalCalled=!1,this._writableState.prefinished=!1,this._writableState.finished=!1,this._writableState.errorEmitted=!1)},errorOrDestroy:function(e,t){var a=e._readableState,n=e._writableState;a&&a.autoDestroy||n&&n.autoDestroy?e.destroy(t):e.emit("error",t)}}},8610:(e,t,a)=>{var n=a(4281).q.ERR_STREAM_PREMATURE_CLOSE;function r(){}e.exports=function e(t,a,s){if("function"==typeof a)return e(t,null,a);a||(a={}),s=function(e){var t=!1;return function(){if(!t){t=!0;for(var a=arguments.length,n=new Array(a),r=0;r<a;r++)n[r]=arguments[r];e.apply(this,n)}}}(s||r);var o=a.readable||!1!==a.readable&&t.readable,l=a.writable||!1!==a.writable&&t.writable,i=function(){t.writable||u()},c=t._writableState&&t._writableState.finished,u=function(){l=!1,c=!0,o||s.call(t)},d=t._readableState&&t._readableState.endEmitted,p=function(){o=!1,d=!0,l||s.call(t)},m=function(e){s.call(t,e)},f=function(){var e;return o&&!d?(t._readableState&&t._readableState.ended||(e=new n),s.call(t,e)):l&&!c?(t._writableState&&t._writableState.ended||(e=new n),s.call(t,e)):void 0},h=function(){t.req.on("finish",u)};return!function(e){return e.setHeader&&"function"==typeof e.abort}(t)?l&&!t._writableState&&(t.on("end",i),t.on("close",i)):(t.on("complete",u),t.on("abort",f),t.req?h():t.on("request",h)),t.on("end",p),t.on("finish",u),!1!==a.error&&t.on("error",m),t.on("close",f),function(){t.removeListener("complete",u),t.removeListener("abort",f),t.removeListener("request",h),t.req&&t.req.removeListener("finish",u),t.removeListener("end",i),t.removeListener("close",i),t.removeListener("finish",u),t.removeListener("end",p),t.removeListener("error",m),t.removeListener("close",f)}}},5167:e=>{e.exports=function(){throw new Error("Readable.from is not available in the browser")}},9946:(e,t,a)=>{var n;var r=a(4281).q,s=r.ERR_MISSING_ARGS,o=r.ERR_STREAM_DESTROYED;function l(e){if(e)throw e}function i(e){e()}function c(e,t){return e.pipe(t)}e.exports=function(){for(var e=arguments.length,t=new Array(e),r=0;r<e;r++)t[r]=arguments[r];var u,d=function(e){return e.length?"function"!=typeof e[e.length-1]?l:e.pop():l}(t);if(Array.isArray(t[0])&&(t=t[0]),t.length<2)throw new s("streams");var p=t.map((function(e,r){var s=r<t.length-1;return function(e,t,r,s){s=function(e){var t=!1;return function(){t||(t=!0,e.apply(void 0,arguments))}}(s);var l=!1;e.on("close",(function(){l=!0})),void 0===n&&(n=a(8610)),n(e,{readable:t,writable:r},(function(e){if(e)return s(e);l=!0,s()}));var i=!1;return function(t){if(!l&&!i)return i=!0,function(e){return e.setHeader&&"function"==typeof e.abort}(e)?e.abort():"function"==typeof e.destroy?e.destroy():void s(t||new o("pipe"))}}(e,s,r>0,(function(e){u||(u=e),e&&p.forEach(i),s||(p.forEach(i),d(u))}))}));return t.reduce(c)}},2457:(e,t,a)=>{var n=a(4281).q.ERR_INVALID_OPT_VALUE;e.exports={getHighWaterMark:function(e,t,a,r){var s=function(e,t,a){return null!=e.highWaterMark?e.highWaterMark:t?e[a]:null}(t,r,a);if(null!=s){if(!isFinite(s)||Math.floor(s)!==s||s<0)throw new n(r?a:"highWaterMark",s);return Math.floor(s)}return e.objectMode?16:16384}}},2503:(e,t,a)=>{e.exports=a(7187).EventEmitter},9509:(e,t,a)=>{var n=a(8764),r=n.Buffer;function s(e,t){for(var a in e)t[a]=e[a]}function o(e,t,a){return r(e,t,a)}r.from&&r.alloc&&r.allocUnsafe&&r.allocUnsafeSlow?e.exports=n:(s(n,t),t.Buffer=o),o.prototype=Object.create(r.prototype),s(r,o),o.from=function(e,t,a){if("number"==typeof e)throw new TypeError("Argument must not be a number");return r(e,t,a)},o.alloc=function(e,t,a){if("number"!=typeof e)throw new TypeError("Argument must be a number");var n=r(e);return void 0!==t?"string"==typeof a?n.fill(t,a):n.fill(t):n.fill(0),n},o.allocUnsafe=function(e){if("number"!=typeof e)throw new TypeError("Argument must be a number");return r(e)},o.allocUnsafeSlow=function(e){if("number"!=typeof e)throw new TypeError("Argument must be a number");return n.SlowBuffer(e)}},4189:(e,t,a)=>{var n=a(9509).Buffer;function r(e,t){this._block=n.alloc(e),this._finalSize=t,this._blockSize=e,this._len=0}r.prototype.update=function(e,t){"string"==typeof e&&(t=t||"utf8",e=n.from(e,t));for(var a=this._block,r=this._blockSize,s=e.length,o=this._len,l=0;l<s;){for(var i=o%r,c=Math.min(s-l,r-i),u=0;u<c;u++)a[i+u]=e[l+u];l+=c,(o+=c)%r==0&&this._update(a)}return this._len+=s,this},r.prototype.digest=function(e){var t=this._len%this._blockSize;this._block[t]=128,this._block.fill(0,t+1),t>=this._finalSize&&(this._update(this._block),this._block.fill(0));var a=8*this._len;if(a<=4294967295)this._block.writeUInt32BE(a,this._blockSize-4);else{var n=(4294967295&a)>>>0,r=(a-n)/4294967296;this._block.writeUInt32BE(r,this._blockSize-8),this._block.writeUInt32BE(n,this._blockSize-4)}this._update(this._block);var s=this._hash();return e?s.toString(e):s},r.prototype._update=function(){throw new Error("_update must be implemented by subclass")},e.exports=r},9072:(e,t,a)=>{var n=e.exports=function(e){e=e.toLowerCase();var t=n[e];if(!t)throw new Error(e+" is not supported (we accept pull requests)");return new t};n.sha=a(4448),n.sha1=a(8336),n.sha224=a(8432),n.sha256=a(7499),n.sha384=a(1686),n.sha512=a(7816)},4448:(e,t,a)=>{var n=a(5717),r=a(4189),s=a(9509).Buffer,o=[1518500249,1859775393,-1894007588,-899497514],l=new Array(80);function i(){this.init(),this._w=l,r.call(this,64,56)}function c(e){return e<<30|e>>>2}function u(e,t,a,n){return 0===e?t&a|~t&n:2===e?t&a|t&n|a&n:t^a^n}n(i,r),i.prototype.init=function(){return this._a=1732584193,this._b=4023233417,this._c=2562383102,this._d=271733878,this._e=3285377520,this},i.prototype._update=function(e){for(var t,a=this._w,n=0|this._a,r=0|this._b,s=0|this._c,l=0|this._d,i=0|this._e,d=0;d<16;++d)a[d]=e.readInt32BE(4*d);for(;d<80;++d)a[d]=a[d-3]^a[d-8]^a[d-14]^a[d-16];for(var p=0;p<80;++p){var m=~~(p/20),f=0|((t=n)<<5|t>>>27)+u(m,r,s,l)+i+a[p]+o[m];i=l,l=s,s=c(r),r=n,n=f}this._a=n+this._a|0,this._b=r+this._b|0,this._c=s+this._c|0,this._d=l+this._d|0,this._e=i+this._e|0},i.prototype._hash=function(){var e=s.allocUnsafe(20);return e.writeInt32BE(0|this._a,0),e.writeInt32BE(0|this._b,4),e.writeInt32BE(0|this._c,8),e.writeInt32BE(0|this._d,12),e.writeInt32BE(0|this._e,16),e},e.exports=i},8336:(e,t,a)=>{var n=a(5717),r=a(4189),s=a(9509).Buffer,o=[1518500249,1859775393,-1894007588,-899497514],l=new Array(80);function i(){this.init(),this._w=l,r.call(this,64,56)}function c(e){return e<<5|e>>>27}function u(e){return e<<30|e>>>2}function d(e,t,a,n){return 0===e?t&a|~t&n:2===e?t&a|t&n|a&n:t^a^n}n(i,r),i.prototype.init=function(){return this._a=1732584193,this._b=4023233417,this._c=2562383102,this._d=271733878,this._e=3285377520,this},i.prototype._update=function(e){for(var t,a=this._w,n=0|this._a,r=0|this._b,s=0|this._c,l=0|this._d,i=0|this._e,p=0;p<16;++p)a[p]=e.readInt32BE(4*p);for(;p<80;++p)a[p]=(t=a[p-3]^a[p-8]^a[p-14]^a[p-16])<<1|t>>>31;for(var m=0;m<80;++m){var f=~~(m/20),h=c(n)+d(f,r,s,l)+i+a[m]+o[f]|0;i=l,l=s,s=u(r),r=n,n=h}this._a=n+this._a|0,this._b=r+this._b|0,this._c=s+this._c|0,this._d=l+this._d|0,this._e=i+this._e|0},i.prototype._hash=function(){var e=s.allocUnsafe(20);return e.writeInt32BE(0|this._a,0),e.writeInt32BE(0|this._b,4),e.writeInt32BE(0|this._c,8),e.writeInt32BE(0|this._d,12),e.writeInt32BE(0|this._e,16),e},e.exports=i},8432:(e,t,a)=>{var n=a(5717),r=a(7499),s=a(4189),o=a(9509).Buffer,l=new Array(64);function i(){this.init(),this._w=l,s.call(this,64,56)}n(i,r),i.prototype.init=function(){return this._a=3238371032,this._b=914150663,this._c=812702999,this._d=4144912697,this._e=4290775857,this._f=1750603025,this._g=1694076839,this._h=3204075428,this},i.prototype._hash=function(){var e=o.allocUnsafe(28);return e.writeInt32BE(this._a,0),e.writeInt32BE(this._b,4),e.writeInt32BE(this._c,8),e.writeInt32BE(this._d,12),e.writeInt32BE(this._e,16),e.writeInt32BE(this._f,20),e.writeInt32BE(this._g,24),e},e.exports=i},7499:(e,t,a)=>{var n=a(5717),r=a(4189),s=a(9509).Buffer,o=[1116352408,1899447441,3049323471,3921009573,961987163,1508970993,2453635748,2870763221,3624381080,310598401,607225278,1426881987,1925078388,2162078206,2614888103,3248222580,3835390401,4022224774,264347078,604807628,770255983,1249150122,1555081692,1996064986,2554220882,2821834349,2952996808,3210313671,3336571891,3584528711,113926993,338241895,666307205,773529912,1294757372,1396182291,1695183700,1986661051,2177026350,2456956037,2730485921,2820302411,3259730800,3345764771,3516065817,3600352804,4094571909,275423344,430227734,506948616,659060556,883997877,958139571,1322822218,1537002063,1747873779,1955562222,2024104815,2227730452,2361852424,2428436474,2756734187,3204031479,3329325298],l=new Array(64);function i(){this.init(),this._w=l,r.call(this,64,56)}function c(e,t,a){return a^e&(t^a)}function u(e,t,a){return e&t|a&(e|t)}function d(e){return(e>>>2|e<<30)^(e>>>13|e<<19)^(e>>>22|e<<10)}function p(e){return(e>>>6|e<<26)^(e>>>11|e<<21)^(e>>>25|e<<7)}function m(e){return(e>>>7|e<<25)^(e>>>18|e<<14)^e>>>3}n(i,r),i.prototype.init=function(){return this._a=1779033703,this._b=3144134277,this._c=1013904242,this._d=2773480762,this._e=1359893119,this._f=2600822924,this._g=528734635,this._h=1541459225,this},i.prototype._update=function(e){for(var t,a=this._w,n=0|this._a,r=0|this._b,s=0|this._c,l=0|this._d,i=0|this._e,f=0|this._f,h=0|this._g,g=0|this._h,y=0;y<16;++y)a[y]=e.readInt32BE(4*y);for(;y<64;++y)a[y]=0|(((t=a[y-2])>>>17|t<<15)^(t>>>19|t<<13)^t>>>10)+a[y-7]+m(a[y-15])+a[y-16];for(var v=0;v<64;++v){var E=g+p(i)+c(i,f,h)+o[v]+a[v]|0,S=d(n)+u(n,r,s)|0;g=h,h=f,f=i,i=l+E|0,l=s,s=r,r=n,n=E+S|0}this._a=n+this._a|0,this._b=r+this._b|0,this._c=s+this._c|0,this._d=l+this._d|0,this._e=i+this._e|0,this._f=f+this._f|0,this._g=h+this._g|0,this._h=g+this._h|0},i.prototype._hash=function(){var e=s.allocUnsafe(32);return e.writeInt32BE(this._a,0),e.writeInt32BE(this._b,4),e.writeInt32BE(this._c,8),e.writeInt32BE(this._d,12),e.writeInt32BE(this._e,16),e.writeInt32BE(this._f,20),e.writeInt32BE(this._g,24),e.writeInt32BE(this._h,28),e},e.exports=i},1686:(e,t,a)=>{var n=a(5717),r=a(7816),s=a(4189),o=a(9509).Buffer,l=new Array(160);function i(){this.init(),this._w=l,s.call(this,128,112)}n(i,r),i.prototype.init=function(){return this._ah=3418070365,this._bh=1654270250,this._ch=2438529370,this._dh=355462360,this._eh=1731405415,this._fh=2394180231,this._gh=3675008525,this._hh=1203062813,this._al=3238371032,this._bl=914150663,this._cl=812702999,this._dl=4144912697,this._el=4290775857,this._fl=1750603025,this._gl=1694076839,this._hl=3204075428,this},i.prototype._hash=function(){var e=o.allocUnsafe(48);function t(t,a,n){e.writeInt32BE(t,n),e.writeInt32BE(a,n+4)}return t(this._ah,this._al,0),t(this._bh,this._bl,8),t(this._ch,this._cl,16),t(this._dh,this._dl,24),t(this._eh,this._el,32),t(this._fh,this._fl,40),e},e.exports=i},7816:(e,t,a)=>{var n=a(5717),r=a(4189),s=a(9509).Buffer,o=[1116352408,3609767458,1899447441,602891725,3049323471,3964484399,3921009573,2173295548,961987163,4081628472,1508970993,3053834265,2453635748,2937671579,2870763221,3664609560,3624381080,2734883394,310598401,1164996542,607225278,1323610764,1426881987,3590304994,1925078388,4068182383,2162078206,991336113,2614888103,633803317,3248222580,3479774868,3835390401,2666613458,4022224774,944711139,264347078,2341262773,604807628,2007800933,770255983,1495990901,1249150122,1856431235,1555081692,3175218132,1996064986,2198950837,2554220882,3999719339,2821834349,766784016,2952996808,2566594879,3210313671,3203337956,3336571891,1034457026,3584528711,2466948901,113926993,3758326383,338241895,168717936,666307205,1188179964,773529912,1546045734,1294757372,1522805485,1396182291,2643833823,1695183700,2343527390,1986661051,1014477480,2177026350,1206759142,2456956037,344077627,2730485921,1290863460,2820302411,3158454273,3259730800,3505952657,3345764771,106217008,3516065817,3606008344,3600352804,1432725776,4094571909,1467031594,275423344,851169720,430227734,3100823752,506948616,1363258195,659060556,3750685593,883997877,3785050280,958139571,3318307427,1322822218,3812723403,1537002063,2003034995,1747873779,3602036899,1955562222,1575990012,2024104815,1125592928,2227730452,2716904306,2361852424,442776044,2428436474,593698344,2756734187,3733110249,3204031479,2999351573,3329325298,3815920427,3391569614,3928383900,3515267271,566280711,3940187606,3454069534,4118630271,4000239992,116418474,1914138554,174292421,2731055270,289380356,3203993006,460393269,320620315,685471733,587496836,852142971,1086792851,1017036298,365543100,1126000580,2618297676,1288033470,3409855158,1501505948,4234509866,1607167915,987167468,1816402316,1246189591],l=new Array(160);function i(){this.init(),this._w=l,r.call(this,128,112)}function c(e,t,a){return a^e&(t^a)}function u(e,t,a){return e&t|a&(e|t)}function d(e,t){return(e>>>28|t<<4)^(t>>>2|e<<30)^(t>>>7|e<<25)}function p(e,t){return(e>>>14|t<<18)^(e>>>18|t<<14)^(t>>>9|e<<23)}function m(e,t){return(e>>>1|t<<31)^(e>>>8|t<<24)^e>>>7}function f(e,t){return(e>>>1|t<<31)^(e>>>8|t<<24)^(e>>>7|t<<25)}function h(e,t){return(e>>>19|t<<13)^(t>>>29|e<<3)^e>>>6}function g(e,t){return(e>>>19|t<<13)^(t>>>29|e<<3)^(e>>>6|t<<26)}function y(e,t){return e>>>0<t>>>0?1:0}n(i,r),i.prototype.init=function(){return this._ah=1779033703,this._bh=3144134277,this._ch=1013904242,this._dh=2773480762,this._eh=1359893119,this._fh=2600822924,this._gh=528734635,this._hh=1541459225,this._al=4089235720,this._bl=2227873595,this._cl=4271175723,this._dl=1595750129,this._el=2917565137,this._fl=725511199,this._gl=4215389547,this._hl=327033209,this},i.prototype._update=function(e){for(var t=this._w,a=0|this._ah,n=0|this._bh,r=0|this._ch,s=0|this._dh,l=0|this._eh,i=0|this._fh,v=0|this._gh,E=0|this._hh,S=0|this._al,w=0|this._bl,b=0|this._cl,x=0|this._dl,_=0|this._el,C=0|this._fl,j=0|this._gl,N=0|this._hl,O=0;O<32;O+=2)t[O]=e.readInt32BE(4*O),t[O+1]=e.readInt32BE(4*O+4);for(;O<160;O+=2){var k=t[O-30],A=t[O-30+1],I=m(k,A),R=f(A,k),P=h(k=t[O-4],A=t[O-4+1]),T=g(A,k),M=t[O-14],D=t[O-14+1],L=t[O-32],q=t[O-32+1],B=R+D|0,U=I+M+y(B,R)|0;U=(U=U+P+y(B=B+T|0,T)|0)+L+y(B=B+q|0,q)|0,t[O]=U,t[O+1]=B}for(var J=0;J<160;J+=2){U=t[J],B=t[J+1];var $=u(a,n,r),V=u(S,w,b),K=d(a,S),F=d(S,a),z=p(l,_),W=p(_,l),H=o[J],G=o[J+1],Z=c(l,i,v),Y=c(_,C,j),X=N+W|0,Q=E+z+y(X,N)|0;Q=(Q=(Q=Q+Z+y(X=X+Y|0,Y)|0)+H+y(X=X+G|0,G)|0)+U+y(X=X+B|0,B)|0;var ee=F+V|0,te=K+$+y(ee,F)|0;E=v,N=j,v=i,j=C,i=l,C=_,l=s+Q+y(_=x+X|0,x)|0,s=r,x=b,r=n,b=w,n=a,w=S,a=Q+te+y(S=X+ee|0,X)|0}this._al=this._al+S|0,this._bl=this._bl+w|0,this._cl=this._cl+b|0,this._dl=this._dl+x|0,this._el=this._el+_|0,this._fl=this._fl+C|0,this._gl=this._gl+j|0,this._hl=this._hl+N|0,this._ah=this._ah+a+y(this._al,S)|0,this._bh=this._bh+n+y(this._bl,w)|0,this._ch=this._ch+r+y(this._cl,b)|0,this._dh=this._dh+s+y(this._dl,x)|0,this._eh=this._eh+l+y(this._el,_)|0,this._fh=this._fh+i+y(this._fl,C)|0,this._gh=this._gh+v+y(this._gl,j)|0,this._hh=this._hh+E+y(this._hl,N)|0},i.prototype._hash=function(){var e=s.allocUnsafe(64);function t(t,a,n){e.writeInt32BE(t,n),e.writeInt32BE(a,n+4)}return t(this._ah,this._al,0),t(this._bh,this._bl,8),t(this._ch,this._cl,16),t(this._dh,this._dl,24),t(this._eh,this._el,32),t(this._fh,this._fl,40),t(this._gh,this._gl,48),t(this._hh,this._hl,56),e},e.exports=i},2830:(e,t,a)=>{e.exports=r;var n=a(7187).EventEmitter;function r(){n.call(this)}a(5717)(r,n),r.Readable=a(9481),r.Writable=a(3982),r.Duplex=a(6753),r.Transform=a(4605),r.PassThrough=a(2725),r.finished=a(8610),r.pipeline=a(9946),r.Stream=r,r.prototype.pipe=function(e,t){var a=this;function r(t){e.writable&&!1===e.write(t)&&a.pause&&a.pause()}function s(){a.readable&&a.resume&&a.resume()}a.on("data",r),e.on("drain",s),e._isStdio||t&&!1===t.end||(a.on("end",l),a.on("close",i));var o=!1;function l(){o||(o=!0,e.end())}function i(){o||(o=!0,"function"==typeof e.destroy&&e.destroy())}function c(e){if(u(),0===n.listenerCount(this,"error"))throw e}function u(){a.removeListener("data",r),e.removeListener("drain",s),a.removeListener("end",l),a.removeListener("close",i),a.removeListener("error",c),e.removeListener("error",c),a.removeListener("end",u),a.removeListener("close",u),e.removeListener("close",u)}return a.on("error",c),e.on("error",c),a.on("end",u),a.on("close",u),e.on("close",u),e.emit("pipe",a),e}},2553:(e,t,a)=>{var n=a(9509).Buffer,r=n.isEncoding||function(e){switch((e=""+e)&&e.toLowerCase()){case"hex":case"utf8":case"utf-8":case"ascii":case"binary":case"base64":case"ucs2":case"ucs-2":case"utf16le":case"utf-16le":case"raw":return!0;default:return!1}};function s(e){var t;switch(this.encoding=function(e){var t=function(e){if(!e)return"utf8";for(var t;;)switch(e){case"utf8":case"utf-8":return"utf8";case"ucs2":case"ucs-2":case"utf16le":case"utf-16le":return"utf16le";case"latin1":case"binary":return"latin1";case"base64":case"ascii":case"hex":return e;default:if(t)return;e=(""+e).toLowerCase(),t=!0}}(e);if("string"!=typeof t&&(n.isEncoding===r||!r(e)))throw new Error("Unknown encoding: "+e);return t||e}(e),this.encoding){case"utf16le":this.text=i,this.end=c,t=4;break;case"utf8":this.fillLast=l,t=4;break;case"base64":this.text=u,this.end=d,t=3;break;default:return this.write=p,void(this.end=m)}this.lastNeed=0,this.lastTotal=0,this.lastChar=n.allocUnsafe(t)}function o(e){return e<=127?0:e>>5==6?2:e>>4==14?3:e>>3==30?4:e>>6==2?-1:-2}function l(e){var t=this.lastTotal-this.lastNeed,a=function(e,t,a){if(128!=(192&t[0]))return e.lastNeed=0,"�";if(e.lastNeed>1&&t.length>1){if(128!=(192&t[1]))return e.lastNeed=1,"�";if(e.lastNeed>2&&t.length>2&&128!=(192&t[2]))return e.lastNeed=2,"�"}}(this,e);return void 0!==a?a:this.lastNeed<=e.length?(e.copy(this.lastChar,t,0,this.lastNeed),this.lastChar.toString(this.encoding,0,this.lastTotal)):(e.copy(this.lastChar,t,0,e.length),void(this.lastNeed-=e.length))}function i(e,t){if((e.length-t)%2==0){var a=e.toString("utf16le",t);if(a){var n=a.charCodeAt(a.length-1);if(n>=55296&&n<=56319)return this.lastNeed=2,this.lastTotal=4,this.lastChar[0]=e[e.length-2],this.lastChar[1]=e[e.length-1],a.slice(0,-1)}return a}return this.lastNeed=1,this.lastTotal=2,this.lastChar[0]=e[e.length-1],e.toString("utf16le",t,e.length-1)}function c(e){var t=e&&e.length?this.write(e):"";if(this.lastNeed){var a=this.lastTotal-this.lastNeed;return t+this.lastChar.toString("utf16le",0,a)}return t}function u(e,t){var a=(e.length-t)%3;return 0===a?e.toString("base64",t):(this.lastNeed=3-a,this.lastTotal=3,1===a?this.lastChar[0]=e[e.length-1]:(this.lastChar[0]=e[e.length-2],this.lastChar[1]=e[e.length-1]),e.toString("base64",t,e.length-a))}function d(e){var t=e&&e.length?this.write(e):"";return this.lastNeed?t+this.lastChar.toString("base64",0,3-this.lastNeed):t}function p(e){return e.toString(this.encoding)}function m(e){return e&&e.length?this.write(e):""}t.s=s,s.prototype.write=function(e){if(0===e.length)return"";var t,a;if(this.lastNeed){if(void 0===(t=this.fillLast(e)))return"";a=this.lastNeed,this.lastNeed=0}else a=0;return a<e.length?t?t+this.text(e,a):this.text(e,a):t||""},s.prototype.end=function(e){var t=e&&e.length?this.write(e):"";return this.lastNeed?t+"�":t},s.prototype.text=function(e,t){var a=function(e,t,a){var n=t.length-1;if(n<a)return 0;var r=o(t[n]);if(r>=0)return r>0&&(e.lastNeed=r-1),r;if(--n<a||-2===r)return 0;if(r=o(t[n]),r>=0)return r>0&&(e.lastNeed=r-2),r;if(--n<a||-2===r)return 0;if(r=o(t[n]),r>=0)return r>0&&(2===r?r=0:e.lastNeed=r-3),r;return 0}(this,e,t);if(!this.lastNeed)return e.toString("utf8",t);this.lastTotal=a;var n=e.length-(a-this.lastNeed);return e.copy(this.lastChar,0,n),e.toString("utf8",t,n)},s.prototype.fillLast=function(e){if(this.lastNeed<=e.length)return e.copy(this.lastChar,this.lastTotal-this.lastNeed,0,this.lastNeed),this.lastChar.toString(this.encoding,0,this.lastTotal);e.copy(this.lastChar,this.lastTotal-this.lastNeed,0,e.length),this.lastNeed-=e.length}},4927:(e,t,a)=>{function n(e){try{if(!a.g.localStorage)return!1}catch(e){return!1}var t=a.g.localStorage[e];return null!=t&&"true"===String(t).toLowerCase()}e.exports=function(e,t){if(n("noDeprecation"))return e;var a=!1;return function(){if(!a){if(n("throwDeprecation"))throw new Error(t);n("traceDeprecation")?console.trace(t):console.warn(t),a=!0}return e.apply(this,arguments)}}},255:e=>{var t={"&":"&amp;",'"':"&quot;","'":"&apos;","<":"&lt;",">":"&gt;"};e.exports=function(e){return e&&e.replace?e.replace(/([&"<>'])/g,(function(e,a){return t[a]})):e}},3479:(e,t,a)=>{var n=a(4155),r=a(255),s=a(2830).Stream;function o(e,t,a){a=a||0;var n,s,l=(n=t,new Array(a||0).join(n||"")),i=e;if("object"==typeof e&&((i=e[s=Object.keys(e)[0]])&&i._elem))return i._elem.name=s,i._elem.icount=a,i._elem.indent=t,i._elem.indents=l,i._elem.interrupt=i,i._elem;var c,u=[],d=[];function p(e){Object.keys(e).forEach((function(t){u.push(function(e,t){return e+'="'+r(t)+'"'}(t,e[t]))}))}switch(typeof i){case"object":if(null===i)break;i._attr&&p(i._attr),i._cdata&&d.push(("<![CDATA["+i._cdata).replace(/\]\]>/g,"]]]]><![CDATA[>")+"]]>"),i.forEach&&(c=!1,d.push(""),i.forEach((function(e){"object"==typeof e?"_attr"==Object.keys(e)[0]?p(e._attr):d.push(o(e,t,a+1)):(d.pop(),c=!0,d.push(r(e)))})),c||d.push(""));break;default:d.push(r(i))}return{name:s,interrupt:!1,attributes:u,content:d,icount:a,indents:l,indent:t}}function l(e,t,a){if("object"!=typeof t)return e(!1,t);var n=t.interrupt?1:t.content.length;function r(){for(;t.content.length;){var r=t.content.shift();if(void 0!==r){if(s(r))return;l(e,r)}}e(!1,(n>1?t.indents:"")+(t.name?"</"+t.name+">":"")+(t.indent&&!a?"\n":"")),a&&a()}function s(t){return!!t.interrupt&&(t.interrupt.append=e,t.interrupt.end=r,t.interrupt=!1,e(!0),!0)}if(e(!1,t.indents+(t.name?"<"+t.name:"")+(t.attributes.length?" "+t.attributes.join(" "):"")+(n?t.name?">":"":t.name?"/>":"")+(t.indent&&n>1?"\n":"")),!n)return e(!1,t.indent?"\n":"");s(t)||r()}e.exports=function(e,t){"object"!=typeof t&&(t={indent:t});var a,r,i=t.stream?new s:null,c="",u=!1,d=t.indent?!0===t.indent?"    ":t.indent:"",p=!0;function m(e){p?n.nextTick(e):e()}function f(e,t){if(void 0!==t&&(c+=t),e&&!u&&(i=i||new s,u=!0),e&&u){var a=c;m((function(){i.emit("data",a)})),c=""}}function h(e,t){l(f,o(e,d,d?1:0),t)}function g(){if(i){var e=c;m((function(){i.emit("data",e),i.emit("end"),i.readable=!1,i.emit("close")}))}}return m((function(){p=!1})),t.declaration&&(a=t.declaration,r={version:"1.0",encoding:a.encoding||"UTF-8"},a.standalone&&(r.standalone=a.standalone),h({"?xml":{_attr:r}}),c=c.replace("/>","?>")),e&&e.forEach?e.forEach((function(t,a){var n;a+1===e.length&&(n=g),h(t,n)})):h(e,g),i?(i.readable=!0,i):c},e.exports.element=e.exports.Element=function(){var e={_elem:o(Array.prototype.slice.call(arguments)),push:function(e){if(!this.append)throw new Error("not assigned to a parent!");var t=this,a=this._elem.indent;l(this.append,o(e,a,this._elem.icount+(a?1:0)),(function(){t.append(!0)}))},close:function(e){void 0!==e&&this.push(e),this.end&&this.end()}};return e}},5102:(e,t,a)=>{var n={"./all.js":5308,"./auth/actions.js":5812,"./auth/components/lock-auth-icon.jsx":7105,"./auth/components/unlock-auth-icon.jsx":3219,"./auth/configs-extensions/wrap-actions.js":3779,"./auth/index.js":3705,"./auth/reducers.js":3962,"./auth/selectors.js":35,"./auth/spec-extensions/wrap-actions.js":489,"./auth/wrap-actions.js":2849,"./configs/actions.js":714,"./configs/helpers.js":2256,"./configs/index.js":6709,"./configs/reducers.js":7743,"./configs/selectors.js":9018,"./configs/spec-actions.js":2698,"./deep-linking/helpers.js":1970,"./deep-linking/index.js":4980,"./deep-linking/layout.js":5858,"./deep-linking/operation-tag-wrapper.jsx":4584,"./deep-linking/operation-wrapper.jsx":877,"./download-url.js":8011,"./err/actions.js":4966,"./err/error-transformers/hook.js":6808,"./err/error-transformers/transformers/not-of-type.js":2392,"./err/error-transformers/transformers/parameter-oneof.js":1835,"./err/index.js":7793,"./err/reducers.js":3527,"./err/selectors.js":7667,"./filter/index.js":9978,"./filter/opsFilter.js":4309,"./icons/components/arrow-down.jsx":6395,"./icons/components/arrow-up.jsx":9689,"./icons/components/arrow.jsx":6984,"./icons/components/close.jsx":2478,"./icons/components/copy.jsx":3388,"./icons/components/lock.jsx":6945,"./icons/components/unlock.jsx":2568,"./icons/index.js":70,"./json-schema-2020-12/components/Accordion/Accordion.jsx":7349,"./json-schema-2020-12/components/ExpandDeepButton/ExpandDeepButton.jsx":6867,"./json-schema-2020-12/components/JSONSchema/JSONSchema.jsx":2675,"./json-schema-2020-12/components/icons/ChevronRight.jsx":2260,"./json-schema-2020-12/components/keywords/$anchor.jsx":4922,"./json-schema-2020-12/components/keywords/$comment.jsx":4685,"./json-schema-2020-12/components/keywords/$defs.jsx":6418,"./json-schema-2020-12/components/keywords/$dynamicAnchor.jsx":1338,"./json-schema-2020-12/components/keywords/$dynamicRef.jsx":7655,"./json-schema-2020-12/components/keywords/$id.jsx":3460,"./json-schema-2020-12/components/keywords/$ref.jsx":2348,"./json-schema-2020-12/components/keywords/$schema.jsx":9359,"./json-schema-2020-12/components/keywords/$vocabulary/$vocabulary.jsx":7568,"./json-schema-2020-12/components/keywords/AdditionalProperties.jsx":5253,"./json-schema-2020-12/components/keywords/AllOf.jsx":6457,"./json-schema-2020-12/components/keywords/AnyOf.jsx":8776,"./json-schema-2020-12/components/keywords/Const.jsx":7308,"./json-schema-2020-12/components/keywords/Constraint/Constraint.jsx":9956,"./json-schema-2020-12/components/keywords/Contains.jsx":8993,"./json-schema-2020-12/components/keywords/ContentSchema.jsx":3484,"./json-schema-2020-12/components/keywords/Default.jsx":5148,"./json-schema-2020-12/components/keywords/DependentRequired/DependentRequired.jsx":4539,"./json-schema-2020-12/components/keywords/DependentSchemas.jsx":6076,"./json-schema-2020-12/components/keywords/Deprecated.jsx":6661,"./json-schema-2020-12/components/keywords/Description/Description.jsx":9446,"./json-schema-2020-12/components/keywords/Else.jsx":7207,"./json-schema-2020-12/components/keywords/Enum/Enum.jsx":1805,"./json-schema-2020-12/components/keywords/If.jsx":487,"./json-schema-2020-12/components/keywords/Items.jsx":9206,"./json-schema-2020-12/components/keywords/Not.jsx":5174,"./json-schema-2020-12/components/keywords/OneOf.jsx":3834,"./json-schema-2020-12/components/keywords/PatternProperties/PatternProperties.jsx":6746,"./json-schema-2020-12/components/keywords/PrefixItems.jsx":3971,"./json-schema-2020-12/components/keywords/Properties/Properties.jsx":5472,"./json-schema-2020-12/components/keywords/PropertyNames.jsx":2338,"./json-schema-2020-12/components/keywords/ReadOnly.jsx":6456,"./json-schema-2020-12/components/keywords/Then.jsx":7401,"./json-schema-2020-12/components/keywords/Title/Title.jsx":8137,"./json-schema-2020-12/components/keywords/Type.jsx":2285,"./json-schema-2020-12/components/keywords/UnevaluatedItems.jsx":5828,"./json-schema-2020-12/components/keywords/UnevaluatedProperties.jsx":6907,"./json-schema-2020-12/components/keywords/WriteOnly.jsx":5789,"./json-schema-2020-12/context.js":9006,"./json-schema-2020-12/fn.js":4121,"./json-schema-2020-12/hoc.jsx":5077,"./json-schema-2020-12/hooks.js":2603,"./json-schema-2020-12/index.js":7139,"./json-schema-2020-12/prop-types.js":6648,"./json-schema-2020-12/samples-extensions/fn/api/encoderAPI.js":9507,"./json-schema-2020-12/samples-extensions/fn/api/formatAPI.js":2906,"./json-schema-2020-12/samples-extensions/fn/api/mediaTypeAPI.js":537,"./json-schema-2020-12/samples-extensions/fn/class/EncoderRegistry.js":674,"./json-schema-2020-12/samples-extensions/fn/class/MediaTypeRegistry.js":3782,"./json-schema-2020-12/samples-extensions/fn/class/Registry.js":4215,"./json-schema-2020-12/samples-extensions/fn/core/constants.js":8338,"./json-schema-2020-12/samples-extensions/fn/core/example.js":3783,"./json-schema-2020-12/samples-extensions/fn/core/merge.js":7078,"./json-schema-2020-12/samples-extensions/fn/core/predicates.js":3084,"./json-schema-2020-12/samples-extensions/fn/core/random.js":5202,"./json-schema-2020-12/samples-extensions/fn/core/type.js":6276,"./json-schema-2020-12/samples-extensions/fn/core/utils.js":9346,"./json-schema-2020-12/samples-extensions/fn/encoders/7bit.js":1433,"./json-schema-2020-12/samples-extensions/fn/encoders/8bit.js":8509,"./json-schema-2020-12/samples-extensions/fn/encoders/base16.js":5709,"./json-schema-2020-12/samples-extensions/fn/encoders/base32.js":4180,"./json-schema-2020-12/samples-extensions/fn/encoders/base64.js":1967,"./json-schema-2020-12/samples-extensions/fn/encoders/binary.js":4366,"./json-schema-2020-12/samples-extensions/fn/encoders/quoted-printable.js":5037,"./json-schema-2020-12/samples-extensions/fn/generators/date-time.js":4045,"./json-schema-2020-12/samples-extensions/fn/generators/date.js":1456,"./json-schema-2020-12/samples-extensions/fn/generators/double.js":560,"./json-schema-2020-12/samples-extensions/fn/generators/duration.js":4299,"./json-schema-2020-12/samples-extensions/fn/generators/email.js":3981,"./json-schema-2020-12/samples-extensions/fn/generators/float.js":1890,"./json-schema-2020-12/samples-extensions/fn/generators/hostname.js":9375,"./json-schema-2020-12/samples-extensions/fn/generators/idn-email.js":4518,"./json-schema-2020-12/samples-extensions/fn/generators/idn-hostname.js":273,"./json-schema-2020-12/samples-extensions/fn/generators/int32.js":7864,"./json-schema-2020-12/samples-extensions/fn/generators/int64.js":1726,"./json-schema-2020-12/samples-extensions/fn/generators/ipv4.js":8793,"./json-schema-2020-12/samples-extensions/fn/generators/ipv6.js":8269,"./json-schema-2020-12/samples-extensions/fn/generators/iri-reference.js":5693,"./json-schema-2020-12/samples-extensions/fn/generators/iri.js":3080,"./json-schema-2020-12/samples-extensions/fn/generators/json-pointer.js":7856,"./json-schema-2020-12/samples-extensions/fn/generators/media-types/application.js":5652,"./json-schema-2020-12/samples-extensions/fn/generators/media-types/audio.js":4342,"./json-schema-2020-12/samples-extensions/fn/generators/media-types/image.js":6724,"./json-schema-2020-12/samples-extensions/fn/generators/media-types/text.js":5378,"./json-schema-2020-12/samples-extensions/fn/generators/media-types/video.js":2974,"./json-schema-2020-12/samples-extensions/fn/generators/password.js":3393,"./json-schema-2020-12/samples-extensions/fn/generators/regex.js":4335,"./json-schema-2020-12/samples-extensions/fn/generators/relative-json-pointer.js":375,"./json-schema-2020-12/samples-extensions/fn/generators/time.js":5243,"./json-schema-2020-12/samples-extensions/fn/generators/uri-reference.js":4692,"./json-schema-2020-12/samples-extensions/fn/generators/uri-template.js":3829,"./json-schema-2020-12/samples-extensions/fn/generators/uri.js":2978,"./json-schema-2020-12/samples-extensions/fn/generators/uuid.js":8859,"./json-schema-2020-12/samples-extensions/fn/index.js":8591,"./json-schema-2020-12/samples-extensions/fn/main.js":4277,"./json-schema-2020-12/samples-extensions/fn/types/array.js":8262,"./json-schema-2020-12/samples-extensions/fn/types/boolean.js":4108,"./json-schema-2020-12/samples-extensions/fn/types/index.js":3273,"./json-schema-2020-12/samples-extensions/fn/types/integer.js":8864,"./json-schema-2020-12/samples-extensions/fn/types/null.js":853,"./json-schema-2020-12/samples-extensions/fn/types/number.js":7742,"./json-schema-2020-12/samples-extensions/fn/types/object.js":6852,"./json-schema-2020-12/samples-extensions/fn/types/string.js":4522,"./layout/actions.js":5474,"./layout/index.js":6821,"./layout/reducers.js":5672,"./layout/selectors.js":4400,"./layout/spec-extensions/wrap-selector.js":8989,"./logs/index.js":9150,"./oas3/actions.js":7002,"./oas3/auth-extensions/wrap-selectors.js":3723,"./oas3/components/callbacks.jsx":3427,"./oas3/components/http-auth.jsx":6775,"./oas3/components/index.js":6467,"./oas3/components/operation-link.jsx":5757,"./oas3/components/operation-servers.jsx":6796,"./oas3/components/request-body-editor.jsx":5327,"./oas3/components/request-body.jsx":2458,"./oas3/components/servers-container.jsx":9928,"./oas3/components/servers.jsx":6617,"./oas3/helpers.jsx":7779,"./oas3/index.js":7451,"./oas3/reducers.js":2109,"./oas3/selectors.js":5065,"./oas3/spec-extensions/selectors.js":1741,"./oas3/spec-extensions/wrap-selectors.js":2044,"./oas3/wrap-components/auth-item.jsx":356,"./oas3/wrap-components/index.js":7761,"./oas3/wrap-components/json-schema-string.jsx":287,"./oas3/wrap-components/markdown.jsx":2460,"./oas3/wrap-components/model.jsx":3499,"./oas3/wrap-components/online-validator-badge.js":58,"./oas3/wrap-components/version-stamp.jsx":9487,"./oas31/after-load.js":7754,"./oas31/components/contact.jsx":9503,"./oas31/components/info.jsx":6133,"./oas31/components/json-schema-dialect.jsx":2562,"./oas31/components/license.jsx":1876,"./oas31/components/model/model.jsx":2718,"./oas31/components/models/models.jsx":263,"./oas31/components/version-pragma-filter.jsx":3429,"./oas31/components/webhooks.jsx":9508,"./oas31/fn.js":4380,"./oas31/index.js":9806,"./oas31/json-schema-2020-12-extensions/components/keywords/Description.jsx":5989,"./oas31/json-schema-2020-12-extensions/components/keywords/Discriminator/Discriminator.jsx":9525,"./oas31/json-schema-2020-12-extensions/components/keywords/Discriminator/DiscriminatorMapping.jsx":7749,"./oas31/json-schema-2020-12-extensions/components/keywords/Example.jsx":9450,"./oas31/json-schema-2020-12-extensions/components/keywords/ExternalDocs.jsx":5324,"./oas31/json-schema-2020-12-extensions/components/keywords/Properties.jsx":9023,"./oas31/json-schema-2020-12-extensions/components/keywords/Xml.jsx":3995,"./oas31/json-schema-2020-12-extensions/fn.js":5800,"./oas31/json-schema-2020-12-extensions/wrap-components/keywords/Default.jsx":4951,"./oas31/json-schema-2020-12-extensions/wrap-components/keywords/Description.jsx":809,"./oas31/json-schema-2020-12-extensions/wrap-components/keywords/Properties.jsx":7536,"./oas31/selectors.js":4280,"./oas31/spec-extensions/selectors.js":9305,"./oas31/spec-extensions/wrap-selectors.js":2884,"./oas31/wrap-components/contact.jsx":7423,"./oas31/wrap-components/info.jsx":284,"./oas31/wrap-components/license.jsx":6608,"./oas31/wrap-components/model.jsx":7042,"./oas31/wrap-components/models.jsx":2914,"./oas31/wrap-components/version-pragma-filter.jsx":1434,"./oas31/wrap-components/version-stamp.jsx":1122,"./on-complete/index.js":8560,"./request-snippets/fn.js":4624,"./request-snippets/index.js":6575,"./request-snippets/request-snippets.jsx":4206,"./request-snippets/selectors.js":4669,"./safe-render/components/error-boundary.jsx":6195,"./safe-render/components/fallback.jsx":9403,"./safe-render/fn.jsx":6189,"./safe-render/index.js":8102,"./samples/fn/get-json-sample-schema.js":2846,"./samples/fn/get-sample-schema.js":6132,"./samples/fn/get-xml-sample-schema.js":1169,"./samples/fn/get-yaml-sample-schema.js":9431,"./samples/fn/index.js":9812,"./samples/index.js":8883,"./spec/actions.js":7612,"./spec/index.js":7038,"./spec/reducers.js":32,"./spec/selectors.js":3881,"./spec/wrap-actions.js":7508,"./swagger-js/configs-wrap-actions.js":4852,"./swagger-js/index.js":9430,"./util/index.js":8525,"./view/fn.js":8347,"./view/index.js":3420,"./view/root-injects.jsx":1548,"core/plugins/all.js":5308,"core/plugins/auth/actions.js":5812,"core/plugins/auth/components/lock-auth-icon.jsx":7105,"core/plugins/auth/components/unlock-auth-icon.jsx":3219,"core/plugins/auth/configs-extensions/wrap-actions.js":3779,"core/plugins/auth/index.js":3705,"core/plugins/auth/reducers.js":3962,"core/plugins/auth/selectors.js":35,"core/plugins/auth/spec-extensions/wrap-actions.js":489,"core/plugins/auth/wrap-actions.js":2849,"core/plugins/configs/actions.js":714,"core/plugins/configs/helpers.js":2256,"core/plugins/configs/index.js":6709,"core/plugins/configs/reducers.js":7743,"core/plugins/configs/selectors.js":9018,"core/plugins/configs/spec-actions.js":2698,"core/plugins/deep-linking/helpers.js":1970,"core/plugins/deep-linking/index.js":4980,"core/plugins/deep-linking/layout.js":5858,"core/plugins/deep-linking/operation-tag-wrapper.jsx":4584,"core/plugins/deep-linking/operation-wrapper.jsx":877,"core/plugins/download-url.js":8011,"core/plugins/err/actions.js":4966,"core/plugins/err/error-transformers/hook.js":6808,"core/plugins/err/error-transformers/transformers/not-of-type.js":2392,"core/plugins/err/error-transformers/transformers/parameter-oneof.js":1835,"core/plugins/err/index.js":7793,"core/plugins/err/reducers.js":3527,"core/plugins/err/selectors.js":7667,"core/plugins/filter/index.js":9978,"core/plugins/filter/opsFilter.js":4309,"core/plugins/icons/components/arrow-down.jsx":6395,"core/plugins/icons/components/arrow-up.jsx":9689,"core/plugins/icons/components/arrow.jsx":6984,"core/plugins/icons/components/close.jsx":2478,"core/plugins/icons/components/copy.jsx":3388,"core/plugins/icons/components/lock.jsx":6945,"core/plugins/icons/components/unlock.jsx":2568,"core/plugins/icons/index.js":70,"core/plugins/json-schema-2020-12/components/Accordion/Accordion.jsx":7349,"core/plugins/json-schema-2020-12/components/ExpandDeepButton/ExpandDeepButton.jsx":6867,"core/plugins/json-schema-2020-12/components/JSONSchema/JSONSchema.jsx":2675,"core/plugins/json-schema-2020-12/components/icons/ChevronRight.jsx":2260,"core/plugins/json-schema-2020-12/components/keywords/$anchor.jsx":4922,"core/plugins/json-schema-2020-12/components/keywords/$comment.jsx":4685,"core/plugins/json-schema-2020-12/components/keywords/$defs.jsx":6418,"core/plugins/json-schema-2020-12/components/keywords/$dynamicAnchor.jsx":1338,"core/plugins/json-schema-2020-12/components/keywords/$dynamicRef.jsx":7655,"core/plugins/json-schema-2020-12/components/keywords/$id.jsx":3460,"core/plugins/json-schema-2020-12/components/keywords/$ref.jsx":2348,"core/plugins/json-schema-2020-12/components/keywords/$schema.jsx":9359,"core/plugins/json-schema-2020-12/components/keywords/$vocabulary/$vocabulary.jsx":7568,"core/plugins/json-schema-2020-12/components/keywords/AdditionalProperties.jsx":5253,"core/plugins/json-schema-2020-12/components/keywords/AllOf.jsx":6457,"core/plugins/json-schema-2020-12/components/keywords/AnyOf.jsx":8776,"core/plugins/json-schema-2020-12/components/keywords/Const.jsx":7308,"core/plugins/json-schema-2020-12/components/keywords/Constraint/Constraint.jsx":9956,"core/plugins/json-schema-2020-12/components/keywords/Contains.jsx":8993,"core/plugins/json-schema-2020-12/components/keywords/ContentSchema.jsx":3484,"core/plugins/json-schema-2020-12/components/keywords/Default.jsx":5148,"core/plugins/json-schema-2020-12/components/keywords/DependentRequired/DependentRequired.jsx":4539,"core/plugins/json-schema-2020-12/components/keywords/DependentSchemas.jsx":6076,"core/plugins/json-schema-2020-12/components/keywords/Deprecated.jsx":6661,"core/plugins/json-schema-2020-12/components/keywords/Description/Description.jsx":9446,"core/plugins/json-schema-2020-12/components/keywords/Else.jsx":7207,"core/plugins/json-schema-2020-12/components/keywords/Enum/Enum.jsx":1805,"core/plugins/json-schema-2020-12/components/keywords/If.jsx":487,"core/plugins/json-schema-2020-12/components/keywords/Items.jsx":9206,"core/plugins/json-schema-2020-12/components/keywords/Not.jsx":5174,"core/plugins/json-schema-2020-12/components/keywords/OneOf.jsx":3834,"core/plugins/json-schema-2020-12/components/keywords/PatternProperties/PatternProperties.jsx":6746,"core/plugins/json-schema-2020-12/components/keywords/PrefixItems.jsx":3971,"core/plugins/json-schema-2020-12/components/keywords/Properties/Properties.jsx":5472,"core/plugins/json-schema-2020-12/components/keywords/PropertyNames.jsx":2338,"core/plugins/json-schema-2020-12/components/keywords/ReadOnly.jsx":6456,"core/plugins/json-schema-2020-12/components/keywords/Then.jsx":7401,"core/plugins/json-schema-2020-12/components/keywords/Title/Title.jsx":8137,"core/plugins/json-schema-2020-12/components/keywords/Type.jsx":2285,"core/plugins/json-schema-2020-12/components/keywords/UnevaluatedItems.jsx":5828,"core/plugins/json-schema-2020-12/components/keywords/UnevaluatedProperties.jsx":6907,"core/plugins/json-schema-2020-12/components/keywords/WriteOnly.jsx":5789,"core/plugins/json-schema-2020-12/context.js":9006,"core/plugins/json-schema-2020-12/fn.js":4121,"core/plugins/json-schema-2020-12/hoc.jsx":5077,"core/plugins/json-schema-2020-12/hooks.js":2603,"core/plugins/json-schema-2020-12/index.js":7139,"core/plugins/json-schema-2020-12/prop-types.js":6648,"core/plugins/json-schema-2020-12/samples-extensions/fn/api/encoderAPI.js":9507,"core/plugins/json-schema-2020-12/samples-extensions/fn/api/formatAPI.js":2906,"core/plugins/json-schema-2020-12/samples-extensions/fn/api/mediaTypeAPI.js":537,"core/plugins/json-schema-2020-12/samples-extensions/fn/class/EncoderRegistry.js":674,"core/plugins/json-schema-2020-12/samples-extensions/fn/class/MediaTypeRegistry.js":3782,"core/plugins/json-schema-2020-12/samples-extensions/fn/class/Registry.js":4215,"core/plugins/json-schema-2020-12/samples-extensions/fn/core/constants.js":8338,"core/plugins/json-schema-2020-12/samples-extensions/fn/core/example.js":3783,"core/plugins/json-schema-2020-12/samples-extensions/fn/core/merge.js":7078,"core/plugins/json-schema-2020-12/samples-extensions/fn/core/predicates.js":3084,"core/plugins/json-schema-2020-12/samples-extensions/fn/core/random.js":5202,"core/plugins/json-schema-2020-12/samples-extensions/fn/core/type.js":6276,"core/plugins/json-schema-2020-12/samples-extensions/fn/core/utils.js":9346,"core/plugins/json-schema-2020-12/samples-extensions/fn/encoders/7bit.js":1433,"core/plugins/json-schema-2020-12/samples-extensions/fn/encoders/8bit.js":8509,"core/plugins/json-schema-2020-12/samples-extensions/fn/encoders/base16.js":5709,"core/plugins/json-schema-2020-12/samples-extensions/fn/encoders/base32.js":4180,"core/plugins/json-schema-2020-12/samples-extensions/fn/encoders/base64.js":1967,"core/plugins/json-schema-2020-12/samples-extensions/fn/encoders/binary.js":4366,"core/plugins/json-schema-2020-12/samples-extensions/fn/encoders/quoted-printable.js":5037,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/date-time.js":4045,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/date.js":1456,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/double.js":560,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/duration.js":4299,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/email.js":3981,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/float.js":1890,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/hostname.js":9375,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/idn-email.js":4518,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/idn-hostname.js":273,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/int32.js":7864,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/int64.js":1726,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/ipv4.js":8793,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/ipv6.js":8269,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/iri-reference.js":5693,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/iri.js":3080,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/json-pointer.js":7856,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/media-types/application.js":5652,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/media-types/audio.js":4342,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/media-types/image.js":6724,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/media-types/text.js":5378,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/media-types/video.js":2974,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/password.js":3393,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/regex.js":4335,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/relative-json-pointer.js":375,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/time.js":5243,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/uri-reference.js":4692,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/uri-template.js":3829,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/uri.js":2978,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/uuid.js":8859,"core/plugins/json-schema-2020-12/samples-extensions/fn/index.js":8591,"core/plugins/json-schema-2020-12/samples-extensions/fn/main.js":4277,"core/plugins/json-schema-2020-12/samples-extensions/fn/types/array.js":8262,"core/plugins/json-schema-2020-12/samples-extensions/fn/types/boolean.js":4108,"core/plugins/json-schema-2020-12/samples-extensions/fn/types/index.js":3273,"core/plugins/json-schema-2020-12/samples-extensions/fn/types/integer.js":8864,"core/plugins/json-schema-2020-12/samples-extensions/fn/types/null.js":853,"core/plugins/json-schema-2020-12/samples-extensions/fn/types/number.js":7742,"core/plugins/json-schema-2020-12/samples-extensions/fn/types/object.js":6852,"core/plugins/json-schema-2020-12/samples-extensions/fn/types/string.js":4522,"core/plugins/layout/actions.js":5474,"core/plugins/layout/index.js":6821,"core/plugins/layout/reducers.js":5672,"core/plugins/layout/selectors.js":4400,"core/plugins/layout/spec-extensions/wrap-selector.js":8989,"core/plugins/logs/index.js":9150,"core/plugins/oas3/actions.js":7002,"core/plugins/oas3/auth-extensions/wrap-selectors.js":3723,"core/plugins/oas3/components/callbacks.jsx":3427,"core/plugins/oas3/components/http-auth.jsx":6775,"core/plugins/oas3/components/index.js":6467,"core/plugins/oas3/components/operation-link.jsx":5757,"core/plugins/oas3/components/operation-servers.jsx":6796,"core/plugins/oas3/components/request-body-editor.jsx":5327,"core/plugins/oas3/components/request-body.jsx":2458,"core/plugins/oas3/components/servers-container.jsx":9928,"core/plugins/oas3/components/servers.jsx":6617,"core/plugins/oas3/helpers.jsx":7779,"core/plugins/oas3/index.js":7451,"core/plugins/oas3/reducers.js":2109,"core/plugins/oas3/selectors.js":5065,"core/plugins/oas3/spec-extensions/selectors.js":1741,"core/plugins/oas3/spec-extensions/wrap-selectors.js":2044,"core/plugins/oas3/wrap-components/auth-item.jsx":356,"core/plugins/oas3/wrap-components/index.js":7761,"core/plugins/oas3/wrap-components/json-schema-string.jsx":287,"core/plugins/oas3/wrap-components/markdown.jsx":2460,"core/plugins/oas3/wrap-components/model.jsx":3499,"core/plugins/oas3/wrap-components/online-validator-badge.js":58,"core/plugins/oas3/wrap-components/version-stamp.jsx":9487,"core/plugins/oas31/after-load.js":7754,"core/plugins/oas31/components/contact.jsx":9503,"core/plugins/oas31/components/info.jsx":6133,"core/plugins/oas31/components/json-schema-dialect.jsx":2562,"core/plugins/oas31/components/license.jsx":1876,"core/plugins/oas31/components/model/model.jsx":2718,"core/plugins/oas31/components/models/models.jsx":263,"core/plugins/oas31/components/version-pragma-filter.jsx":3429,"core/plugins/oas31/components/webhooks.jsx":9508,"core/plugins/oas31/fn.js":4380,"core/plugins/oas31/index.js":9806,"core/plugins/oas31/json-schema-2020-12-extensions/components/keywords/Description.jsx":5989,"core/plugins/oas31/json-schema-2020-12-extensions/components/keywords/Discriminator/Discriminator.jsx":9525,"core/plugins/oas31/json-schema-2020-12-extensions/components/keywords/Discriminator/DiscriminatorMapping.jsx":7749,"core/plugins/oas31/json-schema-2020-12-extensions/components/keywords/Example.jsx":9450,"core/plugins/oas31/json-schema-2020-12-extensions/components/keywords/ExternalDocs.jsx":5324,"core/plugins/oas31/json-schema-2020-12-extensions/components/keywords/Properties.jsx":9023,"core/plugins/oas31/json-schema-2020-12-extensions/components/keywords/Xml.jsx":3995,"core/plugins/oas31/json-schema-2020-12-extensions/fn.js":5800,"core/plugins/oas31/json-schema-2020-12-extensions/wrap-components/keywords/Default.jsx":4951,"core/plugins/oas31/json-schema-2020-12-extensions/wrap-components/keywords/Description.jsx":809,"core/plugins/oas31/json-schema-2020-12-extensions/wrap-components/keywords/Properties.jsx":7536,"core/plugins/oas31/selectors.js":4280,"core/plugins/oas31/spec-extensions/selectors.js":9305,"core/plugins/oas31/spec-extensions/wrap-selectors.js":2884,"core/plugins/oas31/wrap-components/contact.jsx":7423,"core/plugins/oas31/wrap-components/info.jsx":284,"core/plugins/oas31/wrap-components/license.jsx":6608,"core/plugins/oas31/wrap-components/model.jsx":7042,"core/plugins/oas31/wrap-components/models.jsx":2914,"core/plugins/oas31/wrap-components/version-pragma-filter.jsx":1434,"core/plugins/oas31/wrap-components/version-stamp.jsx":1122,"core/plugins/on-complete/index.js":8560,"core/plugins/request-snippets/fn.js":4624,"core/plugins/request-snippets/index.js":6575,"core/plugins/request-snippets/request-snippets.jsx":4206,"core/plugins/request-snippets/selectors.js":4669,"core/plugins/safe-render/components/error-boundary.jsx":6195,"core/plugins/safe-render/components/fallback.jsx":9403,"core/plugins/safe-render/fn.jsx":6189,"core/plugins/safe-render/index.js":8102,"core/plugins/samples/fn/get-json-sample-schema.js":2846,"core/plugins/samples/fn/get-sample-schema.js":6132,"core/plugins/samples/fn/get-xml-sample-schema.js":1169,"core/plugins/samples/fn/get-yaml-sample-schema.js":9431,"core/plugins/samples/fn/index.js":9812,"core/plugins/samples/index.js":8883,"core/plugins/spec/actions.js":7612,"core/plugins/spec/index.js":7038,"core/plugins/spec/reducers.js":32,"core/plugins/spec/selectors.js":3881,"core/plugins/spec/wrap-actions.js":7508,"core/plugins/swagger-js/configs-wrap-actions.js":4852,"core/plugins/swagger-js/index.js":9430,"core/plugins/util/index.js":8525,"core/plugins/view/fn.js":8347,"core/plugins/view/index.js":3420,"core/plugins/view/root-injects.jsx":1548};function r(e){var t=s(e);return a(t)}function s(e){if(!a.o(n,e)){var t=new Error("Cannot find module '"+e+"'");throw t.code="MODULE_NOT_FOUND",t}return n[e]}r.keys=function(){return Object.keys(n)},r.resolve=s,e.exports=r,r.id=5102},2517:e=>{e.exports="data:image/svg+xml;base64,PHN2ZyB3aWR0aD0iMjAwcHgiICBoZWlnaHQ9IjIwMHB4IiAgeG1sbnM9Imh0dHA6Ly93d3cudzMub3JnLzIwMDAvc3ZnIiB2aWV3Qm94PSIwIDAgMTAwIDEwMCIgcHJlc2VydmVBc3BlY3RSYXRpbz0ieE1pZFlNaWQiIGNsYXNzPSJsZHMtcm9sbGluZyIgc3R5bGU9ImJhY2tncm91bmQtaW1hZ2U6IG5vbmU7IGJhY2tncm91bmQtcG9zaXRpb246IGluaXRpYWwgaW5pdGlhbDsgYmFja2dyb3VuZC1yZXBlYXQ6IGluaXRpYWwgaW5pdGlhbDsiPjxjaXJjbGUgY3g9IjUwIiBjeT0iNTAiIGZpbGw9Im5vbmUiIG5nLWF0dHItc3Ryb2tlPSJ7e2NvbmZpZy5jb2xvcn19IiBuZy1hdHRyLXN0cm9rZS13aWR0aD0ie3tjb25maWcud2lkdGh9fSIgbmctYXR0ci1yPSJ7e2NvbmZpZy5yYWRpdXN9fSIgbmctYXR0ci1zdHJva2UtZGFzaGFycmF5PSJ7e2NvbmZpZy5kYXNoYXJyYXl9fSIgc3Ryb2tlPSIjNTU1NTU1IiBzdHJva2Utd2lkdGg9IjEwIiByPSIzNSIgc3Ryb2tlLWRhc2hhcnJheT0iMTY0LjkzMzYxNDMxMzQ2NDE1IDU2Ljk3Nzg3MTQzNzgyMTM4Ij48YW5pbWF0ZVRyYW5zZm9ybSBhdHRyaWJ1dGVOYW1lPSJ0cmFuc2Zvcm0iIHR5cGU9InJvdGF0ZSIgY2FsY01vZGU9ImxpbmVhciIgdmFsdWVzPSIwIDUwIDUwOzM2MCA1MCA1MCIga2V5VGltZXM9IjA7MSIgZHVyPSIxcyIgYmVnaW49IjBzIiByZXBlYXRDb3VudD0iaW5kZWZpbml0ZSI+PC9hbmltYXRlVHJhbnNmb3JtPjwvY2lyY2xlPjwvc3ZnPgo="},8898:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>H.default})},4163:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>G.default})},5527:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>Z.default})},5171:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>Y.default})},2954:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>X.default})},7930:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>Q.default})},6145:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>ee.default})},1778:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>te.default})},29:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>ae.default})},2372:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>ne.default})},8818:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>re.default})},5487:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>se.default})},2565:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>oe.default})},6785:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>le.default})},8136:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>ie.default})},9963:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>ce.default})},4350:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>ue.default})},5942:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>de.default})},313:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>pe.default})},6914:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>me.default})},1772:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>fe.default})},7512:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>he.default})},7204:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>ge.default})},7415:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>ye.default})},2740:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>ve.default})},374:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>Ee.default})},527:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>Se.default})},6235:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>we.default})},3769:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>be.default})},9651:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>xe.default})},3284:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>_e.default})},5496:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>Ce.default})},775:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>je.default})},863:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>Ne.default})},4780:e=>{e.exports=Oe},8096:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>ke.default})},3294:e=>{e.exports=Ae},9725:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({List:()=>Ie.List,Map:()=>Ie.Map,OrderedMap:()=>Ie.OrderedMap,Seq:()=>Ie.Seq,Set:()=>Ie.Set,default:()=>Ie.default,fromJS:()=>Ie.fromJS})},3772:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({JSON_SCHEMA:()=>Re.JSON_SCHEMA,default:()=>Re.default})},9908:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>Pe.default})},1252:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>Te.default})},8747:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>Me.default})},7068:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>De.default})},8646:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>Le.default})},5476:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>qe.default})},1116:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>Be.default})},841:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>Ue.default})},5053:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>Je.default})},2092:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>$e.default})},810:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({Component:()=>Ve.Component,PureComponent:()=>Ve.PureComponent,createContext:()=>Ve.createContext,default:()=>Ve.default,forwardRef:()=>Ve.forwardRef,useCallback:()=>Ve.useCallback,useContext:()=>Ve.useContext,useEffect:()=>Ve.useEffect,useRef:()=>Ve.useRef,useState:()=>Ve.useState})},9874:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({CopyToClipboard:()=>Ke.CopyToClipboard})},9569:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>Fe.default})},9871:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({applyMiddleware:()=>ze.applyMiddleware,bindActionCreators:()=>ze.bindActionCreators,compose:()=>ze.compose,createStore:()=>ze.createStore})},3952:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({Remarkable:()=>We.Remarkable})},8639:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({createSelector:()=>He.createSelector})},8518:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({serializeError:()=>Ge.serializeError})},5013:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({opId:()=>Ze.opId})},8900:(e,t,a)=>{e.exports=(e=>{var t={};return a.d(t,e),t})({default:()=>Ye.default})},2361:()=>{},4616:()=>{},6718:(e,t,a)=>{e.exports=a(1910)}},ct={};function ut(e){var t=ct[e];if(void 0!==t)return t.exports;var a=ct[e]={exports:{}};return it[e].call(a.exports,a,a.exports,ut),a.exports}ut.n=e=>{var t=e&&e.__esModule?()=>e.default:()=>e;return ut.d(t,{a:t}),t},ut.d=(e,t)=>{for(var a in t)ut.o(t,a)&&!ut.o(e,a)&&Object.defineProperty(e,a,{enumerable:!0,get:t[a]})},ut.g=function(){if("object"==typeof globalThis)return globalThis;try{return this||new Function("return this")()}catch(e){if("object"==typeof window)return window}}(),ut.o=(e,t)=>Object.prototype.hasOwnProperty.call(e,t),ut.r=e=>{"undefined"!=typeof Symbol&&Symbol.toStringTag&&Object.defineProperty(e,Symbol.toStringTag,{value:"Module"}),Object.defineProperty(e,"__esModule",{value:!0})};var dt={};(()=>{ut.d(dt,{Z:()=>Ha});var e={};ut.r(e),ut.d(e,{Button:()=>It,Col:()=>kt,Collapse:()=>Lt,Container:()=>Nt,Input:()=>Pt,Link:()=>Mt,Row:()=>At,Select:()=>Tt,TextArea:()=>Rt});var t={};ut.r(t),ut.d(t,{JsonSchemaArrayItemFile:()=>Ra,JsonSchemaArrayItemText:()=>Ia,JsonSchemaForm:()=>Oa,JsonSchema_array:()=>Aa,JsonSchema_boolean:()=>Pa,JsonSchema_object:()=>Ma,JsonSchema_string:()=>ka});const a=(e=>{var t={};return ut.d(t,e),t})({default:()=>Xe.default});var n=ut(6145),r=ut(2740),s=ut(313),o=ut(7698),l=ut.n(o),i=ut(5527),c=ut(7512),u=ut(8136),d=ut(4163),p=ut(6785),m=ut(2565),f=ut(5171),h=ut(810),g=ut(9871),y=ut(9725);const v=(e=>{var t={};return ut.d(t,e),t})({combineReducers:()=>Qe.combineReducers});var E=ut(8518);const S=(e=>{var t={};return ut.d(t,e),t})({default:()=>et.default});var w=ut(4966),b=ut(7504),x=ut(6561);const _=e=>e;class C{constructor(){var e;let t=arguments.length>0&&void 0!==arguments[0]?arguments[0]:{};var a,n,r;l()(this,{state:{},plugins:[],pluginsOptions:{},system:{configs:{},fn:{},components:{},rootInjects:{},statePlugins:{}},boundSystem:{},toolbox:{}},t),this.getSystem=(0,i.default)(e=this._getSystem).call(e,this),this.store=(a=_,n=(0,y.fromJS)(this.state),r=this.getSystem,function(e,t,a){let n=[(0,x._5)(a)];const r=b.Z.__REDUX_DEVTOOLS_EXTENSION_COMPOSE__||g.compose;return(0,g.createStore)(e,t,r((0,g.applyMiddleware)(...n)))}(a,n,r)),this.buildSystem(!1),this.register(this.plugins)}getStore(){return this.store}register(e){let t=!(arguments.length>1&&void 0!==arguments[1])||arguments[1];var a=j(e,this.getSystem(),this.pluginsOptions);O(this.system,a),t&&this.buildSystem();N.call(this.system,e,this.getSystem())&&this.buildSystem()}buildSystem(){let e=!(arguments.length>0&&void 0!==arguments[0])||arguments[0],t=this.getStore().dispatch,a=this.getStore().getState;this.boundSystem=(0,c.default)({},this.getRootInjects(),this.getWrappedAndBoundActions(t),this.getWrappedAndBoundSelectors(a,this.getSystem),this.getStateThunks(a),this.getFn(),this.getConfigs()),e&&this.rebuildReducer()}_getSystem(){return this.boundSystem}getRootInjects(){var e,t,a;return(0,c.default)({getSystem:this.getSystem,getStore:(0,i.default)(e=this.getStore).call(e,this),getComponents:(0,i.default)(t=this.getComponents).call(t,this),getState:this.getStore().getState,getConfigs:(0,i.default)(a=this._getConfigs).call(a,this),Im:y.default,React:h.default},this.system.rootInjects||{})}_getConfigs(){return this.system.configs}getConfigs(){return{configs:this.system.configs}}setConfigs(e){this.system.configs=e}rebuildReducer(){var e;this.store.replaceReducer((e=this.system.statePlugins,function(e){var t;let a=(0,p.default)(t=(0,r.default)(e)).call(t,((t,a)=>(t[a]=function(e){return function(){let t=arguments.length>0&&void 0!==arguments[0]?arguments[0]:new y.Map,a=arguments.length>1?arguments[1]:void 0;if(!e)return t;let n=e[a.type];if(n){const e=k(n)(t,a);return null===e?t:e}return t}}(e[a]),t)),{});return(0,r.default)(a).length?(0,v.combineReducers)(a):_}((0,x.Ay)(e,(e=>e.reducers)))))}getType(e){let t=e[0].toUpperCase()+(0,u.default)(e).call(e,1);return(0,x.Q2)(this.system.statePlugins,((a,n)=>{let r=a[e];if(r)return{[n+t]:r}}))}getSelectors(){return this.getType("selectors")}getActions(){let e=this.getType("actions");return(0,x.Ay)(e,(e=>(0,x.Q2)(e,((e,t)=>{if((0,x.LQ)(e))return{[t]:e}}))))}getWrappedAndBoundActions(e){var t=this;let a=this.getBoundActions(e);return(0,x.Ay)(a,((e,a)=>{let n=this.system.statePlugins[(0,u.default)(a).call(a,0,-7)].wrapActions;return n?(0,x.Ay)(e,((e,a)=>{let r=n[a];return r?((0,d.default)(r)||(r=[r]),(0,p.default)(r).call(r,((e,a)=>{let n=function(){return a(e,t.getSystem())(...arguments)};if(!(0,x.LQ)(n))throw new TypeError("wrapActions needs to return a function that returns a new function (ie the wrapped action)");return k(n)}),e||Function.prototype)):e})):e}))}getWrappedAndBoundSelectors(e,t){var a=this;let n=this.getBoundSelectors(e,t);return(0,x.Ay)(n,((t,n)=>{let r=[(0,u.default)(n).call(n,0,-9)],s=this.system.statePlugins[r].wrapSelectors;return s?(0,x.Ay)(t,((t,n)=>{let o=s[n];return o?((0,d.default)(o)||(o=[o]),(0,p.default)(o).call(o,((t,n)=>{let s=function(){for(var s=arguments.length,o=new Array(s),l=0;l<s;l++)o[l]=arguments[l];return n(t,a.getSystem())(e().getIn(r),...o)};if(!(0,x.LQ)(s))throw new TypeError("wrapSelector needs to return a function that returns a new function (ie the wrapped action)");return s}),t||Function.prototype)):t})):t}))}getStates(e){var t;return(0,p.default)(t=(0,r.default)(this.system.statePlugins)).call(t,((t,a)=>(t[a]=e.get(a),t)),{})}getStateThunks(e){var t;return(0,p.default)(t=(0,r.default)(this.system.statePlugins)).call(t,((t,a)=>(t[a]=()=>e().get(a),t)),{})}getFn(){return{fn:this.system.fn}}getComponents(e){const t=this.system.components[e];return(0,d.default)(t)?(0,p.default)(t).call(t,((e,t)=>t(e,this.getSystem()))):void 0!==e?this.system.components[e]:this.system.components}getBoundSelectors(e,t){return(0,x.Ay)(this.getSelectors(),((a,n)=>{let r=[(0,u.default)(n).call(n,0,-9)];return(0,x.Ay)(a,(a=>function(){for(var n=arguments.length,s=new Array(n),o=0;o<n;o++)s[o]=arguments[o];let l=k(a).apply(null,[e().getIn(r),...s]);return"function"==typeof l&&(l=k(l)(t())),l}))}))}getBoundActions(e){e=e||this.getStore().dispatch;const t=this.getActions(),a=e=>"function"!=typeof e?(0,x.Ay)(e,(e=>a(e))):function(){var t=null;try{t=e(...arguments)}catch(e){t={type:w.NEW_THROWN_ERR,error:!0,payload:(0,E.serializeError)(e)}}finally{return t}};return(0,x.Ay)(t,(t=>(0,g.bindActionCreators)(a(t),e)))}getMapStateToProps(){return()=>(0,c.default)({},this.getSystem())}getMapDispatchToProps(e){return t=>l()({},this.getWrappedAndBoundActions(t),this.getFn(),e)}}function j(e,t,a){if((0,x.Kn)(e)&&!(0,x.kJ)(e))return(0,S.default)({},e);if((0,x.Wl)(e))return j(e(t),t,a);if((0,x.kJ)(e)){var n;const r="chain"===a.pluginLoadType?t.getComponents():{};return(0,p.default)(n=(0,m.default)(e).call(e,(e=>j(e,t,a)))).call(n,O,r)}return{}}function N(e,t){let{hasLoaded:a}=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{},n=a;return(0,x.Kn)(e)&&!(0,x.kJ)(e)&&"function"==typeof e.afterLoad&&(n=!0,k(e.afterLoad).call(this,t)),(0,x.Wl)(e)?N.call(this,e(t),t,{hasLoaded:n}):(0,x.kJ)(e)?(0,m.default)(e).call(e,(e=>N.call(this,e,t,{hasLoaded:n}))):n}function O(){let e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:{},t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};if(!(0,x.Kn)(e))return{};if(!(0,x.Kn)(t))return e;t.wrapComponents&&((0,x.Ay)(t.wrapComponents,((a,n)=>{const r=e.components&&e.components[n];r&&(0,d.default)(r)?(e.components[n]=(0,f.default)(r).call(r,[a]),delete t.wrapComponents[n]):r&&(e.components[n]=[r,a],delete t.wrapComponents[n])})),(0,r.default)(t.wrapComponents).length||delete t.wrapComponents);const{statePlugins:a}=e;if((0,x.Kn)(a))for(let e in a){const r=a[e];if(!(0,x.Kn)(r))continue;const{wrapActions:o,wrapSelectors:l}=r;if((0,x.Kn)(o))for(let a in o){let r=o[a];var n;if((0,d.default)(r)||(r=[r],o[a]=r),t&&t.statePlugins&&t.statePlugins[e]&&t.statePlugins[e].wrapActions&&t.statePlugins[e].wrapActions[a])t.statePlugins[e].wrapActions[a]=(0,f.default)(n=o[a]).call(n,t.statePlugins[e].wrapActions[a])}if((0,x.Kn)(l))for(let a in l){let n=l[a];var s;if((0,d.default)(n)||(n=[n],l[a]=n),t&&t.statePlugins&&t.statePlugins[e]&&t.statePlugins[e].wrapSelectors&&t.statePlugins[e].wrapSelectors[a])t.statePlugins[e].wrapSelectors[a]=(0,f.default)(s=l[a]).call(s,t.statePlugins[e].wrapSelectors[a])}}return l()(e,t)}function k(e){let{logErrors:t=!0}=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};return"function"!=typeof e?e:function(){try{for(var a=arguments.length,n=new Array(a),r=0;r<a;r++)n[r]=arguments[r];return e.call(this,...n)}catch(e){return t&&console.error(e),null}}}var A=ut(7793),I=ut(6821),R=ut(7038),P=ut(3420),T=ut(8883),M=ut(6575),D=ut(9150),L=ut(9430),q=ut(3705),B=ut(8525),U=ut(8011),J=ut(6709),$=ut(4980),V=ut(9978),K=ut(8560),F=ut(8102),z=ut(70),W=ut(775),H=ut(8818),G=(ut(5053),ut(9569),ut(5013));class Z extends h.PureComponent{constructor(e,t){super(e,t),(0,W.default)(this,"toggleShown",(()=>{let{layoutActions:e,tag:t,operationId:a,isShown:n}=this.props;const r=this.getResolvedSubtree();n||void 0!==r||this.requestResolvedSubtree(),e.show(["operations",t,a],!n)})),(0,W.default)(this,"onCancelClick",(()=>{this.setState({tryItOutEnabled:!this.state.tryItOutEnabled})})),(0,W.default)(this,"onTryoutClick",(()=>{this.setState({tryItOutEnabled:!this.state.tryItOutEnabled})})),(0,W.default)(this,"onResetClick",(e=>{const t=this.props.oas3Selectors.selectDefaultRequestBodyValue(...e);this.props.oas3Actions.setRequestBodyValue({value:t,pathMethod:e})})),(0,W.default)(this,"onExecute",(()=>{this.setState({executeInProgress:!0})})),(0,W.default)(this,"getResolvedSubtree",(()=>{const{specSelectors:e,path:t,method:a,specPath:n}=this.props;return n?e.specResolvedSubtree(n.toJS()):e.specResolvedSubtree(["paths",t,a])})),(0,W.default)(this,"requestResolvedSubtree",(()=>{const{specActions:e,path:t,method:a,specPath:n}=this.props;return n?e.requestResolvedSubtree(n.toJS()):e.requestResolvedSubtree(["paths",t,a])}));const{tryItOutEnabled:a}=e.getConfigs();this.state={tryItOutEnabled:!0===a||"true"===a,executeInProgress:!1}}mapStateToProps(e,t){const{op:a,layoutSelectors:n,getConfigs:r}=t,{docExpansion:s,deepLinking:o,displayOperationId:l,displayRequestDuration:i,supportedSubmitMethods:c}=r(),u=n.showSummary(),d=a.getIn(["operation","__originalOperationId"])||a.getIn(["operation","operationId"])||(0,G.opId)(a.get("operation"),t.path,t.method)||a.get("id"),p=["operations",t.tag,d],m=o&&"false"!==o,f=(0,H.default)(c).call(c,t.method)>=0&&(void 0===t.allowTryItOut?t.specSelectors.allowTryItOutFor(t.path,t.method):t.allowTryItOut),h=a.getIn(["operation","security"])||t.specSelectors.security();return{operationId:d,isDeepLinkingEnabled:m,showSummary:u,displayOperationId:l,displayRequestDuration:i,allowTryItOut:f,security:h,isAuthorized:t.authSelectors.isAuthorized(h),isShown:n.isShown(p,"full"===s),jumpToKey:`paths.${t.path}.${t.method}`,response:t.specSelectors.responseFor(t.path,t.method),request:t.specSelectors.requestFor(t.path,t.method)}}componentDidMount(){const{isShown:e}=this.props,t=this.getResolvedSubtree();e&&void 0===t&&this.requestResolvedSubtree()}UNSAFE_componentWillReceiveProps(e){const{response:t,isShown:a}=e,n=this.getResolvedSubtree();t!==this.props.response&&this.setState({executeInProgress:!1}),a&&void 0===n&&this.requestResolvedSubtree()}render(){let{op:e,tag:t,path:a,method:n,security:r,isAuthorized:s,operationId:o,showSummary:l,isShown:i,jumpToKey:c,allowTryItOut:u,response:d,request:p,displayOperationId:m,displayRequestDuration:f,isDeepLinkingEnabled:g,specPath:v,specSelectors:E,specActions:S,getComponent:w,getConfigs:b,layoutSelectors:x,layoutActions:_,authActions:C,authSelectors:j,oas3Actions:N,oas3Selectors:O,fn:k}=this.props;const A=w("operation"),I=this.getResolvedSubtree()||(0,y.Map)(),R=(0,y.fromJS)({op:I,tag:t,path:a,summary:e.getIn(["operation","summary"])||"",deprecated:I.get("deprecated")||e.getIn(["operation","deprecated"])||!1,method:n,security:r,isAuthorized:s,operationId:o,originalOperationId:I.getIn(["operation","__originalOperationId"]),showSummary:l,isShown:i,jumpToKey:c,allowTryItOut:u,request:p,displayOperationId:m,displayRequestDuration:f,isDeepLinkingEnabled:g,executeInProgress:this.state.executeInProgress,tryItOutEnabled:this.state.tryItOutEnabled});return h.default.createElement(A,{operation:R,response:d,request:p,isShown:i,toggleShown:this.toggleShown,onTryoutClick:this.onTryoutClick,onResetClick:this.onResetClick,onCancelClick:this.onCancelClick,onExecute:this.onExecute,specPath:v,specActions:S,specSelectors:E,oas3Actions:N,oas3Selectors:O,layoutActions:_,layoutSelectors:x,authActions:C,authSelectors:j,getComponent:w,getConfigs:b,fn:k})}}(0,W.default)(Z,"defaultProps",{showSummary:!0,response:null,allowTryItOut:!0,displayOperationId:!1,displayRequestDuration:!1});class Y extends h.default.Component{getLayout(){let{getComponent:e,layoutSelectors:t}=this.props;const a=t.current(),n=e(a,!0);return n||(()=>h.default.createElement("h1",null,' No layout defined for "',a,'" '))}render(){const e=this.getLayout();return h.default.createElement(e,null)}}Y.defaultProps={};class X extends h.default.Component{constructor(){super(...arguments),(0,W.default)(this,"close",(()=>{let{authActions:e}=this.props;e.showDefinitions(!1)}))}render(){var e;let{authSelectors:t,authActions:a,getComponent:n,errSelectors:r,specSelectors:s,fn:{AST:o={}}}=this.props,l=t.shownDefinitions();const i=n("auths"),c=n("CloseIcon");return h.default.createElement("div",{className:"dialog-ux"},h.default.createElement("div",{className:"backdrop-ux"}),h.default.createElement("div",{className:"modal-ux"},h.default.createElement("div",{className:"modal-dialog-ux"},h.default.createElement("div",{className:"modal-ux-inner"},h.default.createElement("div",{className:"modal-ux-header"},h.default.createElement("h3",null,"Available authorizations"),h.default.createElement("button",{type:"button",className:"close-modal",onClick:this.close},h.default.createElement(c,null))),h.default.createElement("div",{className:"modal-ux-content"},(0,m.default)(e=l.valueSeq()).call(e,((e,l)=>h.default.createElement(i,{key:l,AST:o,definitions:e,getComponent:n,errSelectors:r,authSelectors:t,authActions:a,specSelectors:s}))))))))}}class Q extends h.default.Component{render(){let{isAuthorized:e,showPopup:t,onClick:a,getComponent:n}=this.props;const r=n("authorizationPopup",!0),s=n("LockAuthIcon",!0),o=n("UnlockAuthIcon",!0);return h.default.createElement("div",{className:"auth-wrapper"},h.default.createElement("button",{className:e?"btn authorize locked":"btn authorize unlocked",onClick:a},h.default.createElement("span",null,"Authorize"),e?h.default.createElement(s,null):h.default.createElement(o,null)),t&&h.default.createElement(r,null))}}class ee extends h.default.Component{render(){const{authActions:e,authSelectors:t,specSelectors:a,getComponent:n}=this.props,r=a.securityDefinitions(),s=t.definitionsToAuthorize(),o=n("authorizeBtn");return r?h.default.createElement(o,{onClick:()=>e.showDefinitions(s),isAuthorized:!!t.authorized().size,showPopup:!!t.shownDefinitions(),getComponent:n}):null}}class te extends h.default.Component{constructor(){super(...arguments),(0,W.default)(this,"onClick",(e=>{e.stopPropagation();let{onClick:t}=this.props;t&&t()}))}render(){let{isAuthorized:e,getComponent:t}=this.props;const a=t("LockAuthOperationIcon",!0),n=t("UnlockAuthOperationIcon",!0);return h.default.createElement("button",{className:"authorization__btn","aria-label":e?"authorization button locked":"authorization button unlocked",onClick:this.onClick},e?h.default.createElement(a,{className:"locked"}):h.default.createElement(n,{className:"unlocked"}))}}class ae extends h.default.Component{constructor(e,t){super(e,t),(0,W.default)(this,"onAuthChange",(e=>{let{name:t}=e;this.setState({[t]:e})})),(0,W.default)(this,"submitAuth",(e=>{e.preventDefault();let{authActions:t}=this.props;t.authorizeWithPersistOption(this.state)})),(0,W.default)(this,"logoutClick",(e=>{e.preventDefault();let{authActions:t,definitions:a}=this.props,n=(0,m.default)(a).call(a,((e,t)=>t)).toArray();this.setState((0,p.default)(n).call(n,((e,t)=>(e[t]="",e)),{})),t.logoutWithPersistOption(n)})),(0,W.default)(this,"close",(e=>{e.preventDefault();let{authActions:t}=this.props;t.showDefinitions(!1)})),this.state={}}render(){var e;let{definitions:t,getComponent:a,authSelectors:r,errSelectors:s}=this.props;const o=a("AuthItem"),l=a("oauth2",!0),i=a("Button");let c=r.authorized(),u=(0,n.default)(t).call(t,((e,t)=>!!c.get(t))),d=(0,n.default)(t).call(t,(e=>"oauth2"!==e.get("type"))),p=(0,n.default)(t).call(t,(e=>"oauth2"===e.get("type")));return h.default.createElement("div",{className:"auth-container"},!!d.size&&h.default.createElement("form",{onSubmit:this.submitAuth},(0,m.default)(d).call(d,((e,t)=>h.default.createElement(o,{key:t,schema:e,name:t,getComponent:a,onAuthChange:this.onAuthChange,authorized:c,errSelectors:s}))).toArray(),h.default.createElement("div",{className:"auth-btn-wrapper"},d.size===u.size?h.default.createElement(i,{className:"btn modal-btn auth",onClick:this.logoutClick},"Logout"):h.default.createElement(i,{type:"submit",className:"btn modal-btn auth authorize"},"Authorize"),h.default.createElement(i,{className:"btn modal-btn auth btn-done",onClick:this.close},"Close"))),p&&p.size?h.default.createElement("div",null,h.default.createElement("div",{className:"scope-def"},h.default.createElement("p",null,"Scopes are used to grant an application different levels of access to data on behalf of the end user. Each API may declare one or more scopes."),h.default.createElement("p",null,"API requires the following scopes. Select which ones you want to grant to Swagger UI.")),(0,m.default)(e=(0,n.default)(t).call(t,(e=>"oauth2"===e.get("type")))).call(e,((e,t)=>h.default.createElement("div",{key:t},h.default.createElement(l,{authorized:c,schema:e,name:t})))).toArray()):null)}}class ne extends h.default.Component{render(){let{schema:e,name:t,getComponent:a,onAuthChange:n,authorized:r,errSelectors:s}=this.props;const o=a("apiKeyAuth"),l=a("basicAuth");let i;const c=e.get("type");switch(c){case"apiKey":i=h.default.createElement(o,{key:t,schema:e,name:t,errSelectors:s,authorized:r,getComponent:a,onChange:n});break;case"basic":i=h.default.createElement(l,{key:t,schema:e,name:t,errSelectors:s,authorized:r,getComponent:a,onChange:n});break;default:i=h.default.createElement("div",{key:t},"Unknown security definition type ",c)}return h.default.createElement("div",{key:`${t}-jump`},i)}}class re extends h.default.Component{render(){let{error:e}=this.props,t=e.get("level"),a=e.get("message"),n=e.get("source");return h.default.createElement("div",{className:"errors"},h.default.createElement("b",null,n," ",t),h.default.createElement("span",null,a))}}class se extends h.default.Component{constructor(e,t){super(e,t),(0,W.default)(this,"onChange",(e=>{let{onChange:t}=this.props,a=e.target.value,n=(0,c.default)({},this.state,{value:a});this.setState(n),t(n)}));let{name:a,schema:n}=this.props,r=this.getValue();this.state={name:a,schema:n,value:r}}getValue(){let{name:e,authorized:t}=this.props;return t&&t.getIn([e,"value"])}render(){var e,t;let{schema:a,getComponent:r,errSelectors:s,name:o}=this.props;const l=r("Input"),i=r("Row"),c=r("Col"),u=r("authError"),d=r("Markdown",!0),p=r("JumpToPath",!0);let f=this.getValue(),g=(0,n.default)(e=s.allErrors()).call(e,(e=>e.get("authId")===o));return h.default.createElement("div",null,h.default.createElement("h4",null,h.default.createElement("code",null,o||a.get("name"))," (apiKey)",h.default.createElement(p,{path:["securityDefinitions",o]})),f&&h.default.createElement("h6",null,"Authorized"),h.default.createElement(i,null,h.default.createElement(d,{source:a.get("description")})),h.default.createElement(i,null,h.default.createElement("p",null,"Name: ",h.default.createElement("code",null,a.get("name")))),h.default.createElement(i,null,h.default.createElement("p",null,"In: ",h.default.createElement("code",null,a.get("in")))),h.default.createElement(i,null,h.default.createElement("label",null,"Value:"),f?h.default.createElement("code",null," ****** "):h.default.createElement(c,null,h.default.createElement(l,{type:"text",onChange:this.onChange,autoFocus:!0}))),(0,m.default)(t=g.valueSeq()).call(t,((e,t)=>h.default.createElement(u,{error:e,key:t}))))}}class oe extends h.default.Component{constructor(e,t){super(e,t),(0,W.default)(this,"onChange",(e=>{let{onChange:t}=this.props,{value:a,name:n}=e.target,r=this.state.value;r[n]=a,this.setState({value:r}),t(this.state)}));let{schema:a,name:n}=this.props,r=this.getValue().username;this.state={name:n,schema:a,value:r?{username:r}:{}}}getValue(){let{authorized:e,name:t}=this.props;return e&&e.getIn([t,"value"])||{}}render(){var e,t;let{schema:a,getComponent:r,name:s,errSelectors:o}=this.props;const l=r("Input"),i=r("Row"),c=r("Col"),u=r("authError"),d=r("JumpToPath",!0),p=r("Markdown",!0);let f=this.getValue().username,g=(0,n.default)(e=o.allErrors()).call(e,(e=>e.get("authId")===s));return h.default.createElement("div",null,h.default.createElement("h4",null,"Basic authorization",h.default.createElement(d,{path:["securityDefinitions",s]})),f&&h.default.createElement("h6",null,"Authorized"),h.default.createElement(i,null,h.default.createElement(p,{source:a.get("description")})),h.default.createElement(i,null,h.default.createElement("label",null,"Username:"),f?h.default.createElement("code",null," ",f," "):h.default.createElement(c,null,h.default.createElement(l,{type:"text",required:"required",name:"username",onChange:this.onChange,autoFocus:!0}))),h.default.createElement(i,null,h.default.createElement("label",null,"Password:"),f?h.default.createElement("code",null," ****** "):h.default.createElement(c,null,h.default.createElement(l,{autoComplete:"new-password",name:"password",type:"password",onChange:this.onChange}))),(0,m.default)(t=g.valueSeq()).call(t,((e,t)=>h.default.createElement(u,{error:e,key:t}))))}}function le(e){const{example:t,showValue:a,getComponent:n,getConfigs:r}=e,s=n("Markdown",!0),o=n("highlightCode");return t?h.default.createElement("div",{className:"example"},t.get("description")?h.default.createElement("section",{className:"example__section"},h.default.createElement("div",{className:"example__section-header"},"Example Description"),h.default.createElement("p",null,h.default.createElement(s,{source:t.get("description")}))):null,a&&t.has("value")?h.default.createElement("section",{className:"example__section"},h.default.createElement("div",{className:"example__section-header"},"Example Value"),h.default.createElement(o,{getConfigs:r,value:(0,x.Pz)(t.get("value"))})):null):null}var ie=ut(6914);class ce extends h.default.PureComponent{constructor(){var e;super(...arguments),e=this,(0,W.default)(this,"_onSelect",(function(t){let{isSyntheticChange:a=!1}=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};"function"==typeof e.props.onSelect&&e.props.onSelect(t,{isSyntheticChange:a})})),(0,W.default)(this,"_onDomSelect",(e=>{if("function"==typeof this.props.onSelect){const t=e.target.selectedOptions[0].getAttribute("value");this._onSelect(t,{isSyntheticChange:!1})}})),(0,W.default)(this,"getCurrentExample",(()=>{const{examples:e,currentExampleKey:t}=this.props,a=e.get(t),n=e.keySeq().first(),r=e.get(n);return a||r||(0,ie.default)({})}))}componentDidMount(){const{onSelect:e,examples:t}=this.props;if("function"==typeof e){const e=t.first(),a=t.keyOf(e);this._onSelect(a,{isSyntheticChange:!0})}}UNSAFE_componentWillReceiveProps(e){const{currentExampleKey:t,examples:a}=e;if(a!==this.props.examples&&!a.has(t)){const e=a.first(),t=a.keyOf(e);this._onSelect(t,{isSyntheticChange:!0})}}render(){const{examples:e,currentExampleKey:t,isValueModified:a,isModifiedValueAvailable:n,showLabels:r}=this.props;return h.default.createElement("div",{className:"examples-select"},r?h.default.createElement("span",{className:"examples-select__section-label"},"Examples: "):null,h.default.createElement("select",{className:"examples-select-element",onChange:this._onDomSelect,value:n&&a?"__MODIFIED__VALUE__":t||""},n?h.default.createElement("option",{value:"__MODIFIED__VALUE__"},"[Modified value]"):null,(0,m.default)(e).call(e,((e,t)=>h.default.createElement("option",{key:t,value:t},e.get("summary")||t))).valueSeq()))}}(0,W.default)(ce,"defaultProps",{examples:y.default.Map({}),onSelect:function(){for(var e=arguments.length,t=new Array(e),a=0;a<e;a++)t[a]=arguments[a];return console.log("DEBUG: ExamplesSelect was not given an onSelect callback",...t)},currentExampleKey:null,showLabels:!0});const ue=e=>y.List.isList(e)?e:(0,x.Pz)(e);class de extends h.default.PureComponent{constructor(e){var t;super(e),t=this,(0,W.default)(this,"_getStateForCurrentNamespace",(()=>{const{currentNamespace:e}=this.props;return(this.state[e]||(0,y.Map)()).toObject()})),(0,W.default)(this,"_setStateForCurrentNamespace",(e=>{const{currentNamespace:t}=this.props;return this._setStateForNamespace(t,e)})),(0,W.default)(this,"_setStateForNamespace",((e,t)=>{const a=(this.state[e]||(0,y.Map)()).mergeDeep(t);return this.setState({[e]:a})})),(0,W.default)(this,"_isCurrentUserInputSameAsExampleValue",(()=>{const{currentUserInputValue:e}=this.props;return this._getCurrentExampleValue()===e})),(0,W.default)(this,"_getValueForExample",((e,t)=>{const{examples:a}=t||this.props;return ue((a||(0,y.Map)({})).getIn([e,"value"]))})),(0,W.default)(this,"_getCurrentExampleValue",(e=>{const{currentKey:t}=e||this.props;return this._getValueForExample(t,e||this.props)})),(0,W.default)(this,"_onExamplesSelect",(function(e){let{isSyntheticChange:a}=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};const{onSelect:n,updateValue:r,currentUserInputValue:s,userHasEditedBody:o}=t.props,{lastUserEditedValue:l}=t._getStateForCurrentNamespace(),i=t._getValueForExample(e);if("__MODIFIED__VALUE__"===e)return r(ue(l)),t._setStateForCurrentNamespace({isModifiedValueSelected:!0});if("function"==typeof n){for(var c=arguments.length,u=new Array(c>2?c-2:0),d=2;d<c;d++)u[d-2]=arguments[d];n(e,{isSyntheticChange:a},...u)}t._setStateForCurrentNamespace({lastDownstreamValue:i,isModifiedValueSelected:a&&o||!!s&&s!==i}),a||"function"==typeof r&&r(ue(i))}));const a=this._getCurrentExampleValue();this.state={[e.currentNamespace]:(0,y.Map)({lastUserEditedValue:this.props.currentUserInputValue,lastDownstreamValue:a,isModifiedValueSelected:this.props.userHasEditedBody||this.props.currentUserInputValue!==a})}}componentWillUnmount(){this.props.setRetainRequestBodyValueFlag(!1)}UNSAFE_componentWillReceiveProps(e){const{currentUserInputValue:t,examples:a,onSelect:r,userHasEditedBody:s}=e,{lastUserEditedValue:o,lastDownstreamValue:l}=this._getStateForCurrentNamespace(),i=this._getValueForExample(e.currentKey,e),c=(0,n.default)(a).call(a,(e=>e.get("value")===t||(0,x.Pz)(e.get("value"))===t));if(c.size){let t;t=c.has(e.currentKey)?e.currentKey:c.keySeq().first(),r(t,{isSyntheticChange:!0})}else t!==this.props.currentUserInputValue&&t!==o&&t!==l&&(this.props.setRetainRequestBodyValueFlag(!0),this._setStateForNamespace(e.currentNamespace,{lastUserEditedValue:e.currentUserInputValue,isModifiedValueSelected:s||t!==i}))}render(){const{currentUserInputValue:e,examples:t,currentKey:a,getComponent:n,userHasEditedBody:r}=this.props,{lastDownstreamValue:s,lastUserEditedValue:o,isModifiedValueSelected:l}=this._getStateForCurrentNamespace(),i=n("ExamplesSelect");return h.default.createElement(i,{examples:t,currentExampleKey:a,onSelect:this._onExamplesSelect,isModifiedValueAvailable:!!o&&o!==s,isValueModified:void 0!==e&&l&&e!==this._getCurrentExampleValue()||r})}}(0,W.default)(de,"defaultProps",{userHasEditedBody:!1,examples:(0,y.Map)({}),currentNamespace:"__DEFAULT__NAMESPACE__",setRetainRequestBodyValueFlag:()=>{},onSelect:function(){for(var e=arguments.length,t=new Array(e),a=0;a<e;a++)t[a]=arguments[a];return console.log("ExamplesSelectValueRetainer: no `onSelect` function was provided",...t)},updateValue:function(){for(var e=arguments.length,t=new Array(e),a=0;a<e;a++)t[a]=arguments[a];return console.log("ExamplesSelectValueRetainer: no `updateValue` function was provided",...t)}});var pe=ut(8898),me=ut(5487),fe=ut(2372),he=ut(8900);class ge extends h.default.Component{constructor(e,t){super(e,t),(0,W.default)(this,"close",(e=>{e.preventDefault();let{authActions:t}=this.props;t.showDefinitions(!1)})),(0,W.default)(this,"authorize",(()=>{let{authActions:e,errActions:t,getConfigs:a,authSelectors:n,oas3Selectors:r}=this.props,s=a(),o=n.getConfigs();t.clear({authId:name,type:"auth",source:"auth"}),function(e){let{auth:t,authActions:a,errActions:n,configs:r,authConfigs:s={},currentServer:o}=e,{schema:l,scopes:i,name:c,clientId:u}=t,p=l.get("flow"),f=[];switch(p){case"password":return void a.authorizePassword(t);case"application":case"clientCredentials":case"client_credentials":return void a.authorizeApplication(t);case"accessCode":case"authorizationCode":case"authorization_code":f.push("response_type=code");break;case"implicit":f.push("response_type=token")}"string"==typeof u&&f.push("client_id="+encodeURIComponent(u));let h=r.oauth2RedirectUrl;if(void 0===h)return void n.newAuthErr({authId:c,source:"validation",level:"error",message:"oauth2RedirectUrl configuration is not passed. Oauth2 authorization cannot be performed."});f.push("redirect_uri="+encodeURIComponent(h));let g=[];if((0,d.default)(i)?g=i:y.default.List.isList(i)&&(g=i.toArray()),g.length>0){let e=s.scopeSeparator||" ";f.push("scope="+encodeURIComponent(g.join(e)))}let v=(0,x.r3)(new Date);if(f.push("state="+encodeURIComponent(v)),void 0!==s.realm&&f.push("realm="+encodeURIComponent(s.realm)),("authorizationCode"===p||"authorization_code"===p||"accessCode"===p)&&s.usePkceWithAuthorizationCodeGrant){const e=(0,x.Uj)(),a=(0,x.Xb)(e);f.push("code_challenge="+a),f.push("code_challenge_method=S256"),t.codeVerifier=e}let{additionalQueryStringParams:E}=s;for(let e in E){var S;void 0!==E[e]&&f.push((0,m.default)(S=[e,E[e]]).call(S,encodeURIComponent).join("="))}const w=l.get("authorizationUrl");let b;b=o?(0,he.default)((0,x.Nm)(w),o,!0).toString():(0,x.Nm)(w);let _,C=[b,f.join("&")].join(-1===(0,H.default)(w).call(w,"?")?"?":"&");_="implicit"===p?a.preAuthorizeImplicit:s.useBasicAuthenticationWithAccessCodeGrant?a.authorizeAccessCodeWithBasicAuthentication:a.authorizeAccessCodeWithFormParams,a.authPopup(C,{auth:t,state:v,redirectUrl:h,callback:_,errCb:n.newAuthErr})}({auth:this.state,currentServer:r.serverEffectiveValue(r.selectedServer()),authActions:e,errActions:t,configs:s,authConfigs:o})})),(0,W.default)(this,"onScopeChange",(e=>{var t,a;let{target:r}=e,{checked:s}=r,o=r.dataset.value;if(s&&-1===(0,H.default)(t=this.state.scopes).call(t,o)){var l;let e=(0,f.default)(l=this.state.scopes).call(l,[o]);this.setState({scopes:e})}else if(!s&&(0,H.default)(a=this.state.scopes).call(a,o)>-1){var i;this.setState({scopes:(0,n.default)(i=this.state.scopes).call(i,(e=>e!==o))})}})),(0,W.default)(this,"onInputChange",(e=>{let{target:{dataset:{name:t},value:a}}=e,n={[t]:a};this.setState(n)})),(0,W.default)(this,"selectScopes",(e=>{var t;e.target.dataset.all?this.setState({scopes:(0,pe.default)((0,me.default)(t=this.props.schema.get("allowedScopes")||this.props.schema.get("scopes")).call(t))}):this.setState({scopes:[]})})),(0,W.default)(this,"logout",(e=>{e.preventDefault();let{authActions:t,errActions:a,name:n}=this.props;a.clear({authId:n,type:"auth",source:"auth"}),t.logoutWithPersistOption([n])}));let{name:a,schema:r,authorized:s,authSelectors:o}=this.props,l=s&&s.get(a),i=o.getConfigs()||{},c=l&&l.get("username")||"",u=l&&l.get("clientId")||i.clientId||"",p=l&&l.get("clientSecret")||i.clientSecret||"",h=l&&l.get("passwordType")||"basic",g=l&&l.get("scopes")||i.scopes||[];"string"==typeof g&&(g=g.split(i.scopeSeparator||" ")),this.state={appName:i.appName,name:a,schema:r,scopes:g,clientId:u,clientSecret:p,username:c,password:"",passwordType:h}}render(){var e,t;let{schema:a,getComponent:r,authSelectors:s,errSelectors:o,name:l,specSelectors:i}=this.props;const c=r("Input"),u=r("Row"),d=r("Col"),p=r("Button"),f=r("authError"),g=r("JumpToPath",!0),y=r("Markdown",!0),v=r("InitializedInput"),{isOAS3:E}=i;let S=E()?a.get("openIdConnectUrl"):null;const w="implicit",b="password",x=E()?S?"authorization_code":"authorizationCode":"accessCode",_=E()?S?"client_credentials":"clientCredentials":"application";let C=!!(s.getConfigs()||{}).usePkceWithAuthorizationCodeGrant,j=a.get("flow"),N=j===x&&C?j+" with PKCE":j,O=a.get("allowedScopes")||a.get("scopes"),k=!!s.authorized().get(l),A=(0,n.default)(e=o.allErrors()).call(e,(e=>e.get("authId")===l)),I=!(0,n.default)(A).call(A,(e=>"validation"===e.get("source"))).size,R=a.get("description");return h.default.createElement("div",null,h.default.createElement("h4",null,l," (OAuth2, ",N,") ",h.default.createElement(g,{path:["securityDefinitions",l]})),this.state.appName?h.default.createElement("h5",null,"Application: ",this.state.appName," "):null,R&&h.default.createElement(y,{source:a.get("description")}),k&&h.default.createElement("h6",null,"Authorized"),S&&h.default.createElement("p",null,"OpenID Connect URL: ",h.default.createElement("code",null,S)),(j===w||j===x)&&h.default.createElement("p",null,"Authorization URL: ",h.default.createElement("code",null,a.get("authorizationUrl"))),(j===b||j===x||j===_)&&h.default.createElement("p",null,"Token URL:",h.default.createElement("code",null," ",a.get("tokenUrl"))),h.default.createElement("p",{className:"flow"},"Flow: ",h.default.createElement("code",null,N)),j!==b?null:h.default.createElement(u,null,h.default.createElement(u,null,h.default.createElement("label",{htmlFor:"oauth_username"},"username:"),k?h.default.createElement("code",null," ",this.state.username," "):h.default.createElement(d,{tablet:10,desktop:10},h.default.createElement("input",{id:"oauth_username",type:"text","data-name":"username",onChange:this.onInputChange,autoFocus:!0}))),h.default.createElement(u,null,h.default.createElement("label",{htmlFor:"oauth_password"},"password:"),k?h.default.createElement("code",null," ****** "):h.default.createElement(d,{tablet:10,desktop:10},h.default.createElement("input",{id:"oauth_password",type:"password","data-name":"password",onChange:this.onInputChange}))),h.default.createElement(u,null,h.default.createElement("label",{htmlFor:"password_type"},"Client credentials location:"),k?h.default.createElement("code",null," ",this.state.passwordType," "):h.default.createElement(d,{tablet:10,desktop:10},h.default.createElement("select",{id:"password_type","data-name":"passwordType",onChange:this.onInputChange},h.default.createElement("option",{value:"basic"},"Authorization header"),h.default.createElement("option",{value:"request-body"},"Request body"))))),(j===_||j===w||j===x||j===b)&&(!k||k&&this.state.clientId)&&h.default.createElement(u,null,h.default.createElement("label",{htmlFor:"client_id"},"client_id:"),k?h.default.createElement("code",null," ****** "):h.default.createElement(d,{tablet:10,desktop:10},h.default.createElement(v,{id:"client_id",type:"text",required:j===b,initialValue:this.state.clientId,"data-name":"clientId",onChange:this.onInputChange}))),(j===_||j===x||j===b)&&h.default.createElement(u,null,h.default.createElement("label",{htmlFor:"client_secret"},"client_secret:"),k?h.default.createElement("code",null," ****** "):h.default.createElement(d,{tablet:10,desktop:10},h.default.createElement(v,{id:"client_secret",initialValue:this.state.clientSecret,type:"password","data-name":"clientSecret",onChange:this.onInputChange}))),!k&&O&&O.size?h.default.createElement("div",{className:"scopes"},h.default.createElement("h2",null,"Scopes:",h.default.createElement("a",{onClick:this.selectScopes,"data-all":!0},"select all"),h.default.createElement("a",{onClick:this.selectScopes},"select none")),(0,m.default)(O).call(O,((e,t)=>{var a;return h.default.createElement(u,{key:t},h.default.createElement("div",{className:"checkbox"},h.default.createElement(c,{"data-value":t,id:`${t}-${j}-checkbox-${this.state.name}`,disabled:k,checked:(0,fe.default)(a=this.state.scopes).call(a,t),type:"checkbox",onChange:this.onScopeChange}),h.default.createElement("label",{htmlFor:`${t}-${j}-checkbox-${this.state.name}`},h.default.createElement("span",{className:"item"}),h.default.createElement("div",{className:"text"},h.default.createElement("p",{className:"name"},t),h.default.createElement("p",{className:"description"},e)))))})).toArray()):null,(0,m.default)(t=A.valueSeq()).call(t,((e,t)=>h.default.createElement(f,{error:e,key:t}))),h.default.createElement("div",{className:"auth-btn-wrapper"},I&&(k?h.default.createElement(p,{className:"btn modal-btn auth authorize",onClick:this.logout},"Logout"):h.default.createElement(p,{className:"btn modal-btn auth authorize",onClick:this.authorize},"Authorize")),h.default.createElement(p,{className:"btn modal-btn auth btn-done",onClick:this.close},"Close")))}}class ye extends h.Component{constructor(){super(...arguments),(0,W.default)(this,"onClick",(()=>{let{specActions:e,path:t,method:a}=this.props;e.clearResponse(t,a),e.clearRequest(t,a)}))}render(){return h.default.createElement("button",{className:"btn btn-clear opblock-control__btn",onClick:this.onClick},"Clear")}}const ve=e=>{let{headers:t}=e;return h.default.createElement("div",null,h.default.createElement("h5",null,"Response headers"),h.default.createElement("pre",{className:"microlight"},t))},Ee=e=>{let{duration:t}=e;return h.default.createElement("div",null,h.default.createElement("h5",null,"Request duration"),h.default.createElement("pre",{className:"microlight"},t," ms"))};class Se extends h.default.Component{shouldComponentUpdate(e){return this.props.response!==e.response||this.props.path!==e.path||this.props.method!==e.method||this.props.displayRequestDuration!==e.displayRequestDuration}render(){const{response:e,getComponent:t,getConfigs:a,displayRequestDuration:n,specSelectors:s,path:o,method:l}=this.props,{showMutatedRequest:i,requestSnippetsEnabled:c}=a(),u=i?s.mutatedRequestFor(o,l):s.requestFor(o,l),p=e.get("status"),f=u.get("url"),g=e.get("headers").toJS(),y=e.get("notDocumented"),v=e.get("error"),E=e.get("text"),S=e.get("duration"),w=(0,r.default)(g),b=g["content-type"]||g["Content-Type"],x=t("responseBody"),_=(0,m.default)(w).call(w,(e=>{var t=(0,d.default)(g[e])?g[e].join():g[e];return h.default.createElement("span",{className:"headerline",key:e}," ",e,": ",t," ")})),C=0!==_.length,j=t("Markdown",!0),N=t("RequestSnippets",!0),O=t("curl");return h.default.createElement("div",null,u&&(!0===c||"true"===c?h.default.createElement(N,{request:u}):h.default.createElement(O,{request:u,getConfigs:a})),f&&h.default.createElement("div",null,h.default.createElement("div",{className:"request-url"},h.default.createElement("h4",null,"Request URL"),h.default.createElement("pre",{className:"microlight"},f))),h.default.createElement("h4",null,"Server response"),h.default.createElement("table",{className:"responses-table live-responses-table"},h.default.createElement("thead",null,h.default.createElement("tr",{className:"responses-header"},h.default.createElement("td",{className:"col_header response-col_status"},"Code"),h.default.createElement("td",{className:"col_header response-col_description"},"Details"))),h.default.createElement("tbody",null,h.default.createElement("tr",{className:"response"},h.default.createElement("td",{className:"response-col_status"},p,y?h.default.createElement("div",{className:"response-undocumented"},h.default.createElement("i",null," Undocumented ")):null),h.default.createElement("td",{className:"response-col_description"},v?h.default.createElement(j,{source:`${""!==e.get("name")?`${e.get("name")}: `:""}${e.get("message")}`}):null,E?h.default.createElement(x,{content:E,contentType:b,url:f,headers:g,getConfigs:a,getComponent:t}):null,C?h.default.createElement(ve,{headers:_}):null,n&&S?h.default.createElement(Ee,{duration:S}):null)))))}}var we=ut(5623);class be extends h.default.Component{constructor(){super(...arguments),(0,W.default)(this,"renderOperationTag",((e,t)=>{const{specSelectors:a,getComponent:n,oas3Selectors:r,layoutSelectors:s,layoutActions:o,getConfigs:l}=this.props,i=a.validOperationMethods(),c=n("OperationContainer",!0),u=n("OperationTag"),d=e.get("operations");return h.default.createElement(u,{key:"operation-"+t,tagObj:e,tag:t,oas3Selectors:r,layoutSelectors:s,layoutActions:o,getConfigs:l,getComponent:n,specUrl:a.url()},h.default.createElement("div",{className:"operation-tag-content"},(0,m.default)(d).call(d,(e=>{const a=e.get("path"),n=e.get("method"),r=y.default.List(["paths",a,n]);return-1===(0,H.default)(i).call(i,n)?null:h.default.createElement(c,{key:`${a}-${n}`,specPath:r,op:e,path:a,method:n,tag:t})})).toArray()))}))}render(){let{specSelectors:e}=this.props;const t=e.taggedOperations();return 0===t.size?h.default.createElement("h3",null," No operations defined in spec!"):h.default.createElement("div",null,(0,m.default)(t).call(t,this.renderOperationTag).toArray(),t.size<1?h.default.createElement("h3",null," No operations defined in spec! "):null)}}var xe=ut(3543);class _e extends h.default.Component{render(){const{tagObj:e,tag:t,children:a,oas3Selectors:n,layoutSelectors:r,layoutActions:s,getConfigs:o,getComponent:l,specUrl:i}=this.props;let{docExpansion:c,deepLinking:u}=o();const d=u&&"false"!==u,p=l("Collapse"),f=l("Markdown",!0),g=l("DeepLink"),y=l("Link"),v=l("ArrowUpIcon"),E=l("ArrowDownIcon");let S,w=e.getIn(["tagDetails","description"],null),b=e.getIn(["tagDetails","externalDocs","description"]),_=e.getIn(["tagDetails","externalDocs","url"]);S=(0,x.Wl)(n)&&(0,x.Wl)(n.selectedServer)?(0,xe.mn)(_,i,{selectedServer:n.selectedServer()}):_;let C=["operations-tag",t],j=r.isShown(C,"full"===c||"list"===c);return h.default.createElement("div",{className:j?"opblock-tag-section is-open":"opblock-tag-section"},h.default.createElement("h3",{onClick:()=>s.show(C,!j),className:w?"opblock-tag":"opblock-tag no-desc",id:(0,m.default)(C).call(C,(e=>(0,x.J6)(e))).join("-"),"data-tag":t,"data-is-open":j},h.default.createElement(g,{enabled:d,isShown:j,path:(0,x.oJ)(t),text:t}),w?h.default.createElement("small",null,h.default.createElement(f,{source:w})):h.default.createElement("small",null),S?h.default.createElement("div",{className:"info__externaldocs"},h.default.createElement("small",null,h.default.createElement(y,{href:(0,x.Nm)(S),onClick:e=>e.stopPropagation(),target:"_blank"},b||S))):null,h.default.createElement("button",{"aria-expanded":j,className:"expand-operation",title:j?"Collapse operation":"Expand operation",onClick:()=>s.show(C,!j)},j?h.default.createElement(v,{className:"arrow"}):h.default.createElement(E,{className:"arrow"}))),h.default.createElement(p,{isOpened:j},a))}}(0,W.default)(_e,"defaultProps",{tagObj:y.default.fromJS({}),tag:""});class Ce extends h.PureComponent{render(){let{specPath:e,response:t,request:a,toggleShown:n,onTryoutClick:r,onResetClick:s,onCancelClick:o,onExecute:l,fn:i,getComponent:c,getConfigs:u,specActions:d,specSelectors:p,authActions:f,authSelectors:g,oas3Actions:y,oas3Selectors:v}=this.props,E=this.props.operation,{deprecated:S,isShown:w,path:b,method:_,op:C,tag:j,operationId:N,allowTryItOut:O,displayRequestDuration:k,tryItOutEnabled:A,executeInProgress:I}=E.toJS(),{description:R,externalDocs:P,schemes:T}=C;const M=P?(0,xe.mn)(P.url,p.url(),{selectedServer:v.selectedServer()}):"";let D=E.getIn(["op"]),L=D.get("responses"),q=(0,x.gp)(D,["parameters"]),B=p.operationScheme(b,_),U=["operations",j,N],J=(0,x.nX)(D);const $=c("responses"),V=c("parameters"),K=c("execute"),F=c("clear"),z=c("Collapse"),W=c("Markdown",!0),H=c("schemes"),G=c("OperationServers"),Z=c("OperationExt"),Y=c("OperationSummary"),X=c("Link"),{showExtensions:Q}=u();if(L&&t&&t.size>0){let e=!L.get(String(t.get("status")))&&!L.get("default");t=t.set("notDocumented",e)}let ee=[b,_];const te=p.validationErrors([b,_]);return h.default.createElement("div",{className:S?"opblock opblock-deprecated":w?`opblock opblock-${_} is-open`:`opblock opblock-${_}`,id:(0,x.J6)(U.join("-"))},h.default.createElement(Y,{operationProps:E,isShown:w,toggleShown:n,getComponent:c,authActions:f,authSelectors:g,specPath:e}),h.default.createElement(z,{isOpened:w},h.default.createElement("div",{className:"opblock-body"},D&&D.size||null===D?null:h.default.createElement("img",{height:"32px",width:"32px",src:ut(2517),className:"opblock-loading-animation"}),S&&h.default.createElement("h4",{className:"opblock-title_normal"}," Warning: Deprecated"),R&&h.default.createElement("div",{className:"opblock-description-wrapper"},h.default.createElement("div",{className:"opblock-description"},h.default.createElement(W,{source:R}))),M?h.default.createElement("div",{className:"opblock-external-docs-wrapper"},h.default.createElement("h4",{className:"opblock-title_normal"},"Find more details"),h.default.createElement("div",{className:"opblock-external-docs"},P.description&&h.default.createElement("span",{className:"opblock-external-docs__description"},h.default.createElement(W,{source:P.description})),h.default.createElement(X,{target:"_blank",className:"opblock-external-docs__link",href:(0,x.Nm)(M)},M))):null,D&&D.size?h.default.createElement(V,{parameters:q,specPath:e.push("parameters"),operation:D,onChangeKey:ee,onTryoutClick:r,onResetClick:s,onCancelClick:o,tryItOutEnabled:A,allowTryItOut:O,fn:i,getComponent:c,specActions:d,specSelectors:p,pathMethod:[b,_],getConfigs:u,oas3Actions:y,oas3Selectors:v}):null,A?h.default.createElement(G,{getComponent:c,path:b,method:_,operationServers:D.get("servers"),pathServers:p.paths().getIn([b,"servers"]),getSelectedServer:v.selectedServer,setSelectedServer:y.setSelectedServer,setServerVariableValue:y.setServerVariableValue,getServerVariable:v.serverVariableValue,getEffectiveServerValue:v.serverEffectiveValue}):null,A&&O&&T&&T.size?h.default.createElement("div",{className:"opblock-schemes"},h.default.createElement(H,{schemes:T,path:b,method:_,specActions:d,currentScheme:B})):null,!A||!O||te.length<=0?null:h.default.createElement("div",{className:"validation-errors errors-wrapper"},"Please correct the following validation errors and try again.",h.default.createElement("ul",null,(0,m.default)(te).call(te,((e,t)=>h.default.createElement("li",{key:t}," ",e," "))))),h.default.createElement("div",{className:A&&t&&O?"btn-group":"execute-wrapper"},A&&O?h.default.createElement(K,{operation:D,specActions:d,specSelectors:p,oas3Selectors:v,oas3Actions:y,path:b,method:_,onExecute:l,disabled:I}):null,A&&t&&O?h.default.createElement(F,{specActions:d,path:b,method:_}):null),I?h.default.createElement("div",{className:"loading-container"},h.default.createElement("div",{className:"loading"})):null,L?h.default.createElement($,{responses:L,request:a,tryItOutResponse:t,getComponent:c,getConfigs:u,specSelectors:p,oas3Actions:y,oas3Selectors:v,specActions:d,produces:p.producesOptionsFor([b,_]),producesValue:p.currentProducesFor([b,_]),specPath:e.push("responses"),path:b,method:_,displayRequestDuration:k,fn:i}):null,Q&&J.size?h.default.createElement(Z,{extensions:J,getComponent:c}):null)))}}(0,W.default)(Ce,"defaultProps",{operation:null,response:null,request:null,specPath:(0,y.List)(),summary:""});const je=(e=>{var t={};return ut.d(t,e),t})({default:()=>tt.default});class Ne extends h.PureComponent{render(){let{isShown:e,toggleShown:t,getComponent:a,authActions:n,authSelectors:r,operationProps:s,specPath:o}=this.props,{summary:l,isAuthorized:i,method:c,op:u,showSummary:d,path:p,operationId:m,originalOperationId:f,displayOperationId:g}=s.toJS(),{summary:y}=u,v=s.get("security");const E=a("authorizeOperationBtn",!0),S=a("OperationSummaryMethod"),w=a("OperationSummaryPath"),b=a("JumpToPath",!0),x=a("CopyToClipboardBtn",!0),_=a("ArrowUpIcon"),C=a("ArrowDownIcon"),j=v&&!!v.count(),N=j&&1===v.size&&v.first().isEmpty(),O=!j||N;return h.default.createElement("div",{className:`opblock-summary opblock-summary-${c}`},h.default.createElement("button",{"aria-label":`${c} ${p.replace(/\//g,"​/")}`,"aria-expanded":e,className:"opblock-summary-control",onClick:t},h.default.createElement(S,{method:c}),h.default.createElement(w,{getComponent:a,operationProps:s,specPath:o}),d?h.default.createElement("div",{className:"opblock-summary-description"},(0,je.default)(y||l)):null,g&&(f||m)?h.default.createElement("span",{className:"opblock-summary-operation-id"},f||m):null,e?h.default.createElement(_,{className:"arrow"}):h.default.createElement(C,{className:"arrow"})),O?null:h.default.createElement(E,{isAuthorized:i,onClick:()=>{const e=r.definitionsForRequirements(v);n.showDefinitions(e)}}),h.default.createElement(x,{textToCopy:`${o.get(1)}`}),h.default.createElement(b,{path:o}))}}(0,W.default)(Ne,"defaultProps",{operationProps:null,specPath:(0,y.List)(),summary:""});class Oe extends h.PureComponent{render(){let{method:e}=this.props;return h.default.createElement("span",{className:"opblock-summary-method"},e.toUpperCase())}}(0,W.default)(Oe,"defaultProps",{operationProps:null});const ke=(e=>{var t={};return ut.d(t,e),t})({default:()=>at.default});class Ae extends h.PureComponent{render(){let{getComponent:e,operationProps:t}=this.props,{deprecated:a,isShown:n,path:r,tag:s,operationId:o,isDeepLinkingEnabled:l}=t.toJS();const i=r.split(/(?=\/)/g);for(let e=1;e<i.length;e+=2)(0,ke.default)(i).call(i,e,0,h.default.createElement("wbr",{key:e}));const c=e("DeepLink");return h.default.createElement("span",{className:a?"opblock-summary-path__deprecated":"opblock-summary-path","data-path":r},h.default.createElement(c,{enabled:l,isShown:n,path:(0,x.oJ)(`${s}/${o}`),text:i}))}}const Ie=e=>{var t;let{extensions:a,getComponent:n}=e,r=n("OperationExtRow");return h.default.createElement("div",{className:"opblock-section"},h.default.createElement("div",{className:"opblock-section-header"},h.default.createElement("h4",null,"Extensions")),h.default.createElement("div",{className:"table-container"},h.default.createElement("table",null,h.default.createElement("thead",null,h.default.createElement("tr",null,h.default.createElement("td",{className:"col_header"},"Field"),h.default.createElement("td",{className:"col_header"},"Value"))),h.default.createElement("tbody",null,(0,m.default)(t=a.entrySeq()).call(t,(e=>{let[t,a]=e;return h.default.createElement(r,{key:`${t}-${a}`,xKey:t,xVal:a})}))))))},Re=e=>{let{xKey:t,xVal:a}=e;const n=a?a.toJS?a.toJS():a:null;return h.default.createElement("tr",null,h.default.createElement("td",null,t),h.default.createElement("td",null,(0,s.default)(n)))};var Pe=ut(29),Te=ut(8096),Me=ut(471),De=ut(9908),Le=ut(7068);const qe=(e=>{var t={};return ut.d(t,e),t})({default:()=>nt.default});var Be=ut(9874);const Ue=e=>{let{value:t,fileName:a,className:r,downloadable:s,getConfigs:o,canCopy:l,language:i}=e;const c=(0,Le.default)(o)?o():null,u=!1!==(0,De.default)(c,"syntaxHighlight")&&(0,De.default)(c,"syntaxHighlight.activated",!0),d=(0,h.useRef)(null);(0,h.useEffect)((()=>{var e;const t=(0,n.default)(e=(0,pe.default)(d.current.childNodes)).call(e,(e=>!!e.nodeType&&e.classList.contains("microlight")));return(0,Pe.default)(t).call(t,(e=>e.addEventListener("mousewheel",p,{passive:!1}))),()=>{(0,Pe.default)(t).call(t,(e=>e.removeEventListener("mousewheel",p)))}}),[t,r,i]);const p=e=>{const{target:t,deltaY:a}=e,{scrollHeight:n,offsetHeight:r,scrollTop:s}=t;n>r&&(0===s&&a<0||r+s>=n&&a>0)&&e.preventDefault()};return h.default.createElement("div",{className:"highlight-code",ref:d},s?h.default.createElement("div",{className:"download-contents",onClick:()=>{(0,qe.default)(t,a)}},"Download"):null,l&&h.default.createElement("div",{className:"copy-to-clipboard"},h.default.createElement(Be.CopyToClipboard,{text:t},h.default.createElement("button",null))),u?h.default.createElement(Me.d3,{language:i,className:(0,Te.default)(r,"microlight"),style:(0,Me.C2)((0,De.default)(c,"syntaxHighlight.theme","agate"))},t):h.default.createElement("pre",{className:(0,Te.default)(r,"microlight")},t))};Ue.defaultProps={fileName:"response.txt"};const Je=Ue;class $e extends h.default.Component{constructor(){super(...arguments),(0,W.default)(this,"onChangeProducesWrapper",(e=>this.props.specActions.changeProducesValue([this.props.path,this.props.method],e))),(0,W.default)(this,"onResponseContentTypeChange",(e=>{let{controlsAcceptHeader:t,value:a}=e;const{oas3Actions:n,path:r,method:s}=this.props;t&&n.setResponseContentType({value:a,path:r,method:s})}))}render(){var e;let{responses:t,tryItOutResponse:a,getComponent:n,getConfigs:r,specSelectors:s,fn:o,producesValue:l,displayRequestDuration:i,specPath:c,path:u,method:d,oas3Selectors:p,oas3Actions:f}=this.props,g=(0,x.iQ)(t);const y=n("contentType"),v=n("liveResponse"),E=n("response");let S=this.props.produces&&this.props.produces.size?this.props.produces:$e.defaultProps.produces;const w=s.isOAS3()?(0,x.QG)(t):null,b=function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:"_";return e.replace(/[^\w-]/g,t)}(`${d}${u}_responses`),_=`${b}_select`;return h.default.createElement("div",{className:"responses-wrapper"},h.default.createElement("div",{className:"opblock-section-header"},h.default.createElement("h4",null,"Responses"),s.isOAS3()?null:h.default.createElement("label",{htmlFor:_},h.default.createElement("span",null,"Response content type"),h.default.createElement(y,{value:l,ariaControls:b,ariaLabel:"Response content type",className:"execute-content-type",contentTypes:S,controlId:_,onChange:this.onChangeProducesWrapper}))),h.default.createElement("div",{className:"responses-inner"},a?h.default.createElement("div",null,h.default.createElement(v,{response:a,getComponent:n,getConfigs:r,specSelectors:s,path:this.props.path,method:this.props.method,displayRequestDuration:i}),h.default.createElement("h4",null,"Responses")):null,h.default.createElement("table",{"aria-live":"polite",className:"responses-table",id:b,role:"region"},h.default.createElement("thead",null,h.default.createElement("tr",{className:"responses-header"},h.default.createElement("td",{className:"col_header response-col_status"},"Code"),h.default.createElement("td",{className:"col_header response-col_description"},"Description"),s.isOAS3()?h.default.createElement("td",{className:"col col_header response-col_links"},"Links"):null)),h.default.createElement("tbody",null,(0,m.default)(e=t.entrySeq()).call(e,(e=>{let[t,i]=e,m=a&&a.get("status")==t?"response_current":"";return h.default.createElement(E,{key:t,path:u,method:d,specPath:c.push(t),isDefault:g===t,fn:o,className:m,code:t,response:i,specSelectors:s,controlsAcceptHeader:i===w,onContentTypeChange:this.onResponseContentTypeChange,contentType:l,getConfigs:r,activeExamplesKey:p.activeExamplesMember(u,d,"responses",t),oas3Actions:f,getComponent:n})})).toArray()))))}}(0,W.default)($e,"defaultProps",{tryItOutResponse:null,produces:(0,y.fromJS)(["application/json"]),displayRequestDuration:!1});const Ve=(e=>{var t={};return ut.d(t,e),t})({default:()=>rt.default});var Ke=ut(2518);class Fe extends h.default.Component{constructor(e,t){super(e,t),(0,W.default)(this,"_onContentTypeChange",(e=>{const{onContentTypeChange:t,controlsAcceptHeader:a}=this.props;this.setState({responseContentType:e}),t({value:e,controlsAcceptHeader:a})})),(0,W.default)(this,"getTargetExamplesKey",(()=>{const{response:e,contentType:t,activeExamplesKey:a}=this.props,n=this.state.responseContentType||t,r=e.getIn(["content",n],(0,y.Map)({})).get("examples",null).keySeq().first();return a||r})),this.state={responseContentType:""}}render(){var e,t;let{path:a,method:n,code:r,response:s,className:o,specPath:l,fn:i,getComponent:c,getConfigs:u,specSelectors:d,contentType:p,controlsAcceptHeader:f,oas3Actions:g}=this.props,{inferSchema:v,getSampleSchema:E}=i,S=d.isOAS3();const{showExtensions:w}=u();let b=w?(0,x.nX)(s):null,_=s.get("headers"),C=s.get("links");const j=c("ResponseExtension"),N=c("headers"),O=c("highlightCode"),k=c("modelExample"),A=c("Markdown",!0),I=c("operationLink"),R=c("contentType"),P=c("ExamplesSelect"),T=c("Example");var M,D;const L=this.state.responseContentType||p,q=s.getIn(["content",L],(0,y.Map)({})),B=q.get("examples",null);if(S){const e=q.get("schema");M=e?v(e.toJS()):null,D=e?(0,y.List)(["content",this.state.responseContentType,"schema"]):l}else M=s.get("schema"),D=s.has("schema")?l.push("schema"):l;let U,J,$=!1,V={includeReadOnly:!0};if(S){var K;if(J=null===(K=q.get("schema"))||void 0===K?void 0:K.toJS(),B){const e=this.getTargetExamplesKey(),t=e=>e.get("value");U=t(B.get(e,(0,y.Map)({}))),void 0===U&&(U=t((0,Ve.default)(B).call(B).next().value)),$=!0}else void 0!==q.get("example")&&(U=q.get("example"),$=!0)}else{J=M,V={...V,includeWriteOnly:!0};const e=s.getIn(["examples",L]);e&&(U=e,$=!0)}let F=((e,t,a)=>{if(null!=e){let n=null;return(0,Ke.O)(e)&&(n="json"),h.default.createElement("div",null,h.default.createElement(t,{className:"example",getConfigs:a,language:n,value:(0,x.Pz)(e)}))}return null})(E(J,L,V,$?U:void 0),O,u);return h.default.createElement("tr",{className:"response "+(o||""),"data-code":r},h.default.createElement("td",{className:"response-col_status"},r),h.default.createElement("td",{className:"response-col_description"},h.default.createElement("div",{className:"response-col_description__inner"},h.default.createElement(A,{source:s.get("description")})),w&&b.size?(0,m.default)(e=b.entrySeq()).call(e,(e=>{let[t,a]=e;return h.default.createElement(j,{key:`${t}-${a}`,xKey:t,xVal:a})})):null,S&&s.get("content")?h.default.createElement("section",{className:"response-controls"},h.default.createElement("div",{className:(0,Te.default)("response-control-media-type",{"response-control-media-type--accept-controller":f})},h.default.createElement("small",{className:"response-control-media-type__title"},"Media type"),h.default.createElement(R,{value:this.state.responseContentType,contentTypes:s.get("content")?s.get("content").keySeq():(0,y.Seq)(),onChange:this._onContentTypeChange,ariaLabel:"Media Type"}),f?h.default.createElement("small",{className:"response-control-media-type__accept-message"},"Controls ",h.default.createElement("code",null,"Accept")," header."):null),B?h.default.createElement("div",{className:"response-control-examples"},h.default.createElement("small",{className:"response-control-examples__title"},"Examples"),h.default.createElement(P,{examples:B,currentExampleKey:this.getTargetExamplesKey(),onSelect:e=>g.setActiveExamplesMember({name:e,pathMethod:[a,n],contextType:"responses",contextName:r}),showLabels:!1})):null):null,F||M?h.default.createElement(k,{specPath:D,getComponent:c,getConfigs:u,specSelectors:d,schema:(0,x.oG)(M),example:F,includeReadOnly:!0}):null,S&&B?h.default.createElement(T,{example:B.get(this.getTargetExamplesKey(),(0,y.Map)({})),getComponent:c,getConfigs:u,omitValue:!0}):null,_?h.default.createElement(N,{headers:_,getComponent:c}):null),S?h.default.createElement("td",{className:"response-col_links"},C?(0,m.default)(t=C.toSeq().entrySeq()).call(t,(e=>{let[t,a]=e;return h.default.createElement(I,{key:t,name:t,link:a,getComponent:c})})):h.default.createElement("i",null,"No links")):null)}}(0,W.default)(Fe,"defaultProps",{response:(0,y.fromJS)({}),onContentTypeChange:()=>{}});const ze=e=>{let{xKey:t,xVal:a}=e;return h.default.createElement("div",{className:"response__extension"},t,": ",String(a))};var We=ut(3769);const He=(e=>{var t={};return ut.d(t,e),t})({default:()=>st.default});const Ge=(e=>{var t={};return ut.d(t,e),t})({default:()=>ot.default});class Ze extends h.default.PureComponent{constructor(){super(...arguments),(0,W.default)(this,"state",{parsedContent:null}),(0,W.default)(this,"updateParsedContent",(e=>{const{content:t}=this.props;if(e!==t)if(t&&t instanceof Blob){var a=new FileReader;a.onload=()=>{this.setState({parsedContent:a.result})},a.readAsText(t)}else this.setState({parsedContent:t.toString()})}))}componentDidMount(){this.updateParsedContent(null)}componentDidUpdate(e){this.updateParsedContent(e.content)}render(){let{content:e,contentType:t,url:n,headers:r={},getConfigs:o,getComponent:l}=this.props;const{parsedContent:i}=this.state,c=l("highlightCode"),u="response_"+(new Date).getTime();let d,p;if(n=n||"",(/^application\/octet-stream/i.test(t)||r["Content-Disposition"]&&/attachment/i.test(r["Content-Disposition"])||r["content-disposition"]&&/attachment/i.test(r["content-disposition"])||r["Content-Description"]&&/File Transfer/i.test(r["Content-Description"])||r["content-description"]&&/File Transfer/i.test(r["content-description"]))&&e.size>0)if("Blob"in window){let s=t||"text/html",o=e instanceof Blob?e:new Blob([e],{type:s}),l=We.default.createObjectURL(o),i=[s,n.substr((0,a.default)(n).call(n,"/")+1),l].join(":"),c=r["content-disposition"]||r["Content-Disposition"];if(void 0!==c){let e=(0,x.DR)(c);null!==e&&(i=e)}p=b.Z.navigator&&b.Z.navigator.msSaveOrOpenBlob?h.default.createElement("div",null,h.default.createElement("a",{href:l,onClick:()=>b.Z.navigator.msSaveOrOpenBlob(o,i)},"Download file")):h.default.createElement("div",null,h.default.createElement("a",{href:l,download:i},"Download file"))}else p=h.default.createElement("pre",{className:"microlight"},"Download headers detected but your browser does not support downloading binary via XHR (Blob).");else if(/json/i.test(t)){let t=null;(0,Ke.O)(e)&&(t="json");try{d=(0,s.default)(JSON.parse(e),null,"  ")}catch(t){d="can't parse JSON.  Raw result:\n\n"+e}p=h.default.createElement(c,{language:t,downloadable:!0,fileName:`${u}.json`,value:d,getConfigs:o,canCopy:!0})}else/xml/i.test(t)?(d=(0,He.default)(e,{textNodesOnSameLine:!0,indentor:"  "}),p=h.default.createElement(c,{downloadable:!0,fileName:`${u}.xml`,value:d,getConfigs:o,canCopy:!0})):p="text/html"===(0,Ge.default)(t)||/text\/plain/.test(t)?h.default.createElement(c,{downloadable:!0,fileName:`${u}.html`,value:e,getConfigs:o,canCopy:!0}):"text/csv"===(0,Ge.default)(t)||/text\/csv/.test(t)?h.default.createElement(c,{downloadable:!0,fileName:`${u}.csv`,value:e,getConfigs:o,canCopy:!0}):/^image\//i.test(t)?(0,fe.default)(t).call(t,"svg")?h.default.createElement("div",null," ",e," "):h.default.createElement("img",{src:We.default.createObjectURL(e)}):/^audio\//i.test(t)?h.default.createElement("pre",{className:"microlight"},h.default.createElement("audio",{controls:!0,key:n},h.default.createElement("source",{src:n,type:t}))):"string"==typeof e?h.default.createElement(c,{downloadable:!0,fileName:`${u}.txt`,value:e,getConfigs:o,canCopy:!0}):e.size>0?i?h.default.createElement("div",null,h.default.createElement("p",{className:"i"},"Unrecognized response type; displaying content as text."),h.default.createElement(c,{downloadable:!0,fileName:`${u}.txt`,value:i,getConfigs:o,canCopy:!0})):h.default.createElement("p",{className:"i"},"Unrecognized response type; unable to display."):null;return p?h.default.createElement("div",null,h.default.createElement("h5",null,"Response body"),p):null}}var Ye=ut(374);class it extends h.Component{constructor(e){super(e),(0,W.default)(this,"onChange",((e,t,a)=>{let{specActions:{changeParamByIdentity:n},onChangeKey:r}=this.props;n(r,e,t,a)})),(0,W.default)(this,"onChangeConsumesWrapper",(e=>{let{specActions:{changeConsumesValue:t},onChangeKey:a}=this.props;t(a,e)})),(0,W.default)(this,"toggleTab",(e=>"parameters"===e?this.setState({parametersVisible:!0,callbackVisible:!1}):"callbacks"===e?this.setState({callbackVisible:!0,parametersVisible:!1}):void 0)),(0,W.default)(this,"onChangeMediaType",(e=>{let{value:t,pathMethod:a}=e,{specActions:n,oas3Selectors:r,oas3Actions:s}=this.props;const o=r.hasUserEditedBody(...a),l=r.shouldRetainRequestBodyValue(...a);s.setRequestContentType({value:t,pathMethod:a}),s.initRequestBodyValidateError({pathMethod:a}),o||(l||s.setRequestBodyValue({value:void 0,pathMethod:a}),n.clearResponse(...a),n.clearRequest(...a),n.clearValidateParams(a))})),this.state={callbackVisible:!1,parametersVisible:!0}}render(){var e;let{onTryoutClick:t,onResetClick:a,parameters:n,allowTryItOut:r,tryItOutEnabled:s,specPath:o,fn:l,getComponent:i,getConfigs:c,specSelectors:d,specActions:g,pathMethod:v,oas3Actions:E,oas3Selectors:S,operation:w}=this.props;const b=i("parameterRow"),x=i("TryItOutButton"),_=i("contentType"),C=i("Callbacks",!0),j=i("RequestBody",!0),N=s&&r,O=d.isOAS3(),k=w.get("requestBody"),A=(0,p.default)(e=(0,Ye.default)((0,p.default)(n).call(n,((e,t)=>{const a=t.get("in");return e[a]??(e[a]=[]),e[a].push(t),e}),{}))).call(e,((e,t)=>(0,f.default)(e).call(e,t)),[]);return h.default.createElement("div",{className:"opblock-section"},h.default.createElement("div",{className:"opblock-section-header"},O?h.default.createElement("div",{className:"tab-header"},h.default.createElement("div",{onClick:()=>this.toggleTab("parameters"),className:`tab-item ${this.state.parametersVisible&&"active"}`},h.default.createElement("h4",{className:"opblock-title"},h.default.createElement("span",null,"Parameters"))),w.get("callbacks")?h.default.createElement("div",{onClick:()=>this.toggleTab("callbacks"),className:`tab-item ${this.state.callbackVisible&&"active"}`},h.default.createElement("h4",{className:"opblock-title"},h.default.createElement("span",null,"Callbacks"))):null):h.default.createElement("div",{className:"tab-header"},h.default.createElement("h4",{className:"opblock-title"},"Parameters")),r?h.default.createElement(x,{isOAS3:d.isOAS3(),hasUserEditedBody:S.hasUserEditedBody(...v),enabled:s,onCancelClick:this.props.onCancelClick,onTryoutClick:t,onResetClick:()=>a(v)}):null),this.state.parametersVisible?h.default.createElement("div",{className:"parameters-container"},A.length?h.default.createElement("div",{className:"table-container"},h.default.createElement("table",{className:"parameters"},h.default.createElement("thead",null,h.default.createElement("tr",null,h.default.createElement("th",{className:"col_header parameters-col_name"},"Name"),h.default.createElement("th",{className:"col_header parameters-col_description"},"Description"))),h.default.createElement("tbody",null,(0,m.default)(A).call(A,((e,t)=>h.default.createElement(b,{fn:l,specPath:o.push(t.toString()),getComponent:i,getConfigs:c,rawParam:e,param:d.parameterWithMetaByIdentity(v,e),key:`${e.get("in")}.${e.get("name")}`,onChange:this.onChange,onChangeConsumes:this.onChangeConsumesWrapper,specSelectors:d,specActions:g,oas3Actions:E,oas3Selectors:S,pathMethod:v,isExecute:N})))))):h.default.createElement("div",{className:"opblock-description-wrapper"},h.default.createElement("p",null,"No parameters"))):null,this.state.callbackVisible?h.default.createElement("div",{className:"callbacks-container opblock-description-wrapper"},h.default.createElement(C,{callbacks:(0,y.Map)(w.get("callbacks")),specPath:(0,u.default)(o).call(o,0,-1).push("callbacks")})):null,O&&k&&this.state.parametersVisible&&h.default.createElement("div",{className:"opblock-section opblock-section-request-body"},h.default.createElement("div",{className:"opblock-section-header"},h.default.createElement("h4",{className:`opblock-title parameter__name ${k.get("required")&&"required"}`},"Request body"),h.default.createElement("label",null,h.default.createElement(_,{value:S.requestContentType(...v),contentTypes:k.get("content",(0,y.List)()).keySeq(),onChange:e=>{this.onChangeMediaType({value:e,pathMethod:v})},className:"body-param-content-type",ariaLabel:"Request content type"}))),h.default.createElement("div",{className:"opblock-description-wrapper"},h.default.createElement(j,{setRetainRequestBodyValueFlag:e=>E.setRetainRequestBodyValueFlag({value:e,pathMethod:v}),userHasEditedBody:S.hasUserEditedBody(...v),specPath:(0,u.default)(o).call(o,0,-1).push("requestBody"),requestBody:k,requestBodyValue:S.requestBodyValue(...v),requestBodyInclusionSetting:S.requestBodyInclusionSetting(...v),requestBodyErrors:S.requestBodyErrors(...v),isExecute:N,getConfigs:c,activeExamplesKey:S.activeExamplesMember(...v,"requestBody","requestBody"),updateActiveExamplesKey:e=>{this.props.oas3Actions.setActiveExamplesMember({name:e,pathMethod:this.props.pathMethod,contextType:"requestBody",contextName:"requestBody"})},onChange:(e,t)=>{if(t){const a=S.requestBodyValue(...v),n=y.Map.isMap(a)?a:(0,y.Map)();return E.setRequestBodyValue({pathMethod:v,value:n.setIn(t,e)})}E.setRequestBodyValue({value:e,pathMethod:v})},onChangeIncludeEmpty:(e,t)=>{E.setRequestBodyInclusion({pathMethod:v,value:t,name:e})},contentType:S.requestContentType(...v)}))))}}(0,W.default)(it,"defaultProps",{onTryoutClick:Function.prototype,onCancelClick:Function.prototype,tryItOutEnabled:!1,allowTryItOut:!0,onChangeKey:[],specPath:[]});const ct=e=>{let{xKey:t,xVal:a}=e;return h.default.createElement("div",{className:"parameter__extension"},t,": ",String(a))},pt={onChange:()=>{},isIncludedOptions:{}};class mt extends h.Component{constructor(){super(...arguments),(0,W.default)(this,"onCheckboxChange",(e=>{const{onChange:t}=this.props;t(e.target.checked)}))}componentDidMount(){const{isIncludedOptions:e,onChange:t}=this.props,{shouldDispatchInit:a,defaultValue:n}=e;a&&t(n)}render(){let{isIncluded:e,isDisabled:t}=this.props;return h.default.createElement("div",null,h.default.createElement("label",{className:(0,Te.default)("parameter__empty_value_toggle",{disabled:t})},h.default.createElement("input",{type:"checkbox",disabled:t,checked:!t&&e,onChange:this.onCheckboxChange}),"Send empty value"))}}(0,W.default)(mt,"defaultProps",pt);var ft=ut(9069);class ht extends h.Component{constructor(e,t){var a;super(e,t),a=this,(0,W.default)(this,"onChangeWrapper",(function(e){let t,n=arguments.length>1&&void 0!==arguments[1]&&arguments[1],{onChange:r,rawParam:s}=a.props;return t=""===e||e&&0===e.size?null:e,r(s,t,n)})),(0,W.default)(this,"_onExampleSelect",(e=>{this.props.oas3Actions.setActiveExamplesMember({name:e,pathMethod:this.props.pathMethod,contextType:"parameters",contextName:this.getParamKey()})})),(0,W.default)(this,"onChangeIncludeEmpty",(e=>{let{specActions:t,param:a,pathMethod:n}=this.props;const r=a.get("name"),s=a.get("in");return t.updateEmptyParamInclusion(n,r,s,e)})),(0,W.default)(this,"setDefaultValue",(()=>{let{specSelectors:e,pathMethod:t,rawParam:a,oas3Selectors:n,fn:r}=this.props;const s=e.parameterWithMetaByIdentity(t,a)||(0,y.Map)(),{schema:o}=(0,ft.Z)(s,{isOAS3:e.isOAS3()}),l=s.get("content",(0,y.Map)()).keySeq().first(),i=o?r.getSampleSchema(o.toJS(),l,{includeWriteOnly:!0}):null;if(s&&void 0===s.get("value")&&"body"!==s.get("in")){let a;if(e.isSwagger2())a=void 0!==s.get("x-example")?s.get("x-example"):void 0!==s.getIn(["schema","example"])?s.getIn(["schema","example"]):o&&o.getIn(["default"]);else if(e.isOAS3()){const e=n.activeExamplesMember(...t,"parameters",this.getParamKey());a=void 0!==s.getIn(["examples",e,"value"])?s.getIn(["examples",e,"value"]):void 0!==s.getIn(["content",l,"example"])?s.getIn(["content",l,"example"]):void 0!==s.get("example")?s.get("example"):void 0!==(o&&o.get("example"))?o&&o.get("example"):void 0!==(o&&o.get("default"))?o&&o.get("default"):s.get("default")}void 0===a||y.List.isList(a)||(a=(0,x.Pz)(a)),void 0!==a?this.onChangeWrapper(a):o&&"object"===o.get("type")&&i&&!s.get("examples")&&this.onChangeWrapper(y.List.isList(i)?i:(0,x.Pz)(i))}})),this.setDefaultValue()}UNSAFE_componentWillReceiveProps(e){let t,{specSelectors:a,pathMethod:n,rawParam:r}=e,s=a.isOAS3(),o=a.parameterWithMetaByIdentity(n,r)||new y.Map;if(o=o.isEmpty()?r:o,s){let{schema:e}=(0,ft.Z)(o,{isOAS3:s});t=e?e.get("enum"):void 0}else t=o?o.get("enum"):void 0;let l,i=o?o.get("value"):void 0;void 0!==i?l=i:r.get("required")&&t&&t.size&&(l=t.first()),void 0!==l&&l!==i&&this.onChangeWrapper((0,x.D$)(l)),this.setDefaultValue()}getParamKey(){const{param:e}=this.props;return e?`${e.get("name")}-${e.get("in")}`:null}render(){var e,t;let{param:a,rawParam:n,getComponent:r,getConfigs:s,isExecute:o,fn:l,onChangeConsumes:i,specSelectors:c,pathMethod:u,specPath:d,oas3Selectors:p}=this.props,f=c.isOAS3();const{showExtensions:g,showCommonExtensions:v}=s();if(a||(a=n),!n)return null;const E=r("JsonSchemaForm"),S=r("ParamBody");let w=a.get("in"),_="body"!==w?null:h.default.createElement(S,{getComponent:r,getConfigs:s,fn:l,param:a,consumes:c.consumesOptionsFor(u),consumesValue:c.contentTypeValues(u).get("requestContentType"),onChange:this.onChangeWrapper,onChangeConsumes:i,isExecute:o,specSelectors:c,pathMethod:u});const C=r("modelExample"),j=r("Markdown",!0),N=r("ParameterExt"),O=r("ParameterIncludeEmpty"),k=r("ExamplesSelectValueRetainer"),A=r("Example");let I,R,P,T,{schema:M}=(0,ft.Z)(a,{isOAS3:f}),D=c.parameterWithMetaByIdentity(u,n)||(0,y.Map)(),L=M?M.get("format"):null,q=M?M.get("type"):null,B=M?M.getIn(["items","type"]):null,U="formData"===w,J="FormData"in b.Z,$=a.get("required"),V=D?D.get("value"):"",K=v?(0,x.po)(M):null,F=g?(0,x.nX)(a):null,z=!1;return void 0!==a&&M&&(I=M.get("items")),void 0!==I?(R=I.get("enum"),P=I.get("default")):M&&(R=M.get("enum")),R&&R.size&&R.size>0&&(z=!0),void 0!==a&&(M&&(P=M.get("default")),void 0===P&&(P=a.get("default")),T=a.get("example"),void 0===T&&(T=a.get("x-example"))),h.default.createElement("tr",{"data-param-name":a.get("name"),"data-param-in":a.get("in")},h.default.createElement("td",{className:"parameters-col_name"},h.default.createElement("div",{className:$?"parameter__name required":"parameter__name"},a.get("name"),$?h.default.createElement("span",null," *"):null),h.default.createElement("div",{className:"parameter__type"},q,B&&`[${B}]`,L&&h.default.createElement("span",{className:"prop-format"},"($",L,")")),h.default.createElement("div",{className:"parameter__deprecated"},f&&a.get("deprecated")?"deprecated":null),h.default.createElement("div",{className:"parameter__in"},"(",a.get("in"),")"),v&&K.size?(0,m.default)(e=K.entrySeq()).call(e,(e=>{let[t,a]=e;return h.default.createElement(N,{key:`${t}-${a}`,xKey:t,xVal:a})})):null,g&&F.size?(0,m.default)(t=F.entrySeq()).call(t,(e=>{let[t,a]=e;return h.default.createElement(N,{key:`${t}-${a}`,xKey:t,xVal:a})})):null),h.default.createElement("td",{className:"parameters-col_description"},a.get("description")?h.default.createElement(j,{source:a.get("description")}):null,!_&&o||!z?null:h.default.createElement(j,{className:"parameter__enum",source:"<i>Available values</i> : "+(0,m.default)(R).call(R,(function(e){return e})).toArray().join(", ")}),!_&&o||void 0===P?null:h.default.createElement(j,{className:"parameter__default",source:"<i>Default value</i> : "+P}),!_&&o||void 0===T?null:h.default.createElement(j,{source:"<i>Example</i> : "+T}),U&&!J&&h.default.createElement("div",null,"Error: your browser does not support FormData"),f&&a.get("examples")?h.default.createElement("section",{className:"parameter-controls"},h.default.createElement(k,{examples:a.get("examples"),onSelect:this._onExampleSelect,updateValue:this.onChangeWrapper,getComponent:r,defaultToFirstExample:!0,currentKey:p.activeExamplesMember(...u,"parameters",this.getParamKey()),currentUserInputValue:V})):null,_?null:h.default.createElement(E,{fn:l,getComponent:r,value:V,required:$,disabled:!o,description:a.get("name"),onChange:this.onChangeWrapper,errors:D.get("errors"),schema:M}),_&&M?h.default.createElement(C,{getComponent:r,specPath:d.push("schema"),getConfigs:s,isExecute:o,specSelectors:c,schema:M,example:_,includeWriteOnly:!0}):null,!_&&o&&a.get("allowEmptyValue")?h.default.createElement(O,{onChange:this.onChangeIncludeEmpty,isIncluded:c.parameterInclusionSettingFor(u,a.get("name"),a.get("in")),isDisabled:!(0,x.O2)(V)}):null,f&&a.get("examples")?h.default.createElement(A,{example:a.getIn(["examples",p.activeExamplesMember(...u,"parameters",this.getParamKey())]),getComponent:r,getConfigs:s}):null))}}var gt=ut(6235);class yt extends h.Component{constructor(){super(...arguments),(0,W.default)(this,"handleValidateParameters",(()=>{let{specSelectors:e,specActions:t,path:a,method:n}=this.props;return t.validateParams([a,n]),e.validateBeforeExecute([a,n])})),(0,W.default)(this,"handleValidateRequestBody",(()=>{let{path:e,method:t,specSelectors:a,oas3Selectors:n,oas3Actions:r}=this.props,s={missingBodyValue:!1,missingRequiredKeys:[]};r.clearRequestBodyValidateError({path:e,method:t});let o=a.getOAS3RequiredRequestBodyContentType([e,t]),l=n.requestBodyValue(e,t),i=n.validateBeforeExecute([e,t]),c=n.requestContentType(e,t);if(!i)return s.missingBodyValue=!0,r.setRequestBodyValidateError({path:e,method:t,validationErrors:s}),!1;if(!o)return!0;let u=n.validateShallowRequired({oas3RequiredRequestBodyContentType:o,oas3RequestContentType:c,oas3RequestBodyValue:l});return!u||u.length<1||((0,Pe.default)(u).call(u,(e=>{s.missingRequiredKeys.push(e)})),r.setRequestBodyValidateError({path:e,method:t,validationErrors:s}),!1)})),(0,W.default)(this,"handleValidationResultPass",(()=>{let{specActions:e,operation:t,path:a,method:n}=this.props;this.props.onExecute&&this.props.onExecute(),e.execute({operation:t,path:a,method:n})})),(0,W.default)(this,"handleValidationResultFail",(()=>{let{specActions:e,path:t,method:a}=this.props;e.clearValidateParams([t,a]),(0,gt.default)((()=>{e.validateParams([t,a])}),40)})),(0,W.default)(this,"handleValidationResult",(e=>{e?this.handleValidationResultPass():this.handleValidationResultFail()})),(0,W.default)(this,"onClick",(()=>{let e=this.handleValidateParameters(),t=this.handleValidateRequestBody(),a=e&&t;this.handleValidationResult(a)})),(0,W.default)(this,"onChangeProducesWrapper",(e=>this.props.specActions.changeProducesValue([this.props.path,this.props.method],e)))}render(){const{disabled:e}=this.props;return h.default.createElement("button",{className:"btn execute opblock-control__btn",onClick:this.onClick,disabled:e},"Execute")}}class vt extends h.default.Component{render(){var e;let{headers:t,getComponent:a}=this.props;const n=a("Property"),r=a("Markdown",!0);return t&&t.size?h.default.createElement("div",{className:"headers-wrapper"},h.default.createElement("h4",{className:"headers__title"},"Headers:"),h.default.createElement("table",{className:"headers"},h.default.createElement("thead",null,h.default.createElement("tr",{className:"header-row"},h.default.createElement("th",{className:"header-col"},"Name"),h.default.createElement("th",{className:"header-col"},"Description"),h.default.createElement("th",{className:"header-col"},"Type"))),h.default.createElement("tbody",null,(0,m.default)(e=t.entrySeq()).call(e,(e=>{let[t,a]=e;if(!y.default.Map.isMap(a))return null;const s=a.get("description"),o=a.getIn(["schema"])?a.getIn(["schema","type"]):a.getIn(["type"]),l=a.getIn(["schema","example"]);return h.default.createElement("tr",{key:t},h.default.createElement("td",{className:"header-col"},t),h.default.createElement("td",{className:"header-col"},s?h.default.createElement(r,{source:s}):null),h.default.createElement("td",{className:"header-col"},o," ",l?h.default.createElement(n,{propKey:"Example",propVal:l,propClass:"header-example"}):null))})).toArray()))):null}}class Et extends h.default.Component{render(){let{editorActions:e,errSelectors:t,layoutSelectors:a,layoutActions:r,getComponent:s}=this.props;const o=s("Collapse");if(e&&e.jumpToLine)var l=e.jumpToLine;let i=t.allErrors(),c=(0,n.default)(i).call(i,(e=>"thrown"===e.get("type")||"error"===e.get("level")));if(!c||c.count()<1)return null;let u=a.isShown(["errorPane"],!0),d=c.sortBy((e=>e.get("line")));return h.default.createElement("pre",{className:"errors-wrapper"},h.default.createElement("hgroup",{className:"error"},h.default.createElement("h4",{className:"errors__title"},"Errors"),h.default.createElement("button",{className:"btn errors__clear-btn",onClick:()=>r.show(["errorPane"],!u)},u?"Hide":"Show")),h.default.createElement(o,{isOpened:u,animated:!0},h.default.createElement("div",{className:"errors"},(0,m.default)(d).call(d,((e,t)=>{let a=e.get("type");return"thrown"===a||"auth"===a?h.default.createElement(St,{key:t,error:e.get("error")||e,jumpToLine:l}):"spec"===a?h.default.createElement(wt,{key:t,error:e,jumpToLine:l}):void 0})))))}}const St=e=>{let{error:t,jumpToLine:a}=e;if(!t)return null;let n=t.get("line");return h.default.createElement("div",{className:"error-wrapper"},t?h.default.createElement("div",null,h.default.createElement("h4",null,t.get("source")&&t.get("level")?bt(t.get("source"))+" "+t.get("level"):"",t.get("path")?h.default.createElement("small",null," at ",t.get("path")):null),h.default.createElement("span",{className:"message thrown"},t.get("message")),h.default.createElement("div",{className:"error-line"},n&&a?h.default.createElement("a",{onClick:(0,i.default)(a).call(a,null,n)},"Jump to line ",n):null)):null)},wt=e=>{let{error:t,jumpToLine:a}=e,n=null;return t.get("path")?n=y.List.isList(t.get("path"))?h.default.createElement("small",null,"at ",t.get("path").join(".")):h.default.createElement("small",null,"at ",t.get("path")):t.get("line")&&!a&&(n=h.default.createElement("small",null,"on line ",t.get("line"))),h.default.createElement("div",{className:"error-wrapper"},t?h.default.createElement("div",null,h.default.createElement("h4",null,bt(t.get("source"))+" "+t.get("level")," ",n),h.default.createElement("span",{className:"message"},t.get("message")),h.default.createElement("div",{className:"error-line"},a?h.default.createElement("a",{onClick:(0,i.default)(a).call(a,null,t.get("line"))},"Jump to line ",t.get("line")):null)):null)};function bt(e){var t;return(0,m.default)(t=(e||"").split(" ")).call(t,(e=>e[0].toUpperCase()+(0,u.default)(e).call(e,1))).join(" ")}St.defaultProps={jumpToLine:null};class xt extends h.default.Component{constructor(){super(...arguments),(0,W.default)(this,"onChangeWrapper",(e=>this.props.onChange(e.target.value)))}componentDidMount(){this.props.contentTypes&&this.props.onChange(this.props.contentTypes.first())}UNSAFE_componentWillReceiveProps(e){var t;e.contentTypes&&e.contentTypes.size&&((0,fe.default)(t=e.contentTypes).call(t,e.value)||e.onChange(e.contentTypes.first()))}render(){let{ariaControls:e,ariaLabel:t,className:a,contentTypes:n,controlId:r,value:s}=this.props;return n&&n.size?h.default.createElement("div",{className:"content-type-wrapper "+(a||"")},h.default.createElement("select",{"aria-controls":e,"aria-label":t,className:"content-type",id:r,onChange:this.onChangeWrapper,value:s||""},(0,m.default)(n).call(n,(e=>h.default.createElement("option",{key:e,value:e},e))).toArray())):null}}(0,W.default)(xt,"defaultProps",{onChange:()=>{},value:null,contentTypes:(0,y.fromJS)(["application/json"])});var _t=ut(863),Ct=ut(5942);function jt(){for(var e,t=arguments.length,a=new Array(t),r=0;r<t;r++)a[r]=arguments[r];return(0,Ct.default)(e=(0,n.default)(a).call(a,(e=>!!e)).join(" ")).call(e)}class Nt extends h.default.Component{render(){let{fullscreen:e,full:t,...a}=this.props;if(e)return h.default.createElement("section",a);let n="swagger-container"+(t?"-full":"");return h.default.createElement("section",(0,_t.default)({},a,{className:jt(a.className,n)}))}}const Ot={mobile:"",tablet:"-tablet",desktop:"-desktop",large:"-hd"};class kt extends h.default.Component{render(){const{hide:e,keepContents:t,mobile:a,tablet:n,desktop:r,large:s,...o}=this.props;if(e&&!t)return h.default.createElement("span",null);let l=[];for(let e in Ot){if(!Object.prototype.hasOwnProperty.call(Ot,e))continue;let t=Ot[e];if(e in this.props){let a=this.props[e];if(a<1){l.push("none"+t);continue}l.push("block"+t),l.push("col-"+a+t)}}e&&l.push("hidden");let i=jt(o.className,...l);return h.default.createElement("section",(0,_t.default)({},o,{className:i}))}}class At extends h.default.Component{render(){return h.default.createElement("div",(0,_t.default)({},this.props,{className:jt(this.props.className,"wrapper")}))}}class It extends h.default.Component{render(){return h.default.createElement("button",(0,_t.default)({},this.props,{className:jt(this.props.className,"button")}))}}(0,W.default)(It,"defaultProps",{className:""});const Rt=e=>h.default.createElement("textarea",e),Pt=e=>h.default.createElement("input",e);class Tt extends h.default.Component{constructor(e,t){let a;super(e,t),(0,W.default)(this,"onChange",(e=>{let t,{onChange:a,multiple:r}=this.props,s=(0,u.default)([]).call(e.target.options);var o;r?t=(0,m.default)(o=(0,n.default)(s).call(s,(function(e){return e.selected}))).call(o,(function(e){return e.value})):t=e.target.value;this.setState({value:t}),a&&a(t)})),a=e.value?e.value:e.multiple?[""]:"",this.state={value:a}}UNSAFE_componentWillReceiveProps(e){e.value!==this.props.value&&this.setState({value:e.value})}render(){var e,t;let{allowedValues:a,multiple:n,allowEmptyValue:r,disabled:s}=this.props,o=(null===(e=this.state.value)||void 0===e||null===(t=e.toJS)||void 0===t?void 0:t.call(e))||this.state.value;return h.default.createElement("select",{className:this.props.className,multiple:n,value:o,onChange:this.onChange,disabled:s},r?h.default.createElement("option",{value:""},"--"):null,(0,m.default)(a).call(a,(function(e,t){return h.default.createElement("option",{key:t,value:String(e)},String(e))})))}}(0,W.default)(Tt,"defaultProps",{multiple:!1,allowEmptyValue:!0});class Mt extends h.default.Component{render(){return h.default.createElement("a",(0,_t.default)({},this.props,{rel:"noopener noreferrer",className:jt(this.props.className,"link")}))}}const Dt=e=>{let{children:t}=e;return h.default.createElement("div",{className:"no-margin"}," ",t," ")};class Lt extends h.default.Component{renderNotAnimated(){return this.props.isOpened?h.default.createElement(Dt,null,this.props.children):h.default.createElement("noscript",null)}render(){let{animated:e,isOpened:t,children:a}=this.props;return e?(a=t?a:null,h.default.createElement(Dt,null,a)):this.renderNotAnimated()}}(0,W.default)(Lt,"defaultProps",{isOpened:!1,animated:!1});class qt extends h.default.Component{constructor(){var e;super(...arguments),this.setTagShown=(0,i.default)(e=this._setTagShown).call(e,this)}_setTagShown(e,t){this.props.layoutActions.show(e,t)}showOp(e,t){let{layoutActions:a}=this.props;a.show(e,t)}render(){let{specSelectors:e,layoutSelectors:t,layoutActions:a,getComponent:n}=this.props,r=e.taggedOperations();const s=n("Collapse");return h.default.createElement("div",null,h.default.createElement("h4",{className:"overview-title"},"Overview"),(0,m.default)(r).call(r,((e,n)=>{let r=e.get("operations"),o=["overview-tags",n],l=t.isShown(o,!0);return h.default.createElement("div",{key:"overview-"+n},h.default.createElement("h4",{onClick:()=>a.show(o,!l),className:"link overview-tag"}," ",l?"-":"+",n),h.default.createElement(s,{isOpened:l,animated:!0},(0,m.default)(r).call(r,(e=>{let{path:n,method:r,id:s}=e.toObject(),o="operations",l=s,i=t.isShown([o,l]);return h.default.createElement(Bt,{key:s,path:n,method:r,id:n+"-"+r,shown:i,showOpId:l,showOpIdPrefix:o,href:`#operation-${l}`,onClick:a.show})})).toArray()))})).toArray(),r.size<1&&h.default.createElement("h3",null," No operations defined in spec! "))}}class Bt extends h.default.Component{constructor(e){var t;super(e),this.onClick=(0,i.default)(t=this._onClick).call(t,this)}_onClick(){let{showOpId:e,showOpIdPrefix:t,onClick:a,shown:n}=this.props;a([t,e],!n)}render(){let{id:e,method:t,shown:a,href:n}=this.props;return h.default.createElement(Mt,{href:n,onClick:this.onClick,className:"block opblock-link "+(a?"shown":"")},h.default.createElement("div",null,h.default.createElement("small",{className:`bold-label-${t}`},t.toUpperCase()),h.default.createElement("span",{className:"bold-label"},e)))}}class Ut extends h.default.Component{componentDidMount(){this.props.initialValue&&(this.inputRef.value=this.props.initialValue)}render(){const{value:e,defaultValue:t,initialValue:a,...n}=this.props;return h.default.createElement("input",(0,_t.default)({},n,{ref:e=>this.inputRef=e}))}}class Jt extends h.default.Component{render(){const{host:e,basePath:t}=this.props;return h.default.createElement("pre",{className:"base-url"},"[ Base URL: ",e,t," ]")}}class $t extends h.default.PureComponent{render(){const{url:e,getComponent:t}=this.props,a=t("Link");return h.default.createElement(a,{target:"_blank",href:(0,x.Nm)(e)},h.default.createElement("span",{className:"url"}," ",e))}}class Vt extends h.default.Component{render(){const{info:e,url:t,host:a,basePath:n,getComponent:r,externalDocs:s,selectedServer:o,url:l}=this.props,i=e.get("version"),c=e.get("description"),u=e.get("title"),d=(0,xe.mn)(e.get("termsOfService"),l,{selectedServer:o}),p=e.get("contact"),m=e.get("license"),f=s&&s.get("url"),g=(0,xe.mn)(f,l,{selectedServer:o}),y=s&&s.get("description"),v=r("Markdown",!0),E=r("Link"),S=r("VersionStamp"),w=r("InfoUrl"),b=r("InfoBasePath"),_=r("License"),C=r("Contact");return h.default.createElement("div",{className:"info"},h.default.createElement("hgroup",{className:"main"},h.default.createElement("h2",{className:"title"},u,i&&h.default.createElement(S,{version:i})),a||n?h.default.createElement(b,{host:a,basePath:n}):null,t&&h.default.createElement(w,{getComponent:r,url:t})),h.default.createElement("div",{className:"description"},h.default.createElement(v,{source:c})),d&&h.default.createElement("div",{className:"info__tos"},h.default.createElement(E,{target:"_blank",href:(0,x.Nm)(d)},"Terms of service")),(null==p?void 0:p.size)>0&&h.default.createElement(C,{getComponent:r,data:p,selectedServer:o,url:t}),(null==m?void 0:m.size)>0&&h.default.createElement(_,{getComponent:r,license:m,selectedServer:o,url:t}),g?h.default.createElement(E,{className:"info__extdocs",target:"_blank",href:(0,x.Nm)(g)},y||g):null)}}const Kt=Vt;class Ft extends h.default.Component{render(){const{specSelectors:e,getComponent:t,oas3Selectors:a}=this.props,n=e.info(),r=e.url(),s=e.basePath(),o=e.host(),l=e.externalDocs(),i=a.selectedServer(),c=t("info");return h.default.createElement("div",null,n&&n.count()?h.default.createElement(c,{info:n,url:r,host:o,basePath:s,externalDocs:l,getComponent:t,selectedServer:i}):null)}}class zt extends h.default.Component{render(){const{data:e,getComponent:t,selectedServer:a,url:n}=this.props,r=e.get("name","the developer"),s=(0,xe.mn)(e.get("url"),n,{selectedServer:a}),o=e.get("email"),l=t("Link");return h.default.createElement("div",{className:"info__contact"},s&&h.default.createElement("div",null,h.default.createElement(l,{href:(0,x.Nm)(s),target:"_blank"},r," - Website")),o&&h.default.createElement(l,{href:(0,x.Nm)(`mailto:${o}`)},s?`Send email to ${r}`:`Contact ${r}`))}}const Wt=zt;class Ht extends h.default.Component{render(){const{license:e,getComponent:t,selectedServer:a,url:n}=this.props,r=e.get("name","License"),s=(0,xe.mn)(e.get("url"),n,{selectedServer:a}),o=t("Link");return h.default.createElement("div",{className:"info__license"},s?h.default.createElement("div",{className:"info__license__url"},h.default.createElement(o,{target:"_blank",href:(0,x.Nm)(s)},r)):h.default.createElement("span",null,r))}}const Gt=Ht;class Zt extends h.default.Component{render(){return null}}class Yt extends h.default.Component{render(){let{getComponent:e}=this.props;const t=e("CopyIcon");return h.default.createElement("div",{className:"view-line-link copy-to-clipboard",title:"Copy to clipboard"},h.default.createElement(Be.CopyToClipboard,{text:this.props.textToCopy},h.default.createElement(t,null)))}}class Xt extends h.default.Component{render(){return h.default.createElement("div",{className:"footer"})}}class Qt extends h.default.Component{constructor(){super(...arguments),(0,W.default)(this,"onFilterChange",(e=>{const{target:{value:t}}=e;this.props.layoutActions.updateFilter(t)}))}render(){const{specSelectors:e,layoutSelectors:t,getComponent:a}=this.props,n=a("Col"),r="loading"===e.loadingStatus(),s="failed"===e.loadingStatus(),o=t.currentFilter(),l=["operation-filter-input"];return s&&l.push("failed"),r&&l.push("loading"),h.default.createElement("div",null,null===o||!1===o||"false"===o?null:h.default.createElement("div",{className:"filter-container"},h.default.createElement(n,{className:"filter wrapper",mobile:12},h.default.createElement("input",{className:l.join(" "),placeholder:"Filter by tag",type:"text",onChange:this.onFilterChange,value:!0===o||"true"===o?"":o,disabled:r}))))}}const ea=Function.prototype;class ta extends h.PureComponent{constructor(e,t){super(e,t),(0,W.default)(this,"updateValues",(e=>{let{param:t,isExecute:a,consumesValue:n=""}=e,r=/xml/i.test(n),s=/json/i.test(n),o=r?t.get("value_xml"):t.get("value");if(void 0!==o){let e=!o&&s?"{}":o;this.setState({value:e}),this.onChange(e,{isXml:r,isEditBox:a})}else r?this.onChange(this.sample("xml"),{isXml:r,isEditBox:a}):this.onChange(this.sample(),{isEditBox:a})})),(0,W.default)(this,"sample",(e=>{let{param:t,fn:a}=this.props,n=a.inferSchema(t.toJS());return a.getSampleSchema(n,e,{includeWriteOnly:!0})})),(0,W.default)(this,"onChange",((e,t)=>{let{isEditBox:a,isXml:n}=t;this.setState({value:e,isEditBox:a}),this._onChange(e,n)})),(0,W.default)(this,"_onChange",((e,t)=>{(this.props.onChange||ea)(e,t)})),(0,W.default)(this,"handleOnChange",(e=>{const{consumesValue:t}=this.props,a=/xml/i.test(t),n=e.target.value;this.onChange(n,{isXml:a,isEditBox:this.state.isEditBox})})),(0,W.default)(this,"toggleIsEditBox",(()=>this.setState((e=>({isEditBox:!e.isEditBox}))))),this.state={isEditBox:!1,value:""}}componentDidMount(){this.updateValues.call(this,this.props)}UNSAFE_componentWillReceiveProps(e){this.updateValues.call(this,e)}render(){let{onChangeConsumes:e,param:t,isExecute:a,specSelectors:n,pathMethod:r,getConfigs:s,getComponent:o}=this.props;const l=o("Button"),i=o("TextArea"),c=o("highlightCode"),u=o("contentType");let d=(n?n.parameterWithMetaByIdentity(r,t):t).get("errors",(0,y.List)()),p=n.contentTypeValues(r).get("requestContentType"),m=this.props.consumes&&this.props.consumes.size?this.props.consumes:ta.defaultProp.consumes,{value:f,isEditBox:g}=this.state,v=null;return(0,Ke.O)(f)&&(v="json"),h.default.createElement("div",{className:"body-param","data-param-name":t.get("name"),"data-param-in":t.get("in")},g&&a?h.default.createElement(i,{className:"body-param__text"+(d.count()?" invalid":""),value:f,onChange:this.handleOnChange}):f&&h.default.createElement(c,{className:"body-param__example",language:v,getConfigs:s,value:f}),h.default.createElement("div",{className:"body-param-options"},a?h.default.createElement("div",{className:"body-param-edit"},h.default.createElement(l,{className:g?"btn cancel body-param__example-edit":"btn edit body-param__example-edit",onClick:this.toggleIsEditBox},g?"Cancel":"Edit")):null,h.default.createElement("label",{htmlFor:""},h.default.createElement("span",null,"Parameter content type"),h.default.createElement(u,{value:p,contentTypes:m,onChange:e,className:"body-param-content-type",ariaLabel:"Parameter content type"}))))}}(0,W.default)(ta,"defaultProp",{consumes:(0,y.fromJS)(["application/json"]),param:(0,y.fromJS)({}),onChange:ea,onChangeConsumes:ea});var aa=ut(4624);class na extends h.default.Component{render(){let{request:e,getConfigs:t}=this.props,a=(0,aa.requestSnippetGenerator_curl_bash)(e);const n=t(),r=(0,De.default)(n,"syntaxHighlight.activated")?h.default.createElement(Me.d3,{language:"bash",className:"curl microlight",style:(0,Me.C2)((0,De.default)(n,"syntaxHighlight.theme"))},a):h.default.createElement("textarea",{readOnly:!0,className:"curl",value:a});return h.default.createElement("div",{className:"curl-command"},h.default.createElement("h4",null,"Curl"),h.default.createElement("div",{className:"copy-to-clipboard"},h.default.createElement(Be.CopyToClipboard,{text:a},h.default.createElement("button",null))),h.default.createElement("div",null,r))}}class ra extends h.default.Component{constructor(){super(...arguments),(0,W.default)(this,"onChange",(e=>{this.setScheme(e.target.value)})),(0,W.default)(this,"setScheme",(e=>{let{path:t,method:a,specActions:n}=this.props;n.setScheme(e,t,a)}))}UNSAFE_componentWillMount(){let{schemes:e}=this.props;this.setScheme(e.first())}UNSAFE_componentWillReceiveProps(e){var t;this.props.currentScheme&&(0,fe.default)(t=e.schemes).call(t,this.props.currentScheme)||this.setScheme(e.schemes.first())}render(){var e;let{schemes:t,currentScheme:a}=this.props;return h.default.createElement("label",{htmlFor:"schemes"},h.default.createElement("span",{className:"schemes-title"},"Schemes"),h.default.createElement("select",{onChange:this.onChange,value:a},(0,m.default)(e=t.valueSeq()).call(e,(e=>h.default.createElement("option",{value:e,key:e},e))).toArray()))}}class sa extends h.default.Component{render(){const{specActions:e,specSelectors:t,getComponent:a}=this.props,n=t.operationScheme(),r=t.schemes(),s=a("schemes");return r&&r.size?h.default.createElement(s,{currentScheme:n,schemes:r,specActions:e}):null}}class oa extends h.Component{constructor(e,t){super(e,t),(0,W.default)(this,"toggleCollapsed",(()=>{this.props.onToggle&&this.props.onToggle(this.props.modelName,!this.state.expanded),this.setState({expanded:!this.state.expanded})})),(0,W.default)(this,"onLoad",(e=>{if(e&&this.props.layoutSelectors){const t=this.props.layoutSelectors.getScrollToKey();y.default.is(t,this.props.specPath)&&this.toggleCollapsed(),this.props.layoutActions.readyToScroll(this.props.specPath,e.parentElement)}}));let{expanded:a,collapsedContent:n}=this.props;this.state={expanded:a,collapsedContent:n||oa.defaultProps.collapsedContent}}componentDidMount(){const{hideSelfOnExpand:e,expanded:t,modelName:a}=this.props;e&&t&&this.props.onToggle(a,t)}UNSAFE_componentWillReceiveProps(e){this.props.expanded!==e.expanded&&this.setState({expanded:e.expanded})}render(){const{title:e,classes:t}=this.props;return this.state.expanded&&this.props.hideSelfOnExpand?h.default.createElement("span",{className:t||""},this.props.children):h.default.createElement("span",{className:t||"",ref:this.onLoad},h.default.createElement("button",{"aria-expanded":this.state.expanded,className:"model-box-control",onClick:this.toggleCollapsed},e&&h.default.createElement("span",{className:"pointer"},e),h.default.createElement("span",{className:"model-toggle"+(this.state.expanded?"":" collapsed")}),!this.state.expanded&&h.default.createElement("span",null,this.state.collapsedContent)),this.state.expanded&&this.props.children)}}(0,W.default)(oa,"defaultProps",{collapsedContent:"{...}",expanded:!1,title:null,onToggle:()=>{},hideSelfOnExpand:!1,specPath:y.default.List([])});var la=ut(1798),ia=ut.n(la);class ca extends h.default.Component{constructor(e,t){super(e,t),(0,W.default)(this,"activeTab",(e=>{let{target:{dataset:{name:t}}}=e;this.setState({activeTab:t})}));let{getConfigs:a,isExecute:n}=this.props,{defaultModelRendering:r}=a(),s=r;"example"!==r&&"model"!==r&&(s="example"),n&&(s="example"),this.state={activeTab:s}}UNSAFE_componentWillReceiveProps(e){e.isExecute&&!this.props.isExecute&&this.props.example&&this.setState({activeTab:"example"})}render(){let{getComponent:e,specSelectors:t,schema:a,example:n,isExecute:r,getConfigs:s,specPath:o,includeReadOnly:l,includeWriteOnly:i}=this.props,{defaultModelExpandDepth:c}=s();const u=e("ModelWrapper"),d=e("highlightCode"),p=ia()(5).toString("base64"),m=ia()(5).toString("base64"),f=ia()(5).toString("base64"),g=ia()(5).toString("base64");let y=t.isOAS3();return h.default.createElement("div",{className:"model-example"},h.default.createElement("ul",{className:"tab",role:"tablist"},h.default.createElement("li",{className:(0,Te.default)("tabitem",{active:"example"===this.state.activeTab}),role:"presentation"},h.default.createElement("button",{"aria-controls":m,"aria-selected":"example"===this.state.activeTab,className:"tablinks","data-name":"example",id:p,onClick:this.activeTab,role:"tab"},r?"Edit Value":"Example Value")),a&&h.default.createElement("li",{className:(0,Te.default)("tabitem",{active:"model"===this.state.activeTab}),role:"presentation"},h.default.createElement("button",{"aria-controls":g,"aria-selected":"model"===this.state.activeTab,className:(0,Te.default)("tablinks",{inactive:r}),"data-name":"model",id:f,onClick:this.activeTab,role:"tab"},y?"Schema":"Model"))),"example"===this.state.activeTab&&h.default.createElement("div",{"aria-hidden":"example"!==this.state.activeTab,"aria-labelledby":p,"data-name":"examplePanel",id:m,role:"tabpanel",tabIndex:"0"},n||h.default.createElement(d,{value:"(no example available)",getConfigs:s})),"model"===this.state.activeTab&&h.default.createElement("div",{"aria-hidden":"example"===this.state.activeTab,"aria-labelledby":f,"data-name":"modelPanel",id:g,role:"tabpanel",tabIndex:"0"},h.default.createElement(u,{schema:a,getComponent:e,getConfigs:s,specSelectors:t,expandDepth:c,specPath:o,includeReadOnly:l,includeWriteOnly:i})))}}class ua extends h.Component{constructor(){super(...arguments),(0,W.default)(this,"onToggle",((e,t)=>{this.props.layoutActions&&this.props.layoutActions.show(this.props.fullPath,t)}))}render(){let{getComponent:e,getConfigs:t}=this.props;const a=e("Model");let n;return this.props.layoutSelectors&&(n=this.props.layoutSelectors.isShown(this.props.fullPath)),h.default.createElement("div",{className:"model-box"},h.default.createElement(a,(0,_t.default)({},this.props,{getConfigs:t,expanded:n,depth:1,onToggle:this.onToggle,expandDepth:this.props.expandDepth||0})))}}var da=ut(1543);class pa extends h.Component{constructor(){super(...arguments),(0,W.default)(this,"getSchemaBasePath",(()=>this.props.specSelectors.isOAS3()?["components","schemas"]:["definitions"])),(0,W.default)(this,"getCollapsedContent",(()=>" ")),(0,W.default)(this,"handleToggle",((e,t)=>{const{layoutActions:a}=this.props;a.show([...this.getSchemaBasePath(),e],t),t&&this.props.specActions.requestResolvedSubtree([...this.getSchemaBasePath(),e])})),(0,W.default)(this,"onLoadModels",(e=>{e&&this.props.layoutActions.readyToScroll(this.getSchemaBasePath(),e)})),(0,W.default)(this,"onLoadModel",(e=>{if(e){const t=e.getAttribute("data-name");this.props.layoutActions.readyToScroll([...this.getSchemaBasePath(),t],e)}}))}render(){var e;let{specSelectors:t,getComponent:a,layoutSelectors:n,layoutActions:r,getConfigs:s}=this.props,o=t.definitions(),{docExpansion:l,defaultModelsExpandDepth:i}=s();if(!o.size||i<0)return null;const c=this.getSchemaBasePath();let u=n.isShown(c,i>0&&"none"!==l);const d=t.isOAS3(),p=a("ModelWrapper"),f=a("Collapse"),g=a("ModelCollapse"),v=a("JumpToPath",!0),E=a("ArrowUpIcon"),S=a("ArrowDownIcon");return h.default.createElement("section",{className:u?"models is-open":"models",ref:this.onLoadModels},h.default.createElement("h4",null,h.default.createElement("button",{"aria-expanded":u,className:"models-control",onClick:()=>r.show(c,!u)},h.default.createElement("span",null,d?"Schemas":"Models"),u?h.default.createElement(E,null):h.default.createElement(S,null))),h.default.createElement(f,{isOpened:u},(0,m.default)(e=o.entrySeq()).call(e,(e=>{let[o]=e;const l=[...c,o],u=y.default.List(l),d=t.specResolvedSubtree(l),m=t.specJson().getIn(l),f=y.Map.isMap(d)?d:y.default.Map(),E=y.Map.isMap(m)?m:y.default.Map(),S=f.get("title")||E.get("title")||o,w=n.isShown(l,!1);w&&0===f.size&&E.size>0&&this.props.specActions.requestResolvedSubtree(l);const b=h.default.createElement(p,{name:o,expandDepth:i,schema:f||y.default.Map(),displayName:S,fullPath:l,specPath:u,getComponent:a,specSelectors:t,getConfigs:s,layoutSelectors:n,layoutActions:r,includeReadOnly:!0,includeWriteOnly:!0}),x=h.default.createElement("span",{className:"model-box"},h.default.createElement("span",{className:"model model-title"},S));return h.default.createElement("div",{id:`model-${o}`,className:"model-container",key:`models-section-${o}`,"data-name":o,ref:this.onLoadModel},h.default.createElement("span",{className:"models-jump-to-path"},h.default.createElement(v,{specPath:u})),h.default.createElement(g,{classes:"model-box",collapsedContent:this.getCollapsedContent(o),onToggle:this.handleToggle,title:x,displayName:S,modelName:o,specPath:u,layoutSelectors:n,layoutActions:r,hideSelfOnExpand:!0,expanded:i>0&&w},b))})).toArray()))}}const ma=e=>{let{value:t,getComponent:a}=e,n=a("ModelCollapse"),r=h.default.createElement("span",null,"Array [ ",t.count()," ]");return h.default.createElement("span",{className:"prop-enum"},"Enum:",h.default.createElement("br",null),h.default.createElement(n,{collapsedContent:r},"[ ",t.join(", ")," ]"))};class fa extends h.Component{render(){var e,t,a,r;let{schema:o,name:l,displayName:i,isRef:c,getComponent:d,getConfigs:p,depth:f,onToggle:g,expanded:v,specPath:E,...S}=this.props,{specSelectors:w,expandDepth:b,includeReadOnly:_,includeWriteOnly:C}=S;const{isOAS3:j}=w;if(!o)return null;const{showExtensions:N}=p();let O=o.get("description"),k=o.get("properties"),A=o.get("additionalProperties"),I=o.get("title")||i||l,R=o.get("required"),P=(0,n.default)(o).call(o,((e,t)=>{var a;return-1!==(0,H.default)(a=["maxProperties","minProperties","nullable","example"]).call(a,t)})),T=o.get("deprecated"),M=o.getIn(["externalDocs","url"]),D=o.getIn(["externalDocs","description"]);const L=d("JumpToPath",!0),q=d("Markdown",!0),B=d("Model"),U=d("ModelCollapse"),J=d("Property"),$=d("Link"),V=()=>h.default.createElement("span",{className:"model-jump-to-path"},h.default.createElement(L,{specPath:E})),K=h.default.createElement("span",null,h.default.createElement("span",null,"{"),"...",h.default.createElement("span",null,"}"),c?h.default.createElement(V,null):""),F=w.isOAS3()?o.get("anyOf"):null,z=w.isOAS3()?o.get("oneOf"):null,W=w.isOAS3()?o.get("not"):null,G=I&&h.default.createElement("span",{className:"model-title"},c&&o.get("$$ref")&&h.default.createElement("span",{className:"model-hint"},o.get("$$ref")),h.default.createElement("span",{className:"model-title__text"},I));return h.default.createElement("span",{className:"model"},h.default.createElement(U,{modelName:l,title:G,onToggle:g,expanded:!!v||f<=b,collapsedContent:K},h.default.createElement("span",{className:"brace-open object"},"{"),c?h.default.createElement(V,null):null,h.default.createElement("span",{className:"inner-object"},h.default.createElement("table",{className:"model"},h.default.createElement("tbody",null,O?h.default.createElement("tr",{className:"description"},h.default.createElement("td",null,"description:"),h.default.createElement("td",null,h.default.createElement(q,{source:O}))):null,M&&h.default.createElement("tr",{className:"external-docs"},h.default.createElement("td",null,"externalDocs:"),h.default.createElement("td",null,h.default.createElement($,{target:"_blank",href:(0,x.Nm)(M)},D||M))),T?h.default.createElement("tr",{className:"property"},h.default.createElement("td",null,"deprecated:"),h.default.createElement("td",null,"true")):null,k&&k.size?(0,m.default)(e=(0,n.default)(t=k.entrySeq()).call(t,(e=>{let[,t]=e;return(!t.get("readOnly")||_)&&(!t.get("writeOnly")||C)}))).call(e,(e=>{let[t,a]=e,n=j()&&a.get("deprecated"),r=y.List.isList(R)&&R.contains(t),s=["property-row"];return n&&s.push("deprecated"),r&&s.push("required"),h.default.createElement("tr",{key:t,className:s.join(" ")},h.default.createElement("td",null,t,r&&h.default.createElement("span",{className:"star"},"*")),h.default.createElement("td",null,h.default.createElement(B,(0,_t.default)({key:`object-${l}-${t}_${a}`},S,{required:r,getComponent:d,specPath:E.push("properties",t),getConfigs:p,schema:a,depth:f+1}))))})).toArray():null,N?h.default.createElement("tr",null,h.default.createElement("td",null," ")):null,N?(0,m.default)(a=o.entrySeq()).call(a,(e=>{let[t,a]=e;if("x-"!==(0,u.default)(t).call(t,0,2))return;const n=a?a.toJS?a.toJS():a:null;return h.default.createElement("tr",{key:t,className:"extension"},h.default.createElement("td",null,t),h.default.createElement("td",null,(0,s.default)(n)))})).toArray():null,A&&A.size?h.default.createElement("tr",null,h.default.createElement("td",null,"< * >:"),h.default.createElement("td",null,h.default.createElement(B,(0,_t.default)({},S,{required:!1,getComponent:d,specPath:E.push("additionalProperties"),getConfigs:p,schema:A,depth:f+1})))):null,F?h.default.createElement("tr",null,h.default.createElement("td",null,"anyOf ->"),h.default.createElement("td",null,(0,m.default)(F).call(F,((e,t)=>h.default.createElement("div",{key:t},h.default.createElement(B,(0,_t.default)({},S,{required:!1,getComponent:d,specPath:E.push("anyOf",t),getConfigs:p,schema:e,depth:f+1}))))))):null,z?h.default.createElement("tr",null,h.default.createElement("td",null,"oneOf ->"),h.default.createElement("td",null,(0,m.default)(z).call(z,((e,t)=>h.default.createElement("div",{key:t},h.default.createElement(B,(0,_t.default)({},S,{required:!1,getComponent:d,specPath:E.push("oneOf",t),getConfigs:p,schema:e,depth:f+1}))))))):null,W?h.default.createElement("tr",null,h.default.createElement("td",null,"not ->"),h.default.createElement("td",null,h.default.createElement("div",null,h.default.createElement(B,(0,_t.default)({},S,{required:!1,getComponent:d,specPath:E.push("not"),getConfigs:p,schema:W,depth:f+1}))))):null))),h.default.createElement("span",{className:"brace-close"},"}")),P.size?(0,m.default)(r=P.entrySeq()).call(r,(e=>{let[t,a]=e;return h.default.createElement(J,{key:`${t}-${a}`,propKey:t,propVal:a,propClass:"property"})})):null)}}class ha extends h.Component{render(){var e;let{getComponent:t,getConfigs:a,schema:r,depth:s,expandDepth:o,name:l,displayName:i,specPath:c}=this.props,u=r.get("description"),d=r.get("items"),p=r.get("title")||i||l,f=(0,n.default)(r).call(r,((e,t)=>{var a;return-1===(0,H.default)(a=["type","items","description","$$ref","externalDocs"]).call(a,t)})),g=r.getIn(["externalDocs","url"]),y=r.getIn(["externalDocs","description"]);const v=t("Markdown",!0),E=t("ModelCollapse"),S=t("Model"),w=t("Property"),b=t("Link"),_=p&&h.default.createElement("span",{className:"model-title"},h.default.createElement("span",{className:"model-title__text"},p));return h.default.createElement("span",{className:"model"},h.default.createElement(E,{title:_,expanded:s<=o,collapsedContent:"[...]"},"[",f.size?(0,m.default)(e=f.entrySeq()).call(e,(e=>{let[t,a]=e;return h.default.createElement(w,{key:`${t}-${a}`,propKey:t,propVal:a,propClass:"property"})})):null,u?h.default.createElement(v,{source:u}):f.size?h.default.createElement("div",{className:"markdown"}):null,g&&h.default.createElement("div",{className:"external-docs"},h.default.createElement(b,{target:"_blank",href:(0,x.Nm)(g)},y||g)),h.default.createElement("span",null,h.default.createElement(S,(0,_t.default)({},this.props,{getConfigs:a,specPath:c.push("items"),name:null,schema:d,required:!1,depth:s+1}))),"]"))}}const ga="property primitive";class ya extends h.Component{render(){var e,t,a;let{schema:r,getComponent:s,getConfigs:o,name:l,displayName:i,depth:c,expandDepth:u}=this.props;const{showExtensions:d}=o();if(!r||!r.get)return h.default.createElement("div",null);let p=r.get("type"),f=r.get("format"),g=r.get("xml"),y=r.get("enum"),v=r.get("title")||i||l,E=r.get("description"),S=(0,x.nX)(r),w=(0,n.default)(r).call(r,((e,t)=>{var a;return-1===(0,H.default)(a=["enum","type","format","description","$$ref","externalDocs"]).call(a,t)})).filterNot(((e,t)=>S.has(t))),b=r.getIn(["externalDocs","url"]),_=r.getIn(["externalDocs","description"]);const C=s("Markdown",!0),j=s("EnumModel"),N=s("Property"),O=s("ModelCollapse"),k=s("Link"),A=v&&h.default.createElement("span",{className:"model-title"},h.default.createElement("span",{className:"model-title__text"},v));return h.default.createElement("span",{className:"model"},h.default.createElement(O,{title:A,expanded:c<=u,collapsedContent:"[...]",hideSelfOnExpand:u!==c},h.default.createElement("span",{className:"prop"},l&&c>1&&h.default.createElement("span",{className:"prop-name"},v),h.default.createElement("span",{className:"prop-type"},p),f&&h.default.createElement("span",{className:"prop-format"},"($",f,")"),w.size?(0,m.default)(e=w.entrySeq()).call(e,(e=>{let[t,a]=e;return h.default.createElement(N,{key:`${t}-${a}`,propKey:t,propVal:a,propClass:ga})})):null,d&&S.size?(0,m.default)(t=S.entrySeq()).call(t,(e=>{let[t,a]=e;return h.default.createElement(N,{key:`${t}-${a}`,propKey:t,propVal:a,propClass:ga})})):null,E?h.default.createElement(C,{source:E}):null,b&&h.default.createElement("div",{className:"external-docs"},h.default.createElement(k,{target:"_blank",href:(0,x.Nm)(b)},_||b)),g&&g.size?h.default.createElement("span",null,h.default.createElement("br",null),h.default.createElement("span",{className:ga},"xml:"),(0,m.default)(a=g.entrySeq()).call(a,(e=>{let[t,a]=e;return h.default.createElement("span",{key:`${t}-${a}`,className:ga},h.default.createElement("br",null),"   ",t,": ",String(a))})).toArray()):null,y&&h.default.createElement(j,{value:y,getComponent:s}))))}}const va=e=>{let{propKey:t,propVal:a,propClass:n}=e;return h.default.createElement("span",{className:n},h.default.createElement("br",null),t,": ",String(a))};class Ea extends h.default.Component{render(){const{onTryoutClick:e,onCancelClick:t,onResetClick:a,enabled:n,hasUserEditedBody:r,isOAS3:s}=this.props,o=s&&r;return h.default.createElement("div",{className:o?"try-out btn-group":"try-out"},n?h.default.createElement("button",{className:"btn try-out__btn cancel",onClick:t},"Cancel"):h.default.createElement("button",{className:"btn try-out__btn",onClick:e},"Try it out "),o&&h.default.createElement("button",{className:"btn try-out__btn reset",onClick:a},"Reset"))}}(0,W.default)(Ea,"defaultProps",{onTryoutClick:Function.prototype,onCancelClick:Function.prototype,onResetClick:Function.prototype,enabled:!1,hasUserEditedBody:!1,isOAS3:!1});class Sa extends h.default.PureComponent{render(){const{bypass:e,isSwagger2:t,isOAS3:a,alsoShow:n}=this.props;return e?h.default.createElement("div",null,this.props.children):t&&a?h.default.createElement("div",{className:"version-pragma"},n,h.default.createElement("div",{className:"version-pragma__message version-pragma__message--ambiguous"},h.default.createElement("div",null,h.default.createElement("h3",null,"Unable to render this definition"),h.default.createElement("p",null,h.default.createElement("code",null,"swagger")," and ",h.default.createElement("code",null,"openapi")," fields cannot be present in the same Swagger or OpenAPI definition. Please remove one of the fields."),h.default.createElement("p",null,"Supported version fields are ",h.default.createElement("code",null,"swagger: ",'"2.0"')," and those that match ",h.default.createElement("code",null,"openapi: 3.0.n")," (for example, ",h.default.createElement("code",null,"openapi: 3.0.0"),").")))):t||a?h.default.createElement("div",null,this.props.children):h.default.createElement("div",{className:"version-pragma"},n,h.default.createElement("div",{className:"version-pragma__message version-pragma__message--missing"},h.default.createElement("div",null,h.default.createElement("h3",null,"Unable to render this definition"),h.default.createElement("p",null,"The provided definition does not specify a valid version field."),h.default.createElement("p",null,"Please indicate a valid Swagger or OpenAPI version field. Supported version fields are ",h.default.createElement("code",null,"swagger: ",'"2.0"')," and those that match ",h.default.createElement("code",null,"openapi: 3.0.n")," (for example, ",h.default.createElement("code",null,"openapi: 3.0.0"),")."))))}}(0,W.default)(Sa,"defaultProps",{alsoShow:null,children:null,bypass:!1});const wa=e=>{let{version:t}=e;return h.default.createElement("small",null,h.default.createElement("pre",{className:"version"}," ",t," "))},ba=e=>{let{enabled:t,path:a,text:n}=e;return h.default.createElement("a",{className:"nostyle",onClick:t?e=>e.preventDefault():null,href:t?`#/${a}`:null},h.default.createElement("span",null,n))},xa=()=>h.default.createElement("div",null,h.default.createElement("svg",{xmlns:"http://www.w3.org/2000/svg",xmlnsXlink:"http://www.w3.org/1999/xlink",className:"svg-assets"},h.default.createElement("defs",null,h.default.createElement("symbol",{viewBox:"0 0 20 20",id:"unlocked"},h.default.createElement("path",{d:"M15.8 8H14V5.6C14 2.703 12.665 1 10 1 7.334 1 6 2.703 6 5.6V6h2v-.801C8 3.754 8.797 3 10 3c1.203 0 2 .754 2 2.199V8H4c-.553 0-1 .646-1 1.199V17c0 .549.428 1.139.951 1.307l1.197.387C5.672 18.861 6.55 19 7.1 19h5.8c.549 0 1.428-.139 1.951-.307l1.196-.387c.524-.167.953-.757.953-1.306V9.199C17 8.646 16.352 8 15.8 8z"})),h.default.createElement("symbol",{viewBox:"0 0 20 20",id:"locked"},h.default.createElement("path",{d:"M15.8 8H14V5.6C14 2.703 12.665 1 10 1 7.334 1 6 2.703 6 5.6V8H4c-.553 0-1 .646-1 1.199V17c0 .549.428 1.139.951 1.307l1.197.387C5.672 18.861 6.55 19 7.1 19h5.8c.549 0 1.428-.139 1.951-.307l1.196-.387c.524-.167.953-.757.953-1.306V9.199C17 8.646 16.352 8 15.8 8zM12 8H8V5.199C8 3.754 8.797 3 10 3c1.203 0 2 .754 2 2.199V8z"})),h.default.createElement("symbol",{viewBox:"0 0 20 20",id:"close"},h.default.createElement("path",{d:"M14.348 14.849c-.469.469-1.229.469-1.697 0L10 11.819l-2.651 3.029c-.469.469-1.229.469-1.697 0-.469-.469-.469-1.229 0-1.697l2.758-3.15-2.759-3.152c-.469-.469-.469-1.228 0-1.697.469-.469 1.228-.469 1.697 0L10 8.183l2.651-3.031c.469-.469 1.228-.469 1.697 0 .469.469.469 1.229 0 1.697l-2.758 3.152 2.758 3.15c.469.469.469 1.229 0 1.698z"})),h.default.createElement("symbol",{viewBox:"0 0 20 20",id:"large-arrow"},h.default.createElement("path",{d:"M13.25 10L6.109 2.58c-.268-.27-.268-.707 0-.979.268-.27.701-.27.969 0l7.83 7.908c.268.271.268.709 0 .979l-7.83 7.908c-.268.271-.701.27-.969 0-.268-.269-.268-.707 0-.979L13.25 10z"})),h.default.createElement("symbol",{viewBox:"0 0 20 20",id:"large-arrow-down"},h.default.createElement("path",{d:"M17.418 6.109c.272-.268.709-.268.979 0s.271.701 0 .969l-7.908 7.83c-.27.268-.707.268-.979 0l-7.908-7.83c-.27-.268-.27-.701 0-.969.271-.268.709-.268.979 0L10 13.25l7.418-7.141z"})),h.default.createElement("symbol",{viewBox:"0 0 20 20",id:"large-arrow-up"},h.default.createElement("path",{d:"M 17.418 14.908 C 17.69 15.176 18.127 15.176 18.397 14.908 C 18.667 14.64 18.668 14.207 18.397 13.939 L 10.489 6.109 C 10.219 5.841 9.782 5.841 9.51 6.109 L 1.602 13.939 C 1.332 14.207 1.332 14.64 1.602 14.908 C 1.873 15.176 2.311 15.176 2.581 14.908 L 10 7.767 L 17.418 14.908 Z"})),h.default.createElement("symbol",{viewBox:"0 0 24 24",id:"jump-to"},h.default.createElement("path",{d:"M19 7v4H5.83l3.58-3.59L8 6l-6 6 6 6 1.41-1.41L5.83 13H21V7z"})),h.default.createElement("symbol",{viewBox:"0 0 24 24",id:"expand"},h.default.createElement("path",{d:"M10 18h4v-2h-4v2zM3 6v2h18V6H3zm3 7h12v-2H6v2z"})),h.default.createElement("symbol",{viewBox:"0 0 15 16",id:"copy"},h.default.createElement("g",{transform:"translate(2, -1)"},h.default.createElement("path",{fill:"#ffffff",fillRule:"evenodd",d:"M2 13h4v1H2v-1zm5-6H2v1h5V7zm2 3V8l-3 3 3 3v-2h5v-2H9zM4.5 9H2v1h2.5V9zM2 12h2.5v-1H2v1zm9 1h1v2c-.02.28-.11.52-.3.7-.19.18-.42.28-.7.3H1c-.55 0-1-.45-1-1V4c0-.55.45-1 1-1h3c0-1.11.89-2 2-2 1.11 0 2 .89 2 2h3c.55 0 1 .45 1 1v5h-1V6H1v9h10v-2zM2 5h8c0-.55-.45-1-1-1H8c-.55 0-1-.45-1-1s-.45-1-1-1-1 .45-1 1-.45 1-1 1H3c-.55 0-1 .45-1 1z"}))))));var _a=ut(5466);class Ca extends h.default.Component{render(){const{errSelectors:e,specSelectors:t,getComponent:a}=this.props,n=a("SvgAssets"),r=a("InfoContainer",!0),s=a("VersionPragmaFilter"),o=a("operations",!0),l=a("Models",!0),i=a("Webhooks",!0),c=a("Row"),u=a("Col"),d=a("errors",!0),p=a("ServersContainer",!0),m=a("SchemesContainer",!0),f=a("AuthorizeBtnContainer",!0),g=a("FilterContainer",!0),y=t.isSwagger2(),v=t.isOAS3(),E=t.isOAS31(),S=!t.specStr(),w=t.loadingStatus();let b=null;if("loading"===w&&(b=h.default.createElement("div",{className:"info"},h.default.createElement("div",{className:"loading-container"},h.default.createElement("div",{className:"loading"})))),"failed"===w&&(b=h.default.createElement("div",{className:"info"},h.default.createElement("div",{className:"loading-container"},h.default.createElement("h4",{className:"title"},"Failed to load API definition."),h.default.createElement(d,null)))),"failedConfig"===w){const t=e.lastError(),a=t?t.get("message"):"";b=h.default.createElement("div",{className:"info failed-config"},h.default.createElement("div",{className:"loading-container"},h.default.createElement("h4",{className:"title"},"Failed to load remote configuration."),h.default.createElement("p",null,a)))}if(!b&&S&&(b=h.default.createElement("h4",null,"No API definition provided.")),b)return h.default.createElement("div",{className:"swagger-ui"},h.default.createElement("div",{className:"loading-container"},b));const x=t.servers(),_=t.schemes(),C=x&&x.size,j=_&&_.size,N=!!t.securityDefinitions();return h.default.createElement("div",{className:"swagger-ui"},h.default.createElement(n,null),h.default.createElement(s,{isSwagger2:y,isOAS3:v,alsoShow:h.default.createElement(d,null)},h.default.createElement(d,null),h.default.createElement(c,{className:"information-container"},h.default.createElement(u,{mobile:12},h.default.createElement(r,null))),C||j||N?h.default.createElement("div",{className:"scheme-container"},h.default.createElement(u,{className:"schemes wrapper",mobile:12},C?h.default.createElement(p,null):null,j?h.default.createElement(m,null):null,N?h.default.createElement(f,null):null)):null,h.default.createElement(g,null),h.default.createElement(c,null,h.default.createElement(u,{mobile:12,desktop:12},h.default.createElement(o,null))),E&&h.default.createElement(c,{className:"webhooks-container"},h.default.createElement(u,{mobile:12,desktop:12},h.default.createElement(i,null))),h.default.createElement(c,null,h.default.createElement(u,{mobile:12,desktop:12},h.default.createElement(l,null)))))}}const ja=(e=>{var t={};return ut.d(t,e),t})({default:()=>lt.default}),Na={value:"",onChange:()=>{},schema:{},keyName:"",required:!1,errors:(0,y.List)()};class Oa extends h.Component{componentDidMount(){const{dispatchInitialValue:e,value:t,onChange:a}=this.props;e?a(t):!1===e&&a("")}render(){let{schema:e,errors:t,value:a,onChange:n,getComponent:r,fn:s,disabled:o}=this.props;const l=e&&e.get?e.get("format"):null,i=e&&e.get?e.get("type"):null;let c=e=>r(e,!1,{failSilently:!0}),u=i?c(l?`JsonSchema_${i}_${l}`:`JsonSchema_${i}`):r("JsonSchema_string");return u||(u=r("JsonSchema_string")),h.default.createElement(u,(0,_t.default)({},this.props,{errors:t,fn:s,getComponent:r,value:a,onChange:n,schema:e,disabled:o}))}}(0,W.default)(Oa,"defaultProps",Na);class ka extends h.Component{constructor(){super(...arguments),(0,W.default)(this,"onChange",(e=>{const t=this.props.schema&&"file"===this.props.schema.get("type")?e.target.files[0]:e.target.value;this.props.onChange(t,this.props.keyName)})),(0,W.default)(this,"onEnumChange",(e=>this.props.onChange(e)))}render(){let{getComponent:e,value:t,schema:a,errors:n,required:r,description:s,disabled:o}=this.props;const l=a&&a.get?a.get("enum"):null,i=a&&a.get?a.get("format"):null,c=a&&a.get?a.get("type"):null,u=a&&a.get?a.get("in"):null;if(t||(t=""),n=n.toJS?n.toJS():[],l){const a=e("Select");return h.default.createElement(a,{className:n.length?"invalid":"",title:n.length?n:"",allowedValues:[...l],value:t,allowEmptyValue:!r,disabled:o,onChange:this.onEnumChange})}const d=o||u&&"formData"===u&&!("FormData"in window),p=e("Input");return c&&"file"===c?h.default.createElement(p,{type:"file",className:n.length?"invalid":"",title:n.length?n:"",onChange:this.onChange,disabled:d}):h.default.createElement(ja.default,{type:i&&"password"===i?"password":"text",className:n.length?"invalid":"",title:n.length?n:"",value:t,minLength:0,debounceTimeout:350,placeholder:s,onChange:this.onChange,disabled:d})}}(0,W.default)(ka,"defaultProps",Na);class Aa extends h.PureComponent{constructor(e,t){super(e,t),(0,W.default)(this,"onChange",(()=>{this.props.onChange(this.state.value)})),(0,W.default)(this,"onItemChange",((e,t)=>{this.setState((a=>{let{value:n}=a;return{value:n.set(t,e)}}),this.onChange)})),(0,W.default)(this,"removeItem",(e=>{this.setState((t=>{let{value:a}=t;return{value:a.delete(e)}}),this.onChange)})),(0,W.default)(this,"addItem",(()=>{const{fn:e}=this.props;let t=Da(this.state.value);this.setState((()=>({value:t.push(e.getSampleSchema(this.state.schema.get("items"),!1,{includeWriteOnly:!0}))})),this.onChange)})),(0,W.default)(this,"onEnumChange",(e=>{this.setState((()=>({value:e})),this.onChange)})),this.state={value:Da(e.value),schema:e.schema}}UNSAFE_componentWillReceiveProps(e){const t=Da(e.value);t!==this.state.value&&this.setState({value:t}),e.schema!==this.state.schema&&this.setState({schema:e.schema})}render(){var e;let{getComponent:t,required:a,schema:r,errors:s,fn:o,disabled:l}=this.props;s=s.toJS?s.toJS():(0,d.default)(s)?s:[];const i=(0,n.default)(s).call(s,(e=>"string"==typeof e)),c=(0,m.default)(e=(0,n.default)(s).call(s,(e=>void 0!==e.needRemove))).call(e,(e=>e.error)),u=this.state.value,p=!!(u&&u.count&&u.count()>0),f=r.getIn(["items","enum"]),g=r.getIn(["items","type"]),v=r.getIn(["items","format"]),E=r.get("items");let S,w=!1,b="file"===g||"string"===g&&"binary"===v;if(g&&v?S=t(`JsonSchema_${g}_${v}`):"boolean"!==g&&"array"!==g&&"object"!==g||(S=t(`JsonSchema_${g}`)),S||b||(w=!0),f){const e=t("Select");return h.default.createElement(e,{className:s.length?"invalid":"",title:s.length?s:"",multiple:!0,value:u,disabled:l,allowedValues:f,allowEmptyValue:!a,onChange:this.onEnumChange})}const x=t("Button");return h.default.createElement("div",{className:"json-schema-array"},p?(0,m.default)(u).call(u,((e,a)=>{var r;const i=(0,y.fromJS)([...(0,m.default)(r=(0,n.default)(s).call(s,(e=>e.index===a))).call(r,(e=>e.error))]);return h.default.createElement("div",{key:a,className:"json-schema-form-item"},b?h.default.createElement(Ra,{value:e,onChange:e=>this.onItemChange(e,a),disabled:l,errors:i,getComponent:t}):w?h.default.createElement(Ia,{value:e,onChange:e=>this.onItemChange(e,a),disabled:l,errors:i}):h.default.createElement(S,(0,_t.default)({},this.props,{value:e,onChange:e=>this.onItemChange(e,a),disabled:l,errors:i,schema:E,getComponent:t,fn:o})),l?null:h.default.createElement(x,{className:`btn btn-sm json-schema-form-item-remove ${c.length?"invalid":null}`,title:c.length?c:"",onClick:()=>this.removeItem(a)}," - "))})):null,l?null:h.default.createElement(x,{className:`btn btn-sm json-schema-form-item-add ${i.length?"invalid":null}`,title:i.length?i:"",onClick:this.addItem},"Add ",g?`${g} `:"","item"))}}(0,W.default)(Aa,"defaultProps",Na);class Ia extends h.Component{constructor(){super(...arguments),(0,W.default)(this,"onChange",(e=>{const t=e.target.value;this.props.onChange(t,this.props.keyName)}))}render(){let{value:e,errors:t,description:a,disabled:n}=this.props;return e||(e=""),t=t.toJS?t.toJS():[],h.default.createElement(ja.default,{type:"text",className:t.length?"invalid":"",title:t.length?t:"",value:e,minLength:0,debounceTimeout:350,placeholder:a,onChange:this.onChange,disabled:n})}}(0,W.default)(Ia,"defaultProps",Na);class Ra extends h.Component{constructor(){super(...arguments),(0,W.default)(this,"onFileChange",(e=>{const t=e.target.files[0];this.props.onChange(t,this.props.keyName)}))}render(){let{getComponent:e,errors:t,disabled:a}=this.props;const n=e("Input"),r=a||!("FormData"in window);return h.default.createElement(n,{type:"file",className:t.length?"invalid":"",title:t.length?t:"",onChange:this.onFileChange,disabled:r})}}(0,W.default)(Ra,"defaultProps",Na);class Pa extends h.Component{constructor(){super(...arguments),(0,W.default)(this,"onEnumChange",(e=>this.props.onChange(e)))}render(){let{getComponent:e,value:t,errors:a,schema:n,required:r,disabled:s}=this.props;a=a.toJS?a.toJS():[];let o=n&&n.get?n.get("enum"):null,l=!o||!r,i=!o&&["true","false"];const c=e("Select");return h.default.createElement(c,{className:a.length?"invalid":"",title:a.length?a:"",value:String(t),disabled:s,allowedValues:o?[...o]:i,allowEmptyValue:l,onChange:this.onEnumChange})}}(0,W.default)(Pa,"defaultProps",Na);const Ta=e=>(0,m.default)(e).call(e,(e=>{const t=void 0!==e.propKey?e.propKey:e.index;let a="string"==typeof e?e:"string"==typeof e.error?e.error:null;if(!t&&a)return a;let n=e.error,r=`/${e.propKey}`;for(;"object"==typeof n;){const e=void 0!==n.propKey?n.propKey:n.index;if(void 0===e)break;if(r+=`/${e}`,!n.error)break;n=n.error}return`${r}: ${n}`}));class Ma extends h.PureComponent{constructor(){super(),(0,W.default)(this,"onChange",(e=>{this.props.onChange(e)})),(0,W.default)(this,"handleOnChange",(e=>{const t=e.target.value;this.onChange(t)}))}render(){let{getComponent:e,value:t,errors:a,disabled:n}=this.props;const r=e("TextArea");return a=a.toJS?a.toJS():(0,d.default)(a)?a:[],h.default.createElement("div",null,h.default.createElement(r,{className:(0,Te.default)({invalid:a.length}),title:a.length?Ta(a).join(", "):"",value:(0,x.Pz)(t),disabled:n,onChange:this.handleOnChange}))}}function Da(e){return y.List.isList(e)?e:(0,d.default)(e)?(0,y.fromJS)(e):(0,y.List)()}function La(){let a={components:{App:Y,authorizationPopup:X,authorizeBtn:Q,AuthorizeBtnContainer:ee,authorizeOperationBtn:te,auths:ae,AuthItem:ne,authError:re,oauth2:ge,apiKeyAuth:se,basicAuth:oe,clear:ye,liveResponse:Se,InitializedInput:Ut,info:Kt,InfoContainer:Ft,InfoUrl:$t,InfoBasePath:Jt,Contact:Wt,License:Gt,JumpToPath:Zt,CopyToClipboardBtn:Yt,onlineValidatorBadge:we.Z,operations:be,operation:Ce,OperationSummary:Ne,OperationSummaryMethod:Oe,OperationSummaryPath:Ae,highlightCode:Je,responses:$e,response:Fe,ResponseExtension:ze,responseBody:Ze,parameters:it,parameterRow:ht,execute:yt,headers:vt,errors:Et,contentType:xt,overview:qt,footer:Xt,FilterContainer:Qt,ParamBody:ta,curl:na,schemes:ra,SchemesContainer:sa,modelExample:ca,ModelWrapper:ua,ModelCollapse:oa,Model:da.Z,Models:pa,EnumModel:ma,ObjectModel:fa,ArrayModel:ha,PrimitiveModel:ya,Property:va,TryItOutButton:Ea,Markdown:_a.Z,BaseLayout:Ca,VersionPragmaFilter:Sa,VersionStamp:wa,OperationExt:Ie,OperationExtRow:Re,ParameterExt:ct,ParameterIncludeEmpty:mt,OperationTag:_e,OperationContainer:Z,DeepLink:ba,SvgAssets:xa,Example:le,ExamplesSelect:ce,ExamplesSelectValueRetainer:de}},n={components:e},r={components:t};return[J.default,B.default,D.default,P.default,R.default,A.default,I.default,T.default,a,n,L.default,r,q.default,U.default,$.default,V.default,K.default,M.default,z.default,(0,F.default)()]}(0,W.default)(Ma,"defaultProps",Na);var qa=ut(7451),Ba=ut(9806),Ua=ut(7139);function Ja(){return[La,qa.default,Ua.default,Ba.default]}var $a=ut(5308);const{GIT_DIRTY:Va,GIT_COMMIT:Ka,PACKAGE_VERSION:Fa,BUILD_TIME:za}={PACKAGE_VERSION:"5.3.1",GIT_COMMIT:"g733e51a",GIT_DIRTY:!0,BUILD_TIME:"Wed, 02 Aug 2023 13:12:32 GMT"};function Wa(e){var t;b.Z.versions=b.Z.versions||{},b.Z.versions.swaggerUi={version:Fa,gitRevision:Ka,gitDirty:Va,buildTimestamp:za};const o={dom_id:null,domNode:null,spec:{},url:"",urls:null,layout:"BaseLayout",docExpansion:"list",maxDisplayedTags:null,filter:null,validatorUrl:"https://validator.swagger.io/validator",oauth2RedirectUrl:`${window.location.protocol}//${window.location.host}${window.location.pathname.substring(0,(0,a.default)(t=window.location.pathname).call(t,"/"))}/oauth2-redirect.html`,persistAuthorization:!1,configs:{},custom:{},displayOperationId:!1,displayRequestDuration:!1,deepLinking:!1,tryItOutEnabled:!1,requestInterceptor:e=>e,responseInterceptor:e=>e,showMutatedRequest:!0,defaultModelRendering:"example",defaultModelExpandDepth:1,defaultModelsExpandDepth:1,showExtensions:!1,showCommonExtensions:!1,withCredentials:void 0,requestSnippetsEnabled:!1,requestSnippets:{generators:{curl_bash:{title:"cURL (bash)",syntax:"bash"},curl_powershell:{title:"cURL (PowerShell)",syntax:"powershell"},curl_cmd:{title:"cURL (CMD)",syntax:"bash"}},defaultExpanded:!0,languages:null},supportedSubmitMethods:["get","put","post","delete","options","head","patch","trace"],queryConfigEnabled:!1,presets:[Ja],plugins:[],pluginsOptions:{pluginLoadType:"legacy"},initialState:{},fn:{},components:{},syntaxHighlight:{activated:!0,theme:"agate"}};let i=e.queryConfigEnabled?(0,x.UG)():{};const c=e.domNode;delete e.domNode;const u=l()({},o,e,i),d={system:{configs:u.configs},plugins:u.presets,pluginsOptions:u.pluginsOptions,state:l()({layout:{layout:u.layout,filter:(0,n.default)(u)},spec:{spec:"",url:u.url},requestSnippets:u.requestSnippets},u.initialState)};if(u.initialState)for(var p in u.initialState)Object.prototype.hasOwnProperty.call(u.initialState,p)&&void 0===u.initialState[p]&&delete d.state[p];var m=new C(d);m.register([u.plugins,()=>({fn:u.fn,components:u.components,state:u.state})]);var f=m.getSystem();const h=e=>{let t=f.specSelectors.getLocalConfig?f.specSelectors.getLocalConfig():{},a=l()({},t,u,e||{},i);if(c&&(a.domNode=c),m.setConfigs(a),f.configsActions.loaded(),null!==e&&(!i.url&&"object"==typeof a.spec&&(0,r.default)(a.spec).length?(f.specActions.updateUrl(""),f.specActions.updateLoadingStatus("success"),f.specActions.updateSpec((0,s.default)(a.spec))):f.specActions.download&&a.url&&!a.urls&&(f.specActions.updateUrl(a.url),f.specActions.download(a.url))),a.domNode)f.render(a.domNode,"App");else if(a.dom_id){let e=document.querySelector(a.dom_id);f.render(e,"App")}else null===a.dom_id||null===a.domNode||console.error("Skipped rendering: no `dom_id` or `domNode` was specified");return f},g=i.config||u.configUrl;return g&&f.specActions&&f.specActions.getConfigByUrl?(f.specActions.getConfigByUrl({url:g,loadRemoteConfig:!0,requestInterceptor:u.requestInterceptor,responseInterceptor:u.responseInterceptor},h),f):h()}Wa.presets={apis:Ja},Wa.plugins=$a.default;const Ha=Wa})();var pt=dt.Z;export{pt as default};
+//# sourceMappingURL=swagger-ui-es-bundle-core.js.map
\ No newline at end of file
@@ -0,0 +1,2 @@
+!function(e,t){"object"==typeof exports&&"object"==typeof module?module.exports=t():"function"==typeof define&&define.amd?define([],t):"object"==typeof exports?exports.SwaggerUICore=t():e.SwaggerUICore=t()}(this,(()=>(()=>{var e={6024:(e,t,n)=>{"use strict";n.d(t,{Z:()=>E});var s=n(4250),r=n.n(s),a=n(1093),o=n.n(a),l=n(8493),c=n.n(l),i=n(3942),u=n.n(i),p=n(6689),m=n.n(p);const d=require("react-immutable-pure-component");var h=n.n(d),g=n(8082),f=n.n(g),y=n(580),v=n.n(y);const S=e=>{const t=e.replace(/~1/g,"/").replace(/~0/g,"~");try{return decodeURIComponent(t)}catch{return t}};class E extends(h()){constructor(){super(...arguments),o()(this,"getModelName",(e=>-1!==c()(e).call(e,"#/definitions/")?S(e.replace(/^.*#\/definitions\//,"")):-1!==c()(e).call(e,"#/components/schemas/")?S(e.replace(/^.*#\/components\/schemas\//,"")):void 0)),o()(this,"getRefSchema",(e=>{let{specSelectors:t}=this.props;return t.findDefinition(e)}))}render(){let{getComponent:e,getConfigs:t,specSelectors:s,schema:a,required:o,name:l,isRef:c,specPath:i,displayName:u,includeReadOnly:p,includeWriteOnly:d}=this.props;const h=e("ObjectModel"),g=e("ArrayModel"),f=e("PrimitiveModel");let y="object",v=a&&a.get("$$ref");if(!l&&v&&(l=this.getModelName(v)),!a&&v&&(a=this.getRefSchema(l)),!a)return m().createElement("span",{className:"model model-title"},m().createElement("span",{className:"model-title__text"},u||l),m().createElement("img",{src:n(2517),height:"20px",width:"20px"}));const S=s.isOAS3()&&a.get("deprecated");switch(c=void 0!==c?c:!!v,y=a&&a.get("type")||y,y){case"object":return m().createElement(h,r()({className:"object"},this.props,{specPath:i,getConfigs:t,schema:a,name:l,deprecated:S,isRef:c,includeReadOnly:p,includeWriteOnly:d}));case"array":return m().createElement(g,r()({className:"array"},this.props,{getConfigs:t,schema:a,name:l,deprecated:S,required:o,includeReadOnly:p,includeWriteOnly:d}));default:return m().createElement(f,r()({},this.props,{getComponent:e,getConfigs:t,schema:a,name:l,deprecated:S,required:o}))}}}o()(E,"propTypes",{schema:u()(f()).isRequired,getComponent:v().func.isRequired,getConfigs:v().func.isRequired,specSelectors:v().object.isRequired,name:v().string,displayName:v().string,isRef:v().bool,required:v().bool,expandDepth:v().number,depth:v().number,specPath:f().list.isRequired,includeReadOnly:v().bool,includeWriteOnly:v().bool})},5623:(e,t,n)=>{"use strict";n.d(t,{Z:()=>d});var s=n(1093),r=n.n(s),a=n(7252),o=n.n(a),l=n(6689),c=n.n(l),i=n(3883),u=n.n(i),p=(n(580),n(1669)),m=n(7504);class d extends c().Component{constructor(e,t){super(e,t),r()(this,"getDefinitionUrl",(()=>{let{specSelectors:e}=this.props;return new(u())(e.url(),m.Z.location).toString()}));let{getConfigs:n}=e,{validatorUrl:s}=n();this.state={url:this.getDefinitionUrl(),validatorUrl:void 0===s?"https://validator.swagger.io/validator":s}}UNSAFE_componentWillReceiveProps(e){let{getConfigs:t}=e,{validatorUrl:n}=t();this.setState({url:this.getDefinitionUrl(),validatorUrl:void 0===n?"https://validator.swagger.io/validator":n})}render(){let{getConfigs:e}=this.props,{spec:t}=e(),n=(0,p.Nm)(this.state.validatorUrl);return"object"==typeof t&&o()(t).length?null:this.state.url&&(0,p.hW)(this.state.validatorUrl)&&(0,p.hW)(this.state.url)?c().createElement("span",{className:"float-right"},c().createElement("a",{target:"_blank",rel:"noopener noreferrer",href:`${n}/debug?url=${encodeURIComponent(this.state.url)}`},c().createElement(h,{src:`${n}?url=${encodeURIComponent(this.state.url)}`,alt:"Online validator badge"}))):null}}class h extends c().Component{constructor(e){super(e),this.state={loaded:!1,error:!1}}componentDidMount(){const e=new Image;e.onload=()=>{this.setState({loaded:!0})},e.onerror=()=>{this.setState({error:!0})},e.src=this.props.src}UNSAFE_componentWillReceiveProps(e){if(e.src!==this.props.src){const t=new Image;t.onload=()=>{this.setState({loaded:!0})},t.onerror=()=>{this.setState({error:!0})},t.src=e.src}}render(){return this.state.error?c().createElement("img",{alt:"Error"}):this.state.loaded?c().createElement("img",{src:this.props.src,alt:this.props.alt}):null}}},2552:(e,t,n)=>{"use strict";n.d(t,{Z:()=>m,s:()=>d});var s=n(6689),r=n.n(s),a=(n(580),n(963));const o=require("remarkable/linkify"),l=require("dompurify");var c=n.n(l),i=n(9003),u=n.n(i);function p(e){let{source:t,className:n="",getConfigs:s}=e;if("string"!=typeof t)return null;const l=new a.Remarkable({html:!0,typographer:!0,breaks:!0,linkTarget:"_blank"}).use(o.linkify);l.core.ruler.disable(["replacements","smartquotes"]);const{useUnsafeMarkdown:c}=s(),i=l.render(t),p=d(i,{useUnsafeMarkdown:c});return t&&i&&p?r().createElement("div",{className:u()(n,"markdown"),dangerouslySetInnerHTML:{__html:p}}):null}c().addHook&&c().addHook("beforeSanitizeElements",(function(e){return e.href&&e.setAttribute("rel","noopener noreferrer"),e})),p.defaultProps={getConfigs:()=>({useUnsafeMarkdown:!1})};const m=p;function d(e){let{useUnsafeMarkdown:t=!1}=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};const n=t,s=t?[]:["style","class"];return t&&!d.hasWarnedAboutDeprecation&&(console.warn("useUnsafeMarkdown display configuration parameter is deprecated since >3.26.0 and will be removed in v4.0.0."),d.hasWarnedAboutDeprecation=!0),c().sanitize(e,{ADD_ATTR:["target"],FORBID_TAGS:["style","form"],ALLOW_DATA_ATTR:n,FORBID_ATTR:s})}d.hasWarnedAboutDeprecation=!1},5308:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>m});var s,r=n(4235),a=n.n(r),o=n(874),l=n.n(o),c=n(1669),i=n(9595);const u=n(5102),p={},m=p;a()(s=l()(u).call(u)).call(s,(function(e){if("./index.js"===e)return;let t=u(e);p[(0,c.Zl)(e)]=t.default?t.default:t})),p.SafeRender=i.default},5812:(e,t,n)=>{"use strict";n.r(t),n.d(t,{AUTHORIZE:()=>m,AUTHORIZE_OAUTH2:()=>g,CONFIGURE_AUTH:()=>y,LOGOUT:()=>d,PRE_AUTHORIZE_OAUTH2:()=>h,RESTORE_AUTHORIZATION:()=>v,SHOW_AUTH_POPUP:()=>p,VALIDATE:()=>f,authPopup:()=>T,authorize:()=>E,authorizeAccessCodeWithBasicAuthentication:()=>A,authorizeAccessCodeWithFormParams:()=>k,authorizeApplication:()=>O,authorizeOauth2:()=>b,authorizeOauth2WithPersistOption:()=>_,authorizePassword:()=>N,authorizeRequest:()=>I,authorizeWithPersistOption:()=>w,configureAuth:()=>P,logout:()=>x,logoutWithPersistOption:()=>C,persistAuthorizationIfNeeded:()=>R,preAuthorizeImplicit:()=>j,restoreAuthorization:()=>q,showDefinitions:()=>S});var s=n(8344),r=n.n(s),a=n(4994),o=n.n(a),l=n(3883),c=n.n(l),i=n(7504),u=n(1669);const p="show_popup",m="authorize",d="logout",h="pre_authorize_oauth2",g="authorize_oauth2",f="validate",y="configure_auth",v="restore_authorization";function S(e){return{type:p,payload:e}}function E(e){return{type:m,payload:e}}const w=e=>t=>{let{authActions:n}=t;n.authorize(e),n.persistAuthorizationIfNeeded()};function x(e){return{type:d,payload:e}}const C=e=>t=>{let{authActions:n}=t;n.logout(e),n.persistAuthorizationIfNeeded()},j=e=>t=>{let{authActions:n,errActions:s}=t,{auth:a,token:o,isValid:l}=e,{schema:c,name:u}=a,p=c.get("flow");delete i.Z.swaggerUIRedirectOauth2,"accessCode"===p||l||s.newAuthErr({authId:u,source:"auth",level:"warning",message:"Authorization may be unsafe, passed state was changed in server Passed state wasn't returned from auth server"}),o.error?s.newAuthErr({authId:u,source:"auth",level:"error",message:r()(o)}):n.authorizeOauth2WithPersistOption({auth:a,token:o})};function b(e){return{type:g,payload:e}}const _=e=>t=>{let{authActions:n}=t;n.authorizeOauth2(e),n.persistAuthorizationIfNeeded()},N=e=>t=>{let{authActions:n}=t,{schema:s,name:r,username:a,password:l,passwordType:c,clientId:i,clientSecret:p}=e,m={grant_type:"password",scope:e.scopes.join(" "),username:a,password:l},d={};switch(c){case"request-body":!function(e,t,n){t&&o()(e,{client_id:t});n&&o()(e,{client_secret:n})}(m,i,p);break;case"basic":d.Authorization="Basic "+(0,u.r3)(i+":"+p);break;default:console.warn(`Warning: invalid passwordType ${c} was passed, not including client id and secret`)}return n.authorizeRequest({body:(0,u.GZ)(m),url:s.get("tokenUrl"),name:r,headers:d,query:{},auth:e})};const O=e=>t=>{let{authActions:n}=t,{schema:s,scopes:r,name:a,clientId:o,clientSecret:l}=e,c={Authorization:"Basic "+(0,u.r3)(o+":"+l)},i={grant_type:"client_credentials",scope:r.join(" ")};return n.authorizeRequest({body:(0,u.GZ)(i),name:a,url:s.get("tokenUrl"),auth:e,headers:c})},k=e=>{let{auth:t,redirectUrl:n}=e;return e=>{let{authActions:s}=e,{schema:r,name:a,clientId:o,clientSecret:l,codeVerifier:c}=t,i={grant_type:"authorization_code",code:t.code,client_id:o,client_secret:l,redirect_uri:n,code_verifier:c};return s.authorizeRequest({body:(0,u.GZ)(i),name:a,url:r.get("tokenUrl"),auth:t})}},A=e=>{let{auth:t,redirectUrl:n}=e;return e=>{let{authActions:s}=e,{schema:r,name:a,clientId:o,clientSecret:l,codeVerifier:c}=t,i={Authorization:"Basic "+(0,u.r3)(o+":"+l)},p={grant_type:"authorization_code",code:t.code,client_id:o,redirect_uri:n,code_verifier:c};return s.authorizeRequest({body:(0,u.GZ)(p),name:a,url:r.get("tokenUrl"),auth:t,headers:i})}},I=e=>t=>{let n,{fn:s,getConfigs:a,authActions:l,errActions:i,oas3Selectors:u,specSelectors:p,authSelectors:m}=t,{body:d,query:h={},headers:g={},name:f,url:y,auth:v}=e,{additionalQueryStringParams:S}=m.getConfigs()||{};if(p.isOAS3()){let e=u.serverEffectiveValue(u.selectedServer());n=c()(y,e,!0)}else n=c()(y,p.url(),!0);"object"==typeof S&&(n.query=o()({},n.query,S));const E=n.toString();let w=o()({Accept:"application/json, text/plain, */*","Content-Type":"application/x-www-form-urlencoded","X-Requested-With":"XMLHttpRequest"},g);s.fetch({url:E,method:"post",headers:w,query:h,body:d,requestInterceptor:a().requestInterceptor,responseInterceptor:a().responseInterceptor}).then((function(e){let t=JSON.parse(e.data),n=t&&(t.error||""),s=t&&(t.parseError||"");e.ok?n||s?i.newAuthErr({authId:f,level:"error",source:"auth",message:r()(t)}):l.authorizeOauth2WithPersistOption({auth:v,token:t}):i.newAuthErr({authId:f,level:"error",source:"auth",message:e.statusText})})).catch((e=>{let t=new Error(e).message;if(e.response&&e.response.data){const n=e.response.data;try{const e="string"==typeof n?JSON.parse(n):n;e.error&&(t+=`, error: ${e.error}`),e.error_description&&(t+=`, description: ${e.error_description}`)}catch(e){}}i.newAuthErr({authId:f,level:"error",source:"auth",message:t})}))};function P(e){return{type:y,payload:e}}function q(e){return{type:v,payload:e}}const R=()=>e=>{let{authSelectors:t,getConfigs:n}=e;if(!n().persistAuthorization)return;const s=t.authorized().toJS();localStorage.setItem("authorized",r()(s))},T=(e,t)=>()=>{i.Z.swaggerUIRedirectOauth2=t,i.Z.open(e)}},7105:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>u});var s=n(7252),r=n.n(s),a=n(6689),o=n.n(a),l=(n(580),n(3901)),c=n.n(l);class i extends o().Component{mapStateToProps(e,t){return{state:e,ownProps:c()(t,r()(t.getSystem()))}}render(){const{getComponent:e,ownProps:t}=this.props,n=e("LockIcon");return o().createElement(n,t)}}const u=i},3219:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>u});var s=n(7252),r=n.n(s),a=n(6689),o=n.n(a),l=(n(580),n(3901)),c=n.n(l);class i extends o().Component{mapStateToProps(e,t){return{state:e,ownProps:c()(t,r()(t.getSystem()))}}render(){const{getComponent:e,ownProps:t}=this.props,n=e("UnlockIcon");return o().createElement(n,t)}}const u=i},3779:(e,t,n)=>{"use strict";n.r(t),n.d(t,{loaded:()=>s});const s=(e,t)=>n=>{const{getConfigs:s,authActions:r}=t,a=s();if(e(n),a.persistAuthorization){const e=localStorage.getItem("authorized");e&&r.restoreAuthorization({authorized:JSON.parse(e)})}}},3705:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>d,preauthorizeApiKey:()=>g,preauthorizeBasic:()=>h});var s=n(593),r=n.n(s),a=n(3962),o=n(5812),l=n(35),c=n(489),i=n(3779),u=n(2849),p=n(7105),m=n(3219);function d(){return{afterLoad(e){this.rootInjects=this.rootInjects||{},this.rootInjects.initOAuth=e.authActions.configureAuth,this.rootInjects.preauthorizeApiKey=r()(g).call(g,null,e),this.rootInjects.preauthorizeBasic=r()(h).call(h,null,e)},components:{LockAuthIcon:p.default,UnlockAuthIcon:m.default,LockAuthOperationIcon:p.default,UnlockAuthOperationIcon:m.default},statePlugins:{auth:{reducers:a.default,actions:o,selectors:l,wrapActions:{authorize:u.authorize,logout:u.logout}},configs:{wrapActions:{loaded:i.loaded}},spec:{wrapActions:{execute:c.execute}}}}}function h(e,t,n,s){const{authActions:{authorize:r},specSelectors:{specJson:a,isOAS3:o}}=e,l=o()?["components","securitySchemes"]:["securityDefinitions"],c=a().getIn([...l,t]);return c?r({[t]:{value:{username:n,password:s},schema:c.toJS()}}):null}function g(e,t,n){const{authActions:{authorize:s},specSelectors:{specJson:r,isOAS3:a}}=e,o=a()?["components","securitySchemes"]:["securityDefinitions"],l=r().getIn([...o,t]);return l?s({[t]:{value:n,schema:l.toJS()}}):null}},3962:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>u});var s=n(4235),r=n.n(s),a=n(4994),o=n.n(a),l=n(5572),c=n(1669),i=n(5812);const u={[i.SHOW_AUTH_POPUP]:(e,t)=>{let{payload:n}=t;return e.set("showDefinitions",n)},[i.AUTHORIZE]:(e,t)=>{var n;let{payload:s}=t,a=(0,l.fromJS)(s),o=e.get("authorized")||(0,l.Map)();return r()(n=a.entrySeq()).call(n,(t=>{let[n,s]=t;if(!(0,c.Wl)(s.getIn))return e.set("authorized",o);let r=s.getIn(["schema","type"]);if("apiKey"===r||"http"===r)o=o.set(n,s);else if("basic"===r){let e=s.getIn(["value","username"]),t=s.getIn(["value","password"]);o=o.setIn([n,"value"],{username:e,header:"Basic "+(0,c.r3)(e+":"+t)}),o=o.setIn([n,"schema"],s.get("schema"))}})),e.set("authorized",o)},[i.AUTHORIZE_OAUTH2]:(e,t)=>{let n,{payload:s}=t,{auth:r,token:a}=s;r.token=o()({},a),n=(0,l.fromJS)(r);let c=e.get("authorized")||(0,l.Map)();return c=c.set(n.get("name"),n),e.set("authorized",c)},[i.LOGOUT]:(e,t)=>{let{payload:n}=t,s=e.get("authorized").withMutations((e=>{r()(n).call(n,(t=>{e.delete(t)}))}));return e.set("authorized",s)},[i.CONFIGURE_AUTH]:(e,t)=>{let{payload:n}=t;return e.set("configs",n)},[i.RESTORE_AUTHORIZATION]:(e,t)=>{let{payload:n}=t;return e.set("authorized",(0,l.fromJS)(n.authorized))}}},35:(e,t,n)=>{"use strict";n.r(t),n.d(t,{authorized:()=>x,definitionsForRequirements:()=>w,definitionsToAuthorize:()=>S,getConfigs:()=>j,getDefinitionsByNames:()=>E,isAuthorized:()=>C,shownDefinitions:()=>v});var s=n(4235),r=n.n(s),a=n(3580),o=n.n(a),l=n(9998),c=n.n(l),i=n(8493),u=n.n(i),p=n(3942),m=n.n(p),d=n(7252),h=n.n(d),g=n(6814),f=n(5572);const y=e=>e,v=(0,g.createSelector)(y,(e=>e.get("showDefinitions"))),S=(0,g.createSelector)(y,(()=>e=>{var t;let{specSelectors:n}=e,s=n.securityDefinitions()||(0,f.Map)({}),a=(0,f.List)();return r()(t=s.entrySeq()).call(t,(e=>{let[t,n]=e,s=(0,f.Map)();s=s.set(t,n),a=a.push(s)})),a})),E=(e,t)=>e=>{var n;let{specSelectors:s}=e;console.warn("WARNING: getDefinitionsByNames is deprecated and will be removed in the next major version.");let a=s.securityDefinitions(),o=(0,f.List)();return r()(n=t.valueSeq()).call(n,(e=>{var t;let n=(0,f.Map)();r()(t=e.entrySeq()).call(t,(e=>{let t,[s,o]=e,l=a.get(s);var c;"oauth2"===l.get("type")&&o.size&&(t=l.get("scopes"),r()(c=t.keySeq()).call(c,(e=>{o.contains(e)||(t=t.delete(e))})),l=l.set("allowedScopes",t));n=n.set(s,l)})),o=o.push(n)})),o},w=function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:(0,f.List)();return e=>{let{authSelectors:n}=e;const s=n.definitionsToAuthorize()||(0,f.List)();let a=(0,f.List)();return r()(s).call(s,(e=>{let n=o()(t).call(t,(t=>t.get(e.keySeq().first())));n&&(r()(e).call(e,((t,s)=>{if("oauth2"===t.get("type")){const o=n.get(s);let l=t.get("scopes");var a;if(f.List.isList(o)&&f.Map.isMap(l))r()(a=l.keySeq()).call(a,(e=>{o.contains(e)||(l=l.delete(e))})),e=e.set(s,t.set("scopes",l))}})),a=a.push(e))})),a}},x=(0,g.createSelector)(y,(e=>e.get("authorized")||(0,f.Map)())),C=(e,t)=>e=>{var n;let{authSelectors:s}=e,r=s.authorized();return f.List.isList(t)?!!c()(n=t.toJS()).call(n,(e=>{var t,n;return-1===u()(t=m()(n=h()(e)).call(n,(e=>!!r.get(e)))).call(t,!1)})).length:null},j=(0,g.createSelector)(y,(e=>e.get("configs")))},489:(e,t,n)=>{"use strict";n.r(t),n.d(t,{execute:()=>s});const s=(e,t)=>{let{authSelectors:n,specSelectors:s}=t;return t=>{let{path:r,method:a,operation:o,extras:l}=t,c={authorized:n.authorized()&&n.authorized().toJS(),definitions:s.securityDefinitions()&&s.securityDefinitions().toJS(),specSecurity:s.security()&&s.security().toJS()};return e({path:r,method:a,operation:o,securities:c,...l})}}},2849:(e,t,n)=>{"use strict";n.r(t),n.d(t,{authorize:()=>i,logout:()=>u});var s=n(9968),r=n.n(s),a=n(7104),o=n.n(a),l=n(4235),c=n.n(l);const i=(e,t)=>n=>{e(n);if(t.getConfigs().persistAuthorization)try{const[{schema:e,value:t}]=r()(n),s="apiKey"===e.get("type"),a="cookie"===e.get("in");s&&a&&(document.cookie=`${e.get("name")}=${t}; SameSite=None; Secure`)}catch(e){console.error("Error persisting cookie based apiKey in document.cookie.",e)}},u=(e,t)=>n=>{const s=t.getConfigs(),r=t.authSelectors.authorized();try{s.persistAuthorization&&o()(n)&&c()(n).call(n,(e=>{const t=r.get(e,{}),n="apiKey"===t.getIn(["schema","type"]),s="cookie"===t.getIn(["schema","in"]);if(n&&s){const e=t.getIn(["schema","name"]);document.cookie=`${e}=; Max-Age=-99999999`}}))}catch(e){console.error("Error deleting cookie based apiKey from document.cookie.",e)}e(n)}},714:(e,t,n)=>{"use strict";n.r(t),n.d(t,{TOGGLE_CONFIGS:()=>r,UPDATE_CONFIGS:()=>s,loaded:()=>l,toggle:()=>o,update:()=>a});const s="configs_update",r="configs_toggle";function a(e,t){return{type:s,payload:{[e]:t}}}function o(e){return{type:r,payload:e}}const l=()=>()=>{}},2256:(e,t,n)=>{"use strict";n.r(t),n.d(t,{parseYamlConfig:()=>a});var s=n(9793),r=n.n(s);const a=(e,t)=>{try{return r().load(e)}catch(e){return t&&t.errActions.newThrownErr(new Error(e)),{}}}},6709:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>i});var s=n(2256),r=n(714),a=n(2698),o=n(9018),l=n(7743);const c={getLocalConfig:()=>(0,s.parseYamlConfig)('---\nurl: "https://petstore.swagger.io/v2/swagger.json"\ndom_id: "#swagger-ui"\nvalidatorUrl: "https://validator.swagger.io/validator"\n')};function i(){return{statePlugins:{spec:{actions:a,selectors:c},configs:{reducers:l.default,actions:r,selectors:o}}}}},7743:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(5572),r=n(714);const a={[r.UPDATE_CONFIGS]:(e,t)=>e.merge((0,s.fromJS)(t.payload)),[r.TOGGLE_CONFIGS]:(e,t)=>{const n=t.payload,s=e.get(n);return e.set(n,!s)}}},9018:(e,t,n)=>{"use strict";n.r(t),n.d(t,{get:()=>a});var s=n(7104),r=n.n(s);const a=(e,t)=>e.getIn(r()(t)?t:[t])},2698:(e,t,n)=>{"use strict";n.r(t),n.d(t,{downloadConfig:()=>r,getConfigByUrl:()=>a});var s=n(2256);const r=e=>t=>{const{fn:{fetch:n}}=t;return n(e)},a=(e,t)=>n=>{let{specActions:r}=n;if(e)return r.downloadConfig(e).then(a,a);function a(n){n instanceof Error||n.status>=400?(r.updateLoadingStatus("failedConfig"),r.updateLoadingStatus("failedConfig"),r.updateUrl(""),console.error(n.statusText+" "+e.url),t(null)):t((0,s.parseYamlConfig)(n.text))}}},1970:(e,t,n)=>{"use strict";n.r(t),n.d(t,{setHash:()=>s});const s=e=>e?history.pushState(null,null,`#${e}`):window.location.hash=""},4980:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(2179),r=n(877),a=n(4584);function o(){return[s.default,{statePlugins:{configs:{wrapActions:{loaded:(e,t)=>function(){e(...arguments);const n=decodeURIComponent(window.location.hash);t.layoutActions.parseDeepLinkHash(n)}}}},wrapComponents:{operation:r.default,OperationTag:a.default}}]}},2179:(e,t,n)=>{"use strict";n.r(t),n.d(t,{clearScrollTo:()=>j,default:()=>b,parseDeepLinkHash:()=>w,readyToScroll:()=>x,scrollTo:()=>E,scrollToElement:()=>C,show:()=>S});var s=n(7104),r=n.n(s),a=n(600),o=n.n(a),l=n(3942),c=n.n(l),i=n(8493),u=n.n(i),p=n(1970);const m=require("zenscroll");var d=n.n(m),h=n(1669),g=n(5572),f=n.n(g);const y="layout_scroll_to",v="layout_clear_scroll",S=(e,t)=>{let{getConfigs:n,layoutSelectors:s}=t;return function(){for(var t=arguments.length,a=new Array(t),o=0;o<t;o++)a[o]=arguments[o];if(e(...a),n().deepLinking)try{let[e,t]=a;e=r()(e)?e:[e];const n=s.urlHashArrayFromIsShownKey(e);if(!n.length)return;const[o,l]=n;if(!t)return(0,p.setHash)("/");2===n.length?(0,p.setHash)((0,h.oJ)(`/${encodeURIComponent(o)}/${encodeURIComponent(l)}`)):1===n.length&&(0,p.setHash)((0,h.oJ)(`/${encodeURIComponent(o)}`))}catch(e){console.error(e)}}},E=e=>({type:y,payload:r()(e)?e:[e]}),w=e=>t=>{let{layoutActions:n,layoutSelectors:s,getConfigs:r}=t;if(r().deepLinking&&e){var a;let t=o()(e).call(e,1);"!"===t[0]&&(t=o()(t).call(t,1)),"/"===t[0]&&(t=o()(t).call(t,1));const r=c()(a=t.split("/")).call(a,(e=>e||"")),l=s.isShownKeyFromUrlHashArray(r),[i,p="",m=""]=l;if("operations"===i){const e=s.isShownKeyFromUrlHashArray([p]);u()(p).call(p,"_")>-1&&(console.warn("Warning: escaping deep link whitespace with `_` will be unsupported in v4.0, use `%20` instead."),n.show(c()(e).call(e,(e=>e.replace(/_/g," "))),!0)),n.show(e,!0)}(u()(p).call(p,"_")>-1||u()(m).call(m,"_")>-1)&&(console.warn("Warning: escaping deep link whitespace with `_` will be unsupported in v4.0, use `%20` instead."),n.show(c()(l).call(l,(e=>e.replace(/_/g," "))),!0)),n.show(l,!0),n.scrollTo(l)}},x=(e,t)=>n=>{const s=n.layoutSelectors.getScrollToKey();f().is(s,(0,g.fromJS)(e))&&(n.layoutActions.scrollToElement(t),n.layoutActions.clearScrollTo())},C=(e,t)=>n=>{try{t=t||n.fn.getScrollParent(e),d().createScroller(t).to(e)}catch(e){console.error(e)}},j=()=>({type:v});const b={fn:{getScrollParent:function(e,t){const n=document.documentElement;let s=getComputedStyle(e);const r="absolute"===s.position,a=t?/(auto|scroll|hidden)/:/(auto|scroll)/;if("fixed"===s.position)return n;for(let t=e;t=t.parentElement;)if(s=getComputedStyle(t),(!r||"static"!==s.position)&&a.test(s.overflow+s.overflowY+s.overflowX))return t;return n}},statePlugins:{layout:{actions:{scrollToElement:C,scrollTo:E,clearScrollTo:j,readyToScroll:x,parseDeepLinkHash:w},selectors:{getScrollToKey:e=>e.get("scrollToKey"),isShownKeyFromUrlHashArray(e,t){const[n,s]=t;return s?["operations",n,s]:n?["operations-tag",n]:[]},urlHashArrayFromIsShownKey(e,t){let[n,s,r]=t;return"operations"==n?[s,r]:"operations-tag"==n?[s]:[]}},reducers:{[y]:(e,t)=>e.set("scrollToKey",f().fromJS(t.payload)),[v]:e=>e.delete("scrollToKey")},wrapActions:{show:S}}}}},4584:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>l});var s=n(1093),r=n.n(s),a=n(6689),o=n.n(a);n(580);const l=(e,t)=>class extends o().Component{constructor(){super(...arguments),r()(this,"onLoad",(e=>{const{tag:n}=this.props,s=["operations-tag",n];t.layoutActions.readyToScroll(s,e)}))}render(){return o().createElement("span",{ref:this.onLoad},o().createElement(e,this.props))}}},877:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>l});var s=n(1093),r=n.n(s),a=n(6689),o=n.n(a);n(8082);const l=(e,t)=>class extends o().Component{constructor(){super(...arguments),r()(this,"onLoad",(e=>{const{operation:n}=this.props,{tag:s,operationId:r}=n.toObject();let{isShownKey:a}=n.toObject();a=a||["operations",s,r],t.layoutActions.readyToScroll(a,e)}))}render(){return o().createElement("span",{ref:this.onLoad},o().createElement(e,this.props))}}},8011:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>h});var s=n(4994),r=n.n(s),a=n(9478),o=n.n(a),l=n(8493),c=n.n(l),i=n(8344),u=n.n(i),p=n(6814),m=n(5572),d=n(7504);function h(e){let{fn:t}=e;return{statePlugins:{spec:{actions:{download:e=>n=>{let{errActions:s,specSelectors:a,specActions:l,getConfigs:c}=n,{fetch:i}=t;const u=c();function p(t){if(t instanceof Error||t.status>=400)return l.updateLoadingStatus("failed"),s.newThrownErr(r()(new Error((t.message||t.statusText)+" "+e),{source:"fetch"})),void(!t.status&&t instanceof Error&&function(){try{let t;if("URL"in d.Z?t=new(o())(e):(t=document.createElement("a"),t.href=e),"https:"!==t.protocol&&"https:"===d.Z.location.protocol){const e=r()(new Error(`Possible mixed-content issue? The page was loaded over https:// but a ${t.protocol}// URL was specified. Check that you are not attempting to load mixed content.`),{source:"fetch"});return void s.newThrownErr(e)}if(t.origin!==d.Z.location.origin){const e=r()(new Error(`Possible cross-origin (CORS) issue? The URL origin (${t.origin}) does not match the page (${d.Z.location.origin}). Check the server returns the correct 'Access-Control-Allow-*' headers.`),{source:"fetch"});s.newThrownErr(e)}}catch(e){return}}());l.updateLoadingStatus("success"),l.updateSpec(t.text),a.url()!==e&&l.updateUrl(e)}e=e||a.url(),l.updateLoadingStatus("loading"),s.clear({source:"fetch"}),i({url:e,loadSpec:!0,requestInterceptor:u.requestInterceptor||(e=>e),responseInterceptor:u.responseInterceptor||(e=>e),credentials:"same-origin",headers:{Accept:"application/json,*/*"}}).then(p,p)},updateLoadingStatus:e=>{let t=[null,"loading","failed","success","failedConfig"];return-1===c()(t).call(t,e)&&console.error(`Error: ${e} is not one of ${u()(t)}`),{type:"spec_update_loading_status",payload:e}}},reducers:{spec_update_loading_status:(e,t)=>"string"==typeof t.payload?e.set("loadingStatus",t.payload):e},selectors:{loadingStatus:(0,p.createSelector)((e=>e||(0,m.Map)()),(e=>e.get("loadingStatus")||null))}}}}}},4966:(e,t,n)=>{"use strict";n.r(t),n.d(t,{CLEAR:()=>i,CLEAR_BY:()=>u,NEW_AUTH_ERR:()=>c,NEW_SPEC_ERR:()=>o,NEW_SPEC_ERR_BATCH:()=>l,NEW_THROWN_ERR:()=>r,NEW_THROWN_ERR_BATCH:()=>a,clear:()=>f,clearBy:()=>y,newAuthErr:()=>g,newSpecErr:()=>d,newSpecErrBatch:()=>h,newThrownErr:()=>p,newThrownErrBatch:()=>m});var s=n(41);const r="err_new_thrown_err",a="err_new_thrown_err_batch",o="err_new_spec_err",l="err_new_spec_err_batch",c="err_new_auth_err",i="err_clear",u="err_clear_by";function p(e){return{type:r,payload:(0,s.serializeError)(e)}}function m(e){return{type:a,payload:e}}function d(e){return{type:o,payload:e}}function h(e){return{type:l,payload:e}}function g(e){return{type:c,payload:e}}function f(){return{type:i,payload:arguments.length>0&&void 0!==arguments[0]?arguments[0]:{}}}function y(){return{type:u,payload:arguments.length>0&&void 0!==arguments[0]?arguments[0]:()=>!0}}},2860:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>u});var s=n(9998),r=n.n(s),a=n(3942),o=n.n(a);const l=require("lodash/reduce");var c=n.n(l);const i=[n(2392),n(1835)];function u(e){var t;let n={jsSpec:{}},s=c()(i,((e,t)=>{try{let s=t.transform(e,n);return r()(s).call(s,(e=>!!e))}catch(t){return console.error("Transformer error:",t),e}}),e);return o()(t=r()(s).call(s,(e=>!!e))).call(t,(e=>(!e.get("line")&&e.get("path"),e)))}},2392:(e,t,n)=>{"use strict";n.r(t),n.d(t,{transform:()=>p});var s=n(3942),r=n.n(s),a=n(8493),o=n.n(a),l=n(600),c=n.n(l),i=n(66),u=n.n(i);function p(e){return r()(e).call(e,(e=>{var t;let n="is not of a type(s)",s=o()(t=e.get("message")).call(t,n);if(s>-1){var r,a;let t=c()(r=e.get("message")).call(r,s+19).split(",");return e.set("message",c()(a=e.get("message")).call(a,0,s)+function(e){return u()(e).call(e,((e,t,n,s)=>n===s.length-1&&s.length>1?e+"or "+t:s[n+1]&&s.length>2?e+t+", ":s[n+1]?e+t+" ":e+t),"should be a")}(t))}return e}))}},1835:(e,t,n)=>{"use strict";n.r(t),n.d(t,{transform:()=>s});n(3942),n(8493),n(1712),n(5572);function s(e,t){let{jsSpec:n}=t;return e}},7793:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(3527),r=n(4966),a=n(7667);function o(e){return{statePlugins:{err:{reducers:(0,s.default)(e),actions:r,selectors:a}}}}},3527:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>y});var s=n(4994),r=n.n(s),a=n(3942),o=n.n(a),l=n(4883),c=n.n(l),i=n(9998),u=n.n(i),p=n(7834),m=n.n(p),d=n(4966),h=n(5572),g=n(2860);let f={line:0,level:"error",message:"Unknown error"};function y(){return{[d.NEW_THROWN_ERR]:(e,t)=>{let{payload:n}=t,s=r()(f,n,{type:"thrown"});return e.update("errors",(e=>(e||(0,h.List)()).push((0,h.fromJS)(s)))).update("errors",(e=>(0,g.default)(e)))},[d.NEW_THROWN_ERR_BATCH]:(e,t)=>{let{payload:n}=t;return n=o()(n).call(n,(e=>(0,h.fromJS)(r()(f,e,{type:"thrown"})))),e.update("errors",(e=>{var t;return c()(t=e||(0,h.List)()).call(t,(0,h.fromJS)(n))})).update("errors",(e=>(0,g.default)(e)))},[d.NEW_SPEC_ERR]:(e,t)=>{let{payload:n}=t,s=(0,h.fromJS)(n);return s=s.set("type","spec"),e.update("errors",(e=>(e||(0,h.List)()).push((0,h.fromJS)(s)).sortBy((e=>e.get("line"))))).update("errors",(e=>(0,g.default)(e)))},[d.NEW_SPEC_ERR_BATCH]:(e,t)=>{let{payload:n}=t;return n=o()(n).call(n,(e=>(0,h.fromJS)(r()(f,e,{type:"spec"})))),e.update("errors",(e=>{var t;return c()(t=e||(0,h.List)()).call(t,(0,h.fromJS)(n))})).update("errors",(e=>(0,g.default)(e)))},[d.NEW_AUTH_ERR]:(e,t)=>{let{payload:n}=t,s=(0,h.fromJS)(r()({},n));return s=s.set("type","auth"),e.update("errors",(e=>(e||(0,h.List)()).push((0,h.fromJS)(s)))).update("errors",(e=>(0,g.default)(e)))},[d.CLEAR]:(e,t)=>{var n;let{payload:s}=t;if(!s||!e.get("errors"))return e;let r=u()(n=e.get("errors")).call(n,(e=>{var t;return m()(t=e.keySeq()).call(t,(t=>{const n=e.get(t),r=s[t];return!r||n!==r}))}));return e.merge({errors:r})},[d.CLEAR_BY]:(e,t)=>{var n;let{payload:s}=t;if(!s||"function"!=typeof s)return e;let r=u()(n=e.get("errors")).call(n,(e=>s(e)));return e.merge({errors:r})}}}},7667:(e,t,n)=>{"use strict";n.r(t),n.d(t,{allErrors:()=>a,lastError:()=>o});var s=n(5572),r=n(6814);const a=(0,r.createSelector)((e=>e),(e=>e.get("errors",(0,s.List)()))),o=(0,r.createSelector)(a,(e=>e.last()))},9978:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>r});var s=n(4309);function r(){return{fn:{opsFilter:s.default}}}},4309:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>l});var s=n(9998),r=n.n(s),a=n(8493),o=n.n(a);function l(e,t){return r()(e).call(e,((e,n)=>-1!==o()(n).call(n,t)))}},6395:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>c});var s=n(4250),r=n.n(s),a=n(6689),o=n.n(a);n(580);const l=e=>{let{className:t,width:n,height:s,...a}=e;return o().createElement("svg",r()({xmlns:"http://www.w3.org/2000/svg",viewBox:"0 0 20 20",className:t,width:n,height:s,"aria-hidden":"true",focusable:"false"},a),o().createElement("path",{d:"M17.418 6.109c.272-.268.709-.268.979 0s.271.701 0 .969l-7.908 7.83c-.27.268-.707.268-.979 0l-7.908-7.83c-.27-.268-.27-.701 0-.969.271-.268.709-.268.979 0L10 13.25l7.418-7.141z"}))};l.defaultProps={className:null,width:20,height:20};const c=l},9689:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>c});var s=n(4250),r=n.n(s),a=n(6689),o=n.n(a);n(580);const l=e=>{let{className:t,width:n,height:s,...a}=e;return o().createElement("svg",r()({xmlns:"http://www.w3.org/2000/svg",viewBox:"0 0 20 20",className:t,width:n,height:s,"aria-hidden":"true",focusable:"false"},a),o().createElement("path",{d:"M 17.418 14.908 C 17.69 15.176 18.127 15.176 18.397 14.908 C 18.667 14.64 18.668 14.207 18.397 13.939 L 10.489 6.109 C 10.219 5.841 9.782 5.841 9.51 6.109 L 1.602 13.939 C 1.332 14.207 1.332 14.64 1.602 14.908 C 1.873 15.176 2.311 15.176 2.581 14.908 L 10 7.767 L 17.418 14.908 Z"}))};l.defaultProps={className:null,width:20,height:20};const c=l},6984:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>c});var s=n(4250),r=n.n(s),a=n(6689),o=n.n(a);n(580);const l=e=>{let{className:t,width:n,height:s,...a}=e;return o().createElement("svg",r()({xmlns:"http://www.w3.org/2000/svg",viewBox:"0 0 20 20",className:t,width:n,height:s,"aria-hidden":"true",focusable:"false"},a),o().createElement("path",{d:"M13.25 10L6.109 2.58c-.268-.27-.268-.707 0-.979.268-.27.701-.27.969 0l7.83 7.908c.268.271.268.709 0 .979l-7.83 7.908c-.268.271-.701.27-.969 0-.268-.269-.268-.707 0-.979L13.25 10z"}))};l.defaultProps={className:null,width:20,height:20};const c=l},2478:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>c});var s=n(4250),r=n.n(s),a=n(6689),o=n.n(a);n(580);const l=e=>{let{className:t,width:n,height:s,...a}=e;return o().createElement("svg",r()({xmlns:"http://www.w3.org/2000/svg",viewBox:"0 0 20 20",className:t,width:n,height:s,"aria-hidden":"true",focusable:"false"},a),o().createElement("path",{d:"M14.348 14.849c-.469.469-1.229.469-1.697 0L10 11.819l-2.651 3.029c-.469.469-1.229.469-1.697 0-.469-.469-.469-1.229 0-1.697l2.758-3.15-2.759-3.152c-.469-.469-.469-1.228 0-1.697.469-.469 1.228-.469 1.697 0L10 8.183l2.651-3.031c.469-.469 1.228-.469 1.697 0 .469.469.469 1.229 0 1.697l-2.758 3.152 2.758 3.15c.469.469.469 1.229 0 1.698z"}))};l.defaultProps={className:null,width:20,height:20};const c=l},3388:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>c});var s=n(4250),r=n.n(s),a=n(6689),o=n.n(a);n(580);const l=e=>{let{className:t,width:n,height:s,...a}=e;return o().createElement("svg",r()({xmlns:"http://www.w3.org/2000/svg",viewBox:"0 0 15 16",className:t,width:n,height:s,"aria-hidden":"true",focusable:"false"},a),o().createElement("g",{transform:"translate(2, -1)"},o().createElement("path",{fill:"#ffffff",fillRule:"evenodd",d:"M2 13h4v1H2v-1zm5-6H2v1h5V7zm2 3V8l-3 3 3 3v-2h5v-2H9zM4.5 9H2v1h2.5V9zM2 12h2.5v-1H2v1zm9 1h1v2c-.02.28-.11.52-.3.7-.19.18-.42.28-.7.3H1c-.55 0-1-.45-1-1V4c0-.55.45-1 1-1h3c0-1.11.89-2 2-2 1.11 0 2 .89 2 2h3c.55 0 1 .45 1 1v5h-1V6H1v9h10v-2zM2 5h8c0-.55-.45-1-1-1H8c-.55 0-1-.45-1-1s-.45-1-1-1-1 .45-1 1-.45 1-1 1H3c-.55 0-1 .45-1 1z"})))};l.defaultProps={className:null,width:15,height:16};const c=l},6945:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>c});var s=n(4250),r=n.n(s),a=n(6689),o=n.n(a);n(580);const l=e=>{let{className:t,width:n,height:s,...a}=e;return o().createElement("svg",r()({xmlns:"http://www.w3.org/2000/svg",viewBox:"0 0 20 20",className:t,width:n,height:s,"aria-hidden":"true",focusable:"false"},a),o().createElement("path",{d:"M15.8 8H14V5.6C14 2.703 12.665 1 10 1 7.334 1 6 2.703 6 5.6V8H4c-.553 0-1 .646-1 1.199V17c0 .549.428 1.139.951 1.307l1.197.387C5.672 18.861 6.55 19 7.1 19h5.8c.549 0 1.428-.139 1.951-.307l1.196-.387c.524-.167.953-.757.953-1.306V9.199C17 8.646 16.352 8 15.8 8zM12 8H8V5.199C8 3.754 8.797 3 10 3c1.203 0 2 .754 2 2.199V8z"}))};l.defaultProps={className:null,width:20,height:20};const c=l},2568:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>c});var s=n(4250),r=n.n(s),a=n(6689),o=n.n(a);n(580);const l=e=>{let{className:t,width:n,height:s,...a}=e;return o().createElement("svg",r()({xmlns:"http://www.w3.org/2000/svg",viewBox:"0 0 20 20",className:t,width:n,height:s,"aria-hidden":"true",focusable:"false"},a),o().createElement("path",{d:"M15.8 8H14V5.6C14 2.703 12.665 1 10 1 7.334 1 6 2.703 6 5.6V6h2v-.801C8 3.754 8.797 3 10 3c1.203 0 2 .754 2 2.199V8H4c-.553 0-1 .646-1 1.199V17c0 .549.428 1.139.951 1.307l1.197.387C5.672 18.861 6.55 19 7.1 19h5.8c.549 0 1.428-.139 1.951-.307l1.196-.387c.524-.167.953-.757.953-1.306V9.199C17 8.646 16.352 8 15.8 8z"}))};l.defaultProps={className:null,width:20,height:20};const c=l},70:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>u});var s=n(9689),r=n(6395),a=n(6984),o=n(2478),l=n(3388),c=n(6945),i=n(2568);const u=()=>({components:{ArrowUpIcon:s.default,ArrowDownIcon:r.default,ArrowIcon:a.default,CloseIcon:o.default,CopyIcon:l.default,LockIcon:c.default,UnlockIcon:i.default}})},7349:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>i});var s=n(6689),r=n.n(s),a=(n(580),n(9003)),o=n.n(a),l=n(2603);const c=e=>{let{expanded:t,children:n,onChange:a}=e;const c=(0,l.useComponent)("ChevronRightIcon"),i=(0,s.useCallback)((e=>{a(e,!t)}),[t,a]);return r().createElement("button",{type:"button",className:"json-schema-2020-12-accordion",onClick:i},r().createElement("div",{className:"json-schema-2020-12-accordion__children"},n),r().createElement("span",{className:o()("json-schema-2020-12-accordion__icon",{"json-schema-2020-12-accordion__icon--expanded":t,"json-schema-2020-12-accordion__icon--collapsed":!t})},r().createElement(c,null)))};c.defaultProps={expanded:!1};const i=c},6867:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(580);const a=e=>{let{expanded:t,onClick:n}=e;const a=(0,s.useCallback)((e=>{n(e,!t)}),[t,n]);return r().createElement("button",{type:"button",className:"json-schema-2020-12-expand-deep-button",onClick:a},t?"Collapse all":"Expand all")}},2675:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>m});var s=n(3942),r=n.n(s),a=n(6689),o=n.n(a),l=(n(580),n(9003)),c=n.n(l),i=(n(6648),n(2603)),u=n(9006);const p=(0,a.forwardRef)(((e,t)=>{let{schema:n,name:s,dependentRequired:l,onExpand:p}=e;const m=(0,i.useFn)(),d=(0,i.useIsExpanded)(),h=(0,i.useIsExpandedDeeply)(),[g,f]=(0,a.useState)(d||h),[y,v]=(0,a.useState)(h),[S,E]=(0,i.useLevel)(),w=(0,i.useIsEmbedded)(),x=m.isExpandable(n)||l.length>0,C=(0,i.useIsCircular)(n),j=(0,i.useRenderedSchemas)(n),b=m.stringifyConstraints(n),_=(0,i.useComponent)("Accordion"),N=(0,i.useComponent)("Keyword$schema"),O=(0,i.useComponent)("Keyword$vocabulary"),k=(0,i.useComponent)("Keyword$id"),A=(0,i.useComponent)("Keyword$anchor"),I=(0,i.useComponent)("Keyword$dynamicAnchor"),P=(0,i.useComponent)("Keyword$ref"),q=(0,i.useComponent)("Keyword$dynamicRef"),R=(0,i.useComponent)("Keyword$defs"),T=(0,i.useComponent)("Keyword$comment"),M=(0,i.useComponent)("KeywordAllOf"),D=(0,i.useComponent)("KeywordAnyOf"),J=(0,i.useComponent)("KeywordOneOf"),$=(0,i.useComponent)("KeywordNot"),L=(0,i.useComponent)("KeywordIf"),K=(0,i.useComponent)("KeywordThen"),V=(0,i.useComponent)("KeywordElse"),U=(0,i.useComponent)("KeywordDependentSchemas"),F=(0,i.useComponent)("KeywordPrefixItems"),z=(0,i.useComponent)("KeywordItems"),B=(0,i.useComponent)("KeywordContains"),W=(0,i.useComponent)("KeywordProperties"),H=(0,i.useComponent)("KeywordPatternProperties"),Z=(0,i.useComponent)("KeywordAdditionalProperties"),G=(0,i.useComponent)("KeywordPropertyNames"),X=(0,i.useComponent)("KeywordUnevaluatedItems"),Y=(0,i.useComponent)("KeywordUnevaluatedProperties"),Q=(0,i.useComponent)("KeywordType"),ee=(0,i.useComponent)("KeywordEnum"),te=(0,i.useComponent)("KeywordConst"),ne=(0,i.useComponent)("KeywordConstraint"),se=(0,i.useComponent)("KeywordDependentRequired"),re=(0,i.useComponent)("KeywordContentSchema"),ae=(0,i.useComponent)("KeywordTitle"),oe=(0,i.useComponent)("KeywordDescription"),le=(0,i.useComponent)("KeywordDefault"),ce=(0,i.useComponent)("KeywordDeprecated"),ie=(0,i.useComponent)("KeywordReadOnly"),ue=(0,i.useComponent)("KeywordWriteOnly"),pe=(0,i.useComponent)("ExpandDeepButton");(0,a.useEffect)((()=>{v(h)}),[h]),(0,a.useEffect)((()=>{v(y)}),[y]);const me=(0,a.useCallback)(((e,t)=>{f(t),!t&&v(!1),p(e,t,!1)}),[p]),de=(0,a.useCallback)(((e,t)=>{f(t),v(t),p(e,t,!0)}),[p]);return o().createElement(u.JSONSchemaLevelContext.Provider,{value:E},o().createElement(u.JSONSchemaDeepExpansionContext.Provider,{value:y},o().createElement(u.JSONSchemaCyclesContext.Provider,{value:j},o().createElement("article",{ref:t,"data-json-schema-level":S,className:c()("json-schema-2020-12",{"json-schema-2020-12--embedded":w,"json-schema-2020-12--circular":C})},o().createElement("div",{className:"json-schema-2020-12-head"},x&&!C?o().createElement(o().Fragment,null,o().createElement(_,{expanded:g,onChange:me},o().createElement(ae,{title:s,schema:n})),o().createElement(pe,{expanded:g,onClick:de})):o().createElement(ae,{title:s,schema:n}),o().createElement(ce,{schema:n}),o().createElement(ie,{schema:n}),o().createElement(ue,{schema:n}),o().createElement(Q,{schema:n,isCircular:C}),b.length>0&&r()(b).call(b,(e=>o().createElement(ne,{key:`${e.scope}-${e.value}`,constraint:e})))),o().createElement("div",{className:c()("json-schema-2020-12-body",{"json-schema-2020-12-body--collapsed":!g})},g&&o().createElement(o().Fragment,null,o().createElement(oe,{schema:n}),!C&&x&&o().createElement(o().Fragment,null,o().createElement(W,{schema:n}),o().createElement(H,{schema:n}),o().createElement(Z,{schema:n}),o().createElement(Y,{schema:n}),o().createElement(G,{schema:n}),o().createElement(M,{schema:n}),o().createElement(D,{schema:n}),o().createElement(J,{schema:n}),o().createElement($,{schema:n}),o().createElement(L,{schema:n}),o().createElement(K,{schema:n}),o().createElement(V,{schema:n}),o().createElement(U,{schema:n}),o().createElement(F,{schema:n}),o().createElement(z,{schema:n}),o().createElement(X,{schema:n}),o().createElement(B,{schema:n}),o().createElement(re,{schema:n})),o().createElement(ee,{schema:n}),o().createElement(te,{schema:n}),o().createElement(se,{schema:n,dependentRequired:l}),o().createElement(le,{schema:n}),o().createElement(N,{schema:n}),o().createElement(O,{schema:n}),o().createElement(k,{schema:n}),o().createElement(A,{schema:n}),o().createElement(I,{schema:n}),o().createElement(P,{schema:n}),!C&&x&&o().createElement(R,{schema:n}),o().createElement(q,{schema:n}),o().createElement(T,{schema:n})))))))}));p.defaultProps={name:"",dependentRequired:[],onExpand:()=>{}};const m=p},2260:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);const a=()=>r().createElement("svg",{xmlns:"http://www.w3.org/2000/svg",width:"24",height:"24",viewBox:"0 0 24 24"},r().createElement("path",{d:"M10 6L8.59 7.41 13.17 12l-4.58 4.59L10 18l6-6z"}))},4922:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(6648);const a=e=>{let{schema:t}=e;return null!=t&&t.$anchor?r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$anchor"},r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$anchor"),r().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},t.$anchor)):null}},4685:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(6648);const a=e=>{let{schema:t}=e;return null!=t&&t.$comment?r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$comment"},r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$comment"),r().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},t.$comment)):null}},6418:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>g});var s=n(7252),r=n.n(s),a=n(3942),o=n.n(a),l=n(1815),c=n.n(l),i=n(6689),u=n.n(i),p=n(9003),m=n.n(p),d=(n(6648),n(2603)),h=n(9006);const g=e=>{var t;let{schema:n}=e;const s=(null==n?void 0:n.$defs)||{},a=(0,d.useIsExpandedDeeply)(),[l,p]=(0,i.useState)(a),[g,f]=(0,i.useState)(!1),y=(0,d.useComponent)("Accordion"),v=(0,d.useComponent)("ExpandDeepButton"),S=(0,d.useComponent)("JSONSchema"),E=(0,i.useCallback)((()=>{p((e=>!e))}),[]),w=(0,i.useCallback)(((e,t)=>{p(t),f(t)}),[]);return 0===r()(s).length?null:u().createElement(h.JSONSchemaDeepExpansionContext.Provider,{value:g},u().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$defs"},u().createElement(y,{expanded:l,onChange:E},u().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$defs")),u().createElement(v,{expanded:l,onClick:w}),u().createElement("strong",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},"object"),u().createElement("ul",{className:m()("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!l})},l&&u().createElement(u().Fragment,null,o()(t=c()(s)).call(t,(e=>{let[t,n]=e;return u().createElement("li",{key:t,className:"json-schema-2020-12-property"},u().createElement(S,{name:t,schema:n}))}))))))}},1338:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(6648);const a=e=>{let{schema:t}=e;return null!=t&&t.$dynamicAnchor?r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$dynamicAnchor"},r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$dynamicAnchor"),r().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},t.$dynamicAnchor)):null}},7655:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(6648);const a=e=>{let{schema:t}=e;return null!=t&&t.$dynamicRef?r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$dynamicRef"},r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$dynamicRef"),r().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},t.$dynamicRef)):null}},3460:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(6648);const a=e=>{let{schema:t}=e;return null!=t&&t.$id?r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$id"},r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$id"),r().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},t.$id)):null}},2348:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(6648);const a=e=>{let{schema:t}=e;return null!=t&&t.$ref?r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$ref"},r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$ref"),r().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},t.$ref)):null}},9359:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(6648);const a=e=>{let{schema:t}=e;return null!=t&&t.$schema?r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$schema"},r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$schema"),r().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},t.$schema)):null}},7568:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>m});var s=n(3942),r=n.n(s),a=n(1815),o=n.n(a),l=n(6689),c=n.n(l),i=n(9003),u=n.n(i),p=(n(6648),n(2603));const m=e=>{var t;let{schema:n}=e;const s=(0,p.useIsExpandedDeeply)(),[a,i]=(0,l.useState)(s),m=(0,p.useComponent)("Accordion"),d=(0,l.useCallback)((()=>{i((e=>!e))}),[]);return null!=n&&n.$vocabulary?"object"!=typeof n.$vocabulary?null:c().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--$vocabulary"},c().createElement(m,{expanded:a,onChange:d},c().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"$vocabulary")),c().createElement("strong",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},"object"),c().createElement("ul",null,a&&r()(t=o()(n.$vocabulary)).call(t,(e=>{let[t,n]=e;return c().createElement("li",{key:t,className:u()("json-schema-2020-12-$vocabulary-uri",{"json-schema-2020-12-$vocabulary-uri--disabled":!n})},c().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},t))})))):null}},5253:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(6648),n(2603));const o=e=>{let{schema:t}=e;const n=(0,a.useFn)(),{additionalProperties:s}=t,o=(0,a.useComponent)("JSONSchema");if(!n.hasKeyword(t,"additionalProperties"))return null;const l=r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Additional properties");return r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--additionalProperties"},!0===s?r().createElement(r().Fragment,null,l,r().createElement("span",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},"allowed")):!1===s?r().createElement(r().Fragment,null,l,r().createElement("span",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},"forbidden")):r().createElement(o,{name:l,schema:s}))}},6457:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>d});var s=n(7104),r=n.n(s),a=n(3942),o=n.n(a),l=n(6689),c=n.n(l),i=n(9003),u=n.n(i),p=(n(6648),n(2603)),m=n(9006);const d=e=>{let{schema:t}=e;const n=(null==t?void 0:t.allOf)||[],s=(0,p.useFn)(),a=(0,p.useIsExpandedDeeply)(),[i,d]=(0,l.useState)(a),[h,g]=(0,l.useState)(!1),f=(0,p.useComponent)("Accordion"),y=(0,p.useComponent)("ExpandDeepButton"),v=(0,p.useComponent)("JSONSchema"),S=(0,p.useComponent)("KeywordType"),E=(0,l.useCallback)((()=>{d((e=>!e))}),[]),w=(0,l.useCallback)(((e,t)=>{d(t),g(t)}),[]);return r()(n)&&0!==n.length?c().createElement(m.JSONSchemaDeepExpansionContext.Provider,{value:h},c().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--allOf"},c().createElement(f,{expanded:i,onChange:E},c().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"All of")),c().createElement(y,{expanded:i,onClick:w}),c().createElement(S,{schema:{allOf:n}}),c().createElement("ul",{className:u()("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!i})},i&&c().createElement(c().Fragment,null,o()(n).call(n,((e,t)=>c().createElement("li",{key:`#${t}`,className:"json-schema-2020-12-property"},c().createElement(v,{name:`#${t} ${s.getTitle(e)}`,schema:e})))))))):null}},8776:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>d});var s=n(7104),r=n.n(s),a=n(3942),o=n.n(a),l=n(6689),c=n.n(l),i=n(9003),u=n.n(i),p=(n(6648),n(2603)),m=n(9006);const d=e=>{let{schema:t}=e;const n=(null==t?void 0:t.anyOf)||[],s=(0,p.useFn)(),a=(0,p.useIsExpandedDeeply)(),[i,d]=(0,l.useState)(a),[h,g]=(0,l.useState)(!1),f=(0,p.useComponent)("Accordion"),y=(0,p.useComponent)("ExpandDeepButton"),v=(0,p.useComponent)("JSONSchema"),S=(0,p.useComponent)("KeywordType"),E=(0,l.useCallback)((()=>{d((e=>!e))}),[]),w=(0,l.useCallback)(((e,t)=>{d(t),g(t)}),[]);return r()(n)&&0!==n.length?c().createElement(m.JSONSchemaDeepExpansionContext.Provider,{value:h},c().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--anyOf"},c().createElement(f,{expanded:i,onChange:E},c().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Any of")),c().createElement(y,{expanded:i,onClick:w}),c().createElement(S,{schema:{anyOf:n}}),c().createElement("ul",{className:u()("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!i})},i&&c().createElement(c().Fragment,null,o()(n).call(n,((e,t)=>c().createElement("li",{key:`#${t}`,className:"json-schema-2020-12-property"},c().createElement(v,{name:`#${t} ${s.getTitle(e)}`,schema:e})))))))):null}},7308:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(6648),n(2603));const o=e=>{let{schema:t}=e;const n=(0,a.useFn)();return n.hasKeyword(t,"const")?r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--const"},r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Const"),r().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--const"},n.stringify(t.const))):null}},9956:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s);n(580);const a=e=>{let{constraint:t}=e;return r().createElement("span",{className:`json-schema-2020-12__constraint json-schema-2020-12__constraint--${t.scope}`},t.value)},o=r().memo(a)},8993:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(6648),n(2603));const o=e=>{let{schema:t}=e;const n=(0,a.useFn)(),s=(0,a.useComponent)("JSONSchema");if(!n.hasKeyword(t,"contains"))return null;const o=r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Contains");return r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--contains"},r().createElement(s,{name:o,schema:t.contains}))}},3484:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(6648),n(2603));const o=e=>{let{schema:t}=e;const n=(0,a.useFn)(),s=(0,a.useComponent)("JSONSchema");if(!n.hasKeyword(t,"contentSchema"))return null;const o=r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Content schema");return r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--contentSchema"},r().createElement(s,{name:o,schema:t.contentSchema}))}},5148:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(6648),n(2603));const o=e=>{let{schema:t}=e;const n=(0,a.useFn)();return n.hasKeyword(t,"default")?r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--default"},r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Default"),r().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--const"},n.stringify(t.default))):null}},4539:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>l});var s=n(3942),r=n.n(s),a=n(6689),o=n.n(a);n(580),n(6648);const l=e=>{let{dependentRequired:t}=e;return 0===t.length?null:o().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--dependentRequired"},o().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Required when defined"),o().createElement("ul",null,r()(t).call(t,(e=>o().createElement("li",{key:e},o().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--warning"},e))))))}},6076:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>g});var s=n(7252),r=n.n(s),a=n(3942),o=n.n(a),l=n(1815),c=n.n(l),i=n(6689),u=n.n(i),p=n(9003),m=n.n(p),d=(n(6648),n(2603)),h=n(9006);const g=e=>{var t;let{schema:n}=e;const s=(null==n?void 0:n.dependentSchemas)||[],a=(0,d.useIsExpandedDeeply)(),[l,p]=(0,i.useState)(a),[g,f]=(0,i.useState)(!1),y=(0,d.useComponent)("Accordion"),v=(0,d.useComponent)("ExpandDeepButton"),S=(0,d.useComponent)("JSONSchema"),E=(0,i.useCallback)((()=>{p((e=>!e))}),[]),w=(0,i.useCallback)(((e,t)=>{p(t),f(t)}),[]);return"object"!=typeof s||0===r()(s).length?null:u().createElement(h.JSONSchemaDeepExpansionContext.Provider,{value:g},u().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--dependentSchemas"},u().createElement(y,{expanded:l,onChange:E},u().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Dependent schemas")),u().createElement(v,{expanded:l,onClick:w}),u().createElement("strong",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},"object"),u().createElement("ul",{className:m()("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!l})},l&&u().createElement(u().Fragment,null,o()(t=c()(s)).call(t,(e=>{let[t,n]=e;return u().createElement("li",{key:t,className:"json-schema-2020-12-property"},u().createElement(S,{name:t,schema:n}))}))))))}},6661:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(6648);const a=e=>{let{schema:t}=e;return!0!==(null==t?void 0:t.deprecated)?null:r().createElement("span",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--warning"},"deprecated")}},9446:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(6648);const a=e=>{let{schema:t}=e;return null!=t&&t.description?r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--description"},r().createElement("div",{className:"json-schema-2020-12-core-keyword__value json-schema-2020-12-core-keyword__value--secondary"},t.description)):null}},7207:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(6648),n(2603));const o=e=>{let{schema:t}=e;const n=(0,a.useFn)(),s=(0,a.useComponent)("JSONSchema");if(!n.hasKeyword(t,"else"))return null;const o=r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Else");return r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--if"},r().createElement(s,{name:o,schema:t.else}))}},1805:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>u});var s=n(7104),r=n.n(s),a=n(3942),o=n.n(a),l=n(6689),c=n.n(l),i=(n(6648),n(2603));const u=e=>{var t;let{schema:n}=e;const s=(0,i.useFn)();return r()(null==n?void 0:n.enum)?c().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--enum"},c().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Allowed values"),c().createElement("ul",null,o()(t=n.enum).call(t,(e=>{const t=s.stringify(e);return c().createElement("li",{key:t},c().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--const"},t))})))):null}},487:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(6648),n(2603));const o=e=>{let{schema:t}=e;const n=(0,a.useFn)(),s=(0,a.useComponent)("JSONSchema");if(!n.hasKeyword(t,"if"))return null;const o=r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"If");return r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--if"},r().createElement(s,{name:o,schema:t.if}))}},9206:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(6648),n(2603));const o=e=>{let{schema:t}=e;const n=(0,a.useFn)(),s=(0,a.useComponent)("JSONSchema");if(!n.hasKeyword(t,"items"))return null;const o=r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Items");return r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--items"},r().createElement(s,{name:o,schema:t.items}))}},5174:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(6648),n(2603));const o=e=>{let{schema:t}=e;const n=(0,a.useFn)(),s=(0,a.useComponent)("JSONSchema");if(!n.hasKeyword(t,"not"))return null;const o=r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Not");return r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--not"},r().createElement(s,{name:o,schema:t.not}))}},3834:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>d});var s=n(7104),r=n.n(s),a=n(3942),o=n.n(a),l=n(6689),c=n.n(l),i=n(9003),u=n.n(i),p=(n(6648),n(2603)),m=n(9006);const d=e=>{let{schema:t}=e;const n=(null==t?void 0:t.oneOf)||[],s=(0,p.useFn)(),a=(0,p.useIsExpandedDeeply)(),[i,d]=(0,l.useState)(a),[h,g]=(0,l.useState)(!1),f=(0,p.useComponent)("Accordion"),y=(0,p.useComponent)("ExpandDeepButton"),v=(0,p.useComponent)("JSONSchema"),S=(0,p.useComponent)("KeywordType"),E=(0,l.useCallback)((()=>{d((e=>!e))}),[]),w=(0,l.useCallback)(((e,t)=>{d(t),g(t)}),[]);return r()(n)&&0!==n.length?c().createElement(m.JSONSchemaDeepExpansionContext.Provider,{value:h},c().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--oneOf"},c().createElement(f,{expanded:i,onChange:E},c().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"One of")),c().createElement(y,{expanded:i,onClick:w}),c().createElement(S,{schema:{oneOf:n}}),c().createElement("ul",{className:u()("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!i})},i&&c().createElement(c().Fragment,null,o()(n).call(n,((e,t)=>c().createElement("li",{key:`#${t}`,className:"json-schema-2020-12-property"},c().createElement(v,{name:`#${t} ${s.getTitle(e)}`,schema:e})))))))):null}},6746:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>m});var s=n(7252),r=n.n(s),a=n(3942),o=n.n(a),l=n(1815),c=n.n(l),i=n(6689),u=n.n(i),p=(n(6648),n(2603));const m=e=>{var t;let{schema:n}=e;const s=(null==n?void 0:n.patternProperties)||{},a=(0,p.useComponent)("JSONSchema");return 0===r()(s).length?null:u().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--patternProperties"},u().createElement("ul",null,o()(t=c()(s)).call(t,(e=>{let[t,n]=e;return u().createElement("li",{key:t,className:"json-schema-2020-12-property"},u().createElement(a,{name:t,schema:n}))}))))}},3971:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>d});var s=n(7104),r=n.n(s),a=n(3942),o=n.n(a),l=n(6689),c=n.n(l),i=n(9003),u=n.n(i),p=(n(6648),n(2603)),m=n(9006);const d=e=>{let{schema:t}=e;const n=(null==t?void 0:t.prefixItems)||[],s=(0,p.useFn)(),a=(0,p.useIsExpandedDeeply)(),[i,d]=(0,l.useState)(a),[h,g]=(0,l.useState)(!1),f=(0,p.useComponent)("Accordion"),y=(0,p.useComponent)("ExpandDeepButton"),v=(0,p.useComponent)("JSONSchema"),S=(0,p.useComponent)("KeywordType"),E=(0,l.useCallback)((()=>{d((e=>!e))}),[]),w=(0,l.useCallback)(((e,t)=>{d(t),g(t)}),[]);return r()(n)&&0!==n.length?c().createElement(m.JSONSchemaDeepExpansionContext.Provider,{value:h},c().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--prefixItems"},c().createElement(f,{expanded:i,onChange:E},c().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Prefix items")),c().createElement(y,{expanded:i,onClick:w}),c().createElement(S,{schema:{prefixItems:n}}),c().createElement("ul",{className:u()("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!i})},i&&c().createElement(c().Fragment,null,o()(n).call(n,((e,t)=>c().createElement("li",{key:`#${t}`,className:"json-schema-2020-12-property"},c().createElement(v,{name:`#${t} ${s.getTitle(e)}`,schema:e})))))))):null}},5472:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>v});var s=n(7104),r=n.n(s),a=n(7252),o=n.n(a),l=n(3942),c=n.n(l),i=n(1815),u=n.n(i),p=n(2605),m=n.n(p),d=n(6689),h=n.n(d),g=n(9003),f=n.n(g),y=(n(6648),n(2603));const v=e=>{var t;let{schema:n}=e;const s=(0,y.useFn)(),a=(null==n?void 0:n.properties)||{},l=r()(null==n?void 0:n.required)?n.required:[],i=(0,y.useComponent)("JSONSchema");return 0===o()(a).length?null:h().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--properties"},h().createElement("ul",null,c()(t=u()(a)).call(t,(e=>{let[t,r]=e;const a=m()(l).call(l,t),o=s.getDependentRequired(t,n);return h().createElement("li",{key:t,className:f()("json-schema-2020-12-property",{"json-schema-2020-12-property--required":a})},h().createElement(i,{name:t,schema:r,dependentRequired:o}))}))))}},2338:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(6648),n(2603));const o=e=>{let{schema:t}=e;const n=(0,a.useFn)(),{propertyNames:s}=t,o=(0,a.useComponent)("JSONSchema"),l=r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Property names");return n.hasKeyword(t,"propertyNames")?r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--propertyNames"},r().createElement(o,{name:l,schema:s})):null}},6456:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(6648);const a=e=>{let{schema:t}=e;return!0!==(null==t?void 0:t.readOnly)?null:r().createElement("span",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--muted"},"read-only")}},7401:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(6648),n(2603));const o=e=>{let{schema:t}=e;const n=(0,a.useFn)(),s=(0,a.useComponent)("JSONSchema");if(!n.hasKeyword(t,"then"))return null;const o=r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Then");return r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--then"},r().createElement(s,{name:o,schema:t.then}))}},8137:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>l});var s=n(6689),r=n.n(s),a=(n(580),n(6648),n(2603));const o=e=>{let{title:t,schema:n}=e;const s=(0,a.useFn)();return t||s.getTitle(n)?r().createElement("div",{className:"json-schema-2020-12__title"},t||s.getTitle(n)):null};o.defaultProps={title:""};const l=o},2285:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>l});var s=n(6689),r=n.n(s),a=(n(580),n(6648),n(2603));const o=e=>{let{schema:t,isCircular:n}=e;const s=(0,a.useFn)().getType(t),o=n?" [circular]":"";return r().createElement("strong",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},`${s}${o}`)};o.defaultProps={isCircular:!1};const l=o},5828:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(6648),n(2603));const o=e=>{let{schema:t}=e;const n=(0,a.useFn)(),{unevaluatedItems:s}=t,o=(0,a.useComponent)("JSONSchema");if(!n.hasKeyword(t,"unevaluatedItems"))return null;const l=r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Unevaluated items");return r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--unevaluatedItems"},r().createElement(o,{name:l,schema:s}))}},6907:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(6648),n(2603));const o=e=>{let{schema:t}=e;const n=(0,a.useFn)(),{unevaluatedProperties:s}=t,o=(0,a.useComponent)("JSONSchema");if(!n.hasKeyword(t,"unevaluatedProperties"))return null;const l=r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--primary"},"Unevaluated properties");return r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--unevaluatedProperties"},r().createElement(o,{name:l,schema:s}))}},5789:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(6648);const a=e=>{let{schema:t}=e;return!0!==(null==t?void 0:t.writeOnly)?null:r().createElement("span",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--muted"},"write-only")}},9006:(e,t,n)=>{"use strict";n.r(t),n.d(t,{JSONSchemaContext:()=>o,JSONSchemaCyclesContext:()=>i,JSONSchemaDeepExpansionContext:()=>c,JSONSchemaLevelContext:()=>l});var s=n(7885),r=n.n(s),a=n(6689);const o=(0,a.createContext)(null);o.displayName="JSONSchemaContext";const l=(0,a.createContext)(0);l.displayName="JSONSchemaLevelContext";const c=(0,a.createContext)(!1);c.displayName="JSONSchemaDeepExpansionContext";const i=(0,a.createContext)(new(r()))},4121:(e,t,n)=>{"use strict";n.r(t),n.d(t,{getDependentRequired:()=>D,getTitle:()=>k,getType:()=>A,hasKeyword:()=>P,isBooleanJSONSchema:()=>I,isExpandable:()=>q,stringify:()=>R,stringifyConstraints:()=>M,upperFirst:()=>O});var s=n(600),r=n.n(s),a=n(6680),o=n.n(a),l=n(7104),c=n.n(l),i=n(3942),u=n.n(i),p=n(2605),m=n.n(p),d=n(4901),h=n.n(d),g=n(9998),f=n.n(g),y=n(8344),v=n.n(y),S=n(1733),E=n.n(S),w=n(66),x=n.n(w),C=n(1815),j=n.n(C),b=n(7885),_=n.n(b),N=n(2603);const O=e=>"string"==typeof e?`${e.charAt(0).toUpperCase()}${r()(e).call(e,1)}`:e,k=e=>{const t=(0,N.useFn)();return null!=e&&e.title?t.upperFirst(e.title):null!=e&&e.$anchor?t.upperFirst(e.$anchor):null!=e&&e.$id?e.$id:""},A=function(e){var t,n;let s=arguments.length>1&&void 0!==arguments[1]?arguments[1]:new(o());const r=(0,N.useFn)();if(null==e)return"any";if(r.isBooleanJSONSchema(e))return e?"any":"never";if("object"!=typeof e)return"any";if(s.has(e))return"any";s.add(e);const{type:a,prefixItems:l,items:i}=e,p=()=>{if(c()(l)){const e=u()(l).call(l,(e=>A(e,s))),t=i?A(i,s):"any";return`array<[${e.join(", ")}], ${t}>`}if(i){return`array<${A(i,s)}>`}return"array<any>"};if(e.not&&"any"===A(e.not))return"never";const d=c()(a)?u()(a).call(a,(e=>"array"===e?p():e)).join(" | "):"array"===a?p():m()(t=["null","boolean","object","array","number","integer","string"]).call(t,a)?a:(()=>{var t,n;if(Object.hasOwn(e,"prefixItems")||Object.hasOwn(e,"items")||Object.hasOwn(e,"contains"))return p();if(Object.hasOwn(e,"properties")||Object.hasOwn(e,"additionalProperties")||Object.hasOwn(e,"patternProperties"))return"object";if(m()(t=["int32","int64"]).call(t,e.format))return"integer";if(m()(n=["float","double"]).call(n,e.format))return"number";if(Object.hasOwn(e,"minimum")||Object.hasOwn(e,"maximum")||Object.hasOwn(e,"exclusiveMinimum")||Object.hasOwn(e,"exclusiveMaximum")||Object.hasOwn(e,"multipleOf"))return"number | integer";if(Object.hasOwn(e,"pattern")||Object.hasOwn(e,"format")||Object.hasOwn(e,"minLength")||Object.hasOwn(e,"maxLength"))return"string";if(void 0!==e.const){if(null===e.const)return"null";if("boolean"==typeof e.const)return"boolean";if("number"==typeof e.const)return h()(e.const)?"integer":"number";if("string"==typeof e.const)return"string";if(c()(e.const))return"array<any>";if("object"==typeof e.const)return"object"}return null})(),g=(t,n)=>{if(c()(e[t])){var r;return`(${u()(r=e[t]).call(r,(e=>A(e,s))).join(n)})`}return null},y=g("oneOf"," | "),v=g("anyOf"," | "),S=g("allOf"," & "),E=f()(n=[d,y,v,S]).call(n,Boolean).join(" | ");return s.delete(e),E||"any"},I=e=>"boolean"==typeof e,P=(e,t)=>null!==e&&"object"==typeof e&&Object.hasOwn(e,t),q=e=>{const t=(0,N.useFn)();return(null==e?void 0:e.$schema)||(null==e?void 0:e.$vocabulary)||(null==e?void 0:e.$id)||(null==e?void 0:e.$anchor)||(null==e?void 0:e.$dynamicAnchor)||(null==e?void 0:e.$ref)||(null==e?void 0:e.$dynamicRef)||(null==e?void 0:e.$defs)||(null==e?void 0:e.$comment)||(null==e?void 0:e.allOf)||(null==e?void 0:e.anyOf)||(null==e?void 0:e.oneOf)||t.hasKeyword(e,"not")||t.hasKeyword(e,"if")||t.hasKeyword(e,"then")||t.hasKeyword(e,"else")||(null==e?void 0:e.dependentSchemas)||(null==e?void 0:e.prefixItems)||t.hasKeyword(e,"items")||t.hasKeyword(e,"contains")||(null==e?void 0:e.properties)||(null==e?void 0:e.patternProperties)||t.hasKeyword(e,"additionalProperties")||t.hasKeyword(e,"propertyNames")||t.hasKeyword(e,"unevaluatedItems")||t.hasKeyword(e,"unevaluatedProperties")||(null==e?void 0:e.description)||(null==e?void 0:e.enum)||t.hasKeyword(e,"const")||t.hasKeyword(e,"contentSchema")||t.hasKeyword(e,"default")},R=e=>{var t;return null===e||m()(t=["number","bigint","boolean"]).call(t,typeof e)?String(e):c()(e)?`[${u()(e).call(e,R).join(", ")}]`:v()(e)},T=(e,t,n)=>{const s="number"==typeof t,r="number"==typeof n;return s&&r?t===n?`${t} ${e}`:`[${t}, ${n}] ${e}`:s?`>= ${t} ${e}`:r?`<= ${n} ${e}`:null},M=e=>{const t=[],n=(e=>{if("number"!=typeof(null==e?void 0:e.multipleOf))return null;if(e.multipleOf<=0)return null;if(1===e.multipleOf)return null;const{multipleOf:t}=e;if(h()(t))return`multiple of ${t}`;const n=10**t.toString().split(".")[1].length;return`multiple of ${t*n}/${n}`})(e);null!==n&&t.push({scope:"number",value:n});const s=(e=>{const t=null==e?void 0:e.minimum,n=null==e?void 0:e.maximum,s=null==e?void 0:e.exclusiveMinimum,r=null==e?void 0:e.exclusiveMaximum,a="number"==typeof t,o="number"==typeof n,l="number"==typeof s,c="number"==typeof r,i=l&&(!a||t<s),u=c&&(!o||n>r);if((a||l)&&(o||c))return`${i?"(":"["}${i?s:t}, ${u?r:n}${u?")":"]"}`;if(a||l)return`${i?">":"≥"} ${i?s:t}`;if(o||c)return`${u?"<":"≤"} ${u?r:n}`;return null})(e);null!==s&&t.push({scope:"number",value:s}),null!=e&&e.format&&t.push({scope:"string",value:e.format});const r=T("characters",null==e?void 0:e.minLength,null==e?void 0:e.maxLength);null!==r&&t.push({scope:"string",value:r}),null!=e&&e.pattern&&t.push({scope:"string",value:`matches ${null==e?void 0:e.pattern}`}),null!=e&&e.contentMediaType&&t.push({scope:"string",value:`media type: ${e.contentMediaType}`}),null!=e&&e.contentEncoding&&t.push({scope:"string",value:`encoding: ${e.contentEncoding}`});const a=T(null!=e&&e.hasUniqueItems?"unique items":"items",null==e?void 0:e.minItems,null==e?void 0:e.maxItems);null!==a&&t.push({scope:"array",value:a});const o=T("contained items",null==e?void 0:e.minContains,null==e?void 0:e.maxContains);null!==o&&t.push({scope:"array",value:o});const l=T("properties",null==e?void 0:e.minProperties,null==e?void 0:e.maxProperties);return null!==l&&t.push({scope:"object",value:l}),t},D=(e,t)=>{var n;return null!=t&&t.dependentRequired?E()(x()(n=j()(t.dependentRequired)).call(n,((t,n)=>{let[s,r]=n;return c()(r)&&m()(r).call(r,e)?(t.add(s),t):t}),new(_()))):[]}},5077:(e,t,n)=>{"use strict";n.r(t),n.d(t,{withJSONSchemaContext:()=>Z});var s=n(6689),r=n.n(s),a=n(2675),o=n(9359),l=n(7568),c=n(3460),i=n(4922),u=n(1338),p=n(2348),m=n(7655),d=n(6418),h=n(4685),g=n(6457),f=n(8776),y=n(3834),v=n(5174),S=n(487),E=n(7401),w=n(7207),x=n(6076),C=n(3971),j=n(9206),b=n(8993),_=n(5472),N=n(6746),O=n(5253),k=n(2338),A=n(5828),I=n(6907),P=n(2285),q=n(1805),R=n(7308),T=n(9956),M=n(4539),D=n(3484),J=n(8137),$=n(9446),L=n(5148),K=n(6661),V=n(6456),U=n(5789),F=n(7349),z=n(6867),B=n(2260),W=n(9006),H=n(4121);const Z=function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};const n={components:{JSONSchema:a.default,Keyword$schema:o.default,Keyword$vocabulary:l.default,Keyword$id:c.default,Keyword$anchor:i.default,Keyword$dynamicAnchor:u.default,Keyword$ref:p.default,Keyword$dynamicRef:m.default,Keyword$defs:d.default,Keyword$comment:h.default,KeywordAllOf:g.default,KeywordAnyOf:f.default,KeywordOneOf:y.default,KeywordNot:v.default,KeywordIf:S.default,KeywordThen:E.default,KeywordElse:w.default,KeywordDependentSchemas:x.default,KeywordPrefixItems:C.default,KeywordItems:j.default,KeywordContains:b.default,KeywordProperties:_.default,KeywordPatternProperties:N.default,KeywordAdditionalProperties:O.default,KeywordPropertyNames:k.default,KeywordUnevaluatedItems:A.default,KeywordUnevaluatedProperties:I.default,KeywordType:P.default,KeywordEnum:q.default,KeywordConst:R.default,KeywordConstraint:T.default,KeywordDependentRequired:M.default,KeywordContentSchema:D.default,KeywordTitle:J.default,KeywordDescription:$.default,KeywordDefault:L.default,KeywordDeprecated:K.default,KeywordReadOnly:V.default,KeywordWriteOnly:U.default,Accordion:F.default,ExpandDeepButton:z.default,ChevronRightIcon:B.default,...t.components},config:{default$schema:"https://json-schema.org/draft/2020-12/schema",defaultExpandedLevels:0,...t.config},fn:{upperFirst:H.upperFirst,getTitle:H.getTitle,getType:H.getType,isBooleanJSONSchema:H.isBooleanJSONSchema,hasKeyword:H.hasKeyword,isExpandable:H.isExpandable,stringify:H.stringify,stringifyConstraints:H.stringifyConstraints,getDependentRequired:H.getDependentRequired,...t.fn}},s=t=>r().createElement(W.JSONSchemaContext.Provider,{value:n},r().createElement(e,t));return s.contexts={JSONSchemaContext:W.JSONSchemaContext},s.displayName=e.displayName,s}},2603:(e,t,n)=>{"use strict";n.r(t),n.d(t,{useComponent:()=>c,useConfig:()=>l,useFn:()=>i,useIsCircular:()=>g,useIsEmbedded:()=>p,useIsExpanded:()=>m,useIsExpandedDeeply:()=>d,useLevel:()=>u,useRenderedSchemas:()=>h});var s=n(7885),r=n.n(s),a=n(6689),o=n(9006);const l=()=>{const{config:e}=(0,a.useContext)(o.JSONSchemaContext);return e},c=e=>{const{components:t}=(0,a.useContext)(o.JSONSchemaContext);return t[e]||null},i=function(){let e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:void 0;const{fn:t}=(0,a.useContext)(o.JSONSchemaContext);return void 0!==e?t[e]:t},u=()=>{const e=(0,a.useContext)(o.JSONSchemaLevelContext);return[e,e+1]},p=()=>{const[e]=u();return e>0},m=()=>{const[e]=u(),{defaultExpandedLevels:t}=l();return t-e>0},d=()=>(0,a.useContext)(o.JSONSchemaDeepExpansionContext),h=function(){let e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:void 0;if(void 0===e)return(0,a.useContext)(o.JSONSchemaCyclesContext);const t=(0,a.useContext)(o.JSONSchemaCyclesContext);return new(r())([...t,e])},g=e=>h().has(e)},7139:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>G});var s=n(2675),r=n(9359),a=n(7568),o=n(3460),l=n(4922),c=n(1338),i=n(2348),u=n(7655),p=n(6418),m=n(4685),d=n(6457),h=n(8776),g=n(3834),f=n(5174),y=n(487),v=n(7401),S=n(7207),E=n(6076),w=n(3971),x=n(9206),C=n(8993),j=n(5472),b=n(6746),_=n(5253),N=n(2338),O=n(5828),k=n(6907),A=n(2285),I=n(1805),P=n(7308),q=n(9956),R=n(4539),T=n(3484),M=n(8137),D=n(9446),J=n(5148),$=n(6661),L=n(6456),K=n(5789),V=n(7349),U=n(6867),F=n(2260),z=n(4121),B=n(8591),W=n(9006),H=n(2603),Z=n(5077);const G=()=>({components:{JSONSchema202012:s.default,JSONSchema202012Keyword$schema:r.default,JSONSchema202012Keyword$vocabulary:a.default,JSONSchema202012Keyword$id:o.default,JSONSchema202012Keyword$anchor:l.default,JSONSchema202012Keyword$dynamicAnchor:c.default,JSONSchema202012Keyword$ref:i.default,JSONSchema202012Keyword$dynamicRef:u.default,JSONSchema202012Keyword$defs:p.default,JSONSchema202012Keyword$comment:m.default,JSONSchema202012KeywordAllOf:d.default,JSONSchema202012KeywordAnyOf:h.default,JSONSchema202012KeywordOneOf:g.default,JSONSchema202012KeywordNot:f.default,JSONSchema202012KeywordIf:y.default,JSONSchema202012KeywordThen:v.default,JSONSchema202012KeywordElse:S.default,JSONSchema202012KeywordDependentSchemas:E.default,JSONSchema202012KeywordPrefixItems:w.default,JSONSchema202012KeywordItems:x.default,JSONSchema202012KeywordContains:C.default,JSONSchema202012KeywordProperties:j.default,JSONSchema202012KeywordPatternProperties:b.default,JSONSchema202012KeywordAdditionalProperties:_.default,JSONSchema202012KeywordPropertyNames:N.default,JSONSchema202012KeywordUnevaluatedItems:O.default,JSONSchema202012KeywordUnevaluatedProperties:k.default,JSONSchema202012KeywordType:A.default,JSONSchema202012KeywordEnum:I.default,JSONSchema202012KeywordConst:P.default,JSONSchema202012KeywordConstraint:q.default,JSONSchema202012KeywordDependentRequired:R.default,JSONSchema202012KeywordContentSchema:T.default,JSONSchema202012KeywordTitle:M.default,JSONSchema202012KeywordDescription:D.default,JSONSchema202012KeywordDefault:J.default,JSONSchema202012KeywordDeprecated:$.default,JSONSchema202012KeywordReadOnly:L.default,JSONSchema202012KeywordWriteOnly:K.default,JSONSchema202012Accordion:V.default,JSONSchema202012ExpandDeepButton:U.default,JSONSchema202012ChevronRightIcon:F.default,withJSONSchema202012Context:Z.withJSONSchemaContext,JSONSchema202012DeepExpansionContext:()=>W.JSONSchemaDeepExpansionContext},fn:{upperFirst:z.upperFirst,jsonSchema202012:{isExpandable:z.isExpandable,hasKeyword:z.hasKeyword,useFn:H.useFn,useConfig:H.useConfig,useComponent:H.useComponent,useIsExpandedDeeply:H.useIsExpandedDeeply,sampleFromSchema:B.sampleFromSchema,sampleFromSchemaGeneric:B.sampleFromSchemaGeneric,sampleEncoderAPI:B.encoderAPI,sampleFormatAPI:B.formatAPI,sampleMediaTypeAPI:B.mediaTypeAPI,createXMLExample:B.createXMLExample,memoizedSampleFromSchema:B.memoizedSampleFromSchema,memoizedCreateXMLExample:B.memoizedCreateXMLExample}}})},6648:(e,t,n)=>{"use strict";n.r(t),n.d(t,{booleanSchema:()=>o,objectSchema:()=>a,schema:()=>l});var s=n(580),r=n.n(s);const a=r().object,o=r().bool,l=r().oneOfType([a,o])},9507:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});const s=new(n(674).default),r=(e,t)=>"function"==typeof t?s.register(e,t):null===t?s.unregister(e):s.get(e);r.getDefaults=()=>s.defaults;const a=r},2906:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>r});const s=new(n(4215).default),r=(e,t)=>"function"==typeof t?s.register(e,t):null===t?s.unregister(e):s.get(e)},537:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});const s=new(n(3782).default),r=(e,t)=>{if("function"==typeof t)return s.register(e,t);if(null===t)return s.unregister(e);const n=e.split(";").at(0),r=`${n.split("/").at(0)}/*`;return s.get(e)||s.get(n)||s.get(r)};r.getDefaults=()=>s.defaults;const a=r},674:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>E});var s=n(1093),r=n.n(s),a=n(6272),o=n.n(a),l=n(6543),c=n.n(l),i=n(4215),u=n(1433),p=n(8509),m=n(4366),d=n(5037),h=n(5709),g=n(4180),f=n(1967);function y(e,t,n){!function(e,t){if(t.has(e))throw new TypeError("Cannot initialize the same private elements twice on an object")}(e,t),t.set(e,n)}var v=new(c());class S extends i.default{constructor(){super(...arguments),y(this,v,{writable:!0,value:{"7bit":u.default,"8bit":p.default,binary:m.default,"quoted-printable":d.default,base16:h.default,base32:g.default,base64:f.default}}),r()(this,"data",{...o()(this,v)})}get defaults(){return{...o()(this,v)}}}const E=S},3782:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>v});var s=n(1093),r=n.n(s),a=n(6272),o=n.n(a),l=n(6543),c=n.n(l),i=n(4215),u=n(5378),p=n(6724),m=n(4342),d=n(2974),h=n(5088);function g(e,t,n){!function(e,t){if(t.has(e))throw new TypeError("Cannot initialize the same private elements twice on an object")}(e,t),t.set(e,n)}var f=new(c());class y extends i.default{constructor(){super(...arguments),g(this,f,{writable:!0,value:{...u.default,...p.default,...m.default,...d.default,...h.default}}),r()(this,"data",{...o()(this,f)})}get defaults(){return{...o()(this,f)}}}const v=y},4215:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(1093),r=n.n(s);const a=class{constructor(){r()(this,"data",{})}register(e,t){this.data[e]=t}unregister(e){void 0===e?this.data={}:delete this.data[e]}get(e){return this.data[e]}}},8338:(e,t,n)=>{"use strict";n.r(t),n.d(t,{ALL_TYPES:()=>r,SCALAR_TYPES:()=>s});const s=["number","integer","string","boolean","null"],r=["array","object",...s]},3783:(e,t,n)=>{"use strict";n.r(t),n.d(t,{extractExample:()=>l,hasExample:()=>o});var s=n(7104),r=n.n(s),a=n(3084);const o=e=>{if(!(0,a.isJSONSchemaObject)(e))return!1;const{examples:t,example:n,default:s}=e;return!!(r()(t)&&t.length>=1)||(void 0!==s||void 0!==n)},l=e=>{if(!(0,a.isJSONSchemaObject)(e))return null;const{examples:t,example:n,default:s}=e;return r()(t)&&t.length>=1?t.at(0):void 0!==s?s:void 0!==n?n:void 0}},7078:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>v});var s=n(7104),r=n.n(s),a=n(4883),o=n.n(a),l=n(1733),c=n.n(l),i=n(7885),u=n.n(i),p=n(7252),m=n.n(p),d=n(9998),h=n.n(d),g=n(1669),f=n(3084);const y=function(e,t){let n=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{};if((0,f.isBooleanJSONSchema)(e)&&!0===e)return!0;if((0,f.isBooleanJSONSchema)(e)&&!1===e)return!1;if((0,f.isBooleanJSONSchema)(t)&&!0===t)return!0;if((0,f.isBooleanJSONSchema)(t)&&!1===t)return!1;if(!(0,f.isJSONSchema)(e))return t;if(!(0,f.isJSONSchema)(t))return e;const s={...t,...e};if(t.type&&e.type&&r()(t.type)&&"string"==typeof t.type){var a;const n=o()(a=(0,g.AF)(t.type)).call(a,e.type);s.type=c()(new(u())(n))}if(r()(t.required)&&r()(e.required)&&(s.required=[...new(u())([...e.required,...t.required])]),t.properties&&e.properties){const r=new(u())([...m()(t.properties),...m()(e.properties)]);s.properties={};for(const a of r){const r=t.properties[a]||{},o=e.properties[a]||{};var l;if(r.readOnly&&!n.includeReadOnly||r.writeOnly&&!n.includeWriteOnly)s.required=h()(l=s.required||[]).call(l,(e=>e!==a));else s.properties[a]=y(o,r,n)}}return(0,f.isJSONSchema)(t.items)&&(0,f.isJSONSchema)(e.items)&&(s.items=y(e.items,t.items,n)),(0,f.isJSONSchema)(t.contains)&&(0,f.isJSONSchema)(e.contains)&&(s.contains=y(e.contains,t.contains,n)),(0,f.isJSONSchema)(t.contentSchema)&&(0,f.isJSONSchema)(e.contentSchema)&&(s.contentSchema=y(e.contentSchema,t.contentSchema,n)),s},v=y},3084:(e,t,n)=>{"use strict";n.r(t),n.d(t,{isBooleanJSONSchema:()=>a,isJSONSchema:()=>l,isJSONSchemaObject:()=>o});var s=n(5452),r=n.n(s);const a=e=>"boolean"==typeof e,o=e=>r()(e),l=e=>a(e)||o(e)},5202:(e,t,n)=>{"use strict";n.r(t),n.d(t,{bytes:()=>l,integer:()=>m,number:()=>p,pick:()=>i,randexp:()=>c,string:()=>u});var s=n(185),r=n.n(s),a=n(9989),o=n.n(a);const l=e=>r()(e),c=e=>{try{return new(o())(e).gen()}catch{return"string"}},i=e=>e.at(0),u=()=>"string",p=()=>0,m=()=>0},6276:(e,t,n)=>{"use strict";n.r(t),n.d(t,{foldType:()=>j,getType:()=>_,inferType:()=>b});var s=n(7104),r=n.n(s),a=n(4901),o=n.n(a),l=n(2605),c=n.n(l),i=n(6680),u=n.n(i),p=n(7252),m=n.n(p),d=n(3942),h=n.n(d),g=n(9998),f=n.n(g),y=n(8338),v=n(3084),S=n(5202),E=n(3783);const w={array:["items","prefixItems","contains","maxContains","minContains","maxItems","minItems","uniqueItems","unevaluatedItems"],object:["properties","additionalProperties","patternProperties","propertyNames","minProperties","maxProperties","required","dependentSchemas","dependentRequired","unevaluatedProperties"],string:["pattern","format","minLength","maxLength","contentEncoding","contentMediaType","contentSchema"],integer:["minimum","maximum","exclusiveMinimum","exclusiveMaximum","multipleOf"]};w.number=w.integer;const x="string",C=e=>void 0===e?null:null===e?"null":r()(e)?"array":o()(e)?"integer":typeof e,j=e=>{if(r()(e)&&e.length>=1){if(c()(e).call(e,"array"))return"array";if(c()(e).call(e,"object"))return"object";{const t=(0,S.pick)(e);if(c()(y.ALL_TYPES).call(y.ALL_TYPES,t))return t}}return c()(y.ALL_TYPES).call(y.ALL_TYPES,e)?e:null},b=function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:new(u());if(!(0,v.isJSONSchemaObject)(e))return x;if(t.has(e))return x;t.add(e);let{type:n,const:s}=e;if(n=j(n),"string"!=typeof n){const t=m()(w);e:for(let s=0;s<t.length;s+=1){const r=t[s],a=w[r];for(let t=0;t<a.length;t+=1){const s=a[t];if(Object.hasOwn(e,s)){n=r;break e}}}}if("string"!=typeof n&&void 0!==s){const e=C(s);n="string"==typeof e?e:n}if("string"!=typeof n){const s=n=>{if(r()(e[n])){var s;const r=h()(s=e[n]).call(s,(e=>b(e,t)));return j(r)}return null},o=s("allOf"),l=s("anyOf"),c=s("oneOf"),i=e.not?b(e.not,t):null;var a;if(o||l||c||i)n=j(f()(a=[o,l,c,i]).call(a,Boolean))}if("string"!=typeof n&&(0,E.hasExample)(e)){const t=(0,E.extractExample)(e),s=C(t);n="string"==typeof s?s:n}return t.delete(e),n||x},_=e=>b(e)},9346:(e,t,n)=>{"use strict";n.r(t),n.d(t,{fromJSONBooleanSchema:()=>r,typeCast:()=>a});var s=n(3084);const r=e=>!1===e?{not:{}}:{},a=e=>(0,s.isBooleanJSONSchema)(e)?r(e):(0,s.isJSONSchemaObject)(e)?e:{}},1433:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>r});var s=n(871).Buffer;const r=e=>s.from(e).toString("ascii")},8509:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>r});var s=n(871).Buffer;const r=e=>s.from(e).toString("utf8")},5709:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>r});var s=n(871).Buffer;const r=e=>s.from(e).toString("hex")},4180:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>r});var s=n(871).Buffer;const r=e=>{const t=s.from(e).toString("utf8"),n="ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";let r=0,a="",o=0,l=0;for(let e=0;e<t.length;e++)for(o=o<<8|t.charCodeAt(e),l+=8;l>=5;)a+=n.charAt(o>>>l-5&31),l-=5;l>0&&(a+=n.charAt(o<<5-l&31),r=(8-8*t.length%5)%5);for(let e=0;e<r;e++)a+="=";return a}},1967:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>r});var s=n(871).Buffer;const r=e=>s.from(e).toString("base64")},4366:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>r});var s=n(871).Buffer;const r=e=>s.from(e).toString("binary")},5037:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(600),r=n.n(s);const a=e=>{let t="";for(let a=0;a<e.length;a++){const o=e.charCodeAt(a);if(61===o)t+="=3D";else if(o>=33&&o<=60||o>=62&&o<=126||9===o||32===o)t+=e.charAt(a);else if(13===o||10===o)t+="\r\n";else if(o>126){const s=unescape(encodeURIComponent(e.charAt(a)));for(let e=0;e<s.length;e++){var n;t+="="+r()(n="0"+s.charCodeAt(e).toString(16)).call(n,-2).toUpperCase()}}else{var s;t+="="+r()(s="0"+o.toString(16)).call(s,-2).toUpperCase()}}return t}},4045:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>(new Date).toISOString()},1456:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>(new Date).toISOString().substring(0,10)},560:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>.1},4299:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"P3D"},3981:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"user@example.com"},1890:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>.1},9375:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"example.com"},4518:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"실례@example.com"},273:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"실례.com"},7864:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>2**30>>>0},1726:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>2**53-1},8793:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"198.51.100.42"},8269:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"2001:0db8:5b96:0000:0000:426f:8e17:642a"},5693:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"path/실례.html"},3080:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"https://실례.com/"},7856:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"/a/b/c"},5088:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});const s=require("@babel/runtime-corejs3/core-js-stable/string/raw");var r=n.n(s),a=n(5202);const o={"application/json":()=>'{"key":"value"}',"application/ld+json":()=>'{"name": "John Doe"}',"application/x-httpd-php":()=>"<?php echo '<p>Hello World!</p>'; ?>","application/rtf":()=>r()`{\rtf1\adeflang1025\ansi\ansicpg1252\uc1`,"application/x-sh":()=>'echo "Hello World!"',"application/xhtml+xml":()=>"<p>content</p>","application/*":()=>(0,a.bytes)(25).toString("binary")}},4342:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>r});var s=n(5202);const r={"audio/*":()=>(0,s.bytes)(25).toString("binary")}},6724:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>r});var s=n(5202);const r={"image/*":()=>(0,s.bytes)(25).toString("binary")}},5378:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s={"text/plain":()=>"string","text/css":()=>".selector { border: 1px solid red }","text/csv":()=>"value1,value2,value3","text/html":()=>"<p>content</p>","text/calendar":()=>"BEGIN:VCALENDAR","text/javascript":()=>"console.dir('Hello world!');","text/xml":()=>'<person age="30">John Doe</person>',"text/*":()=>"string"}},2974:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>r});var s=n(5202);const r={"video/*":()=>(0,s.bytes)(25).toString("binary")}},3393:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"********"},4335:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"^[a-z]+$"},375:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"1/0"},5243:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>(new Date).toISOString().substring(11)},4692:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"path/index.html"},3829:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"https://example.com/dictionary/{term:1}/{term}"},2978:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"https://example.com/"},8859:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>"3fa85f64-5717-4562-b3fc-2c963f66afa6"},8591:(e,t,n)=>{"use strict";n.r(t),n.d(t,{createXMLExample:()=>s.createXMLExample,encoderAPI:()=>r.default,formatAPI:()=>a.default,mediaTypeAPI:()=>o.default,memoizedCreateXMLExample:()=>s.memoizedCreateXMLExample,memoizedSampleFromSchema:()=>s.memoizedSampleFromSchema,sampleFromSchema:()=>s.sampleFromSchema,sampleFromSchemaGeneric:()=>s.sampleFromSchemaGeneric});var s=n(4277),r=n(9507),a=n(2906),o=n(537)},4277:(e,t,n)=>{"use strict";n.r(t),n.d(t,{createXMLExample:()=>T,memoizedCreateXMLExample:()=>J,memoizedSampleFromSchema:()=>$,sampleFromSchema:()=>M,sampleFromSchemaGeneric:()=>R});var s=n(7104),r=n.n(s),a=n(4901),o=n.n(a),l=n(4235),c=n.n(l),i=n(3580),u=n.n(i),p=n(2605),m=n.n(p),d=n(4883),h=n.n(d),g=n(3942),f=n.n(g),y=n(8344),v=n.n(y),S=n(8920),E=n.n(S),w=n(9699),x=n.n(w),C=n(5452),j=n.n(C),b=n(1669),_=n(7481),N=n(3273),O=n(6276),k=n(9346),A=n(3783),I=n(5202),P=n(7078),q=n(3084);const R=function(e){var t;let n=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{},s=arguments.length>2&&void 0!==arguments[2]?arguments[2]:void 0,a=arguments.length>3&&void 0!==arguments[3]&&arguments[3];"function"==typeof(null===(t=e)||void 0===t?void 0:t.toJS)&&(e=e.toJS()),e=(0,k.typeCast)(e);let l=void 0!==s||(0,A.hasExample)(e);const i=!l&&r()(e.oneOf)&&e.oneOf.length>0,p=!l&&r()(e.anyOf)&&e.anyOf.length>0;if(!l&&(i||p)){const t=(0,k.typeCast)(i?(0,I.pick)(e.oneOf):(0,I.pick)(e.anyOf));!(e=(0,P.default)(e,t,n)).xml&&t.xml&&(e.xml=t.xml),(0,A.hasExample)(e)&&(0,A.hasExample)(t)&&(l=!0)}const d={};let{xml:g,properties:y,additionalProperties:v,items:S,contains:E}=e||{},w=(0,O.getType)(e),{includeReadOnly:C,includeWriteOnly:_}=n;g=g||{};let T,{name:M,prefix:D,namespace:J}=g,$={};if(Object.hasOwn(e,"type")||(e.type=w),a&&(M=M||"notagname",T=(D?`${D}:`:"")+M,J)){d[D?`xmlns:${D}`:"xmlns"]=J}a&&($[T]=[]);const L=(0,b.mz)(y);let K,V=0;const U=()=>o()(e.maxProperties)&&e.maxProperties>0&&V>=e.maxProperties,F=t=>!(o()(e.maxProperties)&&e.maxProperties>0)||!U()&&(!(t=>{var n;return!r()(e.required)||0===e.required.length||!m()(n=e.required).call(n,t)})(t)||e.maxProperties-V-(()=>{if(!r()(e.required)||0===e.required.length)return 0;let t=0;var n,s;return a?c()(n=e.required).call(n,(e=>t+=void 0===$[e]?0:1)):c()(s=e.required).call(s,(e=>{var n;t+=void 0===(null===(n=$[T])||void 0===n?void 0:u()(n).call(n,(t=>void 0!==t[e])))?0:1})),e.required.length-t})()>0);if(K=a?function(t){let s=arguments.length>1&&void 0!==arguments[1]?arguments[1]:void 0;if(e&&L[t]){if(L[t].xml=L[t].xml||{},L[t].xml.attribute){const e=r()(L[t].enum)?(0,I.pick)(L[t].enum):void 0;if((0,A.hasExample)(L[t]))d[L[t].xml.name||t]=(0,A.extractExample)(L[t]);else if(void 0!==e)d[L[t].xml.name||t]=e;else{const e=(0,k.typeCast)(L[t]),n=(0,O.getType)(e),s=L[t].xml.name||t;d[s]=N.default[n](e)}return}L[t].xml.name=L[t].xml.name||t}else L[t]||!1===v||(L[t]={xml:{name:t}});let o=R(L[t],n,s,a);var l;F(t)&&(V++,r()(o)?$[T]=h()(l=$[T]).call(l,o):$[T].push(o))}:(t,s)=>{var r;if(F(t)){if(j()(null===(r=e.discriminator)||void 0===r?void 0:r.mapping)&&e.discriminator.propertyName===t&&"string"==typeof e.$$ref){for(const n in e.discriminator.mapping)if(-1!==e.$$ref.search(e.discriminator.mapping[n])){$[t]=n;break}}else $[t]=R(L[t],n,s,a);V++}},l){let t;if(t=void 0!==s?s:(0,A.extractExample)(e),!a){if("number"==typeof t&&"string"===w)return`${t}`;if("string"!=typeof t||"string"===w)return t;try{return JSON.parse(t)}catch{return t}}if("array"===w){if(!r()(t)){if("string"==typeof t)return t;t=[t]}let s=[];return(0,q.isJSONSchemaObject)(S)&&(S.xml=S.xml||g||{},S.xml.name=S.xml.name||g.name,s=f()(t).call(t,(e=>R(S,n,e,a)))),(0,q.isJSONSchemaObject)(E)&&(E.xml=E.xml||g||{},E.xml.name=E.xml.name||g.name,s=[R(E,n,void 0,a),...s]),s=N.default.array(e,{sample:s}),g.wrapped?($[T]=s,x()(d)||$[T].push({_attr:d})):$=s,$}if("object"===w){if("string"==typeof t)return t;for(const e in t){var z,B,W,H;Object.hasOwn(t,e)&&(null!==(z=L[e])&&void 0!==z&&z.readOnly&&!C||null!==(B=L[e])&&void 0!==B&&B.writeOnly&&!_||(null!==(W=L[e])&&void 0!==W&&null!==(H=W.xml)&&void 0!==H&&H.attribute?d[L[e].xml.name||e]=t[e]:K(e,t[e])))}return x()(d)||$[T].push({_attr:d}),$}return $[T]=x()(d)?t:[{_attr:d},t],$}if("array"===w){let t=[];var Z,G;if((0,q.isJSONSchemaObject)(E))if(a&&(E.xml=E.xml||e.xml||{},E.xml.name=E.xml.name||g.name),r()(E.anyOf))t.push(...f()(Z=E.anyOf).call(Z,(e=>R((0,P.default)(e,E,n),n,void 0,a))));else if(r()(E.oneOf)){var X;t.push(...f()(X=E.oneOf).call(X,(e=>R((0,P.default)(e,E,n),n,void 0,a))))}else{if(!(!a||a&&g.wrapped))return R(E,n,void 0,a);t.push(R(E,n,void 0,a))}if((0,q.isJSONSchemaObject)(S))if(a&&(S.xml=S.xml||e.xml||{},S.xml.name=S.xml.name||g.name),r()(S.anyOf))t.push(...f()(G=S.anyOf).call(G,(e=>R((0,P.default)(e,S,n),n,void 0,a))));else if(r()(S.oneOf)){var Y;t.push(...f()(Y=S.oneOf).call(Y,(e=>R((0,P.default)(e,S,n),n,void 0,a))))}else{if(!(!a||a&&g.wrapped))return R(S,n,void 0,a);t.push(R(S,n,void 0,a))}return t=N.default.array(e,{sample:t}),a&&g.wrapped?($[T]=t,x()(d)||$[T].push({_attr:d}),$):t}if("object"===w){for(let e in L){var Q,ee,te;Object.hasOwn(L,e)&&(null!==(Q=L[e])&&void 0!==Q&&Q.deprecated||null!==(ee=L[e])&&void 0!==ee&&ee.readOnly&&!C||null!==(te=L[e])&&void 0!==te&&te.writeOnly&&!_||K(e))}if(a&&d&&$[T].push({_attr:d}),U())return $;if((0,q.isBooleanJSONSchema)(v)&&v)a?$[T].push({additionalProp:"Anything can be here"}):$.additionalProp1={},V++;else if((0,q.isJSONSchemaObject)(v)){var ne,se;const t=v,s=R(t,n,void 0,a);if(a&&"string"==typeof(null==t||null===(ne=t.xml)||void 0===ne?void 0:ne.name)&&"notagname"!==(null==t||null===(se=t.xml)||void 0===se?void 0:se.name))$[T].push(s);else{const t=o()(e.minProperties)&&e.minProperties>0&&V<e.minProperties?e.minProperties-V:3;for(let e=1;e<=t;e++){if(U())return $;if(a){const t={};t["additionalProp"+e]=s.notagname,$[T].push(t)}else $["additionalProp"+e]=s;V++}}}return $}let re;if(void 0!==e.const)re=e.const;else if(e&&r()(e.enum))re=(0,I.pick)((0,b.AF)(e.enum));else{const t=(0,q.isJSONSchemaObject)(e.contentSchema)?R(e.contentSchema,n,void 0,a):void 0;re=N.default[w](e,{sample:t})}return a?($[T]=x()(d)?re:[{_attr:d},re],$):re},T=(e,t,n)=>{const s=R(e,t,n,!0);if(s)return"string"==typeof s?s:E()(s,{declaration:!0,indent:"\t"})},M=(e,t,n)=>R(e,t,n,!1),D=(e,t,n)=>[e,v()(t),v()(n)],J=(0,_.Z)(T,D),$=(0,_.Z)(M,D)},3982:(e,t,n)=>{"use strict";n.r(t),n.d(t,{applyArrayConstraints:()=>p,default:()=>m});var s=n(4901),r=n.n(s),a=n(600),o=n.n(a),l=n(1733),c=n.n(l),i=n(7885),u=n.n(i);const p=function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};const{minItems:n,maxItems:s,uniqueItems:a}=t,{contains:l,minContains:i,maxContains:p}=t;let m=[...e];if(null!=l&&"object"==typeof l){if(r()(i)&&i>1){const e=m.at(0);for(let t=1;t<i;t+=1)m.unshift(e)}r()(p)}if(r()(s)&&s>0&&(m=o()(e).call(e,0,s)),r()(n)&&n>0)for(let e=0;m.length<n;e+=1)m.push(m[e%m.length]);return!0===a&&(m=c()(new(u())(m))),m},m=(e,t)=>{let{sample:n}=t;return p(n,e)}},4108:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=e=>"boolean"!=typeof e.default||e.default},3273:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>p});var s=n(3982),r=n(6852),a=n(4522),o=n(844),l=n(8864),c=n(4108),i=n(853);const u={array:s.default,object:r.default,string:a.default,number:o.default,integer:l.default,boolean:c.default,null:i.default},p=new Proxy(u,{get:(e,t)=>"string"==typeof t&&Object.hasOwn(e,t)?e[t]:()=>`Unknown Type: ${t}`})},8864:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>l});var s=n(5202),r=n(2906),a=n(7864),o=n(1726);const l=e=>{const{format:t}=e;return"string"==typeof t?(e=>{const{format:t}=e,n=(0,r.default)(t);if("function"==typeof n)return n(e);switch(t){case"int32":return(0,a.default)();case"int64":return(0,o.default)()}return(0,s.integer)()})(e):(0,s.integer)()}},853:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>null},844:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>p});var s=n(4901),r=n.n(s);const a=require("@babel/runtime-corejs3/core-js-stable/number/epsilon");var o=n.n(a),l=n(5202),c=n(2906),i=n(1890),u=n(560);const p=e=>{const{format:t}=e;let n;return n="string"==typeof t?(e=>{const{format:t}=e,n=(0,c.default)(t);if("function"==typeof n)return n(e);switch(t){case"float":return(0,i.default)();case"double":return(0,u.default)()}return(0,l.number)()})(e):(0,l.number)(),function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};const{minimum:n,maximum:s,exclusiveMinimum:a,exclusiveMaximum:l}=t,{multipleOf:c}=t,i=r()(e)?1:o();let u="number"==typeof n?n:null,p="number"==typeof s?s:null,m=e;if("number"==typeof a&&(u=null!==u?Math.max(u,a+i):a+i),"number"==typeof l&&(p=null!==p?Math.min(p,l-i):l-i),m=u>p&&e||u||p||m,"number"==typeof c&&c>0){const e=m%c;m=0===e?m:m+c-e}return m}(n,e)}},6852:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=()=>{throw new Error("Not implemented")}},4522:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>J});var s=n(4901),r=n.n(s),a=n(600),o=n.n(a),l=n(7104),c=n.n(l),i=n(8344),u=n.n(i),p=n(4292),m=n.n(p),d=n(5202),h=n(3084),g=n(3981),f=n(4518),y=n(9375),v=n(273),S=n(8793),E=n(8269),w=n(2978),x=n(4692),C=n(3080),j=n(5693),b=n(8859),_=n(3829),N=n(7856),O=n(375),k=n(4045),A=n(1456),I=n(5243),P=n(4299),q=n(3393),R=n(4335),T=n(2906),M=n(9507),D=n(537);const J=function(e){let{sample:t}=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};const{contentEncoding:n,contentMediaType:s,contentSchema:a}=e,{pattern:l,format:i}=e,p=(0,M.default)(n)||m();let J;if("string"==typeof l)J=(0,d.randexp)(l);else if("string"==typeof i)J=(e=>{const{format:t}=e,n=(0,T.default)(t);if("function"==typeof n)return n(e);switch(t){case"email":return(0,g.default)();case"idn-email":return(0,f.default)();case"hostname":return(0,y.default)();case"idn-hostname":return(0,v.default)();case"ipv4":return(0,S.default)();case"ipv6":return(0,E.default)();case"uri":return(0,w.default)();case"uri-reference":return(0,x.default)();case"iri":return(0,C.default)();case"iri-reference":return(0,j.default)();case"uuid":return(0,b.default)();case"uri-template":return(0,_.default)();case"json-pointer":return(0,N.default)();case"relative-json-pointer":return(0,O.default)();case"date-time":return(0,k.default)();case"date":return(0,A.default)();case"time":return(0,I.default)();case"duration":return(0,P.default)();case"password":return(0,q.default)();case"regex":return(0,R.default)()}return(0,d.string)()})(e);else if((0,h.isJSONSchema)(a)&&"string"==typeof s&&void 0!==t)J=c()(t)||"object"==typeof t?u()(t):String(t);else if("string"==typeof s){const t=(0,D.default)(s);"function"==typeof t&&(J=t(e))}else J=(0,d.string)();return p(function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};const{maxLength:n,minLength:s}=t;let a=e;if(r()(n)&&n>0&&(a=o()(a).call(a,0,n)),r()(s)&&s>0){let e=0;for(;a.length<s;)a+=a[e++%a.length]}return a}(J,e))}},5474:(e,t,n)=>{"use strict";n.r(t),n.d(t,{SHOW:()=>l,UPDATE_FILTER:()=>a,UPDATE_LAYOUT:()=>r,UPDATE_MODE:()=>o,changeMode:()=>p,show:()=>u,updateFilter:()=>i,updateLayout:()=>c});var s=n(1669);const r="layout_update_layout",a="layout_update_filter",o="layout_update_mode",l="layout_show";function c(e){return{type:r,payload:e}}function i(e){return{type:a,payload:e}}function u(e){let t=!(arguments.length>1&&void 0!==arguments[1])||arguments[1];return e=(0,s.AF)(e),{type:l,payload:{thing:e,shown:t}}}function p(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:"";return e=(0,s.AF)(e),{type:o,payload:{thing:e,mode:t}}}},6821:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>l});var s=n(5672),r=n(5474),a=n(4400),o=n(8989);function l(){return{statePlugins:{layout:{reducers:s.default,actions:r,selectors:a},spec:{wrapSelectors:o}}}}},5672:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>l});var s=n(4883),r=n.n(s),a=n(5572),o=n(5474);const l={[o.UPDATE_LAYOUT]:(e,t)=>e.set("layout",t.payload),[o.UPDATE_FILTER]:(e,t)=>e.set("filter",t.payload),[o.SHOW]:(e,t)=>{const n=t.payload.shown,s=(0,a.fromJS)(t.payload.thing);return e.update("shown",(0,a.fromJS)({}),(e=>e.set(s,n)))},[o.UPDATE_MODE]:(e,t)=>{var n;let s=t.payload.thing,a=t.payload.mode;return e.setIn(r()(n=["modes"]).call(n,s),(a||"")+"")}}},4400:(e,t,n)=>{"use strict";n.r(t),n.d(t,{current:()=>o,currentFilter:()=>l,isShown:()=>c,showSummary:()=>u,whatMode:()=>i});var s=n(6814),r=n(1669),a=n(5572);const o=e=>e.get("layout"),l=e=>e.get("filter"),c=(e,t,n)=>(t=(0,r.AF)(t),e.get("shown",(0,a.fromJS)({})).get((0,a.fromJS)(t),n)),i=function(e,t){let n=arguments.length>2&&void 0!==arguments[2]?arguments[2]:"";return t=(0,r.AF)(t),e.getIn(["modes",...t],n)},u=(0,s.createSelector)((e=>e),(e=>!c(e,"editor")))},8989:(e,t,n)=>{"use strict";n.r(t),n.d(t,{taggedOperations:()=>a});var s=n(600),r=n.n(s);const a=(e,t)=>function(n){for(var s=arguments.length,a=new Array(s>1?s-1:0),o=1;o<s;o++)a[o-1]=arguments[o];let l=e(n,...a);const{fn:c,layoutSelectors:i,getConfigs:u}=t.getSystem(),p=u(),{maxDisplayedTags:m}=p;let d=i.currentFilter();return d&&!0!==d&&"true"!==d&&"false"!==d&&(l=c.opsFilter(l,d)),m&&!isNaN(m)&&m>=0&&(l=r()(l).call(l,0,m)),l}},9150:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(593),r=n.n(s);function a(e){let{configs:t}=e;const n={debug:0,info:1,log:2,warn:3,error:4},s=e=>n[e]||-1;let{logLevel:a}=t,o=s(a);function l(e){for(var t=arguments.length,n=new Array(t>1?t-1:0),r=1;r<t;r++)n[r-1]=arguments[r];s(e)>=o&&console[e](...n)}return l.warn=r()(l).call(l,null,"warn"),l.error=r()(l).call(l,null,"error"),l.info=r()(l).call(l,null,"info"),l.debug=r()(l).call(l,null,"debug"),{rootInjects:{log:l}}}},7002:(e,t,n)=>{"use strict";n.r(t),n.d(t,{CLEAR_REQUEST_BODY_VALIDATE_ERROR:()=>m,CLEAR_REQUEST_BODY_VALUE:()=>d,SET_REQUEST_BODY_VALIDATE_ERROR:()=>p,UPDATE_ACTIVE_EXAMPLES_MEMBER:()=>l,UPDATE_REQUEST_BODY_INCLUSION:()=>o,UPDATE_REQUEST_BODY_VALUE:()=>r,UPDATE_REQUEST_BODY_VALUE_RETAIN_FLAG:()=>a,UPDATE_REQUEST_CONTENT_TYPE:()=>c,UPDATE_RESPONSE_CONTENT_TYPE:()=>i,UPDATE_SELECTED_SERVER:()=>s,UPDATE_SERVER_VARIABLE_VALUE:()=>u,clearRequestBodyValidateError:()=>C,clearRequestBodyValue:()=>b,initRequestBodyValidateError:()=>j,setActiveExamplesMember:()=>v,setRequestBodyInclusion:()=>y,setRequestBodyValidateError:()=>x,setRequestBodyValue:()=>g,setRequestContentType:()=>S,setResponseContentType:()=>E,setRetainRequestBodyValueFlag:()=>f,setSelectedServer:()=>h,setServerVariableValue:()=>w});const s="oas3_set_servers",r="oas3_set_request_body_value",a="oas3_set_request_body_retain_flag",o="oas3_set_request_body_inclusion",l="oas3_set_active_examples_member",c="oas3_set_request_content_type",i="oas3_set_response_content_type",u="oas3_set_server_variable_value",p="oas3_set_request_body_validate_error",m="oas3_clear_request_body_validate_error",d="oas3_clear_request_body_value";function h(e,t){return{type:s,payload:{selectedServerUrl:e,namespace:t}}}function g(e){let{value:t,pathMethod:n}=e;return{type:r,payload:{value:t,pathMethod:n}}}const f=e=>{let{value:t,pathMethod:n}=e;return{type:a,payload:{value:t,pathMethod:n}}};function y(e){let{value:t,pathMethod:n,name:s}=e;return{type:o,payload:{value:t,pathMethod:n,name:s}}}function v(e){let{name:t,pathMethod:n,contextType:s,contextName:r}=e;return{type:l,payload:{name:t,pathMethod:n,contextType:s,contextName:r}}}function S(e){let{value:t,pathMethod:n}=e;return{type:c,payload:{value:t,pathMethod:n}}}function E(e){let{value:t,path:n,method:s}=e;return{type:i,payload:{value:t,path:n,method:s}}}function w(e){let{server:t,namespace:n,key:s,val:r}=e;return{type:u,payload:{server:t,namespace:n,key:s,val:r}}}const x=e=>{let{path:t,method:n,validationErrors:s}=e;return{type:p,payload:{path:t,method:n,validationErrors:s}}},C=e=>{let{path:t,method:n}=e;return{type:m,payload:{path:t,method:n}}},j=e=>{let{pathMethod:t}=e;return{type:m,payload:{path:t[0],method:t[1]}}},b=e=>{let{pathMethod:t}=e;return{type:d,payload:{pathMethod:t}}}},3723:(e,t,n)=>{"use strict";n.r(t),n.d(t,{definitionsToAuthorize:()=>p});var s=n(4235),r=n.n(s),a=n(9998),o=n.n(a),l=n(66),c=n.n(l),i=n(6814),u=n(5572);const p=(m=(0,i.createSelector)((e=>e),(e=>{let{specSelectors:t}=e;return t.securityDefinitions()}),((e,t)=>{var n;let s=(0,u.List)();return t?(r()(n=t.entrySeq()).call(n,(e=>{let[t,n]=e;const a=n.get("type");var l;if("oauth2"===a&&r()(l=n.get("flows").entrySeq()).call(l,(e=>{let[r,a]=e,l=(0,u.fromJS)({flow:r,authorizationUrl:a.get("authorizationUrl"),tokenUrl:a.get("tokenUrl"),scopes:a.get("scopes"),type:n.get("type"),description:n.get("description")});s=s.push(new u.Map({[t]:o()(l).call(l,(e=>void 0!==e))}))})),"http"!==a&&"apiKey"!==a||(s=s.push(new u.Map({[t]:n}))),"openIdConnect"===a&&n.get("openIdConnectData")){let e=n.get("openIdConnectData"),a=e.get("grant_types_supported")||["authorization_code","implicit"];r()(a).call(a,(r=>{var a;let l=e.get("scopes_supported")&&c()(a=e.get("scopes_supported")).call(a,((e,t)=>e.set(t,"")),new u.Map),i=(0,u.fromJS)({flow:r,authorizationUrl:e.get("authorization_endpoint"),tokenUrl:e.get("token_endpoint"),scopes:l,type:"oauth2",openIdConnectUrl:n.get("openIdConnectUrl")});s=s.push(new u.Map({[t]:o()(i).call(i,(e=>void 0!==e))}))}))}})),s):s})),(e,t)=>function(){for(var n=arguments.length,s=new Array(n),r=0;r<n;r++)s[r]=arguments[r];if(t.getSystem().specSelectors.isOAS3()){let e=t.getState().getIn(["spec","resolvedSubtrees","components","securitySchemes"]);return m(t,e,...s)}return e(...s)});var m},3427:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>i});var s=n(7252),r=n.n(s),a=n(3942),o=n.n(a),l=n(6689),c=n.n(l);n(580),n(8082);const i=e=>{let{callbacks:t,specPath:n,specSelectors:s,getComponent:a}=e;const l=s.callbacksOperations({callbacks:t,specPath:n}),i=r()(l),u=a("OperationContainer",!0);return 0===i.length?c().createElement("span",null,"No callbacks"):c().createElement("div",null,o()(i).call(i,(e=>{var t;return c().createElement("div",{key:`${e}`},c().createElement("h2",null,e),o()(t=l[e]).call(t,(t=>c().createElement(u,{key:`${e}-${t.path}-${t.method}`,op:t.operation,tag:"callbacks",method:t.method,path:t.path,specPath:t.specPath,allowTryItOut:!1}))))})))}},6775:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>d});var s=n(1093),r=n.n(s),a=n(4994),o=n.n(a),l=n(9998),c=n.n(l),i=n(3942),u=n.n(i),p=n(6689),m=n.n(p);n(580);class d extends m().Component{constructor(e,t){super(e,t),r()(this,"onChange",(e=>{let{onChange:t}=this.props,{value:n,name:s}=e.target,r=o()({},this.state.value);s?r[s]=n:r=n,this.setState({value:r},(()=>t(this.state)))}));let{name:n,schema:s}=this.props,a=this.getValue();this.state={name:n,schema:s,value:a}}getValue(){let{name:e,authorized:t}=this.props;return t&&t.getIn([e,"value"])}render(){var e;let{schema:t,getComponent:n,errSelectors:s,name:r}=this.props;const a=n("Input"),o=n("Row"),l=n("Col"),i=n("authError"),p=n("Markdown",!0),d=n("JumpToPath",!0),h=(t.get("scheme")||"").toLowerCase();let g=this.getValue(),f=c()(e=s.allErrors()).call(e,(e=>e.get("authId")===r));if("basic"===h){var y;let e=g?g.get("username"):null;return m().createElement("div",null,m().createElement("h4",null,m().createElement("code",null,r||t.get("name")),"  (http, Basic)",m().createElement(d,{path:["securityDefinitions",r]})),e&&m().createElement("h6",null,"Authorized"),m().createElement(o,null,m().createElement(p,{source:t.get("description")})),m().createElement(o,null,m().createElement("label",null,"Username:"),e?m().createElement("code",null," ",e," "):m().createElement(l,null,m().createElement(a,{type:"text",required:"required",name:"username","aria-label":"auth-basic-username",onChange:this.onChange,autoFocus:!0}))),m().createElement(o,null,m().createElement("label",null,"Password:"),e?m().createElement("code",null," ****** "):m().createElement(l,null,m().createElement(a,{autoComplete:"new-password",name:"password",type:"password","aria-label":"auth-basic-password",onChange:this.onChange}))),u()(y=f.valueSeq()).call(y,((e,t)=>m().createElement(i,{error:e,key:t}))))}var v;return"bearer"===h?m().createElement("div",null,m().createElement("h4",null,m().createElement("code",null,r||t.get("name")),"  (http, Bearer)",m().createElement(d,{path:["securityDefinitions",r]})),g&&m().createElement("h6",null,"Authorized"),m().createElement(o,null,m().createElement(p,{source:t.get("description")})),m().createElement(o,null,m().createElement("label",null,"Value:"),g?m().createElement("code",null," ****** "):m().createElement(l,null,m().createElement(a,{type:"text","aria-label":"auth-bearer-value",onChange:this.onChange,autoFocus:!0}))),u()(v=f.valueSeq()).call(v,((e,t)=>m().createElement(i,{error:e,key:t})))):m().createElement("div",null,m().createElement("em",null,m().createElement("b",null,r)," HTTP authentication: unsupported scheme ",`'${h}'`))}}},6467:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>p});var s=n(3427),r=n(2458),a=n(5757),o=n(6617),l=n(9928),c=n(5327),i=n(6775),u=n(6796);const p={Callbacks:s.default,HttpAuth:i.default,RequestBody:r.default,Servers:o.default,ServersContainer:l.default,RequestBodyEditor:c.default,OperationServers:u.default,operationLink:a.default}},5757:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>u});var s=n(8344),r=n.n(s),a=n(3942),o=n.n(a),l=n(6689),c=n.n(l);n(580),n(8082);class i extends l.Component{render(){const{link:e,name:t,getComponent:n}=this.props,s=n("Markdown",!0);let a=e.get("operationId")||e.get("operationRef"),l=e.get("parameters")&&e.get("parameters").toJS(),i=e.get("description");return c().createElement("div",{className:"operation-link"},c().createElement("div",{className:"description"},c().createElement("b",null,c().createElement("code",null,t)),i?c().createElement(s,{source:i}):null),c().createElement("pre",null,"Operation `",a,"`",c().createElement("br",null),c().createElement("br",null),"Parameters ",function(e,t){var n;if("string"!=typeof t)return"";return o()(n=t.split("\n")).call(n,((t,n)=>n>0?Array(e+1).join(" ")+t:t)).join("\n")}(0,r()(l,null,2))||"{}",c().createElement("br",null)))}}const u=i},6796:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>l});var s=n(1093),r=n.n(s),a=n(6689),o=n.n(a);n(580),n(8082);class l extends o().Component{constructor(){super(...arguments),r()(this,"setSelectedServer",(e=>{const{path:t,method:n}=this.props;return this.forceUpdate(),this.props.setSelectedServer(e,`${t}:${n}`)})),r()(this,"setServerVariableValue",(e=>{const{path:t,method:n}=this.props;return this.forceUpdate(),this.props.setServerVariableValue({...e,namespace:`${t}:${n}`})})),r()(this,"getSelectedServer",(()=>{const{path:e,method:t}=this.props;return this.props.getSelectedServer(`${e}:${t}`)})),r()(this,"getServerVariable",((e,t)=>{const{path:n,method:s}=this.props;return this.props.getServerVariable({namespace:`${n}:${s}`,server:e},t)})),r()(this,"getEffectiveServerValue",(e=>{const{path:t,method:n}=this.props;return this.props.getEffectiveServerValue({server:e,namespace:`${t}:${n}`})}))}render(){const{operationServers:e,pathServers:t,getComponent:n}=this.props;if(!e&&!t)return null;const s=n("Servers"),r=e||t,a=e?"operation":"path";return o().createElement("div",{className:"opblock-section operation-servers"},o().createElement("div",{className:"opblock-section-header"},o().createElement("div",{className:"tab-header"},o().createElement("h4",{className:"opblock-title"},"Servers"))),o().createElement("div",{className:"opblock-description-wrapper"},o().createElement("h4",{className:"message"},"These ",a,"-level options override the global server options."),o().createElement(s,{servers:r,currentServer:this.getSelectedServer(),setSelectedServer:this.setSelectedServer,setServerVariableValue:this.setServerVariableValue,getServerVariable:this.getServerVariable,getEffectiveServerValue:this.getEffectiveServerValue})))}}},5327:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>p});var s=n(1093),r=n.n(s),a=n(6689),o=n.n(a),l=(n(580),n(9003)),c=n.n(l),i=n(1669);const u=Function.prototype;class p extends a.PureComponent{constructor(e,t){super(e,t),r()(this,"applyDefaultValue",(e=>{const{onChange:t,defaultValue:n}=e||this.props;return this.setState({value:n}),t(n)})),r()(this,"onChange",(e=>{this.props.onChange((0,i.Pz)(e))})),r()(this,"onDomChange",(e=>{const t=e.target.value;this.setState({value:t},(()=>this.onChange(t)))})),this.state={value:(0,i.Pz)(e.value)||e.defaultValue},e.onChange(e.value)}UNSAFE_componentWillReceiveProps(e){this.props.value!==e.value&&e.value!==this.state.value&&this.setState({value:(0,i.Pz)(e.value)}),!e.value&&e.defaultValue&&this.state.value&&this.applyDefaultValue(e)}render(){let{getComponent:e,errors:t}=this.props,{value:n}=this.state,s=t.size>0;const r=e("TextArea");return o().createElement("div",{className:"body-param"},o().createElement(r,{className:c()("body-param__text",{invalid:s}),title:t.size?t.join(", "):"",value:n,onChange:this.onDomChange}))}}r()(p,"defaultProps",{onChange:u,userHasEditedBody:!1})},2458:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>y,getDefaultRequestBodyValue:()=>f});var s=n(3942),r=n.n(s),a=n(8493),o=n.n(a),l=n(2605),c=n.n(l),i=n(7104),u=n.n(i),p=n(6689),m=n.n(p),d=(n(580),n(8082),n(5572)),h=n(1669),g=n(2518);const f=(e,t,n,s)=>{const r=e.getIn(["content",t]),a=r.get("schema").toJS(),o=void 0!==r.get("examples"),l=r.get("example"),c=o?r.getIn(["examples",n,"value"]):l,i=s.getSampleSchema(a,t,{includeWriteOnly:!0},c);return(0,h.Pz)(i)},y=e=>{let{userHasEditedBody:t,requestBody:n,requestBodyValue:s,requestBodyInclusionSetting:a,requestBodyErrors:l,getComponent:i,getConfigs:p,specSelectors:y,fn:v,contentType:S,isExecute:E,specPath:w,onChange:x,onChangeIncludeEmpty:C,activeExamplesKey:j,updateActiveExamplesKey:b,setRetainRequestBodyValueFlag:_}=e;const N=e=>{x(e.target.files[0])},O=e=>{let t={key:e,shouldDispatchInit:!1,defaultValue:!0};return"no value"===a.get(e,"no value")&&(t.shouldDispatchInit=!0),t},k=i("Markdown",!0),A=i("modelExample"),I=i("RequestBodyEditor"),P=i("highlightCode"),q=i("ExamplesSelectValueRetainer"),R=i("Example"),T=i("ParameterIncludeEmpty"),{showCommonExtensions:M}=p(),D=n&&n.get("description")||null,J=n&&n.get("content")||new d.OrderedMap;S=S||J.keySeq().first()||"";const $=J.get(S,(0,d.OrderedMap)()),L=$.get("schema",(0,d.OrderedMap)()),K=$.get("examples",null),V=null==K?void 0:r()(K).call(K,((e,t)=>{var s;const r=null===(s=e)||void 0===s?void 0:s.get("value",null);return r&&(e=e.set("value",f(n,S,t,v),r)),e}));if(l=d.List.isList(l)?l:(0,d.List)(),!$.size)return null;const U="object"===$.getIn(["schema","type"]),F="binary"===$.getIn(["schema","format"]),z="base64"===$.getIn(["schema","format"]);if("application/octet-stream"===S||0===o()(S).call(S,"image/")||0===o()(S).call(S,"audio/")||0===o()(S).call(S,"video/")||F||z){const e=i("Input");return E?m().createElement(e,{type:"file",onChange:N}):m().createElement("i",null,"Example values are not available for ",m().createElement("code",null,S)," media types.")}if(U&&("application/x-www-form-urlencoded"===S||0===o()(S).call(S,"multipart/"))&&L.get("properties",(0,d.OrderedMap)()).size>0){var B;const e=i("JsonSchemaForm"),t=i("ParameterExt"),n=L.get("properties",(0,d.OrderedMap)());return s=d.Map.isMap(s)?s:(0,d.OrderedMap)(),m().createElement("div",{className:"table-container"},D&&m().createElement(k,{source:D}),m().createElement("table",null,m().createElement("tbody",null,d.Map.isMap(n)&&r()(B=n.entrySeq()).call(B,(n=>{var o,p;let[g,f]=n;if(f.get("readOnly"))return;let y=M?(0,h.po)(f):null;const S=c()(o=L.get("required",(0,d.List)())).call(o,g),w=f.get("type"),j=f.get("format"),b=f.get("description"),_=s.getIn([g,"value"]),N=s.getIn([g,"errors"])||l,A=a.get(g)||!1,I=f.has("default")||f.has("example")||f.hasIn(["items","example"])||f.hasIn(["items","default"]),P=f.has("enum")&&(1===f.get("enum").size||S),q=I||P;let R="";"array"!==w||q||(R=[]),("object"===w||q)&&(R=v.getSampleSchema(f,!1,{includeWriteOnly:!0})),"string"!=typeof R&&"object"===w&&(R=(0,h.Pz)(R)),"string"==typeof R&&"array"===w&&(R=JSON.parse(R));const D="string"===w&&("binary"===j||"base64"===j);return m().createElement("tr",{key:g,className:"parameters","data-property-name":g},m().createElement("td",{className:"parameters-col_name"},m().createElement("div",{className:S?"parameter__name required":"parameter__name"},g,S?m().createElement("span",null," *"):null),m().createElement("div",{className:"parameter__type"},w,j&&m().createElement("span",{className:"prop-format"},"($",j,")"),M&&y.size?r()(p=y.entrySeq()).call(p,(e=>{let[n,s]=e;return m().createElement(t,{key:`${n}-${s}`,xKey:n,xVal:s})})):null),m().createElement("div",{className:"parameter__deprecated"},f.get("deprecated")?"deprecated":null)),m().createElement("td",{className:"parameters-col_description"},m().createElement(k,{source:b}),E?m().createElement("div",null,m().createElement(e,{fn:v,dispatchInitialValue:!D,schema:f,description:g,getComponent:i,value:void 0===_?R:_,required:S,errors:N,onChange:e=>{x(e,[g])}}),S?null:m().createElement(T,{onChange:e=>C(g,e),isIncluded:A,isIncludedOptions:O(g),isDisabled:u()(_)?0!==_.length:!(0,h.O2)(_)})):null))})))))}const W=f(n,S,j,v);let H=null;return(0,g.O)(W)&&(H="json"),m().createElement("div",null,D&&m().createElement(k,{source:D}),V?m().createElement(q,{userHasEditedBody:t,examples:V,currentKey:j,currentUserInputValue:s,onSelect:e=>{b(e)},updateValue:x,defaultToFirstExample:!0,getComponent:i,setRetainRequestBodyValueFlag:_}):null,E?m().createElement("div",null,m().createElement(I,{value:s,errors:l,defaultValue:W,onChange:x,getComponent:i})):m().createElement(A,{getComponent:i,getConfigs:p,specSelectors:y,expandDepth:1,isExecute:E,schema:$.get("schema"),specPath:w.push("content",S),example:m().createElement(P,{className:"body-param__example",getConfigs:p,language:H,value:(0,h.Pz)(s)||W}),includeWriteOnly:!0}),V?m().createElement(R,{example:V.get(j),getComponent:i,getConfigs:p}):null)}},9928:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(580);class a extends r().Component{render(){const{specSelectors:e,oas3Selectors:t,oas3Actions:n,getComponent:s}=this.props,a=e.servers(),o=s("Servers");return a&&a.size?r().createElement("div",null,r().createElement("span",{className:"servers-title"},"Servers"),r().createElement(o,{servers:a,currentServer:t.selectedServer(),setSelectedServer:n.setSelectedServer,setServerVariableValue:n.setServerVariableValue,getServerVariable:t.serverVariableValue,getEffectiveServerValue:t.serverEffectiveValue})):null}}},6617:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>m});var s=n(1093),r=n.n(s),a=n(3580),o=n.n(a),l=n(3942),c=n.n(l),i=n(6689),u=n.n(i),p=n(5572);n(580),n(8082);class m extends u().Component{constructor(){super(...arguments),r()(this,"onServerChange",(e=>{this.setServer(e.target.value)})),r()(this,"onServerVariableValueChange",(e=>{let{setServerVariableValue:t,currentServer:n}=this.props,s=e.target.getAttribute("data-variable"),r=e.target.value;"function"==typeof t&&t({server:n,key:s,val:r})})),r()(this,"setServer",(e=>{let{setSelectedServer:t}=this.props;t(e)}))}componentDidMount(){var e;let{servers:t,currentServer:n}=this.props;n||this.setServer(null===(e=t.first())||void 0===e?void 0:e.get("url"))}UNSAFE_componentWillReceiveProps(e){let{servers:t,setServerVariableValue:n,getServerVariable:s}=e;if(this.props.currentServer!==e.currentServer||this.props.servers!==e.servers){var r;let a=o()(t).call(t,(t=>t.get("url")===e.currentServer)),l=o()(r=this.props.servers).call(r,(e=>e.get("url")===this.props.currentServer))||(0,p.OrderedMap)();if(!a)return this.setServer(t.first().get("url"));let i=l.get("variables")||(0,p.OrderedMap)(),u=(o()(i).call(i,(e=>e.get("default")))||(0,p.OrderedMap)()).get("default"),m=a.get("variables")||(0,p.OrderedMap)(),d=(o()(m).call(m,(e=>e.get("default")))||(0,p.OrderedMap)()).get("default");c()(m).call(m,((t,r)=>{s(e.currentServer,r)&&u===d||n({server:e.currentServer,key:r,val:t.get("default")||""})}))}}render(){var e,t;let{servers:n,currentServer:s,getServerVariable:r,getEffectiveServerValue:a}=this.props,l=(o()(n).call(n,(e=>e.get("url")===s))||(0,p.OrderedMap)()).get("variables")||(0,p.OrderedMap)(),i=0!==l.size;return u().createElement("div",{className:"servers"},u().createElement("label",{htmlFor:"servers"},u().createElement("select",{onChange:this.onServerChange,value:s},c()(e=n.valueSeq()).call(e,(e=>u().createElement("option",{value:e.get("url"),key:e.get("url")},e.get("url"),e.get("description")&&` - ${e.get("description")}`))).toArray())),i?u().createElement("div",null,u().createElement("div",{className:"computed-url"},"Computed URL:",u().createElement("code",null,a(s))),u().createElement("h4",null,"Server variables"),u().createElement("table",null,u().createElement("tbody",null,c()(t=l.entrySeq()).call(t,(e=>{var t;let[n,a]=e;return u().createElement("tr",{key:n},u().createElement("td",null,n),u().createElement("td",null,a.get("enum")?u().createElement("select",{"data-variable":n,onChange:this.onServerVariableValueChange},c()(t=a.get("enum")).call(t,(e=>u().createElement("option",{selected:e===r(s,n),key:e,value:e},e)))):u().createElement("input",{type:"text",value:r(s,n)||"",onChange:this.onServerVariableValueChange,"data-variable":n})))}))))):null)}}},7779:(e,t,n)=>{"use strict";n.r(t),n.d(t,{OAS30ComponentWrapFactory:()=>u,OAS3ComponentWrapFactory:()=>i,isOAS30:()=>l,isSwagger2:()=>c});var s=n(4250),r=n.n(s),a=n(6689),o=n.n(a);function l(e){const t=e.get("openapi");return"string"==typeof t&&/^3\.0\.([0123])(?:-rc[012])?$/.test(t)}function c(e){const t=e.get("swagger");return"string"==typeof t&&"2.0"===t}function i(e){return(t,n)=>s=>{var a;return"function"==typeof(null===(a=n.specSelectors)||void 0===a?void 0:a.isOAS3)?n.specSelectors.isOAS3()?o().createElement(e,r()({},s,n,{Ori:t})):o().createElement(t,s):(console.warn("OAS3 wrapper: couldn't get spec"),null)}}function u(e){return(t,n)=>s=>{var a;return"function"==typeof(null===(a=n.specSelectors)||void 0===a?void 0:a.isOAS30)?n.specSelectors.isOAS30()?o().createElement(e,r()({},s,n,{Ori:t})):o().createElement(t,s):(console.warn("OAS30 wrapper: couldn't get spec"),null)}}},7451:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>p});var s=n(2044),r=n(3723),a=n(1741),o=n(6467),l=n(7761),c=n(7002),i=n(5065),u=n(2109);function p(){return{components:o.default,wrapComponents:l.default,statePlugins:{spec:{wrapSelectors:s,selectors:a},auth:{wrapSelectors:r},oas3:{actions:c,reducers:u.default,selectors:i}}}}},2109:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>p});var s=n(874),r=n.n(s),a=n(4235),o=n.n(a),l=n(66),c=n.n(l),i=n(5572),u=n(7002);const p={[u.UPDATE_SELECTED_SERVER]:(e,t)=>{let{payload:{selectedServerUrl:n,namespace:s}}=t;const r=s?[s,"selectedServer"]:["selectedServer"];return e.setIn(r,n)},[u.UPDATE_REQUEST_BODY_VALUE]:(e,t)=>{let{payload:{value:n,pathMethod:s}}=t,[a,l]=s;if(!i.Map.isMap(n))return e.setIn(["requestData",a,l,"bodyValue"],n);let c,u=e.getIn(["requestData",a,l,"bodyValue"])||(0,i.Map)();i.Map.isMap(u)||(u=(0,i.Map)());const[...p]=r()(n).call(n);return o()(p).call(p,(e=>{let t=n.getIn([e]);u.has(e)&&i.Map.isMap(t)||(c=u.setIn([e,"value"],t))})),e.setIn(["requestData",a,l,"bodyValue"],c)},[u.UPDATE_REQUEST_BODY_VALUE_RETAIN_FLAG]:(e,t)=>{let{payload:{value:n,pathMethod:s}}=t,[r,a]=s;return e.setIn(["requestData",r,a,"retainBodyValue"],n)},[u.UPDATE_REQUEST_BODY_INCLUSION]:(e,t)=>{let{payload:{value:n,pathMethod:s,name:r}}=t,[a,o]=s;return e.setIn(["requestData",a,o,"bodyInclusion",r],n)},[u.UPDATE_ACTIVE_EXAMPLES_MEMBER]:(e,t)=>{let{payload:{name:n,pathMethod:s,contextType:r,contextName:a}}=t,[o,l]=s;return e.setIn(["examples",o,l,r,a,"activeExample"],n)},[u.UPDATE_REQUEST_CONTENT_TYPE]:(e,t)=>{let{payload:{value:n,pathMethod:s}}=t,[r,a]=s;return e.setIn(["requestData",r,a,"requestContentType"],n)},[u.UPDATE_RESPONSE_CONTENT_TYPE]:(e,t)=>{let{payload:{value:n,path:s,method:r}}=t;return e.setIn(["requestData",s,r,"responseContentType"],n)},[u.UPDATE_SERVER_VARIABLE_VALUE]:(e,t)=>{let{payload:{server:n,namespace:s,key:r,val:a}}=t;const o=s?[s,"serverVariableValues",n,r]:["serverVariableValues",n,r];return e.setIn(o,a)},[u.SET_REQUEST_BODY_VALIDATE_ERROR]:(e,t)=>{let{payload:{path:n,method:s,validationErrors:r}}=t,a=[];if(a.push("Required field is not provided"),r.missingBodyValue)return e.setIn(["requestData",n,s,"errors"],(0,i.fromJS)(a));if(r.missingRequiredKeys&&r.missingRequiredKeys.length>0){const{missingRequiredKeys:t}=r;return e.updateIn(["requestData",n,s,"bodyValue"],(0,i.fromJS)({}),(e=>c()(t).call(t,((e,t)=>e.setIn([t,"errors"],(0,i.fromJS)(a))),e)))}return console.warn("unexpected result: SET_REQUEST_BODY_VALIDATE_ERROR"),e},[u.CLEAR_REQUEST_BODY_VALIDATE_ERROR]:(e,t)=>{let{payload:{path:n,method:s}}=t;const a=e.getIn(["requestData",n,s,"bodyValue"]);if(!i.Map.isMap(a))return e.setIn(["requestData",n,s,"errors"],(0,i.fromJS)([]));const[...o]=r()(a).call(a);return o?e.updateIn(["requestData",n,s,"bodyValue"],(0,i.fromJS)({}),(e=>c()(o).call(o,((e,t)=>e.setIn([t,"errors"],(0,i.fromJS)([]))),e))):e},[u.CLEAR_REQUEST_BODY_VALUE]:(e,t)=>{let{payload:{pathMethod:n}}=t,[s,r]=n;const a=e.getIn(["requestData",s,r,"bodyValue"]);return a?i.Map.isMap(a)?e.setIn(["requestData",s,r,"bodyValue"],(0,i.Map)()):e.setIn(["requestData",s,r,"bodyValue"],""):e}}},5065:(e,t,n)=>{"use strict";n.r(t),n.d(t,{activeExamplesMember:()=>C,hasUserEditedBody:()=>E,requestBodyErrors:()=>x,requestBodyInclusionSetting:()=>w,requestBodyValue:()=>y,requestContentType:()=>j,responseContentType:()=>b,selectDefaultRequestBodyValue:()=>S,selectedServer:()=>f,serverEffectiveValue:()=>O,serverVariableValue:()=>_,serverVariables:()=>N,shouldRetainRequestBodyValue:()=>v,validOperationMethods:()=>P,validateBeforeExecute:()=>k,validateShallowRequired:()=>I});var s=n(3942),r=n.n(s),a=n(4235),o=n.n(a),l=n(7252),c=n.n(l),i=n(8493),u=n.n(i),p=n(5572),m=n(6814),d=n(2458),h=n(1669);const g=e=>function(t){for(var n=arguments.length,s=new Array(n>1?n-1:0),r=1;r<n;r++)s[r-1]=arguments[r];return n=>{if(n.getSystem().specSelectors.isOAS3()){const r=e(t,...s);return"function"==typeof r?r(n):r}return null}};const f=g(((e,t)=>{const n=t?[t,"selectedServer"]:["selectedServer"];return e.getIn(n)||""})),y=g(((e,t,n)=>e.getIn(["requestData",t,n,"bodyValue"])||null)),v=g(((e,t,n)=>e.getIn(["requestData",t,n,"retainBodyValue"])||!1)),S=(e,t,n)=>e=>{const{oas3Selectors:s,specSelectors:r,fn:a}=e.getSystem();if(r.isOAS3()){const e=s.requestContentType(t,n);if(e)return(0,d.getDefaultRequestBodyValue)(r.specResolvedSubtree(["paths",t,n,"requestBody"]),e,s.activeExamplesMember(t,n,"requestBody","requestBody"),a)}return null},E=g(((e,t,n)=>e=>{const{oas3Selectors:s,specSelectors:r,fn:a}=e;let o=!1;const l=s.requestContentType(t,n);let c=s.requestBodyValue(t,n);const i=r.specResolvedSubtree(["paths",t,n,"requestBody"]);if(!i)return!1;if(p.Map.isMap(c)&&(c=(0,h.Pz)(c.mapEntries((e=>p.Map.isMap(e[1])?[e[0],e[1].get("value")]:e)).toJS())),p.List.isList(c)&&(c=(0,h.Pz)(c)),l){const e=(0,d.getDefaultRequestBodyValue)(i,l,s.activeExamplesMember(t,n,"requestBody","requestBody"),a);o=!!c&&c!==e}return o})),w=g(((e,t,n)=>e.getIn(["requestData",t,n,"bodyInclusion"])||(0,p.Map)())),x=g(((e,t,n)=>e.getIn(["requestData",t,n,"errors"])||null)),C=g(((e,t,n,s,r)=>e.getIn(["examples",t,n,s,r,"activeExample"])||null)),j=g(((e,t,n)=>e.getIn(["requestData",t,n,"requestContentType"])||null)),b=g(((e,t,n)=>e.getIn(["requestData",t,n,"responseContentType"])||null)),_=g(((e,t,n)=>{let s;if("string"!=typeof t){const{server:e,namespace:r}=t;s=r?[r,"serverVariableValues",e,n]:["serverVariableValues",e,n]}else{s=["serverVariableValues",t,n]}return e.getIn(s)||null})),N=g(((e,t)=>{let n;if("string"!=typeof t){const{server:e,namespace:s}=t;n=s?[s,"serverVariableValues",e]:["serverVariableValues",e]}else{n=["serverVariableValues",t]}return e.getIn(n)||(0,p.OrderedMap)()})),O=g(((e,t)=>{var n,s;if("string"!=typeof t){const{server:r,namespace:a}=t;s=r,n=a?e.getIn([a,"serverVariableValues",s]):e.getIn(["serverVariableValues",s])}else s=t,n=e.getIn(["serverVariableValues",s]);n=n||(0,p.OrderedMap)();let a=s;return r()(n).call(n,((e,t)=>{a=a.replace(new RegExp(`{${t}}`,"g"),e)})),a})),k=(A=(e,t)=>((e,t)=>(t=t||[],!!e.getIn(["requestData",...t,"bodyValue"])))(e,t),function(){for(var e=arguments.length,t=new Array(e),n=0;n<e;n++)t[n]=arguments[n];return e=>{const n=e.getSystem().specSelectors.specJson();let s=[...t][1]||[];return!n.getIn(["paths",...s,"requestBody","required"])||A(...t)}});var A;const I=(e,t)=>{var n;let{oas3RequiredRequestBodyContentType:s,oas3RequestContentType:r,oas3RequestBodyValue:a}=t,l=[];if(!p.Map.isMap(a))return l;let i=[];return o()(n=c()(s.requestContentType)).call(n,(e=>{if(e===r){let t=s.requestContentType[e];o()(t).call(t,(e=>{u()(i).call(i,e)<0&&i.push(e)}))}})),o()(i).call(i,(e=>{a.getIn([e,"value"])||l.push(e)})),l},P=(0,m.createSelector)((()=>["get","put","post","delete","options","head","patch","trace"]))},1741:(e,t,n)=>{"use strict";n.r(t),n.d(t,{callbacksOperations:()=>w,isOAS3:()=>v,isOAS30:()=>y,isSwagger2:()=>f,servers:()=>E});var s=n(3942),r=n.n(s),a=n(66),o=n.n(a),l=n(9998),c=n.n(l),i=n(2605),u=n.n(i),p=n(4883),m=n.n(p),d=n(5572),h=n(7779);const g=(0,d.Map)(),f=()=>e=>{const t=e.getSystem().specSelectors.specJson();return(0,h.isSwagger2)(t)},y=()=>e=>{const t=e.getSystem().specSelectors.specJson();return(0,h.isOAS30)(t)},v=()=>e=>e.getSystem().specSelectors.isOAS30();function S(e){return function(t){for(var n=arguments.length,s=new Array(n>1?n-1:0),r=1;r<n;r++)s[r-1]=arguments[r];return n=>{if(n.specSelectors.isOAS3()){const r=e(t,...s);return"function"==typeof r?r(n):r}return null}}}const E=S((()=>e=>e.specSelectors.specJson().get("servers",g))),w=S(((e,t)=>{let{callbacks:n,specPath:s}=t;return e=>{var t;const a=e.specSelectors.validOperationMethods();return d.Map.isMap(n)?r()(t=o()(n).call(n,((e,t,n)=>d.Map.isMap(t)?o()(t).call(t,((e,t,o)=>{var l,i;if(!d.Map.isMap(t))return e;const p=r()(l=c()(i=t.entrySeq()).call(i,(e=>{let[t]=e;return u()(a).call(a,t)}))).call(l,(e=>{let[t,r]=e;return{operation:(0,d.Map)({operation:r}),method:t,path:o,callbackName:n,specPath:m()(s).call(s,[n,o,t])}}));return m()(e).call(e,p)}),(0,d.List)()):e),(0,d.List)()).groupBy((e=>e.callbackName))).call(t,(e=>e.toArray())).toObject():{}}}))},2044:(e,t,n)=>{"use strict";n.r(t),n.d(t,{basePath:()=>h,consumes:()=>g,definitions:()=>i,hasHost:()=>u,host:()=>d,produces:()=>f,schemes:()=>y,securityDefinitions:()=>p,validOperationMethods:()=>m});var s=n(6814),r=n(3881),a=n(5572);const o=(0,a.Map)();function l(e){return(t,n)=>function(){if(n.getSystem().specSelectors.isOAS3()){const t=e(...arguments);return"function"==typeof t?t(n):t}return t(...arguments)}}const c=l((0,s.createSelector)((()=>null))),i=l((()=>e=>{const t=e.getSystem().specSelectors.specJson().getIn(["components","schemas"]);return a.Map.isMap(t)?t:o})),u=l((()=>e=>e.getSystem().specSelectors.specJson().hasIn(["servers",0]))),p=l((0,s.createSelector)(r.specJsonWithResolvedSubtrees,(e=>e.getIn(["components","securitySchemes"])||null))),m=(e,t)=>function(n){if(t.specSelectors.isOAS3())return t.oas3Selectors.validOperationMethods();for(var s=arguments.length,r=new Array(s>1?s-1:0),a=1;a<s;a++)r[a-1]=arguments[a];return e(...r)},d=c,h=c,g=c,f=c,y=c},356:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);const a=(0,n(7779).OAS3ComponentWrapFactory)((e=>{let{Ori:t,...n}=e;const{schema:s,getComponent:a,errSelectors:o,authorized:l,onAuthChange:c,name:i}=n,u=a("HttpAuth");return"http"===s.get("type")?r().createElement(u,{key:i,schema:s,name:i,errSelectors:o,authorized:l,getComponent:a,onChange:c}):r().createElement(t,n)}))},7761:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>i});var s=n(2460),r=n(356),a=n(9487),o=n(58),l=n(3499),c=n(287);const i={Markdown:s.default,AuthItem:r.default,JsonSchema_string:c.default,VersionStamp:a.default,model:l.default,onlineValidatorBadge:o.default}},287:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);const a=(0,n(7779).OAS3ComponentWrapFactory)((e=>{let{Ori:t,...n}=e;const{schema:s,getComponent:a,errors:o,onChange:l}=n,c=s&&s.get?s.get("format"):null,i=s&&s.get?s.get("type"):null,u=a("Input");return i&&"string"===i&&c&&("binary"===c||"base64"===c)?r().createElement(u,{type:"file",className:o.length?"invalid":"",title:o.length?o:"",onChange:e=>{l(e.target.files[0])},disabled:t.isDisabled}):r().createElement(t,n)}))},2460:(e,t,n)=>{"use strict";n.r(t),n.d(t,{Markdown:()=>d,default:()=>h});var s=n(7390),r=n.n(s),a=n(6689),o=n.n(a),l=(n(580),n(9003)),c=n.n(l),i=n(963),u=n(7779),p=n(2552);const m=new i.Remarkable("commonmark");m.block.ruler.enable(["table"]),m.set({linkTarget:"_blank"});const d=e=>{let{source:t,className:n="",getConfigs:s}=e;if("string"!=typeof t)return null;if(t){const{useUnsafeMarkdown:e}=s(),a=m.render(t),l=(0,p.s)(a,{useUnsafeMarkdown:e});let i;return"string"==typeof l&&(i=r()(l).call(l)),o().createElement("div",{dangerouslySetInnerHTML:{__html:i},className:c()(n,"renderedMarkdown")})}return null};d.defaultProps={getConfigs:()=>({useUnsafeMarkdown:!1})};const h=(0,u.OAS3ComponentWrapFactory)(d)},3499:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>u});var s=n(4250),r=n.n(s),a=n(6689),o=n.n(a),l=(n(580),n(7779)),c=n(6024);class i extends a.Component{render(){let{getConfigs:e,schema:t}=this.props,n=["model-box"],s=null;return!0===t.get("deprecated")&&(n.push("deprecated"),s=o().createElement("span",{className:"model-deprecated-warning"},"Deprecated:")),o().createElement("div",{className:n.join(" ")},s,o().createElement(c.Z,r()({},this.props,{getConfigs:e,depth:1,expandDepth:this.props.expandDepth||0})))}}const u=(0,l.OAS3ComponentWrapFactory)(i)},58:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(7779),r=n(5623);const a=(0,s.OAS3ComponentWrapFactory)(r.Z)},9487:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);const a=(0,n(7779).OAS30ComponentWrapFactory)((e=>{const{Ori:t}=e;return r().createElement("span",null,r().createElement(t,e),r().createElement("small",{className:"version-stamp"},r().createElement("pre",{className:"version"},"OAS 3.0")))}))},2372:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>l});var s=n(4994),r=n.n(s),a=n(5800),o=n(4380);const l=function(e){let{fn:t,getSystem:n}=e;if(t.jsonSchema202012){const e=(0,a.makeIsExpandable)(t.jsonSchema202012.isExpandable,n);r()(this.fn.jsonSchema202012,{isExpandable:e,getProperties:a.getProperties})}if("function"==typeof t.sampleFromSchema&&t.jsonSchema202012){const e=(0,o.wrapOAS31Fn)({sampleFromSchema:t.jsonSchema202012.sampleFromSchema,sampleFromSchemaGeneric:t.jsonSchema202012.sampleFromSchemaGeneric,createXMLExample:t.jsonSchema202012.createXMLExample,memoizedSampleFromSchema:t.jsonSchema202012.memoizedSampleFromSchema,memoizedCreateXMLExample:t.jsonSchema202012.memoizedCreateXMLExample},n());r()(this.fn,e)}}},9503:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(580),n(1669));const o=e=>{let{getComponent:t,specSelectors:n}=e;const s=n.selectContactNameField(),o=n.selectContactUrl(),l=n.selectContactEmailField(),c=t("Link");return r().createElement("div",{className:"info__contact"},o&&r().createElement("div",null,r().createElement(c,{href:(0,a.Nm)(o),target:"_blank"},s," - Website")),l&&r().createElement(c,{href:(0,a.Nm)(`mailto:${l}`)},o?`Send email to ${s}`:`Contact ${s}`))}},6133:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(580),n(1669));const o=e=>{let{getComponent:t,specSelectors:n}=e;const s=n.version(),o=n.url(),l=n.basePath(),c=n.host(),i=n.selectInfoSummaryField(),u=n.selectInfoDescriptionField(),p=n.selectInfoTitleField(),m=n.selectInfoTermsOfServiceUrl(),d=n.selectExternalDocsUrl(),h=n.selectExternalDocsDescriptionField(),g=n.contact(),f=n.license(),y=t("Markdown",!0),v=t("Link"),S=t("VersionStamp"),E=t("InfoUrl"),w=t("InfoBasePath"),x=t("License",!0),C=t("Contact",!0),j=t("JsonSchemaDialect",!0);return r().createElement("div",{className:"info"},r().createElement("hgroup",{className:"main"},r().createElement("h2",{className:"title"},p,s&&r().createElement(S,{version:s})),(c||l)&&r().createElement(w,{host:c,basePath:l}),o&&r().createElement(E,{getComponent:t,url:o})),i&&r().createElement("p",{className:"info__summary"},i),r().createElement("div",{className:"info__description description"},r().createElement(y,{source:u})),m&&r().createElement("div",{className:"info__tos"},r().createElement(v,{target:"_blank",href:(0,a.Nm)(m)},"Terms of service")),g.size>0&&r().createElement(C,null),f.size>0&&r().createElement(x,null),d&&r().createElement(v,{className:"info__extdocs",target:"_blank",href:(0,a.Nm)(d)},h||d),r().createElement(j,null))}},2562:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(580),n(1669));const o=e=>{let{getComponent:t,specSelectors:n}=e;const s=n.selectJsonSchemaDialectField(),o=n.selectJsonSchemaDialectDefault(),l=t("Link");return r().createElement(r().Fragment,null,s&&s===o&&r().createElement("p",{className:"info__jsonschemadialect"},"JSON Schema dialect:"," ",r().createElement(l,{target:"_blank",href:(0,a.Nm)(s)},s)),s&&s!==o&&r().createElement("div",{className:"error-wrapper"},r().createElement("div",{className:"no-margin"},r().createElement("div",{className:"errors"},r().createElement("div",{className:"errors-wrapper"},r().createElement("h4",{className:"center"},"Warning"),r().createElement("p",{className:"message"},r().createElement("strong",null,"OpenAPI.jsonSchemaDialect")," field contains a value different from the default value of"," ",r().createElement(l,{target:"_blank",href:o},o),". Values different from the default one are currently not supported. Please either omit the field or provide it with the default value."))))))}},1876:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s),a=(n(580),n(1669));const o=e=>{let{getComponent:t,specSelectors:n}=e;const s=n.selectLicenseNameField(),o=n.selectLicenseUrl(),l=t("Link");return r().createElement("div",{className:"info__license"},o?r().createElement("div",{className:"info__license__url"},r().createElement(l,{target:"_blank",href:(0,a.Nm)(o)},s)):r().createElement("span",null,s))}},2718:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>i});var s=n(2605),r=n.n(s),a=n(6689),o=n.n(a);n(580),n(8082);const l=e=>"string"==typeof e&&r()(e).call(e,"#/components/schemas/")?(e=>{const t=e.replace(/~1/g,"/").replace(/~0/g,"~");try{return decodeURIComponent(t)}catch{return t}})(e.replace(/^.*#\/components\/schemas\//,"")):null,c=(0,a.forwardRef)(((e,t)=>{let{schema:n,getComponent:s,onToggle:r}=e;const c=s("JSONSchema202012"),i=l(n.get("$$ref")),u=(0,a.useCallback)(((e,t)=>{r(i,t)}),[i,r]);return o().createElement(c,{name:i,schema:n.toJS(),ref:t,onExpand:u})}));c.defaultProps={name:"",displayName:"",isRef:!1,required:!1,expandDepth:0,depth:1,includeReadOnly:!1,includeWriteOnly:!1,onToggle:()=>{}};const i=c},263:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>d});var s=n(7252),r=n.n(s),a=n(3942),o=n.n(a),l=n(1815),c=n.n(l),i=n(6689),u=n.n(i),p=(n(580),n(9003)),m=n.n(p);const d=e=>{var t;let{specActions:n,specSelectors:s,layoutSelectors:a,layoutActions:l,getComponent:p,getConfigs:d}=e;const h=s.selectSchemas(),g=r()(h).length>0,f=["components","schemas"],{docExpansion:y,defaultModelsExpandDepth:v}=d(),S=v>0&&"none"!==y,E=a.isShown(f,S),w=p("Collapse"),x=p("JSONSchema202012"),C=p("ArrowUpIcon"),j=p("ArrowDownIcon");(0,i.useEffect)((()=>{const e=E&&v>1,t=null!=s.specResolvedSubtree(f);e&&!t&&n.requestResolvedSubtree(f)}),[E,v]);const b=(0,i.useCallback)((()=>{l.show(f,!E)}),[E]),_=(0,i.useCallback)((e=>{null!==e&&l.readyToScroll(f,e)}),[]),N=e=>t=>{null!==t&&l.readyToScroll([...f,e],t)},O=e=>(t,r)=>{if(r){const t=[...f,e];null!=s.specResolvedSubtree(t)||n.requestResolvedSubtree([...f,e])}};return!g||v<0?null:u().createElement("section",{className:m()("models",{"is-open":E}),ref:_},u().createElement("h4",null,u().createElement("button",{"aria-expanded":E,className:"models-control",onClick:b},u().createElement("span",null,"Schemas"),E?u().createElement(C,null):u().createElement(j,null))),u().createElement(w,{isOpened:E},o()(t=c()(h)).call(t,(e=>{let[t,n]=e;return u().createElement(x,{key:t,ref:N(t),schema:n,name:t,onExpand:O(t)})}))))}},3429:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(580);const a=e=>{let{bypass:t,isSwagger2:n,isOAS3:s,isOAS31:a,alsoShow:o,children:l}=e;return t?r().createElement("div",null,l):n&&(s||a)?r().createElement("div",{className:"version-pragma"},o,r().createElement("div",{className:"version-pragma__message version-pragma__message--ambiguous"},r().createElement("div",null,r().createElement("h3",null,"Unable to render this definition"),r().createElement("p",null,r().createElement("code",null,"swagger")," and ",r().createElement("code",null,"openapi")," fields cannot be present in the same Swagger or OpenAPI definition. Please remove one of the fields."),r().createElement("p",null,"Supported version fields are ",r().createElement("code",null,'swagger: "2.0"')," and those that match ",r().createElement("code",null,"openapi: 3.x.y")," (for example,"," ",r().createElement("code",null,"openapi: 3.1.0"),").")))):n||s||a?r().createElement("div",null,l):r().createElement("div",{className:"version-pragma"},o,r().createElement("div",{className:"version-pragma__message version-pragma__message--missing"},r().createElement("div",null,r().createElement("h3",null,"Unable to render this definition"),r().createElement("p",null,"The provided definition does not specify a valid version field."),r().createElement("p",null,"Please indicate a valid Swagger or OpenAPI version field. Supported version fields are ",r().createElement("code",null,'swagger: "2.0"')," and those that match ",r().createElement("code",null,"openapi: 3.x.y")," (for example,"," ",r().createElement("code",null,"openapi: 3.1.0"),")."))))}},9508:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>i});var s=n(7252),r=n.n(s),a=n(3942),o=n.n(a),l=n(6689),c=n.n(l);n(580);const i=e=>{let{specSelectors:t,getComponent:n}=e;const s=t.selectWebhooksOperations(),a=r()(s),l=n("OperationContainer",!0);return 0===a.length?null:c().createElement("div",{className:"webhooks"},c().createElement("h2",null,"Webhooks"),o()(a).call(a,(e=>{var t;return c().createElement("div",{key:`${e}-webhook`},o()(t=s[e]).call(t,(t=>c().createElement(l,{key:`${e}-${t.method}-webhook`,op:t.operation,tag:"webhooks",method:t.method,path:e,specPath:t.specPath,allowTryItOut:!1}))))})))}},4380:(e,t,n)=>{"use strict";n.r(t),n.d(t,{createOnlyOAS31ComponentWrapper:()=>y,createOnlyOAS31Selector:()=>h,createOnlyOAS31SelectorWrapper:()=>g,createSystemSelector:()=>f,isOAS31:()=>d,wrapOAS31Fn:()=>v});var s=n(4250),r=n.n(s),a=n(3015),o=n.n(a),l=n(3942),c=n.n(l),i=n(1815),u=n.n(i),p=n(6689),m=n.n(p);const d=e=>{const t=e.get("openapi");return"string"==typeof t&&/^3\.1\.(?:[1-9]\d*|0)$/.test(t)},h=e=>function(t){for(var n=arguments.length,s=new Array(n>1?n-1:0),r=1;r<n;r++)s[r-1]=arguments[r];return n=>{if(n.getSystem().specSelectors.isOAS31()){const r=e(t,...s);return"function"==typeof r?r(n):r}return null}},g=e=>(t,n)=>function(s){for(var r=arguments.length,a=new Array(r>1?r-1:0),o=1;o<r;o++)a[o-1]=arguments[o];if(n.getSystem().specSelectors.isOAS31()){const r=e(s,...a);return"function"==typeof r?r(t,n):r}return t(...a)},f=e=>function(t){for(var n=arguments.length,s=new Array(n>1?n-1:0),r=1;r<n;r++)s[r-1]=arguments[r];return n=>{const r=e(t,n,...s);return"function"==typeof r?r(n):r}},y=e=>(t,n)=>s=>n.specSelectors.isOAS31()?m().createElement(e,r()({},s,{originalComponent:t,getSystem:n.getSystem})):m().createElement(t,s),v=(e,t)=>{var n;const{fn:s,specSelectors:r}=t;return o()(c()(n=u()(e)).call(n,(e=>{let[t,n]=e;const a=s[t];return[t,function(){return r.isOAS31()?n(...arguments):"function"==typeof a?a(...arguments):void 0}]})))}},9806:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>A});var s=n(9508),r=n(1876),a=n(9503),o=n(6133),l=n(2562),c=n(3429),i=n(2718),u=n(263),p=n(6608),m=n(7423),d=n(284),h=n(7042),g=n(2914),f=n(1434),y=n(1122),v=n(4380),S=n(9305),E=n(2884),w=n(4280),x=n(9450),C=n(3995),j=n(9525),b=n(5324),_=n(809),N=n(4951),O=n(7536),k=n(2372);const A=e=>{let{fn:t}=e;const n=t.createSystemSelector||v.createSystemSelector,A=t.createOnlyOAS31Selector||v.createOnlyOAS31Selector;return{afterLoad:k.default,fn:{isOAS31:v.isOAS31,createSystemSelector:v.createSystemSelector,createOnlyOAS31Selector:v.createOnlyOAS31Selector},components:{Webhooks:s.default,JsonSchemaDialect:l.default,OAS31Info:o.default,OAS31License:r.default,OAS31Contact:a.default,OAS31VersionPragmaFilter:c.default,OAS31Model:i.default,OAS31Models:u.default,JSONSchema202012KeywordExample:x.default,JSONSchema202012KeywordXml:C.default,JSONSchema202012KeywordDiscriminator:j.default,JSONSchema202012KeywordExternalDocs:b.default},wrapComponents:{InfoContainer:d.default,License:p.default,Contact:m.default,VersionPragmaFilter:f.default,VersionStamp:y.default,Model:h.default,Models:g.default,JSONSchema202012KeywordDescription:_.default,JSONSchema202012KeywordDefault:N.default,JSONSchema202012KeywordProperties:O.default},statePlugins:{spec:{selectors:{isOAS31:n(S.isOAS31),license:S.license,selectLicenseNameField:S.selectLicenseNameField,selectLicenseUrlField:S.selectLicenseUrlField,selectLicenseIdentifierField:A(S.selectLicenseIdentifierField),selectLicenseUrl:n(S.selectLicenseUrl),contact:S.contact,selectContactNameField:S.selectContactNameField,selectContactEmailField:S.selectContactEmailField,selectContactUrlField:S.selectContactUrlField,selectContactUrl:n(S.selectContactUrl),selectInfoTitleField:S.selectInfoTitleField,selectInfoSummaryField:A(S.selectInfoSummaryField),selectInfoDescriptionField:S.selectInfoDescriptionField,selectInfoTermsOfServiceField:S.selectInfoTermsOfServiceField,selectInfoTermsOfServiceUrl:n(S.selectInfoTermsOfServiceUrl),selectExternalDocsDescriptionField:S.selectExternalDocsDescriptionField,selectExternalDocsUrlField:S.selectExternalDocsUrlField,selectExternalDocsUrl:n(S.selectExternalDocsUrl),webhooks:A(S.webhooks),selectWebhooksOperations:A(n(S.selectWebhooksOperations)),selectJsonSchemaDialectField:S.selectJsonSchemaDialectField,selectJsonSchemaDialectDefault:S.selectJsonSchemaDialectDefault,selectSchemas:n(S.selectSchemas)},wrapSelectors:{isOAS3:E.isOAS3,selectLicenseUrl:E.selectLicenseUrl}},oas31:{selectors:{selectLicenseUrl:A(n(w.selectLicenseUrl))}}}}}},5989:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(580);const a=e=>{let{schema:t,getSystem:n}=e;if(null==t||!t.description)return null;const{getComponent:s}=n(),a=s("Markdown");return r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--description"},r().createElement("div",{className:"json-schema-2020-12-core-keyword__value json-schema-2020-12-core-keyword__value--secondary"},r().createElement(a,{source:t.description})))}},9525:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>u});var s=n(7252),r=n.n(s),a=n(6689),o=n.n(a),l=(n(580),n(9003)),c=n.n(l),i=n(7749);const u=e=>{let{schema:t,getSystem:n}=e;const s=(null==t?void 0:t.discriminator)||{},{fn:l,getComponent:u}=n(),{useIsExpandedDeeply:p,useComponent:m}=l.jsonSchema202012,d=p(),h=!!s.mapping,[g,f]=(0,a.useState)(d),[y,v]=(0,a.useState)(!1),S=m("Accordion"),E=m("ExpandDeepButton"),w=u("JSONSchema202012DeepExpansionContext")(),x=(0,a.useCallback)((()=>{f((e=>!e))}),[]),C=(0,a.useCallback)(((e,t)=>{f(t),v(t)}),[]);return 0===r()(s).length?null:o().createElement(w.Provider,{value:y},o().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--discriminator"},h?o().createElement(o().Fragment,null,o().createElement(S,{expanded:g,onChange:x},o().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"Discriminator")),o().createElement(E,{expanded:g,onClick:C})):o().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"Discriminator"),s.propertyName&&o().createElement("span",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--muted"},s.propertyName),o().createElement("strong",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},"object"),o().createElement("ul",{className:c()("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!g})},g&&o().createElement("li",{className:"json-schema-2020-12-property"},o().createElement(i.default,{discriminator:s})))))}},7749:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>m});var s=n(7252),r=n.n(s),a=n(3942),o=n.n(a),l=n(1815),c=n.n(l),i=n(6689),u=n.n(i);n(580);const p=e=>{var t;let{discriminator:n}=e;const s=(null==n?void 0:n.mapping)||{};return 0===r()(s).length?null:o()(t=c()(s)).call(t,(e=>{let[t,n]=e;return u().createElement("div",{key:`${t}-${n}`,className:"json-schema-2020-12-keyword"},u().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},t),u().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},n))}))};p.defaultProps={mapping:void 0};const m=p},9450:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(580);const a=e=>{let{schema:t,getSystem:n}=e;const{fn:s}=n(),{hasKeyword:a,stringify:o}=s.jsonSchema202012.useFn();return a(t,"example")?r().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--example"},r().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"Example"),r().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--const"},o(t.example))):null}},5324:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>u});var s=n(7252),r=n.n(s),a=n(6689),o=n.n(a),l=(n(580),n(9003)),c=n.n(l),i=n(1669);const u=e=>{let{schema:t,getSystem:n}=e;const s=(null==t?void 0:t.externalDocs)||{},{fn:l,getComponent:u}=n(),{useIsExpandedDeeply:p,useComponent:m}=l.jsonSchema202012,d=p(),h=!(!s.description&&!s.url),[g,f]=(0,a.useState)(d),[y,v]=(0,a.useState)(!1),S=m("Accordion"),E=m("ExpandDeepButton"),w=u("JSONSchema202012KeywordDescription"),x=u("Link"),C=u("JSONSchema202012DeepExpansionContext")(),j=(0,a.useCallback)((()=>{f((e=>!e))}),[]),b=(0,a.useCallback)(((e,t)=>{f(t),v(t)}),[]);return 0===r()(s).length?null:o().createElement(C.Provider,{value:y},o().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--externalDocs"},h?o().createElement(o().Fragment,null,o().createElement(S,{expanded:g,onChange:j},o().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"External documentation")),o().createElement(E,{expanded:g,onClick:b})):o().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"External documentation"),o().createElement("strong",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},"object"),o().createElement("ul",{className:c()("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!g})},g&&o().createElement(o().Fragment,null,s.description&&o().createElement("li",{className:"json-schema-2020-12-property"},o().createElement(w,{schema:s,getSystem:n})),s.url&&o().createElement("li",{className:"json-schema-2020-12-property"},o().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword"},o().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"url"),o().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},o().createElement(x,{target:"_blank",href:(0,i.Nm)(s.url)},s.url))))))))}},9023:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>y});var s=n(7104),r=n.n(s),a=n(7252),o=n.n(a),l=n(3942),c=n.n(l),i=n(1815),u=n.n(i),p=n(2605),m=n.n(p),d=n(6689),h=n.n(d),g=(n(580),n(9003)),f=n.n(g);const y=e=>{var t;let{schema:n,getSystem:s}=e;const{fn:a}=s(),{useComponent:l}=a.jsonSchema202012,{getDependentRequired:i,getProperties:p}=a.jsonSchema202012.useFn(),d=a.jsonSchema202012.useConfig(),g=r()(null==n?void 0:n.required)?n.required:[],y=l("JSONSchema"),v=p(n,d);return 0===o()(v).length?null:h().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--properties"},h().createElement("ul",null,c()(t=u()(v)).call(t,(e=>{let[t,s]=e;const r=m()(g).call(g,t),a=i(t,n);return h().createElement("li",{key:t,className:f()("json-schema-2020-12-property",{"json-schema-2020-12-property--required":r})},h().createElement(y,{name:t,schema:s,dependentRequired:a}))}))))}},3995:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>i});var s=n(7252),r=n.n(s),a=n(6689),o=n.n(a),l=(n(580),n(9003)),c=n.n(l);const i=e=>{let{schema:t,getSystem:n}=e;const s=(null==t?void 0:t.xml)||{},{fn:l,getComponent:i}=n(),{useIsExpandedDeeply:u,useComponent:p}=l.jsonSchema202012,m=u(),d=!!(s.name||s.namespace||s.prefix),[h,g]=(0,a.useState)(m),[f,y]=(0,a.useState)(!1),v=p("Accordion"),S=p("ExpandDeepButton"),E=i("JSONSchema202012DeepExpansionContext")(),w=(0,a.useCallback)((()=>{g((e=>!e))}),[]),x=(0,a.useCallback)(((e,t)=>{g(t),y(t)}),[]);return 0===r()(s).length?null:o().createElement(E.Provider,{value:f},o().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword--xml"},d?o().createElement(o().Fragment,null,o().createElement(v,{expanded:h,onChange:w},o().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"XML")),o().createElement(S,{expanded:h,onClick:x})):o().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"XML"),!0===s.attribute&&o().createElement("span",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--muted"},"attribute"),!0===s.wrapped&&o().createElement("span",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--muted"},"wrapped"),o().createElement("strong",{className:"json-schema-2020-12__attribute json-schema-2020-12__attribute--primary"},"object"),o().createElement("ul",{className:c()("json-schema-2020-12-keyword__children",{"json-schema-2020-12-keyword__children--collapsed":!h})},h&&o().createElement(o().Fragment,null,s.name&&o().createElement("li",{className:"json-schema-2020-12-property"},o().createElement("div",{className:"json-schema-2020-12-keyword json-schema-2020-12-keyword"},o().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"name"),o().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},s.name))),s.namespace&&o().createElement("li",{className:"json-schema-2020-12-property"},o().createElement("div",{className:"json-schema-2020-12-keyword"},o().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"namespace"),o().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},s.namespace))),s.prefix&&o().createElement("li",{className:"json-schema-2020-12-property"},o().createElement("div",{className:"json-schema-2020-12-keyword"},o().createElement("span",{className:"json-schema-2020-12-keyword__name json-schema-2020-12-keyword__name--secondary"},"prefix"),o().createElement("span",{className:"json-schema-2020-12-keyword__value json-schema-2020-12-keyword__value--secondary"},s.prefix)))))))}},5800:(e,t,n)=>{"use strict";n.r(t),n.d(t,{getProperties:()=>u,makeIsExpandable:()=>i});var s=n(1815),r=n.n(s),a=n(9998),o=n.n(a),l=n(3015),c=n.n(l);const i=(e,t)=>{const{fn:n}=t();if("function"!=typeof e)return null;const{hasKeyword:s}=n.jsonSchema202012;return t=>e(t)||s(t,"example")||(null==t?void 0:t.xml)||(null==t?void 0:t.discriminator)||(null==t?void 0:t.externalDocs)},u=(e,t)=>{let{includeReadOnly:n,includeWriteOnly:s}=t;if(null==e||!e.properties)return{};const a=r()(e.properties),l=o()(a).call(a,(e=>{let[,t]=e;const r=!0===(null==t?void 0:t.readOnly),a=!0===(null==t?void 0:t.writeOnly);return(!r||n)&&(!a||s)}));return c()(l)}},4951:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);const a=(0,n(4380).createOnlyOAS31ComponentWrapper)((e=>{let{schema:t,getSystem:n,originalComponent:s}=e;const{getComponent:a}=n(),o=a("JSONSchema202012KeywordDiscriminator"),l=a("JSONSchema202012KeywordXml"),c=a("JSONSchema202012KeywordExample"),i=a("JSONSchema202012KeywordExternalDocs");return r().createElement(r().Fragment,null,r().createElement(s,{schema:t}),r().createElement(o,{schema:t,getSystem:n}),r().createElement(l,{schema:t,getSystem:n}),r().createElement(i,{schema:t,getSystem:n}),r().createElement(c,{schema:t,getSystem:n}))}))},809:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>r});var s=n(5989);const r=(0,n(4380).createOnlyOAS31ComponentWrapper)(s.default)},7536:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>r});var s=n(9023);const r=(0,n(4380).createOnlyOAS31ComponentWrapper)(s.default)},4280:(e,t,n)=>{"use strict";n.r(t),n.d(t,{selectLicenseUrl:()=>a});var s=n(6814),r=n(3543);const a=(0,s.createSelector)(((e,t)=>t.specSelectors.url()),((e,t)=>t.oas3Selectors.selectedServer()),((e,t)=>t.specSelectors.selectLicenseUrlField()),((e,t)=>t.specSelectors.selectLicenseIdentifierField()),((e,t,n,s)=>n?(0,r.mn)(n,e,{selectedServer:t}):s?`https://spdx.org/licenses/${s}.html`:void 0))},9305:(e,t,n)=>{"use strict";n.r(t),n.d(t,{contact:()=>O,isOAS31:()=>E,license:()=>C,selectContactEmailField:()=>A,selectContactNameField:()=>k,selectContactUrl:()=>P,selectContactUrlField:()=>I,selectExternalDocsDescriptionField:()=>J,selectExternalDocsUrl:()=>L,selectExternalDocsUrlField:()=>$,selectInfoDescriptionField:()=>T,selectInfoSummaryField:()=>R,selectInfoTermsOfServiceField:()=>M,selectInfoTermsOfServiceUrl:()=>D,selectInfoTitleField:()=>q,selectJsonSchemaDialectDefault:()=>V,selectJsonSchemaDialectField:()=>K,selectLicenseIdentifierField:()=>N,selectLicenseNameField:()=>j,selectLicenseUrl:()=>_,selectLicenseUrlField:()=>b,selectSchemas:()=>U,selectWebhooksOperations:()=>x,webhooks:()=>w});var s=n(3942),r=n.n(s),a=n(66),o=n.n(a),l=n(9998),c=n.n(l),i=n(2605),u=n.n(i),p=n(4883),m=n.n(p),d=n(1815),h=n.n(d),g=n(5572),f=n(6814),y=n(3543),v=n(4380);const S=(0,g.Map)(),E=(0,f.createSelector)(((e,t)=>t.specSelectors.specJson()),v.isOAS31),w=()=>e=>e.specSelectors.specJson().get("webhooks",S),x=(0,f.createSelector)(((e,t)=>t.specSelectors.webhooks()),((e,t)=>t.specSelectors.validOperationMethods()),((e,t)=>t.specSelectors.specResolvedSubtree(["webhooks"])),((e,t)=>{var n;return g.Map.isMap(e)?r()(n=o()(e).call(e,((e,n,s)=>{var a,o;if(!g.Map.isMap(n))return e;const l=r()(a=c()(o=n.entrySeq()).call(o,(e=>{let[n]=e;return u()(t).call(t,n)}))).call(a,(e=>{let[t,n]=e;return{operation:(0,g.Map)({operation:n}),method:t,path:s,specPath:(0,g.List)(["webhooks",s,t])}}));return m()(e).call(e,l)}),(0,g.List)()).groupBy((e=>e.path))).call(n,(e=>e.toArray())).toObject():{}})),C=()=>e=>e.specSelectors.info().get("license",S),j=()=>e=>e.specSelectors.license().get("name","License"),b=()=>e=>e.specSelectors.license().get("url"),_=(0,f.createSelector)(((e,t)=>t.specSelectors.url()),((e,t)=>t.oas3Selectors.selectedServer()),((e,t)=>t.specSelectors.selectLicenseUrlField()),((e,t,n)=>{if(n)return(0,y.mn)(n,e,{selectedServer:t})})),N=()=>e=>e.specSelectors.license().get("identifier"),O=()=>e=>e.specSelectors.info().get("contact",S),k=()=>e=>e.specSelectors.contact().get("name","the developer"),A=()=>e=>e.specSelectors.contact().get("email"),I=()=>e=>e.specSelectors.contact().get("url"),P=(0,f.createSelector)(((e,t)=>t.specSelectors.url()),((e,t)=>t.oas3Selectors.selectedServer()),((e,t)=>t.specSelectors.selectContactUrlField()),((e,t,n)=>{if(n)return(0,y.mn)(n,e,{selectedServer:t})})),q=()=>e=>e.specSelectors.info().get("title"),R=()=>e=>e.specSelectors.info().get("summary"),T=()=>e=>e.specSelectors.info().get("description"),M=()=>e=>e.specSelectors.info().get("termsOfService"),D=(0,f.createSelector)(((e,t)=>t.specSelectors.url()),((e,t)=>t.oas3Selectors.selectedServer()),((e,t)=>t.specSelectors.selectInfoTermsOfServiceField()),((e,t,n)=>{if(n)return(0,y.mn)(n,e,{selectedServer:t})})),J=()=>e=>e.specSelectors.externalDocs().get("description"),$=()=>e=>e.specSelectors.externalDocs().get("url"),L=(0,f.createSelector)(((e,t)=>t.specSelectors.url()),((e,t)=>t.oas3Selectors.selectedServer()),((e,t)=>t.specSelectors.selectExternalDocsUrlField()),((e,t,n)=>{if(n)return(0,y.mn)(n,e,{selectedServer:t})})),K=()=>e=>e.specSelectors.specJson().get("jsonSchemaDialect"),V=()=>"https://spec.openapis.org/oas/3.1/dialect/base",U=(0,f.createSelector)(((e,t)=>t.specSelectors.definitions()),((e,t)=>t.specSelectors.specResolvedSubtree(["components","schemas"])),((e,t)=>{var n;return g.Map.isMap(e)?g.Map.isMap(t)?o()(n=h()(e.toJS())).call(n,((e,n)=>{let[s,r]=n;const a=t.get(s);return e[s]=(null==a?void 0:a.toJS())||r,e}),{}):e.toJS():{}}))},2884:(e,t,n)=>{"use strict";n.r(t),n.d(t,{isOAS3:()=>r,selectLicenseUrl:()=>a});var s=n(4380);const r=(e,t)=>function(n){const s=t.specSelectors.isOAS31();for(var r=arguments.length,a=new Array(r>1?r-1:0),o=1;o<r;o++)a[o-1]=arguments[o];return s||e(...a)},a=(0,s.createOnlyOAS31SelectorWrapper)((()=>(e,t)=>t.oas31Selectors.selectLicenseUrl()))},7423:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);const a=(0,n(4380).createOnlyOAS31ComponentWrapper)((e=>{let{getSystem:t}=e;const n=t().getComponent("OAS31Contact",!0);return r().createElement(n,null)}))},284:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);const a=(0,n(4380).createOnlyOAS31ComponentWrapper)((e=>{let{getSystem:t}=e;const n=t().getComponent("OAS31Info",!0);return r().createElement(n,null)}))},6608:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);const a=(0,n(4380).createOnlyOAS31ComponentWrapper)((e=>{let{getSystem:t}=e;const n=t().getComponent("OAS31License",!0);return r().createElement(n,null)}))},7042:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>l});var s=n(6689),r=n.n(s),a=n(4380),o=n(5800);const l=(0,a.createOnlyOAS31ComponentWrapper)((e=>{let{getSystem:t,...n}=e;const s=t(),{getComponent:a,fn:l,getConfigs:c}=s,i=c(),u=a("OAS31Model"),p=a("JSONSchema202012"),m=a("JSONSchema202012Keyword$schema"),d=a("JSONSchema202012Keyword$vocabulary"),h=a("JSONSchema202012Keyword$id"),g=a("JSONSchema202012Keyword$anchor"),f=a("JSONSchema202012Keyword$dynamicAnchor"),y=a("JSONSchema202012Keyword$ref"),v=a("JSONSchema202012Keyword$dynamicRef"),S=a("JSONSchema202012Keyword$defs"),E=a("JSONSchema202012Keyword$comment"),w=a("JSONSchema202012KeywordAllOf"),x=a("JSONSchema202012KeywordAnyOf"),C=a("JSONSchema202012KeywordOneOf"),j=a("JSONSchema202012KeywordNot"),b=a("JSONSchema202012KeywordIf"),_=a("JSONSchema202012KeywordThen"),N=a("JSONSchema202012KeywordElse"),O=a("JSONSchema202012KeywordDependentSchemas"),k=a("JSONSchema202012KeywordPrefixItems"),A=a("JSONSchema202012KeywordItems"),I=a("JSONSchema202012KeywordContains"),P=a("JSONSchema202012KeywordProperties"),q=a("JSONSchema202012KeywordPatternProperties"),R=a("JSONSchema202012KeywordAdditionalProperties"),T=a("JSONSchema202012KeywordPropertyNames"),M=a("JSONSchema202012KeywordUnevaluatedItems"),D=a("JSONSchema202012KeywordUnevaluatedProperties"),J=a("JSONSchema202012KeywordType"),$=a("JSONSchema202012KeywordEnum"),L=a("JSONSchema202012KeywordConst"),K=a("JSONSchema202012KeywordConstraint"),V=a("JSONSchema202012KeywordDependentRequired"),U=a("JSONSchema202012KeywordContentSchema"),F=a("JSONSchema202012KeywordTitle"),z=a("JSONSchema202012KeywordDescription"),B=a("JSONSchema202012KeywordDefault"),W=a("JSONSchema202012KeywordDeprecated"),H=a("JSONSchema202012KeywordReadOnly"),Z=a("JSONSchema202012KeywordWriteOnly"),G=a("JSONSchema202012Accordion"),X=a("JSONSchema202012ExpandDeepButton"),Y=a("JSONSchema202012ChevronRightIcon"),Q=a("withJSONSchema202012Context")(u,{config:{default$schema:"https://spec.openapis.org/oas/3.1/dialect/base",defaultExpandedLevels:i.defaultModelExpandDepth,includeReadOnly:Boolean(n.includeReadOnly),includeWriteOnly:Boolean(n.includeWriteOnly)},components:{JSONSchema:p,Keyword$schema:m,Keyword$vocabulary:d,Keyword$id:h,Keyword$anchor:g,Keyword$dynamicAnchor:f,Keyword$ref:y,Keyword$dynamicRef:v,Keyword$defs:S,Keyword$comment:E,KeywordAllOf:w,KeywordAnyOf:x,KeywordOneOf:C,KeywordNot:j,KeywordIf:b,KeywordThen:_,KeywordElse:N,KeywordDependentSchemas:O,KeywordPrefixItems:k,KeywordItems:A,KeywordContains:I,KeywordProperties:P,KeywordPatternProperties:q,KeywordAdditionalProperties:R,KeywordPropertyNames:T,KeywordUnevaluatedItems:M,KeywordUnevaluatedProperties:D,KeywordType:J,KeywordEnum:$,KeywordConst:L,KeywordConstraint:K,KeywordDependentRequired:V,KeywordContentSchema:U,KeywordTitle:F,KeywordDescription:z,KeywordDefault:B,KeywordDeprecated:W,KeywordReadOnly:H,KeywordWriteOnly:Z,Accordion:G,ExpandDeepButton:X,ChevronRightIcon:Y},fn:{upperFirst:l.upperFirst,isExpandable:(0,o.makeIsExpandable)(l.jsonSchema202012.isExpandable,t),getProperties:o.getProperties}});return r().createElement(Q,n)}))},2914:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(6689),r=n.n(s);const a=(0,n(4380).createOnlyOAS31ComponentWrapper)((e=>{let{getSystem:t}=e;const{getComponent:n,fn:s,getConfigs:o}=t(),l=o();if(a.ModelsWithJSONSchemaContext)return r().createElement(a.ModelsWithJSONSchemaContext,null);const c=n("OAS31Models",!0),i=n("JSONSchema202012"),u=n("JSONSchema202012Keyword$schema"),p=n("JSONSchema202012Keyword$vocabulary"),m=n("JSONSchema202012Keyword$id"),d=n("JSONSchema202012Keyword$anchor"),h=n("JSONSchema202012Keyword$dynamicAnchor"),g=n("JSONSchema202012Keyword$ref"),f=n("JSONSchema202012Keyword$dynamicRef"),y=n("JSONSchema202012Keyword$defs"),v=n("JSONSchema202012Keyword$comment"),S=n("JSONSchema202012KeywordAllOf"),E=n("JSONSchema202012KeywordAnyOf"),w=n("JSONSchema202012KeywordOneOf"),x=n("JSONSchema202012KeywordNot"),C=n("JSONSchema202012KeywordIf"),j=n("JSONSchema202012KeywordThen"),b=n("JSONSchema202012KeywordElse"),_=n("JSONSchema202012KeywordDependentSchemas"),N=n("JSONSchema202012KeywordPrefixItems"),O=n("JSONSchema202012KeywordItems"),k=n("JSONSchema202012KeywordContains"),A=n("JSONSchema202012KeywordProperties"),I=n("JSONSchema202012KeywordPatternProperties"),P=n("JSONSchema202012KeywordAdditionalProperties"),q=n("JSONSchema202012KeywordPropertyNames"),R=n("JSONSchema202012KeywordUnevaluatedItems"),T=n("JSONSchema202012KeywordUnevaluatedProperties"),M=n("JSONSchema202012KeywordType"),D=n("JSONSchema202012KeywordEnum"),J=n("JSONSchema202012KeywordConst"),$=n("JSONSchema202012KeywordConstraint"),L=n("JSONSchema202012KeywordDependentRequired"),K=n("JSONSchema202012KeywordContentSchema"),V=n("JSONSchema202012KeywordTitle"),U=n("JSONSchema202012KeywordDescription"),F=n("JSONSchema202012KeywordDefault"),z=n("JSONSchema202012KeywordDeprecated"),B=n("JSONSchema202012KeywordReadOnly"),W=n("JSONSchema202012KeywordWriteOnly"),H=n("JSONSchema202012Accordion"),Z=n("JSONSchema202012ExpandDeepButton"),G=n("JSONSchema202012ChevronRightIcon"),X=n("withJSONSchema202012Context");return a.ModelsWithJSONSchemaContext=X(c,{config:{default$schema:"https://spec.openapis.org/oas/3.1/dialect/base",defaultExpandedLevels:l.defaultModelsExpandDepth-1,includeReadOnly:!0,includeWriteOnly:!0},components:{JSONSchema:i,Keyword$schema:u,Keyword$vocabulary:p,Keyword$id:m,Keyword$anchor:d,Keyword$dynamicAnchor:h,Keyword$ref:g,Keyword$dynamicRef:f,Keyword$defs:y,Keyword$comment:v,KeywordAllOf:S,KeywordAnyOf:E,KeywordOneOf:w,KeywordNot:x,KeywordIf:C,KeywordThen:j,KeywordElse:b,KeywordDependentSchemas:_,KeywordPrefixItems:N,KeywordItems:O,KeywordContains:k,KeywordProperties:A,KeywordPatternProperties:I,KeywordAdditionalProperties:P,KeywordPropertyNames:q,KeywordUnevaluatedItems:R,KeywordUnevaluatedProperties:T,KeywordType:M,KeywordEnum:D,KeywordConst:J,KeywordConstraint:$,KeywordDependentRequired:L,KeywordContentSchema:K,KeywordTitle:V,KeywordDescription:U,KeywordDefault:F,KeywordDeprecated:z,KeywordReadOnly:B,KeywordWriteOnly:W,Accordion:H,ExpandDeepButton:Z,ChevronRightIcon:G},fn:{upperFirst:s.upperFirst,isExpandable:s.jsonSchema202012.isExpandable,getProperties:s.jsonSchema202012.getProperties}}),r().createElement(a.ModelsWithJSONSchemaContext,null)}));a.ModelsWithJSONSchemaContext=null;const o=a},1434:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>l});var s=n(4250),r=n.n(s),a=n(6689),o=n.n(a);const l=(e,t)=>e=>{const n=t.specSelectors.isOAS31(),s=t.getComponent("OAS31VersionPragmaFilter");return o().createElement(s,r()({isOAS31:n},e))}},1122:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);const a=(0,n(4380).createOnlyOAS31ComponentWrapper)((e=>{let{originalComponent:t,...n}=e;return r().createElement("span",null,r().createElement(t,n),r().createElement("small",{className:"version-stamp"},r().createElement("pre",{className:"version"},"OAS 3.1")))}))},8560:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(9300),r=n.n(s);let a=!1;function o(){return{statePlugins:{spec:{wrapActions:{updateSpec:e=>function(){return a=!0,e(...arguments)},updateJsonSpec:(e,t)=>function(){const n=t.getConfigs().onComplete;return a&&"function"==typeof n&&(r()(n,0),a=!1),e(...arguments)}}}}}}},8223:(e,t,n)=>{"use strict";n.r(t),n.d(t,{requestSnippetGenerator_curl_bash:()=>b,requestSnippetGenerator_curl_cmd:()=>_,requestSnippetGenerator_curl_powershell:()=>j});var s=n(8493),r=n.n(s),a=n(7390),o=n.n(a),l=n(8344),c=n.n(l),i=n(3942),u=n.n(i);const p=require("@babel/runtime-corejs3/core-js-stable/instance/repeat");var m=n.n(p),d=n(7862),h=n.n(d),g=n(2605),f=n.n(g),y=n(7504),v=n(5572);const S=e=>{var t;const n="_**[]";return r()(e).call(e,n)<0?e:o()(t=e.split(n)[0]).call(t)},E=e=>"-d "===e||/^[_\/-]/g.test(e)?e:"'"+e.replace(/'/g,"'\\''")+"'",w=e=>"-d "===(e=e.replace(/\^/g,"^^").replace(/\\"/g,'\\\\"').replace(/"/g,'""').replace(/\n/g,"^\n"))?e.replace(/-d /g,"-d ^\n"):/^[_\/-]/g.test(e)?e:'"'+e+'"',x=e=>"-d "===e?e:/\n/.test(e)?'@"\n'+e.replace(/"/g,'\\"').replace(/`/g,"``").replace(/\$/,"`$")+'\n"@':/^[_\/-]/g.test(e)?e:"'"+e.replace(/"/g,'""').replace(/'/g,"''")+"'";const C=function(e,t,n){let s=arguments.length>3&&void 0!==arguments[3]?arguments[3]:"",r=!1,a="";const o=function(){for(var e=arguments.length,n=new Array(e),s=0;s<e;s++)n[s]=arguments[s];return a+=" "+u()(n).call(n,t).join(" ")},l=function(){for(var e=arguments.length,n=new Array(e),s=0;s<e;s++)n[s]=arguments[s];return a+=u()(n).call(n,t).join(" ")},i=()=>a+=` ${n}`,p=function(){let e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:1;return a+=m()("  ").call("  ",e)};let d=e.get("headers");if(a+="curl"+s,e.has("curlOptions")&&o(...e.get("curlOptions")),o("-X",e.get("method")),i(),p(),l(`${e.get("url")}`),d&&d.size)for(let t of h()(g=e.get("headers")).call(g)){var g;i(),p();let[e,n]=t;l("-H",`${e}: ${n}`),r=r||/^content-type$/i.test(e)&&/^multipart\/form-data$/i.test(n)}const E=e.get("body");var w;if(E)if(r&&f()(w=["POST","PUT","PATCH"]).call(w,e.get("method")))for(let[e,t]of E.entrySeq()){let n=S(e);i(),p(),l("-F"),t instanceof y.Z.File&&"string"==typeof t.valueOf()?o(`${n}=${t.data}${t.type?`;type=${t.type}`:""}`):t instanceof y.Z.File?o(`${n}=@${t.name}${t.type?`;type=${t.type}`:""}`):o(`${n}=${t}`)}else if(E instanceof y.Z.File)i(),p(),l(`--data-binary '@${E.name}'`);else{i(),p(),l("-d ");let t=E;v.Map.isMap(t)?l(function(e){let t=[];for(let[n,s]of e.get("body").entrySeq()){let e=S(n);s instanceof y.Z.File?t.push(`  "${e}": {\n    "name": "${s.name}"${s.type?`,\n    "type": "${s.type}"`:""}\n  }`):t.push(`  "${e}": ${c()(s,null,2).replace(/(\r\n|\r|\n)/g,"\n  ")}`)}return`{\n${t.join(",\n")}\n}`}(e)):("string"!=typeof t&&(t=c()(t)),l(t))}else E||"POST"!==e.get("method")||(i(),p(),l("-d ''"));return a},j=e=>C(e,x,"`\n",".exe"),b=e=>C(e,E,"\\\n"),_=e=>C(e,w,"^\n")},6575:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>o});var s=n(8223),r=n(4669),a=n(4206);const o=()=>({components:{RequestSnippets:a.default},fn:s,statePlugins:{requestSnippets:{selectors:r}}})},4206:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>w});var s=n(9998),r=n.n(s),a=n(1733),o=n.n(a),l=n(4235),c=n.n(l),i=n(3942),u=n.n(i),p=n(6689),m=n.n(p),d=(n(580),n(1712)),h=n.n(d),g=n(5716),f=n.n(g),y=n(2807),v=n(6068);const S={cursor:"pointer",lineHeight:1,display:"inline-flex",backgroundColor:"rgb(250, 250, 250)",paddingBottom:"0",paddingTop:"0",border:"1px solid rgb(51, 51, 51)",borderRadius:"4px 4px 0 0",boxShadow:"none",borderBottom:"none"},E={cursor:"pointer",lineHeight:1,display:"inline-flex",backgroundColor:"rgb(51, 51, 51)",boxShadow:"none",border:"1px solid rgb(51, 51, 51)",paddingBottom:"0",paddingTop:"0",borderRadius:"4px 4px 0 0",marginTop:"-5px",marginRight:"-5px",marginLeft:"-5px",zIndex:"9999",borderBottom:"none"},w=e=>{var t,n;let{request:s,requestSnippetsSelectors:a,getConfigs:l,getComponent:i}=e;const d=f()(l)?l():null,g=!1!==h()(d,"syntaxHighlight")&&h()(d,"syntaxHighlight.activated",!0),w=(0,p.useRef)(null),x=i("ArrowUpIcon"),C=i("ArrowDownIcon"),[j,b]=(0,p.useState)(null===(t=a.getSnippetGenerators())||void 0===t?void 0:t.keySeq().first()),[_,N]=(0,p.useState)(null==a?void 0:a.getDefaultExpanded());(0,p.useEffect)((()=>{}),[]),(0,p.useEffect)((()=>{var e;const t=r()(e=o()(w.current.childNodes)).call(e,(e=>{var t;return!!e.nodeType&&(null===(t=e.classList)||void 0===t?void 0:t.contains("curl-command"))}));return c()(t).call(t,(e=>e.addEventListener("mousewheel",q,{passive:!1}))),()=>{c()(t).call(t,(e=>e.removeEventListener("mousewheel",q)))}}),[s]);const O=a.getSnippetGenerators(),k=O.get(j),A=k.get("fn")(s),I=()=>{N(!_)},P=e=>e===j?E:S,q=e=>{const{target:t,deltaY:n}=e,{scrollHeight:s,offsetHeight:r,scrollTop:a}=t;s>r&&(0===a&&n<0||r+a>=s&&n>0)&&e.preventDefault()},R=g?m().createElement(v.d3,{language:k.get("syntax"),className:"curl microlight",style:(0,v.C2)(h()(d,"syntaxHighlight.theme"))},A):m().createElement("textarea",{readOnly:!0,className:"curl",value:A});return m().createElement("div",{className:"request-snippets",ref:w},m().createElement("div",{style:{width:"100%",display:"flex",justifyContent:"flex-start",alignItems:"center",marginBottom:"15px"}},m().createElement("h4",{onClick:()=>I(),style:{cursor:"pointer"}},"Snippets"),m().createElement("button",{onClick:()=>I(),style:{border:"none",background:"none"},title:_?"Collapse operation":"Expand operation"},_?m().createElement(C,{className:"arrow",width:"10",height:"10"}):m().createElement(x,{className:"arrow",width:"10",height:"10"}))),_&&m().createElement("div",{className:"curl-command"},m().createElement("div",{style:{paddingLeft:"15px",paddingRight:"10px",width:"100%",display:"flex"}},u()(n=O.entrySeq()).call(n,(e=>{let[t,n]=e;return m().createElement("div",{style:P(t),className:"btn",key:t,onClick:()=>(e=>{j!==e&&b(e)})(t)},m().createElement("h4",{style:t===j?{color:"white"}:{}},n.get("title")))}))),m().createElement("div",{className:"copy-to-clipboard"},m().createElement(y.CopyToClipboard,{text:A},m().createElement("button",null))),m().createElement("div",null,R)))}},4669:(e,t,n)=>{"use strict";n.r(t),n.d(t,{getActiveLanguage:()=>h,getDefaultExpanded:()=>g,getGenerators:()=>m,getSnippetGenerators:()=>d});var s=n(9998),r=n.n(s),a=n(2605),o=n.n(a),l=n(3942),c=n.n(l),i=n(6814),u=n(5572);const p=e=>e||(0,u.Map)(),m=(0,i.createSelector)(p,(e=>{const t=e.get("languages"),n=e.get("generators",(0,u.Map)());return!t||t.isEmpty()?n:r()(n).call(n,((e,n)=>o()(t).call(t,n)))})),d=e=>t=>{var n,s;let{fn:a}=t;return r()(n=c()(s=m(e)).call(s,((e,t)=>{const n=(e=>a[`requestSnippetGenerator_${e}`])(t);return"function"!=typeof n?null:e.set("fn",n)}))).call(n,(e=>e))},h=(0,i.createSelector)(p,(e=>e.get("activeLanguage"))),g=(0,i.createSelector)(p,(e=>e.get("defaultExpanded")))},6195:(e,t,n)=>{"use strict";n.r(t),n.d(t,{ErrorBoundary:()=>l,default:()=>c});n(580);var s=n(6689),r=n.n(s),a=n(6189),o=n(9403);class l extends s.Component{static getDerivedStateFromError(e){return{hasError:!0,error:e}}constructor(){super(...arguments),this.state={hasError:!1,error:null}}componentDidCatch(e,t){this.props.fn.componentDidCatch(e,t)}render(){const{getComponent:e,targetName:t,children:n}=this.props;if(this.state.hasError){const n=e("Fallback");return r().createElement(n,{name:t})}return n}}l.defaultProps={targetName:"this component",getComponent:()=>o.default,fn:{componentDidCatch:a.componentDidCatch},children:null};const c=l},9403:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>a});var s=n(6689),r=n.n(s);n(580);const a=e=>{let{name:t}=e;return r().createElement("div",{className:"fallback"},"😱 ",r().createElement("i",null,"Could not render ","t"===t?"this component":t,", see the console."))}},6189:(e,t,n)=>{"use strict";n.r(t),n.d(t,{componentDidCatch:()=>l,withErrorBoundary:()=>c});var s=n(4250),r=n.n(s),a=n(6689),o=n.n(a);const l=console.error,c=e=>t=>{const{getComponent:n,fn:s}=e(),l=n("ErrorBoundary"),c=s.getDisplayName(t);class i extends a.Component{render(){return o().createElement(l,{targetName:c,getComponent:n,fn:s},o().createElement(t,r()({},this.props,this.context)))}}var u;return i.displayName=`WithErrorBoundary(${c})`,(u=t).prototype&&u.prototype.isReactComponent&&(i.prototype.mapStateToProps=t.prototype.mapStateToProps),i}},9595:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>u});const s=require("@babel/runtime-corejs3/core-js-stable/instance/fill");var r=n.n(s);const a=require("lodash/zipObject");var o=n.n(a),l=n(6195),c=n(9403),i=n(6189);const u=function(){let{componentList:e=[],fullOverride:t=!1}=arguments.length>0&&void 0!==arguments[0]?arguments[0]:{};return n=>{var s;let{getSystem:a}=n;const u=t?e:["App","BaseLayout","VersionPragmaFilter","InfoContainer","ServersContainer","SchemesContainer","AuthorizeBtnContainer","FilterContainer","Operations","OperationContainer","parameters","responses","OperationServers","Models","ModelWrapper",...e],p=o()(u,r()(s=Array(u.length)).call(s,((e,t)=>{let{fn:n}=t;return n.withErrorBoundary(e)})));return{fn:{componentDidCatch:i.componentDidCatch,withErrorBoundary:(0,i.withErrorBoundary)(a)},components:{ErrorBoundary:l.default,Fallback:c.default},wrapComponents:p}}}},2846:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>p});var s=n(66),r=n.n(s),a=n(8344),o=n.n(a),l=n(4129),c=n.n(l);const i=[{when:/json/,shouldStringifyTypes:["string"]}],u=["object"],p=e=>(t,n,s,a)=>{const{fn:l}=e(),p=l.memoizedSampleFromSchema(t,n,a),m=typeof p,d=r()(i).call(i,((e,t)=>t.when.test(s)?[...e,...t.shouldStringifyTypes]:e),u);return c()(d,(e=>e===m))?o()(p,null,2):p}},6132:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=e=>function(t){var n,s;let r=arguments.length>1&&void 0!==arguments[1]?arguments[1]:"",a=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{},o=arguments.length>3&&void 0!==arguments[3]?arguments[3]:void 0;const{fn:l}=e();return"function"==typeof(null===(n=t)||void 0===n?void 0:n.toJS)&&(t=t.toJS()),"function"==typeof(null===(s=o)||void 0===s?void 0:s.toJS)&&(o=o.toJS()),/xml/.test(r)?l.getXmlSampleSchema(t,a,o):/(yaml|yml)/.test(r)?l.getYamlSampleSchema(t,a,r,o):l.getJsonSampleSchema(t,a,r,o)}},1169:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>s});const s=e=>(t,n,s)=>{const{fn:r}=e();if(t&&!t.xml&&(t.xml={}),t&&!t.xml.name){if(!t.$$ref&&(t.type||t.items||t.properties||t.additionalProperties))return'<?xml version="1.0" encoding="UTF-8"?>\n\x3c!-- XML example cannot be generated; root element name is undefined --\x3e';if(t.$$ref){let e=t.$$ref.match(/\S*\/(\S+)$/);t.xml.name=e[1]}}return r.memoizedCreateXMLExample(t,n,s)}},9431:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>l});var s=n(600),r=n.n(s),a=n(9793),o=n.n(a);const l=e=>(t,n,s,l)=>{const{fn:c}=e(),i=c.getJsonSampleSchema(t,n,s,l);let u;try{u=o().dump(o().load(i),{lineWidth:-1},{schema:a.JSON_SCHEMA}),"\n"===u[u.length-1]&&(u=r()(u).call(u,0,u.length-1))}catch(e){return console.error(e),"error: could not generate yaml example"}return u.replace(/\t/g,"  ")}},9812:(e,t,n)=>{"use strict";n.r(t),n.d(t,{createXMLExample:()=>K,inferSchema:()=>L,memoizedCreateXMLExample:()=>F,memoizedSampleFromSchema:()=>z,sampleFromSchema:()=>V,sampleFromSchemaGeneric:()=>$});var s=n(8493),r=n.n(s),a=n(4235),o=n.n(a),l=n(7104),c=n.n(l),i=n(2605),u=n.n(i),p=n(5626),m=n.n(p),d=n(600),h=n.n(d),g=n(3580),f=n.n(g),y=n(4883),v=n.n(y),S=n(3942),E=n.n(S),w=n(8344),x=n.n(w),C=n(8920),j=n.n(C),b=n(9989),_=n.n(b),N=n(9699),O=n.n(N),k=n(1669),A=n(7481);const I={string:e=>e.pattern?(e=>{try{return new(_())(e).gen()}catch(e){return"string"}})(e.pattern):"string",string_email:()=>"user@example.com","string_date-time":()=>(new Date).toISOString(),string_date:()=>(new Date).toISOString().substring(0,10),string_uuid:()=>"3fa85f64-5717-4562-b3fc-2c963f66afa6",string_hostname:()=>"example.com",string_ipv4:()=>"198.51.100.42",string_ipv6:()=>"2001:0db8:5b96:0000:0000:426f:8e17:642a",number:()=>0,number_float:()=>0,integer:()=>0,boolean:e=>"boolean"!=typeof e.default||e.default},P=e=>{e=(0,k.mz)(e);let{type:t,format:n}=e,s=I[`${t}_${n}`]||I[t];return(0,k.Wl)(s)?s(e):"Unknown Type: "+e.type},q=e=>(0,k.XV)(e,"$$ref",(e=>"string"==typeof e&&r()(e).call(e,"#")>-1)),R=["maxProperties","minProperties"],T=["minItems","maxItems"],M=["minimum","maximum","exclusiveMinimum","exclusiveMaximum"],D=["minLength","maxLength"],J=function(e,t){var n;let s=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{};var a;(o()(n=["example","default","enum","xml","type",...R,...T,...M,...D]).call(n,(n=>(n=>{void 0===t[n]&&void 0!==e[n]&&(t[n]=e[n])})(n))),void 0!==e.required&&c()(e.required))&&(void 0!==t.required&&t.required.length||(t.required=[]),o()(a=e.required).call(a,(e=>{var n;u()(n=t.required).call(n,e)||t.required.push(e)})));if(e.properties){t.properties||(t.properties={});let n=(0,k.mz)(e.properties);for(let a in n){var l;if(Object.prototype.hasOwnProperty.call(n,a))if(!n[a]||!n[a].deprecated)if(!n[a]||!n[a].readOnly||s.includeReadOnly)if(!n[a]||!n[a].writeOnly||s.includeWriteOnly)if(!t.properties[a])t.properties[a]=n[a],!e.required&&c()(e.required)&&-1!==r()(l=e.required).call(l,a)&&(t.required?t.required.push(a):t.required=[a])}}return e.items&&(t.items||(t.items={}),t.items=J(e.items,t.items,s)),t},$=function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{},n=arguments.length>2&&void 0!==arguments[2]?arguments[2]:void 0,s=arguments.length>3&&void 0!==arguments[3]&&arguments[3];e&&(0,k.Wl)(e.toJS)&&(e=e.toJS());let a=void 0!==n||e&&void 0!==e.example||e&&void 0!==e.default;const l=!a&&e&&e.oneOf&&e.oneOf.length>0,i=!a&&e&&e.anyOf&&e.anyOf.length>0;if(!a&&(l||i)){const n=(0,k.mz)(l?e.oneOf[0]:e.anyOf[0]);if(J(n,e,t),!e.xml&&n.xml&&(e.xml=n.xml),void 0!==e.example&&void 0!==n.example)a=!0;else if(n.properties){e.properties||(e.properties={});let s=(0,k.mz)(n.properties);for(let a in s){var p;if(Object.prototype.hasOwnProperty.call(s,a))if(!s[a]||!s[a].deprecated)if(!s[a]||!s[a].readOnly||t.includeReadOnly)if(!s[a]||!s[a].writeOnly||t.includeWriteOnly)if(!e.properties[a])e.properties[a]=s[a],!n.required&&c()(n.required)&&-1!==r()(p=n.required).call(p,a)&&(e.required?e.required.push(a):e.required=[a])}}}const d={};let{xml:g,type:y,example:S,properties:w,additionalProperties:x,items:C}=e||{},{includeReadOnly:j,includeWriteOnly:b}=t;g=g||{};let _,{name:N,prefix:A,namespace:I}=g,D={};if(s&&(N=N||"notagname",_=(A?A+":":"")+N,I)){d[A?"xmlns:"+A:"xmlns"]=I}s&&(D[_]=[]);const L=t=>m()(t).call(t,(t=>Object.prototype.hasOwnProperty.call(e,t)));e&&!y&&(w||x||L(R)?y="object":C||L(T)?y="array":L(M)?(y="number",e.type="number"):a||e.enum||(y="string",e.type="string"));const K=t=>{var n,s,r,a,o;null!==(null===(n=e)||void 0===n?void 0:n.maxItems)&&void 0!==(null===(s=e)||void 0===s?void 0:s.maxItems)&&(t=h()(t).call(t,0,null===(o=e)||void 0===o?void 0:o.maxItems));if(null!==(null===(r=e)||void 0===r?void 0:r.minItems)&&void 0!==(null===(a=e)||void 0===a?void 0:a.minItems)){let n=0;for(;t.length<(null===(l=e)||void 0===l?void 0:l.minItems);){var l;t.push(t[n++%t.length])}}return t},V=(0,k.mz)(w);let U,F=0;const z=()=>e&&null!==e.maxProperties&&void 0!==e.maxProperties&&F>=e.maxProperties,B=t=>!e||null===e.maxProperties||void 0===e.maxProperties||!z()&&(!(t=>{var n;return!(e&&e.required&&e.required.length&&u()(n=e.required).call(n,t))})(t)||e.maxProperties-F-(()=>{if(!e||!e.required)return 0;let t=0;var n,r;return s?o()(n=e.required).call(n,(e=>t+=void 0===D[e]?0:1)):o()(r=e.required).call(r,(e=>{var n;return t+=void 0===(null===(n=D[_])||void 0===n?void 0:f()(n).call(n,(t=>void 0!==t[e])))?0:1})),e.required.length-t})()>0);if(U=s?function(n){let r=arguments.length>1&&void 0!==arguments[1]?arguments[1]:void 0;if(e&&V[n]){if(V[n].xml=V[n].xml||{},V[n].xml.attribute){const e=c()(V[n].enum)?V[n].enum[0]:void 0,t=V[n].example,s=V[n].default;return void(d[V[n].xml.name||n]=void 0!==t?t:void 0!==s?s:void 0!==e?e:P(V[n]))}V[n].xml.name=V[n].xml.name||n}else V[n]||!1===x||(V[n]={xml:{name:n}});let a=$(e&&V[n]||void 0,t,r,s);var o;B(n)&&(F++,c()(a)?D[_]=v()(o=D[_]).call(o,a):D[_].push(a))}:(n,r)=>{if(B(n)){if(Object.prototype.hasOwnProperty.call(e,"discriminator")&&e.discriminator&&Object.prototype.hasOwnProperty.call(e.discriminator,"mapping")&&e.discriminator.mapping&&Object.prototype.hasOwnProperty.call(e,"$$ref")&&e.$$ref&&e.discriminator.propertyName===n){for(let t in e.discriminator.mapping)if(-1!==e.$$ref.search(e.discriminator.mapping[t])){D[n]=t;break}}else D[n]=$(V[n],t,r,s);F++}},a){let r;if(r=q(void 0!==n?n:void 0!==S?S:e.default),!s){if("number"==typeof r&&"string"===y)return`${r}`;if("string"!=typeof r||"string"===y)return r;try{return JSON.parse(r)}catch(e){return r}}if(e||(y=c()(r)?"array":typeof r),"array"===y){if(!c()(r)){if("string"==typeof r)return r;r=[r]}const n=e?e.items:void 0;n&&(n.xml=n.xml||g||{},n.xml.name=n.xml.name||g.name);let a=E()(r).call(r,(e=>$(n,t,e,s)));return a=K(a),g.wrapped?(D[_]=a,O()(d)||D[_].push({_attr:d})):D=a,D}if("object"===y){if("string"==typeof r)return r;for(let t in r)Object.prototype.hasOwnProperty.call(r,t)&&(e&&V[t]&&V[t].readOnly&&!j||e&&V[t]&&V[t].writeOnly&&!b||(e&&V[t]&&V[t].xml&&V[t].xml.attribute?d[V[t].xml.name||t]=r[t]:U(t,r[t])));return O()(d)||D[_].push({_attr:d}),D}return D[_]=O()(d)?r:[{_attr:d},r],D}if("object"===y){for(let e in V)Object.prototype.hasOwnProperty.call(V,e)&&(V[e]&&V[e].deprecated||V[e]&&V[e].readOnly&&!j||V[e]&&V[e].writeOnly&&!b||U(e));if(s&&d&&D[_].push({_attr:d}),z())return D;if(!0===x)s?D[_].push({additionalProp:"Anything can be here"}):D.additionalProp1={},F++;else if(x){const n=(0,k.mz)(x),r=$(n,t,void 0,s);if(s&&n.xml&&n.xml.name&&"notagname"!==n.xml.name)D[_].push(r);else{const t=null!==e.minProperties&&void 0!==e.minProperties&&F<e.minProperties?e.minProperties-F:3;for(let e=1;e<=t;e++){if(z())return D;if(s){const t={};t["additionalProp"+e]=r.notagname,D[_].push(t)}else D["additionalProp"+e]=r;F++}}}return D}if("array"===y){if(!C)return;let n;var W,H;if(s)C.xml=C.xml||(null===(W=e)||void 0===W?void 0:W.xml)||{},C.xml.name=C.xml.name||g.name;if(c()(C.anyOf))n=E()(H=C.anyOf).call(H,(e=>$(J(C,e,t),t,void 0,s)));else if(c()(C.oneOf)){var Z;n=E()(Z=C.oneOf).call(Z,(e=>$(J(C,e,t),t,void 0,s)))}else{if(!(!s||s&&g.wrapped))return $(C,t,void 0,s);n=[$(C,t,void 0,s)]}return n=K(n),s&&g.wrapped?(D[_]=n,O()(d)||D[_].push({_attr:d}),D):n}let G;if(e&&c()(e.enum))G=(0,k.AF)(e.enum)[0];else{if(!e)return;if(G=P(e),"number"==typeof G){let t=e.minimum;null!=t&&(e.exclusiveMinimum&&t++,G=t);let n=e.maximum;null!=n&&(e.exclusiveMaximum&&n--,G=n)}if("string"==typeof G&&(null!==e.maxLength&&void 0!==e.maxLength&&(G=h()(G).call(G,0,e.maxLength)),null!==e.minLength&&void 0!==e.minLength)){let t=0;for(;G.length<e.minLength;)G+=G[t++%G.length]}}if("file"!==y)return s?(D[_]=O()(d)?G:[{_attr:d},G],D):G},L=e=>(e.schema&&(e=e.schema),e.properties&&(e.type="object"),e),K=(e,t,n)=>{const s=$(e,t,n,!0);if(s)return"string"==typeof s?s:j()(s,{declaration:!0,indent:"\t"})},V=(e,t,n)=>$(e,t,n,!1),U=(e,t,n)=>[e,x()(t),x()(n)],F=(0,A.Z)(K,U),z=(0,A.Z)(V,U)},8883:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>c});var s=n(9812),r=n(2846),a=n(9431),o=n(1169),l=n(6132);const c=e=>{let{getSystem:t}=e;return{fn:{inferSchema:s.inferSchema,sampleFromSchema:s.sampleFromSchema,sampleFromSchemaGeneric:s.sampleFromSchemaGeneric,createXMLExample:s.createXMLExample,memoizedSampleFromSchema:s.memoizedSampleFromSchema,memoizedCreateXMLExample:s.memoizedCreateXMLExample,getJsonSampleSchema:(0,r.default)(t),getYamlSampleSchema:(0,a.default)(t),getXmlSampleSchema:(0,o.default)(t),getSampleSchema:(0,l.default)(t)}}}},7080:(e,t,n)=>{"use strict";n.r(t),n.d(t,{CLEAR_REQUEST:()=>te,CLEAR_RESPONSE:()=>ee,CLEAR_VALIDATE_PARAMS:()=>ne,LOG_REQUEST:()=>Q,SET_MUTATED_REQUEST:()=>Y,SET_REQUEST:()=>X,SET_RESPONSE:()=>G,SET_SCHEME:()=>oe,UPDATE_EMPTY_PARAM_INCLUSION:()=>H,UPDATE_JSON:()=>B,UPDATE_OPERATION_META_VALUE:()=>se,UPDATE_PARAM:()=>W,UPDATE_RESOLVED:()=>re,UPDATE_RESOLVED_SUBTREE:()=>ae,UPDATE_SPEC:()=>F,UPDATE_URL:()=>z,VALIDATE_PARAMS:()=>Z,changeConsumesValue:()=>be,changeParam:()=>ve,changeParamByIdentity:()=>Se,changeProducesValue:()=>_e,clearRequest:()=>Re,clearResponse:()=>qe,clearValidateParams:()=>je,execute:()=>Pe,executeRequest:()=>Ie,invalidateResolvedSubtreeCache:()=>we,logRequest:()=>Ae,parseToJson:()=>me,requestResolvedSubtree:()=>ye,resolveSpec:()=>he,setMutatedRequest:()=>ke,setRequest:()=>Oe,setResponse:()=>Ne,setScheme:()=>Te,updateEmptyParamInclusion:()=>Ce,updateJsonSpec:()=>pe,updateResolved:()=>ie,updateResolvedSubtree:()=>Ee,updateSpec:()=>ce,updateUrl:()=>ue,validateParams:()=>xe});var s=n(7104),r=n.n(s),a=n(3942),o=n.n(a);const l=require("@babel/runtime-corejs3/core-js-stable/object/define-property");var c=n.n(l),i=n(66),u=n.n(i),p=n(7834),m=n.n(p);const d=require("@babel/runtime-corejs3/core-js-stable/promise");var h=n.n(d),g=n(9998),f=n.n(g),y=n(9968),v=n.n(y),S=n(8493),E=n.n(S),w=n(4235),x=n.n(w),C=n(7252),j=n.n(C),b=n(4994),_=n.n(b);const N=require("@babel/runtime-corejs3/core-js-stable/date/now");var O=n.n(N),k=n(9793),A=n.n(k),I=n(5572),P=n(3883),q=n.n(P),R=n(41);const T=require("lodash/isString");var M=n.n(T);const D=require("lodash/debounce");var J=n.n(D);const $=require("lodash/set");var L=n.n($);const K=require("lodash/fp/assocPath");var V=n.n(K),U=n(1669);const F="spec_update_spec",z="spec_update_url",B="spec_update_json",W="spec_update_param",H="spec_update_empty_param_inclusion",Z="spec_validate_param",G="spec_set_response",X="spec_set_request",Y="spec_set_mutated_request",Q="spec_log_request",ee="spec_clear_response",te="spec_clear_request",ne="spec_clear_validate_param",se="spec_update_operation_meta_value",re="spec_update_resolved",ae="spec_update_resolved_subtree",oe="set_scheme",le=e=>M()(e)?e:"";function ce(e){const t=le(e).replace(/\t/g,"  ");if("string"==typeof e)return{type:F,payload:t}}function ie(e){return{type:re,payload:e}}function ue(e){return{type:z,payload:e}}function pe(e){return{type:B,payload:e}}const me=e=>t=>{let{specActions:n,specSelectors:s,errActions:r}=t,{specStr:a}=s,o=null;try{e=e||a(),r.clear({source:"parser"}),o=A().load(e,{schema:k.JSON_SCHEMA})}catch(e){return console.error(e),r.newSpecErr({source:"parser",level:"error",message:e.reason,line:e.mark&&e.mark.line?e.mark.line+1:void 0})}return o&&"object"==typeof o?n.updateJsonSpec(o):{}};let de=!1;const he=(e,t)=>n=>{let{specActions:s,specSelectors:a,errActions:l,fn:{fetch:i,resolve:u,AST:p={}},getConfigs:m}=n;de||(console.warn("specActions.resolveSpec is deprecated since v3.10.0 and will be removed in v4.0.0; use requestResolvedSubtree instead!"),de=!0);const{modelPropertyMacro:d,parameterMacro:h,requestInterceptor:g,responseInterceptor:f}=m();void 0===e&&(e=a.specJson()),void 0===t&&(t=a.url());let y=p.getLineNumberForPath?p.getLineNumberForPath:()=>{},v=a.specStr();return u({fetch:i,spec:e,baseDoc:t,modelPropertyMacro:d,parameterMacro:h,requestInterceptor:g,responseInterceptor:f}).then((e=>{let{spec:t,errors:n}=e;if(l.clear({type:"thrown"}),r()(n)&&n.length>0){let e=o()(n).call(n,(e=>(console.error(e),e.line=e.fullPath?y(v,e.fullPath):null,e.path=e.fullPath?e.fullPath.join("."):null,e.level="error",e.type="thrown",e.source="resolver",c()(e,"message",{enumerable:!0,value:e.message}),e)));l.newThrownErrBatch(e)}return s.updateResolved(t)}))};let ge=[];const fe=J()((async()=>{const e=ge.system;if(!e)return void console.error("debResolveSubtrees: don't have a system to operate on, aborting.");const{errActions:t,errSelectors:n,fn:{resolveSubtree:s,fetch:a,AST:l={}},specSelectors:i,specActions:p}=e;if(!s)return void console.error("Error: Swagger-Client did not provide a `resolveSubtree` method, doing nothing.");let d=l.getLineNumberForPath?l.getLineNumberForPath:()=>{};const g=i.specStr(),{modelPropertyMacro:y,parameterMacro:S,requestInterceptor:E,responseInterceptor:w}=e.getConfigs();try{var x=await u()(ge).call(ge,(async(e,l)=>{let{resultMap:u,specWithCurrentSubtrees:p}=await e;const{errors:x,spec:C}=await s(p,l,{baseDoc:i.url(),modelPropertyMacro:y,parameterMacro:S,requestInterceptor:E,responseInterceptor:w});if(n.allErrors().size&&t.clearBy((e=>{var t;return"thrown"!==e.get("type")||"resolver"!==e.get("source")||!m()(t=e.get("fullPath")).call(t,((e,t)=>e===l[t]||void 0===l[t]))})),r()(x)&&x.length>0){let e=o()(x).call(x,(e=>(e.line=e.fullPath?d(g,e.fullPath):null,e.path=e.fullPath?e.fullPath.join("."):null,e.level="error",e.type="thrown",e.source="resolver",c()(e,"message",{enumerable:!0,value:e.message}),e)));t.newThrownErrBatch(e)}var j,b;C&&i.isOAS3()&&"components"===l[0]&&"securitySchemes"===l[1]&&await h().all(o()(j=f()(b=v()(C)).call(b,(e=>"openIdConnect"===e.type))).call(j,(async e=>{const t={url:e.openIdConnectUrl,requestInterceptor:E,responseInterceptor:w};try{const n=await a(t);n instanceof Error||n.status>=400?console.error(n.statusText+" "+t.url):e.openIdConnectData=JSON.parse(n.text)}catch(e){console.error(e)}})));return L()(u,l,C),p=V()(l,C,p),{resultMap:u,specWithCurrentSubtrees:p}}),h().resolve({resultMap:(i.specResolvedSubtree([])||(0,I.Map)()).toJS(),specWithCurrentSubtrees:i.specJS()}));delete ge.system,ge=[]}catch(e){console.error(e)}p.updateResolvedSubtree([],x.resultMap)}),35),ye=e=>t=>{var n;E()(n=o()(ge).call(ge,(e=>e.join("@@")))).call(n,e.join("@@"))>-1||(ge.push(e),ge.system=t,fe())};function ve(e,t,n,s,r){return{type:W,payload:{path:e,value:s,paramName:t,paramIn:n,isXml:r}}}function Se(e,t,n,s){return{type:W,payload:{path:e,param:t,value:n,isXml:s}}}const Ee=(e,t)=>({type:ae,payload:{path:e,value:t}}),we=()=>({type:ae,payload:{path:[],value:(0,I.Map)()}}),xe=(e,t)=>({type:Z,payload:{pathMethod:e,isOAS3:t}}),Ce=(e,t,n,s)=>({type:H,payload:{pathMethod:e,paramName:t,paramIn:n,includeEmptyValue:s}});function je(e){return{type:ne,payload:{pathMethod:e}}}function be(e,t){return{type:se,payload:{path:e,value:t,key:"consumes_value"}}}function _e(e,t){return{type:se,payload:{path:e,value:t,key:"produces_value"}}}const Ne=(e,t,n)=>({payload:{path:e,method:t,res:n},type:G}),Oe=(e,t,n)=>({payload:{path:e,method:t,req:n},type:X}),ke=(e,t,n)=>({payload:{path:e,method:t,req:n},type:Y}),Ae=e=>({payload:e,type:Q}),Ie=e=>t=>{let{fn:n,specActions:s,specSelectors:a,getConfigs:l,oas3Selectors:c}=t,{pathName:i,method:u,operation:p}=e,{requestInterceptor:m,responseInterceptor:d}=l(),h=p.toJS();var g,y;p&&p.get("parameters")&&x()(g=f()(y=p.get("parameters")).call(y,(e=>e&&!0===e.get("allowEmptyValue")))).call(g,(t=>{if(a.parameterInclusionSettingFor([i,u],t.get("name"),t.get("in"))){e.parameters=e.parameters||{};const n=(0,U.cz)(t,e.parameters);(!n||n&&0===n.size)&&(e.parameters[t.get("name")]="")}}));if(e.contextUrl=q()(a.url()).toString(),h&&h.operationId?e.operationId=h.operationId:h&&i&&u&&(e.operationId=n.opId(h,i,u)),a.isOAS3()){const t=`${i}:${u}`;e.server=c.selectedServer(t)||c.selectedServer();const n=c.serverVariables({server:e.server,namespace:t}).toJS(),s=c.serverVariables({server:e.server}).toJS();e.serverVariables=j()(n).length?n:s,e.requestContentType=c.requestContentType(i,u),e.responseContentType=c.responseContentType(i,u)||"*/*";const a=c.requestBodyValue(i,u),l=c.requestBodyInclusionSetting(i,u);var v;if(a&&a.toJS)e.requestBody=f()(v=o()(a).call(a,(e=>I.Map.isMap(e)?e.get("value"):e))).call(v,((e,t)=>(r()(e)?0!==e.length:!(0,U.O2)(e))||l.get(t))).toJS();else e.requestBody=a}let S=_()({},e);S=n.buildRequest(S),s.setRequest(e.pathName,e.method,S);e.requestInterceptor=async t=>{let n=await m.apply(void 0,[t]),r=_()({},n);return s.setMutatedRequest(e.pathName,e.method,r),n},e.responseInterceptor=d;const E=O()();return n.execute(e).then((t=>{t.duration=O()()-E,s.setResponse(e.pathName,e.method,t)})).catch((t=>{"Failed to fetch"===t.message&&(t.name="",t.message='**Failed to fetch.**  \n**Possible Reasons:** \n  - CORS \n  - Network Failure \n  - URL scheme must be "http" or "https" for CORS request.'),s.setResponse(e.pathName,e.method,{error:!0,err:(0,R.serializeError)(t)})}))},Pe=function(){let{path:e,method:t,...n}=arguments.length>0&&void 0!==arguments[0]?arguments[0]:{};return s=>{let{fn:{fetch:r},specSelectors:a,specActions:o}=s,l=a.specJsonWithResolvedSubtrees().toJS(),c=a.operationScheme(e,t),{requestContentType:i,responseContentType:u}=a.contentTypeValues([e,t]).toJS(),p=/xml/i.test(i),m=a.parameterValues([e,t],p).toJS();return o.executeRequest({...n,fetch:r,spec:l,pathName:e,method:t,parameters:m,requestContentType:i,scheme:c,responseContentType:u})}};function qe(e,t){return{type:ee,payload:{path:e,method:t}}}function Re(e,t){return{type:te,payload:{path:e,method:t}}}function Te(e,t,n){return{type:oe,payload:{scheme:e,path:t,method:n}}}},7038:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>l});var s=n(32),r=n(7080),a=n(3881),o=n(7508);function l(){return{statePlugins:{spec:{wrapActions:o,reducers:s.default,actions:r,selectors:a}}}}},32:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>h});var s=n(66),r=n.n(s),a=n(3942),o=n.n(a),l=n(4994),c=n.n(l),i=n(5572),u=n(1669),p=n(7504),m=n(3881),d=n(7080);const h={[d.UPDATE_SPEC]:(e,t)=>"string"==typeof t.payload?e.set("spec",t.payload):e,[d.UPDATE_URL]:(e,t)=>e.set("url",t.payload+""),[d.UPDATE_JSON]:(e,t)=>e.set("json",(0,u.oG)(t.payload)),[d.UPDATE_RESOLVED]:(e,t)=>e.setIn(["resolved"],(0,u.oG)(t.payload)),[d.UPDATE_RESOLVED_SUBTREE]:(e,t)=>{const{value:n,path:s}=t.payload;return e.setIn(["resolvedSubtrees",...s],(0,u.oG)(n))},[d.UPDATE_PARAM]:(e,t)=>{let{payload:n}=t,{path:s,paramName:r,paramIn:a,param:o,value:l,isXml:c}=n,i=o?(0,u.V9)(o):`${a}.${r}`;const p=c?"value_xml":"value";return e.setIn(["meta","paths",...s,"parameters",i,p],l)},[d.UPDATE_EMPTY_PARAM_INCLUSION]:(e,t)=>{let{payload:n}=t,{pathMethod:s,paramName:r,paramIn:a,includeEmptyValue:o}=n;if(!r||!a)return console.warn("Warning: UPDATE_EMPTY_PARAM_INCLUSION could not generate a paramKey."),e;const l=`${a}.${r}`;return e.setIn(["meta","paths",...s,"parameter_inclusions",l],o)},[d.VALIDATE_PARAMS]:(e,t)=>{let{payload:{pathMethod:n,isOAS3:s}}=t;const a=(0,m.specJsonWithResolvedSubtrees)(e).getIn(["paths",...n]),o=(0,m.parameterValues)(e,n).toJS();return e.updateIn(["meta","paths",...n,"parameters"],(0,i.fromJS)({}),(t=>{var l;return r()(l=a.get("parameters",(0,i.List)())).call(l,((t,r)=>{const a=(0,u.cz)(r,o),l=(0,m.parameterInclusionSettingFor)(e,n,r.get("name"),r.get("in")),c=(0,u.Ik)(r,a,{bypassRequiredCheck:l,isOAS3:s});return t.setIn([(0,u.V9)(r),"errors"],(0,i.fromJS)(c))}),t)}))},[d.CLEAR_VALIDATE_PARAMS]:(e,t)=>{let{payload:{pathMethod:n}}=t;return e.updateIn(["meta","paths",...n,"parameters"],(0,i.fromJS)([]),(e=>o()(e).call(e,(e=>e.set("errors",(0,i.fromJS)([]))))))},[d.SET_RESPONSE]:(e,t)=>{let n,{payload:{res:s,path:r,method:a}}=t;n=s.error?c()({error:!0,name:s.err.name,message:s.err.message,statusCode:s.err.statusCode},s.err.response):s,n.headers=n.headers||{};let o=e.setIn(["responses",r,a],(0,u.oG)(n));return p.Z.Blob&&s.data instanceof p.Z.Blob&&(o=o.setIn(["responses",r,a,"text"],s.data)),o},[d.SET_REQUEST]:(e,t)=>{let{payload:{req:n,path:s,method:r}}=t;return e.setIn(["requests",s,r],(0,u.oG)(n))},[d.SET_MUTATED_REQUEST]:(e,t)=>{let{payload:{req:n,path:s,method:r}}=t;return e.setIn(["mutatedRequests",s,r],(0,u.oG)(n))},[d.UPDATE_OPERATION_META_VALUE]:(e,t)=>{let{payload:{path:n,value:s,key:r}}=t,a=["paths",...n],o=["meta","paths",...n];return e.getIn(["json",...a])||e.getIn(["resolved",...a])||e.getIn(["resolvedSubtrees",...a])?e.setIn([...o,r],(0,i.fromJS)(s)):e},[d.CLEAR_RESPONSE]:(e,t)=>{let{payload:{path:n,method:s}}=t;return e.deleteIn(["responses",n,s])},[d.CLEAR_REQUEST]:(e,t)=>{let{payload:{path:n,method:s}}=t;return e.deleteIn(["requests",n,s])},[d.SET_SCHEME]:(e,t)=>{let{payload:{scheme:n,path:s,method:r}}=t;return s&&r?e.setIn(["scheme",s,r],n):s||r?void 0:e.setIn(["scheme","_defaultScheme"],n)}}},3881:(e,t,n)=>{"use strict";n.r(t),n.d(t,{allowTryItOutFor:()=>de,basePath:()=>Q,canExecuteScheme:()=>Oe,consumes:()=>W,consumesOptionsFor:()=>_e,contentTypeValues:()=>Ce,currentProducesFor:()=>je,definitions:()=>Y,externalDocs:()=>K,findDefinition:()=>X,getOAS3RequiredRequestBodyContentType:()=>Ie,getParameter:()=>ve,hasHost:()=>Se,host:()=>ee,info:()=>L,isMediaTypeSchemaPropertiesEqual:()=>Pe,isOAS3:()=>$,lastError:()=>O,mutatedRequestFor:()=>me,mutatedRequests:()=>ie,operationScheme:()=>Ne,operationWithMeta:()=>ye,operations:()=>B,operationsWithRootInherited:()=>ne,operationsWithTags:()=>ae,parameterInclusionSettingFor:()=>ge,parameterValues:()=>Ee,parameterWithMeta:()=>fe,parameterWithMetaByIdentity:()=>he,parametersIncludeIn:()=>we,parametersIncludeType:()=>xe,paths:()=>F,produces:()=>H,producesOptionsFor:()=>be,requestFor:()=>pe,requests:()=>ce,responseFor:()=>ue,responses:()=>le,schemes:()=>te,security:()=>Z,securityDefinitions:()=>G,semver:()=>U,spec:()=>J,specJS:()=>q,specJson:()=>P,specJsonWithResolvedSubtrees:()=>D,specResolved:()=>R,specResolvedSubtree:()=>T,specSource:()=>I,specStr:()=>A,tagDetails:()=>re,taggedOperations:()=>oe,tags:()=>se,url:()=>k,validOperationMethods:()=>z,validateBeforeExecute:()=>Ae,validationErrors:()=>ke,version:()=>V});var s=n(600),r=n.n(s),a=n(4235),o=n.n(a),l=n(8493),c=n.n(l),i=n(3942),u=n.n(i),p=n(9998),m=n.n(p),d=n(3580),h=n.n(d),g=n(66),f=n.n(g),y=n(9247),v=n.n(y),S=n(5626),E=n.n(S),w=n(7104),x=n.n(w),C=n(6814),j=n(1669),b=n(5572);const _=["get","put","post","delete","options","head","patch","trace"],N=e=>e||(0,b.Map)(),O=(0,C.createSelector)(N,(e=>e.get("lastError"))),k=(0,C.createSelector)(N,(e=>e.get("url"))),A=(0,C.createSelector)(N,(e=>e.get("spec")||"")),I=(0,C.createSelector)(N,(e=>e.get("specSource")||"not-editor")),P=(0,C.createSelector)(N,(e=>e.get("json",(0,b.Map)()))),q=(0,C.createSelector)(P,(e=>e.toJS())),R=(0,C.createSelector)(N,(e=>e.get("resolved",(0,b.Map)()))),T=(e,t)=>e.getIn(["resolvedSubtrees",...t],void 0),M=(e,t)=>b.Map.isMap(e)&&b.Map.isMap(t)?t.get("$$ref")?t:(0,b.OrderedMap)().mergeWith(M,e,t):t,D=(0,C.createSelector)(N,(e=>(0,b.OrderedMap)().mergeWith(M,e.get("json"),e.get("resolvedSubtrees")))),J=e=>P(e),$=(0,C.createSelector)(J,(()=>!1)),L=(0,C.createSelector)(J,(e=>qe(e&&e.get("info")))),K=(0,C.createSelector)(J,(e=>qe(e&&e.get("externalDocs")))),V=(0,C.createSelector)(L,(e=>e&&e.get("version"))),U=(0,C.createSelector)(V,(e=>{var t;return r()(t=/v?([0-9]*)\.([0-9]*)\.([0-9]*)/i.exec(e)).call(t,1)})),F=(0,C.createSelector)(D,(e=>e.get("paths"))),z=(0,C.createSelector)((()=>["get","put","post","delete","options","head","patch"])),B=(0,C.createSelector)(F,(e=>{if(!e||e.size<1)return(0,b.List)();let t=(0,b.List)();return e&&o()(e)?(o()(e).call(e,((e,n)=>{if(!e||!o()(e))return{};o()(e).call(e,((e,s)=>{c()(_).call(_,s)<0||(t=t.push((0,b.fromJS)({path:n,method:s,operation:e,id:`${s}-${n}`})))}))})),t):(0,b.List)()})),W=(0,C.createSelector)(J,(e=>(0,b.Set)(e.get("consumes")))),H=(0,C.createSelector)(J,(e=>(0,b.Set)(e.get("produces")))),Z=(0,C.createSelector)(J,(e=>e.get("security",(0,b.List)()))),G=(0,C.createSelector)(J,(e=>e.get("securityDefinitions"))),X=(e,t)=>{const n=e.getIn(["resolvedSubtrees","definitions",t],null),s=e.getIn(["json","definitions",t],null);return n||s||null},Y=(0,C.createSelector)(J,(e=>{const t=e.get("definitions");return b.Map.isMap(t)?t:(0,b.Map)()})),Q=(0,C.createSelector)(J,(e=>e.get("basePath"))),ee=(0,C.createSelector)(J,(e=>e.get("host"))),te=(0,C.createSelector)(J,(e=>e.get("schemes",(0,b.Map)()))),ne=(0,C.createSelector)(B,W,H,((e,t,n)=>u()(e).call(e,(e=>e.update("operation",(e=>{if(e){if(!b.Map.isMap(e))return;return e.withMutations((e=>(e.get("consumes")||e.update("consumes",(e=>(0,b.Set)(e).merge(t))),e.get("produces")||e.update("produces",(e=>(0,b.Set)(e).merge(n))),e)))}return(0,b.Map)()})))))),se=(0,C.createSelector)(J,(e=>{const t=e.get("tags",(0,b.List)());return b.List.isList(t)?m()(t).call(t,(e=>b.Map.isMap(e))):(0,b.List)()})),re=(e,t)=>{var n;let s=se(e)||(0,b.List)();return h()(n=m()(s).call(s,b.Map.isMap)).call(n,(e=>e.get("name")===t),(0,b.Map)())},ae=(0,C.createSelector)(ne,se,((e,t)=>f()(e).call(e,((e,t)=>{let n=(0,b.Set)(t.getIn(["operation","tags"]));return n.count()<1?e.update("default",(0,b.List)(),(e=>e.push(t))):f()(n).call(n,((e,n)=>e.update(n,(0,b.List)(),(e=>e.push(t)))),e)}),f()(t).call(t,((e,t)=>e.set(t.get("name"),(0,b.List)())),(0,b.OrderedMap)())))),oe=e=>t=>{var n;let{getConfigs:s}=t,{tagsSorter:r,operationsSorter:a}=s();return u()(n=ae(e).sortBy(((e,t)=>t),((e,t)=>{let n="function"==typeof r?r:j.wh.tagsSorter[r];return n?n(e,t):null}))).call(n,((t,n)=>{let s="function"==typeof a?a:j.wh.operationsSorter[a],r=s?v()(t).call(t,s):t;return(0,b.Map)({tagDetails:re(e,n),operations:r})}))},le=(0,C.createSelector)(N,(e=>e.get("responses",(0,b.Map)()))),ce=(0,C.createSelector)(N,(e=>e.get("requests",(0,b.Map)()))),ie=(0,C.createSelector)(N,(e=>e.get("mutatedRequests",(0,b.Map)()))),ue=(e,t,n)=>le(e).getIn([t,n],null),pe=(e,t,n)=>ce(e).getIn([t,n],null),me=(e,t,n)=>ie(e).getIn([t,n],null),de=()=>!0,he=(e,t,n)=>{const s=D(e).getIn(["paths",...t,"parameters"],(0,b.OrderedMap)()),r=e.getIn(["meta","paths",...t,"parameters"],(0,b.OrderedMap)()),a=u()(s).call(s,(e=>{const t=r.get(`${n.get("in")}.${n.get("name")}`),s=r.get(`${n.get("in")}.${n.get("name")}.hash-${n.hashCode()}`);return(0,b.OrderedMap)().merge(e,t,s)}));return h()(a).call(a,(e=>e.get("in")===n.get("in")&&e.get("name")===n.get("name")),(0,b.OrderedMap)())},ge=(e,t,n,s)=>{const r=`${s}.${n}`;return e.getIn(["meta","paths",...t,"parameter_inclusions",r],!1)},fe=(e,t,n,s)=>{const r=D(e).getIn(["paths",...t,"parameters"],(0,b.OrderedMap)()),a=h()(r).call(r,(e=>e.get("in")===s&&e.get("name")===n),(0,b.OrderedMap)());return he(e,t,a)},ye=(e,t,n)=>{var s;const r=D(e).getIn(["paths",t,n],(0,b.OrderedMap)()),a=e.getIn(["meta","paths",t,n],(0,b.OrderedMap)()),o=u()(s=r.get("parameters",(0,b.List)())).call(s,(s=>he(e,[t,n],s)));return(0,b.OrderedMap)().merge(r,a).set("parameters",o)};function ve(e,t,n,s){t=t||[];let r=e.getIn(["meta","paths",...t,"parameters"],(0,b.fromJS)([]));return h()(r).call(r,(e=>b.Map.isMap(e)&&e.get("name")===n&&e.get("in")===s))||(0,b.Map)()}const Se=(0,C.createSelector)(J,(e=>{const t=e.get("host");return"string"==typeof t&&t.length>0&&"/"!==t[0]}));function Ee(e,t,n){t=t||[];let s=ye(e,...t).get("parameters",(0,b.List)());return f()(s).call(s,((e,t)=>{let s=n&&"body"===t.get("in")?t.get("value_xml"):t.get("value");return e.set((0,j.V9)(t,{allowHashes:!1}),s)}),(0,b.fromJS)({}))}function we(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:"";if(b.List.isList(e))return E()(e).call(e,(e=>b.Map.isMap(e)&&e.get("in")===t))}function xe(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:"";if(b.List.isList(e))return E()(e).call(e,(e=>b.Map.isMap(e)&&e.get("type")===t))}function Ce(e,t){t=t||[];let n=D(e).getIn(["paths",...t],(0,b.fromJS)({})),s=e.getIn(["meta","paths",...t],(0,b.fromJS)({})),r=je(e,t);const a=n.get("parameters")||new b.List,o=s.get("consumes_value")?s.get("consumes_value"):xe(a,"file")?"multipart/form-data":xe(a,"formData")?"application/x-www-form-urlencoded":void 0;return(0,b.fromJS)({requestContentType:o,responseContentType:r})}function je(e,t){t=t||[];const n=D(e).getIn(["paths",...t],null);if(null===n)return;const s=e.getIn(["meta","paths",...t,"produces_value"],null),r=n.getIn(["produces",0],null);return s||r||"application/json"}function be(e,t){t=t||[];const n=D(e),s=n.getIn(["paths",...t],null);if(null===s)return;const[r]=t,a=s.get("produces",null),o=n.getIn(["paths",r,"produces"],null),l=n.getIn(["produces"],null);return a||o||l}function _e(e,t){t=t||[];const n=D(e),s=n.getIn(["paths",...t],null);if(null===s)return;const[r]=t,a=s.get("consumes",null),o=n.getIn(["paths",r,"consumes"],null),l=n.getIn(["consumes"],null);return a||o||l}const Ne=(e,t,n)=>{let s=e.get("url").match(/^([a-z][a-z0-9+\-.]*):/),r=x()(s)?s[1]:null;return e.getIn(["scheme",t,n])||e.getIn(["scheme","_defaultScheme"])||r||""},Oe=(e,t,n)=>{var s;return c()(s=["http","https"]).call(s,Ne(e,t,n))>-1},ke=(e,t)=>{t=t||[];let n=e.getIn(["meta","paths",...t,"parameters"],(0,b.fromJS)([]));const s=[];return o()(n).call(n,(e=>{let t=e.get("errors");t&&t.count()&&o()(t).call(t,(e=>s.push(e)))})),s},Ae=(e,t)=>0===ke(e,t).length,Ie=(e,t)=>{var n;let s={requestBody:!1,requestContentType:{}},r=e.getIn(["resolvedSubtrees","paths",...t,"requestBody"],(0,b.fromJS)([]));return r.size<1||(r.getIn(["required"])&&(s.requestBody=r.getIn(["required"])),o()(n=r.getIn(["content"]).entrySeq()).call(n,(e=>{const t=e[0];if(e[1].getIn(["schema","required"])){const n=e[1].getIn(["schema","required"]).toJS();s.requestContentType[t]=n}}))),s},Pe=(e,t,n,s)=>{if((n||s)&&n===s)return!0;let r=e.getIn(["resolvedSubtrees","paths",...t,"requestBody","content"],(0,b.fromJS)([]));if(r.size<2||!n||!s)return!1;let a=r.getIn([n,"schema","properties"],(0,b.fromJS)([])),o=r.getIn([s,"schema","properties"],(0,b.fromJS)([]));return!!a.equals(o)};function qe(e){return b.Map.isMap(e)?e:new b.Map}},7508:(e,t,n)=>{"use strict";n.r(t),n.d(t,{executeRequest:()=>p,updateJsonSpec:()=>u,updateSpec:()=>i,validateParams:()=>m});var s=n(7252),r=n.n(s),a=n(4235),o=n.n(a),l=n(1712),c=n.n(l);const i=(e,t)=>{let{specActions:n}=t;return function(){e(...arguments),n.parseToJson(...arguments)}},u=(e,t)=>{let{specActions:n}=t;return function(){for(var t=arguments.length,s=new Array(t),a=0;a<t;a++)s[a]=arguments[a];e(...s),n.invalidateResolvedSubtreeCache();const[l]=s,i=c()(l,["paths"])||{},u=r()(i);o()(u).call(u,(e=>{c()(i,[e]).$ref&&n.requestResolvedSubtree(["paths",e])})),n.requestResolvedSubtree(["components","securitySchemes"])}},p=(e,t)=>{let{specActions:n}=t;return t=>(n.logRequest(t),e(t))},m=(e,t)=>{let{specSelectors:n}=t;return t=>e(t,n.isOAS3())}},4852:(e,t,n)=>{"use strict";n.r(t),n.d(t,{loaded:()=>s});const s=(e,t)=>function(){e(...arguments);const n=t.getConfigs().withCredentials;void 0!==n&&(t.fn.fetch.withCredentials="string"==typeof n?"true"===n:!!n)}},1241:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>v});const s=require("swagger-client/es/resolver/strategies/generic");var r=n.n(s);const a=require("swagger-client/es/resolver/strategies/openapi-2");var o=n.n(a);const l=require("swagger-client/es/resolver/strategies/openapi-3-0");var c=n.n(l);const i=require("swagger-client/es/resolver/strategies/openapi-3-1-apidom");var u=n.n(i);const p=require("swagger-client/es/resolver"),m=require("swagger-client/es/execute"),d=require("swagger-client/es/http");var h=n.n(d);const g=require("swagger-client/es/subtree-resolver");var f=n(6765),y=n(4852);function v(e){let{configs:t,getConfigs:n}=e;return{fn:{fetch:(0,d.makeHttp)(h(),t.preFetch,t.postFetch),buildRequest:m.buildRequest,execute:m.execute,resolve:(0,p.makeResolve)({strategies:[u(),c(),o(),r()]}),resolveSubtree:async function(e,t){let s=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{};const a=n(),l={modelPropertyMacro:a.modelPropertyMacro,parameterMacro:a.parameterMacro,requestInterceptor:a.requestInterceptor,responseInterceptor:a.responseInterceptor,strategies:[u(),c(),o(),r()]};return(0,g.makeResolveSubtree)(l)(e,t,s)},serializeRes:d.serializeRes,opId:f.opId},statePlugins:{configs:{wrapActions:{loaded:y.loaded}}}}}},8525:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>r});var s=n(1669);function r(){return{fn:{shallowEqualKeys:s.be}}}},8347:(e,t,n)=>{"use strict";n.r(t),n.d(t,{getDisplayName:()=>s});const s=e=>e.displayName||e.name||"Component"},3420:(e,t,n)=>{"use strict";n.r(t),n.d(t,{default:()=>i});var s=n(8344),r=n.n(s),a=n(1669),o=n(950),l=n(8347),c=n(7481);const i=e=>{let{getComponents:t,getStore:n,getSystem:s}=e;const i=(u=(0,o.getComponent)(s,n,t),(0,a.HP)(u,(function(){for(var e=arguments.length,t=new Array(e),n=0;n<e;n++)t[n]=arguments[n];return r()(t)})));var u;const p=(e=>(0,c.Z)(e,(function(){for(var e=arguments.length,t=new Array(e),n=0;n<e;n++)t[n]=arguments[n];return t})))((0,o.withMappedContainer)(s,n,i));return{rootInjects:{getComponent:i,makeMappedContainer:p,render:(0,o.render)(s,n,o.getComponent,t)},fn:{getDisplayName:l.getDisplayName}}}},950:(e,t,n)=>{"use strict";n.r(t),n.d(t,{getComponent:()=>C,render:()=>x,withMappedContainer:()=>w});var s=n(4250),r=n.n(s),a=n(7252),o=n.n(a),l=n(6689),c=n.n(l);const i=require("react-dom");var u=n.n(i),p=n(6695);const m=require("react-redux");var d=n(3901),h=n.n(d),g=n(4292),f=n.n(g);const y=e=>t=>{const{fn:n}=e();class s extends l.Component{render(){return c().createElement(t,r()({},e(),this.props,this.context))}}return s.displayName=`WithSystem(${n.getDisplayName(t)})`,s},v=(e,t)=>n=>{const{fn:s}=e();class a extends l.Component{render(){return c().createElement(m.Provider,{store:t},c().createElement(n,r()({},this.props,this.context)))}}return a.displayName=`WithRoot(${s.getDisplayName(n)})`,a},S=(e,t,n)=>(0,p.compose)(n?v(e,n):f(),(0,m.connect)(((n,s)=>{var r;const a={...s,...e()},o=(null===(r=t.prototype)||void 0===r?void 0:r.mapStateToProps)||(e=>({state:e}));return o(n,a)})),y(e))(t),E=(e,t,n,s)=>{for(const r in t){const a=t[r];"function"==typeof a&&a(n[r],s[r],e())}},w=(e,t,n)=>(t,s)=>{const{fn:r}=e(),a=n(t,"root");class i extends l.Component{constructor(t,n){super(t,n),E(e,s,t,{})}UNSAFE_componentWillReceiveProps(t){E(e,s,t,this.props)}render(){const e=h()(this.props,s?o()(s):[]);return c().createElement(a,e)}}return i.displayName=`WithMappedContainer(${r.getDisplayName(a)})`,i},x=(e,t,n,s)=>r=>{const a=n(e,t,s)("App","root");u().render(c().createElement(a,null),r)},C=(e,t,n)=>function(s,r){let a=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{};if("string"!=typeof s)throw new TypeError("Need a string, to fetch a component. Was given a "+typeof s);const o=n(s);return o?r?"root"===r?S(e,o,t()):S(e,o):o:(a.failSilently||e().log.warn("Could not find component:",s),null)}},6068:(e,t,n)=>{"use strict";n.d(t,{d3:()=>c(),C2:()=>D});var s=n(7252),r=n.n(s),a=n(2605),o=n.n(a);const l=require("react-syntax-highlighter/dist/esm/light");var c=n.n(l);const i=require("react-syntax-highlighter/dist/esm/languages/hljs/javascript");var u=n.n(i);const p=require("react-syntax-highlighter/dist/esm/languages/hljs/json");var m=n.n(p);const d=require("react-syntax-highlighter/dist/esm/languages/hljs/xml");var h=n.n(d);const g=require("react-syntax-highlighter/dist/esm/languages/hljs/bash");var f=n.n(g);const y=require("react-syntax-highlighter/dist/esm/languages/hljs/yaml");var v=n.n(y);const S=require("react-syntax-highlighter/dist/esm/languages/hljs/http");var E=n.n(S);const w=require("react-syntax-highlighter/dist/esm/languages/hljs/powershell");var x=n.n(w);const C=require("react-syntax-highlighter/dist/esm/styles/hljs/agate");var j=n.n(C);const b=require("react-syntax-highlighter/dist/esm/styles/hljs/arta");var _=n.n(b);const N=require("react-syntax-highlighter/dist/esm/styles/hljs/monokai");var O=n.n(N);const k=require("react-syntax-highlighter/dist/esm/styles/hljs/nord");var A=n.n(k);const I=require("react-syntax-highlighter/dist/esm/styles/hljs/obsidian");var P=n.n(I);const q=require("react-syntax-highlighter/dist/esm/styles/hljs/tomorrow-night");var R=n.n(q);c().registerLanguage("json",m()),c().registerLanguage("js",u()),c().registerLanguage("xml",h()),c().registerLanguage("yaml",v()),c().registerLanguage("http",E()),c().registerLanguage("bash",f()),c().registerLanguage("powershell",x()),c().registerLanguage("javascript",u());const T={agate:j(),arta:_(),monokai:O(),nord:A(),obsidian:P(),"tomorrow-night":R()},M=r()(T),D=e=>o()(M).call(M,e)?T[e]:(console.warn(`Request style '${e}' is not available, returning default instead`),j())},1669:(e,t,n)=>{"use strict";n.d(t,{r3:()=>Ce,GZ:()=>be,Xb:()=>Le,oJ:()=>Ae,XV:()=>Re,iQ:()=>fe,J6:()=>Ie,DR:()=>ve,oG:()=>oe,Uj:()=>$e,QG:()=>ke,po:()=>qe,nX:()=>Pe,gp:()=>ye,kJ:()=>pe,O2:()=>Ve,LQ:()=>ce,Wl:()=>ue,Kn:()=>ie,HP:()=>me,AF:()=>le,D$:()=>Me,Ay:()=>de,Q2:()=>he,mz:()=>ae,V9:()=>De,cz:()=>Je,UG:()=>xe,Zl:()=>Se,hW:()=>Oe,Nm:()=>Ne,be:()=>_e,wh:()=>je,Pz:()=>Te,_5:()=>ge,Ik:()=>we});var s=n(7104),r=n.n(s),a=n(3942),o=n.n(a),l=n(7862),c=n.n(l),i=n(4235),u=n.n(i),p=n(9998),m=n.n(p),d=n(7252),h=n.n(d),g=(n(593),n(66)),f=n.n(g),y=n(4994),v=n.n(y),S=n(9247),E=n.n(S),w=(n(600),n(4883),n(5626)),x=n.n(w),C=(n(2605),n(8493)),j=n.n(C),b=n(3580),_=n.n(b);const N=require("@babel/runtime-corejs3/core-js-stable/instance/starts-with");var O=n.n(N),k=n(7390),A=n.n(k),I=n(8344),P=n.n(I),q=n(5572),R=n.n(q);const T=require("@braintree/sanitize-url"),M=require("lodash/camelCase");var D=n.n(M);const J=require("lodash/upperFirst");var $=n.n(J),L=n(541),K=n.n(L);const V=require("lodash/find");var U=n.n(V);n(4129);const F=require("lodash/eq");var z=n.n(F),B=n(5716),W=n.n(B),H=n(7504);const Z=require("css.escape");var G=n.n(Z),X=n(9069),Y=n(185),Q=n.n(Y);const ee=require("sha.js");var te=n.n(ee),ne=n(871).Buffer;const se="default",re=e=>R().Iterable.isIterable(e);function ae(e){return ie(e)?re(e)?e.toJS():e:{}}function oe(e){var t,n;if(re(e))return e;if(e instanceof H.Z.File)return e;if(!ie(e))return e;if(r()(e))return o()(n=R().Seq(e)).call(n,oe).toList();if(W()(c()(e))){var s;const t=function(e){if(!W()(c()(e)))return e;const t={},n="_**[]",s={};for(let r of c()(e).call(e))if(t[r[0]]||s[r[0]]&&s[r[0]].containsMultiple){if(!s[r[0]]){s[r[0]]={containsMultiple:!0,length:1},t[`${r[0]}${n}${s[r[0]].length}`]=t[r[0]],delete t[r[0]]}s[r[0]].length+=1,t[`${r[0]}${n}${s[r[0]].length}`]=r[1]}else t[r[0]]=r[1];return t}(e);return o()(s=R().OrderedMap(t)).call(s,oe)}return o()(t=R().OrderedMap(e)).call(t,oe)}function le(e){return r()(e)?e:[e]}function ce(e){return"function"==typeof e}function ie(e){return!!e&&"object"==typeof e}function ue(e){return"function"==typeof e}function pe(e){return r()(e)}const me=K();function de(e,t){var n;return f()(n=h()(e)).call(n,((n,s)=>(n[s]=t(e[s],s),n)),{})}function he(e,t){var n;return f()(n=h()(e)).call(n,((n,s)=>{let r=t(e[s],s);return r&&"object"==typeof r&&v()(n,r),n}),{})}function ge(e){return t=>{let{dispatch:n,getState:s}=t;return t=>n=>"function"==typeof n?n(e()):t(n)}}function fe(e){var t;let n=e.keySeq();return n.contains(se)?se:E()(t=m()(n).call(n,(e=>"2"===(e+"")[0]))).call(t).first()}function ye(e,t){if(!R().Iterable.isIterable(e))return R().List();let n=e.getIn(r()(t)?t:[t]);return R().List.isList(n)?n:R().List()}function ve(e){let t,n=[/filename\*=[^']+'\w*'"([^"]+)";?/i,/filename\*=[^']+'\w*'([^;]+);?/i,/filename="([^;]*);?"/i,/filename=([^;]*);?/i];if(x()(n).call(n,(n=>(t=n.exec(e),null!==t))),null!==t&&t.length>1)try{return decodeURIComponent(t[1])}catch(e){console.error(e)}return null}function Se(e){return t=e.replace(/\.[^./]*$/,""),$()(D()(t));var t}function Ee(e,t,n,s,a){if(!t)return[];let l=[],c=t.get("nullable"),i=t.get("required"),p=t.get("maximum"),d=t.get("minimum"),h=t.get("type"),g=t.get("format"),f=t.get("maxLength"),y=t.get("minLength"),v=t.get("uniqueItems"),S=t.get("maxItems"),E=t.get("minItems"),w=t.get("pattern");const C=n||!0===i,j=null!=e;if(c&&null===e||!h||!(C||j&&"array"===h||!(!C&&!j)))return[];let b="string"===h&&e,_="array"===h&&r()(e)&&e.length,N="array"===h&&R().List.isList(e)&&e.count();const O=[b,_,N,"array"===h&&"string"==typeof e&&e,"file"===h&&e instanceof H.Z.File,"boolean"===h&&(e||!1===e),"number"===h&&(e||0===e),"integer"===h&&(e||0===e),"object"===h&&"object"==typeof e&&null!==e,"object"===h&&"string"==typeof e&&e],k=x()(O).call(O,(e=>!!e));if(C&&!k&&!s)return l.push("Required field is not provided"),l;if("object"===h&&(null===a||"application/json"===a)){let n=e;if("string"==typeof e)try{n=JSON.parse(e)}catch(e){return l.push("Parameter string value must be valid JSON"),l}var A;if(t&&t.has("required")&&ue(i.isList)&&i.isList()&&u()(i).call(i,(e=>{void 0===n[e]&&l.push({propKey:e,error:"Required property not found"})})),t&&t.has("properties"))u()(A=t.get("properties")).call(A,((e,t)=>{const r=Ee(n[t],e,!1,s,a);l.push(...o()(r).call(r,(e=>({propKey:t,error:e}))))}))}if(w){let t=((e,t)=>{if(!new RegExp(t).test(e))return"Value must follow pattern "+t})(e,w);t&&l.push(t)}if(E&&"array"===h){let t=((e,t)=>{if(!e&&t>=1||e&&e.length<t)return`Array must contain at least ${t} item${1===t?"":"s"}`})(e,E);t&&l.push(t)}if(S&&"array"===h){let t=((e,t)=>{if(e&&e.length>t)return`Array must not contain more then ${t} item${1===t?"":"s"}`})(e,S);t&&l.push({needRemove:!0,error:t})}if(v&&"array"===h){let t=((e,t)=>{if(e&&("true"===t||!0===t)){const t=(0,q.fromJS)(e),n=t.toSet();if(e.length>n.size){let e=(0,q.Set)();if(u()(t).call(t,((n,s)=>{m()(t).call(t,(e=>ue(e.equals)?e.equals(n):e===n)).size>1&&(e=e.add(s))})),0!==e.size)return o()(e).call(e,(e=>({index:e,error:"No duplicates allowed."}))).toArray()}}})(e,v);t&&l.push(...t)}if(f||0===f){let t=((e,t)=>{if(e.length>t)return`Value must be no longer than ${t} character${1!==t?"s":""}`})(e,f);t&&l.push(t)}if(y){let t=((e,t)=>{if(e.length<t)return`Value must be at least ${t} character${1!==t?"s":""}`})(e,y);t&&l.push(t)}if(p||0===p){let t=((e,t)=>{if(e>t)return`Value must be less than ${t}`})(e,p);t&&l.push(t)}if(d||0===d){let t=((e,t)=>{if(e<t)return`Value must be greater than ${t}`})(e,d);t&&l.push(t)}if("string"===h){let t;if(t="date-time"===g?(e=>{if(isNaN(Date.parse(e)))return"Value must be a DateTime"})(e):"uuid"===g?(e=>{if(e=e.toString().toLowerCase(),!/^[{(]?[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12}[)}]?$/.test(e))return"Value must be a Guid"})(e):(e=>{if(e&&"string"!=typeof e)return"Value must be a string"})(e),!t)return l;l.push(t)}else if("boolean"===h){let t=(e=>{if("true"!==e&&"false"!==e&&!0!==e&&!1!==e)return"Value must be a boolean"})(e);if(!t)return l;l.push(t)}else if("number"===h){let t=(e=>{if(!/^-?\d+(\.?\d+)?$/.test(e))return"Value must be a number"})(e);if(!t)return l;l.push(t)}else if("integer"===h){let t=(e=>{if(!/^-?\d+$/.test(e))return"Value must be an integer"})(e);if(!t)return l;l.push(t)}else if("array"===h){if(!_&&!N)return l;e&&u()(e).call(e,((e,n)=>{const r=Ee(e,t.get("items"),!1,s,a);l.push(...o()(r).call(r,(e=>({index:n,error:e}))))}))}else if("file"===h){let t=(e=>{if(e&&!(e instanceof H.Z.File))return"Value must be a file"})(e);if(!t)return l;l.push(t)}return l}const we=function(e,t){let{isOAS3:n=!1,bypassRequiredCheck:s=!1}=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{},r=e.get("required"),{schema:a,parameterContentMediaType:o}=(0,X.Z)(e,{isOAS3:n});return Ee(t,a,r,s,o)},xe=()=>{let e={},t=H.Z.location.search;if(!t)return{};if(""!=t){let n=t.substr(1).split("&");for(let t in n)Object.prototype.hasOwnProperty.call(n,t)&&(t=n[t].split("="),e[decodeURIComponent(t[0])]=t[1]&&decodeURIComponent(t[1])||"")}return e},Ce=e=>{let t;return t=e instanceof ne?e:ne.from(e.toString(),"utf-8"),t.toString("base64")},je={operationsSorter:{alpha:(e,t)=>e.get("path").localeCompare(t.get("path")),method:(e,t)=>e.get("method").localeCompare(t.get("method"))},tagsSorter:{alpha:(e,t)=>e.localeCompare(t)}},be=e=>{let t=[];for(let n in e){let s=e[n];void 0!==s&&""!==s&&t.push([n,"=",encodeURIComponent(s).replace(/%20/g,"+")].join(""))}return t.join("&")},_e=(e,t,n)=>!!U()(n,(n=>z()(e[n],t[n])));function Ne(e){return"string"!=typeof e||""===e?"":(0,T.sanitizeUrl)(e)}function Oe(e){return!(!e||j()(e).call(e,"localhost")>=0||j()(e).call(e,"127.0.0.1")>=0||"none"===e)}function ke(e){if(!R().OrderedMap.isOrderedMap(e))return null;if(!e.size)return null;const t=_()(e).call(e,((e,t)=>O()(t).call(t,"2")&&h()(e.get("content")||{}).length>0)),n=e.get("default")||R().OrderedMap(),s=(n.get("content")||R().OrderedMap()).keySeq().toJS().length?n:null;return t||s}const Ae=e=>"string"==typeof e||e instanceof String?A()(e).call(e).replace(/\s/g,"%20"):"",Ie=e=>G()(Ae(e).replace(/%20/g,"_")),Pe=e=>m()(e).call(e,((e,t)=>/^x-/.test(t))),qe=e=>m()(e).call(e,((e,t)=>/^pattern|maxLength|minLength|maximum|minimum/.test(t)));function Re(e,t){var n;let s=arguments.length>2&&void 0!==arguments[2]?arguments[2]:()=>!0;if("object"!=typeof e||r()(e)||null===e||!t)return e;const a=v()({},e);return u()(n=h()(a)).call(n,(e=>{e===t&&s(a[e],e)?delete a[e]:a[e]=Re(a[e],t,s)})),a}function Te(e){if("string"==typeof e)return e;if(e&&e.toJS&&(e=e.toJS()),"object"==typeof e&&null!==e)try{return P()(e,null,2)}catch(t){return String(e)}return null==e?"":e.toString()}function Me(e){return"number"==typeof e?e.toString():e}function De(e){let{returnAll:t=!1,allowHashes:n=!0}=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};if(!R().Map.isMap(e))throw new Error("paramToIdentifier: received a non-Im.Map parameter as input");const s=e.get("name"),r=e.get("in");let a=[];return e&&e.hashCode&&r&&s&&n&&a.push(`${r}.${s}.hash-${e.hashCode()}`),r&&s&&a.push(`${r}.${s}`),a.push(s),t?a:a[0]||""}function Je(e,t){var n;const s=De(e,{returnAll:!0});return m()(n=o()(s).call(s,(e=>t[e]))).call(n,(e=>void 0!==e))[0]}function $e(){return Ke(Q()(32).toString("base64"))}function Le(e){return Ke(te()("sha256").update(e).digest("base64"))}function Ke(e){return e.replace(/\+/g,"-").replace(/\//g,"_").replace(/=/g,"")}const Ve=e=>!e||!(!re(e)||!e.isEmpty())},2518:(e,t,n)=>{"use strict";function s(e){return function(e){try{return!!JSON.parse(e)}catch(e){return null}}(e)?"json":null}n.d(t,{O:()=>s})},3543:(e,t,n)=>{"use strict";n.d(t,{mn:()=>l});var s=n(9478),r=n.n(s);function a(e){return e.match(/^(?:[a-z]+:)?\/\//i)}function o(e,t){return e?a(e)?(n=e).match(/^\/\//i)?`${window.location.protocol}${n}`:n:new(r())(e,t).href:t;var n}function l(e,t){let{selectedServer:n=""}=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{};try{return function(e,t){let{selectedServer:n=""}=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{};if(!e)return;if(a(e))return e;const s=o(n,t);return a(s)?new(r())(e,s).href:new(r())(e,window.location.href).href}(e,t,{selectedServer:n})}catch{return}}},7504:(e,t,n)=>{"use strict";n.d(t,{Z:()=>s});const s=function(){var e={location:{},history:{},open:()=>{},close:()=>{},File:function(){},FormData:function(){}};if("undefined"==typeof window)return e;try{e=window;for(var t of["File","Blob","FormData"])t in window&&(e[t]=window[t])}catch(e){console.error(e)}return e}()},9069:(e,t,n)=>{"use strict";n.d(t,{Z:()=>u});var s=n(9998),r=n.n(s),a=n(2605),o=n.n(a),l=n(5572),c=n.n(l);const i=c().Set.of("type","format","items","default","maximum","exclusiveMaximum","minimum","exclusiveMinimum","maxLength","minLength","pattern","maxItems","minItems","uniqueItems","enum","multipleOf");function u(e){let{isOAS3:t}=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};if(!c().Map.isMap(e))return{schema:c().Map(),parameterContentMediaType:null};if(!t)return"body"===e.get("in")?{schema:e.get("schema",c().Map()),parameterContentMediaType:null}:{schema:r()(e).call(e,((e,t)=>o()(i).call(i,t))),parameterContentMediaType:null};if(e.get("content")){const t=e.get("content",c().Map({})).keySeq().first();return{schema:e.getIn(["content",t,"schema"],c().Map()),parameterContentMediaType:t}}return{schema:e.get("schema")?e.get("schema",c().Map()):c().Map(),parameterContentMediaType:null}}},7481:(e,t,n)=>{"use strict";n.d(t,{Z:()=>x});var s=n(7104),r=n.n(s),a=n(7834),o=n.n(a),l=n(1733),c=n.n(l),i=n(874),u=n.n(i),p=n(3580),m=n.n(p);const d=require("@babel/runtime-corejs3/core-js-stable/instance/find-index");var h=n.n(d),g=n(2611),f=n.n(g),y=n(541),v=n.n(y);const S=e=>t=>r()(e)&&r()(t)&&e.length===t.length&&o()(e).call(e,((e,n)=>e===t[n])),E=function(){for(var e=arguments.length,t=new Array(e),n=0;n<e;n++)t[n]=arguments[n];return t};class w extends(f()){delete(e){const t=c()(u()(this).call(this)),n=m()(t).call(t,S(e));return super.delete(n)}get(e){const t=c()(u()(this).call(this)),n=m()(t).call(t,S(e));return super.get(n)}has(e){const t=c()(u()(this).call(this));return-1!==h()(t).call(t,S(e))}}const x=function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:E;const{Cache:n}=v();v().Cache=w;const s=v()(e,t);return v().Cache=n,s}},5102:(e,t,n)=>{var s={"./all.js":5308,"./auth/actions.js":5812,"./auth/components/lock-auth-icon.jsx":7105,"./auth/components/unlock-auth-icon.jsx":3219,"./auth/configs-extensions/wrap-actions.js":3779,"./auth/index.js":3705,"./auth/reducers.js":3962,"./auth/selectors.js":35,"./auth/spec-extensions/wrap-actions.js":489,"./auth/wrap-actions.js":2849,"./configs/actions.js":714,"./configs/helpers.js":2256,"./configs/index.js":6709,"./configs/reducers.js":7743,"./configs/selectors.js":9018,"./configs/spec-actions.js":2698,"./deep-linking/helpers.js":1970,"./deep-linking/index.js":4980,"./deep-linking/layout.js":2179,"./deep-linking/operation-tag-wrapper.jsx":4584,"./deep-linking/operation-wrapper.jsx":877,"./download-url.js":8011,"./err/actions.js":4966,"./err/error-transformers/hook.js":2860,"./err/error-transformers/transformers/not-of-type.js":2392,"./err/error-transformers/transformers/parameter-oneof.js":1835,"./err/index.js":7793,"./err/reducers.js":3527,"./err/selectors.js":7667,"./filter/index.js":9978,"./filter/opsFilter.js":4309,"./icons/components/arrow-down.jsx":6395,"./icons/components/arrow-up.jsx":9689,"./icons/components/arrow.jsx":6984,"./icons/components/close.jsx":2478,"./icons/components/copy.jsx":3388,"./icons/components/lock.jsx":6945,"./icons/components/unlock.jsx":2568,"./icons/index.js":70,"./json-schema-2020-12/components/Accordion/Accordion.jsx":7349,"./json-schema-2020-12/components/ExpandDeepButton/ExpandDeepButton.jsx":6867,"./json-schema-2020-12/components/JSONSchema/JSONSchema.jsx":2675,"./json-schema-2020-12/components/icons/ChevronRight.jsx":2260,"./json-schema-2020-12/components/keywords/$anchor.jsx":4922,"./json-schema-2020-12/components/keywords/$comment.jsx":4685,"./json-schema-2020-12/components/keywords/$defs.jsx":6418,"./json-schema-2020-12/components/keywords/$dynamicAnchor.jsx":1338,"./json-schema-2020-12/components/keywords/$dynamicRef.jsx":7655,"./json-schema-2020-12/components/keywords/$id.jsx":3460,"./json-schema-2020-12/components/keywords/$ref.jsx":2348,"./json-schema-2020-12/components/keywords/$schema.jsx":9359,"./json-schema-2020-12/components/keywords/$vocabulary/$vocabulary.jsx":7568,"./json-schema-2020-12/components/keywords/AdditionalProperties.jsx":5253,"./json-schema-2020-12/components/keywords/AllOf.jsx":6457,"./json-schema-2020-12/components/keywords/AnyOf.jsx":8776,"./json-schema-2020-12/components/keywords/Const.jsx":7308,"./json-schema-2020-12/components/keywords/Constraint/Constraint.jsx":9956,"./json-schema-2020-12/components/keywords/Contains.jsx":8993,"./json-schema-2020-12/components/keywords/ContentSchema.jsx":3484,"./json-schema-2020-12/components/keywords/Default.jsx":5148,"./json-schema-2020-12/components/keywords/DependentRequired/DependentRequired.jsx":4539,"./json-schema-2020-12/components/keywords/DependentSchemas.jsx":6076,"./json-schema-2020-12/components/keywords/Deprecated.jsx":6661,"./json-schema-2020-12/components/keywords/Description/Description.jsx":9446,"./json-schema-2020-12/components/keywords/Else.jsx":7207,"./json-schema-2020-12/components/keywords/Enum/Enum.jsx":1805,"./json-schema-2020-12/components/keywords/If.jsx":487,"./json-schema-2020-12/components/keywords/Items.jsx":9206,"./json-schema-2020-12/components/keywords/Not.jsx":5174,"./json-schema-2020-12/components/keywords/OneOf.jsx":3834,"./json-schema-2020-12/components/keywords/PatternProperties/PatternProperties.jsx":6746,"./json-schema-2020-12/components/keywords/PrefixItems.jsx":3971,"./json-schema-2020-12/components/keywords/Properties/Properties.jsx":5472,"./json-schema-2020-12/components/keywords/PropertyNames.jsx":2338,"./json-schema-2020-12/components/keywords/ReadOnly.jsx":6456,"./json-schema-2020-12/components/keywords/Then.jsx":7401,"./json-schema-2020-12/components/keywords/Title/Title.jsx":8137,"./json-schema-2020-12/components/keywords/Type.jsx":2285,"./json-schema-2020-12/components/keywords/UnevaluatedItems.jsx":5828,"./json-schema-2020-12/components/keywords/UnevaluatedProperties.jsx":6907,"./json-schema-2020-12/components/keywords/WriteOnly.jsx":5789,"./json-schema-2020-12/context.js":9006,"./json-schema-2020-12/fn.js":4121,"./json-schema-2020-12/hoc.jsx":5077,"./json-schema-2020-12/hooks.js":2603,"./json-schema-2020-12/index.js":7139,"./json-schema-2020-12/prop-types.js":6648,"./json-schema-2020-12/samples-extensions/fn/api/encoderAPI.js":9507,"./json-schema-2020-12/samples-extensions/fn/api/formatAPI.js":2906,"./json-schema-2020-12/samples-extensions/fn/api/mediaTypeAPI.js":537,"./json-schema-2020-12/samples-extensions/fn/class/EncoderRegistry.js":674,"./json-schema-2020-12/samples-extensions/fn/class/MediaTypeRegistry.js":3782,"./json-schema-2020-12/samples-extensions/fn/class/Registry.js":4215,"./json-schema-2020-12/samples-extensions/fn/core/constants.js":8338,"./json-schema-2020-12/samples-extensions/fn/core/example.js":3783,"./json-schema-2020-12/samples-extensions/fn/core/merge.js":7078,"./json-schema-2020-12/samples-extensions/fn/core/predicates.js":3084,"./json-schema-2020-12/samples-extensions/fn/core/random.js":5202,"./json-schema-2020-12/samples-extensions/fn/core/type.js":6276,"./json-schema-2020-12/samples-extensions/fn/core/utils.js":9346,"./json-schema-2020-12/samples-extensions/fn/encoders/7bit.js":1433,"./json-schema-2020-12/samples-extensions/fn/encoders/8bit.js":8509,"./json-schema-2020-12/samples-extensions/fn/encoders/base16.js":5709,"./json-schema-2020-12/samples-extensions/fn/encoders/base32.js":4180,"./json-schema-2020-12/samples-extensions/fn/encoders/base64.js":1967,"./json-schema-2020-12/samples-extensions/fn/encoders/binary.js":4366,"./json-schema-2020-12/samples-extensions/fn/encoders/quoted-printable.js":5037,"./json-schema-2020-12/samples-extensions/fn/generators/date-time.js":4045,"./json-schema-2020-12/samples-extensions/fn/generators/date.js":1456,"./json-schema-2020-12/samples-extensions/fn/generators/double.js":560,"./json-schema-2020-12/samples-extensions/fn/generators/duration.js":4299,"./json-schema-2020-12/samples-extensions/fn/generators/email.js":3981,"./json-schema-2020-12/samples-extensions/fn/generators/float.js":1890,"./json-schema-2020-12/samples-extensions/fn/generators/hostname.js":9375,"./json-schema-2020-12/samples-extensions/fn/generators/idn-email.js":4518,"./json-schema-2020-12/samples-extensions/fn/generators/idn-hostname.js":273,"./json-schema-2020-12/samples-extensions/fn/generators/int32.js":7864,"./json-schema-2020-12/samples-extensions/fn/generators/int64.js":1726,"./json-schema-2020-12/samples-extensions/fn/generators/ipv4.js":8793,"./json-schema-2020-12/samples-extensions/fn/generators/ipv6.js":8269,"./json-schema-2020-12/samples-extensions/fn/generators/iri-reference.js":5693,"./json-schema-2020-12/samples-extensions/fn/generators/iri.js":3080,"./json-schema-2020-12/samples-extensions/fn/generators/json-pointer.js":7856,"./json-schema-2020-12/samples-extensions/fn/generators/media-types/application.js":5088,"./json-schema-2020-12/samples-extensions/fn/generators/media-types/audio.js":4342,"./json-schema-2020-12/samples-extensions/fn/generators/media-types/image.js":6724,"./json-schema-2020-12/samples-extensions/fn/generators/media-types/text.js":5378,"./json-schema-2020-12/samples-extensions/fn/generators/media-types/video.js":2974,"./json-schema-2020-12/samples-extensions/fn/generators/password.js":3393,"./json-schema-2020-12/samples-extensions/fn/generators/regex.js":4335,"./json-schema-2020-12/samples-extensions/fn/generators/relative-json-pointer.js":375,"./json-schema-2020-12/samples-extensions/fn/generators/time.js":5243,"./json-schema-2020-12/samples-extensions/fn/generators/uri-reference.js":4692,"./json-schema-2020-12/samples-extensions/fn/generators/uri-template.js":3829,"./json-schema-2020-12/samples-extensions/fn/generators/uri.js":2978,"./json-schema-2020-12/samples-extensions/fn/generators/uuid.js":8859,"./json-schema-2020-12/samples-extensions/fn/index.js":8591,"./json-schema-2020-12/samples-extensions/fn/main.js":4277,"./json-schema-2020-12/samples-extensions/fn/types/array.js":3982,"./json-schema-2020-12/samples-extensions/fn/types/boolean.js":4108,"./json-schema-2020-12/samples-extensions/fn/types/index.js":3273,"./json-schema-2020-12/samples-extensions/fn/types/integer.js":8864,"./json-schema-2020-12/samples-extensions/fn/types/null.js":853,"./json-schema-2020-12/samples-extensions/fn/types/number.js":844,"./json-schema-2020-12/samples-extensions/fn/types/object.js":6852,"./json-schema-2020-12/samples-extensions/fn/types/string.js":4522,"./layout/actions.js":5474,"./layout/index.js":6821,"./layout/reducers.js":5672,"./layout/selectors.js":4400,"./layout/spec-extensions/wrap-selector.js":8989,"./logs/index.js":9150,"./oas3/actions.js":7002,"./oas3/auth-extensions/wrap-selectors.js":3723,"./oas3/components/callbacks.jsx":3427,"./oas3/components/http-auth.jsx":6775,"./oas3/components/index.js":6467,"./oas3/components/operation-link.jsx":5757,"./oas3/components/operation-servers.jsx":6796,"./oas3/components/request-body-editor.jsx":5327,"./oas3/components/request-body.jsx":2458,"./oas3/components/servers-container.jsx":9928,"./oas3/components/servers.jsx":6617,"./oas3/helpers.jsx":7779,"./oas3/index.js":7451,"./oas3/reducers.js":2109,"./oas3/selectors.js":5065,"./oas3/spec-extensions/selectors.js":1741,"./oas3/spec-extensions/wrap-selectors.js":2044,"./oas3/wrap-components/auth-item.jsx":356,"./oas3/wrap-components/index.js":7761,"./oas3/wrap-components/json-schema-string.jsx":287,"./oas3/wrap-components/markdown.jsx":2460,"./oas3/wrap-components/model.jsx":3499,"./oas3/wrap-components/online-validator-badge.js":58,"./oas3/wrap-components/version-stamp.jsx":9487,"./oas31/after-load.js":2372,"./oas31/components/contact.jsx":9503,"./oas31/components/info.jsx":6133,"./oas31/components/json-schema-dialect.jsx":2562,"./oas31/components/license.jsx":1876,"./oas31/components/model/model.jsx":2718,"./oas31/components/models/models.jsx":263,"./oas31/components/version-pragma-filter.jsx":3429,"./oas31/components/webhooks.jsx":9508,"./oas31/fn.js":4380,"./oas31/index.js":9806,"./oas31/json-schema-2020-12-extensions/components/keywords/Description.jsx":5989,"./oas31/json-schema-2020-12-extensions/components/keywords/Discriminator/Discriminator.jsx":9525,"./oas31/json-schema-2020-12-extensions/components/keywords/Discriminator/DiscriminatorMapping.jsx":7749,"./oas31/json-schema-2020-12-extensions/components/keywords/Example.jsx":9450,"./oas31/json-schema-2020-12-extensions/components/keywords/ExternalDocs.jsx":5324,"./oas31/json-schema-2020-12-extensions/components/keywords/Properties.jsx":9023,"./oas31/json-schema-2020-12-extensions/components/keywords/Xml.jsx":3995,"./oas31/json-schema-2020-12-extensions/fn.js":5800,"./oas31/json-schema-2020-12-extensions/wrap-components/keywords/Default.jsx":4951,"./oas31/json-schema-2020-12-extensions/wrap-components/keywords/Description.jsx":809,"./oas31/json-schema-2020-12-extensions/wrap-components/keywords/Properties.jsx":7536,"./oas31/selectors.js":4280,"./oas31/spec-extensions/selectors.js":9305,"./oas31/spec-extensions/wrap-selectors.js":2884,"./oas31/wrap-components/contact.jsx":7423,"./oas31/wrap-components/info.jsx":284,"./oas31/wrap-components/license.jsx":6608,"./oas31/wrap-components/model.jsx":7042,"./oas31/wrap-components/models.jsx":2914,"./oas31/wrap-components/version-pragma-filter.jsx":1434,"./oas31/wrap-components/version-stamp.jsx":1122,"./on-complete/index.js":8560,"./request-snippets/fn.js":8223,"./request-snippets/index.js":6575,"./request-snippets/request-snippets.jsx":4206,"./request-snippets/selectors.js":4669,"./safe-render/components/error-boundary.jsx":6195,"./safe-render/components/fallback.jsx":9403,"./safe-render/fn.jsx":6189,"./safe-render/index.js":9595,"./samples/fn/get-json-sample-schema.js":2846,"./samples/fn/get-sample-schema.js":6132,"./samples/fn/get-xml-sample-schema.js":1169,"./samples/fn/get-yaml-sample-schema.js":9431,"./samples/fn/index.js":9812,"./samples/index.js":8883,"./spec/actions.js":7080,"./spec/index.js":7038,"./spec/reducers.js":32,"./spec/selectors.js":3881,"./spec/wrap-actions.js":7508,"./swagger-js/configs-wrap-actions.js":4852,"./swagger-js/index.js":1241,"./util/index.js":8525,"./view/fn.js":8347,"./view/index.js":3420,"./view/root-injects.jsx":950,"core/plugins/all.js":5308,"core/plugins/auth/actions.js":5812,"core/plugins/auth/components/lock-auth-icon.jsx":7105,"core/plugins/auth/components/unlock-auth-icon.jsx":3219,"core/plugins/auth/configs-extensions/wrap-actions.js":3779,"core/plugins/auth/index.js":3705,"core/plugins/auth/reducers.js":3962,"core/plugins/auth/selectors.js":35,"core/plugins/auth/spec-extensions/wrap-actions.js":489,"core/plugins/auth/wrap-actions.js":2849,"core/plugins/configs/actions.js":714,"core/plugins/configs/helpers.js":2256,"core/plugins/configs/index.js":6709,"core/plugins/configs/reducers.js":7743,"core/plugins/configs/selectors.js":9018,"core/plugins/configs/spec-actions.js":2698,"core/plugins/deep-linking/helpers.js":1970,"core/plugins/deep-linking/index.js":4980,"core/plugins/deep-linking/layout.js":2179,"core/plugins/deep-linking/operation-tag-wrapper.jsx":4584,"core/plugins/deep-linking/operation-wrapper.jsx":877,"core/plugins/download-url.js":8011,"core/plugins/err/actions.js":4966,"core/plugins/err/error-transformers/hook.js":2860,"core/plugins/err/error-transformers/transformers/not-of-type.js":2392,"core/plugins/err/error-transformers/transformers/parameter-oneof.js":1835,"core/plugins/err/index.js":7793,"core/plugins/err/reducers.js":3527,"core/plugins/err/selectors.js":7667,"core/plugins/filter/index.js":9978,"core/plugins/filter/opsFilter.js":4309,"core/plugins/icons/components/arrow-down.jsx":6395,"core/plugins/icons/components/arrow-up.jsx":9689,"core/plugins/icons/components/arrow.jsx":6984,"core/plugins/icons/components/close.jsx":2478,"core/plugins/icons/components/copy.jsx":3388,"core/plugins/icons/components/lock.jsx":6945,"core/plugins/icons/components/unlock.jsx":2568,"core/plugins/icons/index.js":70,"core/plugins/json-schema-2020-12/components/Accordion/Accordion.jsx":7349,"core/plugins/json-schema-2020-12/components/ExpandDeepButton/ExpandDeepButton.jsx":6867,"core/plugins/json-schema-2020-12/components/JSONSchema/JSONSchema.jsx":2675,"core/plugins/json-schema-2020-12/components/icons/ChevronRight.jsx":2260,"core/plugins/json-schema-2020-12/components/keywords/$anchor.jsx":4922,"core/plugins/json-schema-2020-12/components/keywords/$comment.jsx":4685,"core/plugins/json-schema-2020-12/components/keywords/$defs.jsx":6418,"core/plugins/json-schema-2020-12/components/keywords/$dynamicAnchor.jsx":1338,"core/plugins/json-schema-2020-12/components/keywords/$dynamicRef.jsx":7655,"core/plugins/json-schema-2020-12/components/keywords/$id.jsx":3460,"core/plugins/json-schema-2020-12/components/keywords/$ref.jsx":2348,"core/plugins/json-schema-2020-12/components/keywords/$schema.jsx":9359,"core/plugins/json-schema-2020-12/components/keywords/$vocabulary/$vocabulary.jsx":7568,"core/plugins/json-schema-2020-12/components/keywords/AdditionalProperties.jsx":5253,"core/plugins/json-schema-2020-12/components/keywords/AllOf.jsx":6457,"core/plugins/json-schema-2020-12/components/keywords/AnyOf.jsx":8776,"core/plugins/json-schema-2020-12/components/keywords/Const.jsx":7308,"core/plugins/json-schema-2020-12/components/keywords/Constraint/Constraint.jsx":9956,"core/plugins/json-schema-2020-12/components/keywords/Contains.jsx":8993,"core/plugins/json-schema-2020-12/components/keywords/ContentSchema.jsx":3484,"core/plugins/json-schema-2020-12/components/keywords/Default.jsx":5148,"core/plugins/json-schema-2020-12/components/keywords/DependentRequired/DependentRequired.jsx":4539,"core/plugins/json-schema-2020-12/components/keywords/DependentSchemas.jsx":6076,"core/plugins/json-schema-2020-12/components/keywords/Deprecated.jsx":6661,"core/plugins/json-schema-2020-12/components/keywords/Description/Description.jsx":9446,"core/plugins/json-schema-2020-12/components/keywords/Else.jsx":7207,"core/plugins/json-schema-2020-12/components/keywords/Enum/Enum.jsx":1805,"core/plugins/json-schema-2020-12/components/keywords/If.jsx":487,"core/plugins/json-schema-2020-12/components/keywords/Items.jsx":9206,"core/plugins/json-schema-2020-12/components/keywords/Not.jsx":5174,"core/plugins/json-schema-2020-12/components/keywords/OneOf.jsx":3834,"core/plugins/json-schema-2020-12/components/keywords/PatternProperties/PatternProperties.jsx":6746,"core/plugins/json-schema-2020-12/components/keywords/PrefixItems.jsx":3971,"core/plugins/json-schema-2020-12/components/keywords/Properties/Properties.jsx":5472,"core/plugins/json-schema-2020-12/components/keywords/PropertyNames.jsx":2338,"core/plugins/json-schema-2020-12/components/keywords/ReadOnly.jsx":6456,"core/plugins/json-schema-2020-12/components/keywords/Then.jsx":7401,"core/plugins/json-schema-2020-12/components/keywords/Title/Title.jsx":8137,"core/plugins/json-schema-2020-12/components/keywords/Type.jsx":2285,"core/plugins/json-schema-2020-12/components/keywords/UnevaluatedItems.jsx":5828,"core/plugins/json-schema-2020-12/components/keywords/UnevaluatedProperties.jsx":6907,"core/plugins/json-schema-2020-12/components/keywords/WriteOnly.jsx":5789,"core/plugins/json-schema-2020-12/context.js":9006,"core/plugins/json-schema-2020-12/fn.js":4121,"core/plugins/json-schema-2020-12/hoc.jsx":5077,"core/plugins/json-schema-2020-12/hooks.js":2603,"core/plugins/json-schema-2020-12/index.js":7139,"core/plugins/json-schema-2020-12/prop-types.js":6648,"core/plugins/json-schema-2020-12/samples-extensions/fn/api/encoderAPI.js":9507,"core/plugins/json-schema-2020-12/samples-extensions/fn/api/formatAPI.js":2906,"core/plugins/json-schema-2020-12/samples-extensions/fn/api/mediaTypeAPI.js":537,"core/plugins/json-schema-2020-12/samples-extensions/fn/class/EncoderRegistry.js":674,"core/plugins/json-schema-2020-12/samples-extensions/fn/class/MediaTypeRegistry.js":3782,"core/plugins/json-schema-2020-12/samples-extensions/fn/class/Registry.js":4215,"core/plugins/json-schema-2020-12/samples-extensions/fn/core/constants.js":8338,"core/plugins/json-schema-2020-12/samples-extensions/fn/core/example.js":3783,"core/plugins/json-schema-2020-12/samples-extensions/fn/core/merge.js":7078,"core/plugins/json-schema-2020-12/samples-extensions/fn/core/predicates.js":3084,"core/plugins/json-schema-2020-12/samples-extensions/fn/core/random.js":5202,"core/plugins/json-schema-2020-12/samples-extensions/fn/core/type.js":6276,"core/plugins/json-schema-2020-12/samples-extensions/fn/core/utils.js":9346,"core/plugins/json-schema-2020-12/samples-extensions/fn/encoders/7bit.js":1433,"core/plugins/json-schema-2020-12/samples-extensions/fn/encoders/8bit.js":8509,"core/plugins/json-schema-2020-12/samples-extensions/fn/encoders/base16.js":5709,"core/plugins/json-schema-2020-12/samples-extensions/fn/encoders/base32.js":4180,"core/plugins/json-schema-2020-12/samples-extensions/fn/encoders/base64.js":1967,"core/plugins/json-schema-2020-12/samples-extensions/fn/encoders/binary.js":4366,"core/plugins/json-schema-2020-12/samples-extensions/fn/encoders/quoted-printable.js":5037,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/date-time.js":4045,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/date.js":1456,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/double.js":560,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/duration.js":4299,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/email.js":3981,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/float.js":1890,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/hostname.js":9375,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/idn-email.js":4518,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/idn-hostname.js":273,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/int32.js":7864,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/int64.js":1726,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/ipv4.js":8793,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/ipv6.js":8269,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/iri-reference.js":5693,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/iri.js":3080,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/json-pointer.js":7856,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/media-types/application.js":5088,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/media-types/audio.js":4342,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/media-types/image.js":6724,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/media-types/text.js":5378,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/media-types/video.js":2974,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/password.js":3393,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/regex.js":4335,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/relative-json-pointer.js":375,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/time.js":5243,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/uri-reference.js":4692,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/uri-template.js":3829,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/uri.js":2978,"core/plugins/json-schema-2020-12/samples-extensions/fn/generators/uuid.js":8859,"core/plugins/json-schema-2020-12/samples-extensions/fn/index.js":8591,"core/plugins/json-schema-2020-12/samples-extensions/fn/main.js":4277,"core/plugins/json-schema-2020-12/samples-extensions/fn/types/array.js":3982,"core/plugins/json-schema-2020-12/samples-extensions/fn/types/boolean.js":4108,"core/plugins/json-schema-2020-12/samples-extensions/fn/types/index.js":3273,"core/plugins/json-schema-2020-12/samples-extensions/fn/types/integer.js":8864,"core/plugins/json-schema-2020-12/samples-extensions/fn/types/null.js":853,"core/plugins/json-schema-2020-12/samples-extensions/fn/types/number.js":844,"core/plugins/json-schema-2020-12/samples-extensions/fn/types/object.js":6852,"core/plugins/json-schema-2020-12/samples-extensions/fn/types/string.js":4522,"core/plugins/layout/actions.js":5474,"core/plugins/layout/index.js":6821,"core/plugins/layout/reducers.js":5672,"core/plugins/layout/selectors.js":4400,"core/plugins/layout/spec-extensions/wrap-selector.js":8989,"core/plugins/logs/index.js":9150,"core/plugins/oas3/actions.js":7002,"core/plugins/oas3/auth-extensions/wrap-selectors.js":3723,"core/plugins/oas3/components/callbacks.jsx":3427,"core/plugins/oas3/components/http-auth.jsx":6775,"core/plugins/oas3/components/index.js":6467,"core/plugins/oas3/components/operation-link.jsx":5757,"core/plugins/oas3/components/operation-servers.jsx":6796,"core/plugins/oas3/components/request-body-editor.jsx":5327,"core/plugins/oas3/components/request-body.jsx":2458,"core/plugins/oas3/components/servers-container.jsx":9928,"core/plugins/oas3/components/servers.jsx":6617,"core/plugins/oas3/helpers.jsx":7779,"core/plugins/oas3/index.js":7451,"core/plugins/oas3/reducers.js":2109,"core/plugins/oas3/selectors.js":5065,"core/plugins/oas3/spec-extensions/selectors.js":1741,"core/plugins/oas3/spec-extensions/wrap-selectors.js":2044,"core/plugins/oas3/wrap-components/auth-item.jsx":356,"core/plugins/oas3/wrap-components/index.js":7761,"core/plugins/oas3/wrap-components/json-schema-string.jsx":287,"core/plugins/oas3/wrap-components/markdown.jsx":2460,"core/plugins/oas3/wrap-components/model.jsx":3499,"core/plugins/oas3/wrap-components/online-validator-badge.js":58,"core/plugins/oas3/wrap-components/version-stamp.jsx":9487,"core/plugins/oas31/after-load.js":2372,"core/plugins/oas31/components/contact.jsx":9503,"core/plugins/oas31/components/info.jsx":6133,"core/plugins/oas31/components/json-schema-dialect.jsx":2562,"core/plugins/oas31/components/license.jsx":1876,"core/plugins/oas31/components/model/model.jsx":2718,"core/plugins/oas31/components/models/models.jsx":263,"core/plugins/oas31/components/version-pragma-filter.jsx":3429,"core/plugins/oas31/components/webhooks.jsx":9508,"core/plugins/oas31/fn.js":4380,"core/plugins/oas31/index.js":9806,"core/plugins/oas31/json-schema-2020-12-extensions/components/keywords/Description.jsx":5989,"core/plugins/oas31/json-schema-2020-12-extensions/components/keywords/Discriminator/Discriminator.jsx":9525,"core/plugins/oas31/json-schema-2020-12-extensions/components/keywords/Discriminator/DiscriminatorMapping.jsx":7749,"core/plugins/oas31/json-schema-2020-12-extensions/components/keywords/Example.jsx":9450,"core/plugins/oas31/json-schema-2020-12-extensions/components/keywords/ExternalDocs.jsx":5324,"core/plugins/oas31/json-schema-2020-12-extensions/components/keywords/Properties.jsx":9023,"core/plugins/oas31/json-schema-2020-12-extensions/components/keywords/Xml.jsx":3995,"core/plugins/oas31/json-schema-2020-12-extensions/fn.js":5800,"core/plugins/oas31/json-schema-2020-12-extensions/wrap-components/keywords/Default.jsx":4951,"core/plugins/oas31/json-schema-2020-12-extensions/wrap-components/keywords/Description.jsx":809,"core/plugins/oas31/json-schema-2020-12-extensions/wrap-components/keywords/Properties.jsx":7536,"core/plugins/oas31/selectors.js":4280,"core/plugins/oas31/spec-extensions/selectors.js":9305,"core/plugins/oas31/spec-extensions/wrap-selectors.js":2884,"core/plugins/oas31/wrap-components/contact.jsx":7423,"core/plugins/oas31/wrap-components/info.jsx":284,"core/plugins/oas31/wrap-components/license.jsx":6608,"core/plugins/oas31/wrap-components/model.jsx":7042,"core/plugins/oas31/wrap-components/models.jsx":2914,"core/plugins/oas31/wrap-components/version-pragma-filter.jsx":1434,"core/plugins/oas31/wrap-components/version-stamp.jsx":1122,"core/plugins/on-complete/index.js":8560,"core/plugins/request-snippets/fn.js":8223,"core/plugins/request-snippets/index.js":6575,"core/plugins/request-snippets/request-snippets.jsx":4206,"core/plugins/request-snippets/selectors.js":4669,"core/plugins/safe-render/components/error-boundary.jsx":6195,"core/plugins/safe-render/components/fallback.jsx":9403,"core/plugins/safe-render/fn.jsx":6189,"core/plugins/safe-render/index.js":9595,"core/plugins/samples/fn/get-json-sample-schema.js":2846,"core/plugins/samples/fn/get-sample-schema.js":6132,"core/plugins/samples/fn/get-xml-sample-schema.js":1169,"core/plugins/samples/fn/get-yaml-sample-schema.js":9431,"core/plugins/samples/fn/index.js":9812,"core/plugins/samples/index.js":8883,"core/plugins/spec/actions.js":7080,"core/plugins/spec/index.js":7038,"core/plugins/spec/reducers.js":32,"core/plugins/spec/selectors.js":3881,"core/plugins/spec/wrap-actions.js":7508,"core/plugins/swagger-js/configs-wrap-actions.js":4852,"core/plugins/swagger-js/index.js":1241,"core/plugins/util/index.js":8525,"core/plugins/view/fn.js":8347,"core/plugins/view/index.js":3420,"core/plugins/view/root-injects.jsx":950};function r(e){var t=a(e);return n(t)}function a(e){if(!n.o(s,e)){var t=new Error("Cannot find module '"+e+"'");throw t.code="MODULE_NOT_FOUND",t}return s[e]}r.keys=function(){return Object.keys(s)},r.resolve=a,e.exports=r,r.id=5102},2517:e=>{"use strict";e.exports="data:image/svg+xml;base64,PHN2ZyB3aWR0aD0iMjAwcHgiICBoZWlnaHQ9IjIwMHB4IiAgeG1sbnM9Imh0dHA6Ly93d3cudzMub3JnLzIwMDAvc3ZnIiB2aWV3Qm94PSIwIDAgMTAwIDEwMCIgcHJlc2VydmVBc3BlY3RSYXRpbz0ieE1pZFlNaWQiIGNsYXNzPSJsZHMtcm9sbGluZyIgc3R5bGU9ImJhY2tncm91bmQtaW1hZ2U6IG5vbmU7IGJhY2tncm91bmQtcG9zaXRpb246IGluaXRpYWwgaW5pdGlhbDsgYmFja2dyb3VuZC1yZXBlYXQ6IGluaXRpYWwgaW5pdGlhbDsiPjxjaXJjbGUgY3g9IjUwIiBjeT0iNTAiIGZpbGw9Im5vbmUiIG5nLWF0dHItc3Ryb2tlPSJ7e2NvbmZpZy5jb2xvcn19IiBuZy1hdHRyLXN0cm9rZS13aWR0aD0ie3tjb25maWcud2lkdGh9fSIgbmctYXR0ci1yPSJ7e2NvbmZpZy5yYWRpdXN9fSIgbmctYXR0ci1zdHJva2UtZGFzaGFycmF5PSJ7e2NvbmZpZy5kYXNoYXJyYXl9fSIgc3Ryb2tlPSIjNTU1NTU1IiBzdHJva2Utd2lkdGg9IjEwIiByPSIzNSIgc3Ryb2tlLWRhc2hhcnJheT0iMTY0LjkzMzYxNDMxMzQ2NDE1IDU2Ljk3Nzg3MTQzNzgyMTM4Ij48YW5pbWF0ZVRyYW5zZm9ybSBhdHRyaWJ1dGVOYW1lPSJ0cmFuc2Zvcm0iIHR5cGU9InJvdGF0ZSIgY2FsY01vZGU9ImxpbmVhciIgdmFsdWVzPSIwIDUwIDUwOzM2MCA1MCA1MCIga2V5VGltZXM9IjA7MSIgZHVyPSIxcyIgYmVnaW49IjBzIiByZXBlYXRDb3VudD0iaW5kZWZpbml0ZSI+PC9hbmltYXRlVHJhbnNmb3JtPjwvY2lyY2xlPjwvc3ZnPgo="},1733:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/array/from")},7104:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/array/is-array")},593:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/instance/bind")},4883:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/instance/concat")},7862:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/instance/entries")},7834:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/instance/every")},9998:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/instance/filter")},3580:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/instance/find")},4235:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/instance/for-each")},2605:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/instance/includes")},8493:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/instance/index-of")},874:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/instance/keys")},3942:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/instance/map")},66:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/instance/reduce")},600:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/instance/slice")},5626:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/instance/some")},9247:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/instance/sort")},7390:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/instance/trim")},8344:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/json/stringify")},2611:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/map")},4901:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/number/is-integer")},4994:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/object/assign")},1815:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/object/entries")},3015:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/object/from-entries")},7252:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/object/keys")},9968:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/object/values")},7885:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/set")},9300:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/set-timeout")},9478:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/url")},6543:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/weak-map")},6680:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/core-js-stable/weak-set")},6272:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/helpers/classPrivateFieldGet")},1093:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/helpers/defineProperty")},4250:e=>{"use strict";e.exports=require("@babel/runtime-corejs3/helpers/extends")},871:e=>{"use strict";e.exports=require("buffer")},9003:e=>{"use strict";e.exports=require("classnames")},5572:e=>{"use strict";e.exports=require("immutable")},9793:e=>{"use strict";e.exports=require("js-yaml")},1712:e=>{"use strict";e.exports=require("lodash/get")},4292:e=>{"use strict";e.exports=require("lodash/identity")},9699:e=>{"use strict";e.exports=require("lodash/isEmpty")},5716:e=>{"use strict";e.exports=require("lodash/isFunction")},5452:e=>{"use strict";e.exports=require("lodash/isPlainObject")},541:e=>{"use strict";e.exports=require("lodash/memoize")},3901:e=>{"use strict";e.exports=require("lodash/omit")},4129:e=>{"use strict";e.exports=require("lodash/some")},580:e=>{"use strict";e.exports=require("prop-types")},9989:e=>{"use strict";e.exports=require("randexp")},185:e=>{"use strict";e.exports=require("randombytes")},6689:e=>{"use strict";e.exports=require("react")},2807:e=>{"use strict";e.exports=require("react-copy-to-clipboard")},8082:e=>{"use strict";e.exports=require("react-immutable-proptypes")},6695:e=>{"use strict";e.exports=require("redux")},963:e=>{"use strict";e.exports=require("remarkable")},6814:e=>{"use strict";e.exports=require("reselect")},41:e=>{"use strict";e.exports=require("serialize-error")},6765:e=>{"use strict";e.exports=require("swagger-client/es/helpers")},3883:e=>{"use strict";e.exports=require("url-parse")},8920:e=>{"use strict";e.exports=require("xml")}},t={};function n(s){var r=t[s];if(void 0!==r)return r.exports;var a=t[s]={exports:{}};return e[s](a,a.exports,n),a.exports}n.n=e=>{var t=e&&e.__esModule?()=>e.default:()=>e;return n.d(t,{a:t}),t},n.d=(e,t)=>{for(var s in t)n.o(t,s)&&!n.o(e,s)&&Object.defineProperty(e,s,{enumerable:!0,get:t[s]})},n.o=(e,t)=>Object.prototype.hasOwnProperty.call(e,t),n.r=e=>{"undefined"!=typeof Symbol&&Symbol.toStringTag&&Object.defineProperty(e,Symbol.toStringTag,{value:"Module"}),Object.defineProperty(e,"__esModule",{value:!0})};var s={};return(()=>{"use strict";n.d(s,{default:()=>xs});var e={};n.r(e),n.d(e,{Button:()=>sn,Col:()=>tn,Collapse:()=>un,Container:()=>Qt,Input:()=>an,Link:()=>ln,Row:()=>nn,Select:()=>on,TextArea:()=>rn});var t={};n.r(t),n.d(t,{JsonSchemaArrayItemFile:()=>os,JsonSchemaArrayItemText:()=>as,JsonSchemaForm:()=>ns,JsonSchema_array:()=>rs,JsonSchema_boolean:()=>ls,JsonSchema_object:()=>is,JsonSchema_string:()=>ss});const r=require("@babel/runtime-corejs3/core-js-stable/instance/last-index-of");var a=n.n(r),o=n(9998),l=n.n(o),c=n(7252),i=n.n(c),u=n(8344),p=n.n(u);const m=require("deep-extend");var d=n.n(m),h=n(593),g=n.n(h),f=n(4994),y=n.n(f),v=n(600),S=n.n(v),E=n(7104),w=n.n(E),x=n(66),C=n.n(x),j=n(3942),b=n.n(j),_=n(4883),N=n.n(_),O=n(6689),k=n.n(O),A=n(6695),I=n(5572),P=n.n(I);const q=require("redux-immutable");var R=n(41);const T=require("lodash/merge");var M=n.n(T),D=n(4966),J=n(7504),$=n(1669);const L=e=>e;class K{constructor(){var e;let t=arguments.length>0&&void 0!==arguments[0]?arguments[0]:{};var n,s,r;d()(this,{state:{},plugins:[],pluginsOptions:{},system:{configs:{},fn:{},components:{},rootInjects:{},statePlugins:{}},boundSystem:{},toolbox:{}},t),this.getSystem=g()(e=this._getSystem).call(e,this),this.store=(n=L,s=(0,I.fromJS)(this.state),r=this.getSystem,function(e,t,n){let s=[(0,$._5)(n)];const r=J.Z.__REDUX_DEVTOOLS_EXTENSION_COMPOSE__||A.compose;return(0,A.createStore)(e,t,r((0,A.applyMiddleware)(...s)))}(n,s,r)),this.buildSystem(!1),this.register(this.plugins)}getStore(){return this.store}register(e){let t=!(arguments.length>1&&void 0!==arguments[1])||arguments[1];var n=V(e,this.getSystem(),this.pluginsOptions);F(this.system,n),t&&this.buildSystem();U.call(this.system,e,this.getSystem())&&this.buildSystem()}buildSystem(){let e=!(arguments.length>0&&void 0!==arguments[0])||arguments[0],t=this.getStore().dispatch,n=this.getStore().getState;this.boundSystem=y()({},this.getRootInjects(),this.getWrappedAndBoundActions(t),this.getWrappedAndBoundSelectors(n,this.getSystem),this.getStateThunks(n),this.getFn(),this.getConfigs()),e&&this.rebuildReducer()}_getSystem(){return this.boundSystem}getRootInjects(){var e,t,n;return y()({getSystem:this.getSystem,getStore:g()(e=this.getStore).call(e,this),getComponents:g()(t=this.getComponents).call(t,this),getState:this.getStore().getState,getConfigs:g()(n=this._getConfigs).call(n,this),Im:P(),React:k()},this.system.rootInjects||{})}_getConfigs(){return this.system.configs}getConfigs(){return{configs:this.system.configs}}setConfigs(e){this.system.configs=e}rebuildReducer(){var e;this.store.replaceReducer((e=this.system.statePlugins,function(e){var t;let n=C()(t=i()(e)).call(t,((t,n)=>(t[n]=function(e){return function(){let t=arguments.length>0&&void 0!==arguments[0]?arguments[0]:new I.Map,n=arguments.length>1?arguments[1]:void 0;if(!e)return t;let s=e[n.type];if(s){const e=z(s)(t,n);return null===e?t:e}return t}}(e[n]),t)),{});return i()(n).length?(0,q.combineReducers)(n):L}((0,$.Ay)(e,(e=>e.reducers)))))}getType(e){let t=e[0].toUpperCase()+S()(e).call(e,1);return(0,$.Q2)(this.system.statePlugins,((n,s)=>{let r=n[e];if(r)return{[s+t]:r}}))}getSelectors(){return this.getType("selectors")}getActions(){let e=this.getType("actions");return(0,$.Ay)(e,(e=>(0,$.Q2)(e,((e,t)=>{if((0,$.LQ)(e))return{[t]:e}}))))}getWrappedAndBoundActions(e){var t=this;let n=this.getBoundActions(e);return(0,$.Ay)(n,((e,n)=>{let s=this.system.statePlugins[S()(n).call(n,0,-7)].wrapActions;return s?(0,$.Ay)(e,((e,n)=>{let r=s[n];return r?(w()(r)||(r=[r]),C()(r).call(r,((e,n)=>{let s=function(){return n(e,t.getSystem())(...arguments)};if(!(0,$.LQ)(s))throw new TypeError("wrapActions needs to return a function that returns a new function (ie the wrapped action)");return z(s)}),e||Function.prototype)):e})):e}))}getWrappedAndBoundSelectors(e,t){var n=this;let s=this.getBoundSelectors(e,t);return(0,$.Ay)(s,((t,s)=>{let r=[S()(s).call(s,0,-9)],a=this.system.statePlugins[r].wrapSelectors;return a?(0,$.Ay)(t,((t,s)=>{let o=a[s];return o?(w()(o)||(o=[o]),C()(o).call(o,((t,s)=>{let a=function(){for(var a=arguments.length,o=new Array(a),l=0;l<a;l++)o[l]=arguments[l];return s(t,n.getSystem())(e().getIn(r),...o)};if(!(0,$.LQ)(a))throw new TypeError("wrapSelector needs to return a function that returns a new function (ie the wrapped action)");return a}),t||Function.prototype)):t})):t}))}getStates(e){var t;return C()(t=i()(this.system.statePlugins)).call(t,((t,n)=>(t[n]=e.get(n),t)),{})}getStateThunks(e){var t;return C()(t=i()(this.system.statePlugins)).call(t,((t,n)=>(t[n]=()=>e().get(n),t)),{})}getFn(){return{fn:this.system.fn}}getComponents(e){const t=this.system.components[e];return w()(t)?C()(t).call(t,((e,t)=>t(e,this.getSystem()))):void 0!==e?this.system.components[e]:this.system.components}getBoundSelectors(e,t){return(0,$.Ay)(this.getSelectors(),((n,s)=>{let r=[S()(s).call(s,0,-9)];return(0,$.Ay)(n,(n=>function(){for(var s=arguments.length,a=new Array(s),o=0;o<s;o++)a[o]=arguments[o];let l=z(n).apply(null,[e().getIn(r),...a]);return"function"==typeof l&&(l=z(l)(t())),l}))}))}getBoundActions(e){e=e||this.getStore().dispatch;const t=this.getActions(),n=e=>"function"!=typeof e?(0,$.Ay)(e,(e=>n(e))):function(){var t=null;try{t=e(...arguments)}catch(e){t={type:D.NEW_THROWN_ERR,error:!0,payload:(0,R.serializeError)(e)}}finally{return t}};return(0,$.Ay)(t,(t=>(0,A.bindActionCreators)(n(t),e)))}getMapStateToProps(){return()=>y()({},this.getSystem())}getMapDispatchToProps(e){return t=>d()({},this.getWrappedAndBoundActions(t),this.getFn(),e)}}function V(e,t,n){if((0,$.Kn)(e)&&!(0,$.kJ)(e))return M()({},e);if((0,$.Wl)(e))return V(e(t),t,n);if((0,$.kJ)(e)){var s;const r="chain"===n.pluginLoadType?t.getComponents():{};return C()(s=b()(e).call(e,(e=>V(e,t,n)))).call(s,F,r)}return{}}function U(e,t){let{hasLoaded:n}=arguments.length>2&&void 0!==arguments[2]?arguments[2]:{},s=n;return(0,$.Kn)(e)&&!(0,$.kJ)(e)&&"function"==typeof e.afterLoad&&(s=!0,z(e.afterLoad).call(this,t)),(0,$.Wl)(e)?U.call(this,e(t),t,{hasLoaded:s}):(0,$.kJ)(e)?b()(e).call(e,(e=>U.call(this,e,t,{hasLoaded:s}))):s}function F(){let e=arguments.length>0&&void 0!==arguments[0]?arguments[0]:{},t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};if(!(0,$.Kn)(e))return{};if(!(0,$.Kn)(t))return e;t.wrapComponents&&((0,$.Ay)(t.wrapComponents,((n,s)=>{const r=e.components&&e.components[s];r&&w()(r)?(e.components[s]=N()(r).call(r,[n]),delete t.wrapComponents[s]):r&&(e.components[s]=[r,n],delete t.wrapComponents[s])})),i()(t.wrapComponents).length||delete t.wrapComponents);const{statePlugins:n}=e;if((0,$.Kn)(n))for(let e in n){const a=n[e];if(!(0,$.Kn)(a))continue;const{wrapActions:o,wrapSelectors:l}=a;if((0,$.Kn)(o))for(let n in o){let r=o[n];var s;if(w()(r)||(r=[r],o[n]=r),t&&t.statePlugins&&t.statePlugins[e]&&t.statePlugins[e].wrapActions&&t.statePlugins[e].wrapActions[n])t.statePlugins[e].wrapActions[n]=N()(s=o[n]).call(s,t.statePlugins[e].wrapActions[n])}if((0,$.Kn)(l))for(let n in l){let s=l[n];var r;if(w()(s)||(s=[s],l[n]=s),t&&t.statePlugins&&t.statePlugins[e]&&t.statePlugins[e].wrapSelectors&&t.statePlugins[e].wrapSelectors[n])t.statePlugins[e].wrapSelectors[n]=N()(r=l[n]).call(r,t.statePlugins[e].wrapSelectors[n])}}return d()(e,t)}function z(e){let{logErrors:t=!0}=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};return"function"!=typeof e?e:function(){try{for(var n=arguments.length,s=new Array(n),r=0;r<n;r++)s[r]=arguments[r];return e.call(this,...s)}catch(e){return t&&console.error(e),null}}}var B=n(7793),W=n(6821),H=n(7038),Z=n(3420),G=n(8883),X=n(6575),Y=n(9150),Q=n(1241),ee=n(3705),te=n(8525),ne=n(8011),se=n(6709),re=n(4980),ae=n(9978),oe=n(8560),le=n(9595),ce=n(70),ie=n(1093),ue=n.n(ie),pe=n(8493),me=n.n(pe),de=(n(580),n(8082),n(6765));class he extends O.PureComponent{constructor(e,t){super(e,t),ue()(this,"toggleShown",(()=>{let{layoutActions:e,tag:t,operationId:n,isShown:s}=this.props;const r=this.getResolvedSubtree();s||void 0!==r||this.requestResolvedSubtree(),e.show(["operations",t,n],!s)})),ue()(this,"onCancelClick",(()=>{this.setState({tryItOutEnabled:!this.state.tryItOutEnabled})})),ue()(this,"onTryoutClick",(()=>{this.setState({tryItOutEnabled:!this.state.tryItOutEnabled})})),ue()(this,"onResetClick",(e=>{const t=this.props.oas3Selectors.selectDefaultRequestBodyValue(...e);this.props.oas3Actions.setRequestBodyValue({value:t,pathMethod:e})})),ue()(this,"onExecute",(()=>{this.setState({executeInProgress:!0})})),ue()(this,"getResolvedSubtree",(()=>{const{specSelectors:e,path:t,method:n,specPath:s}=this.props;return s?e.specResolvedSubtree(s.toJS()):e.specResolvedSubtree(["paths",t,n])})),ue()(this,"requestResolvedSubtree",(()=>{const{specActions:e,path:t,method:n,specPath:s}=this.props;return s?e.requestResolvedSubtree(s.toJS()):e.requestResolvedSubtree(["paths",t,n])}));const{tryItOutEnabled:n}=e.getConfigs();this.state={tryItOutEnabled:!0===n||"true"===n,executeInProgress:!1}}mapStateToProps(e,t){const{op:n,layoutSelectors:s,getConfigs:r}=t,{docExpansion:a,deepLinking:o,displayOperationId:l,displayRequestDuration:c,supportedSubmitMethods:i}=r(),u=s.showSummary(),p=n.getIn(["operation","__originalOperationId"])||n.getIn(["operation","operationId"])||(0,de.opId)(n.get("operation"),t.path,t.method)||n.get("id"),m=["operations",t.tag,p],d=o&&"false"!==o,h=me()(i).call(i,t.method)>=0&&(void 0===t.allowTryItOut?t.specSelectors.allowTryItOutFor(t.path,t.method):t.allowTryItOut),g=n.getIn(["operation","security"])||t.specSelectors.security();return{operationId:p,isDeepLinkingEnabled:d,showSummary:u,displayOperationId:l,displayRequestDuration:c,allowTryItOut:h,security:g,isAuthorized:t.authSelectors.isAuthorized(g),isShown:s.isShown(m,"full"===a),jumpToKey:`paths.${t.path}.${t.method}`,response:t.specSelectors.responseFor(t.path,t.method),request:t.specSelectors.requestFor(t.path,t.method)}}componentDidMount(){const{isShown:e}=this.props,t=this.getResolvedSubtree();e&&void 0===t&&this.requestResolvedSubtree()}UNSAFE_componentWillReceiveProps(e){const{response:t,isShown:n}=e,s=this.getResolvedSubtree();t!==this.props.response&&this.setState({executeInProgress:!1}),n&&void 0===s&&this.requestResolvedSubtree()}render(){let{op:e,tag:t,path:n,method:s,security:r,isAuthorized:a,operationId:o,showSummary:l,isShown:c,jumpToKey:i,allowTryItOut:u,response:p,request:m,displayOperationId:d,displayRequestDuration:h,isDeepLinkingEnabled:g,specPath:f,specSelectors:y,specActions:v,getComponent:S,getConfigs:E,layoutSelectors:w,layoutActions:x,authActions:C,authSelectors:j,oas3Actions:b,oas3Selectors:_,fn:N}=this.props;const O=S("operation"),A=this.getResolvedSubtree()||(0,I.Map)(),P=(0,I.fromJS)({op:A,tag:t,path:n,summary:e.getIn(["operation","summary"])||"",deprecated:A.get("deprecated")||e.getIn(["operation","deprecated"])||!1,method:s,security:r,isAuthorized:a,operationId:o,originalOperationId:A.getIn(["operation","__originalOperationId"]),showSummary:l,isShown:c,jumpToKey:i,allowTryItOut:u,request:m,displayOperationId:d,displayRequestDuration:h,isDeepLinkingEnabled:g,executeInProgress:this.state.executeInProgress,tryItOutEnabled:this.state.tryItOutEnabled});return k().createElement(O,{operation:P,response:p,request:m,isShown:c,toggleShown:this.toggleShown,onTryoutClick:this.onTryoutClick,onResetClick:this.onResetClick,onCancelClick:this.onCancelClick,onExecute:this.onExecute,specPath:f,specActions:v,specSelectors:y,oas3Actions:b,oas3Selectors:_,layoutActions:x,layoutSelectors:w,authActions:C,authSelectors:j,getComponent:S,getConfigs:E,fn:N})}}ue()(he,"defaultProps",{showSummary:!0,response:null,allowTryItOut:!0,displayOperationId:!1,displayRequestDuration:!1});class ge extends k().Component{getLayout(){let{getComponent:e,layoutSelectors:t}=this.props;const n=t.current(),s=e(n,!0);return s||(()=>k().createElement("h1",null,' No layout defined for "',n,'" '))}render(){const e=this.getLayout();return k().createElement(e,null)}}ge.defaultProps={};class fe extends k().Component{constructor(){super(...arguments),ue()(this,"close",(()=>{let{authActions:e}=this.props;e.showDefinitions(!1)}))}render(){var e;let{authSelectors:t,authActions:n,getComponent:s,errSelectors:r,specSelectors:a,fn:{AST:o={}}}=this.props,l=t.shownDefinitions();const c=s("auths"),i=s("CloseIcon");return k().createElement("div",{className:"dialog-ux"},k().createElement("div",{className:"backdrop-ux"}),k().createElement("div",{className:"modal-ux"},k().createElement("div",{className:"modal-dialog-ux"},k().createElement("div",{className:"modal-ux-inner"},k().createElement("div",{className:"modal-ux-header"},k().createElement("h3",null,"Available authorizations"),k().createElement("button",{type:"button",className:"close-modal",onClick:this.close},k().createElement(i,null))),k().createElement("div",{className:"modal-ux-content"},b()(e=l.valueSeq()).call(e,((e,l)=>k().createElement(c,{key:l,AST:o,definitions:e,getComponent:s,errSelectors:r,authSelectors:t,authActions:n,specSelectors:a}))))))))}}class ye extends k().Component{render(){let{isAuthorized:e,showPopup:t,onClick:n,getComponent:s}=this.props;const r=s("authorizationPopup",!0),a=s("LockAuthIcon",!0),o=s("UnlockAuthIcon",!0);return k().createElement("div",{className:"auth-wrapper"},k().createElement("button",{className:e?"btn authorize locked":"btn authorize unlocked",onClick:n},k().createElement("span",null,"Authorize"),e?k().createElement(a,null):k().createElement(o,null)),t&&k().createElement(r,null))}}class ve extends k().Component{render(){const{authActions:e,authSelectors:t,specSelectors:n,getComponent:s}=this.props,r=n.securityDefinitions(),a=t.definitionsToAuthorize(),o=s("authorizeBtn");return r?k().createElement(o,{onClick:()=>e.showDefinitions(a),isAuthorized:!!t.authorized().size,showPopup:!!t.shownDefinitions(),getComponent:s}):null}}class Se extends k().Component{constructor(){super(...arguments),ue()(this,"onClick",(e=>{e.stopPropagation();let{onClick:t}=this.props;t&&t()}))}render(){let{isAuthorized:e,getComponent:t}=this.props;const n=t("LockAuthOperationIcon",!0),s=t("UnlockAuthOperationIcon",!0);return k().createElement("button",{className:"authorization__btn","aria-label":e?"authorization button locked":"authorization button unlocked",onClick:this.onClick},e?k().createElement(n,{className:"locked"}):k().createElement(s,{className:"unlocked"}))}}class Ee extends k().Component{constructor(e,t){super(e,t),ue()(this,"onAuthChange",(e=>{let{name:t}=e;this.setState({[t]:e})})),ue()(this,"submitAuth",(e=>{e.preventDefault();let{authActions:t}=this.props;t.authorizeWithPersistOption(this.state)})),ue()(this,"logoutClick",(e=>{e.preventDefault();let{authActions:t,definitions:n}=this.props,s=b()(n).call(n,((e,t)=>t)).toArray();this.setState(C()(s).call(s,((e,t)=>(e[t]="",e)),{})),t.logoutWithPersistOption(s)})),ue()(this,"close",(e=>{e.preventDefault();let{authActions:t}=this.props;t.showDefinitions(!1)})),this.state={}}render(){var e;let{definitions:t,getComponent:n,authSelectors:s,errSelectors:r}=this.props;const a=n("AuthItem"),o=n("oauth2",!0),c=n("Button");let i=s.authorized(),u=l()(t).call(t,((e,t)=>!!i.get(t))),p=l()(t).call(t,(e=>"oauth2"!==e.get("type"))),m=l()(t).call(t,(e=>"oauth2"===e.get("type")));return k().createElement("div",{className:"auth-container"},!!p.size&&k().createElement("form",{onSubmit:this.submitAuth},b()(p).call(p,((e,t)=>k().createElement(a,{key:t,schema:e,name:t,getComponent:n,onAuthChange:this.onAuthChange,authorized:i,errSelectors:r}))).toArray(),k().createElement("div",{className:"auth-btn-wrapper"},p.size===u.size?k().createElement(c,{className:"btn modal-btn auth",onClick:this.logoutClick},"Logout"):k().createElement(c,{type:"submit",className:"btn modal-btn auth authorize"},"Authorize"),k().createElement(c,{className:"btn modal-btn auth btn-done",onClick:this.close},"Close"))),m&&m.size?k().createElement("div",null,k().createElement("div",{className:"scope-def"},k().createElement("p",null,"Scopes are used to grant an application different levels of access to data on behalf of the end user. Each API may declare one or more scopes."),k().createElement("p",null,"API requires the following scopes. Select which ones you want to grant to Swagger UI.")),b()(e=l()(t).call(t,(e=>"oauth2"===e.get("type")))).call(e,((e,t)=>k().createElement("div",{key:t},k().createElement(o,{authorized:i,schema:e,name:t})))).toArray()):null)}}class we extends k().Component{render(){let{schema:e,name:t,getComponent:n,onAuthChange:s,authorized:r,errSelectors:a}=this.props;const o=n("apiKeyAuth"),l=n("basicAuth");let c;const i=e.get("type");switch(i){case"apiKey":c=k().createElement(o,{key:t,schema:e,name:t,errSelectors:a,authorized:r,getComponent:n,onChange:s});break;case"basic":c=k().createElement(l,{key:t,schema:e,name:t,errSelectors:a,authorized:r,getComponent:n,onChange:s});break;default:c=k().createElement("div",{key:t},"Unknown security definition type ",i)}return k().createElement("div",{key:`${t}-jump`},c)}}class xe extends k().Component{render(){let{error:e}=this.props,t=e.get("level"),n=e.get("message"),s=e.get("source");return k().createElement("div",{className:"errors"},k().createElement("b",null,s," ",t),k().createElement("span",null,n))}}class Ce extends k().Component{constructor(e,t){super(e,t),ue()(this,"onChange",(e=>{let{onChange:t}=this.props,n=e.target.value,s=y()({},this.state,{value:n});this.setState(s),t(s)}));let{name:n,schema:s}=this.props,r=this.getValue();this.state={name:n,schema:s,value:r}}getValue(){let{name:e,authorized:t}=this.props;return t&&t.getIn([e,"value"])}render(){var e,t;let{schema:n,getComponent:s,errSelectors:r,name:a}=this.props;const o=s("Input"),c=s("Row"),i=s("Col"),u=s("authError"),p=s("Markdown",!0),m=s("JumpToPath",!0);let d=this.getValue(),h=l()(e=r.allErrors()).call(e,(e=>e.get("authId")===a));return k().createElement("div",null,k().createElement("h4",null,k().createElement("code",null,a||n.get("name"))," (apiKey)",k().createElement(m,{path:["securityDefinitions",a]})),d&&k().createElement("h6",null,"Authorized"),k().createElement(c,null,k().createElement(p,{source:n.get("description")})),k().createElement(c,null,k().createElement("p",null,"Name: ",k().createElement("code",null,n.get("name")))),k().createElement(c,null,k().createElement("p",null,"In: ",k().createElement("code",null,n.get("in")))),k().createElement(c,null,k().createElement("label",null,"Value:"),d?k().createElement("code",null," ****** "):k().createElement(i,null,k().createElement(o,{type:"text",onChange:this.onChange,autoFocus:!0}))),b()(t=h.valueSeq()).call(t,((e,t)=>k().createElement(u,{error:e,key:t}))))}}class je extends k().Component{constructor(e,t){super(e,t),ue()(this,"onChange",(e=>{let{onChange:t}=this.props,{value:n,name:s}=e.target,r=this.state.value;r[s]=n,this.setState({value:r}),t(this.state)}));let{schema:n,name:s}=this.props,r=this.getValue().username;this.state={name:s,schema:n,value:r?{username:r}:{}}}getValue(){let{authorized:e,name:t}=this.props;return e&&e.getIn([t,"value"])||{}}render(){var e,t;let{schema:n,getComponent:s,name:r,errSelectors:a}=this.props;const o=s("Input"),c=s("Row"),i=s("Col"),u=s("authError"),p=s("JumpToPath",!0),m=s("Markdown",!0);let d=this.getValue().username,h=l()(e=a.allErrors()).call(e,(e=>e.get("authId")===r));return k().createElement("div",null,k().createElement("h4",null,"Basic authorization",k().createElement(p,{path:["securityDefinitions",r]})),d&&k().createElement("h6",null,"Authorized"),k().createElement(c,null,k().createElement(m,{source:n.get("description")})),k().createElement(c,null,k().createElement("label",null,"Username:"),d?k().createElement("code",null," ",d," "):k().createElement(i,null,k().createElement(o,{type:"text",required:"required",name:"username",onChange:this.onChange,autoFocus:!0}))),k().createElement(c,null,k().createElement("label",null,"Password:"),d?k().createElement("code",null," ****** "):k().createElement(i,null,k().createElement(o,{autoComplete:"new-password",name:"password",type:"password",onChange:this.onChange}))),b()(t=h.valueSeq()).call(t,((e,t)=>k().createElement(u,{error:e,key:t}))))}}function be(e){const{example:t,showValue:n,getComponent:s,getConfigs:r}=e,a=s("Markdown",!0),o=s("highlightCode");return t?k().createElement("div",{className:"example"},t.get("description")?k().createElement("section",{className:"example__section"},k().createElement("div",{className:"example__section-header"},"Example Description"),k().createElement("p",null,k().createElement(a,{source:t.get("description")}))):null,n&&t.has("value")?k().createElement("section",{className:"example__section"},k().createElement("div",{className:"example__section-header"},"Example Value"),k().createElement(o,{getConfigs:r,value:(0,$.Pz)(t.get("value"))})):null):null}var _e=n(2611),Ne=n.n(_e);class Oe extends k().PureComponent{constructor(){var e;super(...arguments),e=this,ue()(this,"_onSelect",(function(t){let{isSyntheticChange:n=!1}=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};"function"==typeof e.props.onSelect&&e.props.onSelect(t,{isSyntheticChange:n})})),ue()(this,"_onDomSelect",(e=>{if("function"==typeof this.props.onSelect){const t=e.target.selectedOptions[0].getAttribute("value");this._onSelect(t,{isSyntheticChange:!1})}})),ue()(this,"getCurrentExample",(()=>{const{examples:e,currentExampleKey:t}=this.props,n=e.get(t),s=e.keySeq().first(),r=e.get(s);return n||r||Ne()({})}))}componentDidMount(){const{onSelect:e,examples:t}=this.props;if("function"==typeof e){const e=t.first(),n=t.keyOf(e);this._onSelect(n,{isSyntheticChange:!0})}}UNSAFE_componentWillReceiveProps(e){const{currentExampleKey:t,examples:n}=e;if(n!==this.props.examples&&!n.has(t)){const e=n.first(),t=n.keyOf(e);this._onSelect(t,{isSyntheticChange:!0})}}render(){const{examples:e,currentExampleKey:t,isValueModified:n,isModifiedValueAvailable:s,showLabels:r}=this.props;return k().createElement("div",{className:"examples-select"},r?k().createElement("span",{className:"examples-select__section-label"},"Examples: "):null,k().createElement("select",{className:"examples-select-element",onChange:this._onDomSelect,value:s&&n?"__MODIFIED__VALUE__":t||""},s?k().createElement("option",{value:"__MODIFIED__VALUE__"},"[Modified value]"):null,b()(e).call(e,((e,t)=>k().createElement("option",{key:t,value:t},e.get("summary")||t))).valueSeq()))}}ue()(Oe,"defaultProps",{examples:P().Map({}),onSelect:function(){for(var e=arguments.length,t=new Array(e),n=0;n<e;n++)t[n]=arguments[n];return console.log("DEBUG: ExamplesSelect was not given an onSelect callback",...t)},currentExampleKey:null,showLabels:!0});const ke=e=>I.List.isList(e)?e:(0,$.Pz)(e);class Ae extends k().PureComponent{constructor(e){var t;super(e),t=this,ue()(this,"_getStateForCurrentNamespace",(()=>{const{currentNamespace:e}=this.props;return(this.state[e]||(0,I.Map)()).toObject()})),ue()(this,"_setStateForCurrentNamespace",(e=>{const{currentNamespace:t}=this.props;return this._setStateForNamespace(t,e)})),ue()(this,"_setStateForNamespace",((e,t)=>{const n=(this.state[e]||(0,I.Map)()).mergeDeep(t);return this.setState({[e]:n})})),ue()(this,"_isCurrentUserInputSameAsExampleValue",(()=>{const{currentUserInputValue:e}=this.props;return this._getCurrentExampleValue()===e})),ue()(this,"_getValueForExample",((e,t)=>{const{examples:n}=t||this.props;return ke((n||(0,I.Map)({})).getIn([e,"value"]))})),ue()(this,"_getCurrentExampleValue",(e=>{const{currentKey:t}=e||this.props;return this._getValueForExample(t,e||this.props)})),ue()(this,"_onExamplesSelect",(function(e){let{isSyntheticChange:n}=arguments.length>1&&void 0!==arguments[1]?arguments[1]:{};const{onSelect:s,updateValue:r,currentUserInputValue:a,userHasEditedBody:o}=t.props,{lastUserEditedValue:l}=t._getStateForCurrentNamespace(),c=t._getValueForExample(e);if("__MODIFIED__VALUE__"===e)return r(ke(l)),t._setStateForCurrentNamespace({isModifiedValueSelected:!0});if("function"==typeof s){for(var i=arguments.length,u=new Array(i>2?i-2:0),p=2;p<i;p++)u[p-2]=arguments[p];s(e,{isSyntheticChange:n},...u)}t._setStateForCurrentNamespace({lastDownstreamValue:c,isModifiedValueSelected:n&&o||!!a&&a!==c}),n||"function"==typeof r&&r(ke(c))}));const n=this._getCurrentExampleValue();this.state={[e.currentNamespace]:(0,I.Map)({lastUserEditedValue:this.props.currentUserInputValue,lastDownstreamValue:n,isModifiedValueSelected:this.props.userHasEditedBody||this.props.currentUserInputValue!==n})}}componentWillUnmount(){this.props.setRetainRequestBodyValueFlag(!1)}UNSAFE_componentWillReceiveProps(e){const{currentUserInputValue:t,examples:n,onSelect:s,userHasEditedBody:r}=e,{lastUserEditedValue:a,lastDownstreamValue:o}=this._getStateForCurrentNamespace(),c=this._getValueForExample(e.currentKey,e),i=l()(n).call(n,(e=>e.get("value")===t||(0,$.Pz)(e.get("value"))===t));if(i.size){let t;t=i.has(e.currentKey)?e.currentKey:i.keySeq().first(),s(t,{isSyntheticChange:!0})}else t!==this.props.currentUserInputValue&&t!==a&&t!==o&&(this.props.setRetainRequestBodyValueFlag(!0),this._setStateForNamespace(e.currentNamespace,{lastUserEditedValue:e.currentUserInputValue,isModifiedValueSelected:r||t!==c}))}render(){const{currentUserInputValue:e,examples:t,currentKey:n,getComponent:s,userHasEditedBody:r}=this.props,{lastDownstreamValue:a,lastUserEditedValue:o,isModifiedValueSelected:l}=this._getStateForCurrentNamespace(),c=s("ExamplesSelect");return k().createElement(c,{examples:t,currentExampleKey:n,onSelect:this._onExamplesSelect,isModifiedValueAvailable:!!o&&o!==a,isValueModified:void 0!==e&&l&&e!==this._getCurrentExampleValue()||r})}}ue()(Ae,"defaultProps",{userHasEditedBody:!1,examples:(0,I.Map)({}),currentNamespace:"__DEFAULT__NAMESPACE__",setRetainRequestBodyValueFlag:()=>{},onSelect:function(){for(var e=arguments.length,t=new Array(e),n=0;n<e;n++)t[n]=arguments[n];return console.log("ExamplesSelectValueRetainer: no `onSelect` function was provided",...t)},updateValue:function(){for(var e=arguments.length,t=new Array(e),n=0;n<e;n++)t[n]=arguments[n];return console.log("ExamplesSelectValueRetainer: no `updateValue` function was provided",...t)}});var Ie=n(1733),Pe=n.n(Ie),qe=n(874),Re=n.n(qe),Te=n(2605),Me=n.n(Te),De=n(3883),Je=n.n(De);class $e extends k().Component{constructor(e,t){super(e,t),ue()(this,"close",(e=>{e.preventDefault();let{authActions:t}=this.props;t.showDefinitions(!1)})),ue()(this,"authorize",(()=>{let{authActions:e,errActions:t,getConfigs:n,authSelectors:s,oas3Selectors:r}=this.props,a=n(),o=s.getConfigs();t.clear({authId:name,type:"auth",source:"auth"}),function(e){let{auth:t,authActions:n,errActions:s,configs:r,authConfigs:a={},currentServer:o}=e,{schema:l,scopes:c,name:i,clientId:u}=t,p=l.get("flow"),m=[];switch(p){case"password":return void n.authorizePassword(t);case"application":case"clientCredentials":case"client_credentials":return void n.authorizeApplication(t);case"accessCode":case"authorizationCode":case"authorization_code":m.push("response_type=code");break;case"implicit":m.push("response_type=token")}"string"==typeof u&&m.push("client_id="+encodeURIComponent(u));let d=r.oauth2RedirectUrl;if(void 0===d)return void s.newAuthErr({authId:i,source:"validation",level:"error",message:"oauth2RedirectUrl configuration is not passed. Oauth2 authorization cannot be performed."});m.push("redirect_uri="+encodeURIComponent(d));let h=[];if(w()(c)?h=c:P().List.isList(c)&&(h=c.toArray()),h.length>0){let e=a.scopeSeparator||" ";m.push("scope="+encodeURIComponent(h.join(e)))}let g=(0,$.r3)(new Date);if(m.push("state="+encodeURIComponent(g)),void 0!==a.realm&&m.push("realm="+encodeURIComponent(a.realm)),("authorizationCode"===p||"authorization_code"===p||"accessCode"===p)&&a.usePkceWithAuthorizationCodeGrant){const e=(0,$.Uj)(),n=(0,$.Xb)(e);m.push("code_challenge="+n),m.push("code_challenge_method=S256"),t.codeVerifier=e}let{additionalQueryStringParams:f}=a;for(let e in f){var y;void 0!==f[e]&&m.push(b()(y=[e,f[e]]).call(y,encodeURIComponent).join("="))}const v=l.get("authorizationUrl");let S;S=o?Je()((0,$.Nm)(v),o,!0).toString():(0,$.Nm)(v);let E,x=[S,m.join("&")].join(-1===me()(v).call(v,"?")?"?":"&");E="implicit"===p?n.preAuthorizeImplicit:a.useBasicAuthenticationWithAccessCodeGrant?n.authorizeAccessCodeWithBasicAuthentication:n.authorizeAccessCodeWithFormParams,n.authPopup(x,{auth:t,state:g,redirectUrl:d,callback:E,errCb:s.newAuthErr})}({auth:this.state,currentServer:r.serverEffectiveValue(r.selectedServer()),authActions:e,errActions:t,configs:a,authConfigs:o})})),ue()(this,"onScopeChange",(e=>{var t,n;let{target:s}=e,{checked:r}=s,a=s.dataset.value;if(r&&-1===me()(t=this.state.scopes).call(t,a)){var o;let e=N()(o=this.state.scopes).call(o,[a]);this.setState({scopes:e})}else if(!r&&me()(n=this.state.scopes).call(n,a)>-1){var c;this.setState({scopes:l()(c=this.state.scopes).call(c,(e=>e!==a))})}})),ue()(this,"onInputChange",(e=>{let{target:{dataset:{name:t},value:n}}=e,s={[t]:n};this.setState(s)})),ue()(this,"selectScopes",(e=>{var t;e.target.dataset.all?this.setState({scopes:Pe()(Re()(t=this.props.schema.get("allowedScopes")||this.props.schema.get("scopes")).call(t))}):this.setState({scopes:[]})})),ue()(this,"logout",(e=>{e.preventDefault();let{authActions:t,errActions:n,name:s}=this.props;n.clear({authId:s,type:"auth",source:"auth"}),t.logoutWithPersistOption([s])}));let{name:n,schema:s,authorized:r,authSelectors:a}=this.props,o=r&&r.get(n),c=a.getConfigs()||{},i=o&&o.get("username")||"",u=o&&o.get("clientId")||c.clientId||"",p=o&&o.get("clientSecret")||c.clientSecret||"",m=o&&o.get("passwordType")||"basic",d=o&&o.get("scopes")||c.scopes||[];"string"==typeof d&&(d=d.split(c.scopeSeparator||" ")),this.state={appName:c.appName,name:n,schema:s,scopes:d,clientId:u,clientSecret:p,username:i,password:"",passwordType:m}}render(){var e,t;let{schema:n,getComponent:s,authSelectors:r,errSelectors:a,name:o,specSelectors:c}=this.props;const i=s("Input"),u=s("Row"),p=s("Col"),m=s("Button"),d=s("authError"),h=s("JumpToPath",!0),g=s("Markdown",!0),f=s("InitializedInput"),{isOAS3:y}=c;let v=y()?n.get("openIdConnectUrl"):null;const S="implicit",E="password",w=y()?v?"authorization_code":"authorizationCode":"accessCode",x=y()?v?"client_credentials":"clientCredentials":"application";let C=!!(r.getConfigs()||{}).usePkceWithAuthorizationCodeGrant,j=n.get("flow"),_=j===w&&C?j+" with PKCE":j,N=n.get("allowedScopes")||n.get("scopes"),O=!!r.authorized().get(o),A=l()(e=a.allErrors()).call(e,(e=>e.get("authId")===o)),I=!l()(A).call(A,(e=>"validation"===e.get("source"))).size,P=n.get("description");return k().createElement("div",null,k().createElement("h4",null,o," (OAuth2, ",_,") ",k().createElement(h,{path:["securityDefinitions",o]})),this.state.appName?k().createElement("h5",null,"Application: ",this.state.appName," "):null,P&&k().createElement(g,{source:n.get("description")}),O&&k().createElement("h6",null,"Authorized"),v&&k().createElement("p",null,"OpenID Connect URL: ",k().createElement("code",null,v)),(j===S||j===w)&&k().createElement("p",null,"Authorization URL: ",k().createElement("code",null,n.get("authorizationUrl"))),(j===E||j===w||j===x)&&k().createElement("p",null,"Token URL:",k().createElement("code",null," ",n.get("tokenUrl"))),k().createElement("p",{className:"flow"},"Flow: ",k().createElement("code",null,_)),j!==E?null:k().createElement(u,null,k().createElement(u,null,k().createElement("label",{htmlFor:"oauth_username"},"username:"),O?k().createElement("code",null," ",this.state.username," "):k().createElement(p,{tablet:10,desktop:10},k().createElement("input",{id:"oauth_username",type:"text","data-name":"username",onChange:this.onInputChange,autoFocus:!0}))),k().createElement(u,null,k().createElement("label",{htmlFor:"oauth_password"},"password:"),O?k().createElement("code",null," ****** "):k().createElement(p,{tablet:10,desktop:10},k().createElement("input",{id:"oauth_password",type:"password","data-name":"password",onChange:this.onInputChange}))),k().createElement(u,null,k().createElement("label",{htmlFor:"password_type"},"Client credentials location:"),O?k().createElement("code",null," ",this.state.passwordType," "):k().createElement(p,{tablet:10,desktop:10},k().createElement("select",{id:"password_type","data-name":"passwordType",onChange:this.onInputChange},k().createElement("option",{value:"basic"},"Authorization header"),k().createElement("option",{value:"request-body"},"Request body"))))),(j===x||j===S||j===w||j===E)&&(!O||O&&this.state.clientId)&&k().createElement(u,null,k().createElement("label",{htmlFor:"client_id"},"client_id:"),O?k().createElement("code",null," ****** "):k().createElement(p,{tablet:10,desktop:10},k().createElement(f,{id:"client_id",type:"text",required:j===E,initialValue:this.state.clientId,"data-name":"clientId",onChange:this.onInputChange}))),(j===x||j===w||j===E)&&k().createElement(u,null,k().createElement("label",{htmlFor:"client_secret"},"client_secret:"),O?k().createElement("code",null," ****** "):k().createElement(p,{tablet:10,desktop:10},k().createElement(f,{id:"client_secret",initialValue:this.state.clientSecret,type:"password","data-name":"clientSecret",onChange:this.onInputChange}))),!O&&N&&N.size?k().createElement("div",{className:"scopes"},k().createElement("h2",null,"Scopes:",k().createElement("a",{onClick:this.selectScopes,"data-all":!0},"select all"),k().createElement("a",{onClick:this.selectScopes},"select none")),b()(N).call(N,((e,t)=>{var n;return k().createElement(u,{key:t},k().createElement("div",{className:"checkbox"},k().createElement(i,{"data-value":t,id:`${t}-${j}-checkbox-${this.state.name}`,disabled:O,checked:Me()(n=this.state.scopes).call(n,t),type:"checkbox",onChange:this.onScopeChange}),k().createElement("label",{htmlFor:`${t}-${j}-checkbox-${this.state.name}`},k().createElement("span",{className:"item"}),k().createElement("div",{className:"text"},k().createElement("p",{className:"name"},t),k().createElement("p",{className:"description"},e)))))})).toArray()):null,b()(t=A.valueSeq()).call(t,((e,t)=>k().createElement(d,{error:e,key:t}))),k().createElement("div",{className:"auth-btn-wrapper"},I&&(O?k().createElement(m,{className:"btn modal-btn auth authorize",onClick:this.logout},"Logout"):k().createElement(m,{className:"btn modal-btn auth authorize",onClick:this.authorize},"Authorize")),k().createElement(m,{className:"btn modal-btn auth btn-done",onClick:this.close},"Close")))}}class Le extends O.Component{constructor(){super(...arguments),ue()(this,"onClick",(()=>{let{specActions:e,path:t,method:n}=this.props;e.clearResponse(t,n),e.clearRequest(t,n)}))}render(){return k().createElement("button",{className:"btn btn-clear opblock-control__btn",onClick:this.onClick},"Clear")}}const Ke=e=>{let{headers:t}=e;return k().createElement("div",null,k().createElement("h5",null,"Response headers"),k().createElement("pre",{className:"microlight"},t))},Ve=e=>{let{duration:t}=e;return k().createElement("div",null,k().createElement("h5",null,"Request duration"),k().createElement("pre",{className:"microlight"},t," ms"))};class Ue extends k().Component{shouldComponentUpdate(e){return this.props.response!==e.response||this.props.path!==e.path||this.props.method!==e.method||this.props.displayRequestDuration!==e.displayRequestDuration}render(){const{response:e,getComponent:t,getConfigs:n,displayRequestDuration:s,specSelectors:r,path:a,method:o}=this.props,{showMutatedRequest:l,requestSnippetsEnabled:c}=n(),u=l?r.mutatedRequestFor(a,o):r.requestFor(a,o),p=e.get("status"),m=u.get("url"),d=e.get("headers").toJS(),h=e.get("notDocumented"),g=e.get("error"),f=e.get("text"),y=e.get("duration"),v=i()(d),S=d["content-type"]||d["Content-Type"],E=t("responseBody"),x=b()(v).call(v,(e=>{var t=w()(d[e])?d[e].join():d[e];return k().createElement("span",{className:"headerline",key:e}," ",e,": ",t," ")})),C=0!==x.length,j=t("Markdown",!0),_=t("RequestSnippets",!0),N=t("curl");return k().createElement("div",null,u&&(!0===c||"true"===c?k().createElement(_,{request:u}):k().createElement(N,{request:u,getConfigs:n})),m&&k().createElement("div",null,k().createElement("div",{className:"request-url"},k().createElement("h4",null,"Request URL"),k().createElement("pre",{className:"microlight"},m))),k().createElement("h4",null,"Server response"),k().createElement("table",{className:"responses-table live-responses-table"},k().createElement("thead",null,k().createElement("tr",{className:"responses-header"},k().createElement("td",{className:"col_header response-col_status"},"Code"),k().createElement("td",{className:"col_header response-col_description"},"Details"))),k().createElement("tbody",null,k().createElement("tr",{className:"response"},k().createElement("td",{className:"response-col_status"},p,h?k().createElement("div",{className:"response-undocumented"},k().createElement("i",null," Undocumented ")):null),k().createElement("td",{className:"response-col_description"},g?k().createElement(j,{source:`${""!==e.get("name")?`${e.get("name")}: `:""}${e.get("message")}`}):null,f?k().createElement(E,{content:f,contentType:S,url:m,headers:d,getConfigs:n,getComponent:t}):null,C?k().createElement(Ke,{headers:x}):null,s&&y?k().createElement(Ve,{duration:y}):null)))))}}var Fe=n(5623);class ze extends k().Component{constructor(){super(...arguments),ue()(this,"renderOperationTag",((e,t)=>{const{specSelectors:n,getComponent:s,oas3Selectors:r,layoutSelectors:a,layoutActions:o,getConfigs:l}=this.props,c=n.validOperationMethods(),i=s("OperationContainer",!0),u=s("OperationTag"),p=e.get("operations");return k().createElement(u,{key:"operation-"+t,tagObj:e,tag:t,oas3Selectors:r,layoutSelectors:a,layoutActions:o,getConfigs:l,getComponent:s,specUrl:n.url()},k().createElement("div",{className:"operation-tag-content"},b()(p).call(p,(e=>{const n=e.get("path"),s=e.get("method"),r=P().List(["paths",n,s]);return-1===me()(c).call(c,s)?null:k().createElement(i,{key:`${n}-${s}`,specPath:r,op:e,path:n,method:s,tag:t})})).toArray()))}))}render(){let{specSelectors:e}=this.props;const t=e.taggedOperations();return 0===t.size?k().createElement("h3",null," No operations defined in spec!"):k().createElement("div",null,b()(t).call(t,this.renderOperationTag).toArray(),t.size<1?k().createElement("h3",null," No operations defined in spec! "):null)}}var Be=n(3543);class We extends k().Component{render(){const{tagObj:e,tag:t,children:n,oas3Selectors:s,layoutSelectors:r,layoutActions:a,getConfigs:o,getComponent:l,specUrl:c}=this.props;let{docExpansion:i,deepLinking:u}=o();const p=u&&"false"!==u,m=l("Collapse"),d=l("Markdown",!0),h=l("DeepLink"),g=l("Link"),f=l("ArrowUpIcon"),y=l("ArrowDownIcon");let v,S=e.getIn(["tagDetails","description"],null),E=e.getIn(["tagDetails","externalDocs","description"]),w=e.getIn(["tagDetails","externalDocs","url"]);v=(0,$.Wl)(s)&&(0,$.Wl)(s.selectedServer)?(0,Be.mn)(w,c,{selectedServer:s.selectedServer()}):w;let x=["operations-tag",t],C=r.isShown(x,"full"===i||"list"===i);return k().createElement("div",{className:C?"opblock-tag-section is-open":"opblock-tag-section"},k().createElement("h3",{onClick:()=>a.show(x,!C),className:S?"opblock-tag":"opblock-tag no-desc",id:b()(x).call(x,(e=>(0,$.J6)(e))).join("-"),"data-tag":t,"data-is-open":C},k().createElement(h,{enabled:p,isShown:C,path:(0,$.oJ)(t),text:t}),S?k().createElement("small",null,k().createElement(d,{source:S})):k().createElement("small",null),v?k().createElement("div",{className:"info__externaldocs"},k().createElement("small",null,k().createElement(g,{href:(0,$.Nm)(v),onClick:e=>e.stopPropagation(),target:"_blank"},E||v))):null,k().createElement("button",{"aria-expanded":C,className:"expand-operation",title:C?"Collapse operation":"Expand operation",onClick:()=>a.show(x,!C)},C?k().createElement(f,{className:"arrow"}):k().createElement(y,{className:"arrow"}))),k().createElement(m,{isOpened:C},n))}}ue()(We,"defaultProps",{tagObj:P().fromJS({}),tag:""});class He extends O.PureComponent{render(){let{specPath:e,response:t,request:s,toggleShown:r,onTryoutClick:a,onResetClick:o,onCancelClick:l,onExecute:c,fn:i,getComponent:u,getConfigs:p,specActions:m,specSelectors:d,authActions:h,authSelectors:g,oas3Actions:f,oas3Selectors:y}=this.props,v=this.props.operation,{deprecated:S,isShown:E,path:w,method:x,op:C,tag:j,operationId:_,allowTryItOut:N,displayRequestDuration:O,tryItOutEnabled:A,executeInProgress:I}=v.toJS(),{description:P,externalDocs:q,schemes:R}=C;const T=q?(0,Be.mn)(q.url,d.url(),{selectedServer:y.selectedServer()}):"";let M=v.getIn(["op"]),D=M.get("responses"),J=(0,$.gp)(M,["parameters"]),L=d.operationScheme(w,x),K=["operations",j,_],V=(0,$.nX)(M);const U=u("responses"),F=u("parameters"),z=u("execute"),B=u("clear"),W=u("Collapse"),H=u("Markdown",!0),Z=u("schemes"),G=u("OperationServers"),X=u("OperationExt"),Y=u("OperationSummary"),Q=u("Link"),{showExtensions:ee}=p();if(D&&t&&t.size>0){let e=!D.get(String(t.get("status")))&&!D.get("default");t=t.set("notDocumented",e)}let te=[w,x];const ne=d.validationErrors([w,x]);return k().createElement("div",{className:S?"opblock opblock-deprecated":E?`opblock opblock-${x} is-open`:`opblock opblock-${x}`,id:(0,$.J6)(K.join("-"))},k().createElement(Y,{operationProps:v,isShown:E,toggleShown:r,getComponent:u,authActions:h,authSelectors:g,specPath:e}),k().createElement(W,{isOpened:E},k().createElement("div",{className:"opblock-body"},M&&M.size||null===M?null:k().createElement("img",{height:"32px",width:"32px",src:n(2517),className:"opblock-loading-animation"}),S&&k().createElement("h4",{className:"opblock-title_normal"}," Warning: Deprecated"),P&&k().createElement("div",{className:"opblock-description-wrapper"},k().createElement("div",{className:"opblock-description"},k().createElement(H,{source:P}))),T?k().createElement("div",{className:"opblock-external-docs-wrapper"},k().createElement("h4",{className:"opblock-title_normal"},"Find more details"),k().createElement("div",{className:"opblock-external-docs"},q.description&&k().createElement("span",{className:"opblock-external-docs__description"},k().createElement(H,{source:q.description})),k().createElement(Q,{target:"_blank",className:"opblock-external-docs__link",href:(0,$.Nm)(T)},T))):null,M&&M.size?k().createElement(F,{parameters:J,specPath:e.push("parameters"),operation:M,onChangeKey:te,onTryoutClick:a,onResetClick:o,onCancelClick:l,tryItOutEnabled:A,allowTryItOut:N,fn:i,getComponent:u,specActions:m,specSelectors:d,pathMethod:[w,x],getConfigs:p,oas3Actions:f,oas3Selectors:y}):null,A?k().createElement(G,{getComponent:u,path:w,method:x,operationServers:M.get("servers"),pathServers:d.paths().getIn([w,"servers"]),getSelectedServer:y.selectedServer,setSelectedServer:f.setSelectedServer,setServerVariableValue:f.setServerVariableValue,getServerVariable:y.serverVariableValue,getEffectiveServerValue:y.serverEffectiveValue}):null,A&&N&&R&&R.size?k().createElement("div",{className:"opblock-schemes"},k().createElement(Z,{schemes:R,path:w,method:x,specActions:m,currentScheme:L})):null,!A||!N||ne.length<=0?null:k().createElement("div",{className:"validation-errors errors-wrapper"},"Please correct the following validation errors and try again.",k().createElement("ul",null,b()(ne).call(ne,((e,t)=>k().createElement("li",{key:t}," ",e," "))))),k().createElement("div",{className:A&&t&&N?"btn-group":"execute-wrapper"},A&&N?k().createElement(z,{operation:M,specActions:m,specSelectors:d,oas3Selectors:y,oas3Actions:f,path:w,method:x,onExecute:c,disabled:I}):null,A&&t&&N?k().createElement(B,{specActions:m,path:w,method:x}):null),I?k().createElement("div",{className:"loading-container"},k().createElement("div",{className:"loading"})):null,D?k().createElement(U,{responses:D,request:s,tryItOutResponse:t,getComponent:u,getConfigs:p,specSelectors:d,oas3Actions:f,oas3Selectors:y,specActions:m,produces:d.producesOptionsFor([w,x]),producesValue:d.currentProducesFor([w,x]),specPath:e.push("responses"),path:w,method:x,displayRequestDuration:O,fn:i}):null,ee&&V.size?k().createElement(X,{extensions:V,getComponent:u}):null)))}}ue()(He,"defaultProps",{operation:null,response:null,request:null,specPath:(0,I.List)(),summary:""});const Ze=require("lodash/toString");var Ge=n.n(Ze);class Xe extends O.PureComponent{render(){let{isShown:e,toggleShown:t,getComponent:n,authActions:s,authSelectors:r,operationProps:a,specPath:o}=this.props,{summary:l,isAuthorized:c,method:i,op:u,showSummary:p,path:m,operationId:d,originalOperationId:h,displayOperationId:g}=a.toJS(),{summary:f}=u,y=a.get("security");const v=n("authorizeOperationBtn",!0),S=n("OperationSummaryMethod"),E=n("OperationSummaryPath"),w=n("JumpToPath",!0),x=n("CopyToClipboardBtn",!0),C=n("ArrowUpIcon"),j=n("ArrowDownIcon"),b=y&&!!y.count(),_=b&&1===y.size&&y.first().isEmpty(),N=!b||_;return k().createElement("div",{className:`opblock-summary opblock-summary-${i}`},k().createElement("button",{"aria-label":`${i} ${m.replace(/\//g,"​/")}`,"aria-expanded":e,className:"opblock-summary-control",onClick:t},k().createElement(S,{method:i}),k().createElement(E,{getComponent:n,operationProps:a,specPath:o}),p?k().createElement("div",{className:"opblock-summary-description"},Ge()(f||l)):null,g&&(h||d)?k().createElement("span",{className:"opblock-summary-operation-id"},h||d):null,e?k().createElement(C,{className:"arrow"}):k().createElement(j,{className:"arrow"})),N?null:k().createElement(v,{isAuthorized:c,onClick:()=>{const e=r.definitionsForRequirements(y);s.showDefinitions(e)}}),k().createElement(x,{textToCopy:`${o.get(1)}`}),k().createElement(w,{path:o}))}}ue()(Xe,"defaultProps",{operationProps:null,specPath:(0,I.List)(),summary:""});class Ye extends O.PureComponent{render(){let{method:e}=this.props;return k().createElement("span",{className:"opblock-summary-method"},e.toUpperCase())}}ue()(Ye,"defaultProps",{operationProps:null});const Qe=require("@babel/runtime-corejs3/core-js-stable/instance/splice");var et=n.n(Qe);class tt extends O.PureComponent{render(){let{getComponent:e,operationProps:t}=this.props,{deprecated:n,isShown:s,path:r,tag:a,operationId:o,isDeepLinkingEnabled:l}=t.toJS();const c=r.split(/(?=\/)/g);for(let e=1;e<c.length;e+=2)et()(c).call(c,e,0,k().createElement("wbr",{key:e}));const i=e("DeepLink");return k().createElement("span",{className:n?"opblock-summary-path__deprecated":"opblock-summary-path","data-path":r},k().createElement(i,{enabled:l,isShown:s,path:(0,$.oJ)(`${a}/${o}`),text:c}))}}const nt=e=>{var t;let{extensions:n,getComponent:s}=e,r=s("OperationExtRow");return k().createElement("div",{className:"opblock-section"},k().createElement("div",{className:"opblock-section-header"},k().createElement("h4",null,"Extensions")),k().createElement("div",{className:"table-container"},k().createElement("table",null,k().createElement("thead",null,k().createElement("tr",null,k().createElement("td",{className:"col_header"},"Field"),k().createElement("td",{className:"col_header"},"Value"))),k().createElement("tbody",null,b()(t=n.entrySeq()).call(t,(e=>{let[t,n]=e;return k().createElement(r,{key:`${t}-${n}`,xKey:t,xVal:n})}))))))},st=e=>{let{xKey:t,xVal:n}=e;const s=n?n.toJS?n.toJS():n:null;return k().createElement("tr",null,k().createElement("td",null,t),k().createElement("td",null,p()(s)))};var rt=n(4235),at=n.n(rt),ot=n(9003),lt=n.n(ot),ct=n(6068),it=n(1712),ut=n.n(it),pt=n(5716),mt=n.n(pt);const dt=require("js-file-download");var ht=n.n(dt),gt=n(2807);const ft=e=>{let{value:t,fileName:n,className:s,downloadable:r,getConfigs:a,canCopy:o,language:c}=e;const i=mt()(a)?a():null,u=!1!==ut()(i,"syntaxHighlight")&&ut()(i,"syntaxHighlight.activated",!0),p=(0,O.useRef)(null);(0,O.useEffect)((()=>{var e;const t=l()(e=Pe()(p.current.childNodes)).call(e,(e=>!!e.nodeType&&e.classList.contains("microlight")));return at()(t).call(t,(e=>e.addEventListener("mousewheel",m,{passive:!1}))),()=>{at()(t).call(t,(e=>e.removeEventListener("mousewheel",m)))}}),[t,s,c]);const m=e=>{const{target:t,deltaY:n}=e,{scrollHeight:s,offsetHeight:r,scrollTop:a}=t;s>r&&(0===a&&n<0||r+a>=s&&n>0)&&e.preventDefault()};return k().createElement("div",{className:"highlight-code",ref:p},r?k().createElement("div",{className:"download-contents",onClick:()=>{ht()(t,n)}},"Download"):null,o&&k().createElement("div",{className:"copy-to-clipboard"},k().createElement(gt.CopyToClipboard,{text:t},k().createElement("button",null))),u?k().createElement(ct.d3,{language:c,className:lt()(s,"microlight"),style:(0,ct.C2)(ut()(i,"syntaxHighlight.theme","agate"))},t):k().createElement("pre",{className:lt()(s,"microlight")},t))};ft.defaultProps={fileName:"response.txt"};const yt=ft;class vt extends k().Component{constructor(){super(...arguments),ue()(this,"onChangeProducesWrapper",(e=>this.props.specActions.changeProducesValue([this.props.path,this.props.method],e))),ue()(this,"onResponseContentTypeChange",(e=>{let{controlsAcceptHeader:t,value:n}=e;const{oas3Actions:s,path:r,method:a}=this.props;t&&s.setResponseContentType({value:n,path:r,method:a})}))}render(){var e;let{responses:t,tryItOutResponse:n,getComponent:s,getConfigs:r,specSelectors:a,fn:o,producesValue:l,displayRequestDuration:c,specPath:i,path:u,method:p,oas3Selectors:m,oas3Actions:d}=this.props,h=(0,$.iQ)(t);const g=s("contentType"),f=s("liveResponse"),y=s("response");let v=this.props.produces&&this.props.produces.size?this.props.produces:vt.defaultProps.produces;const S=a.isOAS3()?(0,$.QG)(t):null,E=function(e){let t=arguments.length>1&&void 0!==arguments[1]?arguments[1]:"_";return e.replace(/[^\w-]/g,t)}(`${p}${u}_responses`),w=`${E}_select`;return k().createElement("div",{className:"responses-wrapper"},k().createElement("div",{className:"opblock-section-header"},k().createElement("h4",null,"Responses"),a.isOAS3()?null:k().createElement("label",{htmlFor:w},k().createElement("span",null,"Response content type"),k().createElement(g,{value:l,ariaControls:E,ariaLabel:"Response content type",className:"execute-content-type",contentTypes:v,controlId:w,onChange:this.onChangeProducesWrapper}))),k().createElement("div",{className:"responses-inner"},n?k().createElement("div",null,k().createElement(f,{response:n,getComponent:s,getConfigs:r,specSelectors:a,path:this.props.path,method:this.props.method,displayRequestDuration:c}),k().createElement("h4",null,"Responses")):null,k().createElement("table",{"aria-live":"polite",className:"responses-table",id:E,role:"region"},k().createElement("thead",null,k().createElement("tr",{className:"responses-header"},k().createElement("td",{className:"col_header response-col_status"},"Code"),k().createElement("td",{className:"col_header response-col_description"},"Description"),a.isOAS3()?k().createElement("td",{className:"col col_header response-col_links"},"Links"):null)),k().createElement("tbody",null,b()(e=t.entrySeq()).call(e,(e=>{let[t,c]=e,g=n&&n.get("status")==t?"response_current":"";return k().createElement(y,{key:t,path:u,method:p,specPath:i.push(t),isDefault:h===t,fn:o,className:g,code:t,response:c,specSelectors:a,controlsAcceptHeader:c===S,onContentTypeChange:this.onResponseContentTypeChange,contentType:l,getConfigs:r,activeExamplesKey:m.activeExamplesMember(u,p,"responses",t),oas3Actions:d,getComponent:s})})).toArray()))))}}ue()(vt,"defaultProps",{tryItOutResponse:null,produces:(0,I.fromJS)(["application/json"]),displayRequestDuration:!1});const St=require("@babel/runtime-corejs3/core-js-stable/instance/values");var Et=n.n(St),wt=n(2518);class xt extends k().Component{constructor(e,t){super(e,t),ue()(this,"_onContentTypeChange",(e=>{const{onContentTypeChange:t,controlsAcceptHeader:n}=this.props;this.setState({responseContentType:e}),t({value:e,controlsAcceptHeader:n})})),ue()(this,"getTargetExamplesKey",(()=>{const{response:e,contentType:t,activeExamplesKey:n}=this.props,s=this.state.responseContentType||t,r=e.getIn(["content",s],(0,I.Map)({})).get("examples",null).keySeq().first();return n||r})),this.state={responseContentType:""}}render(){var e,t;let{path:n,method:s,code:r,response:a,className:o,specPath:l,fn:c,getComponent:i,getConfigs:u,specSelectors:p,contentType:m,controlsAcceptHeader:d,oas3Actions:h}=this.props,{inferSchema:g,getSampleSchema:f}=c,y=p.isOAS3();const{showExtensions:v}=u();let S=v?(0,$.nX)(a):null,E=a.get("headers"),w=a.get("links");const x=i("ResponseExtension"),C=i("headers"),j=i("highlightCode"),_=i("modelExample"),N=i("Markdown",!0),O=i("operationLink"),A=i("contentType"),P=i("ExamplesSelect"),q=i("Example");var R,T;const M=this.state.responseContentType||m,D=a.getIn(["content",M],(0,I.Map)({})),J=D.get("examples",null);if(y){const e=D.get("schema");R=e?g(e.toJS()):null,T=e?(0,I.List)(["content",this.state.responseContentType,"schema"]):l}else R=a.get("schema"),T=a.has("schema")?l.push("schema"):l;let L,K,V=!1,U={includeReadOnly:!0};if(y){var F;if(K=null===(F=D.get("schema"))||void 0===F?void 0:F.toJS(),J){const e=this.getTargetExamplesKey(),t=e=>e.get("value");L=t(J.get(e,(0,I.Map)({}))),void 0===L&&(L=t(Et()(J).call(J).next().value)),V=!0}else void 0!==D.get("example")&&(L=D.get("example"),V=!0)}else{K=R,U={...U,includeWriteOnly:!0};const e=a.getIn(["examples",M]);e&&(L=e,V=!0)}let z=((e,t,n)=>{if(null!=e){let s=null;return(0,wt.O)(e)&&(s="json"),k().createElement("div",null,k().createElement(t,{className:"example",getConfigs:n,language:s,value:(0,$.Pz)(e)}))}return null})(f(K,M,U,V?L:void 0),j,u);return k().createElement("tr",{className:"response "+(o||""),"data-code":r},k().createElement("td",{className:"response-col_status"},r),k().createElement("td",{className:"response-col_description"},k().createElement("div",{className:"response-col_description__inner"},k().createElement(N,{source:a.get("description")})),v&&S.size?b()(e=S.entrySeq()).call(e,(e=>{let[t,n]=e;return k().createElement(x,{key:`${t}-${n}`,xKey:t,xVal:n})})):null,y&&a.get("content")?k().createElement("section",{className:"response-controls"},k().createElement("div",{className:lt()("response-control-media-type",{"response-control-media-type--accept-controller":d})},k().createElement("small",{className:"response-control-media-type__title"},"Media type"),k().createElement(A,{value:this.state.responseContentType,contentTypes:a.get("content")?a.get("content").keySeq():(0,I.Seq)(),onChange:this._onContentTypeChange,ariaLabel:"Media Type"}),d?k().createElement("small",{className:"response-control-media-type__accept-message"},"Controls ",k().createElement("code",null,"Accept")," header."):null),J?k().createElement("div",{className:"response-control-examples"},k().createElement("small",{className:"response-control-examples__title"},"Examples"),k().createElement(P,{examples:J,currentExampleKey:this.getTargetExamplesKey(),onSelect:e=>h.setActiveExamplesMember({name:e,pathMethod:[n,s],contextType:"responses",contextName:r}),showLabels:!1})):null):null,z||R?k().createElement(_,{specPath:T,getComponent:i,getConfigs:u,specSelectors:p,schema:(0,$.oG)(R),example:z,includeReadOnly:!0}):null,y&&J?k().createElement(q,{example:J.get(this.getTargetExamplesKey(),(0,I.Map)({})),getComponent:i,getConfigs:u,omitValue:!0}):null,E?k().createElement(C,{headers:E,getComponent:i}):null),y?k().createElement("td",{className:"response-col_links"},w?b()(t=w.toSeq().entrySeq()).call(t,(e=>{let[t,n]=e;return k().createElement(O,{key:t,name:t,link:n,getComponent:i})})):k().createElement("i",null,"No links")):null)}}ue()(xt,"defaultProps",{response:(0,I.fromJS)({}),onContentTypeChange:()=>{}});const Ct=e=>{let{xKey:t,xVal:n}=e;return k().createElement("div",{className:"response__extension"},t,": ",String(n))};var jt=n(9478),bt=n.n(jt);const _t=require("xml-but-prettier");var Nt=n.n(_t);const Ot=require("lodash/toLower");var kt=n.n(Ot);class At extends k().PureComponent{constructor(){super(...arguments),ue()(this,"state",{parsedContent:null}),ue()(this,"updateParsedContent",(e=>{const{content:t}=this.props;if(e!==t)if(t&&t instanceof Blob){var n=new FileReader;n.onload=()=>{this.setState({parsedContent:n.result})},n.readAsText(t)}else this.setState({parsedContent:t.toString()})}))}componentDidMount(){this.updateParsedContent(null)}componentDidUpdate(e){this.updateParsedContent(e.content)}render(){let{content:e,contentType:t,url:n,headers:s={},getConfigs:r,getComponent:o}=this.props;const{parsedContent:l}=this.state,c=o("highlightCode"),i="response_"+(new Date).getTime();let u,m;if(n=n||"",(/^application\/octet-stream/i.test(t)||s["Content-Disposition"]&&/attachment/i.test(s["Content-Disposition"])||s["content-disposition"]&&/attachment/i.test(s["content-disposition"])||s["Content-Description"]&&/File Transfer/i.test(s["Content-Description"])||s["content-description"]&&/File Transfer/i.test(s["content-description"]))&&e.size>0)if("Blob"in window){let r=t||"text/html",o=e instanceof Blob?e:new Blob([e],{type:r}),l=bt().createObjectURL(o),c=[r,n.substr(a()(n).call(n,"/")+1),l].join(":"),i=s["content-disposition"]||s["Content-Disposition"];if(void 0!==i){let e=(0,$.DR)(i);null!==e&&(c=e)}m=J.Z.navigator&&J.Z.navigator.msSaveOrOpenBlob?k().createElement("div",null,k().createElement("a",{href:l,onClick:()=>J.Z.navigator.msSaveOrOpenBlob(o,c)},"Download file")):k().createElement("div",null,k().createElement("a",{href:l,download:c},"Download file"))}else m=k().createElement("pre",{className:"microlight"},"Download headers detected but your browser does not support downloading binary via XHR (Blob).");else if(/json/i.test(t)){let t=null;(0,wt.O)(e)&&(t="json");try{u=p()(JSON.parse(e),null,"  ")}catch(t){u="can't parse JSON.  Raw result:\n\n"+e}m=k().createElement(c,{language:t,downloadable:!0,fileName:`${i}.json`,value:u,getConfigs:r,canCopy:!0})}else/xml/i.test(t)?(u=Nt()(e,{textNodesOnSameLine:!0,indentor:"  "}),m=k().createElement(c,{downloadable:!0,fileName:`${i}.xml`,value:u,getConfigs:r,canCopy:!0})):m="text/html"===kt()(t)||/text\/plain/.test(t)?k().createElement(c,{downloadable:!0,fileName:`${i}.html`,value:e,getConfigs:r,canCopy:!0}):"text/csv"===kt()(t)||/text\/csv/.test(t)?k().createElement(c,{downloadable:!0,fileName:`${i}.csv`,value:e,getConfigs:r,canCopy:!0}):/^image\//i.test(t)?Me()(t).call(t,"svg")?k().createElement("div",null," ",e," "):k().createElement("img",{src:bt().createObjectURL(e)}):/^audio\//i.test(t)?k().createElement("pre",{className:"microlight"},k().createElement("audio",{controls:!0,key:n},k().createElement("source",{src:n,type:t}))):"string"==typeof e?k().createElement(c,{downloadable:!0,fileName:`${i}.txt`,value:e,getConfigs:r,canCopy:!0}):e.size>0?l?k().createElement("div",null,k().createElement("p",{className:"i"},"Unrecognized response type; displaying content as text."),k().createElement(c,{downloadable:!0,fileName:`${i}.txt`,value:l,getConfigs:r,canCopy:!0})):k().createElement("p",{className:"i"},"Unrecognized response type; unable to display."):null;return m?k().createElement("div",null,k().createElement("h5",null,"Response body"),m):null}}var It=n(9968),Pt=n.n(It);class qt extends O.Component{constructor(e){super(e),ue()(this,"onChange",((e,t,n)=>{let{specActions:{changeParamByIdentity:s},onChangeKey:r}=this.props;s(r,e,t,n)})),ue()(this,"onChangeConsumesWrapper",(e=>{let{specActions:{changeConsumesValue:t},onChangeKey:n}=this.props;t(n,e)})),ue()(this,"toggleTab",(e=>"parameters"===e?this.setState({parametersVisible:!0,callbackVisible:!1}):"callbacks"===e?this.setState({callbackVisible:!0,parametersVisible:!1}):void 0)),ue()(this,"onChangeMediaType",(e=>{let{value:t,pathMethod:n}=e,{specActions:s,oas3Selectors:r,oas3Actions:a}=this.props;const o=r.hasUserEditedBody(...n),l=r.shouldRetainRequestBodyValue(...n);a.setRequestContentType({value:t,pathMethod:n}),a.initRequestBodyValidateError({pathMethod:n}),o||(l||a.setRequestBodyValue({value:void 0,pathMethod:n}),s.clearResponse(...n),s.clearRequest(...n),s.clearValidateParams(n))})),this.state={callbackVisible:!1,parametersVisible:!0}}render(){var e;let{onTryoutClick:t,onResetClick:n,parameters:s,allowTryItOut:r,tryItOutEnabled:a,specPath:o,fn:l,getComponent:c,getConfigs:i,specSelectors:u,specActions:p,pathMethod:m,oas3Actions:d,oas3Selectors:h,operation:g}=this.props;const f=c("parameterRow"),y=c("TryItOutButton"),v=c("contentType"),E=c("Callbacks",!0),w=c("RequestBody",!0),x=a&&r,j=u.isOAS3(),_=g.get("requestBody"),O=C()(e=Pt()(C()(s).call(s,((e,t)=>{const n=t.get("in");return e[n]??(e[n]=[]),e[n].push(t),e}),{}))).call(e,((e,t)=>N()(e).call(e,t)),[]);return k().createElement("div",{className:"opblock-section"},k().createElement("div",{className:"opblock-section-header"},j?k().createElement("div",{className:"tab-header"},k().createElement("div",{onClick:()=>this.toggleTab("parameters"),className:`tab-item ${this.state.parametersVisible&&"active"}`},k().createElement("h4",{className:"opblock-title"},k().createElement("span",null,"Parameters"))),g.get("callbacks")?k().createElement("div",{onClick:()=>this.toggleTab("callbacks"),className:`tab-item ${this.state.callbackVisible&&"active"}`},k().createElement("h4",{className:"opblock-title"},k().createElement("span",null,"Callbacks"))):null):k().createElement("div",{className:"tab-header"},k().createElement("h4",{className:"opblock-title"},"Parameters")),r?k().createElement(y,{isOAS3:u.isOAS3(),hasUserEditedBody:h.hasUserEditedBody(...m),enabled:a,onCancelClick:this.props.onCancelClick,onTryoutClick:t,onResetClick:()=>n(m)}):null),this.state.parametersVisible?k().createElement("div",{className:"parameters-container"},O.length?k().createElement("div",{className:"table-container"},k().createElement("table",{className:"parameters"},k().createElement("thead",null,k().createElement("tr",null,k().createElement("th",{className:"col_header parameters-col_name"},"Name"),k().createElement("th",{className:"col_header parameters-col_description"},"Description"))),k().createElement("tbody",null,b()(O).call(O,((e,t)=>k().createElement(f,{fn:l,specPath:o.push(t.toString()),getComponent:c,getConfigs:i,rawParam:e,param:u.parameterWithMetaByIdentity(m,e),key:`${e.get("in")}.${e.get("name")}`,onChange:this.onChange,onChangeConsumes:this.onChangeConsumesWrapper,specSelectors:u,specActions:p,oas3Actions:d,oas3Selectors:h,pathMethod:m,isExecute:x})))))):k().createElement("div",{className:"opblock-description-wrapper"},k().createElement("p",null,"No parameters"))):null,this.state.callbackVisible?k().createElement("div",{className:"callbacks-container opblock-description-wrapper"},k().createElement(E,{callbacks:(0,I.Map)(g.get("callbacks")),specPath:S()(o).call(o,0,-1).push("callbacks")})):null,j&&_&&this.state.parametersVisible&&k().createElement("div",{className:"opblock-section opblock-section-request-body"},k().createElement("div",{className:"opblock-section-header"},k().createElement("h4",{className:`opblock-title parameter__name ${_.get("required")&&"required"}`},"Request body"),k().createElement("label",null,k().createElement(v,{value:h.requestContentType(...m),contentTypes:_.get("content",(0,I.List)()).keySeq(),onChange:e=>{this.onChangeMediaType({value:e,pathMethod:m})},className:"body-param-content-type",ariaLabel:"Request content type"}))),k().createElement("div",{className:"opblock-description-wrapper"},k().createElement(w,{setRetainRequestBodyValueFlag:e=>d.setRetainRequestBodyValueFlag({value:e,pathMethod:m}),userHasEditedBody:h.hasUserEditedBody(...m),specPath:S()(o).call(o,0,-1).push("requestBody"),requestBody:_,requestBodyValue:h.requestBodyValue(...m),requestBodyInclusionSetting:h.requestBodyInclusionSetting(...m),requestBodyErrors:h.requestBodyErrors(...m),isExecute:x,getConfigs:i,activeExamplesKey:h.activeExamplesMember(...m,"requestBody","requestBody"),updateActiveExamplesKey:e=>{this.props.oas3Actions.setActiveExamplesMember({name:e,pathMethod:this.props.pathMethod,contextType:"requestBody",contextName:"requestBody"})},onChange:(e,t)=>{if(t){const n=h.requestBodyValue(...m),s=I.Map.isMap(n)?n:(0,I.Map)();return d.setRequestBodyValue({pathMethod:m,value:s.setIn(t,e)})}d.setRequestBodyValue({value:e,pathMethod:m})},onChangeIncludeEmpty:(e,t)=>{d.setRequestBodyInclusion({pathMethod:m,value:t,name:e})},contentType:h.requestContentType(...m)}))))}}ue()(qt,"defaultProps",{onTryoutClick:Function.prototype,onCancelClick:Function.prototype,tryItOutEnabled:!1,allowTryItOut:!0,onChangeKey:[],specPath:[]});const Rt=e=>{let{xKey:t,xVal:n}=e;return k().createElement("div",{className:"parameter__extension"},t,": ",String(n))},Tt={onChange:()=>{},isIncludedOptions:{}};class Mt extends O.Component{constructor(){super(...arguments),ue()(this,"onCheckboxChange",(e=>{const{onChange:t}=this.props;t(e.target.checked)}))}componentDidMount(){const{isIncludedOptions:e,onChange:t}=this.props,{shouldDispatchInit:n,defaultValue:s}=e;n&&t(s)}render(){let{isIncluded:e,isDisabled:t}=this.props;return k().createElement("div",null,k().createElement("label",{className:lt()("parameter__empty_value_toggle",{disabled:t})},k().createElement("input",{type:"checkbox",disabled:t,checked:!t&&e,onChange:this.onCheckboxChange}),"Send empty value"))}}ue()(Mt,"defaultProps",Tt);var Dt=n(9069);class Jt extends O.Component{constructor(e,t){var n;super(e,t),n=this,ue()(this,"onChangeWrapper",(function(e){let t,s=arguments.length>1&&void 0!==arguments[1]&&arguments[1],{onChange:r,rawParam:a}=n.props;return t=""===e||e&&0===e.size?null:e,r(a,t,s)})),ue()(this,"_onExampleSelect",(e=>{this.props.oas3Actions.setActiveExamplesMember({name:e,pathMethod:this.props.pathMethod,contextType:"parameters",contextName:this.getParamKey()})})),ue()(this,"onChangeIncludeEmpty",(e=>{let{specActions:t,param:n,pathMethod:s}=this.props;const r=n.get("name"),a=n.get("in");return t.updateEmptyParamInclusion(s,r,a,e)})),ue()(this,"setDefaultValue",(()=>{let{specSelectors:e,pathMethod:t,rawParam:n,oas3Selectors:s,fn:r}=this.props;const a=e.parameterWithMetaByIdentity(t,n)||(0,I.Map)(),{schema:o}=(0,Dt.Z)(a,{isOAS3:e.isOAS3()}),l=a.get("content",(0,I.Map)()).keySeq().first(),c=o?r.getSampleSchema(o.toJS(),l,{includeWriteOnly:!0}):null;if(a&&void 0===a.get("value")&&"body"!==a.get("in")){let n;if(e.isSwagger2())n=void 0!==a.get("x-example")?a.get("x-example"):void 0!==a.getIn(["schema","example"])?a.getIn(["schema","example"]):o&&o.getIn(["default"]);else if(e.isOAS3()){const e=s.activeExamplesMember(...t,"parameters",this.getParamKey());n=void 0!==a.getIn(["examples",e,"value"])?a.getIn(["examples",e,"value"]):void 0!==a.getIn(["content",l,"example"])?a.getIn(["content",l,"example"]):void 0!==a.get("example")?a.get("example"):void 0!==(o&&o.get("example"))?o&&o.get("example"):void 0!==(o&&o.get("default"))?o&&o.get("default"):a.get("default")}void 0===n||I.List.isList(n)||(n=(0,$.Pz)(n)),void 0!==n?this.onChangeWrapper(n):o&&"object"===o.get("type")&&c&&!a.get("examples")&&this.onChangeWrapper(I.List.isList(c)?c:(0,$.Pz)(c))}})),this.setDefaultValue()}UNSAFE_componentWillReceiveProps(e){let t,{specSelectors:n,pathMethod:s,rawParam:r}=e,a=n.isOAS3(),o=n.parameterWithMetaByIdentity(s,r)||new I.Map;if(o=o.isEmpty()?r:o,a){let{schema:e}=(0,Dt.Z)(o,{isOAS3:a});t=e?e.get("enum"):void 0}else t=o?o.get("enum"):void 0;let l,c=o?o.get("value"):void 0;void 0!==c?l=c:r.get("required")&&t&&t.size&&(l=t.first()),void 0!==l&&l!==c&&this.onChangeWrapper((0,$.D$)(l)),this.setDefaultValue()}getParamKey(){const{param:e}=this.props;return e?`${e.get("name")}-${e.get("in")}`:null}render(){var e,t;let{param:n,rawParam:s,getComponent:r,getConfigs:a,isExecute:o,fn:l,onChangeConsumes:c,specSelectors:i,pathMethod:u,specPath:p,oas3Selectors:m}=this.props,d=i.isOAS3();const{showExtensions:h,showCommonExtensions:g}=a();if(n||(n=s),!s)return null;const f=r("JsonSchemaForm"),y=r("ParamBody");let v=n.get("in"),S="body"!==v?null:k().createElement(y,{getComponent:r,getConfigs:a,fn:l,param:n,consumes:i.consumesOptionsFor(u),consumesValue:i.contentTypeValues(u).get("requestContentType"),onChange:this.onChangeWrapper,onChangeConsumes:c,isExecute:o,specSelectors:i,pathMethod:u});const E=r("modelExample"),w=r("Markdown",!0),x=r("ParameterExt"),C=r("ParameterIncludeEmpty"),j=r("ExamplesSelectValueRetainer"),_=r("Example");let N,O,A,P,{schema:q}=(0,Dt.Z)(n,{isOAS3:d}),R=i.parameterWithMetaByIdentity(u,s)||(0,I.Map)(),T=q?q.get("format"):null,M=q?q.get("type"):null,D=q?q.getIn(["items","type"]):null,L="formData"===v,K="FormData"in J.Z,V=n.get("required"),U=R?R.get("value"):"",F=g?(0,$.po)(q):null,z=h?(0,$.nX)(n):null,B=!1;return void 0!==n&&q&&(N=q.get("items")),void 0!==N?(O=N.get("enum"),A=N.get("default")):q&&(O=q.get("enum")),O&&O.size&&O.size>0&&(B=!0),void 0!==n&&(q&&(A=q.get("default")),void 0===A&&(A=n.get("default")),P=n.get("example"),void 0===P&&(P=n.get("x-example"))),k().createElement("tr",{"data-param-name":n.get("name"),"data-param-in":n.get("in")},k().createElement("td",{className:"parameters-col_name"},k().createElement("div",{className:V?"parameter__name required":"parameter__name"},n.get("name"),V?k().createElement("span",null," *"):null),k().createElement("div",{className:"parameter__type"},M,D&&`[${D}]`,T&&k().createElement("span",{className:"prop-format"},"($",T,")")),k().createElement("div",{className:"parameter__deprecated"},d&&n.get("deprecated")?"deprecated":null),k().createElement("div",{className:"parameter__in"},"(",n.get("in"),")"),g&&F.size?b()(e=F.entrySeq()).call(e,(e=>{let[t,n]=e;return k().createElement(x,{key:`${t}-${n}`,xKey:t,xVal:n})})):null,h&&z.size?b()(t=z.entrySeq()).call(t,(e=>{let[t,n]=e;return k().createElement(x,{key:`${t}-${n}`,xKey:t,xVal:n})})):null),k().createElement("td",{className:"parameters-col_description"},n.get("description")?k().createElement(w,{source:n.get("description")}):null,!S&&o||!B?null:k().createElement(w,{className:"parameter__enum",source:"<i>Available values</i> : "+b()(O).call(O,(function(e){return e})).toArray().join(", ")}),!S&&o||void 0===A?null:k().createElement(w,{className:"parameter__default",source:"<i>Default value</i> : "+A}),!S&&o||void 0===P?null:k().createElement(w,{source:"<i>Example</i> : "+P}),L&&!K&&k().createElement("div",null,"Error: your browser does not support FormData"),d&&n.get("examples")?k().createElement("section",{className:"parameter-controls"},k().createElement(j,{examples:n.get("examples"),onSelect:this._onExampleSelect,updateValue:this.onChangeWrapper,getComponent:r,defaultToFirstExample:!0,currentKey:m.activeExamplesMember(...u,"parameters",this.getParamKey()),currentUserInputValue:U})):null,S?null:k().createElement(f,{fn:l,getComponent:r,value:U,required:V,disabled:!o,description:n.get("name"),onChange:this.onChangeWrapper,errors:R.get("errors"),schema:q}),S&&q?k().createElement(E,{getComponent:r,specPath:p.push("schema"),getConfigs:a,isExecute:o,specSelectors:i,schema:q,example:S,includeWriteOnly:!0}):null,!S&&o&&n.get("allowEmptyValue")?k().createElement(C,{onChange:this.onChangeIncludeEmpty,isIncluded:i.parameterInclusionSettingFor(u,n.get("name"),n.get("in")),isDisabled:!(0,$.O2)(U)}):null,d&&n.get("examples")?k().createElement(_,{example:n.getIn(["examples",m.activeExamplesMember(...u,"parameters",this.getParamKey())]),getComponent:r,getConfigs:a}):null))}}var $t=n(9300),Lt=n.n($t);class Kt extends O.Component{constructor(){super(...arguments),ue()(this,"handleValidateParameters",(()=>{let{specSelectors:e,specActions:t,path:n,method:s}=this.props;return t.validateParams([n,s]),e.validateBeforeExecute([n,s])})),ue()(this,"handleValidateRequestBody",(()=>{let{path:e,method:t,specSelectors:n,oas3Selectors:s,oas3Actions:r}=this.props,a={missingBodyValue:!1,missingRequiredKeys:[]};r.clearRequestBodyValidateError({path:e,method:t});let o=n.getOAS3RequiredRequestBodyContentType([e,t]),l=s.requestBodyValue(e,t),c=s.validateBeforeExecute([e,t]),i=s.requestContentType(e,t);if(!c)return a.missingBodyValue=!0,r.setRequestBodyValidateError({path:e,method:t,validationErrors:a}),!1;if(!o)return!0;let u=s.validateShallowRequired({oas3RequiredRequestBodyContentType:o,oas3RequestContentType:i,oas3RequestBodyValue:l});return!u||u.length<1||(at()(u).call(u,(e=>{a.missingRequiredKeys.push(e)})),r.setRequestBodyValidateError({path:e,method:t,validationErrors:a}),!1)})),ue()(this,"handleValidationResultPass",(()=>{let{specActions:e,operation:t,path:n,method:s}=this.props;this.props.onExecute&&this.props.onExecute(),e.execute({operation:t,path:n,method:s})})),ue()(this,"handleValidationResultFail",(()=>{let{specActions:e,path:t,method:n}=this.props;e.clearValidateParams([t,n]),Lt()((()=>{e.validateParams([t,n])}),40)})),ue()(this,"handleValidationResult",(e=>{e?this.handleValidationResultPass():this.handleValidationResultFail()})),ue()(this,"onClick",(()=>{let e=this.handleValidateParameters(),t=this.handleValidateRequestBody(),n=e&&t;this.handleValidationResult(n)})),ue()(this,"onChangeProducesWrapper",(e=>this.props.specActions.changeProducesValue([this.props.path,this.props.method],e)))}render(){const{disabled:e}=this.props;return k().createElement("button",{className:"btn execute opblock-control__btn",onClick:this.onClick,disabled:e},"Execute")}}class Vt extends k().Component{render(){var e;let{headers:t,getComponent:n}=this.props;const s=n("Property"),r=n("Markdown",!0);return t&&t.size?k().createElement("div",{className:"headers-wrapper"},k().createElement("h4",{className:"headers__title"},"Headers:"),k().createElement("table",{className:"headers"},k().createElement("thead",null,k().createElement("tr",{className:"header-row"},k().createElement("th",{className:"header-col"},"Name"),k().createElement("th",{className:"header-col"},"Description"),k().createElement("th",{className:"header-col"},"Type"))),k().createElement("tbody",null,b()(e=t.entrySeq()).call(e,(e=>{let[t,n]=e;if(!P().Map.isMap(n))return null;const a=n.get("description"),o=n.getIn(["schema"])?n.getIn(["schema","type"]):n.getIn(["type"]),l=n.getIn(["schema","example"]);return k().createElement("tr",{key:t},k().createElement("td",{className:"header-col"},t),k().createElement("td",{className:"header-col"},a?k().createElement(r,{source:a}):null),k().createElement("td",{className:"header-col"},o," ",l?k().createElement(s,{propKey:"Example",propVal:l,propClass:"header-example"}):null))})).toArray()))):null}}class Ut extends k().Component{render(){let{editorActions:e,errSelectors:t,layoutSelectors:n,layoutActions:s,getComponent:r}=this.props;const a=r("Collapse");if(e&&e.jumpToLine)var o=e.jumpToLine;let c=t.allErrors(),i=l()(c).call(c,(e=>"thrown"===e.get("type")||"error"===e.get("level")));if(!i||i.count()<1)return null;let u=n.isShown(["errorPane"],!0),p=i.sortBy((e=>e.get("line")));return k().createElement("pre",{className:"errors-wrapper"},k().createElement("hgroup",{className:"error"},k().createElement("h4",{className:"errors__title"},"Errors"),k().createElement("button",{className:"btn errors__clear-btn",onClick:()=>s.show(["errorPane"],!u)},u?"Hide":"Show")),k().createElement(a,{isOpened:u,animated:!0},k().createElement("div",{className:"errors"},b()(p).call(p,((e,t)=>{let n=e.get("type");return"thrown"===n||"auth"===n?k().createElement(Ft,{key:t,error:e.get("error")||e,jumpToLine:o}):"spec"===n?k().createElement(zt,{key:t,error:e,jumpToLine:o}):void 0})))))}}const Ft=e=>{let{error:t,jumpToLine:n}=e;if(!t)return null;let s=t.get("line");return k().createElement("div",{className:"error-wrapper"},t?k().createElement("div",null,k().createElement("h4",null,t.get("source")&&t.get("level")?Bt(t.get("source"))+" "+t.get("level"):"",t.get("path")?k().createElement("small",null," at ",t.get("path")):null),k().createElement("span",{className:"message thrown"},t.get("message")),k().createElement("div",{className:"error-line"},s&&n?k().createElement("a",{onClick:g()(n).call(n,null,s)},"Jump to line ",s):null)):null)},zt=e=>{let{error:t,jumpToLine:n}=e,s=null;return t.get("path")?s=I.List.isList(t.get("path"))?k().createElement("small",null,"at ",t.get("path").join(".")):k().createElement("small",null,"at ",t.get("path")):t.get("line")&&!n&&(s=k().createElement("small",null,"on line ",t.get("line"))),k().createElement("div",{className:"error-wrapper"},t?k().createElement("div",null,k().createElement("h4",null,Bt(t.get("source"))+" "+t.get("level")," ",s),k().createElement("span",{className:"message"},t.get("message")),k().createElement("div",{className:"error-line"},n?k().createElement("a",{onClick:g()(n).call(n,null,t.get("line"))},"Jump to line ",t.get("line")):null)):null)};function Bt(e){var t;return b()(t=(e||"").split(" ")).call(t,(e=>e[0].toUpperCase()+S()(e).call(e,1))).join(" ")}Ft.defaultProps={jumpToLine:null};class Wt extends k().Component{constructor(){super(...arguments),ue()(this,"onChangeWrapper",(e=>this.props.onChange(e.target.value)))}componentDidMount(){this.props.contentTypes&&this.props.onChange(this.props.contentTypes.first())}UNSAFE_componentWillReceiveProps(e){var t;e.contentTypes&&e.contentTypes.size&&(Me()(t=e.contentTypes).call(t,e.value)||e.onChange(e.contentTypes.first()))}render(){let{ariaControls:e,ariaLabel:t,className:n,contentTypes:s,controlId:r,value:a}=this.props;return s&&s.size?k().createElement("div",{className:"content-type-wrapper "+(n||"")},k().createElement("select",{"aria-controls":e,"aria-label":t,className:"content-type",id:r,onChange:this.onChangeWrapper,value:a||""},b()(s).call(s,(e=>k().createElement("option",{key:e,value:e},e))).toArray())):null}}ue()(Wt,"defaultProps",{onChange:()=>{},value:null,contentTypes:(0,I.fromJS)(["application/json"])});var Ht=n(4250),Zt=n.n(Ht),Gt=n(7390),Xt=n.n(Gt);function Yt(){for(var e,t=arguments.length,n=new Array(t),s=0;s<t;s++)n[s]=arguments[s];return Xt()(e=l()(n).call(n,(e=>!!e)).join(" ")).call(e)}class Qt extends k().Component{render(){let{fullscreen:e,full:t,...n}=this.props;if(e)return k().createElement("section",n);let s="swagger-container"+(t?"-full":"");return k().createElement("section",Zt()({},n,{className:Yt(n.className,s)}))}}const en={mobile:"",tablet:"-tablet",desktop:"-desktop",large:"-hd"};class tn extends k().Component{render(){const{hide:e,keepContents:t,mobile:n,tablet:s,desktop:r,large:a,...o}=this.props;if(e&&!t)return k().createElement("span",null);let l=[];for(let e in en){if(!Object.prototype.hasOwnProperty.call(en,e))continue;let t=en[e];if(e in this.props){let n=this.props[e];if(n<1){l.push("none"+t);continue}l.push("block"+t),l.push("col-"+n+t)}}e&&l.push("hidden");let c=Yt(o.className,...l);return k().createElement("section",Zt()({},o,{className:c}))}}class nn extends k().Component{render(){return k().createElement("div",Zt()({},this.props,{className:Yt(this.props.className,"wrapper")}))}}class sn extends k().Component{render(){return k().createElement("button",Zt()({},this.props,{className:Yt(this.props.className,"button")}))}}ue()(sn,"defaultProps",{className:""});const rn=e=>k().createElement("textarea",e),an=e=>k().createElement("input",e);class on extends k().Component{constructor(e,t){let n;super(e,t),ue()(this,"onChange",(e=>{let t,{onChange:n,multiple:s}=this.props,r=S()([]).call(e.target.options);var a;s?t=b()(a=l()(r).call(r,(function(e){return e.selected}))).call(a,(function(e){return e.value})):t=e.target.value;this.setState({value:t}),n&&n(t)})),n=e.value?e.value:e.multiple?[""]:"",this.state={value:n}}UNSAFE_componentWillReceiveProps(e){e.value!==this.props.value&&this.setState({value:e.value})}render(){var e,t;let{allowedValues:n,multiple:s,allowEmptyValue:r,disabled:a}=this.props,o=(null===(e=this.state.value)||void 0===e||null===(t=e.toJS)||void 0===t?void 0:t.call(e))||this.state.value;return k().createElement("select",{className:this.props.className,multiple:s,value:o,onChange:this.onChange,disabled:a},r?k().createElement("option",{value:""},"--"):null,b()(n).call(n,(function(e,t){return k().createElement("option",{key:t,value:String(e)},String(e))})))}}ue()(on,"defaultProps",{multiple:!1,allowEmptyValue:!0});class ln extends k().Component{render(){return k().createElement("a",Zt()({},this.props,{rel:"noopener noreferrer",className:Yt(this.props.className,"link")}))}}const cn=e=>{let{children:t}=e;return k().createElement("div",{className:"no-margin"}," ",t," ")};class un extends k().Component{renderNotAnimated(){return this.props.isOpened?k().createElement(cn,null,this.props.children):k().createElement("noscript",null)}render(){let{animated:e,isOpened:t,children:n}=this.props;return e?(n=t?n:null,k().createElement(cn,null,n)):this.renderNotAnimated()}}ue()(un,"defaultProps",{isOpened:!1,animated:!1});class pn extends k().Component{constructor(){var e;super(...arguments),this.setTagShown=g()(e=this._setTagShown).call(e,this)}_setTagShown(e,t){this.props.layoutActions.show(e,t)}showOp(e,t){let{layoutActions:n}=this.props;n.show(e,t)}render(){let{specSelectors:e,layoutSelectors:t,layoutActions:n,getComponent:s}=this.props,r=e.taggedOperations();const a=s("Collapse");return k().createElement("div",null,k().createElement("h4",{className:"overview-title"},"Overview"),b()(r).call(r,((e,s)=>{let r=e.get("operations"),o=["overview-tags",s],l=t.isShown(o,!0);return k().createElement("div",{key:"overview-"+s},k().createElement("h4",{onClick:()=>n.show(o,!l),className:"link overview-tag"}," ",l?"-":"+",s),k().createElement(a,{isOpened:l,animated:!0},b()(r).call(r,(e=>{let{path:s,method:r,id:a}=e.toObject(),o="operations",l=a,c=t.isShown([o,l]);return k().createElement(mn,{key:a,path:s,method:r,id:s+"-"+r,shown:c,showOpId:l,showOpIdPrefix:o,href:`#operation-${l}`,onClick:n.show})})).toArray()))})).toArray(),r.size<1&&k().createElement("h3",null," No operations defined in spec! "))}}class mn extends k().Component{constructor(e){var t;super(e),this.onClick=g()(t=this._onClick).call(t,this)}_onClick(){let{showOpId:e,showOpIdPrefix:t,onClick:n,shown:s}=this.props;n([t,e],!s)}render(){let{id:e,method:t,shown:n,href:s}=this.props;return k().createElement(ln,{href:s,onClick:this.onClick,className:"block opblock-link "+(n?"shown":"")},k().createElement("div",null,k().createElement("small",{className:`bold-label-${t}`},t.toUpperCase()),k().createElement("span",{className:"bold-label"},e)))}}class dn extends k().Component{componentDidMount(){this.props.initialValue&&(this.inputRef.value=this.props.initialValue)}render(){const{value:e,defaultValue:t,initialValue:n,...s}=this.props;return k().createElement("input",Zt()({},s,{ref:e=>this.inputRef=e}))}}class hn extends k().Component{render(){const{host:e,basePath:t}=this.props;return k().createElement("pre",{className:"base-url"},"[ Base URL: ",e,t," ]")}}class gn extends k().PureComponent{render(){const{url:e,getComponent:t}=this.props,n=t("Link");return k().createElement(n,{target:"_blank",href:(0,$.Nm)(e)},k().createElement("span",{className:"url"}," ",e))}}class fn extends k().Component{render(){const{info:e,url:t,host:n,basePath:s,getComponent:r,externalDocs:a,selectedServer:o,url:l}=this.props,c=e.get("version"),i=e.get("description"),u=e.get("title"),p=(0,Be.mn)(e.get("termsOfService"),l,{selectedServer:o}),m=e.get("contact"),d=e.get("license"),h=a&&a.get("url"),g=(0,Be.mn)(h,l,{selectedServer:o}),f=a&&a.get("description"),y=r("Markdown",!0),v=r("Link"),S=r("VersionStamp"),E=r("InfoUrl"),w=r("InfoBasePath"),x=r("License"),C=r("Contact");return k().createElement("div",{className:"info"},k().createElement("hgroup",{className:"main"},k().createElement("h2",{className:"title"},u,c&&k().createElement(S,{version:c})),n||s?k().createElement(w,{host:n,basePath:s}):null,t&&k().createElement(E,{getComponent:r,url:t})),k().createElement("div",{className:"description"},k().createElement(y,{source:i})),p&&k().createElement("div",{className:"info__tos"},k().createElement(v,{target:"_blank",href:(0,$.Nm)(p)},"Terms of service")),(null==m?void 0:m.size)>0&&k().createElement(C,{getComponent:r,data:m,selectedServer:o,url:t}),(null==d?void 0:d.size)>0&&k().createElement(x,{getComponent:r,license:d,selectedServer:o,url:t}),g?k().createElement(v,{className:"info__extdocs",target:"_blank",href:(0,$.Nm)(g)},f||g):null)}}const yn=fn;class vn extends k().Component{render(){const{specSelectors:e,getComponent:t,oas3Selectors:n}=this.props,s=e.info(),r=e.url(),a=e.basePath(),o=e.host(),l=e.externalDocs(),c=n.selectedServer(),i=t("info");return k().createElement("div",null,s&&s.count()?k().createElement(i,{info:s,url:r,host:o,basePath:a,externalDocs:l,getComponent:t,selectedServer:c}):null)}}class Sn extends k().Component{render(){const{data:e,getComponent:t,selectedServer:n,url:s}=this.props,r=e.get("name","the developer"),a=(0,Be.mn)(e.get("url"),s,{selectedServer:n}),o=e.get("email"),l=t("Link");return k().createElement("div",{className:"info__contact"},a&&k().createElement("div",null,k().createElement(l,{href:(0,$.Nm)(a),target:"_blank"},r," - Website")),o&&k().createElement(l,{href:(0,$.Nm)(`mailto:${o}`)},a?`Send email to ${r}`:`Contact ${r}`))}}const En=Sn;class wn extends k().Component{render(){const{license:e,getComponent:t,selectedServer:n,url:s}=this.props,r=e.get("name","License"),a=(0,Be.mn)(e.get("url"),s,{selectedServer:n}),o=t("Link");return k().createElement("div",{className:"info__license"},a?k().createElement("div",{className:"info__license__url"},k().createElement(o,{target:"_blank",href:(0,$.Nm)(a)},r)):k().createElement("span",null,r))}}const xn=wn;class Cn extends k().Component{render(){return null}}class jn extends k().Component{render(){let{getComponent:e}=this.props;const t=e("CopyIcon");return k().createElement("div",{className:"view-line-link copy-to-clipboard",title:"Copy to clipboard"},k().createElement(gt.CopyToClipboard,{text:this.props.textToCopy},k().createElement(t,null)))}}class bn extends k().Component{render(){return k().createElement("div",{className:"footer"})}}class _n extends k().Component{constructor(){super(...arguments),ue()(this,"onFilterChange",(e=>{const{target:{value:t}}=e;this.props.layoutActions.updateFilter(t)}))}render(){const{specSelectors:e,layoutSelectors:t,getComponent:n}=this.props,s=n("Col"),r="loading"===e.loadingStatus(),a="failed"===e.loadingStatus(),o=t.currentFilter(),l=["operation-filter-input"];return a&&l.push("failed"),r&&l.push("loading"),k().createElement("div",null,null===o||!1===o||"false"===o?null:k().createElement("div",{className:"filter-container"},k().createElement(s,{className:"filter wrapper",mobile:12},k().createElement("input",{className:l.join(" "),placeholder:"Filter by tag",type:"text",onChange:this.onFilterChange,value:!0===o||"true"===o?"":o,disabled:r}))))}}const Nn=Function.prototype;class On extends O.PureComponent{constructor(e,t){super(e,t),ue()(this,"updateValues",(e=>{let{param:t,isExecute:n,consumesValue:s=""}=e,r=/xml/i.test(s),a=/json/i.test(s),o=r?t.get("value_xml"):t.get("value");if(void 0!==o){let e=!o&&a?"{}":o;this.setState({value:e}),this.onChange(e,{isXml:r,isEditBox:n})}else r?this.onChange(this.sample("xml"),{isXml:r,isEditBox:n}):this.onChange(this.sample(),{isEditBox:n})})),ue()(this,"sample",(e=>{let{param:t,fn:n}=this.props,s=n.inferSchema(t.toJS());return n.getSampleSchema(s,e,{includeWriteOnly:!0})})),ue()(this,"onChange",((e,t)=>{let{isEditBox:n,isXml:s}=t;this.setState({value:e,isEditBox:n}),this._onChange(e,s)})),ue()(this,"_onChange",((e,t)=>{(this.props.onChange||Nn)(e,t)})),ue()(this,"handleOnChange",(e=>{const{consumesValue:t}=this.props,n=/xml/i.test(t),s=e.target.value;this.onChange(s,{isXml:n,isEditBox:this.state.isEditBox})})),ue()(this,"toggleIsEditBox",(()=>this.setState((e=>({isEditBox:!e.isEditBox}))))),this.state={isEditBox:!1,value:""}}componentDidMount(){this.updateValues.call(this,this.props)}UNSAFE_componentWillReceiveProps(e){this.updateValues.call(this,e)}render(){let{onChangeConsumes:e,param:t,isExecute:n,specSelectors:s,pathMethod:r,getConfigs:a,getComponent:o}=this.props;const l=o("Button"),c=o("TextArea"),i=o("highlightCode"),u=o("contentType");let p=(s?s.parameterWithMetaByIdentity(r,t):t).get("errors",(0,I.List)()),m=s.contentTypeValues(r).get("requestContentType"),d=this.props.consumes&&this.props.consumes.size?this.props.consumes:On.defaultProp.consumes,{value:h,isEditBox:g}=this.state,f=null;return(0,wt.O)(h)&&(f="json"),k().createElement("div",{className:"body-param","data-param-name":t.get("name"),"data-param-in":t.get("in")},g&&n?k().createElement(c,{className:"body-param__text"+(p.count()?" invalid":""),value:h,onChange:this.handleOnChange}):h&&k().createElement(i,{className:"body-param__example",language:f,getConfigs:a,value:h}),k().createElement("div",{className:"body-param-options"},n?k().createElement("div",{className:"body-param-edit"},k().createElement(l,{className:g?"btn cancel body-param__example-edit":"btn edit body-param__example-edit",onClick:this.toggleIsEditBox},g?"Cancel":"Edit")):null,k().createElement("label",{htmlFor:""},k().createElement("span",null,"Parameter content type"),k().createElement(u,{value:m,contentTypes:d,onChange:e,className:"body-param-content-type",ariaLabel:"Parameter content type"}))))}}ue()(On,"defaultProp",{consumes:(0,I.fromJS)(["application/json"]),param:(0,I.fromJS)({}),onChange:Nn,onChangeConsumes:Nn});var kn=n(8223);class An extends k().Component{render(){let{request:e,getConfigs:t}=this.props,n=(0,kn.requestSnippetGenerator_curl_bash)(e);const s=t(),r=ut()(s,"syntaxHighlight.activated")?k().createElement(ct.d3,{language:"bash",className:"curl microlight",style:(0,ct.C2)(ut()(s,"syntaxHighlight.theme"))},n):k().createElement("textarea",{readOnly:!0,className:"curl",value:n});return k().createElement("div",{className:"curl-command"},k().createElement("h4",null,"Curl"),k().createElement("div",{className:"copy-to-clipboard"},k().createElement(gt.CopyToClipboard,{text:n},k().createElement("button",null))),k().createElement("div",null,r))}}class In extends k().Component{constructor(){super(...arguments),ue()(this,"onChange",(e=>{this.setScheme(e.target.value)})),ue()(this,"setScheme",(e=>{let{path:t,method:n,specActions:s}=this.props;s.setScheme(e,t,n)}))}UNSAFE_componentWillMount(){let{schemes:e}=this.props;this.setScheme(e.first())}UNSAFE_componentWillReceiveProps(e){var t;this.props.currentScheme&&Me()(t=e.schemes).call(t,this.props.currentScheme)||this.setScheme(e.schemes.first())}render(){var e;let{schemes:t,currentScheme:n}=this.props;return k().createElement("label",{htmlFor:"schemes"},k().createElement("span",{className:"schemes-title"},"Schemes"),k().createElement("select",{onChange:this.onChange,value:n},b()(e=t.valueSeq()).call(e,(e=>k().createElement("option",{value:e,key:e},e))).toArray()))}}class Pn extends k().Component{render(){const{specActions:e,specSelectors:t,getComponent:n}=this.props,s=t.operationScheme(),r=t.schemes(),a=n("schemes");return r&&r.size?k().createElement(a,{currentScheme:s,schemes:r,specActions:e}):null}}class qn extends O.Component{constructor(e,t){super(e,t),ue()(this,"toggleCollapsed",(()=>{this.props.onToggle&&this.props.onToggle(this.props.modelName,!this.state.expanded),this.setState({expanded:!this.state.expanded})})),ue()(this,"onLoad",(e=>{if(e&&this.props.layoutSelectors){const t=this.props.layoutSelectors.getScrollToKey();P().is(t,this.props.specPath)&&this.toggleCollapsed(),this.props.layoutActions.readyToScroll(this.props.specPath,e.parentElement)}}));let{expanded:n,collapsedContent:s}=this.props;this.state={expanded:n,collapsedContent:s||qn.defaultProps.collapsedContent}}componentDidMount(){const{hideSelfOnExpand:e,expanded:t,modelName:n}=this.props;e&&t&&this.props.onToggle(n,t)}UNSAFE_componentWillReceiveProps(e){this.props.expanded!==e.expanded&&this.setState({expanded:e.expanded})}render(){const{title:e,classes:t}=this.props;return this.state.expanded&&this.props.hideSelfOnExpand?k().createElement("span",{className:t||""},this.props.children):k().createElement("span",{className:t||"",ref:this.onLoad},k().createElement("button",{"aria-expanded":this.state.expanded,className:"model-box-control",onClick:this.toggleCollapsed},e&&k().createElement("span",{className:"pointer"},e),k().createElement("span",{className:"model-toggle"+(this.state.expanded?"":" collapsed")}),!this.state.expanded&&k().createElement("span",null,this.state.collapsedContent)),this.state.expanded&&this.props.children)}}ue()(qn,"defaultProps",{collapsedContent:"{...}",expanded:!1,title:null,onToggle:()=>{},hideSelfOnExpand:!1,specPath:P().List([])});var Rn=n(185),Tn=n.n(Rn);class Mn extends k().Component{constructor(e,t){super(e,t),ue()(this,"activeTab",(e=>{let{target:{dataset:{name:t}}}=e;this.setState({activeTab:t})}));let{getConfigs:n,isExecute:s}=this.props,{defaultModelRendering:r}=n(),a=r;"example"!==r&&"model"!==r&&(a="example"),s&&(a="example"),this.state={activeTab:a}}UNSAFE_componentWillReceiveProps(e){e.isExecute&&!this.props.isExecute&&this.props.example&&this.setState({activeTab:"example"})}render(){let{getComponent:e,specSelectors:t,schema:n,example:s,isExecute:r,getConfigs:a,specPath:o,includeReadOnly:l,includeWriteOnly:c}=this.props,{defaultModelExpandDepth:i}=a();const u=e("ModelWrapper"),p=e("highlightCode"),m=Tn()(5).toString("base64"),d=Tn()(5).toString("base64"),h=Tn()(5).toString("base64"),g=Tn()(5).toString("base64");let f=t.isOAS3();return k().createElement("div",{className:"model-example"},k().createElement("ul",{className:"tab",role:"tablist"},k().createElement("li",{className:lt()("tabitem",{active:"example"===this.state.activeTab}),role:"presentation"},k().createElement("button",{"aria-controls":d,"aria-selected":"example"===this.state.activeTab,className:"tablinks","data-name":"example",id:m,onClick:this.activeTab,role:"tab"},r?"Edit Value":"Example Value")),n&&k().createElement("li",{className:lt()("tabitem",{active:"model"===this.state.activeTab}),role:"presentation"},k().createElement("button",{"aria-controls":g,"aria-selected":"model"===this.state.activeTab,className:lt()("tablinks",{inactive:r}),"data-name":"model",id:h,onClick:this.activeTab,role:"tab"},f?"Schema":"Model"))),"example"===this.state.activeTab&&k().createElement("div",{"aria-hidden":"example"!==this.state.activeTab,"aria-labelledby":m,"data-name":"examplePanel",id:d,role:"tabpanel",tabIndex:"0"},s||k().createElement(p,{value:"(no example available)",getConfigs:a})),"model"===this.state.activeTab&&k().createElement("div",{"aria-hidden":"example"===this.state.activeTab,"aria-labelledby":h,"data-name":"modelPanel",id:g,role:"tabpanel",tabIndex:"0"},k().createElement(u,{schema:n,getComponent:e,getConfigs:a,specSelectors:t,expandDepth:i,specPath:o,includeReadOnly:l,includeWriteOnly:c})))}}class Dn extends O.Component{constructor(){super(...arguments),ue()(this,"onToggle",((e,t)=>{this.props.layoutActions&&this.props.layoutActions.show(this.props.fullPath,t)}))}render(){let{getComponent:e,getConfigs:t}=this.props;const n=e("Model");let s;return this.props.layoutSelectors&&(s=this.props.layoutSelectors.isShown(this.props.fullPath)),k().createElement("div",{className:"model-box"},k().createElement(n,Zt()({},this.props,{getConfigs:t,expanded:s,depth:1,onToggle:this.onToggle,expandDepth:this.props.expandDepth||0})))}}var Jn=n(6024);class $n extends O.Component{constructor(){super(...arguments),ue()(this,"getSchemaBasePath",(()=>this.props.specSelectors.isOAS3()?["components","schemas"]:["definitions"])),ue()(this,"getCollapsedContent",(()=>" ")),ue()(this,"handleToggle",((e,t)=>{const{layoutActions:n}=this.props;n.show([...this.getSchemaBasePath(),e],t),t&&this.props.specActions.requestResolvedSubtree([...this.getSchemaBasePath(),e])})),ue()(this,"onLoadModels",(e=>{e&&this.props.layoutActions.readyToScroll(this.getSchemaBasePath(),e)})),ue()(this,"onLoadModel",(e=>{if(e){const t=e.getAttribute("data-name");this.props.layoutActions.readyToScroll([...this.getSchemaBasePath(),t],e)}}))}render(){var e;let{specSelectors:t,getComponent:n,layoutSelectors:s,layoutActions:r,getConfigs:a}=this.props,o=t.definitions(),{docExpansion:l,defaultModelsExpandDepth:c}=a();if(!o.size||c<0)return null;const i=this.getSchemaBasePath();let u=s.isShown(i,c>0&&"none"!==l);const p=t.isOAS3(),m=n("ModelWrapper"),d=n("Collapse"),h=n("ModelCollapse"),g=n("JumpToPath",!0),f=n("ArrowUpIcon"),y=n("ArrowDownIcon");return k().createElement("section",{className:u?"models is-open":"models",ref:this.onLoadModels},k().createElement("h4",null,k().createElement("button",{"aria-expanded":u,className:"models-control",onClick:()=>r.show(i,!u)},k().createElement("span",null,p?"Schemas":"Models"),u?k().createElement(f,null):k().createElement(y,null))),k().createElement(d,{isOpened:u},b()(e=o.entrySeq()).call(e,(e=>{let[o]=e;const l=[...i,o],u=P().List(l),p=t.specResolvedSubtree(l),d=t.specJson().getIn(l),f=I.Map.isMap(p)?p:P().Map(),y=I.Map.isMap(d)?d:P().Map(),v=f.get("title")||y.get("title")||o,S=s.isShown(l,!1);S&&0===f.size&&y.size>0&&this.props.specActions.requestResolvedSubtree(l);const E=k().createElement(m,{name:o,expandDepth:c,schema:f||P().Map(),displayName:v,fullPath:l,specPath:u,getComponent:n,specSelectors:t,getConfigs:a,layoutSelectors:s,layoutActions:r,includeReadOnly:!0,includeWriteOnly:!0}),w=k().createElement("span",{className:"model-box"},k().createElement("span",{className:"model model-title"},v));return k().createElement("div",{id:`model-${o}`,className:"model-container",key:`models-section-${o}`,"data-name":o,ref:this.onLoadModel},k().createElement("span",{className:"models-jump-to-path"},k().createElement(g,{specPath:u})),k().createElement(h,{classes:"model-box",collapsedContent:this.getCollapsedContent(o),onToggle:this.handleToggle,title:w,displayName:v,modelName:o,specPath:u,layoutSelectors:s,layoutActions:r,hideSelfOnExpand:!0,expanded:c>0&&S},E))})).toArray()))}}const Ln=e=>{let{value:t,getComponent:n}=e,s=n("ModelCollapse"),r=k().createElement("span",null,"Array [ ",t.count()," ]");return k().createElement("span",{className:"prop-enum"},"Enum:",k().createElement("br",null),k().createElement(s,{collapsedContent:r},"[ ",t.join(", ")," ]"))};class Kn extends O.Component{render(){var e,t,n,s;let{schema:r,name:a,displayName:o,isRef:c,getComponent:i,getConfigs:u,depth:m,onToggle:d,expanded:h,specPath:g,...f}=this.props,{specSelectors:y,expandDepth:v,includeReadOnly:E,includeWriteOnly:w}=f;const{isOAS3:x}=y;if(!r)return null;const{showExtensions:C}=u();let j=r.get("description"),_=r.get("properties"),N=r.get("additionalProperties"),O=r.get("title")||o||a,A=r.get("required"),P=l()(r).call(r,((e,t)=>{var n;return-1!==me()(n=["maxProperties","minProperties","nullable","example"]).call(n,t)})),q=r.get("deprecated"),R=r.getIn(["externalDocs","url"]),T=r.getIn(["externalDocs","description"]);const M=i("JumpToPath",!0),D=i("Markdown",!0),J=i("Model"),L=i("ModelCollapse"),K=i("Property"),V=i("Link"),U=()=>k().createElement("span",{className:"model-jump-to-path"},k().createElement(M,{specPath:g})),F=k().createElement("span",null,k().createElement("span",null,"{"),"...",k().createElement("span",null,"}"),c?k().createElement(U,null):""),z=y.isOAS3()?r.get("anyOf"):null,B=y.isOAS3()?r.get("oneOf"):null,W=y.isOAS3()?r.get("not"):null,H=O&&k().createElement("span",{className:"model-title"},c&&r.get("$$ref")&&k().createElement("span",{className:"model-hint"},r.get("$$ref")),k().createElement("span",{className:"model-title__text"},O));return k().createElement("span",{className:"model"},k().createElement(L,{modelName:a,title:H,onToggle:d,expanded:!!h||m<=v,collapsedContent:F},k().createElement("span",{className:"brace-open object"},"{"),c?k().createElement(U,null):null,k().createElement("span",{className:"inner-object"},k().createElement("table",{className:"model"},k().createElement("tbody",null,j?k().createElement("tr",{className:"description"},k().createElement("td",null,"description:"),k().createElement("td",null,k().createElement(D,{source:j}))):null,R&&k().createElement("tr",{className:"external-docs"},k().createElement("td",null,"externalDocs:"),k().createElement("td",null,k().createElement(V,{target:"_blank",href:(0,$.Nm)(R)},T||R))),q?k().createElement("tr",{className:"property"},k().createElement("td",null,"deprecated:"),k().createElement("td",null,"true")):null,_&&_.size?b()(e=l()(t=_.entrySeq()).call(t,(e=>{let[,t]=e;return(!t.get("readOnly")||E)&&(!t.get("writeOnly")||w)}))).call(e,(e=>{let[t,n]=e,s=x()&&n.get("deprecated"),r=I.List.isList(A)&&A.contains(t),o=["property-row"];return s&&o.push("deprecated"),r&&o.push("required"),k().createElement("tr",{key:t,className:o.join(" ")},k().createElement("td",null,t,r&&k().createElement("span",{className:"star"},"*")),k().createElement("td",null,k().createElement(J,Zt()({key:`object-${a}-${t}_${n}`},f,{required:r,getComponent:i,specPath:g.push("properties",t),getConfigs:u,schema:n,depth:m+1}))))})).toArray():null,C?k().createElement("tr",null,k().createElement("td",null," ")):null,C?b()(n=r.entrySeq()).call(n,(e=>{let[t,n]=e;if("x-"!==S()(t).call(t,0,2))return;const s=n?n.toJS?n.toJS():n:null;return k().createElement("tr",{key:t,className:"extension"},k().createElement("td",null,t),k().createElement("td",null,p()(s)))})).toArray():null,N&&N.size?k().createElement("tr",null,k().createElement("td",null,"< * >:"),k().createElement("td",null,k().createElement(J,Zt()({},f,{required:!1,getComponent:i,specPath:g.push("additionalProperties"),getConfigs:u,schema:N,depth:m+1})))):null,z?k().createElement("tr",null,k().createElement("td",null,"anyOf ->"),k().createElement("td",null,b()(z).call(z,((e,t)=>k().createElement("div",{key:t},k().createElement(J,Zt()({},f,{required:!1,getComponent:i,specPath:g.push("anyOf",t),getConfigs:u,schema:e,depth:m+1}))))))):null,B?k().createElement("tr",null,k().createElement("td",null,"oneOf ->"),k().createElement("td",null,b()(B).call(B,((e,t)=>k().createElement("div",{key:t},k().createElement(J,Zt()({},f,{required:!1,getComponent:i,specPath:g.push("oneOf",t),getConfigs:u,schema:e,depth:m+1}))))))):null,W?k().createElement("tr",null,k().createElement("td",null,"not ->"),k().createElement("td",null,k().createElement("div",null,k().createElement(J,Zt()({},f,{required:!1,getComponent:i,specPath:g.push("not"),getConfigs:u,schema:W,depth:m+1}))))):null))),k().createElement("span",{className:"brace-close"},"}")),P.size?b()(s=P.entrySeq()).call(s,(e=>{let[t,n]=e;return k().createElement(K,{key:`${t}-${n}`,propKey:t,propVal:n,propClass:"property"})})):null)}}class Vn extends O.Component{render(){var e;let{getComponent:t,getConfigs:n,schema:s,depth:r,expandDepth:a,name:o,displayName:c,specPath:i}=this.props,u=s.get("description"),p=s.get("items"),m=s.get("title")||c||o,d=l()(s).call(s,((e,t)=>{var n;return-1===me()(n=["type","items","description","$$ref","externalDocs"]).call(n,t)})),h=s.getIn(["externalDocs","url"]),g=s.getIn(["externalDocs","description"]);const f=t("Markdown",!0),y=t("ModelCollapse"),v=t("Model"),S=t("Property"),E=t("Link"),w=m&&k().createElement("span",{className:"model-title"},k().createElement("span",{className:"model-title__text"},m));return k().createElement("span",{className:"model"},k().createElement(y,{title:w,expanded:r<=a,collapsedContent:"[...]"},"[",d.size?b()(e=d.entrySeq()).call(e,(e=>{let[t,n]=e;return k().createElement(S,{key:`${t}-${n}`,propKey:t,propVal:n,propClass:"property"})})):null,u?k().createElement(f,{source:u}):d.size?k().createElement("div",{className:"markdown"}):null,h&&k().createElement("div",{className:"external-docs"},k().createElement(E,{target:"_blank",href:(0,$.Nm)(h)},g||h)),k().createElement("span",null,k().createElement(v,Zt()({},this.props,{getConfigs:n,specPath:i.push("items"),name:null,schema:p,required:!1,depth:r+1}))),"]"))}}const Un="property primitive";class Fn extends O.Component{render(){var e,t,n;let{schema:s,getComponent:r,getConfigs:a,name:o,displayName:c,depth:i,expandDepth:u}=this.props;const{showExtensions:p}=a();if(!s||!s.get)return k().createElement("div",null);let m=s.get("type"),d=s.get("format"),h=s.get("xml"),g=s.get("enum"),f=s.get("title")||c||o,y=s.get("description"),v=(0,$.nX)(s),S=l()(s).call(s,((e,t)=>{var n;return-1===me()(n=["enum","type","format","description","$$ref","externalDocs"]).call(n,t)})).filterNot(((e,t)=>v.has(t))),E=s.getIn(["externalDocs","url"]),w=s.getIn(["externalDocs","description"]);const x=r("Markdown",!0),C=r("EnumModel"),j=r("Property"),_=r("ModelCollapse"),N=r("Link"),O=f&&k().createElement("span",{className:"model-title"},k().createElement("span",{className:"model-title__text"},f));return k().createElement("span",{className:"model"},k().createElement(_,{title:O,expanded:i<=u,collapsedContent:"[...]",hideSelfOnExpand:u!==i},k().createElement("span",{className:"prop"},o&&i>1&&k().createElement("span",{className:"prop-name"},f),k().createElement("span",{className:"prop-type"},m),d&&k().createElement("span",{className:"prop-format"},"($",d,")"),S.size?b()(e=S.entrySeq()).call(e,(e=>{let[t,n]=e;return k().createElement(j,{key:`${t}-${n}`,propKey:t,propVal:n,propClass:Un})})):null,p&&v.size?b()(t=v.entrySeq()).call(t,(e=>{let[t,n]=e;return k().createElement(j,{key:`${t}-${n}`,propKey:t,propVal:n,propClass:Un})})):null,y?k().createElement(x,{source:y}):null,E&&k().createElement("div",{className:"external-docs"},k().createElement(N,{target:"_blank",href:(0,$.Nm)(E)},w||E)),h&&h.size?k().createElement("span",null,k().createElement("br",null),k().createElement("span",{className:Un},"xml:"),b()(n=h.entrySeq()).call(n,(e=>{let[t,n]=e;return k().createElement("span",{key:`${t}-${n}`,className:Un},k().createElement("br",null),"   ",t,": ",String(n))})).toArray()):null,g&&k().createElement(C,{value:g,getComponent:r}))))}}const zn=e=>{let{propKey:t,propVal:n,propClass:s}=e;return k().createElement("span",{className:s},k().createElement("br",null),t,": ",String(n))};class Bn extends k().Component{render(){const{onTryoutClick:e,onCancelClick:t,onResetClick:n,enabled:s,hasUserEditedBody:r,isOAS3:a}=this.props,o=a&&r;return k().createElement("div",{className:o?"try-out btn-group":"try-out"},s?k().createElement("button",{className:"btn try-out__btn cancel",onClick:t},"Cancel"):k().createElement("button",{className:"btn try-out__btn",onClick:e},"Try it out "),o&&k().createElement("button",{className:"btn try-out__btn reset",onClick:n},"Reset"))}}ue()(Bn,"defaultProps",{onTryoutClick:Function.prototype,onCancelClick:Function.prototype,onResetClick:Function.prototype,enabled:!1,hasUserEditedBody:!1,isOAS3:!1});class Wn extends k().PureComponent{render(){const{bypass:e,isSwagger2:t,isOAS3:n,alsoShow:s}=this.props;return e?k().createElement("div",null,this.props.children):t&&n?k().createElement("div",{className:"version-pragma"},s,k().createElement("div",{className:"version-pragma__message version-pragma__message--ambiguous"},k().createElement("div",null,k().createElement("h3",null,"Unable to render this definition"),k().createElement("p",null,k().createElement("code",null,"swagger")," and ",k().createElement("code",null,"openapi")," fields cannot be present in the same Swagger or OpenAPI definition. Please remove one of the fields."),k().createElement("p",null,"Supported version fields are ",k().createElement("code",null,"swagger: ",'"2.0"')," and those that match ",k().createElement("code",null,"openapi: 3.0.n")," (for example, ",k().createElement("code",null,"openapi: 3.0.0"),").")))):t||n?k().createElement("div",null,this.props.children):k().createElement("div",{className:"version-pragma"},s,k().createElement("div",{className:"version-pragma__message version-pragma__message--missing"},k().createElement("div",null,k().createElement("h3",null,"Unable to render this definition"),k().createElement("p",null,"The provided definition does not specify a valid version field."),k().createElement("p",null,"Please indicate a valid Swagger or OpenAPI version field. Supported version fields are ",k().createElement("code",null,"swagger: ",'"2.0"')," and those that match ",k().createElement("code",null,"openapi: 3.0.n")," (for example, ",k().createElement("code",null,"openapi: 3.0.0"),")."))))}}ue()(Wn,"defaultProps",{alsoShow:null,children:null,bypass:!1});const Hn=e=>{let{version:t}=e;return k().createElement("small",null,k().createElement("pre",{className:"version"}," ",t," "))},Zn=e=>{let{enabled:t,path:n,text:s}=e;return k().createElement("a",{className:"nostyle",onClick:t?e=>e.preventDefault():null,href:t?`#/${n}`:null},k().createElement("span",null,s))},Gn=()=>k().createElement("div",null,k().createElement("svg",{xmlns:"http://www.w3.org/2000/svg",xmlnsXlink:"http://www.w3.org/1999/xlink",className:"svg-assets"},k().createElement("defs",null,k().createElement("symbol",{viewBox:"0 0 20 20",id:"unlocked"},k().createElement("path",{d:"M15.8 8H14V5.6C14 2.703 12.665 1 10 1 7.334 1 6 2.703 6 5.6V6h2v-.801C8 3.754 8.797 3 10 3c1.203 0 2 .754 2 2.199V8H4c-.553 0-1 .646-1 1.199V17c0 .549.428 1.139.951 1.307l1.197.387C5.672 18.861 6.55 19 7.1 19h5.8c.549 0 1.428-.139 1.951-.307l1.196-.387c.524-.167.953-.757.953-1.306V9.199C17 8.646 16.352 8 15.8 8z"})),k().createElement("symbol",{viewBox:"0 0 20 20",id:"locked"},k().createElement("path",{d:"M15.8 8H14V5.6C14 2.703 12.665 1 10 1 7.334 1 6 2.703 6 5.6V8H4c-.553 0-1 .646-1 1.199V17c0 .549.428 1.139.951 1.307l1.197.387C5.672 18.861 6.55 19 7.1 19h5.8c.549 0 1.428-.139 1.951-.307l1.196-.387c.524-.167.953-.757.953-1.306V9.199C17 8.646 16.352 8 15.8 8zM12 8H8V5.199C8 3.754 8.797 3 10 3c1.203 0 2 .754 2 2.199V8z"})),k().createElement("symbol",{viewBox:"0 0 20 20",id:"close"},k().createElement("path",{d:"M14.348 14.849c-.469.469-1.229.469-1.697 0L10 11.819l-2.651 3.029c-.469.469-1.229.469-1.697 0-.469-.469-.469-1.229 0-1.697l2.758-3.15-2.759-3.152c-.469-.469-.469-1.228 0-1.697.469-.469 1.228-.469 1.697 0L10 8.183l2.651-3.031c.469-.469 1.228-.469 1.697 0 .469.469.469 1.229 0 1.697l-2.758 3.152 2.758 3.15c.469.469.469 1.229 0 1.698z"})),k().createElement("symbol",{viewBox:"0 0 20 20",id:"large-arrow"},k().createElement("path",{d:"M13.25 10L6.109 2.58c-.268-.27-.268-.707 0-.979.268-.27.701-.27.969 0l7.83 7.908c.268.271.268.709 0 .979l-7.83 7.908c-.268.271-.701.27-.969 0-.268-.269-.268-.707 0-.979L13.25 10z"})),k().createElement("symbol",{viewBox:"0 0 20 20",id:"large-arrow-down"},k().createElement("path",{d:"M17.418 6.109c.272-.268.709-.268.979 0s.271.701 0 .969l-7.908 7.83c-.27.268-.707.268-.979 0l-7.908-7.83c-.27-.268-.27-.701 0-.969.271-.268.709-.268.979 0L10 13.25l7.418-7.141z"})),k().createElement("symbol",{viewBox:"0 0 20 20",id:"large-arrow-up"},k().createElement("path",{d:"M 17.418 14.908 C 17.69 15.176 18.127 15.176 18.397 14.908 C 18.667 14.64 18.668 14.207 18.397 13.939 L 10.489 6.109 C 10.219 5.841 9.782 5.841 9.51 6.109 L 1.602 13.939 C 1.332 14.207 1.332 14.64 1.602 14.908 C 1.873 15.176 2.311 15.176 2.581 14.908 L 10 7.767 L 17.418 14.908 Z"})),k().createElement("symbol",{viewBox:"0 0 24 24",id:"jump-to"},k().createElement("path",{d:"M19 7v4H5.83l3.58-3.59L8 6l-6 6 6 6 1.41-1.41L5.83 13H21V7z"})),k().createElement("symbol",{viewBox:"0 0 24 24",id:"expand"},k().createElement("path",{d:"M10 18h4v-2h-4v2zM3 6v2h18V6H3zm3 7h12v-2H6v2z"})),k().createElement("symbol",{viewBox:"0 0 15 16",id:"copy"},k().createElement("g",{transform:"translate(2, -1)"},k().createElement("path",{fill:"#ffffff",fillRule:"evenodd",d:"M2 13h4v1H2v-1zm5-6H2v1h5V7zm2 3V8l-3 3 3 3v-2h5v-2H9zM4.5 9H2v1h2.5V9zM2 12h2.5v-1H2v1zm9 1h1v2c-.02.28-.11.52-.3.7-.19.18-.42.28-.7.3H1c-.55 0-1-.45-1-1V4c0-.55.45-1 1-1h3c0-1.11.89-2 2-2 1.11 0 2 .89 2 2h3c.55 0 1 .45 1 1v5h-1V6H1v9h10v-2zM2 5h8c0-.55-.45-1-1-1H8c-.55 0-1-.45-1-1s-.45-1-1-1-1 .45-1 1-.45 1-1 1H3c-.55 0-1 .45-1 1z"}))))));var Xn=n(2552);class Yn extends k().Component{render(){const{errSelectors:e,specSelectors:t,getComponent:n}=this.props,s=n("SvgAssets"),r=n("InfoContainer",!0),a=n("VersionPragmaFilter"),o=n("operations",!0),l=n("Models",!0),c=n("Webhooks",!0),i=n("Row"),u=n("Col"),p=n("errors",!0),m=n("ServersContainer",!0),d=n("SchemesContainer",!0),h=n("AuthorizeBtnContainer",!0),g=n("FilterContainer",!0),f=t.isSwagger2(),y=t.isOAS3(),v=t.isOAS31(),S=!t.specStr(),E=t.loadingStatus();let w=null;if("loading"===E&&(w=k().createElement("div",{className:"info"},k().createElement("div",{className:"loading-container"},k().createElement("div",{className:"loading"})))),"failed"===E&&(w=k().createElement("div",{className:"info"},k().createElement("div",{className:"loading-container"},k().createElement("h4",{className:"title"},"Failed to load API definition."),k().createElement(p,null)))),"failedConfig"===E){const t=e.lastError(),n=t?t.get("message"):"";w=k().createElement("div",{className:"info failed-config"},k().createElement("div",{className:"loading-container"},k().createElement("h4",{className:"title"},"Failed to load remote configuration."),k().createElement("p",null,n)))}if(!w&&S&&(w=k().createElement("h4",null,"No API definition provided.")),w)return k().createElement("div",{className:"swagger-ui"},k().createElement("div",{className:"loading-container"},w));const x=t.servers(),C=t.schemes(),j=x&&x.size,b=C&&C.size,_=!!t.securityDefinitions();return k().createElement("div",{className:"swagger-ui"},k().createElement(s,null),k().createElement(a,{isSwagger2:f,isOAS3:y,alsoShow:k().createElement(p,null)},k().createElement(p,null),k().createElement(i,{className:"information-container"},k().createElement(u,{mobile:12},k().createElement(r,null))),j||b||_?k().createElement("div",{className:"scheme-container"},k().createElement(u,{className:"schemes wrapper",mobile:12},j?k().createElement(m,null):null,b?k().createElement(d,null):null,_?k().createElement(h,null):null)):null,k().createElement(g,null),k().createElement(i,null,k().createElement(u,{mobile:12,desktop:12},k().createElement(o,null))),v&&k().createElement(i,{className:"webhooks-container"},k().createElement(u,{mobile:12,desktop:12},k().createElement(c,null))),k().createElement(i,null,k().createElement(u,{mobile:12,desktop:12},k().createElement(l,null)))))}}const Qn=require("react-debounce-input");var es=n.n(Qn);const ts={value:"",onChange:()=>{},schema:{},keyName:"",required:!1,errors:(0,I.List)()};class ns extends O.Component{componentDidMount(){const{dispatchInitialValue:e,value:t,onChange:n}=this.props;e?n(t):!1===e&&n("")}render(){let{schema:e,errors:t,value:n,onChange:s,getComponent:r,fn:a,disabled:o}=this.props;const l=e&&e.get?e.get("format"):null,c=e&&e.get?e.get("type"):null;let i=e=>r(e,!1,{failSilently:!0}),u=c?i(l?`JsonSchema_${c}_${l}`:`JsonSchema_${c}`):r("JsonSchema_string");return u||(u=r("JsonSchema_string")),k().createElement(u,Zt()({},this.props,{errors:t,fn:a,getComponent:r,value:n,onChange:s,schema:e,disabled:o}))}}ue()(ns,"defaultProps",ts);class ss extends O.Component{constructor(){super(...arguments),ue()(this,"onChange",(e=>{const t=this.props.schema&&"file"===this.props.schema.get("type")?e.target.files[0]:e.target.value;this.props.onChange(t,this.props.keyName)})),ue()(this,"onEnumChange",(e=>this.props.onChange(e)))}render(){let{getComponent:e,value:t,schema:n,errors:s,required:r,description:a,disabled:o}=this.props;const l=n&&n.get?n.get("enum"):null,c=n&&n.get?n.get("format"):null,i=n&&n.get?n.get("type"):null,u=n&&n.get?n.get("in"):null;if(t||(t=""),s=s.toJS?s.toJS():[],l){const n=e("Select");return k().createElement(n,{className:s.length?"invalid":"",title:s.length?s:"",allowedValues:[...l],value:t,allowEmptyValue:!r,disabled:o,onChange:this.onEnumChange})}const p=o||u&&"formData"===u&&!("FormData"in window),m=e("Input");return i&&"file"===i?k().createElement(m,{type:"file",className:s.length?"invalid":"",title:s.length?s:"",onChange:this.onChange,disabled:p}):k().createElement(es(),{type:c&&"password"===c?"password":"text",className:s.length?"invalid":"",title:s.length?s:"",value:t,minLength:0,debounceTimeout:350,placeholder:a,onChange:this.onChange,disabled:p})}}ue()(ss,"defaultProps",ts);class rs extends O.PureComponent{constructor(e,t){super(e,t),ue()(this,"onChange",(()=>{this.props.onChange(this.state.value)})),ue()(this,"onItemChange",((e,t)=>{this.setState((n=>{let{value:s}=n;return{value:s.set(t,e)}}),this.onChange)})),ue()(this,"removeItem",(e=>{this.setState((t=>{let{value:n}=t;return{value:n.delete(e)}}),this.onChange)})),ue()(this,"addItem",(()=>{const{fn:e}=this.props;let t=us(this.state.value);this.setState((()=>({value:t.push(e.getSampleSchema(this.state.schema.get("items"),!1,{includeWriteOnly:!0}))})),this.onChange)})),ue()(this,"onEnumChange",(e=>{this.setState((()=>({value:e})),this.onChange)})),this.state={value:us(e.value),schema:e.schema}}UNSAFE_componentWillReceiveProps(e){const t=us(e.value);t!==this.state.value&&this.setState({value:t}),e.schema!==this.state.schema&&this.setState({schema:e.schema})}render(){var e;let{getComponent:t,required:n,schema:s,errors:r,fn:a,disabled:o}=this.props;r=r.toJS?r.toJS():w()(r)?r:[];const c=l()(r).call(r,(e=>"string"==typeof e)),i=b()(e=l()(r).call(r,(e=>void 0!==e.needRemove))).call(e,(e=>e.error)),u=this.state.value,p=!!(u&&u.count&&u.count()>0),m=s.getIn(["items","enum"]),d=s.getIn(["items","type"]),h=s.getIn(["items","format"]),g=s.get("items");let f,y=!1,v="file"===d||"string"===d&&"binary"===h;if(d&&h?f=t(`JsonSchema_${d}_${h}`):"boolean"!==d&&"array"!==d&&"object"!==d||(f=t(`JsonSchema_${d}`)),f||v||(y=!0),m){const e=t("Select");return k().createElement(e,{className:r.length?"invalid":"",title:r.length?r:"",multiple:!0,value:u,disabled:o,allowedValues:m,allowEmptyValue:!n,onChange:this.onEnumChange})}const S=t("Button");return k().createElement("div",{className:"json-schema-array"},p?b()(u).call(u,((e,n)=>{var s;const c=(0,I.fromJS)([...b()(s=l()(r).call(r,(e=>e.index===n))).call(s,(e=>e.error))]);return k().createElement("div",{key:n,className:"json-schema-form-item"},v?k().createElement(os,{value:e,onChange:e=>this.onItemChange(e,n),disabled:o,errors:c,getComponent:t}):y?k().createElement(as,{value:e,onChange:e=>this.onItemChange(e,n),disabled:o,errors:c}):k().createElement(f,Zt()({},this.props,{value:e,onChange:e=>this.onItemChange(e,n),disabled:o,errors:c,schema:g,getComponent:t,fn:a})),o?null:k().createElement(S,{className:`btn btn-sm json-schema-form-item-remove ${i.length?"invalid":null}`,title:i.length?i:"",onClick:()=>this.removeItem(n)}," - "))})):null,o?null:k().createElement(S,{className:`btn btn-sm json-schema-form-item-add ${c.length?"invalid":null}`,title:c.length?c:"",onClick:this.addItem},"Add ",d?`${d} `:"","item"))}}ue()(rs,"defaultProps",ts);class as extends O.Component{constructor(){super(...arguments),ue()(this,"onChange",(e=>{const t=e.target.value;this.props.onChange(t,this.props.keyName)}))}render(){let{value:e,errors:t,description:n,disabled:s}=this.props;return e||(e=""),t=t.toJS?t.toJS():[],k().createElement(es(),{type:"text",className:t.length?"invalid":"",title:t.length?t:"",value:e,minLength:0,debounceTimeout:350,placeholder:n,onChange:this.onChange,disabled:s})}}ue()(as,"defaultProps",ts);class os extends O.Component{constructor(){super(...arguments),ue()(this,"onFileChange",(e=>{const t=e.target.files[0];this.props.onChange(t,this.props.keyName)}))}render(){let{getComponent:e,errors:t,disabled:n}=this.props;const s=e("Input"),r=n||!("FormData"in window);return k().createElement(s,{type:"file",className:t.length?"invalid":"",title:t.length?t:"",onChange:this.onFileChange,disabled:r})}}ue()(os,"defaultProps",ts);class ls extends O.Component{constructor(){super(...arguments),ue()(this,"onEnumChange",(e=>this.props.onChange(e)))}render(){let{getComponent:e,value:t,errors:n,schema:s,required:r,disabled:a}=this.props;n=n.toJS?n.toJS():[];let o=s&&s.get?s.get("enum"):null,l=!o||!r,c=!o&&["true","false"];const i=e("Select");return k().createElement(i,{className:n.length?"invalid":"",title:n.length?n:"",value:String(t),disabled:a,allowedValues:o?[...o]:c,allowEmptyValue:l,onChange:this.onEnumChange})}}ue()(ls,"defaultProps",ts);const cs=e=>b()(e).call(e,(e=>{const t=void 0!==e.propKey?e.propKey:e.index;let n="string"==typeof e?e:"string"==typeof e.error?e.error:null;if(!t&&n)return n;let s=e.error,r=`/${e.propKey}`;for(;"object"==typeof s;){const e=void 0!==s.propKey?s.propKey:s.index;if(void 0===e)break;if(r+=`/${e}`,!s.error)break;s=s.error}return`${r}: ${s}`}));class is extends O.PureComponent{constructor(){super(),ue()(this,"onChange",(e=>{this.props.onChange(e)})),ue()(this,"handleOnChange",(e=>{const t=e.target.value;this.onChange(t)}))}render(){let{getComponent:e,value:t,errors:n,disabled:s}=this.props;const r=e("TextArea");return n=n.toJS?n.toJS():w()(n)?n:[],k().createElement("div",null,k().createElement(r,{className:lt()({invalid:n.length}),title:n.length?cs(n).join(", "):"",value:(0,$.Pz)(t),disabled:s,onChange:this.handleOnChange}))}}function us(e){return I.List.isList(e)?e:w()(e)?(0,I.fromJS)(e):(0,I.List)()}function ps(){let n={components:{App:ge,authorizationPopup:fe,authorizeBtn:ye,AuthorizeBtnContainer:ve,authorizeOperationBtn:Se,auths:Ee,AuthItem:we,authError:xe,oauth2:$e,apiKeyAuth:Ce,basicAuth:je,clear:Le,liveResponse:Ue,InitializedInput:dn,info:yn,InfoContainer:vn,InfoUrl:gn,InfoBasePath:hn,Contact:En,License:xn,JumpToPath:Cn,CopyToClipboardBtn:jn,onlineValidatorBadge:Fe.Z,operations:ze,operation:He,OperationSummary:Xe,OperationSummaryMethod:Ye,OperationSummaryPath:tt,highlightCode:yt,responses:vt,response:xt,ResponseExtension:Ct,responseBody:At,parameters:qt,parameterRow:Jt,execute:Kt,headers:Vt,errors:Ut,contentType:Wt,overview:pn,footer:bn,FilterContainer:_n,ParamBody:On,curl:An,schemes:In,SchemesContainer:Pn,modelExample:Mn,ModelWrapper:Dn,ModelCollapse:qn,Model:Jn.Z,Models:$n,EnumModel:Ln,ObjectModel:Kn,ArrayModel:Vn,PrimitiveModel:Fn,Property:zn,TryItOutButton:Bn,Markdown:Xn.Z,BaseLayout:Yn,VersionPragmaFilter:Wn,VersionStamp:Hn,OperationExt:nt,OperationExtRow:st,ParameterExt:Rt,ParameterIncludeEmpty:Mt,OperationTag:We,OperationContainer:he,DeepLink:Zn,SvgAssets:Gn,Example:be,ExamplesSelect:Oe,ExamplesSelectValueRetainer:Ae}},s={components:e},r={components:t};return[se.default,te.default,Y.default,Z.default,H.default,B.default,W.default,G.default,n,s,Q.default,r,ee.default,ne.default,re.default,ae.default,oe.default,X.default,ce.default,(0,le.default)()]}ue()(is,"defaultProps",ts);var ms=n(7451),ds=n(9806),hs=n(7139);function gs(){return[ps,ms.default,hs.default,ds.default]}var fs=n(5308);const{GIT_DIRTY:ys,GIT_COMMIT:vs,PACKAGE_VERSION:Ss,BUILD_TIME:Es}={PACKAGE_VERSION:"5.3.1",GIT_COMMIT:"g733e51a",GIT_DIRTY:!0,BUILD_TIME:"Wed, 02 Aug 2023 13:12:32 GMT"};function ws(e){var t;J.Z.versions=J.Z.versions||{},J.Z.versions.swaggerUi={version:Ss,gitRevision:vs,gitDirty:ys,buildTimestamp:Es};const n={dom_id:null,domNode:null,spec:{},url:"",urls:null,layout:"BaseLayout",docExpansion:"list",maxDisplayedTags:null,filter:null,validatorUrl:"https://validator.swagger.io/validator",oauth2RedirectUrl:`${window.location.protocol}//${window.location.host}${window.location.pathname.substring(0,a()(t=window.location.pathname).call(t,"/"))}/oauth2-redirect.html`,persistAuthorization:!1,configs:{},custom:{},displayOperationId:!1,displayRequestDuration:!1,deepLinking:!1,tryItOutEnabled:!1,requestInterceptor:e=>e,responseInterceptor:e=>e,showMutatedRequest:!0,defaultModelRendering:"example",defaultModelExpandDepth:1,defaultModelsExpandDepth:1,showExtensions:!1,showCommonExtensions:!1,withCredentials:void 0,requestSnippetsEnabled:!1,requestSnippets:{generators:{curl_bash:{title:"cURL (bash)",syntax:"bash"},curl_powershell:{title:"cURL (PowerShell)",syntax:"powershell"},curl_cmd:{title:"cURL (CMD)",syntax:"bash"}},defaultExpanded:!0,languages:null},supportedSubmitMethods:["get","put","post","delete","options","head","patch","trace"],queryConfigEnabled:!1,presets:[gs],plugins:[],pluginsOptions:{pluginLoadType:"legacy"},initialState:{},fn:{},components:{},syntaxHighlight:{activated:!0,theme:"agate"}};let s=e.queryConfigEnabled?(0,$.UG)():{};const r=e.domNode;delete e.domNode;const o=d()({},n,e,s),c={system:{configs:o.configs},plugins:o.presets,pluginsOptions:o.pluginsOptions,state:d()({layout:{layout:o.layout,filter:l()(o)},spec:{spec:"",url:o.url},requestSnippets:o.requestSnippets},o.initialState)};if(o.initialState)for(var u in o.initialState)Object.prototype.hasOwnProperty.call(o.initialState,u)&&void 0===o.initialState[u]&&delete c.state[u];var m=new K(c);m.register([o.plugins,()=>({fn:o.fn,components:o.components,state:o.state})]);var h=m.getSystem();const g=e=>{let t=h.specSelectors.getLocalConfig?h.specSelectors.getLocalConfig():{},n=d()({},t,o,e||{},s);if(r&&(n.domNode=r),m.setConfigs(n),h.configsActions.loaded(),null!==e&&(!s.url&&"object"==typeof n.spec&&i()(n.spec).length?(h.specActions.updateUrl(""),h.specActions.updateLoadingStatus("success"),h.specActions.updateSpec(p()(n.spec))):h.specActions.download&&n.url&&!n.urls&&(h.specActions.updateUrl(n.url),h.specActions.download(n.url))),n.domNode)h.render(n.domNode,"App");else if(n.dom_id){let e=document.querySelector(n.dom_id);h.render(e,"App")}else null===n.dom_id||null===n.domNode||console.error("Skipped rendering: no `dom_id` or `domNode` was specified");return h},f=s.config||o.configUrl;return f&&h.specActions&&h.specActions.getConfigByUrl?(h.specActions.getConfigByUrl({url:f,loadRemoteConfig:!0,requestInterceptor:o.requestInterceptor,responseInterceptor:o.responseInterceptor},g),h):g()}ws.presets={apis:gs},ws.plugins=fs.default;const xs=ws})(),s=s.default})()));
+//# sourceMappingURL=swagger-ui.js.map
\ No newline at end of file
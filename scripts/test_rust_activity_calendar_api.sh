@@ -0,0 +1,57 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+ACTIVITY_CODE="ICS$(date +%s | tail -c 6)"
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 活動行事曆（iCalendar）API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 建立測試活動（含時間）與全天活動
+echo "1. 建立測試活動..."
+RESPONSE=$(curl -s -X POST "$API_URL/activities" \
+  -H "Content-Type: application/json" \
+  -d "{\"activityId\": \"$ACTIVITY_CODE\", \"name\": \"行事曆測試法會\", \"date\": \"2099-08-15 09:00\", \"location\": \"大雄寶殿\", \"description\": \"上午誦經，下午普度\"}")
+echo "$RESPONSE"
+ID=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+RESPONSE=$(curl -s -X POST "$API_URL/activities" \
+  -H "Content-Type: application/json" \
+  -d "{\"activityId\": \"${ACTIVITY_CODE}D\", \"name\": \"行事曆測試講座\", \"date\": \"2099-09-01\", \"itemType\": \"lecture\"}")
+echo "$RESPONSE"
+ID2=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+echo -e "\n"
+
+# 2. 完整行事曆
+echo "2. 取得完整行事曆..."
+curl -s -i "$API_URL/activities.ics"
+echo -e "\n"
+
+# 3. 依項目類型與狀態篩選（逗號分隔）
+echo "3. 只取 lecture 且為 planning、active 的活動..."
+curl -s "$API_URL/activities.ics?item_type=lecture&state=planning,active"
+echo -e "\n"
+
+# 4. 更新活動後 UID 不變（行事曆會取代原事件）
+echo "4. 更新活動日期後，UID 維持 activity-$ACTIVITY_CODE@..."
+curl -s -X PATCH "$API_URL/activities/$ID" \
+  -H "Content-Type: application/json" \
+  -d '{"date": "2099-08-16 10:00"}' > /dev/null
+curl -s "$API_URL/activities.ics" | grep -A2 "UID:activity-$ACTIVITY_CODE@"
+echo -e "\n"
+
+# 5. 不支援的狀態（預期 400）
+echo "5. 不支援的狀態 bogus（預期 400）..."
+curl -s -w "\nHTTP %{http_code}" "$API_URL/activities.ics?state=bogus"
+echo -e "\n"
+
+# 6. 清除測試活動
+echo "6. 刪除測試活動..."
+curl -s -X DELETE "$API_URL/activities/$ID"
+curl -s -X DELETE "$API_URL/activities/$ID2"
+echo -e "\n"
+
+echo "-----------------------------------------------"
+echo "✅ 測試結束"
+echo "-----------------------------------------------"
@@ -0,0 +1,67 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+ACTIVITY_ID="${ACTIVITY_ID:-54}"
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 活動參加統計與名額 API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 查看活動參加統計（由 joinRecordDB 計算）
+echo "1. 查看活動 $ACTIVITY_ID 的參加統計..."
+RESPONSE=$(curl -s "$API_URL/activities/$ACTIVITY_ID")
+echo "$RESPONSE"
+COUNT=$(echo "$RESPONSE" | grep -o '"participants":[0-9]*' | head -1 | cut -d: -f2)
+echo -e "\n目前參加記錄筆數: $COUNT\n"
+
+# 2. 將名額設為目前筆數（額滿）
+echo "2. 設定名額為 $COUNT..."
+curl -s -X PATCH "$API_URL/activities/$ACTIVITY_ID" \
+  -H "Content-Type: application/json" \
+  -d "{\"capacity\": $COUNT}"
+echo -e "\n"
+
+# 3. 額滿後新增參加記錄（預期 409）
+echo "3. 額滿後新增參加記錄（預期 409 名額已滿）..."
+curl -s -w "\nHTTP %{http_code}" -X POST "$API_URL/join-records" \
+  -H "Content-Type: application/json" \
+  -d "{\"activityId\": $ACTIVITY_ID, \"registrationId\": 1, \"state\": \"confirmed\"}"
+echo -e "\n"
+
+# 4. 已取消的記錄不佔名額
+echo "4. 新增已取消的參加記錄（不佔名額，預期成功）..."
+RESPONSE=$(curl -s -X POST "$API_URL/join-records" \
+  -H "Content-Type: application/json" \
+  -d "{\"activityId\": $ACTIVITY_ID, \"registrationId\": 1, \"state\": \"cancelled\"}")
+echo "$RESPONSE"
+RECORD_ID=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+echo -e "\n"
+
+# 5. 由已取消恢復為確認（預期 409）
+echo "5. 將已取消的記錄恢復為確認（預期 409 名額已滿）..."
+curl -s -w "\nHTTP %{http_code}" -X PATCH "$API_URL/join-records/$RECORD_ID" \
+  -H "Content-Type: application/json" \
+  -d '{"state": "confirmed"}'
+echo -e "\n"
+
+# 6. 批次新增同樣受名額限制
+echo "6. 批次新增參加記錄（預期失敗並回滾）..."
+curl -s -X POST "$API_URL/join-records/batch" \
+  -H "Content-Type: application/json" \
+  -d "{\"operations\": [{\"action\": \"create\", \"data\": {\"activityId\": $ACTIVITY_ID, \"state\": \"confirmed\"}}]}"
+echo -e "\n"
+
+# 7. 取消名額限制並清除測試記錄
+echo "7. 取消名額限制（capacity <= 0 表示不限）並刪除測試記錄..."
+curl -s -X PATCH "$API_URL/activities/$ACTIVITY_ID" \
+  -H "Content-Type: application/json" \
+  -d '{"capacity": 0}'
+echo ""
+curl -s -X DELETE "$API_URL/join-records/$RECORD_ID"
+echo -e "\n"
+
+echo "-----------------------------------------------"
+echo "✅ 測試結束"
+echo "-----------------------------------------------"
@@ -0,0 +1,42 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+ACTIVITY_ID="${ACTIVITY_ID:-54}"
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 法會名冊 API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 超度牌位（依地址分組，含陽上人）
+echo "1. 超度牌位名冊（type=chaodu）..."
+curl -s "$API_URL/activities/$ACTIVITY_ID/roster?type=chaodu"
+echo -e "\n"
+
+# 2. 消災名冊（含固定消災，同地址同名只列一次）
+echo "2. 消災祈福名冊（type=qifu）..."
+curl -s "$API_URL/activities/$ACTIVITY_ID/roster?type=qifu"
+echo -e "\n"
+
+# 3. 點燈名冊 CSV
+echo "3. 點燈名冊 CSV（type=diandeng&format=csv）..."
+curl -s "$API_URL/activities/$ACTIVITY_ID/roster?type=diandeng&format=csv"
+echo -e "\n"
+
+# 4. 牌位與名冊 PDF
+echo "4. 下載超度牌位與消災名冊 PDF..."
+curl -s -o "roster-$ACTIVITY_ID-chaodu.pdf" -w "HTTP %{http_code}, %{size_download} bytes\n" \
+  "$API_URL/activities/$ACTIVITY_ID/roster?type=chaodu&format=pdf"
+curl -s -o "roster-$ACTIVITY_ID-qifu.pdf" -w "HTTP %{http_code}, %{size_download} bytes\n" \
+  "$API_URL/activities/$ACTIVITY_ID/roster?type=qifu&format=pdf"
+echo ""
+
+# 5. 不支援的名冊類型（預期 400）
+echo "5. 不支援的名冊類型（預期 400）..."
+curl -s -w "\nHTTP %{http_code}" "$API_URL/activities/$ACTIVITY_ID/roster?type=pudu"
+echo -e "\n"
+
+echo "-----------------------------------------------"
+echo "✅ 測試結束"
+echo "-----------------------------------------------"
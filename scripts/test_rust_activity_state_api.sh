@@ -0,0 +1,90 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+ACTIVITY_CODE="ST$(date +%s | tail -c 6)"
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 活動狀態流程 API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 建立活動（預設狀態 planning）
+echo "1. 建立活動（預設狀態為 planning 籌劃中）..."
+RESPONSE=$(curl -s -X POST "$API_URL/activities" \
+  -H "Content-Type: application/json" \
+  -d "{\"activityId\": \"$ACTIVITY_CODE\", \"name\": \"狀態流程測試法會\", \"date\": \"2099-12-01 09:00\"}")
+echo "$RESPONSE"
+ID=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+echo -e "\n"
+
+# 2. 不合法的狀態變更（預期 409）
+echo "2. planning 直接改為 completed（預期 409）..."
+curl -s -w "\nHTTP %{http_code}" -X PATCH "$API_URL/activities/$ID" \
+  -H "Content-Type: application/json" \
+  -d '{"state": "completed"}'
+echo -e "\n"
+
+# 3. 不支援的狀態（預期 400）
+echo "3. 不支援的狀態 upcoming-soon（預期 400）..."
+curl -s -w "\nHTTP %{http_code}" -X PATCH "$API_URL/activities/$ID" \
+  -H "Content-Type: application/json" \
+  -d '{"state": "upcoming-soon"}'
+echo -e "\n"
+
+# 4. 開放報名
+echo "4. planning → active（開放報名）..."
+curl -s -X PATCH "$API_URL/activities/$ID" \
+  -H "Content-Type: application/json" \
+  -d '{"state": "active"}'
+echo -e "\n"
+
+# 5. 新增參加記錄
+echo "5. 新增 2 筆參加記錄..."
+for i in 1 2; do
+  curl -s -X POST "$API_URL/join-records" \
+    -H "Content-Type: application/json" \
+    -d "{\"activityId\": $ID, \"state\": \"confirmed\"}" > /dev/null
+done
+curl -s "$API_URL/join-records/by-activity/$ID"
+echo -e "\n"
+
+# 6. 取消活動並一併取消參加記錄
+echo "6. 取消活動並一併取消參加記錄（cascadeJoinRecords）..."
+curl -s -X PATCH "$API_URL/activities/$ID" \
+  -H "Content-Type: application/json" \
+  -d '{"state": "cancelled", "cascadeJoinRecords": true}'
+echo -e "\n"
+
+# 7. 已取消的活動不可重新開放（預期 409）
+echo "7. cancelled → active（預期 409）..."
+curl -s -w "\nHTTP %{http_code}" -X PATCH "$API_URL/activities/$ID" \
+  -H "Content-Type: application/json" \
+  -d '{"state": "active"}'
+echo -e "\n"
+
+# 8. 立即執行活動自動完成
+echo "8. 立即將日期已過的進行中活動標記為已完成..."
+curl -s -X POST "$API_URL/activities/auto-complete"
+echo -e "\n"
+
+# 9. 已刪除的活動不會被自動完成
+echo "9. 日期已過的進行中活動刪除後執行自動完成，還原後仍為 active..."
+PAST_ID=$(curl -s -X POST "$API_URL/activities" \
+  -H "Content-Type: application/json" \
+  -d "{\"activityId\": \"${ACTIVITY_CODE}P\", \"name\": \"已刪除的過期法會\", \"date\": \"2000-01-01\", \"state\": \"active\"}" |
+  grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+curl -s -X DELETE "$API_URL/activities/$PAST_ID" > /dev/null
+curl -s -X POST "$API_URL/activities/auto-complete" > /dev/null
+curl -s -X POST "$API_URL/activities/$PAST_ID/restore" | grep -o '"state":"[a-z]*"'
+curl -s -X DELETE "$API_URL/activities/$PAST_ID" > /dev/null
+echo -e "\n"
+
+# 10. 清除測試活動
+echo "10. 刪除測試活動..."
+curl -s -X DELETE "$API_URL/activities/$ID"
+echo -e "\n"
+
+echo "-----------------------------------------------"
+echo "✅ 測試結束"
+echo "-----------------------------------------------"
@@ -0,0 +1,80 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+ACTIVITY_ID="${ACTIVITY_ID:-54}"
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 活動財務摘要 API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 活動財務摘要
+echo "1. 查看活動 $ACTIVITY_ID 的財務摘要（項目、金額、付款方式、收據、沖帳）..."
+curl -s "$API_URL/activities/$ACTIVITY_ID/summary"
+echo -e "\n"
+
+# 2. 新增一筆現金付款後再查看
+RECORD_ID=$(curl -s "$API_URL/join-records/by-activity/$ACTIVITY_ID" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+echo "2. 參加記錄 $RECORD_ID 新增現金付款 500 後再查看付款方式統計..."
+curl -s -X POST "$API_URL/join-records/$RECORD_ID/payments" \
+  -H "Content-Type: application/json" \
+  -d '{"amount": 500, "paymentMethod": "cash"}'
+echo ""
+curl -s "$API_URL/activities/$ACTIVITY_ID/summary" | grep -o '"byPaymentMethod":\[[^]]*\]'
+echo -e "\n"
+
+# 3. 前端寫入的混合燈種點燈（price 為平均價格小數）仍需正常解析並計入摘要
+echo "3. 建立混合燈種點燈記錄（光明燈 x2 + 太歲燈，price = 2000/3）..."
+RESPONSE=$(curl -s -X POST "$API_URL/join-records" \
+  -H "Content-Type: application/json" \
+  -d "{
+    \"activityId\": $ACTIVITY_ID,
+    \"state\": \"confirmed\",
+    \"items\": [{
+      \"type\": \"diandeng\",
+      \"label\": \"點燈\",
+      \"price\": 666.6666666666666,
+      \"quantity\": 3,
+      \"subtotal\": 2000,
+      \"source\": \"blessing.persons\",
+      \"sourceData\": [{\"id\": 1, \"name\": \"甲\"}, {\"id\": 2, \"name\": \"乙\"}, {\"id\": 3, \"name\": \"丙\"}],
+      \"sourceAddress\": \"\",
+      \"lampDetails\": [
+        {\"personId\": 1, \"personName\": \"甲\", \"lampType\": \"guangming\", \"lampTypeLabel\": \"光明燈\", \"price\": 600},
+        {\"personId\": 2, \"personName\": \"乙\", \"lampType\": \"guangming\", \"lampTypeLabel\": \"光明燈\", \"price\": 600},
+        {\"personId\": 3, \"personName\": \"丙\", \"lampType\": \"taisui\", \"lampTypeLabel\": \"太歲燈\", \"price\": 800}
+      ]
+    }],
+    \"totalAmount\": 2000,
+    \"finalAmount\": 2000
+  }")
+LAMP_RECORD_ID=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+echo "讀取記錄 $LAMP_RECORD_ID 的 items（不應為 null）..."
+curl -s "$API_URL/join-records/$LAMP_RECORD_ID" | grep -o '"items":[^]]*\]' | head -1
+echo ""
+echo "摘要中的點燈項目（應包含這筆 2000）..."
+curl -s "$API_URL/activities/$ACTIVITY_ID/summary" | grep -o '{"itemType":"diandeng"[^}]*}'
+echo ""
+curl -s -X DELETE "$API_URL/join-records/$LAMP_RECORD_ID" > /dev/null
+echo -e "\n"
+
+# 4. 分次付款部分沖帳：已沖帳 500 與之後的轉帳 200 分別計入
+echo "4. 參加記錄 $RECORD_ID 的付款沖帳後再轉帳付款 200，查看沖帳統計..."
+curl -s -X POST "$API_URL/reconciliation-batches" \
+  -H "Content-Type: application/json" \
+  -d "{\"batchDate\": \"$(date +%F)\", \"countedAmount\": 500, \"recordIds\": [$RECORD_ID]}" > /dev/null
+curl -s -X POST "$API_URL/join-records/$RECORD_ID/payments" \
+  -H "Content-Type: application/json" \
+  -d '{"amount": 200, "paymentMethod": "transfer"}' > /dev/null
+curl -s "$API_URL/activities/$ACTIVITY_ID/summary" | grep -o '"reconciliation":{[^]]*\]}'
+echo -e "\n"
+
+# 5. 不存在的活動（預期 404）
+echo "5. 不存在的活動（預期 404）..."
+curl -s -w "\nHTTP %{http_code}" "$API_URL/activities/999999/summary"
+echo -e "\n"
+
+echo "-----------------------------------------------"
+echo "✅ 測試結束"
+echo "-----------------------------------------------"
@@ -0,0 +1,91 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+YEAR="${YEAR:-2027}"
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 活動範本 API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 建立農曆範本
+echo "1. 建立農曆七月十五中元普度範本（限定超度、陽上人、普度，使用價格配置 3）..."
+RESPONSE=$(curl -s -X POST "$API_URL/activity-templates" \
+  -H "Content-Type: application/json" \
+  -d '{
+    "name": "{rocYear}中元普度法會",
+    "icon": "🍚",
+    "location": "大雄寶殿",
+    "lunarMonth": 7,
+    "lunarDay": 15,
+    "time": "09:00",
+    "allowedItems": ["chaodu", "survivors", "pudu"],
+    "priceConfigId": 3
+  }')
+echo "$RESPONSE"
+TEMPLATE_ID=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+echo -e "\n"
+
+echo "2. 建立國曆範本（12 月 31 日 19:00 跨年祈福）..."
+curl -s -X POST "$API_URL/activity-templates" \
+  -H "Content-Type: application/json" \
+  -d '{"name": "{year} 跨年祈福", "solarMonth": 12, "solarDay": 31, "time": "19:00"}'
+echo -e "\n"
+
+echo "3. 農曆與國曆月日同時提供（預期 400）..."
+curl -s -X POST "$API_URL/activity-templates" \
+  -H "Content-Type: application/json" \
+  -d '{"name": "x", "lunarMonth": 7, "lunarDay": 15, "solarMonth": 8, "solarDay": 1}'
+echo -e "\n"
+
+echo "4. 無效的項目類型（預期 400）..."
+curl -s -X POST "$API_URL/activity-templates" \
+  -H "Content-Type: application/json" \
+  -d '{"name": "x", "lunarMonth": 7, "lunarDay": 15, "allowedItems": ["foo"]}'
+echo -e "\n"
+
+echo "5. 查詢範本列表..."
+curl -s "$API_URL/activity-templates?state=active"
+echo -e "\n"
+
+if [ -n "$TEMPLATE_ID" ]; then
+  # 6. 由範本建立活動
+  echo "6. 由範本 $TEMPLATE_ID 建立 $YEAR 年活動..."
+  curl -s -X POST "$API_URL/activities/from-template" \
+    -H "Content-Type: application/json" \
+    -d "{\"templateId\": $TEMPLATE_ID, \"year\": $YEAR}"
+  echo -e "\n"
+
+  echo "7. 同一年重複建立（預期 409）..."
+  curl -s -X POST "$API_URL/activities/from-template" \
+    -H "Content-Type: application/json" \
+    -d "{\"templateId\": $TEMPLATE_ID, \"year\": $YEAR}"
+  echo -e "\n"
+
+  echo "8. 已建立活動的範本不可刪除（預期 409）..."
+  curl -s -X DELETE "$API_URL/activity-templates/$TEMPLATE_ID"
+  echo -e "\n"
+
+  echo "9. 停用範本..."
+  curl -s -X PATCH "$API_URL/activity-templates/$TEMPLATE_ID" \
+    -H "Content-Type: application/json" \
+    -d '{"state": "inactive"}'
+  echo -e "\n"
+
+  echo "10. 停用的範本無法建立活動（預期 409）..."
+  curl -s -X POST "$API_URL/activities/from-template" \
+    -H "Content-Type: application/json" \
+    -d "{\"templateId\": $TEMPLATE_ID, \"year\": $((YEAR + 1))}"
+  echo -e "\n"
+fi
+
+echo "11. 不存在的範本（預期 404）..."
+curl -s -X POST "$API_URL/activities/from-template" \
+  -H "Content-Type: application/json" \
+  -d '{"templateId": 999999, "year": 2027}'
+echo -e "\n"
+
+echo "-----------------------------------------------"
+echo "✅ 活動範本 API 測試完成"
+echo "-----------------------------------------------"
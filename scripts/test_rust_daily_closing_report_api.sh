@@ -0,0 +1,49 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+REPORT_DATE=${1:-$(date +%F)}  # 報表日期（預設今天）
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 每日結帳報表 API ($REPORT_DATE)"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. JSON
+echo "1. 查詢 JSON 報表..."
+JSON_RES=$(curl -s -X GET "$API_URL/reports/daily-closing?date=$REPORT_DATE")
+echo "$JSON_RES" | grep -oP '"totals":\{[^}]*\}'
+echo ""
+
+# 2. CSV
+echo "2. 下載 CSV 報表..."
+curl -s -X GET "$API_URL/reports/daily-closing?date=$REPORT_DATE&format=csv" -o "/tmp/daily-closing-$REPORT_DATE.csv"
+echo "✅ 已儲存 /tmp/daily-closing-$REPORT_DATE.csv"
+head -5 "/tmp/daily-closing-$REPORT_DATE.csv"
+echo ""
+
+# 3. PDF
+echo "3. 下載 PDF 報表..."
+curl -s -X GET "$API_URL/reports/daily-closing?date=$REPORT_DATE&format=pdf" -o "/tmp/daily-closing-$REPORT_DATE.pdf"
+if head -c 5 "/tmp/daily-closing-$REPORT_DATE.pdf" | grep -q "%PDF"; then
+    echo "✅ 已儲存 /tmp/daily-closing-$REPORT_DATE.pdf"
+else
+    echo "❌ PDF 產生失敗"
+fi
+echo ""
+
+# 4. 錯誤日期（預期失敗）
+echo "4. 測試錯誤日期（預期失敗）..."
+curl -s -X GET "$API_URL/reports/daily-closing?date=2026-13-01"
+echo ""
+echo ""
+
+# 5. JSON 欄位檢查（列出無法解析的 contact / items / donateItems / prices）
+echo "5. 測試 JSON 欄位檢查..."
+curl -s -X GET "$API_URL/reports/invalid-json"
+echo ""
+echo ""
+
+echo "-----------------------------------------------"
+echo "🏁 每日結帳報表 API 測試完成"
+echo "-----------------------------------------------"
@@ -0,0 +1,38 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+OUT_DIR="${OUT_DIR:-/tmp}"
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 匯出 API（輸出到 $OUT_DIR）"
+echo "-----------------------------------------------"
+echo ""
+
+for RESOURCE in monthly-donates join-records registrations; do
+  for FORMAT in csv xlsx json; do
+    FILE="$OUT_DIR/export-$RESOURCE.$FORMAT"
+    echo "匯出 $RESOURCE ($FORMAT)..."
+    STATUS=$(curl -s -o "$FILE" -w "%{http_code}" "$API_URL/$RESOURCE/export?format=$FORMAT")
+    echo "HTTP $STATUS，檔案大小 $(wc -c < "$FILE") bytes → $FILE"
+  done
+  echo ""
+done
+
+# 篩選條件與列表相同
+echo "匯出已確認的參與記錄（state=confirmed）..."
+curl -s "$API_URL/join-records/export?state=confirmed" | head -3
+echo ""
+
+# 錯誤參數
+echo "不支援的格式（預期失敗）..."
+curl -s "$API_URL/registrations/export?format=pdf"
+echo ""
+echo "無效的排序欄位（預期失敗）..."
+curl -s "$API_URL/registrations/export?sort=id;drop"
+echo ""
+echo ""
+
+echo "-----------------------------------------------"
+echo "✅ 匯出 API 測試完成"
+echo "-----------------------------------------------"
@@ -0,0 +1,54 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑（需先套用 db/sqlite_householdDB_table.sql 與 db/alter_householdId.sql）
+API_URL="http://localhost:3000/api"
+HOUSEHOLD_ID="${HOUSEHOLD_ID:-3}"
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 戶（歸戶）API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 推導預覽（預設 dryRun）
+echo "1. 由報名記錄推導戶別（dryRun）..."
+curl -s -X POST "$API_URL/households/derive" -H "Content-Type: application/json" -d '{}'
+echo -e "\n"
+
+echo "2. 實際歸戶..."
+curl -s -X POST "$API_URL/households/derive" -H "Content-Type: application/json" \
+  -d '{"dryRun": false}'
+echo -e "\n"
+
+echo "3. 再次推導（已歸戶的報名不會變動）..."
+curl -s -X POST "$API_URL/households/derive" -H "Content-Type: application/json" \
+  -d '{"dryRun": false}'
+echo -e "\n"
+
+# 4. 戶列表與搜尋
+echo "4. 戶列表（前 5 筆）..."
+curl -s "$API_URL/households?limit=5"
+echo -e "\n"
+
+# 5. 手動歸戶每月贊助
+echo "5. 將每月贊助 1 歸入戶 $HOUSEHOLD_ID..."
+curl -s -X POST "$API_URL/households/$HOUSEHOLD_ID/link" -H "Content-Type: application/json" \
+  -d '{"monthlyDonateIds": [1]}'
+echo -e "\n"
+
+echo "6. 歸入不存在的報名記錄（預期 404）..."
+curl -s -X POST "$API_URL/households/$HOUSEHOLD_ID/link" -H "Content-Type: application/json" \
+  -d '{"registrationIds": [999999]}'
+echo -e "\n"
+
+# 7. 戶的完整歷史
+echo "7. 戶 $HOUSEHOLD_ID 的成員、活動、付款、收據與每月贊助..."
+curl -s "$API_URL/households/$HOUSEHOLD_ID"
+echo -e "\n"
+
+echo "8. 查詢不存在的戶（預期 404）..."
+curl -s "$API_URL/households/999999"
+echo -e "\n"
+
+echo "-----------------------------------------------"
+echo "✅ 戶 API 測試完成"
+echo "-----------------------------------------------"
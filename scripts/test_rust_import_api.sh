@@ -0,0 +1,80 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+USER_ID="${USER_ID:-a4954ebc-8591-4288-8ebe-a4af19e718f7}"
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 匯入 API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 可匯入欄位
+echo "1. 查詢每月贊助可匯入欄位..."
+curl -s "$API_URL/import/monthly-donates/fields"
+echo -e "\n"
+
+# 2. 每月贊助試算（dryRun 預設為 true，第 4 列有錯誤）
+CSV=$'姓名,金額,月份,編號\n王小明,"1,200",202701-202712,IMP0001\n王小明,300,2028-01、2028/02,IMP0001\n李四,abc,202713,\n'
+echo "2. 每月贊助試算（預期第 4 列有錯誤）..."
+jq -n --arg c "$CSV" --arg u "$USER_ID" \
+  '{format:"csv",content:$c,mapping:{name:"姓名",price:"金額",months:"月份",donateId:"編號"},userId:$u}' |
+  curl -s -X POST "$API_URL/import/monthly-donates" -H "Content-Type: application/json" -d @-
+echo -e "\n"
+
+# 3. 有錯誤時正式匯入會整批拒絕
+echo "3. 有錯誤時正式匯入（預期失敗，不寫入任何記錄）..."
+jq -n --arg c "$CSV" --arg u "$USER_ID" \
+  '{content:$c,mapping:{name:"姓名",price:"金額",months:"月份",donateId:"編號"},userId:$u,dryRun:false}' |
+  curl -s -X POST "$API_URL/import/monthly-donates" -H "Content-Type: application/json" -d @-
+echo -e "\n"
+
+# 4. 報名記錄正式匯入（標題與目標欄位同名時不需 mapping）
+CSV=$'contact.name,contact.mobile,contact.relationship,salvation.address,salvation.ancestors,salvation.survivors,blessing.address,blessing.persons,blessing.householdHead\n林一,0912000000,本家,台北市,林,林甲,台北市,林甲、林乙,林乙\n'
+echo "4. 匯入報名記錄..."
+jq -n --arg c "$CSV" --arg u "$USER_ID" '{content:$c,userId:$u,dryRun:false}' |
+  curl -s -X POST "$API_URL/import/registrations" -H "Content-Type: application/json" -d @-
+echo -e "\n"
+
+# 5. 參與記錄（金額依目前價格配置計算）
+CSV=$'contact.name,contact.mobile,items.qifu,items.diandeng,paymentState\n張三,0911000000,張三、張太,張三,paid\n'
+echo "5. 參與記錄試算..."
+jq -n --arg c "$CSV" '{content:$c}' |
+  curl -s -X POST "$API_URL/import/join-records" -H "Content-Type: application/json" -d @-
+echo -e "\n"
+
+# 6. XLSX 以 base64 傳送
+if [ -n "$XLSX_FILE" ]; then
+  echo "6. 試算 XLSX 檔案 $XLSX_FILE..."
+  jq -n --arg c "$(base64 -w0 "$XLSX_FILE")" '{format:"xlsx",contentBase64:$c}' |
+    curl -s -X POST "$API_URL/import/registrations" -H "Content-Type: application/json" -d @-
+  echo -e "\n"
+fi
+
+# 7. 欄位對應錯誤
+echo "7. 對應不存在的欄位（預期失敗）..."
+curl -s -X POST "$API_URL/import/monthly-donates" -H "Content-Type: application/json" \
+  -d '{"content":"a,b\n1,2\n","mapping":{"name":"zz"}}'
+echo -e "\n"
+
+# 8. 錯誤列號為試算表中的原始列號（空白列也計入）
+CSV=$'姓名,金額,月份\n\n王小明,300,202701\n\n李四,abc,202702\n'
+echo "8. 含空白列的試算（預期錯誤在第 5 列）..."
+jq -n --arg c "$CSV" '{content:$c,mapping:{name:"姓名",price:"金額",months:"月份"}}' |
+  curl -s -X POST "$API_URL/import/monthly-donates" -H "Content-Type: application/json" -d @-
+echo -e "\n"
+
+# 9. 已刪除的活動不可作為匯入目標
+ACTIVITY_ID=$(curl -s -X POST "$API_URL/activities" -H "Content-Type: application/json" \
+  -d "{\"activityId\": \"IMP$(date +%s | tail -c 6)\", \"name\": \"匯入測試法會\", \"date\": \"2099-12-01\"}" |
+  grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+curl -s -X DELETE "$API_URL/activities/$ACTIVITY_ID" > /dev/null
+CSV=$'contact.name,activityId,items.qifu\n張三,'"$ACTIVITY_ID"$',張三\n'
+echo "9. 參與記錄指定已刪除的活動 $ACTIVITY_ID（預期第 2 列錯誤）..."
+jq -n --arg c "$CSV" '{content:$c}' |
+  curl -s -X POST "$API_URL/import/join-records" -H "Content-Type: application/json" -d @-
+echo -e "\n"
+
+echo "-----------------------------------------------"
+echo "✅ 匯入 API 測試完成"
+echo "-----------------------------------------------"
@@ -0,0 +1,76 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+ACTIVITY_ID=1       # 測試用的活動 ID
+REGISTRATION_ID=1   # 測試用的報名 ID
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 參加記錄批次操作 API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 批次創建兩筆
+echo "1. 批次創建兩筆參加記錄..."
+CREATE_RES=$(curl -s -X POST "$API_URL/join-records/batch" \
+  -H "Content-Type: application/json" \
+  -d "{
+    \"operations\": [
+      {\"action\": \"create\", \"data\": {\"registrationId\": $REGISTRATION_ID, \"activityId\": $ACTIVITY_ID, \"state\": \"pending\", \"totalAmount\": 600, \"finalAmount\": 600, \"notes\": \"sh批次測試\"}},
+      {\"action\": \"create\", \"data\": {\"registrationId\": $REGISTRATION_ID, \"activityId\": $ACTIVITY_ID, \"state\": \"pending\", \"totalAmount\": 800, \"finalAmount\": 800, \"notes\": \"sh批次測試\"}}
+    ]
+  }")
+echo "響應: $CREATE_RES"
+IDS=($(echo $CREATE_RES | grep -oP '(?<="action":"create","id":)[0-9]+'))
+echo "✅ 新建 ID: ${IDS[*]}"
+echo ""
+
+# 2. 批次更新折扣與確認
+echo "2. 批次更新（折扣 100、確認）..."
+UPDATE_RES=$(curl -s -X POST "$API_URL/join-records/batch" \
+  -H "Content-Type: application/json" \
+  -d "{
+    \"operations\": [
+      {\"action\": \"update\", \"id\": ${IDS[0]}, \"data\": {\"discountAmount\": 100, \"finalAmount\": 500, \"state\": \"confirmed\"}},
+      {\"action\": \"update\", \"id\": ${IDS[1]}, \"data\": {\"discountAmount\": 100, \"finalAmount\": 700, \"state\": \"confirmed\"}}
+    ]
+  }")
+echo "響應: $UPDATE_RES" | head -c 400
+echo ""
+echo ""
+
+# 3. atomic 模式下含失敗操作（預期整批回滾）
+echo "3. 含不存在記錄的批次（預期整批回滾）..."
+ATOMIC_RES=$(curl -s -X POST "$API_URL/join-records/batch" \
+  -H "Content-Type: application/json" \
+  -d "{
+    \"operations\": [
+      {\"action\": \"update\", \"id\": ${IDS[0]}, \"data\": {\"state\": \"cancelled\"}},
+      {\"action\": \"delete\", \"id\": 999999}
+    ]
+  }")
+echo "響應: $ATOMIC_RES" | head -c 400
+echo ""
+if echo "$ATOMIC_RES" | grep -q '"committed":false'; then
+    echo "✅ 正確回滾"
+else
+    echo "❌ 未回滾"
+fi
+echo ""
+
+# 4. 批次刪除（清理測試資料）
+echo "4. 批次刪除測試記錄..."
+DELETE_RES=$(curl -s -X POST "$API_URL/join-records/batch" \
+  -H "Content-Type: application/json" \
+  -d "{
+    \"operations\": [
+      {\"action\": \"delete\", \"id\": ${IDS[0]}},
+      {\"action\": \"delete\", \"id\": ${IDS[1]}}
+    ]
+  }")
+echo "響應: $DELETE_RES"
+echo ""
+
+echo "-----------------------------------------------"
+echo "🏁 參加記錄批次操作 API 測試完成"
+echo "-----------------------------------------------"
@@ -0,0 +1,100 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+REGISTRATION_ID=27  # 測試用的報名記錄 ID
+ACTIVITY_CODE="JFR$(date +%s | tail -c 6)"
+TEST_ADMIN="a4954ebc-8591-4288-8ebe-a4af19e718f7"
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 由報名記錄建立參加記錄 API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 建立測試活動
+echo "1. 建立測試活動..."
+RESPONSE=$(curl -s -X POST "$API_URL/activities" \
+  -H "Content-Type: application/json" \
+  -d "{\"activityId\": \"$ACTIVITY_CODE\", \"name\": \"由報名建立測試法會\", \"date\": \"2099-11-01\", \"state\": \"active\"}")
+echo "$RESPONSE"
+ACTIVITY_ID=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+echo -e "\n"
+
+# 2. 依報名記錄建立（超度、點燈、消災，價格取自生效中的價格配置）
+echo "2. 由報名記錄 $REGISTRATION_ID 建立參加記錄（第 1 位消災人員點太歲燈）..."
+RESPONSE=$(curl -s -X POST "$API_URL/join-records/from-registration" \
+  -H "Content-Type: application/json" \
+  -d "{
+    \"registrationId\": $REGISTRATION_ID,
+    \"activityId\": $ACTIVITY_ID,
+    \"itemTypes\": [\"chaodu\", \"survivors\", \"diandeng\", \"qifu\"],
+    \"personLampTypes\": {\"1\": \"taisui\"},
+    \"userId\": \"$TEST_ADMIN\"
+  }")
+echo "$RESPONSE"
+RECORD_ID=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+echo -e "\n"
+
+# 3. 只取指定的消災人員並折扣
+echo "3. 只為消災人員 1 建立消災祈福，折扣 100..."
+RESPONSE=$(curl -s -X POST "$API_URL/join-records/from-registration" \
+  -H "Content-Type: application/json" \
+  -d "{
+    \"registrationId\": $REGISTRATION_ID,
+    \"activityId\": $ACTIVITY_ID,
+    \"itemTypes\": [\"qifu\"],
+    \"selections\": {\"qifu\": [1]},
+    \"discountAmount\": 100,
+    \"userId\": \"$TEST_ADMIN\"
+  }")
+echo "$RESPONSE"
+RECORD_ID2=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+echo -e "\n"
+
+# 4. 活動指定的價格配置缺少項目時使用預設價格（消災祈福 300，與前端一致）
+echo "4. 活動改用只有 chaodu 的價格配置，消災祈福使用預設價格 300..."
+RESPONSE=$(curl -s -X POST "$API_URL/price-configs" \
+  -H "Content-Type: application/json" \
+  -d '{"version": "sh測試-缺項目", "state": "draft", "prices": {"chaodu": 500}}')
+PRICE_CONFIG_ID=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+curl -s -X PATCH "$API_URL/activities/$ACTIVITY_ID" \
+  -H "Content-Type: application/json" \
+  -d "{\"priceConfigId\": $PRICE_CONFIG_ID}" > /dev/null
+RESPONSE=$(curl -s -X POST "$API_URL/join-records/from-registration" \
+  -H "Content-Type: application/json" \
+  -d "{
+    \"registrationId\": $REGISTRATION_ID,
+    \"activityId\": $ACTIVITY_ID,
+    \"itemTypes\": [\"chaodu\", \"qifu\"],
+    \"userId\": \"$TEST_ADMIN\"
+  }")
+echo "$RESPONSE"
+RECORD_ID3=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+echo -e "\n"
+
+# 5. 不支援的項目類型（預期 400）
+echo "5. 不支援的項目類型 bogus（預期 400）..."
+curl -s -w "\nHTTP %{http_code}" -X POST "$API_URL/join-records/from-registration" \
+  -H "Content-Type: application/json" \
+  -d "{\"registrationId\": $REGISTRATION_ID, \"activityId\": $ACTIVITY_ID, \"itemTypes\": [\"bogus\"]}"
+echo -e "\n"
+
+# 6. 找不到報名記錄（預期 404）
+echo "6. 找不到報名記錄（預期 404）..."
+curl -s -w "\nHTTP %{http_code}" -X POST "$API_URL/join-records/from-registration" \
+  -H "Content-Type: application/json" \
+  -d "{\"registrationId\": 999999, \"activityId\": $ACTIVITY_ID, \"itemTypes\": [\"qifu\"]}"
+echo -e "\n"
+
+# 7. 清除測試資料
+echo "7. 刪除測試記錄、價格配置與活動..."
+curl -s -X DELETE "$API_URL/join-records/$RECORD_ID"
+curl -s -X DELETE "$API_URL/join-records/$RECORD_ID2"
+curl -s -X DELETE "$API_URL/join-records/$RECORD_ID3"
+curl -s -X DELETE "$API_URL/price-configs/$PRICE_CONFIG_ID"
+curl -s -X DELETE "$API_URL/activities/$ACTIVITY_ID"
+echo -e "\n"
+
+echo "-----------------------------------------------"
+echo "✅ 測試結束"
+echo "-----------------------------------------------"
@@ -0,0 +1,103 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+RECORD_ID=40  # 測試用的參加記錄 ID
+TEST_ADMIN="a4954ebc-8591-4288-8ebe-a4af19e718f7"
+HANDLED_BY="sh測試"
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 參加記錄付款流水 API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 查詢目前付款流水
+echo "1. 查詢參加記錄 $RECORD_ID 的付款流水..."
+LIST_RES=$(curl -s -X GET "$API_URL/join-records/$RECORD_ID/payments")
+echo "響應: $LIST_RES"
+echo ""
+
+# 2. 現金付款 1000
+echo "2. 新增現金付款 1000..."
+CASH_RES=$(curl -s -X POST "$API_URL/join-records/$RECORD_ID/payments" \
+  -H "Content-Type: application/json" \
+  -d "{
+    \"paymentType\": \"payment\",
+    \"amount\": 1000,
+    \"paymentMethod\": \"cash\",
+    \"handledBy\": \"$HANDLED_BY\",
+    \"userId\": \"$TEST_ADMIN\"
+  }")
+echo "響應: $CASH_RES"
+CASH_PAYMENT_ID=$(echo $CASH_RES | grep -oP '"payments":\[.*' | grep -oP '(?<="id":)[0-9]+' | tail -1)
+echo "✅ 現金付款流水 ID: $CASH_PAYMENT_ID"
+echo ""
+
+# 3. 轉帳付款 600
+echo "3. 新增轉帳付款 600..."
+TRANSFER_RES=$(curl -s -X POST "$API_URL/join-records/$RECORD_ID/payments" \
+  -H "Content-Type: application/json" \
+  -d "{
+    \"paymentType\": \"payment\",
+    \"amount\": 600,
+    \"paymentMethod\": \"transfer\",
+    \"handledBy\": \"$HANDLED_BY\",
+    \"userId\": \"$TEST_ADMIN\"
+  }")
+echo "響應: $TRANSFER_RES"
+echo "$TRANSFER_RES" | grep -oP '"paymentState":"[a-z]+"'
+echo ""
+
+# 4. 退款 100
+echo "4. 新增退款 100..."
+REFUND_RES=$(curl -s -X POST "$API_URL/join-records/$RECORD_ID/payments" \
+  -H "Content-Type: application/json" \
+  -d "{
+    \"paymentType\": \"refund\",
+    \"amount\": 100,
+    \"paymentMethod\": \"cash\",
+    \"notes\": \"sh測試退款\",
+    \"userId\": \"$TEST_ADMIN\"
+  }")
+echo "響應: $REFUND_RES"
+echo ""
+
+# 5. 退款超過已收金額（應失敗）
+echo "5. 測試退款超過已收金額（預期失敗）..."
+OVER_RES=$(curl -s -X POST "$API_URL/join-records/$RECORD_ID/payments" \
+  -H "Content-Type: application/json" \
+  -d "{\"paymentType\": \"refund\", \"amount\": 999999}")
+echo "響應: $OVER_RES"
+if echo "$OVER_RES" | grep -q '"success":false'; then
+    echo "✅ 正確拒絕超額退款"
+else
+    echo "❌ 超額退款未被拒絕"
+fi
+echo ""
+
+# 6. 沖銷第一筆現金付款
+if [ -n "$CASH_PAYMENT_ID" ]; then
+    echo "6. 沖銷現金付款流水 $CASH_PAYMENT_ID..."
+    REVERSE_RES=$(curl -s -X POST "$API_URL/join-records/$RECORD_ID/payments/$CASH_PAYMENT_ID/reverse" \
+      -H "Content-Type: application/json" \
+      -d "{\"reason\": \"sh測試沖銷\", \"handledBy\": \"$HANDLED_BY\", \"userId\": \"$TEST_ADMIN\"}")
+    echo "響應: $REVERSE_RES"
+    echo ""
+
+    echo "7. 重複沖銷同一筆（預期失敗）..."
+    AGAIN_RES=$(curl -s -X POST "$API_URL/join-records/$RECORD_ID/payments/$CASH_PAYMENT_ID/reverse" \
+      -H "Content-Type: application/json" \
+      -d "{}")
+    echo "響應: $AGAIN_RES"
+    echo ""
+fi
+
+# 8. 確認參加記錄的 paidAmount / paymentState
+echo "8. 查詢參加記錄 $RECORD_ID..."
+RECORD_RES=$(curl -s -X GET "$API_URL/join-records/$RECORD_ID")
+echo "$RECORD_RES" | grep -oP '"(paidAmount|finalAmount|paymentState)":("[a-z]+"|[0-9-]+)'
+echo ""
+
+echo "-----------------------------------------------"
+echo "🏁 付款流水 API 測試完成"
+echo "-----------------------------------------------"
@@ -0,0 +1,70 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+YEAR="${YEAR:-2026}"
+SUFFIX=$(date +%s)
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 農曆曆法 API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 農曆年曆
+echo "1. 查詢農曆 $YEAR 年的月份與節日..."
+curl -s "$API_URL/calendar/lunar?year=$YEAR"
+echo -e "\n"
+
+echo "2. 不合理的年份（預期 400）..."
+curl -s "$API_URL/calendar/lunar?year=1800"
+echo -e "\n"
+
+# 3. 國曆 ↔ 農曆
+echo "3. 國曆轉農曆（2026-08-27 應為七月十五）..."
+curl -s "$API_URL/calendar/convert?date=2026-08-27"
+echo -e "\n"
+
+echo "4. 農曆轉國曆（2025 閏六月初一應為 2025-07-25）..."
+curl -s "$API_URL/calendar/convert?lunarYear=2025&lunarMonth=6&lunarDay=1&leapMonth=true"
+echo -e "\n"
+
+echo "5. 不存在的農曆日期（預期 400）..."
+curl -s "$API_URL/calendar/convert?lunarYear=2026&lunarMonth=7&lunarDay=30"
+echo -e "\n"
+
+# 6. 以農曆日期建立活動
+echo "6. 以農曆七月十五建立 $YEAR 年中元普度..."
+RESPONSE=$(curl -s -X POST "$API_URL/activities" \
+  -H "Content-Type: application/json" \
+  -d "{
+    \"activityId\": \"LUNAR-$SUFFIX\",
+    \"name\": \"中元普度\",
+    \"lunarYear\": $YEAR,
+    \"lunarMonth\": 7,
+    \"lunarDay\": 15
+  }")
+echo "$RESPONSE"
+ACTIVITY_ID=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+echo -e "\n"
+
+if [ -n "$ACTIVITY_ID" ]; then
+  echo "7. 改為明年（依農曆重新換算國曆日期）..."
+  curl -s -X PATCH "$API_URL/activities/$ACTIVITY_ID" \
+    -H "Content-Type: application/json" \
+    -d "{\"lunarYear\": $((YEAR + 1))}"
+  echo -e "\n"
+
+  echo "8. 清理測試活動..."
+  curl -s -X DELETE "$API_URL/activities/$ACTIVITY_ID"
+  echo -e "\n"
+fi
+
+echo "9. 不存在的農曆日期建立活動（預期 422）..."
+curl -s -X POST "$API_URL/activities" \
+  -H "Content-Type: application/json" \
+  -d "{\"activityId\": \"LUNAR-BAD-$SUFFIX\", \"name\": \"x\", \"lunarYear\": 2026, \"lunarMonth\": 7, \"lunarDay\": 30}"
+echo -e "\n"
+
+echo "-----------------------------------------------"
+echo "✅ 農曆曆法 API 測試完成"
+echo "-----------------------------------------------"
@@ -0,0 +1,90 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api/monthly-donates"
+TEST_ADMIN="a4954ebc-8591-4288-8ebe-a4af19e718f7"
+NEXT_YEAR=$(( $(date +%Y) + 1 ))
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 每月贊助項目（donateItems）API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 建立測試贊助記錄（含一個既有項目）
+echo "1. 建立測試贊助記錄..."
+RESPONSE=$(curl -s -X POST "$API_URL" \
+  -H "Content-Type: application/json" \
+  -d "{
+    \"name\": \"項目測試贊助者\",
+    \"registrationId\": -1,
+    \"donateType\": \"xiaozai\",
+    \"donateItems\": [{\"donateItemsId\": \"seed001\", \"price\": 100, \"months\": [\"${NEXT_YEAR}01\", \"${NEXT_YEAR}02\"]}]
+  }")
+echo "$RESPONSE"
+ID=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+echo -e "\n"
+
+# 2. 新增贊助項目（只附加到陣列尾端，不覆寫既有項目）
+echo "2. 新增贊助項目 ${NEXT_YEAR}03~${NEXT_YEAR}04..."
+RESPONSE=$(curl -s -X POST "$API_URL/$ID/items" \
+  -H "Content-Type: application/json" \
+  -d "{\"price\": 200, \"months\": [\"${NEXT_YEAR}03\", \"${NEXT_YEAR}04\"], \"userId\": \"$TEST_ADMIN\"}")
+echo "$RESPONSE"
+ITEMS_ID=$(echo "$RESPONSE" | grep -o '"donateItemsId":"[^"]*"' | grep -v seed001 | head -1 | cut -d'"' -f4)
+echo -e "\n"
+echo "新增的 donateItemsId: $ITEMS_ID"
+echo -e "\n"
+
+# 3. 新增時未選月份（預期 400）
+echo "3. 新增時未選月份（預期 400）..."
+curl -s -w "\nHTTP %{http_code}" -X POST "$API_URL/$ID/items" \
+  -H "Content-Type: application/json" \
+  -d '{"price": 200, "months": []}'
+echo -e "\n"
+
+# 4. 更新贊助項目（只合併指定項目，seed001 不受影響）
+echo "4. 更新贊助項目 $ITEMS_ID 金額為 300..."
+curl -s -X PATCH "$API_URL/$ID/items/$ITEMS_ID" \
+  -H "Content-Type: application/json" \
+  -d "{\"price\": 300, \"userId\": \"$TEST_ADMIN\"}"
+echo -e "\n"
+
+# 5. 更新時未提供欄位（預期 400）
+echo "5. 更新時未提供任何欄位（預期 400）..."
+curl -s -w "\nHTTP %{http_code}" -X PATCH "$API_URL/$ID/items/$ITEMS_ID" \
+  -H "Content-Type: application/json" \
+  -d '{}'
+echo -e "\n"
+
+# 6. 更新不存在的項目（預期 404）
+echo "6. 更新不存在的贊助項目（預期 404）..."
+curl -s -w "\nHTTP %{http_code}" -X PATCH "$API_URL/$ID/items/nothere" \
+  -H "Content-Type: application/json" \
+  -d '{"price": 300}'
+echo -e "\n"
+
+# 7. 刪除贊助項目
+echo "7. 刪除贊助項目 $ITEMS_ID..."
+curl -s -X DELETE "$API_URL/$ID/items/$ITEMS_ID?userId=$TEST_ADMIN"
+echo -e "\n"
+
+# 8. 重複刪除（預期 404）
+echo "8. 再次刪除同一項目（預期 404）..."
+curl -s -w "\nHTTP %{http_code}" -X DELETE "$API_URL/$ID/items/$ITEMS_ID"
+echo -e "\n"
+
+# 9. 記錄不存在（預期 404）
+echo "9. 在不存在的記錄新增項目（預期 404）..."
+curl -s -w "\nHTTP %{http_code}" -X POST "$API_URL/999999/items" \
+  -H "Content-Type: application/json" \
+  -d "{\"price\": 200, \"months\": [\"${NEXT_YEAR}05\"]}"
+echo -e "\n"
+
+# 10. 清除測試資料
+echo "10. 刪除測試贊助記錄..."
+curl -s -X DELETE "$API_URL/$ID"
+echo -e "\n"
+
+echo "-----------------------------------------------"
+echo "✅ 測試結束"
+echo "-----------------------------------------------"
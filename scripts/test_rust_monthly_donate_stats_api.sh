@@ -0,0 +1,76 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api/monthly-donates/stats"
+THIS_YEAR=$(date +%Y)
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 每月贊助統計 API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 月度統計（今年）
+echo "1. 查詢 ${THIS_YEAR} 年月度統計..."
+curl -s -X GET "$API_URL/monthly?from=${THIS_YEAR}01&to=${THIS_YEAR}12"
+echo ""
+echo ""
+
+# 2. 贊助總覽
+echo "2. 查詢贊助總覽..."
+curl -s -X GET "$API_URL/donation"
+echo ""
+echo ""
+
+# 3. 贊助類型分布
+echo "3. 查詢贊助類型分布..."
+curl -s -X GET "$API_URL/types"
+echo ""
+echo ""
+
+# 4. 趨勢（月 / 季 / 年）
+for PERIOD in monthly quarterly yearly; do
+  echo "4. 查詢 $PERIOD 趨勢..."
+  curl -s -X GET "$API_URL/trend/$PERIOD?from=${THIS_YEAR}01"
+  echo ""
+  echo ""
+done
+
+# 5. 錯誤參數
+echo "5. 無效期間與月份格式（預期失敗）..."
+curl -s -X GET "$API_URL/trend/weekly"
+echo ""
+curl -s -X GET "$API_URL/monthly?from=${THIS_YEAR}"
+echo ""
+echo ""
+
+
+# 6. 續約名單與缺月名單
+BASE_URL="http://localhost:3000/api/monthly-donates"
+echo "6. 查詢 2 個月內到期的續約名單..."
+curl -s -X GET "$BASE_URL/renewals?within=2"
+echo ""
+echo ""
+echo "7. 查詢續約名單（含已過期）..."
+curl -s -X GET "$BASE_URL/renewals?within=2&includeExpired=true"
+echo ""
+echo ""
+echo "8. 查詢缺月名單..."
+curl -s -X GET "$BASE_URL/gaps"
+echo ""
+echo ""
+
+# 9. 每月分攤明細帳
+echo "9. 查詢 ${THIS_YEAR} 年每月分攤明細..."
+curl -s -X GET "$BASE_URL/ledger?from=${THIS_YEAR}01&to=${THIS_YEAR}12&limit=20"
+echo ""
+echo ""
+
+# 10. 月份範圍過長（預期 400）
+echo "10. 查詢超過 100 年的月趨勢（預期 400）..."
+curl -s -w "\nHTTP %{http_code}" -X GET "$BASE_URL/stats/trend/monthly?from=100001&to=999912"
+echo ""
+echo ""
+
+echo "-----------------------------------------------"
+echo "✅ 每月贊助統計 API 測試完成"
+echo "-----------------------------------------------"
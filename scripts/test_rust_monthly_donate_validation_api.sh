@@ -0,0 +1,109 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api/monthly-donates"
+NEXT_YEAR=$(( $(date +%Y) + 1 ))
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 每月贊助 donateItems 驗證"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 金額為 0 / 非整數（預期 400，errors 列出明細）
+echo "1. price 為 0 與字串（預期 400）..."
+curl -s -w "\nHTTP %{http_code}" -X POST "$API_URL" \
+  -H "Content-Type: application/json" \
+  -d "{
+    \"name\": \"驗證測試贊助者\",
+    \"donateType\": \"xiaozai\",
+    \"donateItems\": [
+      {\"donateItemsId\": \"a000001\", \"price\": 0, \"months\": [\"${NEXT_YEAR}01\"]},
+      {\"donateItemsId\": \"a000002\", \"price\": \"abc\", \"months\": [\"${NEXT_YEAR}02\"]}
+    ]
+  }"
+echo -e "\n"
+
+# 2. 月份格式錯誤 / 同一項目月份重複 / 未選月份（預期 400）
+echo "2. 無效月份 ${NEXT_YEAR}13、重複月份與空月份（預期 400）..."
+curl -s -w "\nHTTP %{http_code}" -X POST "$API_URL" \
+  -H "Content-Type: application/json" \
+  -d "{
+    \"name\": \"驗證測試贊助者\",
+    \"donateType\": \"xiaozai\",
+    \"donateItems\": [
+      {\"donateItemsId\": \"b000001\", \"price\": 100, \"months\": [\"${NEXT_YEAR}13\", \"2026-01\"]},
+      {\"donateItemsId\": \"b000002\", \"price\": 100, \"months\": [\"${NEXT_YEAR}03\", \"${NEXT_YEAR}03\"]},
+      {\"donateItemsId\": \"b000003\", \"price\": 100, \"months\": []}
+    ]
+  }"
+echo -e "\n"
+
+# 3. donateItems 不是陣列（預期 400）
+echo "3. donateItems 為物件（預期 400）..."
+curl -s -w "\nHTTP %{http_code}" -X POST "$API_URL" \
+  -H "Content-Type: application/json" \
+  -d '{"name": "驗證測試贊助者", "donateType": "xiaozai", "donateItems": {"price": 100}}'
+echo -e "\n"
+
+# 4. 不同項目月份重疊（預期 409）
+echo "4. 兩個項目都包含 ${NEXT_YEAR}05（預期 409）..."
+OVERLAP_ITEMS="[
+  {\"donateItemsId\": \"c000001\", \"price\": 100, \"months\": [\"${NEXT_YEAR}04\", \"${NEXT_YEAR}05\"]},
+  {\"donateItemsId\": \"c000002\", \"price\": 200, \"months\": [\"${NEXT_YEAR}05\", \"${NEXT_YEAR}06\"]}
+]"
+curl -s -w "\nHTTP %{http_code}" -X POST "$API_URL" \
+  -H "Content-Type: application/json" \
+  -d "{\"name\": \"驗證測試贊助者\", \"donateType\": \"xiaozai\", \"donateItems\": $OVERLAP_ITEMS}"
+echo -e "\n"
+
+# 5. 設定 allowOverlap 後允許重複贊助
+echo "5. 同樣資料加上 allowOverlap（預期成功）..."
+RESPONSE=$(curl -s -X POST "$API_URL" \
+  -H "Content-Type: application/json" \
+  -d "{\"name\": \"驗證測試贊助者\", \"donateType\": \"xiaozai\", \"donateItems\": $OVERLAP_ITEMS, \"allowOverlap\": true}")
+echo "$RESPONSE"
+ID=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+echo -e "\n"
+
+# 6. 整筆更新時同樣驗證（預期 409 / 400）
+echo "6. PATCH 記錄：重疊月份（預期 409）..."
+curl -s -w "\nHTTP %{http_code}" -X PATCH "$API_URL/$ID" \
+  -H "Content-Type: application/json" \
+  -d "{\"donateItems\": $OVERLAP_ITEMS}"
+echo -e "\n"
+echo "6. PATCH 記錄：負數金額（預期 400）..."
+curl -s -w "\nHTTP %{http_code}" -X PATCH "$API_URL/$ID" \
+  -H "Content-Type: application/json" \
+  -d "{\"donateItems\": [{\"donateItemsId\": \"c000001\", \"price\": -50, \"months\": [\"${NEXT_YEAR}04\"]}]}"
+echo -e "\n"
+
+# 7. 單一項目新增 / 更新：只檢查與該項目重疊的月份
+echo "7. 新增與 c000001 重疊的項目（預期 409）..."
+curl -s -w "\nHTTP %{http_code}" -X POST "$API_URL/$ID/items" \
+  -H "Content-Type: application/json" \
+  -d "{\"price\": 300, \"months\": [\"${NEXT_YEAR}04\"]}"
+echo -e "\n"
+echo "7. 新增不重疊的項目（既有 c000001/c000002 的重疊不影響，預期成功）..."
+curl -s -w "\nHTTP %{http_code}" -X POST "$API_URL/$ID/items" \
+  -H "Content-Type: application/json" \
+  -d "{\"price\": 300, \"months\": [\"${NEXT_YEAR}07\"]}"
+echo -e "\n"
+echo "7. 更新 c000002 月份改為與 c000001 重疊並帶 allowOverlap（預期成功）..."
+curl -s -w "\nHTTP %{http_code}" -X PATCH "$API_URL/$ID/items/c000002" \
+  -H "Content-Type: application/json" \
+  -d "{\"months\": [\"${NEXT_YEAR}04\"], \"allowOverlap\": true}"
+echo -e "\n"
+echo "7. 更新 c000002 無效月份（預期 400）..."
+curl -s -w "\nHTTP %{http_code}" -X PATCH "$API_URL/$ID/items/c000002" \
+  -H "Content-Type: application/json" \
+  -d "{\"months\": [\"${NEXT_YEAR}00\"]}"
+echo -e "\n"
+
+# 8. 清除測試資料
+echo "8. 刪除測試贊助記錄..."
+curl -s -X DELETE "$API_URL/$ID"
+echo -e "\n"
+
+echo "-----------------------------------------------"
+echo "✅ 測試結束"
+echo "-----------------------------------------------"
@@ -0,0 +1,126 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+BATCH_DATE=$(date +%F)  # 沖帳日期（預設今天）
+TEST_ADMIN="a4954ebc-8591-4288-8ebe-a4af19e718f7"
+RECONCILED_BY="sh測試"
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 沖帳批次 API ($BATCH_DATE)"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 沖帳預覽
+echo "1. 查詢 $BATCH_DATE 沖帳預覽..."
+PREVIEW_RES=$(curl -s -X GET "$API_URL/reconciliation-batches/preview?date=$BATCH_DATE")
+echo "響應: $PREVIEW_RES"
+EXPECTED_CASH=$(echo $PREVIEW_RES | grep -oP '(?<="expectedCash":)[0-9-]+')
+echo "✅ 應收現金: $EXPECTED_CASH"
+echo ""
+
+# 2. 建立沖帳批次（實點現金少 100）
+echo "2. 建立沖帳批次..."
+COUNTED=$(( ${EXPECTED_CASH:-0} - 100 ))
+CREATE_RES=$(curl -s -X POST "$API_URL/reconciliation-batches" \
+  -H "Content-Type: application/json" \
+  -d "{
+    \"batchDate\": \"$BATCH_DATE\",
+    \"countedAmount\": $COUNTED,
+    \"reconciledBy\": \"$RECONCILED_BY\",
+    \"notes\": \"sh測試沖帳\",
+    \"userId\": \"$TEST_ADMIN\"
+  }")
+echo "響應: $CREATE_RES"
+BATCH_ID=$(echo $CREATE_RES | grep -oP '(?<="id":)[0-9]+' | head -1)
+echo "✅ 沖帳批次 ID: $BATCH_ID"
+echo ""
+
+# 3. 再次沖帳同一天（預期失敗：已無可沖帳記錄）
+echo "3. 重複沖帳同一天（預期失敗）..."
+AGAIN_RES=$(curl -s -X POST "$API_URL/reconciliation-batches" \
+  -H "Content-Type: application/json" \
+  -d "{\"batchDate\": \"$BATCH_DATE\", \"countedAmount\": 0}")
+echo "響應: $AGAIN_RES"
+if echo "$AGAIN_RES" | grep -q '"success":false'; then
+    echo "✅ 正確拒絕重複沖帳"
+else
+    echo "❌ 重複沖帳未被拒絕"
+fi
+echo ""
+
+if [ -n "$BATCH_ID" ]; then
+    # 4. 查詢批次
+    echo "4. 查詢沖帳批次 $BATCH_ID..."
+    curl -s -X GET "$API_URL/reconciliation-batches/$BATCH_ID"
+    echo ""
+    echo ""
+
+    # 5. 作廢批次
+    echo "5. 作廢沖帳批次 $BATCH_ID..."
+    VOID_RES=$(curl -s -X POST "$API_URL/reconciliation-batches/$BATCH_ID/void" \
+      -H "Content-Type: application/json" \
+      -d "{\"voidReason\": \"sh測試作廢\", \"userId\": \"$TEST_ADMIN\"}")
+    echo "響應: $VOID_RES"
+    echo ""
+fi
+
+# 6. 列出當日批次
+echo "6. 列出 $BATCH_DATE 的沖帳批次..."
+curl -s -X GET "$API_URL/reconciliation-batches?batchDate=$BATCH_DATE"
+echo ""
+echo ""
+
+# 7. 分次付款：已沖帳記錄的後續付款仍需沖帳
+RECORD_ID=40
+echo "7. 參加記錄 $RECORD_ID 現金付款 100 並沖帳，再轉帳付款 100..."
+curl -s -X POST "$API_URL/join-records/$RECORD_ID/payments" -H "Content-Type: application/json" \
+  -d "{\"amount\": 100, \"paymentMethod\": \"cash\", \"userId\": \"$TEST_ADMIN\"}" > /dev/null
+PARTIAL_RES=$(curl -s -X POST "$API_URL/reconciliation-batches" -H "Content-Type: application/json" \
+  -d "{\"batchDate\": \"$BATCH_DATE\", \"countedAmount\": 100, \"recordIds\": [$RECORD_ID], \"userId\": \"$TEST_ADMIN\"}")
+echo "響應: $PARTIAL_RES"
+curl -s -X POST "$API_URL/join-records/$RECORD_ID/payments" -H "Content-Type: application/json" \
+  -d "{\"amount\": 100, \"paymentMethod\": \"transfer\", \"userId\": \"$TEST_ADMIN\"}" > /dev/null
+echo "預覽（預期 recordIds 含 $RECORD_ID，expectedTransfer 含 100）..."
+curl -s -X GET "$API_URL/reconciliation-batches/preview?date=$BATCH_DATE"
+echo ""
+echo ""
+
+# 8. 舊記錄（無流水）已沖帳後新增付款：期初付款流水沿用原批次，不重複列入預覽
+echo "8. 建立已付 300（無流水）的舊記錄並沖帳，再現金付款 50..."
+LEGACY_RES=$(curl -s -X POST "$API_URL/join-records" -H "Content-Type: application/json" \
+  -d "{\"activityId\": 54, \"state\": \"confirmed\", \"totalAmount\": 500, \"finalAmount\": 500,
+       \"paidAmount\": 300, \"paymentState\": \"partial\", \"paymentMethod\": \"cash\", \"paymentDate\": \"$(date -Iseconds)\"}")
+LEGACY_ID=$(echo $LEGACY_RES | grep -oP '(?<="id":)[0-9]+' | head -1)
+curl -s -X POST "$API_URL/reconciliation-batches" -H "Content-Type: application/json" \
+  -d "{\"batchDate\": \"$BATCH_DATE\", \"countedAmount\": 300, \"recordIds\": [$LEGACY_ID], \"userId\": \"$TEST_ADMIN\"}" > /dev/null
+curl -s -X POST "$API_URL/join-records/$LEGACY_ID/payments" -H "Content-Type: application/json" \
+  -d "{\"amount\": 50, \"paymentMethod\": \"cash\", \"userId\": \"$TEST_ADMIN\"}" > /dev/null
+LINES=$(curl -s -X GET "$API_URL/reconciliation-batches/preview?date=$BATCH_DATE&paymentMethod=cash" | grep -o "{\"joinRecordId\":$LEGACY_ID,[^}]*}")
+echo "預覽明細: $LINES"
+if [ "$LINES" = "{\"joinRecordId\":$LEGACY_ID,\"paymentMethod\":\"cash\",\"amount\":50}" ]; then
+    echo "✅ 只列出新付款 50，期初 300 未重複列入"
+else
+    echo "❌ 期初付款被重複列入預覽"
+fi
+echo ""
+
+# 9. 只沖帳轉帳：差額以轉帳應收金額計算
+echo "9. 參加記錄 $RECORD_ID 的轉帳付款沖帳，實點 90（預期 variance = 90 - expectedTransfer）..."
+curl -s -X POST "$API_URL/join-records/$RECORD_ID/payments" -H "Content-Type: application/json" \
+  -d "{\"amount\": 100, \"paymentMethod\": \"transfer\", \"userId\": \"$TEST_ADMIN\"}" > /dev/null
+TRANSFER_RES=$(curl -s -X POST "$API_URL/reconciliation-batches" -H "Content-Type: application/json" \
+  -d "{\"batchDate\": \"$BATCH_DATE\", \"paymentMethod\": \"transfer\", \"countedAmount\": 90, \"recordIds\": [$RECORD_ID], \"userId\": \"$TEST_ADMIN\"}")
+echo "響應: $TRANSFER_RES"
+TRANSFER_EXPECTED=$(echo $TRANSFER_RES | grep -oP '(?<="expectedTransfer":)[0-9-]+')
+TRANSFER_VARIANCE=$(echo $TRANSFER_RES | grep -oP '(?<="variance":)[0-9-]+')
+if [ -n "$TRANSFER_EXPECTED" ] && [ "$TRANSFER_VARIANCE" = "$(( 90 - TRANSFER_EXPECTED ))" ]; then
+    echo "✅ 差額以轉帳應收 $TRANSFER_EXPECTED 計算"
+else
+    echo "❌ 差額計算錯誤"
+fi
+echo ""
+
+echo "-----------------------------------------------"
+echo "🏁 沖帳批次 API 測試完成"
+echo "-----------------------------------------------"
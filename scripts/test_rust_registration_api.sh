@@ -126,4 +126,35 @@ echo -e "\n"
 # curl -s "$BASE_URL/api/registrations" | jq .
 # echo -e "\n"
 
-echo "✅ Registration API 測試完成！"
\ No newline at end of file
+# 18. 業務規則驗證（預期 422，errors 為「欄位: 訊息」）
+echo "1️⃣8️⃣ 手機格式錯誤、其它關係未說明、有祖先無陽上人"
+curl -s -X POST "$BASE_URL/api/registrations" \
+  -H "Content-Type: application/json" \
+  -d '{
+    "formId": "RUST-2024-INVALID",
+    "contact": {"name": "RUST驗證", "phone": "", "mobile": "0812345678", "relationship": "其它", "otherRelationship": ""},
+    "blessing": {"address": "台北市", "persons": [{"id": 1, "name": "甲", "zodiac": "龍", "notes": "", "isHouseholdHead": false}]},
+    "salvation": {"address": "台北市", "ancestors": [{"id": 1, "surname": "王府", "notes": ""}], "survivors": []}
+  }' | jq .
+echo -e "\n"
+
+echo "1️⃣9️⃣ 更新時清空消災與超度（預期 422：至少需填寫一項）"
+curl -s -X PATCH "$BASE_URL/api/registrations/$REGISTRATION_ID" \
+  -H "Content-Type: application/json" \
+  -d '{
+    "blessing": {"address": "", "persons": []},
+    "salvation": {"address": "", "ancestors": [], "survivors": []}
+  }' | jq .
+echo -e "\n"
+
+echo "2️⃣0️⃣ 更新時保留客戶端額外欄位（祖先生肖、聯絡人 email）"
+curl -s -X PATCH "$BASE_URL/api/registrations/$REGISTRATION_ID" \
+  -H "Content-Type: application/json" \
+  -d '{
+    "contact": {"name": "RUST張三RUST", "phone": "02-87654321", "mobile": "0988-765-432", "relationship": "本家", "otherRelationship": "", "email": "zhangsan@example.com"},
+    "salvation": {"address": "台北市中正區", "ancestors": [{"id": 1, "surname": "張府", "zodiac": "虎", "notes": "歷代祖先"}], "survivors": [{"id": 1, "name": "張三", "zodiac": "龍", "notes": ""}]}
+  }' > /dev/null
+curl -s "$BASE_URL/api/registrations/$REGISTRATION_ID" | jq '{email: .data.contact.email, ancestors: .data.salvation.ancestors}'
+echo -e "\n"
+
+echo "✅ Registration API 測試完成！"
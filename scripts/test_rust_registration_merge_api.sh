@@ -0,0 +1,53 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+KEEP_ID="${KEEP_ID:-32}"
+MERGE_ID="${MERGE_ID:-80}"
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 重複報名偵測與合併 API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 疑似重複（預設 minScore=40）
+echo "1. 查詢疑似重複的報名記錄（前 5 組）..."
+curl -s "$API_URL/registrations/duplicates?limit=5"
+echo -e "\n"
+
+echo "2. 只列出高度相似（minScore=80）..."
+curl -s "$API_URL/registrations/duplicates?minScore=80&limit=3"
+echo -e "\n"
+
+# 3. 合併（不符合名單上限時預期 422）
+echo "3. 合併 $MERGE_ID → $KEEP_ID（未設定 force）..."
+curl -s -X POST "$API_URL/registrations/merge" -H "Content-Type: application/json" \
+  -d "{\"keepId\": $KEEP_ID, \"mergeId\": $MERGE_ID}"
+echo -e "\n"
+
+# 被併入者的參加記錄（合併後 sourceData 的 ID 應改為保留者名單中的 ID）
+JOIN_RECORD_ID=$(curl -s -X POST "$API_URL/join-records/from-registration" -H "Content-Type: application/json" \
+  -d "{\"registrationId\": $MERGE_ID, \"activityId\": ${ACTIVITY_ID:-54}, \"itemTypes\": [\"qifu\", \"diandeng\", \"chaodu\"]}" |
+  grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+
+echo "4. 強制合併 $MERGE_ID → $KEEP_ID..."
+curl -s -X POST "$API_URL/registrations/merge" -H "Content-Type: application/json" \
+  -d "{\"keepId\": $KEEP_ID, \"mergeId\": $MERGE_ID, \"force\": true}"
+echo -e "\n"
+
+# 5. 已封存的記錄不可再合併
+echo "5. 再次合併已封存的記錄（預期失敗）..."
+curl -s -X POST "$API_URL/registrations/merge" -H "Content-Type: application/json" \
+  -d "{\"keepId\": $KEEP_ID, \"mergeId\": $MERGE_ID}"
+echo -e "\n"
+
+# 6. 改指向保留者的參加記錄使用合併後的名單 ID
+echo "6. 參加記錄 $JOIN_RECORD_ID 的 sourceData 與保留者 $KEEP_ID 的名單..."
+curl -s "$API_URL/join-records/$JOIN_RECORD_ID" | grep -o '"type":"[a-z_]*"\|"sourceData":\[[^]]*\]'
+echo ""
+curl -s "$API_URL/registrations/$KEEP_ID" | grep -o '"persons":\[[^]]*\]\|"ancestors":\[[^]]*\]'
+echo -e "\n"
+
+echo "-----------------------------------------------"
+echo "✅ 重複報名偵測與合併 API 測試完成"
+echo "-----------------------------------------------"
@@ -0,0 +1,90 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+ACTIVITY_CODE="SD$(date +%s | tail -c 6)"
+USER_ID="d7fe9cb7-26cd-419a-96f5-3dce505844bf"
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 軟刪除 / 還原 / 永久刪除 API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 建立測試活動
+echo "1. 建立測試活動..."
+RESPONSE=$(curl -s -X POST "$API_URL/activities" \
+  -H "Content-Type: application/json" \
+  -d "{\"activityId\": \"$ACTIVITY_CODE\", \"name\": \"軟刪除測試法會\", \"date\": \"2099-10-01\"}")
+echo "$RESPONSE"
+ID=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+echo -e "\n"
+
+# 2. 軟刪除（記錄刪除者）
+echo "2. 刪除活動 ID: $ID（記錄 userDeleted）..."
+curl -s -X DELETE "$API_URL/activities/$ID?userDeleted=$USER_ID"
+echo -e "\n"
+
+# 3. 刪除後查詢（預期 404），列表中不再出現
+echo "3. 刪除後查詢（預期 404）..."
+curl -s -w "\nHTTP %{http_code}" "$API_URL/activities/$ID"
+echo ""
+curl -s "$API_URL/activities" | grep -c "\"activityId\":\"$ACTIVITY_CODE\""
+echo -e "\n"
+
+# 4. 重複刪除（預期 404）
+echo "4. 重複刪除（預期 404）..."
+curl -s -w "\nHTTP %{http_code}" -X DELETE "$API_URL/activities/$ID"
+echo -e "\n"
+
+# 5. 還原
+echo "5. 還原活動 ID: $ID..."
+curl -s -X POST "$API_URL/activities/$ID/restore"
+echo -e "\n"
+
+# 6. 還原未刪除的資料（預期 404）
+echo "6. 還原未刪除的活動（預期 404）..."
+curl -s -w "\nHTTP %{http_code}" -X POST "$API_URL/activities/$ID/restore"
+echo -e "\n"
+
+# 7. 立即永久刪除超過保留期限的資料
+echo "7. 永久刪除超過 90 天的已刪除資料..."
+curl -s -X POST "$API_URL/deleted/purge?retentionDays=90"
+echo -e "\n"
+
+# 8. 保留天數少於 7 天或過大（預期 400）
+echo "8. retentionDays=0 與 retentionDays=1000000000000（預期 400）..."
+curl -s -w "\nHTTP %{http_code}" -X POST "$API_URL/deleted/purge?retentionDays=0"
+echo ""
+curl -s -w "\nHTTP %{http_code}" -X POST "$API_URL/deleted/purge?retentionDays=1000000000000"
+echo -e "\n"
+
+# 9. 已沖帳的參加記錄不可刪除（預期 409），作廢沖帳批次後可刪除
+echo "9. 建立已付款參加記錄並沖帳後刪除（預期 409）..."
+RESPONSE=$(curl -s -X POST "$API_URL/join-records" \
+  -H "Content-Type: application/json" \
+  -d "{\"activityId\": $ID, \"state\": \"confirmed\", \"totalAmount\": 100, \"finalAmount\": 100}")
+RECORD_ID=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+curl -s -X POST "$API_URL/join-records/$RECORD_ID/payments" \
+  -H "Content-Type: application/json" \
+  -d '{"amount": 100, "paymentMethod": "cash"}' > /dev/null
+RESPONSE=$(curl -s -X POST "$API_URL/reconciliation-batches" \
+  -H "Content-Type: application/json" \
+  -d "{\"batchDate\": \"$(date +%F)\", \"countedAmount\": 100, \"recordIds\": [$RECORD_ID]}")
+BATCH_ID=$(echo "$RESPONSE" | grep -o '"id":[0-9]*' | head -1 | cut -d: -f2)
+curl -s -w "\nHTTP %{http_code}" -X DELETE "$API_URL/join-records/$RECORD_ID"
+echo ""
+echo "作廢沖帳批次 $BATCH_ID 後再刪除..."
+curl -s -X POST "$API_URL/reconciliation-batches/$BATCH_ID/void" \
+  -H "Content-Type: application/json" \
+  -d '{"voidReason": "軟刪除測試"}' > /dev/null
+curl -s -w "\nHTTP %{http_code}" -X DELETE "$API_URL/join-records/$RECORD_ID"
+echo -e "\n"
+
+# 10. 清除測試活動（軟刪除，保留期限過後由背景工作永久刪除）
+echo "10. 刪除測試活動..."
+curl -s -X DELETE "$API_URL/activities/$ID"
+echo -e "\n"
+
+echo "-----------------------------------------------"
+echo "✅ 測試結束"
+echo "-----------------------------------------------"
@@ -0,0 +1,40 @@
+#!/bin/bash
+
+# 設定 API 基礎路徑
+API_URL="http://localhost:3000/api"
+YEAR="${YEAR:-2027}"
+
+echo "-----------------------------------------------"
+echo "🚀 開始測試 Rust 太歲 API"
+echo "-----------------------------------------------"
+echo ""
+
+# 1. 指定年份犯太歲的生肖
+echo "1. 查詢 $YEAR 年值 / 沖 / 刑 / 害 / 破太歲..."
+curl -s "$API_URL/taisui?year=$YEAR"
+echo -e "\n"
+
+echo "2. 未指定年份（預設今年）..."
+curl -s "$API_URL/taisui"
+echo -e "\n"
+
+echo "3. 不合理的年份（預期 400）..."
+curl -s "$API_URL/taisui?year=1800"
+echo -e "\n"
+
+# 4. 犯太歲的消災人員
+echo "4. $YEAR 年犯太歲的消災人員（前 10 筆）..."
+curl -s "$API_URL/registrations/taisui-candidates?year=$YEAR&limit=10"
+echo -e "\n"
+
+echo "5. 只列出沖太歲..."
+curl -s -G "$API_URL/registrations/taisui-candidates" --data-urlencode "year=$YEAR" --data-urlencode "taiSuiType=沖太歲"
+echo -e "\n"
+
+echo "6. 無效的太歲類型（預期 400）..."
+curl -s -G "$API_URL/registrations/taisui-candidates" --data-urlencode "taiSuiType=犯太歲"
+echo -e "\n"
+
+echo "-----------------------------------------------"
+echo "✅ 太歲 API 測試完成"
+echo "-----------------------------------------------"